@@ -108,6 +108,74 @@ pub struct MerchantAccountCreate {
     /// Default payment method collect link config
     #[schema(value_type = Option<BusinessCollectLinkConfig>)]
     pub pm_collect_link_config: Option<BusinessCollectLinkConfig>,
+
+    /// The time in seconds after which the lock held on a resource of the merchant, such as a
+    /// payment, expires while a request on it is being processed. The lock is released once the
+    /// request completes, so the ttl only comes into effect for slow or abruptly terminated
+    /// requests. A shorter ttl risks a retried request being processed alongside a slow original
+    /// request, while a longer ttl holds the lock unnecessarily after a request was terminated.
+    /// Defaults to the lock expiry configured for the application when not set.
+    #[schema(minimum = 10, maximum = 3600, example = 180)]
+    pub idempotency_ttl_in_seconds: Option<u32>,
+
+    /// Additional fields of the customers of the merchant which are encrypted at rest, on top of the
+    /// fields which are always encrypted such as the name, email and phone. The fields are keys of
+    /// the customer metadata, specified as `metadata.<key>`, such as `metadata.national_id`. The
+    /// customers can still be listed by the value of these fields, which is matched through a hash
+    /// of the value.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["metadata.national_id"]))]
+    pub customer_pii_encryption_fields: Option<Vec<String>>,
+
+    /// How the statement descriptors of a payment which exceed the maximum length supported by the
+    /// connector are handled. The descriptors are truncated by default, and the payment response
+    /// carries a warning when a descriptor was altered.
+    #[schema(value_type = Option<StatementDescriptorLengthPolicy>, example = "reject")]
+    pub statement_descriptor_length_policy: Option<api_enums::StatementDescriptorLengthPolicy>,
+
+    /// The domains to which the customer may be redirected after a payment. A `return_url` of a
+    /// payment whose host is not in the list is rejected. A domain prefixed with `*.` allows all of
+    /// its subdomains. Any `return_url` is allowed when the list is not configured.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["example.com", "*.example.com"]))]
+    pub allowed_return_url_domains: Option<Vec<String>>,
+
+    /// The capture method of the payments which do not specify one. The capture method of a
+    /// payment takes precedence over the default.
+    #[schema(value_type = Option<CaptureMethod>, example = "manual")]
+    pub default_capture_method: Option<api_enums::CaptureMethod>,
+
+    /// The minimum and maximum amounts of the payments of the merchant, per currency and
+    /// optionally per payment method type. The payments whose amount is out of the limits are
+    /// rejected. There must be at most one limit per currency and payment method type.
+    #[schema(value_type = Option<Vec<TransactionAmountLimit>>)]
+    pub transaction_amount_limits: Option<Vec<TransactionAmountLimit>>,
+
+    /// The currency of the payments which do not specify one. The currency of a payment takes
+    /// precedence over the default.
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub default_currency: Option<api_enums::Currency>,
+
+    /// Whether the payments must specify their currency, which disables the default currency. This
+    /// suits the merchants which accept payments in several currencies, for which no currency is a
+    /// sensible default. Disabled by default.
+    #[schema(default = false, example = false)]
+    pub require_explicit_currency: Option<bool>,
+
+    /// The order in which the connectors are tried for a payment, as rules which are evaluated in
+    /// order. The connectors of the first rule which applies to the payment are tried. The
+    /// connectors which are not configured and enabled for the merchant are left out of the
+    /// failover chain of a payment.
+    #[schema(value_type = Option<Vec<ConnectorFailoverRule>>)]
+    pub connector_failover_order: Option<Vec<ConnectorFailoverRule>>,
+}
+
+/// Deserialize a field of an update which is removed when `null` is passed as `Some(None)`, unlike
+/// a field which is not passed, which is left as it is
+fn deserialize_nullable_field<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
 }
 
 #[cfg(feature = "v1")]
@@ -320,6 +388,82 @@ pub struct MerchantAccountUpdate {
     /// Default payment method collect link config
     #[schema(value_type = Option<BusinessCollectLinkConfig>)]
     pub pm_collect_link_config: Option<BusinessCollectLinkConfig>,
+
+    /// The time in seconds after which the lock held on a resource of the merchant, such as a
+    /// payment, expires while a request on it is being processed. The lock is released once the
+    /// request completes, so the ttl only comes into effect for slow or abruptly terminated
+    /// requests. A shorter ttl risks a retried request being processed alongside a slow original
+    /// request, while a longer ttl holds the lock unnecessarily after a request was terminated.
+    /// Defaults to the lock expiry configured for the application when not set.
+    #[schema(minimum = 10, maximum = 3600, example = 180)]
+    pub idempotency_ttl_in_seconds: Option<u32>,
+
+    /// Additional fields of the customers of the merchant which are encrypted at rest, on top of the
+    /// fields which are always encrypted such as the name, email and phone. The fields are keys of
+    /// the customer metadata, specified as `metadata.<key>`, such as `metadata.national_id`. The
+    /// customers can still be listed by the value of these fields, which is matched through a hash
+    /// of the value.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["metadata.national_id"]))]
+    pub customer_pii_encryption_fields: Option<Vec<String>>,
+
+    /// The connector to which payments of each payment method type are routed by default, when no
+    /// routing algorithm is active on the business profile or no rule of the active routing
+    /// algorithm matches. Each connector must be configured for the merchant with the payment
+    /// method type enabled.
+    #[schema(value_type = Option<HashMap<PaymentMethodType, RoutableConnectors>>, example = json!({"credit": "stripe", "apple_pay": "adyen"}))]
+    pub default_connector_by_method_type:
+        Option<HashMap<api_enums::PaymentMethodType, api_enums::RoutableConnectors>>,
+
+    /// How the statement descriptors of a payment which exceed the maximum length supported by the
+    /// connector are handled. The descriptors are truncated by default, and the payment response
+    /// carries a warning when a descriptor was altered.
+    #[schema(value_type = Option<StatementDescriptorLengthPolicy>, example = "reject")]
+    pub statement_descriptor_length_policy: Option<api_enums::StatementDescriptorLengthPolicy>,
+
+    /// The domains to which the customer may be redirected after a payment. A `return_url` of a
+    /// payment whose host is not in the list is rejected. A domain prefixed with `*.` allows all of
+    /// its subdomains. Any `return_url` is allowed when the list is not configured.
+    #[schema(value_type = Option<Vec<String>>, example = json!(["example.com", "*.example.com"]))]
+    pub allowed_return_url_domains: Option<Vec<String>>,
+
+    /// The order in which the connectors are tried for a payment, as rules which are evaluated in
+    /// order. The connectors of the first rule which applies to the payment are tried. Every
+    /// connector must be configured and enabled for the merchant, and the connectors which are
+    /// disabled after the update are left out of the failover chain of a payment.
+    #[schema(value_type = Option<Vec<ConnectorFailoverRule>>)]
+    pub connector_failover_order: Option<Vec<ConnectorFailoverRule>>,
+
+    /// The capture method of the payments which do not specify one. The capture method of a
+    /// payment takes precedence over the default. Every connector configured and enabled for the
+    /// merchant must support the capture method, as must the connectors configured or enabled
+    /// later. The default is removed when `null` is passed.
+    #[schema(value_type = Option<CaptureMethod>, example = "manual")]
+    #[serde(default, deserialize_with = "deserialize_nullable_field")]
+    pub default_capture_method: Option<Option<api_enums::CaptureMethod>>,
+
+    /// The least cost routing of the merchant, which routes each payment to the eligible connector
+    /// with the lowest estimated cost for the payment, from the cost models of the connectors.
+    #[schema(value_type = Option<LeastCostRoutingConfig>)]
+    pub least_cost_routing: Option<LeastCostRoutingConfig>,
+
+    /// The minimum and maximum amounts of the payments of the merchant, per currency and
+    /// optionally per payment method type. The payments whose amount is out of the limits are
+    /// rejected. There must be at most one limit per currency and payment method type.
+    #[schema(value_type = Option<Vec<TransactionAmountLimit>>)]
+    pub transaction_amount_limits: Option<Vec<TransactionAmountLimit>>,
+
+    /// The currency of the payments which do not specify one. The currency of a payment takes
+    /// precedence over the default. At least one connector configured and enabled for the merchant
+    /// must support the currency. The default is removed when `null` is passed.
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    #[serde(default, deserialize_with = "deserialize_nullable_field")]
+    pub default_currency: Option<Option<api_enums::Currency>>,
+
+    /// Whether the payments must specify their currency, which disables the default currency. This
+    /// suits the merchants which accept payments in several currencies, for which no currency is a
+    /// sensible default. Disabled by default.
+    #[schema(default = false, example = false)]
+    pub require_explicit_currency: Option<bool>,
 }
 
 #[cfg(feature = "v1")]
@@ -342,6 +486,17 @@ impl MerchantAccountUpdate {
             .transpose()
     }
 
+    pub fn get_default_connector_by_method_type_as_value(
+        &self,
+    ) -> CustomResult<Option<serde_json::Value>, errors::ParsingError> {
+        self.default_connector_by_method_type
+            .as_ref()
+            .map(|default_connector_by_method_type| {
+                default_connector_by_method_type.encode_to_value()
+            })
+            .transpose()
+    }
+
     pub fn get_merchant_details_as_secret(
         &self,
     ) -> CustomResult<Option<pii::SecretSerdeValue>, errors::ParsingError> {
@@ -513,6 +668,57 @@ pub struct MerchantAccountResponse {
     /// Default payment method collect link config
     #[schema(value_type = Option<BusinessCollectLinkConfig>)]
     pub pm_collect_link_config: Option<BusinessCollectLinkConfig>,
+
+    /// The time in seconds after which the lock held on a resource of the merchant expires while a
+    /// request on it is being processed. The lock expiry configured for the application is used
+    /// when not set.
+    #[schema(example = 180)]
+    pub idempotency_ttl_in_seconds: Option<i64>,
+
+    /// Additional fields of the customers of the merchant which are encrypted at rest, on top of the
+    /// fields which are always encrypted such as the name, email and phone
+    #[schema(value_type = Option<Vec<String>>, example = json!(["metadata.national_id"]))]
+    pub customer_pii_encryption_fields: Option<Vec<String>>,
+
+    /// The connector to which payments of each payment method type are routed by default, when no
+    /// routing algorithm is active on the business profile or no rule of the active routing
+    /// algorithm matches
+    #[schema(value_type = Option<HashMap<PaymentMethodType, RoutableConnectors>>, example = json!({"credit": "stripe", "apple_pay": "adyen"}))]
+    pub default_connector_by_method_type:
+        Option<HashMap<api_enums::PaymentMethodType, api_enums::RoutableConnectors>>,
+
+    /// How the statement descriptors of a payment which exceed the maximum length supported by the
+    /// connector are handled
+    #[schema(value_type = Option<StatementDescriptorLengthPolicy>, example = "reject")]
+    pub statement_descriptor_length_policy: Option<api_enums::StatementDescriptorLengthPolicy>,
+
+    /// The domains to which the customer may be redirected after a payment
+    #[schema(value_type = Option<Vec<String>>, example = json!(["example.com", "*.example.com"]))]
+    pub allowed_return_url_domains: Option<Vec<String>>,
+
+    /// The order in which the connectors are tried for a payment
+    #[schema(value_type = Option<Vec<ConnectorFailoverRule>>)]
+    pub connector_failover_order: Option<Vec<ConnectorFailoverRule>>,
+
+    /// The capture method of the payments which do not specify one
+    #[schema(value_type = Option<CaptureMethod>, example = "manual")]
+    pub default_capture_method: Option<api_enums::CaptureMethod>,
+
+    /// The least cost routing of the merchant
+    #[schema(value_type = Option<LeastCostRoutingConfig>)]
+    pub least_cost_routing: Option<LeastCostRoutingConfig>,
+
+    /// The minimum and maximum amounts of the payments of the merchant
+    #[schema(value_type = Option<Vec<TransactionAmountLimit>>)]
+    pub transaction_amount_limits: Option<Vec<TransactionAmountLimit>>,
+
+    /// The currency of the payments which do not specify one
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub default_currency: Option<api_enums::Currency>,
+
+    /// Whether the payments must specify their currency
+    #[schema(example = false)]
+    pub require_explicit_currency: Option<bool>,
 }
 
 #[cfg(feature = "v2")]
@@ -627,6 +833,26 @@ pub struct WebhookDetails {
     /// If this property is true, a webhook message is posted whenever a payment fails
     #[schema(example = true)]
     pub payment_failed_enabled: Option<bool>,
+
+    /// If this property is true, the webhooks of a resource are delivered in the order the events
+    /// occurred, with a webhook being held until the merchant acknowledges the previous webhooks of
+    /// the resource. Each webhook carries its position among the webhooks of the resource in the
+    /// `X-Webhook-Sequence-Number` header.
+    #[schema(example = false)]
+    pub ordered_delivery_enabled: Option<bool>,
+
+    /// The minimum amount of the events for which webhooks are posted, per currency, in the lowest
+    /// denomination of the currency. Webhooks are not posted for the events of payments, refunds
+    /// and payouts whose amount is below the minimum amount of their currency, while webhooks are
+    /// posted for all the events in currencies without a minimum amount.
+    #[schema(value_type = Option<HashMap<Currency, i64>>, example = json!({"USD": 10000, "JPY": 15000}))]
+    pub minimum_amounts: Option<HashMap<api_enums::Currency, common_utils::types::MinorUnit>>,
+
+    /// The event types for which webhooks are posted, webhooks being posted for the events of every
+    /// event type when not set. The `payment_succeeded_enabled` and `payment_failed_enabled` flags
+    /// do not filter the webhooks.
+    #[schema(value_type = Option<Vec<EventType>>, example = json!(["payment_succeeded"]))]
+    pub enabled_event_types: Option<Vec<api_enums::EventType>>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -652,6 +878,39 @@ pub struct MerchantConnectorId {
     #[schema(value_type = String)]
     pub merchant_connector_id: id_type::MerchantConnectorAccountId,
 }
+
+/// Rotate the credentials of a merchant connector account. The credentials are stored as the pending credentials of the connector account, and the current credentials continue to be used until the pending credentials are promoted.
+#[cfg(feature = "v1")]
+#[derive(Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantConnectorCredentialsRotateRequest {
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+    /// The new credentials of the connector account
+    #[schema(value_type = MerchantConnectorDetails,example = json!({ "auth_type": "HeaderKey","api_key": "Basic MyVerySecretApiKey" }))]
+    pub connector_account_details: pii::SecretSerdeValue,
+}
+
+/// Promote the pending credentials of a merchant connector account to be its current credentials
+#[cfg(feature = "v1")]
+#[derive(Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantConnectorCredentialsPromoteRequest {
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+    /// Promote the pending credentials without verifying them with a test call to the connector. Required for the connectors whose credentials cannot be verified.
+    #[schema(default = false, example = false)]
+    pub skip_test_call: Option<bool>,
+}
+
 #[cfg(feature = "v2")]
 #[derive(Debug, Deserialize, ToSchema, Serialize)]
 pub struct MerchantConnectorId {
@@ -749,6 +1008,17 @@ pub struct MerchantConnectorCreate {
     /// The connector_wallets_details is used to store wallet details such as certificates and wallet credentials
     #[schema(value_type = Option<ConnectorWalletDetails>)]
     pub connector_wallets_details: Option<ConnectorWalletDetails>,
+
+    /// Timeout in seconds for requests made to the connector. Defaults to 30 seconds if not provided, and must be between 1 and 120 seconds
+    #[schema(example = 30)]
+    pub request_timeout_secs: Option<u16>,
+
+    /// The environment of the connector account
+    #[schema(value_type = Option<ConnectorEnvironment>, example = "sandbox")]
+    pub environment: Option<api_enums::ConnectorEnvironment>,
+
+    /// The windows in which the connector is under maintenance, payments are routed to other connectors during these windows. The windows may overlap, and may recur daily or weekly
+    pub maintenance_windows: Option<Vec<ConnectorMaintenanceWindow>>,
 }
 
 #[cfg(feature = "v2")]
@@ -890,6 +1160,17 @@ pub struct MerchantConnectorCreate {
     /// The connector_wallets_details is used to store wallet details such as certificates and wallet credentials
     #[schema(value_type = Option<ConnectorWalletDetails>)]
     pub connector_wallets_details: Option<ConnectorWalletDetails>,
+
+    /// Timeout in seconds for requests made to the connector. Defaults to 30 seconds if not provided, and must be between 1 and 120 seconds
+    #[schema(example = 30)]
+    pub request_timeout_secs: Option<u16>,
+
+    /// The environment of the connector account. A sandbox connector account cannot be in live mode and a production connector account cannot be in test mode. Defaults to the environment of the test mode of the connector account
+    #[schema(value_type = Option<ConnectorEnvironment>, example = "sandbox")]
+    pub environment: Option<api_enums::ConnectorEnvironment>,
+
+    /// The windows in which the connector is under maintenance, payments are routed to other connectors during these windows. The windows may overlap, and may recur daily or weekly
+    pub maintenance_windows: Option<Vec<ConnectorMaintenanceWindow>>,
 }
 
 #[cfg(feature = "v1")]
@@ -990,6 +1271,148 @@ pub enum ConnectorAuthType {
     NoKey,
 }
 
+/// A rule of the connector failover order, which applies to the payments matching all of its conditions. A condition which is not set matches all payments
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorFailoverRule {
+    /// The payment method type of the payments to which the rule applies
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+    /// The currencies of the payments to which the rule applies
+    #[schema(value_type = Option<Vec<Currency>>, example = json!(["USD", "EUR"]))]
+    pub currencies: Option<Vec<api_enums::Currency>>,
+    /// The countries of the billing address of the payments to which the rule applies
+    #[schema(value_type = Option<Vec<CountryAlpha2>>, example = json!(["US", "DE"]))]
+    pub countries: Option<Vec<api_enums::CountryAlpha2>>,
+    /// The connectors in the order in which they are tried, it must not be empty
+    #[schema(value_type = Vec<RoutableConnectors>, example = json!(["stripe", "adyen"]))]
+    pub connectors: Vec<api_enums::RoutableConnectors>,
+}
+
+/// The least cost routing of a merchant. When enabled, the eligible connectors for a payment which the routing algorithm does not rank are tried in the order of their estimated cost for the payment, the connectors without a cost model applicable to the currency of the payment being tried last. The connectors ranked by the active routing algorithm, or the default connector of the payment method type when no routing algorithm is active, keep their order ahead of them. The connectors which cost the same are tried in the order of the routing algorithm
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LeastCostRoutingConfig {
+    /// Whether the payments are routed to the eligible connector with the lowest estimated cost
+    #[schema(example = true)]
+    pub enabled: bool,
+    /// The cost models of the connectors, there must be at most one cost model per connector
+    pub connector_costs: Vec<ConnectorCostModel>,
+}
+
+/// The cost model of a connector, which is the fees it charges for processing payments
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorCostModel {
+    /// The connector which charges the fees
+    #[schema(value_type = RoutableConnectors, example = "stripe")]
+    pub connector: api_enums::RoutableConnectors,
+    /// The fee which applies to the payments none of the fee rules applies to
+    pub default_fee: ConnectorFee,
+    /// The fees which apply to specific payment methods or countries. The fee of the most specific rule applicable to a payment applies, which is the rule with the most conditions, and the first of them when several rules are as specific
+    pub fee_rules: Option<Vec<ConnectorFeeRule>>,
+}
+
+/// A fee of a connector, which applies to the payments matching all of its conditions. A condition which is not set matches all payments
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorFeeRule {
+    /// The payment method of the payments to which the fee applies
+    #[schema(value_type = Option<PaymentMethod>, example = "card")]
+    pub payment_method: Option<api_enums::PaymentMethod>,
+    /// The payment method type of the payments to which the fee applies
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+    /// The countries of the billing address of the payments to which the fee applies
+    #[schema(value_type = Option<Vec<CountryAlpha2>>, example = json!(["US", "DE"]))]
+    pub countries: Option<Vec<api_enums::CountryAlpha2>>,
+    /// The fee charged for the payments to which the rule applies
+    pub fee: ConnectorFee,
+}
+
+/// The fee charged by a connector for processing a payment
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorFee {
+    /// The fixed fee charged per payment, in the minor unit of the currency of the fee
+    #[schema(value_type = i64, example = 30)]
+    pub fixed_fee: common_utils::types::MinorUnit,
+    /// The currency of the fixed fee, which is required when the fixed fee is not zero. The fee applies only to the payments in the currency, a fee without a currency applies to the payments in any currency
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+    /// The fee charged as a percentage of the amount of the payment, with up to 2 decimal digits
+    #[schema(value_type = Object, example = json!({"percentage": 2.9}))]
+    pub percentage_fee:
+        common_utils::types::Percentage<{ consts::CONNECTOR_FEE_PERCENTAGE_PRECISION_LENGTH }>,
+}
+
+/// The limits of the amount of the payments of a merchant in a currency. The limits of a payment method type take precedence over the limits of the currency which apply to all payment method types. The limits apply to the amount of the order, before any surcharge is added
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TransactionAmountLimit {
+    /// The currency of the payments to which the limits apply
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// The payment method type of the payments to which the limits apply, the limits apply to all payment method types if this is not set
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+    /// The minimum amount of the payments, in the minor unit of the currency
+    #[schema(value_type = Option<i64>, example = 100)]
+    pub min_amount: Option<common_utils::types::MinorUnit>,
+    /// The maximum amount of the payments, in the minor unit of the currency. It must not be less than the minimum amount
+    #[schema(value_type = Option<i64>, example = 1000000)]
+    pub max_amount: Option<common_utils::types::MinorUnit>,
+}
+
+/// The payment for which the connector failover chain is retrieved
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorFailoverChainRequest {
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The payment method type of the payment
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+    /// The currency of the payment
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// The country of the billing address of the payment
+    #[schema(value_type = Option<CountryAlpha2>, example = "US")]
+    pub country: Option<api_enums::CountryAlpha2>,
+}
+
+/// The connectors which would be tried for a payment, according to the connector failover order
+/// of the merchant
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ConnectorFailoverChainResponse {
+    /// The connectors in the order in which they would be tried for the payment. It is empty if no
+    /// rule of the connector failover order applies to the payment.
+    #[schema(value_type = Vec<RoutableConnectors>, example = json!(["stripe", "adyen"]))]
+    pub connectors: Vec<api_enums::RoutableConnectors>,
+    /// The connectors of the applicable rule which are left out of the chain, as they are not
+    /// configured and enabled for the merchant
+    #[schema(value_type = Vec<RoutableConnectors>, example = json!(["checkout"]))]
+    pub inactive_connectors: Vec<api_enums::RoutableConnectors>,
+}
+
+/// A window in which the connector is under maintenance. For a recurring window, the start and end times are those of its first occurrence
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorMaintenanceWindow {
+    /// The time at which the maintenance window starts
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T02:00:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub start_time: time::PrimitiveDateTime,
+    /// The time at which the maintenance window ends, it must be after the start time. A recurring window must end before its next occurrence starts
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T04:00:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub end_time: time::PrimitiveDateTime,
+    /// How often the maintenance window recurs, the window occurs only once if this is not set
+    #[schema(value_type = Option<MaintenanceWindowRecurrence>, example = "weekly")]
+    pub recurrence: Option<api_enums::MaintenanceWindowRecurrence>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(deny_unknown_fields)]
 pub struct MerchantConnectorWebhookDetails {
@@ -1114,6 +1537,21 @@ pub struct MerchantConnectorResponse {
     /// The connector_wallets_details is used to store wallet details such as certificates and wallet credentials
     #[schema(value_type = Option<ConnectorWalletDetails>)]
     pub connector_wallets_details: Option<ConnectorWalletDetails>,
+
+    /// Timeout in seconds for requests made to the connector, the default timeout is used if this is not set
+    #[schema(example = 30)]
+    pub request_timeout_secs: Option<u16>,
+
+    /// The environment of the connector account
+    #[schema(value_type = Option<ConnectorEnvironment>, example = "sandbox")]
+    pub environment: Option<api_enums::ConnectorEnvironment>,
+
+    /// The windows in which the connector is under maintenance
+    pub maintenance_windows: Option<Vec<ConnectorMaintenanceWindow>>,
+
+    /// The status of the rotation of the credentials of the connector account, if the credentials were rotated
+    #[schema(value_type = Option<ConnectorCredentialRotationStatus>, example = "pending")]
+    pub credential_rotation_status: Option<api_enums::ConnectorCredentialRotationStatus>,
 }
 
 #[cfg(feature = "v2")]
@@ -1237,6 +1675,25 @@ pub struct MerchantConnectorResponse {
     /// The connector_wallets_details is used to store wallet details such as certificates and wallet credentials
     #[schema(value_type = Option<ConnectorWalletDetails>)]
     pub connector_wallets_details: Option<ConnectorWalletDetails>,
+
+    /// Timeout in seconds for requests made to the connector, the default timeout is used if this is not set
+    #[schema(example = 30)]
+    pub request_timeout_secs: Option<u16>,
+
+    /// The environment of the connector account
+    #[schema(value_type = Option<ConnectorEnvironment>, example = "sandbox")]
+    pub environment: Option<api_enums::ConnectorEnvironment>,
+
+    /// The windows in which the connector is under maintenance
+    pub maintenance_windows: Option<Vec<ConnectorMaintenanceWindow>>,
+
+    /// Unguessable identifier of the webhook endpoint of the connector account. Webhooks from the connector are to be sent to `/connector_webhooks/{webhook_identifier}`
+    #[schema(example = "whid_2N4iT5eXOfhjf7n4ycBQS4GjfLH4St1W")]
+    pub webhook_identifier: Option<String>,
+
+    /// The status of the rotation of the credentials of the connector account, if the credentials were rotated
+    #[schema(value_type = Option<ConnectorCredentialRotationStatus>, example = "pending")]
+    pub credential_rotation_status: Option<api_enums::ConnectorCredentialRotationStatus>,
 }
 
 #[cfg(feature = "v1")]
@@ -1540,6 +1997,17 @@ pub struct MerchantConnectorUpdate {
     /// The connector_wallets_details is used to store wallet details such as certificates and wallet credentials
     #[schema(value_type = Option<ConnectorWalletDetails>)]
     pub connector_wallets_details: Option<ConnectorWalletDetails>,
+
+    /// Timeout in seconds for requests made to the connector. Defaults to 30 seconds if not provided, and must be between 1 and 120 seconds
+    #[schema(example = 30)]
+    pub request_timeout_secs: Option<u16>,
+
+    /// The environment of the connector account. A sandbox connector account cannot be in live mode and a production connector account cannot be in test mode
+    #[schema(value_type = Option<ConnectorEnvironment>, example = "sandbox")]
+    pub environment: Option<api_enums::ConnectorEnvironment>,
+
+    /// The windows in which the connector is under maintenance, payments are routed to other connectors during these windows. The windows replace the existing windows of the connector account, an empty list removes all the windows
+    pub maintenance_windows: Option<Vec<ConnectorMaintenanceWindow>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -1646,6 +2114,17 @@ pub struct MerchantConnectorUpdate {
 
     /// The connector_wallets_details is used to store wallet details such as certificates and wallet credentials
     pub connector_wallets_details: Option<ConnectorWalletDetails>,
+
+    /// Timeout in seconds for requests made to the connector. Defaults to 30 seconds if not provided, and must be between 1 and 120 seconds
+    #[schema(example = 30)]
+    pub request_timeout_secs: Option<u16>,
+
+    /// The environment of the connector account
+    #[schema(value_type = Option<ConnectorEnvironment>, example = "sandbox")]
+    pub environment: Option<api_enums::ConnectorEnvironment>,
+
+    /// The windows in which the connector is under maintenance, payments are routed to other connectors during these windows. The windows replace the existing windows of the connector account, an empty list removes all the windows
+    pub maintenance_windows: Option<Vec<ConnectorMaintenanceWindow>>,
 }
 
 #[cfg(feature = "v2")]
@@ -1832,6 +2311,226 @@ pub struct ToggleAllKVResponse {
     pub kv_enabled: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantOnboardingStatusUpdateRequest {
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The onboarding status to which the merchant should be moved
+    #[schema(value_type = OnboardingStatus, example = "docs_submitted")]
+    pub status: api_enums::OnboardingStatus,
+    /// The identifier of the operator or system making the change
+    #[schema(max_length = 255, example = "compliance@example.com")]
+    pub changed_by: String,
+    /// The reason for the change in onboarding status
+    #[schema(max_length = 255, example = "KYC documents received")]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MerchantOnboardingStatusResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The current onboarding status of the merchant
+    #[schema(value_type = OnboardingStatus, example = "kyc_pending")]
+    pub status: api_enums::OnboardingStatus,
+    /// The requirements which are yet to be fulfilled before the merchant can go live
+    #[schema(value_type = Vec<OnboardingRequirement>)]
+    pub outstanding_requirements: Vec<api_enums::OnboardingRequirement>,
+    /// The onboarding statuses to which the merchant can be moved from the current status
+    #[schema(value_type = Vec<OnboardingStatus>)]
+    pub allowed_transitions: Vec<api_enums::OnboardingStatus>,
+    /// The history of onboarding status transitions of the merchant, oldest first
+    pub transitions: Vec<MerchantOnboardingTransition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MerchantOnboardingTransition {
+    /// The onboarding status before the transition
+    #[schema(value_type = OnboardingStatus, example = "kyc_pending")]
+    pub previous_status: api_enums::OnboardingStatus,
+    /// The onboarding status after the transition
+    #[schema(value_type = OnboardingStatus, example = "docs_submitted")]
+    pub new_status: api_enums::OnboardingStatus,
+    /// The identifier of the operator or system which made the change
+    #[schema(example = "compliance@example.com")]
+    pub changed_by: String,
+    /// The reason for the change in onboarding status
+    #[schema(example = "KYC documents received")]
+    pub reason: Option<String>,
+    /// The time at which the transition was made
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AuditLogRetrieveRequest {
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The type of the resource whose audit trail is to be retrieved
+    #[schema(value_type = AuditLogResourceType, example = "api_key")]
+    pub resource_type: api_enums::AuditLogResourceType,
+    /// The identifier of the resource, which is the merchant id for merchant accounts, the key id
+    /// for API keys and the profile id for routing configuration
+    #[schema(max_length = 64, example = "dev_5Fx9peyouhJ81sMD7Oxm")]
+    pub resource_id: String,
+    /// The maximum number of entries to be returned
+    #[schema(minimum = 1, maximum = 100, example = 10)]
+    pub limit: Option<i64>,
+    /// The number of entries to be skipped
+    #[schema(example = 0)]
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AuditLogResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The type of the resource
+    #[schema(value_type = AuditLogResourceType, example = "api_key")]
+    pub resource_type: api_enums::AuditLogResourceType,
+    /// The identifier of the resource
+    #[schema(example = "dev_5Fx9peyouhJ81sMD7Oxm")]
+    pub resource_id: String,
+    /// The changes made to the resource within the retention period, latest first
+    pub entries: Vec<AuditLogEntry>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AuditLogEntry {
+    /// The user or the key which made the change
+    #[schema(example = "user:a0d2697e-fd43-4e8a-a2a8-f6e0b3bbacc8")]
+    pub actor: String,
+    /// The kind of change made to the resource
+    #[schema(value_type = AuditLogAction, example = "update")]
+    pub action: api_enums::AuditLogAction,
+    /// The fields of the resource which were changed. The values of sensitive fields are redacted.
+    pub changes: Vec<AuditLogFieldChange>,
+    /// The time at which the change was made
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AuditLogFieldChange {
+    /// The name of the field
+    #[schema(example = "return_url")]
+    pub field: String,
+    /// The value of the field before the change
+    #[schema(value_type = Object, example = "https://example.com")]
+    pub before: serde_json::Value,
+    /// The value of the field after the change
+    #[schema(value_type = Object, example = "https://example.com/return")]
+    pub after: serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LegalHoldCreateRequest {
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The entity of the record to be held
+    #[schema(value_type = RetentionEntity, example = "payments")]
+    pub entity_type: api_enums::RetentionEntity,
+    /// The identifier of the record, which is the payment id for payments, the refund id for
+    /// refunds, the dispute id for disputes, the customer id for customers and the file id for files
+    #[schema(max_length = 64, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub record_id: String,
+    /// The reason for which the record is held, such as the reference of a litigation
+    #[schema(max_length = 255, example = "Litigation 2024-117")]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LegalHoldListRequest {
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The maximum number of legal holds to be returned
+    #[schema(minimum = 1, maximum = 100, example = 10)]
+    pub limit: Option<i64>,
+    /// The number of legal holds to be skipped
+    #[schema(example = 0)]
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegalHoldDeleteRequest {
+    pub merchant_id: id_type::MerchantId,
+    pub entity_type: api_enums::RetentionEntity,
+    pub record_id: String,
+}
+
+/// A legal hold on a record, which exempts the record from the purge of the records past their
+/// retention period for as long as the hold is in place
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LegalHoldResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The entity of the held record
+    #[schema(value_type = RetentionEntity, example = "payments")]
+    pub entity_type: api_enums::RetentionEntity,
+    /// The identifier of the held record
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub record_id: String,
+    /// The reason for which the record is held
+    #[schema(example = "Litigation 2024-117")]
+    pub reason: Option<String>,
+    /// The time at which the hold was placed
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodRetokenizationRequest {
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// Re-tokenize only the payment methods which were last modified before this time, such as the
+    /// time at which the network tokenization service was switched
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub last_modified_before: Option<time::PrimitiveDateTime>,
+    /// Re-tokenize only the payment methods whose last re-tokenization failed
+    #[serde(default)]
+    pub retokenization_pending_only: bool,
+    /// Re-tokenize only the payment methods whose id comes after this payment method id, as returned
+    /// in `next_starting_after` by the previous request
+    #[schema(example = "pm_ahkXUYStB2IRGKpNV7Ke")]
+    pub starting_after: Option<String>,
+    /// The maximum number of payment methods re-tokenized in the request
+    #[schema(minimum = 1, maximum = 100, example = 100)]
+    pub limit: Option<i64>,
+}
+
+/// The number of the payment methods with each outcome of the re-tokenization
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PaymentMethodRetokenizationResponse {
+    /// The number of the payment methods whose network token was replaced
+    pub retokenized: usize,
+    /// The number of the payment methods whose card network is not supported by the network
+    /// tokenization service
+    pub skipped: usize,
+    /// The number of the payment methods whose re-tokenization failed, which keep their network
+    /// token and are flagged for a retry
+    pub failed: usize,
+    /// The payment method id to be passed as `starting_after` to re-tokenize the next payment
+    /// methods, absent when no payment methods are left to be re-tokenized
+    pub next_starting_after: Option<String>,
+}
+
 /// Merchant connector details used to make payments.
 #[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct MerchantConnectorDetailsWrap {
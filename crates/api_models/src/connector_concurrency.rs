@@ -0,0 +1,21 @@
+use crate::enums::Connector;
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorConcurrencyRequest {
+    /// The connector whose concurrent requests are requested
+    pub connector: Connector,
+}
+
+/// The requests in flight to a connector, against the limit on the concurrent requests to the
+/// connector
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorConcurrencyResponse {
+    /// The connector whose concurrent requests are reported
+    pub connector: Connector,
+    /// The limit on the concurrent requests to the connector, absent when the requests to the
+    /// connector are not limited
+    pub limit: Option<u16>,
+    /// The number of the requests in flight to the connector, which is only tracked for the
+    /// connectors with a limit
+    pub in_flight_requests: u16,
+}
@@ -0,0 +1,33 @@
+use crate::enums::Connector;
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorLatencyQuery {
+    /// The window in seconds, ending now, over which the latency distribution is computed
+    pub window_in_seconds: Option<u32>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorLatencyRequest {
+    /// The connector whose latency distribution is requested
+    pub connector: Connector,
+    /// The window in seconds, ending now, over which the latency distribution is computed
+    pub window_in_seconds: Option<u32>,
+}
+
+/// The distribution of the latency of the calls made to a connector. The percentiles are the upper
+/// bounds of the histogram buckets they fall in, and are absent when no calls were sampled.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorLatencyResponse {
+    /// The connector whose latency distribution is reported
+    pub connector: Connector,
+    /// The window in seconds, ending now, over which the latency distribution was computed
+    pub window_in_seconds: u32,
+    /// The number of connector calls sampled within the window
+    pub sample_count: u64,
+    /// The median latency in milliseconds
+    pub p50_ms: Option<u64>,
+    /// The 95th percentile latency in milliseconds
+    pub p95_ms: Option<u64>,
+    /// The 99th percentile latency in milliseconds
+    pub p99_ms: Option<u64>,
+}
@@ -3,3 +3,15 @@ pub const MAX_ORDER_FULFILLMENT_EXPIRY: i64 = 1800;
 
 /// Min payment intent fulfillment expiry
 pub const MIN_ORDER_FULFILLMENT_EXPIRY: i64 = 60;
+
+/// Max number of tags on a payment attempt
+pub const MAX_PAYMENT_ATTEMPT_TAGS: usize = 10;
+
+/// Max length of the key of a payment attempt tag
+pub const MAX_PAYMENT_ATTEMPT_TAG_KEY_LENGTH: usize = 40;
+
+/// Max length of the value of a payment attempt tag
+pub const MAX_PAYMENT_ATTEMPT_TAG_VALUE_LENGTH: usize = 255;
+
+/// Max number of tax identifiers of a customer
+pub const MAX_CUSTOMER_TAX_IDS: usize = 10;
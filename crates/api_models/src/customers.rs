@@ -1,11 +1,12 @@
 use common_utils::{
     crypto, custom_serde,
     encryption::Encryption,
+    errors::{CustomResult, ValidationError},
     id_type,
     pii::{self, EmailStrategy},
-    types::{keymanager::ToEncryptable, Description},
+    types::{keymanager::ToEncryptable, CustomerTaxId, CustomerTaxIds, Description},
 };
-use masking::{ExposeInterface, Secret, SwitchStrategy};
+use masking::{ExposeInterface, PeekInterface, Secret, SwitchStrategy};
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -46,6 +47,10 @@ pub struct CustomerRequest {
     /// object.
     #[schema(value_type = Option<Object>,example = json!({ "city": "NY", "unit": "245" }))]
     pub metadata: Option<pii::SecretSerdeValue>,
+    /// The tax identifiers of the customer, such as their VAT or GST registration numbers, for
+    /// the jurisdictions the customer is registered in
+    #[schema(value_type = Option<Vec<CustomerTaxId>>)]
+    pub tax_ids: Option<CustomerTaxIds>,
 }
 
 #[derive(Debug, Default, Clone, Deserialize, Serialize, ToSchema)]
@@ -56,6 +61,13 @@ pub struct CustomerListRequest {
     /// Limit
     #[schema(example = 32)]
     pub limit: Option<u16>,
+    /// Key of the customer metadata to filter the customers by, the key must be designated for
+    /// encryption in the `customer_pii_encryption_fields` of the merchant account
+    #[schema(example = "national_id")]
+    pub metadata_key: Option<String>,
+    /// Value of the metadata key to filter the customers by
+    #[schema(value_type = Option<String>)]
+    pub metadata_value: Option<Secret<String>>,
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -73,6 +85,10 @@ impl CustomerRequest {
     pub fn get_optional_email(&self) -> Option<pii::Email> {
         self.email.clone()
     }
+    /// Validate the tax identifiers of the customer, if any
+    pub fn validate_tax_ids(&self) -> CustomResult<(), ValidationError> {
+        self.tax_ids.as_ref().map_or(Ok(()), validate_tax_ids)
+    }
 }
 
 /// The customer details
@@ -241,9 +257,17 @@ pub struct CustomerResponse {
     /// object.
     #[schema(value_type = Option<Object>,example = json!({ "city": "NY", "unit": "245" }))]
     pub metadata: Option<pii::SecretSerdeValue>,
+    /// The tax identifiers of the customer, such as their VAT or GST registration numbers, for
+    /// the jurisdictions the customer is registered in
+    #[schema(value_type = Option<Vec<CustomerTaxId>>)]
+    pub tax_ids: Option<CustomerTaxIds>,
     /// The identifier for the default payment method.
     #[schema(max_length = 64, example = "pm_djh2837dwduh890123")]
     pub default_payment_method_id: Option<String>,
+    /// The identifier for the saved payment method the customer last paid with successfully,
+    /// which is distinct from the default payment method
+    #[schema(max_length = 64, example = "pm_djh2837dwduh890123")]
+    pub preferred_payment_method_id: Option<String>,
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -422,6 +446,10 @@ pub struct CustomerUpdateRequest {
     /// object.
     #[schema(value_type = Option<Object>,example = json!({ "city": "NY", "unit": "245" }))]
     pub metadata: Option<pii::SecretSerdeValue>,
+    /// The tax identifiers of the customer, such as their VAT or GST registration numbers, for
+    /// the jurisdictions the customer is registered in
+    #[schema(value_type = Option<Vec<CustomerTaxId>>)]
+    pub tax_ids: Option<CustomerTaxIds>,
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -436,6 +464,10 @@ impl CustomerUpdateRequest {
     pub fn get_address(&self) -> Option<payments::AddressDetails> {
         self.address.clone()
     }
+    /// Validate the tax identifiers of the customer, if any
+    pub fn validate_tax_ids(&self) -> CustomResult<(), ValidationError> {
+        self.tax_ids.as_ref().map_or(Ok(()), validate_tax_ids)
+    }
 }
 
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
@@ -502,3 +534,352 @@ impl UpdateCustomerId {
         Self(id)
     }
 }
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CustomerConnectorSyncRetryRequest {
+    /// The maximum number of pending or failed connector syncs to be retried
+    #[schema(example = 100)]
+    pub limit: Option<u16>,
+}
+
+/// The status of the propagation of the customer details to a connector
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerConnectorSyncResponse {
+    /// The identifier of the customer
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    /// The label against which the connector customer is stored, which is the merchant connector account id
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub connector_label: String,
+    /// The connector at which the customer exists
+    #[schema(example = "stripe")]
+    pub connector_name: String,
+    /// The status of the sync to the connector
+    #[schema(value_type = CustomerConnectorSyncStatus)]
+    pub sync_status: common_enums::CustomerConnectorSyncStatus,
+    /// The reason the connector could not be updated, if the sync failed
+    pub error_message: Option<String>,
+    /// The time at which the sync status was last updated
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+/// The query parameters to retrieve the velocity counters of a customer
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CustomerVelocityCountersQuery {
+    /// The currency of the amount counter
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: common_enums::Currency,
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Serialize)]
+pub struct CustomerVelocityCountersRequest {
+    pub customer_id: id_type::CustomerId,
+    pub currency: common_enums::Currency,
+}
+
+/// The current values of the velocity counters of a customer, along with the velocity limits
+/// configured for the merchant
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerVelocityCountersResponse {
+    /// The identifier of the customer
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    /// The currency of the amount counter
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: common_enums::Currency,
+    /// The number of transactions made by the customer in the current hour
+    #[schema(example = 3)]
+    pub transaction_count: i64,
+    /// The maximum number of transactions a customer can make in an hour
+    #[schema(example = 10)]
+    pub max_transactions_per_hour: Option<i64>,
+    /// The amount paid by the customer in the currency in the current day
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: common_utils::types::MinorUnit,
+    /// The maximum amount a customer can pay in a day
+    #[schema(value_type = Option<i64>, example = 100000)]
+    pub max_amount_per_day: Option<common_utils::types::MinorUnit>,
+}
+
+/// Validate the number of the tax identifiers of a customer, and the format of each of them
+/// against the format of its kind in the country which issued it
+pub fn validate_tax_ids(tax_ids: &CustomerTaxIds) -> CustomResult<(), ValidationError> {
+    if tax_ids.len() > crate::consts::MAX_CUSTOMER_TAX_IDS {
+        return Err(ValidationError::InvalidValue {
+            message: format!(
+                "A maximum of {} tax identifiers can be specified",
+                crate::consts::MAX_CUSTOMER_TAX_IDS
+            ),
+        }
+        .into());
+    }
+
+    for tax_id in tax_ids.iter() {
+        validate_tax_id(tax_id)?;
+    }
+
+    Ok(())
+}
+
+fn validate_tax_id(tax_id: &CustomerTaxId) -> CustomResult<(), ValidationError> {
+    use common_enums::{CountryAlpha2, TaxIdType};
+
+    let country_matches = match tax_id.tax_id_type {
+        TaxIdType::EuVat => tax_id.country.is_eu_member(),
+        TaxIdType::GbVat => tax_id.country == CountryAlpha2::GB,
+        TaxIdType::ChVat => tax_id.country == CountryAlpha2::CH,
+        TaxIdType::NoVat => tax_id.country == CountryAlpha2::NO,
+        TaxIdType::InGst => tax_id.country == CountryAlpha2::IN,
+        TaxIdType::AuAbn => tax_id.country == CountryAlpha2::AU,
+        TaxIdType::NzGst => tax_id.country == CountryAlpha2::NZ,
+        TaxIdType::SgGst => tax_id.country == CountryAlpha2::SG,
+        TaxIdType::CaBn => tax_id.country == CountryAlpha2::CA,
+        TaxIdType::BrCnpj | TaxIdType::BrCpf => tax_id.country == CountryAlpha2::BR,
+        TaxIdType::MxRfc => tax_id.country == CountryAlpha2::MX,
+        TaxIdType::UsEin => tax_id.country == CountryAlpha2::US,
+        TaxIdType::Other => true,
+    };
+    if !country_matches {
+        return Err(ValidationError::InvalidValue {
+            message: format!(
+                "Tax identifiers of type `{}` are not issued in the country `{}`",
+                tax_id.tax_id_type, tax_id.country
+            ),
+        }
+        .into());
+    }
+
+    // The separators used when formatting the identifiers are not part of them
+    let value = tax_id
+        .value
+        .peek()
+        .chars()
+        .filter(|character| !matches!(character, ' ' | '-' | '.' | '/'))
+        .collect::<String>()
+        .to_uppercase();
+    let is_digits = |value: &str, lengths: &[usize]| {
+        lengths.contains(&value.len()) && value.bytes().all(|byte| byte.is_ascii_digit())
+    };
+    let is_alphanumeric = |value: &str, min_length: usize, max_length: usize| {
+        (min_length..=max_length).contains(&value.len())
+            && value.bytes().all(|byte| byte.is_ascii_alphanumeric())
+    };
+
+    let is_valid = match tax_id.tax_id_type {
+        TaxIdType::EuVat => {
+            // The VAT identification numbers of Greece are prefixed with `EL` instead of its
+            // country code
+            let prefix = match tax_id.country {
+                CountryAlpha2::GR => "EL".to_string(),
+                country => country.to_string(),
+            };
+            value
+                .strip_prefix(prefix.as_str())
+                .is_some_and(|number| is_alphanumeric(number, 2, 12))
+        }
+        TaxIdType::GbVat => value
+            .strip_prefix("GB")
+            .is_some_and(|number| is_digits(number, &[9, 12])),
+        TaxIdType::ChVat => value.strip_prefix("CHE").is_some_and(|number| {
+            let number = ["MWST", "TVA", "IVA"]
+                .iter()
+                .find_map(|suffix| number.strip_suffix(suffix))
+                .unwrap_or(number);
+            is_digits(number, &[9])
+        }),
+        TaxIdType::NoVat => is_digits(value.strip_suffix("MVA").unwrap_or(&value), &[9]),
+        TaxIdType::InGst => {
+            value.len() == 15
+                && value.bytes().enumerate().all(|(index, byte)| match index {
+                    0 | 1 | 7..=10 => byte.is_ascii_digit(),
+                    2..=6 | 11 => byte.is_ascii_uppercase(),
+                    _ => byte.is_ascii_alphanumeric(),
+                })
+        }
+        TaxIdType::AuAbn => is_digits(&value, &[11]),
+        TaxIdType::NzGst => is_digits(&value, &[8, 9]),
+        TaxIdType::SgGst => is_alphanumeric(&value, 9, 10),
+        TaxIdType::CaBn => {
+            is_digits(&value, &[9])
+                || (value.len() == 15
+                    && value.is_ascii()
+                    && is_digits(&value[..9], &[9])
+                    && value[9..11].bytes().all(|byte| byte.is_ascii_uppercase())
+                    && is_digits(&value[11..], &[4]))
+        }
+        TaxIdType::BrCnpj => is_digits(&value, &[14]),
+        TaxIdType::BrCpf => is_digits(&value, &[11]),
+        TaxIdType::MxRfc => is_alphanumeric(&value, 12, 13),
+        TaxIdType::UsEin => is_digits(&value, &[9]),
+        TaxIdType::Other => (1..=64).contains(&tax_id.value.peek().len()),
+    };
+    if !is_valid {
+        return Err(ValidationError::InvalidValue {
+            message: format!(
+                "The tax identifier of type `{}` issued in the country `{}` is not in a valid format",
+                tax_id.tax_id_type, tax_id.country
+            ),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// The details of a communication of the merchant with a customer, to be appended to the
+/// communication log of the customer
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerCommunicationCreateRequest {
+    /// The identifier of the payment the communication is about, if any
+    #[schema(value_type = Option<String>, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: Option<id_type::PaymentId>,
+    /// The channel through which the communication took place
+    #[schema(value_type = CustomerCommunicationChannel, example = "email")]
+    pub channel: common_enums::CustomerCommunicationChannel,
+    /// The summary of the communication
+    #[schema(value_type = String, max_length = 2048, example = "Customer confirmed the receipt of the order")]
+    pub summary: Secret<String>,
+    /// The identifier of the file of the communication, such as the transcript of a call or a copy
+    /// of an email, uploaded through the files API
+    #[schema(example = "file_4bKPjRs9kdaD9wM0pbXd")]
+    pub file_id: Option<String>,
+    /// The time at which the communication took place, defaults to the current time
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub communicated_at: Option<time::PrimitiveDateTime>,
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Serialize)]
+pub struct CustomerCommunicationCreate {
+    pub customer_id: id_type::CustomerId,
+    pub request: CustomerCommunicationCreateRequest,
+}
+
+/// The constraints to list the communication log of a customer
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerCommunicationListConstraints {
+    /// The maximum number of entries to be listed, the most recent ones first
+    #[schema(example = 10)]
+    pub limit: Option<u16>,
+    /// The number of entries to be skipped
+    #[schema(example = 0)]
+    pub offset: Option<u32>,
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Serialize)]
+pub struct CustomerCommunicationList {
+    pub customer_id: id_type::CustomerId,
+    pub constraints: CustomerCommunicationListConstraints,
+}
+
+/// An entry of the communication log of a customer
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerCommunicationResponse {
+    /// The identifier of the entry
+    #[schema(example = "comm_4bKPjRs9kdaD9wM0pbXd")]
+    pub id: String,
+    /// The identifier of the customer
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    /// The identifier of the payment the communication is about, if any
+    #[schema(value_type = Option<String>, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: Option<id_type::PaymentId>,
+    /// The channel through which the communication took place
+    #[schema(value_type = CustomerCommunicationChannel, example = "email")]
+    pub channel: common_enums::CustomerCommunicationChannel,
+    /// The summary of the communication
+    #[schema(value_type = String, example = "Customer confirmed the receipt of the order")]
+    pub summary: Secret<String>,
+    /// The identifier of the file of the communication, if any
+    #[schema(example = "file_4bKPjRs9kdaD9wM0pbXd")]
+    pub file_id: Option<String>,
+    /// The time at which the communication took place
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub communicated_at: time::PrimitiveDateTime,
+    /// The time at which the entry was written
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[cfg(test)]
+mod tests {
+    use common_enums::{CountryAlpha2, TaxIdType};
+
+    use super::*;
+
+    fn tax_id(tax_id_type: TaxIdType, country: CountryAlpha2, value: &str) -> CustomerTaxId {
+        CustomerTaxId {
+            tax_id_type,
+            country,
+            value: Secret::new(value.to_string()),
+            label: None,
+        }
+    }
+
+    #[test]
+    fn test_valid_tax_ids() {
+        let tax_ids = CustomerTaxIds::from(vec![
+            tax_id(TaxIdType::EuVat, CountryAlpha2::DE, "DE 123 456 789"),
+            tax_id(TaxIdType::EuVat, CountryAlpha2::GR, "EL123456789"),
+            tax_id(TaxIdType::GbVat, CountryAlpha2::GB, "GB123456789"),
+            tax_id(TaxIdType::ChVat, CountryAlpha2::CH, "CHE-123.456.789 MWST"),
+            tax_id(TaxIdType::InGst, CountryAlpha2::IN, "22AAAAA0000A1Z5"),
+            tax_id(TaxIdType::CaBn, CountryAlpha2::CA, "123456789RT0001"),
+            tax_id(TaxIdType::UsEin, CountryAlpha2::US, "12-3456789"),
+        ]);
+
+        assert!(validate_tax_ids(&tax_ids).is_ok());
+    }
+
+    #[test]
+    fn test_invalid_tax_ids() {
+        let invalid_tax_ids = [
+            // The prefix does not match the country
+            tax_id(TaxIdType::EuVat, CountryAlpha2::DE, "FR123456789"),
+            // Not a member state of the European Union
+            tax_id(TaxIdType::EuVat, CountryAlpha2::GB, "GB123456789"),
+            tax_id(TaxIdType::GbVat, CountryAlpha2::GB, "GB1234"),
+            tax_id(TaxIdType::InGst, CountryAlpha2::IN, "22AAAAA0000A1Z"),
+            tax_id(TaxIdType::UsEin, CountryAlpha2::CA, "123456789"),
+        ];
+
+        for tax_id in invalid_tax_ids {
+            assert!(validate_tax_ids(&CustomerTaxIds::from(vec![tax_id])).is_err());
+        }
+    }
+
+    #[test]
+    fn test_tax_id_for_country() {
+        let tax_ids = CustomerTaxIds::from(vec![
+            tax_id(TaxIdType::GbVat, CountryAlpha2::GB, "GB123456789"),
+            tax_id(TaxIdType::EuVat, CountryAlpha2::DE, "DE123456789"),
+        ]);
+
+        assert_eq!(
+            tax_ids
+                .get_for_country(CountryAlpha2::GB)
+                .map(|tax_id| tax_id.tax_id_type),
+            Some(TaxIdType::GbVat)
+        );
+        assert_eq!(
+            tax_ids
+                .get_for_country(CountryAlpha2::FR)
+                .map(|tax_id| tax_id.tax_id_type),
+            Some(TaxIdType::EuVat)
+        );
+        assert!(tax_ids.get_for_country(CountryAlpha2::US).is_none());
+    }
+}
@@ -110,6 +110,36 @@ pub struct DisputeEvidenceBlock {
     pub file_metadata_response: files::FileMetadataResponse,
 }
 
+/// Manifest included in the archive of the evidence files of a dispute
+#[derive(Clone, Debug, Serialize)]
+pub struct DisputeEvidenceArchiveManifest {
+    /// The identifier for dispute
+    pub dispute_id: String,
+    /// The evidence files referenced by the dispute
+    pub files: Vec<DisputeEvidenceArchiveEntry>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct DisputeEvidenceArchiveEntry {
+    /// Evidence type
+    pub evidence_type: EvidenceType,
+    /// The identifier for the file
+    pub file_id: String,
+    /// The name of the file in the archive, if the file could be included in the archive
+    pub archived_file_name: Option<String>,
+    /// Whether the file could be included in the archive
+    pub status: DisputeEvidenceArchiveFileStatus,
+    /// The reason for which the file could not be included in the archive
+    pub error_message: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DisputeEvidenceArchiveFileStatus {
+    Included,
+    Missing,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 #[serde(deny_unknown_fields)]
 pub struct DisputeListGetConstraints {
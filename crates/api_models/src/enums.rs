@@ -3,6 +3,7 @@ use std::str::FromStr;
 pub use common_enums::*;
 #[cfg(feature = "dummy_connector")]
 use common_utils::errors;
+use common_utils::types::MinorUnit;
 use utoipa::ToSchema;
 
 #[derive(
@@ -294,6 +295,185 @@ impl Connector {
     pub fn is_pre_processing_required_before_authorize(&self) -> bool {
         matches!(self, Self::Airwallex)
     }
+    /// The capabilities implemented by the integration of the connector
+    pub fn get_capabilities(&self) -> ConnectorCapabilities {
+        ConnectorCapabilities {
+            partial_capture: matches!(self, Self::Adyen | Self::Checkout | Self::Globalpay),
+            void: matches!(
+                self,
+                Self::Aci
+                    | Self::Adyen
+                    | Self::Airwallex
+                    | Self::Authorizedotnet
+                    | Self::Bambora
+                    | Self::Bankofamerica
+                    | Self::Billwerk
+                    | Self::Bluesnap
+                    | Self::Braintree
+                    | Self::Checkout
+                    | Self::Cybersource
+                    | Self::Datatrans
+                    | Self::Deutschebank
+                    | Self::Dlocal
+                    | Self::Fiserv
+                    | Self::Fiservemea
+                    | Self::Fiuu
+                    | Self::Forte
+                    | Self::Globalpay
+                    | Self::Helcim
+                    | Self::Klarna
+                    | Self::Nexinets
+                    | Self::Nexixpay
+                    | Self::Nmi
+                    | Self::Noon
+                    | Self::Novalnet
+                    | Self::Nuvei
+                    | Self::Payme
+                    | Self::Paypal
+                    | Self::Payu
+                    | Self::Placetopay
+                    | Self::Powertranz
+                    | Self::Prophetpay
+                    | Self::Rapyd
+                    | Self::Square
+                    | Self::Stax
+                    | Self::Stripe
+                    | Self::Tsys
+                    | Self::Wellsfargo
+                    | Self::Worldline
+                    | Self::Worldpay
+            ),
+            refund: !matches!(
+                self,
+                Self::Adyenplatform
+                    | Self::Bitpay
+                    | Self::Cashtocode
+                    | Self::Coinbase
+                    | Self::Cryptopay
+                    | Self::Ebanx
+                    | Self::Gpayments
+                    | Self::Mifinity
+                    | Self::Netcetera
+                    | Self::Opennode
+                    | Self::Payone
+                    | Self::Plaid
+                    | Self::Riskified
+                    | Self::Signifyd
+                    | Self::Taxjar
+                    | Self::Threedsecureio
+                    | Self::Wise
+                    | Self::Zsl
+            ),
+            incremental_authorization: matches!(self, Self::Cybersource | Self::Wellsfargo),
+            three_ds: matches!(
+                self,
+                Self::Adyen
+                    | Self::Airwallex
+                    | Self::Bambora
+                    | Self::Billwerk
+                    | Self::Bluesnap
+                    | Self::Braintree
+                    | Self::Checkout
+                    | Self::Cybersource
+                    | Self::Dlocal
+                    | Self::Fiuu
+                    | Self::Globalpay
+                    | Self::Nexixpay
+                    | Self::Nmi
+                    | Self::Noon
+                    | Self::Novalnet
+                    | Self::Nuvei
+                    | Self::Paybox
+                    | Self::Payme
+                    | Self::Paypal
+                    | Self::Powertranz
+                    | Self::Rapyd
+                    | Self::Shift4
+                    | Self::Stripe
+            ),
+            mandates: matches!(
+                self,
+                Self::Aci
+                    | Self::Adyen
+                    | Self::Authorizedotnet
+                    | Self::Bamboraapac
+                    | Self::Bankofamerica
+                    | Self::Braintree
+                    | Self::Cybersource
+                    | Self::Deutschebank
+                    | Self::Globalpay
+                    | Self::Gocardless
+                    | Self::Multisafepay
+                    | Self::Nexinets
+                    | Self::Noon
+                    | Self::Novalnet
+                    | Self::Nuvei
+                    | Self::Payme
+                    | Self::Stripe
+                    | Self::Wellsfargo
+            ),
+            idempotency_key: self.get_idempotency_key_header().is_some(),
+            max_refund_window_in_days: self.get_max_refund_window_in_days(),
+            amount_limits: self.get_amount_limits(),
+        }
+    }
+    /// The limits on the amount of a payment which are documented by the connector, per currency.
+    /// The amount of a payment is unbounded for the connectors, and the currencies, without a
+    /// documented limit.
+    pub fn get_amount_limits(&self) -> Vec<ConnectorAmountLimit> {
+        let limit = |currency, minimum_amount, maximum_amount| ConnectorAmountLimit {
+            currency,
+            minimum_amount: minimum_amount.map(MinorUnit::new),
+            maximum_amount: maximum_amount.map(MinorUnit::new),
+        };
+        match self {
+            Self::Stripe => vec![
+                limit(Some(Currency::USD), Some(50), Some(99_999_999)),
+                limit(Some(Currency::EUR), Some(50), Some(99_999_999)),
+                limit(Some(Currency::GBP), Some(30), Some(99_999_999)),
+                limit(Some(Currency::AUD), Some(50), Some(99_999_999)),
+                limit(Some(Currency::CAD), Some(50), Some(99_999_999)),
+                limit(Some(Currency::INR), Some(50), Some(99_999_999)),
+                limit(Some(Currency::JPY), Some(50), Some(99_999_999)),
+                limit(None, None, Some(99_999_999)),
+            ],
+            Self::Razorpay => vec![limit(Some(Currency::INR), Some(100), None)],
+            _ => Vec::new(),
+        }
+    }
+    /// The number of days after the payment within which the connector accepts refunds, for the
+    /// connectors which are known to reject the refunds of older payments. This is the default of
+    /// the refund window, which can be configured per connector.
+    pub fn get_max_refund_window_in_days(&self) -> Option<i64> {
+        match self {
+            Self::Authorizedotnet => Some(120),
+            Self::Braintree | Self::Paypal => Some(180),
+            Self::Square => Some(365),
+            _ => None,
+        }
+    }
+    /// The header in which the connector accepts an idempotency key, which is attached to the
+    /// authorize, capture, refund and void requests made to the connector so that the connector
+    /// deduplicates a request made again for the same operation. Adyen, Checkout, GoCardless and Stripe support idempotency keys. The
+    /// integrations of the connectors which require an idempotency key of their own format, such
+    /// as Helcim and Nexixpay, attach their own key instead.
+    pub fn get_idempotency_key_header(&self) -> Option<&'static str> {
+        match self {
+            Self::Adyen | Self::Gocardless | Self::Stripe => Some("Idempotency-Key"),
+            Self::Checkout => Some("Cko-Idempotency-Key"),
+            _ => None,
+        }
+    }
+    /// The error codes with which the connector reports that it rejected the credentials of the
+    /// connector account, for the connectors which report the authentication failures with a status
+    /// code other than 401
+    pub fn get_authentication_failure_error_codes(&self) -> &'static [&'static str] {
+        match self {
+            Self::Authorizedotnet => &["E00007", "E00008"],
+            Self::Paypal => &["AUTHENTICATION_FAILURE"],
+            _ => &[],
+        }
+    }
     #[cfg(feature = "dummy_connector")]
     pub fn validate_dummy_connector_enabled(
         &self,
@@ -321,6 +501,73 @@ impl Connector {
     }
 }
 
+/// The payment capabilities supported by the integration of a connector
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Serialize, ToSchema)]
+pub struct ConnectorCapabilities {
+    /// Whether the authorized amount can be captured in multiple partial captures
+    pub partial_capture: bool,
+    /// Whether an authorized payment can be voided
+    pub void: bool,
+    /// Whether payments can be refunded
+    pub refund: bool,
+    /// Whether the authorized amount can be incremented after the authorization
+    pub incremental_authorization: bool,
+    /// Whether payments can be authenticated using 3DS
+    pub three_ds: bool,
+    /// Whether mandates can be set up and used for recurring payments
+    pub mandates: bool,
+    /// Whether the connector deduplicates the requests made for the same operation using an
+    /// idempotency key
+    pub idempotency_key: bool,
+    /// The number of days after the payment within which the connector accepts refunds, if the
+    /// connector limits it
+    pub max_refund_window_in_days: Option<i64>,
+    /// The limits on the amount of a payment accepted by the connector, per currency
+    pub amount_limits: Vec<ConnectorAmountLimit>,
+}
+
+impl ConnectorCapabilities {
+    /// The limits on the amount of a payment in the currency. The limits of the currency take
+    /// precedence over the limits which apply to all the currencies.
+    pub fn get_amount_limit(&self, currency: Currency) -> Option<&ConnectorAmountLimit> {
+        self.amount_limits
+            .iter()
+            .find(|limit| limit.currency == Some(currency))
+            .or_else(|| {
+                self.amount_limits
+                    .iter()
+                    .find(|limit| limit.currency.is_none())
+            })
+    }
+
+    /// Whether the connector accepts a payment of the amount in the currency
+    pub fn is_amount_supported(&self, amount: MinorUnit, currency: Currency) -> bool {
+        self.get_amount_limit(currency).map_or(true, |limit| {
+            limit
+                .minimum_amount
+                .map_or(true, |minimum_amount| amount >= minimum_amount)
+                && limit
+                    .maximum_amount
+                    .map_or(true, |maximum_amount| amount <= maximum_amount)
+        })
+    }
+}
+
+/// The limits on the amount of a payment accepted by a connector in a currency
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+pub struct ConnectorAmountLimit {
+    /// The currency to which the limits apply, the limits apply to all the currencies without
+    /// limits of their own if this is not set
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<Currency>,
+    /// The minimum amount of a payment, in the minor unit of the currency
+    #[schema(value_type = Option<i64>, example = 50)]
+    pub minimum_amount: Option<MinorUnit>,
+    /// The maximum amount of a payment, in the minor unit of the currency
+    #[schema(value_type = Option<i64>, example = 99999999)]
+    pub maximum_amount: Option<MinorUnit>,
+}
+
 #[cfg(feature = "payouts")]
 #[derive(
     Clone,
@@ -631,6 +878,37 @@ mod test {
 
         assert!(user_address_country_is_us.eq(&user_address_country_is_all))
     }
+
+    #[test]
+    fn test_connector_amount_limits_by_currency() {
+        let capabilities = Connector::Stripe.get_capabilities();
+
+        // The limits of the currency
+        assert!(!capabilities.is_amount_supported(MinorUnit::new(49), Currency::USD));
+        assert!(capabilities.is_amount_supported(MinorUnit::new(50), Currency::USD));
+        assert!(!capabilities.is_amount_supported(MinorUnit::new(29), Currency::GBP));
+        assert!(capabilities.is_amount_supported(MinorUnit::new(30), Currency::GBP));
+        assert!(!capabilities.is_amount_supported(MinorUnit::new(100_000_000), Currency::EUR));
+
+        // The limits which apply to all the currencies without limits of their own
+        assert!(capabilities.is_amount_supported(MinorUnit::new(1), Currency::SEK));
+        assert!(!capabilities.is_amount_supported(MinorUnit::new(100_000_000), Currency::SEK));
+
+        // The limits of a currency apply only to the currency
+        let capabilities = Connector::Razorpay.get_capabilities();
+        assert!(!capabilities.is_amount_supported(MinorUnit::new(99), Currency::INR));
+        assert!(capabilities.is_amount_supported(MinorUnit::new(100), Currency::INR));
+        assert!(capabilities.is_amount_supported(MinorUnit::new(1), Currency::USD));
+    }
+
+    #[test]
+    fn test_connector_amount_unbounded_without_documented_limits() {
+        let capabilities = Connector::Adyen.get_capabilities();
+
+        assert!(capabilities.amount_limits.is_empty());
+        assert!(capabilities.is_amount_supported(MinorUnit::new(1), Currency::USD));
+        assert!(capabilities.is_amount_supported(MinorUnit::new(i64::MAX), Currency::USD));
+    }
 }
 
 /// Denotes the retry action
@@ -24,8 +24,18 @@ impl actix_web::ResponseError for ApiErrorResponse {
     fn error_response(&self) -> actix_web::HttpResponse {
         use actix_web::http::header;
 
-        actix_web::HttpResponseBuilder::new(self.status_code())
-            .insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON))
-            .body(self.to_string())
+        let mut response = actix_web::HttpResponseBuilder::new(self.status_code());
+        response.insert_header((header::CONTENT_TYPE, mime::APPLICATION_JSON));
+
+        if let Some(retry_after_in_secs) = self
+            .get_internal_error()
+            .extra
+            .as_ref()
+            .and_then(|extra| extra.retry_after_in_secs)
+        {
+            response.insert_header((header::RETRY_AFTER, retry_after_in_secs));
+        }
+
+        response.body(self.to_string())
     }
 }
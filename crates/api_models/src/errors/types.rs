@@ -79,6 +79,10 @@ pub struct Extra {
     pub reason: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub connector_transaction_id: Option<String>,
+    /// The number of seconds after which the request can be retried, which is also sent in the
+    /// `Retry-After` header of the response
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_after_in_secs: Option<u32>,
 }
 
 #[derive(Serialize, Debug, Clone)]
@@ -19,7 +19,7 @@ use common_utils::{
     impl_api_event_type,
 };
 
-use crate::customers::CustomerListRequest;
+use crate::customers::{CustomerConnectorSyncRetryRequest, CustomerListRequest};
 #[allow(unused_imports)]
 use crate::{
     admin::*,
@@ -29,6 +29,8 @@ use crate::{
     },
     api_keys::*,
     cards_info::*,
+    connector_concurrency::*,
+    connector_latency::*,
     disputes::*,
     files::*,
     mandates::*,
@@ -37,6 +39,7 @@ use crate::{
     },
     payment_methods::*,
     payments::*,
+    settlements::*,
     user::{UserKeyTransferRequest, UserTransferKeyResponse},
     verifications::*,
 };
@@ -85,6 +88,18 @@ impl_api_event_type!(
         ToggleKVRequest,
         ToggleAllKVRequest,
         ToggleAllKVResponse,
+        MerchantOnboardingStatusUpdateRequest,
+        MerchantOnboardingStatusResponse,
+        AuditLogRetrieveRequest,
+        ConnectorFailoverChainRequest,
+        ConnectorFailoverChainResponse,
+        AuditLogResponse,
+        LegalHoldCreateRequest,
+        LegalHoldListRequest,
+        LegalHoldDeleteRequest,
+        LegalHoldResponse,
+        PaymentMethodRetokenizationRequest,
+        PaymentMethodRetokenizationResponse,
         MerchantAccountDeleteResponse,
         MerchantAccountUpdate,
         CardInfoResponse,
@@ -135,7 +150,16 @@ impl_api_event_type!(
         OrganizationCreateRequest,
         OrganizationUpdateRequest,
         OrganizationId,
-        CustomerListRequest
+        CustomerListRequest,
+        CustomerConnectorSyncRetryRequest,
+        ConnectorConcurrencyRequest,
+        ConnectorConcurrencyResponse,
+        ConnectorLatencyRequest,
+        ConnectorLatencyResponse,
+        SettlementFileIngestRequest,
+        SettlementFileIngestResponse,
+        SettlementLineItemsUnmatchedListRequest,
+        SettlementLineItemsUnmatchedListResponse
     )
 );
 
@@ -173,6 +197,20 @@ impl ApiEventMetric for PaymentMethodIntentCreate {
     }
 }
 
+#[cfg(feature = "v1")]
+impl ApiEventMetric for MerchantConnectorCredentialsRotateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for MerchantConnectorCredentialsPromoteRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
 impl ApiEventMetric for DisputeListFilters {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::ResourceListAPI)
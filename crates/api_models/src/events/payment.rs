@@ -9,23 +9,33 @@ use crate::payment_methods::CustomerPaymentMethodsListResponse;
 use crate::payment_methods::CustomerPaymentMethodsListResponse;
 use crate::{
     payment_methods::{
-        CustomerDefaultPaymentMethodResponse, DefaultPaymentMethod, ListCountriesCurrenciesRequest,
-        ListCountriesCurrenciesResponse, PaymentMethodCollectLinkRenderRequest,
-        PaymentMethodCollectLinkRequest, PaymentMethodCollectLinkResponse,
-        PaymentMethodDeleteResponse, PaymentMethodListRequest, PaymentMethodListResponse,
-        PaymentMethodResponse, PaymentMethodUpdate,
+        BankAccountTokenId, BankAccountTokenResponse, BankAccountTokenizeRequest,
+        BankAccountVerificationRequest, CustomerDefaultPaymentMethodResponse, DefaultPaymentMethod,
+        ListCountriesCurrenciesRequest, ListCountriesCurrenciesResponse,
+        PaymentMethodCollectLinkRenderRequest, PaymentMethodCollectLinkRequest,
+        PaymentMethodCollectLinkResponse, PaymentMethodDeleteResponse, PaymentMethodListRequest,
+        PaymentMethodListResponse, PaymentMethodRecommendationRequest,
+        PaymentMethodRecommendationResponse, PaymentMethodResponse, PaymentMethodUpdate,
+        PaymentMethodsListForSessionRequest, PaymentMethodsListForSessionResponse,
     },
     payments::{
-        ExtendedCardInfoResponse, PaymentIdType, PaymentListConstraints,
+        CaptureResponse, ExtendedCardInfoResponse, PaymentIdType, PaymentListConstraints,
         PaymentListFilterConstraints, PaymentListFilters, PaymentListFiltersV2,
-        PaymentListResponse, PaymentListResponseV2, PaymentsAggregateResponse,
+        PaymentListResponse, PaymentListResponseV2, PaymentReceiptResponse,
+        PaymentTimelineResponse, PaymentVoidEligibilityResponse, PaymentsAggregateResponse,
         PaymentsApproveRequest, PaymentsCancelRequest, PaymentsCaptureRequest,
-        PaymentsCompleteAuthorizeRequest, PaymentsDynamicTaxCalculationRequest,
+        PaymentsCaptureVoidRequest, PaymentsCompleteAuthorizeRequest,
+        PaymentsDistinctCardCountResponse, PaymentsDynamicTaxCalculationRequest,
         PaymentsDynamicTaxCalculationResponse, PaymentsExternalAuthenticationRequest,
-        PaymentsExternalAuthenticationResponse, PaymentsIncrementalAuthorizationRequest,
-        PaymentsManualUpdateRequest, PaymentsManualUpdateResponse, PaymentsRejectRequest,
-        PaymentsRequest, PaymentsResponse, PaymentsRetrieveRequest, PaymentsSessionResponse,
-        PaymentsStartRequest, RedirectionResponse,
+        PaymentsExternalAuthenticationResponse, PaymentsFulfillmentRequest,
+        PaymentsFulfillmentResponse, PaymentsIncrementalAuthorizationRequest,
+        PaymentsManualUpdateRequest, PaymentsManualUpdateResponse,
+        PaymentsPendingReviewListConstraints, PaymentsPendingReviewListResponse,
+        PaymentsRejectRequest, PaymentsRequest, PaymentsResponse, PaymentsResumeRequest,
+        PaymentsRetrieveRequest, PaymentsSessionResponse, PaymentsSettlementAmountsRequest,
+        PaymentsSettlementAmountsResponse, PaymentsStartRequest, RedirectionResponse,
+        RelatedPaymentsResponse, SimulateWebhookRequest, SimulateWebhookResponse,
+        SparsePaymentsResponse,
     },
 };
 impl ApiEventMetric for PaymentsRetrieveRequest {
@@ -81,6 +91,18 @@ impl ApiEventMetric for PaymentsCancelRequest {
     }
 }
 
+impl ApiEventMetric for PaymentsCaptureVoidRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for CaptureResponse {}
+
+impl ApiEventMetric for PaymentsResumeRequest {}
+
 impl ApiEventMetric for PaymentsApproveRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::Payment {
@@ -116,6 +138,12 @@ impl ApiEventMetric for PaymentsResponse {
     }
 }
 
+impl ApiEventMetric for SparsePaymentsResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        self.response.get_api_event_type()
+    }
+}
+
 impl ApiEventMetric for PaymentMethodResponse {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::PaymentMethod {
@@ -150,6 +178,46 @@ impl ApiEventMetric for PaymentMethodDeleteResponse {
 
 impl ApiEventMetric for CustomerPaymentMethodsListResponse {}
 
+impl ApiEventMetric for PaymentMethodRecommendationRequest {}
+
+impl ApiEventMetric for PaymentMethodRecommendationResponse {}
+
+impl ApiEventMetric for PaymentMethodsListForSessionRequest {}
+
+impl ApiEventMetric for PaymentMethodsListForSessionResponse {}
+
+impl ApiEventMetric for BankAccountTokenizeRequest {}
+
+impl ApiEventMetric for BankAccountVerificationRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PaymentMethod {
+            payment_method_id: self.token_id.clone(),
+            payment_method: Some(common_enums::PaymentMethod::BankDebit),
+            payment_method_type: Some(common_enums::PaymentMethodType::Ach),
+        })
+    }
+}
+
+impl ApiEventMetric for BankAccountTokenId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PaymentMethod {
+            payment_method_id: self.token_id.clone(),
+            payment_method: Some(common_enums::PaymentMethod::BankDebit),
+            payment_method_type: Some(common_enums::PaymentMethodType::Ach),
+        })
+    }
+}
+
+impl ApiEventMetric for BankAccountTokenResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PaymentMethod {
+            payment_method_id: self.token_id.clone(),
+            payment_method: Some(common_enums::PaymentMethod::BankDebit),
+            payment_method_type: Some(common_enums::PaymentMethodType::Ach),
+        })
+    }
+}
+
 impl ApiEventMetric for PaymentMethodListRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::PaymentMethodList {
@@ -243,6 +311,84 @@ impl ApiEventMetric for PaymentsAggregateResponse {
     }
 }
 
+impl ApiEventMetric for PaymentsDistinctCardCountResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for PaymentsSettlementAmountsRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for PaymentsSettlementAmountsResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for PaymentsPendingReviewListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for PaymentsPendingReviewListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for RelatedPaymentsResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PaymentReceiptResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PaymentTimelineResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PaymentVoidEligibilityResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SimulateWebhookRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for SimulateWebhookResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
 impl ApiEventMetric for RedirectionResponse {}
 
 impl ApiEventMetric for PaymentsIncrementalAuthorizationRequest {
@@ -281,6 +427,22 @@ impl ApiEventMetric for PaymentsManualUpdateResponse {
     }
 }
 
+impl ApiEventMetric for PaymentsFulfillmentRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PaymentsFulfillmentResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
 impl ApiEventMetric for PaymentsSessionResponse {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::Payment {
@@ -3,7 +3,7 @@ use common_utils::events::{ApiEventMetric, ApiEventsType};
 use crate::payouts::{
     PayoutActionRequest, PayoutCreateRequest, PayoutCreateResponse, PayoutLinkInitiateRequest,
     PayoutListConstraints, PayoutListFilterConstraints, PayoutListFilters, PayoutListResponse,
-    PayoutRetrieveRequest,
+    PayoutRetrieveRequest, PayoutReturnsRequest, PayoutReturnsResponse,
 };
 
 impl ApiEventMetric for PayoutRetrieveRequest {
@@ -69,3 +69,15 @@ impl ApiEventMetric for PayoutLinkInitiateRequest {
         })
     }
 }
+
+impl ApiEventMetric for PayoutReturnsRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for PayoutReturnsResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
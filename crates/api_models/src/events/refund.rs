@@ -1,9 +1,10 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::refunds::{
-    RefundAggregateResponse, RefundListFilters, RefundListMetaData, RefundListRequest,
-    RefundListResponse, RefundManualUpdateRequest, RefundRequest, RefundResponse,
-    RefundUpdateRequest, RefundsRetrieveRequest,
+    RefundAggregateResponse, RefundEligibilityRequest, RefundEligibilityResponse,
+    RefundListFilters, RefundListMetaData, RefundListRequest, RefundListResponse,
+    RefundManualUpdateRequest, RefundRequest, RefundResponse, RefundUpdateRequest,
+    RefundsRetrieveRequest,
 };
 
 impl ApiEventMetric for RefundRequest {
@@ -27,6 +28,22 @@ impl ApiEventMetric for RefundResponse {
     }
 }
 
+impl ApiEventMetric for RefundEligibilityRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for RefundEligibilityResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
 impl ApiEventMetric for RefundsRetrieveRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::Refund {
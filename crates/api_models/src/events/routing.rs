@@ -2,11 +2,12 @@ use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::routing::{
     LinkedRoutingConfigRetrieveResponse, MerchantRoutingAlgorithm, ProfileDefaultRoutingConfig,
-    RoutingAlgorithmId, RoutingConfigRequest, RoutingDictionaryRecord, RoutingKind,
-    RoutingLinkWrapper, RoutingPayloadWrapper, RoutingRetrieveLinkQuery,
-    RoutingRetrieveLinkQueryWrapper, RoutingRetrieveQuery, SuccessBasedRoutingConfig,
-    SuccessBasedRoutingPayloadWrapper, SuccessBasedRoutingUpdateConfigQuery,
-    ToggleSuccessBasedRoutingQuery, ToggleSuccessBasedRoutingWrapper,
+    RoutingAlgorithmId, RoutingConfigRequest, RoutingDictionaryRecord,
+    RoutingEffectiveWeightsResponse, RoutingKind, RoutingLinkWrapper, RoutingPayloadWrapper,
+    RoutingRetrieveLinkQuery, RoutingRetrieveLinkQueryWrapper, RoutingRetrieveQuery,
+    SuccessBasedRoutingConfig, SuccessBasedRoutingPayloadWrapper,
+    SuccessBasedRoutingUpdateConfigQuery, ToggleSuccessBasedRoutingQuery,
+    ToggleSuccessBasedRoutingWrapper,
 };
 
 impl ApiEventMetric for RoutingKind {
@@ -27,6 +28,12 @@ impl ApiEventMetric for RoutingAlgorithmId {
     }
 }
 
+impl ApiEventMetric for RoutingEffectiveWeightsResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
 impl ApiEventMetric for RoutingDictionaryRecord {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::Routing)
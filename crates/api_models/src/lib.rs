@@ -5,6 +5,8 @@ pub mod apple_pay_certificates_migration;
 pub mod blocklist;
 pub mod cards_info;
 pub mod conditional_configs;
+pub mod connector_concurrency;
+pub mod connector_latency;
 pub mod connector_onboarding;
 pub mod consts;
 pub mod currency;
@@ -31,6 +33,7 @@ pub mod poll;
 pub mod recon;
 pub mod refunds;
 pub mod routing;
+pub mod settlements;
 pub mod surcharge_decision_configs;
 pub mod user;
 pub mod user_role;
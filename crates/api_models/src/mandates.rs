@@ -1,3 +1,4 @@
+use common_utils::types::MinorUnit;
 use masking::Secret;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
@@ -43,6 +44,27 @@ pub struct MandateResponse {
     /// Details about the customer’s acceptance
     #[schema(value_type = Option<CustomerAcceptance>)]
     pub customer_acceptance: Option<payments::CustomerAcceptance>,
+    /// The usage constraints of the mandate
+    pub constraints: Option<MandateConstraints>,
+    /// The amount which can still be charged using the mandate, if the total amount of the mandate is capped
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub remaining_amount: Option<MinorUnit>,
+}
+
+/// The usage constraints of a mandate, used for variable recurring payments. Merchant initiated
+/// payments which do not satisfy the constraints are rejected.
+#[derive(Default, Debug, Deserialize, Serialize, ToSchema, Clone, Copy, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct MandateConstraints {
+    /// The maximum amount which can be charged in a single payment
+    #[schema(value_type = Option<i64>, example = 1000)]
+    pub max_amount_per_transaction: Option<MinorUnit>,
+    /// The maximum amount which can be charged across all the payments made using the mandate
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub total_amount: Option<MinorUnit>,
+    /// The calendar period in which the mandate can be charged at most once
+    #[schema(value_type = Option<MandateFrequency>, example = "monthly")]
+    pub frequency: Option<api_enums::MandateFrequency>,
 }
 
 #[derive(Default, Debug, Deserialize, Serialize, ToSchema, Clone)]
@@ -1281,6 +1281,153 @@ pub struct RequestPaymentMethodTypes {
     pub installment_payment_enabled: bool,
 }
 
+/// Request to rank the payment methods enabled for the merchant by their relevance to a payment
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodRecommendationRequest {
+    /// The identifier for the customer, used to prefer the payment methods the customer has used before
+    #[schema(value_type = Option<String>, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<id_type::CustomerId>,
+
+    /// The two-letter ISO country code of the customer
+    #[schema(value_type = Option<CountryAlpha2>, example = "US")]
+    pub country: Option<api_enums::CountryAlpha2>,
+
+    /// The currency of the payment
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+
+    /// The amount of the payment, in the lowest denomination of the currency
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub amount: Option<MinorUnit>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentMethodRecommendationResponse {
+    /// The eligible payment methods, ordered from the most to the least relevant
+    pub recommendations: Vec<PaymentMethodRecommendation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, ToSchema)]
+pub struct PaymentMethodRecommendation {
+    /// The type of payment method
+    #[schema(value_type = PaymentMethod, example = "card")]
+    pub payment_method: api_enums::PaymentMethod,
+
+    /// The subtype of payment method
+    #[schema(value_type = PaymentMethodType, example = "credit")]
+    pub payment_method_type: api_enums::PaymentMethodType,
+
+    /// The reason for which the payment method is recommended at its position
+    pub reason: PaymentMethodRecommendationReason,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentMethodRecommendationReason {
+    /// The customer has used the payment method before, more recently used methods are ranked higher
+    PreviouslyUsed,
+    /// The payment method is available for the country, currency and amount of the payment
+    AvailableForPayment,
+}
+
+/// Request to list the payment methods available for a checkout session, before the payment is
+/// created
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodsListForSessionRequest {
+    /// The amount of the payment, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+
+    /// The currency of the payment
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The two-letter ISO country code of the customer
+    #[schema(value_type = Option<CountryAlpha2>, example = "US")]
+    pub country: Option<api_enums::CountryAlpha2>,
+
+    /// The identifier for the customer, used to prefer the payment methods the customer has used before
+    #[schema(value_type = Option<String>, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<id_type::CustomerId>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentMethodsListForSessionResponse {
+    /// The publishable key of the merchant, with which the client initializes the checkout
+    #[schema(example = "pk_snd_3b33cd9404234113804aa1accaabe22f")]
+    pub publishable_key: String,
+
+    /// The payment methods available for the session, ordered from the most to the least relevant
+    pub payment_methods: Vec<SessionPaymentMethod>,
+
+    /// The reason for which no payment method is available for the session, when the list is empty
+    pub unavailable_reason: Option<PaymentMethodsUnavailableReason>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct SessionPaymentMethod {
+    /// The type of payment method
+    #[schema(value_type = PaymentMethod, example = "wallet")]
+    pub payment_method: api_enums::PaymentMethod,
+
+    /// The subtype of payment method
+    #[schema(value_type = PaymentMethodType, example = "google_pay")]
+    pub payment_method_type: api_enums::PaymentMethodType,
+
+    /// The reason for which the payment method is recommended at its position
+    pub reason: PaymentMethodRecommendationReason,
+
+    /// The connectors through which the payment method is available
+    pub connectors: Vec<SessionPaymentMethodConnector>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct SessionPaymentMethodConnector {
+    /// The name of the connector
+    #[schema(example = "stripe")]
+    pub connector: String,
+
+    /// The identifier of the merchant connector account
+    #[schema(value_type = String, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+
+    /// The data which the client requires to present the payment method, such as the merchant identifiers of the wallets
+    pub client_data: Option<SessionPaymentMethodClientData>,
+}
+
+/// The data which the client requires to present a payment method
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum SessionPaymentMethodClientData {
+    ApplePay {
+        /// The merchant identifier registered with Apple Pay
+        merchant_identifier: String,
+    },
+    GooglePay {
+        /// The merchant identifier registered with Google Pay
+        merchant_id: Option<String>,
+        /// The name of the merchant displayed by Google Pay
+        merchant_name: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentMethodsUnavailableReason {
+    /// The merchant has no payment methods enabled
+    NoPaymentMethodsEnabled,
+    /// None of the payment methods accept the country of the customer
+    UnsupportedCountry,
+    /// None of the payment methods accept the currency
+    UnsupportedCurrency,
+    /// The amount is out of the limits of all the payment methods
+    UnsupportedAmount,
+    /// The payment methods accepting the country do not accept the currency, or the amount is out of their limits
+    UnsupportedCombination,
+}
+
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
@@ -1581,6 +1728,32 @@ pub struct PaymentMethodListResponse {
 
     /// flag that indicates whether to calculate tax on the order amount
     pub is_tax_calculation_enabled: bool,
+
+    /// The payment method types enabled for the merchant which are excluded from the list, along with the reason for which they are excluded
+    pub excluded_payment_methods: Vec<ExcludedPaymentMethod>,
+}
+
+/// A payment method type enabled for the merchant which is excluded from the list of the payment methods available for the payment
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+pub struct ExcludedPaymentMethod {
+    #[schema(value_type = PaymentMethod, example = "bank_redirect")]
+    pub payment_method: api_enums::PaymentMethod,
+    #[schema(value_type = PaymentMethodType, example = "ideal")]
+    pub payment_method_type: api_enums::PaymentMethodType,
+    /// The reason for which the payment method type is excluded
+    pub reason: PaymentMethodExclusionReason,
+    /// The country of the payment, for a payment method type which is not available in the country
+    #[schema(value_type = Option<CountryAlpha2>, example = "US")]
+    pub country: Option<api_enums::CountryAlpha2>,
+}
+
+/// The reason for which a payment method type is excluded from the list of the payment methods available for the payment
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, ToSchema, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentMethodExclusionReason {
+    /// The payment method type is not available in the country of the payment
+    NotAvailableInCountry,
 }
 
 #[derive(Eq, PartialEq, Hash, Debug, serde::Deserialize, ToSchema)]
@@ -1633,6 +1806,15 @@ pub struct CustomerPaymentMethodsListResponse {
     pub is_guest_customer: Option<bool>,
 }
 
+#[derive(Debug, Default, serde::Deserialize, ToSchema)]
+pub struct PaymentMethodDeleteRequest {
+    /// Whether the tokens of the payment method provisioned at the connectors are to be revoked as
+    /// well. Tokens which could not be revoked are revoked later, without failing the deletion.
+    #[serde(default)]
+    #[schema(default = false, example = true)]
+    pub revoke_connector_tokens: bool,
+}
+
 #[cfg(all(
     any(feature = "v2", feature = "v1"),
     not(feature = "payment_methods_v2")
@@ -1646,6 +1828,26 @@ pub struct PaymentMethodDeleteResponse {
     /// Whether payment method was deleted or not
     #[schema(example = true)]
     pub deleted: bool,
+
+    /// The status of the revocation of the token at each connector where it was provisioned, if
+    /// the revocation was requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connector_token_revocations: Option<Vec<ConnectorTokenRevocationResponse>>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct ConnectorTokenRevocationResponse {
+    /// The identifier of the merchant connector account the token was provisioned for
+    #[schema(value_type = String, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+
+    /// The name of the connector
+    #[schema(example = "stripe")]
+    pub connector: String,
+
+    /// The status of the revocation of the token at the connector
+    #[schema(value_type = ConnectorTokenRevocationStatus)]
+    pub status: api_enums::ConnectorTokenRevocationStatus,
 }
 
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
@@ -1825,6 +2027,11 @@ pub struct CustomerPaymentMethod {
     #[schema(example = true)]
     pub default_payment_method_set: bool,
 
+    /// Indicates if the payment method is the one the customer last paid with successfully, so
+    /// that it can be pre-selected
+    #[schema(example = true)]
+    pub preferred_payment_method_set: bool,
+
     /// The billing details of the payment method
     #[schema(value_type = Option<Address>)]
     pub billing: Option<payments::Address>,
@@ -1933,6 +2140,105 @@ pub struct MaskedBankDetails {
     pub mask: String,
 }
 
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BankAccountTokenizeRequest {
+    /// The unique identifier of the customer the bank account belongs to
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The details of the bank account to be tokenized
+    pub bank_account: BankAccountDetails,
+
+    /// The token of the bank account at the connector, if the bank account is tokenized at the
+    /// connector as well
+    #[schema(example = "ba_1NmyB2ClCIKljWvsAHCrNZXY")]
+    pub connector_bank_token: Option<String>,
+
+    /// The merchant connector account at which the bank account is tokenized, required if
+    /// `connector_bank_token` is provided
+    #[schema(value_type = Option<String>, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BankAccountDetails {
+    /// The account number of the bank account
+    #[schema(value_type = String, example = "000123456789")]
+    pub account_number: masking::Secret<String>,
+
+    /// The routing number of the bank of the bank account
+    #[schema(value_type = String, example = "110000000")]
+    pub routing_number: masking::Secret<String>,
+
+    /// The name of the owner of the bank account
+    #[schema(value_type = Option<String>, example = "John Doe")]
+    pub bank_account_holder_name: Option<masking::Secret<String>>,
+
+    /// The name of the bank
+    #[schema(value_type = Option<BankNames>, example = "ach")]
+    pub bank_name: Option<common_enums::BankNames>,
+
+    /// The type of the bank account
+    #[schema(value_type = Option<BankType>, example = "checking")]
+    pub bank_type: Option<common_enums::BankType>,
+
+    /// The type of the owner of the bank account
+    #[schema(value_type = Option<BankHolderType>, example = "personal")]
+    pub bank_holder_type: Option<common_enums::BankHolderType>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BankAccountVerificationRequest {
+    /// The identifier of the bank account token, populated from the path
+    #[serde(skip)]
+    pub token_id: String,
+
+    /// The outcome of the verification of the ownership of the bank account
+    #[schema(value_type = BankAccountVerificationStatus, example = "verified")]
+    pub verification_status: api_enums::BankAccountVerificationStatus,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct BankAccountTokenId {
+    pub token_id: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct BankAccountTokenResponse {
+    /// The token of the bank account, which can be used as the `payment_token` of ACH bank debit
+    /// payments once the bank account is verified
+    #[schema(example = "bat_Wb2z0pEf4B7jjsA6M0Kk")]
+    pub token_id: String,
+
+    /// The unique identifier of the customer the bank account belongs to
+    #[schema(value_type = String, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// Partially masked account number of the bank account
+    #[schema(value_type = String, example = "0001****6789")]
+    pub account_number: common_utils::new_type::MaskedBankAccount,
+
+    /// Partially masked routing number of the bank of the bank account
+    #[schema(value_type = String, example = "110***000")]
+    pub routing_number: common_utils::new_type::MaskedRoutingNumber,
+
+    /// The verification status of the bank account, only verified bank accounts can be charged
+    #[schema(value_type = BankAccountVerificationStatus, example = "verified")]
+    pub verification_status: api_enums::BankAccountVerificationStatus,
+
+    /// The merchant connector account at which the bank account is tokenized, if any
+    #[schema(value_type = Option<String>, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+
+    /// A timestamp (ISO 8601 code) that determines when the bank account was tokenized
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: time::PrimitiveDateTime,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct PaymentMethodId {
     pub payment_method_id: String,
@@ -2254,6 +2560,7 @@ impl From<PaymentMethodRecord> for customers::CustomerRequest {
                 last_name: Some(record.billing_address_last_name),
             }),
             metadata: None,
+            tax_ids: None,
         }
     }
 }
@@ -6,14 +6,17 @@ use std::{
 pub mod additional_info;
 use cards::CardNumber;
 use common_utils::{
-    consts::default_payments_list_limit,
+    consts::{default_payments_list_limit, AMOUNT_BREAKDOWN_TAX_RATE_PRECISION_LENGTH},
     crypto,
     errors::ValidationError,
     ext_traits::{ConfigExt, Encode, ValueExt},
     hashing::HashedString,
     id_type,
     pii::{self, Email, EmailStrategy},
-    types::{keymanager::ToEncryptable, MinorUnit, StringMajorUnit},
+    types::{
+        keymanager::ToEncryptable, MinorUnit, PaymentAttemptTags, Percentage, RetryAdvice,
+        StringMajorUnit,
+    },
 };
 use error_stack::ResultExt;
 use masking::{ExposeInterface, PeekInterface, Secret, SwitchStrategy, WithType};
@@ -29,8 +32,8 @@ use crate::{
     admin::{self, MerchantConnectorInfo},
     disputes, enums as api_enums,
     ephemeral_key::EphemeralKeyCreateResponse,
-    mandates::RecurringDetails,
-    refunds,
+    mandates::{MandateConstraints, RecurringDetails},
+    refunds, routing,
 };
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -426,6 +429,13 @@ pub struct PaymentsRequest {
     #[schema(value_type = Option<Vec<Connector>>, max_length = 255, example = json!(["stripe", "adyen"]))]
     pub connector: Option<Vec<api_enums::Connector>>,
 
+    /// Forces the payment through this connector, bypassing routing. The connector must be configured for the profile of the payment and eligible for the payment, and the `merchant_connector_id` is required if the profile has multiple accounts of the connector. It cannot be provided along with `routing`.
+    #[schema(value_type = Option<RoutableConnectorChoice>, example = json!({
+        "connector": "stripe",
+        "merchant_connector_id": "mca_123"
+    }))]
+    pub connector_override: Option<routing::RoutableConnectorChoice>,
+
     #[schema(value_type = Option<CaptureMethod>, example = "automatic")]
     pub capture_method: Option<api_enums::CaptureMethod>,
 
@@ -661,8 +671,44 @@ pub struct PaymentsRequest {
     )]
     pub merchant_order_reference_id: Option<String>,
 
+    /// Whether only one payment can be created for the `merchant_order_reference_id`. When enabled,
+    /// creating a payment with the `merchant_order_reference_id` of an existing payment returns the
+    /// existing payment instead of creating a new one.
+    #[schema(default = false, example = true)]
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    pub enforce_unique_merchant_order_reference_id: Option<bool>,
+
+    /// Whether the payment is created even if it is a likely duplicate of a payment created
+    /// recently, with the same amount, currency, payment method and customer. To be set when the
+    /// customer genuinely makes the same purchase again.
+    #[schema(default = false, example = true)]
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    pub allow_duplicate_payment: Option<bool>,
+
     /// Whether to calculate tax for this payment intent
     pub skip_external_tax_calculation: Option<bool>,
+
+    /// Tags of the payment attempt, such as the campaign or the channel through which the payment
+    /// was made. Unlike the metadata, the tags can be used to query the payment attempts. You can
+    /// specify up to 10 tags, with keys up to 40 characters long and values up to 255 characters long.
+    #[schema(value_type = Option<HashMap<String, String>>, example = json!({"campaign_id": "summer_sale", "channel": "email"}))]
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    pub tags: Option<PaymentAttemptTags>,
+
+    /// Breakdown of the amount into the subtotal, tax, shipping and discount, for connectors which
+    /// support itemized receipts. The components of the breakdown must sum to the amount.
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    pub amount_breakdown: Option<AmountBreakdown>,
+
+    /// The payment which this payment renews or retries, such as the previous payment of a
+    /// subscription. The linked payments form a chain, which can be fetched from any of them.
+    #[schema(value_type = Option<String>, max_length = 64, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    pub parent_payment_id: Option<id_type::PaymentId>,
+
+    /// When the CVV of a stored card has to be collected again from the customer for this payment.
+    /// Overrides the policy configured for the merchant. Honored only when passed with the API key.
+    pub cvv_recollection_policy: Option<CvvRecollectionPolicy>,
 }
 
 /// Checks if the inner values of two options are equal
@@ -678,6 +724,14 @@ fn are_optional_values_invalid<T: PartialEq>(
 }
 
 impl PaymentsRequest {
+    /// Get the merchant order reference id, if only one payment can be created for it
+    pub fn get_unique_merchant_order_reference_id(&self) -> Option<&str> {
+        self.enforce_unique_merchant_order_reference_id
+            .unwrap_or(false)
+            .then_some(self.merchant_order_reference_id.as_deref())
+            .flatten()
+    }
+
     /// Get the customer id
     ///
     /// First check the id for `customer.id`
@@ -688,6 +742,80 @@ impl PaymentsRequest {
             .or(self.customer.as_ref().map(|customer| &customer.id))
     }
 
+    /// Validate the number of tags and the length of their keys and values
+    pub fn validate_tags(&self) -> common_utils::errors::CustomResult<(), ValidationError> {
+        let Some(tags) = self.tags.as_ref() else {
+            return Ok(());
+        };
+
+        if tags.len() > crate::consts::MAX_PAYMENT_ATTEMPT_TAGS {
+            return Err(ValidationError::InvalidValue {
+                message: format!(
+                    "A maximum of {} tags can be specified",
+                    crate::consts::MAX_PAYMENT_ATTEMPT_TAGS
+                ),
+            }
+            .into());
+        }
+
+        for (key, value) in tags.iter() {
+            if key.is_empty()
+                || key.chars().count() > crate::consts::MAX_PAYMENT_ATTEMPT_TAG_KEY_LENGTH
+            {
+                return Err(ValidationError::InvalidValue {
+                    message: format!(
+                        "Tag keys should be between 1 and {} characters long",
+                        crate::consts::MAX_PAYMENT_ATTEMPT_TAG_KEY_LENGTH
+                    ),
+                }
+                .into());
+            }
+            if value.chars().count() > crate::consts::MAX_PAYMENT_ATTEMPT_TAG_VALUE_LENGTH {
+                return Err(ValidationError::InvalidValue {
+                    message: format!(
+                        "The value of the tag `{key}` should be at most {} characters long",
+                        crate::consts::MAX_PAYMENT_ATTEMPT_TAG_VALUE_LENGTH
+                    ),
+                }
+                .into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validate the token of the wallet payment method data, if any
+    pub fn validate_wallet_data(&self) -> common_utils::errors::CustomResult<(), ValidationError> {
+        match self
+            .payment_method_data
+            .as_ref()
+            .and_then(|payment_method_data| payment_method_data.payment_method_data.as_ref())
+        {
+            Some(PaymentMethodData::Wallet(wallet_data)) => wallet_data.validate(),
+            _ => Ok(()),
+        }
+    }
+
+    /// Validate the amount breakdown against the amount of the payment, returning the breakdown
+    /// with the tax amount reconciled
+    pub fn get_validated_amount_breakdown(
+        &self,
+    ) -> common_utils::errors::CustomResult<Option<AmountBreakdown>, ValidationError> {
+        let Some(amount_breakdown) = self.amount_breakdown.clone() else {
+            return Ok(None);
+        };
+        let amount = self
+            .amount
+            .ok_or(ValidationError::MissingRequiredField {
+                field_name: "amount".to_string(),
+            })
+            .attach_printable("The amount is required when the amount breakdown is passed")?;
+
+        amount_breakdown
+            .validate_and_reconcile(MinorUnit::from(amount))
+            .map(Some)
+    }
+
     /// Checks if the customer details are passed in both places
     /// If they are passed in both places, check for both the values to be equal
     /// Or else, return the field which has inconsistent data
@@ -796,6 +924,148 @@ mod payments_request_test {
             Some(vec!["customer_id and customer.id"])
         );
     }
+
+    #[test]
+    fn test_invalid_case_where_tag_key_is_too_long() {
+        let tags = HashMap::from([("a".repeat(41), "value".to_string())]);
+        let payments_request = PaymentsRequest {
+            tags: Some(PaymentAttemptTags::from(tags)),
+            ..Default::default()
+        };
+
+        assert!(payments_request.validate_tags().is_err());
+    }
+
+    #[test]
+    fn test_valid_case_where_tags_are_within_limits() {
+        let tags = HashMap::from([
+            ("campaign_id".to_string(), "summer_sale".to_string()),
+            ("channel".to_string(), "email".to_string()),
+        ]);
+        let payments_request = PaymentsRequest {
+            tags: Some(PaymentAttemptTags::from(tags)),
+            ..Default::default()
+        };
+
+        assert!(payments_request.validate_tags().is_ok());
+    }
+
+    #[test]
+    fn test_valid_case_where_multibyte_tags_are_within_character_limits() {
+        let tags = HashMap::from([("ü".repeat(40), "€".repeat(255))]);
+        let payments_request = PaymentsRequest {
+            tags: Some(PaymentAttemptTags::from(tags)),
+            ..Default::default()
+        };
+
+        assert!(payments_request.validate_tags().is_ok());
+    }
+
+    #[allow(clippy::unwrap_used)]
+    fn amount_breakdown_with_tax_rate(tax_amount: Option<MinorUnit>) -> AmountBreakdown {
+        AmountBreakdown {
+            subtotal: MinorUnit::new(1999),
+            tax_amount,
+            tax_rate: Some(Percentage::from_string("8.25".to_string()).unwrap()),
+            shipping_amount: Some(MinorUnit::new(500)),
+            discount_amount: Some(MinorUnit::new(200)),
+        }
+    }
+
+    #[test]
+    fn test_amount_breakdown_tax_is_derived_from_the_total_within_rounding() {
+        // 8.25% of 1999 is 164.9175, the stated total rounds the tax down to 164
+        let amount_breakdown = amount_breakdown_with_tax_rate(None)
+            .validate_and_reconcile(MinorUnit::new(1999 + 164 + 500 - 200));
+
+        assert_eq!(
+            amount_breakdown.map(|breakdown| breakdown.tax_amount).ok(),
+            Some(Some(MinorUnit::new(164)))
+        );
+    }
+
+    #[test]
+    fn test_amount_breakdown_which_does_not_sum_to_the_total_is_rejected() {
+        let amount_breakdown = amount_breakdown_with_tax_rate(Some(MinorUnit::new(165)));
+
+        assert!(amount_breakdown
+            .clone()
+            .validate_and_reconcile(MinorUnit::new(1999 + 165 + 500 - 200))
+            .is_ok());
+        assert!(amount_breakdown
+            .validate_and_reconcile(MinorUnit::new(1999 + 170 + 500 - 200))
+            .is_err());
+    }
+
+    fn apple_pay_wallet_data(payment_data: serde_json::Value) -> ApplePayWalletData {
+        use base64::Engine;
+
+        ApplePayWalletData {
+            payment_data: common_utils::consts::BASE64_ENGINE.encode(payment_data.to_string()),
+            payment_method: ApplepayPaymentMethod {
+                display_name: "Visa 0326".to_string(),
+                network: "Visa".to_string(),
+                pm_type: "debit".to_string(),
+            },
+            transaction_identifier: "transaction_identifier".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_apple_pay_payment_data_with_the_encryption_key_of_its_version_is_valid() {
+        let wallet_data = apple_pay_wallet_data(serde_json::json!({
+            "version": "EC_v1",
+            "data": "encrypted_data",
+            "signature": "signature",
+            "header": {
+                "ephemeralPublicKey": "ephemeral_public_key",
+                "publicKeyHash": "public_key_hash",
+                "transactionId": "transaction_id",
+            },
+        }));
+
+        assert!(wallet_data.validate().is_ok());
+    }
+
+    #[test]
+    fn test_apple_pay_payment_data_without_the_encryption_key_of_its_version_is_rejected() {
+        let wallet_data = apple_pay_wallet_data(serde_json::json!({
+            "version": "RSA_v1",
+            "data": "encrypted_data",
+            "signature": "signature",
+            "header": {
+                "ephemeralPublicKey": "ephemeral_public_key",
+                "publicKeyHash": "public_key_hash",
+                "transactionId": "transaction_id",
+            },
+        }));
+
+        assert!(wallet_data.validate().is_err());
+    }
+
+    #[test]
+    fn test_apple_pay_payment_data_which_is_not_base64_encoded_is_rejected() {
+        let mut wallet_data = apple_pay_wallet_data(serde_json::json!({}));
+        wallet_data.payment_data = "not base64 encoded".to_string();
+
+        assert!(wallet_data.validate().is_err());
+    }
+
+    #[test]
+    fn test_google_pay_tokenization_type_must_be_supported() {
+        let tokenization_data = |token_type: &str| GpayTokenizationData {
+            token_type: token_type.to_string(),
+            token: "token".to_string(),
+        };
+
+        assert!(matches!(
+            tokenization_data("PAYMENT_GATEWAY").get_tokenization_type(),
+            Ok(GpayTokenizationType::PaymentGateway)
+        ));
+        assert!(tokenization_data("payment_gateway")
+            .get_tokenization_type()
+            .is_err());
+    }
 }
 
 /// Fee information to be charged on the payment being collected
@@ -1082,6 +1352,126 @@ impl From<MinorUnit> for Amount {
         }
     }
 }
+
+/// When the CVV of a stored card has to be collected again from the customer. The CVV is never
+/// required for merchant initiated payments, and is not required when neither condition is set.
+#[derive(Debug, Clone, Default, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CvvRecollectionPolicy {
+    /// The CVV is required for payments of at least the threshold amount of their currency, in
+    /// the lowest denomination of the currency. Payments in currencies without a threshold amount
+    /// do not require the CVV because of their amount.
+    #[schema(value_type = Option<HashMap<Currency, i64>>, example = json!({"USD": 50000, "JPY": 75000}))]
+    pub amount_thresholds: Option<HashMap<enums::Currency, MinorUnit>>,
+
+    /// The CVV is required for the first payment with the stored card in a session of this many
+    /// seconds. The session starts whenever the CVV is collected.
+    #[schema(value_type = Option<u32>, example = 1800)]
+    pub session_duration_in_seconds: Option<u32>,
+}
+
+/// Breakdown of the amount of the payment into its components
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AmountBreakdown {
+    /// The amount of the items purchased, before tax, shipping and discount
+    #[schema(value_type = i64, example = 6000)]
+    pub subtotal: MinorUnit,
+
+    /// The tax charged on the payment. If not passed, it is derived from the amount of the payment
+    #[schema(value_type = Option<i64>, example = 495)]
+    pub tax_amount: Option<MinorUnit>,
+
+    /// The tax rate applied to the subtotal, as a percentage with up to 2 decimal digits
+    #[schema(value_type = Option<Object>, example = json!({"percentage": 8.25}))]
+    pub tax_rate: Option<Percentage<AMOUNT_BREAKDOWN_TAX_RATE_PRECISION_LENGTH>>,
+
+    /// The amount charged for shipping
+    #[schema(value_type = Option<i64>, example = 500)]
+    pub shipping_amount: Option<MinorUnit>,
+
+    /// The discount deducted from the payment
+    #[schema(value_type = Option<i64>, example = 200)]
+    pub discount_amount: Option<MinorUnit>,
+}
+
+impl AmountBreakdown {
+    /// Validate that the components of the breakdown sum to the provided total, and fill in the tax
+    /// amount if it was not passed.
+    ///
+    /// A tax computed from the tax rate is rarely a whole number of minor units, and merchants may
+    /// round it either way. The tax amount is therefore accepted if it is within one minor unit of
+    /// the tax computed from the rate, and when only the rate is passed, the tax amount is taken to
+    /// be whatever reconciles the breakdown with the total.
+    pub fn validate_and_reconcile(
+        mut self,
+        total: MinorUnit,
+    ) -> common_utils::errors::CustomResult<Self, ValidationError> {
+        let subtotal = self.subtotal.get_amount_as_i64();
+        let shipping_amount = self.shipping_amount.unwrap_or_default().get_amount_as_i64();
+        let discount_amount = self.discount_amount.unwrap_or_default().get_amount_as_i64();
+
+        let tax_from_rate = self
+            .tax_rate
+            .as_ref()
+            .map(|tax_rate| tax_rate.apply_and_ceil_result(self.subtotal))
+            .transpose()
+            .change_context(ValidationError::InvalidValue {
+                message: "Unable to apply the tax rate to the subtotal".to_string(),
+            })?
+            .map(|tax| tax.get_amount_as_i64());
+
+        let tax_amount = match (self.tax_amount, tax_from_rate) {
+            (Some(tax_amount), _) => tax_amount.get_amount_as_i64(),
+            (None, Some(_)) => total
+                .get_amount_as_i64()
+                .saturating_sub(subtotal)
+                .saturating_sub(shipping_amount)
+                .saturating_add(discount_amount),
+            (None, None) => 0,
+        };
+
+        if [subtotal, tax_amount, shipping_amount, discount_amount]
+            .iter()
+            .any(|amount| amount.is_negative())
+        {
+            return Err(ValidationError::InvalidValue {
+                message: "The amounts in the amount breakdown cannot be negative".to_string(),
+            }
+            .into());
+        }
+
+        // The tax computed from the rate is rounded up, so the tax amount may be one less
+        if let Some(tax_from_rate) = tax_from_rate {
+            if !(tax_from_rate.saturating_sub(1)..=tax_from_rate).contains(&tax_amount) {
+                return Err(ValidationError::InvalidValue {
+                    message: format!(
+                        "The tax amount {tax_amount} does not match the tax rate applied to the subtotal"
+                    ),
+                }
+                .into());
+            }
+        }
+
+        let breakdown_total = subtotal
+            .saturating_add(tax_amount)
+            .saturating_add(shipping_amount)
+            .saturating_sub(discount_amount);
+        if breakdown_total != total.get_amount_as_i64() {
+            return Err(ValidationError::InvalidValue {
+                message: format!(
+                    "The amount breakdown sums to {breakdown_total}, which does not match the amount {}",
+                    total.get_amount_as_i64()
+                ),
+            }
+            .into());
+        }
+
+        self.tax_amount = Some(MinorUnit::new(tax_amount));
+        Ok(self)
+    }
+}
+
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone)]
 #[serde(deny_unknown_fields)]
 pub struct PaymentsRedirectRequest {
@@ -1204,6 +1594,8 @@ pub struct MandateAmountData {
         "frequency": "DAILY"
     }"#)]
     pub metadata: Option<pii::SecretSerdeValue>,
+    /// The usage constraints to be enforced on the merchant initiated payments made using the mandate
+    pub constraints: Option<MandateConstraints>,
 }
 
 #[derive(Eq, PartialEq, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
@@ -2839,6 +3231,17 @@ pub enum WalletData {
     Mifinity(MifinityData),
 }
 
+impl WalletData {
+    /// Validate the presence and shape of the token of the wallets which carry a token
+    pub fn validate(&self) -> common_utils::errors::CustomResult<(), ValidationError> {
+        match self {
+            Self::ApplePay(apple_pay_wallet_data) => apple_pay_wallet_data.validate(),
+            Self::GooglePay(google_pay_wallet_data) => google_pay_wallet_data.validate(),
+            _ => Ok(()),
+        }
+    }
+}
+
 impl GetAddressFromPaymentMethodData for WalletData {
     fn get_billing_address(&self) -> Option<Address> {
         match self {
@@ -3057,6 +3460,54 @@ pub struct GpayTokenizationData {
     pub token: String,
 }
 
+/// The type of the tokenization of a Google Pay payment method
+#[derive(Eq, PartialEq, Clone, Copy, Debug, serde::Deserialize, serde::Serialize, Display)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[strum(serialize_all = "SCREAMING_SNAKE_CASE")]
+pub enum GpayTokenizationType {
+    /// The payment method is tokenized by the gateway processing the payment
+    PaymentGateway,
+    /// The payment method is encrypted for the merchant, who decrypts it
+    Direct,
+}
+
+impl GpayTokenizationData {
+    /// Get the type of the tokenization, which must be one of the types supported by Google Pay
+    pub fn get_tokenization_type(
+        &self,
+    ) -> common_utils::errors::CustomResult<GpayTokenizationType, ValidationError> {
+        match self.token_type.as_str() {
+            "PAYMENT_GATEWAY" => Ok(GpayTokenizationType::PaymentGateway),
+            "DIRECT" => Ok(GpayTokenizationType::Direct),
+            token_type => Err(ValidationError::InvalidValue {
+                message: format!(
+                    "The Google Pay tokenization type `{token_type}` is not supported, it must be \
+                     one of `PAYMENT_GATEWAY` and `DIRECT`"
+                ),
+            }
+            .into()),
+        }
+    }
+}
+
+impl GooglePayWalletData {
+    /// Validate that the tokenization of the payment method is of a supported type, and carries a
+    /// token
+    pub fn validate(&self) -> common_utils::errors::CustomResult<(), ValidationError> {
+        self.tokenization_data.get_tokenization_type()?;
+
+        if self.tokenization_data.token.trim().is_empty() {
+            return Err(ValidationError::MissingRequiredField {
+                field_name: "payment_method_data.wallet.google_pay.tokenization_data.token"
+                    .to_string(),
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct ApplePayWalletData {
     /// The payment data of Apple pay
@@ -3067,6 +3518,94 @@ pub struct ApplePayWalletData {
     pub transaction_identifier: String,
 }
 
+/// The encrypted payment data of an Apple Pay token, which is passed base64 encoded in the
+/// `payment_data` of the wallet data
+#[derive(Clone, Debug, serde::Deserialize)]
+pub struct ApplePayPaymentData {
+    /// The version of the encryption of the payment data
+    pub version: ApplePayPaymentDataVersion,
+    /// The encrypted payment data
+    pub data: Secret<String>,
+    /// The signature of the payment data
+    pub signature: Secret<String>,
+    /// The parameters of the encryption of the payment data
+    pub header: ApplePayPaymentDataHeader,
+}
+
+/// The version of the encryption of the payment data of an Apple Pay token
+#[derive(Eq, PartialEq, Clone, Copy, Debug, serde::Deserialize)]
+pub enum ApplePayPaymentDataVersion {
+    /// Elliptic curve encryption
+    #[serde(rename = "EC_v1")]
+    EcV1,
+    /// RSA encryption
+    #[serde(rename = "RSA_v1")]
+    RsaV1,
+}
+
+/// The parameters of the encryption of the payment data of an Apple Pay token
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplePayPaymentDataHeader {
+    /// The ephemeral public key, for elliptic curve encryption
+    pub ephemeral_public_key: Option<Secret<String>>,
+    /// The wrapped symmetric key, for RSA encryption
+    pub wrapped_key: Option<Secret<String>>,
+    /// The hash of the public key of the certificate used to encrypt the payment data
+    pub public_key_hash: Secret<String>,
+    /// The identifier of the transaction
+    pub transaction_id: Secret<String>,
+}
+
+impl ApplePayWalletData {
+    /// Get the encrypted payment data, decoded from the `payment_data`
+    pub fn get_payment_data(
+        &self,
+    ) -> common_utils::errors::CustomResult<ApplePayPaymentData, ValidationError> {
+        use base64::Engine;
+
+        let invalid_payment_data = |message: &str| ValidationError::InvalidValue {
+            message: format!("The Apple Pay payment data is malformed, {message}"),
+        };
+
+        let decoded_payment_data = common_utils::consts::BASE64_ENGINE
+            .decode(self.payment_data.trim())
+            .change_context(invalid_payment_data("it must be base64 encoded"))?;
+
+        serde_json::from_slice::<ApplePayPaymentData>(&decoded_payment_data).change_context(
+            invalid_payment_data("it must have a version, data, signature and header"),
+        )
+    }
+
+    /// Validate the structure of the payment data, and that it carries the encryption parameters
+    /// of its version
+    pub fn validate(&self) -> common_utils::errors::CustomResult<(), ValidationError> {
+        let payment_data = self.get_payment_data()?;
+
+        let encryption_key = match payment_data.version {
+            ApplePayPaymentDataVersion::EcV1 => payment_data.header.ephemeral_public_key,
+            ApplePayPaymentDataVersion::RsaV1 => payment_data.header.wrapped_key,
+        };
+        let is_encryption_key_missing = encryption_key
+            .as_ref()
+            .map_or(true, |encryption_key| encryption_key.peek().is_empty());
+
+        if is_encryption_key_missing
+            || payment_data.data.peek().is_empty()
+            || payment_data.signature.peek().is_empty()
+        {
+            return Err(ValidationError::InvalidValue {
+                message: "The Apple Pay payment data is malformed, the encrypted data, signature \
+                          or encryption key is missing"
+                    .to_string(),
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Eq, PartialEq, Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct ApplepayPaymentMethod {
     /// The name to be displayed on Apple Pay button
@@ -3591,6 +4130,12 @@ pub struct PaymentsCaptureRequest {
     /// The Amount to be captured/ debited from the user's payment method.
     #[schema(value_type = i64, example = 6540)]
     pub amount_to_capture: Option<MinorUnit>,
+    /// The currency in which the amount is to be captured. Defaults to the currency of the payment.
+    /// Capturing in a different currency is supported only by a few connectors, and the payment is
+    /// then settled in the capture currency, so the presentment currency of the payment and the
+    /// capture currency have to be tracked separately for the reconciliation of the settlement.
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
     /// Decider to refund the uncaptured amount
     pub refund_uncaptured_amount: Option<bool>,
     /// Provides information about a card payment that customers see on their statements.
@@ -3671,6 +4216,28 @@ pub enum NextActionData {
     },
 }
 
+impl NextActionData {
+    /// The client side experience which has to be rendered for performing the next action
+    pub fn get_payment_experience(&self) -> Option<api_enums::PaymentExperience> {
+        match self {
+            Self::RedirectToUrl { .. } => Some(api_enums::PaymentExperience::RedirectToUrl),
+            Self::QrCodeInformation { .. } | Self::FetchQrCodeInformation { .. } => {
+                Some(api_enums::PaymentExperience::DisplayQrCode)
+            }
+            Self::DisplayVoucherInformation { .. } => {
+                Some(api_enums::PaymentExperience::DisplayVoucher)
+            }
+            Self::ThirdPartySdkSessionToken { .. }
+            | Self::ThreeDsInvoke { .. }
+            | Self::InvokeSdkClient { .. } => Some(api_enums::PaymentExperience::InvokeSdkClient),
+            Self::WaitScreenInformation { .. } => {
+                Some(api_enums::PaymentExperience::DisplayWaitScreen)
+            }
+            Self::DisplayBankTransferInformation { .. } => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
 pub struct ThreeDsData {
     /// ThreeDS authentication url - to initiate authentication
@@ -4028,9 +4595,17 @@ pub struct PaymentsResponse {
     #[schema(max_length = 255, example = "Payment for shoes purchase")]
     pub statement_descriptor_suffix: Option<String>,
 
+    /// Warnings about the statement descriptors which exceeded the maximum length supported by the connector, and were truncated before they were sent to the connector
+    #[schema(value_type = Option<Vec<String>>)]
+    pub statement_descriptor_warnings: Option<Vec<String>>,
+
     /// Additional information required for redirection
     pub next_action: Option<NextActionData>,
 
+    /// A token to resume the payment with, if the customer leaves the payment while it requires their action. The payment can be resumed until the client secret expires
+    #[schema(value_type = Option<String>, example = "rsm_3f1c2a9b0e8d4c7a6b5e4d3c2b1a0f9e8d7c6b5a4f3e2d1c0b9a8f7e6d5c4b3a")]
+    pub resume_token: Option<Secret<String>>,
+
     /// If the payment was cancelled the reason will be provided here
     pub cancellation_reason: Option<String>,
 
@@ -4089,6 +4664,58 @@ pub struct PaymentsResponse {
     /// Frm message contains information about the frm response
     pub frm_message: Option<FrmMessage>,
 
+    /// The score returned by the fraud check performed before calling the connector
+    #[schema(example = 80)]
+    pub fraud_score: Option<i32>,
+
+    /// The decision taken on the payment based on the fraud check performed before calling the connector
+    #[schema(value_type = Option<FraudDecision>, example = "approve")]
+    pub fraud_decision: Option<api_enums::FraudDecision>,
+
+    /// The amount approved by the connector, when the connector approved less than the requested amount. The payment can either be captured for at most this amount or cancelled
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub approved_amount: Option<MinorUnit>,
+
+    /// The date on which the payment is expected to be settled by the connector, either as reported by the connector or as computed from the settlement delay of the connector. Only present for captured payments
+    #[schema(value_type = Option<String>, format = Date, example = "2022-09-12")]
+    pub expected_settlement_date: Option<time::Date>,
+
+    /// The advice of the card network on whether and when the payment can be retried, when the payment was declined along with such an advice
+    pub retry_advice: Option<RetryAdvice>,
+
+    /// The number of times the request to the connector was retried on network errors, when the request was retried
+    #[schema(value_type = Option<u8>, example = 1)]
+    pub connector_network_retry_count: Option<u8>,
+
+    /// The attempt which was declined since the connector required the customer to be authenticated, when the payment was retried with 3DS. The `next_action` carries the challenge for the customer
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4_1")]
+    pub stepped_up_from_attempt_id: Option<String>,
+
+    /// Tags of the payment attempt
+    #[schema(value_type = Option<HashMap<String, String>>, example = json!({"campaign_id": "summer_sale", "channel": "email"}))]
+    pub tags: Option<PaymentAttemptTags>,
+
+    /// Breakdown of the amount into the subtotal, tax, shipping and discount
+    pub amount_breakdown: Option<AmountBreakdown>,
+
+    /// The payment which this payment renews or retries
+    #[schema(value_type = Option<String>, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub parent_payment_id: Option<id_type::PaymentId>,
+
+    /// The fulfillment status of the order of the payment, as reported by the merchant
+    #[schema(value_type = Option<FulfillmentStatus>, example = "shipped")]
+    pub fulfillment_status: Option<api_enums::FulfillmentStatus>,
+
+    /// The time at which the order of the payment was shipped
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub shipped_at: Option<PrimitiveDateTime>,
+
+    /// The time at which the order of the payment was delivered
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub delivered_at: Option<PrimitiveDateTime>,
+
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object.
     #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
     pub metadata: Option<serde_json::Value>,
@@ -4187,6 +4814,100 @@ pub struct PaymentsResponse {
     pub connector_mandate_id: Option<String>,
 }
 
+impl PaymentsResponse {
+    /// Get the names of the top-level fields of the payment response, as per its schema. The
+    /// schema is built only once, on the first call.
+    pub fn get_field_names() -> &'static HashSet<String> {
+        static FIELD_NAMES: std::sync::OnceLock<HashSet<String>> = std::sync::OnceLock::new();
+
+        FIELD_NAMES.get_or_init(|| match <Self as ToSchema<'_>>::schema().1 {
+            utoipa::openapi::RefOr::T(utoipa::openapi::Schema::Object(object)) => {
+                object.properties.into_keys().collect()
+            }
+            _ => HashSet::new(),
+        })
+    }
+}
+
+/// The top-level fields of the payment response which are requested by the caller
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaymentsResponseFields(HashSet<String>);
+
+impl std::str::FromStr for PaymentsResponseFields {
+    type Err = ValidationError;
+
+    /// Parse the comma separated names of the fields, rejecting the names which are not fields of
+    /// the payment response
+    fn from_str(fields: &str) -> Result<Self, Self::Err> {
+        let fields = fields
+            .split(',')
+            .map(str::trim)
+            .filter(|field| !field.is_empty())
+            .map(ToOwned::to_owned)
+            .collect::<HashSet<_>>();
+
+        if fields.is_empty() {
+            return Err(ValidationError::InvalidValue {
+                message: "At least one field of the payment response must be requested".to_string(),
+            });
+        }
+
+        let known_fields = PaymentsResponse::get_field_names();
+        let mut unknown_fields = fields
+            .iter()
+            .filter(|field| !known_fields.contains(field))
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        if !unknown_fields.is_empty() {
+            unknown_fields.sort_unstable();
+            return Err(ValidationError::InvalidValue {
+                message: format!(
+                    "Unknown fields of the payment response requested: {}",
+                    unknown_fields.join(", ")
+                ),
+            });
+        }
+
+        Ok(Self(fields))
+    }
+}
+
+impl PaymentsResponseFields {
+    pub fn contains(&self, field: &str) -> bool {
+        self.0.contains(field)
+    }
+}
+
+/// The payment response, serialized with only the top-level fields requested by the caller
+#[derive(Clone, Debug)]
+pub struct SparsePaymentsResponse {
+    pub response: PaymentsResponse,
+    /// The fields to be serialized, all the fields of the response are serialized if not provided
+    pub fields: Option<PaymentsResponseFields>,
+}
+
+impl Serialize for SparsePaymentsResponse {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let Some(fields) = &self.fields else {
+            return self.response.serialize(serializer);
+        };
+
+        match serde_json::to_value(&self.response).map_err(serde::ser::Error::custom)? {
+            serde_json::Value::Object(response) => serializer.collect_map(
+                response
+                    .into_iter()
+                    .filter(|(field, _)| fields.contains(field)),
+            ),
+            _ => Err(serde::ser::Error::custom(
+                "Payment response is not serialized as an object",
+            )),
+        }
+    }
+}
+
 /// Fee information to be charged on the payment being collected
 #[derive(Setter, Clone, Default, Debug, PartialEq, serde::Serialize, ToSchema)]
 pub struct PaymentChargeResponse {
@@ -4398,6 +5119,256 @@ pub struct PaymentsAggregateResponse {
     pub status_with_count: HashMap<enums::IntentStatus, i64>,
 }
 
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct PaymentsDistinctCardCountResponse {
+    /// The number of distinct cards used in the payments created in the time range
+    pub distinct_card_count: i64,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentsSettlementAmountsRequest {
+    /// The date on which the payments are expected to be settled
+    pub settlement_date: time::Date,
+}
+
+/// The amount of the payments in a currency which are expected to be settled on the date
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct PaymentsSettlementAmount {
+    /// The currency of the payments
+    pub currency: api_enums::Currency,
+    /// The sum of the amounts of the payments in the currency
+    pub amount: MinorUnit,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct PaymentsSettlementAmountsResponse {
+    /// The date on which the payments are expected to be settled
+    pub settlement_date: time::Date,
+    /// The amounts of the captured payments expected to be settled on the date, by currency
+    pub settlement_amounts: Vec<PaymentsSettlementAmount>,
+}
+
+/// Constraints on the payments listed as pending a review of their fraud check
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentsPendingReviewListConstraints {
+    /// Only the payments processed through this connector
+    pub connector: Option<api_enums::Connector>,
+    /// Only the payments with at least this fraud score
+    pub min_fraud_score: Option<i32>,
+    /// Only the payments created before this time, which are the ones pending review for longer
+    #[serde(
+        default,
+        with = "common_utils::custom_serde::iso8601::option",
+        rename = "created.lt"
+    )]
+    pub created_lt: Option<PrimitiveDateTime>,
+    /// The maximum number of payments to be returned
+    #[serde(default = "default_payments_list_limit")]
+    pub limit: u32,
+    /// The number of payments to be skipped
+    pub offset: Option<u32>,
+}
+
+/// A payment pending a review of its fraud check
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct PaymentPendingReview {
+    /// The identifier of the payment
+    pub payment_id: id_type::PaymentId,
+    /// The identifier of the attempt whose fraud check is to be reviewed
+    pub attempt_id: String,
+    /// The profile of the payment
+    pub profile_id: id_type::ProfileId,
+    /// The amount of the payment
+    pub amount: MinorUnit,
+    /// The currency of the payment
+    pub currency: Option<api_enums::Currency>,
+    /// The connector through which the payment is processed
+    pub connector: Option<String>,
+    /// The score given to the payment by the fraud check
+    pub fraud_score: Option<i32>,
+    /// The decision of the fraud check on the payment
+    pub fraud_decision: Option<api_enums::FraudDecision>,
+    /// The time at which the payment was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+/// The payments pending a review of their fraud check, the oldest first
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct PaymentsPendingReviewListResponse {
+    /// The number of payments returned
+    pub count: usize,
+    /// The payments pending review
+    pub data: Vec<PaymentPendingReview>,
+}
+
+/// A payment of a chain of related payments, such as the renewals of a subscription or the
+/// retries of a payment
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct RelatedPayment {
+    /// The identifier of the payment
+    #[schema(value_type = String, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: id_type::PaymentId,
+
+    /// The payment which this payment renews or retries
+    #[schema(value_type = Option<String>, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub parent_payment_id: Option<id_type::PaymentId>,
+
+    /// The status of the payment
+    #[schema(value_type = IntentStatus, example = "succeeded")]
+    pub status: api_enums::IntentStatus,
+
+    /// The amount of the payment
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+
+    /// The currency of the payment
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+
+    /// Time when the payment was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct RelatedPaymentsResponse {
+    /// The payment for which the related payments were requested
+    #[schema(value_type = String, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: id_type::PaymentId,
+
+    /// The payments of the chain which the payment belongs to, including the payment itself, in
+    /// the order they were created
+    pub related_payments: Vec<RelatedPayment>,
+}
+
+/// The receipt of a payment, assembled from the payment, its captures and its refunds
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PaymentReceiptResponse {
+    /// The identifier of the payment
+    #[schema(value_type = String, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: id_type::PaymentId,
+
+    /// A token with which the receipt can be retrieved using the publishable key, such as for sharing the receipt with the customer
+    #[schema(value_type = Option<String>, example = "rcpt_5e1a9b0c8d7f6e4a3b2c1d0e9f8a7b6c")]
+    pub receipt_token: Option<Secret<String>>,
+
+    /// The merchant of the payment
+    pub merchant: ReceiptMerchantDetails,
+
+    /// The status of the payment
+    #[schema(value_type = IntentStatus, example = "succeeded")]
+    pub status: api_enums::IntentStatus,
+
+    /// The currency of the payment
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The amount of the payment
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+
+    /// Breakdown of the amount into the subtotal, tax, shipping and discount
+    pub amount_breakdown: Option<AmountBreakdown>,
+
+    /// The amount captured from the customer
+    #[schema(value_type = i64, example = 6540)]
+    pub amount_captured: MinorUnit,
+
+    /// The amount refunded to the customer, from the refunds which succeeded
+    #[schema(value_type = i64, example = 1000)]
+    pub amount_refunded: MinorUnit,
+
+    /// The amount which the customer paid after the refunds
+    #[schema(value_type = i64, example = 5540)]
+    pub net_amount: MinorUnit,
+
+    /// The payment method used for the payment
+    #[schema(value_type = Option<PaymentMethod>, example = "card")]
+    pub payment_method: Option<api_enums::PaymentMethod>,
+
+    /// The type of the payment method used for the payment
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+
+    /// The masked details of the payment method used for the payment, such as the last four digits of the card
+    pub payment_method_data: Option<PaymentMethodDataResponse>,
+
+    /// A description of the payment
+    #[schema(example = "It's my first payment request")]
+    pub description: Option<String>,
+
+    /// The captures of the payment, when the payment was captured in multiple parts
+    pub captures: Vec<ReceiptCapture>,
+
+    /// The refunds of the payment
+    pub refunds: Vec<ReceiptRefund>,
+
+    /// Time when the payment was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ReceiptMerchantDetails {
+    /// The identifier of the merchant
+    #[schema(value_type = String, example = "merchant_1668273825")]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The name of the merchant
+    #[schema(value_type = Option<String>, example = "NewAge Retailer")]
+    pub merchant_name: Option<Secret<String>>,
+}
+
+/// A capture of a payment which was captured in multiple parts
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ReceiptCapture {
+    /// The identifier of the capture
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4_1_capture_1")]
+    pub capture_id: String,
+
+    /// The status of the capture
+    #[schema(value_type = CaptureStatus, example = "charged")]
+    pub status: api_enums::CaptureStatus,
+
+    /// The amount of the capture
+    #[schema(value_type = i64, example = 2000)]
+    pub amount: MinorUnit,
+
+    /// Time when the capture was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+/// A refund of a payment
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ReceiptRefund {
+    /// The identifier of the refund
+    #[schema(example = "ref_mbabizu24mvu3mela5njyhpit4")]
+    pub refund_id: String,
+
+    /// The status of the refund
+    #[schema(value_type = RefundStatus, example = "succeeded")]
+    pub status: refunds::RefundStatus,
+
+    /// The amount of the refund
+    #[schema(value_type = i64, example = 1000)]
+    pub amount: MinorUnit,
+
+    /// The reason of the refund
+    pub reason: Option<String>,
+
+    /// Time when the refund was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct AmountFilter {
     /// The start amount to filter list of transactions which are greater than or equal to the start amount
@@ -4665,7 +5636,8 @@ pub struct PaymentsRetrieveRequest {
     pub force_sync: bool,
     /// The parameters passed to a retrieve request
     pub param: Option<String>,
-    /// The name of the connector
+    /// The name of the connector. A payment retrieved by the transaction id of the connector is
+    /// looked up among the payments made through this connector
     pub connector: Option<String>,
     /// Merchant connector details used to make payments.
     #[schema(value_type = Option<MerchantConnectorDetailsWrap>)]
@@ -5439,6 +6411,10 @@ pub struct PaymentRetrieveBody {
     pub expand_captures: Option<bool>,
     /// If enabled provides list of attempts linked to payment intent
     pub expand_attempts: Option<bool>,
+    /// The top-level fields of the payment response to be returned, as a comma separated list.
+    /// All the fields are returned if not provided
+    #[schema(example = "payment_id,status,amount")]
+    pub fields: Option<String>,
 }
 
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
@@ -5478,6 +6454,174 @@ pub struct PaymentsCancelRequest {
     pub merchant_connector_details: Option<admin::MerchantConnectorDetailsWrap>,
 }
 
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+pub struct PaymentsResumeRequest {
+    /// The resume token which was returned when the payment required the action of the customer
+    #[schema(value_type = String, example = "rsm_3f1c2a9b0e8d4c7a6b5e4d3c2b1a0f9e8d7c6b5a4f3e2d1c0b9a8f7e6d5c4b3a")]
+    pub resume_token: Secret<String>,
+}
+
+/// The request to simulate a webhook of a test payment
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SimulateWebhookRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: id_type::PaymentId,
+    /// The type of the event to simulate, which should be applicable to the current status of the
+    /// payment
+    #[schema(value_type = EventType, example = "payment_succeeded")]
+    pub event_type: api_enums::EventType,
+}
+
+/// The webhook simulated for a test payment, along with the outcome of its delivery
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct SimulateWebhookResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: id_type::PaymentId,
+    /// The identifier for the event of the simulated webhook
+    #[schema(example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub event_id: String,
+    /// The type of the event of the simulated webhook
+    #[schema(value_type = EventType, example = "payment_succeeded")]
+    pub event_type: api_enums::EventType,
+    /// Whether the merchant server acknowledged the simulated webhook
+    pub is_delivery_successful: bool,
+    /// The HTTP status code returned by the merchant server, which is not set when the merchant
+    /// server could not be reached
+    #[schema(example = 200)]
+    pub delivery_status_code: Option<u16>,
+}
+
+/// The timeline of a payment, which is every event of the payment across all its attempts in
+/// chronological order
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PaymentTimelineResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: id_type::PaymentId,
+    /// The events of the payment in chronological order. The events which occurred at the same time are ordered by the step of the lifecycle of the payment which they record.
+    pub events: Vec<PaymentTimelineEvent>,
+}
+
+/// An event in the timeline of a payment
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+pub struct PaymentTimelineEvent {
+    /// The type of the event
+    pub event_type: PaymentTimelineEventType,
+    /// The type of the record from which the event is derived
+    pub source: PaymentTimelineEventSource,
+    /// The identifier of the record from which the event is derived, such as the attempt id or the refund id
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4_1")]
+    pub source_id: String,
+    /// Time when the event occurred
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub timestamp: PrimitiveDateTime,
+    /// The connector through which the event occurred
+    #[schema(example = "stripe")]
+    pub connector: Option<String>,
+    /// The amount of the event, such as the amount captured or refunded
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub amount: Option<MinorUnit>,
+    /// The error code of the event, for a declined attempt or a failed refund
+    pub error_code: Option<String>,
+    /// The error message of the event, for a declined attempt or a failed refund
+    pub error_message: Option<String>,
+}
+
+/// The type of an event in the timeline of a payment
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, ToSchema, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentTimelineEventType {
+    /// The payment was created
+    Created,
+    /// An attempt of the payment was created
+    AttemptCreated,
+    /// An attempt of the payment was authorized
+    Authorized,
+    /// An attempt of the payment was declined or failed
+    Declined,
+    /// An attempt of the payment, or a part of it, was captured
+    Captured,
+    /// An attempt of the payment was voided
+    Voided,
+    /// A refund of the payment was initiated
+    RefundInitiated,
+    /// A refund of the payment succeeded
+    Refunded,
+    /// A refund of the payment failed
+    RefundFailed,
+    /// A dispute was raised on the payment
+    Disputed,
+    /// A dispute raised on the payment was won by the merchant
+    DisputeWon,
+    /// A dispute raised on the payment was lost or accepted by the merchant
+    DisputeLost,
+    /// A dispute raised on the payment was cancelled or expired
+    DisputeClosed,
+    /// A webhook of the payment, its refunds or its disputes was received from the connector
+    WebhookReceived,
+    /// A webhook of the payment was sent to the merchant
+    WebhookSent,
+}
+
+/// The type of the record from which an event in the timeline of a payment is derived
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, ToSchema, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentTimelineEventSource {
+    Payment,
+    PaymentAttempt,
+    Capture,
+    Refund,
+    Dispute,
+    Webhook,
+    IncomingWebhook,
+}
+
+/// Whether a payment can be voided, along with the reason for which it cannot be
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+pub struct PaymentVoidEligibilityResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: id_type::PaymentId,
+    /// Whether the payment can be voided
+    pub voidable: bool,
+    /// The reason for which the payment cannot be voided, if it is not voidable
+    pub reason: Option<VoidIneligibilityReason>,
+    /// The amount which would be released by voiding the payment. For a payment which was partially captured, only the portion which has not been captured can be voided.
+    #[schema(value_type = i64, example = 6540)]
+    pub voidable_amount: MinorUnit,
+}
+
+/// The reason for which a payment cannot be voided
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, ToSchema, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VoidIneligibilityReason {
+    /// The authorized amount of the payment has been captured
+    AlreadyCaptured,
+    /// The payment has already been voided or cancelled
+    AlreadyVoided,
+    /// The payment failed, and there is no authorization to be voided
+    PaymentFailed,
+    /// The outcome of the payment is not yet known, such as when it is being processed or is under review
+    PaymentInProgress,
+    /// The connector of the payment does not support voiding the authorization of the payment
+    ConnectorNotSupported,
+}
+
+#[derive(Default, Debug, serde::Serialize, Clone)]
+pub struct PaymentsCaptureVoidRequest {
+    /// The identifier for the payment
+    pub payment_id: id_type::PaymentId,
+    /// The identifier for the capture to be voided
+    pub capture_id: String,
+}
+
 #[derive(Default, Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
 pub struct PaymentsIncrementalAuthorizationRequest {
     /// The identifier for the payment
@@ -5550,6 +6694,39 @@ pub struct PaymentsManualUpdateResponse {
     pub connector_transaction_id: Option<String>,
 }
 
+/// Request for updating the fulfillment status of the order of a payment
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+pub struct PaymentsFulfillmentRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: id_type::PaymentId,
+    /// The new fulfillment status of the order
+    #[schema(value_type = FulfillmentStatus, example = "shipped")]
+    pub fulfillment_status: api_enums::FulfillmentStatus,
+    /// The time at which the order reached the fulfillment status, defaults to the current time
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub fulfilled_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+pub struct PaymentsFulfillmentResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: id_type::PaymentId,
+    /// The fulfillment status of the order
+    #[schema(value_type = FulfillmentStatus, example = "shipped")]
+    pub fulfillment_status: api_enums::FulfillmentStatus,
+    /// The time at which the order was shipped
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub shipped_at: Option<PrimitiveDateTime>,
+    /// The time at which the order was delivered
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub delivered_at: Option<PrimitiveDateTime>,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
 pub enum ThreeDsCompletionIndicator {
     /// 3DS method successfully completed
@@ -809,6 +809,71 @@ pub struct PayoutListFilters {
     pub payout_method: Vec<common_enums::PayoutType>,
 }
 
+/// The returns of previously succeeded payouts reported by the bank, such as the entries of a
+/// return file
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutReturnsRequest {
+    /// The connector through which the returned payouts were made
+    #[schema(value_type = PayoutConnectors, example = "wise")]
+    pub connector: api_enums::PayoutConnectors,
+    /// The returns to be applied to the payouts
+    pub returns: Vec<PayoutReturnEntry>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutReturnEntry {
+    /// The reference of the payout at the bank, which is the `connector_payout_id` of the payout
+    #[schema(example = "1727866418")]
+    pub bank_reference: String,
+    /// The code of the reason for which the payout was returned, as reported by the bank
+    #[schema(example = "AC04")]
+    pub return_reason_code: String,
+    /// The description of the reason for which the payout was returned, as reported by the bank
+    #[schema(example = "Account closed")]
+    pub return_reason_message: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct PayoutReturnsResponse {
+    /// The result of applying each return, in the order of the returns in the request
+    pub results: Vec<PayoutReturnEntryResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct PayoutReturnEntryResponse {
+    /// The reference of the payout at the bank
+    #[schema(example = "1727866418")]
+    pub bank_reference: String,
+    /// The identifier of the payout which was resolved from the bank reference
+    #[schema(example = "187282ab-40ef-47a9-9206-5099ba31e432")]
+    pub payout_id: Option<String>,
+    /// The result of applying the return to the payout
+    pub result: PayoutReturnResult,
+    /// The status of the payout after the return was applied
+    #[schema(value_type = Option<PayoutStatus>, example = "returned")]
+    pub status: Option<api_enums::PayoutStatus>,
+    /// The reason for which the return could not be applied
+    #[schema(example = "Failed to update the payout")]
+    pub error_message: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutReturnResult {
+    /// The payout was transitioned to returned
+    Returned,
+    /// The return was already applied to the payout
+    AlreadyReturned,
+    /// The payout did not succeed, and hence cannot be returned
+    NotReturnable,
+    /// No payout was found for the bank reference
+    NotFound,
+    /// The return could not be applied, the return file can be submitted again
+    Failed,
+}
+
 #[derive(Clone, Debug, serde::Serialize, ToSchema)]
 pub struct PayoutLinkResponse {
     pub payout_link_id: String,
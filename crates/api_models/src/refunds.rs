@@ -139,6 +139,12 @@ pub struct RefundResponse {
     pub currency: String,
     /// The status for refund
     pub status: RefundStatus,
+    /// Whether the refund was accepted by the connector, which processes it asynchronously. The
+    /// status of such a refund remains `pending` until the connector notifies the outcome of the
+    /// refund through a webhook, or the outcome is fetched by syncing the refund with the
+    /// connector.
+    #[serde(default)]
+    pub awaiting_connector_confirmation: bool,
     /// An arbitrary string attached to the object. Often useful for displaying to users and your customer support executive
     pub reason: Option<String>,
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object
@@ -168,6 +174,55 @@ pub struct RefundResponse {
     pub charges: Option<ChargeRefunds>,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RefundEligibilityRequest {
+    /// The payment id against which the refund would be initiated
+    #[schema(value_type = String, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: common_utils::id_type::PaymentId,
+    /// The amount which would be refunded. If not provided, the eligibility of refunding the remaining refundable amount is checked
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub amount: Option<MinorUnit>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, ToSchema)]
+pub struct RefundEligibilityResponse {
+    /// The payment id against which the refund would be initiated
+    #[schema(value_type = String)]
+    pub payment_id: common_utils::id_type::PaymentId,
+    /// Whether the refund can be initiated
+    pub eligible: bool,
+    /// The reason for which the refund cannot be initiated, if it is not eligible
+    pub reason: Option<RefundIneligibilityReason>,
+    /// The amount which can still be refunded, which is the captured amount less the amount already refunded
+    #[schema(value_type = i64, example = 6540)]
+    pub refundable_amount: MinorUnit,
+    /// The amount which has already been refunded, or is being refunded
+    #[schema(value_type = i64, example = 0)]
+    pub refunded_amount: MinorUnit,
+}
+
+/// The reason for which a refund cannot be initiated
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize, ToSchema, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RefundIneligibilityReason {
+    /// The payment has not succeeded or been captured
+    PaymentNotCaptured,
+    /// The connector of the payment does not support refunds for the payment method
+    ConnectorNotSupported,
+    /// The payment is older than the refund window
+    RefundWindowExpired,
+    /// The captured amount has already been refunded
+    FullyRefunded,
+    /// The amount exceeds the amount which can still be refunded
+    AmountExceedsRefundableAmount,
+    /// The maximum number of refunds for the payment has been reached
+    MaximumRefundCountReached,
+    /// The amount is not a positive integer
+    InvalidAmount,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
 pub struct RefundListRequest {
     /// The identifier for the payment
@@ -167,6 +167,91 @@ pub struct ConnectorVolumeSplit {
     pub split: u8,
 }
 
+impl ConnectorVolumeSplit {
+    /// Check that at least one connector of the volume split receives traffic, the splits being
+    /// unsigned and hence never negative
+    fn validate_splits(splits: &[Self]) -> Result<(), error_stack::Report<ParsingError>> {
+        if splits.iter().all(|split| split.split == 0) {
+            Err(ParsingError::StructParseFailure(
+                "At least one connector must have a non zero split for Volume split Algorithm",
+            ))?
+        }
+        Ok(())
+    }
+
+    /// Compute the share of the traffic which each connector of the volume split receives, when
+    /// the weight of the unavailable connectors is redistributed to the available connectors in
+    /// proportion to their weights. If none of the connectors is available, the traffic is split
+    /// across all the connectors as configured.
+    pub fn get_effective_weights(
+        splits: &[Self],
+        is_available: impl Fn(&RoutableConnectorChoice) -> bool,
+    ) -> Vec<ConnectorEffectiveWeight> {
+        let availability = splits
+            .iter()
+            .map(|split| is_available(&split.connector))
+            .collect::<Vec<_>>();
+        let total_available_weight: u32 = splits
+            .iter()
+            .zip(availability.iter())
+            .filter(|(_, available)| **available)
+            .map(|(split, _)| u32::from(split.split))
+            .sum();
+        let (total_weight, redistribute) = if total_available_weight > 0 {
+            (total_available_weight, true)
+        } else {
+            (
+                splits.iter().map(|split| u32::from(split.split)).sum(),
+                false,
+            )
+        };
+
+        splits
+            .iter()
+            .zip(availability)
+            .map(|(split, available)| {
+                let weight = if available || !redistribute {
+                    u32::from(split.split)
+                } else {
+                    0
+                };
+                ConnectorEffectiveWeight {
+                    connector: split.connector.clone(),
+                    configured_split: split.split,
+                    effective_split: if total_weight > 0 {
+                        f64::from(weight) * 100.0 / f64::from(total_weight)
+                    } else {
+                        0.0
+                    },
+                    available,
+                }
+            })
+            .collect()
+    }
+}
+
+/// The share of the traffic which a connector of a volume split receives, after redistributing
+/// the weight of the unavailable connectors
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ConnectorEffectiveWeight {
+    pub connector: RoutableConnectorChoice,
+    /// The split configured for the connector
+    pub configured_split: u8,
+    /// The percentage of the traffic which the connector receives
+    #[schema(example = 70.0)]
+    pub effective_split: f64,
+    /// Whether the connector is available, that is enabled and not in maintenance
+    pub available: bool,
+}
+
+/// The effective weights of the connectors of a volume split routing algorithm
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RoutingEffectiveWeightsResponse {
+    #[schema(value_type = String)]
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    pub weights: Vec<ConnectorEffectiveWeight>,
+}
+
 /// Routable Connector chosen for a payment
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
 #[serde(from = "RoutableChoiceSerde", into = "RoutableChoiceSerde")]
@@ -326,6 +411,7 @@ impl TryFrom<RoutingAlgorithmSerde> for RoutingAlgorithm {
                     "Connectors list can't be empty for Volume split Algorithm",
                 ))?
             }
+            RoutingAlgorithmSerde::VolumeSplit(i) => ConnectorVolumeSplit::validate_splits(i)?,
             _ => {}
         };
         Ok(match value {
@@ -391,6 +477,9 @@ impl TryFrom<StraightThroughAlgorithmSerde> for StraightThroughAlgorithm {
                     "Connectors list can't be empty for Volume split Algorithm",
                 ))?
             }
+            StraightThroughAlgorithmInner::VolumeSplit(i) => {
+                ConnectorVolumeSplit::validate_splits(i)?
+            }
             _ => {}
         };
 
@@ -648,3 +737,95 @@ impl CurrentBlockThreshold {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_volume_split(connector: RoutableConnectors, split: u8) -> ConnectorVolumeSplit {
+        ConnectorVolumeSplit {
+            connector: RoutableConnectorChoice {
+                choice_kind: RoutableChoiceKind::OnlyConnector,
+                connector,
+                merchant_connector_id: None,
+            },
+            split,
+        }
+    }
+
+    fn get_effective_splits(weights: &[ConnectorEffectiveWeight]) -> Vec<f64> {
+        weights
+            .iter()
+            .map(|weight| (weight.effective_split * 100.0).round() / 100.0)
+            .collect()
+    }
+
+    #[test]
+    fn test_effective_weights_of_available_connectors_are_the_configured_splits() {
+        let splits = vec![
+            get_volume_split(RoutableConnectors::Stripe, 70),
+            get_volume_split(RoutableConnectors::Adyen, 30),
+        ];
+
+        let weights = ConnectorVolumeSplit::get_effective_weights(&splits, |_| true);
+
+        assert_eq!(get_effective_splits(&weights), vec![70.0, 30.0]);
+        assert!(weights.iter().all(|weight| weight.available));
+    }
+
+    #[test]
+    fn test_weight_of_unavailable_connector_is_redistributed_in_proportion() {
+        let splits = vec![
+            get_volume_split(RoutableConnectors::Stripe, 50),
+            get_volume_split(RoutableConnectors::Adyen, 30),
+            get_volume_split(RoutableConnectors::Checkout, 20),
+        ];
+
+        let weights = ConnectorVolumeSplit::get_effective_weights(&splits, |connector| {
+            connector.connector != RoutableConnectors::Adyen
+        });
+
+        assert_eq!(get_effective_splits(&weights), vec![71.43, 0.0, 28.57]);
+        assert_eq!(
+            weights
+                .iter()
+                .map(|weight| weight.configured_split)
+                .collect::<Vec<_>>(),
+            vec![50, 30, 20]
+        );
+        assert_eq!(
+            weights
+                .iter()
+                .map(|weight| weight.available)
+                .collect::<Vec<_>>(),
+            vec![true, false, true]
+        );
+    }
+
+    #[test]
+    fn test_configured_splits_are_kept_when_no_connector_is_available() {
+        let splits = vec![
+            get_volume_split(RoutableConnectors::Stripe, 60),
+            get_volume_split(RoutableConnectors::Adyen, 40),
+        ];
+
+        let weights = ConnectorVolumeSplit::get_effective_weights(&splits, |_| false);
+
+        assert_eq!(get_effective_splits(&weights), vec![60.0, 40.0]);
+        assert!(weights.iter().all(|weight| !weight.available));
+    }
+
+    #[test]
+    fn test_configured_splits_are_kept_when_only_zero_split_connectors_are_available() {
+        let splits = vec![
+            get_volume_split(RoutableConnectors::Stripe, 100),
+            get_volume_split(RoutableConnectors::Adyen, 0),
+        ];
+
+        let weights = ConnectorVolumeSplit::get_effective_weights(&splits, |connector| {
+            connector.connector == RoutableConnectors::Adyen
+        });
+
+        assert_eq!(get_effective_splits(&weights), vec![100.0, 0.0]);
+    }
+}
@@ -0,0 +1,90 @@
+use common_utils::types::MinorUnit;
+
+use crate::enums::{
+    Connector, Currency, SettlementReconciliationStatus, SettlementTransactionType,
+};
+
+/// A settlement file received from a connector, to be reconciled against the payments and refunds
+/// processed through the connector
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct SettlementFileIngestRequest {
+    /// The connector which sent the settlement file
+    pub connector: Connector,
+    /// The identifier of the settlement file or batch at the connector
+    pub settlement_id: String,
+    /// The line items of the settlement file
+    pub line_items: Vec<SettlementLineItem>,
+}
+
+/// A line item of a settlement file received from a connector
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct SettlementLineItem {
+    /// The identifier of the line item within the settlement
+    pub line_item_id: String,
+    /// Whether the line item settles a payment or a refund
+    pub transaction_type: SettlementTransactionType,
+    /// The connector's identifier of the settled payment or refund
+    pub connector_transaction_id: String,
+    /// The settled amount, in the lowest denomination of the currency
+    pub amount: MinorUnit,
+    /// The currency of the settled amount
+    pub currency: Currency,
+    /// The time at which the connector settled the transaction
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub settled_at: Option<time::PrimitiveDateTime>,
+}
+
+/// The outcome of reconciling a settlement line item against our records
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct SettlementLineItemResponse {
+    /// The connector which sent the settlement file
+    pub connector_name: String,
+    /// The identifier of the settlement file or batch at the connector
+    pub settlement_id: String,
+    /// The identifier of the line item within the settlement
+    pub line_item_id: String,
+    /// Whether the line item settles a payment or a refund
+    pub transaction_type: SettlementTransactionType,
+    /// The connector's identifier of the settled payment or refund
+    pub connector_transaction_id: String,
+    /// The settled amount, in the lowest denomination of the currency
+    pub amount: MinorUnit,
+    /// The currency of the settled amount
+    pub currency: Currency,
+    /// The time at which the connector settled the transaction
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub settled_at: Option<time::PrimitiveDateTime>,
+    /// The payment the line item was matched to
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    /// The payment attempt the line item was matched to
+    pub attempt_id: Option<String>,
+    /// The refund the line item was matched to
+    pub refund_id: Option<String>,
+    /// The outcome of reconciling the line item
+    pub reconciliation_status: SettlementReconciliationStatus,
+    /// The time at which the line item was first ingested
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct SettlementFileIngestResponse {
+    /// The reconciled line items of the settlement file
+    pub line_items: Vec<SettlementLineItemResponse>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SettlementLineItemsUnmatchedListRequest {
+    /// The maximum number of line items to return
+    pub limit: Option<i64>,
+    /// The number of line items to skip
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct SettlementLineItemsUnmatchedListResponse {
+    /// The line items which need manual review, either because they do not match our record of
+    /// the transaction or because we have no record of the transaction
+    pub line_items: Vec<SettlementLineItemResponse>,
+}
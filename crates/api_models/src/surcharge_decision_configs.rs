@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use common_utils::{
     consts::SURCHARGE_PERCENTAGE_PRECISION_LENGTH,
     events,
@@ -76,6 +78,22 @@ impl events::ApiEventMetric for SurchargeDecisionConfigReq {
 #[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
 pub struct MerchantSurchargeConfigs {
     pub show_surcharge_breakup_screen: Option<bool>,
+    /// Surcharge applied to the payment method types for which the surcharge rules do not yield a
+    /// surcharge
+    pub payment_method_type_surcharges:
+        Option<HashMap<common_enums::PaymentMethodType, SurchargeDetailsOutput>>,
+}
+
+impl MerchantSurchargeConfigs {
+    pub fn get_payment_method_type_surcharge(
+        &self,
+        payment_method_type: common_enums::PaymentMethodType,
+    ) -> Option<SurchargeDetailsOutput> {
+        self.payment_method_type_surcharges
+            .as_ref()
+            .and_then(|surcharges| surcharges.get(&payment_method_type))
+            .cloned()
+    }
 }
 
 pub type SurchargeDecisionManagerResponse = SurchargeDecisionManagerRecord;
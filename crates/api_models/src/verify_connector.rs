@@ -1,4 +1,5 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
+use masking::Secret;
 
 use crate::{admin, enums};
 
@@ -8,4 +9,78 @@ pub struct VerifyConnectorRequest {
     pub connector_account_details: admin::ConnectorAuthType,
 }
 
-common_utils::impl_api_event_type!(Miscellaneous, (VerifyConnectorRequest));
+/// A tokenization of a test card against a connector, without charging the card, to verify the
+/// tokenization setup of the merchant before going live
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct TokenizationTestRequest {
+    /// The connector account whose tokenization setup is tested, which must be in test mode
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    /// The test card to tokenize, the test card of the connector is used if this is not set. Only
+    /// the well known test card numbers are accepted
+    pub card: Option<TokenizationTestCard>,
+    /// Whether a network token is also provisioned for the card
+    #[serde(default)]
+    pub network_tokenization: bool,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct TokenizationTestCard {
+    pub card_number: cards::CardNumber,
+    pub card_exp_month: Secret<String>,
+    pub card_exp_year: Secret<String>,
+    pub card_cvc: Secret<String>,
+}
+
+/// A step of the tokenization test
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenizationTestStep {
+    /// Storing the card in the vault
+    Vault,
+    /// Provisioning the token of the card at the connector
+    ConnectorProvisioning,
+    /// Provisioning the network token of the card
+    NetworkToken,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenizationTestStepStatus {
+    Succeeded,
+    Failed,
+    /// The step is not run, since it is not requested or a previous step failed
+    Skipped,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct TokenizationTestStepResult {
+    pub step: TokenizationTestStep,
+    pub status: TokenizationTestStepStatus,
+    /// The error code of the failure, as returned by the connector for the connector provisioning
+    pub error_code: Option<String>,
+    /// The error message of the failure, as returned by the connector for the connector
+    /// provisioning
+    pub error_message: Option<String>,
+    /// The http status code of the response of the connector for the connector provisioning
+    pub connector_http_status_code: Option<u16>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct TokenizationTestResponse {
+    pub connector_name: enums::Connector,
+    /// Whether all the steps which are run succeeded
+    pub succeeded: bool,
+    /// The step which failed, if any
+    pub failed_step: Option<TokenizationTestStep>,
+    /// The results of the steps, in the order in which they are run
+    pub steps: Vec<TokenizationTestStepResult>,
+}
+
+common_utils::impl_api_event_type!(
+    Miscellaneous,
+    (
+        VerifyConnectorRequest,
+        TokenizationTestRequest,
+        TokenizationTestResponse
+    )
+);
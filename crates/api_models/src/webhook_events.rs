@@ -187,3 +187,51 @@ impl common_utils::events::ApiEventMetric for WebhookDeliveryRetryRequestInterna
         })
     }
 }
+
+/// The state of the delivery of the webhooks of a merchant, across all the instances of the
+/// application.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookDeliveryQueueResponse {
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, value_type = String, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The number of webhooks being delivered to the merchant.
+    #[schema(example = 10)]
+    pub in_flight_deliveries: u32,
+
+    /// The number of webhooks waiting for a delivery slot of the merchant.
+    #[schema(example = 25)]
+    pub queued_deliveries: u32,
+
+    /// The number of consecutive failed deliveries to the merchant.
+    #[schema(example = 0)]
+    pub consecutive_failures: u32,
+
+    /// The time until which the deliveries to the merchant are backed off, after consecutive
+    /// failed deliveries. The deliveries are deferred to their automatic retries meanwhile.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub backed_off_until: Option<PrimitiveDateTime>,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookDeliveryQueueResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookDeliveryQueueRequestInternal {
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookDeliveryQueueRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id: self.merchant_id.clone(),
+        })
+    }
+}
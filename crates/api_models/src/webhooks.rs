@@ -57,6 +57,8 @@ pub enum IncomingWebhookEvent {
     PayoutExpired,
     #[cfg(feature = "payouts")]
     PayoutReversed,
+    #[cfg(feature = "payouts")]
+    PayoutReturned,
 }
 
 pub enum WebhookFlow {
@@ -157,7 +159,8 @@ impl From<IncomingWebhookEvent> for WebhookFlow {
             | IncomingWebhookEvent::PayoutCancelled
             | IncomingWebhookEvent::PayoutCreated
             | IncomingWebhookEvent::PayoutExpired
-            | IncomingWebhookEvent::PayoutReversed => Self::Payout,
+            | IncomingWebhookEvent::PayoutReversed
+            | IncomingWebhookEvent::PayoutReturned => Self::Payout,
         }
     }
 }
@@ -68,6 +68,12 @@ pub enum ApiClientError {
     #[error("connection closed before a message could complete")]
     ConnectionClosedIncompleteMessage,
 
+    #[error("Failed to connect to the server: {0}")]
+    ConnectionFailed(String),
+
+    #[error("Connection was reset while sending the request")]
+    ConnectionReset,
+
     #[error("Server responded with Internal Server Error")]
     InternalServerErrorReceived,
     #[error("Server responded with Bad Gateway")]
@@ -86,6 +92,21 @@ impl ApiClientError {
     pub fn is_connection_closed_before_message_could_complete(&self) -> bool {
         self == &Self::ConnectionClosedIncompleteMessage
     }
+    /// Whether the request certainly did not reach the server, since the connection to the
+    /// server could not be established
+    pub fn is_connection_failure(&self) -> bool {
+        matches!(self, Self::ConnectionFailed(_))
+    }
+    /// Whether the error is a network error, such as a failure to connect or the connection
+    /// being closed or reset, as opposed to an error response from the server
+    pub fn is_network_error(&self) -> bool {
+        matches!(
+            self,
+            Self::ConnectionFailed(_)
+                | Self::ConnectionReset
+                | Self::ConnectionClosedIncompleteMessage
+        )
+    }
 }
 
 impl From<std::io::Error> for ApplicationError {
@@ -119,6 +140,8 @@ pub enum AttemptStatus {
     AuthenticationPending,
     AuthenticationSuccessful,
     Authorized,
+    /// The connector authorized less than the requested amount
+    PartiallyAuthorized,
     AuthorizationFailed,
     Charged,
     Authorizing,
@@ -282,6 +305,7 @@ impl AttemptStatus {
             | Self::AuthenticationPending
             | Self::AuthenticationSuccessful
             | Self::Authorized
+            | Self::PartiallyAuthorized
             | Self::AuthorizationFailed
             | Self::Authorizing
             | Self::CodInitiated
@@ -349,6 +373,32 @@ pub enum FraudCheckStatus {
     TransactionFailure,
 }
 
+/// The decision taken on a payment based on the fraud check performed before calling the connector
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FraudDecision {
+    /// The payment can be processed
+    Approve,
+    /// The payment can be processed, but has to be reviewed by the merchant
+    Review,
+    /// The payment must not be processed
+    Decline,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -376,6 +426,10 @@ pub enum CaptureStatus {
     Pending,
     // Capture request failed
     Failed,
+    // Capture was voided before it was charged
+    Voided,
+    // Capture was charged and reversed through a refund before it was settled
+    Reversed,
 }
 
 #[derive(
@@ -1277,6 +1331,7 @@ pub enum EventType {
     PayoutCancelled,
     PayoutExpired,
     PayoutReversed,
+    PayoutReturned,
 }
 
 #[derive(
@@ -1468,7 +1523,9 @@ impl From<AttemptStatus> for PaymentMethodStatus {
             | AttemptStatus::PartialChargedAndChargeable
             | AttemptStatus::ConfirmationAwaited
             | AttemptStatus::DeviceDataCollectionPending => Self::Inactive,
-            AttemptStatus::Charged | AttemptStatus::Authorized => Self::Active,
+            AttemptStatus::Charged
+            | AttemptStatus::Authorized
+            | AttemptStatus::PartiallyAuthorized => Self::Active,
         }
     }
 }
@@ -1507,6 +1564,8 @@ pub enum PaymentExperience {
     InvokePaymentApp,
     /// Contains the data for displaying wait screen
     DisplayWaitScreen,
+    /// The voucher details to be displayed to the customer.
+    DisplayVoucher,
 }
 
 /// Indicates the sub type of payment method. Eg: 'google_pay' & 'apple_pay' for wallets.
@@ -2347,6 +2406,7 @@ pub enum PayoutStatus {
     Initiated,
     Expired,
     Reversed,
+    Returned,
     Pending,
     Ineligible,
     #[default]
@@ -2564,6 +2624,226 @@ pub enum ReconStatus {
     Disabled,
 }
 
+/// The onboarding status of a merchant account. Payments can be created only for merchant accounts
+/// which are `live`.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum OnboardingStatus {
+    /// The merchant is yet to submit the KYC documents
+    #[default]
+    KycPending,
+    /// The KYC documents have been submitted and are pending review
+    DocsSubmitted,
+    /// The merchant account has been approved, but is not yet live
+    Approved,
+    /// The merchant account is live and can accept payments
+    Live,
+}
+
+/// How the statement descriptors of a payment which exceed the maximum length supported by the
+/// connector are handled
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum StatementDescriptorLengthPolicy {
+    /// The descriptors are truncated to the maximum length
+    #[default]
+    Truncate,
+    /// The payment is rejected
+    Reject,
+    /// The descriptors are truncated to the maximum length, and the shorter descriptors are padded
+    /// with spaces to the maximum length, so that all the descriptors have the same length
+    Pad,
+}
+
+/// The kind of a tax identifier of a customer, which determines the jurisdiction that issues it and
+/// the format of the identifier
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum TaxIdType {
+    /// European Union VAT identification number
+    EuVat,
+    /// United Kingdom VAT registration number
+    GbVat,
+    /// Swiss VAT number
+    ChVat,
+    /// Norwegian VAT number
+    NoVat,
+    /// Indian Goods and Services Tax identification number
+    InGst,
+    /// Australian Business Number
+    AuAbn,
+    /// New Zealand GST number
+    NzGst,
+    /// Singaporean GST registration number
+    SgGst,
+    /// Canadian Business Number
+    CaBn,
+    /// Brazilian CNPJ number, of businesses
+    BrCnpj,
+    /// Brazilian CPF number, of individuals
+    BrCpf,
+    /// Mexican RFC number
+    MxRfc,
+    /// United States Employer Identification Number
+    UsEin,
+    /// Any other tax identifier, whose format is not validated
+    Other,
+}
+
+/// The resources whose changes are recorded in the audit log
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditLogResourceType {
+    MerchantAccount,
+    ApiKey,
+    /// The routing algorithms activated for a business profile, identified by the profile id
+    RoutingConfig,
+    /// A routing algorithm of a business profile, identified by the algorithm id
+    RoutingAlgorithm,
+    /// The default fallback routing of a merchant or a business profile, identified by the id of
+    /// the merchant or the profile
+    DefaultRoutingConfig,
+}
+
+/// The change made to a resource recorded in the audit log
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditLogAction {
+    Create,
+    Update,
+    Revoke,
+}
+
+impl OnboardingStatus {
+    /// The statuses to which a merchant account in this status can be moved
+    pub fn get_allowed_transitions(self) -> Vec<Self> {
+        match self {
+            Self::KycPending => vec![Self::DocsSubmitted],
+            // The submitted documents may be rejected, in which case they have to be submitted again
+            Self::DocsSubmitted => vec![Self::KycPending, Self::Approved],
+            Self::Approved => vec![Self::Live],
+            Self::Live => vec![],
+        }
+    }
+
+    pub fn can_transition_to(self, status: Self) -> bool {
+        self.get_allowed_transitions().contains(&status)
+    }
+
+    /// The requirements which are yet to be fulfilled for the merchant account to go live
+    pub fn get_outstanding_requirements(self) -> Vec<OnboardingRequirement> {
+        match self {
+            Self::KycPending => vec![
+                OnboardingRequirement::KycDocuments,
+                OnboardingRequirement::ComplianceApproval,
+                OnboardingRequirement::GoLive,
+            ],
+            Self::DocsSubmitted => vec![
+                OnboardingRequirement::ComplianceApproval,
+                OnboardingRequirement::GoLive,
+            ],
+            Self::Approved => vec![OnboardingRequirement::GoLive],
+            Self::Live => vec![],
+        }
+    }
+}
+
+/// A requirement to be fulfilled by a merchant account during onboarding
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum OnboardingRequirement {
+    /// The KYC documents of the merchant have to be submitted
+    KycDocuments,
+    /// The submitted documents have to be reviewed and approved
+    ComplianceApproval,
+    /// The approved merchant account has to be made live
+    GoLive,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -3297,3 +3577,395 @@ pub enum SurchargeCalculationOverride {
     /// Calculate surcharge
     Calculate,
 }
+
+/// The status of the propagation of the customer details to a connector at which the customer exists
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CustomerConnectorSyncStatus {
+    /// The customer details are yet to be updated at the connector
+    #[default]
+    Pending,
+    /// The connector has the latest customer details
+    Synced,
+    /// The connector could not be updated, the sync has to be retried
+    Failed,
+}
+
+/// The channel through which the merchant communicated with a customer
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CustomerCommunicationChannel {
+    Email,
+    Phone,
+    Chat,
+    Sms,
+    Letter,
+    Other,
+}
+
+/// The calendar period in which a mandate may be charged at most once
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum MandateFrequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// The kind of transaction a settlement line item settles
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SettlementTransactionType {
+    Payment,
+    Refund,
+}
+
+/// The outcome of reconciling a settlement line item against our records
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SettlementReconciliationStatus {
+    /// The line item settles a transaction we have a record of, for the same amount and currency
+    Matched,
+    /// The line item settles a transaction we have a record of, but the amount or currency differs
+    Unmatched,
+    /// The line item references a transaction we have no record of
+    Orphan,
+}
+
+/// The status of the revocation of a payment method token provisioned at a connector
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ConnectorTokenRevocationStatus {
+    /// The token has been revoked at the connector
+    Revoked,
+    /// The token could not be revoked at the connector and is to be revoked by the cleanup job
+    PendingCleanup,
+}
+
+/// The step of a 3DS challenge flow which a 3DS session is in
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ThreeDsSessionStatus {
+    /// The issuer requested a challenge and the completion of the challenge is awaited
+    ChallengeRequired,
+    /// The completion of the challenge has been received
+    Completed,
+    /// The challenge was not completed before the session expired
+    Expired,
+}
+
+/// The environment of the connector account, live transactions are processed only in production
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ConnectorEnvironment {
+    /// The connector account processes test transactions
+    Sandbox,
+    /// The connector account processes live transactions
+    Production,
+}
+
+/// The state of the rotation of the credentials of a connector account
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ConnectorCredentialRotationStatus {
+    /// The pending credentials are yet to be validated, the primary credentials are in use
+    Pending,
+    /// The test call with the pending credentials failed, the primary credentials are in use
+    Failed,
+    /// The pending credentials were validated and promoted to be the primary credentials
+    Completed,
+    /// The primary credentials failed to authenticate with the connector, and the pending
+    /// credentials were verified and promoted in their place. The primary credentials are kept as
+    /// the pending credentials, so that they can be promoted back.
+    FailedOver,
+}
+
+/// How often a maintenance window of a connector account recurs
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum MaintenanceWindowRecurrence {
+    /// The maintenance window recurs every day
+    Daily,
+    /// The maintenance window recurs every week
+    Weekly,
+}
+
+/// The verification status of a tokenized bank account, only verified bank accounts can be charged
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BankAccountVerificationStatus {
+    /// The ownership of the bank account is yet to be verified
+    #[default]
+    Pending,
+    /// The ownership of the bank account has been verified
+    Verified,
+    /// The verification of the bank account failed
+    Failed,
+}
+
+/// The fulfillment status of the order of a payment, as recorded by the merchant
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum FulfillmentStatus {
+    /// The order has been shipped to the customer
+    Shipped,
+    /// The order has been delivered to the customer
+    Delivered,
+}
+
+impl FulfillmentStatus {
+    /// Whether the fulfillment status of an order can move from the current status to this
+    /// status. An order is shipped before it is delivered, and its status never moves backwards.
+    pub fn is_valid_transition_from(self, current_status: Option<Self>) -> bool {
+        matches!(
+            (current_status, self),
+            (None, Self::Shipped) | (Some(Self::Shipped), Self::Delivered)
+        )
+    }
+}
+
+/// The entities whose records are retained for a configurable period, after which the records are
+/// purged
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RetentionEntity {
+    /// The personal data of the payments is anonymized, while the payments themselves are kept
+    Payments,
+    Refunds,
+    Disputes,
+    /// The personal data of the customers is anonymized, while the customers themselves are kept
+    Customers,
+    Files,
+}
+
+/// The risk tier of a customer, derived from the history of the disputes and the declines of the
+/// payments of the customer
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Ord,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CustomerRiskTier {
+    Low,
+    Medium,
+    High,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fulfillment_status_transitions() {
+        assert!(FulfillmentStatus::Shipped.is_valid_transition_from(None));
+        assert!(
+            FulfillmentStatus::Delivered.is_valid_transition_from(Some(FulfillmentStatus::Shipped))
+        );
+
+        // An order is shipped before it is delivered
+        assert!(!FulfillmentStatus::Delivered.is_valid_transition_from(None));
+        // The status never moves backwards, nor is set again
+        assert!(!FulfillmentStatus::Shipped
+            .is_valid_transition_from(Some(FulfillmentStatus::Delivered)));
+        assert!(
+            !FulfillmentStatus::Shipped.is_valid_transition_from(Some(FulfillmentStatus::Shipped))
+        );
+        assert!(!FulfillmentStatus::Delivered
+            .is_valid_transition_from(Some(FulfillmentStatus::Delivered)));
+    }
+}
@@ -11,6 +11,40 @@ impl Display for NumericCountryCodeParseError {
 }
 
 impl CountryAlpha2 {
+    /// Whether the country is a member state of the European Union
+    pub const fn is_eu_member(self) -> bool {
+        matches!(
+            self,
+            Self::AT
+                | Self::BE
+                | Self::BG
+                | Self::HR
+                | Self::CY
+                | Self::CZ
+                | Self::DK
+                | Self::EE
+                | Self::FI
+                | Self::FR
+                | Self::DE
+                | Self::GR
+                | Self::HU
+                | Self::IE
+                | Self::IT
+                | Self::LV
+                | Self::LT
+                | Self::LU
+                | Self::MT
+                | Self::NL
+                | Self::PL
+                | Self::PT
+                | Self::RO
+                | Self::SK
+                | Self::SI
+                | Self::ES
+                | Self::SE
+        )
+    }
+
     pub const fn from_alpha2_to_alpha3(code: Self) -> CountryAlpha3 {
         match code {
             Self::AF => CountryAlpha3::AFG,
@@ -48,6 +48,12 @@ pub fn default_payouts_list_limit() -> u32 {
 /// surcharge percentage maximum precision length
 pub const SURCHARGE_PERCENTAGE_PRECISION_LENGTH: u8 = 2;
 
+/// amount breakdown tax rate maximum precision length
+pub const AMOUNT_BREAKDOWN_TAX_RATE_PRECISION_LENGTH: u8 = 2;
+
+/// connector fee percentage maximum precision length
+pub const CONNECTOR_FEE_PERCENTAGE_PRECISION_LENGTH: u8 = 2;
+
 /// Header Key for application overhead of a request
 pub const X_HS_LATENCY: &str = "x-hs-latency";
 
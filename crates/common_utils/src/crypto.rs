@@ -238,6 +238,34 @@ impl VerifySignature for HmacSha512 {
     }
 }
 
+/// Represents the RSASSA-PKCS1-v1_5 signature algorithm with SHA-256, the secret being the PKCS#8
+/// DER encoded private key
+#[derive(Debug)]
+pub struct RsaSha256;
+
+impl SignMessage for RsaSha256 {
+    fn sign_message(
+        &self,
+        secret: &[u8],
+        msg: &[u8],
+    ) -> CustomResult<Vec<u8>, errors::CryptoError> {
+        let key_pair = ring::signature::RsaKeyPair::from_pkcs8(secret)
+            .change_context(errors::CryptoError::MessageSigningFailed)
+            .attach_printable("Failed to parse the RSA private key")?;
+        let mut signature = vec![0; key_pair.public().modulus_len()];
+        key_pair
+            .sign(
+                &ring::signature::RSA_PKCS1_SHA256,
+                &ring::rand::SystemRandom::new(),
+                msg,
+                &mut signature,
+            )
+            .change_context(errors::CryptoError::MessageSigningFailed)?;
+
+        Ok(signature)
+    }
+}
+
 ///
 /// Blake3
 #[derive(Debug)]
@@ -515,6 +543,32 @@ where
     }
 }
 
+/// The encoding of a signature, such as the signature of a request made to a connector
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SignatureEncoding {
+    /// Lowercase hex
+    Hex,
+    /// Standard base64 with padding
+    Base64,
+    /// URL safe base64 without padding
+    Base64UrlSafe,
+}
+
+impl SignatureEncoding {
+    /// Encode the signature
+    pub fn encode(self, signature: &[u8]) -> String {
+        use base64::Engine;
+
+        match self {
+            Self::Hex => hex::encode(signature),
+            Self::Base64 => crate::consts::BASE64_ENGINE.encode(signature),
+            Self::Base64UrlSafe => {
+                base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(signature)
+            }
+        }
+    }
+}
+
 /// Type alias for `Option<Encryptable<Secret<String>>>`
 pub type OptionalEncryptableSecretString = Option<Encryptable<Secret<String>>>;
 /// Type alias for `Option<Encryptable<Secret<String>>>` used for `name` field
@@ -535,8 +589,10 @@ pub type EncryptableEmail = Encryptable<Secret<String, pii::EmailStrategy>>;
 #[cfg(test)]
 mod crypto_tests {
     #![allow(clippy::expect_used)]
+    use base64::Engine;
+
     use super::{DecodeMessage, EncodeMessage, SignMessage, VerifySignature};
-    use crate::crypto::GenerateDigest;
+    use crate::{consts::BASE64_ENGINE, crypto::GenerateDigest};
 
     #[test]
     fn test_hmac_sha256_sign_message() {
@@ -615,6 +671,21 @@ mod crypto_tests {
         assert_eq!(signature, right_signature);
     }
 
+    #[test]
+    fn test_rsa_sha256_sign_message() {
+        let message = r#"{"type":"payment_intent"}"#.as_bytes();
+        let secret = BASE64_ENGINE.decode("MIIEvAIBADANBgkqhkiG9w0BAQEFAASCBKYwggSiAgEAAoIBAQDC5pA9DhSq3s4xqevsYYOhU+RrYXFz2+27nQD818womsWJjMB+a70zPTULt2SuPzINA8CZv43A0hdKRekwzY2xbMeginKxYLy71HaVaWBJU8CpUJsYA6Wq379UAgb7EAAeoL+QpKvLt7W9Q+uzFRE6mY6A7DTyU/ZK7cer+dj+zL8F+ziuOVPTeEDFlE2mDnaVtx/ibTK5CHFt54GDC6xcBuIdafwfL6EDd4XwNWu1xphsDuQXhm5icKcAoEPe0nb3TKXx+LUZm9WBiLCbzSh3MUBnxqp4c506J3y4N14/9osM6lVXIBGnUKfEQBkMnt8r4/6LJdntEugDKMkDv4+1AgMBAAECggEAKubJtUbWMc6doL0Am6XTbb2juCdY6+HrcLLctsthu0ZmWSLMdIeOvCqq9ItH9Dsg71B2IuHReDJGwiilbgKxP+7r+mC/YmAi602XVMg/U6TF8f82RJD49KYP3ZQcqUMaY6OKx3IYBt3HeYPr7B2lDzsh7SsRdI4a1t+EZky3AU75uMp9z1RzTxbNbOh3hzwjb8nxCbQR+sMGy1f4w/oU5GLbB1kaJfx//KpsZ8pAZAzJ5m6rZrxg+k8k4/pPRjE/qakACJUrONSPw5drnqxoui9mxYeETowkrWor7vsK9vVqlr96+2KVlpz14xcjtTj5OoSy7D1ByUjNJLyIfsyc+QKBgQD0aiGBgev4JicasUmO/dvk4bRxQnr7tuPR2ALXLbNRSaUYV3AF7M9rSMDOq7YOG6wGdCz+1/ydhUgtCdLQ6kNe1tm3PsvuOiLi8k4A9dhnBII+JcCgn0AlG7Ry02hTuDqUw21aBDx4MxPgtA1zHCs63P9VkDPPV+npQo+VYB7YHwKBgQDMI5n/ZsCEQH+G57dyiHlc248bcqzDgR0baKDEq0FFV7yfCboxRVEXSt9w63AQywaNy0uFZXJ3snWvQJp/HrareKItUHOoIyiVkbFQCFaCgtR4iNLvBAgBg6fuuOyJF8B6Pc9u53bNAH1vJmW0zcOz3MoWPeopakaojT9+LuBtqwKBgGobBO63G1zAG2vQGVH1TRgBEBe3N7K9BAweTaVilp0sbnqxwtUfqDA4BlPyYXegdb7bgokj9OvhZ6L9x8KgYJz0gtG1dky2elyxukFaeRbH807NjuFsISm67EyJEJp/uRbJ/Ff+zM2VxwRexhP5356PQF3B22whC1ATcEf10Y4bAoGAb87kDt4Bfa4x6vrJIEMdbb2XsSnq9B2oipWQ0IcX9dnrJpRWVZwooQBSR2+8boY0rb72OzboLiUhjf5tYEi7NkmZUgZpgmLXXKxymGoor8zmgKo4Fc94bxPkC71fWGsY8hsenRjLmda13QYD2LaGhOHeEbaRpWNfFfxfFUaph4cCgYAeQyrpCRElPL4G6njf3ylZSrhAnWoGSOVQq2R5iBMFGuUVbfVzB3kN4Xg4nmTDLEfooCCv4bS0Xze6bwqL7yeXU0WkLib9ZJVK0t2QlcmwzsuqtBjnnau4kRjETnv3SBfpcjszU2WwTnN+qORKs808Mk42bQJCIqaHJn9UgeR1Bg==")
+            .expect("private key decoding");
+        let right_signature = hex::decode("22ce203567555e18d807d7cddf5f5b2d876a80839bfa2bc52a2e5f734d4d3ac2ea11649ba14f9146d9b6d9052ebb9c9283a6bc714a5133a6f4a2c455f5442d45339ad1cbcc4e021e78dc4b0720381af689d47bd85d4c3bd3cb4d9c37ca5fdf188455dc44ed3591567b4341822a2796b1404b7f9bac40e17cacd89a70de923bd15d5649320cb08fefc2343baf94cfd0e624092738eb2ac2e32efea6918f38a72ce1ba37057d20f1b2ad8f110eea0d43314cd9a10be772339cfa8eeff92964a7e8a0058ca22d631950729ca55426a7590a657f978ce8275f47f0b99ca7feb0a6882b37ebd349dbc1dcf3711e18303b13d3094a5efb81417331b43b81522d181344")
+            .expect("signature decoding");
+
+        let signature = super::RsaSha256
+            .sign_message(&secret, message)
+            .expect("Signature");
+
+        assert_eq!(signature, right_signature);
+    }
+
     #[test]
     fn test_hmac_sha512_verify_signature() {
         let right_signature = hex::decode("38b0bc1ea66b14793e39cd58e93d37b799a507442d0dd8d37443fa95dec58e57da6db4742636fea31201c48e57a66e73a308a2e5a5c6bb831e4e39fe2227c00f")
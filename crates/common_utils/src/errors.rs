@@ -53,6 +53,24 @@ pub enum ParsingError {
     /// Failed to convert the given integer because of integer overflow error
     #[error("Integer Overflow error")]
     IntegerOverflow,
+    /// The amount format does not have the precision required for the currency
+    #[error(
+        "Amount format with {decimal_places} decimal places cannot represent amounts in {currency}"
+    )]
+    InsufficientAmountPrecision {
+        /// The currency of the amount
+        currency: String,
+        /// The number of decimal places of the amount format
+        decimal_places: u8,
+    },
+    /// The amount has more decimal places than the currency
+    #[error("Amount with {decimal_places} decimal places is not a valid amount in {currency}")]
+    ExcessAmountPrecision {
+        /// The currency of the amount
+        currency: String,
+        /// The number of decimal places of the amount
+        decimal_places: u32,
+    },
 }
 
 /// Validation errors.
@@ -10,8 +10,14 @@ impl<T: Strategy<String>> Serialize for HashedString<T> {
     where
         S: Serializer,
     {
-        let hashed_value = blake3::hash(self.0.peek().as_bytes()).to_hex();
-        hashed_value.serialize(serializer)
+        self.get_hash().serialize(serializer)
+    }
+}
+
+impl<T: Strategy<String>> HashedString<T> {
+    /// Get the hex encoded blake3 hash of the string
+    pub fn get_hash(&self) -> String {
+        blake3::hash(self.0.peek().as_bytes()).to_hex().to_string()
     }
 }
 
@@ -123,6 +123,29 @@ impl MerchantId {
         format!("guard_blocklist_for_{}", self.get_string_repr())
     }
 
+    /// get_velocity_limits_key
+    pub fn get_velocity_limits_key(&self) -> String {
+        format!("velocity_limits_{}", self.get_string_repr())
+    }
+
+    /// get_duplicate_payment_config_key
+    pub fn get_duplicate_payment_config_key(&self) -> String {
+        format!("duplicate_payment_config_{}", self.get_string_repr())
+    }
+
+    /// get_cvv_recollection_policy_key
+    pub fn get_cvv_recollection_policy_key(&self) -> String {
+        format!("cvv_recollection_policy_{}", self.get_string_repr())
+    }
+
+    /// get_payment_method_country_availability_key
+    pub fn get_payment_method_country_availability_key(&self) -> String {
+        format!(
+            "payment_method_country_availability_{}",
+            self.get_string_repr()
+        )
+    }
+
     /// get_merchant_fingerprint_secret_key
     pub fn get_merchant_fingerprint_secret_key(&self) -> String {
         format!("fingerprint_secret_{}", self.get_string_repr())
@@ -29,6 +29,7 @@ pub mod signals;
 pub mod transformers;
 pub mod types;
 pub mod validation;
+pub mod versioned;
 
 /// Used for hashing
 pub mod hashing;
@@ -104,6 +104,11 @@ impl From<Secret<String>> for MaskedRoutingNumber {
         Self::from(secret.expose())
     }
 }
+impl ExposeInterface<Secret<String>> for MaskedRoutingNumber {
+    fn expose(self) -> Secret<String> {
+        self.0
+    }
+}
 
 /// Masked bank account
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -119,6 +124,11 @@ impl From<Secret<String>> for MaskedBankAccount {
         Self::from(secret.expose())
     }
 }
+impl ExposeInterface<Secret<String>> for MaskedBankAccount {
+    fn expose(self) -> Secret<String> {
+        self.0
+    }
+}
 
 /// Masked IBAN
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
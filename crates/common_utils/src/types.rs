@@ -6,6 +6,7 @@ pub mod authentication;
 
 use std::{
     borrow::Cow,
+    collections::HashMap,
     fmt::Display,
     ops::{Add, Sub},
     primitive::i64,
@@ -98,6 +99,31 @@ impl<const PRECISION: u8> Percentage<PRECISION> {
         }
     }
 
+    /// apply the percentage to amount and floor the result
+    #[allow(clippy::as_conversions)]
+    pub fn apply_and_floor_result(
+        &self,
+        amount: MinorUnit,
+    ) -> CustomResult<MinorUnit, PercentageError> {
+        let max_amount = i64::MAX / 10000;
+        let amount = amount.0;
+        if amount > max_amount {
+            // value gets rounded off after i64::MAX/10000
+            Err(report!(PercentageError::UnableToApplyPercentage {
+                percentage: self.percentage,
+                amount: MinorUnit::new(amount),
+            }))
+            .attach_printable(format!(
+                "Cannot calculate percentage for amount greater than {}",
+                max_amount
+            ))
+        } else {
+            let percentage_f64 = f64::from(self.percentage);
+            let result = (amount as f64 * (percentage_f64 / 100.0)).floor() as i64;
+            Ok(MinorUnit::new(result))
+        }
+    }
+
     fn is_valid_string_value(value: &str) -> CustomResult<bool, PercentageError> {
         let float_value = Self::is_valid_float_string(value)?;
         Ok(Self::is_valid_range(float_value) && Self::is_valid_precision_length(value))
@@ -351,6 +377,149 @@ impl AmountConvertor for MinorUnitForConnector {
     }
 }
 
+/// The format in which a connector expects amounts to be sent
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AmountSerializationStrategy {
+    /// Integer in the minor unit of the currency, e.g. `1050` for 10.50 USD
+    MinorUnit,
+    /// String in the minor unit of the currency, e.g. `"1050"` for 10.50 USD
+    StringMinorUnit,
+    /// Float in the major unit of the currency, e.g. `10.5` for 10.50 USD
+    FloatMajorUnit,
+    /// String in the major unit of the currency, with the number of decimal places of the
+    /// currency, e.g. `"10.50"` for 10.50 USD
+    StringMajorUnit,
+    /// String in the major unit of the currency with a fixed number of decimal places, for
+    /// connectors which expect the same number of decimal places for all currencies, e.g.
+    /// `"10.500"` for 10.50 USD with 3 decimal places
+    FixedDecimalStringMajorUnit {
+        /// The number of decimal places of the amount
+        decimal_places: u8,
+    },
+}
+
+impl AmountSerializationStrategy {
+    /// Validate that amounts in the currency can be represented in the format without losing
+    /// precision, which is not the case for a fixed number of decimal places less than the
+    /// number of decimal places of the currency
+    pub fn validate_currency(
+        &self,
+        currency: enums::Currency,
+    ) -> Result<(), error_stack::Report<ParsingError>> {
+        match self {
+            Self::FixedDecimalStringMajorUnit { decimal_places }
+                if *decimal_places < currency.number_of_digits_after_decimal_point() =>
+            {
+                Err(error_stack::report!(
+                    ParsingError::InsufficientAmountPrecision {
+                        currency: currency.to_string(),
+                        decimal_places: *decimal_places,
+                    }
+                ))
+            }
+            Self::MinorUnit
+            | Self::StringMinorUnit
+            | Self::FloatMajorUnit
+            | Self::StringMajorUnit
+            | Self::FixedDecimalStringMajorUnit { .. } => Ok(()),
+        }
+    }
+
+    /// Validate that the amount in the major unit of the currency has no more significant decimal
+    /// places than the currency, as the fraction of the minor unit would otherwise be dropped
+    fn validate_major_unit_amount(
+        amount: &str,
+        currency: enums::Currency,
+    ) -> Result<(), error_stack::Report<ParsingError>> {
+        let decimal_places = Decimal::from_str(amount)
+            .map_err(|e| ParsingError::StringToDecimalConversionFailure {
+                error: e.to_string(),
+            })?
+            .normalize()
+            .scale();
+
+        if decimal_places > u32::from(currency.number_of_digits_after_decimal_point()) {
+            return Err(error_stack::report!(ParsingError::ExcessAmountPrecision {
+                currency: currency.to_string(),
+                decimal_places,
+            }));
+        }
+        Ok(())
+    }
+}
+
+/// An amount in the format expected by a connector
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum SerializedAmount {
+    /// Integer in the minor unit of the currency
+    MinorUnit(MinorUnit),
+    /// Float in the major unit of the currency
+    FloatMajorUnit(FloatMajorUnit),
+    /// String in the minor or the major unit of the currency
+    String(String),
+}
+
+impl AmountConvertor for AmountSerializationStrategy {
+    type Output = SerializedAmount;
+    fn convert(
+        &self,
+        amount: MinorUnit,
+        currency: enums::Currency,
+    ) -> Result<Self::Output, error_stack::Report<ParsingError>> {
+        self.validate_currency(currency)?;
+        match self {
+            Self::MinorUnit => Ok(SerializedAmount::MinorUnit(amount)),
+            Self::StringMinorUnit => amount
+                .to_minor_unit_as_string()
+                .map(|amount| SerializedAmount::String(amount.0)),
+            Self::FloatMajorUnit => amount
+                .to_major_unit_as_f64(currency)
+                .map(SerializedAmount::FloatMajorUnit),
+            Self::StringMajorUnit => amount
+                .to_major_unit_as_string(currency)
+                .map(|amount| SerializedAmount::String(amount.0)),
+            Self::FixedDecimalStringMajorUnit { decimal_places } => {
+                let amount_decimal = Decimal::new(
+                    amount.0,
+                    u32::from(currency.number_of_digits_after_decimal_point()),
+                );
+                Ok(SerializedAmount::String(format!(
+                    "{amount_decimal:.precision$}",
+                    precision = usize::from(*decimal_places)
+                )))
+            }
+        }
+    }
+
+    fn convert_back(
+        &self,
+        amount: SerializedAmount,
+        currency: enums::Currency,
+    ) -> Result<MinorUnit, error_stack::Report<ParsingError>> {
+        match (self, amount) {
+            (Self::MinorUnit, SerializedAmount::MinorUnit(amount)) => Ok(amount),
+            (Self::StringMinorUnit, SerializedAmount::String(amount)) => {
+                StringMinorUnit::new(amount).to_minor_unit_as_i64()
+            }
+            (Self::FloatMajorUnit, SerializedAmount::FloatMajorUnit(amount)) => {
+                amount.to_minor_unit_as_i64(currency)
+            }
+            (
+                Self::StringMajorUnit | Self::FixedDecimalStringMajorUnit { .. },
+                SerializedAmount::String(amount),
+            ) => {
+                Self::validate_major_unit_amount(&amount, currency)?;
+                StringMajorUnit::new(amount).to_minor_unit_as_i64(currency)
+            }
+            _ => Err(error_stack::report!(ParsingError::StructParseFailure(
+                "SerializedAmount"
+            ))),
+        }
+    }
+}
+
 /// This Unit struct represents MinorUnit in which core amount works
 #[derive(
     Default,
@@ -937,6 +1106,77 @@ mod amount_conversion_tests {
         assert_eq!(converted_back_amount, request_amount);
     }
 
+    #[test]
+    fn amount_serialization_with_fixed_decimal_places() {
+        let request_amount = MinorUnit::new(1050);
+        let strategy =
+            AmountSerializationStrategy::FixedDecimalStringMajorUnit { decimal_places: 3 };
+
+        let converted_amount = strategy
+            .convert(request_amount, TWO_DECIMAL_CURRENCY)
+            .unwrap();
+        assert_eq!(
+            converted_amount,
+            SerializedAmount::String("10.500".to_string())
+        );
+        let converted_back_amount = strategy
+            .convert_back(converted_amount, TWO_DECIMAL_CURRENCY)
+            .unwrap();
+        assert_eq!(converted_back_amount, request_amount);
+
+        let converted_amount = strategy
+            .convert(request_amount, THREE_DECIMAL_CURRENCY)
+            .unwrap();
+        assert_eq!(
+            converted_amount,
+            SerializedAmount::String("1.050".to_string())
+        );
+
+        let converted_amount = strategy
+            .convert(request_amount, ZERO_DECIMAL_CURRENCY)
+            .unwrap();
+        assert_eq!(
+            converted_amount,
+            SerializedAmount::String("1050.000".to_string())
+        );
+    }
+
+    #[test]
+    fn amount_deserialization_with_excess_precision_is_rejected() {
+        let strategy = AmountSerializationStrategy::StringMajorUnit;
+
+        let converted_back_amount = strategy
+            .convert_back(
+                SerializedAmount::String("10.500".to_string()),
+                TWO_DECIMAL_CURRENCY,
+            )
+            .unwrap();
+        assert_eq!(converted_back_amount, MinorUnit::new(1050));
+        assert!(strategy
+            .convert_back(
+                SerializedAmount::String("10.505".to_string()),
+                TWO_DECIMAL_CURRENCY,
+            )
+            .is_err());
+        assert!(strategy
+            .convert_back(
+                SerializedAmount::String("10.5".to_string()),
+                ZERO_DECIMAL_CURRENCY,
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn amount_serialization_with_insufficient_precision_is_rejected() {
+        let strategy =
+            AmountSerializationStrategy::FixedDecimalStringMajorUnit { decimal_places: 2 };
+
+        assert!(strategy.validate_currency(TWO_DECIMAL_CURRENCY).is_ok());
+        assert!(strategy
+            .convert(MinorUnit::new(1050), THREE_DECIMAL_CURRENCY)
+            .is_err());
+    }
+
     #[test]
     fn amount_conversion_to_string_minor_unit() {
         let request_amount = MinorUnit::new(999999999);
@@ -974,6 +1214,136 @@ pub struct ChargeRefunds {
 
 crate::impl_to_sql_from_sql_json!(ChargeRefunds);
 
+/// Key-value tags attached to a payment attempt, which can be used to query the payment attempts
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(transparent)]
+pub struct PaymentAttemptTags(HashMap<String, String>);
+
+impl PaymentAttemptTags {
+    /// Get the value of the tag with the provided key
+    pub fn get(&self, key: &str) -> Option<&String> {
+        self.0.get(key)
+    }
+
+    /// Get an iterator over the keys and values of the tags
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.0.iter()
+    }
+
+    /// Get the number of tags
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Check whether there are no tags
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<HashMap<String, String>> for PaymentAttemptTags {
+    fn from(tags: HashMap<String, String>) -> Self {
+        Self(tags)
+    }
+}
+
+crate::impl_to_sql_from_sql_json!(PaymentAttemptTags);
+
+/// The advice of the card network, returned along with a decline, on whether and when the payment
+/// can be retried
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RetryAdvice {
+    /// The payment can be retried
+    Retryable,
+    /// The payment must not be retried with the same payment method
+    NotRetryable,
+    /// The payment can be retried once the duration has elapsed since the decline
+    RetryAfter {
+        /// The duration in seconds after which the payment can be retried
+        retry_after_seconds: i64,
+    },
+}
+
+impl RetryAdvice {
+    /// Check whether the payment can be retried immediately
+    pub fn is_retryable_now(&self) -> bool {
+        matches!(self, Self::Retryable)
+    }
+}
+
+crate::impl_to_sql_from_sql_json!(RetryAdvice);
+
+/// A tax identifier of a customer, such as their VAT or GST registration number, issued in the
+/// jurisdiction of a country
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+pub struct CustomerTaxId {
+    /// The kind of the tax identifier
+    #[schema(value_type = TaxIdType, example = "eu_vat")]
+    pub tax_id_type: enums::TaxIdType,
+    /// The country whose jurisdiction issued the tax identifier
+    #[schema(value_type = CountryAlpha2, example = "DE")]
+    pub country: enums::CountryAlpha2,
+    /// The tax identifier
+    #[schema(value_type = String, example = "DE123456789")]
+    pub value: masking::Secret<String>,
+    /// A label for the tax identifier, such as the name of the business it is registered to
+    #[schema(example = "Acme GmbH")]
+    pub label: Option<String>,
+}
+
+/// The tax identifiers of a customer, for the jurisdictions the customer is registered in
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, ToSchema)]
+#[serde(transparent)]
+pub struct CustomerTaxIds(Vec<CustomerTaxId>);
+
+impl CustomerTaxIds {
+    /// Get the tax identifier which applies to a transaction in the country, which is the one
+    /// issued in the country, or an EU VAT identification number for the member states of the
+    /// European Union
+    pub fn get_for_country(&self, country: enums::CountryAlpha2) -> Option<&CustomerTaxId> {
+        self.0
+            .iter()
+            .find(|tax_id| tax_id.country == country)
+            .or_else(|| {
+                if country.is_eu_member() {
+                    self.0
+                        .iter()
+                        .find(|tax_id| tax_id.tax_id_type == enums::TaxIdType::EuVat)
+                } else {
+                    None
+                }
+            })
+    }
+
+    /// Get an iterator over the tax identifiers
+    pub fn iter(&self) -> impl Iterator<Item = &CustomerTaxId> {
+        self.0.iter()
+    }
+
+    /// Get the number of tax identifiers
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Check whether there are no tax identifiers
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<Vec<CustomerTaxId>> for CustomerTaxIds {
+    fn from(tax_ids: Vec<CustomerTaxId>) -> Self {
+        Self(tax_ids)
+    }
+}
+
 /// A common type of domain type that can be used for fields that contain a string with restriction of length
 #[derive(Debug, Clone, Serialize, Hash, PartialEq, Eq, AsExpression)]
 #[diesel(sql_type = sql_types::Text)]
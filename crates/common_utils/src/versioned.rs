@@ -0,0 +1,41 @@
+//! Utilities for values that are persisted in a versioned serialized shape
+
+use serde::Deserialize;
+
+use crate::{
+    errors::{CustomResult, ParsingError},
+    ext_traits::ByteSliceExt,
+};
+
+/// The version assumed for serialized values which were written before versioning was introduced
+pub const LEGACY_VALUE_VERSION: u8 = 1;
+
+/// A value whose serialized representation carries a schema version.
+///
+/// This allows values written with an older shape of the type to be upgraded to the current shape
+/// when they are read back, instead of failing to deserialize.
+pub trait VersionedValue: Sized {
+    /// The version with which the current shape of the value is serialized
+    const CURRENT_VERSION: u8;
+
+    /// Deserialize `bytes` which were written with the provided `version` into the current shape
+    fn deserialize_version(version: u8, bytes: &[u8]) -> CustomResult<Self, ParsingError>;
+
+    /// Read the version of the serialized value and deserialize it into the current shape.
+    ///
+    /// Returns the version the value was serialized with, along with the upgraded value.
+    fn from_versioned_slice(bytes: &[u8]) -> CustomResult<(u8, Self), ParsingError> {
+        let ValueVersion { version } = bytes.parse_struct("ValueVersion")?;
+        Self::deserialize_version(version, bytes).map(|value| (version, value))
+    }
+}
+
+#[derive(Deserialize)]
+struct ValueVersion {
+    #[serde(default = "legacy_value_version")]
+    version: u8,
+}
+
+fn legacy_value_version() -> u8 {
+    LEGACY_VALUE_VERSION
+}
@@ -0,0 +1,179 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{enums as storage_enums, schema::audit_log};
+
+/// The value recorded in place of the values of the redacted fields
+pub const REDACTED_VALUE: &str = "*** redacted ***";
+
+/// The fields which change along with every change of a resource, and are not recorded
+const UNAUDITED_FIELDS: &[&str] = &["modified_at", "last_used", "updated_by"];
+
+/// The change of a single field of a resource
+#[derive(Clone, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub struct AuditLogFieldChange {
+    pub field: String,
+    pub before: serde_json::Value,
+    pub after: serde_json::Value,
+}
+
+/// Who made a change, and how the change is to be recorded in the audit log
+#[derive(Clone, Debug)]
+pub struct AuditLogContext {
+    /// The user or the key which made the change
+    pub actor: String,
+    /// The fields whose values are not recorded. Only whether they changed is recorded.
+    pub redacted_fields: Vec<String>,
+}
+
+impl AuditLogContext {
+    fn redact(&self, field: &str, value: serde_json::Value) -> serde_json::Value {
+        if value.is_null()
+            || !self
+                .redacted_fields
+                .iter()
+                .any(|redacted| redacted == field)
+        {
+            value
+        } else {
+            serde_json::Value::String(REDACTED_VALUE.to_string())
+        }
+    }
+
+    /// Get the changes of the top level fields between the two states of a resource, with the
+    /// values of the redacted fields redacted. The resource is considered to be created if its
+    /// state before the change is not an object, and to be deleted if its state after the change
+    /// is not an object.
+    pub fn get_field_changes(
+        &self,
+        before: &serde_json::Value,
+        after: &serde_json::Value,
+    ) -> Vec<AuditLogFieldChange> {
+        let Some(fields) = before.as_object().or(after.as_object()) else {
+            return Vec::new();
+        };
+
+        fields
+            .keys()
+            .filter(|field| !UNAUDITED_FIELDS.contains(&field.as_str()))
+            .filter_map(|field| {
+                let before_value = before
+                    .get(field)
+                    .cloned()
+                    .unwrap_or(serde_json::Value::Null);
+                let after_value = after.get(field).cloned().unwrap_or(serde_json::Value::Null);
+
+                (before_value != after_value).then(|| AuditLogFieldChange {
+                    field: field.to_owned(),
+                    before: self.redact(field, before_value),
+                    after: self.redact(field, after_value),
+                })
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = audit_log)]
+pub struct AuditLogNew {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub actor: String,
+    pub resource_type: storage_enums::AuditLogResourceType,
+    pub resource_id: String,
+    pub action: storage_enums::AuditLogAction,
+    pub changes: serde_json::Value,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(table_name = audit_log, check_for_backend(diesel::pg::Pg))]
+pub struct AuditLog {
+    #[serde(skip)]
+    pub id: i32,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub actor: String,
+    pub resource_type: storage_enums::AuditLogResourceType,
+    pub resource_id: String,
+    pub action: storage_enums::AuditLogAction,
+    pub changes: serde_json::Value,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_context() -> AuditLogContext {
+        AuditLogContext {
+            actor: "admin_api".to_string(),
+            redacted_fields: vec!["webhook_details".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_only_changed_fields_are_recorded() {
+        let before = serde_json::json!({
+            "return_url": "https://example.com",
+            "metadata": null,
+            "modified_at": "2024-10-01T00:00:00",
+        });
+        let after = serde_json::json!({
+            "return_url": "https://example.com/return",
+            "metadata": null,
+            "modified_at": "2024-10-02T00:00:00",
+        });
+
+        assert_eq!(
+            get_context().get_field_changes(&before, &after),
+            vec![AuditLogFieldChange {
+                field: "return_url".to_string(),
+                before: serde_json::json!("https://example.com"),
+                after: serde_json::json!("https://example.com/return"),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_redacted_fields_are_recorded_without_values() {
+        let before = serde_json::json!({ "webhook_details": null });
+        let after = serde_json::json!({ "webhook_details": { "webhook_password": "secret" } });
+
+        let changes = get_context().get_field_changes(&before, &after);
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].before, serde_json::Value::Null);
+        assert_eq!(changes[0].after, serde_json::json!(REDACTED_VALUE));
+    }
+
+    #[test]
+    fn test_all_fields_are_recorded_for_deleted_resources() {
+        let before = serde_json::json!({ "name": "key", "description": null });
+
+        let changes = get_context().get_field_changes(&before, &serde_json::Value::Null);
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].field, "name");
+    }
+
+    #[test]
+    fn test_all_fields_are_recorded_for_created_resources() {
+        let after = serde_json::json!({
+            "name": "key",
+            "description": null,
+            "webhook_details": { "webhook_password": "secret" },
+        });
+
+        let changes = get_context().get_field_changes(&serde_json::Value::Null, &after);
+
+        assert_eq!(changes.len(), 2);
+        assert!(changes
+            .iter()
+            .all(|change| change.before == serde_json::Value::Null));
+        assert!(changes.contains(&AuditLogFieldChange {
+            field: "webhook_details".to_string(),
+            before: serde_json::Value::Null,
+            after: serde_json::json!(REDACTED_VALUE),
+        }));
+    }
+}
@@ -0,0 +1,91 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{enums as storage_enums, schema::bank_account_token};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = bank_account_token)]
+pub struct BankAccountTokenNew {
+    pub token_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub locker_id: String,
+    pub fingerprint: String,
+    pub masked_account_number: String,
+    pub masked_routing_number: String,
+    pub verification_status: storage_enums::BankAccountVerificationStatus,
+    pub connector_bank_token: Option<String>,
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(
+    table_name = bank_account_token,
+    primary_key(token_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct BankAccountToken {
+    pub token_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    /// The reference of the bank account details stored in the locker
+    pub locker_id: String,
+    /// The fingerprint of the bank account, used to avoid tokenizing a bank account more than once
+    /// for a customer
+    pub fingerprint: String,
+    pub masked_account_number: String,
+    pub masked_routing_number: String,
+    pub verification_status: storage_enums::BankAccountVerificationStatus,
+    /// The token of the bank account at the connector
+    pub connector_bank_token: Option<String>,
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug)]
+pub enum BankAccountTokenUpdate {
+    VerificationStatusUpdate {
+        verification_status: storage_enums::BankAccountVerificationStatus,
+    },
+    ConnectorBankTokenUpdate {
+        connector_bank_token: String,
+        merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = bank_account_token)]
+pub struct BankAccountTokenUpdateInternal {
+    pub verification_status: Option<storage_enums::BankAccountVerificationStatus>,
+    pub connector_bank_token: Option<String>,
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+impl From<BankAccountTokenUpdate> for BankAccountTokenUpdateInternal {
+    fn from(bank_account_token_update: BankAccountTokenUpdate) -> Self {
+        let modified_at = common_utils::date_time::now();
+        match bank_account_token_update {
+            BankAccountTokenUpdate::VerificationStatusUpdate {
+                verification_status,
+            } => Self {
+                verification_status: Some(verification_status),
+                connector_bank_token: None,
+                merchant_connector_id: None,
+                modified_at,
+            },
+            BankAccountTokenUpdate::ConnectorBankTokenUpdate {
+                connector_bank_token,
+                merchant_connector_id,
+            } => Self {
+                verification_status: None,
+                connector_bank_token: Some(connector_bank_token),
+                merchant_connector_id: Some(merchant_connector_id),
+                modified_at,
+            },
+        }
+    }
+}
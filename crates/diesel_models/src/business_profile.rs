@@ -1,7 +1,7 @@
 use std::collections::{HashMap, HashSet};
 
 use common_enums::{AuthenticationConnectors, UIWidgetFormLayout};
-use common_utils::{encryption::Encryption, pii};
+use common_utils::{encryption::Encryption, pii, types::MinorUnit};
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
 use masking::Secret;
 
@@ -303,6 +303,26 @@ impl Profile {
     pub fn get_id(&self) -> &common_utils::id_type::ProfileId {
         &self.id
     }
+
+    /// The routing configuration of the profile, as recorded in the audit log
+    #[cfg(feature = "v1")]
+    pub fn get_routing_config(&self) -> serde_json::Value {
+        serde_json::json!({
+            "routing_algorithm": self.routing_algorithm,
+            "payout_routing_algorithm": self.payout_routing_algorithm,
+            "dynamic_routing_algorithm": self.dynamic_routing_algorithm,
+        })
+    }
+
+    /// The routing configuration of the profile, as recorded in the audit log
+    #[cfg(feature = "v2")]
+    pub fn get_routing_config(&self) -> serde_json::Value {
+        serde_json::json!({
+            "routing_algorithm_id": self.routing_algorithm_id,
+            "payout_routing_algorithm_id": self.payout_routing_algorithm_id,
+            "dynamic_routing_algorithm": self.dynamic_routing_algorithm,
+        })
+    }
 }
 
 #[cfg(feature = "v2")]
@@ -514,6 +534,42 @@ pub struct WebhookDetails {
     pub payment_created_enabled: Option<bool>,
     pub payment_succeeded_enabled: Option<bool>,
     pub payment_failed_enabled: Option<bool>,
+    pub ordered_delivery_enabled: Option<bool>,
+    pub minimum_amounts: Option<HashMap<common_enums::Currency, MinorUnit>>,
+    pub enabled_event_types: Option<Vec<common_enums::EventType>>,
+}
+
+impl WebhookDetails {
+    pub fn is_ordered_delivery_enabled(&self) -> bool {
+        self.ordered_delivery_enabled.unwrap_or(false)
+    }
+
+    /// Whether webhooks are posted for the events of the event type, which is the case for every
+    /// event type unless the merchant subscribed to specific event types. The legacy
+    /// `payment_succeeded_enabled` and `payment_failed_enabled` flags were stored before webhooks
+    /// were filtered by the event type, and are ignored so that the profiles which have them keep
+    /// receiving the webhooks of every event type.
+    pub fn is_event_type_enabled(&self, event_type: common_enums::EventType) -> bool {
+        self.enabled_event_types
+            .as_ref()
+            .map_or(true, |enabled_event_types| {
+                enabled_event_types.contains(&event_type)
+            })
+    }
+
+    /// Whether the amount is at least the minimum amount configured for its currency. Amounts are
+    /// compared only with the minimum amount of their own currency, amounts in currencies without
+    /// a minimum amount always meet it.
+    pub fn meets_minimum_amount(
+        &self,
+        amount: MinorUnit,
+        currency: common_enums::Currency,
+    ) -> bool {
+        self.minimum_amounts
+            .as_ref()
+            .and_then(|minimum_amounts| minimum_amounts.get(&currency))
+            .map_or(true, |minimum_amount| amount >= *minimum_amount)
+    }
 }
 
 common_utils::impl_to_sql_from_sql_json!(WebhookDetails);
@@ -558,3 +614,101 @@ pub struct BusinessGenericLinkConfig {
 }
 
 common_utils::impl_to_sql_from_sql_json!(BusinessPayoutLinkConfig);
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_webhook_details(
+        minimum_amounts: Option<HashMap<common_enums::Currency, MinorUnit>>,
+    ) -> WebhookDetails {
+        WebhookDetails {
+            webhook_version: None,
+            webhook_username: None,
+            webhook_password: None,
+            webhook_url: None,
+            payment_created_enabled: None,
+            payment_succeeded_enabled: None,
+            payment_failed_enabled: None,
+            ordered_delivery_enabled: None,
+            minimum_amounts,
+            enabled_event_types: None,
+        }
+    }
+
+    #[test]
+    fn test_minimum_amount_is_compared_in_the_currency_of_the_amount() {
+        let webhook_details = get_webhook_details(Some(HashMap::from([
+            (common_enums::Currency::USD, MinorUnit::new(10000)),
+            (common_enums::Currency::JPY, MinorUnit::new(15000)),
+            (common_enums::Currency::BHD, MinorUnit::new(100000)),
+        ])));
+
+        // 100.00 USD, 99.99 USD
+        assert!(webhook_details
+            .meets_minimum_amount(MinorUnit::new(10000), common_enums::Currency::USD));
+        assert!(!webhook_details
+            .meets_minimum_amount(MinorUnit::new(9999), common_enums::Currency::USD));
+        // 15000 JPY, 14999 JPY, where the amount is above the minimum amount of USD
+        assert!(webhook_details
+            .meets_minimum_amount(MinorUnit::new(15000), common_enums::Currency::JPY));
+        assert!(!webhook_details
+            .meets_minimum_amount(MinorUnit::new(14999), common_enums::Currency::JPY));
+        // 50.000 BHD, which is below the minimum amount of 100.000 BHD
+        assert!(!webhook_details
+            .meets_minimum_amount(MinorUnit::new(50000), common_enums::Currency::BHD));
+        // No minimum amount for EUR
+        assert!(
+            webhook_details.meets_minimum_amount(MinorUnit::new(1), common_enums::Currency::EUR)
+        );
+    }
+
+    #[test]
+    fn test_every_event_type_is_enabled_without_a_subscription() {
+        let webhook_details = get_webhook_details(None);
+
+        assert!(webhook_details.is_event_type_enabled(common_enums::EventType::PaymentSucceeded));
+        assert!(webhook_details.is_event_type_enabled(common_enums::EventType::PaymentFailed));
+        assert!(webhook_details.is_event_type_enabled(common_enums::EventType::RefundSucceeded));
+    }
+
+    #[test]
+    fn test_only_the_subscribed_event_types_are_enabled() {
+        let mut webhook_details = get_webhook_details(None);
+        webhook_details.enabled_event_types = Some(vec![common_enums::EventType::PaymentSucceeded]);
+
+        assert!(webhook_details.is_event_type_enabled(common_enums::EventType::PaymentSucceeded));
+        assert!(!webhook_details.is_event_type_enabled(common_enums::EventType::PaymentFailed));
+        assert!(!webhook_details.is_event_type_enabled(common_enums::EventType::RefundSucceeded));
+    }
+
+    #[test]
+    fn test_legacy_event_type_flags_are_ignored() {
+        let mut webhook_details = get_webhook_details(None);
+        webhook_details.payment_succeeded_enabled = Some(false);
+        webhook_details.payment_failed_enabled = Some(false);
+
+        assert!(webhook_details.is_event_type_enabled(common_enums::EventType::PaymentSucceeded));
+        assert!(webhook_details.is_event_type_enabled(common_enums::EventType::PaymentFailed));
+
+        // The legacy flags stored in the profiles deserialize without a subscription
+        let webhook_details: WebhookDetails = serde_json::from_value(serde_json::json!({
+            "webhook_url": "https://example.com/webhooks",
+            "payment_succeeded_enabled": false,
+            "payment_failed_enabled": false
+        }))
+        .unwrap();
+        assert!(webhook_details.is_event_type_enabled(common_enums::EventType::PaymentFailed));
+    }
+
+    #[test]
+    fn test_all_amounts_meet_the_minimum_amount_without_minimum_amounts() {
+        let webhook_details = get_webhook_details(None);
+
+        assert!(
+            webhook_details.meets_minimum_amount(MinorUnit::new(1), common_enums::Currency::USD)
+        );
+    }
+}
@@ -69,6 +69,7 @@ pub enum CaptureUpdate {
         error_message: Option<String>,
         error_reason: Option<String>,
     },
+    ReversalUpdate,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -136,6 +137,11 @@ impl From<CaptureUpdate> for CaptureUpdateInternal {
                 modified_at: now,
                 ..Self::default()
             },
+            CaptureUpdate::ReversalUpdate => Self {
+                status: Some(storage_enums::CaptureStatus::Reversed),
+                modified_at: now,
+                ..Self::default()
+            },
         }
     }
 }
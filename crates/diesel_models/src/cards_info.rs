@@ -1,10 +1,17 @@
-use diesel::{Identifiable, Queryable, Selectable};
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
 use time::PrimitiveDateTime;
 
 use crate::{enums as storage_enums, schema::cards_info};
 
 #[derive(
-    Clone, Debug, Queryable, Identifiable, Selectable, serde::Deserialize, serde::Serialize,
+    Clone,
+    Debug,
+    Queryable,
+    Identifiable,
+    Selectable,
+    Insertable,
+    serde::Deserialize,
+    serde::Serialize,
 )]
 #[diesel(table_name = cards_info, primary_key(card_iin), check_for_backend(diesel::pg::Pg))]
 pub struct CardInfo {
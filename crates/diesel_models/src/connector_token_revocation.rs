@@ -0,0 +1,49 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{enums as storage_enums, schema::connector_token_revocation};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = connector_token_revocation)]
+pub struct ConnectorTokenRevocationNew {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_method_id: String,
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub connector_name: String,
+    pub connector_token: String,
+    pub revocation_status: storage_enums::ConnectorTokenRevocationStatus,
+    pub error_message: Option<String>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(
+    table_name = connector_token_revocation,
+    primary_key(merchant_id, payment_method_id, merchant_connector_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct ConnectorTokenRevocation {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    /// The identifier of the deleted payment method the token was provisioned for
+    pub payment_method_id: String,
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub connector_name: String,
+    /// The connector's identifier of the token, which is the connector mandate id
+    pub connector_token: String,
+    pub revocation_status: storage_enums::ConnectorTokenRevocationStatus,
+    /// The reason the token could not be revoked at the connector
+    pub error_message: Option<String>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = connector_token_revocation)]
+pub struct ConnectorTokenRevocationUpdate {
+    pub revocation_status: storage_enums::ConnectorTokenRevocationStatus,
+    pub error_message: Option<String>,
+    pub modified_at: time::PrimitiveDateTime,
+}
@@ -0,0 +1,66 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::schema::connector_webhook_offset;
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = connector_webhook_offset)]
+pub struct ConnectorWebhookOffsetNew {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    pub connector_name: String,
+    pub read_offset: Option<String>,
+    pub log_id: Option<String>,
+    pub reset_count: i32,
+    pub last_reset_at: Option<time::PrimitiveDateTime>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+impl ConnectorWebhookOffsetNew {
+    /// Offset of a connector account whose webhooks have not been read yet
+    pub fn new(
+        merchant_id: common_utils::id_type::MerchantId,
+        merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+        connector_name: String,
+    ) -> Self {
+        let now = common_utils::date_time::now();
+        Self {
+            merchant_id,
+            merchant_connector_id,
+            connector_name,
+            read_offset: None,
+            log_id: None,
+            reset_count: 0,
+            last_reset_at: None,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+}
+
+/// The position up to which the webhooks of a connector account, delivered through a log or a
+/// queue of the connector which is polled, have been read
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(
+    table_name = connector_webhook_offset,
+    primary_key(merchant_id, merchant_connector_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct ConnectorWebhookOffset {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    pub connector_name: String,
+    /// The offset or cursor of the last webhook read from the log of the connector, not set when
+    /// no webhook has been read yet
+    pub read_offset: Option<String>,
+    /// The identifier of the log of the connector to which the offset belongs, for the connectors
+    /// which rotate their logs
+    pub log_id: Option<String>,
+    /// The number of times the offset was reset, since the log of the connector was truncated or
+    /// rotated
+    pub reset_count: i32,
+    pub last_reset_at: Option<time::PrimitiveDateTime>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
@@ -0,0 +1,50 @@
+use common_utils::encryption::Encryption;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{enums as storage_enums, schema::customer_communication};
+
+/// An entry of the log of the communication of the merchant with a customer. The entries are
+/// immutable once written, so that they can be attached as evidence to the disputes, except for
+/// the redaction of their content along with the customer.
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = customer_communication)]
+pub struct CustomerCommunicationNew {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub channel: storage_enums::CustomerCommunicationChannel,
+    pub summary: Encryption,
+    pub file_id: Option<String>,
+    pub communicated_at: time::PrimitiveDateTime,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(
+    table_name = customer_communication,
+    primary_key(id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct CustomerCommunication {
+    pub id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub channel: storage_enums::CustomerCommunicationChannel,
+    pub summary: Encryption,
+    /// The file of the communication, such as the transcript of a call or a copy of an email
+    pub file_id: Option<String>,
+    pub communicated_at: time::PrimitiveDateTime,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+/// The redaction of the content of the entries, when the customer is redacted. The rest of the
+/// entry is kept, so that the communication can still be accounted for.
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = customer_communication, treat_none_as_null = true)]
+pub struct CustomerCommunicationRedaction {
+    pub summary: Encryption,
+    pub file_id: Option<String>,
+}
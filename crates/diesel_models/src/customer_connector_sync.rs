@@ -0,0 +1,35 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{enums as storage_enums, schema::customer_connector_sync};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = customer_connector_sync)]
+pub struct CustomerConnectorSyncNew {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub connector_label: String,
+    pub connector_name: String,
+    pub sync_status: storage_enums::CustomerConnectorSyncStatus,
+    pub error_message: Option<String>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(
+    table_name = customer_connector_sync,
+    primary_key(merchant_id, customer_id, connector_label),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct CustomerConnectorSync {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    /// The key of the customer's `connector_customer` map the sync applies to
+    pub connector_label: String,
+    pub connector_name: String,
+    pub sync_status: storage_enums::CustomerConnectorSyncStatus,
+    pub error_message: Option<String>,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
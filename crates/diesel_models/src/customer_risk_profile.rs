@@ -0,0 +1,50 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{enums as storage_enums, schema::customer_risk_profile};
+
+/// The risk profile of a customer, which changes slowly with the disputes and the declines of the
+/// payments of the customer, unlike the fraud score of a single payment
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = customer_risk_profile)]
+pub struct CustomerRiskProfileNew {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub risk_score: i16,
+    pub risk_tier: storage_enums::CustomerRiskTier,
+    pub dispute_count: i32,
+    pub lost_dispute_count: i32,
+    pub decline_count: i32,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(
+    table_name = customer_risk_profile,
+    primary_key(merchant_id, customer_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct CustomerRiskProfile {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    /// The risk score of the customer, between 0 and 100
+    pub risk_score: i16,
+    pub risk_tier: storage_enums::CustomerRiskTier,
+    /// The number of the disputes raised on the payments of the customer
+    pub dispute_count: i32,
+    /// The number of the disputes raised on the payments of the customer which the merchant lost
+    pub lost_dispute_count: i32,
+    /// The number of the payments of the customer which were declined
+    pub decline_count: i32,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = customer_risk_profile)]
+pub struct CustomerRiskProfileUpdateInternal {
+    pub risk_score: i16,
+    pub risk_tier: storage_enums::CustomerRiskTier,
+    pub modified_at: time::PrimitiveDateTime,
+}
@@ -30,6 +30,7 @@ pub struct CustomerNew {
     pub address_id: Option<String>,
     pub updated_by: Option<String>,
     pub version: ApiVersion,
+    pub tax_ids: Option<Encryption>,
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -58,6 +59,8 @@ impl From<CustomerNew> for Customer {
             default_payment_method_id: None,
             updated_by: customer_new.updated_by,
             version: customer_new.version,
+            tax_ids: customer_new.tax_ids,
+            preferred_payment_method_id: None,
         }
     }
 }
@@ -148,6 +151,8 @@ pub struct Customer {
     pub default_payment_method_id: Option<String>,
     pub updated_by: Option<String>,
     pub version: ApiVersion,
+    pub tax_ids: Option<Encryption>,
+    pub preferred_payment_method_id: Option<String>,
 }
 
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
@@ -193,6 +198,8 @@ pub struct CustomerUpdateInternal {
     pub address_id: Option<String>,
     pub default_payment_method_id: Option<Option<String>>,
     pub updated_by: Option<String>,
+    pub tax_ids: Option<Encryption>,
+    pub preferred_payment_method_id: Option<Option<String>>,
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -208,6 +215,8 @@ impl CustomerUpdateInternal {
             connector_customer,
             address_id,
             default_payment_method_id,
+            tax_ids,
+            preferred_payment_method_id,
             ..
         } = self;
 
@@ -224,6 +233,9 @@ impl CustomerUpdateInternal {
             default_payment_method_id: default_payment_method_id
                 .flatten()
                 .map_or(source.default_payment_method_id, Some),
+            tax_ids: tax_ids.map_or(source.tax_ids, Some),
+            preferred_payment_method_id: preferred_payment_method_id
+                .unwrap_or(source.preferred_payment_method_id),
             ..source
         }
     }
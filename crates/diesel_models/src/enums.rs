@@ -177,8 +177,29 @@ pub struct MandateAmountData {
     pub start_date: Option<PrimitiveDateTime>,
     pub end_date: Option<PrimitiveDateTime>,
     pub metadata: Option<pii::SecretSerdeValue>,
+    pub constraints: Option<MandateConstraints>,
 }
 
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    FromSqlRow,
+    AsExpression,
+)]
+#[diesel(sql_type = Jsonb)]
+pub struct MandateConstraints {
+    pub max_amount_per_transaction: Option<common_utils::types::MinorUnit>,
+    pub total_amount: Option<common_utils::types::MinorUnit>,
+    pub frequency: Option<MandateFrequency>,
+}
+
+common_utils::impl_to_sql_from_sql_json!(MandateConstraints);
+
 #[derive(
     Clone,
     Copy,
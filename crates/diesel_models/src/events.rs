@@ -30,6 +30,7 @@ pub struct EventNew {
     pub response: Option<Encryption>,
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
+    pub sequence_number: Option<i64>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -37,6 +38,7 @@ pub struct EventNew {
 pub struct EventUpdateInternal {
     pub is_webhook_notified: Option<bool>,
     pub response: Option<Encryption>,
+    pub sequence_number: Option<i64>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable, Selectable)]
@@ -61,6 +63,9 @@ pub struct Event {
     pub response: Option<Encryption>,
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
+    /// Position of the event among the events of the same resource, when the business profile
+    /// requires the events of a resource to be delivered in order
+    pub sequence_number: Option<i64>,
 }
 
 pub struct EventWithEncryption {
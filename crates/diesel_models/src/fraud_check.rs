@@ -27,6 +27,8 @@ pub struct FraudCheck {
     pub modified_at: PrimitiveDateTime,
     pub last_step: FraudCheckLastStep,
     pub payment_capture_method: Option<storage_enums::CaptureMethod>, // In postFrm, we are updating capture method from automatic to manual. To store the merchant actual capture method, we are storing the actual capture method in payment_capture_method. It will be useful while approving the FRM decision.
+    /// The reviewer who approved or declined the payment, when it was sent for review
+    pub reviewed_by: Option<String>,
 }
 
 #[derive(router_derive::Setter, Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -49,6 +51,7 @@ pub struct FraudCheckNew {
     pub modified_at: PrimitiveDateTime,
     pub last_step: FraudCheckLastStep,
     pub payment_capture_method: Option<storage_enums::CaptureMethod>,
+    pub reviewed_by: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -68,6 +71,10 @@ pub enum FraudCheckUpdate {
         status: FraudCheckStatus,
         error_message: Option<Option<String>>,
     },
+    ReviewUpdate {
+        reviewed_by: Option<String>,
+        last_step: FraudCheckLastStep,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -81,6 +88,7 @@ pub struct FraudCheckUpdateInternal {
     metadata: Option<serde_json::Value>,
     last_step: FraudCheckLastStep,
     payment_capture_method: Option<storage_enums::CaptureMethod>,
+    reviewed_by: Option<String>,
 }
 
 impl From<FraudCheckUpdate> for FraudCheckUpdateInternal {
@@ -113,6 +121,14 @@ impl From<FraudCheckUpdate> for FraudCheckUpdateInternal {
                 frm_error: error_message,
                 ..Default::default()
             },
+            FraudCheckUpdate::ReviewUpdate {
+                reviewed_by,
+                last_step,
+            } => Self {
+                reviewed_by,
+                last_step,
+                ..Default::default()
+            },
         }
     }
 }
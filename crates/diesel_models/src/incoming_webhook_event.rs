@@ -0,0 +1,48 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::schema::incoming_webhook_event;
+
+/// A webhook received from a connector for a payment, its refunds or its disputes. The webhooks
+/// are recorded for the timeline of the payment, after they were processed.
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = incoming_webhook_event)]
+pub struct IncomingWebhookEventNew {
+    pub event_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub connector: String,
+    pub event_type: String,
+    pub received_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(
+    table_name = incoming_webhook_event,
+    primary_key(event_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct IncomingWebhookEvent {
+    pub event_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    /// The payment which the webhook was received for, or whose refund or dispute it was
+    /// received for
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub connector: String,
+    /// The type of the event of the webhook, as identified from the webhook of the connector
+    pub event_type: String,
+    pub received_at: time::PrimitiveDateTime,
+}
+
+impl From<IncomingWebhookEventNew> for IncomingWebhookEvent {
+    fn from(event: IncomingWebhookEventNew) -> Self {
+        Self {
+            event_id: event.event_id,
+            merchant_id: event.merchant_id,
+            payment_id: event.payment_id,
+            connector: event.connector,
+            event_type: event.event_type,
+            received_at: event.received_at,
+        }
+    }
+}
@@ -22,6 +22,7 @@ use crate::{
 pub enum DBOperation {
     Insert { insertable: Insertable },
     Update { updatable: Updateable },
+    Delete { deletable: Deletable },
 }
 
 impl DBOperation {
@@ -29,6 +30,7 @@ impl DBOperation {
         match self {
             Self::Insert { .. } => "insert",
             Self::Update { .. } => "update",
+            Self::Delete { .. } => "delete",
         }
     }
     pub fn table<'a>(&self) -> &'a str {
@@ -56,6 +58,11 @@ impl DBOperation {
                 Updateable::PaymentMethodUpdate(_) => "payment_method",
                 Updateable::MandateUpdate(_) => " mandate",
             },
+            Self::Delete { deletable } => match deletable {
+                Deletable::ReverseLookUpsByPkId(_)
+                | Deletable::ReverseLookUpsByNamespace(_)
+                | Deletable::ReverseLookUpsByLookupIds(_) => "reverse_lookup",
+            },
         }
     }
 }
@@ -68,6 +75,7 @@ pub enum DBResult {
     Address(Box<Address>),
     Customer(Box<Customer>),
     ReverseLookUp(Box<ReverseLookup>),
+    ReverseLookUps(Vec<ReverseLookup>),
     Payouts(Box<Payouts>),
     PayoutAttempt(Box<PayoutAttempt>),
     PaymentMethod(Box<PaymentMethod>),
@@ -173,6 +181,17 @@ impl DBOperation {
                     Customer::update_by_id(conn, cust.orig.id.clone(), cust.update_data).await?,
                 )),
             },
+            Self::Delete { deletable } => match deletable {
+                Deletable::ReverseLookUpsByPkId(rev) => DBResult::ReverseLookUps(
+                    ReverseLookup::delete_by_pk_id(&rev.pk_id, conn).await?,
+                ),
+                Deletable::ReverseLookUpsByNamespace(rev) => DBResult::ReverseLookUps(
+                    ReverseLookup::delete_by_namespace(&rev.merchant_id, conn).await?,
+                ),
+                Deletable::ReverseLookUpsByLookupIds(rev) => DBResult::ReverseLookUps(
+                    ReverseLookup::delete_by_lookup_ids(rev.lookup_ids, conn).await?,
+                ),
+            },
         })
     }
 }
@@ -227,6 +246,140 @@ pub enum Updateable {
     MandateUpdate(MandateUpdateMems),
 }
 
+impl Updateable {
+    /// Whether the record has been modified in the database since the update was made, in which
+    /// case applying the update would overwrite the newer modifications with stale data.
+    ///
+    /// Updates to records which are not found, or which do not track their modification time, are
+    /// not considered superseded.
+    pub async fn is_superseded(&self, conn: &PgPooledConn) -> crate::StorageResult<bool> {
+        let modified_at = match self {
+            #[cfg(feature = "v1")]
+            Self::PaymentIntentUpdate(a) => find_modified_at(
+                PaymentIntent::find_by_payment_id_merchant_id(
+                    conn,
+                    &a.orig.payment_id,
+                    &a.orig.merchant_id,
+                )
+                .await,
+                |current: PaymentIntent| current.modified_at,
+            )?
+            .map(|current| (a.orig.modified_at, current)),
+            #[cfg(feature = "v2")]
+            Self::PaymentIntentUpdate(a) => find_modified_at(
+                PaymentIntent::find_by_global_id(conn, &a.orig.id).await,
+                |current: PaymentIntent| current.modified_at,
+            )?
+            .map(|current| (a.orig.modified_at, current)),
+            #[cfg(feature = "v1")]
+            Self::PaymentAttemptUpdate(a) => find_modified_at(
+                PaymentAttempt::find_by_merchant_id_attempt_id(
+                    conn,
+                    &a.orig.merchant_id,
+                    &a.orig.attempt_id,
+                )
+                .await,
+                |current: PaymentAttempt| current.modified_at,
+            )?
+            .map(|current| (a.orig.modified_at, current)),
+            #[cfg(feature = "v2")]
+            Self::PaymentAttemptUpdate(a) => find_modified_at(
+                PaymentAttempt::find_by_id(conn, &a.orig.id).await,
+                |current: PaymentAttempt| current.modified_at,
+            )?
+            .map(|current| (a.orig.modified_at, current)),
+            Self::RefundUpdate(a) => find_modified_at(
+                Refund::find_by_merchant_id_refund_id(conn, &a.orig.merchant_id, &a.orig.refund_id)
+                    .await,
+                |current: Refund| current.modified_at,
+            )?
+            .map(|current| (a.orig.modified_at, current)),
+            Self::AddressUpdate(a) => find_modified_at(
+                Address::find_by_address_id(conn, &a.orig.address_id).await,
+                |current: Address| current.modified_at,
+            )?
+            .map(|current| (a.orig.modified_at, current)),
+            Self::PayoutsUpdate(a) => find_modified_at(
+                Payouts::find_by_merchant_id_payout_id(
+                    conn,
+                    &a.orig.merchant_id,
+                    &a.orig.payout_id,
+                )
+                .await,
+                |current: Payouts| current.last_modified_at,
+            )?
+            .map(|current| (a.orig.last_modified_at, current)),
+            Self::PayoutAttemptUpdate(a) => find_modified_at(
+                PayoutAttempt::find_by_merchant_id_payout_attempt_id(
+                    conn,
+                    &a.orig.merchant_id,
+                    &a.orig.payout_attempt_id,
+                )
+                .await,
+                |current: PayoutAttempt| current.last_modified_at,
+            )?
+            .map(|current| (a.orig.last_modified_at, current)),
+            #[cfg(all(
+                any(feature = "v1", feature = "v2"),
+                not(feature = "payment_methods_v2")
+            ))]
+            Self::PaymentMethodUpdate(v) => find_modified_at(
+                PaymentMethod::find_by_payment_method_id(conn, &v.orig.payment_method_id).await,
+                |current: PaymentMethod| current.last_modified,
+            )?
+            .map(|current| (v.orig.last_modified, current)),
+            #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+            Self::PaymentMethodUpdate(v) => find_modified_at(
+                PaymentMethod::find_by_id(conn, &v.orig.id).await,
+                |current: PaymentMethod| current.last_modified,
+            )?
+            .map(|current| (v.orig.last_modified, current)),
+            #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+            Self::CustomerUpdate(cust) => find_modified_at(
+                Customer::find_by_customer_id_merchant_id(
+                    conn,
+                    &cust.orig.customer_id,
+                    &cust.orig.merchant_id,
+                )
+                .await,
+                |current: Customer| current.modified_at,
+            )?
+            .map(|current| (cust.orig.modified_at, current)),
+            #[cfg(all(feature = "v2", feature = "customer_v2"))]
+            Self::CustomerUpdate(cust) => find_modified_at(
+                Customer::find_by_global_id(conn, &cust.orig.id).await,
+                |current: Customer| current.modified_at,
+            )?
+            .map(|current| (cust.orig.modified_at, current)),
+            // Mandates do not track their modification time
+            Self::MandateUpdate(_) => None,
+        };
+
+        Ok(modified_at.is_some_and(|(orig, current)| current > orig))
+    }
+}
+
+fn find_modified_at<T>(
+    record: crate::StorageResult<T>,
+    get_modified_at: impl FnOnce(T) -> time::PrimitiveDateTime,
+) -> crate::StorageResult<Option<time::PrimitiveDateTime>> {
+    match record {
+        Ok(record) => Ok(Some(get_modified_at(record))),
+        Err(error) => match error.current_context() {
+            errors::DatabaseError::NotFound => Ok(None),
+            _ => Err(error),
+        },
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "table", content = "data")]
+pub enum Deletable {
+    ReverseLookUpsByPkId(ReverseLookupDeleteMems),
+    ReverseLookUpsByNamespace(ReverseLookupNamespaceDeleteMems),
+    ReverseLookUpsByLookupIds(ReverseLookupIdsDeleteMems),
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CustomerUpdateMems {
     pub orig: Customer,
@@ -280,3 +433,18 @@ pub struct MandateUpdateMems {
     pub orig: Mandate,
     pub update_data: MandateUpdateInternal,
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReverseLookupDeleteMems {
+    pub pk_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReverseLookupNamespaceDeleteMems {
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReverseLookupIdsDeleteMems {
+    pub lookup_ids: Vec<String>,
+}
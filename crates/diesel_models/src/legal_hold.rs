@@ -0,0 +1,31 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{enums as storage_enums, schema::legal_hold};
+
+/// A legal hold on a record, which exempts the record from being purged once its retention period
+/// has elapsed, for as long as the hold is in place
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = legal_hold)]
+pub struct LegalHoldNew {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub entity_type: storage_enums::RetentionEntity,
+    pub record_id: String,
+    pub reason: Option<String>,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(
+    table_name = legal_hold,
+    primary_key(merchant_id, entity_type, record_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct LegalHold {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub entity_type: storage_enums::RetentionEntity,
+    /// The identifier of the record within the entity, such as the payment id for payments
+    pub record_id: String,
+    pub reason: Option<String>,
+    pub created_at: time::PrimitiveDateTime,
+}
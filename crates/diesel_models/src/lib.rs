@@ -1,15 +1,22 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_log;
+pub mod bank_account_token;
 pub mod blocklist_lookup;
 pub mod business_profile;
 pub mod capture;
 pub mod cards_info;
 pub mod configs;
+pub mod connector_token_revocation;
+pub mod connector_webhook_offset;
 
 pub mod authentication;
 pub mod authorization;
 pub mod blocklist;
 pub mod blocklist_fingerprint;
+pub mod customer_communication;
+pub mod customer_connector_sync;
+pub mod customer_risk_profile;
 pub mod customers;
 pub mod dispute;
 pub mod enums;
@@ -21,13 +28,16 @@ pub mod file;
 pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
+pub mod incoming_webhook_event;
 #[cfg(feature = "kv_store")]
 pub mod kv;
+pub mod legal_hold;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
+pub mod merchant_onboarding_audit_log;
 pub mod organization;
 pub mod payment_attempt;
 pub mod payment_intent;
@@ -38,9 +48,12 @@ pub mod payouts;
 pub mod process_tracker;
 pub mod query;
 pub mod refund;
+pub mod resource_sequence;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod settlement_reconciliation;
+pub mod three_ds_session;
 pub mod unified_translations;
 
 #[allow(unused_qualifications)]
@@ -52,6 +65,7 @@ pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_event_sequence;
 
 use diesel_impl::{DieselArray, OptionalDieselArray};
 
@@ -35,6 +35,8 @@ pub struct Mandate {
     pub original_payment_id: Option<common_utils::id_type::PaymentId>,
     pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub updated_by: Option<String>,
+    pub constraints: Option<storage_enums::MandateConstraints>,
+    pub last_charged_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(
@@ -73,6 +75,8 @@ pub struct MandateNew {
     pub original_payment_id: Option<common_utils::id_type::PaymentId>,
     pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub updated_by: Option<String>,
+    pub constraints: Option<storage_enums::MandateConstraints>,
+    pub last_charged_at: Option<PrimitiveDateTime>,
 }
 
 impl MandateNew {
@@ -89,6 +93,10 @@ pub enum MandateUpdate {
     CaptureAmountUpdate {
         amount_captured: Option<i64>,
     },
+    UsageUpdate {
+        amount_captured: Option<i64>,
+        last_charged_at: PrimitiveDateTime,
+    },
     ConnectorReferenceUpdate {
         connector_mandate_ids: Option<pii::SecretSerdeValue>,
     },
@@ -135,6 +143,7 @@ pub struct MandateUpdateInternal {
     payment_method_id: Option<String>,
     original_payment_id: Option<common_utils::id_type::PaymentId>,
     updated_by: Option<String>,
+    last_charged_at: Option<PrimitiveDateTime>,
 }
 
 impl From<MandateUpdate> for MandateUpdateInternal {
@@ -148,6 +157,7 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 payment_method_id: None,
                 original_payment_id: None,
                 updated_by: None,
+                last_charged_at: None,
             },
             MandateUpdate::CaptureAmountUpdate { amount_captured } => Self {
                 mandate_status: None,
@@ -157,6 +167,15 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 payment_method_id: None,
                 original_payment_id: None,
                 updated_by: None,
+                last_charged_at: None,
+            },
+            MandateUpdate::UsageUpdate {
+                amount_captured,
+                last_charged_at,
+            } => Self {
+                amount_captured,
+                last_charged_at: Some(last_charged_at),
+                ..Default::default()
             },
             MandateUpdate::ConnectorReferenceUpdate {
                 connector_mandate_ids,
@@ -190,6 +209,7 @@ impl MandateUpdateInternal {
             payment_method_id,
             original_payment_id,
             updated_by,
+            last_charged_at,
         } = self;
 
         Mandate {
@@ -200,6 +220,7 @@ impl MandateUpdateInternal {
             payment_method_id: payment_method_id.unwrap_or(source.payment_method_id),
             original_payment_id: original_payment_id.map_or(source.original_payment_id, Some),
             updated_by: updated_by.map_or(source.updated_by, Some),
+            last_charged_at: last_charged_at.map_or(source.last_charged_at, Some),
             ..source
         }
     }
@@ -234,6 +255,8 @@ impl From<&MandateNew> for Mandate {
             original_payment_id: mandate_new.original_payment_id.clone(),
             merchant_connector_id: mandate_new.merchant_connector_id.clone(),
             updated_by: mandate_new.updated_by.clone(),
+            constraints: mandate_new.constraints,
+            last_charged_at: mandate_new.last_charged_at,
         }
     }
 }
@@ -1,4 +1,10 @@
-use common_utils::{encryption::Encryption, pii};
+use common_utils::{
+    consts::CONNECTOR_FEE_PERCENTAGE_PRECISION_LENGTH,
+    encryption::Encryption,
+    errors::{CustomResult, PercentageError},
+    pii,
+    types::{MinorUnit, Percentage},
+};
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
 
 use crate::enums as storage_enums;
@@ -51,6 +57,18 @@ pub struct MerchantAccount {
     pub payment_link_config: Option<serde_json::Value>,
     pub pm_collect_link_config: Option<serde_json::Value>,
     pub version: common_enums::ApiVersion,
+    pub onboarding_status: storage_enums::OnboardingStatus,
+    pub idempotency_ttl_in_seconds: Option<i64>,
+    pub default_connector_by_method_type: Option<serde_json::Value>,
+    pub customer_pii_encryption_fields: Option<Vec<String>>,
+    pub statement_descriptor_length_policy: Option<storage_enums::StatementDescriptorLengthPolicy>,
+    pub allowed_return_url_domains: Option<Vec<String>>,
+    pub connector_failover_order: Option<ConnectorFailoverOrder>,
+    pub default_capture_method: Option<storage_enums::CaptureMethod>,
+    pub least_cost_routing: Option<LeastCostRouting>,
+    pub transaction_amount_limits: Option<TransactionAmountLimits>,
+    pub default_currency: Option<storage_enums::Currency>,
+    pub require_explicit_currency: Option<bool>,
 }
 
 #[cfg(feature = "v1")]
@@ -83,6 +101,18 @@ pub struct MerchantAccountSetter {
     pub payment_link_config: Option<serde_json::Value>,
     pub pm_collect_link_config: Option<serde_json::Value>,
     pub version: common_enums::ApiVersion,
+    pub onboarding_status: storage_enums::OnboardingStatus,
+    pub idempotency_ttl_in_seconds: Option<i64>,
+    pub default_connector_by_method_type: Option<serde_json::Value>,
+    pub customer_pii_encryption_fields: Option<Vec<String>>,
+    pub statement_descriptor_length_policy: Option<storage_enums::StatementDescriptorLengthPolicy>,
+    pub allowed_return_url_domains: Option<Vec<String>>,
+    pub connector_failover_order: Option<ConnectorFailoverOrder>,
+    pub default_capture_method: Option<storage_enums::CaptureMethod>,
+    pub least_cost_routing: Option<LeastCostRouting>,
+    pub transaction_amount_limits: Option<TransactionAmountLimits>,
+    pub default_currency: Option<storage_enums::Currency>,
+    pub require_explicit_currency: Option<bool>,
 }
 
 #[cfg(feature = "v1")]
@@ -117,6 +147,18 @@ impl From<MerchantAccountSetter> for MerchantAccount {
             payment_link_config: item.payment_link_config,
             pm_collect_link_config: item.pm_collect_link_config,
             version: item.version,
+            onboarding_status: item.onboarding_status,
+            idempotency_ttl_in_seconds: item.idempotency_ttl_in_seconds,
+            customer_pii_encryption_fields: item.customer_pii_encryption_fields,
+            statement_descriptor_length_policy: item.statement_descriptor_length_policy,
+            allowed_return_url_domains: item.allowed_return_url_domains,
+            connector_failover_order: item.connector_failover_order,
+            default_capture_method: item.default_capture_method,
+            least_cost_routing: item.least_cost_routing,
+            transaction_amount_limits: item.transaction_amount_limits,
+            default_currency: item.default_currency,
+            require_explicit_currency: item.require_explicit_currency,
+            default_connector_by_method_type: item.default_connector_by_method_type,
         }
     }
 }
@@ -148,6 +190,7 @@ pub struct MerchantAccount {
     pub recon_status: storage_enums::ReconStatus,
     pub version: common_enums::ApiVersion,
     pub id: common_utils::id_type::MerchantId,
+    pub onboarding_status: storage_enums::OnboardingStatus,
 }
 
 #[cfg(feature = "v2")]
@@ -165,6 +208,7 @@ impl From<MerchantAccountSetter> for MerchantAccount {
             organization_id: item.organization_id,
             recon_status: item.recon_status,
             version: item.version,
+            onboarding_status: item.onboarding_status,
         }
     }
 }
@@ -182,6 +226,7 @@ pub struct MerchantAccountSetter {
     pub organization_id: common_utils::id_type::OrganizationId,
     pub recon_status: storage_enums::ReconStatus,
     pub version: common_enums::ApiVersion,
+    pub onboarding_status: storage_enums::OnboardingStatus,
 }
 
 impl MerchantAccount {
@@ -228,6 +273,18 @@ pub struct MerchantAccountNew {
     pub payment_link_config: Option<serde_json::Value>,
     pub pm_collect_link_config: Option<serde_json::Value>,
     pub version: common_enums::ApiVersion,
+    pub onboarding_status: storage_enums::OnboardingStatus,
+    pub idempotency_ttl_in_seconds: Option<i64>,
+    pub default_connector_by_method_type: Option<serde_json::Value>,
+    pub customer_pii_encryption_fields: Option<Vec<String>>,
+    pub statement_descriptor_length_policy: Option<storage_enums::StatementDescriptorLengthPolicy>,
+    pub allowed_return_url_domains: Option<Vec<String>>,
+    pub connector_failover_order: Option<ConnectorFailoverOrder>,
+    pub default_capture_method: Option<storage_enums::CaptureMethod>,
+    pub least_cost_routing: Option<LeastCostRouting>,
+    pub transaction_amount_limits: Option<TransactionAmountLimits>,
+    pub default_currency: Option<storage_enums::Currency>,
+    pub require_explicit_currency: Option<bool>,
 }
 
 #[cfg(feature = "v2")]
@@ -244,6 +301,7 @@ pub struct MerchantAccountNew {
     pub recon_status: storage_enums::ReconStatus,
     pub id: common_utils::id_type::MerchantId,
     pub version: common_enums::ApiVersion,
+    pub onboarding_status: storage_enums::OnboardingStatus,
 }
 
 #[cfg(feature = "v2")]
@@ -289,4 +347,511 @@ pub struct MerchantAccountUpdateInternal {
     pub recon_status: Option<storage_enums::ReconStatus>,
     pub payment_link_config: Option<serde_json::Value>,
     pub pm_collect_link_config: Option<serde_json::Value>,
+    pub idempotency_ttl_in_seconds: Option<i64>,
+    pub default_connector_by_method_type: Option<serde_json::Value>,
+    pub customer_pii_encryption_fields: Option<Vec<String>>,
+    pub statement_descriptor_length_policy: Option<storage_enums::StatementDescriptorLengthPolicy>,
+    pub allowed_return_url_domains: Option<Vec<String>>,
+    pub connector_failover_order: Option<ConnectorFailoverOrder>,
+    pub default_capture_method: Option<Option<storage_enums::CaptureMethod>>,
+    pub least_cost_routing: Option<LeastCostRouting>,
+    pub transaction_amount_limits: Option<TransactionAmountLimits>,
+    pub default_currency: Option<Option<storage_enums::Currency>>,
+    pub require_explicit_currency: Option<bool>,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = merchant_account)]
+pub struct MerchantAccountOnboardingStatusUpdate {
+    pub onboarding_status: storage_enums::OnboardingStatus,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+/// A rule of the failover order of the connectors of a merchant, which applies to the payments
+/// matching all of its conditions. A condition which is not set matches all payments.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorFailoverRule {
+    pub payment_method_type: Option<storage_enums::PaymentMethodType>,
+    pub currencies: Option<Vec<storage_enums::Currency>>,
+    pub countries: Option<Vec<storage_enums::CountryAlpha2>>,
+    /// The connectors in the order in which they are tried
+    pub connectors: Vec<storage_enums::RoutableConnectors>,
+}
+
+impl ConnectorFailoverRule {
+    fn is_applicable(
+        &self,
+        payment_method_type: Option<storage_enums::PaymentMethodType>,
+        currency: storage_enums::Currency,
+        country: Option<storage_enums::CountryAlpha2>,
+    ) -> bool {
+        self.payment_method_type
+            .map_or(true, |rule_payment_method_type| {
+                payment_method_type == Some(rule_payment_method_type)
+            })
+            && self
+                .currencies
+                .as_ref()
+                .map_or(true, |currencies| currencies.contains(&currency))
+            && self.countries.as_ref().map_or(true, |countries| {
+                country.is_some_and(|country| countries.contains(&country))
+            })
+    }
+}
+
+/// The failover order of the connectors of a merchant, as rules which are evaluated in order
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize, diesel::AsExpression,
+)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+#[serde(transparent)]
+pub struct ConnectorFailoverOrder(pub Vec<ConnectorFailoverRule>);
+
+impl ConnectorFailoverOrder {
+    /// Get the connectors in the order in which they would be tried for a payment, from the first
+    /// rule which applies to the payment. The chain is empty if no rule applies.
+    pub fn get_failover_chain(
+        &self,
+        payment_method_type: Option<storage_enums::PaymentMethodType>,
+        currency: storage_enums::Currency,
+        country: Option<storage_enums::CountryAlpha2>,
+    ) -> Vec<storage_enums::RoutableConnectors> {
+        self.0
+            .iter()
+            .find(|rule| rule.is_applicable(payment_method_type, currency, country))
+            .map(|rule| rule.connectors.clone())
+            .unwrap_or_default()
+    }
+}
+
+common_utils::impl_to_sql_from_sql_json!(ConnectorFailoverOrder);
+
+/// The fee charged by a connector for processing a payment, as a fixed fee in the minor unit of its
+/// currency and a percentage of the amount of the payment
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorFee {
+    pub fixed_fee: MinorUnit,
+    /// The currency of the fixed fee, the fee applies only to the payments in the currency. A fee
+    /// without a currency has no fixed fee, and applies to the payments in any currency.
+    pub currency: Option<storage_enums::Currency>,
+    pub percentage_fee: Percentage<CONNECTOR_FEE_PERCENTAGE_PRECISION_LENGTH>,
+}
+
+impl ConnectorFee {
+    /// Whether the fee applies to a payment in the currency, which is the case if its fixed fee is
+    /// in the currency, so that the costs of the connectors are compared in the same currency
+    fn is_applicable(&self, currency: storage_enums::Currency) -> bool {
+        self.currency
+            .map_or(true, |fee_currency| fee_currency == currency)
+    }
+
+    fn get_fee_amount(&self, amount: MinorUnit) -> CustomResult<MinorUnit, PercentageError> {
+        Ok(self.fixed_fee + self.percentage_fee.apply_and_ceil_result(amount)?)
+    }
+}
+
+/// A fee of a connector which applies to the payments matching all of its conditions. A condition
+/// which is not set matches all payments.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorFeeRule {
+    pub payment_method: Option<storage_enums::PaymentMethod>,
+    pub payment_method_type: Option<storage_enums::PaymentMethodType>,
+    pub countries: Option<Vec<storage_enums::CountryAlpha2>>,
+    pub fee: ConnectorFee,
+}
+
+impl ConnectorFeeRule {
+    fn is_applicable(
+        &self,
+        currency: storage_enums::Currency,
+        payment_method: Option<storage_enums::PaymentMethod>,
+        payment_method_type: Option<storage_enums::PaymentMethodType>,
+        country: Option<storage_enums::CountryAlpha2>,
+    ) -> bool {
+        self.fee.is_applicable(currency)
+            && self.payment_method.map_or(true, |rule_payment_method| {
+                payment_method == Some(rule_payment_method)
+            })
+            && self
+                .payment_method_type
+                .map_or(true, |rule_payment_method_type| {
+                    payment_method_type == Some(rule_payment_method_type)
+                })
+            && self.countries.as_ref().map_or(true, |countries| {
+                country.is_some_and(|country| countries.contains(&country))
+            })
+    }
+
+    /// The number of conditions of the rule, a rule with more conditions is more specific
+    fn get_specificity(&self) -> usize {
+        [
+            self.payment_method.is_some(),
+            self.payment_method_type.is_some(),
+            self.countries.is_some(),
+        ]
+        .into_iter()
+        .filter(|is_set| *is_set)
+        .count()
+    }
+}
+
+/// The cost model of a connector, which is the fees it charges for processing payments
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorCostModel {
+    pub connector: storage_enums::RoutableConnectors,
+    /// The fee which applies to the payments no fee rule applies to
+    pub default_fee: ConnectorFee,
+    pub fee_rules: Vec<ConnectorFeeRule>,
+}
+
+impl ConnectorCostModel {
+    /// Get the fee which applies to a payment, which is that of the most specific rule applicable
+    /// to the payment. The first of the most specific rules applies when several are applicable.
+    /// No fee applies if neither a rule nor the default fee applies to the currency of the payment.
+    fn get_fee(
+        &self,
+        currency: storage_enums::Currency,
+        payment_method: Option<storage_enums::PaymentMethod>,
+        payment_method_type: Option<storage_enums::PaymentMethodType>,
+        country: Option<storage_enums::CountryAlpha2>,
+    ) -> Option<&ConnectorFee> {
+        self.fee_rules
+            .iter()
+            .filter(|rule| {
+                rule.is_applicable(currency, payment_method, payment_method_type, country)
+            })
+            .fold(
+                None,
+                |most_specific_rule: Option<&ConnectorFeeRule>, rule| match most_specific_rule {
+                    Some(most_specific_rule)
+                        if most_specific_rule.get_specificity() >= rule.get_specificity() =>
+                    {
+                        Some(most_specific_rule)
+                    }
+                    _ => Some(rule),
+                },
+            )
+            .map(|rule| &rule.fee)
+            .or_else(|| Some(&self.default_fee).filter(|fee| fee.is_applicable(currency)))
+    }
+}
+
+/// The least cost routing of a merchant, which routes the payments to the eligible connector with
+/// the lowest estimated cost for the payment
+#[derive(
+    Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize, diesel::AsExpression,
+)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct LeastCostRouting {
+    pub enabled: bool,
+    pub connector_costs: Vec<ConnectorCostModel>,
+}
+
+impl LeastCostRouting {
+    /// Estimate the cost of processing a payment with the connector, in the currency of the
+    /// payment, from the fee of its cost model which applies to the payment. The cost is `None` if
+    /// the connector has no cost model, or no fee of the cost model applies to the currency.
+    pub fn estimate_connector_cost(
+        &self,
+        connector: storage_enums::RoutableConnectors,
+        amount: MinorUnit,
+        currency: storage_enums::Currency,
+        payment_method: Option<storage_enums::PaymentMethod>,
+        payment_method_type: Option<storage_enums::PaymentMethodType>,
+        country: Option<storage_enums::CountryAlpha2>,
+    ) -> CustomResult<Option<MinorUnit>, PercentageError> {
+        self.connector_costs
+            .iter()
+            .find(|cost_model| cost_model.connector == connector)
+            .and_then(|cost_model| {
+                cost_model.get_fee(currency, payment_method, payment_method_type, country)
+            })
+            .map(|fee| fee.get_fee_amount(amount))
+            .transpose()
+    }
+}
+
+common_utils::impl_to_sql_from_sql_json!(LeastCostRouting);
+
+/// The limits of the amount of the payments of a merchant in a currency, for a payment method type
+/// or for all payment method types if it is not set
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct TransactionAmountLimit {
+    pub currency: storage_enums::Currency,
+    pub payment_method_type: Option<storage_enums::PaymentMethodType>,
+    pub min_amount: Option<MinorUnit>,
+    pub max_amount: Option<MinorUnit>,
+}
+
+impl TransactionAmountLimit {
+    /// Whether the amount is within the limits
+    pub fn is_within_limits(&self, amount: MinorUnit) -> bool {
+        self.min_amount
+            .map_or(true, |min_amount| amount >= min_amount)
+            && self
+                .max_amount
+                .map_or(true, |max_amount| amount <= max_amount)
+    }
+}
+
+/// The limits of the amount of the payments of a merchant, per currency and payment method type
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize, diesel::AsExpression,
+)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+#[serde(transparent)]
+pub struct TransactionAmountLimits(pub Vec<TransactionAmountLimit>);
+
+impl TransactionAmountLimits {
+    /// Get the limits which apply to a payment, the limits of the payment method type of the
+    /// payment taking precedence over the limits of the currency for all payment method types
+    pub fn get_applicable_limit(
+        &self,
+        currency: storage_enums::Currency,
+        payment_method_type: Option<storage_enums::PaymentMethodType>,
+    ) -> Option<&TransactionAmountLimit> {
+        let currency_limits = self.0.iter().filter(|limit| limit.currency == currency);
+
+        payment_method_type
+            .and_then(|payment_method_type| {
+                currency_limits
+                    .clone()
+                    .find(|limit| limit.payment_method_type == Some(payment_method_type))
+            })
+            .or_else(|| {
+                currency_limits
+                    .clone()
+                    .find(|limit| limit.payment_method_type.is_none())
+            })
+    }
+}
+
+common_utils::impl_to_sql_from_sql_json!(TransactionAmountLimits);
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+
+    fn get_failover_order() -> ConnectorFailoverOrder {
+        ConnectorFailoverOrder(vec![
+            ConnectorFailoverRule {
+                payment_method_type: Some(storage_enums::PaymentMethodType::Credit),
+                currencies: Some(vec![storage_enums::Currency::EUR]),
+                countries: Some(vec![storage_enums::CountryAlpha2::DE]),
+                connectors: vec![
+                    storage_enums::RoutableConnectors::Adyen,
+                    storage_enums::RoutableConnectors::Stripe,
+                ],
+            },
+            ConnectorFailoverRule {
+                payment_method_type: None,
+                currencies: Some(vec![storage_enums::Currency::USD]),
+                countries: None,
+                connectors: vec![
+                    storage_enums::RoutableConnectors::Stripe,
+                    storage_enums::RoutableConnectors::Checkout,
+                ],
+            },
+        ])
+    }
+
+    #[test]
+    fn test_failover_chain_is_taken_from_the_first_applicable_rule() {
+        assert_eq!(
+            get_failover_order().get_failover_chain(
+                Some(storage_enums::PaymentMethodType::Credit),
+                storage_enums::Currency::EUR,
+                Some(storage_enums::CountryAlpha2::DE),
+            ),
+            vec![
+                storage_enums::RoutableConnectors::Adyen,
+                storage_enums::RoutableConnectors::Stripe,
+            ]
+        );
+        assert_eq!(
+            get_failover_order().get_failover_chain(
+                Some(storage_enums::PaymentMethodType::Credit),
+                storage_enums::Currency::USD,
+                None,
+            ),
+            vec![
+                storage_enums::RoutableConnectors::Stripe,
+                storage_enums::RoutableConnectors::Checkout,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_payment_method_type_limit_takes_precedence_over_currency_limit() {
+        let transaction_amount_limits = TransactionAmountLimits(vec![
+            TransactionAmountLimit {
+                currency: storage_enums::Currency::USD,
+                payment_method_type: None,
+                min_amount: Some(MinorUnit::new(100)),
+                max_amount: Some(MinorUnit::new(1_000_000)),
+            },
+            TransactionAmountLimit {
+                currency: storage_enums::Currency::USD,
+                payment_method_type: Some(storage_enums::PaymentMethodType::Ach),
+                min_amount: None,
+                max_amount: Some(MinorUnit::new(5_000_000)),
+            },
+        ]);
+
+        let card_limit = transaction_amount_limits
+            .get_applicable_limit(
+                storage_enums::Currency::USD,
+                Some(storage_enums::PaymentMethodType::Credit),
+            )
+            .expect("currency limit");
+        assert!(!card_limit.is_within_limits(MinorUnit::new(99)));
+        assert!(!card_limit.is_within_limits(MinorUnit::new(2_000_000)));
+        assert!(card_limit.is_within_limits(MinorUnit::new(100)));
+
+        let ach_limit = transaction_amount_limits
+            .get_applicable_limit(
+                storage_enums::Currency::USD,
+                Some(storage_enums::PaymentMethodType::Ach),
+            )
+            .expect("payment method type limit");
+        assert!(ach_limit.is_within_limits(MinorUnit::new(2_000_000)));
+
+        assert!(transaction_amount_limits
+            .get_applicable_limit(storage_enums::Currency::EUR, None)
+            .is_none());
+    }
+
+    fn get_connector_fee(fixed_fee: i64, percentage_fee: &str) -> ConnectorFee {
+        ConnectorFee {
+            fixed_fee: MinorUnit::new(fixed_fee),
+            currency: Some(storage_enums::Currency::USD),
+            percentage_fee: Percentage::from_string(percentage_fee.to_string())
+                .expect("valid percentage"),
+        }
+    }
+
+    fn get_least_cost_routing() -> LeastCostRouting {
+        LeastCostRouting {
+            enabled: true,
+            connector_costs: vec![ConnectorCostModel {
+                connector: storage_enums::RoutableConnectors::Stripe,
+                default_fee: get_connector_fee(30, "2.75"),
+                fee_rules: vec![
+                    ConnectorFeeRule {
+                        payment_method: Some(storage_enums::PaymentMethod::Card),
+                        payment_method_type: None,
+                        countries: None,
+                        fee: get_connector_fee(25, "2.5"),
+                    },
+                    ConnectorFeeRule {
+                        payment_method: Some(storage_enums::PaymentMethod::Card),
+                        payment_method_type: None,
+                        countries: Some(vec![storage_enums::CountryAlpha2::DE]),
+                        fee: get_connector_fee(25, "1.5"),
+                    },
+                ],
+            }],
+        }
+    }
+
+    #[test]
+    fn test_connector_cost_is_estimated_from_the_most_specific_applicable_fee() {
+        let least_cost_routing = get_least_cost_routing();
+        let estimate = |payment_method, country| {
+            least_cost_routing
+                .estimate_connector_cost(
+                    storage_enums::RoutableConnectors::Stripe,
+                    MinorUnit::new(10000),
+                    storage_enums::Currency::USD,
+                    payment_method,
+                    None,
+                    country,
+                )
+                .expect("cost estimation")
+        };
+
+        assert_eq!(
+            estimate(
+                Some(storage_enums::PaymentMethod::Card),
+                Some(storage_enums::CountryAlpha2::DE)
+            ),
+            Some(MinorUnit::new(175))
+        );
+        assert_eq!(
+            estimate(
+                Some(storage_enums::PaymentMethod::Card),
+                Some(storage_enums::CountryAlpha2::US)
+            ),
+            Some(MinorUnit::new(275))
+        );
+        assert_eq!(
+            estimate(Some(storage_enums::PaymentMethod::Wallet), None),
+            Some(MinorUnit::new(305))
+        );
+    }
+
+    #[test]
+    fn test_connector_cost_is_not_estimated_without_cost_model() {
+        assert_eq!(
+            get_least_cost_routing()
+                .estimate_connector_cost(
+                    storage_enums::RoutableConnectors::Adyen,
+                    MinorUnit::new(10000),
+                    storage_enums::Currency::USD,
+                    Some(storage_enums::PaymentMethod::Card),
+                    None,
+                    None,
+                )
+                .expect("cost estimation"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_connector_cost_is_estimated_from_the_fees_in_the_currency_of_the_payment() {
+        let mut least_cost_routing = get_least_cost_routing();
+        let estimate = |least_cost_routing: &LeastCostRouting| {
+            least_cost_routing
+                .estimate_connector_cost(
+                    storage_enums::RoutableConnectors::Stripe,
+                    MinorUnit::new(10000),
+                    storage_enums::Currency::EUR,
+                    Some(storage_enums::PaymentMethod::Card),
+                    None,
+                    Some(storage_enums::CountryAlpha2::DE),
+                )
+                .expect("cost estimation")
+        };
+
+        // The fixed fees in USD are not compared to the amount of a payment in EUR
+        assert_eq!(estimate(&least_cost_routing), None);
+
+        if let Some(cost_model) = least_cost_routing.connector_costs.first_mut() {
+            cost_model.fee_rules.push(ConnectorFeeRule {
+                payment_method: Some(storage_enums::PaymentMethod::Card),
+                payment_method_type: None,
+                countries: None,
+                fee: ConnectorFee {
+                    fixed_fee: MinorUnit::new(20),
+                    currency: Some(storage_enums::Currency::EUR),
+                    percentage_fee: Percentage::from_string("2".to_string())
+                        .expect("valid percentage"),
+                },
+            });
+        }
+        assert_eq!(estimate(&least_cost_routing), Some(MinorUnit::new(220)));
+    }
+
+    #[test]
+    fn test_rule_with_countries_does_not_apply_without_country() {
+        assert!(get_failover_order()
+            .get_failover_chain(
+                Some(storage_enums::PaymentMethodType::Credit),
+                storage_enums::Currency::EUR,
+                None,
+            )
+            .is_empty());
+    }
 }
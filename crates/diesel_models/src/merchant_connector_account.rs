@@ -50,6 +50,13 @@ pub struct MerchantConnectorAccount {
     pub additional_merchant_data: Option<Encryption>,
     pub connector_wallets_details: Option<Encryption>,
     pub version: common_enums::ApiVersion,
+    pub request_timeout_secs: Option<i16>,
+    pub webhook_identifier: Option<String>,
+    pub environment: Option<common_enums::ConnectorEnvironment>,
+    pub maintenance_windows: Option<ConnectorMaintenanceWindows>,
+    /// The credentials which are to replace the connector account details once validated
+    pub pending_connector_account_details: Option<Encryption>,
+    pub credential_rotation_status: Option<common_enums::ConnectorCredentialRotationStatus>,
 }
 
 #[cfg(feature = "v1")]
@@ -94,6 +101,13 @@ pub struct MerchantConnectorAccount {
     pub additional_merchant_data: Option<Encryption>,
     pub connector_wallets_details: Option<Encryption>,
     pub version: common_enums::ApiVersion,
+    pub request_timeout_secs: Option<i16>,
+    pub webhook_identifier: Option<String>,
+    pub environment: Option<common_enums::ConnectorEnvironment>,
+    pub maintenance_windows: Option<ConnectorMaintenanceWindows>,
+    /// The credentials which are to replace the connector account details once validated
+    pub pending_connector_account_details: Option<Encryption>,
+    pub credential_rotation_status: Option<common_enums::ConnectorCredentialRotationStatus>,
     pub id: id_type::MerchantConnectorAccountId,
 }
 
@@ -135,6 +149,12 @@ pub struct MerchantConnectorAccountNew {
     pub additional_merchant_data: Option<Encryption>,
     pub connector_wallets_details: Option<Encryption>,
     pub version: common_enums::ApiVersion,
+    pub request_timeout_secs: Option<i16>,
+    pub webhook_identifier: Option<String>,
+    pub environment: Option<common_enums::ConnectorEnvironment>,
+    pub maintenance_windows: Option<ConnectorMaintenanceWindows>,
+    pub pending_connector_account_details: Option<Encryption>,
+    pub credential_rotation_status: Option<common_enums::ConnectorCredentialRotationStatus>,
 }
 
 #[cfg(feature = "v2")]
@@ -161,6 +181,12 @@ pub struct MerchantConnectorAccountNew {
     pub status: storage_enums::ConnectorStatus,
     pub additional_merchant_data: Option<Encryption>,
     pub connector_wallets_details: Option<Encryption>,
+    pub request_timeout_secs: Option<i16>,
+    pub webhook_identifier: Option<String>,
+    pub environment: Option<common_enums::ConnectorEnvironment>,
+    pub maintenance_windows: Option<ConnectorMaintenanceWindows>,
+    pub pending_connector_account_details: Option<Encryption>,
+    pub credential_rotation_status: Option<common_enums::ConnectorCredentialRotationStatus>,
     pub id: id_type::MerchantConnectorAccountId,
     pub version: common_enums::ApiVersion,
 }
@@ -189,6 +215,12 @@ pub struct MerchantConnectorAccountUpdateInternal {
     pub status: Option<storage_enums::ConnectorStatus>,
     pub connector_wallets_details: Option<Encryption>,
     pub additional_merchant_data: Option<Encryption>,
+    pub request_timeout_secs: Option<i16>,
+    pub webhook_identifier: Option<String>,
+    pub environment: Option<common_enums::ConnectorEnvironment>,
+    pub maintenance_windows: Option<ConnectorMaintenanceWindows>,
+    pub pending_connector_account_details: Option<Option<Encryption>>,
+    pub credential_rotation_status: Option<common_enums::ConnectorCredentialRotationStatus>,
 }
 
 #[cfg(feature = "v2")]
@@ -211,6 +243,12 @@ pub struct MerchantConnectorAccountUpdateInternal {
     pub status: Option<storage_enums::ConnectorStatus>,
     pub connector_wallets_details: Option<Encryption>,
     pub additional_merchant_data: Option<Encryption>,
+    pub request_timeout_secs: Option<i16>,
+    pub webhook_identifier: Option<String>,
+    pub environment: Option<common_enums::ConnectorEnvironment>,
+    pub maintenance_windows: Option<ConnectorMaintenanceWindows>,
+    pub pending_connector_account_details: Option<Option<Encryption>>,
+    pub credential_rotation_status: Option<common_enums::ConnectorCredentialRotationStatus>,
 }
 
 #[cfg(feature = "v1")]
@@ -235,6 +273,16 @@ impl MerchantConnectorAccountUpdateInternal {
             modified_at: self.modified_at.unwrap_or(source.modified_at),
             pm_auth_config: self.pm_auth_config,
             status: self.status.unwrap_or(source.status),
+            request_timeout_secs: self.request_timeout_secs.or(source.request_timeout_secs),
+            webhook_identifier: self.webhook_identifier.or(source.webhook_identifier),
+            environment: self.environment.or(source.environment),
+            maintenance_windows: self.maintenance_windows.or(source.maintenance_windows),
+            pending_connector_account_details: self
+                .pending_connector_account_details
+                .unwrap_or(source.pending_connector_account_details),
+            credential_rotation_status: self
+                .credential_rotation_status
+                .or(source.credential_rotation_status),
 
             ..source
         }
@@ -258,8 +306,143 @@ impl MerchantConnectorAccountUpdateInternal {
             modified_at: self.modified_at.unwrap_or(source.modified_at),
             pm_auth_config: self.pm_auth_config,
             status: self.status.unwrap_or(source.status),
+            request_timeout_secs: self.request_timeout_secs.or(source.request_timeout_secs),
+            webhook_identifier: self.webhook_identifier.or(source.webhook_identifier),
+            environment: self.environment.or(source.environment),
+            maintenance_windows: self.maintenance_windows.or(source.maintenance_windows),
+            pending_connector_account_details: self
+                .pending_connector_account_details
+                .unwrap_or(source.pending_connector_account_details),
+            credential_rotation_status: self
+                .credential_rotation_status
+                .or(source.credential_rotation_status),
 
             ..source
         }
     }
 }
+
+/// A window in which the connector is under maintenance. For a recurring window, the start and
+/// end times are those of its first occurrence
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorMaintenanceWindow {
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub start_time: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub end_time: time::PrimitiveDateTime,
+    pub recurrence: Option<common_enums::MaintenanceWindowRecurrence>,
+}
+
+impl ConnectorMaintenanceWindow {
+    /// Check whether the time falls in any occurrence of the maintenance window
+    pub fn is_active_at(&self, time: time::PrimitiveDateTime) -> bool {
+        if time < self.start_time {
+            return false;
+        }
+
+        let Some(recurrence) = self.recurrence else {
+            return time < self.end_time;
+        };
+        let interval = get_maintenance_window_recurrence_interval(recurrence);
+        let elapsed_in_occurrence = (time - self.start_time)
+            .whole_seconds()
+            .rem_euclid(interval.whole_seconds());
+
+        elapsed_in_occurrence < (self.end_time - self.start_time).whole_seconds()
+    }
+}
+
+/// Get the interval between the starts of consecutive occurrences of a recurring maintenance window
+pub fn get_maintenance_window_recurrence_interval(
+    recurrence: common_enums::MaintenanceWindowRecurrence,
+) -> time::Duration {
+    match recurrence {
+        common_enums::MaintenanceWindowRecurrence::Daily => time::Duration::DAY,
+        common_enums::MaintenanceWindowRecurrence::Weekly => time::Duration::WEEK,
+    }
+}
+
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize, diesel::AsExpression,
+)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+#[serde(transparent)]
+pub struct ConnectorMaintenanceWindows(pub Vec<ConnectorMaintenanceWindow>);
+
+impl ConnectorMaintenanceWindows {
+    /// Check whether the time falls in any of the maintenance windows, which may overlap
+    pub fn is_in_maintenance_at(&self, time: time::PrimitiveDateTime) -> bool {
+        self.0.iter().any(|window| window.is_active_at(time))
+    }
+}
+
+common_utils::impl_to_sql_from_sql_json!(ConnectorMaintenanceWindows);
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    /// A time on a day of November 2024, the 4th of which is a Monday
+    fn get_time(day: u8, hour: u8) -> time::PrimitiveDateTime {
+        time::Date::from_calendar_date(2024, time::Month::November, day)
+            .unwrap()
+            .with_hms(hour, 0, 0)
+            .unwrap()
+    }
+
+    fn get_window(
+        start_time: time::PrimitiveDateTime,
+        end_time: time::PrimitiveDateTime,
+        recurrence: Option<common_enums::MaintenanceWindowRecurrence>,
+    ) -> ConnectorMaintenanceWindow {
+        ConnectorMaintenanceWindow {
+            start_time,
+            end_time,
+            recurrence,
+        }
+    }
+
+    #[test]
+    fn test_one_time_maintenance_window_is_active_only_between_its_start_and_end() {
+        let window = get_window(get_time(4, 2), get_time(4, 4), None);
+
+        assert!(!window.is_active_at(get_time(4, 1)));
+        assert!(window.is_active_at(get_time(4, 2)));
+        assert!(window.is_active_at(get_time(4, 3)));
+        assert!(!window.is_active_at(get_time(4, 4)));
+        assert!(!window.is_active_at(get_time(5, 3)));
+    }
+
+    #[test]
+    fn test_recurring_maintenance_window_is_active_in_every_occurrence() {
+        let daily_window = get_window(
+            get_time(4, 2),
+            get_time(4, 4),
+            Some(common_enums::MaintenanceWindowRecurrence::Daily),
+        );
+        assert!(!daily_window.is_active_at(get_time(3, 3)));
+        assert!(daily_window.is_active_at(get_time(5, 3)));
+        assert!(!daily_window.is_active_at(get_time(5, 4)));
+
+        let weekly_window = get_window(
+            get_time(4, 2),
+            get_time(4, 4),
+            Some(common_enums::MaintenanceWindowRecurrence::Weekly),
+        );
+        assert!(!weekly_window.is_active_at(get_time(5, 3)));
+        assert!(weekly_window.is_active_at(get_time(11, 3)));
+        assert!(!weekly_window.is_active_at(get_time(11, 5)));
+    }
+
+    #[test]
+    fn test_connector_is_not_in_maintenance_once_its_windows_are_removed() {
+        let time = get_time(4, 3);
+        let maintenance_windows =
+            ConnectorMaintenanceWindows(vec![get_window(get_time(4, 2), get_time(4, 4), None)]);
+        assert!(maintenance_windows.is_in_maintenance_at(time));
+
+        // The windows are removed by updating the connector account with an empty list
+        assert!(!ConnectorMaintenanceWindows::default().is_in_maintenance_at(time));
+    }
+}
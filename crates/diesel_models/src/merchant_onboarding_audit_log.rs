@@ -0,0 +1,28 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{enums as storage_enums, schema::merchant_onboarding_audit_log};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = merchant_onboarding_audit_log)]
+pub struct MerchantOnboardingAuditLogNew {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub previous_status: storage_enums::OnboardingStatus,
+    pub new_status: storage_enums::OnboardingStatus,
+    pub changed_by: String,
+    pub reason: Option<String>,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(table_name = merchant_onboarding_audit_log, check_for_backend(diesel::pg::Pg))]
+pub struct MerchantOnboardingAuditLog {
+    #[serde(skip)]
+    pub id: i32,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub previous_status: storage_enums::OnboardingStatus,
+    pub new_status: storage_enums::OnboardingStatus,
+    pub changed_by: String,
+    pub reason: Option<String>,
+    pub created_at: time::PrimitiveDateTime,
+}
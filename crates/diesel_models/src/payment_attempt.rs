@@ -72,6 +72,15 @@ pub struct PaymentAttempt {
     pub id: String,
     pub shipping_cost: Option<MinorUnit>,
     pub order_tax_amount: Option<MinorUnit>,
+    pub fraud_score: Option<i32>,
+    pub fraud_decision: Option<storage_enums::FraudDecision>,
+    pub approved_amount: Option<MinorUnit>,
+    pub expected_settlement_date: Option<time::Date>,
+    pub card_hash: Option<String>,
+    pub retry_advice: Option<common_utils::types::RetryAdvice>,
+    pub tags: Option<common_utils::types::PaymentAttemptTags>,
+    pub is_connector_override: Option<bool>,
+    pub stepped_up_from_attempt_id: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -148,6 +157,15 @@ pub struct PaymentAttempt {
     pub card_network: Option<String>,
     pub shipping_cost: Option<MinorUnit>,
     pub order_tax_amount: Option<MinorUnit>,
+    pub fraud_score: Option<i32>,
+    pub fraud_decision: Option<storage_enums::FraudDecision>,
+    pub approved_amount: Option<MinorUnit>,
+    pub expected_settlement_date: Option<time::Date>,
+    pub card_hash: Option<String>,
+    pub retry_advice: Option<common_utils::types::RetryAdvice>,
+    pub tags: Option<common_utils::types::PaymentAttemptTags>,
+    pub is_connector_override: Option<bool>,
+    pub stepped_up_from_attempt_id: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -224,6 +242,15 @@ pub struct PaymentAttemptNew {
     pub card_network: Option<String>,
     pub shipping_cost: Option<MinorUnit>,
     pub order_tax_amount: Option<MinorUnit>,
+    pub fraud_score: Option<i32>,
+    pub fraud_decision: Option<storage_enums::FraudDecision>,
+    pub approved_amount: Option<MinorUnit>,
+    pub expected_settlement_date: Option<time::Date>,
+    pub card_hash: Option<String>,
+    pub retry_advice: Option<common_utils::types::RetryAdvice>,
+    pub tags: Option<common_utils::types::PaymentAttemptTags>,
+    pub is_connector_override: Option<bool>,
+    pub stepped_up_from_attempt_id: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -296,6 +323,15 @@ pub struct PaymentAttemptNew {
     pub card_network: Option<String>,
     pub shipping_cost: Option<MinorUnit>,
     pub order_tax_amount: Option<MinorUnit>,
+    pub fraud_score: Option<i32>,
+    pub fraud_decision: Option<storage_enums::FraudDecision>,
+    pub approved_amount: Option<MinorUnit>,
+    pub expected_settlement_date: Option<time::Date>,
+    pub card_hash: Option<String>,
+    pub retry_advice: Option<common_utils::types::RetryAdvice>,
+    pub tags: Option<common_utils::types::PaymentAttemptTags>,
+    pub is_connector_override: Option<bool>,
+    pub stepped_up_from_attempt_id: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -370,6 +406,7 @@ pub enum PaymentAttemptUpdate {
         tax_amount: Option<MinorUnit>,
         updated_by: String,
         merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+        is_connector_override: Option<bool>,
     },
     AuthenticationTypeUpdate {
         authentication_type: storage_enums::AuthenticationType,
@@ -408,6 +445,8 @@ pub enum PaymentAttemptUpdate {
         customer_acceptance: Option<pii::SecretSerdeValue>,
         shipping_cost: Option<MinorUnit>,
         order_tax_amount: Option<MinorUnit>,
+        card_hash: Option<String>,
+        is_connector_override: Option<bool>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -451,6 +490,8 @@ pub enum PaymentAttemptUpdate {
         unified_message: Option<Option<String>>,
         payment_method_data: Option<serde_json::Value>,
         charge_id: Option<String>,
+        approved_amount: Option<MinorUnit>,
+        expected_settlement_date: Option<time::Date>,
     },
     UnresolvedResponseUpdate {
         status: storage_enums::AttemptStatus,
@@ -480,6 +521,7 @@ pub enum PaymentAttemptUpdate {
         connector_transaction_id: Option<String>,
         payment_method_data: Option<serde_json::Value>,
         authentication_type: Option<storage_enums::AuthenticationType>,
+        retry_advice: Option<common_utils::types::RetryAdvice>,
     },
     CaptureUpdate {
         amount_to_capture: Option<MinorUnit>,
@@ -529,6 +571,11 @@ pub enum PaymentAttemptUpdate {
         unified_message: Option<String>,
         connector_transaction_id: Option<String>,
     },
+    FraudCheckUpdate {
+        fraud_score: Option<i32>,
+        fraud_decision: Option<storage_enums::FraudDecision>,
+        updated_by: String,
+    },
 }
 
 #[cfg(feature = "v2")]
@@ -816,6 +863,13 @@ pub struct PaymentAttemptUpdateInternal {
     pub card_network: Option<String>,
     pub shipping_cost: Option<MinorUnit>,
     pub order_tax_amount: Option<MinorUnit>,
+    pub fraud_score: Option<i32>,
+    pub fraud_decision: Option<storage_enums::FraudDecision>,
+    pub approved_amount: Option<MinorUnit>,
+    pub expected_settlement_date: Option<time::Date>,
+    pub card_hash: Option<String>,
+    pub retry_advice: Option<common_utils::types::RetryAdvice>,
+    pub is_connector_override: Option<bool>,
 }
 
 #[cfg(feature = "v2")]
@@ -1004,6 +1058,13 @@ impl PaymentAttemptUpdate {
             card_network,
             shipping_cost,
             order_tax_amount,
+            fraud_score,
+            fraud_decision,
+            approved_amount,
+            expected_settlement_date,
+            card_hash,
+            retry_advice,
+            is_connector_override,
         } = PaymentAttemptUpdateInternal::from(self).populate_derived_fields(&source);
         PaymentAttempt {
             amount: amount.unwrap_or(source.amount),
@@ -1059,6 +1120,13 @@ impl PaymentAttemptUpdate {
             card_network: card_network.or(source.card_network),
             shipping_cost: shipping_cost.or(source.shipping_cost),
             order_tax_amount: order_tax_amount.or(source.order_tax_amount),
+            fraud_score: fraud_score.or(source.fraud_score),
+            fraud_decision: fraud_decision.or(source.fraud_decision),
+            approved_amount: approved_amount.or(source.approved_amount),
+            expected_settlement_date: expected_settlement_date.or(source.expected_settlement_date),
+            card_hash: card_hash.or(source.card_hash),
+            retry_advice: retry_advice.or(source.retry_advice),
+            is_connector_override: is_connector_override.or(source.is_connector_override),
             ..source
         }
     }
@@ -2053,6 +2121,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                fraud_score: None,
+                fraud_decision: None,
+                approved_amount: None,
+                expected_settlement_date: None,
+                card_hash: None,
+                retry_advice: None,
+                is_connector_override: None,
             },
             PaymentAttemptUpdate::AuthenticationTypeUpdate {
                 authentication_type,
@@ -2107,6 +2182,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                fraud_score: None,
+                fraud_decision: None,
+                approved_amount: None,
+                expected_settlement_date: None,
+                card_hash: None,
+                retry_advice: None,
+                is_connector_override: None,
             },
             PaymentAttemptUpdate::ConfirmUpdate {
                 amount,
@@ -2141,6 +2223,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 customer_acceptance,
                 shipping_cost,
                 order_tax_amount,
+                card_hash,
+                is_connector_override,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -2191,6 +2275,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost,
                 order_tax_amount,
+                fraud_score: None,
+                fraud_decision: None,
+                approved_amount: None,
+                expected_settlement_date: None,
+                card_hash,
+                retry_advice: None,
+                is_connector_override,
             },
             PaymentAttemptUpdate::VoidUpdate {
                 status,
@@ -2246,6 +2337,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                fraud_score: None,
+                fraud_decision: None,
+                approved_amount: None,
+                expected_settlement_date: None,
+                card_hash: None,
+                retry_advice: None,
+                is_connector_override: None,
             },
             PaymentAttemptUpdate::RejectUpdate {
                 status,
@@ -2302,6 +2400,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                fraud_score: None,
+                fraud_decision: None,
+                approved_amount: None,
+                expected_settlement_date: None,
+                card_hash: None,
+                retry_advice: None,
+                is_connector_override: None,
             },
             PaymentAttemptUpdate::BlocklistUpdate {
                 status,
@@ -2358,6 +2463,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                fraud_score: None,
+                fraud_decision: None,
+                approved_amount: None,
+                expected_settlement_date: None,
+                card_hash: None,
+                retry_advice: None,
+                is_connector_override: None,
             },
             PaymentAttemptUpdate::PaymentMethodDetailsUpdate {
                 payment_method_id,
@@ -2412,6 +2524,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                fraud_score: None,
+                fraud_decision: None,
+                approved_amount: None,
+                expected_settlement_date: None,
+                card_hash: None,
+                retry_advice: None,
+                is_connector_override: None,
             },
             PaymentAttemptUpdate::ResponseUpdate {
                 status,
@@ -2434,6 +2553,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 unified_message,
                 payment_method_data,
                 charge_id,
+                approved_amount,
+                expected_settlement_date,
             } => Self {
                 status: Some(status),
                 connector: connector.map(Some),
@@ -2484,6 +2605,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                fraud_score: None,
+                fraud_decision: None,
+                approved_amount,
+                expected_settlement_date,
+                card_hash: None,
+                retry_advice: None,
+                is_connector_override: None,
             },
             PaymentAttemptUpdate::ErrorUpdate {
                 connector,
@@ -2498,6 +2626,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 connector_transaction_id,
                 payment_method_data,
                 authentication_type,
+                retry_advice,
             } => Self {
                 connector: connector.map(Some),
                 status: Some(status),
@@ -2548,6 +2677,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                fraud_score: None,
+                fraud_decision: None,
+                approved_amount: None,
+                expected_settlement_date: None,
+                card_hash: None,
+                retry_advice,
+                is_connector_override: None,
             },
             PaymentAttemptUpdate::StatusUpdate { status, updated_by } => Self {
                 status: Some(status),
@@ -2599,6 +2735,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                fraud_score: None,
+                fraud_decision: None,
+                approved_amount: None,
+                expected_settlement_date: None,
+                card_hash: None,
+                retry_advice: None,
+                is_connector_override: None,
             },
             PaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
@@ -2609,6 +2752,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 tax_amount,
                 updated_by,
                 merchant_connector_id,
+                is_connector_override,
             } => Self {
                 payment_token,
                 modified_at: common_utils::date_time::now(),
@@ -2659,6 +2803,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                fraud_score: None,
+                fraud_decision: None,
+                approved_amount: None,
+                expected_settlement_date: None,
+                card_hash: None,
+                retry_advice: None,
+                is_connector_override,
             },
             PaymentAttemptUpdate::UnresolvedResponseUpdate {
                 status,
@@ -2720,6 +2871,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                fraud_score: None,
+                fraud_decision: None,
+                approved_amount: None,
+                expected_settlement_date: None,
+                card_hash: None,
+                retry_advice: None,
+                is_connector_override: None,
             },
             PaymentAttemptUpdate::PreprocessingUpdate {
                 status,
@@ -2779,6 +2937,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                fraud_score: None,
+                fraud_decision: None,
+                approved_amount: None,
+                expected_settlement_date: None,
+                card_hash: None,
+                retry_advice: None,
+                is_connector_override: None,
             },
             PaymentAttemptUpdate::CaptureUpdate {
                 multiple_capture_count,
@@ -2834,6 +2999,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                fraud_score: None,
+                fraud_decision: None,
+                approved_amount: None,
+                expected_settlement_date: None,
+                card_hash: None,
+                retry_advice: None,
+                is_connector_override: None,
             },
             PaymentAttemptUpdate::AmountToCaptureUpdate {
                 status,
@@ -2889,6 +3061,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                fraud_score: None,
+                fraud_decision: None,
+                approved_amount: None,
+                expected_settlement_date: None,
+                card_hash: None,
+                retry_advice: None,
+                is_connector_override: None,
             },
             PaymentAttemptUpdate::ConnectorResponse {
                 authentication_data,
@@ -2947,6 +3126,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                fraud_score: None,
+                fraud_decision: None,
+                approved_amount: None,
+                expected_settlement_date: None,
+                card_hash: None,
+                retry_advice: None,
+                is_connector_override: None,
             },
             PaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
                 amount,
@@ -3001,6 +3187,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                fraud_score: None,
+                fraud_decision: None,
+                approved_amount: None,
+                expected_settlement_date: None,
+                card_hash: None,
+                retry_advice: None,
+                is_connector_override: None,
             },
             PaymentAttemptUpdate::AuthenticationUpdate {
                 status,
@@ -3058,6 +3251,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                fraud_score: None,
+                fraud_decision: None,
+                approved_amount: None,
+                expected_settlement_date: None,
+                card_hash: None,
+                retry_advice: None,
+                is_connector_override: None,
             },
             PaymentAttemptUpdate::ManualUpdate {
                 status,
@@ -3118,6 +3318,75 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 card_network: None,
                 shipping_cost: None,
                 order_tax_amount: None,
+                fraud_score: None,
+                fraud_decision: None,
+                approved_amount: None,
+                expected_settlement_date: None,
+                card_hash: None,
+                retry_advice: None,
+                is_connector_override: None,
+            },
+            PaymentAttemptUpdate::FraudCheckUpdate {
+                fraud_score,
+                fraud_decision,
+                updated_by,
+            } => Self {
+                fraud_score,
+                fraud_decision,
+                approved_amount: None,
+                expected_settlement_date: None,
+                card_hash: None,
+                retry_advice: None,
+                is_connector_override: None,
+                modified_at: common_utils::date_time::now(),
+                updated_by,
+                amount: None,
+                net_amount: None,
+                currency: None,
+                status: None,
+                connector_transaction_id: None,
+                amount_to_capture: None,
+                connector: None,
+                authentication_type: None,
+                payment_method: None,
+                error_message: None,
+                payment_method_id: None,
+                cancellation_reason: None,
+                mandate_id: None,
+                browser_info: None,
+                payment_token: None,
+                error_code: None,
+                connector_metadata: None,
+                payment_method_data: None,
+                payment_method_type: None,
+                payment_experience: None,
+                business_sub_label: None,
+                straight_through_algorithm: None,
+                preprocessing_step_id: None,
+                error_reason: None,
+                capture_method: None,
+                connector_response_reference_id: None,
+                multiple_capture_count: None,
+                surcharge_amount: None,
+                tax_amount: None,
+                amount_capturable: None,
+                merchant_connector_id: None,
+                authentication_data: None,
+                encoded_data: None,
+                unified_code: None,
+                unified_message: None,
+                external_three_ds_authentication_attempted: None,
+                authentication_connector: None,
+                authentication_id: None,
+                fingerprint_id: None,
+                payment_method_billing_address_id: None,
+                charge_id: None,
+                client_source: None,
+                client_version: None,
+                customer_acceptance: None,
+                card_network: None,
+                shipping_cost: None,
+                order_tax_amount: None,
             },
         }
     }
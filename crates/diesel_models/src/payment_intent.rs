@@ -1,5 +1,10 @@
 use common_enums::{PaymentMethodType, RequestIncrementalAuthorization};
-use common_utils::{encryption::Encryption, pii, types::MinorUnit};
+use common_utils::{
+    consts::AMOUNT_BREAKDOWN_TAX_RATE_PRECISION_LENGTH,
+    encryption::Encryption,
+    pii,
+    types::{MinorUnit, Percentage},
+};
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
@@ -134,6 +139,12 @@ pub struct PaymentIntent {
     pub organization_id: common_utils::id_type::OrganizationId,
     pub tax_details: Option<TaxDetails>,
     pub skip_external_tax_calculation: Option<bool>,
+    pub amount_breakdown: Option<AmountBreakdown>,
+    pub parent_payment_id: Option<common_utils::id_type::PaymentId>,
+    pub root_payment_id: Option<common_utils::id_type::PaymentId>,
+    pub fulfillment_status: Option<storage_enums::FulfillmentStatus>,
+    pub shipped_at: Option<PrimitiveDateTime>,
+    pub delivered_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, PartialEq)]
@@ -180,6 +191,28 @@ pub struct TransactionDetailsUiConfiguration {
 
 common_utils::impl_to_sql_from_sql_json!(TransactionDetailsUiConfiguration);
 
+/// Breakdown of the amount of the payment into its components, with the tax amount reconciled
+/// against the amount
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct AmountBreakdown {
+    pub subtotal: MinorUnit,
+    pub tax_amount: MinorUnit,
+    pub tax_rate: Option<Percentage<AMOUNT_BREAKDOWN_TAX_RATE_PRECISION_LENGTH>>,
+    pub shipping_amount: Option<MinorUnit>,
+    pub discount_amount: Option<MinorUnit>,
+}
+
+impl AmountBreakdown {
+    /// The sum of the components of the breakdown
+    pub fn get_total(&self) -> MinorUnit {
+        self.subtotal + self.tax_amount + self.shipping_amount.unwrap_or_default()
+            - self.discount_amount.unwrap_or_default()
+    }
+}
+
+common_utils::impl_to_sql_from_sql_json!(AmountBreakdown);
+
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize, diesel::AsExpression)]
 #[diesel(sql_type = diesel::sql_types::Jsonb)]
 pub struct TaxDetails {
@@ -328,6 +361,12 @@ pub struct PaymentIntentNew {
     pub organization_id: common_utils::id_type::OrganizationId,
     pub tax_details: Option<TaxDetails>,
     pub skip_external_tax_calculation: Option<bool>,
+    pub amount_breakdown: Option<AmountBreakdown>,
+    pub parent_payment_id: Option<common_utils::id_type::PaymentId>,
+    pub root_payment_id: Option<common_utils::id_type::PaymentId>,
+    pub fulfillment_status: Option<storage_enums::FulfillmentStatus>,
+    pub shipped_at: Option<PrimitiveDateTime>,
+    pub delivered_at: Option<PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v2")]
@@ -489,6 +528,12 @@ pub enum PaymentIntentUpdate {
         updated_by: String,
         shipping_details: Option<Encryption>,
     },
+    FulfillmentUpdate {
+        fulfillment_status: storage_enums::FulfillmentStatus,
+        shipped_at: Option<PrimitiveDateTime>,
+        delivered_at: Option<PrimitiveDateTime>,
+        updated_by: String,
+    },
 }
 
 #[cfg(feature = "v2")]
@@ -620,6 +665,9 @@ pub struct PaymentIntentUpdateInternal {
     pub shipping_details: Option<Encryption>,
     pub is_payment_processor_token_flow: Option<bool>,
     pub tax_details: Option<TaxDetails>,
+    pub fulfillment_status: Option<storage_enums::FulfillmentStatus>,
+    pub shipped_at: Option<PrimitiveDateTime>,
+    pub delivered_at: Option<PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v2")]
@@ -733,6 +781,9 @@ impl PaymentIntentUpdate {
             shipping_details,
             is_payment_processor_token_flow,
             tax_details,
+            fulfillment_status,
+            shipped_at,
+            delivered_at,
         } = self.into();
         PaymentIntent {
             amount: amount.unwrap_or(source.amount),
@@ -778,6 +829,9 @@ impl PaymentIntentUpdate {
             is_payment_processor_token_flow: is_payment_processor_token_flow
                 .or(source.is_payment_processor_token_flow),
             tax_details: tax_details.or(source.tax_details),
+            fulfillment_status: fulfillment_status.or(source.fulfillment_status),
+            shipped_at: shipped_at.or(source.shipped_at),
+            delivered_at: delivered_at.or(source.delivered_at),
             ..source
         }
     }
@@ -1336,6 +1390,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                fulfillment_status: None,
+                shipped_at: None,
+                delivered_at: None,
             },
             PaymentIntentUpdate::Update(value) => Self {
                 amount: Some(value.amount),
@@ -1375,6 +1432,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 authorization_count: None,
                 is_payment_processor_token_flow: value.is_payment_processor_token_flow,
                 tax_details: None,
+                fulfillment_status: None,
+                shipped_at: None,
+                delivered_at: None,
             },
             PaymentIntentUpdate::PaymentCreateUpdate {
                 return_url,
@@ -1421,6 +1481,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                fulfillment_status: None,
+                shipped_at: None,
+                delivered_at: None,
             },
             PaymentIntentUpdate::PGStatusUpdate {
                 status,
@@ -1463,6 +1526,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                fulfillment_status: None,
+                shipped_at: None,
+                delivered_at: None,
             },
             PaymentIntentUpdate::MerchantStatusUpdate {
                 status,
@@ -1506,6 +1572,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                fulfillment_status: None,
+                shipped_at: None,
+                delivered_at: None,
             },
             PaymentIntentUpdate::ResponseUpdate {
                 // amount,
@@ -1557,6 +1626,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                fulfillment_status: None,
+                shipped_at: None,
+                delivered_at: None,
             },
             PaymentIntentUpdate::PaymentAttemptAndAttemptCountUpdate {
                 active_attempt_id,
@@ -1599,6 +1671,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                fulfillment_status: None,
+                shipped_at: None,
+                delivered_at: None,
             },
             PaymentIntentUpdate::StatusAndAttemptUpdate {
                 status,
@@ -1642,6 +1717,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                fulfillment_status: None,
+                shipped_at: None,
+                delivered_at: None,
             },
             PaymentIntentUpdate::ApproveUpdate {
                 status,
@@ -1684,6 +1762,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                fulfillment_status: None,
+                shipped_at: None,
+                delivered_at: None,
             },
             PaymentIntentUpdate::RejectUpdate {
                 status,
@@ -1726,6 +1807,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                fulfillment_status: None,
+                shipped_at: None,
+                delivered_at: None,
             },
             PaymentIntentUpdate::SurchargeApplicableUpdate {
                 surcharge_applicable,
@@ -1767,6 +1851,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                fulfillment_status: None,
+                shipped_at: None,
+                delivered_at: None,
             },
             PaymentIntentUpdate::IncrementalAuthorizationAmountUpdate { amount } => Self {
                 amount: Some(amount),
@@ -1805,6 +1892,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                fulfillment_status: None,
+                shipped_at: None,
+                delivered_at: None,
             },
             PaymentIntentUpdate::AuthorizationCountUpdate {
                 authorization_count,
@@ -1845,6 +1935,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                fulfillment_status: None,
+                shipped_at: None,
+                delivered_at: None,
             },
             PaymentIntentUpdate::CompleteAuthorizeUpdate {
                 shipping_address_id,
@@ -1885,6 +1978,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                fulfillment_status: None,
+                shipped_at: None,
+                delivered_at: None,
             },
             PaymentIntentUpdate::ManualUpdate { status, updated_by } => Self {
                 status,
@@ -1923,6 +2019,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                fulfillment_status: None,
+                shipped_at: None,
+                delivered_at: None,
             },
             PaymentIntentUpdate::SessionResponseUpdate {
                 tax_details,
@@ -1933,6 +2032,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_address_id,
                 amount: None,
                 tax_details: Some(tax_details),
+                fulfillment_status: None,
+                shipped_at: None,
+                delivered_at: None,
                 currency: None,
                 status: None,
                 amount_captured: None,
@@ -1967,6 +2069,52 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details,
                 is_payment_processor_token_flow: None,
             },
+            PaymentIntentUpdate::FulfillmentUpdate {
+                fulfillment_status,
+                shipped_at,
+                delivered_at,
+                updated_by,
+            } => Self {
+                status: None,
+                updated_by,
+                amount: None,
+                currency: None,
+                amount_captured: None,
+                customer_id: None,
+                return_url: None,
+                setup_future_usage: None,
+                off_session: None,
+                metadata: None,
+                billing_address_id: None,
+                shipping_address_id: None,
+                modified_at: common_utils::date_time::now(),
+                active_attempt_id: None,
+                business_country: None,
+                business_label: None,
+                description: None,
+                statement_descriptor_name: None,
+                statement_descriptor_suffix: None,
+                order_details: None,
+                attempt_count: None,
+                merchant_decision: None,
+                payment_confirm_source: None,
+                surcharge_applicable: None,
+                incremental_authorization_allowed: None,
+                authorization_count: None,
+                session_expiry: None,
+                fingerprint_id: None,
+                request_external_three_ds_authentication: None,
+                frm_metadata: None,
+                customer_details: None,
+                billing_details: None,
+                merchant_order_reference_id: None,
+                shipping_details: None,
+                is_payment_processor_token_flow: None,
+                tax_details: None,
+                fulfillment_status: Some(fulfillment_status),
+                shipped_at,
+                delivered_at,
+            },
         }
     }
 }
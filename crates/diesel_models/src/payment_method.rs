@@ -62,6 +62,7 @@ pub struct PaymentMethod {
     pub network_token_requestor_reference_id: Option<String>,
     pub network_token_locker_id: Option<String>,
     pub network_token_payment_method_data: Option<Encryption>,
+    pub retokenization_pending: Option<bool>,
 }
 
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
@@ -153,6 +154,7 @@ pub struct PaymentMethodNew {
     pub network_token_requestor_reference_id: Option<String>,
     pub network_token_locker_id: Option<String>,
     pub network_token_payment_method_data: Option<Encryption>,
+    pub retokenization_pending: Option<bool>,
 }
 
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
@@ -211,6 +213,25 @@ pub struct TokenizeCoreWorkflow {
     pub pm: storage_enums::PaymentMethod,
 }
 
+/// The criteria to select the card payment methods of a merchant which have to be re-tokenized
+/// with the network tokenization service
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[derive(Clone, Debug)]
+pub struct PaymentMethodRetokenizationCriteria {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    /// Select the payment methods which were last modified before this time, such as the time at
+    /// which the network tokenization service was switched
+    pub last_modified_before: Option<PrimitiveDateTime>,
+    /// Select only the payment methods whose last re-tokenization failed
+    pub retokenization_pending_only: bool,
+    /// Select only the payment methods whose id comes after this payment method id, so that the
+    /// payment methods which are left as they are are not selected again
+    pub starting_after: Option<String>,
+}
+
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
@@ -252,6 +273,14 @@ pub enum PaymentMethodUpdate {
     ConnectorMandateDetailsUpdate {
         connector_mandate_details: Option<serde_json::Value>,
     },
+    NetworkTokenDataUpdate {
+        network_token_requestor_reference_id: Option<String>,
+        network_token_locker_id: Option<String>,
+        network_token_payment_method_data: Option<Encryption>,
+    },
+    RetokenizationPendingUpdate {
+        retokenization_pending: Option<bool>,
+    },
 }
 
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
@@ -382,6 +411,7 @@ pub struct PaymentMethodUpdateInternal {
     last_modified: PrimitiveDateTime,
     network_token_locker_id: Option<String>,
     network_token_payment_method_data: Option<Encryption>,
+    retokenization_pending: Option<bool>,
 }
 
 #[cfg(all(
@@ -404,6 +434,10 @@ impl PaymentMethodUpdateInternal {
             status,
             connector_mandate_details,
             updated_by,
+            network_token_requestor_reference_id,
+            network_token_locker_id,
+            network_token_payment_method_data,
+            retokenization_pending,
             ..
         } = self;
 
@@ -417,6 +451,14 @@ impl PaymentMethodUpdateInternal {
             connector_mandate_details: connector_mandate_details
                 .map_or(source.connector_mandate_details, Some),
             updated_by: updated_by.map_or(source.updated_by, Some),
+            network_token_requestor_reference_id: network_token_requestor_reference_id
+                .map_or(source.network_token_requestor_reference_id, Some),
+            network_token_locker_id: network_token_locker_id
+                .map_or(source.network_token_locker_id, Some),
+            network_token_payment_method_data: network_token_payment_method_data
+                .map_or(source.network_token_payment_method_data, Some),
+            retokenization_pending: retokenization_pending
+                .map_or(source.retokenization_pending, Some),
             last_modified: common_utils::date_time::now(),
             ..source
         }
@@ -449,6 +491,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 last_modified: common_utils::date_time::now(),
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
+                retokenization_pending: None,
             },
             PaymentMethodUpdate::PaymentMethodDataUpdate {
                 payment_method_data,
@@ -468,6 +511,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 last_modified: common_utils::date_time::now(),
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
+                retokenization_pending: None,
             },
             PaymentMethodUpdate::LastUsedUpdate { last_used_at } => Self {
                 metadata: None,
@@ -485,6 +529,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 last_modified: common_utils::date_time::now(),
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
+                retokenization_pending: None,
             },
             PaymentMethodUpdate::UpdatePaymentMethodDataAndLastUsed {
                 payment_method_data,
@@ -505,6 +550,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 last_modified: common_utils::date_time::now(),
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
+                retokenization_pending: None,
             },
             PaymentMethodUpdate::NetworkTransactionIdAndStatusUpdate {
                 network_transaction_id,
@@ -525,6 +571,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 last_modified: common_utils::date_time::now(),
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
+                retokenization_pending: None,
             },
             PaymentMethodUpdate::StatusUpdate { status } => Self {
                 metadata: None,
@@ -542,6 +589,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 last_modified: common_utils::date_time::now(),
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
+                retokenization_pending: None,
             },
             PaymentMethodUpdate::AdditionalDataUpdate {
                 payment_method_data,
@@ -569,6 +617,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 last_modified: common_utils::date_time::now(),
                 network_token_locker_id,
                 network_token_payment_method_data,
+                retokenization_pending: None,
             },
             PaymentMethodUpdate::ConnectorMandateDetailsUpdate {
                 connector_mandate_details,
@@ -588,6 +637,49 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 last_modified: common_utils::date_time::now(),
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
+                retokenization_pending: None,
+            },
+            PaymentMethodUpdate::NetworkTokenDataUpdate {
+                network_token_requestor_reference_id,
+                network_token_locker_id,
+                network_token_payment_method_data,
+            } => Self {
+                metadata: None,
+                payment_method_data: None,
+                last_used_at: None,
+                network_transaction_id: None,
+                status: None,
+                locker_id: None,
+                network_token_requestor_reference_id,
+                payment_method: None,
+                connector_mandate_details: None,
+                updated_by: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                last_modified: common_utils::date_time::now(),
+                network_token_locker_id,
+                network_token_payment_method_data,
+                retokenization_pending: Some(false),
+            },
+            PaymentMethodUpdate::RetokenizationPendingUpdate {
+                retokenization_pending,
+            } => Self {
+                metadata: None,
+                payment_method_data: None,
+                last_used_at: None,
+                network_transaction_id: None,
+                status: None,
+                locker_id: None,
+                network_token_requestor_reference_id: None,
+                payment_method: None,
+                connector_mandate_details: None,
+                updated_by: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                last_modified: common_utils::date_time::now(),
+                network_token_locker_id: None,
+                network_token_payment_method_data: None,
+                retokenization_pending,
             },
         }
     }
@@ -798,6 +890,7 @@ impl From<&PaymentMethodNew> for PaymentMethod {
             network_token_payment_method_data: payment_method_new
                 .network_token_payment_method_data
                 .clone(),
+            retokenization_pending: payment_method_new.retokenization_pending,
         }
     }
 }
@@ -210,6 +210,10 @@ pub enum ProcessTrackerRunner {
     OutgoingWebhookRetryWorkflow,
     AttachPayoutAccountWorkflow,
     PaymentMethodStatusUpdateWorkflow,
+    FrmReviewTimeoutWorkflow,
+    DataRetentionPurgeWorkflow,
+    ReverseLookupReconciliationWorkflow,
+    AuditLogPurgeWorkflow,
 }
 
 #[cfg(test)]
@@ -1,10 +1,19 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_log;
+pub mod bank_account_token;
 pub mod blocklist_lookup;
 pub mod business_profile;
 mod capture;
 pub mod cards_info;
 pub mod configs;
+pub mod connector_token_revocation;
+pub mod connector_webhook_offset;
+pub mod customer_communication;
+pub mod customer_connector_sync;
+pub mod customer_risk_profile;
+#[cfg(feature = "v1")]
+pub mod data_retention;
 
 pub mod authentication;
 pub mod authorization;
@@ -19,11 +28,14 @@ pub mod fraud_check;
 pub mod generic_link;
 pub mod generics;
 pub mod gsm;
+pub mod incoming_webhook_event;
+pub mod legal_hold;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
+pub mod merchant_onboarding_audit_log;
 pub mod organization;
 pub mod payment_attempt;
 pub mod payment_intent;
@@ -33,11 +45,15 @@ pub mod payout_attempt;
 pub mod payouts;
 pub mod process_tracker;
 pub mod refund;
+pub mod resource_sequence;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod settlement_reconciliation;
+pub mod three_ds_session;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_event_sequence;
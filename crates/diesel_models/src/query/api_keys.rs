@@ -1,6 +1,15 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
+    OptionalExtension, QueryDsl,
+};
+use error_stack::{report, ResultExt};
+use router_env::logger;
 
-use super::generics;
+use super::generics::{
+    self,
+    db_metrics::{track_database_call, DatabaseOperation},
+};
 use crate::{
     api_keys::{ApiKey, ApiKeyNew, ApiKeyUpdate, ApiKeyUpdateInternal, HashedApiKey},
     errors,
@@ -82,6 +91,29 @@ impl ApiKey {
         .await
     }
 
+    /// Find the API key and lock it until the end of the transaction, so that it is not changed by
+    /// another transaction before the transaction updates it
+    pub async fn find_optional_by_merchant_id_key_id_for_update(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        key_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        let query = <Self as HasTable>::table()
+            .filter(
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::key_id.eq(key_id.to_owned())),
+            )
+            .for_update();
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_result_async(conn), DatabaseOperation::FindOne)
+            .await
+            .optional()
+            .map_err(|error| report!(error).change_context(errors::DatabaseError::Others))
+            .attach_printable("Error finding API key for update")
+    }
+
     pub async fn find_optional_by_hashed_api_key(
         conn: &PgPooledConn,
         hashed_api_key: HashedApiKey,
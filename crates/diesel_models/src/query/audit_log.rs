@@ -0,0 +1,112 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use error_stack::ResultExt;
+
+use super::generics;
+use crate::{
+    audit_log::{AuditLog, AuditLogContext, AuditLogNew},
+    enums as storage_enums,
+    errors::DatabaseError,
+    schema::audit_log::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl AuditLogNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<AuditLog> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl AuditLogContext {
+    /// Record the change of the resource in the audit log, from its state before the change to its
+    /// state after the change, either of which is absent for a created or a deleted resource. To
+    /// be called in the transaction which makes the change, so that the change is made only if it
+    /// is recorded.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn record<T: serde::Serialize + Sync>(
+        &self,
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        resource_type: storage_enums::AuditLogResourceType,
+        resource_id: &str,
+        action: storage_enums::AuditLogAction,
+        before: Option<&T>,
+        after: Option<&T>,
+    ) -> StorageResult<()> {
+        let to_value = |resource: Option<&T>| {
+            resource
+                .map(|resource| {
+                    serde_json::to_value(resource)
+                        .change_context(DatabaseError::Others)
+                        .attach_printable("Failed to serialize the audited resource")
+                })
+                .transpose()
+                .map(Option::unwrap_or_default)
+        };
+        let before = to_value(before)?;
+        let after = to_value(after)?;
+
+        let changes = self.get_field_changes(&before, &after);
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        let created_at = common_utils::date_time::now();
+        AuditLogNew {
+            merchant_id: merchant_id.to_owned(),
+            actor: self.actor.clone(),
+            resource_type,
+            resource_id: resource_id.to_owned(),
+            action,
+            changes: serde_json::to_value(changes)
+                .change_context(DatabaseError::Others)
+                .attach_printable("Failed to serialize the audit log changes")?,
+            created_at,
+        }
+        .insert(conn)
+        .await?;
+
+        Ok(())
+    }
+}
+
+impl AuditLog {
+    pub async fn find_by_merchant_id_resource(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        resource_type: storage_enums::AuditLogResourceType,
+        resource_id: &str,
+        created_after: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::resource_type.eq(resource_type))
+                .and(dsl::resource_id.eq(resource_id.to_owned()))
+                .and(dsl::created_at.ge(created_after)),
+            limit,
+            offset,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    /// Delete the entries of all the merchants which were created before the time, which are past
+    /// the retention period of the audit log
+    pub async fn delete_created_before(
+        conn: &PgPooledConn,
+        created_before: time::PrimitiveDateTime,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::created_at.lt(created_before),
+        )
+        .await
+        .or_else(|error| match error.current_context() {
+            DatabaseError::NotFound => Ok(false),
+            _ => Err(error),
+        })
+    }
+}
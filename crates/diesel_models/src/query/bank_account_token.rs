@@ -0,0 +1,67 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    bank_account_token::{
+        BankAccountToken, BankAccountTokenNew, BankAccountTokenUpdate,
+        BankAccountTokenUpdateInternal,
+    },
+    schema::bank_account_token::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl BankAccountTokenNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<BankAccountToken> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl BankAccountToken {
+    pub async fn find_by_merchant_id_token_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        token_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::token_id.eq(token_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn find_by_merchant_id_customer_id_fingerprint(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        fingerprint: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned()))
+                .and(dsl::fingerprint.eq(fingerprint.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update_with_token_id(
+        self,
+        conn: &PgPooledConn,
+        bank_account_token_update: BankAccountTokenUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::token_id.eq(self.token_id.to_owned()),
+            BankAccountTokenUpdateInternal::from(bank_account_token_update),
+        )
+        .await
+    }
+}
@@ -1,6 +1,15 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, result::Error as DieselError,
+    BoolExpressionMethods, ExpressionMethods, QueryDsl, Table,
+};
+use error_stack::{report, ResultExt};
+use router_env::logger;
 
-use super::generics;
+use super::generics::{
+    self,
+    db_metrics::{track_database_call, DatabaseOperation},
+};
 #[cfg(feature = "v1")]
 use crate::schema::business_profile::dsl::{self, profile_id as dsl_identifier};
 #[cfg(feature = "v2")]
@@ -48,6 +57,28 @@ impl Profile {
         .await
     }
 
+    /// Find the profile and lock it until the end of the transaction, so that it is not changed by
+    /// another transaction before the transaction updates it
+    pub async fn find_by_profile_id_for_update(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> StorageResult<Self> {
+        let query = <Self as HasTable>::table()
+            .filter(dsl_identifier.eq(profile_id.to_owned()))
+            .for_update();
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_result_async(conn), DatabaseOperation::FindOne)
+            .await
+            .map_err(|error| match error {
+                DieselError::NotFound => {
+                    report!(error).change_context(errors::DatabaseError::NotFound)
+                }
+                _ => report!(error).change_context(errors::DatabaseError::Others),
+            })
+            .attach_printable("Error finding profile for update")
+    }
+
     pub async fn find_by_merchant_id_profile_id(
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
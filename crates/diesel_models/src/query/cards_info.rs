@@ -1,6 +1,17 @@
-use diesel::associations::HasTable;
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, upsert::excluded, BoolExpressionMethods,
+    ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+use router_env::logger;
+use time::PrimitiveDateTime;
 
-use crate::{cards_info::CardInfo, query::generics, PgPooledConn, StorageResult};
+use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+use crate::{
+    cards_info::CardInfo, errors::DatabaseError, query::generics, schema::cards_info::dsl,
+    PgPooledConn, StorageResult,
+};
 
 impl CardInfo {
     pub async fn find_by_iin(conn: &PgPooledConn, card_iin: &str) -> StorageResult<Option<Self>> {
@@ -10,4 +21,75 @@ impl CardInfo {
         )
         .await
     }
+
+    pub async fn find_existing_card_iins(
+        conn: &PgPooledConn,
+        card_iins: Vec<String>,
+    ) -> StorageResult<Vec<String>> {
+        let query = <Self as HasTable>::table()
+            .filter(dsl::card_iin.eq_any(card_iins))
+            .select(dsl::card_iin);
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(DatabaseError::Others)
+            .attach_printable("Error while filtering existing card iins")
+    }
+
+    /// Insert the provided records, updating the existing records having the same `card_iin`.
+    ///
+    /// The records must not contain duplicate `card_iin` values, as a single insert statement
+    /// cannot update the same row more than once.
+    pub async fn bulk_upsert(conn: &PgPooledConn, records: Vec<Self>) -> StorageResult<usize> {
+        let query = diesel::insert_into(<Self as HasTable>::table())
+            .values(records)
+            .on_conflict(dsl::card_iin)
+            .do_update()
+            .set((
+                dsl::card_issuer.eq(excluded(dsl::card_issuer)),
+                dsl::card_network.eq(excluded(dsl::card_network)),
+                dsl::card_type.eq(excluded(dsl::card_type)),
+                dsl::card_subtype.eq(excluded(dsl::card_subtype)),
+                dsl::card_issuing_country.eq(excluded(dsl::card_issuing_country)),
+                dsl::bank_code_id.eq(excluded(dsl::bank_code_id)),
+                dsl::bank_code.eq(excluded(dsl::bank_code)),
+                dsl::country_code.eq(excluded(dsl::country_code)),
+                dsl::last_updated.eq(excluded(dsl::last_updated)),
+                dsl::last_updated_provider.eq(excluded(dsl::last_updated_provider)),
+            ));
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.execute_async(conn), DatabaseOperation::Insert)
+            .await
+            .change_context(DatabaseError::Others)
+            .attach_printable("Error while upserting card info records")
+    }
+
+    /// Delete the records which were not updated since `updated_before`, returning the
+    /// `card_iin` of the deleted records.
+    pub async fn delete_not_updated_since(
+        conn: &PgPooledConn,
+        updated_before: PrimitiveDateTime,
+    ) -> StorageResult<Vec<String>> {
+        let query = diesel::delete(<Self as HasTable>::table())
+            .filter(
+                dsl::last_updated
+                    .is_null()
+                    .or(dsl::last_updated.lt(updated_before)),
+            )
+            .returning(dsl::card_iin);
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(
+            query.get_results_async(conn),
+            DatabaseOperation::DeleteWithResult,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Error while deleting stale card info records")
+    }
 }
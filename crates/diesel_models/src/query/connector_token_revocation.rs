@@ -0,0 +1,112 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, upsert::excluded, BoolExpressionMethods,
+    ExpressionMethods,
+};
+use error_stack::ResultExt;
+use router_env::logger;
+
+use super::generics::{
+    self,
+    db_metrics::{track_database_call, DatabaseOperation},
+};
+use crate::{
+    connector_token_revocation::{
+        ConnectorTokenRevocation, ConnectorTokenRevocationNew, ConnectorTokenRevocationUpdate,
+    },
+    enums as storage_enums,
+    errors::DatabaseError,
+    schema::connector_token_revocation::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ConnectorTokenRevocationNew {
+    /// Insert the revocations, replacing the outcome of an earlier revocation of the same token,
+    /// such as one recorded by an earlier attempt to delete the payment method
+    pub async fn bulk_upsert(
+        conn: &PgPooledConn,
+        revocations: Vec<Self>,
+    ) -> StorageResult<Vec<ConnectorTokenRevocation>> {
+        let query = diesel::insert_into(<ConnectorTokenRevocation as HasTable>::table())
+            .values(revocations)
+            .on_conflict((
+                dsl::merchant_id,
+                dsl::payment_method_id,
+                dsl::merchant_connector_id,
+            ))
+            .do_update()
+            .set((
+                dsl::connector_token.eq(excluded(dsl::connector_token)),
+                dsl::revocation_status.eq(excluded(dsl::revocation_status)),
+                dsl::error_message.eq(excluded(dsl::error_message)),
+                dsl::modified_at.eq(excluded(dsl::modified_at)),
+            ));
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<ConnectorTokenRevocation, _, _>(
+            query.get_results_async(conn),
+            DatabaseOperation::Insert,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Error while upserting connector token revocations")
+    }
+}
+
+impl ConnectorTokenRevocation {
+    pub async fn find_by_merchant_id_payment_method_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_method_id.eq(payment_method_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn find_by_revocation_status(
+        conn: &PgPooledConn,
+        revocation_status: storage_enums::ConnectorTokenRevocationStatus,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::revocation_status.eq(revocation_status),
+            Some(limit),
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_payment_method_id_merchant_connector_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_id: &str,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+        revocation_update: ConnectorTokenRevocationUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_method_id.eq(payment_method_id.to_owned()))
+                .and(dsl::merchant_connector_id.eq(merchant_connector_id.to_owned())),
+            revocation_update,
+        )
+        .await
+    }
+}
@@ -0,0 +1,126 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
+    PgExpressionMethods,
+};
+use error_stack::{report, ResultExt};
+use router_env::logger;
+
+use super::generics::{
+    self,
+    db_metrics::{track_database_call, DatabaseOperation},
+};
+use crate::{
+    connector_webhook_offset::{ConnectorWebhookOffset, ConnectorWebhookOffsetNew},
+    errors::DatabaseError,
+    schema::connector_webhook_offset::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ConnectorWebhookOffsetNew {
+    /// Get the offset of the connector account, creating it if it does not exist
+    pub async fn insert_or_get(self, conn: &PgPooledConn) -> StorageResult<ConnectorWebhookOffset> {
+        // The update of the existing offset does not change it, so that the offset is returned
+        // in the same query
+        let query = diesel::insert_into(<ConnectorWebhookOffset as HasTable>::table())
+            .values(self)
+            .on_conflict((dsl::merchant_id, dsl::merchant_connector_id))
+            .do_update()
+            .set(dsl::modified_at.eq(dsl::modified_at));
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<ConnectorWebhookOffset, _, _>(
+            query.get_result_async(conn),
+            DatabaseOperation::Insert,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Error while getting connector webhook offset")
+    }
+}
+
+impl ConnectorWebhookOffset {
+    pub async fn find_optional_by_merchant_id_merchant_connector_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::merchant_connector_id.eq(merchant_connector_id.to_owned())),
+        )
+        .await
+    }
+
+    /// Move the offset from the current offset to the new offset. The offset is moved only if it
+    /// is still the current offset in the current log, so that of the pollers which read from the
+    /// same offset only one advances it. Returns the offset if it was moved.
+    pub async fn advance(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+        log_id: Option<String>,
+        current_offset: Option<String>,
+        new_offset: String,
+    ) -> StorageResult<Option<Self>> {
+        let query = diesel::update(<Self as HasTable>::table())
+            .filter(
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::merchant_connector_id.eq(merchant_connector_id.to_owned()))
+                    .and(dsl::log_id.is_not_distinct_from(log_id))
+                    .and(dsl::read_offset.is_not_distinct_from(current_offset)),
+            )
+            .set((
+                dsl::read_offset.eq(new_offset),
+                dsl::modified_at.eq(common_utils::date_time::now()),
+            ));
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Update)
+            .await
+            .change_context(DatabaseError::Others)
+            .attach_printable("Error while advancing connector webhook offset")
+            .map(|offsets: Vec<Self>| offsets.into_iter().next())
+    }
+
+    /// Move the offset to the provided offset of the provided log, regardless of the current
+    /// offset, when the log of the connector was truncated or rotated
+    pub async fn reset(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+        log_id: Option<String>,
+        read_offset: Option<String>,
+    ) -> StorageResult<Self> {
+        let now = common_utils::date_time::now();
+        let query = diesel::update(<Self as HasTable>::table())
+            .filter(
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::merchant_connector_id.eq(merchant_connector_id.to_owned())),
+            )
+            .set((
+                dsl::log_id.eq(log_id),
+                dsl::read_offset.eq(read_offset),
+                dsl::reset_count.eq(dsl::reset_count + 1),
+                dsl::last_reset_at.eq(now),
+                dsl::modified_at.eq(now),
+            ));
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Update)
+            .await
+            .change_context(DatabaseError::Others)
+            .attach_printable("Error while resetting connector webhook offset")?
+            .into_iter()
+            .next()
+            .ok_or(report!(DatabaseError::NotFound))
+            .attach_printable("Connector webhook offset not found")
+    }
+}
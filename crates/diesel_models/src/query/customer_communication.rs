@@ -0,0 +1,70 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    customer_communication::{
+        CustomerCommunication, CustomerCommunicationNew, CustomerCommunicationRedaction,
+    },
+    schema::customer_communication::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl CustomerCommunicationNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<CustomerCommunication> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl CustomerCommunication {
+    pub async fn find_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned())),
+            limit,
+            offset,
+            Some(dsl::communicated_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn find_by_merchant_id_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq(payment_id.to_owned())),
+            None,
+            None,
+            Some(dsl::communicated_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn redact_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        redaction: CustomerCommunicationRedaction,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned())),
+            redaction,
+        )
+        .await
+    }
+}
@@ -0,0 +1,85 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, upsert::excluded, BoolExpressionMethods,
+    ExpressionMethods,
+};
+use error_stack::ResultExt;
+use router_env::logger;
+
+use super::generics::{
+    self,
+    db_metrics::{track_database_call, DatabaseOperation},
+};
+use crate::{
+    customer_connector_sync::{CustomerConnectorSync, CustomerConnectorSyncNew},
+    enums as storage_enums,
+    errors::DatabaseError,
+    schema::customer_connector_sync::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl CustomerConnectorSyncNew {
+    /// Insert the sync entry, replacing the status of an existing entry for the same customer and
+    /// connector label.
+    pub async fn upsert(self, conn: &PgPooledConn) -> StorageResult<CustomerConnectorSync> {
+        let query = diesel::insert_into(<CustomerConnectorSync as HasTable>::table())
+            .values(self)
+            .on_conflict((dsl::merchant_id, dsl::customer_id, dsl::connector_label))
+            .do_update()
+            .set((
+                dsl::connector_name.eq(excluded(dsl::connector_name)),
+                dsl::sync_status.eq(excluded(dsl::sync_status)),
+                dsl::error_message.eq(excluded(dsl::error_message)),
+                dsl::modified_at.eq(excluded(dsl::modified_at)),
+            ));
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<CustomerConnectorSync, _, _>(
+            query.get_result_async(conn),
+            DatabaseOperation::Insert,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Error while upserting customer connector sync entry")
+    }
+}
+
+impl CustomerConnectorSync {
+    pub async fn find_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned())),
+            None,
+            None,
+            Some(dsl::connector_label.asc()),
+        )
+        .await
+    }
+
+    /// List the entries of the merchant which are yet to be synced to the connector, the oldest
+    /// local change first.
+    pub async fn find_by_merchant_id_sync_statuses(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        sync_statuses: Vec<storage_enums::CustomerConnectorSyncStatus>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::sync_status.eq_any(sync_statuses)),
+            Some(limit),
+            None,
+            Some(dsl::modified_at.asc()),
+        )
+        .await
+    }
+}
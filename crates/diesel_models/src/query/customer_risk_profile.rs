@@ -0,0 +1,89 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, upsert::excluded, BoolExpressionMethods,
+    ExpressionMethods,
+};
+use error_stack::ResultExt;
+use router_env::logger;
+
+use super::generics::{
+    self,
+    db_metrics::{track_database_call, DatabaseOperation},
+};
+use crate::{
+    customer_risk_profile::{
+        CustomerRiskProfile, CustomerRiskProfileNew, CustomerRiskProfileUpdateInternal,
+    },
+    errors::DatabaseError,
+    schema::customer_risk_profile::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl CustomerRiskProfileNew {
+    /// Insert the risk profile, or add its counters to the counters of the existing risk profile of
+    /// the customer. The risk score and the tier of an existing risk profile are left unchanged.
+    pub async fn upsert_adding_counters(
+        self,
+        conn: &PgPooledConn,
+    ) -> StorageResult<CustomerRiskProfile> {
+        let query = diesel::insert_into(<CustomerRiskProfile as HasTable>::table())
+            .values(self)
+            .on_conflict((dsl::merchant_id, dsl::customer_id))
+            .do_update()
+            .set((
+                dsl::dispute_count.eq(dsl::dispute_count + excluded(dsl::dispute_count)),
+                dsl::lost_dispute_count
+                    .eq(dsl::lost_dispute_count + excluded(dsl::lost_dispute_count)),
+                dsl::decline_count.eq(dsl::decline_count + excluded(dsl::decline_count)),
+                dsl::modified_at.eq(excluded(dsl::modified_at)),
+            ));
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<CustomerRiskProfile, _, _>(
+            query.get_result_async(conn),
+            DatabaseOperation::Insert,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Error while upserting customer risk profile")
+    }
+}
+
+impl CustomerRiskProfile {
+    pub async fn find_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned())),
+        )
+        .await
+    }
+
+    /// Update the risk score and the tier of the risk profile, provided that its counters have not
+    /// changed since it was read, so that a score derived from stale counters does not overwrite
+    /// the score derived from the latest counters. Returns whether the risk profile was updated.
+    pub async fn update_if_counters_unchanged(
+        &self,
+        conn: &PgPooledConn,
+        update: CustomerRiskProfileUpdateInternal,
+    ) -> StorageResult<bool> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::customer_id.eq(self.customer_id.to_owned()))
+                .and(dsl::dispute_count.eq(self.dispute_count))
+                .and(dsl::lost_dispute_count.eq(self.lost_dispute_count))
+                .and(dsl::decline_count.eq(self.decline_count)),
+            update,
+        )
+        .await
+        .map(|updated_count| updated_count > 0)
+    }
+}
@@ -26,6 +26,13 @@ impl CustomerNew {
 pub struct CustomerListConstraints {
     pub limit: i64,
     pub offset: Option<i64>,
+    pub metadata_value_hash: Option<CustomerMetadataValueHash>,
+}
+
+/// Hash of the value of an encrypted key of the customer metadata
+pub struct CustomerMetadataValueHash {
+    pub metadata_key: String,
+    pub value_hash: String,
 }
 
 // #[cfg(all(feature = "v2", feature = "customer_v2"))]
@@ -63,14 +70,33 @@ impl Customer {
         merchant_id: &id_type::MerchantId,
         constraints: CustomerListConstraints,
     ) -> StorageResult<Vec<Self>> {
-        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
-            conn,
-            dsl::merchant_id.eq(merchant_id.to_owned()),
-            Some(constraints.limit),
-            constraints.offset,
-            Some(dsl::created_at),
-        )
-        .await
+        match constraints.metadata_value_hash {
+            Some(metadata_value_hash) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    dsl::merchant_id.eq(merchant_id.to_owned()).and(
+                        diesel::dsl::sql::<diesel::sql_types::Bool>("(metadata -> ")
+                            .bind::<diesel::sql_types::Text, _>(metadata_value_hash.metadata_key)
+                            .sql(" ->> 'value_hash') = ")
+                            .bind::<diesel::sql_types::Text, _>(metadata_value_hash.value_hash),
+                    ),
+                    Some(constraints.limit),
+                    constraints.offset,
+                    Some(dsl::created_at),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    dsl::merchant_id.eq(merchant_id.to_owned()),
+                    Some(constraints.limit),
+                    constraints.offset,
+                    Some(dsl::created_at),
+                )
+                .await
+            }
+        }
     }
 
     #[cfg(all(feature = "v2", feature = "customer_v2"))]
@@ -0,0 +1,515 @@
+//! The queries of the records whose retention period has elapsed.
+//!
+//! A record is purgeable once it is older than the retention cutoff, unless it is under a legal
+//! hold, or the purge would break a record which must still be kept, such as a payment with an
+//! open dispute or a pending refund. The personal data of the payments, including the payment
+//! method data of their attempts, and of the customers is anonymized in place, so that the payments and the customers remain consistent with the records
+//! referencing them, while the refunds, the disputes and the files are deleted.
+//!
+//! The records are updated and deleted in the database directly, so the purge is only run for the
+//! records old enough to no longer be held in the key-value store.
+
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::encryption::Encryption;
+use diesel::{
+    associations::HasTable,
+    debug_query,
+    dsl::{exists, not},
+    pg::Pg,
+    BoolExpressionMethods, ExpressionMethods, NullableExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+use router_env::logger;
+use time::PrimitiveDateTime;
+
+use super::generics::{
+    self,
+    db_metrics::{track_database_call, DatabaseOperation},
+};
+use crate::{
+    address::Address,
+    customers::Customer,
+    dispute::Dispute,
+    enums as storage_enums,
+    errors::DatabaseError,
+    file::FileMetadata,
+    payment_attempt::PaymentAttempt,
+    payment_intent::PaymentIntent,
+    refund::Refund,
+    schema::{
+        address, customer_communication, customers, dispute, file_metadata, legal_hold,
+        payment_attempt, payment_intent, refund,
+    },
+    PgPooledConn, StorageResult,
+};
+
+/// The statuses of the disputes which are yet to be resolved
+const OPEN_DISPUTE_STATUSES: [storage_enums::DisputeStatus; 2] = [
+    storage_enums::DisputeStatus::DisputeOpened,
+    storage_enums::DisputeStatus::DisputeChallenged,
+];
+
+/// The statuses of the refunds which are yet to be processed by the connector
+const PENDING_REFUND_STATUSES: [storage_enums::RefundStatus; 2] = [
+    storage_enums::RefundStatus::Pending,
+    storage_enums::RefundStatus::ManualReview,
+];
+
+const FINAL_REFUND_STATUSES: [storage_enums::RefundStatus; 3] = [
+    storage_enums::RefundStatus::Success,
+    storage_enums::RefundStatus::Failure,
+    storage_enums::RefundStatus::TransactionFailure,
+];
+
+const FINAL_DISPUTE_STATUSES: [storage_enums::DisputeStatus; 5] = [
+    storage_enums::DisputeStatus::DisputeExpired,
+    storage_enums::DisputeStatus::DisputeAccepted,
+    storage_enums::DisputeStatus::DisputeCancelled,
+    storage_enums::DisputeStatus::DisputeWon,
+    storage_enums::DisputeStatus::DisputeLost,
+];
+
+impl PaymentIntent {
+    /// The merchant ids and the payment ids of the payments created before the cutoff which still
+    /// hold personal data, and which are neither under a legal hold, nor have an open dispute or
+    /// a pending refund
+    pub async fn find_purgeable_created_before(
+        conn: &PgPooledConn,
+        created_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<(common_utils::id_type::MerchantId, String)>> {
+        let query = <Self as HasTable>::table()
+            .select((payment_intent::merchant_id, payment_intent::payment_id))
+            .filter(payment_intent::created_at.lt(created_before))
+            .filter(
+                payment_intent::customer_details
+                    .is_not_null()
+                    .or(payment_intent::billing_details.is_not_null())
+                    .or(payment_intent::shipping_details.is_not_null())
+                    .or(exists(
+                        payment_attempt::table
+                            .filter(payment_attempt::merchant_id.eq(payment_intent::merchant_id))
+                            .filter(payment_attempt::payment_id.eq(payment_intent::payment_id))
+                            .filter(payment_attempt::payment_method_data.is_not_null().or(
+                                payment_attempt::payment_method_billing_address_id.is_not_null(),
+                            )),
+                    )),
+            )
+            .filter(not(exists(
+                legal_hold::table
+                    .filter(legal_hold::merchant_id.eq(payment_intent::merchant_id))
+                    .filter(legal_hold::entity_type.eq(storage_enums::RetentionEntity::Payments))
+                    .filter(legal_hold::record_id.eq(payment_intent::payment_id)),
+            )))
+            .filter(not(exists(
+                dispute::table
+                    .filter(dispute::merchant_id.eq(payment_intent::merchant_id))
+                    .filter(dispute::payment_id.eq(payment_intent::payment_id))
+                    .filter(dispute::dispute_status.eq_any(OPEN_DISPUTE_STATUSES.to_vec())),
+            )))
+            .filter(not(exists(
+                refund::table
+                    .filter(refund::merchant_id.eq(payment_intent::merchant_id))
+                    .filter(refund::payment_id.eq(payment_intent::payment_id))
+                    .filter(refund::refund_status.eq_any(PENDING_REFUND_STATUSES.to_vec())),
+            )))
+            .order(payment_intent::created_at.asc())
+            .limit(limit);
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(DatabaseError::Others)
+            .attach_printable("Error filtering purgeable payment intents")
+    }
+
+    /// Remove the personal data of the payments, keeping the payments themselves
+    pub async fn anonymize_by_merchant_id_payment_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_ids: Vec<String>,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            payment_intent::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(payment_intent::payment_id.eq_any(payment_ids)),
+            (
+                payment_intent::customer_details.eq(None::<Encryption>),
+                payment_intent::billing_details.eq(None::<Encryption>),
+                payment_intent::shipping_details.eq(None::<Encryption>),
+                payment_intent::description.eq(None::<String>),
+                payment_intent::return_url.eq(None::<String>),
+                payment_intent::metadata.eq(None::<serde_json::Value>),
+                payment_intent::modified_at.eq(common_utils::date_time::now()),
+            ),
+        )
+        .await
+    }
+}
+
+impl PaymentAttempt {
+    /// Remove the payment method data of the attempts of the payments, and unlink their payment
+    /// method billing addresses, which are anonymized with
+    /// [`Address::anonymize_payment_method_billing_by_merchant_id_payment_ids`]
+    pub async fn anonymize_by_merchant_id_payment_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_ids: Vec<String>,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            payment_attempt::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(payment_attempt::payment_id.eq_any(payment_ids)),
+            (
+                payment_attempt::payment_method_data.eq(None::<serde_json::Value>),
+                payment_attempt::payment_method_billing_address_id.eq(None::<String>),
+                payment_attempt::modified_at.eq(common_utils::date_time::now()),
+            ),
+        )
+        .await
+    }
+}
+
+impl Customer {
+    /// The merchant ids and the customer ids of the customers last modified before the cutoff
+    /// which still hold personal data, and which are neither under a legal hold, nor have a
+    /// payment created after the cutoff or a payment with an open dispute.
+    ///
+    /// The customers are kept as long as they keep making payments, even if the customers
+    /// themselves are not modified.
+    pub async fn find_purgeable_modified_before(
+        conn: &PgPooledConn,
+        modified_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<(common_utils::id_type::MerchantId, String)>> {
+        let query = <Self as HasTable>::table()
+            .select((customers::merchant_id, customers::customer_id))
+            .filter(customers::modified_at.lt(modified_before))
+            .filter(
+                customers::name
+                    .is_not_null()
+                    .or(customers::email.is_not_null())
+                    .or(customers::phone.is_not_null()),
+            )
+            .filter(not(exists(
+                legal_hold::table
+                    .filter(legal_hold::merchant_id.eq(customers::merchant_id))
+                    .filter(legal_hold::entity_type.eq(storage_enums::RetentionEntity::Customers))
+                    .filter(legal_hold::record_id.eq(customers::customer_id)),
+            )))
+            .filter(not(exists(
+                payment_intent::table
+                    .filter(payment_intent::merchant_id.eq(customers::merchant_id))
+                    .filter(payment_intent::customer_id.eq(customers::customer_id.nullable()))
+                    .filter(payment_intent::created_at.ge(modified_before)),
+            )))
+            .filter(not(exists(
+                payment_intent::table
+                    .filter(payment_intent::merchant_id.eq(customers::merchant_id))
+                    .filter(payment_intent::customer_id.eq(customers::customer_id.nullable()))
+                    .filter(exists(
+                        dispute::table
+                            .filter(dispute::merchant_id.eq(payment_intent::merchant_id))
+                            .filter(dispute::payment_id.eq(payment_intent::payment_id))
+                            .filter(dispute::dispute_status.eq_any(OPEN_DISPUTE_STATUSES.to_vec())),
+                    )),
+            )))
+            .order(customers::modified_at.asc())
+            .limit(limit);
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(DatabaseError::Others)
+            .attach_printable("Error filtering purgeable customers")
+    }
+
+    /// Remove the personal data of the customers, keeping the customers themselves
+    pub async fn anonymize_by_merchant_id_customer_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_ids: Vec<String>,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            customers::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(customers::customer_id.eq_any(customer_ids)),
+            (
+                customers::name.eq(None::<Encryption>),
+                customers::email.eq(None::<Encryption>),
+                customers::phone.eq(None::<Encryption>),
+                customers::phone_country_code.eq(None::<String>),
+                customers::description.eq(None::<String>),
+                customers::metadata.eq(None::<serde_json::Value>),
+                customers::tax_ids.eq(None::<Encryption>),
+                customers::modified_at.eq(common_utils::date_time::now()),
+            ),
+        )
+        .await
+    }
+}
+
+impl Address {
+    /// Remove the personal data of the addresses of the payments
+    pub async fn anonymize_by_merchant_id_payment_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_ids: Vec<String>,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            address::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(address::payment_id.eq_any(payment_ids)),
+            (
+                address::line1.eq(None::<Encryption>),
+                address::line2.eq(None::<Encryption>),
+                address::line3.eq(None::<Encryption>),
+                address::state.eq(None::<Encryption>),
+                address::zip.eq(None::<Encryption>),
+                address::first_name.eq(None::<Encryption>),
+                address::last_name.eq(None::<Encryption>),
+                address::phone_number.eq(None::<Encryption>),
+                address::email.eq(None::<Encryption>),
+                address::city.eq(None::<String>),
+                address::modified_at.eq(common_utils::date_time::now()),
+            ),
+        )
+        .await
+    }
+
+    /// Remove the personal data of the payment method billing addresses of the attempts of the
+    /// payments, which must be done before the attempts are anonymized as it unlinks them
+    pub async fn anonymize_payment_method_billing_by_merchant_id_payment_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_ids: Vec<String>,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            address::merchant_id.eq(merchant_id.to_owned()).and(
+                address::address_id.nullable().eq_any(
+                    payment_attempt::table
+                        .select(payment_attempt::payment_method_billing_address_id)
+                        .filter(payment_attempt::merchant_id.eq(merchant_id.to_owned()))
+                        .filter(payment_attempt::payment_id.eq_any(payment_ids)),
+                ),
+            ),
+            (
+                address::line1.eq(None::<Encryption>),
+                address::line2.eq(None::<Encryption>),
+                address::line3.eq(None::<Encryption>),
+                address::state.eq(None::<Encryption>),
+                address::zip.eq(None::<Encryption>),
+                address::first_name.eq(None::<Encryption>),
+                address::last_name.eq(None::<Encryption>),
+                address::phone_number.eq(None::<Encryption>),
+                address::email.eq(None::<Encryption>),
+                address::city.eq(None::<String>),
+                address::modified_at.eq(common_utils::date_time::now()),
+            ),
+        )
+        .await
+    }
+
+    /// Remove the personal data of the addresses of the customers
+    pub async fn anonymize_by_merchant_id_customer_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_ids: Vec<String>,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            address::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(address::customer_id.eq_any(customer_ids)),
+            (
+                address::line1.eq(None::<Encryption>),
+                address::line2.eq(None::<Encryption>),
+                address::line3.eq(None::<Encryption>),
+                address::state.eq(None::<Encryption>),
+                address::zip.eq(None::<Encryption>),
+                address::first_name.eq(None::<Encryption>),
+                address::last_name.eq(None::<Encryption>),
+                address::phone_number.eq(None::<Encryption>),
+                address::email.eq(None::<Encryption>),
+                address::city.eq(None::<String>),
+                address::modified_at.eq(common_utils::date_time::now()),
+            ),
+        )
+        .await
+    }
+}
+
+impl Refund {
+    /// The merchant ids and the refund ids of the processed refunds last modified before the
+    /// cutoff, which are not under a legal hold, and whose payment is neither under a legal hold
+    /// nor has an open dispute
+    pub async fn find_purgeable_modified_before(
+        conn: &PgPooledConn,
+        modified_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<(common_utils::id_type::MerchantId, String)>> {
+        let query = <Self as HasTable>::table()
+            .select((refund::merchant_id, refund::refund_id))
+            .filter(refund::modified_at.lt(modified_before))
+            .filter(refund::refund_status.eq_any(FINAL_REFUND_STATUSES.to_vec()))
+            .filter(not(exists(
+                legal_hold::table
+                    .filter(legal_hold::merchant_id.eq(refund::merchant_id))
+                    .filter(
+                        legal_hold::entity_type
+                            .eq(storage_enums::RetentionEntity::Refunds)
+                            .and(legal_hold::record_id.eq(refund::refund_id))
+                            .or(legal_hold::entity_type
+                                .eq(storage_enums::RetentionEntity::Payments)
+                                .and(legal_hold::record_id.eq(refund::payment_id))),
+                    ),
+            )))
+            .filter(not(exists(
+                dispute::table
+                    .filter(dispute::merchant_id.eq(refund::merchant_id))
+                    .filter(dispute::payment_id.eq(refund::payment_id))
+                    .filter(dispute::dispute_status.eq_any(OPEN_DISPUTE_STATUSES.to_vec())),
+            )))
+            .order(refund::modified_at.asc())
+            .limit(limit);
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(DatabaseError::Others)
+            .attach_printable("Error filtering purgeable refunds")
+    }
+
+    pub async fn delete_by_merchant_id_refund_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        refund_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_delete_with_results::<<Self as HasTable>::Table, _, _>(
+            conn,
+            refund::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(refund::refund_id.eq_any(refund_ids)),
+        )
+        .await
+    }
+}
+
+impl Dispute {
+    /// The merchant ids and the dispute ids of the resolved disputes last modified before the
+    /// cutoff, which are not under a legal hold, and whose payment is not under a legal hold
+    pub async fn find_purgeable_modified_before(
+        conn: &PgPooledConn,
+        modified_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<(common_utils::id_type::MerchantId, String)>> {
+        let query = <Self as HasTable>::table()
+            .select((dispute::merchant_id, dispute::dispute_id))
+            .filter(dispute::modified_at.lt(modified_before))
+            .filter(dispute::dispute_status.eq_any(FINAL_DISPUTE_STATUSES.to_vec()))
+            .filter(not(exists(
+                legal_hold::table
+                    .filter(legal_hold::merchant_id.eq(dispute::merchant_id))
+                    .filter(
+                        legal_hold::entity_type
+                            .eq(storage_enums::RetentionEntity::Disputes)
+                            .and(legal_hold::record_id.eq(dispute::dispute_id))
+                            .or(legal_hold::entity_type
+                                .eq(storage_enums::RetentionEntity::Payments)
+                                .and(legal_hold::record_id.eq(dispute::payment_id))),
+                    ),
+            )))
+            .order(dispute::modified_at.asc())
+            .limit(limit);
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(DatabaseError::Others)
+            .attach_printable("Error filtering purgeable disputes")
+    }
+
+    pub async fn delete_by_merchant_id_dispute_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        dispute_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_delete_with_results::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dispute::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dispute::dispute_id.eq_any(dispute_ids)),
+        )
+        .await
+    }
+}
+
+impl FileMetadata {
+    /// The merchant ids and the file ids of the files created before the cutoff, which are not
+    /// under a legal hold, which are not submitted as the evidence of a dispute which is yet to be
+    /// resolved, and which are not attached to the communication log of a customer, as the log is
+    /// evidence for the disputes
+    pub async fn find_purgeable_created_before(
+        conn: &PgPooledConn,
+        created_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<(common_utils::id_type::MerchantId, String)>> {
+        let query = <Self as HasTable>::table()
+            .select((file_metadata::merchant_id, file_metadata::file_id))
+            .filter(file_metadata::created_at.lt(created_before))
+            .filter(not(exists(
+                legal_hold::table
+                    .filter(legal_hold::merchant_id.eq(file_metadata::merchant_id))
+                    .filter(legal_hold::entity_type.eq(storage_enums::RetentionEntity::Files))
+                    .filter(legal_hold::record_id.eq(file_metadata::file_id)),
+            )))
+            .filter(not(exists(
+                dispute::table
+                    .filter(dispute::merchant_id.eq(file_metadata::merchant_id))
+                    .filter(not(
+                        dispute::dispute_status.eq_any(FINAL_DISPUTE_STATUSES.to_vec())
+                    ))
+                    // The evidence is an object of the file ids keyed by the type of the evidence
+                    .filter(diesel::dsl::sql::<diesel::sql_types::Bool>(
+                        "EXISTS (SELECT 1 FROM jsonb_path_query(dispute.evidence, '$.*') AS evidence_file_id WHERE evidence_file_id #>> '{}' = file_metadata.file_id)",
+                    )),
+            )))
+            .filter(not(exists(
+                customer_communication::table
+                    .filter(customer_communication::merchant_id.eq(file_metadata::merchant_id))
+                    .filter(customer_communication::file_id.eq(file_metadata::file_id.nullable())),
+            )))
+            .order(file_metadata::created_at.asc())
+            .limit(limit);
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(DatabaseError::Others)
+            .attach_printable("Error filtering purgeable files")
+    }
+
+    pub async fn delete_by_merchant_id_file_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        file_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_delete_with_results::<<Self as HasTable>::Table, _, _>(
+            conn,
+            file_metadata::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(file_metadata::file_id.eq_any(file_ids)),
+        )
+        .await
+    }
+}
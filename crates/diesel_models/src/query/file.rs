@@ -1,4 +1,4 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
 
 use super::generics;
 use crate::{
@@ -29,6 +29,28 @@ impl FileMetadata {
         .await
     }
 
+    pub async fn find_by_merchant_id_file_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        file_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::file_id.eq_any(file_ids)),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
     pub async fn delete_by_merchant_id_file_id(
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -0,0 +1,49 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    enums as storage_enums,
+    legal_hold::{LegalHold, LegalHoldNew},
+    schema::legal_hold::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl LegalHoldNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<LegalHold> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl LegalHold {
+    pub async fn find_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            limit,
+            offset,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_entity_type_record_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        entity_type: storage_enums::RetentionEntity,
+        record_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::entity_type.eq(entity_type))
+                .and(dsl::record_id.eq(record_id.to_owned())),
+        )
+        .await
+    }
+}
@@ -1,7 +1,15 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
-use error_stack::report;
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, sql_types, BoolExpressionMethods,
+    ExpressionMethods, Table,
+};
+use error_stack::{report, ResultExt};
+use router_env::logger;
 
-use super::generics;
+use super::generics::{
+    self,
+    db_metrics::{track_database_call, DatabaseOperation},
+};
 use crate::{errors, mandate::*, schema::mandate::dsl, PgPooledConn, StorageResult};
 
 impl MandateNew {
@@ -88,4 +96,97 @@ impl Mandate {
                 .attach_printable("Error while updating mandate")
         })
     }
+
+    /// Add the amount of a payment to the amount captured of the mandate, only if the amount
+    /// captured remains within `total_amount` and the mandate has not been charged since
+    /// `period_start`. The constraints are checked and the usage is recorded in the same
+    /// statement, so that concurrent payments cannot both be charged against what remains of the
+    /// mandate. Returns `None` if the payment would exceed the constraints of the mandate.
+    pub async fn reserve_usage_by_merchant_id_mandate_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        mandate_id: &str,
+        amount: i64,
+        total_amount: Option<i64>,
+        period_start: Option<time::PrimitiveDateTime>,
+    ) -> StorageResult<Option<Self>> {
+        let is_within_constraints = diesel::dsl::sql::<sql_types::Bool>("(")
+            .bind::<sql_types::Nullable<sql_types::BigInt>, _>(total_amount)
+            .sql(" IS NULL OR COALESCE(amount_captured, 0) + ")
+            .bind::<sql_types::BigInt, _>(amount)
+            .sql(" <= ")
+            .bind::<sql_types::Nullable<sql_types::BigInt>, _>(total_amount)
+            .sql(") AND (")
+            .bind::<sql_types::Nullable<sql_types::Timestamp>, _>(period_start)
+            .sql(" IS NULL OR last_charged_at IS NULL OR last_charged_at < ")
+            .bind::<sql_types::Nullable<sql_types::Timestamp>, _>(period_start)
+            .sql(")");
+        let query = diesel::update(<Self as HasTable>::table())
+            .filter(
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::mandate_id.eq(mandate_id.to_owned()))
+                    .and(is_within_constraints),
+            )
+            .set((
+                dsl::amount_captured.eq(
+                    diesel::dsl::sql::<sql_types::Nullable<sql_types::BigInt>>(
+                        "COALESCE(amount_captured, 0) + ",
+                    )
+                    .bind::<sql_types::BigInt, _>(amount),
+                ),
+                dsl::last_charged_at.eq(common_utils::date_time::now()),
+            ));
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Update)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error while reserving mandate usage")
+            .map(|mandates: Vec<Self>| mandates.into_iter().next())
+    }
+
+    /// Subtract the amount of a payment which failed from the amount captured of the mandate. The
+    /// charge of the mandate recorded since `charged_since`, if provided, is cleared, so that the
+    /// mandate can be charged again in the period.
+    pub async fn release_usage_by_merchant_id_mandate_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        mandate_id: &str,
+        amount: i64,
+        charged_since: Option<time::PrimitiveDateTime>,
+    ) -> StorageResult<Self> {
+        let query = diesel::update(<Self as HasTable>::table())
+            .filter(
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::mandate_id.eq(mandate_id.to_owned())),
+            )
+            .set((
+                dsl::amount_captured.eq(
+                    diesel::dsl::sql::<sql_types::Nullable<sql_types::BigInt>>(
+                        "GREATEST(COALESCE(amount_captured, 0) - ",
+                    )
+                    .bind::<sql_types::BigInt, _>(amount)
+                    .sql(", 0)"),
+                ),
+                dsl::last_charged_at.eq(diesel::dsl::sql::<
+                    sql_types::Nullable<sql_types::Timestamp>,
+                >("CASE WHEN last_charged_at >= ")
+                .bind::<sql_types::Nullable<sql_types::Timestamp>, _>(charged_since)
+                .sql(" THEN NULL ELSE last_charged_at END")),
+            ));
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Update)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error while releasing mandate usage")?
+            .into_iter()
+            .next()
+            .ok_or(report!(errors::DatabaseError::NotFound))
+            .attach_printable("Mandate not found")
+    }
 }
@@ -1,13 +1,25 @@
-use diesel::{associations::HasTable, ExpressionMethods, Table};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, result::Error as DieselError,
+    BoolExpressionMethods, ExpressionMethods, QueryDsl, Table,
+};
+use error_stack::{report, ResultExt};
+use router_env::logger;
 
-use super::generics;
+use super::generics::{
+    self,
+    db_metrics::{track_database_call, DatabaseOperation},
+};
 #[cfg(feature = "v1")]
 use crate::schema::merchant_account::dsl::{self, merchant_id as dsl_identifier};
 #[cfg(feature = "v2")]
 use crate::schema_v2::merchant_account::dsl::{self, id as dsl_identifier};
 use crate::{
-    errors,
-    merchant_account::{MerchantAccount, MerchantAccountNew, MerchantAccountUpdateInternal},
+    enums as storage_enums, errors,
+    merchant_account::{
+        MerchantAccount, MerchantAccountNew, MerchantAccountOnboardingStatusUpdate,
+        MerchantAccountUpdateInternal,
+    },
     PgPooledConn, StorageResult,
 };
 
@@ -56,6 +68,32 @@ impl MerchantAccount {
         .await
     }
 
+    /// Move the merchant account to the new onboarding status, only if it is still in the current
+    /// status. This ensures that only one of multiple concurrent transitions from a status succeeds.
+    pub async fn update_onboarding_status(
+        conn: &PgPooledConn,
+        identifier: &common_utils::id_type::MerchantId,
+        current_status: storage_enums::OnboardingStatus,
+        new_status: storage_enums::OnboardingStatus,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl_identifier
+                .eq(identifier.to_owned())
+                .and(dsl::onboarding_status.eq(current_status)),
+            MerchantAccountOnboardingStatusUpdate {
+                onboarding_status: new_status,
+                modified_at: common_utils::date_time::now(),
+            },
+        )
+        .await
+    }
+
     pub async fn delete_by_merchant_id(
         conn: &PgPooledConn,
         identifier: &common_utils::id_type::MerchantId,
@@ -78,6 +116,28 @@ impl MerchantAccount {
         .await
     }
 
+    /// Find the merchant account and lock it until the end of the transaction, so that it is not
+    /// changed by another transaction before the transaction updates it
+    pub async fn find_by_merchant_id_for_update(
+        conn: &PgPooledConn,
+        identifier: &common_utils::id_type::MerchantId,
+    ) -> StorageResult<Self> {
+        let query = <Self as HasTable>::table()
+            .filter(dsl_identifier.eq(identifier.to_owned()))
+            .for_update();
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_result_async(conn), DatabaseOperation::FindOne)
+            .await
+            .map_err(|error| match error {
+                DieselError::NotFound => {
+                    report!(error).change_context(errors::DatabaseError::NotFound)
+                }
+                _ => report!(error).change_context(errors::DatabaseError::Others),
+            })
+            .attach_printable("Error finding merchant account for update")
+    }
+
     pub async fn find_by_publishable_key(
         conn: &PgPooledConn,
         publishable_key: &str,
@@ -0,0 +1,30 @@
+use diesel::{associations::HasTable, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    merchant_onboarding_audit_log::{MerchantOnboardingAuditLog, MerchantOnboardingAuditLogNew},
+    schema::merchant_onboarding_audit_log::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl MerchantOnboardingAuditLogNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<MerchantOnboardingAuditLog> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl MerchantOnboardingAuditLog {
+    pub async fn find_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+}
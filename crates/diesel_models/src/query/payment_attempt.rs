@@ -1,9 +1,9 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use async_bb8_diesel::AsyncRunQueryDsl;
 use diesel::{
     associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
-    QueryDsl, Table,
+    PgJsonbExpressionMethods, QueryDsl, Table,
 };
 use error_stack::{report, ResultExt};
 
@@ -98,6 +98,21 @@ impl PaymentAttempt {
         .await
     }
 
+    /// Find the payment attempts with the provided attempt ids, across all the merchants
+    #[cfg(feature = "v1")]
+    pub async fn find_by_attempt_ids(
+        conn: &PgPooledConn,
+        attempt_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(conn, dsl::attempt_id.eq_any(attempt_ids), None, None, None)
+        .await
+    }
+
     #[cfg(feature = "v1")]
     pub async fn find_by_connector_transaction_id_payment_id_merchant_id(
         conn: &PgPooledConn,
@@ -180,6 +195,23 @@ impl PaymentAttempt {
         .await
     }
 
+    #[cfg(feature = "v1")]
+    pub async fn find_by_merchant_id_connector_connector_txn_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector: &str,
+        connector_txn_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::connector.eq(connector.to_owned()))
+                .and(dsl::connector_transaction_id.eq(connector_txn_id.to_owned())),
+        )
+        .await
+    }
+
     #[cfg(feature = "v1")]
     pub async fn find_by_merchant_id_attempt_id(
         conn: &PgPooledConn,
@@ -407,4 +439,174 @@ impl PaymentAttempt {
         .change_context(DatabaseError::Others)
         .attach_printable("Error filtering count of payments")
     }
+
+    #[cfg(feature = "v1")]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn filter_by_merchant_id_tag(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        tag_key: &str,
+        tag_value: &str,
+        profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+        status: Option<Vec<enums::AttemptStatus>>,
+        time_range: Option<common_utils::types::TimeRange>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        let tag = common_utils::types::PaymentAttemptTags::from(HashMap::from([(
+            tag_key.to_owned(),
+            tag_value.to_owned(),
+        )]));
+
+        // The containment operator can make use of the GIN index on the tags
+        let mut filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::tags.contains(tag))
+            .order(dsl::created_at.desc())
+            .into_boxed();
+
+        if let Some(profile_id_list) = profile_id_list {
+            filter = filter.filter(dsl::profile_id.eq_any(profile_id_list));
+        }
+        if let Some(status) = status {
+            filter = filter.filter(dsl::status.eq_any(status));
+        }
+        if let Some(time_range) = time_range {
+            filter = filter.filter(dsl::created_at.ge(time_range.start_time));
+            if let Some(end_time) = time_range.end_time {
+                filter = filter.filter(dsl::created_at.le(end_time));
+            }
+        }
+        if let Some(limit) = limit {
+            filter = filter.limit(limit);
+        }
+        if let Some(offset) = offset {
+            filter = filter.offset(offset);
+        }
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Error filtering payment attempts by tag")
+    }
+
+    #[cfg(feature = "v1")]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn filter_by_merchant_id_pending_review(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+        connector: Option<Vec<String>>,
+        min_fraud_score: Option<i32>,
+        created_before: Option<time::PrimitiveDateTime>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        // The oldest payments are the closest to being resolved by the review timeout
+        let mut filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::fraud_decision.eq(enums::FraudDecision::Review))
+            .order(dsl::created_at.asc())
+            .into_boxed();
+
+        if let Some(profile_id_list) = profile_id_list {
+            filter = filter.filter(dsl::profile_id.eq_any(profile_id_list));
+        }
+        if let Some(connector) = connector {
+            filter = filter.filter(dsl::connector.eq_any(connector));
+        }
+        if let Some(min_fraud_score) = min_fraud_score {
+            filter = filter.filter(dsl::fraud_score.ge(min_fraud_score));
+        }
+        if let Some(created_before) = created_before {
+            filter = filter.filter(dsl::created_at.lt(created_before));
+        }
+        if let Some(limit) = limit {
+            filter = filter.limit(limit);
+        }
+        if let Some(offset) = offset {
+            filter = filter.offset(offset);
+        }
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Error filtering payment attempts pending review")
+    }
+
+    /// The amounts of the captured payment attempts of the merchant which are expected to be
+    /// settled on the date, summed by currency
+    #[cfg(feature = "v1")]
+    pub async fn get_settlement_amounts_by_currency(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        settlement_date: time::Date,
+    ) -> StorageResult<Vec<(enums::Currency, common_utils::types::MinorUnit)>> {
+        // The amount to capture and the net amount are preferred to the amount of the attempt, as
+        // they include the partial captures and the surcharges respectively
+        let settlement_amount = diesel::dsl::sql::<diesel::sql_types::BigInt>(
+            "CAST(SUM(COALESCE(amount_to_capture, net_amount, amount)) AS BIGINT)",
+        );
+        let filter = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::expected_settlement_date.eq(settlement_date))
+            .filter(dsl::status.eq_any(vec![
+                enums::AttemptStatus::Charged,
+                enums::AttemptStatus::PartialCharged,
+                enums::AttemptStatus::PartialChargedAndChargeable,
+            ]))
+            .filter(dsl::currency.is_not_null())
+            .group_by(dsl::currency)
+            .select((dsl::currency, settlement_amount));
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&filter).to_string());
+
+        let settlements = db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_results_async::<(Option<enums::Currency>, i64)>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Error summing the settlement amounts of payment attempts")?;
+
+        Ok(settlements
+            .into_iter()
+            .filter_map(|(currency, amount)| {
+                currency.map(|currency| (currency, common_utils::types::MinorUnit::new(amount)))
+            })
+            .collect())
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn get_distinct_card_hash_count(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        time_range: common_utils::types::TimeRange,
+    ) -> StorageResult<i64> {
+        let mut filter = <Self as HasTable>::table()
+            .select(diesel::dsl::count_distinct(dsl::card_hash))
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::created_at.ge(time_range.start_time))
+            .into_boxed();
+
+        if let Some(end_time) = time_range.end_time {
+            filter = filter.filter(dsl::created_at.le(end_time));
+        }
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&filter).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_result_async::<i64>(conn),
+            db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Error counting the distinct card hashes of payment attempts")
+    }
 }
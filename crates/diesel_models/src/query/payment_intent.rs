@@ -1,3 +1,4 @@
+use common_utils::errors::{CustomResult, ValidationError};
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
 
 use super::generics;
@@ -13,6 +14,70 @@ use crate::{
     PgPooledConn, StorageResult,
 };
 
+/// A key of the metadata of the payments, which is a path of keys separated by `.` for a value
+/// nested within the metadata, such as `order.reference` for `{"order": {"reference": "..."}}`.
+///
+/// The path of the key is inlined in the query rather than bound to it, so that the query can be
+/// served by an index on the key. Only the keys with such an index are efficiently queryable, the
+/// queries on the other keys scan all the payments of the merchant. The index on a key is
+/// configured by the operator for the keys the merchants search by, for instance
+///
+/// ```sql
+/// CREATE INDEX CONCURRENTLY payment_intent_metadata_order_reference_index
+///     ON payment_intent (merchant_id, (metadata #>> '{order,reference}'));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PaymentMetadataKey {
+    path: Vec<String>,
+}
+
+impl PaymentMetadataKey {
+    /// Parse the key, whose keys may only consist of alphanumeric characters, `_` and `-`
+    pub fn new(key: &str) -> CustomResult<Self, ValidationError> {
+        let path = key
+            .split('.')
+            .map(|path_key| {
+                let is_valid = !path_key.is_empty()
+                    && path_key
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+
+                is_valid.then(|| path_key.to_owned())
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| ValidationError::InvalidValue {
+                message: format!(
+                    "metadata key `{key}` should consist of keys separated by `.`, each of which \
+                     may only contain alphanumeric characters, `_` and `-`"
+                ),
+            })?;
+
+        Ok(Self { path })
+    }
+
+    /// The path of the key as a postgres text array literal, such as `{order,reference}`
+    #[cfg(feature = "v1")]
+    fn get_path_literal(&self) -> String {
+        format!("{{{}}}", self.path.join(","))
+    }
+
+    /// Whether the value at the key of the metadata is the given value. A value which is not a
+    /// string is compared by its JSON representation, and a `null` value does not match, as in the
+    /// query.
+    pub fn matches(&self, metadata: &serde_json::Value, value: &str) -> bool {
+        let metadata_value = self
+            .path
+            .iter()
+            .try_fold(metadata, |metadata, path_key| metadata.get(path_key));
+
+        match metadata_value {
+            None | Some(serde_json::Value::Null) => false,
+            Some(serde_json::Value::String(metadata_value)) => metadata_value == value,
+            Some(metadata_value) => metadata_value.to_string() == value,
+        }
+    }
+}
+
 impl PaymentIntentNew {
     pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentIntent> {
         generics::generic_insert(conn, self).await
@@ -133,4 +198,104 @@ impl PaymentIntent {
         )
         .await
     }
+
+    /// Find the payments of the merchant with the value at the key of their metadata, the most
+    /// recently created first
+    #[cfg(feature = "v1")]
+    pub async fn find_by_merchant_id_metadata_value(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        key: &PaymentMetadataKey,
+        value: &str,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        // The path is validated to only contain characters which need no quoting
+        let metadata_value = diesel::dsl::sql::<diesel::sql_types::Bool>(&format!(
+            "(metadata #>> '{}') = ",
+            key.get_path_literal()
+        ))
+        .bind::<diesel::sql_types::Text, _>(value.to_owned());
+
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(metadata_value),
+            Some(limit),
+            Some(offset),
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    /// Find the payments of the chain of related payments which starts at the root payment,
+    /// including the root payment itself
+    #[cfg(feature = "v1")]
+    pub async fn find_by_root_payment_id_merchant_id(
+        conn: &PgPooledConn,
+        root_payment_id: &common_utils::id_type::PaymentId,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()).and(
+                dsl::root_payment_id
+                    .eq(root_payment_id.to_owned())
+                    .or(dsl::payment_id.eq(root_payment_id.to_owned())),
+            ),
+            Some(limit),
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::PaymentMetadataKey;
+
+    #[test]
+    fn test_metadata_key_is_parsed_into_its_path() {
+        assert!(PaymentMetadataKey::new("order.reference").is_ok());
+        assert!(PaymentMetadataKey::new("order_id-1").is_ok());
+        assert!(PaymentMetadataKey::new("").is_err());
+        assert!(PaymentMetadataKey::new("order..reference").is_err());
+        assert!(PaymentMetadataKey::new("order.reference.").is_err());
+        assert!(PaymentMetadataKey::new("order'reference").is_err());
+        assert!(PaymentMetadataKey::new("order,reference").is_err());
+    }
+
+    #[test]
+    fn test_metadata_key_matches_the_nested_string_value() {
+        let key = PaymentMetadataKey::new("order.reference").unwrap();
+        let metadata = serde_json::json!({"order": {"reference": "ord_123"}});
+
+        assert!(key.matches(&metadata, "ord_123"));
+        assert!(!key.matches(&metadata, "ord_456"));
+        assert!(!key.matches(&metadata, "\"ord_123\""));
+    }
+
+    #[test]
+    fn test_metadata_key_matches_other_values_by_their_json_representation() {
+        let key = PaymentMetadataKey::new("order.quantity").unwrap();
+
+        assert!(key.matches(&serde_json::json!({"order": {"quantity": 2}}), "2"));
+        assert!(key.matches(&serde_json::json!({"order": {"quantity": true}}), "true"));
+        assert!(key.matches(&serde_json::json!({"order": {"quantity": [1, 2]}}), "[1,2]"));
+    }
+
+    #[test]
+    fn test_metadata_key_does_not_match_a_missing_or_null_value() {
+        let key = PaymentMetadataKey::new("order.reference").unwrap();
+
+        assert!(!key.matches(&serde_json::json!({"order": {}}), "ord_123"));
+        assert!(!key.matches(&serde_json::json!({"order": "ord_123"}), "ord_123"));
+        assert!(!key.matches(&serde_json::json!({"order": {"reference": null}}), "null"));
+        assert!(!key.matches(&serde_json::Value::Null, "null"));
+    }
 }
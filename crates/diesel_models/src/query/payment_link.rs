@@ -1,4 +1,5 @@
-use diesel::{associations::HasTable, ExpressionMethods};
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use time::PrimitiveDateTime;
 
 use super::generics;
 use crate::{
@@ -24,4 +25,36 @@ impl PaymentLink {
         )
         .await
     }
+
+    /// Find the payment links which expired before the time, oldest first
+    pub async fn find_expired_before(
+        conn: &PgPooledConn,
+        expired_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::fulfilment_time.lt(expired_before),
+            Some(limit),
+            None,
+            Some(dsl::fulfilment_time.asc()),
+        )
+        .await
+    }
+
+    /// Delete the payment links with the ids which expired before the time, returning the deleted
+    /// payment links
+    pub async fn delete_expired_before_by_payment_link_ids(
+        conn: &PgPooledConn,
+        payment_link_ids: Vec<String>,
+        expired_before: PrimitiveDateTime,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_delete_with_results::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::payment_link_id
+                .eq_any(payment_link_ids)
+                .and(dsl::fulfilment_time.lt(expired_before)),
+        )
+        .await
+    }
 }
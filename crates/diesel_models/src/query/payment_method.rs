@@ -183,6 +183,45 @@ impl PaymentMethod {
         .await
     }
 
+    pub async fn find_for_retokenization(
+        conn: &PgPooledConn,
+        criteria: &payment_method::PaymentMethodRetokenizationCriteria,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = <Self as HasTable>::table()
+            .filter(
+                dsl::merchant_id
+                    .eq(criteria.merchant_id.to_owned())
+                    .and(dsl::payment_method.eq(storage_enums::PaymentMethod::Card))
+                    .and(dsl::status.eq(storage_enums::PaymentMethodStatus::Active)),
+            )
+            .order(dsl::payment_method_id.asc())
+            .limit(limit)
+            .into_boxed();
+
+        if criteria.retokenization_pending_only {
+            query = query.filter(dsl::retokenization_pending.eq(true));
+        }
+
+        if let Some(starting_after) = &criteria.starting_after {
+            query = query.filter(dsl::payment_method_id.gt(starting_after.to_owned()));
+        }
+
+        if let Some(last_modified_before) = criteria.last_modified_before {
+            query = query.filter(dsl::last_modified.lt(last_modified_before));
+        }
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async(conn),
+            generics::db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to find payment methods for retokenization")
+    }
+
     pub async fn update_with_payment_method_id(
         self,
         conn: &PgPooledConn,
@@ -53,6 +53,20 @@ impl Refund {
         .await
     }
 
+    /// Find the refunds with the provided refund ids, across all the merchants
+    pub async fn find_by_refund_ids(
+        conn: &PgPooledConn,
+        refund_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(conn, dsl::refund_id.eq_any(refund_ids), None, None, None)
+        .await
+    }
+
     pub async fn find_by_merchant_id_connector_refund_id_connector(
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -0,0 +1,91 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, query_dsl::methods::FilterDsl, upsert::excluded,
+    BoolExpressionMethods, ExpressionMethods,
+};
+use error_stack::ResultExt;
+use router_env::logger;
+
+use super::generics::{
+    self,
+    db_metrics::{track_database_call, DatabaseOperation},
+};
+use crate::{
+    errors::DatabaseError,
+    resource_sequence::{ResourceSequence, ResourceSequenceNew},
+    schema::resource_sequence::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ResourceSequenceNew {
+    /// Issue the next value of the sequence, creating the sequence with the value of `self` if it
+    /// does not exist. The issued value is the `last_value` of the returned sequence.
+    pub async fn increment(self, conn: &PgPooledConn) -> StorageResult<ResourceSequence> {
+        let query = diesel::insert_into(<ResourceSequence as HasTable>::table())
+            .values(self)
+            .on_conflict((dsl::namespace, dsl::resource_id))
+            .do_update()
+            .set((
+                dsl::last_value.eq(dsl::last_value + 1),
+                dsl::modified_at.eq(excluded(dsl::modified_at)),
+            ));
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<ResourceSequence, _, _>(
+            query.get_result_async(conn),
+            DatabaseOperation::Insert,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Error while incrementing resource sequence")
+    }
+
+    /// Reserve the values of the sequence from `first_value` up to the last value of `self`,
+    /// creating the sequence with the last value if it does not exist, or raising the last value
+    /// of the sequence to it if `first_value` is higher than every value issued or reserved from
+    /// the sequence. Returns the sequence, which is `None` when `first_value` is not higher than
+    /// the last value of the sequence.
+    pub async fn reserve_from(
+        self,
+        conn: &PgPooledConn,
+        first_value: i64,
+    ) -> StorageResult<Option<ResourceSequence>> {
+        let query = diesel::insert_into(<ResourceSequence as HasTable>::table())
+            .values(self)
+            .on_conflict((dsl::namespace, dsl::resource_id))
+            .do_update()
+            .set((
+                dsl::last_value.eq(excluded(dsl::last_value)),
+                dsl::modified_at.eq(excluded(dsl::modified_at)),
+            ))
+            .filter(dsl::last_value.lt(first_value));
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<ResourceSequence, _, _>(
+            query.get_results_async(conn),
+            DatabaseOperation::Insert,
+        )
+        .await
+        .map(|sequences| sequences.into_iter().next())
+        .change_context(DatabaseError::Others)
+        .attach_printable("Error while reserving resource sequence values")
+    }
+}
+
+impl ResourceSequence {
+    pub async fn find_optional_by_namespace_resource_id(
+        conn: &PgPooledConn,
+        namespace: &str,
+        resource_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::namespace
+                .eq(namespace.to_owned())
+                .and(dsl::resource_id.eq(resource_id.to_owned())),
+        )
+        .await
+    }
+}
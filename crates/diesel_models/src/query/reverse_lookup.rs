@@ -1,8 +1,10 @@
-use diesel::{associations::HasTable, ExpressionMethods};
+use diesel::{
+    associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table, TextExpressionMethods,
+};
 
 use super::generics;
 use crate::{
-    reverse_lookup::{ReverseLookup, ReverseLookupNew},
+    reverse_lookup::{get_reverse_lookup_namespace_prefix, ReverseLookup, ReverseLookupNew},
     schema::reverse_lookup::dsl,
     PgPooledConn, StorageResult,
 };
@@ -28,4 +30,142 @@ impl ReverseLookup {
         )
         .await
     }
+
+    pub async fn find_by_pk_id(pk_id: &str, conn: &PgPooledConn) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(conn, dsl::pk_id.eq(pk_id.to_owned()), None, None, None)
+        .await
+    }
+
+    pub async fn find_by_source(
+        source: &str,
+        limit: Option<i64>,
+        offset: Option<i64>,
+        conn: &PgPooledConn,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::source.eq(source.to_owned()),
+            limit,
+            offset,
+            Some(dsl::lookup_id.asc()),
+        )
+        .await
+    }
+
+    /// Find a page of the reverse lookups inserted by any of the sources, in the order of their
+    /// lookup ids, starting after the provided lookup id
+    pub async fn find_by_sources_after_lookup_id(
+        sources: Vec<String>,
+        starting_after: Option<&str>,
+        limit: i64,
+        conn: &PgPooledConn,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::source
+                .eq_any(sources)
+                .and(dsl::lookup_id.gt(starting_after.unwrap_or_default().to_owned())),
+            Some(limit),
+            None,
+            Some(dsl::lookup_id.asc()),
+        )
+        .await
+    }
+
+    pub async fn find_by_lookup_ids(
+        lookup_ids: Vec<String>,
+        conn: &PgPooledConn,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(conn, dsl::lookup_id.eq_any(lookup_ids), None, None, None)
+        .await
+    }
+
+    /// Delete the reverse lookups with the provided lookup ids, returning the deleted lookups
+    pub async fn delete_by_lookup_ids(
+        lookup_ids: Vec<String>,
+        conn: &PgPooledConn,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_delete_with_results::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::lookup_id.eq_any(lookup_ids),
+        )
+        .await
+    }
+
+    /// Delete all the reverse lookups pointing to `pk_id`, returning the deleted lookups.
+    ///
+    /// Deleting when no lookups exist is not an error, so that the deletion can be retried.
+    pub async fn delete_by_pk_id(pk_id: &str, conn: &PgPooledConn) -> StorageResult<Vec<Self>> {
+        generics::generic_delete_with_results::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::pk_id.eq(pk_id.to_owned()),
+        )
+        .await
+    }
+
+    /// Delete all the reverse lookups pointing to any of the `pk_ids`, returning the deleted lookups
+    pub async fn delete_by_pk_ids(
+        pk_ids: Vec<String>,
+        conn: &PgPooledConn,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_delete_with_results::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::pk_id.eq_any(pk_ids),
+        )
+        .await
+    }
+
+    /// Find all the reverse lookups in the namespace of the merchant
+    pub async fn find_by_namespace(
+        merchant_id: &common_utils::id_type::MerchantId,
+        conn: &PgPooledConn,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::lookup_id.like(get_namespace_pattern(merchant_id)),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
+    /// Delete all the reverse lookups in the namespace of the merchant, returning the deleted
+    /// lookups. The lookups of the merchant which were inserted before they were namespaced are
+    /// not deleted.
+    pub async fn delete_by_namespace(
+        merchant_id: &common_utils::id_type::MerchantId,
+        conn: &PgPooledConn,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_delete_with_results::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::lookup_id.like(get_namespace_pattern(merchant_id)),
+        )
+        .await
+    }
+}
+
+/// The `LIKE` pattern matching the lookup ids in the namespace of the merchant, with the wildcards
+/// in the merchant id escaped
+fn get_namespace_pattern(merchant_id: &common_utils::id_type::MerchantId) -> String {
+    let prefix = get_reverse_lookup_namespace_prefix(merchant_id)
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("{prefix}%")
 }
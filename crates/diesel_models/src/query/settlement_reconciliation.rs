@@ -0,0 +1,79 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, upsert::excluded, BoolExpressionMethods,
+    ExpressionMethods,
+};
+use error_stack::ResultExt;
+use router_env::logger;
+
+use super::generics::{
+    self,
+    db_metrics::{track_database_call, DatabaseOperation},
+};
+use crate::{
+    enums as storage_enums,
+    errors::DatabaseError,
+    schema::settlement_reconciliation::dsl,
+    settlement_reconciliation::{SettlementReconciliation, SettlementReconciliationNew},
+    PgPooledConn, StorageResult,
+};
+
+impl SettlementReconciliation {
+    /// Insert the line items of a settlement file. Line items which were ingested before, for
+    /// example when the same settlement file is ingested again, are reconciled again.
+    ///
+    /// The line item identifiers must be distinct within the batch, and the batch must be small
+    /// enough for its bind parameters to fit in a single statement.
+    pub async fn bulk_upsert(
+        conn: &PgPooledConn,
+        line_items: Vec<SettlementReconciliationNew>,
+    ) -> StorageResult<Vec<Self>> {
+        let query = diesel::insert_into(<Self as HasTable>::table())
+            .values(line_items)
+            .on_conflict((
+                dsl::merchant_id,
+                dsl::connector_name,
+                dsl::settlement_id,
+                dsl::line_item_id,
+            ))
+            .do_update()
+            .set((
+                dsl::transaction_type.eq(excluded(dsl::transaction_type)),
+                dsl::connector_transaction_id.eq(excluded(dsl::connector_transaction_id)),
+                dsl::amount.eq(excluded(dsl::amount)),
+                dsl::currency.eq(excluded(dsl::currency)),
+                dsl::settled_at.eq(excluded(dsl::settled_at)),
+                dsl::payment_id.eq(excluded(dsl::payment_id)),
+                dsl::attempt_id.eq(excluded(dsl::attempt_id)),
+                dsl::refund_id.eq(excluded(dsl::refund_id)),
+                dsl::reconciliation_status.eq(excluded(dsl::reconciliation_status)),
+                dsl::modified_at.eq(excluded(dsl::modified_at)),
+            ));
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Insert)
+            .await
+            .change_context(DatabaseError::Others)
+            .attach_printable("Error while upserting settlement reconciliation line items")
+    }
+
+    pub async fn find_by_merchant_id_reconciliation_statuses(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        reconciliation_statuses: Vec<storage_enums::SettlementReconciliationStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::reconciliation_status.eq_any(reconciliation_statuses)),
+            Some(limit),
+            Some(offset),
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+}
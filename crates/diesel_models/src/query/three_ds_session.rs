@@ -0,0 +1,76 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    enums as storage_enums,
+    schema::three_ds_session::dsl,
+    three_ds_session::{ThreeDsSession, ThreeDsSessionNew, ThreeDsSessionUpdate},
+    PgPooledConn, StorageResult,
+};
+
+impl ThreeDsSessionNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ThreeDsSession> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ThreeDsSession {
+    pub async fn find_by_session_id(conn: &PgPooledConn, session_id: &str) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::session_id.eq(session_id.to_owned()),
+        )
+        .await
+    }
+
+    /// Update the session only if it is still in the provided status, so that concurrent steps of
+    /// the flow cannot both move the session forward. Returns a not found error if the session is
+    /// no longer in the provided status.
+    pub async fn update_by_session_id_status(
+        conn: &PgPooledConn,
+        session_id: &str,
+        current_status: storage_enums::ThreeDsSessionStatus,
+        session_update: ThreeDsSessionUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::session_id
+                .eq(session_id.to_owned())
+                .and(dsl::status.eq(current_status)),
+            session_update,
+        )
+        .await
+    }
+
+    /// The oldest sessions which expired before the cutoff
+    pub async fn find_expired_before(
+        conn: &PgPooledConn,
+        expired_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::expires_at.lt(expired_before),
+            Some(limit),
+            None,
+            Some(dsl::expires_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn delete_by_session_ids(
+        conn: &PgPooledConn,
+        session_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_delete_with_results::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::session_id.eq_any(session_ids),
+        )
+        .await
+    }
+}
@@ -0,0 +1,97 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, upsert::excluded, BoolExpressionMethods,
+    ExpressionMethods,
+};
+use error_stack::ResultExt;
+use router_env::logger;
+
+use super::generics::{
+    self,
+    db_metrics::{track_database_call, DatabaseOperation},
+};
+use crate::{
+    enums as storage_enums,
+    errors::DatabaseError,
+    schema::webhook_event_sequence::dsl,
+    webhook_event_sequence::{
+        WebhookEventSequence, WebhookEventSequenceNew, WebhookEventSequenceUpdateInternal,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl WebhookEventSequenceNew {
+    /// Assign the next sequence number of the resource, creating the sequence state of the
+    /// resource if it does not exist. The assigned sequence number is the `last_sequence_number`
+    /// of the returned state.
+    pub async fn increment_sequence_number(
+        self,
+        conn: &PgPooledConn,
+    ) -> StorageResult<WebhookEventSequence> {
+        let query = diesel::insert_into(<WebhookEventSequence as HasTable>::table())
+            .values(self)
+            .on_conflict((
+                dsl::merchant_id,
+                dsl::primary_object_type,
+                dsl::primary_object_id,
+            ))
+            .do_update()
+            .set((
+                dsl::last_sequence_number.eq(dsl::last_sequence_number + 1),
+                dsl::modified_at.eq(excluded(dsl::modified_at)),
+            ));
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<WebhookEventSequence, _, _>(
+            query.get_result_async(conn),
+            DatabaseOperation::Insert,
+        )
+        .await
+        .change_context(DatabaseError::Others)
+        .attach_printable("Error while incrementing webhook event sequence number")
+    }
+}
+
+impl WebhookEventSequence {
+    pub async fn find_optional_by_merchant_id_primary_object(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        primary_object_type: storage_enums::EventObjectType,
+        primary_object_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::primary_object_type.eq(primary_object_type))
+                .and(dsl::primary_object_id.eq(primary_object_id.to_owned())),
+        )
+        .await
+    }
+
+    /// Record the acknowledgement of the event with the provided sequence number. The last
+    /// acknowledged sequence number is never moved backwards, so that an acknowledgement of an
+    /// event delivered out of order does not unblock the events waiting on a later event.
+    pub async fn acknowledge_sequence_number(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        primary_object_type: storage_enums::EventObjectType,
+        primary_object_id: &str,
+        sequence_number: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::primary_object_type.eq(primary_object_type))
+                .and(dsl::primary_object_id.eq(primary_object_id.to_owned()))
+                .and(dsl::last_acknowledged_sequence_number.lt(sequence_number)),
+            WebhookEventSequenceUpdateInternal {
+                last_acknowledged_sequence_number: sequence_number,
+                modified_at: common_utils::date_time::now(),
+            },
+        )
+        .await
+    }
+}
@@ -289,6 +289,16 @@ impl RefundUpdate {
     }
 }
 
+impl Refund {
+    /// Whether the refund was accepted by the connector and is being processed asynchronously,
+    /// with its outcome to be notified through a webhook or fetched through a refund sync
+    pub fn is_awaiting_connector_confirmation(&self) -> bool {
+        self.sent_to_gateway
+            && self.connector_refund_id.is_some()
+            && self.refund_status == storage_enums::RefundStatus::Pending
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Deserialize, Serialize)]
 pub struct RefundCoreWorkflow {
     pub refund_internal_reference_id: String,
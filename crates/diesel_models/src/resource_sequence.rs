@@ -0,0 +1,44 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::schema::resource_sequence;
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = resource_sequence)]
+pub struct ResourceSequenceNew {
+    pub namespace: String,
+    pub resource_id: String,
+    pub last_value: i64,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+impl ResourceSequenceNew {
+    /// Sequence of the resource in the namespace, of which the last value issued or reserved is
+    /// `last_value`
+    pub fn new(namespace: String, resource_id: String, last_value: i64) -> Self {
+        let now = common_utils::date_time::now();
+        Self {
+            namespace,
+            resource_id,
+            last_value,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(
+    table_name = resource_sequence,
+    primary_key(namespace, resource_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct ResourceSequence {
+    pub namespace: String,
+    pub resource_id: String,
+    /// The highest value issued or reserved from the sequence
+    pub last_value: i64,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
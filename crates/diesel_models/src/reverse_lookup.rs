@@ -1,21 +1,21 @@
+use common_utils::{
+    errors::{CustomResult, ParsingError},
+    ext_traits::ByteSliceExt,
+    id_type,
+    versioned::{VersionedValue, LEGACY_VALUE_VERSION},
+};
 use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use error_stack::{report, ResultExt};
 
 use crate::schema::reverse_lookup;
 
 ///
 /// This reverse lookup table basically looks up id's and get result_id that you want. This is
 /// useful for KV where you can't lookup without key
-#[derive(
-    Clone,
-    Debug,
-    serde::Serialize,
-    serde::Deserialize,
-    Identifiable,
-    Queryable,
-    Selectable,
-    Eq,
-    PartialEq,
-)]
+///
+/// The serialized representation of this struct in redis carries a `version`, refer
+/// [`VersionedValue`] for how values written with an older shape are read.
+#[derive(Clone, Debug, serde::Deserialize, Identifiable, Queryable, Selectable, Eq, PartialEq)]
 #[diesel(table_name = reverse_lookup, primary_key(lookup_id), check_for_backend(diesel::pg::Pg))]
 pub struct ReverseLookup {
     /// Primary key. The key id.
@@ -59,3 +59,169 @@ impl From<ReverseLookupNew> for ReverseLookup {
         }
     }
 }
+
+/// The separator between the namespace of a reverse lookup and its lookup id. Merchant ids cannot
+/// contain it, so that the namespace of a lookup id is unambiguous.
+const REVERSE_LOOKUP_NAMESPACE_SEPARATOR: char = ':';
+
+/// Get the prefix of the lookup ids in the namespace of the merchant
+pub fn get_reverse_lookup_namespace_prefix(merchant_id: &id_type::MerchantId) -> String {
+    format!(
+        "{}{REVERSE_LOOKUP_NAMESPACE_SEPARATOR}",
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Get the lookup id in the namespace of the merchant, which isolates the reverse lookups of the
+/// merchant from those of the other merchants. The lookups inserted before they were namespaced
+/// carry the lookup id without the namespace.
+pub fn get_namespaced_lookup_id(merchant_id: &id_type::MerchantId, lookup_id: &str) -> String {
+    format!(
+        "{}{lookup_id}",
+        get_reverse_lookup_namespace_prefix(merchant_id)
+    )
+}
+
+/// The version with which [`ReverseLookup`] values are currently serialized
+pub const REVERSE_LOOKUP_VALUE_VERSION: u8 = 2;
+
+/// The shape of [`ReverseLookup`] values written before the value was versioned
+#[derive(Clone, Debug, serde::Deserialize)]
+struct ReverseLookupV1 {
+    lookup_id: String,
+    sk_id: String,
+    pk_id: String,
+    source: String,
+    updated_by: String,
+}
+
+impl From<ReverseLookupV1> for ReverseLookup {
+    fn from(value: ReverseLookupV1) -> Self {
+        Self {
+            lookup_id: value.lookup_id,
+            sk_id: value.sk_id,
+            pk_id: value.pk_id,
+            source: value.source,
+            updated_by: value.updated_by,
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+struct ReverseLookupValue<'a> {
+    version: u8,
+    lookup_id: &'a str,
+    sk_id: &'a str,
+    pk_id: &'a str,
+    source: &'a str,
+    updated_by: &'a str,
+}
+
+impl serde::Serialize for ReverseLookup {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        ReverseLookupValue {
+            version: REVERSE_LOOKUP_VALUE_VERSION,
+            lookup_id: &self.lookup_id,
+            sk_id: &self.sk_id,
+            pk_id: &self.pk_id,
+            source: &self.source,
+            updated_by: &self.updated_by,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl VersionedValue for ReverseLookup {
+    const CURRENT_VERSION: u8 = REVERSE_LOOKUP_VALUE_VERSION;
+
+    fn deserialize_version(version: u8, bytes: &[u8]) -> CustomResult<Self, ParsingError> {
+        match version {
+            LEGACY_VALUE_VERSION => bytes
+                .parse_struct::<ReverseLookupV1>("ReverseLookupV1")
+                .map(Self::from),
+            REVERSE_LOOKUP_VALUE_VERSION => bytes.parse_struct("ReverseLookup"),
+            _ => Err(report!(ParsingError::StructParseFailure("ReverseLookup")))
+                .attach_printable(format!("Unsupported reverse lookup version: {version}")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use common_utils::{id_type, versioned::VersionedValue};
+
+    use super::{
+        get_namespaced_lookup_id, get_reverse_lookup_namespace_prefix, ReverseLookup,
+        REVERSE_LOOKUP_VALUE_VERSION,
+    };
+
+    fn reverse_lookup() -> ReverseLookup {
+        ReverseLookup {
+            lookup_id: "pa_conn_trans_merchant_1_txn_1".to_string(),
+            sk_id: "pa_attempt_1".to_string(),
+            pk_id: "mid_merchant_1_pid_payment_1".to_string(),
+            source: "payment_attempt".to_string(),
+            updated_by: "redis_kv".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_v1_value_is_upgraded_to_current_shape() {
+        let v1_value = br#"{
+            "lookup_id": "pa_conn_trans_merchant_1_txn_1",
+            "sk_id": "pa_attempt_1",
+            "pk_id": "mid_merchant_1_pid_payment_1",
+            "source": "payment_attempt",
+            "updated_by": "redis_kv"
+        }"#;
+
+        let (version, value) = ReverseLookup::from_versioned_slice(v1_value).unwrap();
+
+        assert_eq!(version, 1);
+        assert_eq!(value, reverse_lookup());
+    }
+
+    #[test]
+    fn test_current_value_round_trip() {
+        let serialized = serde_json::to_vec(&reverse_lookup()).unwrap();
+
+        let (version, value) = ReverseLookup::from_versioned_slice(&serialized).unwrap();
+
+        assert_eq!(version, REVERSE_LOOKUP_VALUE_VERSION);
+        assert_eq!(value, reverse_lookup());
+    }
+
+    #[test]
+    fn test_unknown_version_fails() {
+        let value = br#"{
+            "version": 200,
+            "lookup_id": "pa_conn_trans_merchant_1_txn_1",
+            "sk_id": "pa_attempt_1",
+            "pk_id": "mid_merchant_1_pid_payment_1",
+            "source": "payment_attempt",
+            "updated_by": "redis_kv"
+        }"#;
+
+        assert!(ReverseLookup::from_versioned_slice(value).is_err());
+    }
+
+    #[test]
+    fn test_namespaced_lookup_ids_are_isolated_per_merchant() {
+        let merchant_1 =
+            id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1")).unwrap();
+        let merchant_2 =
+            id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_2")).unwrap();
+
+        let lookup_id = get_namespaced_lookup_id(&merchant_1, "order_1");
+
+        assert_eq!(lookup_id, "merchant_1:order_1");
+        assert_ne!(lookup_id, get_namespaced_lookup_id(&merchant_2, "order_1"));
+        assert!(lookup_id.starts_with(&get_reverse_lookup_namespace_prefix(&merchant_1)));
+        assert!(!lookup_id.starts_with(&get_reverse_lookup_namespace_prefix(&merchant_2)));
+    }
+}
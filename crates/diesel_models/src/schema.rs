@@ -57,6 +57,27 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    audit_log (id) {
+        id -> Int4,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 255]
+        actor -> Varchar,
+        #[max_length = 32]
+        resource_type -> Varchar,
+        #[max_length = 64]
+        resource_id -> Varchar,
+        #[max_length = 32]
+        action -> Varchar,
+        changes -> Jsonb,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -123,6 +144,36 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    bank_account_token (token_id) {
+        #[max_length = 64]
+        token_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 64]
+        locker_id -> Varchar,
+        #[max_length = 128]
+        fingerprint -> Varchar,
+        #[max_length = 64]
+        masked_account_number -> Varchar,
+        #[max_length = 64]
+        masked_routing_number -> Varchar,
+        #[max_length = 32]
+        verification_status -> Varchar,
+        #[max_length = 255]
+        connector_bank_token -> Nullable<Varchar>,
+        #[max_length = 64]
+        merchant_connector_id -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -287,6 +338,119 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    connector_token_revocation (merchant_id, payment_method_id, merchant_connector_id) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_method_id -> Varchar,
+        #[max_length = 64]
+        merchant_connector_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 64]
+        connector_name -> Varchar,
+        #[max_length = 255]
+        connector_token -> Varchar,
+        #[max_length = 32]
+        revocation_status -> Varchar,
+        #[max_length = 1024]
+        error_message -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    connector_webhook_offset (merchant_id, merchant_connector_id) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 128]
+        merchant_connector_id -> Varchar,
+        #[max_length = 64]
+        connector_name -> Varchar,
+        #[max_length = 255]
+        read_offset -> Nullable<Varchar>,
+        #[max_length = 255]
+        log_id -> Nullable<Varchar>,
+        reset_count -> Int4,
+        last_reset_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    customer_communication (id) {
+        #[max_length = 64]
+        id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        channel -> Varchar,
+        summary -> Bytea,
+        #[max_length = 64]
+        file_id -> Nullable<Varchar>,
+        communicated_at -> Timestamp,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    customer_connector_sync (merchant_id, customer_id, connector_label) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 255]
+        connector_label -> Varchar,
+        #[max_length = 64]
+        connector_name -> Varchar,
+        #[max_length = 32]
+        sync_status -> Varchar,
+        #[max_length = 1024]
+        error_message -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    customer_risk_profile (merchant_id, customer_id) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        risk_score -> Int2,
+        #[max_length = 16]
+        risk_tier -> Varchar,
+        dispute_count -> Int4,
+        lost_dispute_count -> Int4,
+        decline_count -> Int4,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -314,6 +478,9 @@ diesel::table! {
         #[max_length = 64]
         updated_by -> Nullable<Varchar>,
         version -> ApiVersion,
+        tax_ids -> Nullable<Bytea>,
+        #[max_length = 64]
+        preferred_payment_method_id -> Nullable<Varchar>,
     }
 }
 
@@ -412,6 +579,7 @@ diesel::table! {
         response -> Nullable<Bytea>,
         delivery_attempt -> Nullable<WebhookDeliveryAttempt>,
         metadata -> Nullable<Jsonb>,
+        sequence_number -> Nullable<Int8>,
     }
 }
 
@@ -474,6 +642,8 @@ diesel::table! {
         #[max_length = 64]
         last_step -> Varchar,
         payment_capture_method -> Nullable<CaptureMethod>,
+        #[max_length = 255]
+        reviewed_by -> Nullable<Varchar>,
     }
 }
 
@@ -530,6 +700,25 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    incoming_webhook_event (event_id) {
+        #[max_length = 64]
+        event_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        connector -> Varchar,
+        #[max_length = 64]
+        event_type -> Varchar,
+        received_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -555,6 +744,23 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    legal_hold (merchant_id, entity_type, record_id) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 32]
+        entity_type -> Varchar,
+        #[max_length = 64]
+        record_id -> Varchar,
+        #[max_length = 255]
+        reason -> Nullable<Varchar>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -634,6 +840,8 @@ diesel::table! {
         merchant_connector_id -> Nullable<Varchar>,
         #[max_length = 64]
         updated_by -> Nullable<Varchar>,
+        constraints -> Nullable<Jsonb>,
+        last_charged_at -> Nullable<Timestamp>,
     }
 }
 
@@ -678,6 +886,20 @@ diesel::table! {
         payment_link_config -> Nullable<Jsonb>,
         pm_collect_link_config -> Nullable<Jsonb>,
         version -> ApiVersion,
+        #[max_length = 32]
+        onboarding_status -> Varchar,
+        idempotency_ttl_in_seconds -> Nullable<Int8>,
+        default_connector_by_method_type -> Nullable<Jsonb>,
+        customer_pii_encryption_fields -> Nullable<Array<Nullable<Text>>>,
+        #[max_length = 16]
+        statement_descriptor_length_policy -> Nullable<Varchar>,
+        allowed_return_url_domains -> Nullable<Array<Nullable<Text>>>,
+        connector_failover_order -> Nullable<Jsonb>,
+        default_capture_method -> Nullable<CaptureMethod>,
+        least_cost_routing -> Nullable<Jsonb>,
+        transaction_amount_limits -> Nullable<Jsonb>,
+        default_currency -> Nullable<Currency>,
+        require_explicit_currency -> Nullable<Bool>,
     }
 }
 
@@ -718,6 +940,15 @@ diesel::table! {
         additional_merchant_data -> Nullable<Bytea>,
         connector_wallets_details -> Nullable<Bytea>,
         version -> ApiVersion,
+        request_timeout_secs -> Nullable<Int2>,
+        #[max_length = 64]
+        webhook_identifier -> Nullable<Varchar>,
+        #[max_length = 32]
+        environment -> Nullable<Varchar>,
+        maintenance_windows -> Nullable<Jsonb>,
+        pending_connector_account_details -> Nullable<Bytea>,
+        #[max_length = 32]
+        credential_rotation_status -> Nullable<Varchar>,
     }
 }
 
@@ -733,6 +964,26 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    merchant_onboarding_audit_log (id) {
+        id -> Int4,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 32]
+        previous_status -> Varchar,
+        #[max_length = 32]
+        new_status -> Varchar,
+        #[max_length = 255]
+        changed_by -> Varchar,
+        #[max_length = 255]
+        reason -> Nullable<Varchar>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -846,6 +1097,18 @@ diesel::table! {
         card_network -> Nullable<Varchar>,
         shipping_cost -> Nullable<Int8>,
         order_tax_amount -> Nullable<Int8>,
+        fraud_score -> Nullable<Int4>,
+        #[max_length = 32]
+        fraud_decision -> Nullable<Varchar>,
+        approved_amount -> Nullable<Int8>,
+        expected_settlement_date -> Nullable<Date>,
+        #[max_length = 128]
+        card_hash -> Nullable<Varchar>,
+        retry_advice -> Nullable<Jsonb>,
+        tags -> Nullable<Jsonb>,
+        is_connector_override -> Nullable<Bool>,
+        #[max_length = 64]
+        stepped_up_from_attempt_id -> Nullable<Varchar>,
     }
 }
 
@@ -926,6 +1189,15 @@ diesel::table! {
         organization_id -> Varchar,
         tax_details -> Nullable<Jsonb>,
         skip_external_tax_calculation -> Nullable<Bool>,
+        amount_breakdown -> Nullable<Jsonb>,
+        #[max_length = 64]
+        parent_payment_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        root_payment_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        fulfillment_status -> Nullable<Varchar>,
+        shipped_at -> Nullable<Timestamp>,
+        delivered_at -> Nullable<Timestamp>,
     }
 }
 
@@ -1017,6 +1289,7 @@ diesel::table! {
         #[max_length = 64]
         network_token_locker_id -> Nullable<Varchar>,
         network_token_payment_method_data -> Nullable<Bytea>,
+        retokenization_pending -> Nullable<Bool>,
     }
 }
 
@@ -1186,6 +1459,21 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    resource_sequence (namespace, resource_id) {
+        #[max_length = 64]
+        namespace -> Varchar,
+        #[max_length = 255]
+        resource_id -> Varchar,
+        last_value -> Int8,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1253,6 +1541,61 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    settlement_reconciliation (merchant_id, connector_name, settlement_id, line_item_id) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        connector_name -> Varchar,
+        #[max_length = 128]
+        settlement_id -> Varchar,
+        #[max_length = 128]
+        line_item_id -> Varchar,
+        #[max_length = 32]
+        transaction_type -> Varchar,
+        #[max_length = 128]
+        connector_transaction_id -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        settled_at -> Nullable<Timestamp>,
+        #[max_length = 64]
+        payment_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        attempt_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        refund_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        reconciliation_status -> Varchar,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    three_ds_session (session_id) {
+        #[max_length = 64]
+        session_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        authentication_id -> Varchar,
+        #[max_length = 64]
+        ds_trans_id -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        flow_state -> Jsonb,
+        expires_at -> Timestamp,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1360,10 +1703,29 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    webhook_event_sequence (merchant_id, primary_object_type, primary_object_id) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        primary_object_type -> EventObjectType,
+        #[max_length = 64]
+        primary_object_id -> Varchar,
+        last_sequence_number -> Int8,
+        last_acknowledged_sequence_number -> Int8,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
+    audit_log,
     authentication,
+    bank_account_token,
     blocklist,
     blocklist_fingerprint,
     blocklist_lookup,
@@ -1371,6 +1733,11 @@ diesel::allow_tables_to_appear_in_same_query!(
     captures,
     cards_info,
     configs,
+    connector_token_revocation,
+    connector_webhook_offset,
+    customer_communication,
+    customer_connector_sync,
+    customer_risk_profile,
     customers,
     dashboard_metadata,
     dispute,
@@ -1379,12 +1746,15 @@ diesel::allow_tables_to_appear_in_same_query!(
     fraud_check,
     gateway_status_map,
     generic_link,
+    incoming_webhook_event,
     incremental_authorization,
+    legal_hold,
     locker_mock_up,
     mandate,
     merchant_account,
     merchant_connector_account,
     merchant_key_store,
+    merchant_onboarding_audit_log,
     organization,
     payment_attempt,
     payment_intent,
@@ -1394,12 +1764,16 @@ diesel::allow_tables_to_appear_in_same_query!(
     payouts,
     process_tracker,
     refund,
+    resource_sequence,
     reverse_lookup,
     roles,
     routing_algorithm,
+    settlement_reconciliation,
+    three_ds_session,
     unified_translations,
     user_authentication_methods,
     user_key_store,
     user_roles,
     users,
+    webhook_event_sequence,
 );
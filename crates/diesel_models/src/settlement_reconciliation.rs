@@ -0,0 +1,51 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{enums as storage_enums, schema::settlement_reconciliation};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = settlement_reconciliation)]
+pub struct SettlementReconciliationNew {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub connector_name: String,
+    pub settlement_id: String,
+    pub line_item_id: String,
+    pub transaction_type: storage_enums::SettlementTransactionType,
+    pub connector_transaction_id: String,
+    pub amount: common_utils::types::MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub settled_at: Option<time::PrimitiveDateTime>,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub attempt_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub reconciliation_status: storage_enums::SettlementReconciliationStatus,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(
+    table_name = settlement_reconciliation,
+    primary_key(merchant_id, connector_name, settlement_id, line_item_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct SettlementReconciliation {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub connector_name: String,
+    /// The identifier of the settlement file or batch at the connector
+    pub settlement_id: String,
+    /// The identifier of the line item within the settlement
+    pub line_item_id: String,
+    pub transaction_type: storage_enums::SettlementTransactionType,
+    /// The connector's identifier of the settled payment or refund
+    pub connector_transaction_id: String,
+    pub amount: common_utils::types::MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub settled_at: Option<time::PrimitiveDateTime>,
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    pub attempt_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub reconciliation_status: storage_enums::SettlementReconciliationStatus,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
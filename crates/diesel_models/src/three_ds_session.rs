@@ -0,0 +1,66 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{enums as storage_enums, schema::three_ds_session};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = three_ds_session)]
+pub struct ThreeDsSessionNew {
+    pub session_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub authentication_id: String,
+    pub ds_trans_id: String,
+    pub status: storage_enums::ThreeDsSessionStatus,
+    pub flow_state: ThreeDsSessionFlowState,
+    pub expires_at: time::PrimitiveDateTime,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(
+    table_name = three_ds_session,
+    primary_key(session_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct ThreeDsSession {
+    pub session_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub authentication_id: String,
+    /// The directory server's identifier of the 3DS transaction, which the session is looked up by
+    pub ds_trans_id: String,
+    pub status: storage_enums::ThreeDsSessionStatus,
+    /// The state of the 3DS flow carried between the steps of the flow
+    pub flow_state: ThreeDsSessionFlowState,
+    /// Time after which the challenge can no longer be completed
+    pub expires_at: time::PrimitiveDateTime,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+impl ThreeDsSession {
+    pub fn is_expired(&self, now: time::PrimitiveDateTime) -> bool {
+        self.status == storage_enums::ThreeDsSessionStatus::Expired || self.expires_at <= now
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct ThreeDsSessionFlowState {
+    pub threeds_server_transaction_id: Option<String>,
+    pub acs_trans_id: Option<String>,
+    pub acs_url: Option<String>,
+    pub challenge_request: Option<String>,
+    pub message_version: Option<common_utils::types::SemanticVersion>,
+    pub trans_status: Option<common_enums::TransactionStatus>,
+}
+
+common_utils::impl_to_sql_from_sql_json!(ThreeDsSessionFlowState);
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = three_ds_session)]
+pub struct ThreeDsSessionUpdate {
+    pub status: Option<storage_enums::ThreeDsSessionStatus>,
+    pub flow_state: Option<ThreeDsSessionFlowState>,
+    pub modified_at: time::PrimitiveDateTime,
+}
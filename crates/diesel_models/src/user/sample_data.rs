@@ -281,6 +281,15 @@ impl PaymentAttemptBatchNew {
             organization_id: self.organization_id,
             shipping_cost: self.shipping_cost,
             order_tax_amount: self.order_tax_amount,
+            fraud_score: None,
+            fraud_decision: None,
+            approved_amount: None,
+            expected_settlement_date: None,
+            card_hash: None,
+            retry_advice: None,
+            tags: None,
+            is_connector_override: None,
+            stepped_up_from_attempt_id: None,
         }
     }
 }
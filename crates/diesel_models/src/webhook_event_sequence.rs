@@ -0,0 +1,62 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{enums as storage_enums, schema::webhook_event_sequence};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_event_sequence)]
+pub struct WebhookEventSequenceNew {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub primary_object_type: storage_enums::EventObjectType,
+    pub primary_object_id: String,
+    pub last_sequence_number: i64,
+    pub last_acknowledged_sequence_number: i64,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+impl WebhookEventSequenceNew {
+    /// Sequence state for a resource whose first event is being assigned a sequence number
+    pub fn new(
+        merchant_id: common_utils::id_type::MerchantId,
+        primary_object_type: storage_enums::EventObjectType,
+        primary_object_id: String,
+    ) -> Self {
+        let now = common_utils::date_time::now();
+        Self {
+            merchant_id,
+            primary_object_type,
+            primary_object_id,
+            last_sequence_number: 1,
+            last_acknowledged_sequence_number: 0,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Deserialize, Serialize)]
+#[diesel(
+    table_name = webhook_event_sequence,
+    primary_key(merchant_id, primary_object_type, primary_object_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct WebhookEventSequence {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    /// The type of the resource, as the identifiers of resources of different types may collide
+    pub primary_object_type: storage_enums::EventObjectType,
+    pub primary_object_id: String,
+    /// The sequence number assigned to the latest event of the resource
+    pub last_sequence_number: i64,
+    /// The highest sequence number among the events of the resource acknowledged by the merchant
+    pub last_acknowledged_sequence_number: i64,
+    pub created_at: time::PrimitiveDateTime,
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_event_sequence)]
+pub struct WebhookEventSequenceUpdateInternal {
+    pub last_acknowledged_sequence_number: i64,
+    pub modified_at: time::PrimitiveDateTime,
+}
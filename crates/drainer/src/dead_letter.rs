@@ -0,0 +1,520 @@
+//! Dead letter stream of the drainer.
+//!
+//! Entries which cannot be applied to the database are moved from the drainer stream to the dead
+//! letter stream of the tenant, instead of being dropped or retried indefinitely. The entries in
+//! the dead letter stream can be listed and replayed manually once the cause of the failure has
+//! been fixed, so that redis and the database do not diverge permanently.
+//!
+//! An update is not replayed if the record has been modified in the database since the update was
+//! made, as replaying it would roll back the newer modifications, and an insert of a record which
+//! already exists is considered applied. The routes require the admin API key of the drainer.
+
+use std::{collections::HashMap, sync::Arc};
+
+use actix_web::{web, HttpRequest, Scope};
+use diesel_models::errors::DatabaseError;
+use error_stack::report;
+use masking::{PeekInterface, Secret, StrongSecret};
+use redis_interface as redis;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    errors::{self, DeadLetterError},
+    kv, metrics, pg_connection,
+    query::ExecuteQuery,
+    services::{self, log_and_return_error_response, Store},
+    StreamData,
+};
+
+const API_KEY_HEADER: &str = "api-key";
+
+const SOURCE_STREAM_FIELD: &str = "dead_letter_source_stream";
+const SOURCE_ENTRY_ID_FIELD: &str = "dead_letter_source_entry_id";
+const ERROR_FIELD: &str = "dead_letter_error";
+const DEAD_LETTERED_AT_FIELD: &str = "dead_lettered_at";
+
+/// Number of entries read from the dead letter stream if a count is not provided
+const DEFAULT_READ_COUNT: u64 = 100;
+
+/// Time after which the failed attempts of an entry are forgotten (in seconds)
+const ENTRY_ATTEMPTS_EXPIRY: i64 = 24 * 60 * 60;
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DeadLetterEntry {
+    pub entry_id: String,
+    pub fields: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct DeadLetterListRequest {
+    pub count: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(default)]
+pub struct DeadLetterReplayRequest {
+    /// The entries to be replayed, all the entries read from the dead letter stream are replayed
+    /// if not provided
+    pub entry_ids: Option<Vec<String>>,
+    pub count: Option<u64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DeadLetterReplayFailure {
+    pub entry_id: String,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct DeadLetterReplayResponse {
+    pub replayed: Vec<String>,
+    /// The updates which were discarded, as the record had been modified since the update was made
+    pub superseded: Vec<String>,
+    pub failed: Vec<DeadLetterReplayFailure>,
+}
+
+enum ReplayOutcome {
+    Applied,
+    Superseded,
+}
+
+impl Store {
+    #[inline(always)]
+    fn get_entry_attempts_key(&self, stream_name: &str) -> String {
+        format!("{stream_name}_attempts")
+    }
+
+    /// Record a failed attempt to apply the entry to the database, and return the number of
+    /// attempts made so far
+    pub(crate) async fn increment_entry_attempts(
+        &self,
+        stream_name: &str,
+        entry_id: &str,
+    ) -> errors::DrainerResult<usize> {
+        let attempts_key = self.get_entry_attempts_key(stream_name);
+        let attempts = self
+            .redis_conn
+            .increment_fields_in_hash(&attempts_key, &[(entry_id, 1)])
+            .await
+            .map_err(errors::DrainerError::from)?;
+        self.redis_conn
+            .set_expiry(&attempts_key, ENTRY_ATTEMPTS_EXPIRY)
+            .await
+            .map_err(errors::DrainerError::from)?;
+
+        Ok(attempts.first().copied().unwrap_or_default())
+    }
+
+    /// Forget the failed attempts to apply the entry, once the entry has been applied or moved to
+    /// the dead letter stream
+    pub(crate) async fn clear_entry_attempts(&self, stream_name: &str, entry_id: &str) {
+        if let Err(error) = self
+            .redis_conn
+            .delete_hash_field(&self.get_entry_attempts_key(stream_name), entry_id)
+            .await
+        {
+            logger::error!(operation = "clear_entry_attempts", ?error);
+        }
+    }
+
+    /// Move an entry of the drainer stream which cannot be applied to the database to the dead
+    /// letter stream
+    #[instrument(skip_all)]
+    pub(crate) async fn move_to_dead_letter_stream(
+        &self,
+        stream_name: &str,
+        entry_id: &str,
+        entry: HashMap<String, String>,
+        error: String,
+    ) -> errors::DrainerResult<()> {
+        let fields = get_dead_letter_fields(stream_name, entry_id, entry, error);
+
+        self.redis_conn
+            .stream_append_entry(
+                &self.config.dead_letter_stream_name,
+                &redis::RedisEntryId::AutoGeneratedID,
+                fields,
+            )
+            .await
+            .map_err(errors::DrainerError::from)?;
+        self.clear_entry_attempts(stream_name, entry_id).await;
+
+        logger::error!(
+            stream = stream_name,
+            entry_id,
+            "Moved the entry to the dead letter stream"
+        );
+        metrics::ENTRIES_MOVED_TO_DEAD_LETTER.add(
+            &metrics::CONTEXT,
+            1,
+            &[metrics::KeyValue::new("stream", stream_name.to_owned())],
+        );
+
+        Ok(())
+    }
+
+    /// List the oldest entries of the dead letter stream
+    pub async fn list_dead_letter_entries(
+        &self,
+        count: u64,
+    ) -> errors::DrainerResult<Vec<DeadLetterEntry>> {
+        let stream_name = &self.config.dead_letter_stream_name;
+        // "0-0" id gives first entry
+        let stream_read = match self
+            .redis_conn
+            .stream_read_entries(stream_name.as_str(), "0-0", Some(count))
+            .await
+        {
+            Ok(stream_read) => stream_read,
+            Err(error) => match error.current_context() {
+                redis::errors::RedisError::StreamEmptyOrNotAvailable => return Ok(Vec::new()),
+                _ => return Err(errors::DrainerError::from(error).into()),
+            },
+        };
+
+        Ok(stream_read
+            .get(&self.redis_conn.add_prefix(stream_name))
+            .cloned()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(entry_id, fields)| DeadLetterEntry { entry_id, fields })
+            .collect())
+    }
+
+    /// Find the entries of the dead letter stream with the ids, wherever they are in the stream
+    async fn find_dead_letter_entries(
+        &self,
+        entry_ids: &[String],
+    ) -> (Vec<DeadLetterEntry>, Vec<DeadLetterReplayFailure>) {
+        let stream_name = &self.config.dead_letter_stream_name;
+        let mut entries = Vec::with_capacity(entry_ids.len());
+        let mut failures = Vec::new();
+
+        for entry_id in entry_ids {
+            match self
+                .redis_conn
+                .stream_read_entries_in_range(stream_name, entry_id, entry_id, Some(1))
+                .await
+            {
+                Ok(stream_entries) => match stream_entries.into_iter().next() {
+                    Some((entry_id, fields)) => entries.push(DeadLetterEntry { entry_id, fields }),
+                    None => failures.push(DeadLetterReplayFailure {
+                        entry_id: entry_id.clone(),
+                        error: "The entry does not exist in the dead letter stream".to_string(),
+                    }),
+                },
+                Err(error) => failures.push(DeadLetterReplayFailure {
+                    entry_id: entry_id.clone(),
+                    error: format!("{error:?}"),
+                }),
+            }
+        }
+
+        (entries, failures)
+    }
+
+    /// Apply the entries of the dead letter stream to the database, either the entries with the
+    /// ids or the oldest `count` entries of the stream.
+    ///
+    /// The entries which are applied successfully are deleted from the dead letter stream, the
+    /// entries which fail to be applied again are retained.
+    #[instrument(skip_all)]
+    pub async fn replay_dead_letter_entries(
+        self: &Arc<Self>,
+        entry_ids: Option<&[String]>,
+        count: u64,
+    ) -> errors::DrainerResult<DeadLetterReplayResponse> {
+        let mut response = DeadLetterReplayResponse::default();
+        let entries = match entry_ids {
+            Some(entry_ids) => {
+                let (entries, failures) = self.find_dead_letter_entries(entry_ids).await;
+                response.failed = failures;
+                entries
+            }
+            None => self.list_dead_letter_entries(count).await?,
+        };
+
+        for entry in entries {
+            match self.replay_dead_letter_entry(entry.fields).await {
+                Ok(outcome) => {
+                    self.redis_conn
+                        .stream_delete_entries(
+                            &self.config.dead_letter_stream_name,
+                            entry.entry_id.as_str(),
+                        )
+                        .await
+                        .map_err(errors::DrainerError::from)?;
+                    match outcome {
+                        ReplayOutcome::Applied => {
+                            metrics::DEAD_LETTER_ENTRIES_REPLAYED.add(&metrics::CONTEXT, 1, &[]);
+                            response.replayed.push(entry.entry_id);
+                        }
+                        ReplayOutcome::Superseded => {
+                            logger::warn!(entry_id = %entry.entry_id, "Discarded the dead letter entry, as the record has been modified since");
+                            response.superseded.push(entry.entry_id);
+                        }
+                    }
+                }
+                Err(error) => {
+                    logger::error!(entry_id = %entry.entry_id, %error, "Failed to replay the dead letter entry");
+                    response.failed.push(DeadLetterReplayFailure {
+                        entry_id: entry.entry_id,
+                        error,
+                    });
+                }
+            }
+        }
+
+        self.record_dead_letter_depth().await;
+        Ok(response)
+    }
+
+    async fn replay_dead_letter_entry(
+        self: &Arc<Self>,
+        fields: HashMap<String, String>,
+    ) -> Result<ReplayOutcome, String> {
+        let data = StreamData::from_hashmap(fields).map_err(|error| format!("{error:?}"))?;
+
+        if let kv::DBOperation::Update { updatable } = &data.typed_sql {
+            let conn = pg_connection(&self.master_pool).await;
+            if updatable
+                .is_superseded(&conn)
+                .await
+                .map_err(|error| format!("{error:?}"))?
+            {
+                return Ok(ReplayOutcome::Superseded);
+            }
+        }
+
+        match data.typed_sql.execute_query(self, data.pushed_at).await {
+            Ok(()) => Ok(ReplayOutcome::Applied),
+            // The record was inserted since the entry was moved to the dead letter stream
+            Err(error) if matches!(error.current_context(), DatabaseError::UniqueViolation) => {
+                Ok(ReplayOutcome::Applied)
+            }
+            Err(error) => Err(format!("{error:?}")),
+        }
+    }
+
+    /// Record the number of entries in the dead letter stream, a non-zero depth indicates that
+    /// redis and the database have diverged
+    pub(crate) async fn record_dead_letter_depth(&self) {
+        let stream_name = &self.config.dead_letter_stream_name;
+        match self.redis_conn.stream_get_length(stream_name).await {
+            Ok(depth) => metrics::DEAD_LETTER_STREAM_DEPTH.observe(
+                &metrics::CONTEXT,
+                u64::try_from(depth).unwrap_or(u64::MAX),
+                &[metrics::KeyValue::new("stream", stream_name.to_owned())],
+            ),
+            Err(error) => {
+                logger::error!(operation = "dead_letter_depth", ?error);
+            }
+        }
+    }
+}
+
+/// The fields of the entry in the dead letter stream, which are the fields of the entry in the
+/// drainer stream along with where the entry came from and why it could not be applied
+fn get_dead_letter_fields(
+    stream_name: &str,
+    entry_id: &str,
+    entry: HashMap<String, String>,
+    error: String,
+) -> Vec<(String, String)> {
+    let mut fields = entry.into_iter().collect::<Vec<_>>();
+    fields.extend([
+        (SOURCE_STREAM_FIELD.to_string(), stream_name.to_string()),
+        (SOURCE_ENTRY_ID_FIELD.to_string(), entry_id.to_string()),
+        (ERROR_FIELD.to_string(), error),
+        (
+            DEAD_LETTERED_AT_FIELD.to_string(),
+            common_utils::date_time::now_unix_timestamp().to_string(),
+        ),
+    ]);
+    fields
+}
+
+pub struct DeadLetter;
+
+impl DeadLetter {
+    pub fn server(stores: HashMap<String, Arc<Store>>, admin_api_key: Secret<String>) -> Scope {
+        web::scope("dead_letter")
+            .app_data(web::Data::new(stores))
+            .app_data(web::Data::new(admin_api_key))
+            .service(web::resource("/{tenant_id}").route(web::get().to(list_dead_letter_entries)))
+            .service(
+                web::resource("/{tenant_id}/replay")
+                    .route(web::post().to(replay_dead_letter_entries)),
+            )
+    }
+}
+
+fn authenticate(
+    req: &HttpRequest,
+    admin_api_key: &Secret<String>,
+) -> Result<(), error_stack::Report<DeadLetterError>> {
+    let api_key = req
+        .headers()
+        .get(API_KEY_HEADER)
+        .and_then(|api_key| api_key.to_str().ok())
+        .ok_or_else(|| report!(DeadLetterError::Unauthorized))?;
+
+    if StrongSecret::new(api_key.to_string()) != StrongSecret::new(admin_api_key.peek().clone()) {
+        Err(
+            report!(DeadLetterError::Unauthorized).attach_printable("Admin Authentication Failure")
+        )?;
+    }
+
+    Ok(())
+}
+
+fn get_store<'a>(
+    stores: &'a HashMap<String, Arc<Store>>,
+    tenant_id: &str,
+) -> Result<&'a Arc<Store>, error_stack::Report<DeadLetterError>> {
+    stores.get(tenant_id).ok_or_else(|| {
+        report!(DeadLetterError::TenantNotFound {
+            tenant_id: tenant_id.to_string(),
+        })
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn list_dead_letter_entries(
+    req: HttpRequest,
+    stores: web::Data<HashMap<String, Arc<Store>>>,
+    admin_api_key: web::Data<Secret<String>>,
+    path: web::Path<String>,
+    query: web::Query<DeadLetterListRequest>,
+) -> impl actix_web::Responder {
+    let tenant_id = path.into_inner();
+    let result =
+        match authenticate(&req, &admin_api_key).and_then(|()| get_store(&stores, &tenant_id)) {
+            Ok(store) => store
+                .list_dead_letter_entries(query.count.unwrap_or(DEFAULT_READ_COUNT))
+                .await
+                .map_err(|error| {
+                    let message = error.to_string();
+                    error.change_context(DeadLetterError::ReadFailed { message })
+                }),
+            Err(error) => Err(error),
+        };
+
+    match result {
+        Ok(entries) => services::http_response_json(
+            serde_json::to_string(&entries)
+                .map_err(|err| {
+                    logger::error!(serialization_error=?err);
+                })
+                .unwrap_or_default(),
+        ),
+        Err(error) => log_and_return_error_response(error),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn replay_dead_letter_entries(
+    req: HttpRequest,
+    stores: web::Data<HashMap<String, Arc<Store>>>,
+    admin_api_key: web::Data<Secret<String>>,
+    path: web::Path<String>,
+    json_payload: Option<web::Json<DeadLetterReplayRequest>>,
+) -> impl actix_web::Responder {
+    let tenant_id = path.into_inner();
+    let request = json_payload
+        .map(|payload| payload.into_inner())
+        .unwrap_or_default();
+    let result =
+        match authenticate(&req, &admin_api_key).and_then(|()| get_store(&stores, &tenant_id)) {
+            Ok(store) => store
+                .replay_dead_letter_entries(
+                    request.entry_ids.as_deref(),
+                    request.count.unwrap_or(DEFAULT_READ_COUNT),
+                )
+                .await
+                .map_err(|error| {
+                    let message = error.to_string();
+                    error.change_context(DeadLetterError::ReplayFailed { message })
+                }),
+            Err(error) => Err(error),
+        };
+
+    match result {
+        Ok(response) => services::http_response_json(
+            serde_json::to_string(&response)
+                .map_err(|err| {
+                    logger::error!(serialization_error=?err);
+                })
+                .unwrap_or_default(),
+        ),
+        Err(error) => log_and_return_error_response(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use actix_web::test::TestRequest;
+
+    use super::*;
+
+    #[test]
+    fn test_dead_letter_fields_record_the_source_of_the_entry() {
+        let entry = HashMap::from([("typed_sql".to_string(), "{}".to_string())]);
+
+        let fields = get_dead_letter_fields(
+            "drainer_stream_1",
+            "1700000000000-0",
+            entry,
+            "UniqueViolation".to_string(),
+        )
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+
+        assert_eq!(fields.get("typed_sql").unwrap(), "{}");
+        assert_eq!(fields.get(SOURCE_STREAM_FIELD).unwrap(), "drainer_stream_1");
+        assert_eq!(
+            fields.get(SOURCE_ENTRY_ID_FIELD).unwrap(),
+            "1700000000000-0"
+        );
+        assert_eq!(fields.get(ERROR_FIELD).unwrap(), "UniqueViolation");
+        assert!(fields.contains_key(DEAD_LETTERED_AT_FIELD));
+    }
+
+    #[test]
+    fn test_request_with_the_admin_api_key_is_authenticated() {
+        let req = TestRequest::default()
+            .insert_header((API_KEY_HEADER, "test_admin"))
+            .to_http_request();
+
+        assert!(authenticate(&req, &Secret::new("test_admin".to_string())).is_ok());
+    }
+
+    #[test]
+    fn test_request_with_another_api_key_is_not_authenticated() {
+        let req = TestRequest::default()
+            .insert_header((API_KEY_HEADER, "test_admin_"))
+            .to_http_request();
+
+        assert!(matches!(
+            authenticate(&req, &Secret::new("test_admin".to_string()))
+                .unwrap_err()
+                .current_context(),
+            DeadLetterError::Unauthorized
+        ));
+    }
+
+    #[test]
+    fn test_request_without_an_api_key_is_not_authenticated() {
+        let req = TestRequest::default().to_http_request();
+
+        assert!(matches!(
+            authenticate(&req, &Secret::new("test_admin".to_string()))
+                .unwrap_err()
+                .current_context(),
+            DeadLetterError::Unauthorized
+        ));
+    }
+}
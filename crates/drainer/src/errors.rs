@@ -27,6 +27,18 @@ pub enum HealthCheckError {
     RedisError { message: String },
 }
 
+#[derive(Debug, Error, Clone, serde::Serialize)]
+pub enum DeadLetterError {
+    #[error("API key not provided or invalid API key used")]
+    Unauthorized,
+    #[error("No drainer found for the tenant: {tenant_id}")]
+    TenantNotFound { tenant_id: String },
+    #[error("Failed to read the dead letter stream: {message}")]
+    ReadFailed { message: String },
+    #[error("Failed to replay the dead letter entries: {message}")]
+    ReplayFailed { message: String },
+}
+
 impl From<std::io::Error> for DrainerError {
     fn from(err: std::io::Error) -> Self {
         Self::IoError(err)
@@ -56,3 +68,17 @@ impl actix_web::ResponseError for HealthCheckError {
         }
     }
 }
+
+impl actix_web::ResponseError for DeadLetterError {
+    fn status_code(&self) -> reqwest::StatusCode {
+        use reqwest::StatusCode;
+
+        match self {
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::TenantNotFound { .. } => StatusCode::NOT_FOUND,
+            Self::ReadFailed { .. } | Self::ReplayFailed { .. } => {
+                StatusCode::INTERNAL_SERVER_ERROR
+            }
+        }
+    }
+}
@@ -3,6 +3,7 @@ use std::{
     sync::{atomic, Arc},
 };
 
+use diesel_models::{errors::DatabaseError, kv};
 use router_env::tracing::Instrument;
 use tokio::{
     sync::{mpsc, oneshot},
@@ -90,6 +91,11 @@ impl Handler {
                 self.conf.num_partitions,
             )
             .await;
+            if stream_index == 0 {
+                for store in self.stores.values() {
+                    store.record_dead_letter_depth().await;
+                }
+            }
             time::sleep(self.loop_interval).await;
         }
 
@@ -244,8 +250,8 @@ async fn drainer(
 
     let mut last_processed_id = String::new();
 
-    for (entry_id, entry) in entries.clone() {
-        let data = match StreamData::from_hashmap(entry) {
+    for (index, (entry_id, entry)) in entries.clone().into_iter().enumerate() {
+        let data = match StreamData::from_hashmap(entry.clone()) {
             Ok(data) => data,
             Err(err) => {
                 logger::error!(operation = "deserialization", err=?err);
@@ -258,8 +264,21 @@ async fn drainer(
                     }],
                 );
 
-                // break from the loop in case of a deser error
-                break;
+                // The entry can never be applied, move it to the dead letter stream instead of
+                // blocking the stream on it. Break from the loop if it could not be moved.
+                match store
+                    .move_to_dead_letter_stream(stream_name, &entry_id, entry, format!("{err:?}"))
+                    .await
+                {
+                    Ok(()) => {
+                        last_processed_id = entry_id;
+                        continue;
+                    }
+                    Err(error) => {
+                        logger::error!(operation = "move_to_dead_letter", ?error);
+                        break;
+                    }
+                }
             }
         };
 
@@ -267,19 +286,66 @@ async fn drainer(
         tracing::Span::current().record("global_id", data.global_id);
         tracing::Span::current().record("session_id", &session_id);
 
-        match data.typed_sql.execute_query(&store, data.pushed_at).await {
-            Ok(_) => {
-                last_processed_id = entry_id;
+        let is_insert = matches!(data.typed_sql, kv::DBOperation::Insert { .. });
+        let error = match data.typed_sql.execute_query(&store, data.pushed_at).await {
+            Ok(_) => None,
+            Err(err) if is_applied_despite_error(is_insert, err.current_context()) => {
+                logger::info!(
+                    operation = "insert",
+                    "record already exists, clearing the entry"
+                );
+                None
+            }
+            Err(err) => Some(err),
+        };
+
+        let Some(error) = error else {
+            // A failed attempt ends the cycle, so only the first entry of a cycle could have
+            // been attempted before
+            if index == 0 {
+                store.clear_entry_attempts(stream_name, &entry_id).await;
             }
-            Err(err) => match err.current_context() {
-                // In case of Uniqueviolation we can't really do anything to fix it so just clear
-                // it from the stream
-                diesel_models::errors::DatabaseError::UniqueViolation => {
-                    last_processed_id = entry_id;
+            last_processed_id = entry_id;
+            continue;
+        };
+
+        let should_move_to_dead_letter = match error.current_context() {
+            // Retry the entry in the next cycle, as the database could not be reached
+            DatabaseError::DatabaseConnectionError => false,
+            // The error could be transient, retry the entry until the attempts are exhausted
+            DatabaseError::Others => {
+                match store.increment_entry_attempts(stream_name, &entry_id).await {
+                    Ok(attempts) => attempts >= usize::from(store.config.max_query_attempts),
+                    Err(error) => {
+                        logger::error!(operation = "increment_entry_attempts", ?error);
+                        false
+                    }
                 }
-                // break from the loop in case of an error in query
-                _ => break,
-            },
+            }
+            // The query would fail the same way on every retry, a unique violation being reached
+            // only by the updates and the deletes
+            DatabaseError::UniqueViolation
+            | DatabaseError::NotFound
+            | DatabaseError::NoFieldsToUpdate
+            | DatabaseError::QueryGenerationFailed => true,
+        };
+
+        if !should_move_to_dead_letter {
+            break;
+        }
+
+        // break from the loop in case the entry could not be moved, so that it is retried
+        match store
+            .move_to_dead_letter_stream(stream_name, &entry_id, entry, format!("{error:?}"))
+            .await
+        {
+            Ok(()) => {
+                last_processed_id = entry_id;
+            }
+            Err(error) => {
+                logger::error!(operation = "move_to_dead_letter", ?error);
+                break;
+            }
         }
     }
 
@@ -301,3 +367,45 @@ async fn drainer(
 
     Ok(())
 }
+
+/// Whether the query of the entry is applied even though it failed. The record of an insert may
+/// already exist, since the entry may be pushed to the stream again when the push is retried and
+/// the records may be inserted directly into the database, such as when the reverse lookups are
+/// repaired, so a duplicate insert is cleared from the stream as applied.
+fn is_applied_despite_error(is_insert: bool, error: &DatabaseError) -> bool {
+    is_insert && matches!(error, DatabaseError::UniqueViolation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duplicate_insert_is_applied() {
+        assert!(is_applied_despite_error(
+            true,
+            &DatabaseError::UniqueViolation
+        ));
+    }
+
+    #[test]
+    fn test_unique_violation_of_an_update_is_not_applied() {
+        assert!(!is_applied_despite_error(
+            false,
+            &DatabaseError::UniqueViolation
+        ));
+    }
+
+    #[test]
+    fn test_other_errors_of_an_insert_are_not_applied() {
+        assert!(!is_applied_despite_error(
+            true,
+            &DatabaseError::DatabaseConnectionError
+        ));
+        assert!(!is_applied_despite_error(true, &DatabaseError::Others));
+        assert!(!is_applied_despite_error(
+            true,
+            &DatabaseError::QueryGenerationFailed
+        ));
+    }
+}
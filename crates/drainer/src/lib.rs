@@ -1,4 +1,5 @@
 mod connection;
+mod dead_letter;
 pub mod errors;
 mod handler;
 mod health_check;
@@ -66,7 +67,12 @@ pub async fn start_web_server(
 ) -> Result<Server, errors::DrainerError> {
     let server = conf.server.clone();
     let web_server = actix_web::HttpServer::new(move || {
-        actix_web::App::new().service(health_check::Health::server(conf.clone(), stores.clone()))
+        actix_web::App::new()
+            .service(health_check::Health::server(conf.clone(), stores.clone()))
+            .service(dead_letter::DeadLetter::server(
+                stores.clone(),
+                conf.secrets.get_inner().admin_api_key.clone(),
+            ))
     })
     .bind((server.host.as_str(), server.port))?
     .run();
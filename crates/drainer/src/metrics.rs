@@ -1,6 +1,7 @@
 pub use router_env::opentelemetry::KeyValue;
 use router_env::{
-    counter_metric, global_meter, histogram_metric, histogram_metric_i64, metrics_context,
+    counter_metric, gauge_metric, global_meter, histogram_metric, histogram_metric_i64,
+    metrics_context,
 };
 
 metrics_context!(CONTEXT);
@@ -16,6 +17,10 @@ counter_metric!(SUCCESSFUL_SHUTDOWN, DRAINER_METER);
 counter_metric!(STREAM_EMPTY, DRAINER_METER);
 counter_metric!(STREAM_PARSE_FAIL, DRAINER_METER);
 counter_metric!(DRAINER_HEALTH, DRAINER_METER);
+counter_metric!(ENTRIES_MOVED_TO_DEAD_LETTER, DRAINER_METER);
+counter_metric!(DEAD_LETTER_ENTRIES_REPLAYED, DRAINER_METER);
+
+gauge_metric!(DEAD_LETTER_STREAM_DEPTH, DRAINER_METER);
 
 histogram_metric!(QUERY_EXECUTION_TIME, DRAINER_METER); // Time in (ms) milliseconds
 histogram_metric!(REDIS_STREAM_READ_TIME, DRAINER_METER); // Time in (ms) milliseconds
@@ -74,6 +74,14 @@ pub struct Settings<S: SecretState> {
     pub encryption_management: EncryptionManagementConfig,
     pub secrets_management: SecretsManagementConfig,
     pub multitenancy: Multitenancy,
+    pub secrets: SecretStateContainer<Secrets, S>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct Secrets {
+    /// Admin API key required by the dead letter routes
+    pub admin_api_key: Secret<String>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -114,6 +122,8 @@ pub struct DrainerSettings {
     pub max_read_count: u64,
     pub shutdown_interval: u32, // in milliseconds
     pub loop_interval: u32,     // in milliseconds
+    pub dead_letter_stream_name: String,
+    pub max_query_attempts: u8,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -186,6 +196,8 @@ impl Default for DrainerSettings {
             max_read_count: 100,
             shutdown_interval: 1000, // in milliseconds
             loop_interval: 100,      // in milliseconds
+            dead_letter_stream_name: "DRAINER_DEAD_LETTER_STREAM".into(),
+            max_query_attempts: 5,
         }
     }
 }
@@ -230,12 +242,34 @@ impl Database {
     }
 }
 
+impl Secrets {
+    fn validate(&self) -> Result<(), errors::DrainerError> {
+        common_utils::fp_utils::when(self.admin_api_key.is_default_or_empty(), || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "admin api key must not be empty".into(),
+            ))
+        })
+    }
+}
+
 impl DrainerSettings {
     fn validate(&self) -> Result<(), errors::DrainerError> {
         common_utils::fp_utils::when(self.stream_name.is_default_or_empty(), || {
             Err(errors::DrainerError::ConfigParsingError(
                 "drainer stream name must not be empty".into(),
             ))
+        })?;
+
+        common_utils::fp_utils::when(self.dead_letter_stream_name.is_default_or_empty(), || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer dead letter stream name must not be empty".into(),
+            ))
+        })?;
+
+        common_utils::fp_utils::when(self.max_query_attempts == 0, || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "drainer max query attempts must be greater than zero".into(),
+            ))
         })
     }
 }
@@ -293,6 +327,7 @@ impl Settings<SecuredSecret> {
             errors::DrainerError::ConfigParsingError("invalid Redis configuration".into())
         })?;
         self.drainer.validate()?;
+        self.secrets.get_inner().validate()?;
 
         // The logger may not yet be initialized when validating the application configuration
         #[allow(clippy::print_stderr)]
@@ -140,6 +140,7 @@ impl ConnectorCommon for Bambora {
             reason: Some(response.message),
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -146,6 +146,7 @@ impl ConnectorCommon for Bitpay {
             reason: response.message,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
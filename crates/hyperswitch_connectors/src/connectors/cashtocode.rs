@@ -143,6 +143,7 @@ impl ConnectorCommon for Cashtocode {
             reason: None,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -253,6 +253,7 @@ impl<F>
                     reason: None,
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 }),
             ),
             CashtocodePaymentsResponse::CashtoCodeData(response_data) => {
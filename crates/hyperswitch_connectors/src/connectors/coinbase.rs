@@ -128,6 +128,7 @@ impl ConnectorCommon for Coinbase {
             reason: response.error.code,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -7,7 +7,7 @@ use common_utils::{
     errors::CustomResult,
     ext_traits::ByteSliceExt,
     request::{Method, Request, RequestBuilder, RequestContent},
-    types::{AmountConvertor, StringMajorUnit, StringMajorUnitForConnector},
+    types::{AmountConvertor, AmountSerializationStrategy, SerializedAmount},
 };
 use error_stack::ResultExt;
 use hex::encode;
@@ -46,13 +46,13 @@ use crate::{
 
 #[derive(Clone)]
 pub struct Cryptopay {
-    amount_converter: &'static (dyn AmountConvertor<Output = StringMajorUnit> + Sync),
+    amount_converter: &'static (dyn AmountConvertor<Output = SerializedAmount> + Sync),
 }
 
 impl Cryptopay {
     pub fn new() -> &'static Self {
         &Self {
-            amount_converter: &StringMajorUnitForConnector,
+            amount_converter: &AmountSerializationStrategy::StringMajorUnit,
         }
     }
 }
@@ -190,6 +190,7 @@ impl ConnectorCommon for Cryptopay {
             reason: response.error.reason,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
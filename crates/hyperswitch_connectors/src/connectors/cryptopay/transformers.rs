@@ -1,7 +1,7 @@
 use common_enums::enums;
 use common_utils::{
     pii,
-    types::{MinorUnit, StringMajorUnit},
+    types::{MinorUnit, SerializedAmount, StringMajorUnit},
 };
 use hyperswitch_domain_models::{
     payment_method_data::PaymentMethodData,
@@ -22,12 +22,12 @@ use crate::{
 
 #[derive(Debug, Serialize)]
 pub struct CryptopayRouterData<T> {
-    pub amount: StringMajorUnit,
+    pub amount: SerializedAmount,
     pub router_data: T,
 }
 
-impl<T> From<(StringMajorUnit, T)> for CryptopayRouterData<T> {
-    fn from((amount, item): (StringMajorUnit, T)) -> Self {
+impl<T> From<(SerializedAmount, T)> for CryptopayRouterData<T> {
+    fn from((amount, item): (SerializedAmount, T)) -> Self {
         Self {
             amount,
             router_data: item,
@@ -35,9 +35,9 @@ impl<T> From<(StringMajorUnit, T)> for CryptopayRouterData<T> {
     }
 }
 
-#[derive(Default, Debug, Serialize)]
+#[derive(Debug, Serialize)]
 pub struct CryptopayPaymentsRequest {
-    price_amount: StringMajorUnit,
+    price_amount: SerializedAmount,
     price_currency: enums::Currency,
     pay_currency: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -171,6 +171,7 @@ impl<F, T>
                 status_code: item.http_code,
                 attempt_status: None,
                 connector_transaction_id: Some(payment_response.id.clone()),
+                network_advice_code: None,
             })
         } else {
             let redirection_data = item
@@ -235,7 +236,7 @@ pub struct CryptopayPaymentResponseData {
     pub address: Option<Secret<String>>,
     pub network: Option<String>,
     pub uri: Option<String>,
-    pub price_amount: Option<StringMajorUnit>,
+    pub price_amount: Option<SerializedAmount>,
     pub price_currency: Option<String>,
     pub pay_amount: Option<StringMajorUnit>,
     pub pay_currency: Option<String>,
@@ -167,6 +167,7 @@ impl ConnectorCommon for Deutschebank {
             reason: Some(response.message),
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -137,6 +137,7 @@ impl ConnectorCommon for Digitalvirgo {
             reason: response.reason,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -5,14 +5,12 @@ use std::fmt::Debug;
 use api_models::webhooks::IncomingWebhookEvent;
 use common_enums::enums;
 use common_utils::{
-    crypto::{self, SignMessage},
     date_time,
     errors::CustomResult,
     ext_traits::ByteSliceExt,
     request::{Method, Request, RequestBuilder, RequestContent},
 };
 use error_stack::{report, ResultExt};
-use hex::encode;
 use hyperswitch_domain_models::{
     router_data::{AccessToken, ErrorResponse, RouterData},
     router_flow_types::{
@@ -36,10 +34,14 @@ use hyperswitch_interfaces::{
     configs::Connectors,
     errors,
     events::connector_api_logs::ConnectorEvent,
+    request_signing::{
+        RequestSigner, SignatureEncoding, SignatureLocation, SigningAlgorithm, SigningComponent,
+        SigningCredentials, SigningRequest,
+    },
     types::{self, Response},
     webhooks,
 };
-use masking::{Mask, Maskable, PeekInterface};
+use masking::{Mask, Maskable, PeekInterface, Secret};
 use transformers as dlocal;
 
 use crate::{
@@ -50,6 +52,21 @@ use crate::{
 #[derive(Debug, Clone)]
 pub struct Dlocal;
 
+const DLOCAL_REQUEST_SIGNER: RequestSigner = RequestSigner {
+    algorithm: SigningAlgorithm::HmacSha256,
+    components: &[
+        SigningComponent::KeyId,
+        SigningComponent::Timestamp,
+        SigningComponent::Body,
+    ],
+    separator: "",
+    encoding: SignatureEncoding::Hex,
+    location: SignatureLocation::Header {
+        name: headers::AUTHORIZATION,
+        prefix: "V2-HMAC-SHA256, Signature: ",
+    },
+};
+
 impl api::Payment for Dlocal {}
 impl api::PaymentToken for Dlocal {}
 impl api::PaymentSession for Dlocal {}
@@ -77,25 +94,21 @@ where
         let date = date_time::date_as_yyyymmddthhmmssmmmz()
             .change_context(errors::ConnectorError::RequestEncodingFailed)?;
         let auth = dlocal::DlocalAuthType::try_from(&req.connector_auth_type)?;
-        let sign_req: String = format!(
-            "{}{}{}",
-            auth.x_login.peek(),
-            date,
-            dlocal_req.get_inner_value().peek().to_owned()
-        );
-        let authz = crypto::HmacSha256::sign_message(
-            &crypto::HmacSha256,
-            auth.secret.peek().as_bytes(),
-            sign_req.as_bytes(),
-        )
-        .change_context(errors::ConnectorError::RequestEncodingFailed)
-        .attach_printable("Failed to sign the message")?;
-        let auth_string: String = format!("V2-HMAC-SHA256, Signature: {}", encode(authz));
-        let headers = vec![
-            (
-                headers::AUTHORIZATION.to_string(),
-                auth_string.into_masked(),
-            ),
+        let signed_request = DLOCAL_REQUEST_SIGNER.sign(
+            &SigningRequest {
+                method: self.get_http_method(),
+                path: None,
+                body: dlocal_req.get_inner_value().peek(),
+                timestamp: Some(date.as_str()),
+                nonce: None,
+            },
+            &SigningCredentials {
+                key_id: Some(auth.x_login.clone()),
+                secret: Secret::new(auth.secret.peek().as_bytes().to_vec()),
+            },
+        )?;
+        let mut headers = signed_request.headers;
+        headers.extend([
             (headers::X_LOGIN.to_string(), auth.x_login.into_masked()),
             (
                 headers::X_TRANS_KEY.to_string(),
@@ -107,7 +120,7 @@ where
                 headers::CONTENT_TYPE.to_string(),
                 Self.get_content_type().to_string().into(),
             ),
-        ];
+        ]);
         Ok(headers)
     }
 }
@@ -149,6 +162,7 @@ impl ConnectorCommon for Dlocal {
             reason: response.param,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
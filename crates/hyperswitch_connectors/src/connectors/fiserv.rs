@@ -165,6 +165,7 @@ impl ConnectorCommon for Fiserv {
                     status_code: res.status_code,
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 })
             })
             .unwrap_or(ErrorResponse {
@@ -174,6 +175,7 @@ impl ConnectorCommon for Fiserv {
                 status_code: res.status_code,
                 attempt_status: None,
                 connector_transaction_id: None,
+                network_advice_code: None,
             }))
     }
 }
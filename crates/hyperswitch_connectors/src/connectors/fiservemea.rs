@@ -229,6 +229,7 @@ impl ConnectorCommon for Fiservemea {
                     },
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 })
             }
             None => Ok(ErrorResponse {
@@ -241,6 +242,7 @@ impl ConnectorCommon for Fiservemea {
                 reason: response.response_type,
                 attempt_status: None,
                 connector_transaction_id: None,
+                network_advice_code: None,
             }),
         }
     }
@@ -167,6 +167,7 @@ impl ConnectorCommon for Fiuu {
             reason: Some(response.error_desc.clone()),
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -612,6 +612,7 @@ impl<F>
                     status_code: item.http_code,
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 }),
                 ..item.data
             }),
@@ -670,6 +671,7 @@ impl<F>
                             status_code: item.http_code,
                             attempt_status: None,
                             connector_transaction_id: None,
+                            network_advice_code: None,
                         })
                     } else {
                         Ok(PaymentsResponseData::TransactionResponse {
@@ -767,6 +769,7 @@ impl TryFrom<RefundsResponseRouterData<Execute, FiuuRefundResponse>>
                     status_code: item.http_code,
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 }),
                 ..item.data
             }),
@@ -893,6 +896,7 @@ impl TryFrom<PaymentsSyncResponseRouterData<FiuuPaymentSyncResponse>> for Paymen
                 reason: Some(item.response.stat_name.clone().to_string()),
                 attempt_status: Some(enums::AttemptStatus::Failure),
                 connector_transaction_id: None,
+                network_advice_code: None,
             })
         } else {
             None
@@ -1036,6 +1040,7 @@ impl TryFrom<PaymentsCaptureResponseRouterData<PaymentCaptureResponse>>
                 ),
                 attempt_status: None,
                 connector_transaction_id: None,
+                network_advice_code: None,
             })
         } else {
             None
@@ -1147,6 +1152,7 @@ impl TryFrom<PaymentsCancelResponseRouterData<FiuuPaymentCancelResponse>>
                 ),
                 attempt_status: None,
                 connector_transaction_id: None,
+                network_advice_code: None,
             })
         } else {
             None
@@ -1239,6 +1245,7 @@ impl TryFrom<RefundsResponseRouterData<RSync, FiuuRefundSyncResponse>>
                     status_code: item.http_code,
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 }),
                 ..item.data
             }),
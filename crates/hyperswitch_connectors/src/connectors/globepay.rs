@@ -151,6 +151,7 @@ impl ConnectorCommon for Globepay {
             reason: Some(response.return_msg),
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -319,6 +319,7 @@ fn get_error_response(
         status_code,
         attempt_status: None,
         connector_transaction_id: None,
+        network_advice_code: None,
     }
 }
 
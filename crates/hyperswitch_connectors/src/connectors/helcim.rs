@@ -165,6 +165,7 @@ impl ConnectorCommon for Helcim {
             reason: Some(error_string),
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
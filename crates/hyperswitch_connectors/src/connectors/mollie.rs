@@ -122,6 +122,7 @@ impl ConnectorCommon for Mollie {
             reason: response.field,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
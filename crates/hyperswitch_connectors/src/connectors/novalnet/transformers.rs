@@ -369,6 +369,7 @@ pub fn get_error_response(result: ResultData, status_code: u16) -> ErrorResponse
         status_code,
         attempt_status: None,
         connector_transaction_id: None,
+        network_advice_code: None,
     }
 }
 
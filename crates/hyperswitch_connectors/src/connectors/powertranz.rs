@@ -140,6 +140,7 @@ impl ConnectorCommon for Powertranz {
             reason: None,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -151,6 +151,7 @@ impl ConnectorCommon for Square {
             reason: Some(reason),
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -16,14 +16,15 @@ use hyperswitch_domain_models::{
         access_token_auth::AccessTokenAuth,
         payments::{
             Authorize, Capture, CreateConnectorCustomer, PSync, PaymentMethodToken, Session,
-            SetupMandate, Void,
+            SetupMandate, UpdateConnectorCustomer, Void,
         },
         refunds::{Execute, RSync},
     },
     router_request_types::{
-        AccessTokenRequestData, ConnectorCustomerData, PaymentMethodTokenizationData,
-        PaymentsAuthorizeData, PaymentsCancelData, PaymentsCaptureData, PaymentsSessionData,
-        PaymentsSyncData, RefundsData, SetupMandateRequestData,
+        AccessTokenRequestData, ConnectorCustomerData, ConnectorCustomerUpdateData,
+        PaymentMethodTokenizationData, PaymentsAuthorizeData, PaymentsCancelData,
+        PaymentsCaptureData, PaymentsSessionData, PaymentsSyncData, RefundsData,
+        SetupMandateRequestData,
     },
     router_response_types::{PaymentsResponseData, RefundsResponseData},
     types::{
@@ -132,6 +133,7 @@ impl ConnectorCommon for Stax {
             ),
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -154,6 +156,12 @@ impl ConnectorValidation for Stax {
 
 impl api::ConnectorCustomer for Stax {}
 
+impl
+    ConnectorIntegration<UpdateConnectorCustomer, ConnectorCustomerUpdateData, PaymentsResponseData>
+    for Stax
+{
+}
+
 impl ConnectorIntegration<CreateConnectorCustomer, ConnectorCustomerData, PaymentsResponseData>
     for Stax
 {
@@ -141,6 +141,7 @@ impl ConnectorCommon for Taxjar {
             reason: Some(response.detail),
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
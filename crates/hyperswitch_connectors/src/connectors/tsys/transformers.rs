@@ -217,6 +217,7 @@ fn get_error_response(
         status_code,
         attempt_status: None,
         connector_transaction_id: None,
+        network_advice_code: None,
     }
 }
 
@@ -163,6 +163,7 @@ impl ConnectorCommon for Volt {
             reason: Some(reason),
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -267,6 +268,7 @@ impl ConnectorIntegration<AccessTokenAuth, AccessTokenRequestData, AccessToken>
             reason: Some(response.message),
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
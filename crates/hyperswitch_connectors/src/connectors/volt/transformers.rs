@@ -342,6 +342,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, VoltPaymentsResponseData, T, PaymentsRe
                             status_code: item.http_code,
                             attempt_status: None,
                             connector_transaction_id: Some(payment_response.id),
+                            network_advice_code: None,
                         })
                     } else {
                         Ok(PaymentsResponseData::TransactionResponse {
@@ -383,6 +384,7 @@ impl<F, T> TryFrom<ResponseRouterData<F, VoltPaymentsResponseData, T, PaymentsRe
                             status_code: item.http_code,
                             attempt_status: None,
                             connector_transaction_id: Some(webhook_response.payment.clone()),
+                            network_advice_code: None,
                         })
                     } else {
                         Ok(PaymentsResponseData::TransactionResponse {
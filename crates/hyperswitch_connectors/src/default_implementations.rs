@@ -30,17 +30,17 @@ use hyperswitch_domain_models::{
         payments::{
             Approve, AuthorizeSessionToken, CalculateTax, CompleteAuthorize,
             CreateConnectorCustomer, IncrementalAuthorization, PostProcessing, PreProcessing,
-            Reject, SdkSessionUpdate,
+            Reject, SdkSessionUpdate, UpdateConnectorCustomer,
         },
         webhooks::VerifyWebhookSource,
     },
     router_request_types::{
         AcceptDisputeRequestData, AuthorizeSessionTokenData, CompleteAuthorizeData,
-        ConnectorCustomerData, DefendDisputeRequestData, MandateRevokeRequestData,
-        PaymentsApproveData, PaymentsIncrementalAuthorizationData, PaymentsPostProcessingData,
-        PaymentsPreProcessingData, PaymentsRejectData, PaymentsTaxCalculationData,
-        RetrieveFileRequestData, SdkPaymentsSessionUpdateData, SubmitEvidenceRequestData,
-        UploadFileRequestData, VerifyWebhookSourceRequestData,
+        ConnectorCustomerData, ConnectorCustomerUpdateData, DefendDisputeRequestData,
+        MandateRevokeRequestData, PaymentsApproveData, PaymentsIncrementalAuthorizationData,
+        PaymentsPostProcessingData, PaymentsPreProcessingData, PaymentsRejectData,
+        PaymentsTaxCalculationData, RetrieveFileRequestData, SdkPaymentsSessionUpdateData,
+        SubmitEvidenceRequestData, UploadFileRequestData, VerifyWebhookSourceRequestData,
     },
     router_response_types::{
         AcceptDisputeResponse, DefendDisputeResponse, MandateRevokeResponseData,
@@ -285,6 +285,13 @@ macro_rules! default_imp_for_create_customer {
             ConnectorCustomerData,
             PaymentsResponseData,
         > for $path::$connector
+        {}
+            impl
+            ConnectorIntegration<
+            UpdateConnectorCustomer,
+            ConnectorCustomerUpdateData,
+            PaymentsResponseData,
+        > for $path::$connector
         {}
     )*
     };
@@ -14,7 +14,8 @@ use hyperswitch_domain_models::{
         payments::{
             Approve, Authorize, AuthorizeSessionToken, CalculateTax, Capture, CompleteAuthorize,
             CreateConnectorCustomer, IncrementalAuthorization, PSync, PaymentMethodToken,
-            PostProcessing, PreProcessing, Reject, SdkSessionUpdate, Session, SetupMandate, Void,
+            PostProcessing, PreProcessing, Reject, SdkSessionUpdate, Session, SetupMandate,
+            UpdateConnectorCustomer, Void,
         },
         refunds::{Execute, RSync},
         webhooks::VerifyWebhookSource,
@@ -22,9 +23,9 @@ use hyperswitch_domain_models::{
     },
     router_request_types::{
         AcceptDisputeRequestData, AccessTokenRequestData, AuthorizeSessionTokenData,
-        CompleteAuthorizeData, ConnectorCustomerData, DefendDisputeRequestData,
-        MandateRevokeRequestData, PaymentMethodTokenizationData, PaymentsApproveData,
-        PaymentsAuthorizeData, PaymentsCancelData, PaymentsCaptureData,
+        CompleteAuthorizeData, ConnectorCustomerData, ConnectorCustomerUpdateData,
+        DefendDisputeRequestData, MandateRevokeRequestData, PaymentMethodTokenizationData,
+        PaymentsApproveData, PaymentsAuthorizeData, PaymentsCancelData, PaymentsCaptureData,
         PaymentsIncrementalAuthorizationData, PaymentsPostProcessingData,
         PaymentsPreProcessingData, PaymentsRejectData, PaymentsSessionData, PaymentsSyncData,
         PaymentsTaxCalculationData, RefundsData, RetrieveFileRequestData,
@@ -160,6 +161,13 @@ macro_rules! default_imp_for_new_connector_integration_payment {
                 ConnectorCustomerData,
                 PaymentsResponseData,
             > for $path::$connector{}
+            impl
+            ConnectorIntegrationV2<
+            UpdateConnectorCustomer,
+            PaymentFlowData,
+                ConnectorCustomerUpdateData,
+                PaymentsResponseData,
+            > for $path::$connector{}
             impl ConnectorIntegrationV2<
             PreProcessing,
             PaymentFlowData,
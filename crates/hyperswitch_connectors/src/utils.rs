@@ -176,6 +176,7 @@ pub(crate) fn is_payment_failure(status: AttemptStatus) -> bool {
         | AttemptStatus::AuthenticationPending
         | AttemptStatus::AuthenticationSuccessful
         | AttemptStatus::Authorized
+        | AttemptStatus::PartiallyAuthorized
         | AttemptStatus::Charged
         | AttemptStatus::Authorizing
         | AttemptStatus::CodInitiated
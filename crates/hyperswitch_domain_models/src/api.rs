@@ -20,6 +20,25 @@ pub enum ApplicationResponse<R> {
     GenericLinkForm(Box<GenericLinks>),
 }
 
+impl<R> ApplicationResponse<R> {
+    /// Convert the json body of the response, keeping the rest of the response as it is
+    pub fn map_json_body<T>(self, f: impl FnOnce(R) -> T) -> ApplicationResponse<T> {
+        match self {
+            Self::Json(body) => ApplicationResponse::Json(f(body)),
+            Self::JsonWithHeaders((body, headers)) => {
+                ApplicationResponse::JsonWithHeaders((f(body), headers))
+            }
+            Self::StatusOk => ApplicationResponse::StatusOk,
+            Self::TextPlain(text) => ApplicationResponse::TextPlain(text),
+            Self::JsonForRedirection(response) => ApplicationResponse::JsonForRedirection(response),
+            Self::Form(form) => ApplicationResponse::Form(form),
+            Self::PaymentLinkForm(form) => ApplicationResponse::PaymentLinkForm(form),
+            Self::FileData(file_data) => ApplicationResponse::FileData(file_data),
+            Self::GenericLinkForm(form) => ApplicationResponse::GenericLinkForm(form),
+        }
+    }
+}
+
 impl<T: ApiEventMetric> ApiEventMetric for ApplicationResponse<T> {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         match self {
@@ -1,7 +1,9 @@
 use api_models::customers::CustomerRequestWithEncryption;
+use base64::Engine;
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
 use common_enums::DeleteStatus;
 use common_utils::{
+    consts::BASE64_ENGINE,
     crypto, date_time,
     encryption::Encryption,
     errors::{CustomResult, ValidationError},
@@ -11,12 +13,25 @@ use common_utils::{
         Description,
     },
 };
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+use common_utils::{errors::ParsingError, ext_traits::ValueExt, types::CustomerTaxIds};
 use diesel_models::customers::CustomerUpdateInternal;
 use error_stack::ResultExt;
-use masking::{PeekInterface, Secret};
+use masking::{ExposeInterface, PeekInterface, Secret};
 use time::PrimitiveDateTime;
 
 use crate::type_encryption as types;
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+use crate::type_encryption::AsyncLift;
+
+/// Prefix of the fields designated for encryption which refer to a key of the customer metadata
+pub const ENCRYPTABLE_METADATA_FIELD_PREFIX: &str = "metadata.";
+
+/// Maximum length of a key of the customer metadata designated for encryption
+pub const MAX_ENCRYPTABLE_METADATA_KEY_LENGTH: usize = 64;
+
+const ENCRYPTED_VALUE_KEY: &str = "encrypted_value";
+const VALUE_HASH_KEY: &str = "value_hash";
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 #[derive(Clone, Debug)]
@@ -36,6 +51,27 @@ pub struct Customer {
     pub default_payment_method_id: Option<String>,
     pub updated_by: Option<String>,
     pub version: common_enums::ApiVersion,
+    pub tax_ids: crypto::OptionalEncryptableValue,
+    /// The saved payment method the customer last paid with, which is distinct from the default
+    /// payment method set by the customer
+    pub preferred_payment_method_id: Option<String>,
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+impl Customer {
+    /// Get the tax identifiers of the customer, which are stored encrypted
+    pub fn get_tax_ids(&self) -> CustomResult<Option<CustomerTaxIds>, ParsingError> {
+        self.tax_ids
+            .as_ref()
+            .map(|tax_ids| {
+                tax_ids
+                    .get_inner()
+                    .peek()
+                    .clone()
+                    .parse_value("CustomerTaxIds")
+            })
+            .transpose()
+    }
 }
 
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
@@ -83,6 +119,8 @@ impl super::behaviour::Conversion for Customer {
             default_payment_method_id: self.default_payment_method_id,
             updated_by: self.updated_by,
             version: self.version,
+            tax_ids: self.tax_ids.map(Encryption::from),
+            preferred_payment_method_id: self.preferred_payment_method_id,
         })
     }
 
@@ -117,6 +155,30 @@ impl super::behaviour::Conversion for Customer {
             .change_context(ValidationError::InvalidValue {
                 message: "Failed while decrypting customer data".to_string(),
             })?;
+        let metadata = decrypt_metadata_fields(
+            state,
+            item.metadata,
+            keymanager::Identifier::Merchant(item.merchant_id.clone()),
+            key.peek(),
+        )
+        .await?;
+        let tax_ids = item
+            .tax_ids
+            .async_lift(|inner| async {
+                types::crypto_operation(
+                    state,
+                    common_utils::type_name!(Self::DstType),
+                    types::CryptoOperation::DecryptOptional(inner),
+                    keymanager::Identifier::Merchant(item.merchant_id.clone()),
+                    key.peek(),
+                )
+                .await
+                .and_then(|val| val.try_into_optionaloperation())
+            })
+            .await
+            .change_context(ValidationError::InvalidValue {
+                message: "Failed while decrypting customer tax identifiers".to_string(),
+            })?;
 
         Ok(Self {
             customer_id: item.customer_id,
@@ -127,13 +189,15 @@ impl super::behaviour::Conversion for Customer {
             phone_country_code: item.phone_country_code,
             description: item.description,
             created_at: item.created_at,
-            metadata: item.metadata,
+            metadata,
             modified_at: item.modified_at,
             connector_customer: item.connector_customer,
             address_id: item.address_id,
             default_payment_method_id: item.default_payment_method_id,
             updated_by: item.updated_by,
             version: item.version,
+            tax_ids,
+            preferred_payment_method_id: item.preferred_payment_method_id,
         })
     }
 
@@ -154,6 +218,7 @@ impl super::behaviour::Conversion for Customer {
             address_id: self.address_id,
             updated_by: self.updated_by,
             version: self.version,
+            tax_ids: self.tax_ids.map(Encryption::from),
         })
     }
 }
@@ -217,6 +282,13 @@ impl super::behaviour::Conversion for Customer {
             .change_context(ValidationError::InvalidValue {
                 message: "Failed while decrypting customer data".to_string(),
             })?;
+        let metadata = decrypt_metadata_fields(
+            state,
+            item.metadata,
+            keymanager::Identifier::Merchant(item.merchant_id.clone()),
+            key.peek(),
+        )
+        .await?;
 
         Ok(Self {
             id: item.id,
@@ -228,7 +300,7 @@ impl super::behaviour::Conversion for Customer {
             phone_country_code: item.phone_country_code,
             description: item.description,
             created_at: item.created_at,
-            metadata: item.metadata,
+            metadata,
             modified_at: item.modified_at,
             connector_customer: item.connector_customer,
             default_payment_method_id: item.default_payment_method_id,
@@ -368,6 +440,7 @@ pub enum CustomerUpdate {
         metadata: Option<pii::SecretSerdeValue>,
         connector_customer: Option<pii::SecretSerdeValue>,
         address_id: Option<String>,
+        tax_ids: crypto::OptionalEncryptableValue,
     },
     ConnectorCustomer {
         connector_customer: Option<pii::SecretSerdeValue>,
@@ -375,6 +448,9 @@ pub enum CustomerUpdate {
     UpdateDefaultPaymentMethod {
         default_payment_method_id: Option<Option<String>>,
     },
+    UpdatePreferredPaymentMethod {
+        preferred_payment_method_id: Option<String>,
+    },
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -390,6 +466,7 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 metadata,
                 connector_customer,
                 address_id,
+                tax_ids,
             } => Self {
                 name: name.map(Encryption::from),
                 email: email.map(Encryption::from),
@@ -402,6 +479,8 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 address_id,
                 default_payment_method_id: None,
                 updated_by: None,
+                tax_ids: tax_ids.map(Encryption::from),
+                preferred_payment_method_id: None,
             },
             CustomerUpdate::ConnectorCustomer { connector_customer } => Self {
                 connector_customer,
@@ -415,6 +494,8 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 default_payment_method_id: None,
                 updated_by: None,
                 address_id: None,
+                tax_ids: None,
+                preferred_payment_method_id: None,
             },
             CustomerUpdate::UpdateDefaultPaymentMethod {
                 default_payment_method_id,
@@ -430,7 +511,197 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 connector_customer: None,
                 updated_by: None,
                 address_id: None,
+                tax_ids: None,
+                preferred_payment_method_id: None,
             },
+            CustomerUpdate::UpdatePreferredPaymentMethod {
+                preferred_payment_method_id,
+            } => Self {
+                preferred_payment_method_id: Some(preferred_payment_method_id),
+                modified_at: date_time::now(),
+                name: None,
+                email: None,
+                phone: None,
+                description: None,
+                phone_country_code: None,
+                metadata: None,
+                connector_customer: None,
+                default_payment_method_id: None,
+                updated_by: None,
+                address_id: None,
+                tax_ids: None,
+            },
+        }
+    }
+}
+
+/// Get the key of the customer metadata referred to by a field designated for encryption, the
+/// field must be of the form `metadata.<key>`
+pub fn get_encryptable_metadata_key(field: &str) -> Option<&str> {
+    field
+        .strip_prefix(ENCRYPTABLE_METADATA_FIELD_PREFIX)
+        .filter(|key| {
+            !key.is_empty()
+                && key.len() <= MAX_ENCRYPTABLE_METADATA_KEY_LENGTH
+                && key
+                    .chars()
+                    .all(|char| char.is_ascii_alphanumeric() || char == '_' || char == '-')
+        })
+}
+
+/// Get the deterministic hash of a value of the customer metadata, which is stored alongside the
+/// encrypted value so that customers can be searched by the value.
+///
+/// The hash is keyed with the key of the merchant, so that values with little entropy like emails
+/// or phone numbers cannot be recovered from their hash without the key.
+pub fn get_metadata_value_hash(
+    value: &serde_json::Value,
+    key: &[u8],
+) -> CustomResult<String, ValidationError> {
+    use crypto::SignMessage;
+
+    let value = match value {
+        serde_json::Value::String(value) => value.clone(),
+        value => value.to_string(),
+    };
+    crypto::HmacSha256
+        .sign_message(key, value.as_bytes())
+        .map(|value_hash| BASE64_ENGINE.encode(value_hash))
+        .change_context(ValidationError::InvalidValue {
+            message: "Failed while hashing customer metadata".to_string(),
+        })
+}
+
+fn get_encrypted_metadata_value(value: &serde_json::Value) -> Option<&str> {
+    value
+        .as_object()
+        .filter(|value| value.len() == 2 && value.contains_key(VALUE_HASH_KEY))
+        .and_then(|value| value.get(ENCRYPTED_VALUE_KEY))
+        .and_then(|encrypted_value| encrypted_value.as_str())
+}
+
+/// Validate the customer metadata provided in a request.
+///
+/// The values of the form in which encrypted values are stored are rejected, as they would be
+/// decrypted when the customer is read instead of being returned as provided.
+pub fn validate_metadata_fields(
+    metadata: Option<&pii::SecretSerdeValue>,
+) -> CustomResult<(), ValidationError> {
+    let has_reserved_value = metadata
+        .and_then(|metadata| metadata.peek().as_object())
+        .is_some_and(|metadata_object| {
+            metadata_object
+                .values()
+                .any(|value| get_encrypted_metadata_value(value).is_some())
+        });
+
+    if has_reserved_value {
+        Err(ValidationError::InvalidValue {
+            message: format!(
+                "metadata values must not be objects of the keys `{ENCRYPTED_VALUE_KEY}` and `{VALUE_HASH_KEY}`, which are reserved for encrypted values"
+            ),
+        })?
+    }
+
+    Ok(())
+}
+
+/// Encrypt the values of the customer metadata designated for encryption.
+///
+/// Each value is replaced by its encrypted value and its hash, the values are decrypted again when
+/// the customer is read. The metadata must have been validated with [`validate_metadata_fields`].
+pub async fn encrypt_metadata_fields(
+    state: &KeyManagerState,
+    metadata: Option<pii::SecretSerdeValue>,
+    fields: &[String],
+    identifier: keymanager::Identifier,
+    key: &[u8],
+) -> CustomResult<Option<pii::SecretSerdeValue>, ValidationError> {
+    let Some(metadata) = metadata else {
+        return Ok(None);
+    };
+    let mut metadata = metadata.expose();
+
+    if let Some(metadata_object) = metadata.as_object_mut() {
+        for metadata_key in fields
+            .iter()
+            .filter_map(|field| get_encryptable_metadata_key(field))
+        {
+            let Some(value) = metadata_object.get_mut(metadata_key) else {
+                continue;
+            };
+
+            let value_hash = get_metadata_value_hash(value, key)?;
+            let encrypted_value = types::crypto_operation(
+                state,
+                common_utils::type_name!(diesel_models::customers::Customer),
+                types::CryptoOperation::Encrypt(Secret::<_, masking::WithType>::new(value.take())),
+                identifier.clone(),
+                key,
+            )
+            .await
+            .and_then(|val| val.try_into_operation())
+            .change_context(ValidationError::InvalidValue {
+                message: "Failed while encrypting customer metadata".to_string(),
+            })?;
+
+            let encrypted_value = Encryption::from(encrypted_value).into_inner();
+            *value = serde_json::Value::Object(serde_json::Map::from_iter([
+                (
+                    ENCRYPTED_VALUE_KEY.to_string(),
+                    serde_json::Value::String(BASE64_ENGINE.encode(encrypted_value.peek())),
+                ),
+                (
+                    VALUE_HASH_KEY.to_string(),
+                    serde_json::Value::String(value_hash),
+                ),
+            ]));
         }
     }
+
+    Ok(Some(Secret::new(metadata)))
+}
+
+/// Decrypt the encrypted values of the customer metadata
+async fn decrypt_metadata_fields(
+    state: &KeyManagerState,
+    metadata: Option<pii::SecretSerdeValue>,
+    identifier: keymanager::Identifier,
+    key: &[u8],
+) -> CustomResult<Option<pii::SecretSerdeValue>, ValidationError> {
+    let Some(metadata) = metadata else {
+        return Ok(None);
+    };
+    let mut metadata = metadata.expose();
+
+    if let Some(metadata_object) = metadata.as_object_mut() {
+        for value in metadata_object.values_mut() {
+            let Some(encrypted_value) = get_encrypted_metadata_value(value)
+                .map(|encrypted_value| BASE64_ENGINE.decode(encrypted_value))
+                .transpose()
+                .change_context(ValidationError::InvalidValue {
+                    message: "Failed while decoding customer metadata".to_string(),
+                })?
+            else {
+                continue;
+            };
+
+            let decrypted_value = types::crypto_operation::<serde_json::Value, masking::WithType>(
+                state,
+                common_utils::type_name!(diesel_models::customers::Customer),
+                types::CryptoOperation::Decrypt(Encryption::new(Secret::new(encrypted_value))),
+                identifier.clone(),
+                key,
+            )
+            .await
+            .and_then(|val| val.try_into_operation())
+            .change_context(ValidationError::InvalidValue {
+                message: "Failed while decrypting customer metadata".to_string(),
+            })?;
+
+            *value = decrypted_value.into_inner().expose();
+        }
+    }
+
+    Ok(Some(Secret::new(metadata)))
 }
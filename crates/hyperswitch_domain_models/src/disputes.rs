@@ -87,3 +87,64 @@ impl
         })
     }
 }
+
+/// The attribute of the disputes which their outcomes are grouped by
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DisputeOutcomeGroupBy {
+    /// The reason code of the dispute, as sent by the connector
+    ReasonCode,
+    /// The connector the dispute was raised through
+    Connector,
+}
+
+/// The outcomes of the disputes challenged by the merchant. The disputes which were challenged
+/// but are yet to be decided are counted as pending, and not as lost.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DisputeOutcomeStats {
+    /// The reason code or connector of the disputes, `None` if the outcomes are not grouped or
+    /// for the disputes without a reason code
+    pub group: Option<String>,
+    pub won: i64,
+    pub lost: i64,
+    pub pending: i64,
+}
+
+impl DisputeOutcomeStats {
+    /// Aggregate the count of the disputes by their connector, reason code and status into the
+    /// outcomes of the disputes, grouped as requested. The disputes which were not challenged are
+    /// not counted.
+    pub fn from_status_counts(
+        group_by: Option<DisputeOutcomeGroupBy>,
+        status_counts: impl IntoIterator<
+            Item = (String, Option<String>, common_enums::DisputeStatus, i64),
+        >,
+    ) -> Vec<Self> {
+        let mut outcomes = std::collections::BTreeMap::<Option<String>, Self>::new();
+
+        for (connector, reason_code, dispute_status, count) in status_counts {
+            let (won, lost, pending) = match dispute_status {
+                common_enums::DisputeStatus::DisputeWon => (count, 0, 0),
+                common_enums::DisputeStatus::DisputeLost => (0, count, 0),
+                common_enums::DisputeStatus::DisputeChallenged => (0, 0, count),
+                common_enums::DisputeStatus::DisputeOpened
+                | common_enums::DisputeStatus::DisputeExpired
+                | common_enums::DisputeStatus::DisputeAccepted
+                | common_enums::DisputeStatus::DisputeCancelled => continue,
+            };
+            let group = match group_by {
+                None => None,
+                Some(DisputeOutcomeGroupBy::ReasonCode) => reason_code,
+                Some(DisputeOutcomeGroupBy::Connector) => Some(connector),
+            };
+            let stats = outcomes.entry(group.clone()).or_insert_with(|| Self {
+                group,
+                ..Default::default()
+            });
+            stats.won += won;
+            stats.lost += lost;
+            stats.pending += pending;
+        }
+
+        outcomes.into_values().collect()
+    }
+}
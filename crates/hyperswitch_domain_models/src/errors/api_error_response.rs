@@ -50,6 +50,18 @@ pub enum ApiErrorResponse {
     VerificationFailed { data: Option<serde_json::Value> },
     #[error(error_type = ErrorType::ProcessingError, code = "CE_08", message = "Dispute operation failed while processing with connector. Retry operation")]
     DisputeFailed { data: Option<serde_json::Value> },
+    #[error(error_type = ErrorType::ProcessingError, code = "CE_09", message = "{message}")]
+    NetworkTokenCryptogramRefreshFailed { message: String },
+    #[error(error_type = ErrorType::ConnectorError, code = "CE_10", message = "Too many concurrent requests to {connector}. Retry after {retry_after_in_secs} seconds")]
+    ConnectorConcurrencyLimitReached {
+        connector: String,
+        retry_after_in_secs: u32,
+    },
+    #[error(error_type = ErrorType::ConnectorError, code = "CE_11", message = "{connector} is rate limiting requests. Retry after {retry_after_in_secs} seconds")]
+    ConnectorRateLimited {
+        connector: String,
+        retry_after_in_secs: u32,
+    },
 
     #[error(error_type = ErrorType::LockTimeout, code = "HE_00", message = "Resource is busy. Please try again later.")]
     ResourceBusy,
@@ -274,6 +286,22 @@ pub enum ApiErrorResponse {
     LinkConfigurationError { message: String },
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_41", message = "Payout validation failed")]
     PayoutFailed { data: Option<serde_json::Value> },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_42", message = "{message}")]
+    EnvironmentMismatch { message: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_43", message = "{message}")]
+    VelocityLimitExceeded { message: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_44", message = "{message}")]
+    DuplicatePaymentSuspected { message: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_45", message = "The CVV of the stored card is required for this payment")]
+    CvvRecollectionRequired,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_46", message = "The return_url is not in the domains allowed for the merchant")]
+    InvalidReturnUrl,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_47", message = "The refund window of {refund_window_in_days} days of the connector for the payment has expired")]
+    RefundWindowExpired { refund_window_in_days: i64 },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_48", message = "{message}")]
+    AmountOutOfRange { message: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_49", message = "The {token} has expired")]
+    TokenExpired { token: &'static str },
 
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_01", message = "Failed to authenticate the webhook")]
     WebhookAuthenticationFailed,
@@ -287,6 +315,10 @@ pub enum ApiErrorResponse {
     WebhookUnprocessableEntity,
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_06", message = "Merchant Secret set my merchant for webhook source verification is invalid")]
     WebhookInvalidMerchantSecret,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "WE_07", message = "The webhook timestamp is outside the allowed tolerance window")]
+    WebhookTimestampExpired,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "WE_08", message = "The webhook event has already been received")]
+    WebhookEventReplayed,
     #[error(error_type = ErrorType::ServerNotAvailable, code = "IE", message = "{reason} as data mismatched for {field_names}")]
     IntegrityCheckFailed {
         reason: String,
@@ -355,6 +387,15 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::DisputeFailed { data } => {
                 AER::BadRequest(ApiError::new("CE", 8, "Dispute operation failed while processing with connector. Retry operation", Some(Extra { data: data.clone(), ..Default::default()})))
             }
+            Self::NetworkTokenCryptogramRefreshFailed { message } => {
+                AER::BadRequest(ApiError::new("CE", 9, message.to_string(), None))
+            }
+            Self::ConnectorConcurrencyLimitReached { connector, retry_after_in_secs } => {
+                AER::ConnectorError(ApiError::new("CE", 10, format!("Too many concurrent requests to {connector}. Retry after {retry_after_in_secs} seconds"), Some(Extra { connector: Some(connector.clone()), retry_after_in_secs: Some(*retry_after_in_secs), ..Default::default()})), StatusCode::SERVICE_UNAVAILABLE)
+            }
+            Self::ConnectorRateLimited { connector, retry_after_in_secs } => {
+                AER::ConnectorError(ApiError::new("CE", 11, format!("{connector} is rate limiting requests. Retry after {retry_after_in_secs} seconds"), Some(Extra { connector: Some(connector.clone()), retry_after_in_secs: Some(*retry_after_in_secs), ..Default::default()})), StatusCode::TOO_MANY_REQUESTS)
+            }
 
             Self::ResourceBusy => {
                 AER::Unprocessable(ApiError::new("HE", 0, "There was an issue processing the webhook body", None))
@@ -627,6 +668,30 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::PayoutFailed { data } => {
                 AER::BadRequest(ApiError::new("IR", 41, "Payout failed while processing with connector.", Some(Extra { data: data.clone(), ..Default::default()})))
             },
+            Self::EnvironmentMismatch { message } => {
+                AER::BadRequest(ApiError::new("IR", 42, message, None))
+            },
+            Self::VelocityLimitExceeded { message } => {
+                AER::BadRequest(ApiError::new("IR", 43, message, None))
+            },
+            Self::DuplicatePaymentSuspected { message } => {
+                AER::BadRequest(ApiError::new("IR", 44, message, None))
+            },
+            Self::CvvRecollectionRequired => {
+                AER::BadRequest(ApiError::new("IR", 45, "The CVV of the stored card is required for this payment, collect it from the customer and provide it in `payment_method_data.card_token.card_cvc`", None))
+            },
+            Self::InvalidReturnUrl => {
+                AER::BadRequest(ApiError::new("IR", 46, "The return_url is not in the domains allowed for the merchant, configure the domain in `allowed_return_url_domains` of the merchant account", None))
+            },
+            Self::RefundWindowExpired { refund_window_in_days } => {
+                AER::BadRequest(ApiError::new("IR", 47, format!("The refund window of {refund_window_in_days} days of the connector for the payment has expired, the payment can no longer be refunded through the connector"), None))
+            },
+            Self::AmountOutOfRange { message } => {
+                AER::BadRequest(ApiError::new("IR", 48, message, None))
+            },
+            Self::TokenExpired { token } => {
+                AER::Gone(ApiError::new("IR", 49, format!("The {token} has expired"), None))
+            },
 
             Self::WebhookAuthenticationFailed => {
                 AER::Unauthorized(ApiError::new("WE", 1, "Webhook authentication failed", None))
@@ -646,6 +711,12 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::WebhookInvalidMerchantSecret => {
                 AER::BadRequest(ApiError::new("WE", 6, "Merchant Secret set for webhook source verificartion is invalid", None))
             }
+            Self::WebhookTimestampExpired => {
+                AER::BadRequest(ApiError::new("WE", 7, "The webhook timestamp is outside the allowed tolerance window", None))
+            }
+            Self::WebhookEventReplayed => {
+                AER::BadRequest(ApiError::new("WE", 8, "The webhook event has already been received", None))
+            }
             Self::IntegrityCheckFailed {
                 reason,
                 field_names,
@@ -685,6 +756,7 @@ impl From<ApiErrorResponse> for router_data::ErrorResponse {
             },
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         }
     }
 }
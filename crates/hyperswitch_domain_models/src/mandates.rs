@@ -1,9 +1,12 @@
-use api_models::payments::{
-    AcceptanceType as ApiAcceptanceType, CustomerAcceptance as ApiCustomerAcceptance,
-    MandateAmountData as ApiMandateAmountData, MandateData as ApiMandateData, MandateType,
-    OnlineMandate as ApiOnlineMandate,
+use api_models::{
+    mandates::MandateConstraints as ApiMandateConstraints,
+    payments::{
+        AcceptanceType as ApiAcceptanceType, CustomerAcceptance as ApiCustomerAcceptance,
+        MandateAmountData as ApiMandateAmountData, MandateData as ApiMandateData, MandateType,
+        OnlineMandate as ApiOnlineMandate,
+    },
 };
-use common_enums::Currency;
+use common_enums::{Currency, MandateFrequency};
 use common_utils::{date_time, errors::ParsingError, pii, types::MinorUnit};
 use error_stack::ResultExt;
 use masking::{PeekInterface, Secret};
@@ -45,6 +48,14 @@ pub struct MandateAmountData {
     pub start_date: Option<PrimitiveDateTime>,
     pub end_date: Option<PrimitiveDateTime>,
     pub metadata: Option<pii::SecretSerdeValue>,
+    pub constraints: Option<MandateConstraints>,
+}
+
+#[derive(Clone, Copy, Debug, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
+pub struct MandateConstraints {
+    pub max_amount_per_transaction: Option<MinorUnit>,
+    pub total_amount: Option<MinorUnit>,
+    pub frequency: Option<MandateFrequency>,
 }
 
 // The fields on this struct are optional, as we want to allow the merchant to provide partial
@@ -130,6 +141,7 @@ impl From<ApiMandateAmountData> for MandateAmountData {
             start_date: value.start_date,
             end_date: value.end_date,
             metadata: value.metadata,
+            constraints: value.constraints.map(Into::into),
         }
     }
 }
@@ -142,6 +154,7 @@ impl From<MandateAmountData> for diesel_models::enums::MandateAmountData {
             start_date: value.start_date,
             end_date: value.end_date,
             metadata: value.metadata,
+            constraints: value.constraints.map(Into::into),
         }
     }
 }
@@ -154,6 +167,47 @@ impl From<diesel_models::enums::MandateAmountData> for MandateAmountData {
             start_date: value.start_date,
             end_date: value.end_date,
             metadata: value.metadata,
+            constraints: value.constraints.map(Into::into),
+        }
+    }
+}
+
+impl From<ApiMandateConstraints> for MandateConstraints {
+    fn from(value: ApiMandateConstraints) -> Self {
+        Self {
+            max_amount_per_transaction: value.max_amount_per_transaction,
+            total_amount: value.total_amount,
+            frequency: value.frequency,
+        }
+    }
+}
+
+impl From<MandateConstraints> for ApiMandateConstraints {
+    fn from(value: MandateConstraints) -> Self {
+        Self {
+            max_amount_per_transaction: value.max_amount_per_transaction,
+            total_amount: value.total_amount,
+            frequency: value.frequency,
+        }
+    }
+}
+
+impl From<MandateConstraints> for diesel_models::enums::MandateConstraints {
+    fn from(value: MandateConstraints) -> Self {
+        Self {
+            max_amount_per_transaction: value.max_amount_per_transaction,
+            total_amount: value.total_amount,
+            frequency: value.frequency,
+        }
+    }
+}
+
+impl From<diesel_models::enums::MandateConstraints> for MandateConstraints {
+    fn from(value: diesel_models::enums::MandateConstraints) -> Self {
+        Self {
+            max_amount_per_transaction: value.max_amount_per_transaction,
+            total_amount: value.total_amount,
+            frequency: value.frequency,
         }
     }
 }
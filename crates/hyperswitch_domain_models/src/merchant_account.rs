@@ -8,7 +8,11 @@ use common_utils::{
     types::keymanager::{self},
 };
 use diesel_models::{
-    enums::MerchantStorageScheme, merchant_account::MerchantAccountUpdateInternal,
+    enums::MerchantStorageScheme,
+    merchant_account::{
+        ConnectorFailoverOrder, LeastCostRouting, MerchantAccountUpdateInternal,
+        TransactionAmountLimit, TransactionAmountLimits,
+    },
 };
 use error_stack::ResultExt;
 use masking::{PeekInterface, Secret};
@@ -47,6 +51,18 @@ pub struct MerchantAccount {
     pub payment_link_config: Option<serde_json::Value>,
     pub pm_collect_link_config: Option<serde_json::Value>,
     pub version: common_enums::ApiVersion,
+    pub onboarding_status: common_enums::OnboardingStatus,
+    pub idempotency_ttl_in_seconds: Option<i64>,
+    pub customer_pii_encryption_fields: Option<Vec<String>>,
+    pub statement_descriptor_length_policy: Option<common_enums::StatementDescriptorLengthPolicy>,
+    pub allowed_return_url_domains: Option<Vec<String>>,
+    pub connector_failover_order: Option<ConnectorFailoverOrder>,
+    pub default_capture_method: Option<common_enums::CaptureMethod>,
+    pub least_cost_routing: Option<LeastCostRouting>,
+    pub transaction_amount_limits: Option<TransactionAmountLimits>,
+    pub default_currency: Option<common_enums::Currency>,
+    pub require_explicit_currency: Option<bool>,
+    pub default_connector_by_method_type: Option<serde_json::Value>,
 }
 
 #[cfg(feature = "v1")]
@@ -81,6 +97,18 @@ pub struct MerchantAccountSetter {
     pub payment_link_config: Option<serde_json::Value>,
     pub pm_collect_link_config: Option<serde_json::Value>,
     pub version: common_enums::ApiVersion,
+    pub onboarding_status: common_enums::OnboardingStatus,
+    pub idempotency_ttl_in_seconds: Option<i64>,
+    pub customer_pii_encryption_fields: Option<Vec<String>>,
+    pub statement_descriptor_length_policy: Option<common_enums::StatementDescriptorLengthPolicy>,
+    pub allowed_return_url_domains: Option<Vec<String>>,
+    pub connector_failover_order: Option<ConnectorFailoverOrder>,
+    pub default_capture_method: Option<common_enums::CaptureMethod>,
+    pub least_cost_routing: Option<LeastCostRouting>,
+    pub transaction_amount_limits: Option<TransactionAmountLimits>,
+    pub default_currency: Option<common_enums::Currency>,
+    pub require_explicit_currency: Option<bool>,
+    pub default_connector_by_method_type: Option<serde_json::Value>,
 }
 
 #[cfg(feature = "v1")]
@@ -115,6 +143,18 @@ impl From<MerchantAccountSetter> for MerchantAccount {
             payment_link_config: item.payment_link_config,
             pm_collect_link_config: item.pm_collect_link_config,
             version: item.version,
+            onboarding_status: item.onboarding_status,
+            idempotency_ttl_in_seconds: item.idempotency_ttl_in_seconds,
+            customer_pii_encryption_fields: item.customer_pii_encryption_fields,
+            statement_descriptor_length_policy: item.statement_descriptor_length_policy,
+            allowed_return_url_domains: item.allowed_return_url_domains,
+            connector_failover_order: item.connector_failover_order,
+            default_capture_method: item.default_capture_method,
+            least_cost_routing: item.least_cost_routing,
+            transaction_amount_limits: item.transaction_amount_limits,
+            default_currency: item.default_currency,
+            require_explicit_currency: item.require_explicit_currency,
+            default_connector_by_method_type: item.default_connector_by_method_type,
         }
     }
 }
@@ -133,6 +173,7 @@ pub struct MerchantAccountSetter {
     pub modified_at: time::PrimitiveDateTime,
     pub organization_id: common_utils::id_type::OrganizationId,
     pub recon_status: diesel_models::enums::ReconStatus,
+    pub onboarding_status: common_enums::OnboardingStatus,
 }
 
 #[cfg(feature = "v2")]
@@ -149,6 +190,7 @@ impl From<MerchantAccountSetter> for MerchantAccount {
             modified_at,
             organization_id,
             recon_status,
+            onboarding_status,
         } = item;
         Self {
             id,
@@ -161,6 +203,7 @@ impl From<MerchantAccountSetter> for MerchantAccount {
             modified_at,
             organization_id,
             recon_status,
+            onboarding_status,
         }
     }
 }
@@ -178,6 +221,7 @@ pub struct MerchantAccount {
     pub modified_at: time::PrimitiveDateTime,
     pub organization_id: common_utils::id_type::OrganizationId,
     pub recon_status: diesel_models::enums::ReconStatus,
+    pub onboarding_status: common_enums::OnboardingStatus,
 }
 
 impl MerchantAccount {
@@ -197,6 +241,146 @@ impl MerchantAccount {
     pub fn get_org_id(&self) -> &common_utils::id_type::OrganizationId {
         &self.organization_id
     }
+
+    #[cfg(feature = "v1")]
+    /// Get the fields of the customer, in addition to the PII fields, which are encrypted at rest
+    pub fn get_customer_pii_encryption_fields(&self) -> &[String] {
+        self.customer_pii_encryption_fields
+            .as_deref()
+            .unwrap_or_default()
+    }
+
+    #[cfg(feature = "v2")]
+    /// Get the fields of the customer, in addition to the PII fields, which are encrypted at rest
+    pub fn get_customer_pii_encryption_fields(&self) -> &[String] {
+        &[]
+    }
+
+    #[cfg(feature = "v1")]
+    /// Get the policy for the statement descriptors which exceed the maximum length supported by
+    /// the connector
+    pub fn get_statement_descriptor_length_policy(
+        &self,
+    ) -> common_enums::StatementDescriptorLengthPolicy {
+        self.statement_descriptor_length_policy.unwrap_or_default()
+    }
+
+    #[cfg(feature = "v2")]
+    /// Get the policy for the statement descriptors which exceed the maximum length supported by
+    /// the connector
+    pub fn get_statement_descriptor_length_policy(
+        &self,
+    ) -> common_enums::StatementDescriptorLengthPolicy {
+        common_enums::StatementDescriptorLengthPolicy::default()
+    }
+
+    #[cfg(feature = "v1")]
+    /// Get the domains to which the customer may be redirected after a payment, if configured
+    pub fn get_allowed_return_url_domains(&self) -> Option<&[String]> {
+        self.allowed_return_url_domains.as_deref()
+    }
+
+    #[cfg(feature = "v2")]
+    /// Get the domains to which the customer may be redirected after a payment, if configured
+    pub fn get_allowed_return_url_domains(&self) -> Option<&[String]> {
+        None
+    }
+
+    #[cfg(feature = "v1")]
+    /// Get the connectors in the order in which they would be tried for a payment, from the
+    /// connector failover order of the merchant
+    pub fn get_failover_chain(
+        &self,
+        payment_method_type: Option<common_enums::PaymentMethodType>,
+        currency: common_enums::Currency,
+        country: Option<common_enums::CountryAlpha2>,
+    ) -> Vec<common_enums::RoutableConnectors> {
+        self.connector_failover_order
+            .as_ref()
+            .map(|failover_order| {
+                failover_order.get_failover_chain(payment_method_type, currency, country)
+            })
+            .unwrap_or_default()
+    }
+
+    #[cfg(feature = "v2")]
+    /// Get the connectors in the order in which they would be tried for a payment, from the
+    /// connector failover order of the merchant
+    pub fn get_failover_chain(
+        &self,
+        _payment_method_type: Option<common_enums::PaymentMethodType>,
+        _currency: common_enums::Currency,
+        _country: Option<common_enums::CountryAlpha2>,
+    ) -> Vec<common_enums::RoutableConnectors> {
+        Vec::new()
+    }
+
+    #[cfg(feature = "v1")]
+    /// Get the capture method of the payments which do not specify one, if configured
+    pub fn get_default_capture_method(&self) -> Option<common_enums::CaptureMethod> {
+        self.default_capture_method
+    }
+
+    #[cfg(feature = "v2")]
+    /// Get the capture method of the payments which do not specify one, if configured
+    pub fn get_default_capture_method(&self) -> Option<common_enums::CaptureMethod> {
+        None
+    }
+
+    #[cfg(feature = "v1")]
+    /// Get the least cost routing of the merchant, if it is enabled
+    pub fn get_least_cost_routing(&self) -> Option<&LeastCostRouting> {
+        self.least_cost_routing
+            .as_ref()
+            .filter(|least_cost_routing| least_cost_routing.enabled)
+    }
+
+    #[cfg(feature = "v2")]
+    /// Get the least cost routing of the merchant, if it is enabled
+    pub fn get_least_cost_routing(&self) -> Option<&LeastCostRouting> {
+        None
+    }
+
+    #[cfg(feature = "v1")]
+    /// Get the limits of the amount of the payments of the merchant which apply to a payment, if
+    /// configured
+    pub fn get_transaction_amount_limit(
+        &self,
+        currency: common_enums::Currency,
+        payment_method_type: Option<common_enums::PaymentMethodType>,
+    ) -> Option<&TransactionAmountLimit> {
+        self.transaction_amount_limits
+            .as_ref()
+            .and_then(|limits| limits.get_applicable_limit(currency, payment_method_type))
+    }
+
+    #[cfg(feature = "v2")]
+    /// Get the limits of the amount of the payments of the merchant which apply to a payment, if
+    /// configured
+    pub fn get_transaction_amount_limit(
+        &self,
+        _currency: common_enums::Currency,
+        _payment_method_type: Option<common_enums::PaymentMethodType>,
+    ) -> Option<&TransactionAmountLimit> {
+        None
+    }
+
+    #[cfg(feature = "v1")]
+    /// Get the currency of the payments which do not specify one, if configured and the merchant
+    /// does not require the currency of the payments to be specified
+    pub fn get_default_currency(&self) -> Option<common_enums::Currency> {
+        match self.require_explicit_currency {
+            Some(true) => None,
+            Some(false) | None => self.default_currency,
+        }
+    }
+
+    #[cfg(feature = "v2")]
+    /// Get the currency of the payments which do not specify one, if configured and the merchant
+    /// does not require the currency of the payments to be specified
+    pub fn get_default_currency(&self) -> Option<common_enums::Currency> {
+        None
+    }
 }
 
 #[cfg(feature = "v1")]
@@ -224,6 +408,17 @@ pub enum MerchantAccountUpdate {
         default_profile: Option<Option<common_utils::id_type::ProfileId>>,
         payment_link_config: Option<serde_json::Value>,
         pm_collect_link_config: Option<serde_json::Value>,
+        idempotency_ttl_in_seconds: Option<i64>,
+        customer_pii_encryption_fields: Option<Vec<String>>,
+        statement_descriptor_length_policy: Option<common_enums::StatementDescriptorLengthPolicy>,
+        allowed_return_url_domains: Option<Vec<String>>,
+        connector_failover_order: Option<ConnectorFailoverOrder>,
+        default_capture_method: Option<Option<common_enums::CaptureMethod>>,
+        least_cost_routing: Option<LeastCostRouting>,
+        transaction_amount_limits: Option<TransactionAmountLimits>,
+        default_currency: Option<Option<common_enums::Currency>>,
+        require_explicit_currency: Option<bool>,
+        default_connector_by_method_type: Option<serde_json::Value>,
     },
     StorageSchemeUpdate {
         storage_scheme: MerchantStorageScheme,
@@ -282,6 +477,17 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 default_profile,
                 payment_link_config,
                 pm_collect_link_config,
+                idempotency_ttl_in_seconds,
+                customer_pii_encryption_fields,
+                statement_descriptor_length_policy,
+                allowed_return_url_domains,
+                connector_failover_order,
+                default_capture_method,
+                least_cost_routing,
+                transaction_amount_limits,
+                default_currency,
+                require_explicit_currency,
+                default_connector_by_method_type,
             } => Self {
                 merchant_name: merchant_name.map(Encryption::from),
                 merchant_details: merchant_details.map(Encryption::from),
@@ -304,6 +510,17 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 default_profile,
                 payment_link_config,
                 pm_collect_link_config,
+                idempotency_ttl_in_seconds,
+                customer_pii_encryption_fields,
+                statement_descriptor_length_policy,
+                allowed_return_url_domains,
+                connector_failover_order,
+                default_capture_method,
+                least_cost_routing,
+                transaction_amount_limits,
+                default_currency,
+                require_explicit_currency,
+                default_connector_by_method_type,
                 storage_scheme: None,
                 organization_id: None,
                 is_recon_enabled: None,
@@ -335,6 +552,17 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 recon_status: None,
                 payment_link_config: None,
                 pm_collect_link_config: None,
+                idempotency_ttl_in_seconds: None,
+                customer_pii_encryption_fields: None,
+                statement_descriptor_length_policy: None,
+                allowed_return_url_domains: None,
+                connector_failover_order: None,
+                default_capture_method: None,
+                least_cost_routing: None,
+                transaction_amount_limits: None,
+                default_currency: None,
+                require_explicit_currency: None,
+                default_connector_by_method_type: None,
             },
             MerchantAccountUpdate::ReconUpdate { recon_status } => Self {
                 recon_status: Some(recon_status),
@@ -362,6 +590,17 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 default_profile: None,
                 payment_link_config: None,
                 pm_collect_link_config: None,
+                idempotency_ttl_in_seconds: None,
+                customer_pii_encryption_fields: None,
+                statement_descriptor_length_policy: None,
+                allowed_return_url_domains: None,
+                connector_failover_order: None,
+                default_capture_method: None,
+                least_cost_routing: None,
+                transaction_amount_limits: None,
+                default_currency: None,
+                require_explicit_currency: None,
+                default_connector_by_method_type: None,
             },
             MerchantAccountUpdate::UnsetDefaultProfile => Self {
                 default_profile: Some(None),
@@ -389,6 +628,17 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 recon_status: None,
                 payment_link_config: None,
                 pm_collect_link_config: None,
+                idempotency_ttl_in_seconds: None,
+                customer_pii_encryption_fields: None,
+                statement_descriptor_length_policy: None,
+                allowed_return_url_domains: None,
+                connector_failover_order: None,
+                default_capture_method: None,
+                least_cost_routing: None,
+                transaction_amount_limits: None,
+                default_currency: None,
+                require_explicit_currency: None,
+                default_connector_by_method_type: None,
             },
             MerchantAccountUpdate::ModifiedAtUpdate => Self {
                 modified_at: now,
@@ -416,6 +666,17 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 recon_status: None,
                 payment_link_config: None,
                 pm_collect_link_config: None,
+                idempotency_ttl_in_seconds: None,
+                customer_pii_encryption_fields: None,
+                statement_descriptor_length_policy: None,
+                allowed_return_url_domains: None,
+                connector_failover_order: None,
+                default_capture_method: None,
+                least_cost_routing: None,
+                transaction_amount_limits: None,
+                default_currency: None,
+                require_explicit_currency: None,
+                default_connector_by_method_type: None,
             },
         }
     }
@@ -496,6 +757,7 @@ impl super::behaviour::Conversion for MerchantAccount {
             organization_id: self.organization_id,
             recon_status: self.recon_status,
             version: crate::consts::API_VERSION,
+            onboarding_status: self.onboarding_status,
         };
 
         Ok(diesel_models::MerchantAccount::from(setter))
@@ -555,6 +817,7 @@ impl super::behaviour::Conversion for MerchantAccount {
                 modified_at: item.modified_at,
                 organization_id: item.organization_id,
                 recon_status: item.recon_status,
+                onboarding_status: item.onboarding_status,
             })
         }
         .await
@@ -576,6 +839,7 @@ impl super::behaviour::Conversion for MerchantAccount {
             organization_id: self.organization_id,
             recon_status: self.recon_status,
             version: crate::consts::API_VERSION,
+            onboarding_status: self.onboarding_status,
         })
     }
 }
@@ -615,6 +879,18 @@ impl super::behaviour::Conversion for MerchantAccount {
             payment_link_config: self.payment_link_config,
             pm_collect_link_config: self.pm_collect_link_config,
             version: self.version,
+            onboarding_status: self.onboarding_status,
+            idempotency_ttl_in_seconds: self.idempotency_ttl_in_seconds,
+            customer_pii_encryption_fields: self.customer_pii_encryption_fields,
+            statement_descriptor_length_policy: self.statement_descriptor_length_policy,
+            allowed_return_url_domains: self.allowed_return_url_domains,
+            connector_failover_order: self.connector_failover_order,
+            default_capture_method: self.default_capture_method,
+            least_cost_routing: self.least_cost_routing,
+            transaction_amount_limits: self.transaction_amount_limits,
+            default_currency: self.default_currency,
+            require_explicit_currency: self.require_explicit_currency,
+            default_connector_by_method_type: self.default_connector_by_method_type,
         };
 
         Ok(diesel_models::MerchantAccount::from(setter))
@@ -692,6 +968,18 @@ impl super::behaviour::Conversion for MerchantAccount {
                 payment_link_config: item.payment_link_config,
                 pm_collect_link_config: item.pm_collect_link_config,
                 version: item.version,
+                onboarding_status: item.onboarding_status,
+                idempotency_ttl_in_seconds: item.idempotency_ttl_in_seconds,
+                customer_pii_encryption_fields: item.customer_pii_encryption_fields,
+                statement_descriptor_length_policy: item.statement_descriptor_length_policy,
+                allowed_return_url_domains: item.allowed_return_url_domains,
+                connector_failover_order: item.connector_failover_order,
+                default_capture_method: item.default_capture_method,
+                least_cost_routing: item.least_cost_routing,
+                transaction_amount_limits: item.transaction_amount_limits,
+                default_currency: item.default_currency,
+                require_explicit_currency: item.require_explicit_currency,
+                default_connector_by_method_type: item.default_connector_by_method_type,
             })
         }
         .await
@@ -730,6 +1018,18 @@ impl super::behaviour::Conversion for MerchantAccount {
             payment_link_config: self.payment_link_config,
             pm_collect_link_config: self.pm_collect_link_config,
             version: crate::consts::API_VERSION,
+            onboarding_status: self.onboarding_status,
+            idempotency_ttl_in_seconds: self.idempotency_ttl_in_seconds,
+            customer_pii_encryption_fields: self.customer_pii_encryption_fields,
+            statement_descriptor_length_policy: self.statement_descriptor_length_policy,
+            allowed_return_url_domains: self.allowed_return_url_domains,
+            connector_failover_order: self.connector_failover_order,
+            default_capture_method: self.default_capture_method,
+            least_cost_routing: self.least_cost_routing,
+            transaction_amount_limits: self.transaction_amount_limits,
+            default_currency: self.default_currency,
+            require_explicit_currency: self.require_explicit_currency,
+            default_connector_by_method_type: self.default_connector_by_method_type,
         })
     }
 }
@@ -6,7 +6,12 @@ use common_utils::{
     id_type, pii, type_name,
     types::keymanager::{Identifier, KeyManagerState, ToEncryptable},
 };
-use diesel_models::{enums, merchant_connector_account::MerchantConnectorAccountUpdateInternal};
+use diesel_models::{
+    enums,
+    merchant_connector_account::{
+        ConnectorMaintenanceWindows, MerchantConnectorAccountUpdateInternal,
+    },
+};
 use error_stack::ResultExt;
 use masking::{PeekInterface, Secret};
 use rustc_hash::FxHashMap;
@@ -41,6 +46,12 @@ pub struct MerchantConnectorAccount {
     pub connector_wallets_details: Option<Encryptable<pii::SecretSerdeValue>>,
     pub additional_merchant_data: Option<Encryptable<pii::SecretSerdeValue>>,
     pub version: common_enums::ApiVersion,
+    pub request_timeout_secs: Option<i16>,
+    pub webhook_identifier: Option<String>,
+    pub environment: Option<enums::ConnectorEnvironment>,
+    pub maintenance_windows: Option<ConnectorMaintenanceWindows>,
+    pub pending_connector_account_details: Option<Encryptable<pii::SecretSerdeValue>>,
+    pub credential_rotation_status: Option<enums::ConnectorCredentialRotationStatus>,
 }
 
 #[cfg(feature = "v1")]
@@ -73,6 +84,12 @@ pub struct MerchantConnectorAccount {
     pub connector_wallets_details: Option<Encryptable<pii::SecretSerdeValue>>,
     pub additional_merchant_data: Option<Encryptable<pii::SecretSerdeValue>>,
     pub version: common_enums::ApiVersion,
+    pub request_timeout_secs: Option<i16>,
+    pub webhook_identifier: Option<String>,
+    pub environment: Option<enums::ConnectorEnvironment>,
+    pub maintenance_windows: Option<ConnectorMaintenanceWindows>,
+    pub pending_connector_account_details: Option<Encryptable<pii::SecretSerdeValue>>,
+    pub credential_rotation_status: Option<enums::ConnectorCredentialRotationStatus>,
 }
 
 #[cfg(feature = "v2")]
@@ -82,6 +99,17 @@ impl MerchantConnectorAccount {
     }
 }
 
+impl MerchantConnectorAccount {
+    /// Check whether the connector account is currently in one of its maintenance windows
+    pub fn is_in_maintenance(&self) -> bool {
+        self.maintenance_windows
+            .as_ref()
+            .map_or(false, |maintenance_windows| {
+                maintenance_windows.is_in_maintenance_at(date_time::now())
+            })
+    }
+}
+
 #[cfg(feature = "v1")]
 #[derive(Debug)]
 pub enum MerchantConnectorAccountUpdate {
@@ -102,10 +130,24 @@ pub enum MerchantConnectorAccountUpdate {
         status: Option<enums::ConnectorStatus>,
         connector_wallets_details: Option<Encryptable<pii::SecretSerdeValue>>,
         additional_merchant_data: Option<Encryptable<pii::SecretSerdeValue>>,
+        request_timeout_secs: Option<i16>,
+        environment: Option<enums::ConnectorEnvironment>,
+        maintenance_windows: Option<ConnectorMaintenanceWindows>,
     },
     ConnectorWalletDetailsUpdate {
         connector_wallets_details: Encryptable<pii::SecretSerdeValue>,
     },
+    /// Stage, promote or discard the pending credentials of the connector account. The pending
+    /// credentials are replaced by `pending_connector_account_details`, and the connector account
+    /// details by `connector_account_details` if present.
+    CredentialRotationUpdate {
+        connector_account_details: Option<Encryptable<pii::SecretSerdeValue>>,
+        pending_connector_account_details: Option<Encryptable<pii::SecretSerdeValue>>,
+        credential_rotation_status: enums::ConnectorCredentialRotationStatus,
+    },
+    WebhookIdentifierUpdate {
+        webhook_identifier: String,
+    },
 }
 
 #[cfg(feature = "v2")]
@@ -125,10 +167,21 @@ pub enum MerchantConnectorAccountUpdate {
         status: Option<enums::ConnectorStatus>,
         connector_wallets_details: Option<Encryptable<pii::SecretSerdeValue>>,
         additional_merchant_data: Option<Encryptable<pii::SecretSerdeValue>>,
+        request_timeout_secs: Option<i16>,
+        environment: Option<enums::ConnectorEnvironment>,
+        maintenance_windows: Option<ConnectorMaintenanceWindows>,
     },
     ConnectorWalletDetailsUpdate {
         connector_wallets_details: Encryptable<pii::SecretSerdeValue>,
     },
+    /// Stage, promote or discard the pending credentials of the connector account. The pending
+    /// credentials are replaced by `pending_connector_account_details`, and the connector account
+    /// details by `connector_account_details` if present.
+    CredentialRotationUpdate {
+        connector_account_details: Option<Encryptable<pii::SecretSerdeValue>>,
+        pending_connector_account_details: Option<Encryptable<pii::SecretSerdeValue>>,
+        credential_rotation_status: enums::ConnectorCredentialRotationStatus,
+    },
 }
 
 #[cfg(feature = "v1")]
@@ -165,6 +218,14 @@ impl behaviour::Conversion for MerchantConnectorAccount {
                 connector_wallets_details: self.connector_wallets_details.map(Encryption::from),
                 additional_merchant_data: self.additional_merchant_data.map(|data| data.into()),
                 version: self.version,
+                request_timeout_secs: self.request_timeout_secs,
+                webhook_identifier: self.webhook_identifier,
+                environment: self.environment,
+                maintenance_windows: self.maintenance_windows,
+                pending_connector_account_details: self
+                    .pending_connector_account_details
+                    .map(Encryption::from),
+                credential_rotation_status: self.credential_rotation_status,
             },
         )
     }
@@ -183,6 +244,7 @@ impl behaviour::Conversion for MerchantConnectorAccount {
                 connector_account_details: other.connector_account_details,
                 additional_merchant_data: other.additional_merchant_data,
                 connector_wallets_details: other.connector_wallets_details,
+                pending_connector_account_details: other.pending_connector_account_details,
             })),
             identifier.clone(),
             key.peek(),
@@ -229,6 +291,12 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             connector_wallets_details: decrypted_data.connector_wallets_details,
             additional_merchant_data: decrypted_data.additional_merchant_data,
             version: other.version,
+            request_timeout_secs: other.request_timeout_secs,
+            webhook_identifier: other.webhook_identifier,
+            environment: other.environment,
+            maintenance_windows: other.maintenance_windows,
+            pending_connector_account_details: decrypted_data.pending_connector_account_details,
+            credential_rotation_status: other.credential_rotation_status,
         })
     }
 
@@ -260,6 +328,14 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             connector_wallets_details: self.connector_wallets_details.map(Encryption::from),
             additional_merchant_data: self.additional_merchant_data.map(|data| data.into()),
             version: self.version,
+            request_timeout_secs: self.request_timeout_secs,
+            webhook_identifier: self.webhook_identifier,
+            environment: self.environment,
+            maintenance_windows: self.maintenance_windows,
+            pending_connector_account_details: self
+                .pending_connector_account_details
+                .map(Encryption::from),
+            credential_rotation_status: self.credential_rotation_status,
         })
     }
 }
@@ -293,6 +369,14 @@ impl behaviour::Conversion for MerchantConnectorAccount {
                 connector_wallets_details: self.connector_wallets_details.map(Encryption::from),
                 additional_merchant_data: self.additional_merchant_data.map(|data| data.into()),
                 version: self.version,
+                request_timeout_secs: self.request_timeout_secs,
+                webhook_identifier: self.webhook_identifier,
+                environment: self.environment,
+                maintenance_windows: self.maintenance_windows,
+                pending_connector_account_details: self
+                    .pending_connector_account_details
+                    .map(Encryption::from),
+                credential_rotation_status: self.credential_rotation_status,
             },
         )
     }
@@ -312,6 +396,7 @@ impl behaviour::Conversion for MerchantConnectorAccount {
                 connector_account_details: other.connector_account_details,
                 additional_merchant_data: other.additional_merchant_data,
                 connector_wallets_details: other.connector_wallets_details,
+                pending_connector_account_details: other.pending_connector_account_details,
             })),
             identifier.clone(),
             key.peek(),
@@ -350,6 +435,12 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             connector_wallets_details: decrypted_data.connector_wallets_details,
             additional_merchant_data: decrypted_data.additional_merchant_data,
             version: other.version,
+            request_timeout_secs: other.request_timeout_secs,
+            webhook_identifier: other.webhook_identifier,
+            environment: other.environment,
+            maintenance_windows: other.maintenance_windows,
+            pending_connector_account_details: decrypted_data.pending_connector_account_details,
+            credential_rotation_status: other.credential_rotation_status,
         })
     }
 
@@ -376,6 +467,14 @@ impl behaviour::Conversion for MerchantConnectorAccount {
             connector_wallets_details: self.connector_wallets_details.map(Encryption::from),
             additional_merchant_data: self.additional_merchant_data.map(|data| data.into()),
             version: self.version,
+            request_timeout_secs: self.request_timeout_secs,
+            webhook_identifier: self.webhook_identifier,
+            environment: self.environment,
+            maintenance_windows: self.maintenance_windows,
+            pending_connector_account_details: self
+                .pending_connector_account_details
+                .map(Encryption::from),
+            credential_rotation_status: self.credential_rotation_status,
         })
     }
 }
@@ -401,6 +500,9 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 status,
                 connector_wallets_details,
                 additional_merchant_data,
+                request_timeout_secs,
+                environment,
+                maintenance_windows,
             } => Self {
                 connector_type,
                 connector_name,
@@ -420,6 +522,12 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 status,
                 connector_wallets_details: connector_wallets_details.map(Encryption::from),
                 additional_merchant_data: additional_merchant_data.map(Encryption::from),
+                request_timeout_secs,
+                webhook_identifier: None,
+                environment,
+                maintenance_windows,
+                pending_connector_account_details: None,
+                credential_rotation_status: None,
             },
             MerchantConnectorAccountUpdate::ConnectorWalletDetailsUpdate {
                 connector_wallets_details,
@@ -442,6 +550,72 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 pm_auth_config: None,
                 status: None,
                 additional_merchant_data: None,
+                request_timeout_secs: None,
+                webhook_identifier: None,
+                environment: None,
+                maintenance_windows: None,
+                pending_connector_account_details: None,
+                credential_rotation_status: None,
+            },
+            MerchantConnectorAccountUpdate::WebhookIdentifierUpdate { webhook_identifier } => {
+                Self {
+                    webhook_identifier: Some(webhook_identifier),
+                    modified_at: Some(date_time::now()),
+                    connector_type: None,
+                    connector_name: None,
+                    connector_account_details: None,
+                    connector_label: None,
+                    test_mode: None,
+                    disabled: None,
+                    merchant_connector_id: None,
+                    payment_methods_enabled: None,
+                    frm_configs: None,
+                    metadata: None,
+                    connector_webhook_details: None,
+                    frm_config: None,
+                    applepay_verified_domains: None,
+                    pm_auth_config: None,
+                    status: None,
+                    connector_wallets_details: None,
+                    additional_merchant_data: None,
+                    request_timeout_secs: None,
+                    environment: None,
+                    maintenance_windows: None,
+                    pending_connector_account_details: None,
+                    credential_rotation_status: None,
+                }
+            }
+            MerchantConnectorAccountUpdate::CredentialRotationUpdate {
+                connector_account_details,
+                pending_connector_account_details,
+                credential_rotation_status,
+            } => Self {
+                connector_account_details: connector_account_details.map(Encryption::from),
+                pending_connector_account_details: Some(
+                    pending_connector_account_details.map(Encryption::from),
+                ),
+                credential_rotation_status: Some(credential_rotation_status),
+                modified_at: Some(date_time::now()),
+                connector_type: None,
+                connector_name: None,
+                connector_label: None,
+                test_mode: None,
+                disabled: None,
+                merchant_connector_id: None,
+                payment_methods_enabled: None,
+                frm_configs: None,
+                metadata: None,
+                connector_webhook_details: None,
+                frm_config: None,
+                applepay_verified_domains: None,
+                pm_auth_config: None,
+                status: None,
+                connector_wallets_details: None,
+                additional_merchant_data: None,
+                request_timeout_secs: None,
+                webhook_identifier: None,
+                environment: None,
+                maintenance_windows: None,
             },
         }
     }
@@ -465,6 +639,9 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 status,
                 connector_wallets_details,
                 additional_merchant_data,
+                request_timeout_secs,
+                environment,
+                maintenance_windows,
             } => Self {
                 connector_type,
                 connector_account_details: connector_account_details.map(Encryption::from),
@@ -480,6 +657,12 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 status,
                 connector_wallets_details: connector_wallets_details.map(Encryption::from),
                 additional_merchant_data: additional_merchant_data.map(Encryption::from),
+                request_timeout_secs,
+                webhook_identifier: None,
+                environment,
+                maintenance_windows,
+                pending_connector_account_details: None,
+                credential_rotation_status: None,
             },
             MerchantConnectorAccountUpdate::ConnectorWalletDetailsUpdate {
                 connector_wallets_details,
@@ -498,6 +681,40 @@ impl From<MerchantConnectorAccountUpdate> for MerchantConnectorAccountUpdateInte
                 pm_auth_config: None,
                 status: None,
                 additional_merchant_data: None,
+                request_timeout_secs: None,
+                webhook_identifier: None,
+                environment: None,
+                maintenance_windows: None,
+                pending_connector_account_details: None,
+                credential_rotation_status: None,
+            },
+            MerchantConnectorAccountUpdate::CredentialRotationUpdate {
+                connector_account_details,
+                pending_connector_account_details,
+                credential_rotation_status,
+            } => Self {
+                connector_account_details: connector_account_details.map(Encryption::from),
+                pending_connector_account_details: Some(
+                    pending_connector_account_details.map(Encryption::from),
+                ),
+                credential_rotation_status: Some(credential_rotation_status),
+                modified_at: Some(date_time::now()),
+                connector_type: None,
+                connector_label: None,
+                disabled: None,
+                payment_methods_enabled: None,
+                metadata: None,
+                connector_webhook_details: None,
+                frm_config: None,
+                applepay_verified_domains: None,
+                pm_auth_config: None,
+                status: None,
+                connector_wallets_details: None,
+                additional_merchant_data: None,
+                request_timeout_secs: None,
+                webhook_identifier: None,
+                environment: None,
+                maintenance_windows: None,
             },
         }
     }
@@ -518,12 +735,14 @@ pub struct DecryptedMca {
     pub connector_account_details: Encryptable<pii::SecretSerdeValue>,
     pub connector_wallets_details: Option<Encryptable<pii::SecretSerdeValue>>,
     pub additional_merchant_data: Option<Encryptable<pii::SecretSerdeValue>>,
+    pub pending_connector_account_details: Option<Encryptable<pii::SecretSerdeValue>>,
 }
 
 pub struct EncryptedMca {
     pub connector_account_details: Encryption,
     pub connector_wallets_details: Option<Encryption>,
     pub additional_merchant_data: Option<Encryption>,
+    pub pending_connector_account_details: Option<Encryption>,
 }
 
 pub struct DecryptedUpdateMca {
@@ -544,11 +763,12 @@ impl ToEncryptable<DecryptedMca, Secret<serde_json::Value>, Encryption> for Encr
             )?,
             connector_wallets_details: hashmap.remove("connector_wallets_details"),
             additional_merchant_data: hashmap.remove("additional_merchant_data"),
+            pending_connector_account_details: hashmap.remove("pending_connector_account_details"),
         })
     }
 
     fn to_encryptable(self) -> FxHashMap<String, Encryption> {
-        let mut map = FxHashMap::with_capacity_and_hasher(3, Default::default());
+        let mut map = FxHashMap::with_capacity_and_hasher(4, Default::default());
 
         map.insert(
             "connector_account_details".to_string(),
@@ -558,6 +778,8 @@ impl ToEncryptable<DecryptedMca, Secret<serde_json::Value>, Encryption> for Encr
             .map(|s| map.insert("connector_wallets_details".to_string(), s));
         self.additional_merchant_data
             .map(|s| map.insert("additional_merchant_data".to_string(), s));
+        self.pending_connector_account_details
+            .map(|s| map.insert("pending_connector_account_details".to_string(), s));
         map
     }
 }
@@ -603,6 +825,7 @@ impl ToEncryptable<DecryptedMca, Secret<serde_json::Value>, Secret<serde_json::V
             )?,
             connector_wallets_details: hashmap.remove("connector_wallets_details"),
             additional_merchant_data: hashmap.remove("additional_merchant_data"),
+            pending_connector_account_details: None,
         })
     }
 
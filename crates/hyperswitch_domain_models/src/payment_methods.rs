@@ -54,6 +54,7 @@ pub struct PaymentMethod {
     pub network_token_requestor_reference_id: Option<String>,
     pub network_token_locker_id: Option<String>,
     pub network_token_payment_method_data: OptionalEncryptableValue,
+    pub retokenization_pending: Option<bool>,
 }
 
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
@@ -147,6 +148,7 @@ impl super::behaviour::Conversion for PaymentMethod {
             network_token_payment_method_data: self
                 .network_token_payment_method_data
                 .map(|val| val.into()),
+            retokenization_pending: self.retokenization_pending,
         })
     }
 
@@ -234,6 +236,7 @@ impl super::behaviour::Conversion for PaymentMethod {
                         .and_then(|val| val.try_into_optionaloperation())
                     })
                     .await?,
+                retokenization_pending: item.retokenization_pending,
             })
         }
         .await
@@ -282,6 +285,7 @@ impl super::behaviour::Conversion for PaymentMethod {
             network_token_payment_method_data: self
                 .network_token_payment_method_data
                 .map(|val| val.into()),
+            retokenization_pending: self.retokenization_pending,
         })
     }
 }
@@ -76,6 +76,18 @@ pub struct PaymentIntent {
     pub organization_id: id_type::OrganizationId,
     pub tax_details: Option<TaxDetails>,
     pub skip_external_tax_calculation: Option<bool>,
+    pub amount_breakdown: Option<diesel_models::payment_intent::AmountBreakdown>,
+    /// The payment which this payment renews or retries, linking the payments of a recurring
+    /// relationship or a retry sequence into a chain
+    pub parent_payment_id: Option<id_type::PaymentId>,
+    /// The first payment of the chain of related payments which this payment belongs to, which is
+    /// not set for the first payment itself
+    pub root_payment_id: Option<id_type::PaymentId>,
+    /// The fulfillment status of the order, recorded by the merchant independently of the status
+    /// of the payment
+    pub fulfillment_status: Option<common_enums::FulfillmentStatus>,
+    pub shipped_at: Option<PrimitiveDateTime>,
+    pub delivered_at: Option<PrimitiveDateTime>,
 }
 
 impl PaymentIntent {
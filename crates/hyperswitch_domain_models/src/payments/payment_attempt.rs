@@ -96,6 +96,17 @@ pub trait PaymentAttemptInterface {
         storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<PaymentAttempt, errors::StorageError>;
 
+    /// Find the payment attempt of the merchant made through the connector, with the connector's
+    /// transaction id
+    #[cfg(feature = "v1")]
+    async fn find_payment_attempt_by_merchant_id_connector_connector_txn_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        connector: &str,
+        connector_txn_id: &str,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<PaymentAttempt, errors::StorageError>;
+
     #[cfg(feature = "v1")]
     async fn find_payment_attempt_by_payment_id_merchant_id_attempt_id(
         &self,
@@ -160,6 +171,46 @@ pub trait PaymentAttemptInterface {
         profile_id_list: Option<Vec<id_type::ProfileId>>,
         storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<i64, errors::StorageError>;
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn list_payments_by_tag(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        tag_key: &str,
+        tag_value: &str,
+        filters: &PaymentAttemptTagFilters,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PaymentAttempt>, errors::StorageError>;
+
+    /// List the payment attempts of the merchant which are pending a review of their fraud check,
+    /// the oldest first
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn list_payments_pending_review(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        filters: &PaymentAttemptReviewFilters,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PaymentAttempt>, errors::StorageError>;
+
+    /// Count the distinct cards used in the payment attempts of the merchant created in the time
+    /// range, by their card hashes
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn get_distinct_card_hash_count_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        time_range: common_utils::types::TimeRange,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<i64, errors::StorageError>;
+
+    /// Sum the amounts of the captured payment attempts of the merchant which are expected to be
+    /// settled on the date, by currency
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn get_settlement_amounts_by_currency(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        settlement_date: time::Date,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<(storage_enums::Currency, MinorUnit)>, errors::StorageError>;
 }
 
 #[cfg(feature = "v2")]
@@ -221,6 +272,15 @@ pub struct PaymentAttempt {
     pub external_reference_id: Option<String>,
     pub shipping_cost: Option<MinorUnit>,
     pub order_tax_amount: Option<MinorUnit>,
+    pub fraud_score: Option<i32>,
+    pub fraud_decision: Option<storage_enums::FraudDecision>,
+    pub approved_amount: Option<MinorUnit>,
+    pub expected_settlement_date: Option<time::Date>,
+    pub card_hash: Option<String>,
+    pub retry_advice: Option<common_utils::types::RetryAdvice>,
+    pub tags: Option<common_utils::types::PaymentAttemptTags>,
+    pub is_connector_override: Option<bool>,
+    pub stepped_up_from_attempt_id: Option<String>,
     pub id: String,
 }
 
@@ -336,6 +396,15 @@ pub struct PaymentAttempt {
     pub organization_id: id_type::OrganizationId,
     pub shipping_cost: Option<MinorUnit>,
     pub order_tax_amount: Option<MinorUnit>,
+    pub fraud_score: Option<i32>,
+    pub fraud_decision: Option<storage_enums::FraudDecision>,
+    pub approved_amount: Option<MinorUnit>,
+    pub expected_settlement_date: Option<time::Date>,
+    pub card_hash: Option<String>,
+    pub retry_advice: Option<common_utils::types::RetryAdvice>,
+    pub tags: Option<common_utils::types::PaymentAttemptTags>,
+    pub is_connector_override: Option<bool>,
+    pub stepped_up_from_attempt_id: Option<String>,
 }
 
 #[cfg(feature = "v2")]
@@ -363,6 +432,28 @@ impl PaymentAttempt {
     }
 }
 
+/// Constraints on the payment attempts listed by tag, in addition to the tag itself
+#[derive(Clone, Debug, Default)]
+pub struct PaymentAttemptTagFilters {
+    pub profile_id_list: Option<Vec<id_type::ProfileId>>,
+    pub status: Option<Vec<storage_enums::AttemptStatus>>,
+    pub time_range: Option<common_utils::types::TimeRange>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+/// Constraints on the payment attempts listed as pending a review of their fraud check
+#[derive(Clone, Debug, Default)]
+pub struct PaymentAttemptReviewFilters {
+    pub profile_id_list: Option<Vec<id_type::ProfileId>>,
+    pub connector: Option<Vec<String>>,
+    pub min_fraud_score: Option<i32>,
+    /// Only the attempts created before this time, which are the ones pending review for longer
+    pub created_before: Option<PrimitiveDateTime>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PaymentListFilters {
     pub connector: Vec<String>,
@@ -492,6 +583,15 @@ pub struct PaymentAttemptNew {
     pub organization_id: id_type::OrganizationId,
     pub shipping_cost: Option<MinorUnit>,
     pub order_tax_amount: Option<MinorUnit>,
+    pub fraud_score: Option<i32>,
+    pub fraud_decision: Option<storage_enums::FraudDecision>,
+    pub approved_amount: Option<MinorUnit>,
+    pub expected_settlement_date: Option<time::Date>,
+    pub card_hash: Option<String>,
+    pub retry_advice: Option<common_utils::types::RetryAdvice>,
+    pub tags: Option<common_utils::types::PaymentAttemptTags>,
+    pub is_connector_override: Option<bool>,
+    pub stepped_up_from_attempt_id: Option<String>,
 }
 
 #[cfg(feature = "v2")]
@@ -555,6 +655,7 @@ pub enum PaymentAttemptUpdate {
         tax_amount: Option<MinorUnit>,
         updated_by: String,
         merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+        is_connector_override: Option<bool>,
     },
     AuthenticationTypeUpdate {
         authentication_type: storage_enums::AuthenticationType,
@@ -593,6 +694,8 @@ pub enum PaymentAttemptUpdate {
         customer_acceptance: Option<pii::SecretSerdeValue>,
         shipping_cost: Option<MinorUnit>,
         order_tax_amount: Option<MinorUnit>,
+        card_hash: Option<String>,
+        is_connector_override: Option<bool>,
     },
     RejectUpdate {
         status: storage_enums::AttemptStatus,
@@ -636,6 +739,8 @@ pub enum PaymentAttemptUpdate {
         unified_message: Option<Option<String>>,
         payment_method_data: Option<serde_json::Value>,
         charge_id: Option<String>,
+        approved_amount: Option<MinorUnit>,
+        expected_settlement_date: Option<time::Date>,
     },
     UnresolvedResponseUpdate {
         status: storage_enums::AttemptStatus,
@@ -665,6 +770,7 @@ pub enum PaymentAttemptUpdate {
         connector_transaction_id: Option<String>,
         payment_method_data: Option<serde_json::Value>,
         authentication_type: Option<storage_enums::AuthenticationType>,
+        retry_advice: Option<common_utils::types::RetryAdvice>,
     },
     CaptureUpdate {
         amount_to_capture: Option<MinorUnit>,
@@ -714,6 +820,11 @@ pub enum PaymentAttemptUpdate {
         unified_message: Option<String>,
         connector_transaction_id: Option<String>,
     },
+    FraudCheckUpdate {
+        fraud_score: Option<i32>,
+        fraud_decision: Option<storage_enums::FraudDecision>,
+        updated_by: String,
+    },
 }
 
 // TODO: Add fields as necessary
@@ -814,6 +925,15 @@ impl behaviour::Conversion for PaymentAttempt {
             organization_id: self.organization_id,
             card_network,
             order_tax_amount: self.order_tax_amount,
+            fraud_score: self.fraud_score,
+            fraud_decision: self.fraud_decision,
+            approved_amount: self.approved_amount,
+            expected_settlement_date: self.expected_settlement_date,
+            card_hash: self.card_hash,
+            retry_advice: self.retry_advice,
+            tags: self.tags,
+            is_connector_override: self.is_connector_override,
+            stepped_up_from_attempt_id: self.stepped_up_from_attempt_id,
             shipping_cost: self.shipping_cost,
         })
     }
@@ -891,6 +1011,15 @@ impl behaviour::Conversion for PaymentAttempt {
                 profile_id: storage_model.profile_id,
                 organization_id: storage_model.organization_id,
                 order_tax_amount: storage_model.order_tax_amount,
+                fraud_score: storage_model.fraud_score,
+                fraud_decision: storage_model.fraud_decision,
+                approved_amount: storage_model.approved_amount,
+                expected_settlement_date: storage_model.expected_settlement_date,
+                card_hash: storage_model.card_hash,
+                retry_advice: storage_model.retry_advice,
+                tags: storage_model.tags,
+                is_connector_override: storage_model.is_connector_override,
+                stepped_up_from_attempt_id: storage_model.stepped_up_from_attempt_id,
                 shipping_cost: storage_model.shipping_cost,
             })
         }
@@ -972,6 +1101,15 @@ impl behaviour::Conversion for PaymentAttempt {
             organization_id: self.organization_id,
             card_network,
             order_tax_amount: self.order_tax_amount,
+            fraud_score: self.fraud_score,
+            fraud_decision: self.fraud_decision,
+            approved_amount: self.approved_amount,
+            expected_settlement_date: self.expected_settlement_date,
+            card_hash: self.card_hash,
+            retry_advice: self.retry_advice,
+            tags: self.tags,
+            is_connector_override: self.is_connector_override,
+            stepped_up_from_attempt_id: self.stepped_up_from_attempt_id,
             shipping_cost: self.shipping_cost,
         })
     }
@@ -1047,6 +1185,15 @@ impl behaviour::Conversion for PaymentAttempt {
             payment_method_id,
             shipping_cost,
             order_tax_amount,
+            fraud_score,
+            fraud_decision,
+            approved_amount,
+            expected_settlement_date,
+            card_hash,
+            retry_advice,
+            tags,
+            is_connector_override,
+            stepped_up_from_attempt_id,
             connector,
         } = self;
 
@@ -1100,6 +1247,15 @@ impl behaviour::Conversion for PaymentAttempt {
             organization_id,
             card_network,
             order_tax_amount,
+            fraud_score,
+            fraud_decision,
+            approved_amount,
+            expected_settlement_date,
+            card_hash,
+            retry_advice,
+            tags,
+            is_connector_override,
+            stepped_up_from_attempt_id,
             shipping_cost,
             routing_result,
             authentication_applied,
@@ -1168,6 +1324,15 @@ impl behaviour::Conversion for PaymentAttempt {
                 profile_id: storage_model.profile_id,
                 organization_id: storage_model.organization_id,
                 order_tax_amount: storage_model.order_tax_amount,
+                fraud_score: storage_model.fraud_score,
+                fraud_decision: storage_model.fraud_decision,
+                approved_amount: storage_model.approved_amount,
+                expected_settlement_date: storage_model.expected_settlement_date,
+                card_hash: storage_model.card_hash,
+                retry_advice: storage_model.retry_advice,
+                tags: storage_model.tags,
+                is_connector_override: storage_model.is_connector_override,
+                stepped_up_from_attempt_id: storage_model.stepped_up_from_attempt_id,
                 shipping_cost: storage_model.shipping_cost,
                 payment_method_subtype: storage_model.payment_method_subtype,
                 authentication_applied: storage_model.authentication_applied,
@@ -1238,6 +1403,15 @@ impl behaviour::Conversion for PaymentAttempt {
             organization_id: self.organization_id,
             card_network,
             order_tax_amount: self.order_tax_amount,
+            fraud_score: self.fraud_score,
+            fraud_decision: self.fraud_decision,
+            approved_amount: self.approved_amount,
+            expected_settlement_date: self.expected_settlement_date,
+            card_hash: self.card_hash,
+            retry_advice: self.retry_advice,
+            tags: self.tags,
+            is_connector_override: self.is_connector_override,
+            stepped_up_from_attempt_id: self.stepped_up_from_attempt_id,
             shipping_cost: self.shipping_cost,
             amount_to_capture: self.amount_to_capture,
         })
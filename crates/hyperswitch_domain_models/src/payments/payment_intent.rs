@@ -12,6 +12,8 @@ use common_utils::{
         MinorUnit,
     },
 };
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub use diesel_models::query::payment_intent::PaymentMetadataKey;
 use diesel_models::{
     PaymentIntent as DieselPaymentIntent, PaymentIntentNew as DieselPaymentIntentNew,
 };
@@ -78,6 +80,34 @@ pub trait PaymentIntentInterface {
         storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<Vec<PaymentIntent>, errors::StorageError>;
 
+    /// Find at most `limit` payments of the chain of related payments which starts at the root
+    /// payment, including the root payment itself, in the order they were created
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn find_payment_intents_by_root_payment_id_merchant_id(
+        &self,
+        state: &KeyManagerState,
+        root_payment_id: &id_type::PaymentId,
+        merchant_id: &id_type::MerchantId,
+        merchant_key_store: &MerchantKeyStore,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+        limit: i64,
+    ) -> error_stack::Result<Vec<PaymentIntent>, errors::StorageError>;
+
+    /// Find the payments of the merchant with the value at the key of their metadata, the most
+    /// recently created first. Only the keys with an index are efficiently queryable, see
+    /// [`PaymentMetadataKey`].
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn find_payments_by_metadata(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        key: &PaymentMetadataKey,
+        value: &str,
+        pagination: PaymentIntentPagination,
+        merchant_key_store: &MerchantKeyStore,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PaymentIntent>, errors::StorageError>;
+
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn filter_payment_intents_by_time_range_constraints(
         &self,
@@ -96,6 +126,15 @@ pub trait PaymentIntentInterface {
         constraints: &common_utils::types::TimeRange,
     ) -> error_stack::Result<Vec<(common_enums::IntentStatus, i64)>, errors::StorageError>;
 
+    /// Number and total amount of the payments of the merchant created within the time range,
+    /// grouped by status and currency
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn get_merchant_volume_metrics(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        time_range: &common_utils::types::TimeRange,
+    ) -> error_stack::Result<Vec<MerchantVolumeMetric>, errors::StorageError>;
+
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn get_filtered_payment_intents_attempt(
         &self,
@@ -115,6 +154,26 @@ pub trait PaymentIntentInterface {
     ) -> error_stack::Result<Vec<String>, errors::StorageError>;
 }
 
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[derive(Clone, Copy, Debug)]
+pub struct PaymentIntentPagination {
+    pub limit: u32,
+    pub offset: u32,
+}
+
+/// Transaction volume of a merchant for a status and currency.
+///
+/// Amounts are only ever summed within a currency, a merchant transacting in multiple currencies
+/// has a separate metric for each of them.
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MerchantVolumeMetric {
+    pub status: storage_enums::IntentStatus,
+    pub currency: Option<storage_enums::Currency>,
+    pub count: i64,
+    pub total_amount: MinorUnit,
+}
+
 #[derive(Clone, Debug, PartialEq, router_derive::DebugAsDisplay, Serialize, Deserialize)]
 pub struct CustomerData {
     pub name: Option<Secret<String>>,
@@ -260,6 +319,12 @@ pub enum PaymentIntentUpdate {
         updated_by: String,
         shipping_details: Option<Encryptable<Secret<serde_json::Value>>>,
     },
+    FulfillmentUpdate {
+        fulfillment_status: storage_enums::FulfillmentStatus,
+        shipped_at: Option<PrimitiveDateTime>,
+        delivered_at: Option<PrimitiveDateTime>,
+        updated_by: String,
+    },
 }
 
 // TODO: remove all enum variants and create new variants that should be used for v2
@@ -411,6 +476,9 @@ pub struct PaymentIntentUpdateInternal {
     pub shipping_details: Option<Encryptable<Secret<serde_json::Value>>>,
     pub is_payment_processor_token_flow: Option<bool>,
     pub tax_details: Option<diesel_models::TaxDetails>,
+    pub fulfillment_status: Option<storage_enums::FulfillmentStatus>,
+    pub shipped_at: Option<PrimitiveDateTime>,
+    pub delivered_at: Option<PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v2")]
@@ -784,6 +852,19 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details,
                 ..Default::default()
             },
+            PaymentIntentUpdate::FulfillmentUpdate {
+                fulfillment_status,
+                shipped_at,
+                delivered_at,
+                updated_by,
+            } => Self {
+                fulfillment_status: Some(fulfillment_status),
+                shipped_at,
+                delivered_at,
+                modified_at: Some(common_utils::date_time::now()),
+                updated_by,
+                ..Default::default()
+            },
         }
     }
 }
@@ -1103,6 +1184,17 @@ impl From<PaymentIntentUpdate> for DieselPaymentIntentUpdate {
                 updated_by,
                 shipping_details: shipping_details.map(Encryption::from),
             },
+            PaymentIntentUpdate::FulfillmentUpdate {
+                fulfillment_status,
+                shipped_at,
+                delivered_at,
+                updated_by,
+            } => Self::FulfillmentUpdate {
+                fulfillment_status,
+                shipped_at,
+                delivered_at,
+                updated_by,
+            },
         }
     }
 }
@@ -1217,6 +1309,9 @@ impl From<PaymentIntentUpdateInternal> for diesel_models::PaymentIntentUpdateInt
             shipping_details,
             is_payment_processor_token_flow,
             tax_details,
+            fulfillment_status,
+            shipped_at,
+            delivered_at,
         } = value;
         Self {
             amount,
@@ -1255,6 +1350,9 @@ impl From<PaymentIntentUpdateInternal> for diesel_models::PaymentIntentUpdateInt
             shipping_details: shipping_details.map(Encryption::from),
             is_payment_processor_token_flow,
             tax_details,
+            fulfillment_status,
+            shipped_at,
+            delivered_at,
         }
     }
 }
@@ -1783,6 +1881,12 @@ impl behaviour::Conversion for PaymentIntent {
             shipping_cost: self.shipping_cost,
             tax_details: self.tax_details,
             skip_external_tax_calculation: self.skip_external_tax_calculation,
+            amount_breakdown: self.amount_breakdown,
+            parent_payment_id: self.parent_payment_id,
+            root_payment_id: self.root_payment_id,
+            fulfillment_status: self.fulfillment_status,
+            shipped_at: self.shipped_at,
+            delivered_at: self.delivered_at,
         })
     }
 
@@ -1870,6 +1974,12 @@ impl behaviour::Conversion for PaymentIntent {
                 is_payment_processor_token_flow: storage_model.is_payment_processor_token_flow,
                 organization_id: storage_model.organization_id,
                 skip_external_tax_calculation: storage_model.skip_external_tax_calculation,
+                amount_breakdown: storage_model.amount_breakdown,
+                parent_payment_id: storage_model.parent_payment_id,
+                root_payment_id: storage_model.root_payment_id,
+                fulfillment_status: storage_model.fulfillment_status,
+                shipped_at: storage_model.shipped_at,
+                delivered_at: storage_model.delivered_at,
             })
         }
         .await
@@ -1932,6 +2042,12 @@ impl behaviour::Conversion for PaymentIntent {
             shipping_cost: self.shipping_cost,
             tax_details: self.tax_details,
             skip_external_tax_calculation: self.skip_external_tax_calculation,
+            amount_breakdown: self.amount_breakdown,
+            parent_payment_id: self.parent_payment_id,
+            root_payment_id: self.root_payment_id,
+            fulfillment_status: self.fulfillment_status,
+            shipped_at: self.shipped_at,
+            delivered_at: self.delivered_at,
         })
     }
 }
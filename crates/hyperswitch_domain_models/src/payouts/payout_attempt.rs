@@ -48,6 +48,55 @@ pub trait PayoutAttemptInterface {
         _merchant_id: &id_type::MerchantId,
         _storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<PayoutListFilters, errors::StorageError>;
+
+    /// Apply the returns of payouts reported by the bank, such as the entries of a return file.
+    /// The payouts are resolved by their bank reference, which is the reference of the payout at
+    /// the connector.
+    async fn apply_payout_returns(
+        &self,
+        _merchant_id: &id_type::MerchantId,
+        _payout_returns: Vec<PayoutReturn>,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PayoutReturnResult>, errors::StorageError>;
+}
+
+/// The return of a previously succeeded payout, reported by the bank
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutReturn {
+    /// The connector through which the payout was made, the bank reference is only unique for
+    /// the payouts of a connector
+    pub connector: String,
+    pub bank_reference: String,
+    pub return_reason_code: Option<String>,
+    pub return_reason_message: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutReturnResult {
+    pub bank_reference: String,
+    pub outcome: PayoutReturnOutcome,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PayoutReturnOutcome {
+    /// The payout was transitioned to returned
+    Returned(Box<ReturnedPayout>),
+    /// The return was already applied to the payout
+    AlreadyReturned(Box<PayoutAttempt>),
+    /// The payout did not succeed, and hence cannot be returned
+    NotReturnable(Box<PayoutAttempt>),
+    /// No payout of the connector was found for the bank reference
+    NotFound,
+    /// The return could not be applied, it can be applied again
+    Failed(String),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReturnedPayout {
+    pub payouts: Payouts,
+    pub payout_attempt: PayoutAttempt,
+    pub previous_payouts: Payouts,
+    pub previous_payout_attempt: PayoutAttempt,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -31,6 +31,8 @@ pub struct RouterData<Flow, Request, Response> {
     pub auth_type: common_enums::enums::AuthenticationType,
     pub connector_meta_data: Option<common_utils::pii::SecretSerdeValue>,
     pub connector_wallets_details: Option<common_utils::pii::SecretSerdeValue>,
+    /// Connector specific override of the default request timeout (in seconds)
+    pub connector_request_timeout_secs: Option<u64>,
     pub amount_captured: Option<i64>,
     pub access_token: Option<AccessToken>,
     pub session_token: Option<String>,
@@ -64,6 +66,8 @@ pub struct RouterData<Flow, Request, Response> {
     pub test_mode: Option<bool>,
     pub connector_http_status_code: Option<u16>,
     pub external_latency: Option<u128>,
+    /// The number of times the connector request was retried after a network error
+    pub connector_network_retry_count: Option<u8>,
     /// Contains apple pay flow type simplified or manual
     pub apple_pay_flow: Option<payment_method_data::ApplePayFlow>,
 
@@ -258,6 +262,11 @@ pub struct PaymentMethodBalance {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ConnectorResponseData {
     pub additional_payment_method_data: Option<AdditionalPaymentMethodConnectorResponse>,
+    /// The amount approved by the connector, if the connector approved less than the requested
+    /// amount, as in the case of partial approvals of prepaid cards
+    pub approved_amount: Option<MinorUnit>,
+    /// The date on which the connector expects to settle the payment, if reported by the connector
+    pub expected_settlement_date: Option<time::Date>,
 }
 
 impl ConnectorResponseData {
@@ -266,8 +275,39 @@ impl ConnectorResponseData {
     ) -> Self {
         Self {
             additional_payment_method_data: Some(additional_payment_method_data),
+            approved_amount: None,
+            expected_settlement_date: None,
         }
     }
+
+    pub fn with_approved_amount(approved_amount: MinorUnit) -> Self {
+        Self {
+            additional_payment_method_data: None,
+            approved_amount: Some(approved_amount),
+            expected_settlement_date: None,
+        }
+    }
+
+    pub fn set_approved_amount(mut self, approved_amount: Option<MinorUnit>) -> Self {
+        self.approved_amount = approved_amount;
+        self
+    }
+
+    pub fn with_expected_settlement_date(expected_settlement_date: time::Date) -> Self {
+        Self {
+            additional_payment_method_data: None,
+            approved_amount: None,
+            expected_settlement_date: Some(expected_settlement_date),
+        }
+    }
+
+    pub fn set_expected_settlement_date(
+        mut self,
+        expected_settlement_date: Option<time::Date>,
+    ) -> Self {
+        self.expected_settlement_date = expected_settlement_date;
+        self
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -296,6 +336,9 @@ pub struct ErrorResponse {
     pub status_code: u16,
     pub attempt_status: Option<common_enums::enums::AttemptStatus>,
     pub connector_transaction_id: Option<String>,
+    /// The advice code of the card network on whether the payment can be retried, if the
+    /// connector returned one along with the decline
+    pub network_advice_code: Option<String>,
 }
 
 impl Default for ErrorResponse {
@@ -307,6 +350,7 @@ impl Default for ErrorResponse {
             status_code: http::StatusCode::INTERNAL_SERVER_ERROR.as_u16(),
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         }
     }
 }
@@ -320,6 +364,7 @@ impl ErrorResponse {
             status_code: http::StatusCode::INTERNAL_SERVER_ERROR.as_u16(),
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         }
     }
 }
@@ -38,6 +38,9 @@ pub struct PaymentMethodToken;
 #[derive(Debug, Clone)]
 pub struct CreateConnectorCustomer;
 
+#[derive(Debug, Clone)]
+pub struct UpdateConnectorCustomer;
+
 #[derive(Debug, Clone)]
 pub struct SetupMandate;
 
@@ -70,6 +70,10 @@ pub struct PaymentsAuthorizeData {
     /// if the connector provides support to accept multiple reference ids.
     /// In case the connector supports only one reference id, Hyperswitch's Payment ID will be sent as reference.
     pub merchant_order_reference_id: Option<String>,
+    /// Breakdown of the original amount into subtotal, tax, shipping and discount
+    pub amount_breakdown: Option<diesel_models::payment_intent::AmountBreakdown>,
+    /// Tax identifier of the customer for the country of the payment
+    pub customer_tax_id: Option<common_utils::types::CustomerTaxId>,
     pub integrity_object: Option<AuthoriseIntegrityObject>,
 }
 
@@ -153,6 +157,8 @@ pub struct ConnectorCustomerData {
     pub name: Option<Secret<String>>,
     pub preprocessing_id: Option<String>,
     pub payment_method_data: PaymentMethodData,
+    /// Tax identifier of the customer for the country of the payment
+    pub tax_id: Option<common_utils::types::CustomerTaxId>,
 }
 
 impl TryFrom<SetupMandateRequestData> for ConnectorCustomerData {
@@ -165,6 +171,7 @@ impl TryFrom<SetupMandateRequestData> for ConnectorCustomerData {
             phone: None,
             name: None,
             preprocessing_id: None,
+            tax_id: None,
         })
     }
 }
@@ -189,10 +196,22 @@ impl
             phone: None,
             name: data.request.customer_name.clone(),
             preprocessing_id: data.preprocessing_id.clone(),
+            tax_id: data.request.customer_tax_id.clone(),
         })
     }
 }
 
+/// The customer details to be propagated to a customer which already exists at the connector
+#[derive(Debug, Clone)]
+pub struct ConnectorCustomerUpdateData {
+    pub connector_customer_id: String,
+    pub description: Option<common_utils::types::Description>,
+    pub email: Option<pii::Email>,
+    pub phone: Option<Secret<String>>,
+    pub name: Option<Secret<String>>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
 #[derive(Debug, Clone)]
 pub struct PaymentMethodTokenizationData {
     pub payment_method_data: PaymentMethodData,
@@ -5,15 +5,16 @@ use hyperswitch_domain_models::{
     router_flow_types::payments::{
         Approve, Authorize, AuthorizeSessionToken, CalculateTax, Capture, CompleteAuthorize,
         CreateConnectorCustomer, IncrementalAuthorization, PSync, PaymentMethodToken,
-        PostProcessing, PreProcessing, Reject, SdkSessionUpdate, Session, SetupMandate, Void,
+        PostProcessing, PreProcessing, Reject, SdkSessionUpdate, Session, SetupMandate,
+        UpdateConnectorCustomer, Void,
     },
     router_request_types::{
         AuthorizeSessionTokenData, CompleteAuthorizeData, ConnectorCustomerData,
-        PaymentMethodTokenizationData, PaymentsApproveData, PaymentsAuthorizeData,
-        PaymentsCancelData, PaymentsCaptureData, PaymentsIncrementalAuthorizationData,
-        PaymentsPostProcessingData, PaymentsPreProcessingData, PaymentsRejectData,
-        PaymentsSessionData, PaymentsSyncData, PaymentsTaxCalculationData,
-        SdkPaymentsSessionUpdateData, SetupMandateRequestData,
+        ConnectorCustomerUpdateData, PaymentMethodTokenizationData, PaymentsApproveData,
+        PaymentsAuthorizeData, PaymentsCancelData, PaymentsCaptureData,
+        PaymentsIncrementalAuthorizationData, PaymentsPostProcessingData,
+        PaymentsPreProcessingData, PaymentsRejectData, PaymentsSessionData, PaymentsSyncData,
+        PaymentsTaxCalculationData, SdkPaymentsSessionUpdateData, SetupMandateRequestData,
     },
     router_response_types::{PaymentsResponseData, TaxCalculationResponseData},
 };
@@ -137,11 +138,16 @@ pub trait PaymentTokenV2:
 /// trait ConnectorCustomerV2
 pub trait ConnectorCustomerV2:
     ConnectorIntegrationV2<
-    CreateConnectorCustomer,
-    PaymentFlowData,
-    ConnectorCustomerData,
-    PaymentsResponseData,
->
+        CreateConnectorCustomer,
+        PaymentFlowData,
+        ConnectorCustomerData,
+        PaymentsResponseData,
+    > + ConnectorIntegrationV2<
+        UpdateConnectorCustomer,
+        PaymentFlowData,
+        ConnectorCustomerUpdateData,
+        PaymentsResponseData,
+    >
 {
 }
 
@@ -110,6 +110,21 @@ pub enum ConnectorError {
     InSufficientBalanceInPaymentMethod,
     #[error("Server responded with Request Timeout")]
     RequestTimeoutReceived,
+    #[error("Request to {connector} timed out after {elapsed_time_ms} ms")]
+    ConnectorTimeout {
+        connector: String,
+        elapsed_time_ms: u128,
+    },
+    #[error("All the {connector} permits for concurrent requests are in use")]
+    ConcurrencyLimitReached {
+        connector: String,
+        retry_after_in_secs: u32,
+    },
+    #[error("{connector} is rate limiting requests and is backed off")]
+    RateLimited {
+        connector: String,
+        retry_after_in_secs: u32,
+    },
     #[error("The given currency method is not configured with the given connector")]
     CurrencyNotSupported {
         message: String,
@@ -129,7 +144,10 @@ pub enum ConnectorError {
 impl ConnectorError {
     /// fn is_connector_timeout
     pub fn is_connector_timeout(&self) -> bool {
-        self == &Self::RequestTimeoutReceived
+        matches!(
+            self,
+            Self::RequestTimeoutReceived | Self::ConnectorTimeout { .. }
+        )
     }
 }
 
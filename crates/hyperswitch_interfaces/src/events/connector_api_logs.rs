@@ -25,6 +25,9 @@ pub struct ConnectorEvent {
     refund_id: Option<String>,
     dispute_id: Option<String>,
     status_code: u16,
+    network_retry_count: Option<u8>,
+    rate_limit_retry_count: Option<u8>,
+    rate_limit_deferred_ms: Option<u128>,
 }
 
 impl ConnectorEvent {
@@ -66,9 +69,24 @@ impl ConnectorEvent {
             refund_id,
             dispute_id,
             status_code,
+            network_retry_count: None,
+            rate_limit_retry_count: None,
+            rate_limit_deferred_ms: None,
         }
     }
 
+    /// Set the number of times the request was retried after a network error
+    pub fn set_network_retry_count(&mut self, network_retry_count: u8) {
+        self.network_retry_count = Some(network_retry_count);
+    }
+
+    /// Set the number of times the request was retried after being rate limited by the connector,
+    /// and the time for which it was deferred while the connector was backed off
+    pub fn set_rate_limit_deferral(&mut self, retry_count: u8, deferred_ms: u128) {
+        self.rate_limit_retry_count = Some(retry_count);
+        self.rate_limit_deferred_ms = Some(deferred_ms);
+    }
+
     /// fn set_response_body
     pub fn set_response_body<T: Serialize>(&mut self, response: &T) {
         match masking::masked_serialize(response) {
@@ -93,4 +111,22 @@ impl ConnectorEvent {
     pub fn set_error(&mut self, error: serde_json::Value) {
         self.error = Some(error.to_string());
     }
+
+    /// Apply the redaction to the request, the response and the error of the event, which are
+    /// left unchanged if they are not valid JSON
+    pub fn redact(&mut self, redact: impl Fn(&mut serde_json::Value)) {
+        let redact_body = |body: &mut String| {
+            if let Ok(mut value) = serde_json::from_str::<serde_json::Value>(body) {
+                redact(&mut value);
+                *body = value.to_string();
+            }
+        };
+        redact_body(&mut self.request);
+        if let Some(masked_response) = self.masked_response.as_mut() {
+            redact_body(masked_response);
+        }
+        if let Some(error) = self.error.as_mut() {
+            redact_body(error);
+        }
+    }
 }
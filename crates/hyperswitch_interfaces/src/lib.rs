@@ -14,6 +14,8 @@ pub mod events;
 /// connector integrity check interface
 pub mod integrity;
 pub mod metrics;
+/// signing of the requests made to the connectors
+pub mod request_signing;
 pub mod secrets_interface;
 pub mod types;
 pub mod webhooks;
@@ -9,20 +9,21 @@ use hyperswitch_domain_models::{
         payments::{
             Authorize, AuthorizeSessionToken, Balance, CalculateTax, Capture, CompleteAuthorize,
             CreateConnectorCustomer, IncrementalAuthorization, InitPayment, PSync,
-            PaymentMethodToken, PostProcessing, PreProcessing, Session, SetupMandate, Void,
+            PaymentMethodToken, PostProcessing, PreProcessing, Session, SetupMandate,
+            UpdateConnectorCustomer, Void,
         },
         refunds::{Execute, RSync},
         webhooks::VerifyWebhookSource,
     },
     router_request_types::{
         AcceptDisputeRequestData, AccessTokenRequestData, AuthorizeSessionTokenData,
-        CompleteAuthorizeData, ConnectorCustomerData, DefendDisputeRequestData,
-        MandateRevokeRequestData, PaymentMethodTokenizationData, PaymentsAuthorizeData,
-        PaymentsCancelData, PaymentsCaptureData, PaymentsIncrementalAuthorizationData,
-        PaymentsPostProcessingData, PaymentsPreProcessingData, PaymentsSessionData,
-        PaymentsSyncData, PaymentsTaxCalculationData, RefundsData, RetrieveFileRequestData,
-        SetupMandateRequestData, SubmitEvidenceRequestData, UploadFileRequestData,
-        VerifyWebhookSourceRequestData,
+        CompleteAuthorizeData, ConnectorCustomerData, ConnectorCustomerUpdateData,
+        DefendDisputeRequestData, MandateRevokeRequestData, PaymentMethodTokenizationData,
+        PaymentsAuthorizeData, PaymentsCancelData, PaymentsCaptureData,
+        PaymentsIncrementalAuthorizationData, PaymentsPostProcessingData,
+        PaymentsPreProcessingData, PaymentsSessionData, PaymentsSyncData,
+        PaymentsTaxCalculationData, RefundsData, RetrieveFileRequestData, SetupMandateRequestData,
+        SubmitEvidenceRequestData, UploadFileRequestData, VerifyWebhookSourceRequestData,
     },
     router_response_types::{
         AcceptDisputeResponse, DefendDisputeResponse, MandateRevokeResponseData,
@@ -114,6 +115,13 @@ pub type IncrementalAuthorizationType = dyn ConnectorIntegration<
 pub type ConnectorCustomerType =
     dyn ConnectorIntegration<CreateConnectorCustomer, ConnectorCustomerData, PaymentsResponseData>;
 
+/// Type alias for `ConnectorIntegration<UpdateConnectorCustomer, ConnectorCustomerUpdateData, PaymentsResponseData>`
+pub type ConnectorCustomerUpdateType = dyn ConnectorIntegration<
+    UpdateConnectorCustomer,
+    ConnectorCustomerUpdateData,
+    PaymentsResponseData,
+>;
+
 /// Type alias for `ConnectorIntegration<Execute, RefundsData, RefundsResponseData>`
 pub type RefundExecuteType = dyn ConnectorIntegration<Execute, RefundsData, RefundsResponseData>;
 /// Type alias for `ConnectorIntegration<RSync, RefundsData, RefundsResponseData>`
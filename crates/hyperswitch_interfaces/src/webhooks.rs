@@ -1,11 +1,11 @@
 //! Webhooks interface
 
-use common_utils::{crypto, errors::CustomResult, ext_traits::ValueExt};
+use common_utils::{crypto, errors::CustomResult, ext_traits::ValueExt, request::Request};
 use error_stack::ResultExt;
-use hyperswitch_domain_models::api::ApplicationResponse;
+use hyperswitch_domain_models::{api::ApplicationResponse, router_data::ConnectorAuthType};
 use masking::{ExposeInterface, Secret};
 
-use crate::{api::ConnectorCommon, errors};
+use crate::{api::ConnectorCommon, configs::Connectors, errors};
 
 /// struct IncomingWebhookRequestDetails
 #[derive(Debug)]
@@ -23,6 +23,15 @@ pub struct IncomingWebhookRequestDetails<'a> {
 }
 
 /// Trait defining incoming webhook
+/// The reason for which a payout was returned by the bank, reported in an incoming webhook
+#[derive(Clone, Debug, Default)]
+pub struct PayoutReturnDetails {
+    /// return_reason_code
+    pub return_reason_code: Option<String>,
+    /// return_reason_message
+    pub return_reason_message: Option<String>,
+}
+
 #[async_trait::async_trait]
 pub trait IncomingWebhook: ConnectorCommon + Sync {
     /// fn get_webhook_body_decoding_algorithm
@@ -142,6 +151,17 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
         Ok(Vec::new())
     }
 
+    /// fn get_webhook_signed_timestamp
+    ///
+    /// The timestamp included by the connector in the signed webhook, in the unit in which the
+    /// connector sends it. Used to reject replayed webhooks.
+    fn get_webhook_signed_timestamp(
+        &self,
+        _request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<i64>, errors::ConnectorError> {
+        Ok(None)
+    }
+
     /// fn verify_webhook_source
     async fn verify_webhook_source(
         &self,
@@ -199,6 +219,22 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
         _request: &IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<Box<dyn masking::ErasedMaskSerialize>, errors::ConnectorError>;
 
+    /// fn build_webhook_resource_fetch_request
+    ///
+    /// The request to fetch the resource of the webhook from the connector. Used to enrich the
+    /// resource object of webhooks which do not carry all the fields of the resource.
+    fn build_webhook_resource_fetch_request(
+        &self,
+        _request: &IncomingWebhookRequestDetails<'_>,
+        _auth_type: &ConnectorAuthType,
+        _connectors: &Connectors,
+    ) -> CustomResult<Request, errors::ConnectorError> {
+        Err(errors::ConnectorError::NotImplemented(
+            "build_webhook_resource_fetch_request method".to_string(),
+        )
+        .into())
+    }
+
     /// fn get_webhook_api_response
     fn get_webhook_api_response(
         &self,
@@ -215,6 +251,14 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
         Err(errors::ConnectorError::NotImplemented("get_dispute_details method".to_string()).into())
     }
 
+    /// fn get_payout_return_details
+    fn get_payout_return_details(
+        &self,
+        _request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<PayoutReturnDetails, errors::ConnectorError> {
+        Ok(PayoutReturnDetails::default())
+    }
+
     /// fn get_external_authentication_details
     fn get_external_authentication_details(
         &self,
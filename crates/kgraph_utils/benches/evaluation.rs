@@ -72,6 +72,10 @@ fn build_test_data(
         status: api_enums::ConnectorStatus::Inactive,
         additional_merchant_data: None,
         connector_wallets_details: None,
+        request_timeout_secs: None,
+        environment: None,
+        maintenance_windows: None,
+        credential_rotation_status: None,
     };
 
     #[cfg(feature = "v1")]
@@ -97,6 +101,11 @@ fn build_test_data(
         status: api_enums::ConnectorStatus::Inactive,
         additional_merchant_data: None,
         connector_wallets_details: None,
+        request_timeout_secs: None,
+        environment: None,
+        maintenance_windows: None,
+        webhook_identifier: None,
+        credential_rotation_status: None,
     };
     let config = CountryCurrencyFilter {
         connector_configs: HashMap::new(),
@@ -760,6 +760,10 @@ mod tests {
             status: api_enums::ConnectorStatus::Inactive,
             additional_merchant_data: None,
             connector_wallets_details: None,
+            request_timeout_secs: None,
+            environment: None,
+            maintenance_windows: None,
+            credential_rotation_status: None,
         };
         #[cfg(feature = "v1")]
         let stripe_account = MerchantConnectorResponse {
@@ -820,6 +824,11 @@ mod tests {
             status: api_enums::ConnectorStatus::Inactive,
             additional_merchant_data: None,
             connector_wallets_details: None,
+            request_timeout_secs: None,
+            environment: None,
+            maintenance_windows: None,
+            webhook_identifier: None,
+            credential_rotation_status: None,
         };
 
         let config_map = kgraph_types::CountryCurrencyFilter {
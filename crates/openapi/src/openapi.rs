@@ -77,6 +77,15 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payments::payments_capture,
         routes::payments::payments_connector_session,
         routes::payments::payments_cancel,
+        routes::payments::payments_void_capture,
+        routes::payments::payments_fulfillment_update,
+        routes::payments::payments_resume,
+        routes::payments::payments_receipt_retrieve,
+        routes::payments::payments_receipt_retrieve_by_token,
+        routes::payments::payments_void_eligibility_check,
+        routes::payments::payments_timeline_retrieve,
+        routes::payments::payments_simulate_webhook,
+        routes::payments::payments_list_payment_methods_for_session,
         routes::payments::payments_list,
         routes::payments::payments_incremental_authorization,
         routes::payment_link::payment_link_retrieve,
@@ -100,6 +109,14 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::merchant_account::update_merchant_account,
         routes::merchant_account::delete_merchant_account,
         routes::merchant_account::merchant_account_kv_status,
+        routes::merchant_account::merchant_account_onboarding_status,
+        routes::merchant_account::merchant_account_update_onboarding_status,
+        routes::merchant_account::merchant_account_audit_logs,
+        routes::merchant_account::merchant_account_connector_failover_chain,
+        routes::merchant_account::merchant_account_legal_hold_create,
+        routes::merchant_account::merchant_account_legal_holds_list,
+        routes::merchant_account::merchant_account_legal_hold_delete,
+        routes::merchant_account::merchant_account_payment_methods_retokenize,
 
         // Routes for merchant connector account
         routes::merchant_connector_account::connector_create,
@@ -107,6 +124,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::merchant_connector_account::connector_list,
         routes::merchant_connector_account::connector_update,
         routes::merchant_connector_account::connector_delete,
+        routes::merchant_connector_account::connector_webhook_identifier_rotate,
 
         //Routes for gsm
         routes::gsm::create_gsm_rule,
@@ -151,6 +169,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::routing::routing_create_config,
         routes::routing::routing_link_config,
         routes::routing::routing_retrieve_config,
+        routes::routing::routing_retrieve_effective_weights,
         routes::routing::list_routing_configs,
         routes::routing::routing_unlink_config,
         routes::routing::routing_update_default_config,
@@ -189,13 +208,16 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::webhook_events::list_initial_webhook_delivery_attempts,
         routes::webhook_events::list_webhook_delivery_attempts,
         routes::webhook_events::retry_webhook_delivery_attempt,
+        routes::webhook_events::retrieve_webhook_delivery_queue,
 
         // Routes for poll apis
         routes::poll::retrieve_poll_status,
     ),
     components(schemas(
         common_utils::types::MinorUnit,
+        common_utils::types::RetryAdvice,
         common_utils::types::TimeRange,
+        common_utils::types::CustomerTaxId,
         common_utils::link_utils::GenericLinkUiConfig,
         common_utils::link_utils::EnabledPaymentMethod,
         common_utils::payout_method_utils::AdditionalPayoutMethodData,
@@ -234,8 +256,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::PaymentMethodList,
         api_models::payment_methods::CustomerPaymentMethod,
         api_models::payment_methods::PaymentMethodListResponse,
+        api_models::payment_methods::ExcludedPaymentMethod,
+        api_models::payment_methods::PaymentMethodExclusionReason,
         api_models::payment_methods::CustomerPaymentMethodsListResponse,
         api_models::payment_methods::PaymentMethodDeleteResponse,
+        api_models::payment_methods::ConnectorTokenRevocationResponse,
         api_models::payment_methods::PaymentMethodUpdate,
         api_models::payment_methods::CustomerDefaultPaymentMethodResponse,
         api_models::payment_methods::CardDetailFromLocker,
@@ -252,10 +277,15 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::PaymentMethod,
         api_models::enums::PaymentMethodType,
         api_models::enums::ConnectorType,
+        api_models::enums::ConnectorTokenRevocationStatus,
+        api_models::enums::ConnectorEnvironment,
+        api_models::enums::ConnectorCredentialRotationStatus,
+        api_models::enums::MaintenanceWindowRecurrence,
         api_models::enums::PayoutConnectors,
         api_models::enums::AuthenticationConnectors,
         api_models::enums::Currency,
         api_models::enums::IntentStatus,
+        api_models::enums::FulfillmentStatus,
         api_models::enums::CaptureMethod,
         api_models::enums::FutureUsage,
         api_models::enums::AuthenticationType,
@@ -263,6 +293,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::PaymentMethod,
         api_models::enums::PaymentMethodIssuerCode,
         api_models::enums::MandateStatus,
+        api_models::enums::MandateFrequency,
         api_models::enums::PaymentExperience,
         api_models::enums::BankNames,
         api_models::enums::BankType,
@@ -273,11 +304,19 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::CountryAlpha2,
         api_models::enums::FieldType,
         api_models::enums::FrmAction,
+        api_models::enums::FraudDecision,
+        api_models::enums::TaxIdType,
         api_models::enums::FrmPreferredFlowTypes,
         api_models::enums::RetryAction,
         api_models::enums::AttemptStatus,
         api_models::enums::CaptureStatus,
         api_models::enums::ReconStatus,
+        api_models::enums::OnboardingStatus,
+        api_models::enums::StatementDescriptorLengthPolicy,
+        api_models::enums::OnboardingRequirement,
+        api_models::enums::AuditLogResourceType,
+        api_models::enums::AuditLogAction,
+        api_models::enums::RetentionEntity,
         api_models::enums::ConnectorStatus,
         api_models::enums::AuthorizationStatus,
         api_models::enums::PaymentMethodStatus,
@@ -295,6 +334,14 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::PaymentMethodsEnabled,
         api_models::admin::MerchantConnectorDetailsWrap,
         api_models::admin::MerchantConnectorDetails,
+        api_models::admin::ConnectorMaintenanceWindow,
+        api_models::admin::ConnectorFailoverRule,
+        api_models::admin::ConnectorFailoverChainResponse,
+        api_models::admin::LeastCostRoutingConfig,
+        api_models::admin::ConnectorCostModel,
+        api_models::admin::ConnectorFeeRule,
+        api_models::admin::ConnectorFee,
+        api_models::admin::TransactionAmountLimit,
         api_models::admin::MerchantConnectorWebhookDetails,
         api_models::admin::ProfileCreate,
         api_models::admin::ProfileResponse,
@@ -426,6 +473,28 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::SamsungPayTokenData,
         api_models::payments::ApplepayPaymentMethod,
         api_models::payments::PaymentsCancelRequest,
+        api_models::payments::PaymentsFulfillmentRequest,
+        api_models::payments::PaymentsFulfillmentResponse,
+        api_models::payments::PaymentsResumeRequest,
+        api_models::payments::PaymentReceiptResponse,
+        api_models::payments::ReceiptMerchantDetails,
+        api_models::payments::ReceiptCapture,
+        api_models::payments::ReceiptRefund,
+        api_models::payments::PaymentVoidEligibilityResponse,
+        api_models::payments::VoidIneligibilityReason,
+        api_models::payments::PaymentTimelineResponse,
+        api_models::payments::PaymentTimelineEvent,
+        api_models::payments::PaymentTimelineEventType,
+        api_models::payments::PaymentTimelineEventSource,
+        api_models::payments::SimulateWebhookRequest,
+        api_models::payments::SimulateWebhookResponse,
+        api_models::payment_methods::PaymentMethodsListForSessionRequest,
+        api_models::payment_methods::PaymentMethodsListForSessionResponse,
+        api_models::payment_methods::SessionPaymentMethod,
+        api_models::payment_methods::SessionPaymentMethodConnector,
+        api_models::payment_methods::SessionPaymentMethodClientData,
+        api_models::payment_methods::PaymentMethodsUnavailableReason,
+        api_models::payment_methods::PaymentMethodRecommendationReason,
         api_models::payments::PaymentListConstraints,
         api_models::payments::PaymentListResponse,
         api_models::payments::CashappQr,
@@ -457,6 +526,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::BacsBankTransferInstructions,
         api_models::payments::RedirectResponse,
         api_models::payments::RequestSurchargeDetails,
+        api_models::payments::AmountBreakdown,
+        api_models::payments::CvvRecollectionPolicy,
         api_models::payments::PaymentAttemptResponse,
         api_models::payments::CaptureResponse,
         api_models::payments::PaymentsIncrementalAuthorizationRequest,
@@ -490,6 +561,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::AmountFilter,
         api_models::mandates::MandateRevokedResponse,
         api_models::mandates::MandateResponse,
+        api_models::mandates::MandateConstraints,
         api_models::mandates::MandateCardDetails,
         api_models::mandates::RecurringDetails,
         api_models::mandates::ProcessorPaymentToken,
@@ -541,6 +613,16 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantDetails,
         api_models::admin::ToggleKVRequest,
         api_models::admin::ToggleKVResponse,
+        api_models::admin::MerchantOnboardingStatusUpdateRequest,
+        api_models::admin::MerchantOnboardingStatusResponse,
+        api_models::admin::MerchantOnboardingTransition,
+        api_models::admin::AuditLogResponse,
+        api_models::admin::AuditLogEntry,
+        api_models::admin::AuditLogFieldChange,
+        api_models::admin::LegalHoldCreateRequest,
+        api_models::admin::LegalHoldResponse,
+        api_models::admin::PaymentMethodRetokenizationRequest,
+        api_models::admin::PaymentMethodRetokenizationResponse,
         api_models::admin::WebhookDetails,
         api_models::api_keys::ApiKeyExpiration,
         api_models::api_keys::CreateApiKeyRequest,
@@ -568,6 +650,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::routing::RoutingAlgorithm,
         api_models::routing::StraightThroughAlgorithm,
         api_models::routing::ConnectorVolumeSplit,
+        api_models::routing::ConnectorEffectiveWeight,
+        api_models::routing::RoutingEffectiveWeightsResponse,
         api_models::routing::ConnectorSelection,
         api_models::routing::ToggleSuccessBasedRoutingQuery,
         api_models::routing::SuccessBasedRoutingConfig,
@@ -597,6 +681,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::webhook_events::EventRetrieveResponse,
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
+        api_models::webhook_events::WebhookDeliveryQueueResponse,
         api_models::enums::WebhookDeliveryAttempt,
         api_models::enums::PaymentChargeType,
         api_models::enums::StripeChargeType,
@@ -123,6 +123,7 @@ Never share your secret api keys. Keep them guarded and secure.
     ),
     components(schemas(
         common_utils::types::MinorUnit,
+        common_utils::types::RetryAdvice,
         common_utils::types::TimeRange,
         common_utils::link_utils::GenericLinkUiConfig,
         common_utils::link_utils::EnabledPaymentMethod,
@@ -162,6 +163,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::PaymentMethodList,
         api_models::payment_methods::CustomerPaymentMethod,
         api_models::payment_methods::PaymentMethodListResponse,
+        api_models::payment_methods::ExcludedPaymentMethod,
+        api_models::payment_methods::PaymentMethodExclusionReason,
         api_models::payment_methods::CustomerPaymentMethodsListResponse,
         api_models::payment_methods::PaymentMethodDeleteResponse,
         api_models::payment_methods::PaymentMethodUpdate,
@@ -180,6 +183,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::PaymentMethod,
         api_models::enums::PaymentMethodType,
         api_models::enums::ConnectorType,
+        api_models::enums::ConnectorEnvironment,
+        api_models::enums::ConnectorCredentialRotationStatus,
+        api_models::enums::MaintenanceWindowRecurrence,
         api_models::enums::PayoutConnectors,
         api_models::enums::AuthenticationConnectors,
         api_models::enums::Currency,
@@ -191,6 +197,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::PaymentMethod,
         api_models::enums::PaymentMethodIssuerCode,
         api_models::enums::MandateStatus,
+        api_models::enums::MandateFrequency,
         api_models::enums::PaymentExperience,
         api_models::enums::BankNames,
         api_models::enums::BankType,
@@ -201,11 +208,17 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::CountryAlpha2,
         api_models::enums::FieldType,
         api_models::enums::FrmAction,
+        api_models::enums::FraudDecision,
         api_models::enums::FrmPreferredFlowTypes,
         api_models::enums::RetryAction,
         api_models::enums::AttemptStatus,
         api_models::enums::CaptureStatus,
         api_models::enums::ReconStatus,
+        api_models::enums::OnboardingStatus,
+        api_models::enums::StatementDescriptorLengthPolicy,
+        api_models::enums::OnboardingRequirement,
+        api_models::enums::AuditLogResourceType,
+        api_models::enums::AuditLogAction,
         api_models::enums::ConnectorStatus,
         api_models::enums::AuthorizationStatus,
         api_models::enums::PaymentMethodStatus,
@@ -224,6 +237,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::PaymentMethodsEnabled,
         api_models::admin::MerchantConnectorDetailsWrap,
         api_models::admin::MerchantConnectorDetails,
+        api_models::admin::ConnectorMaintenanceWindow,
         api_models::admin::MerchantConnectorWebhookDetails,
         api_models::admin::ProfileCreate,
         api_models::admin::ProfileResponse,
@@ -389,6 +403,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::BacsBankTransferInstructions,
         api_models::payments::RedirectResponse,
         api_models::payments::RequestSurchargeDetails,
+        api_models::payments::AmountBreakdown,
+        api_models::payments::CvvRecollectionPolicy,
         api_models::payments::PaymentAttemptResponse,
         api_models::payments::CaptureResponse,
         api_models::payments::PaymentsIncrementalAuthorizationRequest,
@@ -422,6 +438,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::AmountFilter,
         api_models::mandates::MandateRevokedResponse,
         api_models::mandates::MandateResponse,
+        api_models::mandates::MandateConstraints,
         api_models::mandates::MandateCardDetails,
         api_models::mandates::RecurringDetails,
         api_models::mandates::ProcessorPaymentToken,
@@ -477,6 +494,12 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantDetails,
         api_models::admin::ToggleKVRequest,
         api_models::admin::ToggleKVResponse,
+        api_models::admin::MerchantOnboardingStatusUpdateRequest,
+        api_models::admin::MerchantOnboardingStatusResponse,
+        api_models::admin::MerchantOnboardingTransition,
+        api_models::admin::AuditLogResponse,
+        api_models::admin::AuditLogEntry,
+        api_models::admin::AuditLogFieldChange,
         api_models::admin::WebhookDetails,
         api_models::api_keys::ApiKeyExpiration,
         api_models::api_keys::CreateApiKeyRequest,
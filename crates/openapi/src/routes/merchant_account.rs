@@ -270,6 +270,193 @@ pub async fn delete_merchant_account() {}
 )]
 pub async fn merchant_account_kv_status() {}
 
+#[cfg(feature = "v1")]
+/// Merchant Account - Onboarding Status
+///
+/// Retrieve the onboarding status of the Merchant Account
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/onboarding",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Onboarding status of the Merchant Account retrieved", body = MerchantOnboardingStatusResponse),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Retrieve the onboarding status of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_onboarding_status() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Update Onboarding Status
+///
+/// Move the Merchant Account to the next onboarding status
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/onboarding",
+    request_body (
+        content = MerchantOnboardingStatusUpdateRequest,
+        examples (
+            ("Submit KYC documents" = (
+                value = json!({
+                "status": "docs_submitted",
+                "changed_by": "compliance@example.com",
+                "reason": "KYC documents received"
+                })
+        )))
+    ),
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Onboarding status of the Merchant Account updated", body = MerchantOnboardingStatusResponse),
+        (status = 400, description = "Invalid onboarding status transition"),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Update the onboarding status of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_update_onboarding_status() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Audit Logs
+///
+/// Retrieve the audit trail of a resource of the Merchant Account, which records the changes made
+/// to the resource within the retention period
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/audit_logs",
+    params (
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("resource_type" = AuditLogResourceType, Query, description = "The type of the resource"),
+        ("resource_id" = String, Query, description = "The identifier of the resource, which is the merchant id for merchant accounts, the key id for API keys and the profile id for routing configuration"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of entries to be returned, between 1 and 100"),
+        ("offset" = Option<i64>, Query, description = "The number of entries to be skipped"),
+    ),
+    responses(
+        (status = 200, description = "Audit trail of the resource retrieved", body = AuditLogResponse),
+        (status = 400, description = "Invalid query parameters")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Retrieve the audit trail of a resource of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_audit_logs() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Connector Failover Chain
+///
+/// Retrieve the connectors which would be tried for a payment, according to the connector failover
+/// order of the Merchant Account. The connectors which are not configured and enabled for the
+/// merchant are left out of the chain.
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/failover_chain",
+    params (
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("payment_method_type" = Option<PaymentMethodType>, Query, description = "The payment method type of the payment"),
+        ("currency" = Currency, Query, description = "The currency of the payment"),
+        ("country" = Option<CountryAlpha2>, Query, description = "The country of the billing address of the payment"),
+    ),
+    responses(
+        (status = 200, description = "Connector failover chain retrieved", body = ConnectorFailoverChainResponse),
+        (status = 400, description = "Invalid query parameters"),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Retrieve the connector failover chain of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_connector_failover_chain() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Legal Hold Create
+///
+/// Place a legal hold on a record of the Merchant Account, which exempts the record from the purge
+/// of the records past their retention period for as long as the hold is in place
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/legal_holds",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    request_body = LegalHoldCreateRequest,
+    responses(
+        (status = 200, description = "Legal hold placed on the record", body = LegalHoldResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 409, description = "The record is already under a legal hold")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Place a legal hold on a record of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_legal_hold_create() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Legal Hold List
+///
+/// List the legal holds of the Merchant Account, latest first
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/legal_holds",
+    params (
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("limit" = Option<i64>, Query, description = "The maximum number of legal holds to be returned, between 1 and 100"),
+        ("offset" = Option<i64>, Query, description = "The number of legal holds to be skipped"),
+    ),
+    responses(
+        (status = 200, description = "Legal holds retrieved", body = Vec<LegalHoldResponse>),
+        (status = 400, description = "Invalid query parameters")
+    ),
+    tag = "Merchant Account",
+    operation_id = "List the legal holds of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_legal_holds_list() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Legal Hold Delete
+///
+/// Release a legal hold on a record of the Merchant Account, after which the record is purged once
+/// it is past its retention period
+#[utoipa::path(
+    delete,
+    path = "/accounts/{account_id}/legal_holds/{entity_type}/{record_id}",
+    params (
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("entity_type" = RetentionEntity, Path, description = "The entity of the held record"),
+        ("record_id" = String, Path, description = "The identifier of the held record"),
+    ),
+    responses(
+        (status = 200, description = "Legal hold released", body = LegalHoldResponse),
+        (status = 404, description = "The record is not under a legal hold")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Release a legal hold on a record of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_legal_hold_delete() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Payment Methods Retokenize
+///
+/// Re-tokenize the stored cards of the Merchant Account with the network tokenization service,
+/// after the service was switched. A payment method keeps its network token until the new token is
+/// confirmed to be active, and is flagged for a retry when its re-tokenization fails.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/payment_methods/retokenize",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    request_body = PaymentMethodRetokenizationRequest,
+    responses(
+        (status = 200, description = "Payment methods re-tokenized", body = PaymentMethodRetokenizationResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Re-tokenize the payment methods of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_payment_methods_retokenize() {}
+
 /// Merchant Connector - List
 ///
 /// List Merchant Connector Details for the merchant
@@ -304,6 +304,28 @@ pub async fn connector_update() {}
 )]
 pub async fn connector_delete() {}
 
+/// Merchant Connector - Rotate Webhook Identifier
+///
+/// Rotate the identifier in the webhook endpoint of a Merchant Connector. Webhooks sent to the previous endpoint are accepted for a grace period of 24 hours
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/connectors/{connector_id}/webhook_identifier/rotate",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("connector_id" = i32, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Webhook identifier of the Merchant Connector rotated", body = MerchantConnectorResponse),
+        (status = 404, description = "Merchant Connector does not exist in records"),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Rotate the Webhook Identifier of a Merchant Connector",
+    security(("admin_api_key" = []))
+)]
+pub async fn connector_webhook_identifier_rotate() {}
+
 /// Merchant Connector - Delete
 ///
 /// Delete or Detach a Merchant Connector from Merchant Account
@@ -161,6 +161,7 @@ pub async fn payment_method_update_api() {}
     path = "/payment_methods/{method_id}",
     params (
         ("method_id" = String, Path, description = "The unique identifier for the Payment Method"),
+        ("revoke_connector_tokens" = Option<bool>, Query, description = "Whether the tokens of the payment method provisioned at the connectors are to be revoked as well"),
     ),
     responses(
         (status = 200, description = "Payment Method deleted", body = PaymentMethodDeleteResponse),
@@ -432,6 +432,181 @@ pub fn payments_connector_session() {}
 )]
 pub fn payments_cancel() {}
 
+/// Payments - Void Capture
+///
+/// Void a capture of a payment with multiple partial captures. The capture is reversed through a refund of its amount at the connector, which can be done once the capture is charged and until it is settled. A capture whose outcome at the connector is not yet known has to be synced first, and a settled capture is returned only through a refund of the payment.
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/captures/{capture_id}/void",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment"),
+        ("capture_id" = String, Path, description = "The identifier for the capture")
+    ),
+    responses(
+        (status = 200, description = "Capture voided", body = CaptureResponse),
+        (status = 400, description = "Capture cannot be voided"),
+        (status = 404, description = "Capture does not exist")
+    ),
+    tag = "Payments",
+    operation_id = "Void a Capture",
+    security(("api_key" = []))
+)]
+pub fn payments_void_capture() {}
+
+/// Payments - Update Fulfillment
+///
+/// Update the fulfillment status of the order of a payment. The status moves from `shipped` to `delivered`, and is tracked independently of the status of the payment. The shipping date is included in the evidence submitted for the disputes of the payment.
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/fulfillment",
+    request_body=PaymentsFulfillmentRequest,
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Fulfillment status updated", body = PaymentsFulfillmentResponse),
+        (status = 400, description = "Invalid fulfillment time"),
+        (status = 404, description = "Payment does not exist"),
+        (status = 412, description = "Invalid fulfillment status transition")
+    ),
+    tag = "Payments",
+    operation_id = "Update the Fulfillment of a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_fulfillment_update() {}
+
+/// Payments - Resume
+///
+/// Resume a payment which the customer left while it required their action, such as a redirection to authenticate the payment. The payment is returned in its current state along with the next action to continue it, or as completed if it completed while the customer was away. The payment can be resumed with the resume token returned along with the next action, until the client secret of the payment expires.
+#[utoipa::path(
+    post,
+    path = "/payments/resume",
+    request_body=PaymentsResumeRequest,
+    responses(
+        (status = 200, description = "Payment resumed", body = PaymentsResponse),
+        (status = 400, description = "Payment cannot be resumed"),
+        (status = 404, description = "Payment does not exist")
+    ),
+    tag = "Payments",
+    operation_id = "Resume a Payment",
+    security(("publishable_key" = []))
+)]
+pub fn payments_resume() {}
+
+/// Payments - Receipt
+///
+/// Retrieve the receipt of a payment, with the amount breakdown, the merchant, the masked details of the payment method, the captures of a payment which was captured in multiple parts and the refunds of the payment. The receipt token returned along with the receipt can be used to retrieve the receipt with the publishable key, such as for sharing the receipt with the customer.
+#[utoipa::path(
+    get,
+    path = "/payments/{payment_id}/receipt",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Receipt of the payment retrieved", body = PaymentReceiptResponse),
+        (status = 404, description = "Payment does not exist")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve the Receipt of a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_receipt_retrieve() {}
+
+/// Payments - Receipt by Token
+///
+/// Retrieve the receipt of a payment with the receipt token returned along with the receipt of the payment.
+#[utoipa::path(
+    get,
+    path = "/payments/receipts/{receipt_token}",
+    params(
+        ("receipt_token" = String, Path, description = "The receipt token of the payment")
+    ),
+    responses(
+        (status = 200, description = "Receipt of the payment retrieved", body = PaymentReceiptResponse),
+        (status = 404, description = "Receipt does not exist")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve the Receipt of a Payment by Token",
+    security(("publishable_key" = []))
+)]
+pub fn payments_receipt_retrieve_by_token() {}
+
+/// Payments - Void Eligibility
+///
+/// Check whether a payment can be voided, without voiding it, along with the reason for which it cannot be voided, such as for disabling the action of voiding the payment with an explanation. For a payment which was partially captured, only the portion which has not been captured can be voided.
+#[utoipa::path(
+    get,
+    path = "/payments/{payment_id}/void_eligibility",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Void eligibility of the payment retrieved", body = PaymentVoidEligibilityResponse),
+        (status = 404, description = "Payment does not exist")
+    ),
+    tag = "Payments",
+    operation_id = "Check the Void Eligibility of a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_void_eligibility_check() {}
+
+/// Payments - Timeline
+///
+/// Retrieve the timeline of a payment, which is every event of the payment across all its attempts, captures, refunds and disputes, along with the webhooks sent for the payment, in chronological order. Events which occurred at the same time are ordered by the step of the lifecycle of the payment which they record.
+#[utoipa::path(
+    get,
+    path = "/payments/{payment_id}/timeline",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Timeline of the payment retrieved", body = PaymentTimelineResponse),
+        (status = 404, description = "Payment does not exist")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve the Timeline of a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_timeline_retrieve() {}
+
+/// Payments - Simulate Webhook
+///
+/// Simulate a webhook of a test payment. The webhook of the chosen event type is generated from the current state of the payment, and is signed and delivered to the webhook endpoint of the merchant in the same way as a webhook of the payment. The event type should be applicable to the current status of the payment. Webhooks can be simulated only for test payments.
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/simulate_webhook",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    request_body=SimulateWebhookRequest,
+    responses(
+        (status = 200, description = "Webhook simulated", body = SimulateWebhookResponse),
+        (status = 400, description = "Payment is not a test payment, event type is not applicable to the payment, or webhooks are not configured"),
+        (status = 404, description = "Payment does not exist")
+    ),
+    tag = "Payments",
+    operation_id = "Simulate a Webhook of a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_simulate_webhook() {}
+
+/// Payments - Payment Methods for a Session
+///
+/// List the payment methods available for a checkout session before the payment is created, for the amount, currency and country of the payment. The payment methods are ordered from the most to the least relevant, preferring the payment methods the customer has used before, and are returned with the connectors through which they are available and the data the client requires to present them, such as the merchant identifiers of the wallets. When no payment method is available, the reason is returned.
+#[utoipa::path(
+    post,
+    path = "/payments/payment_methods",
+    request_body=PaymentMethodsListForSessionRequest,
+    responses(
+        (status = 200, description = "Payment methods available for the session", body = PaymentMethodsListForSessionResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payments",
+    operation_id = "List Payment Methods for a Session",
+    security(("api_key" = []))
+)]
+pub fn payments_list_payment_methods_for_session() {}
+
 /// Payments - List
 ///
 /// To list the *payments*
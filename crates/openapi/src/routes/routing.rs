@@ -87,6 +87,30 @@ pub async fn routing_link_config() {}
 )]
 pub async fn routing_retrieve_config() {}
 
+#[cfg(feature = "v1")]
+/// Routing - Retrieve Effective Weights
+///
+/// Retrieve the share of the traffic which each connector of a volume split routing algorithm receives, after redistributing the split of the connectors which are disabled or in maintenance to the available connectors
+
+#[utoipa::path(
+    get,
+    path = "/routing/{routing_algorithm_id}/effective_weights",
+    params(
+        ("routing_algorithm_id" = String, Path, description = "The unique identifier for a config"),
+    ),
+    responses(
+        (status = 200, description = "Successfully fetched the effective weights", body = RoutingEffectiveWeightsResponse),
+        (status = 400, description = "Routing config is not a volume split"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden")
+    ),
+   tag = "Routing",
+   operation_id = "Retrieve the effective weights of a routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_retrieve_effective_weights() {}
+
 #[cfg(feature = "v2")]
 /// Routing - Retrieve
 ///
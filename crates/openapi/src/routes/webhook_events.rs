@@ -97,3 +97,21 @@ pub fn list_webhook_delivery_attempts() {}
     security(("admin_api_key" = []))
 )]
 pub fn retry_webhook_delivery_attempt() {}
+
+/// Events - Delivery Queue
+///
+/// Retrieve the state of the delivery of the webhooks of a Merchant Account, across all the instances of the application.
+#[utoipa::path(
+    get,
+    path = "/events/{merchant_id}/delivery_queue",
+    params(
+        ("merchant_id" = String, Path, description = "The unique identifier for the Merchant Account."),
+    ),
+    responses(
+        (status = 200, description = "The state of the delivery of the webhooks retrieved successfully", body = WebhookDeliveryQueueResponse),
+    ),
+    tag = "Event",
+    operation_id = "Retrieve the webhook delivery queue of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub fn retrieve_webhook_delivery_queue() {}
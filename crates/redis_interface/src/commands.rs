@@ -12,15 +12,19 @@ use common_utils::{
     errors::CustomResult,
     ext_traits::{AsyncExt, ByteSliceExt, Encode, StringExt},
     fp_utils,
+    versioned::VersionedValue,
 };
 use error_stack::{report, ResultExt};
 use fred::{
-    interfaces::{HashesInterface, KeysInterface, ListInterface, SetsInterface, StreamsInterface},
+    interfaces::{
+        HashesInterface, KeysInterface, ListInterface, LuaInterface, SetsInterface,
+        StreamsInterface,
+    },
     prelude::RedisErrorKind,
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
         MultipleValues, RedisKey, RedisMap, RedisValue, ScanType, Scanner, SetOptions, XCap,
-        XReadResponse,
+        XReadResponse, XReadValue,
     },
 };
 use futures::StreamExt;
@@ -184,6 +188,23 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::GetFailed)
     }
 
+    /// Check whether each of the keys exists by issuing all the `EXISTS` commands together, so
+    /// that they are pipelined to redis instead of waiting for each reply before sending the next
+    /// command. The keys may hash to different slots, which a single multi-key command would not
+    /// allow in a cluster.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn exists_multiple_keys_pipelined(
+        &self,
+        keys: &[String],
+    ) -> CustomResult<Vec<bool>, errors::RedisError> {
+        futures::future::try_join_all(
+            keys.iter()
+                .map(|key| self.pool.exists::<bool, _>(self.add_prefix(key))),
+        )
+        .await
+        .change_context(errors::RedisError::GetFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn get_and_deserialize_key<T>(
         &self,
@@ -202,6 +223,72 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::JsonDeserializationFailed)
     }
 
+    /// Get and deserialize a value whose serialized representation carries a schema version.
+    ///
+    /// Values written with an older version are upgraded to the current shape, and are written
+    /// back to redis in the current version so that stale values are migrated on access.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_and_deserialize_versioned_key<T>(
+        &self,
+        key: &str,
+        type_name: &'static str,
+    ) -> CustomResult<T, errors::RedisError>
+    where
+        T: VersionedValue + serde::Serialize + Debug,
+    {
+        let value_bytes = self.get_key::<Vec<u8>>(key).await?;
+
+        fp_utils::when(value_bytes.is_empty(), || Err(errors::RedisError::NotFound))?;
+
+        let (version, value) = T::from_versioned_slice(&value_bytes)
+            .change_context(errors::RedisError::JsonDeserializationFailed)
+            .attach_printable_lazy(|| format!("Failed to deserialize versioned {type_name}"))?;
+
+        if version < T::CURRENT_VERSION {
+            // A failure to migrate the stored value is not fatal, the value would be upgraded
+            // again on the next read
+            let _ = self
+                .serialize_and_replace_existing_key(key, &value)
+                .await
+                .map_err(|error| {
+                    tracing::warn!(
+                        ?error,
+                        type_name,
+                        stored_version = version,
+                        "Failed to migrate versioned value in redis"
+                    )
+                });
+        }
+
+        Ok(value)
+    }
+
+    /// Overwrite the value of a key only if the key already exists, retaining its TTL
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn serialize_and_replace_existing_key<V>(
+        &self,
+        key: &str,
+        value: V,
+    ) -> CustomResult<(), errors::RedisError>
+    where
+        V: serde::Serialize + Debug,
+    {
+        let serialized = value
+            .encode_to_vec()
+            .change_context(errors::RedisError::JsonSerializationFailed)?;
+
+        self.pool
+            .set(
+                self.add_prefix(key),
+                serialized.as_slice(),
+                Some(Expiration::KEEPTTL),
+                Some(SetOptions::XX),
+                false,
+            )
+            .await
+            .change_context(errors::RedisError::SetFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn delete_key(&self, key: &str) -> CustomResult<DelReply, errors::RedisError> {
         self.pool
@@ -210,6 +297,18 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::DeleteFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn delete_hash_field(
+        &self,
+        key: &str,
+        field: &str,
+    ) -> CustomResult<DelReply, errors::RedisError> {
+        self.pool
+            .hdel(self.add_prefix(key), field)
+            .await
+            .change_context(errors::RedisError::DeleteFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn delete_multiple_keys(
         &self,
@@ -229,6 +328,21 @@ impl super::RedisConnectionPool {
         Ok(del_result)
     }
 
+    /// Delete the keys by issuing all the `DEL` commands together, so that they are pipelined to
+    /// redis instead of waiting for each reply before sending the next command.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn delete_multiple_keys_pipelined(
+        &self,
+        keys: &[String],
+    ) -> CustomResult<Vec<DelReply>, errors::RedisError> {
+        futures::future::try_join_all(
+            keys.iter()
+                .map(|key| self.pool.del::<DelReply, _>(self.add_prefix(key))),
+        )
+        .await
+        .change_context(errors::RedisError::DeleteFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_key_with_expiry<V>(
         &self,
@@ -413,6 +527,20 @@ impl super::RedisConnectionPool {
         Ok(values_after_increment)
     }
 
+    /// Atomically increment the integer value of the key, returning the value after the increment.
+    /// A key which does not exist is created with a value of zero before the increment.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_key_by(
+        &self,
+        key: &str,
+        increment: i64,
+    ) -> CustomResult<i64, errors::RedisError> {
+        self.pool
+            .incr_by(self.add_prefix(key), increment)
+            .await
+            .change_context(errors::RedisError::IncrementKeyFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn hscan(
         &self,
@@ -475,6 +603,72 @@ impl super::RedisConnectionPool {
             .await)
     }
 
+    /// Scan a page of the keys matching the pattern, starting from the cursor returned with the
+    /// previous page, or from `"0"` for the first page. Returns the keys, with the key prefix, and
+    /// the cursor of the next page, which is `"0"` once every key has been scanned.
+    ///
+    /// Unlike [`Self::scan`], a single `SCAN` is issued, so that large keyspaces can be scanned a
+    /// page at a time.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn scan_page(
+        &self,
+        pattern: &str,
+        cursor: &str,
+        count: u32,
+    ) -> CustomResult<(Vec<String>, String), errors::RedisError> {
+        const SCAN_PAGE_SCRIPT: &str = r#"
+            return redis.call("SCAN", ARGV[1], "MATCH", ARGV[2], "COUNT", ARGV[3])
+        "#;
+
+        let page: Vec<RedisValue> = self
+            .evaluate_redis_script(
+                SCAN_PAGE_SCRIPT,
+                Vec::new(),
+                vec![
+                    cursor.to_string(),
+                    self.add_prefix(pattern),
+                    count.to_string(),
+                ],
+            )
+            .await
+            .change_context(errors::RedisError::ScanFailed)?;
+
+        match <[RedisValue; 2]>::try_from(page) {
+            Ok([next_cursor, keys]) => Ok((
+                keys.into_array()
+                    .into_iter()
+                    .filter_map(|key| key.into_string())
+                    .collect(),
+                next_cursor
+                    .into_string()
+                    .ok_or(errors::RedisError::UnknownResult)?,
+            )),
+            Err(_) => Err(report!(errors::RedisError::UnknownResult)),
+        }
+    }
+
+    /// Evaluate the lua script atomically. The keys are prefixed with the key prefix, while the
+    /// arguments are passed as they are.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn evaluate_redis_script<T>(
+        &self,
+        lua_script: &'static str,
+        keys: Vec<String>,
+        args: Vec<String>,
+    ) -> CustomResult<T, errors::RedisError>
+    where
+        T: FromRedis + Unpin + Send + 'static,
+    {
+        let keys = keys
+            .iter()
+            .map(|key| self.add_prefix(key))
+            .collect::<Vec<_>>();
+        self.pool
+            .eval(lua_script, keys, args)
+            .await
+            .change_context(errors::RedisError::ScriptEvaluationFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn hscan_and_deserialize<T>(
         &self,
@@ -510,6 +704,22 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::GetHashFieldFailed)
     }
 
+    /// Check whether each of the fields exists in its hash by issuing all the `HEXISTS` commands
+    /// together, so that they are pipelined to redis instead of waiting for each reply before
+    /// sending the next command
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn hash_fields_exist_pipelined(
+        &self,
+        key_fields: &[(String, String)],
+    ) -> CustomResult<Vec<bool>, errors::RedisError> {
+        futures::future::try_join_all(key_fields.iter().map(|(key, field)| {
+            self.pool
+                .hexists::<bool, _, _>(self.add_prefix(key), field.as_str())
+        }))
+        .await
+        .change_context(errors::RedisError::GetHashFieldFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn get_hash_fields<V>(&self, key: &str) -> CustomResult<V, errors::RedisError>
     where
@@ -521,6 +731,25 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::GetHashFieldFailed)
     }
 
+    /// Get all the fields of each of the hashes by issuing all the `HGETALL` commands together,
+    /// so that they are pipelined to redis instead of waiting for each reply before sending the
+    /// next command
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_multiple_hash_fields_pipelined<V>(
+        &self,
+        keys: &[String],
+    ) -> CustomResult<Vec<V>, errors::RedisError>
+    where
+        V: FromRedis + Unpin + Send + 'static,
+    {
+        futures::future::try_join_all(
+            keys.iter()
+                .map(|key| self.pool.hgetall::<V, _>(self.add_prefix(key))),
+        )
+        .await
+        .change_context(errors::RedisError::GetHashFieldFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn get_hash_field_and_deserialize<V>(
         &self,
@@ -622,6 +851,20 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::StreamAcknowledgeFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn stream_read_entries_in_range(
+        &self,
+        stream: &str,
+        start: &str,
+        end: &str,
+        count: Option<u64>,
+    ) -> CustomResult<Vec<XReadValue<String, String, String>>, errors::RedisError> {
+        self.pool
+            .xrange_values(self.add_prefix(stream), start, end, count)
+            .await
+            .change_context(errors::RedisError::StreamReadFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn stream_get_length(&self, stream: &str) -> CustomResult<usize, errors::RedisError> {
         self.pool
@@ -891,6 +1134,47 @@ mod tests {
         assert!(is_invalid_redis_entry_error);
     }
 
+    #[tokio::test]
+    async fn test_scan_page_visits_every_key() {
+        let is_every_key_found = tokio::task::spawn_blocking(move || {
+            futures::executor::block_on(async {
+                // Arrange
+                let pool = RedisConnectionPool::new(&RedisSettings::default())
+                    .await
+                    .expect("failed to create redis connection pool");
+                let keys = (0..25)
+                    .map(|index| format!("scan_page_test_{index}"))
+                    .collect::<Vec<_>>();
+                for key in &keys {
+                    let _ = pool.set_key(key, "value".to_string()).await;
+                }
+
+                // Act
+                let mut scanned_keys = std::collections::HashSet::new();
+                let mut cursor = "0".to_string();
+                loop {
+                    let (page, next_cursor) = pool
+                        .scan_page("scan_page_test_*", &cursor, 10)
+                        .await
+                        .expect("failed to scan a page of the keys");
+                    scanned_keys.extend(page);
+                    if next_cursor == "0" {
+                        break;
+                    }
+                    cursor = next_cursor;
+                }
+
+                // Assert Setup
+                keys.iter()
+                    .all(|key| scanned_keys.contains(&pool.add_prefix(key)))
+            })
+        })
+        .await
+        .expect("Spawn block failure");
+
+        assert!(is_every_key_found);
+    }
+
     #[tokio::test]
     async fn test_delete_existing_key_success() {
         let is_success = tokio::task::spawn_blocking(move || {
@@ -914,6 +1198,46 @@ mod tests {
         assert!(is_success);
     }
 
+    #[tokio::test]
+    async fn test_delete_hash_field_deletes_only_the_field() {
+        let replies = tokio::task::spawn_blocking(move || {
+            futures::executor::block_on(async {
+                // Arrange
+                let pool = RedisConnectionPool::new(&RedisSettings::default())
+                    .await
+                    .expect("failed to create redis connection pool");
+                let _ = pool
+                    .set_hash_fields(
+                        "hash_key",
+                        vec![
+                            ("field_1".to_string(), "value".to_string()),
+                            ("field_2".to_string(), "value".to_string()),
+                        ],
+                        None,
+                    )
+                    .await;
+
+                // Act
+                let first_reply = pool.delete_hash_field("hash_key", "field_1").await;
+                let second_reply = pool.delete_hash_field("hash_key", "field_1").await;
+                let remaining_field = pool
+                    .get_hash_field::<Option<String>>("hash_key", "field_2")
+                    .await;
+
+                // Assert Setup
+                (
+                    matches!(first_reply, Ok(crate::DelReply::KeyDeleted)),
+                    matches!(second_reply, Ok(crate::DelReply::KeyNotDeleted)),
+                    matches!(remaining_field, Ok(Some(_))),
+                )
+            })
+        })
+        .await
+        .expect("Spawn block failure");
+
+        assert_eq!(replies, (true, true, true));
+    }
+
     #[tokio::test]
     async fn test_delete_non_existing_key_success() {
         let is_success = tokio::task::spawn_blocking(move || {
@@ -78,4 +78,10 @@ pub enum RedisError {
     PopListElementsFailed,
     #[error("Failed to increment hash field in Redis")]
     IncrementHashFieldFailed,
+    #[error("Failed to increment key in Redis")]
+    IncrementKeyFailed,
+    #[error("Failed to evaluate script in Redis")]
+    ScriptEvaluationFailed,
+    #[error("Failed to scan keys in Redis")]
+    ScanFailed,
 }
@@ -99,6 +99,62 @@ async fn main() -> CustomResult<(), ProcessTrackerError> {
 
     logger::debug!(startup_config=?state.conf);
 
+    #[cfg(feature = "v1")]
+    if matches!(scheduler_flow, scheduler::SchedulerFlow::Producer)
+        && state.conf.data_retention.enabled
+    {
+        for (tenant, store) in &state.stores {
+            if let Err(error) = router::core::data_retention::add_purge_task(
+                store.as_ref(),
+                &state.conf.data_retention,
+            )
+            .await
+            {
+                logger::error!(
+                    ?error,
+                    %tenant,
+                    "Failed to schedule the purge of the records past their retention period"
+                );
+            }
+        }
+    }
+
+    if matches!(scheduler_flow, scheduler::SchedulerFlow::Producer)
+        && state.conf.reverse_lookup_reconciliation.enabled
+    {
+        for (tenant, store) in &state.stores {
+            if let Err(error) =
+                router::core::reverse_lookup_reconciliation::add_reconciliation_task(
+                    store.as_ref(),
+                    &state.conf.reverse_lookup_reconciliation,
+                )
+                .await
+            {
+                logger::error!(
+                    ?error,
+                    %tenant,
+                    "Failed to schedule the reconciliation of the reverse lookups"
+                );
+            }
+        }
+    }
+
+    if matches!(scheduler_flow, scheduler::SchedulerFlow::Producer) && state.conf.audit_log.enabled
+    {
+        for (tenant, store) in &state.stores {
+            if let Err(error) =
+                router::core::audit_log::add_purge_task(store.as_ref(), &state.conf.audit_log).await
+            {
+                logger::error!(
+                    ?error,
+                    %tenant,
+                    "Failed to schedule the purge of the audit log entries past their retention \
+                     period"
+                );
+            }
+        }
+    }
+
     start_scheduler(&state, scheduler_flow, (tx, rx)).await?;
 
     logger::error!("Scheduler shut down");
@@ -329,6 +385,30 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PaymentMethodStatusUpdateWorkflow => Ok(Box::new(
                     workflows::payment_method_status_update::PaymentMethodStatusUpdateWorkflow,
                 )),
+                storage::ProcessTrackerRunner::FrmReviewTimeoutWorkflow => {
+                    #[cfg(feature = "frm")]
+                    {
+                        Ok(Box::new(
+                            workflows::frm_review_timeout::FrmReviewTimeoutWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "frm"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run FRM review timeout workflow when frm feature is disabled",
+                        )
+                    }
+                }
+                storage::ProcessTrackerRunner::DataRetentionPurgeWorkflow => Ok(Box::new(
+                    workflows::data_retention_purge::DataRetentionPurgeWorkflow,
+                )),
+                storage::ProcessTrackerRunner::ReverseLookupReconciliationWorkflow => Ok(Box::new(
+                    workflows::reverse_lookup_reconciliation::ReverseLookupReconciliationWorkflow,
+                )),
+                storage::ProcessTrackerRunner::AuditLogPurgeWorkflow => {
+                    Ok(Box::new(workflows::audit_log_purge::AuditLogPurgeWorkflow))
+                }
             }
         };
 
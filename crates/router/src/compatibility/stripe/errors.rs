@@ -495,6 +495,9 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 Self::PaymentIntentPaymentAttemptFailed { data }
             }
             errors::ApiErrorResponse::DisputeFailed { data } => Self::DisputeFailed { data },
+            errors::ApiErrorResponse::NetworkTokenCryptogramRefreshFailed { .. } => {
+                Self::PaymentIntentAuthenticationFailure { data: None }
+            }
             errors::ApiErrorResponse::InvalidCardData { data: _ } => Self::InvalidCardType, // Maybe it is better to de generalize this router error
             errors::ApiErrorResponse::CardExpired { data: _ } => Self::ExpiredCard,
             errors::ApiErrorResponse::RefundNotPossible { connector: _ } => Self::RefundFailed,
@@ -518,6 +521,30 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 connector,
                 status_code,
             },
+            errors::ApiErrorResponse::ConnectorConcurrencyLimitReached {
+                connector,
+                retry_after_in_secs,
+            } => Self::ExternalConnectorError {
+                code: "CE_10".to_string(),
+                message: format!(
+                    "Too many concurrent requests to {connector}. Retry after \
+                     {retry_after_in_secs} seconds"
+                ),
+                connector,
+                status_code: 503,
+            },
+            errors::ApiErrorResponse::ConnectorRateLimited {
+                connector,
+                retry_after_in_secs,
+            } => Self::ExternalConnectorError {
+                code: "CE_11".to_string(),
+                message: format!(
+                    "{connector} is rate limiting requests. Retry after {retry_after_in_secs} \
+                     seconds"
+                ),
+                connector,
+                status_code: 429,
+            },
             errors::ApiErrorResponse::IncorrectConnectorNameGiven => {
                 Self::IncorrectConnectorNameGiven
             }
@@ -572,7 +599,11 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::ClientSecretInvalid => Self::PaymentIntentInvalidParameter {
                 param: "client_secret".to_owned(),
             },
-            errors::ApiErrorResponse::InvalidRequestData { message } => {
+            errors::ApiErrorResponse::InvalidRequestData { message }
+            | errors::ApiErrorResponse::EnvironmentMismatch { message }
+            | errors::ApiErrorResponse::VelocityLimitExceeded { message }
+            | errors::ApiErrorResponse::DuplicatePaymentSuspected { message }
+            | errors::ApiErrorResponse::AmountOutOfRange { message } => {
                 Self::InvalidRequestData { message }
             }
             errors::ApiErrorResponse::PreconditionFailed { message } => {
@@ -582,6 +613,24 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
                 field_name: field_name.to_string(),
                 param: field_name.to_string(),
             },
+            errors::ApiErrorResponse::CvvRecollectionRequired => Self::ParameterMissing {
+                field_name: "card_cvc".to_string(),
+                param: "card_cvc".to_string(),
+            },
+            errors::ApiErrorResponse::InvalidReturnUrl => Self::InvalidRequestData {
+                message: "The return_url is not in the domains allowed for the merchant"
+                    .to_string(),
+            },
+            errors::ApiErrorResponse::RefundWindowExpired {
+                refund_window_in_days,
+            } => Self::InvalidRequestData {
+                message: format!(
+                    "The refund window of {refund_window_in_days} days for the payment has expired"
+                ),
+            },
+            errors::ApiErrorResponse::TokenExpired { token } => Self::InvalidRequestData {
+                message: format!("The {token} has expired"),
+            },
             errors::ApiErrorResponse::MaximumRefundCount => Self::MaximumRefundCount,
             errors::ApiErrorResponse::PaymentNotSucceeded => Self::PaymentFailed,
             errors::ApiErrorResponse::DuplicateMandate => Self::DuplicateMandate,
@@ -644,9 +693,9 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             | errors::ApiErrorResponse::WebhookProcessingFailure
             | errors::ApiErrorResponse::WebhookAuthenticationFailed
             | errors::ApiErrorResponse::WebhookUnprocessableEntity
-            | errors::ApiErrorResponse::WebhookInvalidMerchantSecret => {
-                Self::WebhookProcessingError
-            }
+            | errors::ApiErrorResponse::WebhookInvalidMerchantSecret
+            | errors::ApiErrorResponse::WebhookTimestampExpired
+            | errors::ApiErrorResponse::WebhookEventReplayed => Self::WebhookProcessingError,
             errors::ApiErrorResponse::IncorrectPaymentMethodConfiguration => {
                 Self::PaymentMethodUnactivated
             }
@@ -835,6 +884,9 @@ impl ErrorSwitch<StripeErrorCode> for CustomersErrorResponse {
             Self::MandateActive => SC::MandateActive,
             Self::CustomerNotFound => SC::CustomerNotFound,
             Self::CustomerAlreadyExists => SC::DuplicateCustomer,
+            Self::InvalidRequestData { message } => SC::InvalidRequestData {
+                message: message.clone(),
+            },
         }
     }
 }
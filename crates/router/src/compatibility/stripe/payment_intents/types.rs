@@ -745,6 +745,7 @@ impl ForeignTryFrom<(Option<MandateData>, Option<String>)> for Option<payments::
                             start_date: mandate.start_date,
                             end_date: mandate.end_date,
                             metadata: None,
+                            constraints: None,
                         },
                     )),
                     StripeMandateType::MultiUse => Some(payments::MandateType::MultiUse(Some(
@@ -754,6 +755,7 @@ impl ForeignTryFrom<(Option<MandateData>, Option<String>)> for Option<payments::
                             start_date: mandate.start_date,
                             end_date: mandate.end_date,
                             metadata: None,
+                            constraints: None,
                         },
                     ))),
                 },
@@ -764,6 +766,7 @@ impl ForeignTryFrom<(Option<MandateData>, Option<String>)> for Option<payments::
                         start_date: mandate.start_date,
                         end_date: mandate.end_date,
                         metadata: None,
+                        constraints: None,
                     },
                 ))),
             },
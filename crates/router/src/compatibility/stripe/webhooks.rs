@@ -150,6 +150,8 @@ impl From<common_enums::PayoutStatus> for StripePayoutStatus {
             common_enums::PayoutStatus::Initiated => Self::PayoutInitiated,
             common_enums::PayoutStatus::Expired => Self::PayoutExpired,
             common_enums::PayoutStatus::Reversed => Self::PayoutReversed,
+            // Stripe marks the payouts returned by the bank as failed
+            common_enums::PayoutStatus::Returned => Self::PayoutFailure,
             common_enums::PayoutStatus::Pending
             | common_enums::PayoutStatus::Ineligible
             | common_enums::PayoutStatus::RequiresCreation
@@ -296,6 +298,7 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PayoutProcessing => "payout.created",
         api_models::enums::EventType::PayoutExpired => "payout.failed",
         api_models::enums::EventType::PayoutReversed => "payout.reconciliation_completed",
+        api_models::enums::EventType::PayoutReturned => "payout.failed",
     }
 }
 
@@ -26,6 +26,7 @@ pub async fn compatibility_api_wrap<'a, 'b, U, T, Q, F, Fut, S, E, E2>(
     lock_action: api_locking::LockAction,
 ) -> HttpResponse
 where
+    U: auth::GetLockExpiry,
     F: Fn(SessionState, U, T, ReqState) -> Fut,
     Fut: Future<Output = CustomResult<api::ApplicationResponse<Q>, E2>>,
     E2: ErrorSwitch<E> + std::error::Error + Send + Sync + 'static,
@@ -105,6 +105,7 @@ impl Default for super::settings::Refund {
         Self {
             max_attempts: 10,
             max_age: 365,
+            connector_max_age: HashMap::new(),
         }
     }
 }
@@ -128,12 +129,169 @@ impl Default for super::settings::DrainerSettings {
     }
 }
 
+impl Default for super::settings::OrderedWebhookDeliverySettings {
+    fn default() -> Self {
+        Self {
+            gap_timeout: 300,
+            poll_interval: 30,
+        }
+    }
+}
+
+impl Default for super::settings::WebhookDeliverySchedulingSettings {
+    fn default() -> Self {
+        Self {
+            max_concurrent_deliveries: 10,
+            max_queued_deliveries: 1000,
+            failure_threshold: 5,
+            backoff_base: 30,
+            backoff_max: 3600,
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorLogRedaction {
+    fn default() -> Self {
+        Self {
+            field_paths: crate::services::connector_log_redaction::KNOWN_SENSITIVE_FIELDS
+                .iter()
+                .map(|field| field.to_string())
+                .collect(),
+            replacement: common_utils::pii::REDACTED.to_string(),
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorNetworkRetry {
+    fn default() -> Self {
+        Self {
+            max_retries: 2,
+            backoff_ms: 100,
+            idempotency_key_headers: vec!["Idempotency-Key".to_string()],
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorLatencyTracking {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            sampling_rate: 0.1,
+            bucket_duration_in_secs: 300,
+            retention_in_secs: 24 * 60 * 60,
+        }
+    }
+}
+
+impl Default for super::settings::SettlementCalendar {
+    fn default() -> Self {
+        Self {
+            non_working_days: HashSet::from([time::Weekday::Saturday, time::Weekday::Sunday]),
+            holidays: HashSet::new(),
+        }
+    }
+}
+
+#[cfg(feature = "frm")]
+impl Default for super::settings::FrmReviewTimeout {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            timeout_in_secs: 24 * 60 * 60,
+            decision: common_enums::FraudDecision::Decline,
+        }
+    }
+}
+
+impl Default for super::settings::DataRetention {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            purge_interval_in_secs: 24 * 60 * 60,
+            batch_size: 100,
+            max_batches_per_run: 10,
+            policies: HashMap::new(),
+        }
+    }
+}
+
+impl Default for super::settings::CustomerRisk {
+    fn default() -> Self {
+        Self {
+            default_tier: common_enums::CustomerRiskTier::Low,
+            medium_tier_min_score: 40,
+            high_tier_min_score: 70,
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorConcurrency {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            limits: HashMap::new(),
+            lease_ttl_in_secs: 120,
+            max_wait_in_millis: 2000,
+            retry_interval_in_millis: 50,
+            retry_after_in_secs: 1,
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorRateLimit {
+    fn default() -> Self {
+        Self {
+            connectors: HashSet::new(),
+            max_retries: 1,
+            max_deferral_in_secs: 5,
+            default_retry_after_in_secs: 1,
+            max_retry_after_in_secs: 60,
+        }
+    }
+}
+
+impl Default for super::settings::TokenExpiry {
+    fn default() -> Self {
+        Self {
+            max_session_expiry_in_secs: crate::consts::MAX_SESSION_EXPIRY,
+            // 5 minutes
+            grace_period_in_secs: 5 * 60,
+            // 90 days
+            receipt_token_expiry_in_secs: 90 * 24 * 60 * 60,
+        }
+    }
+}
+
+impl Default for super::settings::ReverseLookupReconciliation {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_in_secs: 60 * 60,
+            scan_count: 1000,
+            repair: false,
+        }
+    }
+}
+
+impl Default for super::settings::AuditLogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            retention_in_days: 90,
+            purge_interval_in_secs: 86400,
+            redacted_fields: Vec::new(),
+        }
+    }
+}
+
 #[cfg(feature = "kv_store")]
 impl Default for super::settings::KvConfig {
     fn default() -> Self {
         Self {
             ttl: 900,
             soft_kill: Some(false),
+            drainer_push_retry_count: Some(storage_impl::DEFAULT_DRAINER_PUSH_RETRY_COUNT),
+            reverse_lookup_read_repair: Some(false),
         }
     }
 }
@@ -147,6 +147,21 @@ impl SecretsHandler for settings::ApiKeys {
     }
 }
 
+#[async_trait::async_trait]
+impl SecretsHandler for settings::CardHash {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let card_hash = value.get_inner();
+        let salt = secret_management_client
+            .get_secret(card_hash.salt.clone())
+            .await?;
+
+        Ok(value.transition_state(|_| Self { salt }))
+    }
+}
+
 #[async_trait::async_trait]
 impl SecretsHandler for settings::ApplePayDecryptConfig {
     async fn convert_to_raw_secret(
@@ -371,6 +386,12 @@ pub(crate) async fn fetch_raw_secrets(
             .await
             .expect("Failed to decrypt api_keys configs");
 
+    #[allow(clippy::expect_used)]
+    let card_hash =
+        settings::CardHash::convert_to_raw_secret(conf.card_hash, secret_management_client)
+            .await
+            .expect("Failed to decrypt card_hash configs");
+
     #[cfg(feature = "olap")]
     #[allow(clippy::expect_used)]
     let connector_onboarding = settings::ConnectorOnboarding::convert_to_raw_secret(
@@ -460,6 +481,7 @@ pub(crate) async fn fetch_raw_secrets(
         payout_method_filters: conf.payout_method_filters,
         bank_config: conf.bank_config,
         api_keys,
+        card_hash,
         file_storage: conf.file_storage,
         tokenization: conf.tokenization,
         connector_customer: conf.connector_customer,
@@ -493,6 +515,7 @@ pub(crate) async fn fetch_raw_secrets(
         kv_config: conf.kv_config,
         #[cfg(feature = "frm")]
         frm: conf.frm,
+        merchant_onboarding: conf.merchant_onboarding,
         #[cfg(feature = "olap")]
         report_download_config: conf.report_download_config,
         events: conf.events,
@@ -512,5 +535,19 @@ pub(crate) async fn fetch_raw_secrets(
             .network_tokenization_supported_card_networks,
         network_tokenization_service,
         network_tokenization_supported_connectors: conf.network_tokenization_supported_connectors,
+        surcharge_limits: conf.surcharge_limits,
+        connector_log_redaction: conf.connector_log_redaction,
+        connector_network_retry: conf.connector_network_retry,
+        audit_log: conf.audit_log,
+        connector_latency_tracking: conf.connector_latency_tracking,
+        settlement_delay: conf.settlement_delay,
+        soft_decline_step_up: conf.soft_decline_step_up,
+        data_retention: conf.data_retention,
+        reverse_lookup_reconciliation: conf.reverse_lookup_reconciliation,
+        customer_risk: conf.customer_risk,
+        connector_concurrency: conf.connector_concurrency,
+        connector_rate_limit: conf.connector_rate_limit,
+        token_expiry: conf.token_expiry,
+        payment_method_country_availability: conf.payment_method_country_availability,
     }
 }
@@ -6,7 +6,7 @@ use std::{
 #[cfg(feature = "olap")]
 use analytics::{opensearch::OpenSearchConfig, ReportConfig};
 use api_models::{enums, payment_methods::RequiredFieldInfo};
-use common_utils::ext_traits::ConfigExt;
+use common_utils::{ext_traits::ConfigExt, types::Percentage};
 use config::{Environment, File};
 use error_stack::ResultExt;
 #[cfg(feature = "email")]
@@ -74,6 +74,7 @@ pub struct Settings<S: SecretState> {
     pub pm_filters: ConnectorFilters,
     pub bank_config: BankRedirectConfig,
     pub api_keys: SecretStateContainer<ApiKeys, S>,
+    pub card_hash: SecretStateContainer<CardHash, S>,
     pub file_storage: FileStorageConfig,
     pub encryption_management: EncryptionManagementConfig,
     pub secrets_management: SecretsManagementConfig,
@@ -90,6 +91,7 @@ pub struct Settings<S: SecretState> {
     pub required_fields: RequiredFields,
     pub delayed_session_response: DelayedSessionConfig,
     pub webhook_source_verification_call: WebhookSourceVerificationCall,
+    pub cross_currency_capture: CrossCurrencyCaptureConfig,
     pub payment_method_auth: SecretStateContainer<PaymentMethodAuth, S>,
     pub connector_request_reference_id_config: ConnectorRequestReferenceIdConfig,
     #[cfg(feature = "payouts")]
@@ -108,6 +110,7 @@ pub struct Settings<S: SecretState> {
     pub kv_config: KvConfig,
     #[cfg(feature = "frm")]
     pub frm: Frm,
+    pub merchant_onboarding: MerchantOnboarding,
     #[cfg(feature = "olap")]
     pub report_download_config: ReportConfig,
     #[cfg(feature = "olap")]
@@ -127,6 +130,20 @@ pub struct Settings<S: SecretState> {
     pub network_tokenization_supported_card_networks: NetworkTokenizationSupportedCardNetworks,
     pub network_tokenization_service: Option<SecretStateContainer<NetworkTokenizationService, S>>,
     pub network_tokenization_supported_connectors: NetworkTokenizationSupportedConnectors,
+    pub surcharge_limits: SurchargeLimits,
+    pub connector_log_redaction: ConnectorLogRedaction,
+    pub connector_network_retry: ConnectorNetworkRetry,
+    pub audit_log: AuditLogConfig,
+    pub connector_latency_tracking: ConnectorLatencyTracking,
+    pub settlement_delay: SettlementDelay,
+    pub soft_decline_step_up: SoftDeclineStepUp,
+    pub data_retention: DataRetention,
+    pub reverse_lookup_reconciliation: ReverseLookupReconciliation,
+    pub customer_risk: CustomerRisk,
+    pub connector_concurrency: ConnectorConcurrency,
+    pub connector_rate_limit: ConnectorRateLimit,
+    pub token_expiry: TokenExpiry,
+    pub payment_method_country_availability: PaymentMethodCountryAvailability,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -207,12 +224,42 @@ pub struct UnmaskedHeaders {
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct Frm {
     pub enabled: bool,
+    /// Whether payments should proceed to the connector when the fraud check provider is unavailable
+    #[serde(default)]
+    pub fail_open: bool,
+    #[serde(default)]
+    pub review_timeout: FrmReviewTimeout,
+}
+
+/// The resolution of the payments sent for review by the fraud check which are not reviewed by the
+/// merchant in time
+#[cfg(feature = "frm")]
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct FrmReviewTimeout {
+    /// Whether the payments which are not reviewed in time are resolved automatically
+    pub enabled: bool,
+    /// The time in seconds after which a payment which has not been reviewed is resolved
+    pub timeout_in_secs: u32,
+    /// The decision with which a payment which has not been reviewed in time is resolved, either
+    /// `approve` or `decline`
+    pub decision: common_enums::FraudDecision,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct MerchantOnboarding {
+    /// Whether new merchant accounts have to be onboarded before they can accept payments
+    pub enabled: bool,
 }
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct KvConfig {
     pub ttl: u32,
     pub soft_kill: Option<bool>,
+    /// Number of times a failed push to the drainer stream is retried before the operation fails
+    pub drainer_push_retry_count: Option<u8>,
+    /// Whether reverse lookups found in the database on a KV miss are written back to redis
+    pub reverse_lookup_read_repair: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -353,6 +400,9 @@ pub struct ConnectorCustomer {
     #[cfg(feature = "payouts")]
     #[serde(deserialize_with = "deserialize_hashset")]
     pub payout_connector_list: HashSet<enums::PayoutConnectors>,
+    /// Connectors to which customer details are propagated when the customer is updated
+    #[serde(default, deserialize_with = "deserialize_hashset")]
+    pub sync_connector_list: HashSet<enums::Connector>,
 }
 
 #[cfg(feature = "dummy_connector")]
@@ -401,6 +451,246 @@ pub struct NetworkTransactionIdSupportedConnectors {
     pub connector_list: HashSet<enums::Connector>,
 }
 
+/// Limits on the surcharge which can be applied to a payment, for the countries which cap surcharges
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct SurchargeLimits {
+    /// Maximum surcharge permitted, as a percentage of the payment amount, keyed by the billing
+    /// country of the payment. Surcharges are not capped for countries without a configured limit.
+    pub max_surcharge_percentage: HashMap<
+        enums::CountryAlpha2,
+        Percentage<{ common_utils::consts::SURCHARGE_PERCENTAGE_PRECISION_LENGTH }>,
+    >,
+}
+
+impl SurchargeLimits {
+    pub fn get_max_surcharge_percentage(
+        &self,
+        billing_country: Option<enums::CountryAlpha2>,
+    ) -> Option<&Percentage<{ common_utils::consts::SURCHARGE_PERCENTAGE_PRECISION_LENGTH }>> {
+        billing_country.and_then(|country| self.max_surcharge_percentage.get(&country))
+    }
+}
+
+/// Redaction applied to the requests sent to and the responses received from connectors, before
+/// they are logged or stored
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorLogRedaction {
+    /// Paths of the fields to be redacted, with the keys of nested fields separated by `.`, and
+    /// `*` matching any key. A path consisting of a single key matches the field at any depth.
+    pub field_paths: Vec<String>,
+    /// The value which replaces the redacted fields
+    pub replacement: String,
+}
+
+/// Retries of the requests to connectors which failed with a network error, such as a failure to
+/// connect or a connection reset, as opposed to an error returned by the connector
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorNetworkRetry {
+    /// The maximum number of retries of a request, `0` disables the retries
+    pub max_retries: u8,
+    /// The delay before the first retry in milliseconds, which is doubled for every further retry
+    pub backoff_ms: u64,
+    /// The headers which carry an idempotency key. A request which may have reached the connector
+    /// is only retried if it has any of these headers, so that the connector deduplicates it.
+    pub idempotency_key_headers: Vec<String>,
+}
+
+/// Field level audit log of the changes to merchant accounts, API keys and routing configuration
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct AuditLogConfig {
+    /// Whether the changes are recorded in the audit log
+    pub enabled: bool,
+    /// The number of days for which the entries of the audit log are retained
+    pub retention_in_days: u16,
+    /// The interval in seconds between the purges of the entries past the retention period
+    pub purge_interval_in_secs: u32,
+    /// The fields whose values are not recorded, only whether they changed, in addition to the
+    /// fields which are always redacted
+    pub redacted_fields: Vec<String>,
+}
+
+/// Tracking of the latency distribution of the calls made to each connector. The latencies of the
+/// sampled calls are aggregated into histograms in redis, one for every bucket of time.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorLatencyTracking {
+    /// Whether the latencies of the connector calls are tracked
+    pub enabled: bool,
+    /// The fraction of the connector calls whose latency is recorded, between 0 and 1
+    pub sampling_rate: f64,
+    /// The duration in seconds of the buckets of time into which the latencies are aggregated
+    pub bucket_duration_in_secs: u32,
+    /// The time in seconds for which the aggregated latencies are retained, which is the largest
+    /// window over which the latency distribution can be computed
+    pub retention_in_secs: u32,
+}
+
+/// The delays after which the connectors settle the captured payments, from which the expected
+/// settlement date of a payment is computed when the connector does not report it
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct SettlementDelay {
+    /// The number of working days after the capture of a payment at which the payment is settled,
+    /// by connector
+    pub connectors: HashMap<String, u16>,
+    /// The days on which payments are not settled
+    pub calendar: SettlementCalendar,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct SettlementCalendar {
+    /// The days of the week on which payments are not settled
+    pub non_working_days: HashSet<time::Weekday>,
+    /// The dates on which payments are not settled, in the `YYYY-MM-DD` format
+    pub holidays: HashSet<time::Date>,
+}
+
+/// The decline codes of the connectors which indicate that the connector requires the customer to
+/// be authenticated, on which a payment without 3DS is retried with 3DS
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct SoftDeclineStepUp {
+    /// The authentication required decline codes, by connector
+    pub connectors: HashMap<String, StepUpDeclineCodes>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct StepUpDeclineCodes {
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub decline_codes: HashSet<String>,
+}
+
+/// Retention of the records of the payments, the refunds, the disputes, the customers and the
+/// files, past which the records are purged by the scheduler. The records of the entities without a
+/// retention policy are retained indefinitely.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct DataRetention {
+    /// Whether the records past their retention period are purged
+    pub enabled: bool,
+    /// The interval in seconds between the runs of the purge
+    pub purge_interval_in_secs: u32,
+    /// The number of the records of an entity which are purged at a time
+    pub batch_size: u16,
+    /// The maximum number of batches of the records of an entity purged in a run, so that a run
+    /// catching up on a large backlog does not hold the scheduler for long
+    pub max_batches_per_run: u16,
+    /// The retention policies, by entity
+    pub policies: HashMap<common_enums::RetentionEntity, RetentionPolicy>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct RetentionPolicy {
+    /// The number of days for which the records of the entity are retained
+    pub retention_in_days: u16,
+}
+
+/// The recurring reconciliation of the reverse lookups stored in redis with the ones stored in the
+/// database, which scans a page of the reverse lookups in redis in every run
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ReverseLookupReconciliation {
+    /// Whether the reverse lookups are reconciled
+    pub enabled: bool,
+    /// The interval in seconds between the runs of the reconciliation
+    pub interval_in_secs: u32,
+    /// The number of the keys in redis visited by the scan in a run
+    pub scan_count: u32,
+    /// Whether the mismatches found are repaired, otherwise they are only reported
+    pub repair: bool,
+}
+
+/// The derivation of the risk tiers of the customers from their risk scores, which are derived from
+/// the disputes raised on the payments of the customers and the payments which were declined
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct CustomerRisk {
+    /// The risk tier of the customers without a history of disputes and declines
+    pub default_tier: common_enums::CustomerRiskTier,
+    /// The minimum risk score, out of 100, of the customers of the medium risk tier
+    pub medium_tier_min_score: u8,
+    /// The minimum risk score, out of 100, of the customers of the high risk tier
+    pub high_tier_min_score: u8,
+}
+
+/// Limits on the number of the calls in flight to each connector at a time, across all the
+/// instances of the application. The calls in flight hold permits which are leased in redis, so
+/// that the permit of a call which is never released expires with its lease.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorConcurrency {
+    /// Whether the calls in flight to the connectors are limited
+    pub enabled: bool,
+    /// The maximum number of the calls in flight at a time, by connector. The calls to the
+    /// connectors which are not listed are not limited.
+    pub limits: HashMap<String, u16>,
+    /// The time in seconds after which the permit of a call expires, which should be longer than
+    /// the timeout of the connector calls
+    pub lease_ttl_in_secs: u32,
+    /// The time in milliseconds for which a call waits for a permit while all the permits of the
+    /// connector are in use, after which the call is shed
+    pub max_wait_in_millis: u32,
+    /// The interval in milliseconds at which a waiting call tries to acquire a permit again
+    pub retry_interval_in_millis: u32,
+    /// The time in seconds after which the caller of a shed call is asked to retry
+    pub retry_after_in_secs: u32,
+}
+
+/// Honoring of the `Retry-After` header of the responses with the status `429 Too Many Requests`
+/// from the connectors. A rate limited connector is backed off across all the instances of the
+/// application for the time in the header, and the calls to the connector are deferred until the
+/// backoff ends.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorRateLimit {
+    /// The connectors of which the `Retry-After` header is honored
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub connectors: HashSet<String>,
+    /// The maximum number of times a call which was rate limited is deferred and retried, `0`
+    /// disables the retries while still backing off the connector
+    pub max_retries: u8,
+    /// The maximum time in seconds for which a call is deferred in total, after which the call is
+    /// shed while the connector is backed off
+    pub max_deferral_in_secs: u32,
+    /// The time in seconds for which the connector is backed off when the `Retry-After` header is
+    /// missing or malformed
+    pub default_retry_after_in_secs: u32,
+    /// The maximum time in seconds for which the connector is backed off, to which the time in the
+    /// `Retry-After` header is capped
+    pub max_retry_after_in_secs: u32,
+}
+
+/// Expiry of the short-lived tokens which resolve to a payment, which are the client secret and
+/// the payment link of the payment, the resume token and the receipt token
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct TokenExpiry {
+    /// The maximum expiry in seconds of a payment session, to which the expiry requested for the
+    /// payment and the default expiry of the business profile are capped
+    pub max_session_expiry_in_secs: u32,
+    /// The time in seconds after the expiry of a payment session within which a customer, who was
+    /// completing the action required by the payment when the session expired, may still complete
+    /// the payment
+    pub grace_period_in_secs: u32,
+    /// The time in seconds after the creation of a payment for which its receipt token resolves
+    pub receipt_token_expiry_in_secs: u32,
+}
+
+/// The countries in which the payment method types are available, for the payment method types
+/// which are only legal or available in certain countries. The payment method types which are not
+/// listed are available in every country.
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct PaymentMethodCountryAvailability {
+    #[serde(deserialize_with = "deserialize_hashmap")]
+    pub countries: HashMap<enums::PaymentMethodType, HashSet<enums::CountryAlpha2>>,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct NetworkTokenizationSupportedCardNetworks {
     #[serde(deserialize_with = "deserialize_hashset")]
@@ -581,6 +871,9 @@ pub enum DecryptionScheme {
 pub struct Refund {
     pub max_attempts: usize,
     pub max_age: i64,
+    /// The number of days after the payment within which refunds are accepted, by connector,
+    /// overriding the refund window of the connector
+    pub connector_max_age: HashMap<String, i64>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -673,6 +966,87 @@ pub struct DrainerSettings {
 pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
     pub ignore_error: WebhookIgnoreErrorSettings,
+    /// Tolerance window for the signed timestamps of incoming webhooks, keyed by connector name.
+    /// Timestamps are not validated for connectors without a configured tolerance.
+    pub timestamp_tolerance: HashMap<String, WebhookTimestampTolerance>,
+    pub ordered_delivery: OrderedWebhookDeliverySettings,
+    pub delivery_scheduling: WebhookDeliverySchedulingSettings,
+    /// Enrichment of the resource of incoming webhooks, keyed by connector name. Webhooks are
+    /// enriched only for the connectors which opted in to it.
+    pub resource_enrichment: HashMap<String, WebhookResourceEnrichment>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebhookResourceEnrichment {
+    /// Paths of the fields of the resource which are required for processing the webhook, with
+    /// the keys of nested fields separated by `.`. The resource is fetched from the connector
+    /// when any of these fields is missing in the webhook.
+    pub required_fields: Vec<String>,
+}
+
+/// Settings for the in-order delivery of the webhooks of a resource, for the business profiles
+/// which opted in to it
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct OrderedWebhookDeliverySettings {
+    /// Time in seconds for which a webhook is held while a previous webhook of the resource is
+    /// unacknowledged, after which the webhook is delivered out of order
+    pub gap_timeout: u64,
+    /// Interval in seconds at which a held webhook checks whether it can be delivered
+    pub poll_interval: u64,
+}
+
+/// Settings for the scheduling of the delivery of outgoing webhooks, which isolates the deliveries
+/// to each merchant from those to the other merchants
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct WebhookDeliverySchedulingSettings {
+    /// Maximum number of deliveries to a merchant which are in flight at a time
+    pub max_concurrent_deliveries: u32,
+    /// Maximum number of deliveries to a merchant which wait for a delivery slot, beyond which the
+    /// deliveries are deferred to the outgoing webhook retry task
+    pub max_queued_deliveries: u32,
+    /// Number of consecutive failed deliveries to a merchant after which the deliveries to the
+    /// merchant are backed off
+    pub failure_threshold: u32,
+    /// Time in seconds for which the deliveries to a merchant are backed off once the failure
+    /// threshold is reached, doubled for every further failed delivery
+    pub backoff_base: u64,
+    /// Maximum time in seconds for which the deliveries to a merchant are backed off
+    pub backoff_max: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct WebhookTimestampTolerance {
+    /// Maximum allowed difference between the signed timestamp and the current time, in `unit`
+    pub tolerance: u64,
+    /// The unit in which the connector sends the signed timestamp
+    pub unit: WebhookTimestampUnit,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookTimestampUnit {
+    Seconds,
+    Milliseconds,
+}
+
+impl WebhookTimestampUnit {
+    pub fn current_timestamp(self) -> i64 {
+        let now = time::OffsetDateTime::now_utc();
+        match self {
+            Self::Seconds => now.unix_timestamp(),
+            Self::Milliseconds => now.unix_timestamp() * 1000 + i64::from(now.millisecond()),
+        }
+    }
+
+    /// Convert a duration in this unit to seconds, rounding up
+    pub fn to_seconds(self, duration: u64) -> u64 {
+        match self {
+            Self::Seconds => duration,
+            Self::Milliseconds => duration.div_ceil(1000),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -703,6 +1077,14 @@ pub struct ApiKeys {
     pub enable_partial_auth: bool,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct CardHash {
+    /// Key used for calculating the salted hashes of card numbers, which are stored on the payment
+    /// attempts for analytics
+    pub salt: Secret<String>,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct DelayedSessionConfig {
     #[serde(deserialize_with = "deserialize_hashset")]
@@ -715,6 +1097,14 @@ pub struct WebhookSourceVerificationCall {
     pub connectors_with_webhook_source_verification_call: HashSet<enums::Connector>,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct CrossCurrencyCaptureConfig {
+    /// The connectors which accept capturing an authorized payment in a currency other than the
+    /// currency it was authorized in
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub connectors_with_cross_currency_capture: HashSet<enums::Connector>,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct ApplePayDecryptConfig {
     pub apple_pay_ppc: Secret<String>,
@@ -782,6 +1172,11 @@ impl Settings<SecuredSecret> {
                     .with_list_parse_key("events.kafka.brokers")
                     .with_list_parse_key("proxy.bypass_proxy_urls")
                     .with_list_parse_key("connectors.supported.wallets")
+                    .with_list_parse_key("connector_log_redaction.field_paths")
+                    .with_list_parse_key("connector_network_retry.idempotency_key_headers")
+                    .with_list_parse_key("audit_log.redacted_fields")
+                    .with_list_parse_key("settlement_delay.calendar.non_working_days")
+                    .with_list_parse_key("settlement_delay.calendar.holidays")
                     .with_list_parse_key("connector_request_reference_id_config.merchant_ids_send_payment_id_as_connector_request_id"),
 
             )
@@ -836,6 +1231,7 @@ impl Settings<SecuredSecret> {
         #[cfg(feature = "kv_store")]
         self.drainer.validate()?;
         self.api_keys.get_inner().validate()?;
+        self.card_hash.get_inner().validate()?;
 
         self.file_storage
             .validate()
@@ -865,6 +1261,20 @@ impl Settings<SecuredSecret> {
             .transpose()?;
 
         self.key_manager.get_inner().validate()?;
+        self.connector_log_redaction.validate()?;
+        self.connector_network_retry.validate()?;
+        self.audit_log.validate()?;
+        self.data_retention.validate()?;
+        self.reverse_lookup_reconciliation.validate()?;
+        self.customer_risk.validate()?;
+        self.connector_concurrency.validate()?;
+        self.connector_rate_limit.validate()?;
+        self.token_expiry.validate()?;
+        self.connector_latency_tracking.validate()?;
+        self.settlement_delay.calendar.validate()?;
+        self.webhooks.delivery_scheduling.validate()?;
+        #[cfg(feature = "frm")]
+        self.frm.review_timeout.validate()?;
 
         Ok(())
     }
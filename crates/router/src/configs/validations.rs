@@ -153,6 +153,16 @@ impl super::settings::ApiKeys {
     }
 }
 
+impl super::settings::CardHash {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.salt.peek().is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "card hash salt must not be empty".into(),
+            ))
+        })
+    }
+}
+
 impl super::settings::LockSettings {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -257,3 +267,294 @@ impl super::settings::KeyManagerConfig {
         })
     }
 }
+
+impl super::settings::ConnectorLogRedaction {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.replacement.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector log redaction replacement must not be empty".into(),
+            ))
+        })?;
+
+        self.field_paths.iter().try_for_each(|field_path| {
+            when(field_path.split('.').any(str::is_empty), || {
+                Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "connector log redaction field path `{field_path}` is invalid"
+                )))
+            })
+        })?;
+
+        let uncovered_fields = self.get_uncovered_sensitive_fields();
+        when(!uncovered_fields.is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(format!(
+                "connector log redaction does not cover the sensitive fields: {}",
+                uncovered_fields.join(", ")
+            )))
+        })
+    }
+}
+
+impl super::settings::ConnectorNetworkRetry {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.max_retries > crate::consts::MAX_CONNECTOR_NETWORK_RETRIES,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "connector network retry max_retries must not exceed {}",
+                    crate::consts::MAX_CONNECTOR_NETWORK_RETRIES
+                )))
+            },
+        )?;
+
+        self.idempotency_key_headers.iter().try_for_each(|header| {
+            when(header.is_empty(), || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "connector network retry idempotency key headers must not be empty".into(),
+                ))
+            })
+        })
+    }
+}
+
+impl super::settings::ConnectorLatencyTracking {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(!(0.0..=1.0).contains(&self.sampling_rate), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector latency tracking sampling_rate must be between 0 and 1".into(),
+            ))
+        })?;
+
+        when(self.bucket_duration_in_secs == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector latency tracking bucket_duration_in_secs must be greater than 0".into(),
+            ))
+        })?;
+
+        when(
+            self.retention_in_secs < self.bucket_duration_in_secs,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                "connector latency tracking retention_in_secs must not be less than bucket_duration_in_secs".into(),
+            ))
+            },
+        )
+    }
+}
+
+impl super::settings::WebhookDeliverySchedulingSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.max_concurrent_deliveries == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "webhook delivery scheduling max_concurrent_deliveries must be greater than 0"
+                    .into(),
+            ))
+        })?;
+
+        when(self.failure_threshold == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "webhook delivery scheduling failure_threshold must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.backoff_max < self.backoff_base, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "webhook delivery scheduling backoff_max must not be less than backoff_base".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::DataRetention {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        if !self.enabled {
+            return Ok(());
+        }
+
+        when(self.purge_interval_in_secs == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "data retention purge_interval_in_secs must be greater than 0".into(),
+            ))
+        })?;
+
+        when(
+            self.batch_size == 0 || self.max_batches_per_run == 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "data retention batch_size and max_batches_per_run must be greater than 0"
+                        .into(),
+                ))
+            },
+        )?;
+
+        self.policies.iter().try_for_each(|(entity, policy)| {
+            when(policy.retention_in_days == 0, || {
+                Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "data retention retention_in_days of {entity} must be greater than 0"
+                )))
+            })
+        })
+    }
+}
+
+impl super::settings::CustomerRisk {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.medium_tier_min_score == 0
+                || self.medium_tier_min_score >= self.high_tier_min_score
+                || self.high_tier_min_score > 100,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "customer risk tier scores must satisfy 0 < medium_tier_min_score < high_tier_min_score <= 100".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::ConnectorConcurrency {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.limits.values().any(|limit| *limit == 0), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector concurrency limits must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.lease_ttl_in_secs == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector concurrency lease_ttl_in_secs must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.retry_interval_in_millis == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector concurrency retry_interval_in_millis must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::ConnectorRateLimit {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.default_retry_after_in_secs == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector rate limit default_retry_after_in_secs must be greater than 0".into(),
+            ))
+        })?;
+
+        when(
+            self.max_retry_after_in_secs < self.default_retry_after_in_secs,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "connector rate limit max_retry_after_in_secs must not be less than \
+                     default_retry_after_in_secs"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::TokenExpiry {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            !(crate::consts::MIN_SESSION_EXPIRY..=crate::consts::MAX_SESSION_EXPIRY)
+                .contains(&self.max_session_expiry_in_secs),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "token expiry max_session_expiry_in_secs must be between {} and {}",
+                    crate::consts::MIN_SESSION_EXPIRY,
+                    crate::consts::MAX_SESSION_EXPIRY
+                )))
+            },
+        )?;
+
+        when(self.receipt_token_expiry_in_secs == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "token expiry receipt_token_expiry_in_secs must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::ReverseLookupReconciliation {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        if !self.enabled {
+            return Ok(());
+        }
+
+        when(self.interval_in_secs == 0 || self.scan_count == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "reverse lookup reconciliation interval_in_secs and scan_count must be greater \
+                 than 0"
+                    .into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::AuditLogConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.enabled && self.retention_in_days == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "audit log retention_in_days must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.enabled && self.purge_interval_in_secs == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "audit log purge_interval_in_secs must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+#[cfg(feature = "frm")]
+impl super::settings::FrmReviewTimeout {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.enabled && self.timeout_in_secs == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "frm review timeout_in_secs must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.decision == common_enums::FraudDecision::Review, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "frm review timeout decision must be either approve or decline".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::SettlementCalendar {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.non_working_days.len() >= 7, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "settlement calendar must have at least one working day in the week".into(),
+            ))
+        })
+    }
+}
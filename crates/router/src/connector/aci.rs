@@ -97,6 +97,7 @@ impl ConnectorCommon for Aci {
             }),
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
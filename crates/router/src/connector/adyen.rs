@@ -92,6 +92,7 @@ impl ConnectorCommon for Adyen {
             reason: Some(response.message),
             attempt_status: None,
             connector_transaction_id: response.psp_reference,
+            network_advice_code: None,
         })
     }
 }
@@ -992,6 +993,7 @@ impl
                     status_code: res.status_code,
                     attempt_status: Some(enums::AttemptStatus::Failure),
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 }),
                 ..data.clone()
             })
@@ -105,6 +105,24 @@ pub struct AdditionalData {
     #[cfg(feature = "payouts")]
     payout_eligible: Option<PayoutEligibility>,
     funds_availability: Option<String>,
+    /// The advice of the card network on retrying a refused payment, such as
+    /// `01: New account information available`
+    #[serde(skip_serializing)]
+    merchant_advice_code: Option<String>,
+}
+
+impl AdditionalData {
+    /// Get the code of the advice of the card network, without its description
+    fn get_network_advice_code(&self) -> Option<String> {
+        self.merchant_advice_code.as_ref().and_then(|advice| {
+            advice
+                .split(':')
+                .next()
+                .map(str::trim)
+                .filter(|code| !code.is_empty())
+                .map(ToOwned::to_owned)
+        })
+    }
 }
 
 #[derive(Default, Debug, Serialize, Deserialize)]
@@ -3339,6 +3357,10 @@ pub fn get_adyen_response(
             status_code,
             attempt_status: None,
             connector_transaction_id: Some(response.psp_reference.clone()),
+            network_advice_code: response
+                .additional_data
+                .as_ref()
+                .and_then(AdditionalData::get_network_advice_code),
         })
     } else {
         None
@@ -3402,6 +3424,7 @@ pub fn get_webhook_response(
             status_code,
             attempt_status: None,
             connector_transaction_id: Some(response.transaction_id.clone()),
+            network_advice_code: None,
         })
     } else {
         None
@@ -3468,6 +3491,7 @@ pub fn get_redirection_response(
             status_code,
             attempt_status: None,
             connector_transaction_id: response.psp_reference.clone(),
+            network_advice_code: None,
         })
     } else {
         None
@@ -3540,6 +3564,7 @@ pub fn get_present_to_shopper_response(
             status_code,
             attempt_status: None,
             connector_transaction_id: response.psp_reference.clone(),
+            network_advice_code: None,
         })
     } else {
         None
@@ -3598,6 +3623,7 @@ pub fn get_qr_code_response(
             status_code,
             attempt_status: None,
             connector_transaction_id: response.psp_reference.clone(),
+            network_advice_code: None,
         })
     } else {
         None
@@ -3648,6 +3674,7 @@ pub fn get_redirection_error_response(
         status_code,
         attempt_status: None,
         connector_transaction_id: response.psp_reference.clone(),
+        network_advice_code: None,
     });
     // We don't get connector transaction id for redirections in Adyen.
     let payments_response_data = types::PaymentsResponseData::TransactionResponse {
@@ -5132,6 +5159,7 @@ impl ForeignTryFrom<(&Self, AdyenDisputeResponse)> for types::AcceptDisputeRoute
                     )?,
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 }),
                 ..data.clone()
             })
@@ -5170,6 +5198,7 @@ impl ForeignTryFrom<(&Self, AdyenDisputeResponse)> for types::SubmitEvidenceRout
                     )?,
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 }),
                 ..data.clone()
             })
@@ -5210,6 +5239,7 @@ impl ForeignTryFrom<(&Self, AdyenDisputeResponse)> for types::DefendDisputeRoute
                     )?,
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 }),
                 ..data.clone()
             })
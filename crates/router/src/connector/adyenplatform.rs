@@ -13,6 +13,7 @@ use error_stack::report;
 use error_stack::ResultExt;
 #[cfg(feature = "payouts")]
 use http::HeaderName;
+use hyperswitch_interfaces::webhooks::PayoutReturnDetails;
 #[cfg(feature = "payouts")]
 use masking::Secret;
 #[cfg(feature = "payouts")]
@@ -101,6 +102,7 @@ impl ConnectorCommon for Adyenplatform {
             reason: response.detail,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -425,6 +427,30 @@ impl api::IncomingWebhook for Adyenplatform {
         }
     }
 
+    fn get_payout_return_details(
+        &self,
+        #[cfg(feature = "payouts")] request: &api::IncomingWebhookRequestDetails<'_>,
+        #[cfg(not(feature = "payouts"))] _request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<PayoutReturnDetails, errors::ConnectorError> {
+        #[cfg(feature = "payouts")]
+        {
+            let webhook_body: adyenplatform::AdyenplatformIncomingWebhook = request
+                .body
+                .parse_struct("AdyenplatformIncomingWebhook")
+                .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+
+            // Adyen reports the return reason as a code, such as `accountHierarchyNotActive`
+            Ok(PayoutReturnDetails {
+                return_reason_code: webhook_body.data.reason,
+                return_reason_message: None,
+            })
+        }
+        #[cfg(not(feature = "payouts"))]
+        {
+            Ok(PayoutReturnDetails::default())
+        }
+    }
+
     fn get_webhook_resource_object(
         &self,
         #[cfg(feature = "payouts")] request: &api::IncomingWebhookRequestDetails<'_>,
@@ -361,6 +361,8 @@ pub struct AdyenplatformIncomingWebhookData {
     pub reference: String,
     pub priority: AdyenPayoutPriority,
     pub tracking: Option<AdyenplatformInstantStatus>,
+    /// The reason for which the transfer was returned or failed
+    pub reason: Option<String>,
 }
 
 #[cfg(feature = "payouts")]
@@ -431,7 +433,7 @@ impl
                 | AdyenplatformWebhookStatus::Received => Self::PayoutCreated,
                 AdyenplatformWebhookStatus::Pending => Self::PayoutProcessing,
                 AdyenplatformWebhookStatus::Failed => Self::PayoutFailure,
-                AdyenplatformWebhookStatus::Returned => Self::PayoutReversed,
+                AdyenplatformWebhookStatus::Returned => Self::PayoutReturned,
             },
         }
     }
@@ -101,6 +101,7 @@ impl ConnectorCommon for Airwallex {
             reason: response.source,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -1005,6 +1006,24 @@ impl api::IncomingWebhook for Airwallex {
         Ok(format!("{}{}", timestamp, String::from_utf8_lossy(request.body)).into_bytes())
     }
 
+    fn get_webhook_signed_timestamp(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<i64>, errors::ConnectorError> {
+        request
+            .headers
+            .get("x-timestamp")
+            .map(|header_value| {
+                header_value
+                    .to_str()
+                    .change_context(errors::ConnectorError::WebhookSignatureNotFound)?
+                    .parse::<i64>()
+                    .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+                    .attach_printable("Failed to parse the x-timestamp header")
+            })
+            .transpose()
+    }
+
     fn get_webhook_object_reference_id(
         &self,
         request: &api::IncomingWebhookRequestDetails<'_>,
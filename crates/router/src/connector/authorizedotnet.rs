@@ -1009,6 +1009,7 @@ fn get_error_response(
                     status_code,
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 })
             })
             .unwrap_or_else(|| types::ErrorResponse {
@@ -1018,6 +1019,7 @@ fn get_error_response(
                 status_code,
                 attempt_status: None,
                 connector_transaction_id: None,
+                network_advice_code: None,
             })),
         Some(authorizedotnet::TransactionResponse::AuthorizedotnetTransactionResponseError(_))
         | None => {
@@ -1034,6 +1036,7 @@ fn get_error_response(
                 status_code,
                 attempt_status: None,
                 connector_transaction_id: None,
+                network_advice_code: None,
             })
         }
     }
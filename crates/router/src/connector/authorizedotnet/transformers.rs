@@ -429,6 +429,7 @@ impl<F, T>
                     status_code: item.http_code,
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 });
                 Ok(Self {
                     response,
@@ -1077,6 +1078,7 @@ impl<F, T>
                         status_code: item.http_code,
                         attempt_status: None,
                         connector_transaction_id: Some(transaction_response.transaction_id.clone()),
+                        network_advice_code: None,
                     })
                 });
                 let metadata = transaction_response
@@ -1175,6 +1177,7 @@ impl<F, T>
                         status_code: item.http_code,
                         attempt_status: None,
                         connector_transaction_id: Some(transaction_response.transaction_id.clone()),
+                        network_advice_code: None,
                     })
                 });
                 let metadata = transaction_response
@@ -1322,6 +1325,7 @@ impl<F> TryFrom<types::RefundsResponseRouterData<F, AuthorizedotnetRefundRespons
                 status_code: item.http_code,
                 attempt_status: None,
                 connector_transaction_id: Some(transaction_response.transaction_id.clone()),
+                network_advice_code: None,
             })
         });
 
@@ -1604,6 +1608,7 @@ fn get_err_response(
         status_code,
         attempt_status: None,
         connector_transaction_id: None,
+        network_advice_code: None,
     })
 }
 
@@ -154,6 +154,7 @@ impl ConnectorCommon for Bamboraapac {
                     reason: response_data.declined_message,
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 })
             }
             Err(error_msg) => {
@@ -332,6 +332,7 @@ impl<F>
                     reason: Some(declined_message),
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 }),
                 ..item.data
             })
@@ -486,6 +487,7 @@ impl<F>
                     reason: None,
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 }),
                 ..item.data
             })
@@ -648,6 +650,7 @@ impl<F>
                     reason: Some(declined_message),
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 }),
                 ..item.data
             })
@@ -949,6 +952,7 @@ impl<F>
                     reason: Some(declined_message),
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 }),
                 ..item.data
             })
@@ -261,6 +261,7 @@ impl ConnectorCommon for Bankofamerica {
                     reason,
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 })
             }
             transformers::BankOfAmericaErrorResponse::AuthenticationError(response) => {
@@ -271,6 +272,7 @@ impl ConnectorCommon for Bankofamerica {
                     reason: Some(response.response.rmsg),
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 })
             }
         }
@@ -429,6 +431,7 @@ impl
                 .unwrap_or(consts::NO_ERROR_MESSAGE.to_string()),
             attempt_status,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -553,6 +556,7 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
                 .unwrap_or(consts::NO_ERROR_MESSAGE.to_string()),
             attempt_status,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -747,6 +751,7 @@ impl ConnectorIntegration<api::Capture, types::PaymentsCaptureData, types::Payme
                 .unwrap_or(consts::NO_ERROR_MESSAGE.to_string()),
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -870,6 +875,7 @@ impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsR
                 .unwrap_or(consts::NO_ERROR_MESSAGE.to_string()),
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -1448,6 +1448,7 @@ impl<F, T>
             status_code: item.http_code,
             attempt_status: None,
             connector_transaction_id: Some(error_response.id.clone()),
+            network_advice_code: None,
         });
 
         match transaction_status {
@@ -1472,13 +1473,21 @@ fn get_error_response_if_failure(
     ),
 ) -> Option<types::ErrorResponse> {
     if utils::is_payment_failure(status) {
-        Some(types::ErrorResponse::foreign_from((
-            &info_response.error_information,
-            &info_response.risk_information,
-            Some(status),
-            http_code,
-            info_response.id.clone(),
-        )))
+        let network_advice_code = info_response
+            .processor_information
+            .as_ref()
+            .and_then(|processor_information| processor_information.merchant_advice.as_ref())
+            .and_then(|merchant_advice| merchant_advice.code.clone());
+        Some(types::ErrorResponse {
+            network_advice_code,
+            ..types::ErrorResponse::foreign_from((
+                &info_response.error_information,
+                &info_response.risk_information,
+                Some(status),
+                http_code,
+                info_response.id.clone(),
+            ))
+        })
     } else {
         None
     }
@@ -2279,6 +2288,7 @@ impl
             status_code,
             attempt_status,
             connector_transaction_id: Some(transaction_id.clone()),
+            network_advice_code: None,
         }
     }
 }
@@ -2534,6 +2544,7 @@ impl ForeignFrom<(&BankOfAmericaErrorInformationResponse, u16)> for types::Error
             status_code,
             attempt_status: None,
             connector_transaction_id: Some(error_response.id.clone()),
+            network_advice_code: None,
         }
     }
 }
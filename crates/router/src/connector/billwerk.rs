@@ -126,6 +126,7 @@ impl ConnectorCommon for Billwerk {
             reason: Some(response.error),
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
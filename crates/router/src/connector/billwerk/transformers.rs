@@ -269,6 +269,7 @@ impl<F, T>
                 status_code: item.http_code,
                 attempt_status: None,
                 connector_transaction_id: Some(item.response.handle.clone()),
+                network_advice_code: None,
             })
         } else {
             None
@@ -141,6 +141,7 @@ impl ConnectorCommon for Bluesnap {
                     reason: Some(reason),
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 }
             }
             bluesnap::BluesnapErrors::Auth(error_res) => ErrorResponse {
@@ -150,6 +151,7 @@ impl ConnectorCommon for Bluesnap {
                 reason: Some(error_res.error_description),
                 attempt_status: None,
                 connector_transaction_id: None,
+                network_advice_code: None,
             },
             bluesnap::BluesnapErrors::General(error_response) => {
                 let (error_res, attempt_status) = if res.status_code == 403
@@ -172,6 +174,7 @@ impl ConnectorCommon for Bluesnap {
                     reason: Some(error_res),
                     attempt_status,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 }
             }
         };
@@ -150,6 +150,7 @@ impl ConnectorCommon for Boku {
                     reason: response.reason,
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 })
             }
             Err(_) => get_xml_deserialized(res, event_builder),
@@ -716,6 +717,7 @@ fn get_xml_deserialized(
                 reason: Some(response_data),
                 attempt_status: None,
                 connector_transaction_id: None,
+                network_advice_code: None,
             })
         }
     }
@@ -433,6 +433,7 @@ impl<F>
                         reason: Some(transaction_data.status.to_string().clone()),
                         attempt_status: None,
                         connector_transaction_id: Some(transaction_data.id),
+                        network_advice_code: None,
                         status_code: item.http_code,
                     })
                 } else {
@@ -521,6 +522,7 @@ fn get_error_response<T>(
         status_code: http_code,
         attempt_status: None,
         connector_transaction_id: None,
+        network_advice_code: None,
     })
 }
 
@@ -608,6 +610,7 @@ impl<F>
                         reason: Some(transaction_data.status.to_string().clone()),
                         attempt_status: None,
                         connector_transaction_id: Some(transaction_data.id),
+                        network_advice_code: None,
                         status_code: item.http_code,
                     })
                 } else {
@@ -690,6 +693,7 @@ impl<F>
                         reason: Some(transaction_data.status.to_string().clone()),
                         attempt_status: None,
                         connector_transaction_id: Some(transaction_data.id),
+                        network_advice_code: None,
                         status_code: item.http_code,
                     })
                 } else {
@@ -754,6 +758,7 @@ impl<F>
                         reason: Some(transaction_data.status.to_string().clone()),
                         attempt_status: None,
                         connector_transaction_id: Some(transaction_data.id),
+                        network_advice_code: None,
                         status_code: item.http_code,
                     })
                 } else {
@@ -927,6 +932,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, BraintreeRefundRespo
                             reason: Some(refund_data.status.to_string().clone()),
                             attempt_status: None,
                             connector_transaction_id: Some(refund_data.id),
+                            network_advice_code: None,
                             status_code: item.http_code,
                         })
                     } else {
@@ -1267,6 +1273,7 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<BraintreeCaptureResponse>>
                         reason: Some(transaction_data.status.to_string().clone()),
                         attempt_status: None,
                         connector_transaction_id: Some(transaction_data.id),
+                        network_advice_code: None,
                         status_code: item.http_code,
                     })
                 } else {
@@ -1377,6 +1384,7 @@ impl<F, T>
                         reason: Some(void_data.status.to_string().clone()),
                         attempt_status: None,
                         connector_transaction_id: None,
+                        network_advice_code: None,
                         status_code: item.http_code,
                     })
                 } else {
@@ -1480,6 +1488,7 @@ impl<F, T>
                         reason: Some(edge_data.node.status.to_string().clone()),
                         attempt_status: None,
                         connector_transaction_id: None,
+                        network_advice_code: None,
                         status_code: item.http_code,
                     })
                 } else {
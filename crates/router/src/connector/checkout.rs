@@ -149,6 +149,7 @@ impl ConnectorCommon for Checkout {
                 .or(response.error_type),
             attempt_status: None,
             connector_transaction_id: response.request_id,
+            network_advice_code: None,
         })
     }
 }
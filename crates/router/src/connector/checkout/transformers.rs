@@ -672,6 +672,7 @@ impl TryFrom<types::PaymentsResponseRouterData<PaymentsResponse>>
                 reason: item.response.response_summary,
                 attempt_status: None,
                 connector_transaction_id: Some(item.response.id.clone()),
+                network_advice_code: None,
             })
         } else {
             None
@@ -725,6 +726,7 @@ impl TryFrom<types::PaymentsSyncResponseRouterData<PaymentsResponse>>
                 reason: item.response.response_summary,
                 attempt_status: None,
                 connector_transaction_id: Some(item.response.id.clone()),
+                network_advice_code: None,
             })
         } else {
             None
@@ -9,7 +9,7 @@ use common_enums::FutureUsage;
 use common_utils::{
     ext_traits::{OptionExt, ValueExt},
     pii,
-    types::SemanticVersion,
+    types::{MinorUnit, SemanticVersion, StringMajorUnit, StringMajorUnitForConnector},
 };
 use error_stack::ResultExt;
 use josekit::jwt::decode_header;
@@ -2103,7 +2103,7 @@ pub enum CybersourcePaymentStatus {
     Accepted,
     Cancelled,
     StatusNotReceived,
-    //PartialAuthorized, not being consumed yet.
+    PartialAuthorized,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -2128,6 +2128,9 @@ impl ForeignFrom<(CybersourcePaymentStatus, bool)> for enums::AttemptStatus {
             CybersourcePaymentStatus::Succeeded | CybersourcePaymentStatus::Transmitted => {
                 Self::Charged
             }
+            // Cybersource does not capture a partially authorized payment even in AutoCapture
+            // Payment, the merchant either captures or reverses the authorized amount
+            CybersourcePaymentStatus::PartialAuthorized => Self::PartiallyAuthorized,
             CybersourcePaymentStatus::Voided
             | CybersourcePaymentStatus::Reversed
             | CybersourcePaymentStatus::Cancelled => Self::Voided,
@@ -2168,6 +2171,51 @@ pub struct CybersourcePaymentsResponse {
     risk_information: Option<ClientRiskInformation>,
     token_information: Option<CybersourceTokenInformation>,
     error_information: Option<CybersourceErrorInformation>,
+    order_information: Option<CybersourceResponseOrderInformation>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CybersourceResponseOrderInformation {
+    amount_details: Option<CybersourceResponseAmountDetails>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CybersourceResponseAmountDetails {
+    authorized_amount: Option<StringMajorUnit>,
+    currency: Option<enums::Currency>,
+}
+
+impl CybersourcePaymentsResponse {
+    /// Get the amount authorized by Cybersource, in case of a partial authorization
+    fn get_partially_authorized_amount(
+        &self,
+    ) -> Result<Option<MinorUnit>, error_stack::Report<errors::ConnectorError>> {
+        if !matches!(
+            self.status,
+            Some(CybersourcePaymentStatus::PartialAuthorized)
+        ) {
+            return Ok(None);
+        }
+        self.order_information
+            .as_ref()
+            .and_then(|order_information| order_information.amount_details.as_ref())
+            .and_then(|amount_details| {
+                amount_details
+                    .authorized_amount
+                    .clone()
+                    .zip(amount_details.currency)
+            })
+            .map(|(authorized_amount, currency)| {
+                utils::convert_back_amount_to_minor_units(
+                    &StringMajorUnitForConnector,
+                    authorized_amount,
+                    currency,
+                )
+            })
+            .transpose()
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -2219,6 +2267,14 @@ pub struct ClientProcessorInformation {
     network_transaction_id: Option<String>,
     avs: Option<Avs>,
     card_verification: Option<CardVerification>,
+    merchant_advice: Option<MerchantAdvice>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MerchantAdvice {
+    code: Option<String>,
+    code_raw: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -2311,6 +2367,7 @@ impl<F, T>
             status_code: item.http_code,
             attempt_status: None,
             connector_transaction_id: Some(error_response.id.clone()),
+            network_advice_code: None,
         });
         match transaction_status {
             Some(status) => Self {
@@ -2330,13 +2387,21 @@ fn get_error_response_if_failure(
     (info_response, status, http_code): (&CybersourcePaymentsResponse, enums::AttemptStatus, u16),
 ) -> Option<types::ErrorResponse> {
     if utils::is_payment_failure(status) {
-        Some(types::ErrorResponse::foreign_from((
-            &info_response.error_information,
-            &info_response.risk_information,
-            Some(status),
-            http_code,
-            info_response.id.clone(),
-        )))
+        let network_advice_code = info_response
+            .processor_information
+            .as_ref()
+            .and_then(|processor_information| processor_information.merchant_advice.as_ref())
+            .and_then(|merchant_advice| merchant_advice.code.clone());
+        Some(types::ErrorResponse {
+            network_advice_code,
+            ..types::ErrorResponse::foreign_from((
+                &info_response.error_information,
+                &info_response.risk_information,
+                Some(status),
+                http_code,
+                info_response.id.clone(),
+            ))
+        })
     } else {
         None
     }
@@ -2413,12 +2478,15 @@ impl
             item.data.request.is_auto_capture()?,
         ));
         let response = get_payment_response((&item.response, status, item.http_code));
+        let approved_amount = item.response.get_partially_authorized_amount()?;
         let connector_response = item
             .response
             .processor_information
             .as_ref()
             .map(types::AdditionalPaymentMethodConnectorResponse::from)
-            .map(types::ConnectorResponseData::with_additional_payment_method_data);
+            .map(types::ConnectorResponseData::with_additional_payment_method_data)
+            .map(|connector_response| connector_response.set_approved_amount(approved_amount))
+            .or(approved_amount.map(types::ConnectorResponseData::with_approved_amount));
 
         Ok(Self {
             status,
@@ -2508,6 +2576,7 @@ impl<F>
                         status_code: item.http_code,
                         attempt_status: None,
                         connector_transaction_id: Some(error_response.id.clone()),
+                        network_advice_code: None,
                     }),
                     status: enums::AttemptStatus::AuthenticationFailed,
                     ..item.data
@@ -2913,6 +2982,7 @@ impl<F>
                     status_code: item.http_code,
                     attempt_status: None,
                     connector_transaction_id: Some(error_response.id.clone()),
+                    network_advice_code: None,
                 });
                 Ok(Self {
                     response,
@@ -2951,12 +3021,15 @@ impl<F>
             item.data.request.is_auto_capture()?,
         ));
         let response = get_payment_response((&item.response, status, item.http_code));
+        let approved_amount = item.response.get_partially_authorized_amount()?;
         let connector_response = item
             .response
             .processor_information
             .as_ref()
             .map(types::AdditionalPaymentMethodConnectorResponse::from)
-            .map(types::ConnectorResponseData::with_additional_payment_method_data);
+            .map(types::ConnectorResponseData::with_additional_payment_method_data)
+            .map(|connector_response| connector_response.set_approved_amount(approved_amount))
+            .or(approved_amount.map(types::ConnectorResponseData::with_approved_amount));
 
         Ok(Self {
             status,
@@ -3808,6 +3881,7 @@ impl
             status_code,
             attempt_status,
             connector_transaction_id: Some(transaction_id.clone()),
+            network_advice_code: None,
         }
     }
 }
@@ -3854,3 +3928,63 @@ fn get_cybersource_card_type(card_network: common_enums::CardNetwork) -> Option<
         common_enums::CardNetwork::Interac | common_enums::CardNetwork::RuPay => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_payments_response(status: &str, authorized_amount: &str) -> CybersourcePaymentsResponse {
+        serde_json::from_value(serde_json::json!({
+            "id": "7171234567890123456789",
+            "status": status,
+            "orderInformation": {
+                "amountDetails": {
+                    "authorizedAmount": authorized_amount,
+                    "currency": "USD"
+                }
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_partially_authorized_amount_is_converted_to_minor_units() {
+        let response = get_payments_response("PARTIAL_AUTHORIZED", "12.50");
+
+        assert_eq!(
+            response.get_partially_authorized_amount().unwrap(),
+            Some(MinorUnit::new(1250))
+        );
+    }
+
+    #[test]
+    fn test_authorized_amount_of_a_full_authorization_is_not_a_partial_approval() {
+        let response = get_payments_response("AUTHORIZED", "12.50");
+
+        assert_eq!(response.get_partially_authorized_amount().unwrap(), None);
+    }
+
+    #[test]
+    fn test_partial_authorization_without_the_authorized_amount_has_no_approved_amount() {
+        let response: CybersourcePaymentsResponse = serde_json::from_value(serde_json::json!({
+            "id": "7171234567890123456789",
+            "status": "PARTIAL_AUTHORIZED",
+            "orderInformation": {
+                "amountDetails": {
+                    "currency": "USD"
+                }
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(response.get_partially_authorized_amount().unwrap(), None);
+    }
+
+    #[test]
+    fn test_invalid_partially_authorized_amount_is_rejected() {
+        let response = get_payments_response("PARTIAL_AUTHORIZED", "twelve");
+
+        assert!(response.get_partially_authorized_amount().is_err());
+    }
+}
@@ -131,6 +131,7 @@ impl ConnectorCommon for Datatrans {
             reason: Some(response.error.message.clone()),
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
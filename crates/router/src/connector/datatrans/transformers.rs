@@ -285,6 +285,7 @@ impl<F>
                 reason: Some(error.message.clone()),
                 attempt_status: None,
                 connector_transaction_id: None,
+                network_advice_code: None,
                 status_code: item.http_code,
             }),
             DatatransResponse::TransactionResponse(response) => {
@@ -338,6 +339,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, DatatransRefundsResp
                     reason: Some(error.message),
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                     status_code: item.http_code,
                 }),
                 ..item.data
@@ -367,6 +369,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, DatatransSyncResponse>
                 reason: Some(error.message),
                 attempt_status: None,
                 connector_transaction_id: None,
+                network_advice_code: None,
                 status_code: item.http_code,
             }),
             DatatransSyncResponse::Response(response) => Ok(types::RefundsResponseData {
@@ -396,6 +399,7 @@ impl TryFrom<types::PaymentsSyncResponseRouterData<DatatransSyncResponse>>
                     reason: Some(error.message),
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                     status_code: item.http_code,
                 });
                 Ok(Self {
@@ -119,6 +119,7 @@ impl<const T: u8> ConnectorCommon for DummyConnector<T> {
             reason: response.error.reason,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
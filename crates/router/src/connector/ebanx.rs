@@ -124,6 +124,7 @@ impl ConnectorCommon for Ebanx {
             reason: response.message,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -149,6 +149,7 @@ impl ConnectorCommon for Forte {
             reason: None,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -130,10 +130,20 @@ impl ConnectorCommon for Gocardless {
             reason: Some(error_reason.join("; ")),
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
 
+impl
+    ConnectorIntegration<
+        api::UpdateConnectorCustomer,
+        types::ConnectorCustomerUpdateData,
+        types::PaymentsResponseData,
+    > for Gocardless
+{
+}
+
 impl
     ConnectorIntegration<
         api::CreateConnectorCustomer,
@@ -123,6 +123,7 @@ impl ConnectorCommon for Gpayments {
             reason: response.error_detail,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
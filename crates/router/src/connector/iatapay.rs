@@ -137,6 +137,7 @@ impl ConnectorCommon for Iatapay {
                 reason: Some(consts::CONNECTOR_UNAUTHORIZED_ERROR.to_string()),
                 attempt_status: None,
                 connector_transaction_id: None,
+                network_advice_code: None,
             }
         } else {
             let response: iatapay::IatapayErrorResponse = res
@@ -155,6 +156,7 @@ impl ConnectorCommon for Iatapay {
                 reason: response.reason,
                 attempt_status: None,
                 connector_transaction_id: None,
+                network_advice_code: None,
             }
         };
         Ok(response_error_message)
@@ -276,6 +278,7 @@ impl ConnectorIntegration<api::AccessTokenAuth, types::AccessTokenRequestData, t
             reason: None,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
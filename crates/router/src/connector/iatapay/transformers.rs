@@ -340,6 +340,7 @@ fn get_iatpay_response(
             status_code,
             attempt_status: Some(status),
             connector_transaction_id: response.iata_payment_id.clone(),
+            network_advice_code: None,
         })
     } else {
         None
@@ -529,6 +530,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
                 status_code: item.http_code,
                 attempt_status: None,
                 connector_transaction_id: Some(item.response.iata_refund_id.clone()),
+                network_advice_code: None,
             })
         } else {
             Ok(types::RefundsResponseData {
@@ -567,6 +569,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
                 status_code: item.http_code,
                 attempt_status: None,
                 connector_transaction_id: Some(item.response.iata_refund_id.clone()),
+                network_advice_code: None,
             })
         } else {
             Ok(types::RefundsResponseData {
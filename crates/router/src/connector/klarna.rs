@@ -93,6 +93,7 @@ impl ConnectorCommon for Klarna {
             reason,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -531,6 +532,7 @@ impl
                     )),
                     (
                         common_enums::PaymentExperience::DisplayQrCode
+                        | common_enums::PaymentExperience::DisplayVoucher
                         | common_enums::PaymentExperience::DisplayWaitScreen
                         | common_enums::PaymentExperience::InvokePaymentApp
                         | common_enums::PaymentExperience::InvokeSdkClient
@@ -131,6 +131,7 @@ impl ConnectorCommon for Mifinity {
                 reason: Some(consts::CONNECTOR_UNAUTHORIZED_ERROR.to_string()),
                 attempt_status: None,
                 connector_transaction_id: None,
+                network_advice_code: None,
             })
         } else {
             let response: Result<
@@ -166,6 +167,7 @@ impl ConnectorCommon for Mifinity {
                         ),
                         attempt_status: None,
                         connector_transaction_id: None,
+                        network_advice_code: None,
                     })
                 }
 
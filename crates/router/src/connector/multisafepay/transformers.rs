@@ -1117,6 +1117,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, MultisafepayRefundRe
                         status_code: item.http_code,
                         attempt_status,
                         connector_transaction_id: None,
+                        network_advice_code: None,
                     }),
                     ..item.data
                 })
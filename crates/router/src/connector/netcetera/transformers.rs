@@ -110,6 +110,7 @@ impl
                     status_code: item.http_code,
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 })
             }
         };
@@ -179,6 +180,7 @@ impl
                 status_code: item.http_code,
                 attempt_status: None,
                 connector_transaction_id: None,
+                network_advice_code: None,
             }),
         };
         Ok(Self {
@@ -138,6 +138,7 @@ impl ConnectorCommon for Nexinets {
             reason: Some(connector_reason),
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -103,6 +103,7 @@ impl ConnectorCommon for Nmi {
             code: response.response_code,
             attempt_status: None,
             connector_transaction_id: Some(response.transactionid),
+            network_advice_code: None,
         })
     }
 }
@@ -224,6 +224,7 @@ impl
                     status_code: item.http_code,
                     attempt_status: None,
                     connector_transaction_id: Some(item.response.transactionid),
+                    network_advice_code: None,
                 }),
                 enums::AttemptStatus::Failure,
             ),
@@ -401,6 +402,7 @@ impl ForeignFrom<(NmiCompleteResponse, u16)> for types::ErrorResponse {
             status_code: http_code,
             attempt_status: None,
             connector_transaction_id: Some(response.transactionid),
+            network_advice_code: None,
         }
     }
 }
@@ -871,6 +873,7 @@ impl ForeignFrom<(StandardResponse, u16)> for types::ErrorResponse {
             status_code: http_code,
             attempt_status: None,
             connector_transaction_id: Some(response.transactionid),
+            network_advice_code: None,
         }
     }
 }
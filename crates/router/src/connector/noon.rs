@@ -164,6 +164,7 @@ impl ConnectorCommon for Noon {
                     reason: Some(noon_error_response.message),
                     attempt_status,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 })
             }
             Err(error_message) => {
@@ -586,6 +586,7 @@ impl<F, T>
                     status_code: item.http_code,
                     attempt_status: Some(status),
                     connector_transaction_id: Some(order.id.to_string()),
+                    network_advice_code: None,
                 }),
                 _ => {
                     let connector_response_reference_id =
@@ -822,6 +823,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
                 reason: Some(response.message.clone()),
                 attempt_status: None,
                 connector_transaction_id: Some(response.result.transaction.id.clone()),
+                network_advice_code: None,
             })
         } else {
             Ok(types::RefundsResponseData {
@@ -890,6 +892,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundSyncResponse>>
                 reason: Some(response.message.clone()),
                 attempt_status: None,
                 connector_transaction_id: Some(noon_transaction.id.clone()),
+                network_advice_code: None,
             })
         } else {
             Ok(types::RefundsResponseData {
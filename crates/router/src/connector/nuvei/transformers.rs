@@ -1794,6 +1794,7 @@ fn get_error_response<T>(
         status_code: http_code,
         attempt_status: None,
         connector_transaction_id: None,
+        network_advice_code: None,
     })
 }
 
@@ -115,6 +115,7 @@ impl ConnectorCommon for Opayo {
             reason: response.reason,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -117,6 +117,7 @@ impl ConnectorCommon for Opennode {
             reason: None,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -133,6 +133,7 @@ impl ConnectorCommon for Paybox {
             reason: response.reason,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
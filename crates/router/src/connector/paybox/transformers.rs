@@ -618,6 +618,7 @@ impl<F, T>
                     status_code: item.http_code,
                     attempt_status: None,
                     connector_transaction_id: Some(item.response.transaction_number),
+                    network_advice_code: None,
                 }),
                 ..item.data
             }),
@@ -677,6 +678,7 @@ impl<F>
                             status_code: item.http_code,
                             attempt_status: None,
                             connector_transaction_id: Some(response.transaction_number),
+                            network_advice_code: None,
                         }),
                         ..item.data
                     }),
@@ -706,6 +708,7 @@ impl<F>
                     status_code: item.http_code,
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 }),
                 ..item.data
             }),
@@ -751,6 +754,7 @@ impl<F, T> TryFrom<types::ResponseRouterData<F, PayboxSyncResponse, T, types::Pa
                     status_code: item.http_code,
                     attempt_status: None,
                     connector_transaction_id: Some(item.response.transaction_number),
+                    network_advice_code: None,
                 }),
                 ..item.data
             }),
@@ -840,6 +844,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, PayboxSyncResponse>>
                     status_code: item.http_code,
                     attempt_status: None,
                     connector_transaction_id: Some(item.response.transaction_number),
+                    network_advice_code: None,
                 }),
                 ..item.data
             }),
@@ -871,6 +876,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, TransactionResponse>
                     status_code: item.http_code,
                     attempt_status: None,
                     connector_transaction_id: Some(item.response.transaction_number),
+                    network_advice_code: None,
                 }),
                 ..item.data
             }),
@@ -936,6 +942,7 @@ impl<F>
                     status_code: item.http_code,
                     attempt_status: None,
                     connector_transaction_id: Some(response.transaction_number),
+                    network_advice_code: None,
                 }),
                 ..item.data
             }),
@@ -126,6 +126,7 @@ impl ConnectorCommon for Payme {
                     )),
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 })
             }
             Err(error_msg) => {
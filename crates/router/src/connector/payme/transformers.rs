@@ -226,6 +226,7 @@ impl ForeignFrom<(&PaymePaySaleResponse, u16)> for types::ErrorResponse {
             status_code: http_code,
             attempt_status: None,
             connector_transaction_id: Some(pay_sale_response.payme_sale_id.clone()),
+            network_advice_code: None,
         }
     }
 }
@@ -308,6 +309,7 @@ impl ForeignFrom<(&SaleQuery, u16)> for types::ErrorResponse {
             status_code: http_code,
             attempt_status: None,
             connector_transaction_id: Some(sale_query_response.sale_payme_id.clone()),
+            network_advice_code: None,
         }
     }
 }
@@ -1016,6 +1018,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, PaymeRefundResponse>
                 status_code: item.http_code,
                 attempt_status: None,
                 connector_transaction_id: payme_response.payme_transaction_id.clone(),
+                network_advice_code: None,
             })
         } else {
             Ok(types::RefundsResponseData {
@@ -1095,6 +1098,7 @@ impl TryFrom<types::PaymentsCancelResponseRouterData<PaymeVoidResponse>>
                 status_code: item.http_code,
                 attempt_status: None,
                 connector_transaction_id: payme_response.payme_transaction_id.clone(),
+                network_advice_code: None,
             })
         } else {
             // Since we are not receiving payme_sale_id, we are not populating the transaction response
@@ -1156,6 +1160,7 @@ impl<F, T>
                 status_code: item.http_code,
                 attempt_status: None,
                 connector_transaction_id: Some(pay_sale_response.payme_transaction_id.clone()),
+                network_advice_code: None,
             })
         } else {
             Ok(types::RefundsResponseData {
@@ -210,6 +210,7 @@ impl ConnectorCommon for Payone {
                 ),
                 attempt_status: None,
                 connector_transaction_id: None,
+                network_advice_code: None,
             }),
             None => Ok(ErrorResponse {
                 status_code: res.status_code,
@@ -218,6 +219,7 @@ impl ConnectorCommon for Payone {
                 reason: None,
                 attempt_status: None,
                 connector_transaction_id: None,
+                network_advice_code: None,
             }),
         }
     }
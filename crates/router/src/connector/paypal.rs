@@ -137,6 +137,7 @@ impl Paypal {
             reason: error_reason.or(Some(response.message)),
             attempt_status: None,
             connector_transaction_id: response.debug_id,
+            network_advice_code: None,
         })
     }
 }
@@ -309,6 +310,7 @@ impl ConnectorCommon for Paypal {
             reason,
             attempt_status: None,
             connector_transaction_id: response.debug_id,
+            network_advice_code: None,
         })
     }
 }
@@ -445,6 +447,7 @@ impl ConnectorIntegration<api::AccessTokenAuth, types::AccessTokenRequestData, t
             reason: Some(response.error_description),
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -683,7 +686,33 @@ impl ConnectorIntegration<api::Authorize, types::PaymentsAuthorizeData, types::P
             req.request.currency,
         )?;
         let connector_router_data = paypal::PaypalRouterData::try_from((amount, req))?;
-        let connector_req = paypal::PaypalPaymentsRequest::try_from(&connector_router_data)?;
+        // The breakdown cannot be sent when a surcharge is added to the amount, as the breakdown
+        // would no longer add up to the amount
+        let amount_breakdown = req
+            .request
+            .amount_breakdown
+            .as_ref()
+            .filter(|amount_breakdown| amount_breakdown.get_total() == req.request.minor_amount)
+            .map(|amount_breakdown| {
+                let convert = |amount| {
+                    connector_utils::convert_amount(
+                        self.amount_converter,
+                        amount,
+                        req.request.currency,
+                    )
+                };
+                Ok::<_, error_stack::Report<errors::ConnectorError>>(
+                    paypal::PaypalAmountBreakdown {
+                        item_total: convert(amount_breakdown.subtotal)?,
+                        tax_total: convert(amount_breakdown.tax_amount)?,
+                        shipping: amount_breakdown.shipping_amount.map(convert).transpose()?,
+                        discount: amount_breakdown.discount_amount.map(convert).transpose()?,
+                    },
+                )
+            })
+            .transpose()?;
+        let connector_req =
+            paypal::PaypalPaymentsRequest::try_from((&connector_router_data, amount_breakdown))?;
         Ok(RequestContent::Json(Box::new(connector_req)))
     }
 
@@ -890,6 +919,7 @@ impl
                             code: consts::NO_ERROR_CODE.to_string(),
                             message: consts::NO_ERROR_MESSAGE.to_string(),
                             connector_transaction_id: None,
+                            network_advice_code: None,
                             reason: Some(format!("{} Connector Responsded with LiabilityShift: {:?}, EnrollmentStatus: {:?}, and AuthenticationStatus: {:?}",
                             consts::CANNOT_CONTINUE_AUTH,
                             liability_response
@@ -76,17 +76,50 @@ pub struct OrderRequestAmount {
     pub breakdown: AmountBreakdown,
 }
 
-impl From<&PaypalRouterData<&types::PaymentsAuthorizeRouterData>> for OrderRequestAmount {
-    fn from(item: &PaypalRouterData<&types::PaymentsAuthorizeRouterData>) -> Self {
+/// Amounts of the breakdown of the payment amount, converted to the unit expected by paypal
+#[derive(Debug, Clone)]
+pub struct PaypalAmountBreakdown {
+    pub item_total: StringMajorUnit,
+    pub tax_total: StringMajorUnit,
+    pub shipping: Option<StringMajorUnit>,
+    pub discount: Option<StringMajorUnit>,
+}
+
+impl
+    From<(
+        &PaypalRouterData<&types::PaymentsAuthorizeRouterData>,
+        Option<&PaypalAmountBreakdown>,
+    )> for OrderRequestAmount
+{
+    fn from(
+        (item, amount_breakdown): (
+            &PaypalRouterData<&types::PaymentsAuthorizeRouterData>,
+            Option<&PaypalAmountBreakdown>,
+        ),
+    ) -> Self {
+        let currency_code = item.router_data.request.currency;
+        let order_amount = |value: StringMajorUnit| OrderAmount {
+            currency_code,
+            value,
+        };
+        let breakdown = match amount_breakdown {
+            Some(amount_breakdown) => AmountBreakdown {
+                item_total: order_amount(amount_breakdown.item_total.clone()),
+                tax_total: Some(order_amount(amount_breakdown.tax_total.clone())),
+                shipping: amount_breakdown.shipping.clone().map(order_amount),
+                discount: amount_breakdown.discount.clone().map(order_amount),
+            },
+            None => AmountBreakdown {
+                item_total: order_amount(item.amount.clone()),
+                tax_total: None,
+                shipping: None,
+                discount: None,
+            },
+        };
         Self {
-            currency_code: item.router_data.request.currency,
+            currency_code,
             value: item.amount.clone(),
-            breakdown: AmountBreakdown {
-                item_total: OrderAmount {
-                    currency_code: item.router_data.request.currency,
-                    value: item.amount.clone(),
-                },
-            },
+            breakdown,
         }
     }
 }
@@ -94,6 +127,12 @@ impl From<&PaypalRouterData<&types::PaymentsAuthorizeRouterData>> for OrderReque
 #[derive(Default, Debug, Serialize, Deserialize, Eq, PartialEq)]
 pub struct AmountBreakdown {
     item_total: OrderAmount,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tax_total: Option<OrderAmount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shipping: Option<OrderAmount>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discount: Option<OrderAmount>,
 }
 
 #[derive(Default, Debug, Serialize, Eq, PartialEq)]
@@ -120,8 +159,22 @@ pub struct ItemDetails {
     unit_amount: OrderAmount,
 }
 
-impl From<&PaypalRouterData<&types::PaymentsAuthorizeRouterData>> for ItemDetails {
-    fn from(item: &PaypalRouterData<&types::PaymentsAuthorizeRouterData>) -> Self {
+impl
+    From<(
+        &PaypalRouterData<&types::PaymentsAuthorizeRouterData>,
+        Option<&PaypalAmountBreakdown>,
+    )> for ItemDetails
+{
+    fn from(
+        (item, amount_breakdown): (
+            &PaypalRouterData<&types::PaymentsAuthorizeRouterData>,
+            Option<&PaypalAmountBreakdown>,
+        ),
+    ) -> Self {
+        // The amount of the items has to add up to the item total of the breakdown
+        let value = amount_breakdown
+            .map(|amount_breakdown| amount_breakdown.item_total.clone())
+            .unwrap_or_else(|| item.amount.clone());
         Self {
             name: format!(
                 "Payment for invoice {}",
@@ -130,7 +183,7 @@ impl From<&PaypalRouterData<&types::PaymentsAuthorizeRouterData>> for ItemDetail
             quantity: 1,
             unit_amount: OrderAmount {
                 currency_code: item.router_data.request.currency,
-                value: item.amount.clone(),
+                value,
             },
         }
     }
@@ -366,16 +419,24 @@ fn get_payee(auth_type: &PaypalAuthType) -> Option<Payee> {
         })
 }
 
-impl TryFrom<&PaypalRouterData<&types::PaymentsAuthorizeRouterData>> for PaypalPaymentsRequest {
+impl
+    TryFrom<(
+        &PaypalRouterData<&types::PaymentsAuthorizeRouterData>,
+        Option<PaypalAmountBreakdown>,
+    )> for PaypalPaymentsRequest
+{
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
-        item: &PaypalRouterData<&types::PaymentsAuthorizeRouterData>,
+        (item, amount_breakdown): (
+            &PaypalRouterData<&types::PaymentsAuthorizeRouterData>,
+            Option<PaypalAmountBreakdown>,
+        ),
     ) -> Result<Self, Self::Error> {
         let paypal_auth: PaypalAuthType =
             PaypalAuthType::try_from(&item.router_data.connector_auth_type)?;
         let payee = get_payee(&paypal_auth);
 
-        let amount = OrderRequestAmount::from(item);
+        let amount = OrderRequestAmount::from((item, amount_breakdown.as_ref()));
 
         let intent = if item.router_data.request.is_auto_capture()? {
             PaypalPaymentIntent::Capture
@@ -387,7 +448,7 @@ impl TryFrom<&PaypalRouterData<&types::PaymentsAuthorizeRouterData>> for PaypalP
             item.router_data.connector_request_reference_id.clone();
 
         let shipping_address = ShippingAddress::from(item);
-        let item_details = vec![ItemDetails::from(item)];
+        let item_details = vec![ItemDetails::from((item, amount_breakdown.as_ref()))];
 
         let purchase_units = vec![PurchaseUnitRequest {
             reference_id: Some(connector_request_reference_id.clone()),
@@ -1776,6 +1837,7 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<PaypalCaptureResponse>>
         let amount_captured = match status {
             storage_enums::AttemptStatus::Pending
             | storage_enums::AttemptStatus::Authorized
+            | storage_enums::AttemptStatus::PartiallyAuthorized
             | storage_enums::AttemptStatus::Failure
             | storage_enums::AttemptStatus::RouterDeclined
             | storage_enums::AttemptStatus::AuthenticationFailed
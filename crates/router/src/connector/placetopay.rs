@@ -118,6 +118,7 @@ impl ConnectorCommon for Placetopay {
             reason: Some(response.status.message),
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -312,6 +312,7 @@ impl<F, T>
                     status_code: item.http_code,
                     attempt_status: None,
                     connector_transaction_id: Some(item.response.payment_id),
+                    network_advice_code: None,
                 })
             } else {
                 Ok(types::PaymentsResponseData::TransactionResponse {
@@ -398,6 +399,7 @@ impl<F, T> TryFrom<types::ResponseRouterData<F, PlaidSyncResponse, T, types::Pay
                     status_code: item.http_code,
                     attempt_status: None,
                     connector_transaction_id: Some(item.response.payment_id),
+                    network_advice_code: None,
                 })
             } else {
                 Ok(types::PaymentsResponseData::TransactionResponse {
@@ -125,6 +125,7 @@ impl ConnectorCommon for Prophetpay {
             reason: Some(response.to_string()),
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -109,6 +109,7 @@ impl ConnectorCommon for Rapyd {
                     reason: response_data.status.message,
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 })
             }
             Err(error_msg) => {
@@ -451,6 +451,7 @@ impl<F, T>
                             reason: data.failure_message.to_owned(),
                             attempt_status: None,
                             connector_transaction_id: None,
+                            network_advice_code: None,
                         }),
                     ),
                     _ => {
@@ -495,6 +496,7 @@ impl<F, T>
                     reason: item.response.status.message,
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 }),
             ),
         };
@@ -139,6 +139,7 @@ impl ConnectorCommon for Razorpay {
                             ),
                             attempt_status: None,
                             connector_transaction_id: None,
+                            network_advice_code: None,
                         })
                     }
                     razorpay::ErrorResponse::RazorpayStringError(error_string) => {
@@ -149,6 +150,7 @@ impl ConnectorCommon for Razorpay {
                             reason: Some(error_string.clone()),
                             attempt_status: None,
                             connector_transaction_id: None,
+                            network_advice_code: None,
                         })
                     }
                 }
@@ -815,6 +815,7 @@ impl<F, T>
                         status_code: item.http_code,
                         attempt_status: None,
                         connector_transaction_id: None,
+                        network_advice_code: None,
                     }),
                     ..item.data
                 })
@@ -1275,6 +1276,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
                 status_code: item.http_code,
                 attempt_status: None,
                 connector_transaction_id: Some(item.response.refund.unique_request_id.clone()),
+                network_advice_code: None,
             }),
         };
         Ok(Self {
@@ -136,6 +136,7 @@ impl ConnectorCommon for Riskified {
             message: response.error.message.clone(),
             reason: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
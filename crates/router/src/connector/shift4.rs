@@ -102,6 +102,7 @@ impl ConnectorCommon for Shift4 {
             reason: None,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -108,6 +108,7 @@ impl ConnectorCommon for Signifyd {
             reason: Some(response.errors.to_string()),
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
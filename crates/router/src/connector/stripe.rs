@@ -128,6 +128,7 @@ impl ConnectorCommon for Stripe {
             reason: response.error.message,
             attempt_status: None,
             connector_transaction_id: response.error.payment_intent.map(|pi| pi.id),
+            network_advice_code: None,
         })
     }
 }
@@ -327,6 +328,7 @@ impl
             }),
             attempt_status: None,
             connector_transaction_id: response.error.payment_intent.map(|pi| pi.id),
+            network_advice_code: response.error.network_advice_code,
         })
     }
 }
@@ -459,6 +461,142 @@ impl
             }),
             attempt_status: None,
             connector_transaction_id: response.error.payment_intent.map(|pi| pi.id),
+            network_advice_code: response.error.network_advice_code,
+        })
+    }
+}
+
+impl
+    services::ConnectorIntegration<
+        api::UpdateConnectorCustomer,
+        types::ConnectorCustomerUpdateData,
+        types::PaymentsResponseData,
+    > for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::ConnectorCustomerUpdateRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::ConnectorCustomerUpdateType::get_content_type(self)
+                .to_string()
+                .into(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::ConnectorCustomerUpdateRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}v1/customers/{}",
+            self.base_url(connectors),
+            req.request.connector_customer_id
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::ConnectorCustomerUpdateRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let connector_req = stripe::CustomerUpdateRequest::try_from(req)?;
+        Ok(RequestContent::FormUrlEncoded(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::ConnectorCustomerUpdateRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::ConnectorCustomerUpdateType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(types::ConnectorCustomerUpdateType::get_headers(
+                    self, req, connectors,
+                )?)
+                .set_body(types::ConnectorCustomerUpdateType::get_request_body(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::ConnectorCustomerUpdateRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::ConnectorCustomerUpdateRouterData, errors::ConnectorError>
+    where
+        types::PaymentsResponseData: Clone,
+    {
+        let response: stripe::StripeCustomerResponse = res
+            .response
+            .parse_struct("StripeCustomerResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        let response: stripe::ErrorResponse = res
+            .response
+            .parse_struct("ErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        event_builder.map(|i| i.set_error_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+
+        Ok(types::ErrorResponse {
+            status_code: res.status_code,
+            code: response
+                .error
+                .code
+                .clone()
+                .unwrap_or_else(|| consts::NO_ERROR_CODE.to_string()),
+            message: response
+                .error
+                .code
+                .unwrap_or_else(|| consts::NO_ERROR_MESSAGE.to_string()),
+            reason: response.error.message.map(|message| {
+                response
+                    .error
+                    .decline_code
+                    .map(|decline_code| {
+                        format!("message - {}, decline_code - {}", message, decline_code)
+                    })
+                    .unwrap_or(message)
+            }),
+            attempt_status: None,
+            connector_transaction_id: response.error.payment_intent.map(|pi| pi.id),
+            network_advice_code: response.error.network_advice_code,
         })
     }
 }
@@ -587,6 +725,7 @@ impl
             }),
             attempt_status: None,
             connector_transaction_id: response.error.payment_intent.map(|pi| pi.id),
+            network_advice_code: response.error.network_advice_code,
         })
     }
 }
@@ -740,6 +879,7 @@ impl
             }),
             attempt_status: None,
             connector_transaction_id: response.error.payment_intent.map(|pi| pi.id),
+            network_advice_code: response.error.network_advice_code,
         })
     }
 }
@@ -794,7 +934,9 @@ impl
                 self.base_url(connectors),
                 "v1/payment_intents",
                 x,
-                "?expand[0]=latest_charge" //updated payment_id(if present) reside inside latest_charge field
+                // updated payment_id(if present) reside inside latest_charge field, while the
+                // settlement date of the charge resides inside its balance transaction
+                "?expand[0]=latest_charge&expand[1]=latest_charge.balance_transaction"
             )),
             x => x.change_context(errors::ConnectorError::MissingConnectorTransactionID),
         }
@@ -906,6 +1048,7 @@ impl
             }),
             attempt_status: None,
             connector_transaction_id: response.error.payment_intent.map(|pi| pi.id),
+            network_advice_code: response.error.network_advice_code,
         })
     }
 }
@@ -1153,6 +1296,7 @@ impl
             }),
             attempt_status: None,
             connector_transaction_id: response.error.payment_intent.map(|pi| pi.id),
+            network_advice_code: response.error.network_advice_code,
         })
     }
 }
@@ -1280,6 +1424,7 @@ impl
             }),
             attempt_status: None,
             connector_transaction_id: response.error.payment_intent.map(|pi| pi.id),
+            network_advice_code: response.error.network_advice_code,
         })
     }
 }
@@ -1440,6 +1585,7 @@ impl
             }),
             attempt_status: None,
             connector_transaction_id: response.error.payment_intent.map(|pi| pi.id),
+            network_advice_code: response.error.network_advice_code,
         })
     }
 }
@@ -1605,6 +1751,7 @@ impl services::ConnectorIntegration<api::Execute, types::RefundsData, types::Ref
             }),
             attempt_status: None,
             connector_transaction_id: response.error.payment_intent.map(|pi| pi.id),
+            network_advice_code: response.error.network_advice_code,
         })
     }
 }
@@ -1736,6 +1883,7 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
             }),
             attempt_status: None,
             connector_transaction_id: response.error.payment_intent.map(|pi| pi.id),
+            network_advice_code: response.error.network_advice_code,
         })
     }
 }
@@ -1890,6 +2038,7 @@ impl
             }),
             attempt_status: None,
             connector_transaction_id: response.error.payment_intent.map(|pi| pi.id),
+            network_advice_code: response.error.network_advice_code,
         })
     }
 }
@@ -2003,6 +2152,7 @@ impl
             }),
             attempt_status: None,
             connector_transaction_id: response.error.payment_intent.map(|pi| pi.id),
+            network_advice_code: response.error.network_advice_code,
         })
     }
 }
@@ -2134,6 +2284,7 @@ impl
             }),
             attempt_status: None,
             connector_transaction_id: response.error.payment_intent.map(|pi| pi.id),
+            network_advice_code: response.error.network_advice_code,
         })
     }
 }
@@ -2208,6 +2359,59 @@ impl api::IncomingWebhook for Stripe {
         .into_bytes())
     }
 
+    fn get_webhook_signed_timestamp(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<i64>, errors::ConnectorError> {
+        let mut security_header_kvs = get_signature_elements_from_header(request.headers)?;
+
+        let timestamp = security_header_kvs
+            .remove("t")
+            .ok_or(errors::ConnectorError::WebhookSignatureNotFound)?;
+
+        String::from_utf8_lossy(&timestamp)
+            .parse::<i64>()
+            .map(Some)
+            .change_context(errors::ConnectorError::WebhookSignatureNotFound)
+            .attach_printable("Failed to parse the timestamp in the Stripe-Signature header")
+    }
+
+    fn build_webhook_resource_fetch_request(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+        auth_type: &types::ConnectorAuthType,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<services::Request, errors::ConnectorError> {
+        let details: stripe::WebhookEvent = request
+            .body
+            .parse_struct("WebhookEvent")
+            .change_context(errors::ConnectorError::WebhookResourceObjectNotFound)?;
+
+        let resource = match details.event_data.event_object.object {
+            stripe::WebhookEventObjectType::PaymentIntent => "v1/payment_intents",
+            stripe::WebhookEventObjectType::Charge => "v1/charges",
+            stripe::WebhookEventObjectType::Refund => "v1/refunds",
+            stripe::WebhookEventObjectType::Dispute | stripe::WebhookEventObjectType::Source => {
+                return Err(errors::ConnectorError::NotImplemented(
+                    "Fetching the dispute and source resources of webhooks".to_string(),
+                )
+                .into())
+            }
+        };
+
+        Ok(services::RequestBuilder::new()
+            .method(services::Method::Get)
+            .url(&format!(
+                "{}{}/{}",
+                self.base_url(connectors),
+                resource,
+                details.event_data.event_object.id
+            ))
+            .attach_default_headers()
+            .headers(self.get_auth_header(auth_type)?)
+            .build())
+    }
+
     fn get_webhook_object_reference_id(
         &self,
         request: &api::IncomingWebhookRequestDetails<'_>,
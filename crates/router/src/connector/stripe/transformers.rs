@@ -256,6 +256,20 @@ pub struct CustomerRequest {
     pub phone: Option<Secret<String>>,
     pub name: Option<Secret<String>>,
     pub source: Option<Secret<String>>,
+    #[serde(rename = "tax_id_data[0][type]")]
+    pub tax_id_type: Option<api_enums::TaxIdType>,
+    #[serde(rename = "tax_id_data[0][value]")]
+    pub tax_id_value: Option<Secret<String>>,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+pub struct CustomerUpdateRequest {
+    pub description: Option<common_utils::types::Description>,
+    pub email: Option<Email>,
+    pub phone: Option<Secret<String>>,
+    pub name: Option<Secret<String>>,
+    #[serde(flatten)]
+    pub meta_data: HashMap<String, String>,
 }
 
 #[derive(Debug, Eq, PartialEq, Deserialize, Serialize)]
@@ -2043,12 +2057,45 @@ impl TryFrom<&types::TokenizationRouterData> for TokenRequest {
 impl TryFrom<&types::ConnectorCustomerRouterData> for CustomerRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::ConnectorCustomerRouterData) -> Result<Self, Self::Error> {
+        // Stripe only accepts the tax identifiers of the kinds it recognizes
+        let tax_id = item
+            .request
+            .tax_id
+            .as_ref()
+            .filter(|tax_id| tax_id.tax_id_type != api_enums::TaxIdType::Other);
         Ok(Self {
             description: item.request.description.to_owned(),
             email: item.request.email.to_owned(),
             phone: item.request.phone.to_owned(),
             name: item.request.name.to_owned(),
             source: item.request.preprocessing_id.to_owned().map(Secret::new),
+            tax_id_type: tax_id.map(|tax_id| tax_id.tax_id_type),
+            tax_id_value: tax_id.map(|tax_id| tax_id.value.clone()),
+        })
+    }
+}
+
+impl TryFrom<&types::ConnectorCustomerUpdateRouterData> for CustomerUpdateRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::ConnectorCustomerUpdateRouterData) -> Result<Self, Self::Error> {
+        let meta_data = item
+            .request
+            .metadata
+            .as_ref()
+            .map(|metadata| {
+                serde_json::from_value::<HashMap<String, Value>>(metadata.peek().clone())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|(key, value)| (format!("metadata[{}]", key), value.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        Ok(Self {
+            description: item.request.description.to_owned(),
+            email: item.request.email.to_owned(),
+            phone: item.request.phone.to_owned(),
+            name: item.request.name.to_owned(),
+            meta_data,
         })
     }
 }
@@ -2200,6 +2247,21 @@ pub enum StripeChargeEnum {
 pub struct StripeCharge {
     pub id: String,
     pub payment_method_details: Option<StripePaymentMethodDetailsResponse>,
+    pub balance_transaction: Option<StripeBalanceTransactionEnum>,
+}
+
+#[derive(Debug, Eq, PartialEq, Deserialize, Clone, Serialize)]
+#[serde(untagged)]
+pub enum StripeBalanceTransactionEnum {
+    BalanceTransactionId(String),
+    BalanceTransactionObject(StripeBalanceTransaction),
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct StripeBalanceTransaction {
+    pub id: String,
+    /// The unix timestamp at which the funds of the transaction become available in the balance
+    pub available_on: i64,
 }
 
 #[derive(Deserialize, Clone, Debug, PartialEq, Eq, Serialize)]
@@ -2384,6 +2446,23 @@ fn extract_payment_method_connector_response_from_latest_charge(
     .map(types::ConnectorResponseData::with_additional_payment_method_data)
 }
 
+/// The date on which the funds of the charge become available in the balance of the merchant,
+/// which is reported only when the balance transaction of the charge is expanded
+fn extract_expected_settlement_date_from_latest_charge(
+    stripe_charge_enum: &StripeChargeEnum,
+) -> Option<time::Date> {
+    match stripe_charge_enum {
+        StripeChargeEnum::ChargeObject(StripeCharge {
+            balance_transaction:
+                Some(StripeBalanceTransactionEnum::BalanceTransactionObject(balance_transaction)),
+            ..
+        }) => time::OffsetDateTime::from_unix_timestamp(balance_transaction.available_on)
+            .ok()
+            .map(|available_on| available_on.date()),
+        _ => None,
+    }
+}
+
 fn extract_payment_method_connector_response_from_latest_attempt(
     stripe_latest_attempt: &LatestAttempt,
 ) -> Option<types::ConnectorResponseData> {
@@ -2636,11 +2715,21 @@ impl<F, T>
 
         let status = enums::AttemptStatus::from(item.response.status.to_owned());
 
+        let expected_settlement_date = item
+            .response
+            .latest_charge
+            .as_ref()
+            .and_then(extract_expected_settlement_date_from_latest_charge);
         let connector_response_data = item
             .response
             .latest_charge
             .as_ref()
-            .and_then(extract_payment_method_connector_response_from_latest_charge);
+            .and_then(extract_payment_method_connector_response_from_latest_charge)
+            .map(|connector_response| {
+                connector_response.set_expected_settlement_date(expected_settlement_date)
+            })
+            .or(expected_settlement_date
+                .map(types::ConnectorResponseData::with_expected_settlement_date));
 
         let response = if connector_util::is_payment_failure(status) {
             types::PaymentsResponseData::foreign_try_from((
@@ -3060,6 +3149,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
                 status_code: item.http_code,
                 attempt_status: None,
                 connector_transaction_id: Some(item.response.id),
+                network_advice_code: None,
             })
         } else {
             Ok(types::RefundsResponseData {
@@ -3095,6 +3185,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
                 status_code: item.http_code,
                 attempt_status: None,
                 connector_transaction_id: Some(item.response.id),
+                network_advice_code: None,
             })
         } else {
             Ok(types::RefundsResponseData {
@@ -3118,6 +3209,8 @@ pub struct ErrorDetails {
     pub message: Option<String>,
     pub param: Option<String>,
     pub decline_code: Option<String>,
+    /// The advice of the card network on retrying the payment, for the declines by the issuer
+    pub network_advice_code: Option<String>,
     pub payment_intent: Option<PaymentIntentErrorResponse>,
 }
 
@@ -3448,6 +3541,7 @@ impl<F, T> TryFrom<types::ResponseRouterData<F, ChargesResponse, T, types::Payme
                 status_code: item.http_code,
                 attempt_status: Some(status),
                 connector_transaction_id: Some(item.response.id),
+                network_advice_code: None,
             })
         } else {
             Ok(types::PaymentsResponseData::TransactionResponse {
@@ -4065,6 +4159,9 @@ impl ForeignTryFrom<(&Option<ErrorDetails>, u16, String)> for types::PaymentsRes
             status_code: http_code,
             attempt_status: None,
             connector_transaction_id: Some(response_id),
+            network_advice_code: response
+                .as_ref()
+                .and_then(|error_details| error_details.network_advice_code.clone()),
         })
     }
 }
@@ -4247,3 +4344,48 @@ mod test_validate_shipping_address_against_payment_method {
         }
     }
 }
+
+#[cfg(test)]
+mod test_extract_expected_settlement_date {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::date;
+
+    use super::{extract_expected_settlement_date_from_latest_charge, StripeChargeEnum};
+
+    #[test]
+    fn should_extract_settlement_date_from_expanded_balance_transaction() {
+        let latest_charge: StripeChargeEnum = serde_json::from_value(serde_json::json!({
+            "id": "ch_1",
+            "balance_transaction": {
+                "id": "txn_1",
+                "available_on": 1730419200
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            extract_expected_settlement_date_from_latest_charge(&latest_charge),
+            Some(date!(2024 - 11 - 01))
+        );
+    }
+
+    #[test]
+    fn should_not_extract_settlement_date_without_expanded_balance_transaction() {
+        let latest_charge: StripeChargeEnum = serde_json::from_value(serde_json::json!({
+            "id": "ch_1",
+            "balance_transaction": "txn_1"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            extract_expected_settlement_date_from_latest_charge(&latest_charge),
+            None
+        );
+        assert_eq!(
+            extract_expected_settlement_date_from_latest_charge(&StripeChargeEnum::ChargeId(
+                "ch_1".to_string()
+            )),
+            None
+        );
+    }
+}
@@ -122,6 +122,7 @@ impl ConnectorCommon for Threedsecureio {
                     reason: response.error_description,
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 })
             }
             Err(err) => {
@@ -123,6 +123,7 @@ impl
                     status_code: item.http_code,
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 })
             }
         };
@@ -204,6 +205,7 @@ impl
                         status_code: item.http_code,
                         attempt_status: None,
                         connector_transaction_id: None,
+                        network_advice_code: None,
                     })
                 }
                 ThreedsecureioErrorResponseWrapper::ErrorString(error) => {
@@ -214,6 +216,7 @@ impl
                         status_code: item.http_code,
                         attempt_status: None,
                         connector_transaction_id: None,
+                        network_advice_code: None,
                     })
                 }
             },
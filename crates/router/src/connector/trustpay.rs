@@ -161,6 +161,7 @@ impl ConnectorCommon for Trustpay {
                         .or(response_data.payment_description),
                     attempt_status: None,
                     connector_transaction_id: response_data.instance_id,
+                    network_advice_code: None,
                 })
             }
             Err(error_msg) => {
@@ -337,6 +338,7 @@ impl ConnectorIntegration<api::AccessTokenAuth, types::AccessTokenRequestData, t
             reason: response.result_info.additional_info,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
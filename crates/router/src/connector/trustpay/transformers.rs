@@ -720,6 +720,7 @@ fn handle_cards_response(
             status_code,
             attempt_status: None,
             connector_transaction_id: Some(response.instance_id.clone()),
+            network_advice_code: None,
         })
     } else {
         None
@@ -787,6 +788,7 @@ fn handle_bank_redirects_error_response(
         status_code,
         attempt_status: None,
         connector_transaction_id: None,
+        network_advice_code: None,
     });
     let payment_response_data = types::PaymentsResponseData::TransactionResponse {
         resource_id: types::ResponseId::NoResponseId,
@@ -839,6 +841,7 @@ fn handle_bank_redirects_sync_response(
                     .payment_request_id
                     .clone(),
             ),
+            network_advice_code: None,
         })
     } else {
         None
@@ -893,6 +896,7 @@ pub fn handle_webhook_response(
             status_code,
             attempt_status: None,
             connector_transaction_id: payment_information.references.payment_request_id.clone(),
+            network_advice_code: None,
         })
     } else {
         None
@@ -1001,6 +1005,7 @@ impl<F, T> TryFrom<types::ResponseRouterData<F, TrustpayAuthUpdateResponse, T, t
                     status_code: item.http_code,
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 }),
                 ..item.data
             }),
@@ -1488,6 +1493,7 @@ fn handle_cards_refund_response(
             status_code,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     } else {
         None
@@ -1522,6 +1528,7 @@ fn handle_webhooks_refund_response(
             status_code,
             attempt_status: None,
             connector_transaction_id: response.references.payment_request_id.clone(),
+            network_advice_code: None,
         })
     } else {
         None
@@ -1550,6 +1557,7 @@ fn handle_bank_redirects_refund_response(
             status_code,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     } else {
         None
@@ -1586,6 +1594,7 @@ fn handle_bank_redirects_refund_sync_response(
             status_code,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     } else {
         None
@@ -1609,6 +1618,7 @@ fn handle_bank_redirects_refund_sync_error_response(
         status_code,
         attempt_status: None,
         connector_transaction_id: None,
+        network_advice_code: None,
     });
     //unreachable case as we are sending error as Some()
     let refund_response_data = types::RefundsResponseData {
@@ -1729,6 +1729,7 @@ impl<F, T>
             status_code: item.http_code,
             attempt_status: None,
             connector_transaction_id: Some(error_response.id.clone()),
+            network_advice_code: None,
         });
         match transaction_status {
             Some(status) => Self {
@@ -2468,6 +2469,7 @@ impl
             status_code,
             attempt_status,
             connector_transaction_id: Some(transaction_id.clone()),
+            network_advice_code: None,
         }
     }
 }
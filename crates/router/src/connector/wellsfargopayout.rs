@@ -132,6 +132,7 @@ impl ConnectorCommon for Wellsfargopayout {
             reason: response.reason,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
@@ -102,6 +102,7 @@ impl ConnectorCommon for Wise {
                         reason: None,
                         attempt_status: None,
                         connector_transaction_id: None,
+                        network_advice_code: None,
                     })
                 } else {
                     Ok(types::ErrorResponse {
@@ -111,6 +112,7 @@ impl ConnectorCommon for Wise {
                         reason: None,
                         attempt_status: None,
                         connector_transaction_id: None,
+                        network_advice_code: None,
                     })
                 }
             }
@@ -121,6 +123,7 @@ impl ConnectorCommon for Wise {
                 reason: None,
                 attempt_status: None,
                 connector_transaction_id: None,
+                network_advice_code: None,
             }),
         }
     }
@@ -327,6 +330,7 @@ impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::Pa
             reason: None,
             attempt_status: None,
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
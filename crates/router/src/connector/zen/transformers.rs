@@ -939,6 +939,7 @@ fn get_zen_response(
             status_code,
             attempt_status: Some(status),
             connector_transaction_id: Some(response.id.clone()),
+            network_advice_code: None,
         })
     } else {
         None
@@ -1086,6 +1087,7 @@ fn get_zen_refund_response(
             status_code,
             attempt_status: None,
             connector_transaction_id: Some(response.id.clone()),
+            network_advice_code: None,
         })
     } else {
         None
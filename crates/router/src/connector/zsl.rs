@@ -98,6 +98,7 @@ impl ConnectorCommon for Zsl {
             reason: Some(error_reason),
             attempt_status: Some(common_enums::AttemptStatus::Failure),
             connector_transaction_id: None,
+            network_advice_code: None,
         })
     }
 }
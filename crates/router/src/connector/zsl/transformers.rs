@@ -352,6 +352,7 @@ impl<F, T>
                         status_code: item.http_code,
                         attempt_status: Some(enums::AttemptStatus::Failure),
                         connector_transaction_id: Some(item.response.mer_ref.clone()),
+                        network_advice_code: None,
                     }),
                     ..item.data
                 })
@@ -368,6 +369,7 @@ impl<F, T>
                     status_code: item.http_code,
                     attempt_status: Some(enums::AttemptStatus::Failure),
                     connector_transaction_id: Some(item.response.mer_ref.clone()),
+                    network_advice_code: None,
                 }),
                 ..item.data
             })
@@ -472,6 +474,7 @@ impl<F>
                     status_code: item.http_code,
                     attempt_status: Some(enums::AttemptStatus::Failure),
                     connector_transaction_id: Some(item.response.mer_ref.clone()),
+                    network_advice_code: None,
                 }),
                 ..item.data
             })
@@ -18,12 +18,26 @@ pub(crate) const ALPHABETS: [char; 62] = [
 ];
 /// API client request timeout (in seconds)
 pub const REQUEST_TIME_OUT: u64 = 30;
+/// Minimum request timeout (in seconds) that can be configured for a merchant connector account
+pub const MIN_CONNECTOR_REQUEST_TIME_OUT: u16 = 1;
+/// Maximum request timeout (in seconds) that can be configured for a merchant connector account
+pub const MAX_CONNECTOR_REQUEST_TIME_OUT: u16 = 120;
+/// Maximum number of retries that can be configured for the requests to connectors which failed
+/// with a network error
+pub const MAX_CONNECTOR_NETWORK_RETRIES: u8 = 5;
 pub const REQUEST_TIMEOUT_ERROR_CODE: &str = "TIMEOUT";
 pub const REQUEST_TIMEOUT_ERROR_MESSAGE: &str = "Connector did not respond in specified time";
 pub const REQUEST_TIMEOUT_PAYMENT_NOT_FOUND: &str = "Timed out ,payment not found";
 pub const REQUEST_TIMEOUT_ERROR_MESSAGE_FROM_PSYNC: &str =
     "This Payment has been moved to failed as there is no response from the connector";
 
+/// The duration of the claim of a worker on a payment which it syncs with the connector (in seconds)
+pub const PAYMENT_SYNC_CLAIM_LEASE_DURATION: i64 = 5 * 60;
+
+/// The duration within which the credentials of a connector account are failed over at most once
+/// (in seconds)
+pub const CONNECTOR_CREDENTIALS_FAIL_OVER_LOCK_DURATION: i64 = 60;
+
 ///Payment intent fulfillment default timeout (in seconds)
 pub const DEFAULT_FULFILLMENT_TIME: i64 = 15 * 60;
 
@@ -109,10 +123,23 @@ pub const MAX_INTENT_FULFILLMENT_EXPIRY: u32 = 1800;
 /// Min payment intent fulfillment expiry
 pub const MIN_INTENT_FULFILLMENT_EXPIRY: u32 = 60;
 
+/// Max idempotency lock ttl that can be configured for a merchant
+pub const MAX_IDEMPOTENCY_TTL_IN_SECONDS: u32 = 3600;
+
+/// Min idempotency lock ttl that can be configured for a merchant
+pub const MIN_IDEMPOTENCY_TTL_IN_SECONDS: u32 = 10;
+
+/// Max number of additional customer fields that can be designated for encryption by a merchant
+pub const MAX_CUSTOMER_PII_ENCRYPTION_FIELDS: usize = 20;
+
 pub const LOCKER_HEALTH_CALL_PATH: &str = "/health";
 
 pub const AUTHENTICATION_ID_PREFIX: &str = "authn";
 
+pub const THREE_DS_SESSION_ID_PREFIX: &str = "3dss";
+/// Time in seconds within which the challenge of a 3DS session is to be completed
+pub const THREE_DS_SESSION_TTL: i64 = 10 * 60;
+
 // URL for checking the outgoing call
 pub const OUTGOING_CALL_URL: &str = "https://api.stripe.com/healthcheck";
 
@@ -127,6 +154,32 @@ pub const DEFAULT_POLL_FREQUENCY: i8 = 5;
 pub(crate) const REDUCE_ACCESS_TOKEN_EXPIRY_TIME: u8 = 15;
 pub const CONNECTOR_CREDS_TOKEN_TTL: i64 = 900;
 
+/// Prefix of the redis key under which imported card info records are cached
+pub const CARD_INFO_CACHE_PREFIX: &str = "CARD_INFO_";
+// 1 day = 86400 seconds
+pub const CARD_INFO_CACHE_TTL: i64 = 60 * 60 * 24;
+/// Number of card info records upserted in a single transaction during bulk imports
+pub const CARD_INFO_BULK_UPSERT_BATCH_SIZE: usize = 1000;
+
+/// Prefix of the redis key under which connector responses for read operations are cached
+pub const CONNECTOR_READ_CACHE_PREFIX: &str = "CONNECTOR_READ_";
+/// Time in seconds for which a cached connector read response is served before calling the connector again
+pub const CONNECTOR_READ_CACHE_TTL: i64 = 30;
+
+/// Prefix of the redis key under which the latency histograms of connector calls are aggregated
+pub const CONNECTOR_LATENCY_PREFIX: &str = "CONNECTOR_LATENCY_";
+
+/// Prefix of the redis keys under which the permits of the calls in flight to a connector are leased
+pub const CONNECTOR_CONCURRENCY_PREFIX: &str = "CONNECTOR_CONCURRENCY_";
+
+/// Prefix of the redis key holding the time until which a rate limited connector is backed off
+pub const CONNECTOR_RATE_LIMIT_PREFIX: &str = "CONNECTOR_RATE_LIMIT_";
+
+/// Length of the webhook identifier embedded in the webhook endpoint of a merchant connector account
+pub const WEBHOOK_IDENTIFIER_LENGTH: usize = 32;
+/// Time in seconds for which webhooks sent to a rotated webhook identifier are still accepted
+pub const WEBHOOK_IDENTIFIER_ROTATION_GRACE_PERIOD: i64 = 24 * 60 * 60;
+
 //max_amount allowed is 999999999 in minor units
 pub const MAX_ALLOWED_AMOUNT: i64 = 999999999;
 
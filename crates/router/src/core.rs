@@ -3,6 +3,7 @@ pub mod api_keys;
 pub mod api_locking;
 #[cfg(feature = "v1")]
 pub mod apple_pay_certificates_migration;
+pub mod audit_log;
 pub mod authentication;
 #[cfg(feature = "v1")]
 pub mod blocklist;
@@ -10,11 +11,15 @@ pub mod cache;
 pub mod cards_info;
 pub mod conditional_config;
 pub mod configs;
+pub mod connector_concurrency;
+pub mod connector_latency;
 #[cfg(feature = "olap")]
 pub mod connector_onboarding;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub mod currency;
 pub mod customers;
+#[cfg(feature = "v1")]
+pub mod data_retention;
 pub mod disputes;
 pub mod encryption;
 pub mod errors;
@@ -40,7 +45,10 @@ pub mod poll;
 pub mod recon;
 #[cfg(feature = "v1")]
 pub mod refunds;
+pub mod reverse_lookup_reconciliation;
 pub mod routing;
+#[cfg(feature = "v1")]
+pub mod settlements;
 pub mod surcharge_decision_config;
 #[cfg(feature = "olap")]
 pub mod user;
@@ -49,7 +57,6 @@ pub mod user_role;
 pub mod utils;
 #[cfg(feature = "olap")]
 pub mod verification;
-#[cfg(feature = "olap")]
 pub mod verify_connector;
 #[cfg(feature = "v1")]
 pub mod webhooks;
@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{collections::HashMap, str::FromStr};
 
 use api_models::{
     admin::{self as admin_types},
@@ -10,9 +10,14 @@ use common_utils::{
     id_type, pii, type_name,
     types::keymanager::{self as km_types, KeyManagerState, ToEncryptable},
 };
-use diesel_models::configs;
 #[cfg(all(any(feature = "v1", feature = "v2"), feature = "olap"))]
 use diesel_models::organization::OrganizationBridge;
+use diesel_models::{
+    configs,
+    merchant_connector_account::{
+        get_maintenance_window_recurrence_interval, ConnectorMaintenanceWindows,
+    },
+};
 use error_stack::{report, FutureExt, ResultExt};
 use hyperswitch_domain_models::merchant_connector_account::{
     McaFromRequest, McaFromRequestfromUpdate,
@@ -23,11 +28,14 @@ use regex::Regex;
 use router_env::metrics::add_attributes;
 use uuid::Uuid;
 
+#[cfg(feature = "v1")]
+use crate::core::webhooks::webhook_identifier;
 #[cfg(any(feature = "v1", feature = "v2"))]
 use crate::types::transformers::ForeignFrom;
 use crate::{
     consts,
     core::{
+        audit_log,
         encryption::transfer_encryption_key,
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
         payment_methods::{cards, transformers},
@@ -36,10 +44,10 @@ use crate::{
         routing, utils as core_utils,
     },
     db::StorageInterface,
-    routes::{metrics, SessionState},
+    routes::{app::ReqState, metrics, SessionState},
     services::{
         self,
-        api::{self as service_api, client},
+        api::{self as service_api, client, ConnectorValidation},
         authentication, pm_auth as payment_initiation_service,
     },
     types::{
@@ -58,6 +66,7 @@ use crate::{
 const IBAN_MAX_LENGTH: usize = 34;
 const BACS_SORT_CODE_LENGTH: usize = 6;
 const BACS_MAX_ACCOUNT_NUMBER_LENGTH: usize = 8;
+const ONBOARDING_AUDIT_LOG_FIELD_MAX_LENGTH: usize = 255;
 
 #[inline]
 pub fn create_merchant_publishable_key() -> String {
@@ -68,6 +77,17 @@ pub fn create_merchant_publishable_key() -> String {
     )
 }
 
+/// New merchant accounts have to be onboarded before they can accept payments only when merchant
+/// onboarding is enabled
+#[cfg(feature = "olap")]
+fn get_initial_onboarding_status(state: &SessionState) -> api_enums::OnboardingStatus {
+    if state.conf.merchant_onboarding.enabled {
+        api_enums::OnboardingStatus::KycPending
+    } else {
+        api_enums::OnboardingStatus::Live
+    }
+}
+
 pub async fn insert_merchant_configs(
     db: &dyn StorageInterface,
     merchant_id: &id_type::MerchantId,
@@ -319,6 +339,35 @@ impl MerchantAccountCreateBridge for api::MerchantAccountCreate {
             },
         )?;
 
+        if let Some(idempotency_ttl_in_seconds) = self.idempotency_ttl_in_seconds {
+            helpers::validate_idempotency_ttl(idempotency_ttl_in_seconds)?;
+        }
+
+        if let Some(ref customer_pii_encryption_fields) = self.customer_pii_encryption_fields {
+            helpers::validate_customer_pii_encryption_fields(customer_pii_encryption_fields)?;
+        }
+
+        if let Some(ref allowed_return_url_domains) = self.allowed_return_url_domains {
+            helpers::validate_allowed_return_url_domains(allowed_return_url_domains)?;
+        }
+
+        // The connectors of the merchant are not configured yet, so the connectors of the failover
+        // order are only checked to be active when the failover chain of a payment is retrieved
+        if let Some(ref connector_failover_order) = self.connector_failover_order {
+            validate_connector_failover_rules(connector_failover_order)?;
+        }
+
+        if let Some(ref transaction_amount_limits) = self.transaction_amount_limits {
+            validate_transaction_amount_limits(transaction_amount_limits)?;
+        }
+
+        // The connectors of the merchant are not configured yet, so the default currency is only
+        // validated against the connectors once the merchant is updated
+        validate_default_currency_is_applicable(
+            self.default_currency,
+            self.require_explicit_currency,
+        )?;
+
         // Get the enable payment response hash as a boolean, where the default value is true
         let enable_payment_response_hash = self.get_enable_payment_response_hash();
 
@@ -403,6 +452,22 @@ impl MerchantAccountCreateBridge for api::MerchantAccountCreate {
                     payment_link_config: None,
                     pm_collect_link_config,
                     version: hyperswitch_domain_models::consts::API_VERSION,
+                    onboarding_status: get_initial_onboarding_status(state),
+                    idempotency_ttl_in_seconds: self.idempotency_ttl_in_seconds.map(i64::from),
+                    customer_pii_encryption_fields: self.customer_pii_encryption_fields,
+                    statement_descriptor_length_policy: self.statement_descriptor_length_policy,
+                    allowed_return_url_domains: self.allowed_return_url_domains,
+                    default_connector_by_method_type: None,
+                    connector_failover_order: self
+                        .connector_failover_order
+                        .map(ForeignInto::foreign_into),
+                    default_capture_method: self.default_capture_method,
+                    least_cost_routing: None,
+                    transaction_amount_limits: self
+                        .transaction_amount_limits
+                        .map(ForeignInto::foreign_into),
+                    default_currency: self.default_currency,
+                    require_explicit_currency: self.require_explicit_currency,
                 },
             )
         }
@@ -670,6 +735,7 @@ impl MerchantAccountCreateBridge for api::MerchantAccountCreate {
                     modified_at: date_time::now(),
                     organization_id: organization.get_organization_id(),
                     recon_status: diesel_models::enums::ReconStatus::NotRequested,
+                    onboarding_status: get_initial_onboarding_status(state),
                 }),
             )
         }
@@ -877,6 +943,66 @@ impl MerchantAccountUpdateBridge for api::MerchantAccountUpdate {
             },
         )?;
 
+        if let Some(idempotency_ttl_in_seconds) = self.idempotency_ttl_in_seconds {
+            helpers::validate_idempotency_ttl(idempotency_ttl_in_seconds)?;
+        }
+
+        if let Some(ref customer_pii_encryption_fields) = self.customer_pii_encryption_fields {
+            helpers::validate_customer_pii_encryption_fields(customer_pii_encryption_fields)?;
+        }
+
+        if let Some(ref allowed_return_url_domains) = self.allowed_return_url_domains {
+            helpers::validate_allowed_return_url_domains(allowed_return_url_domains)?;
+        }
+
+        if let Some(ref default_connector_by_method_type) = self.default_connector_by_method_type {
+            validate_default_connector_by_method_type(
+                state,
+                merchant_id,
+                key_store,
+                default_connector_by_method_type,
+            )
+            .await?;
+        }
+
+        if let Some(Some(default_capture_method)) = self.default_capture_method {
+            validate_default_capture_method(state, merchant_id, key_store, default_capture_method)
+                .await?;
+        }
+
+        if let Some(ref connector_failover_order) = self.connector_failover_order {
+            validate_connector_failover_order(
+                state,
+                merchant_id,
+                key_store,
+                connector_failover_order,
+            )
+            .await?;
+        }
+
+        if let Some(ref least_cost_routing) = self.least_cost_routing {
+            validate_least_cost_routing(least_cost_routing)?;
+        }
+
+        if let Some(ref transaction_amount_limits) = self.transaction_amount_limits {
+            validate_transaction_amount_limits(transaction_amount_limits)?;
+        }
+
+        validate_default_currency_is_applicable(
+            self.default_currency.flatten(),
+            self.require_explicit_currency,
+        )?;
+
+        if let Some(Some(default_currency)) = self.default_currency {
+            validate_default_currency(state, merchant_id, key_store, default_currency).await?;
+        }
+
+        let default_connector_by_method_type = self
+            .get_default_connector_by_method_type_as_value()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "default_connector_by_method_type",
+            })?;
+
         let webhook_details = self.webhook_details.map(ForeignInto::foreign_into);
 
         let parent_merchant_id = get_parent_merchant(
@@ -975,6 +1101,19 @@ impl MerchantAccountUpdateBridge for api::MerchantAccountUpdate {
             payment_link_config: None,
             pm_collect_link_config,
             routing_algorithm: self.routing_algorithm,
+            idempotency_ttl_in_seconds: self.idempotency_ttl_in_seconds.map(i64::from),
+            customer_pii_encryption_fields: self.customer_pii_encryption_fields,
+            statement_descriptor_length_policy: self.statement_descriptor_length_policy,
+            allowed_return_url_domains: self.allowed_return_url_domains,
+            default_connector_by_method_type,
+            connector_failover_order: self.connector_failover_order.map(ForeignInto::foreign_into),
+            default_capture_method: self.default_capture_method,
+            least_cost_routing: self.least_cost_routing.map(ForeignInto::foreign_into),
+            transaction_amount_limits: self
+                .transaction_amount_limits
+                .map(ForeignInto::foreign_into),
+            default_currency: self.default_currency,
+            require_explicit_currency: self.require_explicit_currency,
         })
     }
 }
@@ -1048,6 +1187,7 @@ pub async fn merchant_account_update(
     merchant_id: &id_type::MerchantId,
     _profile_id: Option<id_type::ProfileId>,
     req: api::MerchantAccountUpdate,
+    req_state: ReqState,
 ) -> RouterResponse<api::MerchantAccountResponse> {
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
@@ -1065,15 +1205,28 @@ pub async fn merchant_account_update(
         .await
         .attach_printable("Failed to create merchant account update object")?;
 
-    let response = db
-        .update_specific_fields_in_merchant(
-            key_manager_state,
-            merchant_id,
-            merchant_account_storage_object,
-            &key_store,
-        )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let response = match audit_log::get_audit_log_context(&state, &req_state) {
+        Some(audit_log) => {
+            db.update_specific_fields_in_merchant_with_audit_log(
+                key_manager_state,
+                merchant_id,
+                merchant_account_storage_object,
+                &key_store,
+                audit_log,
+            )
+            .await
+        }
+        None => {
+            db.update_specific_fields_in_merchant(
+                key_manager_state,
+                merchant_id,
+                merchant_account_storage_object,
+                &key_store,
+            )
+            .await
+        }
+    }
+    .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
     Ok(service_api::ApplicationResponse::Json(
         api::MerchantAccountResponse::foreign_try_from(response)
@@ -1193,6 +1346,415 @@ async fn validate_merchant_id(
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
 }
 
+/// Validate that each connector configured and enabled as a payment processor for the merchant
+/// supports the default capture method
+#[cfg(feature = "v1")]
+async fn validate_default_capture_method(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    key_store: &domain::MerchantKeyStore,
+    default_capture_method: api_enums::CaptureMethod,
+) -> RouterResult<()> {
+    let merchant_connector_accounts = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &state.into(),
+            merchant_id,
+            false,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    for mca in merchant_connector_accounts
+        .iter()
+        .filter(|mca| mca.connector_type == api_enums::ConnectorType::PaymentProcessor)
+    {
+        validate_connector_supports_default_capture_method(
+            state,
+            &mca.connector_name,
+            Some(mca.get_id()),
+            default_capture_method,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Validate that the connector supports the default capture method of the merchant, so that a
+/// connector which is configured or enabled after the default was set is checked against it
+#[cfg(feature = "v1")]
+fn validate_connector_supports_default_capture_method(
+    state: &SessionState,
+    connector_name: &str,
+    merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+    default_capture_method: api_enums::CaptureMethod,
+) -> RouterResult<()> {
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        connector_name,
+        api::GetToken::Connector,
+        merchant_connector_id,
+    )?;
+
+    if connector_data
+        .connector
+        .validate_capture_method(Some(default_capture_method), None)
+        .is_err()
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Connector {connector_name} does not support the capture method {default_capture_method}, which is the default capture method of the merchant"
+            ),
+        }));
+    }
+
+    Ok(())
+}
+
+/// Validate that the default currency is not configured while the payments are required to specify
+/// their currency, which disables the default currency
+#[cfg(feature = "v1")]
+fn validate_default_currency_is_applicable(
+    default_currency: Option<api_enums::Currency>,
+    require_explicit_currency: Option<bool>,
+) -> RouterResult<()> {
+    utils::when(
+        default_currency.is_some() && require_explicit_currency == Some(true),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "default_currency cannot be configured when require_explicit_currency is enabled"
+                    .to_string(),
+            }))
+        },
+    )
+}
+
+/// Whether the connector supports the currency as per its payment method filters. A connector
+/// without payment method filters, or with a filter which does not restrict the currencies,
+/// supports every currency.
+#[cfg(feature = "v1")]
+fn is_currency_supported_by_connector(
+    pm_filters: &crate::configs::settings::ConnectorFilters,
+    connector_name: &str,
+    currency: api_enums::Currency,
+) -> bool {
+    pm_filters.0.get(connector_name).map_or(true, |filters| {
+        filters.0.values().any(|filter| {
+            filter
+                .currency
+                .as_ref()
+                .map_or(true, |currencies| currencies.contains(&currency))
+        })
+    })
+}
+
+/// Validate that at least one of the connectors configured and enabled as a payment processor for
+/// the merchant supports the default currency. The default currency of a merchant without any such
+/// connector is not validated.
+#[cfg(feature = "v1")]
+async fn validate_default_currency(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    key_store: &domain::MerchantKeyStore,
+    default_currency: api_enums::Currency,
+) -> RouterResult<()> {
+    let merchant_connector_accounts = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &state.into(),
+            merchant_id,
+            false,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let payment_processors = merchant_connector_accounts
+        .iter()
+        .filter(|mca| mca.connector_type == api_enums::ConnectorType::PaymentProcessor)
+        .map(|mca| mca.connector_name.as_str())
+        .collect::<Vec<_>>();
+
+    validate_default_currency_is_supported(
+        &state.conf.pm_filters,
+        &payment_processors,
+        default_currency,
+    )
+}
+
+/// Validate that at least one of the payment processors supports the default currency. The
+/// default currency is not validated when there are no payment processors.
+#[cfg(feature = "v1")]
+fn validate_default_currency_is_supported(
+    pm_filters: &crate::configs::settings::ConnectorFilters,
+    payment_processors: &[&str],
+    default_currency: api_enums::Currency,
+) -> RouterResult<()> {
+    let is_supported = payment_processors.is_empty()
+        || payment_processors.iter().any(|connector_name| {
+            is_currency_supported_by_connector(pm_filters, connector_name, default_currency)
+        });
+
+    utils::when(!is_supported, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "None of the connectors of the merchant supports the currency {default_currency}"
+            ),
+        }))
+    })
+}
+
+/// Validate that every rule of the connector failover order has connectors
+fn validate_connector_failover_rules(
+    connector_failover_order: &[admin_types::ConnectorFailoverRule],
+) -> RouterResult<()> {
+    if connector_failover_order
+        .iter()
+        .any(|rule| rule.connectors.is_empty())
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Every rule of the connector failover order must have connectors".to_string(),
+        }));
+    }
+
+    Ok(())
+}
+
+/// Get the names of the connectors which are configured and enabled as payment processors for the
+/// merchant
+#[cfg(feature = "v1")]
+async fn get_active_payment_processors(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<std::collections::HashSet<String>> {
+    Ok(state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &state.into(),
+            merchant_id,
+            false,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?
+        .into_iter()
+        .filter(|mca| mca.connector_type == api_enums::ConnectorType::PaymentProcessor)
+        .map(|mca| mca.connector_name)
+        .collect())
+}
+
+/// Validate that every rule of the connector failover order has connectors, and that each of the
+/// connectors is configured and enabled as a payment processor for the merchant
+#[cfg(feature = "v1")]
+async fn validate_connector_failover_order(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    key_store: &domain::MerchantKeyStore,
+    connector_failover_order: &[admin_types::ConnectorFailoverRule],
+) -> RouterResult<()> {
+    validate_connector_failover_rules(connector_failover_order)?;
+
+    if connector_failover_order.is_empty() {
+        return Ok(());
+    }
+
+    let active_connectors = get_active_payment_processors(state, merchant_id, key_store).await?;
+
+    let inactive_connector = connector_failover_order
+        .iter()
+        .flat_map(|rule| rule.connectors.iter())
+        .find(|connector| !active_connectors.contains(&connector.to_string()));
+
+    match inactive_connector {
+        Some(connector) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Connector {connector} of the connector failover order is not configured and enabled for the merchant"
+            ),
+        })),
+        None => Ok(()),
+    }
+}
+
+/// Validate the least cost routing, which must have a cost model when it is enabled and at most
+/// one cost model per connector. Every fee must have a non-negative fixed fee, with the currency of
+/// the fixed fee when it is not zero, and every fee rule must have at least one condition.
+#[cfg(feature = "v1")]
+fn validate_least_cost_routing(
+    least_cost_routing: &admin_types::LeastCostRoutingConfig,
+) -> RouterResult<()> {
+    let invalid_request = |message: String| {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message
+        }))
+    };
+
+    if least_cost_routing.enabled && least_cost_routing.connector_costs.is_empty() {
+        return invalid_request(
+            "Least cost routing must have at least one connector cost model when it is enabled"
+                .to_string(),
+        );
+    }
+
+    let mut connectors = std::collections::HashSet::new();
+    for cost_model in &least_cost_routing.connector_costs {
+        if !connectors.insert(cost_model.connector) {
+            return invalid_request(format!(
+                "Connector {} has more than one cost model",
+                cost_model.connector
+            ));
+        }
+
+        for fee_rule in cost_model.fee_rules.iter().flatten() {
+            if fee_rule.payment_method.is_none()
+                && fee_rule.payment_method_type.is_none()
+                && fee_rule.countries.is_none()
+            {
+                return invalid_request(format!(
+                    "The fee rules of the cost model of connector {} must have at least one condition",
+                    cost_model.connector
+                ));
+            }
+            if fee_rule
+                .countries
+                .as_ref()
+                .is_some_and(|countries| countries.is_empty())
+            {
+                return invalid_request(format!(
+                    "The countries of the fee rules of the cost model of connector {} must not be empty",
+                    cost_model.connector
+                ));
+            }
+        }
+
+        for fee in std::iter::once(&cost_model.default_fee)
+            .chain(cost_model.fee_rules.iter().flatten().map(|rule| &rule.fee))
+        {
+            if fee.fixed_fee < common_utils::types::MinorUnit::zero() {
+                return invalid_request(format!(
+                    "The fixed fees of the cost model of connector {} must not be negative",
+                    cost_model.connector
+                ));
+            }
+            if fee.fixed_fee != common_utils::types::MinorUnit::zero() && fee.currency.is_none() {
+                return invalid_request(format!(
+                    "The fixed fees of the cost model of connector {} must have a currency",
+                    cost_model.connector
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate that there is at most one transaction amount limit per currency and payment method
+/// type, and that the amounts of each limit are not negative with the minimum amount not exceeding
+/// the maximum amount
+#[cfg(feature = "v1")]
+fn validate_transaction_amount_limits(
+    transaction_amount_limits: &[admin_types::TransactionAmountLimit],
+) -> RouterResult<()> {
+    let mut limit_keys = std::collections::HashSet::new();
+    if let Some(limit) = transaction_amount_limits
+        .iter()
+        .find(|limit| !limit_keys.insert((limit.currency, limit.payment_method_type)))
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Currency {} has more than one transaction amount limit for the payment method type {}",
+                limit.currency,
+                limit
+                    .payment_method_type
+                    .map_or("all".to_string(), |payment_method_type| payment_method_type
+                        .to_string())
+            ),
+        }));
+    }
+
+    match transaction_amount_limits.iter().find(|limit| {
+        [limit.min_amount, limit.max_amount]
+            .into_iter()
+            .flatten()
+            .any(|amount| amount < common_utils::types::MinorUnit::zero())
+            || limit
+                .min_amount
+                .zip(limit.max_amount)
+                .is_some_and(|(min_amount, max_amount)| min_amount > max_amount)
+    }) {
+        Some(limit) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The transaction amount limit of currency {} must have non negative amounts, with the minimum amount not exceeding the maximum amount",
+                limit.currency
+            ),
+        })),
+        None => Ok(()),
+    }
+}
+
+/// Validate that each default connector is configured as a payment processor for the merchant,
+/// with the payment method type it is the default for enabled
+#[cfg(feature = "v1")]
+async fn validate_default_connector_by_method_type(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    key_store: &domain::MerchantKeyStore,
+    default_connector_by_method_type: &HashMap<
+        api_enums::PaymentMethodType,
+        api_enums::RoutableConnectors,
+    >,
+) -> RouterResult<()> {
+    if default_connector_by_method_type.is_empty() {
+        return Ok(());
+    }
+
+    let merchant_connector_accounts = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &state.into(),
+            merchant_id,
+            false,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    for (payment_method_type, connector) in default_connector_by_method_type {
+        let is_supported = merchant_connector_accounts
+            .iter()
+            .filter(|mca| {
+                mca.connector_type == api_enums::ConnectorType::PaymentProcessor
+                    && mca.connector_name == connector.to_string()
+            })
+            .flat_map(|mca| mca.payment_methods_enabled.clone().unwrap_or_default())
+            .filter_map(|payment_methods_enabled| {
+                payment_methods_enabled
+                    .expose()
+                    .parse_value::<admin_types::PaymentMethodsEnabled>("PaymentMethodsEnabled")
+                    .ok()
+            })
+            .flat_map(|payment_methods_enabled| {
+                payment_methods_enabled
+                    .payment_method_types
+                    .unwrap_or_default()
+            })
+            .any(|payment_method_type_info| {
+                payment_method_type_info.payment_method_type == *payment_method_type
+            });
+
+        if !is_supported {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Connector {connector} is not configured with payment method type {payment_method_type} enabled"
+                ),
+            }));
+        }
+    }
+
+    Ok(())
+}
+
 struct ConnectorAuthTypeAndMetadataValidation<'a> {
     connector_name: &'a api_models::enums::Connector,
     auth_type: &'a types::ConnectorAuthType,
@@ -1698,8 +2260,88 @@ impl<'a> ConnectorStatusAndDisabledValidation<'a> {
     }
 }
 
-struct PaymentMethodsEnabled<'a> {
-    payment_methods_enabled: &'a Option<Vec<api_models::admin::PaymentMethodsEnabled>>,
+struct ConnectorRequestTimeoutValidation<'a> {
+    request_timeout_secs: &'a Option<u16>,
+}
+
+impl<'a> ConnectorRequestTimeoutValidation<'a> {
+    fn validate_request_timeout(&self) -> RouterResult<Option<i16>> {
+        self.request_timeout_secs
+            .map(|timeout| {
+                if !(consts::MIN_CONNECTOR_REQUEST_TIME_OUT
+                    ..=consts::MAX_CONNECTOR_REQUEST_TIME_OUT)
+                    .contains(&timeout)
+                {
+                    return Err(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!(
+                            "request_timeout_secs must be between {} and {} seconds",
+                            consts::MIN_CONNECTOR_REQUEST_TIME_OUT,
+                            consts::MAX_CONNECTOR_REQUEST_TIME_OUT
+                        ),
+                    }
+                    .into());
+                }
+                i16::try_from(timeout)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to convert request_timeout_secs")
+            })
+            .transpose()
+    }
+}
+
+struct ConnectorEnvironmentValidation<'a> {
+    environment: &'a Option<api_enums::ConnectorEnvironment>,
+    test_mode: Option<bool>,
+}
+
+impl<'a> ConnectorEnvironmentValidation<'a> {
+    fn validate_environment(&self) -> RouterResult<Option<api_enums::ConnectorEnvironment>> {
+        self.environment
+            .map(|environment| {
+                helpers::validate_connector_environment(environment, self.test_mode)
+                    .map(|()| environment)
+            })
+            .transpose()
+    }
+}
+
+struct ConnectorMaintenanceWindowsValidation<'a> {
+    maintenance_windows: &'a Option<Vec<api_models::admin::ConnectorMaintenanceWindow>>,
+}
+
+impl<'a> ConnectorMaintenanceWindowsValidation<'a> {
+    fn validate_maintenance_windows(&self) -> RouterResult<Option<ConnectorMaintenanceWindows>> {
+        self.maintenance_windows
+            .as_ref()
+            .map(|maintenance_windows| {
+                maintenance_windows.iter().try_for_each(|window| {
+                    let duration = window.end_time - window.start_time;
+                    utils::when(duration <= time::Duration::ZERO, || {
+                        Err(errors::ApiErrorResponse::InvalidRequestData {
+                            message: "end_time of a maintenance window must be after its start_time"
+                                .to_string(),
+                        })
+                    })?;
+                    // A recurring window must end before its next occurrence starts
+                    window.recurrence.map_or(Ok(()), |recurrence| {
+                        let interval = get_maintenance_window_recurrence_interval(recurrence);
+                        utils::when(duration >= interval, || {
+                            Err(errors::ApiErrorResponse::InvalidRequestData {
+                                message: format!(
+                                    "a {recurrence} maintenance window must be shorter than its recurrence interval"
+                                ),
+                            })
+                        })
+                    })
+                })?;
+                Ok(ForeignFrom::foreign_from(maintenance_windows.clone()))
+            })
+            .transpose()
+    }
+}
+
+struct PaymentMethodsEnabled<'a> {
+    payment_methods_enabled: &'a Option<Vec<api_models::admin::PaymentMethodsEnabled>>,
 }
 
 impl<'a> PaymentMethodsEnabled<'a> {
@@ -2055,6 +2697,19 @@ impl MerchantConnectorAccountUpdateBridge for api_models::admin::MerchantConnect
         };
         let (connector_status, disabled) =
             connector_status_and_disabled_validation.validate_status_and_disabled()?;
+        let request_timeout_secs = ConnectorRequestTimeoutValidation {
+            request_timeout_secs: &self.request_timeout_secs,
+        }
+        .validate_request_timeout()?;
+        let environment = ConnectorEnvironmentValidation {
+            environment: &self.environment,
+            test_mode: None,
+        }
+        .validate_environment()?;
+        let maintenance_windows = ConnectorMaintenanceWindowsValidation {
+            maintenance_windows: &self.maintenance_windows,
+        }
+        .validate_maintenance_windows()?;
 
         let pm_auth_config_validation = PMAuthConfigValidation {
             connector_type: &self.connector_type,
@@ -2140,6 +2795,9 @@ impl MerchantConnectorAccountUpdateBridge for api_models::admin::MerchantConnect
             status: Some(connector_status),
             additional_merchant_data: encrypted_data.additional_merchant_data,
             connector_wallets_details: encrypted_data.connector_wallets_details,
+            request_timeout_secs,
+            environment,
+            maintenance_windows,
         })
     }
 }
@@ -2220,6 +2878,19 @@ impl MerchantConnectorAccountUpdateBridge for api_models::admin::MerchantConnect
         };
         let (connector_status, disabled) =
             connector_status_and_disabled_validation.validate_status_and_disabled()?;
+        let request_timeout_secs = ConnectorRequestTimeoutValidation {
+            request_timeout_secs: &self.request_timeout_secs,
+        }
+        .validate_request_timeout()?;
+        let environment = ConnectorEnvironmentValidation {
+            environment: &self.environment,
+            test_mode: self.test_mode.or(mca.test_mode),
+        }
+        .validate_environment()?;
+        let maintenance_windows = ConnectorMaintenanceWindowsValidation {
+            maintenance_windows: &self.maintenance_windows,
+        }
+        .validate_maintenance_windows()?;
 
         if self.connector_type != api_enums::ConnectorType::PaymentMethodAuth {
             if let Some(val) = self.pm_auth_config.clone() {
@@ -2310,6 +2981,9 @@ impl MerchantConnectorAccountUpdateBridge for api_models::admin::MerchantConnect
             status: Some(connector_status),
             additional_merchant_data: encrypted_data.additional_merchant_data,
             connector_wallets_details: encrypted_data.connector_wallets_details,
+            request_timeout_secs,
+            environment,
+            maintenance_windows,
         })
     }
 }
@@ -2374,6 +3048,19 @@ impl MerchantConnectorAccountCreateBridge for api::MerchantConnectorCreate {
         };
         let (connector_status, disabled) =
             connector_status_and_disabled_validation.validate_status_and_disabled()?;
+        let request_timeout_secs = ConnectorRequestTimeoutValidation {
+            request_timeout_secs: &self.request_timeout_secs,
+        }
+        .validate_request_timeout()?;
+        let environment = ConnectorEnvironmentValidation {
+            environment: &self.environment,
+            test_mode: None,
+        }
+        .validate_environment()?;
+        let maintenance_windows = ConnectorMaintenanceWindowsValidation {
+            maintenance_windows: &self.maintenance_windows,
+        }
+        .validate_maintenance_windows()?;
         let identifier = km_types::Identifier::Merchant(business_profile.merchant_id.clone());
         let merchant_recipient_data = if let Some(data) = &self.additional_merchant_data {
             Some(
@@ -2459,6 +3146,12 @@ impl MerchantConnectorAccountCreateBridge for api::MerchantConnectorCreate {
             pm_auth_config: self.pm_auth_config.clone(),
             status: connector_status,
             connector_wallets_details: encrypted_data.connector_wallets_details,
+            request_timeout_secs,
+            webhook_identifier: None,
+            environment,
+            maintenance_windows,
+            pending_connector_account_details: None,
+            credential_rotation_status: None,
             additional_merchant_data: encrypted_data.additional_merchant_data,
             version: hyperswitch_domain_models::consts::API_VERSION,
         })
@@ -2543,6 +3236,21 @@ impl MerchantConnectorAccountCreateBridge for api::MerchantConnectorCreate {
         };
         let (connector_status, disabled) =
             connector_status_and_disabled_validation.validate_status_and_disabled()?;
+        let request_timeout_secs = ConnectorRequestTimeoutValidation {
+            request_timeout_secs: &self.request_timeout_secs,
+        }
+        .validate_request_timeout()?;
+        // Connector accounts belong to the environment of their test mode unless specified
+        let environment = ConnectorEnvironmentValidation {
+            environment: &self.environment,
+            test_mode: self.test_mode,
+        }
+        .validate_environment()?
+        .or_else(|| helpers::get_connector_account_environment(self.test_mode));
+        let maintenance_windows = ConnectorMaintenanceWindowsValidation {
+            maintenance_windows: &self.maintenance_windows,
+        }
+        .validate_maintenance_windows()?;
         let identifier = km_types::Identifier::Merchant(business_profile.merchant_id.clone());
         let merchant_recipient_data = if let Some(data) = &self.additional_merchant_data {
             Some(
@@ -2628,6 +3336,12 @@ impl MerchantConnectorAccountCreateBridge for api::MerchantConnectorCreate {
             pm_auth_config: self.pm_auth_config.clone(),
             status: connector_status,
             connector_wallets_details: encrypted_data.connector_wallets_details,
+            request_timeout_secs,
+            webhook_identifier: Some(webhook_identifier::generate_webhook_identifier()),
+            environment,
+            maintenance_windows,
+            pending_connector_account_details: None,
+            credential_rotation_status: None,
             test_mode: self.test_mode,
             business_country: self.business_country,
             business_label: self.business_label.clone(),
@@ -2746,6 +3460,20 @@ pub async fn create_connector(
     };
     let routable_connector = connector_type_and_connector_enum.get_routable_connector()?;
 
+    #[cfg(feature = "v1")]
+    if let Some(default_capture_method) = merchant_account.get_default_capture_method() {
+        if req.connector_type == api_enums::ConnectorType::PaymentProcessor
+            && req.disabled != Some(true)
+        {
+            validate_connector_supports_default_capture_method(
+                &state,
+                &req.connector_name.to_string(),
+                None,
+                default_capture_method,
+            )?;
+        }
+    }
+
     // The purpose of this merchant account update is just to update the
     // merchant account `modified_at` field for KGraph cache invalidation
     state
@@ -2787,6 +3515,19 @@ pub async fn create_connector(
             },
         )?;
 
+    // The lookup is inserted only once the merchant connector account exists, so that a failed
+    // insert of the account does not leave a lookup to an account which does not exist
+    #[cfg(feature = "v1")]
+    if let Some(webhook_identifier) = mca.webhook_identifier.as_deref() {
+        webhook_identifier::insert_webhook_identifier_lookup(
+            &state,
+            merchant_id,
+            &mca.get_id(),
+            webhook_identifier,
+        )
+        .await?;
+    }
+
     #[cfg(feature = "v1")]
     //update merchant default config
     let merchant_default_config_update = MerchantDefaultConfigUpdate {
@@ -3062,6 +3803,20 @@ pub async fn update_connector(
         .await?;
     core_utils::validate_profile_id_from_auth_layer(profile_id, &mca)?;
 
+    #[cfg(feature = "v1")]
+    if let Some(default_capture_method) = merchant_account.get_default_capture_method() {
+        if req.connector_type == api_enums::ConnectorType::PaymentProcessor
+            && req.disabled.or(mca.disabled) != Some(true)
+        {
+            validate_connector_supports_default_capture_method(
+                &state,
+                &mca.connector_name,
+                Some(mca.get_id()),
+                default_capture_method,
+            )?;
+        }
+    }
+
     let payment_connector = req
         .clone()
         .create_domain_model_from_request(
@@ -3156,6 +3911,465 @@ pub async fn delete_connector(
     Ok(service_api::ApplicationResponse::Json(response))
 }
 
+/// Replace the webhook identifier of the merchant connector account with a new one. Webhooks sent
+/// to the previous identifier are still accepted for a grace period, to allow the merchant to
+/// update the webhook endpoint at the connector.
+#[cfg(feature = "v1")]
+pub async fn rotate_connector_webhook_identifier(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+) -> RouterResponse<api_models::admin::MerchantConnectorResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let mca = db
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            key_manager_state,
+            &merchant_id,
+            &merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_string(),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &mca)?;
+
+    let previous_webhook_identifier = mca.webhook_identifier.clone();
+    let new_webhook_identifier = webhook_identifier::generate_webhook_identifier();
+
+    // The lookup is inserted before the identifier is assigned, so that the identifier can be
+    // resolved as soon as it is visible to the merchant
+    webhook_identifier::insert_webhook_identifier_lookup(
+        &state,
+        &merchant_id,
+        &merchant_connector_id,
+        &new_webhook_identifier,
+    )
+    .await?;
+
+    if let Some(previous_webhook_identifier) = previous_webhook_identifier.as_deref() {
+        webhook_identifier::retain_previous_webhook_identifier(&state, previous_webhook_identifier)
+            .await?;
+    }
+
+    let updated_mca = db
+        .update_merchant_connector_account(
+            key_manager_state,
+            mca,
+            storage::MerchantConnectorAccountUpdate::WebhookIdentifierUpdate {
+                webhook_identifier: new_webhook_identifier,
+            }
+            .into(),
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while updating the webhook identifier of MerchantConnectorAccount: id: {:?}",
+                merchant_connector_id
+            )
+        })?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        updated_mca.foreign_try_into()?,
+    ))
+}
+
+#[cfg(feature = "v1")]
+async fn find_merchant_connector_account_for_credential_rotation(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<(domain::MerchantKeyStore, domain::MerchantConnectorAccount)> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let mca = db
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            key_manager_state,
+            merchant_id,
+            merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_string(),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &mca)?;
+
+    Ok((key_store, mca))
+}
+
+#[cfg(feature = "v1")]
+async fn update_connector_credential_rotation(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    mca: domain::MerchantConnectorAccount,
+    mca_update: storage::MerchantConnectorAccountUpdate,
+) -> RouterResult<domain::MerchantConnectorAccount> {
+    let merchant_connector_id = mca.get_id();
+    state
+        .store
+        .update_merchant_connector_account(&state.into(), mca, mca_update.into(), key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while updating the credential rotation of MerchantConnectorAccount: id: {:?}",
+                merchant_connector_id
+            )
+        })
+}
+
+/// Store the new credentials of the connector account as its pending credentials. The current
+/// credentials continue to be used until the pending credentials are promoted.
+#[cfg(feature = "v1")]
+pub async fn rotate_connector_credentials(
+    state: SessionState,
+    profile_id: Option<id_type::ProfileId>,
+    req: admin_types::MerchantConnectorCredentialsRotateRequest,
+) -> RouterResponse<api_models::admin::MerchantConnectorResponse> {
+    let (key_store, mca) = find_merchant_connector_account_for_credential_rotation(
+        &state,
+        &req.merchant_id,
+        profile_id,
+        &req.merchant_connector_id,
+    )
+    .await?;
+
+    let auth: types::ConnectorAuthType = req
+        .connector_account_details
+        .clone()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "connector_account_details".to_string(),
+            expected_format: "auth_type and api_key".to_string(),
+        })?;
+    let connector_name = mca.connector_name.as_str();
+    let connector_enum = api_models::enums::Connector::from_str(connector_name)
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "connector",
+        })
+        .attach_printable_lazy(|| format!("unable to parse connector name {connector_name:?}"))?;
+    ConnectorAuthTypeAndMetadataValidation {
+        connector_name: &connector_enum,
+        auth_type: &auth,
+        connector_meta_data: &mca.metadata,
+    }
+    .validate_auth_and_metadata_type()?;
+
+    let pending_connector_account_details = domain_types::crypto_operation(
+        &(&state).into(),
+        type_name!(domain::MerchantConnectorAccount),
+        domain_types::CryptoOperation::Encrypt(req.connector_account_details),
+        km_types::Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Unable to encrypt the pending connector account details")?;
+
+    let updated_mca = update_connector_credential_rotation(
+        &state,
+        &key_store,
+        mca,
+        storage::MerchantConnectorAccountUpdate::CredentialRotationUpdate {
+            connector_account_details: None,
+            pending_connector_account_details: Some(pending_connector_account_details),
+            credential_rotation_status: api_enums::ConnectorCredentialRotationStatus::Pending,
+        },
+    )
+    .await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        updated_mca.foreign_try_into()?,
+    ))
+}
+
+/// Promote the pending credentials of the connector account to be its current credentials, once
+/// they are verified with a test call to the connector. The credentials which fail the test call
+/// are kept as pending, with the rotation marked as failed, so that they can be corrected.
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub async fn promote_connector_credentials(
+    state: SessionState,
+    profile_id: Option<id_type::ProfileId>,
+    req: admin_types::MerchantConnectorCredentialsPromoteRequest,
+) -> RouterResponse<api_models::admin::MerchantConnectorResponse> {
+    let (key_store, mca) = find_merchant_connector_account_for_credential_rotation(
+        &state,
+        &req.merchant_id,
+        profile_id,
+        &req.merchant_connector_id,
+    )
+    .await?;
+
+    let pending_connector_account_details = mca.pending_connector_account_details.clone().ok_or(
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: "The connector account has no pending credentials to be promoted".to_string(),
+        },
+    )?;
+
+    if !req.skip_test_call.unwrap_or(false) {
+        let connector_name = mca.connector_name.as_str();
+        let connector_enum = api_models::enums::Connector::from_str(connector_name)
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "connector",
+            })
+            .attach_printable_lazy(|| {
+                format!("unable to parse connector name {connector_name:?}")
+            })?;
+        let connector_account_details = pending_connector_account_details
+            .clone()
+            .into_inner()
+            .parse_value("ConnectorAuthType")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the pending connector account details")?;
+
+        let verification = crate::core::verify_connector::verify_connector_credentials(
+            state.clone(),
+            api_models::verify_connector::VerifyConnectorRequest {
+                connector_name: connector_enum,
+                connector_account_details,
+            },
+            None,
+        )
+        .await;
+
+        match verification {
+            Ok(_) => {}
+            // The credentials could not be verified, which is not a failure of the rotation
+            Err(error)
+                if matches!(
+                    error.current_context(),
+                    errors::ApiErrorResponse::FlowNotSupported { .. }
+                ) =>
+            {
+                return Err(error).attach_printable(
+                    "The credentials of the connector cannot be verified, skip_test_call is to be set to promote them",
+                );
+            }
+            Err(error) => {
+                update_connector_credential_rotation(
+                    &state,
+                    &key_store,
+                    mca,
+                    storage::MerchantConnectorAccountUpdate::CredentialRotationUpdate {
+                        connector_account_details: None,
+                        pending_connector_account_details: Some(pending_connector_account_details),
+                        credential_rotation_status:
+                            api_enums::ConnectorCredentialRotationStatus::Failed,
+                    },
+                )
+                .await?;
+                return Err(error);
+            }
+        }
+    }
+
+    let updated_mca = update_connector_credential_rotation(
+        &state,
+        &key_store,
+        mca,
+        storage::MerchantConnectorAccountUpdate::CredentialRotationUpdate {
+            connector_account_details: Some(pending_connector_account_details),
+            pending_connector_account_details: None,
+            credential_rotation_status: api_enums::ConnectorCredentialRotationStatus::Completed,
+        },
+    )
+    .await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        updated_mca.foreign_try_into()?,
+    ))
+}
+
+/// Whether the connector rejected the credentials of the connector account, which it reports with
+/// the 401 status code, or with one of the error codes the connector documents for authentication
+/// failures. A 403 status code is not an authentication failure, as the connectors report with it
+/// the operations which the credentials are not permitted to perform.
+pub fn is_connector_authentication_failure(
+    connector: api_enums::Connector,
+    error_response: &types::ErrorResponse,
+) -> bool {
+    error_response.status_code == 401
+        || connector
+            .get_authentication_failure_error_codes()
+            .contains(&error_response.code.as_str())
+}
+
+/// Fail over to the pending credentials of the connector account when the connector rejects the
+/// current credentials, so that the following payments are made with the pending credentials.
+///
+/// It is only done for the credentials which are pending, once they are verified with a test call
+/// to the connector. The credentials which fail the test call are kept as pending, with the
+/// rotation marked as failed. The current credentials are kept as the pending credentials once
+/// failed over, so that they can be promoted back. A fail over is attempted at most once per
+/// connector account within the fail over lock duration, with the connector account as it is
+/// stored then, as concurrent payments may fail with the same credentials.
+#[cfg(feature = "v1")]
+pub async fn fail_over_connector_credentials(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    mca: &domain::MerchantConnectorAccount,
+    error_response: &types::ErrorResponse,
+) -> RouterResult<()> {
+    if mca.credential_rotation_status != Some(api_enums::ConnectorCredentialRotationStatus::Pending)
+    {
+        return Ok(());
+    }
+    let connector_name = mca.connector_name.as_str();
+    let connector_enum = api_models::enums::Connector::from_str(connector_name)
+        .change_context(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "connector",
+        })
+        .attach_printable_lazy(|| format!("unable to parse connector name {connector_name:?}"))?;
+    if !is_connector_authentication_failure(connector_enum, error_response) {
+        return Ok(());
+    }
+
+    // The lock is left to expire, so that the credentials are not verified again by every payment
+    // failing with the same credentials
+    let fail_over_lock_key = format!(
+        "connector_credentials_fail_over_{}",
+        mca.get_id().get_string_repr()
+    );
+    let is_locked = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .set_key_if_not_exists_with_expiry(
+            &fail_over_lock_key,
+            "locked",
+            Some(consts::CONNECTOR_CREDENTIALS_FAIL_OVER_LOCK_DURATION),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to lock the fail over of the connector credentials")?;
+    if !matches!(is_locked, redis_interface::SetnxReply::KeySet) {
+        return Ok(());
+    }
+
+    let stored_mca = state
+        .store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &state.into(),
+            &mca.merchant_id,
+            &mca.get_id(),
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the connector account to fail over")?;
+
+    // The credentials may have been rotated since the payment was made with them
+    let Some(pending_connector_account_details) = stored_mca
+        .pending_connector_account_details
+        .clone()
+        .filter(|_| {
+            stored_mca.credential_rotation_status
+                == Some(api_enums::ConnectorCredentialRotationStatus::Pending)
+                && stored_mca.connector_account_details.peek()
+                    == mca.connector_account_details.peek()
+        })
+    else {
+        return Ok(());
+    };
+
+    let connector_account_details = pending_connector_account_details
+        .clone()
+        .into_inner()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the pending connector account details")?;
+
+    let verification = crate::core::verify_connector::verify_connector_credentials(
+        state.clone(),
+        api_models::verify_connector::VerifyConnectorRequest {
+            connector_name: connector_enum,
+            connector_account_details,
+        },
+        None,
+    )
+    .await;
+
+    match verification {
+        Ok(_) => {}
+        // The credentials which cannot be verified are not failed over to
+        Err(error)
+            if matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::FlowNotSupported { .. }
+            ) =>
+        {
+            crate::logger::info!(
+                merchant_connector_id = ?stored_mca.get_id(),
+                "Not failing over to the pending credentials of the connector account as they cannot be verified"
+            );
+            return Ok(());
+        }
+        Err(error) => {
+            update_connector_credential_rotation(
+                state,
+                key_store,
+                stored_mca,
+                storage::MerchantConnectorAccountUpdate::CredentialRotationUpdate {
+                    connector_account_details: None,
+                    pending_connector_account_details: Some(pending_connector_account_details),
+                    credential_rotation_status:
+                        api_enums::ConnectorCredentialRotationStatus::Failed,
+                },
+            )
+            .await?;
+            return Err(error).attach_printable(
+                "The pending credentials of the connector account failed verification",
+            );
+        }
+    }
+
+    let merchant_connector_id = stored_mca.get_id();
+    let primary_connector_account_details = stored_mca.connector_account_details.clone();
+    update_connector_credential_rotation(
+        state,
+        key_store,
+        stored_mca,
+        storage::MerchantConnectorAccountUpdate::CredentialRotationUpdate {
+            connector_account_details: Some(pending_connector_account_details),
+            pending_connector_account_details: Some(primary_connector_account_details),
+            credential_rotation_status: api_enums::ConnectorCredentialRotationStatus::FailedOver,
+        },
+    )
+    .await?;
+
+    crate::logger::warn!(
+        ?merchant_connector_id,
+        "Failed over to the pending credentials of the connector account as the connector rejected its current credentials"
+    );
+
+    Ok(())
+}
+
 #[cfg(feature = "v2")]
 pub async fn delete_connector(
     state: SessionState,
@@ -3340,6 +4554,192 @@ pub async fn check_merchant_account_kv_status(
     ))
 }
 
+/// Retrieve the connectors which would be tried for a payment, according to the connector failover
+/// order of the merchant. The connectors which are not configured and enabled for the merchant at
+/// the time, such as the connectors disabled after the failover order was set, are left out of the
+/// chain and reported separately.
+#[cfg(feature = "v1")]
+pub async fn retrieve_connector_failover_chain(
+    state: SessionState,
+    req: api_models::admin::ConnectorFailoverChainRequest,
+) -> RouterResponse<api_models::admin::ConnectorFailoverChainResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &req.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(key_manager_state, &req.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let failover_chain =
+        merchant_account.get_failover_chain(req.payment_method_type, req.currency, req.country);
+
+    let active_connectors = if failover_chain.is_empty() {
+        std::collections::HashSet::new()
+    } else {
+        get_active_payment_processors(&state, &req.merchant_id, &key_store).await?
+    };
+
+    let (connectors, inactive_connectors) = failover_chain
+        .into_iter()
+        .partition(|connector| active_connectors.contains(&connector.to_string()));
+
+    Ok(service_api::ApplicationResponse::Json(
+        api_models::admin::ConnectorFailoverChainResponse {
+            connectors,
+            inactive_connectors,
+        },
+    ))
+}
+
+pub async fn retrieve_merchant_onboarding_status(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<api_models::admin::MerchantOnboardingStatusResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(key_manager_state, &merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    get_merchant_onboarding_status_response(db, &merchant_account)
+        .await
+        .map(service_api::ApplicationResponse::Json)
+}
+
+/// Validate the fields of the update which are recorded in the onboarding audit log against the
+/// lengths of their columns
+fn validate_merchant_onboarding_status_update(
+    req: &api_models::admin::MerchantOnboardingStatusUpdateRequest,
+) -> RouterResult<()> {
+    let changed_by_length = req.changed_by.chars().count();
+    if changed_by_length == 0 || changed_by_length > ONBOARDING_AUDIT_LOG_FIELD_MAX_LENGTH {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "changed_by should be between 1 and {ONBOARDING_AUDIT_LOG_FIELD_MAX_LENGTH} characters"
+            ),
+        }));
+    }
+
+    if req
+        .reason
+        .as_ref()
+        .is_some_and(|reason| reason.chars().count() > ONBOARDING_AUDIT_LOG_FIELD_MAX_LENGTH)
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "reason should be at most {ONBOARDING_AUDIT_LOG_FIELD_MAX_LENGTH} characters long"
+            ),
+        }));
+    }
+
+    Ok(())
+}
+
+pub async fn update_merchant_onboarding_status(
+    state: SessionState,
+    req: api_models::admin::MerchantOnboardingStatusUpdateRequest,
+) -> RouterResponse<api_models::admin::MerchantOnboardingStatusResponse> {
+    validate_merchant_onboarding_status_update(&req)?;
+
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &req.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(key_manager_state, &req.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let current_status = merchant_account.onboarding_status;
+    if !current_status.can_transition_to(req.status) {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Merchant onboarding status cannot be changed from `{current_status}` to `{}`",
+                req.status
+            ),
+        }));
+    }
+
+    // The update is conditional on the current status, it is not found if the status was changed
+    // concurrently
+    let updated_merchant_account = db
+        .update_merchant_onboarding_status(
+            key_manager_state,
+            merchant_account,
+            req.status,
+            req.changed_by,
+            req.reason,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Merchant onboarding status was changed from `{current_status}` by another request"
+            ),
+        })?;
+
+    get_merchant_onboarding_status_response(db, &updated_merchant_account)
+        .await
+        .map(service_api::ApplicationResponse::Json)
+}
+
+async fn get_merchant_onboarding_status_response(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+) -> RouterResult<api_models::admin::MerchantOnboardingStatusResponse> {
+    let transitions = db
+        .find_merchant_onboarding_audit_logs_by_merchant_id(merchant_account.get_id())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch merchant onboarding audit logs")?
+        .into_iter()
+        .map(
+            |audit_log| api_models::admin::MerchantOnboardingTransition {
+                previous_status: audit_log.previous_status,
+                new_status: audit_log.new_status,
+                changed_by: audit_log.changed_by,
+                reason: audit_log.reason,
+                created_at: audit_log.created_at,
+            },
+        )
+        .collect();
+
+    let status = merchant_account.onboarding_status;
+    Ok(api_models::admin::MerchantOnboardingStatusResponse {
+        merchant_id: merchant_account.get_id().to_owned(),
+        status,
+        outstanding_requirements: status.get_outstanding_requirements(),
+        allowed_transitions: status.get_allowed_transitions(),
+        transitions,
+    })
+}
+
 pub fn get_frm_config_as_secret(
     frm_configs: Option<Vec<api_models::admin::FrmConfigs>>,
 ) -> Option<Vec<Secret<serde_json::Value>>> {
@@ -4566,3 +5966,287 @@ async fn locker_recipient_create_call(
 
     Ok(store_resp.card_reference)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_error_response(status_code: u16, code: &str, message: &str) -> types::ErrorResponse {
+        types::ErrorResponse {
+            code: code.to_string(),
+            message: message.to_string(),
+            reason: None,
+            status_code,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_is_connector_authentication_failure() {
+        assert!(is_connector_authentication_failure(
+            api_enums::Connector::Stripe,
+            &get_error_response(401, "invalid_request", "The request is invalid")
+        ));
+        // The credentials are not permitted to perform the operation
+        assert!(!is_connector_authentication_failure(
+            api_enums::Connector::Stripe,
+            &get_error_response(403, "forbidden", "Forbidden")
+        ));
+        // Reported with a successful status code, with an error code of the connector
+        assert!(is_connector_authentication_failure(
+            api_enums::Connector::Authorizedotnet,
+            &get_error_response(
+                200,
+                "E00007",
+                "User authentication failed due to invalid authentication values."
+            )
+        ));
+        assert!(!is_connector_authentication_failure(
+            api_enums::Connector::Stripe,
+            &get_error_response(200, "E00007", "Authentication failed")
+        ));
+        assert!(!is_connector_authentication_failure(
+            api_enums::Connector::Stripe,
+            &get_error_response(402, "card_declined", "Your card was declined.")
+        ));
+    }
+
+    fn get_onboarding_status_update_request(
+        changed_by: String,
+        reason: Option<String>,
+    ) -> api_models::admin::MerchantOnboardingStatusUpdateRequest {
+        api_models::admin::MerchantOnboardingStatusUpdateRequest {
+            merchant_id: id_type::MerchantId::default(),
+            status: api_enums::OnboardingStatus::DocsSubmitted,
+            changed_by,
+            reason,
+        }
+    }
+
+    #[test]
+    fn test_onboarding_status_update_within_the_column_lengths_is_valid() {
+        let req = get_onboarding_status_update_request(
+            "ü".repeat(ONBOARDING_AUDIT_LOG_FIELD_MAX_LENGTH),
+            Some("KYC documents received".to_string()),
+        );
+
+        assert!(validate_merchant_onboarding_status_update(&req).is_ok());
+    }
+
+    #[test]
+    fn test_onboarding_status_update_with_an_empty_or_too_long_changed_by_is_invalid() {
+        let empty_changed_by = get_onboarding_status_update_request(String::new(), None);
+        let long_changed_by = get_onboarding_status_update_request(
+            "a".repeat(ONBOARDING_AUDIT_LOG_FIELD_MAX_LENGTH + 1),
+            None,
+        );
+
+        assert!(validate_merchant_onboarding_status_update(&empty_changed_by).is_err());
+        assert!(validate_merchant_onboarding_status_update(&long_changed_by).is_err());
+    }
+
+    #[cfg(feature = "v1")]
+    fn get_pm_filters() -> crate::configs::settings::ConnectorFilters {
+        use crate::configs::settings::{
+            ConnectorFilters, CurrencyCountryFlowFilter, PaymentMethodFilterKey,
+            PaymentMethodFilters,
+        };
+
+        let filter = |currencies: Option<Vec<api_enums::Currency>>| CurrencyCountryFlowFilter {
+            currency: currencies.map(|currencies| currencies.into_iter().collect()),
+            country: None,
+            not_available_flows: None,
+        };
+        ConnectorFilters(HashMap::from([
+            (
+                "stripe".to_string(),
+                PaymentMethodFilters(HashMap::from([
+                    (
+                        PaymentMethodFilterKey::PaymentMethodType(
+                            api_enums::PaymentMethodType::Credit,
+                        ),
+                        filter(Some(vec![
+                            api_enums::Currency::USD,
+                            api_enums::Currency::EUR,
+                        ])),
+                    ),
+                    (
+                        PaymentMethodFilterKey::PaymentMethodType(
+                            api_enums::PaymentMethodType::Klarna,
+                        ),
+                        filter(Some(vec![api_enums::Currency::SEK])),
+                    ),
+                ])),
+            ),
+            (
+                "adyen".to_string(),
+                PaymentMethodFilters(HashMap::from([(
+                    PaymentMethodFilterKey::PaymentMethodType(api_enums::PaymentMethodType::Credit),
+                    filter(None),
+                )])),
+            ),
+        ]))
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_currency_is_supported_by_any_payment_method_filter_of_the_connector() {
+        let pm_filters = get_pm_filters();
+
+        assert!(is_currency_supported_by_connector(
+            &pm_filters,
+            "stripe",
+            api_enums::Currency::USD
+        ));
+        assert!(is_currency_supported_by_connector(
+            &pm_filters,
+            "stripe",
+            api_enums::Currency::SEK
+        ));
+        assert!(!is_currency_supported_by_connector(
+            &pm_filters,
+            "stripe",
+            api_enums::Currency::JPY
+        ));
+        // A filter which does not restrict the currencies supports every currency
+        assert!(is_currency_supported_by_connector(
+            &pm_filters,
+            "adyen",
+            api_enums::Currency::JPY
+        ));
+        // A connector without filters supports every currency
+        assert!(is_currency_supported_by_connector(
+            &pm_filters,
+            "checkout",
+            api_enums::Currency::JPY
+        ));
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_default_currency_must_be_supported_by_a_payment_processor() {
+        let pm_filters = get_pm_filters();
+
+        assert!(validate_default_currency_is_supported(
+            &pm_filters,
+            &["stripe"],
+            api_enums::Currency::EUR
+        )
+        .is_ok());
+        assert!(validate_default_currency_is_supported(
+            &pm_filters,
+            &["stripe"],
+            api_enums::Currency::JPY
+        )
+        .is_err());
+        assert!(validate_default_currency_is_supported(
+            &pm_filters,
+            &["stripe", "adyen"],
+            api_enums::Currency::JPY
+        )
+        .is_ok());
+        // The default currency of a merchant without payment processors is not validated
+        assert!(
+            validate_default_currency_is_supported(&pm_filters, &[], api_enums::Currency::JPY)
+                .is_ok()
+        );
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_default_currency_cannot_be_configured_when_the_currency_is_required() {
+        assert!(validate_default_currency_is_applicable(
+            Some(api_enums::Currency::USD),
+            Some(true)
+        )
+        .is_err());
+        assert!(validate_default_currency_is_applicable(
+            Some(api_enums::Currency::USD),
+            Some(false)
+        )
+        .is_ok());
+        assert!(validate_default_currency_is_applicable(None, Some(true)).is_ok());
+    }
+
+    #[cfg(feature = "v1")]
+    fn get_merchant_account(
+        default_currency: Option<api_enums::Currency>,
+        require_explicit_currency: Option<bool>,
+    ) -> domain::MerchantAccount {
+        let now = date_time::now();
+        domain::MerchantAccount::from(domain::MerchantAccountSetter {
+            merchant_id: id_type::MerchantId::default(),
+            return_url: None,
+            enable_payment_response_hash: false,
+            payment_response_hash_key: None,
+            redirect_to_merchant_with_http_post: false,
+            merchant_name: None,
+            merchant_details: None,
+            webhook_details: None,
+            sub_merchants_enabled: None,
+            parent_merchant_id: None,
+            publishable_key: create_merchant_publishable_key(),
+            storage_scheme: MerchantStorageScheme::PostgresOnly,
+            locker_id: None,
+            metadata: None,
+            routing_algorithm: None,
+            primary_business_details: serde_json::json!([]),
+            frm_routing_algorithm: None,
+            created_at: now,
+            modified_at: now,
+            intent_fulfillment_time: None,
+            payout_routing_algorithm: None,
+            organization_id: id_type::OrganizationId::default(),
+            is_recon_enabled: false,
+            default_profile: None,
+            recon_status: diesel_models::enums::ReconStatus::NotRequested,
+            payment_link_config: None,
+            pm_collect_link_config: None,
+            version: hyperswitch_domain_models::consts::API_VERSION,
+            onboarding_status: common_enums::OnboardingStatus::default(),
+            idempotency_ttl_in_seconds: None,
+            customer_pii_encryption_fields: None,
+            statement_descriptor_length_policy: None,
+            allowed_return_url_domains: None,
+            connector_failover_order: None,
+            default_capture_method: None,
+            least_cost_routing: None,
+            transaction_amount_limits: None,
+            default_currency,
+            require_explicit_currency,
+            default_connector_by_method_type: None,
+        })
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_default_currency_is_disabled_when_the_currency_is_required() {
+        assert_eq!(
+            get_merchant_account(Some(api_enums::Currency::USD), None).get_default_currency(),
+            Some(api_enums::Currency::USD)
+        );
+        assert_eq!(
+            get_merchant_account(Some(api_enums::Currency::USD), Some(false))
+                .get_default_currency(),
+            Some(api_enums::Currency::USD)
+        );
+        assert_eq!(
+            get_merchant_account(Some(api_enums::Currency::USD), Some(true)).get_default_currency(),
+            None
+        );
+        assert_eq!(
+            get_merchant_account(None, None).get_default_currency(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_onboarding_status_update_with_a_too_long_reason_is_invalid() {
+        let req = get_onboarding_status_update_request(
+            "compliance@example.com".to_string(),
+            Some("a".repeat(ONBOARDING_AUDIT_LOG_FIELD_MAX_LENGTH + 1)),
+        );
+
+        assert!(validate_merchant_onboarding_status_update(&req).is_err());
+    }
+}
@@ -8,9 +8,12 @@ use router_env::{instrument, metrics::add_attributes, tracing};
 use crate::{
     configs::settings,
     consts,
-    core::errors::{self, RouterResponse, StorageErrorExt},
+    core::{
+        audit_log,
+        errors::{self, RouterResponse, StorageErrorExt},
+    },
     db::domain,
-    routes::{metrics, SessionState},
+    routes::{app::ReqState, metrics, SessionState},
     services::{authentication, ApplicationResponse},
     types::{api, storage, transformers::ForeignInto},
     utils,
@@ -114,6 +117,7 @@ pub async fn create_api_key(
     state: SessionState,
     api_key: api::CreateApiKeyRequest,
     key_store: domain::MerchantKeyStore,
+    req_state: ReqState,
 ) -> RouterResponse<api::CreateApiKeyResponse> {
     let api_key_config = state.conf.api_keys.get_inner();
     let store = state.store.as_ref();
@@ -134,11 +138,16 @@ pub async fn create_api_key(
         last_used: None,
     };
 
-    let api_key = store
-        .insert_api_key(api_key)
-        .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to insert new API key")?;
+    let api_key = match audit_log::get_audit_log_context(&state, &req_state) {
+        Some(audit_log) => {
+            store
+                .insert_api_key_with_audit_log(api_key, audit_log)
+                .await
+        }
+        None => store.insert_api_key(api_key).await,
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to insert new API key")?;
 
     let state_inner = state.clone();
     let hashed_api_key = api_key.hashed_api_key.clone();
@@ -275,19 +284,34 @@ pub async fn retrieve_api_key(
 pub async fn update_api_key(
     state: SessionState,
     api_key: api::UpdateApiKeyRequest,
+    req_state: ReqState,
 ) -> RouterResponse<api::RetrieveApiKeyResponse> {
     let merchant_id = api_key.merchant_id.clone();
     let key_id = api_key.key_id.clone();
     let store = state.store.as_ref();
 
-    let api_key = store
-        .update_api_key(
-            merchant_id.to_owned(),
-            key_id.to_owned(),
-            api_key.foreign_into(),
-        )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound)?;
+    let api_key = match audit_log::get_audit_log_context(&state, &req_state) {
+        Some(audit_log) => {
+            store
+                .update_api_key_with_audit_log(
+                    merchant_id.to_owned(),
+                    key_id.to_owned(),
+                    api_key.foreign_into(),
+                    audit_log,
+                )
+                .await
+        }
+        None => {
+            store
+                .update_api_key(
+                    merchant_id.to_owned(),
+                    key_id.to_owned(),
+                    api_key.foreign_into(),
+                )
+                .await
+        }
+    }
+    .to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound)?;
 
     let state_inner = state.clone();
     let hashed_api_key = api_key.hashed_api_key.clone();
@@ -431,6 +455,7 @@ pub async fn revoke_api_key(
     state: SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
     key_id: &str,
+    req_state: ReqState,
 ) -> RouterResponse<api::RevokeApiKeyResponse> {
     let store = state.store.as_ref();
 
@@ -439,10 +464,15 @@ pub async fn revoke_api_key(
         .await
         .to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound)?;
 
-    let revoked = store
-        .revoke_api_key(merchant_id, key_id)
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound)?;
+    let revoked = match audit_log::get_audit_log_context(&state, &req_state) {
+        Some(audit_log) => {
+            store
+                .revoke_api_key_with_audit_log(merchant_id, key_id, audit_log)
+                .await
+        }
+        None => store.revoke_api_key(merchant_id, key_id).await,
+    }
+    .to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound)?;
 
     if let Some(api_key) = api_key {
         let hashed_api_key = api_key.hashed_api_key;
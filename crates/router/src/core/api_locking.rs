@@ -50,11 +50,14 @@ impl LockingInput {
 }
 
 impl LockAction {
+    /// Acquire the lock for the locking input. The lock expires after `lock_expiry_in_seconds` when
+    /// provided, or after the expiry configured for the application otherwise.
     #[instrument(skip_all)]
     pub async fn perform_locking_action<A>(
         self,
         state: &A,
         merchant_id: common_utils::id_type::MerchantId,
+        lock_expiry_in_seconds: Option<i64>,
     ) -> RouterResult<()>
     where
         A: SessionStateInfo,
@@ -71,8 +74,9 @@ impl LockAction {
                     .conf()
                     .lock_settings
                     .delay_between_retries_in_milliseconds;
-                let redis_lock_expiry_seconds =
-                    state.conf().lock_settings.redis_lock_expiry_seconds;
+                let redis_lock_expiry_seconds = lock_expiry_in_seconds.unwrap_or_else(|| {
+                    i64::from(state.conf().lock_settings.redis_lock_expiry_seconds)
+                });
                 let lock_retries = input
                     .override_lock_retries
                     .unwrap_or(state.conf().lock_settings.lock_retries);
@@ -81,7 +85,7 @@ impl LockAction {
                         .set_key_if_not_exists_with_expiry(
                             redis_locking_key.as_str(),
                             state.get_request_id(),
-                            Some(i64::from(redis_lock_expiry_seconds)),
+                            Some(redis_lock_expiry_seconds),
                         )
                         .await;
 
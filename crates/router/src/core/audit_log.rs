@@ -0,0 +1,189 @@
+use common_utils::{date_time, ext_traits::ValueExt};
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    configs::settings::AuditLogConfig,
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        utils,
+    },
+    db::StorageInterface,
+    routes::{app::ReqState, SessionState},
+    services::ApplicationResponse,
+    types::{api::admin, storage},
+};
+
+const AUDIT_LOG_PURGE_TASK: &str = "AUDIT_LOG_PURGE";
+const AUDIT_LOG_PURGE_TAG: &str = "AUDIT_LOG";
+
+/// The fields whose values are never recorded in the audit log, along with the fields configured
+/// to be redacted
+pub const DEFAULT_REDACTED_FIELDS: &[&str] = &[
+    "merchant_name",
+    "merchant_details",
+    "webhook_details",
+    "payment_response_hash_key",
+    "publishable_key",
+    "metadata",
+    "hashed_api_key",
+];
+
+/// The actor recorded for changes made by requests whose authentication is not known
+const UNKNOWN_ACTOR: &str = "unknown";
+
+const AUDIT_LOG_DEFAULT_LIMIT: i64 = 10;
+const AUDIT_LOG_MAX_LIMIT: i64 = 100;
+
+/// Get the context with which the changes made by the request are to be recorded in the audit
+/// log, or `None` if the audit log is disabled
+pub fn get_audit_log_context(
+    state: &SessionState,
+    req_state: &ReqState,
+) -> Option<storage::AuditLogContext> {
+    let config = &state.conf.audit_log;
+
+    config.enabled.then(|| storage::AuditLogContext {
+        actor: req_state
+            .auth_type
+            .as_ref()
+            .map(|auth_type| auth_type.get_actor())
+            .unwrap_or_else(|| UNKNOWN_ACTOR.to_string()),
+        redacted_fields: get_redacted_fields(config),
+    })
+}
+
+/// Get the fields whose values are not recorded, which are the fields always redacted along with
+/// the fields configured to be redacted
+fn get_redacted_fields(config: &AuditLogConfig) -> Vec<String> {
+    let mut redacted_fields = DEFAULT_REDACTED_FIELDS
+        .iter()
+        .map(|field| field.to_string())
+        .collect::<Vec<_>>();
+
+    for field in &config.redacted_fields {
+        if !redacted_fields.contains(field) {
+            redacted_fields.push(field.to_owned());
+        }
+    }
+
+    redacted_fields
+}
+
+fn get_retention(state: &SessionState) -> time::Duration {
+    time::Duration::days(state.conf.audit_log.retention_in_days.into())
+}
+
+/// The time of the next purge of the entries past the retention period
+pub fn get_next_purge_schedule_time(config: &AuditLogConfig) -> time::PrimitiveDateTime {
+    date_time::now().saturating_add(time::Duration::seconds(i64::from(
+        config.purge_interval_in_secs,
+    )))
+}
+
+/// Schedule the recurring task which purges the entries of the audit log past the retention
+/// period. A single task is scheduled for the store, which reschedules itself after every run.
+/// The entries are purged separately from the changes they record, so that the changes are not
+/// slowed down by the purge.
+#[instrument(skip_all)]
+pub async fn add_purge_task(
+    db: &dyn StorageInterface,
+    config: &AuditLogConfig,
+) -> RouterResult<()> {
+    utils::add_recurring_process_tracker_task(
+        db,
+        storage::ProcessTrackerRunner::AuditLogPurgeWorkflow,
+        AUDIT_LOG_PURGE_TASK,
+        AUDIT_LOG_PURGE_TAG,
+        serde_json::json!({}),
+        get_next_purge_schedule_time(config),
+        "AuditLogPurge",
+    )
+    .await
+}
+
+/// Purge the entries of the audit log of all the merchants which are past the retention period
+#[instrument(skip_all)]
+pub async fn purge_expired_audit_logs(state: &SessionState) -> RouterResult<()> {
+    state
+        .store
+        .delete_audit_logs_created_before(date_time::now().saturating_sub(get_retention(state)))
+        .await
+        .map(|_| ())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to purge the expired audit logs")
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_audit_logs(
+    state: SessionState,
+    req: admin::AuditLogRetrieveRequest,
+) -> RouterResponse<admin::AuditLogResponse> {
+    let limit = req.limit.unwrap_or(AUDIT_LOG_DEFAULT_LIMIT);
+    if !(1..=AUDIT_LOG_MAX_LIMIT).contains(&limit) {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("limit should be between 1 and {AUDIT_LOG_MAX_LIMIT}"),
+        }
+        .into());
+    }
+
+    let created_after = common_utils::date_time::now().saturating_sub(get_retention(&state));
+    let audit_logs = state
+        .store
+        .find_audit_logs_by_resource(
+            &req.merchant_id,
+            req.resource_type,
+            &req.resource_id,
+            created_after,
+            Some(limit),
+            req.offset,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the audit logs")?;
+
+    let entries = audit_logs
+        .into_iter()
+        .map(|audit_log| {
+            let changes: Vec<admin::AuditLogFieldChange> = audit_log
+                .changes
+                .parse_value("AuditLogFieldChange")
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+            Ok(admin::AuditLogEntry {
+                actor: audit_log.actor,
+                action: audit_log.action,
+                changes,
+                created_at: audit_log.created_at,
+            })
+        })
+        .collect::<errors::RouterResult<Vec<_>>>()?;
+
+    Ok(ApplicationResponse::Json(admin::AuditLogResponse {
+        merchant_id: req.merchant_id,
+        resource_type: req.resource_type,
+        resource_id: req.resource_id,
+        entries,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_configured_redacted_fields_extend_the_defaults() {
+        let config = AuditLogConfig {
+            redacted_fields: vec!["return_url".to_string(), "metadata".to_string()],
+            ..AuditLogConfig::default()
+        };
+
+        let redacted_fields = get_redacted_fields(&config);
+
+        assert!(DEFAULT_REDACTED_FIELDS
+            .iter()
+            .all(|field| redacted_fields.contains(&field.to_string())));
+        assert!(redacted_fields.contains(&"return_url".to_string()));
+        assert_eq!(redacted_fields.len(), DEFAULT_REDACTED_FIELDS.len() + 1);
+    }
+}
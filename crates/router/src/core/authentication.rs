@@ -1,5 +1,6 @@
 pub(crate) mod utils;
 
+pub mod three_ds_session;
 pub mod transformers;
 pub mod types;
 
@@ -8,6 +9,7 @@ use common_enums::Currency;
 use common_utils::errors::CustomResult;
 use error_stack::ResultExt;
 use masking::ExposeInterface;
+use router_env::logger;
 
 use super::errors::StorageErrorExt;
 use crate::{
@@ -69,6 +71,13 @@ pub async fn perform_authentication(
     .await?;
     let authentication =
         utils::update_trackers(state, response.clone(), authentication_data, None).await?;
+    if authentication.trans_status == Some(common_enums::TransactionStatus::ChallengeRequired) {
+        // The challenge can still be completed without a session, so a failure to persist the
+        // session does not fail the authentication
+        let _ = three_ds_session::create_three_ds_session(state, &authentication)
+            .await
+            .inspect_err(|error| logger::error!(?error, "Failed to create 3DS session"));
+    }
     response
         .response
         .map_err(|err| ApiErrorResponse::ExternalConnectorError {
@@ -104,6 +113,21 @@ pub async fn perform_post_authentication(
         .await
         .to_not_found_response(ApiErrorResponse::InternalServerError)
         .attach_printable_lazy(|| format!("Error while fetching authentication record with authentication_id {authentication_id}"))?;
+    if let Some(ds_trans_id) = authentication.ds_trans_id.as_ref() {
+        if let Some(session) = three_ds_session::find_three_ds_session(
+            state,
+            &business_profile.merchant_id,
+            ds_trans_id,
+        )
+        .await?
+        {
+            if let three_ds_session::ThreeDsSessionCompletion::AlreadyCompleted(_) =
+                three_ds_session::complete_three_ds_session(state, session).await?
+            {
+                return Ok(authentication);
+            }
+        }
+    }
     if !authentication.authentication_status.is_terminal_status() && is_pull_mechanism_enabled {
         let router_data = transformers::construct_post_authentication_router_data(
             authentication_connector.to_string(),
@@ -0,0 +1,246 @@
+//! Persistence of the state of a 3DS challenge flow between the requests of the flow.
+//!
+//! A session is created once the issuer requests a challenge, and is looked up by the DS
+//! transaction id when the result of the challenge and the completion of the challenge arrive.
+//! The challenge is to be completed within [`consts::THREE_DS_SESSION_TTL`], after which the
+//! session is considered expired. The expired sessions are deleted by the data retention purge.
+
+use error_stack::ResultExt;
+use router_env::logger;
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResult},
+    routes::SessionState,
+    types::storage::{self, enums},
+};
+
+/// The outcome of the completion of a challenge
+#[derive(Debug)]
+pub enum ThreeDsSessionCompletion {
+    /// The session was completed by this request
+    Completed(storage::ThreeDsSession),
+    /// The session had already been completed by an earlier request, the completion is to be
+    /// treated as a duplicate
+    AlreadyCompleted(storage::ThreeDsSession),
+}
+
+pub fn get_flow_state(
+    authentication: &storage::Authentication,
+) -> storage::ThreeDsSessionFlowState {
+    storage::ThreeDsSessionFlowState {
+        threeds_server_transaction_id: authentication.threeds_server_transaction_id.clone(),
+        acs_trans_id: authentication.acs_trans_id.clone(),
+        acs_url: authentication.acs_url.clone(),
+        challenge_request: authentication.challenge_request.clone(),
+        message_version: authentication.message_version.clone(),
+        trans_status: authentication.trans_status.clone(),
+    }
+}
+
+/// Create a session for the challenge requested during the authentication.
+///
+/// Returns `None` if the authentication does not carry a DS transaction id to key the session by.
+pub async fn create_three_ds_session(
+    state: &SessionState,
+    authentication: &storage::Authentication,
+) -> RouterResult<Option<storage::ThreeDsSession>> {
+    let Some(ds_trans_id) = authentication.ds_trans_id.clone() else {
+        return Ok(None);
+    };
+    let now = common_utils::date_time::now();
+    let session = storage::ThreeDsSessionNew {
+        session_id: common_utils::generate_id_with_default_len(consts::THREE_DS_SESSION_ID_PREFIX),
+        merchant_id: authentication.merchant_id.clone(),
+        authentication_id: authentication.authentication_id.clone(),
+        ds_trans_id,
+        status: enums::ThreeDsSessionStatus::ChallengeRequired,
+        flow_state: get_flow_state(authentication),
+        expires_at: now.saturating_add(time::Duration::seconds(consts::THREE_DS_SESSION_TTL)),
+        created_at: now,
+        modified_at: now,
+    };
+
+    state
+        .store
+        .insert_three_ds_session(session)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert 3DS session")
+        .map(Some)
+}
+
+/// Find the session of the provided DS transaction id, if any
+pub async fn find_three_ds_session(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    ds_trans_id: &str,
+) -> RouterResult<Option<storage::ThreeDsSession>> {
+    match state
+        .store
+        .find_three_ds_session_by_merchant_id_ds_trans_id(merchant_id, ds_trans_id)
+        .await
+    {
+        Ok(session) => Ok(Some(session)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch 3DS session"),
+    }
+}
+
+/// Store the result of the challenge reported by the 3DS server on the session of the
+/// authentication, if the session is still awaiting the challenge
+pub async fn store_challenge_result(
+    state: &SessionState,
+    authentication: &storage::Authentication,
+) -> RouterResult<()> {
+    let Some(ds_trans_id) = authentication.ds_trans_id.as_ref() else {
+        return Ok(());
+    };
+    match find_three_ds_session(state, &authentication.merchant_id, ds_trans_id).await? {
+        Some(session) if session.status == enums::ThreeDsSessionStatus::ChallengeRequired => {
+            update_three_ds_session_flow_state(state, session, get_flow_state(authentication))
+                .await
+                .map(|_| ())
+        }
+        Some(_) | None => Ok(()),
+    }
+}
+
+/// Store the state of the flow reached by an intermediate step of the challenge
+pub async fn update_three_ds_session_flow_state(
+    state: &SessionState,
+    session: storage::ThreeDsSession,
+    flow_state: storage::ThreeDsSessionFlowState,
+) -> RouterResult<storage::ThreeDsSession> {
+    let session = ensure_session_is_active(state, session).await?;
+    state
+        .store
+        .update_three_ds_session_by_session_id_status(
+            &session.session_id,
+            enums::ThreeDsSessionStatus::ChallengeRequired,
+            storage::ThreeDsSessionUpdate {
+                status: None,
+                flow_state: Some(flow_state),
+                modified_at: common_utils::date_time::now(),
+            },
+        )
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "The 3DS session is no longer awaiting the challenge".to_string(),
+                })
+            } else {
+                error.change_context(errors::ApiErrorResponse::InternalServerError)
+            }
+        })
+        .attach_printable("Failed to update 3DS session")
+}
+
+/// Mark the challenge of the session as completed.
+///
+/// A completion of a session which has already been completed is reported as
+/// [`ThreeDsSessionCompletion::AlreadyCompleted`], while a completion of an expired session fails.
+pub async fn complete_three_ds_session(
+    state: &SessionState,
+    session: storage::ThreeDsSession,
+) -> RouterResult<ThreeDsSessionCompletion> {
+    if session.status == enums::ThreeDsSessionStatus::Completed {
+        logger::info!(session_id = %session.session_id, "3DS session has already been completed");
+        return Ok(ThreeDsSessionCompletion::AlreadyCompleted(session));
+    }
+    let session = ensure_session_is_active(state, session).await?;
+
+    let update_result = state
+        .store
+        .update_three_ds_session_by_session_id_status(
+            &session.session_id,
+            enums::ThreeDsSessionStatus::ChallengeRequired,
+            storage::ThreeDsSessionUpdate {
+                status: Some(enums::ThreeDsSessionStatus::Completed),
+                flow_state: None,
+                modified_at: common_utils::date_time::now(),
+            },
+        )
+        .await;
+
+    match update_result {
+        Ok(session) => Ok(ThreeDsSessionCompletion::Completed(session)),
+        // The session was moved out of the challenge by a concurrent request
+        Err(error) if error.current_context().is_db_not_found() => {
+            let current_session =
+                find_three_ds_session(state, &session.merchant_id, &session.ds_trans_id)
+                    .await?
+                    .ok_or(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("3DS session not found after a failed update")?;
+            if current_session.status == enums::ThreeDsSessionStatus::Completed {
+                Ok(ThreeDsSessionCompletion::AlreadyCompleted(current_session))
+            } else {
+                Err(expired_session_error())
+            }
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to complete 3DS session"),
+    }
+}
+
+/// Delete the sessions which have expired, in batches of the oldest sessions first. Returns the
+/// number of the sessions deleted.
+pub async fn purge_expired_three_ds_sessions(state: &SessionState) -> RouterResult<usize> {
+    let config = &state.conf.data_retention;
+    let now = common_utils::date_time::now();
+    let mut purged_count = 0;
+
+    for _ in 0..config.max_batches_per_run {
+        let count = state
+            .store
+            .delete_expired_three_ds_sessions(now, i64::from(config.batch_size))
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to delete the expired 3DS sessions")?;
+        purged_count += count;
+
+        if count < usize::from(config.batch_size) {
+            break;
+        }
+    }
+
+    Ok(purged_count)
+}
+
+fn expired_session_error() -> error_stack::Report<errors::ApiErrorResponse> {
+    error_stack::report!(errors::ApiErrorResponse::PreconditionFailed {
+        message: "The 3DS session has expired".to_string(),
+    })
+}
+
+/// Fail if the session has expired, marking the session as expired if it had not been already
+async fn ensure_session_is_active(
+    state: &SessionState,
+    session: storage::ThreeDsSession,
+) -> RouterResult<storage::ThreeDsSession> {
+    if !session.is_expired(common_utils::date_time::now()) {
+        return Ok(session);
+    }
+
+    if session.status == enums::ThreeDsSessionStatus::ChallengeRequired {
+        let _ = state
+            .store
+            .update_three_ds_session_by_session_id_status(
+                &session.session_id,
+                enums::ThreeDsSessionStatus::ChallengeRequired,
+                storage::ThreeDsSessionUpdate {
+                    status: Some(enums::ThreeDsSessionStatus::Expired),
+                    flow_state: None,
+                    modified_at: common_utils::date_time::now(),
+                },
+            )
+            .await
+            .inspect_err(|error| logger::error!(?error, "Failed to mark 3DS session as expired"));
+    }
+
+    Err(expired_session_error())
+}
@@ -154,6 +154,7 @@ pub fn construct_router_data<F: Clone, Req, Res>(
         auth_type: common_enums::AuthenticationType::NoThreeDs,
         connector_meta_data: merchant_connector_account.get_metadata(),
         connector_wallets_details: merchant_connector_account.get_connector_wallets_details(),
+        connector_request_timeout_secs: merchant_connector_account.get_connector_request_timeout(),
         amount_captured: None,
         minor_amount_captured: None,
         access_token: None,
@@ -175,6 +176,7 @@ pub fn construct_router_data<F: Clone, Req, Res>(
         test_mode,
         connector_http_status_code: None,
         external_latency: None,
+        connector_network_retry_count: None,
         apple_pay_flow: None,
         frm_metadata: None,
         dispute_id: None,
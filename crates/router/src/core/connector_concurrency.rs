@@ -0,0 +1,40 @@
+use api_models::connector_concurrency as connector_concurrency_api;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse},
+    routes::SessionState,
+    services::{connector_concurrency, ApplicationResponse},
+};
+
+#[instrument(skip_all)]
+pub async fn retrieve_connector_concurrency(
+    state: SessionState,
+    req: connector_concurrency_api::ConnectorConcurrencyRequest,
+) -> RouterResponse<connector_concurrency_api::ConnectorConcurrencyResponse> {
+    let config = &state.conf.connector_concurrency;
+    if !config.enabled {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Connector concurrency limits are not enabled".to_string(),
+        }));
+    }
+
+    let connector = req.connector.to_string();
+    let limit = connector_concurrency::get_connector_concurrency_limit(config, &connector);
+    let in_flight_requests = match limit {
+        Some(_) => connector_concurrency::get_in_flight_count(&state, &connector)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the connector requests in flight")?,
+        None => 0,
+    };
+
+    Ok(ApplicationResponse::Json(
+        connector_concurrency_api::ConnectorConcurrencyResponse {
+            connector: req.connector,
+            limit,
+            in_flight_requests,
+        },
+    ))
+}
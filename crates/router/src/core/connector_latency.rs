@@ -0,0 +1,57 @@
+use api_models::connector_latency as connector_latency_api;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse},
+    routes::SessionState,
+    services::{connector_latency, ApplicationResponse},
+};
+
+/// The window over which the latency distribution is computed when none is requested
+const DEFAULT_CONNECTOR_LATENCY_WINDOW_IN_SECS: u32 = 60 * 60;
+
+#[instrument(skip_all)]
+pub async fn retrieve_connector_latency(
+    state: SessionState,
+    req: connector_latency_api::ConnectorLatencyRequest,
+) -> RouterResponse<connector_latency_api::ConnectorLatencyResponse> {
+    let config = &state.conf.connector_latency_tracking;
+    if !config.enabled {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Connector latency tracking is not enabled".to_string(),
+        }));
+    }
+
+    let window_in_seconds = req
+        .window_in_seconds
+        .unwrap_or(DEFAULT_CONNECTOR_LATENCY_WINDOW_IN_SECS.min(config.retention_in_secs));
+    if window_in_seconds == 0 || window_in_seconds > config.retention_in_secs {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "window_in_seconds should be between 1 and {}",
+                config.retention_in_secs
+            ),
+        }));
+    }
+
+    let distribution = connector_latency::get_connector_latency_distribution(
+        &state,
+        &req.connector.to_string(),
+        window_in_seconds,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch the connector latency distribution")?;
+
+    Ok(ApplicationResponse::Json(
+        connector_latency_api::ConnectorLatencyResponse {
+            connector: req.connector,
+            window_in_seconds,
+            sample_count: distribution.sample_count,
+            p50_ms: distribution.p50_ms,
+            p95_ms: distribution.p95_ms,
+            p99_ms: distribution.p99_ms,
+        },
+    ))
+}
@@ -163,6 +163,9 @@ pub async fn update_mca(
         test_mode: None,
         additional_merchant_data: None,
         connector_wallets_details: None,
+        request_timeout_secs: None,
+        environment: None,
+        maintenance_windows: None,
     };
     #[cfg(feature = "v2")]
     let request = MerchantConnectorUpdate {
@@ -179,6 +182,9 @@ pub async fn update_mca(
         merchant_id: merchant_id.clone(),
         additional_merchant_data: None,
         connector_wallets_details: None,
+        request_timeout_secs: None,
+        environment: None,
+        maintenance_windows: None,
     };
     let mca_response =
         admin::update_connector(state.clone(), &merchant_id, None, &connector_id, request).await?;
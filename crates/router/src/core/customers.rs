@@ -13,14 +13,12 @@ use error_stack::{report, ResultExt};
 use masking::{Secret, SwitchStrategy};
 use router_env::{instrument, tracing};
 
-#[cfg(all(feature = "v2", feature = "customer_v2"))]
-use crate::core::payment_methods::cards::create_encrypted_data;
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 use crate::utils::CustomerAddress;
 use crate::{
     core::{
         errors::{self, StorageErrorExt},
-        payment_methods::{cards, network_tokenization},
+        payment_methods::{cards, cards::create_encrypted_data, network_tokenization},
     },
     db::StorageInterface,
     pii::PeekInterface,
@@ -34,6 +32,12 @@ use crate::{
     },
 };
 
+#[cfg(all(feature = "v1", not(feature = "customer_v2")))]
+pub mod communication;
+#[cfg(all(feature = "v1", not(feature = "customer_v2")))]
+pub mod connector_sync;
+pub mod risk_profile;
+
 pub const REDACTED: &str = "Redacted";
 
 #[instrument(skip(state))]
@@ -122,6 +126,12 @@ impl CustomerCreateBridge for customers::CustomerRequest {
         key_manager_state: &'a KeyManagerState,
         state: &'a SessionState,
     ) -> errors::CustomResult<domain::Customer, errors::CustomersErrorResponse> {
+        self.validate_tax_ids().map_err(|error| {
+            report!(errors::CustomersErrorResponse::InvalidRequestData {
+                message: error.current_context().to_string(),
+            })
+        })?;
+
         // Setting default billing address to Db
         let address = self.get_address();
         let merchant_id = merchant_account.get_id();
@@ -163,6 +173,31 @@ impl CustomerCreateBridge for customers::CustomerRequest {
         let encryptable_customer = CustomerRequestWithEmail::from_encryptable(encrypted_data)
             .change_context(errors::CustomersErrorResponse::InternalServerError)?;
 
+        domain::validate_metadata_fields(self.metadata.as_ref()).map_err(|error| {
+            let message = error.current_context().to_string();
+            error.change_context(errors::CustomersErrorResponse::InvalidRequestData { message })
+        })?;
+
+        let metadata = domain::encrypt_metadata_fields(
+            key_manager_state,
+            self.metadata.clone(),
+            merchant_account.get_customer_pii_encryption_fields(),
+            Identifier::Merchant(key_store.merchant_id.clone()),
+            key,
+        )
+        .await
+        .change_context(errors::CustomersErrorResponse::InternalServerError)
+        .attach_printable("Failed while encrypting customer metadata")?;
+
+        let tax_ids = self
+            .tax_ids
+            .clone()
+            .async_map(|tax_ids| create_encrypted_data(state, key_store, tax_ids))
+            .await
+            .transpose()
+            .change_context(errors::CustomersErrorResponse::InternalServerError)
+            .attach_printable("Failed while encrypting customer tax identifiers")?;
+
         Ok(domain::Customer {
             customer_id: merchant_reference_id
                 .to_owned()
@@ -173,7 +208,7 @@ impl CustomerCreateBridge for customers::CustomerRequest {
             phone: encryptable_customer.phone,
             description: self.description.clone(),
             phone_country_code: self.phone_country_code.clone(),
-            metadata: self.metadata.clone(),
+            metadata,
             connector_customer: None,
             address_id: address_from_db.clone().map(|addr| addr.address_id),
             created_at: common_utils::date_time::now(),
@@ -181,6 +216,8 @@ impl CustomerCreateBridge for customers::CustomerRequest {
             default_payment_method_id: None,
             updated_by: None,
             version: hyperswitch_domain_models::consts::API_VERSION,
+            tax_ids,
+            preferred_payment_method_id: None,
         })
     }
 
@@ -495,24 +532,32 @@ pub async fn retrieve_customer(
 #[instrument(skip(state))]
 pub async fn list_customers(
     state: SessionState,
-    merchant_id: id_type::MerchantId,
+    merchant_account: domain::MerchantAccount,
     _profile_id_list: Option<Vec<id_type::ProfileId>>,
     key_store: domain::MerchantKeyStore,
     request: customers::CustomerListRequest,
 ) -> errors::CustomerResponse<Vec<customers::CustomerResponse>> {
     let db = state.store.as_ref();
 
+    let metadata_value_hash = get_customer_metadata_value_hash(
+        &merchant_account,
+        &key_store,
+        request.metadata_key,
+        request.metadata_value,
+    )?;
+
     let customer_list_constraints = crate::db::customers::CustomerListConstraints {
         limit: request
             .limit
             .unwrap_or(crate::consts::DEFAULT_LIST_API_LIMIT),
         offset: request.offset,
+        metadata_value_hash,
     };
 
     let domain_customers = db
         .list_customers_by_merchant_id(
             &(&state).into(),
-            &merchant_id,
+            merchant_account.get_id(),
             &key_store,
             customer_list_constraints,
         )
@@ -534,6 +579,54 @@ pub async fn list_customers(
     Ok(services::ApplicationResponse::Json(customers))
 }
 
+/// Get the hash of the metadata value to filter the customers by, the customers can only be
+/// filtered by the keys of the metadata which are designated for encryption
+fn get_customer_metadata_value_hash(
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    metadata_key: Option<String>,
+    metadata_value: Option<Secret<String>>,
+) -> errors::CustomResult<
+    Option<crate::db::customers::CustomerMetadataValueHash>,
+    errors::CustomersErrorResponse,
+> {
+    match (metadata_key, metadata_value) {
+        (Some(metadata_key), Some(metadata_value)) => {
+            let is_encrypted_metadata_key = merchant_account
+                .get_customer_pii_encryption_fields()
+                .iter()
+                .any(|field| {
+                    domain::get_encryptable_metadata_key(field) == Some(metadata_key.as_str())
+                });
+            if !is_encrypted_metadata_key {
+                return Err(report!(errors::CustomersErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "Customers cannot be filtered by the metadata key `{metadata_key}` as it is not designated for encryption"
+                    ),
+                }));
+            }
+
+            let value_hash = domain::get_metadata_value_hash(
+                &serde_json::Value::String(metadata_value.peek().to_owned()),
+                key_store.key.get_inner().peek(),
+            )
+            .change_context(errors::CustomersErrorResponse::InternalServerError)
+            .attach_printable("Failed while hashing the customer metadata value")?;
+
+            Ok(Some(crate::db::customers::CustomerMetadataValueHash {
+                value_hash,
+                metadata_key,
+            }))
+        }
+        (None, None) => Ok(None),
+        _ => Err(report!(
+            errors::CustomersErrorResponse::InvalidRequestData {
+                message: "metadata_key and metadata_value must be provided together".to_string(),
+            }
+        )),
+    }
+}
+
 #[cfg(all(
     feature = "v2",
     feature = "customer_v2",
@@ -883,6 +976,16 @@ impl CustomerDeleteBridge for customers::CustomerId {
             }
         }?;
 
+        #[cfg(all(feature = "v1", not(feature = "customer_v2")))]
+        communication::redact_customer_communications(
+            state,
+            merchant_account.get_id(),
+            key_store,
+            &self.customer_id,
+        )
+        .await
+        .change_context(errors::CustomersErrorResponse::InternalServerError)?;
+
         let updated_customer = storage::CustomerUpdate::Update {
             name: Some(redacted_encrypted_value.clone()),
             email: Some(
@@ -903,6 +1006,16 @@ impl CustomerDeleteBridge for customers::CustomerId {
             metadata: None,
             connector_customer: None,
             address_id: None,
+            tax_ids: Some(
+                create_encrypted_data(
+                    state,
+                    key_store,
+                    common_utils::types::CustomerTaxIds::default(),
+                )
+                .await
+                .change_context(errors::CustomersErrorResponse::InternalServerError)
+                .attach_printable("Failed while encrypting customer tax identifiers")?,
+            ),
         };
 
         db.update_customer_by_customer_id_merchant_id(
@@ -965,6 +1078,20 @@ pub async fn update_customer(
         )
         .await?;
 
+    // The sync status is tracked per connector, failures are retried separately and do not fail
+    // the customer update
+    #[cfg(all(feature = "v1", not(feature = "customer_v2")))]
+    connector_sync::sync_customer_to_connectors(
+        &state,
+        &merchant_account,
+        &key_store,
+        &updated_customer,
+        None,
+    )
+    .await
+    .map_err(|error| router_env::logger::error!(connector_customer_sync_error=?error))
+    .ok();
+
     update_customer.generate_response(&updated_customer)
 }
 
@@ -1153,6 +1280,12 @@ impl CustomerUpdateBridge for customers::CustomerUpdateRequest {
         state: &'a SessionState,
         domain_customer: &'a domain::Customer,
     ) -> errors::CustomResult<domain::Customer, errors::CustomersErrorResponse> {
+        self.validate_tax_ids().map_err(|error| {
+            report!(errors::CustomersErrorResponse::InvalidRequestData {
+                message: error.current_context().to_string(),
+            })
+        })?;
+
         let update_address_for_update_customer = AddressStructForDbUpdate {
             update_customer: self,
             merchant_account,
@@ -1188,6 +1321,31 @@ impl CustomerUpdateBridge for customers::CustomerUpdateRequest {
         let encryptable_customer = CustomerRequestWithEmail::from_encryptable(encrypted_data)
             .change_context(errors::CustomersErrorResponse::InternalServerError)?;
 
+        domain::validate_metadata_fields(self.metadata.as_ref()).map_err(|error| {
+            let message = error.current_context().to_string();
+            error.change_context(errors::CustomersErrorResponse::InvalidRequestData { message })
+        })?;
+
+        let metadata = domain::encrypt_metadata_fields(
+            key_manager_state,
+            self.metadata.clone(),
+            merchant_account.get_customer_pii_encryption_fields(),
+            Identifier::Merchant(key_store.merchant_id.clone()),
+            key,
+        )
+        .await
+        .change_context(errors::CustomersErrorResponse::InternalServerError)
+        .attach_printable("Failed while encrypting customer metadata")?;
+
+        let tax_ids = self
+            .tax_ids
+            .clone()
+            .async_map(|tax_ids| create_encrypted_data(state, key_store, tax_ids))
+            .await
+            .transpose()
+            .change_context(errors::CustomersErrorResponse::InternalServerError)
+            .attach_printable("Failed while encrypting customer tax identifiers")?;
+
         let response = db
             .update_customer_by_customer_id_merchant_id(
                 key_manager_state,
@@ -1199,10 +1357,11 @@ impl CustomerUpdateBridge for customers::CustomerUpdateRequest {
                     email: encryptable_customer.email,
                     phone: Box::new(encryptable_customer.phone),
                     phone_country_code: self.phone_country_code.clone(),
-                    metadata: self.metadata.clone(),
+                    metadata,
                     description: self.description.clone(),
                     connector_customer: None,
                     address_id: address.clone().map(|addr| addr.address_id),
+                    tax_ids,
                 },
                 key_store,
                 merchant_account.storage_scheme,
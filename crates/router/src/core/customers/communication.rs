@@ -0,0 +1,284 @@
+//! Log of the communication of the merchant with its customers.
+//!
+//! The entries are linked to the customer, and optionally to the payment they are about, so that
+//! the communication regarding a disputed payment can be attached as evidence to the dispute. The
+//! summary of the communication is encrypted with the key of the merchant like the rest of the
+//! customer data, and the entries are never modified once written.
+
+use common_utils::{date_time, fp_utils, id_type, type_name, types::keymanager::Identifier};
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services,
+    types::{api::customers, domain, storage},
+    utils,
+};
+
+const MAX_COMMUNICATION_SUMMARY_LENGTH: usize = 2048;
+
+const DEFAULT_COMMUNICATION_LIST_LIMIT: u16 = 10;
+
+fn validate_communication_summary(summary: &str) -> RouterResult<()> {
+    let summary_length = summary.chars().count();
+    fp_utils::when(
+        summary_length == 0 || summary_length > MAX_COMMUNICATION_SUMMARY_LENGTH,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "summary must be between 1 and {MAX_COMMUNICATION_SUMMARY_LENGTH} characters long"
+                ),
+            }))
+        },
+    )
+}
+
+/// Append an entry to the communication log of the customer
+#[instrument(skip_all)]
+pub async fn create_customer_communication(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: customers::CustomerCommunicationCreate,
+) -> RouterResponse<customers::CustomerCommunicationResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_account.get_id();
+    let customers::CustomerCommunicationCreate {
+        customer_id,
+        request,
+    } = req;
+
+    validate_communication_summary(request.summary.peek())?;
+
+    db.find_customer_by_customer_id_merchant_id(
+        key_manager_state,
+        &customer_id,
+        merchant_id,
+        &key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    if let Some(payment_id) = &request.payment_id {
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                key_manager_state,
+                payment_id,
+                merchant_id,
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        fp_utils::when(
+            payment_intent.customer_id.as_ref() != Some(&customer_id),
+            || {
+                Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "The payment does not belong to the customer".to_string(),
+                }))
+            },
+        )?;
+    }
+
+    if let Some(file_id) = &request.file_id {
+        db.find_file_metadata_by_merchant_id_file_id(merchant_id, file_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::FileNotFound)?;
+    }
+
+    let summary = domain::types::crypto_operation(
+        key_manager_state,
+        type_name!(storage::CustomerCommunication),
+        domain::types::CryptoOperation::Encrypt(request.summary),
+        Identifier::Merchant(merchant_id.to_owned()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt the summary of the customer communication")?;
+
+    let now = date_time::now();
+    let customer_communication = db
+        .insert_customer_communication(storage::CustomerCommunicationNew {
+            id: utils::generate_id(crate::consts::ID_LENGTH, "comm"),
+            merchant_id: merchant_id.to_owned(),
+            customer_id,
+            payment_id: request.payment_id,
+            channel: request.channel,
+            summary: summary.into(),
+            file_id: request.file_id,
+            communicated_at: request.communicated_at.unwrap_or(now),
+            created_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the customer communication")?;
+
+    Ok(services::ApplicationResponse::Json(
+        decrypt_customer_communication(&state, &key_store, customer_communication).await?,
+    ))
+}
+
+/// List the communication log of the customer, the most recent communication first
+#[instrument(skip_all)]
+pub async fn list_customer_communications(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: customers::CustomerCommunicationList,
+) -> RouterResponse<Vec<customers::CustomerCommunicationResponse>> {
+    let db = &*state.store;
+    let merchant_id = merchant_account.get_id();
+
+    db.find_customer_by_customer_id_merchant_id(
+        &(&state).into(),
+        &req.customer_id,
+        merchant_id,
+        &key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let customer_communications = db
+        .list_customer_communications_by_merchant_id_customer_id(
+            merchant_id,
+            &req.customer_id,
+            Some(i64::from(
+                req.constraints
+                    .limit
+                    .unwrap_or(DEFAULT_COMMUNICATION_LIST_LIMIT),
+            )),
+            req.constraints.offset.map(i64::from),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the customer communications")?;
+
+    let mut response = Vec::with_capacity(customer_communications.len());
+    for customer_communication in customer_communications {
+        response.push(
+            decrypt_customer_communication(&state, &key_store, customer_communication).await?,
+        );
+    }
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+/// The communication log regarding the payment, the oldest communication first, as attached to the
+/// evidence of the disputes of the payment
+pub async fn get_payment_customer_communications(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<Vec<customers::CustomerCommunicationResponse>> {
+    let customer_communications = state
+        .store
+        .list_customer_communications_by_merchant_id_payment_id(merchant_id, payment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the customer communications of the payment")?;
+
+    let mut response = Vec::with_capacity(customer_communications.len());
+    for customer_communication in customer_communications {
+        response
+            .push(decrypt_customer_communication(state, key_store, customer_communication).await?);
+    }
+
+    Ok(response)
+}
+
+/// Redact the content of the communication log of the customer, along with the rest of the data of
+/// the customer. The entries themselves are kept, so that the communication can still be accounted
+/// for in the evidence of the disputes.
+pub async fn redact_customer_communications(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<usize> {
+    let summary = domain::types::crypto_operation(
+        &state.into(),
+        type_name!(storage::CustomerCommunication),
+        domain::types::CryptoOperation::Encrypt(Secret::new(
+            crate::core::customers::REDACTED.to_string(),
+        )),
+        Identifier::Merchant(merchant_id.to_owned()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt the redacted summary of the customer communications")?;
+
+    state
+        .store
+        .redact_customer_communications_by_merchant_id_customer_id(
+            merchant_id,
+            customer_id,
+            storage::CustomerCommunicationRedaction {
+                summary: summary.into(),
+                file_id: None,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to redact the customer communications")
+}
+
+async fn decrypt_customer_communication(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    customer_communication: storage::CustomerCommunication,
+) -> RouterResult<customers::CustomerCommunicationResponse> {
+    let summary: Secret<String> = domain::types::crypto_operation(
+        &state.into(),
+        type_name!(storage::CustomerCommunication),
+        domain::types::CryptoOperation::Decrypt(customer_communication.summary),
+        Identifier::Merchant(customer_communication.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt the summary of the customer communication")?
+    .into_inner();
+
+    Ok(customers::CustomerCommunicationResponse {
+        id: customer_communication.id,
+        customer_id: customer_communication.customer_id,
+        payment_id: customer_communication.payment_id,
+        channel: customer_communication.channel,
+        summary,
+        file_id: customer_communication.file_id,
+        communicated_at: customer_communication.communicated_at,
+        created_at: customer_communication.created_at,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_communication_summary_length_in_characters() {
+        assert!(validate_communication_summary("Called about the delayed delivery").is_ok());
+        assert!(validate_communication_summary("").is_err());
+
+        // Multi-byte characters are counted once, not once per byte
+        let summary = "é".repeat(MAX_COMMUNICATION_SUMMARY_LENGTH);
+        assert!(summary.len() > MAX_COMMUNICATION_SUMMARY_LENGTH);
+        assert!(validate_communication_summary(&summary).is_ok());
+
+        let summary = "a".repeat(MAX_COMMUNICATION_SUMMARY_LENGTH + 1);
+        assert!(validate_communication_summary(&summary).is_err());
+    }
+}
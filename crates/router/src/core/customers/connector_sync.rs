@@ -0,0 +1,470 @@
+//! Propagation of the customer details to the connectors at which the customer exists.
+//!
+//! The sync status is tracked per connector, so that a partial failure can be retried against the
+//! connectors at which the sync failed without calling the connectors at which it succeeded.
+
+use std::marker::PhantomData;
+
+use common_utils::{crypto::Encryptable, ext_traits::ValueExt, id_type, pii};
+use error_stack::ResultExt;
+use masking::ExposeOptionInterface;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        payments::{self, helpers},
+    },
+    logger,
+    routes::SessionState,
+    services,
+    types::{
+        self,
+        api::{self, customers},
+        domain,
+        storage::{self, enums},
+        transformers::ForeignFrom,
+        PaymentAddress,
+    },
+};
+
+const IRRELEVANT_ATTEMPT_ID_IN_CUSTOMER_SYNC_FLOW: &str =
+    "irrelevant_attempt_id_in_customer_sync_flow";
+
+const IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_CUSTOMER_SYNC_FLOW: &str =
+    "irrelevant_connector_request_reference_id_in_customer_sync_flow";
+
+const DEFAULT_CONNECTOR_SYNC_RETRY_LIMIT: u16 = 100;
+
+/// The maximum length of the reason of a failed sync, as stored in the database
+const SYNC_ERROR_MESSAGE_MAX_LENGTH: usize = 1024;
+
+/// Propagate the name, email, phone, description and metadata of the customer to the connectors
+/// configured in `connector_customer.sync_connector_list` at which the customer exists.
+///
+/// When `connector_labels` is provided, only the connectors stored against those labels in the
+/// customer's `connector_customer` map are synced.
+#[instrument(skip_all)]
+pub async fn sync_customer_to_connectors(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    customer: &domain::Customer,
+    connector_labels: Option<&[String]>,
+) -> RouterResult<Vec<storage::CustomerConnectorSync>> {
+    let connector_customer_map = customer
+        .connector_customer
+        .clone()
+        .expose_option()
+        .and_then(|connector_customer| connector_customer.as_object().cloned())
+        .unwrap_or_default();
+
+    let mut sync_entries = Vec::new();
+    for (connector_label, connector_customer_id) in connector_customer_map {
+        if connector_labels.is_some_and(|labels| !labels.contains(&connector_label)) {
+            continue;
+        }
+        let Some(connector_customer_id) = connector_customer_id.as_str() else {
+            continue;
+        };
+
+        // A failure to sync the customer to one connector must not prevent the sync to the
+        // remaining connectors
+        match sync_customer_to_connector(
+            state,
+            merchant_account,
+            key_store,
+            customer,
+            &connector_label,
+            connector_customer_id,
+        )
+        .await
+        {
+            Ok(Some(sync_entry)) => sync_entries.push(sync_entry),
+            Ok(None) => {}
+            Err(error) => logger::error!(
+                ?error,
+                connector_label = %connector_label,
+                "Failed to sync the customer to the connector"
+            ),
+        }
+    }
+
+    Ok(sync_entries)
+}
+
+/// Sync the customer to the connector stored against the label, returning the sync status of the
+/// connector, or `None` if the customer is not synced to the connector.
+async fn sync_customer_to_connector(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    customer: &domain::Customer,
+    connector_label: &str,
+    connector_customer_id: &str,
+) -> RouterResult<Option<storage::CustomerConnectorSync>> {
+    // Customers created at a connector before the merchant connector account id was used as the
+    // label cannot be resolved to a merchant connector account and are not synced.
+    let Some(merchant_connector_account) = get_merchant_connector_account_by_label(
+        state,
+        merchant_account,
+        key_store,
+        connector_label,
+    )
+    .await?
+    else {
+        logger::debug!(
+            "Skipping connector customer sync for label {connector_label}, no merchant connector account found"
+        );
+        return Ok(None);
+    };
+
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &merchant_connector_account.connector_name,
+        api::GetToken::Connector,
+        Some(merchant_connector_account.get_id()),
+    )?;
+
+    if !state
+        .conf
+        .connector_customer
+        .sync_connector_list
+        .contains(&connector_data.connector_name)
+    {
+        return Ok(None);
+    }
+
+    let connector_name = merchant_connector_account.connector_name.clone();
+    upsert_sync_status(
+        state,
+        customer,
+        connector_label,
+        &connector_name,
+        enums::CustomerConnectorSyncStatus::Pending,
+        None,
+    )
+    .await?;
+
+    let (sync_status, error_message) = match update_connector_customer(
+        state,
+        merchant_account,
+        customer,
+        &connector_data,
+        merchant_connector_account,
+        connector_customer_id,
+    )
+    .await
+    {
+        Ok(()) => (enums::CustomerConnectorSyncStatus::Synced, None),
+        Err(error_message) => {
+            logger::error!(
+                connector_customer_sync_error = %error_message,
+                connector = %connector_name
+            );
+            (
+                enums::CustomerConnectorSyncStatus::Failed,
+                Some(truncate_error_message(error_message)),
+            )
+        }
+    };
+
+    upsert_sync_status(
+        state,
+        customer,
+        connector_label,
+        &connector_name,
+        sync_status,
+        error_message,
+    )
+    .await
+    .map(Some)
+}
+
+/// Truncate the reason of a failed sync to the length of the column it is stored in
+fn truncate_error_message(error_message: String) -> String {
+    if error_message.chars().count() <= SYNC_ERROR_MESSAGE_MAX_LENGTH {
+        error_message
+    } else {
+        error_message
+            .chars()
+            .take(SYNC_ERROR_MESSAGE_MAX_LENGTH)
+            .collect()
+    }
+}
+
+/// Retry the connector customer sync for the customers of the merchant whose latest local change
+/// was not propagated to all connectors, calling only the connectors at which the sync is pending
+/// or failed.
+#[instrument(skip_all)]
+pub async fn retry_customer_connector_syncs(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    limit: i64,
+) -> RouterResult<Vec<storage::CustomerConnectorSync>> {
+    let db = state.store.as_ref();
+    let pending_sync_entries = db
+        .list_customer_connector_syncs_needing_sync(merchant_account.get_id(), limit)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list customers needing connector sync")?;
+
+    let mut connector_labels_by_customer: Vec<(id_type::CustomerId, Vec<String>)> = Vec::new();
+    for sync_entry in pending_sync_entries {
+        match connector_labels_by_customer
+            .iter_mut()
+            .find(|(customer_id, _)| *customer_id == sync_entry.customer_id)
+        {
+            Some((_, connector_labels)) => connector_labels.push(sync_entry.connector_label),
+            None => connector_labels_by_customer
+                .push((sync_entry.customer_id, vec![sync_entry.connector_label])),
+        }
+    }
+
+    let mut sync_entries = Vec::new();
+    for (customer_id, connector_labels) in connector_labels_by_customer {
+        let customer = match db
+            .find_customer_by_customer_id_merchant_id(
+                &state.into(),
+                &customer_id,
+                merchant_account.get_id(),
+                key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+        {
+            Ok(customer) => customer,
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    customer_id = ?customer_id,
+                    "Failed to fetch customer for connector sync"
+                );
+                continue;
+            }
+        };
+
+        sync_entries.extend(
+            sync_customer_to_connectors(
+                state,
+                merchant_account,
+                key_store,
+                &customer,
+                Some(&connector_labels),
+            )
+            .await?,
+        );
+    }
+
+    Ok(sync_entries)
+}
+
+pub async fn retry_connector_syncs(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: customers::CustomerConnectorSyncRetryRequest,
+) -> RouterResponse<Vec<customers::CustomerConnectorSyncResponse>> {
+    let limit = req.limit.unwrap_or(DEFAULT_CONNECTOR_SYNC_RETRY_LIMIT);
+    let sync_entries =
+        retry_customer_connector_syncs(&state, &merchant_account, &key_store, i64::from(limit))
+            .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        sync_entries
+            .into_iter()
+            .map(customers::CustomerConnectorSyncResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+async fn get_merchant_connector_account_by_label(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    connector_label: &str,
+) -> RouterResult<Option<domain::MerchantConnectorAccount>> {
+    let Ok(merchant_connector_id) =
+        id_type::MerchantConnectorAccountId::wrap(connector_label.to_owned())
+    else {
+        return Ok(None);
+    };
+
+    match state
+        .store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &state.into(),
+            merchant_account.get_id(),
+            &merchant_connector_id,
+            key_store,
+        )
+        .await
+    {
+        Ok(merchant_connector_account) => Ok(Some(merchant_connector_account)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch merchant connector account for customer sync")),
+    }
+}
+
+async fn upsert_sync_status(
+    state: &SessionState,
+    customer: &domain::Customer,
+    connector_label: &str,
+    connector_name: &str,
+    sync_status: enums::CustomerConnectorSyncStatus,
+    error_message: Option<String>,
+) -> RouterResult<storage::CustomerConnectorSync> {
+    let now = common_utils::date_time::now();
+    state
+        .store
+        .upsert_customer_connector_sync(storage::CustomerConnectorSyncNew {
+            merchant_id: customer.merchant_id.clone(),
+            customer_id: customer.customer_id.clone(),
+            connector_label: connector_label.to_owned(),
+            connector_name: connector_name.to_owned(),
+            sync_status,
+            error_message,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update customer connector sync status")
+}
+
+/// Update the customer at the connector, returning the reason of the failure if the connector
+/// could not be updated.
+async fn update_connector_customer(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    customer: &domain::Customer,
+    connector_data: &api::ConnectorData,
+    merchant_connector_account: domain::MerchantConnectorAccount,
+    connector_customer_id: &str,
+) -> Result<(), String> {
+    let router_data = construct_connector_customer_update_router_data(
+        merchant_account,
+        customer,
+        connector_data,
+        helpers::MerchantConnectorAccountType::DbVal(merchant_connector_account),
+        connector_customer_id,
+    )
+    .map_err(|error| format!("{error:?}"))?;
+
+    let connector_integration: services::BoxedPaymentConnectorIntegrationInterface<
+        api::UpdateConnectorCustomer,
+        types::ConnectorCustomerUpdateData,
+        types::PaymentsResponseData,
+    > = connector_data.connector.get_connector_integration();
+
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+        None,
+    )
+    .await
+    .map_err(|error| error.current_context().to_string())?;
+
+    response.response.map(|_| ()).map_err(|error| {
+        error
+            .reason
+            .unwrap_or_else(|| format!("{}: {}", error.code, error.message))
+    })
+}
+
+fn construct_connector_customer_update_router_data(
+    merchant_account: &domain::MerchantAccount,
+    customer: &domain::Customer,
+    connector_data: &api::ConnectorData,
+    merchant_connector_account: helpers::MerchantConnectorAccountType,
+    connector_customer_id: &str,
+) -> RouterResult<types::ConnectorCustomerUpdateRouterData> {
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .get_connector_account_details()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    Ok(types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.get_id().clone(),
+        customer_id: Some(customer.customer_id.clone()),
+        connector_customer: Some(connector_customer_id.to_owned()),
+        connector: connector_data.connector_name.to_string(),
+        payment_id: id_type::PaymentId::get_irrelevant_id("customer_sync")
+            .get_string_repr()
+            .to_owned(),
+        attempt_id: IRRELEVANT_ATTEMPT_ID_IN_CUSTOMER_SYNC_FLOW.to_string(),
+        status: enums::AttemptStatus::default(),
+        payment_method: enums::PaymentMethod::default(),
+        connector_auth_type: auth_type,
+        description: None,
+        return_url: None,
+        address: PaymentAddress::default(),
+        auth_type: enums::AuthenticationType::default(),
+        connector_meta_data: merchant_connector_account.get_metadata(),
+        connector_wallets_details: merchant_connector_account.get_connector_wallets_details(),
+        connector_request_timeout_secs: merchant_connector_account.get_connector_request_timeout(),
+        amount_captured: None,
+        minor_amount_captured: None,
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        payment_method_token: None,
+        recurring_mandate_payment_data: None,
+        preprocessing_id: None,
+        payment_method_balance: None,
+        connector_api_version: None,
+        payment_method_status: None,
+        request: types::ConnectorCustomerUpdateData {
+            connector_customer_id: connector_customer_id.to_owned(),
+            description: customer.description.clone(),
+            email: customer.email.clone().map(pii::Email::from),
+            phone: customer.phone.clone().map(Encryptable::into_inner),
+            name: customer.name.clone().map(Encryptable::into_inner),
+            metadata: customer.metadata.clone(),
+        },
+        response: Err(types::ErrorResponse::get_not_implemented()),
+        connector_request_reference_id:
+            IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_CUSTOMER_SYNC_FLOW.to_string(),
+        test_mode: merchant_connector_account.is_test_mode_on(),
+        connector_http_status_code: None,
+        external_latency: None,
+        connector_network_retry_count: None,
+        apple_pay_flow: None,
+        frm_metadata: None,
+        #[cfg(feature = "payouts")]
+        payout_method_data: None,
+        #[cfg(feature = "payouts")]
+        quote_id: None,
+        refund_id: None,
+        dispute_id: None,
+        connector_response: None,
+        integrity_check: Ok(()),
+        additional_merchant_data: None,
+        header_payload: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_error_message_keeps_short_messages() {
+        let error_message = "customer not found".to_string();
+        assert_eq!(truncate_error_message(error_message.clone()), error_message);
+    }
+
+    #[test]
+    fn test_truncate_error_message_truncates_to_column_length() {
+        let error_message = "é".repeat(SYNC_ERROR_MESSAGE_MAX_LENGTH + 10);
+        let truncated = truncate_error_message(error_message);
+        assert_eq!(truncated.chars().count(), SYNC_ERROR_MESSAGE_MAX_LENGTH);
+        assert!(truncated.chars().all(|character| character == 'é'));
+    }
+}
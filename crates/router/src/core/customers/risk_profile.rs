@@ -0,0 +1,313 @@
+//! Risk profiles of the customers.
+//!
+//! The risk profile of a customer carries a risk score and a risk tier across the payments of the
+//! customer, derived from the disputes raised on the payments of the customer and the payments of
+//! the customer which were declined. Unlike the fraud score of a payment, which is assessed for the
+//! payment alone, the risk profile changes slowly over the history of the customer. A customer
+//! without a risk profile, which has no history yet, is of the default tier configured in
+//! `customer_risk.default_tier`.
+
+use common_utils::{date_time, id_type};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    configs::settings::CustomerRisk,
+    core::errors::{self, RouterResult},
+    routes::SessionState,
+    types::storage::{self, enums},
+};
+
+const DISPUTE_RISK_SCORE: i32 = 20;
+const LOST_DISPUTE_RISK_SCORE: i32 = 40;
+const DECLINE_RISK_SCORE: i32 = 5;
+/// The declines alone cannot place a customer in a higher risk tier than this, since declines are
+/// mostly for reasons other than fraud, such as insufficient funds
+const MAX_DECLINES_RISK_SCORE: i32 = 30;
+const MAX_RISK_SCORE: i32 = 100;
+
+/// The number of the declines from which the customer is flagged as being repeatedly declined
+const REPEATED_DECLINES_COUNT: i32 = 3;
+
+/// An event which changes the risk profile of the customer
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CustomerRiskEvent {
+    /// A dispute was raised on a payment of the customer
+    DisputeOpened,
+    /// The merchant lost a dispute raised on a payment of the customer
+    DisputeLost,
+    /// A payment of the customer was declined
+    PaymentDeclined,
+}
+
+/// A flag on the history of the customer, which is derived from the risk profile of the customer
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CustomerRiskFlag {
+    /// A dispute was raised on a payment of the customer
+    ChargebackHistory,
+    /// The merchant lost a dispute raised on a payment of the customer
+    LostChargeback,
+    /// Several payments of the customer were declined
+    RepeatedDeclines,
+}
+
+/// The risk of a customer, as consulted in the fraud and the velocity checks of the payments of the
+/// customer
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustomerRiskAssessment {
+    pub tier: enums::CustomerRiskTier,
+    /// The risk score of the customer, which is not set for a customer without a history
+    pub score: Option<i16>,
+    pub flags: Vec<CustomerRiskFlag>,
+}
+
+impl CustomerRiskAssessment {
+    fn default_for_new_customer(config: &CustomerRisk) -> Self {
+        Self {
+            tier: config.default_tier,
+            score: None,
+            flags: Vec::new(),
+        }
+    }
+
+    fn from_profile(profile: &storage::CustomerRiskProfile) -> Self {
+        let flags = [
+            (profile.dispute_count > 0).then_some(CustomerRiskFlag::ChargebackHistory),
+            (profile.lost_dispute_count > 0).then_some(CustomerRiskFlag::LostChargeback),
+            (profile.decline_count >= REPEATED_DECLINES_COUNT)
+                .then_some(CustomerRiskFlag::RepeatedDeclines),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        Self {
+            tier: profile.risk_tier,
+            score: Some(profile.risk_score),
+            flags,
+        }
+    }
+
+    /// Whether the payments of the customer are to be reviewed by the merchant even when the fraud
+    /// check provider approves them
+    pub fn requires_review(&self) -> bool {
+        self.tier == enums::CustomerRiskTier::High
+            || self.flags.contains(&CustomerRiskFlag::LostChargeback)
+    }
+}
+
+/// The risk score of a customer with the history, between 0 and 100
+fn calculate_risk_score(dispute_count: i32, lost_dispute_count: i32, decline_count: i32) -> i16 {
+    let declines_score = decline_count
+        .saturating_mul(DECLINE_RISK_SCORE)
+        .min(MAX_DECLINES_RISK_SCORE);
+    let score = dispute_count
+        .saturating_mul(DISPUTE_RISK_SCORE)
+        .saturating_add(lost_dispute_count.saturating_mul(LOST_DISPUTE_RISK_SCORE))
+        .saturating_add(declines_score)
+        .clamp(0, MAX_RISK_SCORE);
+
+    i16::try_from(score).unwrap_or(i16::MAX)
+}
+
+fn get_risk_tier(config: &CustomerRisk, risk_score: i16) -> enums::CustomerRiskTier {
+    if risk_score >= i16::from(config.high_tier_min_score) {
+        enums::CustomerRiskTier::High
+    } else if risk_score >= i16::from(config.medium_tier_min_score) {
+        enums::CustomerRiskTier::Medium
+    } else {
+        enums::CustomerRiskTier::Low
+    }
+}
+
+/// Get the risk of the customer, which is the default risk tier for a customer without a history
+#[instrument(skip_all)]
+pub async fn get_customer_risk(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<CustomerRiskAssessment> {
+    match state
+        .store
+        .find_customer_risk_profile_by_merchant_id_customer_id(merchant_id, customer_id)
+        .await
+    {
+        Ok(profile) => Ok(CustomerRiskAssessment::from_profile(&profile)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(
+            CustomerRiskAssessment::default_for_new_customer(&state.conf.customer_risk),
+        ),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the customer risk profile"),
+    }
+}
+
+/// Record the event in the risk profile of the customer, creating the risk profile for a customer
+/// without a history, and update the risk score and the tier of the customer
+#[instrument(skip_all)]
+pub async fn record_customer_risk_event(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+    event: CustomerRiskEvent,
+) -> RouterResult<storage::CustomerRiskProfile> {
+    let config = &state.conf.customer_risk;
+    let (dispute_count, lost_dispute_count, decline_count) = match event {
+        CustomerRiskEvent::DisputeOpened => (1, 0, 0),
+        CustomerRiskEvent::DisputeLost => (0, 1, 0),
+        CustomerRiskEvent::PaymentDeclined => (0, 0, 1),
+    };
+    let risk_score = calculate_risk_score(dispute_count, lost_dispute_count, decline_count);
+    let now = date_time::now();
+
+    // The counters are added atomically, so that the concurrent events of the customer are all
+    // counted, after which the risk score is derived from the counters
+    let mut profile = state
+        .store
+        .upsert_customer_risk_profile_adding_counters(storage::CustomerRiskProfileNew {
+            merchant_id: merchant_id.to_owned(),
+            customer_id: customer_id.to_owned(),
+            risk_score,
+            risk_tier: get_risk_tier(config, risk_score),
+            dispute_count,
+            lost_dispute_count,
+            decline_count,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record the event in the customer risk profile")?;
+
+    let risk_score = calculate_risk_score(
+        profile.dispute_count,
+        profile.lost_dispute_count,
+        profile.decline_count,
+    );
+    let risk_tier = get_risk_tier(config, risk_score);
+    if risk_score == profile.risk_score && risk_tier == profile.risk_tier {
+        return Ok(profile);
+    }
+
+    // A concurrent event which changed the counters updates the risk score from its own counters
+    let is_updated = state
+        .store
+        .update_customer_risk_profile_if_counters_unchanged(
+            &profile,
+            storage::CustomerRiskProfileUpdateInternal {
+                risk_score,
+                risk_tier,
+                modified_at: date_time::now(),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the customer risk profile")?;
+
+    if is_updated {
+        if risk_tier != profile.risk_tier {
+            logger::info!(
+                previous_risk_tier = %profile.risk_tier,
+                %risk_tier,
+                "The risk tier of the customer changed"
+            );
+        }
+        profile.risk_score = risk_score;
+        profile.risk_tier = risk_tier;
+    }
+
+    Ok(profile)
+}
+
+/// Record the event in the risk profile of the customer without failing the flow in which the
+/// event occurred, since the risk profile is only consulted for the later payments of the customer
+pub async fn record_customer_risk_event_if_customer_present(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    customer_id: Option<&id_type::CustomerId>,
+    event: CustomerRiskEvent,
+) {
+    let Some(customer_id) = customer_id else {
+        return;
+    };
+
+    if let Err(error) = record_customer_risk_event(state, merchant_id, customer_id, event).await {
+        logger::error!(?error, ?event, "Failed to update the customer risk profile");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_risk_score_of_a_customer_without_history() {
+        assert_eq!(calculate_risk_score(0, 0, 0), 0);
+    }
+
+    #[test]
+    fn test_calculate_risk_score_caps_the_declines() {
+        assert_eq!(calculate_risk_score(0, 0, 2), 10);
+        assert_eq!(
+            calculate_risk_score(0, 0, 100),
+            i16::try_from(MAX_DECLINES_RISK_SCORE).unwrap_or_default()
+        );
+    }
+
+    #[test]
+    fn test_calculate_risk_score_is_at_most_the_max_risk_score() {
+        assert_eq!(calculate_risk_score(1, 1, 1), 65);
+        assert_eq!(
+            calculate_risk_score(3, 2, 10),
+            i16::try_from(MAX_RISK_SCORE).unwrap_or_default()
+        );
+        assert_eq!(
+            calculate_risk_score(i32::MAX, i32::MAX, i32::MAX),
+            i16::try_from(MAX_RISK_SCORE).unwrap_or_default()
+        );
+    }
+
+    #[test]
+    fn test_get_risk_tier_at_the_tier_boundaries() {
+        let config = CustomerRisk::default();
+
+        assert_eq!(get_risk_tier(&config, 0), enums::CustomerRiskTier::Low);
+        assert_eq!(
+            get_risk_tier(&config, i16::from(config.medium_tier_min_score) - 1),
+            enums::CustomerRiskTier::Low
+        );
+        assert_eq!(
+            get_risk_tier(&config, i16::from(config.medium_tier_min_score)),
+            enums::CustomerRiskTier::Medium
+        );
+        assert_eq!(
+            get_risk_tier(&config, i16::from(config.high_tier_min_score) - 1),
+            enums::CustomerRiskTier::Medium
+        );
+        assert_eq!(
+            get_risk_tier(&config, i16::from(config.high_tier_min_score)),
+            enums::CustomerRiskTier::High
+        );
+        assert_eq!(
+            get_risk_tier(&config, i16::try_from(MAX_RISK_SCORE).unwrap_or_default()),
+            enums::CustomerRiskTier::High
+        );
+    }
+
+    #[test]
+    fn test_customer_risk_assessment_requires_review() {
+        let assessment = CustomerRiskAssessment {
+            tier: enums::CustomerRiskTier::Low,
+            score: Some(40),
+            flags: vec![CustomerRiskFlag::LostChargeback],
+        };
+        assert!(assessment.requires_review());
+
+        let assessment = CustomerRiskAssessment {
+            tier: enums::CustomerRiskTier::Medium,
+            score: Some(50),
+            flags: vec![CustomerRiskFlag::ChargebackHistory],
+        };
+        assert!(!assessment.requires_review());
+    }
+}
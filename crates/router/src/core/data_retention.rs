@@ -0,0 +1,278 @@
+//! Retention of the records of the payments, the refunds, the disputes, the customers and the files.
+//!
+//! The records past the retention period of their entity are purged by a recurring process tracker
+//! task, in batches of the oldest records first. The records under a legal hold placed by the
+//! merchant, and the records which would break a record that must still be kept, are never purged.
+
+use std::collections::HashMap;
+
+use common_utils::date_time;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+
+use crate::{
+    configs::settings::DataRetention,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils,
+    },
+    db::StorageInterface,
+    routes::{metrics, SessionState},
+    services::ApplicationResponse,
+    types::{
+        api::admin,
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+};
+
+const DATA_RETENTION_PURGE_TASK: &str = "DATA_RETENTION_PURGE";
+const DATA_RETENTION_PURGE_TAG: &str = "DATA_RETENTION";
+
+const LEGAL_HOLD_DEFAULT_LIMIT: i64 = 10;
+const LEGAL_HOLD_MAX_LIMIT: i64 = 100;
+const LEGAL_HOLD_RECORD_ID_MAX_LENGTH: usize = 64;
+const LEGAL_HOLD_REASON_MAX_LENGTH: usize = 255;
+
+/// Schedule the recurring task which purges the records past their retention period. A single
+/// task is scheduled for the store, which reschedules itself after every run.
+#[instrument(skip_all)]
+pub async fn add_purge_task(db: &dyn StorageInterface, config: &DataRetention) -> RouterResult<()> {
+    let schedule_time = date_time::now().saturating_add(time::Duration::seconds(i64::from(
+        config.purge_interval_in_secs,
+    )));
+
+    utils::add_recurring_process_tracker_task(
+        db,
+        storage::ProcessTrackerRunner::DataRetentionPurgeWorkflow,
+        DATA_RETENTION_PURGE_TASK,
+        DATA_RETENTION_PURGE_TAG,
+        serde_json::json!({}),
+        schedule_time,
+        "DataRetentionPurge",
+    )
+    .await
+}
+
+/// Purge the records of every entity with a retention policy which are past their retention
+/// period. Returns the number of the records purged, by entity.
+#[instrument(skip_all)]
+pub async fn purge_expired_records(
+    state: &SessionState,
+) -> RouterResult<HashMap<enums::RetentionEntity, usize>> {
+    let config = &state.conf.data_retention;
+    let mut purged_records = HashMap::new();
+
+    for (entity, policy) in &config.policies {
+        let retained_before = date_time::now()
+            .saturating_sub(time::Duration::days(i64::from(policy.retention_in_days)));
+        let mut purged_count = 0;
+
+        for _ in 0..config.max_batches_per_run {
+            let records = state
+                .store
+                .find_purgeable_records(*entity, retained_before, i64::from(config.batch_size))
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| format!("Failed to fetch the purgeable {entity}"))?;
+            let is_last_batch = records.len() < usize::from(config.batch_size);
+
+            let mut record_ids_by_merchant: HashMap<_, Vec<_>> = HashMap::new();
+            for record in records {
+                record_ids_by_merchant
+                    .entry(record.merchant_id)
+                    .or_default()
+                    .push(record.record_id);
+            }
+
+            for (merchant_id, record_ids) in record_ids_by_merchant {
+                let record_ids = match entity {
+                    enums::RetentionEntity::Files => {
+                        delete_stored_files(state, &merchant_id, record_ids).await
+                    }
+                    enums::RetentionEntity::Payments
+                    | enums::RetentionEntity::Refunds
+                    | enums::RetentionEntity::Disputes
+                    | enums::RetentionEntity::Customers => record_ids,
+                };
+                if record_ids.is_empty() {
+                    continue;
+                }
+
+                let count = state
+                    .store
+                    .purge_records(*entity, &merchant_id, record_ids)
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable_lazy(|| format!("Failed to purge the {entity}"))?;
+                purged_count += count;
+            }
+
+            if is_last_batch {
+                break;
+            }
+        }
+
+        metrics::DATA_RETENTION_RECORDS_PURGED_COUNT.add(
+            &metrics::CONTEXT,
+            u64::try_from(purged_count).unwrap_or(u64::MAX),
+            &add_attributes([("entity", entity.to_string())]),
+        );
+        logger::info!(%entity, purged_count, "Purged the records past their retention period");
+        purged_records.insert(*entity, purged_count);
+    }
+
+    Ok(purged_records)
+}
+
+/// Delete the contents of the files stored by the router, before their metadata is deleted.
+/// Returns the ids of the files whose metadata can be deleted, leaving out the files whose
+/// contents could not be deleted, so that they are retried on the next run.
+async fn delete_stored_files(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    file_ids: Vec<String>,
+) -> Vec<String> {
+    let mut deleted_file_ids = Vec::with_capacity(file_ids.len());
+
+    for file_id in file_ids {
+        let file_metadata = match state
+            .store
+            .find_file_metadata_by_merchant_id_file_id(merchant_id, &file_id)
+            .await
+        {
+            Ok(file_metadata) => file_metadata,
+            Err(error) => {
+                logger::error!(?error, %file_id, "Failed to fetch the metadata of the file");
+                continue;
+            }
+        };
+
+        // The files uploaded to the connectors are stored by the connectors, and only their
+        // metadata is deleted
+        if let (Some(enums::FileUploadProvider::Router), Some(provider_file_id)) = (
+            file_metadata.file_upload_provider,
+            file_metadata.provider_file_id.as_ref(),
+        ) {
+            if let Err(error) = state
+                .file_storage_client
+                .delete_file(provider_file_id)
+                .await
+            {
+                logger::error!(?error, %file_id, "Failed to delete the contents of the file");
+                continue;
+            }
+        }
+
+        deleted_file_ids.push(file_id);
+    }
+
+    deleted_file_ids
+}
+
+fn validate_legal_hold(req: &admin::LegalHoldCreateRequest) -> RouterResult<()> {
+    if req.record_id.is_empty() || req.record_id.len() > LEGAL_HOLD_RECORD_ID_MAX_LENGTH {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "record_id should be between 1 and {LEGAL_HOLD_RECORD_ID_MAX_LENGTH} characters"
+            ),
+        }
+        .into());
+    }
+
+    if req
+        .reason
+        .as_ref()
+        .is_some_and(|reason| reason.len() > LEGAL_HOLD_REASON_MAX_LENGTH)
+    {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "reason should be at most {LEGAL_HOLD_REASON_MAX_LENGTH} characters long"
+            ),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Place a legal hold on a record of the merchant. The hold may be placed on a record before the
+/// record exists, such as on a payment which is yet to be created with a known id.
+#[instrument(skip_all)]
+pub async fn create_legal_hold(
+    state: SessionState,
+    req: admin::LegalHoldCreateRequest,
+) -> RouterResponse<admin::LegalHoldResponse> {
+    validate_legal_hold(&req)?;
+
+    let legal_hold = state
+        .store
+        .insert_legal_hold(storage::LegalHoldNew {
+            merchant_id: req.merchant_id,
+            entity_type: req.entity_type,
+            record_id: req.record_id,
+            reason: req.reason,
+            created_at: date_time::now(),
+        })
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "The record is already under a legal hold".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        admin::LegalHoldResponse::foreign_from(legal_hold),
+    ))
+}
+
+/// List the legal holds of the merchant, latest first
+#[instrument(skip_all)]
+pub async fn list_legal_holds(
+    state: SessionState,
+    req: admin::LegalHoldListRequest,
+) -> RouterResponse<Vec<admin::LegalHoldResponse>> {
+    let limit = req.limit.unwrap_or(LEGAL_HOLD_DEFAULT_LIMIT);
+    if !(1..=LEGAL_HOLD_MAX_LIMIT).contains(&limit) {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("limit should be between 1 and {LEGAL_HOLD_MAX_LIMIT}"),
+        }
+        .into());
+    }
+
+    let legal_holds = state
+        .store
+        .list_legal_holds_by_merchant_id(&req.merchant_id, Some(limit), req.offset)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the legal holds")?;
+
+    Ok(ApplicationResponse::Json(
+        legal_holds
+            .into_iter()
+            .map(admin::LegalHoldResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+/// Release the legal hold on a record of the merchant, after which the record is purged once it
+/// is past its retention period
+#[instrument(skip_all)]
+pub async fn delete_legal_hold(
+    state: SessionState,
+    req: admin::LegalHoldDeleteRequest,
+) -> RouterResponse<admin::LegalHoldResponse> {
+    let legal_hold = state
+        .store
+        .delete_legal_hold_by_merchant_id_entity_type_record_id(
+            &req.merchant_id,
+            req.entity_type,
+            &req.record_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "The record is not under a legal hold".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(
+        admin::LegalHoldResponse::foreign_from(legal_hold),
+    ))
+}
@@ -25,6 +25,7 @@ use crate::{
         AcceptDisputeRequestData, AcceptDisputeResponse, DefendDisputeRequestData,
         DefendDisputeResponse, SubmitEvidenceRequestData, SubmitEvidenceResponse,
     },
+    utils,
 };
 
 #[instrument(skip(state))]
@@ -287,7 +288,7 @@ pub async fn submit_evidence(
             })
         },
     )?;
-    let submit_evidence_request_data = transformers::get_evidence_request_data(
+    let mut submit_evidence_request_data = transformers::get_evidence_request_data(
         &state,
         &merchant_account,
         &key_store,
@@ -307,6 +308,14 @@ pub async fn submit_evidence(
         .await
         .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
 
+    // Fall back to the shipping date reported through the fulfillment of the payment, when no
+    // shipping date was uploaded as evidence
+    if submit_evidence_request_data.shipping_date.is_none() {
+        submit_evidence_request_data.shipping_date = payment_intent
+            .shipped_at
+            .map(|shipped_at| shipped_at.date().to_string());
+    }
+
     let payment_attempt = db
         .find_payment_attempt_by_attempt_id_merchant_id(
             &dispute.attempt_id,
@@ -526,6 +535,152 @@ pub async fn retrieve_dispute_evidence(
     Ok(services::ApplicationResponse::Json(dispute_evidence_vec))
 }
 
+/// Export all the evidence files attached to a dispute as a single zip archive.
+///
+/// The archive contains a `manifest.json` listing every evidence file referenced by the dispute.
+/// Files which could not be retrieved are noted in the manifest instead of failing the export. The
+/// communication of the merchant with the customer regarding the disputed payment is included as
+/// `customer_communications.json`, if any.
+#[instrument(skip(state))]
+pub async fn download_dispute_evidence(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: disputes::DisputeId,
+) -> RouterResponse<serde_json::Value> {
+    let dispute = state
+        .store
+        .find_dispute_by_merchant_id_dispute_id(merchant_account.get_id(), &req.dispute_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: req.dispute_id.clone(),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &dispute)?;
+    let dispute_evidence: api::DisputeEvidence = dispute
+        .evidence
+        .clone()
+        .parse_value("DisputeEvidence")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while parsing dispute evidence record")?;
+    let evidence_files: HashMap<String, diesel_models::file::FileMetadata> = state
+        .store
+        .list_dispute_evidence_files(merchant_account.get_id(), &dispute.dispute_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list dispute evidence files")?
+        .into_iter()
+        .map(|file_metadata| (file_metadata.file_id.clone(), file_metadata))
+        .collect();
+
+    let mut archive = utils::zip_archive::ZipArchiveWriter::new();
+    let mut manifest_entries = Vec::new();
+    for (evidence_type, file_id) in transformers::get_dispute_evidence_file_ids(dispute_evidence) {
+        // The same file may be attached as more than one type of evidence
+        let file_data = match evidence_files.get(&file_id).cloned() {
+            Some(file_metadata) => {
+                let file_name = file_metadata.file_name.clone();
+                transformers::retrieve_evidence_file_data(
+                    &state,
+                    file_metadata,
+                    &merchant_account,
+                    &key_store,
+                )
+                .await
+                .map(|file_data| (file_name, file_data))
+            }
+            None => Err("File does not exist".to_string()),
+        };
+        let manifest_entry = match file_data {
+            Ok((file_name, file_data)) => {
+                // The name of the file is provided by the merchant, it is used only when it cannot
+                // be extracted outside of the directory the archive is extracted to
+                let archived_file_name = format!(
+                    "{evidence_type}_{}",
+                    file_name
+                        .as_deref()
+                        .and_then(utils::zip_archive::sanitize_file_name)
+                        .unwrap_or(&file_id)
+                );
+                archive
+                    .add_file(&archived_file_name, &file_data)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to add evidence file to the archive")?;
+                dispute_models::DisputeEvidenceArchiveEntry {
+                    evidence_type,
+                    file_id,
+                    archived_file_name: Some(archived_file_name),
+                    status: dispute_models::DisputeEvidenceArchiveFileStatus::Included,
+                    error_message: None,
+                }
+            }
+            Err(error_message) => dispute_models::DisputeEvidenceArchiveEntry {
+                evidence_type,
+                file_id,
+                archived_file_name: None,
+                status: dispute_models::DisputeEvidenceArchiveFileStatus::Missing,
+                error_message: Some(error_message),
+            },
+        };
+        manifest_entries.push(manifest_entry);
+    }
+
+    #[cfg(all(feature = "v1", not(feature = "customer_v2")))]
+    {
+        // The evidence is exported without the communication with the customer when it cannot be
+        // retrieved, like the evidence files which cannot be retrieved
+        let customer_communications =
+            crate::core::customers::communication::get_payment_customer_communications(
+                &state,
+                merchant_account.get_id(),
+                &key_store,
+                &dispute.payment_id,
+            )
+            .await
+            .inspect_err(|error| {
+                router_env::logger::error!(
+                    ?error,
+                    "Failed to retrieve the customer communications of the disputed payment"
+                )
+            })
+            .unwrap_or_default();
+        if !customer_communications.is_empty() {
+            let customer_communications = customer_communications
+                .encode_to_vec()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to encode the customer communications")?;
+            archive
+                .add_file("customer_communications.json", &customer_communications)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to add the customer communications to the archive")?;
+        }
+    }
+
+    let manifest = dispute_models::DisputeEvidenceArchiveManifest {
+        dispute_id: dispute.dispute_id,
+        files: manifest_entries,
+    }
+    .encode_to_vec()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encode dispute evidence manifest")?;
+    archive
+        .add_file("manifest.json", &manifest)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add manifest to the archive")?;
+    let archive = archive
+        .finish()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to create dispute evidence archive")?;
+    let content_type = "application/zip"
+        .parse::<mime::Mime>()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse archive content type")?;
+    Ok(services::ApplicationResponse::FileData((
+        archive,
+        content_type,
+    )))
+}
+
 pub async fn delete_evidence(
     state: SessionState,
     merchant_account: domain::MerchantAccount,
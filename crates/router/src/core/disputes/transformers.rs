@@ -3,7 +3,10 @@ use common_utils::errors::CustomResult;
 use error_stack::ResultExt;
 
 use crate::{
-    core::{errors, files::helpers::retrieve_file_and_provider_file_id_from_file_id},
+    core::{
+        errors,
+        files::helpers::{retrieve_file_and_provider_file_id_from_file_id, retrieve_file_data},
+    },
     routes::SessionState,
     types::{
         api::{self, DisputeEvidence},
@@ -398,3 +401,67 @@ pub async fn get_dispute_evidence_vec(
     }
     Ok(dispute_evidence_blocks)
 }
+
+/// The identifiers of the files attached to the dispute evidence along with their evidence type
+pub fn get_dispute_evidence_file_ids(
+    dispute_evidence: DisputeEvidence,
+) -> Vec<(EvidenceType, String)> {
+    [
+        (
+            EvidenceType::CancellationPolicy,
+            dispute_evidence.cancellation_policy,
+        ),
+        (
+            EvidenceType::CustomerCommunication,
+            dispute_evidence.customer_communication,
+        ),
+        (
+            EvidenceType::CustomerSignature,
+            dispute_evidence.customer_signature,
+        ),
+        (EvidenceType::Receipt, dispute_evidence.receipt),
+        (EvidenceType::RefundPolicy, dispute_evidence.refund_policy),
+        (
+            EvidenceType::ServiceDocumentation,
+            dispute_evidence.service_documentation,
+        ),
+        (
+            EvidenceType::ShippingDocumentation,
+            dispute_evidence.shipping_documentation,
+        ),
+        (
+            EvidenceType::InvoiceShowingDistinctTransactions,
+            dispute_evidence.invoice_showing_distinct_transactions,
+        ),
+        (
+            EvidenceType::RecurringTransactionAgreement,
+            dispute_evidence.recurring_transaction_agreement,
+        ),
+        (
+            EvidenceType::UncategorizedFile,
+            dispute_evidence.uncategorized_file,
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(evidence_type, file_id)| file_id.map(|file_id| (evidence_type, file_id)))
+    .collect()
+}
+
+/// Retrieve the contents of an evidence file, returning the reason for which the file could not
+/// be retrieved on failure
+pub async fn retrieve_evidence_file_data(
+    state: &SessionState,
+    file_metadata: diesel_models::file::FileMetadata,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> Result<Vec<u8>, String> {
+    retrieve_file_data(state, file_metadata, merchant_account, key_store)
+        .await
+        .map_err(|error| {
+            router_env::logger::error!(?error, "Failed to retrieve dispute evidence file");
+            match error.current_context() {
+                errors::ApiErrorResponse::FileNotAvailable => "File is not available".to_string(),
+                _ => "Failed to retrieve the file".to_string(),
+            }
+        })
+}
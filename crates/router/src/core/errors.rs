@@ -318,6 +318,12 @@ pub enum RoutingError {
     VolumeSplitFailed,
     #[error("Unable to parse metadata")]
     MetadataParsingError,
+    #[error("Unable to parse the default connectors by payment method type of the merchant")]
+    DefaultConnectorParsingError,
+    #[error("Unable to estimate the cost of the connector for the payment")]
+    ConnectorCostEstimationFailed,
+    #[error("Failed to fetch the merchant connector accounts of the merchant")]
+    MerchantConnectorAccountFetchFailed,
 }
 
 #[derive(Debug, Clone, thiserror::Error)]
@@ -34,6 +34,9 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for CustomersError
                 "Customer with the given `customer_id` already exists",
                 None,
             )),
+            Self::InvalidRequestData { message } => {
+                AER::Unprocessable(ApiError::new("IR", 6, message.to_string(), None))
+            }
         }
     }
 }
@@ -62,6 +65,9 @@ impl ErrorSwitch<CustomersErrorResponse> for ApiErrorResponse {
             Self::InternalServerError => CER::InternalServerError,
             Self::MandateActive => CER::MandateActive,
             Self::CustomerNotFound => CER::CustomerNotFound,
+            Self::InvalidRequestData { message } => CER::InvalidRequestData {
+                message: message.clone(),
+            },
             _ => CER::InternalServerError,
         }
     }
@@ -168,6 +168,20 @@ impl<T> ConnectorErrorExt<T> for error_stack::Result<T, errors::ConnectorError>
                 }
                 .into()
             }
+            errors::ConnectorError::ConcurrencyLimitReached {
+                connector,
+                retry_after_in_secs,
+            } => err.change_context(errors::ApiErrorResponse::ConnectorConcurrencyLimitReached {
+                connector: connector.to_owned(),
+                retry_after_in_secs: *retry_after_in_secs,
+            }),
+            errors::ConnectorError::RateLimited {
+                connector,
+                retry_after_in_secs,
+            } => err.change_context(errors::ApiErrorResponse::ConnectorRateLimited {
+                connector: connector.to_owned(),
+                retry_after_in_secs: *retry_after_in_secs,
+            }),
             errors::ConnectorError::FailedToObtainIntegrationUrl
             | errors::ConnectorError::RequestEncodingFailed
             | errors::ConnectorError::RequestEncodingFailedWithReason(_)
@@ -216,6 +230,7 @@ impl<T> ConnectorErrorExt<T> for error_stack::Result<T, errors::ConnectorError>
             | errors::ConnectorError::MissingPaymentMethodType
             | errors::ConnectorError::InSufficientBalanceInPaymentMethod
             | errors::ConnectorError::RequestTimeoutReceived
+            | errors::ConnectorError::ConnectorTimeout { .. }
             | errors::ConnectorError::CurrencyNotSupported { .. }
             | errors::ConnectorError::InvalidConnectorConfig { .. }
             | errors::ConnectorError::AmountConversionFailed { .. }
@@ -275,6 +290,8 @@ impl<T> ConnectorErrorExt<T> for error_stack::Result<T, errors::ConnectorError>
                 errors::ConnectorError::CurrencyNotSupported { message, connector} => errors::ApiErrorResponse::CurrencyNotSupported { message: format!("Credentials for the currency {message} are not configured with the connector {connector}/hyperswitch") },
                 errors::ConnectorError::FailedToObtainAuthType =>  errors::ApiErrorResponse::InvalidConnectorConfiguration {config: "connector_account_details".to_string()},
                 errors::ConnectorError::InvalidConnectorConfig { config }  => errors::ApiErrorResponse::InvalidConnectorConfiguration { config: config.to_string() },
+                errors::ConnectorError::ConcurrencyLimitReached { connector, retry_after_in_secs } => errors::ApiErrorResponse::ConnectorConcurrencyLimitReached { connector: connector.to_owned(), retry_after_in_secs: *retry_after_in_secs },
+                errors::ConnectorError::RateLimited { connector, retry_after_in_secs } => errors::ApiErrorResponse::ConnectorRateLimited { connector: connector.to_owned(), retry_after_in_secs: *retry_after_in_secs },
                 errors::ConnectorError::FailedToObtainIntegrationUrl |
                 errors::ConnectorError::RequestEncodingFailed |
                 errors::ConnectorError::RequestEncodingFailedWithReason(_) |
@@ -314,6 +331,7 @@ impl<T> ConnectorErrorExt<T> for error_stack::Result<T, errors::ConnectorError>
                 errors::ConnectorError::MissingPaymentMethodType |
                 errors::ConnectorError::InSufficientBalanceInPaymentMethod |
                 errors::ConnectorError::RequestTimeoutReceived |
+                errors::ConnectorError::ConnectorTimeout { .. } |
                 errors::ConnectorError::ProcessingStepFailed(None)|
                 errors::ConnectorError::GenericError {..} |
                 errors::ConnectorError::AmountConversionFailed => errors::ApiErrorResponse::InternalServerError
@@ -361,6 +379,20 @@ impl<T> ConnectorErrorExt<T> for error_stack::Result<T, errors::ConnectorError>
                         wallet_name: wallet_name.to_string(),
                     }
                 }
+                errors::ConnectorError::ConcurrencyLimitReached {
+                    connector,
+                    retry_after_in_secs,
+                } => errors::ApiErrorResponse::ConnectorConcurrencyLimitReached {
+                    connector: connector.to_owned(),
+                    retry_after_in_secs: *retry_after_in_secs,
+                },
+                errors::ConnectorError::RateLimited {
+                    connector,
+                    retry_after_in_secs,
+                } => errors::ApiErrorResponse::ConnectorRateLimited {
+                    connector: connector.to_owned(),
+                    retry_after_in_secs: *retry_after_in_secs,
+                },
                 errors::ConnectorError::RequestEncodingFailed
                 | errors::ConnectorError::RequestEncodingFailedWithReason(_)
                 | errors::ConnectorError::ParsingFailed
@@ -407,6 +439,7 @@ impl<T> ConnectorErrorExt<T> for error_stack::Result<T, errors::ConnectorError>
                 | errors::ConnectorError::MissingPaymentMethodType
                 | errors::ConnectorError::InSufficientBalanceInPaymentMethod
                 | errors::ConnectorError::RequestTimeoutReceived
+                | errors::ConnectorError::ConnectorTimeout { .. }
                 | errors::ConnectorError::CurrencyNotSupported { .. }
                 | errors::ConnectorError::ProcessingStepFailed(None)
                 | errors::ConnectorError::AmountConversionFailed { .. }
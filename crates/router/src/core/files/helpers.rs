@@ -170,6 +170,32 @@ pub async fn retrieve_file_from_connector(
     Ok(retrieve_file_response.file_data)
 }
 
+/// Retrieve the contents of the file from the file storage or the connector it was uploaded to
+pub async fn retrieve_file_data(
+    state: &SessionState,
+    file_metadata: diesel_models::file::FileMetadata,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> CustomResult<Vec<u8>, errors::ApiErrorResponse> {
+    let (provider, provider_file_id) = match (
+        file_metadata.file_upload_provider,
+        file_metadata.provider_file_id.clone(),
+        file_metadata.available,
+    ) {
+        (Some(provider), Some(provider_file_id), true) => (provider, provider_file_id),
+        _ => Err(errors::ApiErrorResponse::FileNotAvailable)
+            .attach_printable("File not available")?,
+    };
+    match provider {
+        diesel_models::enums::FileUploadProvider::Router => state
+            .file_storage_client
+            .retrieve_file(&provider_file_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError),
+        _ => retrieve_file_from_connector(state, file_metadata, merchant_account, key_store).await,
+    }
+}
+
 pub async fn retrieve_file_and_provider_file_id_from_file_id(
     state: &SessionState,
     file_id: Option<String>,
@@ -34,8 +34,8 @@ use crate::{
         domain, fraud_check as frm_types,
         storage::{
             enums::{
-                AttemptStatus, FraudCheckLastStep, FraudCheckStatus, FraudCheckType, FrmSuggestion,
-                IntentStatus,
+                AttemptStatus, FraudCheckLastStep, FraudCheckStatus, FraudCheckType, FraudDecision,
+                FrmSuggestion, IntentStatus,
             },
             fraud_check::{FraudCheck, FraudCheckUpdate},
             PaymentIntent,
@@ -45,6 +45,8 @@ use crate::{
 };
 pub mod flows;
 pub mod operation;
+#[cfg(feature = "v1")]
+pub mod review;
 pub mod types;
 
 #[cfg(feature = "v2")]
@@ -515,40 +517,91 @@ where
             ) {
                 let fraud_check_operation = &mut frm_info.fraud_check_operation;
 
-                let frm_router_data = fraud_check_operation
-                    .to_domain()?
-                    .pre_payment_frm(
+                let frm_result = async {
+                    let frm_router_data = fraud_check_operation
+                        .to_domain()?
+                        .pre_payment_frm(
+                            state,
+                            payment_data,
+                            frm_data,
+                            merchant_account,
+                            customer,
+                            key_store.clone(),
+                        )
+                        .await?;
+                    let _router_data = call_frm_service::<F, frm_api::Transaction, _, D>(
                         state,
                         payment_data,
                         frm_data,
                         merchant_account,
+                        &key_store,
                         customer,
-                        key_store.clone(),
                     )
                     .await?;
-                let _router_data = call_frm_service::<F, frm_api::Transaction, _, D>(
+                    let is_provider_unavailable =
+                        is_frm_provider_unavailable_response(&frm_router_data.response);
+                    let frm_data_updated = fraud_check_operation
+                        .to_update_tracker()?
+                        .update_tracker(
+                            state,
+                            &key_store,
+                            frm_data.clone(),
+                            payment_data,
+                            None,
+                            frm_router_data,
+                        )
+                        .await?;
+                    Ok::<_, error_stack::Report<errors::ApiErrorResponse>>((
+                        frm_data_updated,
+                        is_provider_unavailable,
+                    ))
+                }
+                .await;
+
+                let (frm_data_updated, frm_outcome) = match frm_result {
+                    Ok((frm_data_updated, is_provider_unavailable)) => {
+                        let frm_fraud_check = frm_data_updated.fraud_check.clone();
+                        payment_data.set_frm_message(frm_fraud_check.clone());
+                        let frm_outcome = if is_provider_unavailable {
+                            logger::error!(
+                                frm_error = ?frm_fraud_check.frm_error,
+                                "Fraud check provider is unavailable"
+                            );
+                            FraudCheckOutcome::ProviderUnavailable
+                        } else {
+                            FraudCheckOutcome::Checked(frm_fraud_check.frm_status)
+                        };
+                        (frm_data_updated, frm_outcome)
+                    }
+                    Err(error) if is_frm_provider_unreachable(&error) => {
+                        logger::error!(?error, "Fraud check provider is unavailable");
+                        (frm_data.to_owned(), FraudCheckOutcome::ProviderUnavailable)
+                    }
+                    Err(error) => return Err(error),
+                };
+
+                let fraud_decision = get_fraud_decision(frm_outcome, state.conf.frm.fail_open);
+                #[cfg(feature = "v1")]
+                let fraud_decision = apply_customer_risk_to_fraud_decision(
+                    state,
+                    merchant_account.get_id(),
+                    payment_data.get_payment_intent().customer_id.as_ref(),
+                    fraud_decision,
+                )
+                .await;
+                #[cfg(feature = "v1")]
+                review::record_fraud_decision(
                     state,
-                    payment_data,
-                    frm_data,
                     merchant_account,
-                    &key_store,
-                    customer,
+                    payment_data,
+                    frm_outcome
+                        .get_status()
+                        .and(frm_data_updated.fraud_check.frm_score),
+                    fraud_decision,
                 )
                 .await?;
-                let frm_data_updated = fraud_check_operation
-                    .to_update_tracker()?
-                    .update_tracker(
-                        state,
-                        &key_store,
-                        frm_data.clone(),
-                        payment_data,
-                        None,
-                        frm_router_data,
-                    )
-                    .await?;
-                let frm_fraud_check = frm_data_updated.fraud_check.clone();
-                payment_data.set_frm_message(frm_fraud_check.clone());
-                if matches!(frm_fraud_check.frm_status, FraudCheckStatus::Fraud) {
+
+                if matches!(fraud_decision, Some(FraudDecision::Decline)) {
                     *should_continue_transaction = false;
                     frm_info.suggested_action = Some(FrmSuggestion::FrmCancelTransaction);
                 }
@@ -633,6 +686,15 @@ where
                     frm_info.suggested_action = Some(FrmSuggestion::FrmCancelTransaction);
                 } else if matches!(frm_fraud_check.frm_status, FraudCheckStatus::ManualReview) {
                     frm_info.suggested_action = Some(FrmSuggestion::FrmManualReview);
+                    #[cfg(feature = "v1")]
+                    review::record_fraud_decision(
+                        state,
+                        merchant_account,
+                        payment_data,
+                        frm_fraud_check.frm_score,
+                        Some(FraudDecision::Review),
+                    )
+                    .await?;
                 }
                 fraud_check_operation
                     .to_domain()?
@@ -763,6 +825,105 @@ pub fn is_operation_allowed<Op: Debug>(operation: &Op) -> bool {
     .contains(&format!("{operation:?}").as_str())
 }
 
+/// The outcome of the fraud check of a payment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FraudCheckOutcome {
+    /// The fraud check provider processed the fraud check, with the status of the fraud check
+    Checked(FraudCheckStatus),
+    /// The fraud check provider could not be reached, timed out or failed with a server error
+    ProviderUnavailable,
+}
+
+impl FraudCheckOutcome {
+    /// The status of the fraud check, if the fraud check provider processed the fraud check
+    pub fn get_status(self) -> Option<FraudCheckStatus> {
+        match self {
+            Self::Checked(frm_status) => Some(frm_status),
+            Self::ProviderUnavailable => None,
+        }
+    }
+}
+
+/// Whether the error response of the fraud check provider indicates that the provider is
+/// unavailable, such as a timeout or a server error, as opposed to a rejection of the fraud check
+/// request
+fn is_frm_provider_unavailable_response(frm_response: &frm_types::FrmResponse) -> bool {
+    let (frm_types::FrmResponse::Sale(Err(error_response))
+    | frm_types::FrmResponse::Checkout(Err(error_response))
+    | frm_types::FrmResponse::Transaction(Err(error_response))
+    | frm_types::FrmResponse::Fulfillment(Err(error_response))
+    | frm_types::FrmResponse::RecordReturn(Err(error_response))) = frm_response
+    else {
+        return false;
+    };
+
+    error_response.status_code >= 500
+}
+
+/// Whether the fraud check failed because the request could not be sent to the fraud check
+/// provider, such as a failure to connect to the provider
+fn is_frm_provider_unreachable(error: &error_stack::Report<errors::ApiErrorResponse>) -> bool {
+    error.contains::<errors::ApiClientError>()
+}
+
+/// Derive the fraud decision to be recorded on the payment attempt from the outcome of the fraud
+/// check.
+///
+/// A payment whose fraud check provider is unavailable is either sent for review (fail open) or
+/// declined (fail closed). No decision is taken for a fraud check which failed for any other
+/// reason, such as the provider rejecting the fraud check request.
+pub fn get_fraud_decision(
+    frm_outcome: FraudCheckOutcome,
+    fail_open: bool,
+) -> Option<FraudDecision> {
+    match frm_outcome {
+        FraudCheckOutcome::Checked(FraudCheckStatus::Legit) => Some(FraudDecision::Approve),
+        FraudCheckOutcome::Checked(FraudCheckStatus::ManualReview | FraudCheckStatus::Pending) => {
+            Some(FraudDecision::Review)
+        }
+        FraudCheckOutcome::Checked(FraudCheckStatus::Fraud) => Some(FraudDecision::Decline),
+        FraudCheckOutcome::Checked(FraudCheckStatus::TransactionFailure) => None,
+        FraudCheckOutcome::ProviderUnavailable => {
+            if fail_open {
+                Some(FraudDecision::Review)
+            } else {
+                Some(FraudDecision::Decline)
+            }
+        }
+    }
+}
+
+/// Send the payment for review instead of approving it when the risk profile of the customer
+/// requires the payments of the customer to be reviewed, such as for a customer of the high risk
+/// tier. The fraud decision is left unchanged if the risk profile cannot be fetched.
+#[cfg(feature = "v1")]
+async fn apply_customer_risk_to_fraud_decision(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    customer_id: Option<&common_utils::id_type::CustomerId>,
+    fraud_decision: Option<FraudDecision>,
+) -> Option<FraudDecision> {
+    let Some((customer_id, FraudDecision::Approve)) = customer_id.zip(fraud_decision) else {
+        return fraud_decision;
+    };
+
+    match super::customers::risk_profile::get_customer_risk(state, merchant_id, customer_id).await {
+        Ok(customer_risk) if customer_risk.requires_review() => {
+            logger::info!(
+                risk_tier = %customer_risk.tier,
+                flags = ?customer_risk.flags,
+                "Sending the payment for review for the risk profile of the customer"
+            );
+            Some(FraudDecision::Review)
+        }
+        Ok(_) => fraud_decision,
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch the customer risk profile");
+            fraud_decision
+        }
+    }
+}
+
 #[cfg(feature = "v1")]
 impl From<PaymentToFrmData> for PaymentDetails {
     fn from(payment_data: PaymentToFrmData) -> Self {
@@ -909,3 +1070,83 @@ pub async fn make_fulfillment_api_call(
             })?;
     Ok(services::ApplicationResponse::Json(fulfillment_response))
 }
+
+#[cfg(test)]
+mod tests {
+    use hyperswitch_domain_models::router_data::ErrorResponse;
+
+    use super::*;
+
+    #[test]
+    fn test_fraud_decision_of_checked_fraud_check() {
+        assert_eq!(
+            get_fraud_decision(FraudCheckOutcome::Checked(FraudCheckStatus::Legit), false),
+            Some(FraudDecision::Approve)
+        );
+        assert_eq!(
+            get_fraud_decision(
+                FraudCheckOutcome::Checked(FraudCheckStatus::ManualReview),
+                false
+            ),
+            Some(FraudDecision::Review)
+        );
+        assert_eq!(
+            get_fraud_decision(FraudCheckOutcome::Checked(FraudCheckStatus::Fraud), true),
+            Some(FraudDecision::Decline)
+        );
+    }
+
+    #[test]
+    fn test_failed_fraud_check_does_not_decline_the_payment() {
+        assert_eq!(
+            get_fraud_decision(
+                FraudCheckOutcome::Checked(FraudCheckStatus::TransactionFailure),
+                false
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_fraud_decision_of_unavailable_provider() {
+        assert_eq!(
+            get_fraud_decision(FraudCheckOutcome::ProviderUnavailable, true),
+            Some(FraudDecision::Review)
+        );
+        assert_eq!(
+            get_fraud_decision(FraudCheckOutcome::ProviderUnavailable, false),
+            Some(FraudDecision::Decline)
+        );
+    }
+
+    #[test]
+    fn test_only_server_errors_indicate_unavailable_provider() {
+        let get_response = |status_code| {
+            frm_types::FrmResponse::Transaction(Err(ErrorResponse {
+                status_code,
+                ..Default::default()
+            }))
+        };
+
+        assert!(is_frm_provider_unavailable_response(&get_response(503)));
+        assert!(is_frm_provider_unavailable_response(&get_response(504)));
+        assert!(!is_frm_provider_unavailable_response(&get_response(400)));
+        assert!(!is_frm_provider_unavailable_response(&get_response(422)));
+    }
+
+    #[test]
+    fn test_only_connection_errors_indicate_unreachable_provider() {
+        let connection_error = error_stack::report!(errors::ApiClientError::ConnectionFailed(
+            "refused".to_string()
+        ))
+        .change_context(errors::ConnectorError::ProcessingStepFailed(None))
+        .change_context(errors::ApiErrorResponse::InternalServerError);
+        let validation_error = error_stack::report!(errors::ConnectorError::MissingRequiredField {
+            field_name: "email"
+        })
+        .change_context(errors::ApiErrorResponse::InternalServerError);
+
+        assert!(is_frm_provider_unreachable(&connection_error));
+        assert!(!is_frm_provider_unreachable(&validation_error));
+    }
+}
@@ -83,6 +83,7 @@ pub async fn construct_fulfillment_router_data<'a>(
         auth_type: payment_attempt.authentication_type.unwrap_or_default(),
         connector_meta_data: merchant_connector_account.get_metadata(),
         connector_wallets_details: merchant_connector_account.get_connector_wallets_details(),
+        connector_request_timeout_secs: merchant_connector_account.get_connector_request_timeout(),
         amount_captured: payment_intent
             .amount_captured
             .map(|amt| amt.get_amount_as_i64()),
@@ -116,6 +117,7 @@ pub async fn construct_fulfillment_router_data<'a>(
         connector_api_version: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_network_retry_count: None,
         apple_pay_flow: None,
         frm_metadata: None,
         refund_id: None,
@@ -85,6 +85,7 @@ impl ConstructFlowSpecificData<RecordReturn, FraudCheckRecordReturnData, FraudCh
             auth_type: storage_enums::AuthenticationType::NoThreeDs,
             connector_meta_data: None,
             connector_wallets_details: None,
+            connector_request_timeout_secs: None,
             amount_captured: None,
             minor_amount_captured: None,
             request: FraudCheckRecordReturnData {
@@ -115,6 +116,7 @@ impl ConstructFlowSpecificData<RecordReturn, FraudCheckRecordReturnData, FraudCh
             payment_method_balance: None,
             connector_http_status_code: None,
             external_latency: None,
+            connector_network_retry_count: None,
             connector_api_version: None,
             apple_pay_flow: None,
             frm_metadata: None,
@@ -81,6 +81,7 @@ impl ConstructFlowSpecificData<frm_api::Sale, FraudCheckSaleData, FraudCheckResp
             auth_type: storage_enums::AuthenticationType::NoThreeDs,
             connector_meta_data: None,
             connector_wallets_details: None,
+            connector_request_timeout_secs: None,
             amount_captured: None,
             minor_amount_captured: None,
             request: FraudCheckSaleData {
@@ -123,6 +124,7 @@ impl ConstructFlowSpecificData<frm_api::Sale, FraudCheckSaleData, FraudCheckResp
             payment_method_balance: None,
             connector_http_status_code: None,
             external_latency: None,
+            connector_network_retry_count: None,
             connector_api_version: None,
             apple_pay_flow: None,
             frm_metadata: self.frm_metadata.clone(),
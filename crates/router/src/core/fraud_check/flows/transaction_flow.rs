@@ -93,6 +93,7 @@ impl
             auth_type: storage_enums::AuthenticationType::NoThreeDs,
             connector_meta_data: None,
             connector_wallets_details: None,
+            connector_request_timeout_secs: None,
             amount_captured: None,
             minor_amount_captured: None,
             request: FraudCheckTransactionData {
@@ -128,6 +129,7 @@ impl
             payment_method_balance: None,
             connector_http_status_code: None,
             external_latency: None,
+            connector_network_retry_count: None,
             connector_api_version: None,
             payment_method_status: None,
             apple_pay_flow: None,
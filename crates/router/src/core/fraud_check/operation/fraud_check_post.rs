@@ -136,6 +136,7 @@ impl GetTracker<PaymentToFrmData> for FraudCheckPost {
                     modified_at: common_utils::date_time::now(),
                     last_step: FraudCheckLastStep::Processing,
                     payment_capture_method: payment_data.payment_attempt.capture_method,
+                    reviewed_by: None,
                 })
                 .await
             }
@@ -311,6 +312,7 @@ where
                 payment_id: frm_data.payment_intent.get_id().to_owned(),
                 merchant_id: None,
                 amount_to_capture: None,
+                currency: None,
                 refund_uncaptured_amount: None,
                 statement_descriptor_suffix: None,
                 statement_descriptor_prefix: None,
@@ -0,0 +1,484 @@
+//! Review of the payments sent for review by the fraud check.
+//!
+//! A payment whose fraud check asks for a manual review is recorded with the `review` fraud
+//! decision, which places it in the review queue of the merchant. The reviewer either approves the
+//! payment, which proceeds to capture it, or declines it, which voids it, and the decision is
+//! recorded on the payment attempt along with the reviewer, who is the user or the API key which
+//! made the request. A payment which is not reviewed within the configured review timeout is
+//! resolved with the configured decision.
+
+#[cfg(feature = "olap")]
+use api_models::payments::{
+    PaymentPendingReview, PaymentsPendingReviewListConstraints, PaymentsPendingReviewListResponse,
+};
+use api_models::payments::{PaymentsApproveRequest, PaymentsRejectRequest, PaymentsResponse};
+#[cfg(feature = "olap")]
+use common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1;
+use common_utils::{date_time, id_type};
+#[cfg(feature = "olap")]
+use error_stack::report;
+use error_stack::ResultExt;
+#[cfg(feature = "olap")]
+use hyperswitch_domain_models::payments::payment_attempt::PaymentAttemptReviewFilters;
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::{self, CallConnectorAction},
+    },
+    db::StorageInterface,
+    routes::{app::ReqState, metrics, SessionState},
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums::FraudDecision, fraud_check::FraudCheckUpdate},
+    },
+};
+
+const FRM_REVIEW_TIMEOUT_TASK: &str = "FRM_REVIEW_TIMEOUT";
+const FRM_REVIEW_TIMEOUT_TAG: &str = "FRM_REVIEW";
+
+/// Approve a payment which was sent for review by the fraud check, proceeding to capture it
+#[instrument(skip_all)]
+pub async fn approve_reviewed_payment(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    req: PaymentsApproveRequest,
+) -> RouterResponse<PaymentsResponse> {
+    let reviewed_by = get_reviewer(&req_state);
+    let response = payments::payments_core::<
+        api::Capture,
+        PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::Capture>,
+    >(
+        state.clone(),
+        req_state,
+        merchant_account.clone(),
+        profile_id,
+        key_store,
+        payments::PaymentApprove,
+        api::PaymentsCaptureRequest {
+            payment_id: req.payment_id.clone(),
+            ..Default::default()
+        },
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Trigger,
+        None,
+        api::HeaderPayload::default(),
+    )
+    .await?;
+
+    // The payment was already processed, so a failure to record the decision does not fail it
+    record_review_decision(
+        &state,
+        &merchant_account,
+        &req.payment_id,
+        FraudDecision::Approve,
+        reviewed_by,
+    )
+    .await
+    .inspect_err(|error| logger::error!(?error, "Failed to record the review decision"))
+    .ok();
+
+    Ok(response)
+}
+
+/// Decline a payment which was sent for review by the fraud check, voiding it
+#[instrument(skip_all)]
+pub async fn reject_reviewed_payment(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    req: PaymentsRejectRequest,
+) -> RouterResponse<PaymentsResponse> {
+    let reviewed_by = get_reviewer(&req_state);
+    let response = payments::payments_core::<
+        api::Void,
+        PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::Void>,
+    >(
+        state.clone(),
+        req_state,
+        merchant_account.clone(),
+        profile_id,
+        key_store,
+        payments::PaymentReject,
+        api::PaymentsCancelRequest {
+            payment_id: req.payment_id.clone(),
+            cancellation_reason: Some("Rejected by merchant".to_string()),
+            ..Default::default()
+        },
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Trigger,
+        None,
+        api::HeaderPayload::default(),
+    )
+    .await?;
+
+    // The payment was already processed, so a failure to record the decision does not fail it
+    record_review_decision(
+        &state,
+        &merchant_account,
+        &req.payment_id,
+        FraudDecision::Decline,
+        reviewed_by,
+    )
+    .await
+    .inspect_err(|error| logger::error!(?error, "Failed to record the review decision"))
+    .ok();
+
+    Ok(response)
+}
+
+/// Resolve a payment which was not reviewed within the review timeout with the configured decision
+#[instrument(skip_all)]
+pub async fn resolve_unreviewed_payment(
+    state: &SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_id: id_type::PaymentId,
+) -> RouterResult<()> {
+    match get_review_timeout_resolution(state.conf.frm.review_timeout.decision) {
+        FraudDecision::Approve => {
+            Box::pin(approve_reviewed_payment(
+                state.clone(),
+                state.get_req_state(),
+                merchant_account,
+                None,
+                key_store,
+                PaymentsApproveRequest { payment_id },
+            ))
+            .await?
+        }
+        FraudDecision::Review | FraudDecision::Decline => {
+            Box::pin(reject_reviewed_payment(
+                state.clone(),
+                state.get_req_state(),
+                merchant_account,
+                None,
+                key_store,
+                PaymentsRejectRequest { payment_id },
+            ))
+            .await?
+        }
+    };
+
+    Ok(())
+}
+
+/// List the payments of the merchant which are pending a review of their fraud check, the oldest
+/// first, as they are the closest to being resolved by the review timeout
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn list_payments_pending_review(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id_list: Option<Vec<id_type::ProfileId>>,
+    constraints: PaymentsPendingReviewListConstraints,
+) -> RouterResponse<PaymentsPendingReviewListResponse> {
+    let filters = get_review_filters(constraints, profile_id_list)?;
+
+    let payment_attempts = state
+        .store
+        .list_payments_pending_review(
+            merchant_account.get_id(),
+            &filters,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the payments pending review")?;
+
+    let data = payment_attempts
+        .into_iter()
+        .map(|payment_attempt| PaymentPendingReview {
+            payment_id: payment_attempt.payment_id,
+            attempt_id: payment_attempt.attempt_id,
+            profile_id: payment_attempt.profile_id,
+            amount: payment_attempt.amount,
+            currency: payment_attempt.currency,
+            connector: payment_attempt.connector,
+            fraud_score: payment_attempt.fraud_score,
+            fraud_decision: payment_attempt.fraud_decision,
+            created: payment_attempt.created_at,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentsPendingReviewListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+#[cfg(feature = "olap")]
+fn get_review_filters(
+    constraints: PaymentsPendingReviewListConstraints,
+    profile_id_list: Option<Vec<id_type::ProfileId>>,
+) -> RouterResult<PaymentAttemptReviewFilters> {
+    if constraints.limit < 1 || constraints.limit > PAYMENTS_LIST_MAX_LIMIT_V1 {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("limit should be in between 1 and {PAYMENTS_LIST_MAX_LIMIT_V1}"),
+        }));
+    }
+
+    Ok(PaymentAttemptReviewFilters {
+        profile_id_list,
+        connector: constraints
+            .connector
+            .map(|connector| vec![connector.to_string()]),
+        min_fraud_score: constraints.min_fraud_score,
+        created_before: constraints.created_lt,
+        limit: Some(i64::from(constraints.limit)),
+        offset: constraints.offset.map(i64::from),
+    })
+}
+
+/// The reviewer of a payment, which is the user or the API key which made the request. There is no
+/// reviewer for the payments resolved by the review timeout.
+fn get_reviewer(req_state: &ReqState) -> Option<String> {
+    req_state
+        .auth_type
+        .as_ref()
+        .map(|auth_type| auth_type.get_actor())
+}
+
+/// The decision with which a payment not reviewed within the review timeout is resolved. A payment
+/// is declined when the configured decision is to review it, as it cannot remain pending review.
+fn get_review_timeout_resolution(configured_decision: FraudDecision) -> FraudDecision {
+    match configured_decision {
+        FraudDecision::Approve => FraudDecision::Approve,
+        FraudDecision::Review | FraudDecision::Decline => FraudDecision::Decline,
+    }
+}
+
+/// Whether the payment attempt is still pending a review of its fraud check
+pub fn is_review_pending(fraud_decision: Option<FraudDecision>) -> bool {
+    fraud_decision == Some(FraudDecision::Review)
+}
+
+/// Record the decision taken on a reviewed payment on its payment attempt, and the reviewer who
+/// took it on its fraud check. The reviewer is `None` for the payments resolved by the review
+/// timeout.
+async fn record_review_decision(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payment_id: &id_type::PaymentId,
+    fraud_decision: FraudDecision,
+    reviewed_by: Option<String>,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let merchant_id = merchant_account.get_id();
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let Some(fraud_check) = db
+        .find_fraud_check_by_payment_id_if_present(payment_id.to_owned(), merchant_id.to_owned())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the fraud check of the payment")?
+    else {
+        return Ok(());
+    };
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            payment_id,
+            merchant_id,
+            &fraud_check.attempt_id,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    db.update_payment_attempt_with_attempt_id(
+        payment_attempt,
+        storage::PaymentAttemptUpdate::FraudCheckUpdate {
+            fraud_score: None,
+            fraud_decision: Some(fraud_decision),
+            updated_by: storage_scheme.to_string(),
+        },
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let last_step = fraud_check.last_step;
+    db.update_fraud_check_response_with_attempt_id(
+        fraud_check,
+        FraudCheckUpdate::ReviewUpdate {
+            reviewed_by,
+            last_step,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to record the reviewer of the payment")?;
+
+    Ok(())
+}
+
+/// Record the fraud score and decision of the fraud check on the payment attempt. The resolution of
+/// the payments sent for review is scheduled for when the review timeout elapses, if enabled.
+pub async fn record_fraud_decision<F, D>(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payment_data: &mut D,
+    fraud_score: Option<i32>,
+    fraud_decision: Option<FraudDecision>,
+) -> RouterResult<()>
+where
+    F: Send + Clone,
+    D: payments::OperationSessionGetters<F> + payments::OperationSessionSetters<F>,
+{
+    let payment_attempt = state
+        .store
+        .update_payment_attempt_with_attempt_id(
+            payment_data.get_payment_attempt().clone(),
+            storage::PaymentAttemptUpdate::FraudCheckUpdate {
+                fraud_score,
+                fraud_decision,
+                updated_by: merchant_account.storage_scheme.to_string(),
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let review_timeout = &state.conf.frm.review_timeout;
+    if review_timeout.enabled && matches!(fraud_decision, Some(FraudDecision::Review)) {
+        // The payment can still be reviewed when the resolution could not be scheduled
+        add_review_timeout_task(
+            &*state.store,
+            &payment_attempt,
+            review_timeout.timeout_in_secs,
+        )
+        .await
+        .inspect_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to schedule the review timeout of the payment"
+            )
+        })
+        .ok();
+    }
+
+    payment_data.set_payment_attempt(payment_attempt);
+    Ok(())
+}
+
+async fn add_review_timeout_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+    timeout_in_secs: u32,
+) -> RouterResult<()> {
+    let schedule_time =
+        date_time::now().saturating_add(time::Duration::seconds(i64::from(timeout_in_secs)));
+
+    let tracking_data = storage::FrmReviewTimeoutTrackingData {
+        payment_id: payment_attempt.payment_id.clone(),
+        merchant_id: payment_attempt.merchant_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+    };
+
+    let runner = storage::ProcessTrackerRunner::FrmReviewTimeoutWorkflow;
+    let process_tracker_id = format!(
+        "{runner}_{FRM_REVIEW_TIMEOUT_TASK}_{}",
+        payment_attempt.attempt_id
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        FRM_REVIEW_TIMEOUT_TASK,
+        runner,
+        [FRM_REVIEW_TIMEOUT_TAG],
+        tracking_data,
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct FRM_REVIEW_TIMEOUT process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting FRM_REVIEW_TIMEOUT task to process_tracker for attempt_id: {}",
+                payment_attempt.attempt_id
+            )
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("flow", "FrmReviewTimeout")]),
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_unreviewed_payment_is_not_left_pending_review() {
+        assert_eq!(
+            get_review_timeout_resolution(FraudDecision::Approve),
+            FraudDecision::Approve
+        );
+        assert_eq!(
+            get_review_timeout_resolution(FraudDecision::Review),
+            FraudDecision::Decline
+        );
+        assert_eq!(
+            get_review_timeout_resolution(FraudDecision::Decline),
+            FraudDecision::Decline
+        );
+    }
+
+    #[test]
+    fn test_review_is_pending_only_for_review_decision() {
+        assert!(is_review_pending(Some(FraudDecision::Review)));
+        assert!(!is_review_pending(Some(FraudDecision::Approve)));
+        assert!(!is_review_pending(Some(FraudDecision::Decline)));
+        assert!(!is_review_pending(None));
+    }
+
+    #[cfg(feature = "olap")]
+    #[test]
+    fn test_review_filters_from_constraints() {
+        let constraints = PaymentsPendingReviewListConstraints {
+            connector: Some(api_models::enums::Connector::Stripe),
+            min_fraud_score: Some(50),
+            created_lt: None,
+            limit: 20,
+            offset: Some(40),
+        };
+
+        let filters = get_review_filters(constraints.clone(), None).unwrap();
+        assert_eq!(filters.connector, Some(vec!["stripe".to_string()]));
+        assert_eq!(filters.min_fraud_score, Some(50));
+        assert_eq!(filters.limit, Some(20));
+        assert_eq!(filters.offset, Some(40));
+
+        for limit in [0, PAYMENTS_LIST_MAX_LIMIT_V1 + 1] {
+            let constraints = PaymentsPendingReviewListConstraints {
+                limit,
+                ..constraints.clone()
+            };
+            assert!(get_review_filters(constraints, None).is_err());
+        }
+    }
+}
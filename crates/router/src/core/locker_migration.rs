@@ -80,6 +80,7 @@ pub async fn rust_locker_migration(
     let constraints = CustomerListConstraints {
         limit: u16::MAX,
         offset: None,
+        metadata_value_hash: None,
     };
 
     let domain_customers = db
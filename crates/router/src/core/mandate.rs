@@ -279,6 +279,103 @@ where
     }
 }
 
+/// Whether the usage of the mandate is recorded when a payment of the mandate is confirmed,
+/// rather than after the payment is charged, as the mandate constrains its total amount or the
+/// frequency of its charges
+pub fn is_mandate_usage_reserved(mandate: &storage::Mandate) -> bool {
+    mandate.mandate_type == storage_enums::MandateType::MultiUse
+        && mandate.constraints.is_some_and(|constraints| {
+            constraints.total_amount.is_some() || constraints.frequency.is_some()
+        })
+}
+
+/// Record the amount of a payment against the constraints of the mandate when the payment is
+/// confirmed. The constraints are checked and the usage is recorded atomically, so that the
+/// payments confirmed concurrently against the mandate cannot together exceed its constraints.
+pub async fn reserve_mandate_usage(
+    state: &SessionState,
+    mandate: &storage::Mandate,
+    amount: common_utils::types::MinorUnit,
+) -> errors::RouterResult<()> {
+    let Some(constraints) = mandate
+        .constraints
+        .filter(|_| is_mandate_usage_reserved(mandate))
+    else {
+        return Ok(());
+    };
+
+    state
+        .store
+        .reserve_mandate_usage(
+            &mandate.merchant_id,
+            &mandate.mandate_id,
+            amount.get_amount_as_i64(),
+            constraints
+                .total_amount
+                .map(|total_amount| total_amount.get_amount_as_i64()),
+            constraints.frequency.map(|frequency| {
+                payment_helper::get_mandate_period_start(
+                    frequency,
+                    common_utils::date_time::now(),
+                )
+            }),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to reserve mandate usage")?
+        .map(|_| ())
+        .ok_or(report!(errors::ApiErrorResponse::MandateValidationFailed {
+            reason: "request amount exceeds the remaining amount or the frequency allowed by the mandate".into()
+        }))
+}
+
+/// Release the usage recorded for a payment of the mandate which failed, so that the amount and
+/// the charge of the period can be used by another payment
+pub async fn release_mandate_usage(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    mandate_id: &str,
+    amount: common_utils::types::MinorUnit,
+    storage_scheme: MerchantStorageScheme,
+) -> errors::RouterResult<()> {
+    let mandate = match state
+        .store
+        .find_mandate_by_merchant_id_mandate_id(merchant_id, mandate_id, storage_scheme)
+        .await
+    {
+        Ok(mandate) => mandate,
+        // The mandate of a payment which was to set up the mandate is only created once the
+        // payment succeeds
+        Err(error) if error.current_context().is_db_not_found() => return Ok(()),
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch mandate")
+        }
+    };
+    let Some(constraints) = mandate
+        .constraints
+        .filter(|_| is_mandate_usage_reserved(&mandate))
+    else {
+        return Ok(());
+    };
+
+    state
+        .store
+        .release_mandate_usage(
+            merchant_id,
+            mandate_id,
+            amount.get_amount_as_i64(),
+            constraints.frequency.map(|frequency| {
+                payment_helper::get_mandate_period_start(frequency, common_utils::date_time::now())
+            }),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to release mandate usage")
+        .map(|_| ())
+}
+
 pub async fn mandate_procedure<F, FData>(
     state: &SessionState,
     resp: &types::RouterData<F, FData, types::PaymentsResponseData>,
@@ -323,16 +420,23 @@ where
                     )
                     .await
                     .change_context(errors::ApiErrorResponse::MandateUpdateFailed),
+                // The usage was recorded when the payment was confirmed
+                storage_enums::MandateType::MultiUse
+                    if is_mandate_usage_reserved(&orig_mandate) =>
+                {
+                    Ok(orig_mandate)
+                }
                 storage_enums::MandateType::MultiUse => state
                     .store
                     .update_mandate_by_merchant_id_mandate_id(
                         &resp.merchant_id,
                         mandate_id,
-                        storage::MandateUpdate::CaptureAmountUpdate {
+                        storage::MandateUpdate::UsageUpdate {
                             amount_captured: Some(
                                 orig_mandate.amount_captured.unwrap_or(0)
                                     + resp.request.get_amount(),
                             ),
+                            last_charged_at: common_utils::date_time::now(),
                         },
                         orig_mandate,
                         storage_scheme,
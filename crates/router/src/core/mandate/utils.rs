@@ -19,6 +19,29 @@ pub async fn construct_mandate_revoke_router_data(
     merchant_connector_account: helpers::MerchantConnectorAccountType,
     merchant_account: &domain::MerchantAccount,
     mandate: Mandate,
+) -> CustomResult<types::MandateRevokeRouterData, errors::ApiErrorResponse> {
+    construct_connector_mandate_revoke_router_data(
+        merchant_connector_account,
+        merchant_account,
+        mandate.customer_id,
+        mandate.connector,
+        mandate.original_payment_id,
+        types::MandateRevokeRequestData {
+            mandate_id: mandate.mandate_id,
+            connector_mandate_id: mandate.connector_mandate_id,
+        },
+    )
+}
+
+/// Construct the router data to revoke a mandate at the connector, for mandates which may not have
+/// a mandate record, such as the connector mandates stored against a payment method
+pub fn construct_connector_mandate_revoke_router_data(
+    merchant_connector_account: helpers::MerchantConnectorAccountType,
+    merchant_account: &domain::MerchantAccount,
+    customer_id: common_utils::id_type::CustomerId,
+    connector: String,
+    original_payment_id: Option<common_utils::id_type::PaymentId>,
+    request: types::MandateRevokeRequestData,
 ) -> CustomResult<types::MandateRevokeRouterData, errors::ApiErrorResponse> {
     let auth_type: types::ConnectorAuthType = merchant_connector_account
         .get_connector_account_details()
@@ -27,11 +50,10 @@ pub async fn construct_mandate_revoke_router_data(
     let router_data = types::RouterData {
         flow: PhantomData,
         merchant_id: merchant_account.get_id().clone(),
-        customer_id: Some(mandate.customer_id),
+        customer_id: Some(customer_id),
         connector_customer: None,
-        connector: mandate.connector,
-        payment_id: mandate
-            .original_payment_id
+        connector,
+        payment_id: original_payment_id
             .unwrap_or_else(|| {
                 common_utils::id_type::PaymentId::get_irrelevant_id("mandate_revoke")
             })
@@ -47,6 +69,7 @@ pub async fn construct_mandate_revoke_router_data(
         auth_type: diesel_models::enums::AuthenticationType::default(),
         connector_meta_data: None,
         connector_wallets_details: None,
+        connector_request_timeout_secs: None,
         amount_captured: None,
         minor_amount_captured: None,
         access_token: None,
@@ -58,16 +81,14 @@ pub async fn construct_mandate_revoke_router_data(
         payment_method_balance: None,
         connector_api_version: None,
         payment_method_status: None,
-        request: types::MandateRevokeRequestData {
-            mandate_id: mandate.mandate_id,
-            connector_mandate_id: mandate.connector_mandate_id,
-        },
+        request,
         response: Err(types::ErrorResponse::get_not_implemented()),
         connector_request_reference_id:
             IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_MANDATE_REVOKE_FLOW.to_string(),
         test_mode: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_network_retry_count: None,
         apple_pay_flow: None,
         frm_metadata: None,
         #[cfg(feature = "payouts")]
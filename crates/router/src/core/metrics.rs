@@ -42,9 +42,11 @@ counter_metric!(
 counter_metric!(WEBHOOK_INCOMING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_INCOMING_FILTERED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_SOURCE_VERIFIED_COUNT, GLOBAL_METER);
+counter_metric!(WEBHOOK_RESOURCE_ENRICHMENT_COUNT, GLOBAL_METER); // No. of incoming webhooks whose resource was fetched from the connector
 counter_metric!(WEBHOOK_OUTGOING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_RECEIVED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_NOT_RECEIVED_COUNT, GLOBAL_METER);
+counter_metric!(WEBHOOK_OUTGOING_DELIVERY_DEFERRED_COUNT, GLOBAL_METER); // No. of outgoing webhooks whose delivery was deferred to the retry task
 counter_metric!(WEBHOOK_PAYMENT_NOT_FOUND, GLOBAL_METER);
 counter_metric!(
     WEBHOOK_EVENT_TYPE_IDENTIFICATION_FAILURE_COUNT,
@@ -84,6 +86,9 @@ counter_metric!(
     GLOBAL_METER
 );
 counter_metric!(DYNAMIC_SUCCESS_BASED_ROUTING, GLOBAL_METER);
+counter_metric!(ROUTING_CONNECTOR_IN_MAINTENANCE_DIVERTED, GLOBAL_METER);
+counter_metric!(DUPLICATE_PAYMENT_DETECTED, GLOBAL_METER);
+counter_metric!(CVV_RECOLLECTION_REQUIRED, GLOBAL_METER);
 
 #[cfg(feature = "partial-auth")]
 counter_metric!(PARTIAL_AUTH_FAILURE, GLOBAL_METER);
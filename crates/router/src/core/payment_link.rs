@@ -22,9 +22,10 @@ use time::PrimitiveDateTime;
 
 use super::{
     errors::{self, RouterResult, StorageErrorExt},
-    payments::helpers,
+    payments::{helpers, token_expiry},
 };
 use crate::{
+    configs::settings,
     consts,
     errors::RouterResponse,
     get_payment_link_config_value, get_payment_link_config_value_based_on_priority,
@@ -177,7 +178,11 @@ pub async fn form_payment_link_data(
 
     // converting first letter of merchant name to upperCase
     let merchant_name = capitalize_first_char(&payment_link_config.seller_name);
-    let payment_link_status = check_payment_link_status(session_expiry);
+    let payment_link_status = check_payment_link_status_for_payment(
+        &state.conf.token_expiry,
+        session_expiry,
+        payment_intent.status,
+    );
 
     let is_terminal_state = check_payment_link_invalid_conditions(
         &payment_intent.status,
@@ -507,6 +512,54 @@ pub fn check_payment_link_status(
     }
 }
 
+/// The status of the payment link of the payment, which stays active through the grace period for
+/// a customer in the middle of completing the payment when the payment link expires
+pub fn check_payment_link_status_for_payment(
+    token_expiry_config: &settings::TokenExpiry,
+    payment_link_expiry: PrimitiveDateTime,
+    payment_status: storage_enums::IntentStatus,
+) -> api_models::payments::PaymentLinkStatus {
+    if token_expiry::get_token_expiry_status(
+        token_expiry_config,
+        payment_link_expiry,
+        payment_status,
+    )
+    .is_resolvable()
+    {
+        api_models::payments::PaymentLinkStatus::Active
+    } else {
+        api_models::payments::PaymentLinkStatus::Expired
+    }
+}
+
+/// Delete the payment links which expired before the grace period of the customers in the middle
+/// of completing the payments, in batches of the oldest payment links first. Returns the number of
+/// the payment links deleted.
+pub async fn purge_expired_payment_links(state: &SessionState) -> RouterResult<usize> {
+    let config = &state.conf.data_retention;
+    let expired_before = common_utils::date_time::now().saturating_sub(time::Duration::seconds(
+        i64::from(state.conf.token_expiry.grace_period_in_secs),
+    ));
+    let mut purged_count = 0;
+
+    for _ in 0..config.max_batches_per_run {
+        let count = state
+            .store
+            .delete_payment_links_expired_before(expired_before, i64::from(config.batch_size))
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to delete the expired payment links")?
+            .len();
+        purged_count += count;
+
+        if count < usize::from(config.batch_size) {
+            break;
+        }
+    }
+
+    Ok(purged_count)
+}
+
 fn validate_order_details(
     order_details: Option<Vec<Secret<serde_json::Value>>>,
     currency: api_models::enums::Currency,
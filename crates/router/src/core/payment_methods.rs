@@ -1,6 +1,31 @@
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub mod bank_account_tokenization;
 pub mod cards;
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+pub mod connector_token_revocation;
+pub mod country_availability;
 pub mod migration;
 pub mod network_tokenization;
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "customer_v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub mod recommendation;
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub mod retokenization;
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "customer_v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub mod session_payment_methods;
 pub mod surcharge_decision_configs;
 pub mod transformers;
 pub mod utils;
@@ -656,6 +681,21 @@ pub async fn retrieve_payment_method_with_token(
             .unwrap_or_default()
         }
 
+        storage::PaymentTokenData::BankAccountToken(bank_account_token) => {
+            let payment_method_data = bank_account_tokenization::get_bank_debit_data_for_token(
+                state,
+                merchant_key_store,
+                bank_account_token,
+                payment_intent.customer_id.as_ref(),
+            )
+            .await?;
+            storage::PaymentMethodDataWithId {
+                payment_method_data: Some(payment_method_data),
+                payment_method: Some(enums::PaymentMethod::BankDebit),
+                payment_method_id: None,
+            }
+        }
+
         storage::PaymentTokenData::WalletToken(_) => storage::PaymentMethodDataWithId {
             payment_method: None,
             payment_method_data: None,
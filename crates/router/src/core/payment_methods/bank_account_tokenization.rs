@@ -0,0 +1,587 @@
+//! Tokenization of bank accounts for reuse across ACH bank debit payments.
+//!
+//! The details of the bank account are stored in the locker, and only the masked account and
+//! routing numbers are stored against the token. A bank account is tokenized at most once per
+//! customer, which is ensured through a fingerprint of the account and routing numbers computed
+//! with the fingerprint secret of the merchant, as is done for cards.
+//!
+//! The token can be used as the `payment_token` of a payment, but only once the ownership of the
+//! bank account has been verified.
+
+use api_models::{enums as api_enums, payment_methods as api_payment_methods};
+use common_utils::{
+    crypto::{self, SignMessage},
+    encryption::Encryption,
+    ext_traits::StringExt,
+    id_type,
+    new_type::{MaskedBankAccount, MaskedRoutingNumber},
+    type_name,
+    types::keymanager::Identifier,
+};
+use diesel_models::payment_method;
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+use super::{cards, transformers as payment_methods};
+use crate::{
+    consts,
+    core::{
+        blocklist::utils as blocklist_utils,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    },
+    routes::SessionState,
+    services,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+    utils,
+};
+
+const BANK_ACCOUNT_TOKEN_PREFIX: &str = "bat";
+
+/// Resolve the payment token to a tokenized bank account, if the payment token is the token of a
+/// tokenized bank account
+pub fn get_payment_token_data(payment_token: &str) -> Option<storage::PaymentTokenData> {
+    payment_token
+        .strip_prefix(BANK_ACCOUNT_TOKEN_PREFIX)
+        .filter(|suffix| suffix.starts_with('_'))
+        .map(|_| {
+            storage::PaymentTokenData::BankAccountToken(storage::BankAccountTokenReference {
+                token_id: payment_token.to_owned(),
+            })
+        })
+}
+
+fn generate_bank_account_fingerprint(
+    merchant_fingerprint_secret: &str,
+    bank_account: &api_payment_methods::BankAccountDetails,
+) -> RouterResult<String> {
+    let message = format!(
+        "{}:{}",
+        bank_account.routing_number.peek(),
+        bank_account.account_number.peek()
+    );
+    let fingerprint = crypto::HmacSha512
+        .sign_message(merchant_fingerprint_secret.as_bytes(), message.as_bytes())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate the bank account fingerprint")?;
+
+    Ok(hex::encode(fingerprint))
+}
+
+fn get_bank_account_token_response(
+    bank_account_token: storage::BankAccountToken,
+) -> api_payment_methods::BankAccountTokenResponse {
+    api_payment_methods::BankAccountTokenResponse {
+        token_id: bank_account_token.token_id,
+        customer_id: bank_account_token.customer_id,
+        account_number: MaskedBankAccount::from(bank_account_token.masked_account_number),
+        routing_number: MaskedRoutingNumber::from(bank_account_token.masked_routing_number),
+        verification_status: bank_account_token.verification_status,
+        merchant_connector_id: bank_account_token.merchant_connector_id,
+        created: bank_account_token.created_at,
+    }
+}
+
+/// Store the details of the bank account in the locker, encrypted with the key of the merchant
+async fn store_bank_account_in_locker(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &id_type::CustomerId,
+    bank_account: &api_payment_methods::BankAccountDetails,
+) -> RouterResult<String> {
+    let bank_account_data = serde_json::to_string(bank_account)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the bank account details")?;
+
+    let enc_data = domain::types::crypto_operation(
+        &state.into(),
+        type_name!(payment_method::PaymentMethod),
+        domain::types::CryptoOperation::Encrypt(Secret::new(bank_account_data)),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt the bank account details")?;
+    let enc_data = hex::encode(Encryption::from(enc_data).into_inner().peek());
+
+    let payload =
+        payment_methods::StoreLockerReq::LockerGeneric(payment_methods::StoreGenericReq {
+            merchant_id: merchant_account.get_id().to_owned(),
+            merchant_customer_id: customer_id.to_owned(),
+            enc_data,
+            ttl: state.conf.locker.ttl_for_storage_in_secs,
+        });
+    let store_resp = cards::add_card_to_hs_locker(
+        state,
+        &payload,
+        customer_id,
+        api_enums::LockerChoice::HyperswitchCardVault,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store the bank account details in the locker")?;
+
+    Ok(store_resp.card_reference)
+}
+
+async fn retrieve_bank_account_from_locker(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    bank_account_token: &storage::BankAccountToken,
+) -> RouterResult<api_payment_methods::BankAccountDetails> {
+    cards::get_payment_method_from_hs_locker(
+        state,
+        key_store,
+        &bank_account_token.customer_id,
+        &bank_account_token.merchant_id,
+        &bank_account_token.locker_id,
+        Some(api_enums::LockerChoice::HyperswitchCardVault),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch the bank account details from the locker")?
+    .expose()
+    .parse_struct("BankAccountDetails")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to parse the bank account details from the locker")
+}
+
+/// The connector bank token was registered for another bank account
+fn connector_bank_token_conflict_error() -> errors::ApiErrorResponse {
+    errors::ApiErrorResponse::PreconditionFailed {
+        message: "The connector bank token belongs to another bank account".to_string(),
+    }
+}
+
+/// Delete the bank account details stored in the locker for a bank account which could not be
+/// tokenized, so that the details are not left behind in the locker
+async fn delete_bank_account_from_locker(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+    locker_id: &str,
+) {
+    if let Err(error) =
+        cards::delete_card_from_hs_locker(state, customer_id, merchant_id, locker_id).await
+    {
+        logger::error!(
+            ?error,
+            "Failed to delete the bank account details from the locker"
+        );
+    }
+}
+
+/// Attach the connector bank token to a bank account which was already tokenized without it.
+///
+/// A bank account which was already tokenized with another connector bank token is rejected, as
+/// only one connector bank token is kept for the bank account.
+async fn attach_connector_bank_token(
+    state: &SessionState,
+    bank_account_token: storage::BankAccountToken,
+    connector_bank_token: Option<(String, id_type::MerchantConnectorAccountId)>,
+) -> RouterResult<storage::BankAccountToken> {
+    let Some((connector_bank_token, merchant_connector_id)) = connector_bank_token else {
+        return Ok(bank_account_token);
+    };
+
+    match (
+        bank_account_token.connector_bank_token.as_deref(),
+        bank_account_token.merchant_connector_id.as_ref(),
+    ) {
+        (None, _) => state
+            .store
+            .update_bank_account_token(
+                bank_account_token,
+                storage::BankAccountTokenUpdate::ConnectorBankTokenUpdate {
+                    connector_bank_token,
+                    merchant_connector_id,
+                },
+            )
+            .await
+            .map_err(|error| {
+                if error.current_context().is_db_unique_violation() {
+                    error.change_context(connector_bank_token_conflict_error())
+                } else {
+                    error
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to attach the connector bank token")
+                }
+            }),
+        (Some(existing_token), Some(existing_merchant_connector_id))
+            if existing_token == connector_bank_token
+                && existing_merchant_connector_id == &merchant_connector_id =>
+        {
+            Ok(bank_account_token)
+        }
+        (Some(_), _) => Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "The bank account is already tokenized as `{}` with another connector bank token",
+                bank_account_token.token_id
+            ),
+        })),
+    }
+}
+
+/// Tokenize the bank account of the customer.
+///
+/// If the bank account was already tokenized for the customer, the existing token is returned
+/// instead of creating a new one.
+#[cfg(not(feature = "customer_v2"))]
+#[instrument(skip_all)]
+pub async fn tokenize_bank_account(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: api_payment_methods::BankAccountTokenizeRequest,
+) -> RouterResponse<api_payment_methods::BankAccountTokenResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+
+    let connector_bank_token = match (req.connector_bank_token, req.merchant_connector_id) {
+        (Some(connector_bank_token), Some(merchant_connector_id)) => {
+            db.find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+                &(&state).into(),
+                merchant_id,
+                &merchant_connector_id,
+                &key_store,
+            )
+            .await
+            .to_not_found_response(
+                errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+                    id: merchant_connector_id.get_string_repr().to_string(),
+                },
+            )?;
+            Some((connector_bank_token, merchant_connector_id))
+        }
+        (None, None) => None,
+        _ => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`connector_bank_token` and `merchant_connector_id` must be provided together"
+                .to_string(),
+        })?,
+    };
+
+    db.find_customer_by_customer_id_merchant_id(
+        &(&state).into(),
+        &req.customer_id,
+        merchant_id,
+        &key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    if let Some((token, merchant_connector_id)) = connector_bank_token.as_ref() {
+        match db
+            .find_bank_account_token_by_connector_bank_token(
+                merchant_id,
+                merchant_connector_id,
+                token,
+            )
+            .await
+        {
+            Ok(existing) if existing.customer_id == req.customer_id => {
+                return Ok(services::ApplicationResponse::Json(
+                    get_bank_account_token_response(existing),
+                ));
+            }
+            Ok(_) => Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The connector bank token belongs to another customer".to_string(),
+            })?,
+            Err(error) if error.current_context().is_db_not_found() => {}
+            Err(error) => Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find the bank account by connector bank token")?,
+        }
+    }
+
+    let merchant_fingerprint_secret =
+        blocklist_utils::get_merchant_fingerprint_secret(&state, merchant_id).await?;
+    let fingerprint =
+        generate_bank_account_fingerprint(&merchant_fingerprint_secret, &req.bank_account)?;
+
+    match db
+        .find_bank_account_token_by_merchant_id_customer_id_fingerprint(
+            merchant_id,
+            &req.customer_id,
+            &fingerprint,
+        )
+        .await
+    {
+        Ok(existing) => {
+            logger::info!(token_id = %existing.token_id, "Bank account is already tokenized");
+            let existing =
+                attach_connector_bank_token(&state, existing, connector_bank_token).await?;
+            return Ok(services::ApplicationResponse::Json(
+                get_bank_account_token_response(existing),
+            ));
+        }
+        Err(error) if error.current_context().is_db_not_found() => {}
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find the bank account by fingerprint")?,
+    }
+
+    let locker_id = store_bank_account_in_locker(
+        &state,
+        &merchant_account,
+        &key_store,
+        &req.customer_id,
+        &req.bank_account,
+    )
+    .await?;
+
+    let (connector_bank_token_value, merchant_connector_id) = connector_bank_token.clone().unzip();
+    let now = common_utils::date_time::now();
+    let bank_account_token = storage::BankAccountTokenNew {
+        token_id: utils::generate_id(consts::ID_LENGTH, BANK_ACCOUNT_TOKEN_PREFIX),
+        merchant_id: merchant_id.to_owned(),
+        customer_id: req.customer_id.clone(),
+        locker_id: locker_id.clone(),
+        fingerprint: fingerprint.clone(),
+        masked_account_number: MaskedBankAccount::from(req.bank_account.account_number.clone())
+            .expose()
+            .expose(),
+        masked_routing_number: MaskedRoutingNumber::from(req.bank_account.routing_number.clone())
+            .expose()
+            .expose(),
+        verification_status: enums::BankAccountVerificationStatus::Pending,
+        connector_bank_token: connector_bank_token_value,
+        merchant_connector_id,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let bank_account_token = match db.insert_bank_account_token(bank_account_token).await {
+        Ok(bank_account_token) => bank_account_token,
+        Err(error) => {
+            delete_bank_account_from_locker(&state, merchant_id, &req.customer_id, &locker_id)
+                .await;
+
+            if !error.current_context().is_db_unique_violation() {
+                return Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to insert the bank account token");
+            }
+
+            // Either the same bank account was tokenized concurrently for the customer, or the
+            // connector bank token was registered concurrently for another bank account
+            match db
+                .find_bank_account_token_by_merchant_id_customer_id_fingerprint(
+                    merchant_id,
+                    &req.customer_id,
+                    &fingerprint,
+                )
+                .await
+            {
+                Ok(existing) => {
+                    attach_connector_bank_token(&state, existing, connector_bank_token).await?
+                }
+                Err(error) if error.current_context().is_db_not_found() => {
+                    Err(connector_bank_token_conflict_error())?
+                }
+                Err(error) => Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to find the concurrently tokenized bank account")?,
+            }
+        }
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        get_bank_account_token_response(bank_account_token),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_bank_account_token(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: api_payment_methods::BankAccountTokenId,
+) -> RouterResponse<api_payment_methods::BankAccountTokenResponse> {
+    let bank_account_token = state
+        .store
+        .find_bank_account_token_by_merchant_id_token_id(merchant_account.get_id(), &req.token_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_bank_account_token_response(bank_account_token),
+    ))
+}
+
+/// Record the outcome of the verification of the ownership of the bank account.
+///
+/// The verification status can only be changed while it is pending, recording the same outcome
+/// again leaves the bank account unchanged.
+#[instrument(skip_all)]
+pub async fn update_bank_account_verification(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: api_payment_methods::BankAccountVerificationRequest,
+) -> RouterResponse<api_payment_methods::BankAccountTokenResponse> {
+    let db = state.store.as_ref();
+    let bank_account_token = db
+        .find_bank_account_token_by_merchant_id_token_id(merchant_account.get_id(), &req.token_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    let bank_account_token = match (
+        bank_account_token.verification_status,
+        req.verification_status,
+    ) {
+        (_, enums::BankAccountVerificationStatus::Pending) => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "`verification_status` must be either `verified` or `failed`".to_string(),
+            })?
+        }
+        (current_status, requested_status) if current_status == requested_status => {
+            bank_account_token
+        }
+        (enums::BankAccountVerificationStatus::Pending, verification_status) => db
+            .update_bank_account_token(
+                bank_account_token,
+                storage::BankAccountTokenUpdate::VerificationStatusUpdate {
+                    verification_status,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the verification status of the bank account")?,
+        (current_status, _) => Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "The verification status of the bank account cannot be changed as it is already {current_status}"
+            ),
+        })?,
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        get_bank_account_token_response(bank_account_token),
+    ))
+}
+
+/// Get the details of the tokenized bank account to be charged for the payment.
+///
+/// Only bank accounts which are verified, and which belong to the customer of the payment, can be
+/// charged. The payment must hence have a customer.
+#[instrument(skip_all)]
+pub async fn get_bank_debit_data_for_token(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    token_data: &storage::BankAccountTokenReference,
+    customer_id: Option<&id_type::CustomerId>,
+) -> RouterResult<domain::PaymentMethodData> {
+    let bank_account_token = state
+        .store
+        .find_bank_account_token_by_merchant_id_token_id(
+            &key_store.merchant_id,
+            &token_data.token_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    let customer_id = customer_id.ok_or(errors::ApiErrorResponse::MissingRequiredField {
+        field_name: "customer_id",
+    })?;
+    utils::when(customer_id != &bank_account_token.customer_id, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The bank account token does not belong to the customer of the payment"
+                .to_string(),
+        }))
+    })?;
+
+    utils::when(
+        bank_account_token.verification_status != enums::BankAccountVerificationStatus::Verified,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "The bank account cannot be charged as its verification is {}",
+                    bank_account_token.verification_status
+                ),
+            }))
+        },
+    )?;
+
+    let bank_account =
+        retrieve_bank_account_from_locker(state, key_store, &bank_account_token).await?;
+
+    Ok(domain::PaymentMethodData::BankDebit(
+        domain::BankDebitData::AchBankDebit {
+            account_number: bank_account.account_number,
+            routing_number: bank_account.routing_number,
+            card_holder_name: None,
+            bank_account_holder_name: bank_account.bank_account_holder_name,
+            bank_name: bank_account.bank_name,
+            bank_type: bank_account.bank_type,
+            bank_holder_type: bank_account.bank_holder_type,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_bank_account(
+        account_number: &str,
+        routing_number: &str,
+    ) -> api_payment_methods::BankAccountDetails {
+        api_payment_methods::BankAccountDetails {
+            account_number: Secret::new(account_number.to_string()),
+            routing_number: Secret::new(routing_number.to_string()),
+            bank_account_holder_name: None,
+            bank_name: None,
+            bank_type: None,
+            bank_holder_type: None,
+        }
+    }
+
+    #[test]
+    fn test_only_bank_account_tokens_resolve_to_a_bank_account() {
+        assert!(matches!(
+            get_payment_token_data("bat_abc123"),
+            Some(storage::PaymentTokenData::BankAccountToken(token)) if token.token_id == "bat_abc123"
+        ));
+        assert!(get_payment_token_data("token_abc123").is_none());
+        assert!(get_payment_token_data("batabc123").is_none());
+    }
+
+    #[test]
+    fn test_bank_account_fingerprint_is_stable_for_the_merchant() {
+        let bank_account = get_bank_account("000123456789", "110000000");
+
+        let fingerprint = generate_bank_account_fingerprint("secret_1", &bank_account).unwrap();
+        assert_eq!(
+            fingerprint,
+            generate_bank_account_fingerprint("secret_1", &bank_account).unwrap()
+        );
+        assert_ne!(
+            fingerprint,
+            generate_bank_account_fingerprint("secret_2", &bank_account).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_bank_account_fingerprint_differs_for_another_account() {
+        let fingerprint = generate_bank_account_fingerprint(
+            "secret_1",
+            &get_bank_account("000123456789", "110000000"),
+        )
+        .unwrap();
+
+        assert_ne!(
+            fingerprint,
+            generate_bank_account_fingerprint(
+                "secret_1",
+                &get_bank_account("000123456780", "110000000"),
+            )
+            .unwrap()
+        );
+    }
+}
@@ -78,7 +78,7 @@ use crate::{
         errors::{self, StorageErrorExt},
         payment_methods::{network_tokenization, transformers as payment_methods, vault},
         payments::{
-            helpers,
+            cvv_recollection, helpers,
             routing::{self, SessionFlowRoutingInput},
         },
         utils as core_utils,
@@ -199,6 +199,7 @@ pub async fn create_payment_method(
                 network_token_requestor_reference_id,
                 network_token_locker_id,
                 network_token_payment_method_data,
+                retokenization_pending: None,
             },
             storage_scheme,
         )
@@ -771,6 +772,7 @@ pub async fn skip_locker_call_and_migrate_payment_method(
                 network_token_requestor_reference_id: None,
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
+                retokenization_pending: None,
             },
             merchant_account.storage_scheme,
         )
@@ -3069,6 +3071,19 @@ pub async fn list_payment_methods(
         response
     );
 
+    let merchant_country_availability =
+        super::country_availability::get_merchant_country_availability(
+            &state,
+            merchant_account.get_id(),
+        )
+        .await?;
+    let excluded_payment_methods = super::country_availability::filter_payment_methods_by_country(
+        &state.conf.payment_method_country_availability,
+        &merchant_country_availability,
+        billing_address.as_ref().and_then(|address| address.country),
+        &mut response,
+    );
+
     // Filter out wallet payment method from mca if customer has already saved it
     customer
         .as_ref()
@@ -3339,6 +3354,7 @@ pub async fn list_payment_methods(
             merchant_connector_id: None,
             surcharge_amount: None,
             tax_amount: None,
+            is_connector_override: None,
         };
 
         state
@@ -3833,6 +3849,7 @@ pub async fn list_payment_methods(
                             start_date: i.start_date,
                             end_date: i.end_date,
                             metadata: i.metadata,
+                            constraints: i.constraints.map(Into::into),
                         })
                     }
                     hyperswitch_domain_models::mandates::MandateDataType::MultiUse(Some(i)) => {
@@ -3842,6 +3859,7 @@ pub async fn list_payment_methods(
                             start_date: i.start_date,
                             end_date: i.end_date,
                             metadata: i.metadata,
+                            constraints: i.constraints.map(Into::into),
                         }))
                     }
                     hyperswitch_domain_models::mandates::MandateDataType::MultiUse(None) => {
@@ -3853,6 +3871,7 @@ pub async fn list_payment_methods(
                 .show_surcharge_breakup_screen
                 .unwrap_or_default(),
             currency,
+            excluded_payment_methods,
             request_external_three_ds_authentication,
             collect_shipping_details_from_wallets,
             collect_billing_details_from_wallets,
@@ -4261,7 +4280,7 @@ pub async fn filter_payment_methods(
     todo!()
 }
 
-fn filter_amount_based(
+pub(super) fn filter_amount_based(
     payment_method: &RequestPaymentMethodTypes,
     amount: Option<MinorUnit>,
 ) -> bool {
@@ -4439,6 +4458,10 @@ pub async fn list_customer_payment_method(
 
     let requires_cvv = is_requires_cvv.config != "false";
 
+    let cvv_recollection_policy =
+        cvv_recollection::get_cvv_recollection_policy(state, merchant_account.get_id(), None)
+            .await?;
+
     let resp = db
         .find_payment_method_by_customer_id_merchant_id_status(
             &(state.into()),
@@ -4453,6 +4476,26 @@ pub async fn list_customer_payment_method(
         .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
     let mut customer_pms = Vec::new();
 
+    let cvv_recollection_payment_method_ids = match payment_intent.as_ref() {
+        Some(payment_intent) if payment_intent.off_session != Some(true) => {
+            let card_payment_method_ids = resp
+                .iter()
+                .filter(|pm| pm.payment_method == Some(enums::PaymentMethod::Card))
+                .map(|pm| pm.payment_method_id.as_str())
+                .collect::<Vec<_>>();
+            cvv_recollection::get_payment_methods_requiring_cvv_recollection(
+                state,
+                &cvv_recollection_policy,
+                merchant_account.get_id(),
+                &card_payment_method_ids,
+                payment_intent.amount,
+                payment_intent.currency,
+            )
+            .await?
+        }
+        _ => HashSet::new(),
+    };
+
     let profile_id = payment_intent
         .as_ref()
         .map(|payment_intent| {
@@ -4546,6 +4589,8 @@ pub async fn list_customer_payment_method(
         } else {
             requires_cvv && !(off_session_payment_flag && pm.connector_mandate_details.is_some())
         };
+        let requires_cvv =
+            requires_cvv || cvv_recollection_payment_method_ids.contains(&pm.payment_method_id);
         // Need validation for enabled payment method ,querying MCA
         let pma = api::CustomerPaymentMethod {
             payment_token: parent_payment_method_token.to_owned(),
@@ -4567,8 +4612,10 @@ pub async fn list_customer_payment_method(
             surcharge_details: None,
             requires_cvv,
             last_used_at: Some(pm.last_used_at),
-            default_payment_method_set: customer.default_payment_method_id.is_some()
-                && customer.default_payment_method_id == Some(pm.payment_method_id),
+            default_payment_method_set: customer.default_payment_method_id.as_ref()
+                == Some(&pm.payment_method_id),
+            preferred_payment_method_set: customer.preferred_payment_method_id.as_ref()
+                == Some(&pm.payment_method_id),
             billing: payment_method_billing,
         };
         if requires_cvv || mca_enabled {
@@ -5192,6 +5239,50 @@ pub async fn update_last_used_at(
 
     Ok(())
 }
+
+/// Record the saved payment method which was used in a successful payment as the preferred
+/// payment method of the customer
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+pub async fn update_preferred_payment_method(
+    payment_method: &domain::PaymentMethod,
+    state: &routes::SessionState,
+    storage_scheme: MerchantStorageScheme,
+    key_store: &domain::MerchantKeyStore,
+) -> errors::RouterResult<()> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(
+            key_manager_state,
+            &payment_method.customer_id,
+            &payment_method.merchant_id,
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    if customer.preferred_payment_method_id.as_ref() == Some(&payment_method.payment_method_id) {
+        return Ok(());
+    }
+
+    db.update_customer_by_customer_id_merchant_id(
+        key_manager_state,
+        customer.customer_id.clone(),
+        customer.merchant_id.clone(),
+        customer,
+        CustomerUpdate::UpdatePreferredPaymentMethod {
+            preferred_payment_method_id: Some(payment_method.payment_method_id.clone()),
+        },
+        key_store,
+        storage_scheme,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update the preferred payment method id for the customer")?;
+
+    Ok(())
+}
 #[cfg(feature = "payouts")]
 pub async fn get_bank_from_hs_locker(
     state: &routes::SessionState,
@@ -5412,6 +5503,7 @@ pub async fn delete_payment_method(
     _merchant_account: domain::MerchantAccount,
     _pm_id: api::PaymentMethodId,
     _key_store: domain::MerchantKeyStore,
+    _delete_request: api::PaymentMethodDeleteRequest,
 ) -> errors::RouterResponse<api::PaymentMethodDeleteResponse> {
     todo!()
 }
@@ -5423,6 +5515,7 @@ pub async fn delete_payment_method(
     merchant_account: domain::MerchantAccount,
     pm_id: api::PaymentMethodId,
     key_store: domain::MerchantKeyStore,
+    delete_request: api::PaymentMethodDeleteRequest,
 ) -> errors::RouterResponse<api::PaymentMethodDeleteResponse> {
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
@@ -5448,6 +5541,22 @@ pub async fn delete_payment_method(
         .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Customer not found for the payment method")?;
 
+    // The tokens are revoked before the payment method is deleted, so that a failed deletion can be
+    // retried and revokes the tokens which were not revoked by the earlier attempt
+    let connector_token_revocations = if delete_request.revoke_connector_tokens {
+        Some(
+            super::connector_token_revocation::revoke_connector_tokens(
+                &state,
+                &merchant_account,
+                &key_store,
+                &key,
+            )
+            .await?,
+        )
+    } else {
+        None
+    };
+
     if key.payment_method == Some(enums::PaymentMethod::Card) {
         let response = delete_card_from_locker(
             &state,
@@ -5492,10 +5601,49 @@ pub async fn delete_payment_method(
     .await
     .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
 
-    if customer.default_payment_method_id.as_ref() == Some(&pm_id.payment_method_id) {
+    let customer = if customer.default_payment_method_id.as_ref() == Some(&pm_id.payment_method_id)
+    {
         let customer_update = CustomerUpdate::UpdateDefaultPaymentMethod {
             default_payment_method_id: Some(None),
         };
+        db.update_customer_by_customer_id_merchant_id(
+            key_manager_state,
+            key.customer_id.clone(),
+            key.merchant_id.clone(),
+            customer,
+            customer_update,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the default payment method id for the customer")?
+    } else {
+        customer
+    };
+
+    if customer.preferred_payment_method_id.as_ref() == Some(&pm_id.payment_method_id) {
+        // The preference falls back to the most recently used of the remaining payment methods
+        let preferred_payment_method_id = db
+            .find_payment_method_by_customer_id_merchant_id_status(
+                key_manager_state,
+                &key_store,
+                &key.customer_id,
+                &key.merchant_id,
+                enums::PaymentMethodStatus::Active,
+                Some(1),
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find the payment methods of the customer")?
+            .into_iter()
+            .next()
+            .map(|payment_method| payment_method.payment_method_id);
+
+        let customer_update = CustomerUpdate::UpdatePreferredPaymentMethod {
+            preferred_payment_method_id,
+        };
         db.update_customer_by_customer_id_merchant_id(
             key_manager_state,
             key.customer_id,
@@ -5507,13 +5655,14 @@ pub async fn delete_payment_method(
         )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to update the default payment method id for the customer")?;
+        .attach_printable("Failed to update the preferred payment method id for the customer")?;
     };
 
     Ok(services::ApplicationResponse::Json(
         api::PaymentMethodDeleteResponse {
             payment_method_id: key.payment_method_id.clone(),
             deleted: true,
+            connector_token_revocations,
         },
     ))
 }
@@ -5525,6 +5674,7 @@ pub async fn delete_payment_method(
     _merchant_account: domain::MerchantAccount,
     _pm_id: api::PaymentMethodId,
     _key_store: domain::MerchantKeyStore,
+    _delete_request: api::PaymentMethodDeleteRequest,
 ) -> errors::RouterResponse<api::PaymentMethodDeleteResponse> {
     todo!()
 }
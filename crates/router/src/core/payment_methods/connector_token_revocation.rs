@@ -0,0 +1,233 @@
+//! Revocation of the tokens of a deleted payment method at the connectors where they were
+//! provisioned.
+//!
+//! The tokens are the connector mandates stored against the payment method. A failure to revoke a
+//! token does not block the deletion of the payment method, the token is instead recorded as
+//! pending cleanup so that it can be revoked later.
+//!
+//! The tokens are revoked before the payment method is deleted, so that the outcome of every
+//! revocation is recorded while the connector mandates of the payment method are still known. The
+//! revocation is idempotent: when the deletion is retried, the tokens already revoked are not
+//! revoked again, and the outcome of the other tokens replaces the outcome recorded earlier.
+
+use api_models::payment_methods::ConnectorTokenRevocationResponse;
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        mandate,
+        payments::{helpers, CallConnectorAction},
+    },
+    routes::SessionState,
+    services,
+    types::{
+        self,
+        api::{ConnectorData, GetToken},
+        domain,
+        storage::{self, enums},
+    },
+};
+
+/// Revoke the tokens of the payment method at each connector where they were provisioned, and
+/// record the outcome of each revocation
+#[instrument(skip_all)]
+pub async fn revoke_connector_tokens(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: &domain::PaymentMethod,
+) -> RouterResult<Vec<ConnectorTokenRevocationResponse>> {
+    let db = state.store.as_ref();
+
+    let Some(connector_mandate_details) = payment_method
+        .connector_mandate_details
+        .clone()
+        .map(|details| {
+            details.parse_value::<storage::PaymentsMandateReference>("PaymentsMandateReference")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize to Payment Mandate Reference")?
+    else {
+        return Ok(Vec::new());
+    };
+
+    let (revoked, _): (Vec<_>, Vec<_>) = db
+        .find_connector_token_revocations_by_payment_method_id(
+            merchant_account.get_id(),
+            &payment_method.payment_method_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch connector token revocations")?
+        .into_iter()
+        .partition(|revocation| {
+            revocation.revocation_status == enums::ConnectorTokenRevocationStatus::Revoked
+        });
+
+    let mut revocations = Vec::new();
+    for (merchant_connector_id, mandate_reference) in connector_mandate_details.iter() {
+        // The token was revoked by an earlier attempt to delete the payment method
+        if revoked.iter().any(|revocation| {
+            &revocation.merchant_connector_id == merchant_connector_id
+                && revocation.connector_token == mandate_reference.connector_mandate_id
+        }) {
+            continue;
+        }
+
+        let merchant_connector_account = match db
+            .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+                &state.into(),
+                merchant_account.get_id(),
+                merchant_connector_id,
+                key_store,
+            )
+            .await
+        {
+            Ok(merchant_connector_account) => merchant_connector_account,
+            Err(error) if error.current_context().is_db_not_found() => {
+                logger::warn!(
+                    ?merchant_connector_id,
+                    "Skipping revocation of connector token as the merchant connector account was not found"
+                );
+                continue;
+            }
+            Err(error) => Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch merchant connector account")?,
+        };
+        let connector_name = merchant_connector_account.connector_name.clone();
+
+        let (revocation_status, error_message) = match revoke_connector_token(
+            state,
+            merchant_account,
+            merchant_connector_account,
+            payment_method,
+            mandate_reference.connector_mandate_id.clone(),
+        )
+        .await
+        {
+            Ok(Ok(())) => (enums::ConnectorTokenRevocationStatus::Revoked, None),
+            Ok(Err(error_response)) => {
+                logger::warn!(
+                    ?error_response,
+                    connector = %connector_name,
+                    "Connector failed to revoke the token, flagging it for cleanup"
+                );
+                (
+                    enums::ConnectorTokenRevocationStatus::PendingCleanup,
+                    Some(error_response.message),
+                )
+            }
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    connector = %connector_name,
+                    "Failed to revoke the token at the connector, flagging it for cleanup"
+                );
+                (
+                    enums::ConnectorTokenRevocationStatus::PendingCleanup,
+                    Some("Failed to call the connector".to_string()),
+                )
+            }
+        };
+
+        let now = common_utils::date_time::now();
+        revocations.push(storage::ConnectorTokenRevocationNew {
+            merchant_id: merchant_account.get_id().clone(),
+            payment_method_id: payment_method.payment_method_id.clone(),
+            merchant_connector_id: merchant_connector_id.clone(),
+            customer_id: payment_method.customer_id.clone(),
+            connector_name,
+            connector_token: mandate_reference.connector_mandate_id.clone(),
+            revocation_status,
+            error_message,
+            created_at: now,
+            modified_at: now,
+        });
+    }
+
+    let revocations = db
+        .upsert_connector_token_revocations(revocations)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record connector token revocations")?;
+
+    Ok(revoked
+        .into_iter()
+        .chain(revocations)
+        .map(|revocation| ConnectorTokenRevocationResponse {
+            merchant_connector_id: revocation.merchant_connector_id,
+            connector: revocation.connector_name,
+            status: revocation.revocation_status,
+        })
+        .collect())
+}
+
+/// Revoke the connector mandate at the connector.
+///
+/// Returns the error response of the connector if the connector failed to revoke the mandate.
+async fn revoke_connector_token(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    merchant_connector_account: domain::MerchantConnectorAccount,
+    payment_method: &domain::PaymentMethod,
+    connector_mandate_id: String,
+) -> RouterResult<Result<(), types::ErrorResponse>> {
+    let connector_data = ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &merchant_connector_account.connector_name,
+        GetToken::Connector,
+        Some(merchant_connector_account.merchant_connector_id.clone()),
+    )?;
+    let connector_integration: services::BoxedMandateRevokeConnectorIntegrationInterface<
+        types::api::MandateRevoke,
+        types::MandateRevokeRequestData,
+        types::MandateRevokeResponseData,
+    > = connector_data.connector.get_connector_integration();
+
+    // The connector mandate may not have a mandate of its own, such as one set up through
+    // `setup_future_usage`, in which case the connector mandate id identifies the mandate
+    let mandate_id = match state
+        .store
+        .find_mandate_by_merchant_id_connector_mandate_id(
+            merchant_account.get_id(),
+            &connector_mandate_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(mandate) => mandate.mandate_id,
+        Err(error) if error.current_context().is_db_not_found() => connector_mandate_id.clone(),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the mandate of the connector mandate")?,
+    };
+
+    let router_data = mandate::utils::construct_connector_mandate_revoke_router_data(
+        helpers::MerchantConnectorAccountType::DbVal(merchant_connector_account.clone()),
+        merchant_account,
+        payment_method.customer_id.clone(),
+        merchant_connector_account.connector_name,
+        None,
+        types::MandateRevokeRequestData {
+            mandate_id,
+            connector_mandate_id: Some(connector_mandate_id),
+        },
+    )?;
+
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        CallConnectorAction::Trigger,
+        None,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    Ok(response.response.map(|_| ()))
+}
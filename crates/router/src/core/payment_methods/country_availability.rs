@@ -0,0 +1,339 @@
+//! Availability of the payment method types by country.
+//!
+//! Some payment method types are only legal or available in certain countries, such as iDEAL in
+//! the Netherlands or Boleto in Brazil. The countries in which such payment method types are
+//! available are configured in `payment_method_country_availability.countries`, and the payment
+//! method types which are not available in the country of the payment are excluded from the list
+//! of the payment methods available for the payment, along with the reason for which they are
+//! excluded. A merchant with a special enablement of a payment method type can override the
+//! countries of the payment method type through a config with the key
+//! `payment_method_country_availability_{merchant_id}`, which maps the payment method types to the
+//! countries in which they are available for the merchant.
+
+use std::collections::{HashMap, HashSet};
+
+use api_models::payment_methods::{
+    ExcludedPaymentMethod, PaymentMethodExclusionReason, ResponsePaymentMethodIntermediate,
+};
+use common_utils::id_type;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    configs::settings::PaymentMethodCountryAvailability,
+    core::errors::{self, RouterResult},
+    routes::SessionState,
+    types::api::enums,
+};
+
+/// The countries in which the payment method types are available for a merchant, which take
+/// precedence over the configured countries of the payment method types
+pub type MerchantCountryAvailability =
+    HashMap<enums::PaymentMethodType, HashSet<enums::CountryAlpha2>>;
+
+/// Get the countries in which the payment method types are available for the merchant, which are
+/// empty for a merchant without a special enablement of any payment method type
+#[instrument(skip_all)]
+pub async fn get_merchant_country_availability(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<MerchantCountryAvailability> {
+    let config = match state
+        .store
+        .find_config_by_key(&merchant_id.get_payment_method_country_availability_key())
+        .await
+    {
+        Ok(config) => config,
+        Err(error) if error.current_context().is_db_not_found() => {
+            return Ok(MerchantCountryAvailability::new())
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find the payment method country availability")
+        }
+    };
+
+    serde_json::from_str::<MerchantCountryAvailability>(&config.config)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the payment method country availability")
+}
+
+/// Whether the payment method type is available in the country, for the merchant with the
+/// countries in which the payment method types are available for the merchant
+pub fn is_available_in_country(
+    config: &PaymentMethodCountryAvailability,
+    merchant_availability: &MerchantCountryAvailability,
+    payment_method_type: enums::PaymentMethodType,
+    country: enums::CountryAlpha2,
+) -> bool {
+    merchant_availability
+        .get(&payment_method_type)
+        .or_else(|| config.countries.get(&payment_method_type))
+        .map_or(true, |countries| countries.contains(&country))
+}
+
+/// Exclude the payment method types which are not available in the country of the payment from
+/// the payment methods, returning the payment method types which were excluded. The payment
+/// methods are not filtered for a payment of which the country is not known.
+pub fn filter_payment_methods_by_country(
+    config: &PaymentMethodCountryAvailability,
+    merchant_availability: &MerchantCountryAvailability,
+    country: Option<enums::CountryAlpha2>,
+    payment_methods: &mut Vec<ResponsePaymentMethodIntermediate>,
+) -> Vec<ExcludedPaymentMethod> {
+    let Some(country) = country else {
+        return Vec::new();
+    };
+
+    let mut excluded_payment_methods = Vec::<ExcludedPaymentMethod>::new();
+    payment_methods.retain(|payment_method| {
+        let is_available = is_available_in_country(
+            config,
+            merchant_availability,
+            payment_method.payment_method_type,
+            country,
+        );
+
+        // The payment method type is enabled through several connectors, and is excluded once
+        let is_excluded = excluded_payment_methods.iter().any(|excluded| {
+            excluded.payment_method == payment_method.payment_method
+                && excluded.payment_method_type == payment_method.payment_method_type
+        });
+        if !is_available && !is_excluded {
+            excluded_payment_methods.push(ExcludedPaymentMethod {
+                payment_method: payment_method.payment_method,
+                payment_method_type: payment_method.payment_method_type,
+                reason: PaymentMethodExclusionReason::NotAvailableInCountry,
+                country: Some(country),
+            });
+        }
+
+        is_available
+    });
+
+    if !excluded_payment_methods.is_empty() {
+        logger::info!(
+            ?excluded_payment_methods,
+            "Excluded payment methods not available in the country of the payment"
+        );
+    }
+
+    excluded_payment_methods
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_config() -> PaymentMethodCountryAvailability {
+        let sepa_countries = HashSet::from([
+            enums::CountryAlpha2::AT,
+            enums::CountryAlpha2::BE,
+            enums::CountryAlpha2::DE,
+            enums::CountryAlpha2::ES,
+            enums::CountryAlpha2::FR,
+            enums::CountryAlpha2::IT,
+            enums::CountryAlpha2::NL,
+        ]);
+
+        PaymentMethodCountryAvailability {
+            countries: HashMap::from([
+                (
+                    enums::PaymentMethodType::Ideal,
+                    HashSet::from([enums::CountryAlpha2::NL]),
+                ),
+                (
+                    enums::PaymentMethodType::Boleto,
+                    HashSet::from([enums::CountryAlpha2::BR]),
+                ),
+                (enums::PaymentMethodType::Sepa, sepa_countries),
+            ]),
+        }
+    }
+
+    fn get_payment_method(
+        payment_method: enums::PaymentMethod,
+        payment_method_type: enums::PaymentMethodType,
+        connector: &str,
+    ) -> ResponsePaymentMethodIntermediate {
+        ResponsePaymentMethodIntermediate {
+            payment_method_type,
+            payment_experience: None,
+            card_networks: None,
+            payment_method,
+            connector: connector.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_ideal_is_available_only_in_the_netherlands() {
+        let config = get_config();
+        let merchant_availability = MerchantCountryAvailability::new();
+
+        assert!(is_available_in_country(
+            &config,
+            &merchant_availability,
+            enums::PaymentMethodType::Ideal,
+            enums::CountryAlpha2::NL
+        ));
+        assert!(!is_available_in_country(
+            &config,
+            &merchant_availability,
+            enums::PaymentMethodType::Ideal,
+            enums::CountryAlpha2::DE
+        ));
+    }
+
+    #[test]
+    fn test_boleto_is_available_only_in_brazil() {
+        let config = get_config();
+        let merchant_availability = MerchantCountryAvailability::new();
+
+        assert!(is_available_in_country(
+            &config,
+            &merchant_availability,
+            enums::PaymentMethodType::Boleto,
+            enums::CountryAlpha2::BR
+        ));
+        assert!(!is_available_in_country(
+            &config,
+            &merchant_availability,
+            enums::PaymentMethodType::Boleto,
+            enums::CountryAlpha2::US
+        ));
+    }
+
+    #[test]
+    fn test_sepa_is_available_only_in_the_sepa_countries() {
+        let config = get_config();
+        let merchant_availability = MerchantCountryAvailability::new();
+
+        for country in [enums::CountryAlpha2::DE, enums::CountryAlpha2::FR] {
+            assert!(is_available_in_country(
+                &config,
+                &merchant_availability,
+                enums::PaymentMethodType::Sepa,
+                country
+            ));
+        }
+        assert!(!is_available_in_country(
+            &config,
+            &merchant_availability,
+            enums::PaymentMethodType::Sepa,
+            enums::CountryAlpha2::US
+        ));
+    }
+
+    #[test]
+    fn test_payment_method_type_not_listed_is_available_in_every_country() {
+        let config = get_config();
+        let merchant_availability = MerchantCountryAvailability::new();
+
+        assert!(is_available_in_country(
+            &config,
+            &merchant_availability,
+            enums::PaymentMethodType::Credit,
+            enums::CountryAlpha2::US
+        ));
+    }
+
+    #[test]
+    fn test_merchant_availability_overrides_configured_countries() {
+        let config = get_config();
+        let merchant_availability = MerchantCountryAvailability::from([(
+            enums::PaymentMethodType::Ideal,
+            HashSet::from([enums::CountryAlpha2::NL, enums::CountryAlpha2::BE]),
+        )]);
+
+        assert!(is_available_in_country(
+            &config,
+            &merchant_availability,
+            enums::PaymentMethodType::Ideal,
+            enums::CountryAlpha2::BE
+        ));
+        assert!(!is_available_in_country(
+            &config,
+            &merchant_availability,
+            enums::PaymentMethodType::Ideal,
+            enums::CountryAlpha2::DE
+        ));
+    }
+
+    #[test]
+    fn test_filter_payment_methods_by_country() {
+        let config = get_config();
+        let merchant_availability = MerchantCountryAvailability::new();
+        let mut payment_methods = vec![
+            get_payment_method(
+                enums::PaymentMethod::BankRedirect,
+                enums::PaymentMethodType::Ideal,
+                "adyen",
+            ),
+            get_payment_method(
+                enums::PaymentMethod::BankRedirect,
+                enums::PaymentMethodType::Ideal,
+                "stripe",
+            ),
+            get_payment_method(
+                enums::PaymentMethod::Voucher,
+                enums::PaymentMethodType::Boleto,
+                "adyen",
+            ),
+            get_payment_method(
+                enums::PaymentMethod::Card,
+                enums::PaymentMethodType::Credit,
+                "stripe",
+            ),
+        ];
+
+        let excluded_payment_methods = filter_payment_methods_by_country(
+            &config,
+            &merchant_availability,
+            Some(enums::CountryAlpha2::NL),
+            &mut payment_methods,
+        );
+
+        assert_eq!(
+            payment_methods
+                .iter()
+                .map(|payment_method| payment_method.payment_method_type)
+                .collect::<Vec<_>>(),
+            vec![
+                enums::PaymentMethodType::Ideal,
+                enums::PaymentMethodType::Ideal,
+                enums::PaymentMethodType::Credit
+            ]
+        );
+        assert_eq!(
+            excluded_payment_methods,
+            vec![ExcludedPaymentMethod {
+                payment_method: enums::PaymentMethod::Voucher,
+                payment_method_type: enums::PaymentMethodType::Boleto,
+                reason: PaymentMethodExclusionReason::NotAvailableInCountry,
+                country: Some(enums::CountryAlpha2::NL),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_payment_methods_are_not_filtered_without_country() {
+        let config = get_config();
+        let merchant_availability = MerchantCountryAvailability::new();
+        let mut payment_methods = vec![get_payment_method(
+            enums::PaymentMethod::Voucher,
+            enums::PaymentMethodType::Boleto,
+            "adyen",
+        )];
+
+        let excluded_payment_methods = filter_payment_methods_by_country(
+            &config,
+            &merchant_availability,
+            None,
+            &mut payment_methods,
+        );
+
+        assert_eq!(payment_methods.len(), 1);
+        assert!(excluded_payment_methods.is_empty());
+    }
+}
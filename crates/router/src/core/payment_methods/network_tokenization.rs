@@ -30,7 +30,8 @@ pub struct CardData {
     card_number: CardNumber,
     exp_month: Secret<String>,
     exp_year: Secret<String>,
-    card_security_code: Secret<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    card_security_code: Option<Secret<String>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -81,6 +82,9 @@ pub struct GetCardToken {
 pub struct AuthenticationDetails {
     cryptogram: Secret<String>,
     token: CardNumber, //network token
+    /// The unix timestamp until which the cryptogram is valid
+    #[serde(default)]
+    cryptogram_expiry: Option<i64>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -272,7 +276,8 @@ pub async fn make_card_network_tokenization_request(
         card_number: card.card_number.clone(),
         exp_month: card.card_exp_month.clone(),
         exp_year: card.card_exp_year.clone(),
-        card_security_code: card.card_cvc.clone(),
+        // The security code of a stored card is not kept, an empty code is left out of the request
+        card_security_code: (!card.card_cvc.peek().is_empty()).then(|| card.card_cvc.clone()),
     };
 
     let payload = card_data
@@ -512,6 +517,29 @@ pub async fn check_token_status_with_tokenization_service(
     tokenization_service: &settings::NetworkTokenizationService,
 ) -> CustomResult<(Option<Secret<String>>, Option<Secret<String>>), errors::NetworkTokenizationError>
 {
+    let token_status = get_token_status_from_tokenization_service(
+        state,
+        customer_id,
+        network_token_requestor_reference_id,
+        tokenization_service,
+    )
+    .await?;
+
+    match token_status.token_status {
+        TokenStatus::Active => Ok((
+            Some(token_status.token_expiry_month),
+            Some(token_status.token_expiry_year),
+        )),
+        TokenStatus::Inactive => Ok((None, None)),
+    }
+}
+
+async fn get_token_status_from_tokenization_service(
+    state: &routes::SessionState,
+    customer_id: &id_type::CustomerId,
+    network_token_requestor_reference_id: String,
+    tokenization_service: &settings::NetworkTokenizationService,
+) -> CustomResult<CheckTokenStatusResponsePayload, errors::NetworkTokenizationError> {
     let mut request = services::Request::new(
         services::Method::Post,
         tokenization_service.check_token_status_url.as_str(),
@@ -569,12 +597,211 @@ pub async fn check_token_status_with_tokenization_service(
         .parse_struct("Delete Network Tokenization Response")
         .change_context(errors::NetworkTokenizationError::ResponseDeserializationFailed)?;
 
-    match check_token_status_response.payload.token_status {
-        TokenStatus::Active => Ok((
-            Some(check_token_status_response.payload.token_expiry_month),
-            Some(check_token_status_response.payload.token_expiry_year),
-        )),
-        TokenStatus::Inactive => Ok((None, None)),
+    Ok(check_token_status_response.payload)
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+fn get_network_token_cryptogram_key(
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+    payment_method_id: &str,
+) -> String {
+    format!(
+        "network_token_cryptogram_{}_{}_{payment_method_id}",
+        merchant_id.get_string_repr(),
+        payment_id.get_string_repr()
+    )
+}
+
+/// The time in seconds for which the cryptogram can be kept, until the expiry reported by the
+/// token service. A cryptogram without a reported expiry is not kept.
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+fn get_network_token_cryptogram_ttl(cryptogram_expiry: Option<i64>, now: i64) -> Option<i64> {
+    cryptogram_expiry
+        .map(|cryptogram_expiry| cryptogram_expiry.saturating_sub(now))
+        .filter(|ttl| *ttl > 0)
+}
+
+/// Get a fresh cryptogram for the network token of the payment method, for a merchant initiated
+/// payment with the stored network token.
+///
+/// The cryptogram is requested from the token service and kept until the expiry reported by the
+/// token service, so that the retries of the same payment do not request another one. The
+/// cryptogram is never reused across payments, and a stale cryptogram is never sent, so the payment
+/// fails when no fresh cryptogram can be obtained. When the token service reports the network token
+/// as no longer active, the card of the payment method is tokenized again, so that the payment is
+/// retried with the new network token.
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub async fn get_fresh_cryptogram_for_network_token(
+    state: &routes::SessionState,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &id_type::PaymentId,
+    payment_method: &domain::PaymentMethod,
+) -> errors::RouterResult<Secret<String>> {
+    let network_token_requestor_ref_id = payment_method
+        .network_token_requestor_reference_id
+        .clone()
+        .ok_or(errors::ApiErrorResponse::NetworkTokenCryptogramRefreshFailed {
+            message: "The network token of the payment method is not managed by the token service, a fresh cryptogram cannot be obtained for it".to_string(),
+        })?;
+    let network_tokenization_service = state
+        .conf
+        .network_tokenization_service
+        .as_ref()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Network Tokenization Service not configured")?
+        .get_inner();
+
+    let cryptogram_key = get_network_token_cryptogram_key(
+        &payment_method.merchant_id,
+        payment_id,
+        &payment_method.payment_method_id,
+    );
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .map_err(|redis_error| logger::error!(?redis_error))
+        .ok();
+
+    if let Some(redis_conn) = &redis_conn {
+        if let Ok(cryptogram) = redis_conn
+            .get_and_deserialize_key::<Secret<String>>(&cryptogram_key, "NetworkTokenCryptogram")
+            .await
+        {
+            return Ok(cryptogram);
+        }
+    }
+
+    let token_response = record_operation_time(
+        get_network_token(
+            state,
+            payment_method.customer_id.clone(),
+            network_token_requestor_ref_id.clone(),
+            network_tokenization_service,
+        ),
+        &metrics::FETCH_NETWORK_TOKEN_TIME,
+        &metrics::CONTEXT,
+        &[],
+    )
+    .await;
+
+    match token_response {
+        Ok(token_response) => {
+            let authentication_details = token_response.authentication_details;
+            let cryptogram_ttl = get_network_token_cryptogram_ttl(
+                authentication_details.cryptogram_expiry,
+                common_utils::date_time::now_unix_timestamp(),
+            );
+            if let Some((redis_conn, cryptogram_ttl)) = redis_conn.as_ref().zip(cryptogram_ttl) {
+                redis_conn
+                    .serialize_and_set_key_with_expiry(
+                        &cryptogram_key,
+                        &authentication_details.cryptogram,
+                        cryptogram_ttl,
+                    )
+                    .await
+                    .map_err(|error| {
+                        logger::error!(?error, "Failed to store the network token cryptogram")
+                    })
+                    .ok();
+            }
+            Ok(authentication_details.cryptogram)
+        }
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to fetch a fresh cryptogram from the tokenization service"
+            );
+            let token_status = get_token_status_from_tokenization_service(
+                state,
+                &payment_method.customer_id,
+                network_token_requestor_ref_id,
+                network_tokenization_service,
+            )
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to check the network token status"))
+            .ok();
+
+            let token_status = token_status.map(|token_status| token_status.token_status);
+            if matches!(token_status, Some(TokenStatus::Inactive)) {
+                reprovision_network_token(state, key_store, payment_method).await;
+            }
+            Err(get_cryptogram_refresh_error(token_status))
+                .attach_printable("Failed to fetch a fresh cryptogram for the network token")
+        }
+    }
+}
+
+/// The error with which the payment fails when no fresh cryptogram could be obtained for the
+/// network token, per the status of the network token at the token service
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+fn get_cryptogram_refresh_error(token_status: Option<TokenStatus>) -> errors::ApiErrorResponse {
+    let message = match token_status {
+        Some(TokenStatus::Inactive) => "The network token of the payment method is no longer active and is being provisioned again, retry the payment",
+        Some(TokenStatus::Active) | None => {
+            "A fresh cryptogram could not be obtained for the network token of the payment method"
+        }
+    };
+    errors::ApiErrorResponse::NetworkTokenCryptogramRefreshFailed {
+        message: message.to_string(),
+    }
+}
+
+/// Tokenize the card of the payment method again, replacing its deactivated network token. The
+/// payment method is flagged for re-tokenization when this fails.
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+async fn reprovision_network_token(
+    state: &routes::SessionState,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: &domain::PaymentMethod,
+) {
+    let outcome = async {
+        let merchant_account = state
+            .store
+            .find_merchant_account_by_merchant_id(
+                &state.into(),
+                &payment_method.merchant_id,
+                key_store,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+        payment_methods::retokenization::retokenize_payment_method(
+            state,
+            &merchant_account,
+            key_store,
+            payment_method.clone(),
+        )
+        .await
+    }
+    .await;
+
+    match outcome {
+        Ok(outcome) => logger::info!(
+            ?outcome,
+            payment_method_id = %payment_method.payment_method_id,
+            "Provisioned the deactivated network token again"
+        ),
+        Err(error) => logger::error!(
+            ?error,
+            payment_method_id = %payment_method.payment_method_id,
+            "Failed to provision the deactivated network token again"
+        ),
     }
 }
 
@@ -697,3 +924,40 @@ pub async fn delete_network_token_from_tokenization_service(
             .attach_printable("Delete Token at Token service failed")
     }
 }
+
+#[cfg(all(
+    test,
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_network_token_cryptogram_is_kept_until_its_expiry() {
+        let now = 1_700_000_000;
+        assert_eq!(
+            get_network_token_cryptogram_ttl(Some(now + 300), now),
+            Some(300)
+        );
+        assert_eq!(get_network_token_cryptogram_ttl(Some(now), now), None);
+        assert_eq!(get_network_token_cryptogram_ttl(Some(now - 60), now), None);
+        assert_eq!(get_network_token_cryptogram_ttl(None, now), None);
+    }
+
+    #[test]
+    fn test_payment_fails_when_no_fresh_cryptogram_is_obtained() {
+        for token_status in [None, Some(TokenStatus::Active), Some(TokenStatus::Inactive)] {
+            assert!(matches!(
+                get_cryptogram_refresh_error(token_status),
+                errors::ApiErrorResponse::NetworkTokenCryptogramRefreshFailed { .. }
+            ));
+        }
+
+        assert!(matches!(
+            get_cryptogram_refresh_error(Some(TokenStatus::Inactive)),
+            errors::ApiErrorResponse::NetworkTokenCryptogramRefreshFailed { message }
+                if message.contains("provisioned again")
+        ));
+    }
+}
@@ -0,0 +1,379 @@
+use std::{cmp::Reverse, collections::HashMap};
+
+use api_models::{
+    admin::{AcceptedCountries, AcceptedCurrencies, PaymentMethodsEnabled},
+    enums as api_enums,
+    payment_methods::{
+        PaymentMethodRecommendation, PaymentMethodRecommendationReason,
+        PaymentMethodRecommendationRequest, PaymentMethodRecommendationResponse,
+        RequestPaymentMethodTypes,
+    },
+};
+use common_enums::ConnectorType;
+use masking::ExposeInterface;
+use router_env::logger;
+use time::PrimitiveDateTime;
+
+use super::cards;
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services,
+    types::domain,
+};
+
+/// Usage of a payment method saved by the customer
+#[derive(Debug, Clone)]
+pub struct SavedPaymentMethodUsage {
+    pub payment_method: api_enums::PaymentMethod,
+    pub payment_method_type: api_enums::PaymentMethodType,
+    pub last_used_at: PrimitiveDateTime,
+}
+
+#[derive(Default)]
+struct RankingSignals {
+    last_used_at: Option<PrimitiveDateTime>,
+    supporting_connectors: usize,
+}
+
+fn is_country_accepted(
+    accepted_countries: Option<&AcceptedCountries>,
+    country: Option<api_enums::CountryAlpha2>,
+) -> bool {
+    match (accepted_countries, country) {
+        (Some(AcceptedCountries::EnableOnly(countries)), Some(country)) => {
+            countries.contains(&country)
+        }
+        (Some(AcceptedCountries::DisableOnly(countries)), Some(country)) => {
+            !countries.contains(&country)
+        }
+        _ => true,
+    }
+}
+
+fn is_currency_accepted(
+    accepted_currencies: Option<&AcceptedCurrencies>,
+    currency: Option<api_enums::Currency>,
+) -> bool {
+    match (accepted_currencies, currency) {
+        (Some(AcceptedCurrencies::EnableOnly(currencies)), Some(currency)) => {
+            currencies.contains(&currency)
+        }
+        (Some(AcceptedCurrencies::DisableOnly(currencies)), Some(currency)) => {
+            !currencies.contains(&currency)
+        }
+        _ => true,
+    }
+}
+
+pub fn is_available_for_payment(
+    req: &PaymentMethodRecommendationRequest,
+    payment_method_type_info: &RequestPaymentMethodTypes,
+) -> bool {
+    is_country_accepted(
+        payment_method_type_info.accepted_countries.as_ref(),
+        req.country,
+    ) && is_currency_accepted(
+        payment_method_type_info.accepted_currencies.as_ref(),
+        req.currency,
+    ) && cards::filter_amount_based(payment_method_type_info, req.amount)
+}
+
+/// Rank the payment methods which are available for the payment.
+///
+/// Payment methods the customer has used before are ranked first, the most recently used one
+/// first. The remaining payment methods are ranked by the number of connectors supporting them,
+/// with ties broken by the name of the payment method so that the ranking is deterministic.
+pub fn rank_payment_methods(
+    req: &PaymentMethodRecommendationRequest,
+    payment_methods_enabled: &[PaymentMethodsEnabled],
+    saved_payment_methods: &[SavedPaymentMethodUsage],
+) -> Vec<PaymentMethodRecommendation> {
+    let mut signals: HashMap<
+        (api_enums::PaymentMethod, api_enums::PaymentMethodType),
+        RankingSignals,
+    > = HashMap::new();
+
+    for payment_method_enabled in payment_methods_enabled {
+        for payment_method_type_info in payment_method_enabled
+            .payment_method_types
+            .iter()
+            .flatten()
+            .filter(|payment_method_type_info| {
+                is_available_for_payment(req, payment_method_type_info)
+            })
+        {
+            signals
+                .entry((
+                    payment_method_enabled.payment_method,
+                    payment_method_type_info.payment_method_type,
+                ))
+                .or_default()
+                .supporting_connectors += 1;
+        }
+    }
+
+    for saved_payment_method in saved_payment_methods {
+        // Saved payment methods which are not available for the payment are not recommended
+        if let Some(ranking_signals) = signals.get_mut(&(
+            saved_payment_method.payment_method,
+            saved_payment_method.payment_method_type,
+        )) {
+            ranking_signals.last_used_at = ranking_signals
+                .last_used_at
+                .max(Some(saved_payment_method.last_used_at));
+        }
+    }
+
+    let mut ranked_payment_methods = signals.into_iter().collect::<Vec<_>>();
+    ranked_payment_methods.sort_by_key(
+        |((payment_method, payment_method_type), ranking_signals)| {
+            (
+                Reverse(ranking_signals.last_used_at),
+                Reverse(ranking_signals.supporting_connectors),
+                payment_method.to_string(),
+                payment_method_type.to_string(),
+            )
+        },
+    );
+
+    ranked_payment_methods
+        .into_iter()
+        .map(|((payment_method, payment_method_type), ranking_signals)| {
+            PaymentMethodRecommendation {
+                payment_method,
+                payment_method_type,
+                reason: if ranking_signals.last_used_at.is_some() {
+                    PaymentMethodRecommendationReason::PreviouslyUsed
+                } else {
+                    PaymentMethodRecommendationReason::AvailableForPayment
+                },
+            }
+        })
+        .collect()
+}
+
+/// The payment processor accounts of the merchant which are not disabled, limited to the accounts
+/// of the profile if any
+pub async fn get_payment_processor_accounts(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: Option<&common_utils::id_type::ProfileId>,
+) -> RouterResult<Vec<domain::MerchantConnectorAccount>> {
+    Ok(state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &state.into(),
+            merchant_account.get_id(),
+            false,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?
+        .into_iter()
+        .filter(|mca| {
+            mca.connector_type == ConnectorType::PaymentProcessor
+                && profile_id.map_or(true, |profile_id| mca.profile_id == *profile_id)
+        })
+        .collect())
+}
+
+/// The payment methods enabled on the merchant connector account, skipping those which cannot be
+/// parsed
+pub fn get_payment_methods_enabled(
+    mca: &domain::MerchantConnectorAccount,
+) -> Vec<PaymentMethodsEnabled> {
+    mca.payment_methods_enabled
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|payment_method_enabled| {
+            serde_json::from_value::<PaymentMethodsEnabled>(payment_method_enabled.expose())
+                .inspect_err(|error| {
+                    logger::error!(?error, "Failed to parse payment methods enabled")
+                })
+                .ok()
+        })
+        .collect()
+}
+
+/// Usages of the active payment methods saved by the customer
+pub async fn get_saved_payment_method_usages(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: Option<&common_utils::id_type::CustomerId>,
+) -> RouterResult<Vec<SavedPaymentMethodUsage>> {
+    let Some(customer_id) = customer_id else {
+        return Ok(Vec::new());
+    };
+
+    Ok(state
+        .store
+        .find_payment_method_by_customer_id_merchant_id_list(
+            &state.into(),
+            key_store,
+            customer_id,
+            merchant_account.get_id(),
+            None,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?
+        .into_iter()
+        .filter(|payment_method| payment_method.status == common_enums::PaymentMethodStatus::Active)
+        .filter_map(|payment_method| {
+            Some(SavedPaymentMethodUsage {
+                payment_method: payment_method.payment_method?,
+                payment_method_type: payment_method.payment_method_type?,
+                last_used_at: payment_method.last_used_at,
+            })
+        })
+        .collect())
+}
+
+pub async fn recommend_payment_methods(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: PaymentMethodRecommendationRequest,
+) -> RouterResponse<PaymentMethodRecommendationResponse> {
+    let payment_methods_enabled =
+        get_payment_processor_accounts(&state, &merchant_account, &key_store, profile_id.as_ref())
+            .await?
+            .iter()
+            .flat_map(get_payment_methods_enabled)
+            .collect::<Vec<_>>();
+
+    let saved_payment_methods = get_saved_payment_method_usages(
+        &state,
+        &merchant_account,
+        &key_store,
+        req.customer_id.as_ref(),
+    )
+    .await?;
+
+    let recommendations =
+        rank_payment_methods(&req, &payment_methods_enabled, &saved_payment_methods);
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentMethodRecommendationResponse { recommendations },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payment_method_type_info(
+        payment_method_type: api_enums::PaymentMethodType,
+        accepted_countries: Option<AcceptedCountries>,
+    ) -> RequestPaymentMethodTypes {
+        RequestPaymentMethodTypes {
+            payment_method_type,
+            payment_experience: None,
+            card_networks: None,
+            accepted_currencies: None,
+            accepted_countries,
+            minimum_amount: None,
+            maximum_amount: None,
+            recurring_enabled: true,
+            installment_payment_enabled: true,
+        }
+    }
+
+    fn payment_methods_enabled() -> Vec<PaymentMethodsEnabled> {
+        vec![
+            PaymentMethodsEnabled {
+                payment_method: api_enums::PaymentMethod::Card,
+                payment_method_types: Some(vec![
+                    payment_method_type_info(api_enums::PaymentMethodType::Credit, None),
+                    payment_method_type_info(api_enums::PaymentMethodType::Debit, None),
+                ]),
+            },
+            PaymentMethodsEnabled {
+                payment_method: api_enums::PaymentMethod::Card,
+                payment_method_types: Some(vec![payment_method_type_info(
+                    api_enums::PaymentMethodType::Debit,
+                    None,
+                )]),
+            },
+            PaymentMethodsEnabled {
+                payment_method: api_enums::PaymentMethod::Wallet,
+                payment_method_types: Some(vec![payment_method_type_info(
+                    api_enums::PaymentMethodType::ApplePay,
+                    Some(AcceptedCountries::EnableOnly(vec![
+                        api_enums::CountryAlpha2::US,
+                    ])),
+                )]),
+            },
+        ]
+    }
+
+    fn request(country: api_enums::CountryAlpha2) -> PaymentMethodRecommendationRequest {
+        PaymentMethodRecommendationRequest {
+            customer_id: None,
+            country: Some(country),
+            currency: Some(api_enums::Currency::USD),
+            amount: None,
+        }
+    }
+
+    #[test]
+    fn test_rank_payment_methods_prefers_previously_used_methods() {
+        let saved_payment_methods = vec![SavedPaymentMethodUsage {
+            payment_method: api_enums::PaymentMethod::Wallet,
+            payment_method_type: api_enums::PaymentMethodType::ApplePay,
+            last_used_at: time::macros::datetime!(2024-09-01 10:00),
+        }];
+
+        let recommendations = rank_payment_methods(
+            &request(api_enums::CountryAlpha2::US),
+            &payment_methods_enabled(),
+            &saved_payment_methods,
+        );
+
+        let ranked = recommendations
+            .iter()
+            .map(|recommendation| (recommendation.payment_method_type, recommendation.reason))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            ranked,
+            vec![
+                (
+                    api_enums::PaymentMethodType::ApplePay,
+                    PaymentMethodRecommendationReason::PreviouslyUsed
+                ),
+                (
+                    api_enums::PaymentMethodType::Debit,
+                    PaymentMethodRecommendationReason::AvailableForPayment
+                ),
+                (
+                    api_enums::PaymentMethodType::Credit,
+                    PaymentMethodRecommendationReason::AvailableForPayment
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rank_payment_methods_excludes_unavailable_methods() {
+        let saved_payment_methods = vec![SavedPaymentMethodUsage {
+            payment_method: api_enums::PaymentMethod::Wallet,
+            payment_method_type: api_enums::PaymentMethodType::ApplePay,
+            last_used_at: time::macros::datetime!(2024-09-01 10:00),
+        }];
+
+        let recommendations = rank_payment_methods(
+            &request(api_enums::CountryAlpha2::DE),
+            &payment_methods_enabled(),
+            &saved_payment_methods,
+        );
+
+        assert!(recommendations.iter().all(|recommendation| {
+            recommendation.payment_method_type != api_enums::PaymentMethodType::ApplePay
+        }));
+        assert_eq!(recommendations.len(), 2);
+    }
+}
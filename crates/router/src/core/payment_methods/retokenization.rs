@@ -0,0 +1,549 @@
+//! Re-tokenization of the stored cards with the network tokenization service.
+//!
+//! When the network tokenization service is switched, the stored cards are tokenized again with
+//! the newly configured service, without any customer interaction. The new network token replaces
+//! the old one in a single update of the payment method, and only once the new token is confirmed
+//! to be active, so that the payment method keeps working with the old token until then. When the
+//! re-tokenization fails, the old token is left in place and the payment method is flagged so that
+//! the re-tokenization can be retried.
+
+use api_models::payment_methods::{CardDetailsPaymentMethod, PaymentMethodsData};
+use common_utils::{crypto::Encryptable, id_type};
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+use super::{cards, network_tokenization};
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services,
+    types::{
+        api::{self, admin},
+        domain, storage,
+    },
+};
+
+/// The maximum number of payment methods re-tokenized in a single request
+const RETOKENIZATION_MAX_LIMIT: i64 = 100;
+
+/// The outcome of the re-tokenization of a payment method
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RetokenizationOutcome {
+    /// The network token of the payment method was replaced by a new network token
+    Retokenized,
+    /// The card network of the payment method is not supported by the network tokenization
+    /// service, the payment method is left as it is
+    Skipped,
+    /// The re-tokenization failed, the old network token is left in place and the payment method
+    /// is flagged for retry
+    Failed,
+}
+
+/// The number of payment methods with each outcome in a run of the re-tokenization job
+#[derive(Clone, Debug, Default)]
+pub struct RetokenizationSummary {
+    pub retokenized: usize,
+    pub skipped: usize,
+    pub failed: usize,
+    /// The id of the last payment method of the run, from which the next run continues, absent when
+    /// no payment methods are left to be re-tokenized
+    pub next_starting_after: Option<String>,
+}
+
+/// The network token which is requested to replace the network token of a payment method
+struct NewNetworkToken {
+    network_token_requestor_reference_id: String,
+    network_token_locker_id: String,
+    network_token_payment_method_data: Option<Encryptable<Secret<serde_json::Value>>>,
+}
+
+/// Re-tokenize at most `limit` of the payment methods of the merchant which match the request, to be
+/// called repeatedly with the returned `next_starting_after` until no payment methods are left to
+/// be re-tokenized
+#[instrument(skip_all)]
+pub async fn retokenize_merchant_payment_methods(
+    state: SessionState,
+    req: admin::PaymentMethodRetokenizationRequest,
+) -> RouterResponse<admin::PaymentMethodRetokenizationResponse> {
+    let limit = req.limit.unwrap_or(RETOKENIZATION_MAX_LIMIT);
+    if !(1..=RETOKENIZATION_MAX_LIMIT).contains(&limit) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("limit must be between 1 and {RETOKENIZATION_MAX_LIMIT}"),
+        }));
+    }
+    if state.conf.network_tokenization_service.is_none() {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Network tokenization service is not configured".to_string(),
+        }));
+    }
+
+    let key_manager_state = &(&state).into();
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &req.merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(key_manager_state, &req.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let criteria = storage::PaymentMethodRetokenizationCriteria {
+        merchant_id: req.merchant_id,
+        last_modified_before: req.last_modified_before,
+        retokenization_pending_only: req.retokenization_pending_only,
+        starting_after: req.starting_after,
+    };
+    let summary =
+        retokenize_payment_methods(&state, &merchant_account, &key_store, &criteria, limit).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        admin::PaymentMethodRetokenizationResponse {
+            retokenized: summary.retokenized,
+            skipped: summary.skipped,
+            failed: summary.failed,
+            next_starting_after: summary.next_starting_after,
+        },
+    ))
+}
+
+/// Re-tokenize the payment methods of the merchant which match the criteria, at most `limit` of
+/// them in a single run
+#[instrument(skip_all)]
+pub async fn retokenize_payment_methods(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    criteria: &storage::PaymentMethodRetokenizationCriteria,
+    limit: i64,
+) -> RouterResult<RetokenizationSummary> {
+    let payment_methods = state
+        .store
+        .list_payment_methods_for_retokenization(&state.into(), key_store, criteria, limit)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the payment methods for retokenization")?;
+
+    let payment_method_ids = payment_methods
+        .iter()
+        .map(|payment_method| payment_method.payment_method_id.clone())
+        .collect::<Vec<_>>();
+    let mut summary = RetokenizationSummary {
+        next_starting_after: get_next_starting_after(payment_method_ids, limit),
+        ..Default::default()
+    };
+    for payment_method in payment_methods {
+        let payment_method_id = payment_method.payment_method_id.clone();
+        match retokenize_payment_method(state, merchant_account, key_store, payment_method).await {
+            Ok(RetokenizationOutcome::Retokenized) => summary.retokenized += 1,
+            Ok(RetokenizationOutcome::Skipped) => summary.skipped += 1,
+            Ok(RetokenizationOutcome::Failed) => summary.failed += 1,
+            // The failure of a payment method does not stop the re-tokenization of the others
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    %payment_method_id,
+                    "Failed to retokenize the payment method"
+                );
+                summary.failed += 1;
+            }
+        }
+    }
+
+    logger::info!(
+        retokenized = summary.retokenized,
+        skipped = summary.skipped,
+        failed = summary.failed,
+        "Completed retokenization of payment methods"
+    );
+
+    Ok(summary)
+}
+
+/// The payment method id from which the next run continues. The payment methods are listed in the
+/// order of their ids, so that the payment methods which are skipped or fail in a run are not listed
+/// again by the next run. No payment methods are left once fewer than `limit` are listed.
+fn get_next_starting_after(payment_method_ids: Vec<String>, limit: i64) -> Option<String> {
+    let is_last_page = usize::try_from(limit)
+        .map(|limit| payment_method_ids.len() < limit)
+        .unwrap_or(true);
+    if is_last_page {
+        None
+    } else {
+        payment_method_ids.into_iter().last()
+    }
+}
+
+/// Request a new network token for the stored card of the payment method, and replace the network
+/// token of the payment method with it
+#[instrument(skip_all)]
+pub async fn retokenize_payment_method(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: domain::PaymentMethod,
+) -> RouterResult<RetokenizationOutcome> {
+    if state.conf.network_tokenization_service.is_none() {
+        return Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Network tokenization service is not configured");
+    }
+
+    let db = state.store.as_ref();
+    let new_network_token = match request_new_network_token(
+        state,
+        merchant_account,
+        key_store,
+        &payment_method,
+    )
+    .await
+    {
+        Ok(Some(new_network_token)) => new_network_token,
+        Ok(None) => return Ok(RetokenizationOutcome::Skipped),
+        Err(error) => {
+            logger::error!(
+                ?error,
+                payment_method_id = %payment_method.payment_method_id,
+                "Failed to retokenize the payment method"
+            );
+            db.update_payment_method(
+                &state.into(),
+                key_store,
+                payment_method,
+                storage::PaymentMethodUpdate::RetokenizationPendingUpdate {
+                    retokenization_pending: Some(true),
+                },
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to flag the payment method for retokenization")?;
+            return Ok(RetokenizationOutcome::Failed);
+        }
+    };
+
+    let customer_id = payment_method.customer_id.clone();
+    let payment_method_id = payment_method.payment_method_id.clone();
+    let old_network_token_requestor_reference_id =
+        payment_method.network_token_requestor_reference_id.clone();
+    let old_network_token_locker_id = payment_method.network_token_locker_id.clone();
+
+    // The old network token is replaced along with its locker entry and its card details in a
+    // single update, so that the payment method never refers to a partially replaced token
+    let update_result = db
+        .update_payment_method(
+            &state.into(),
+            key_store,
+            payment_method,
+            storage::PaymentMethodUpdate::NetworkTokenDataUpdate {
+                network_token_requestor_reference_id: Some(
+                    new_network_token
+                        .network_token_requestor_reference_id
+                        .clone(),
+                ),
+                network_token_locker_id: Some(new_network_token.network_token_locker_id.clone()),
+                network_token_payment_method_data: new_network_token
+                    .network_token_payment_method_data
+                    .map(Into::into),
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the network token of the payment method");
+
+    if let Err(error) = update_result {
+        // The payment method still refers to the old network token, the new one is not used
+        delete_network_token(
+            state,
+            &customer_id,
+            merchant_account.get_id(),
+            &payment_method_id,
+            Some(new_network_token.network_token_locker_id),
+            new_network_token.network_token_requestor_reference_id,
+        )
+        .await;
+        return Err(error);
+    }
+
+    if let Some(old_network_token_requestor_reference_id) = old_network_token_requestor_reference_id
+    {
+        delete_network_token(
+            state,
+            &customer_id,
+            merchant_account.get_id(),
+            &payment_method_id,
+            old_network_token_locker_id,
+            old_network_token_requestor_reference_id,
+        )
+        .await;
+    }
+
+    Ok(RetokenizationOutcome::Retokenized)
+}
+
+/// Request a new network token for the stored card of the payment method, returning `None` if the
+/// card network is not supported by the network tokenization service
+async fn request_new_network_token(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: &domain::PaymentMethod,
+) -> RouterResult<Option<NewNetworkToken>> {
+    let card_details = payment_method
+        .payment_method_data
+        .clone()
+        .map(|payment_method_data| payment_method_data.into_inner().expose())
+        .and_then(|value| serde_json::from_value::<PaymentMethodsData>(value).ok())
+        .and_then(|payment_method_data| match payment_method_data {
+            PaymentMethodsData::Card(card_details) => Some(card_details),
+            _ => None,
+        });
+
+    let Some(card_details) = card_details.filter(|card_details| {
+        card_details
+            .card_network
+            .as_ref()
+            .is_some_and(|card_network| {
+                state
+                    .conf
+                    .network_tokenization_supported_card_networks
+                    .card_networks
+                    .contains(card_network)
+            })
+    }) else {
+        return Ok(None);
+    };
+
+    let card = cards::get_card_from_locker(
+        state,
+        &payment_method.customer_id,
+        &payment_method.merchant_id,
+        payment_method
+            .locker_id
+            .as_ref()
+            .unwrap_or(&payment_method.payment_method_id),
+    )
+    .await?;
+
+    let card = domain::Card {
+        card_number: card.card_number,
+        card_exp_month: card.card_exp_month,
+        card_exp_year: card.card_exp_year,
+        // The card security code is not stored, the empty code is left out of the tokenization
+        // request
+        card_cvc: Secret::new(String::new()),
+        card_issuer: card_details.card_issuer,
+        card_network: card_details.card_network,
+        card_type: card_details.card_type,
+        card_issuing_country: card_details.issuer_country,
+        bank_code: None,
+        nick_name: card_details.nick_name,
+    };
+
+    let (token_response, network_token_requestor_reference_id) =
+        network_tokenization::make_card_network_tokenization_request(
+            state,
+            &card,
+            &payment_method.customer_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to request a new network token")?;
+    let network_token_requestor_reference_id = network_token_requestor_reference_id
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Missing the reference of the new network token")?;
+
+    match store_new_network_token(
+        state,
+        merchant_account,
+        key_store,
+        payment_method,
+        token_response,
+        &network_token_requestor_reference_id,
+    )
+    .await
+    {
+        Ok((network_token_locker_id, network_token_payment_method_data)) => {
+            Ok(Some(NewNetworkToken {
+                network_token_requestor_reference_id,
+                network_token_locker_id,
+                network_token_payment_method_data,
+            }))
+        }
+        Err(error) => {
+            // The new network token is not used by the payment method, so it is removed from the
+            // tokenization service
+            delete_network_token(
+                state,
+                &payment_method.customer_id,
+                &payment_method.merchant_id,
+                &payment_method.payment_method_id,
+                None,
+                network_token_requestor_reference_id,
+            )
+            .await;
+            Err(error)
+        }
+    }
+}
+
+/// Confirm that the new network token is active, and store it in the locker. Returns the locker id
+/// and the encrypted card details of the network token.
+async fn store_new_network_token(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: &domain::PaymentMethod,
+    token_response: network_tokenization::CardNetworkTokenResponsePayload,
+    network_token_requestor_reference_id: &str,
+) -> RouterResult<(String, Option<Encryptable<Secret<serde_json::Value>>>)> {
+    let network_tokenization_service = state
+        .conf
+        .network_tokenization_service
+        .as_ref()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Network tokenization service is not configured")?;
+
+    let (token_exp_month, token_exp_year) =
+        network_tokenization::check_token_status_with_tokenization_service(
+            state,
+            &payment_method.customer_id,
+            network_token_requestor_reference_id.to_owned(),
+            network_tokenization_service.get_inner(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to check the status of the new network token")?;
+    if token_exp_month.is_none() || token_exp_year.is_none() {
+        return Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("The new network token is not active");
+    }
+
+    let token_details = api::CardDetail {
+        card_number: token_response.token,
+        card_exp_month: token_response.token_expiry_month,
+        card_exp_year: token_response.token_expiry_year,
+        card_holder_name: None,
+        nick_name: None,
+        card_issuing_country: None,
+        card_network: Some(token_response.card_brand.clone()),
+        card_issuer: None,
+        card_type: None,
+    };
+
+    let payment_method_create = api::PaymentMethodCreate {
+        payment_method: payment_method.payment_method,
+        payment_method_type: payment_method.payment_method_type,
+        payment_method_issuer: payment_method.payment_method_issuer.clone(),
+        payment_method_issuer_code: payment_method.payment_method_issuer_code,
+        card: Some(token_details.clone()),
+        #[cfg(feature = "payouts")]
+        wallet: None,
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        metadata: None,
+        customer_id: Some(payment_method.customer_id.clone()),
+        card_network: Some(token_response.card_brand.to_string()),
+        client_secret: None,
+        payment_method_data: None,
+        billing: None,
+        connector_mandate_details: None,
+        network_transaction_id: None,
+    };
+
+    let (response, _) = Box::pin(cards::add_card_to_locker(
+        state,
+        payment_method_create,
+        &token_details,
+        &payment_method.customer_id,
+        merchant_account,
+        None,
+    ))
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to add the new network token to the locker")?;
+
+    let network_token_payment_method_data = match response.card {
+        Some(card) => Some(
+            cards::create_encrypted_data(
+                state,
+                key_store,
+                PaymentMethodsData::Card(CardDetailsPaymentMethod::from(card)),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encrypt the network token details")?,
+        ),
+        None => None,
+    };
+
+    Ok((
+        response.payment_method_id,
+        network_token_payment_method_data,
+    ))
+}
+
+/// Delete the network token from the locker, if it was stored there, and from the tokenization
+/// service. A failure to delete the token does not affect the payment method, which no longer
+/// refers to it.
+async fn delete_network_token(
+    state: &SessionState,
+    customer_id: &id_type::CustomerId,
+    merchant_id: &id_type::MerchantId,
+    payment_method_id: &str,
+    network_token_locker_id: Option<String>,
+    network_token_requestor_reference_id: String,
+) {
+    if network_token_locker_id.is_some() {
+        let _ = network_tokenization::delete_network_token_from_locker_and_token_service(
+            state,
+            customer_id,
+            merchant_id,
+            payment_method_id.to_owned(),
+            network_token_locker_id,
+            network_token_requestor_reference_id,
+        )
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to delete the network token"));
+    } else if let Some(network_tokenization_service) = &state.conf.network_tokenization_service {
+        // Without a locker id of its own, the locker entry of the payment method is the card
+        // itself, so the network token is only deleted from the tokenization service
+        let _ = network_tokenization::delete_network_token_from_tokenization_service(
+            state,
+            network_token_requestor_reference_id,
+            customer_id,
+            network_tokenization_service.get_inner(),
+        )
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to delete the network token"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payment_method_ids(count: usize) -> Vec<String> {
+        (1..=count).map(|index| format!("pm_{index:03}")).collect()
+    }
+
+    #[test]
+    fn test_get_next_starting_after_continues_from_the_last_payment_method() {
+        assert_eq!(
+            get_next_starting_after(payment_method_ids(3), 3),
+            Some("pm_003".to_string())
+        );
+    }
+
+    #[test]
+    fn test_get_next_starting_after_stops_on_the_last_page() {
+        assert_eq!(get_next_starting_after(payment_method_ids(2), 3), None);
+        assert_eq!(get_next_starting_after(Vec::new(), 3), None);
+    }
+}
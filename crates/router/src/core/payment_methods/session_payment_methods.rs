@@ -0,0 +1,327 @@
+use api_models::{
+    admin::PaymentMethodsEnabled,
+    enums as api_enums,
+    payment_methods::{
+        PaymentMethodRecommendationRequest, PaymentMethodsListForSessionRequest,
+        PaymentMethodsListForSessionResponse, PaymentMethodsUnavailableReason,
+        SessionPaymentMethod, SessionPaymentMethodClientData, SessionPaymentMethodConnector,
+    },
+    payments::{ApplePayCombinedMetadata, ApplepaySessionTokenMetadata, GpaySessionTokenData},
+};
+use common_utils::{ext_traits::OptionExt, fp_utils, types::MinorUnit};
+use error_stack::report;
+use masking::ExposeInterface;
+use router_env::{instrument, tracing};
+
+use super::recommendation;
+use crate::{
+    core::{
+        errors::{self, RouterResponse},
+        payments::helpers,
+    },
+    routes::SessionState,
+    services,
+    types::domain,
+};
+
+/// The data which the client requires to present the payment method of the connector, from the
+/// wallet metadata of the merchant connector account
+fn get_client_data(
+    state: &SessionState,
+    payment_method_type: api_enums::PaymentMethodType,
+    mca: &domain::MerchantConnectorAccount,
+) -> Option<SessionPaymentMethodClientData> {
+    match payment_method_type {
+        api_enums::PaymentMethodType::ApplePay => {
+            let merchant_identifier =
+                match helpers::get_applepay_metadata(mca.metadata.clone()).ok()? {
+                    ApplepaySessionTokenMetadata::ApplePayCombined(
+                        ApplePayCombinedMetadata::Manual {
+                            session_token_data, ..
+                        },
+                    )
+                    | ApplepaySessionTokenMetadata::ApplePay(
+                        api_models::payments::ApplePayMetadata {
+                            session_token_data, ..
+                        },
+                    ) => session_token_data.merchant_identifier,
+                    // The session with Apple Pay is validated with the merchant identifier of the
+                    // platform in the simplified flow
+                    ApplepaySessionTokenMetadata::ApplePayCombined(
+                        ApplePayCombinedMetadata::Simplified { .. },
+                    ) => state
+                        .conf
+                        .applepay_merchant_configs
+                        .get_inner()
+                        .common_merchant_identifier
+                        .clone()
+                        .expose(),
+                };
+            Some(SessionPaymentMethodClientData::ApplePay {
+                merchant_identifier,
+            })
+        }
+        api_enums::PaymentMethodType::GooglePay => {
+            let google_pay_metadata = mca
+                .metadata
+                .clone()
+                .parse_value::<GpaySessionTokenData>("GpaySessionTokenData")
+                .ok()?;
+            Some(SessionPaymentMethodClientData::GooglePay {
+                merchant_id: google_pay_metadata.data.merchant_info.merchant_id,
+                merchant_name: google_pay_metadata.data.merchant_info.merchant_name,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// The reason for which none of the enabled payment methods is available for the session. Each of
+/// the country, the currency and the amount is checked on its own first, so that the reason names
+/// the input which no payment method accepts.
+fn get_unavailable_reason(
+    req: &PaymentMethodsListForSessionRequest,
+    payment_methods_enabled: &[PaymentMethodsEnabled],
+) -> PaymentMethodsUnavailableReason {
+    let is_any_available = |recommendation_request: PaymentMethodRecommendationRequest| {
+        payment_methods_enabled
+            .iter()
+            .flat_map(|payment_method_enabled| {
+                payment_method_enabled.payment_method_types.iter().flatten()
+            })
+            .any(|payment_method_type_info| {
+                recommendation::is_available_for_payment(
+                    &recommendation_request,
+                    payment_method_type_info,
+                )
+            })
+    };
+    let request = |country, currency, amount| PaymentMethodRecommendationRequest {
+        customer_id: None,
+        country,
+        currency,
+        amount,
+    };
+
+    if !payment_methods_enabled
+        .iter()
+        .any(|payment_method_enabled| {
+            payment_method_enabled
+                .payment_method_types
+                .iter()
+                .flatten()
+                .next()
+                .is_some()
+        })
+    {
+        PaymentMethodsUnavailableReason::NoPaymentMethodsEnabled
+    } else if !is_any_available(request(req.country, None, None)) {
+        PaymentMethodsUnavailableReason::UnsupportedCountry
+    } else if !is_any_available(request(None, Some(req.currency), None)) {
+        PaymentMethodsUnavailableReason::UnsupportedCurrency
+    } else if !is_any_available(request(None, None, Some(req.amount))) {
+        PaymentMethodsUnavailableReason::UnsupportedAmount
+    } else {
+        PaymentMethodsUnavailableReason::UnsupportedCombination
+    }
+}
+
+/// List the payment methods available for a checkout session before the payment is created,
+/// ranked as the payment method recommendations are, along with the connectors through which
+/// each payment method is available and the data the client requires to present it.
+#[instrument(skip_all)]
+pub async fn list_payment_methods_for_session(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: PaymentMethodsListForSessionRequest,
+) -> RouterResponse<PaymentMethodsListForSessionResponse> {
+    fp_utils::when(req.amount < MinorUnit::zero(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount cannot be negative".to_string(),
+        }))
+    })?;
+
+    let recommendation_request = PaymentMethodRecommendationRequest {
+        customer_id: req.customer_id.clone(),
+        country: req.country,
+        currency: Some(req.currency),
+        amount: Some(req.amount),
+    };
+
+    let payment_processor_accounts = recommendation::get_payment_processor_accounts(
+        &state,
+        &merchant_account,
+        &key_store,
+        profile_id.as_ref(),
+    )
+    .await?
+    .into_iter()
+    .map(|mca| {
+        let payment_methods_enabled = recommendation::get_payment_methods_enabled(&mca);
+        (mca, payment_methods_enabled)
+    })
+    .collect::<Vec<_>>();
+    let payment_methods_enabled = payment_processor_accounts
+        .iter()
+        .flat_map(|(_, payment_methods_enabled)| payment_methods_enabled.iter().cloned())
+        .collect::<Vec<_>>();
+
+    let saved_payment_methods = recommendation::get_saved_payment_method_usages(
+        &state,
+        &merchant_account,
+        &key_store,
+        req.customer_id.as_ref(),
+    )
+    .await?;
+
+    let payment_methods = recommendation::rank_payment_methods(
+        &recommendation_request,
+        &payment_methods_enabled,
+        &saved_payment_methods,
+    )
+    .into_iter()
+    .map(|recommendation| SessionPaymentMethod {
+        connectors: payment_processor_accounts
+            .iter()
+            .filter(|(_, payment_methods_enabled)| {
+                payment_methods_enabled
+                    .iter()
+                    .filter(|payment_method_enabled| {
+                        payment_method_enabled.payment_method == recommendation.payment_method
+                    })
+                    .flat_map(|payment_method_enabled| {
+                        payment_method_enabled.payment_method_types.iter().flatten()
+                    })
+                    .any(|payment_method_type_info| {
+                        payment_method_type_info.payment_method_type
+                            == recommendation.payment_method_type
+                            && recommendation::is_available_for_payment(
+                                &recommendation_request,
+                                payment_method_type_info,
+                            )
+                    })
+            })
+            .map(|(mca, _)| SessionPaymentMethodConnector {
+                connector: mca.connector_name.clone(),
+                merchant_connector_id: mca.get_id(),
+                client_data: get_client_data(&state, recommendation.payment_method_type, mca),
+            })
+            .collect(),
+        payment_method: recommendation.payment_method,
+        payment_method_type: recommendation.payment_method_type,
+        reason: recommendation.reason,
+    })
+    .collect::<Vec<_>>();
+
+    let unavailable_reason = payment_methods
+        .is_empty()
+        .then(|| get_unavailable_reason(&req, &payment_methods_enabled));
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentMethodsListForSessionResponse {
+            publishable_key: merchant_account.publishable_key,
+            payment_methods,
+            unavailable_reason,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use api_models::{
+        admin::{AcceptedCountries, AcceptedCurrencies},
+        payment_methods::RequestPaymentMethodTypes,
+    };
+
+    use super::*;
+
+    fn payment_methods_enabled() -> Vec<PaymentMethodsEnabled> {
+        vec![
+            PaymentMethodsEnabled {
+                payment_method: api_enums::PaymentMethod::Card,
+                payment_method_types: Some(vec![RequestPaymentMethodTypes {
+                    payment_method_type: api_enums::PaymentMethodType::Credit,
+                    payment_experience: None,
+                    card_networks: None,
+                    accepted_currencies: Some(AcceptedCurrencies::EnableOnly(vec![
+                        api_enums::Currency::USD,
+                    ])),
+                    accepted_countries: Some(AcceptedCountries::EnableOnly(vec![
+                        api_enums::CountryAlpha2::US,
+                    ])),
+                    minimum_amount: None,
+                    maximum_amount: Some(MinorUnit::new(10000)),
+                    recurring_enabled: true,
+                    installment_payment_enabled: true,
+                }]),
+            },
+            PaymentMethodsEnabled {
+                payment_method: api_enums::PaymentMethod::Wallet,
+                payment_method_types: Some(vec![RequestPaymentMethodTypes {
+                    payment_method_type: api_enums::PaymentMethodType::Paypal,
+                    payment_experience: None,
+                    card_networks: None,
+                    accepted_currencies: Some(AcceptedCurrencies::EnableOnly(vec![
+                        api_enums::Currency::EUR,
+                    ])),
+                    accepted_countries: Some(AcceptedCountries::EnableOnly(vec![
+                        api_enums::CountryAlpha2::DE,
+                    ])),
+                    minimum_amount: None,
+                    maximum_amount: None,
+                    recurring_enabled: true,
+                    installment_payment_enabled: true,
+                }]),
+            },
+        ]
+    }
+
+    fn request(
+        amount: i64,
+        currency: api_enums::Currency,
+        country: api_enums::CountryAlpha2,
+    ) -> PaymentMethodsListForSessionRequest {
+        PaymentMethodsListForSessionRequest {
+            amount: MinorUnit::new(amount),
+            currency,
+            country: Some(country),
+            customer_id: None,
+        }
+    }
+
+    #[test]
+    fn test_get_unavailable_reason() {
+        let payment_methods_enabled = payment_methods_enabled();
+
+        assert_eq!(
+            get_unavailable_reason(
+                &request(1000, api_enums::Currency::USD, api_enums::CountryAlpha2::US),
+                &[],
+            ),
+            PaymentMethodsUnavailableReason::NoPaymentMethodsEnabled
+        );
+        assert_eq!(
+            get_unavailable_reason(
+                &request(1000, api_enums::Currency::USD, api_enums::CountryAlpha2::IN),
+                &payment_methods_enabled,
+            ),
+            PaymentMethodsUnavailableReason::UnsupportedCountry
+        );
+        assert_eq!(
+            get_unavailable_reason(
+                &request(1000, api_enums::Currency::INR, api_enums::CountryAlpha2::US),
+                &payment_methods_enabled,
+            ),
+            PaymentMethodsUnavailableReason::UnsupportedCurrency
+        );
+        assert_eq!(
+            get_unavailable_reason(
+                &request(1000, api_enums::Currency::EUR, api_enums::CountryAlpha2::US),
+                &payment_methods_enabled,
+            ),
+            PaymentMethodsUnavailableReason::UnsupportedCombination
+        );
+    }
+}
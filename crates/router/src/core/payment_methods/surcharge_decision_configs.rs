@@ -3,6 +3,10 @@ use api_models::{
     payments, routing,
     surcharge_decision_configs::{self, SurchargeDecisionConfigs, SurchargeDecisionManagerRecord},
 };
+use common_utils::{
+    consts,
+    errors::{CustomResult, PercentageError},
+};
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
@@ -23,8 +27,9 @@ use serde::{Deserialize, Serialize};
 use storage_impl::redis::cache::{self, SURCHARGE_CACHE};
 
 use crate::{
+    configs::settings,
     core::{
-        errors::{self, ConditionalConfigError as ConfigError},
+        errors::{self, ConditionalConfigError as ConfigError, RouterResult},
         payments::{
             conditional_configs::ConditionalConfigResult, routing::make_dsl_input_for_surcharge,
             types,
@@ -71,6 +76,7 @@ impl SurchargeSource {
         &self,
         backend_input: &backend::BackendInput,
         payment_attempt: &storage::PaymentAttempt,
+        surcharge_limits: &settings::SurchargeLimits,
         surcharge_metadata_and_key: (&mut types::SurchargeMetadata, types::SurchargeKey),
     ) -> ConditionalConfigResult<Option<types::SurchargeDetails>> {
         match self {
@@ -79,12 +85,29 @@ impl SurchargeSource {
                     backend_input.clone(),
                     &interpreter.cached_algorithm,
                 )?;
-                Ok(surcharge_output
-                    .surcharge_details
+                // Fall back to the surcharge configured for the payment method type when the
+                // surcharge rules do not yield a surcharge
+                let surcharge_details = surcharge_output.surcharge_details.or_else(|| {
+                    backend_input.payment_method.payment_method_type.and_then(
+                        |payment_method_type| {
+                            interpreter
+                                .merchant_surcharge_configs
+                                .get_payment_method_type_surcharge(payment_method_type)
+                        },
+                    )
+                });
+                let max_surcharge_percentage = surcharge_limits.get_max_surcharge_percentage(
+                    backend_input
+                        .payment
+                        .billing_country
+                        .map(|country| country.to_alpha2()),
+                );
+                Ok(surcharge_details
                     .map(|surcharge_details| {
                         get_surcharge_details_from_surcharge_output(
                             surcharge_details,
                             payment_attempt,
+                            max_surcharge_percentage,
                         )
                     })
                     .transpose()?
@@ -186,6 +209,7 @@ pub async fn perform_surcharge_decision_management_for_payment_method_list(
                         .generate_surcharge_details_and_populate_surcharge_metadata(
                             &backend_input,
                             payment_attempt,
+                            &state.conf.surcharge_limits,
                             (
                                 &mut surcharge_metadata,
                                 types::SurchargeKey::PaymentMethodData(
@@ -211,6 +235,7 @@ pub async fn perform_surcharge_decision_management_for_payment_method_list(
                     .generate_surcharge_details_and_populate_surcharge_metadata(
                         &backend_input,
                         payment_attempt,
+                        &state.conf.surcharge_limits,
                         (
                             &mut surcharge_metadata,
                             types::SurchargeKey::PaymentMethodData(
@@ -275,6 +300,7 @@ pub async fn perform_surcharge_decision_management_for_session_flow(
         surcharge_source.generate_surcharge_details_and_populate_surcharge_metadata(
             &backend_input,
             payment_attempt,
+            &state.conf.surcharge_limits,
             (
                 &mut surcharge_metadata,
                 types::SurchargeKey::PaymentMethodData(
@@ -352,6 +378,7 @@ pub async fn perform_surcharge_decision_management_for_saved_cards(
             .generate_surcharge_details_and_populate_surcharge_metadata(
                 &backend_input,
                 payment_attempt,
+                &state.conf.surcharge_limits,
                 (
                     &mut surcharge_metadata,
                     types::SurchargeKey::Token(payment_token),
@@ -426,6 +453,7 @@ pub async fn perform_surcharge_decision_management_for_saved_cards(
             .generate_surcharge_details_and_populate_surcharge_metadata(
                 &backend_input,
                 payment_attempt,
+                &state.conf.surcharge_limits,
                 (
                     &mut surcharge_metadata,
                     types::SurchargeKey::Token(payment_token),
@@ -445,6 +473,9 @@ pub async fn perform_surcharge_decision_management_for_saved_cards(
 fn get_surcharge_details_from_surcharge_output(
     _surcharge_details: surcharge_decision_configs::SurchargeDetailsOutput,
     _payment_attempt: &storage::PaymentAttempt,
+    _max_surcharge_percentage: Option<
+        &common_utils_types::Percentage<{ consts::SURCHARGE_PERCENTAGE_PRECISION_LENGTH }>,
+    >,
 ) -> ConditionalConfigResult<types::SurchargeDetails> {
     todo!()
 }
@@ -453,6 +484,9 @@ fn get_surcharge_details_from_surcharge_output(
 fn get_surcharge_details_from_surcharge_output(
     surcharge_details: surcharge_decision_configs::SurchargeDetailsOutput,
     payment_attempt: &storage::PaymentAttempt,
+    max_surcharge_percentage: Option<
+        &common_utils_types::Percentage<{ consts::SURCHARGE_PERCENTAGE_PRECISION_LENGTH }>,
+    >,
 ) -> ConditionalConfigResult<types::SurchargeDetails> {
     let surcharge_amount = match surcharge_details.surcharge.clone() {
         surcharge_decision_configs::SurchargeOutput::Fixed { amount } => amount,
@@ -461,6 +495,30 @@ fn get_surcharge_details_from_surcharge_output(
             .change_context(ConfigError::DslExecutionError)
             .attach_printable("Failed to Calculate surcharge amount by applying percentage")?,
     };
+    let mut surcharge = match surcharge_details.surcharge {
+        surcharge_decision_configs::SurchargeOutput::Fixed { amount } => {
+            common_utils_types::Surcharge::Fixed(amount)
+        }
+        surcharge_decision_configs::SurchargeOutput::Rate(percentage) => {
+            common_utils_types::Surcharge::Rate(percentage)
+        }
+    };
+    let max_surcharge_amount =
+        get_max_surcharge_amount(max_surcharge_percentage, payment_attempt.amount)
+            .change_context(ConfigError::DslExecutionError)?;
+    // Surcharges exceeding the limit of the billing country are capped to the limit
+    let surcharge_amount = match max_surcharge_amount {
+        Some(max_surcharge_amount) if surcharge_amount > max_surcharge_amount => {
+            logger::info!(
+                ?surcharge_amount,
+                ?max_surcharge_amount,
+                "Capping the surcharge to the maximum surcharge permitted in the billing country"
+            );
+            surcharge = common_utils_types::Surcharge::Fixed(max_surcharge_amount);
+            max_surcharge_amount
+        }
+        _ => surcharge_amount,
+    };
     let tax_on_surcharge_amount = surcharge_details
         .tax_on_surcharge
         .clone()
@@ -474,14 +532,7 @@ fn get_surcharge_details_from_surcharge_output(
         .unwrap_or_default();
     Ok(types::SurchargeDetails {
         original_amount: payment_attempt.amount,
-        surcharge: match surcharge_details.surcharge {
-            surcharge_decision_configs::SurchargeOutput::Fixed { amount } => {
-                common_utils_types::Surcharge::Fixed(amount)
-            }
-            surcharge_decision_configs::SurchargeOutput::Rate(percentage) => {
-                common_utils_types::Surcharge::Rate(percentage)
-            }
-        },
+        surcharge,
         tax_on_surcharge: surcharge_details.tax_on_surcharge,
         surcharge_amount,
         tax_on_surcharge_amount,
@@ -489,6 +540,47 @@ fn get_surcharge_details_from_surcharge_output(
     })
 }
 
+fn get_max_surcharge_amount(
+    max_surcharge_percentage: Option<
+        &common_utils_types::Percentage<{ consts::SURCHARGE_PERCENTAGE_PRECISION_LENGTH }>,
+    >,
+    amount: common_utils_types::MinorUnit,
+) -> CustomResult<Option<common_utils_types::MinorUnit>, PercentageError> {
+    max_surcharge_percentage
+        .map(|max_surcharge_percentage| max_surcharge_percentage.apply_and_floor_result(amount))
+        .transpose()
+}
+
+/// Validate that the surcharge sent in the payment request does not exceed the maximum surcharge
+/// permitted in the billing country of the payment
+pub fn validate_request_surcharge_limit(
+    surcharge_limits: &settings::SurchargeLimits,
+    billing_country: Option<common_enums::CountryAlpha2>,
+    surcharge_details: &types::SurchargeDetails,
+) -> RouterResult<()> {
+    let max_surcharge_amount = get_max_surcharge_amount(
+        surcharge_limits.get_max_surcharge_percentage(billing_country),
+        surcharge_details.original_amount,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to calculate the maximum surcharge amount")?;
+
+    match (max_surcharge_amount, billing_country) {
+        (Some(max_surcharge_amount), Some(billing_country))
+            if surcharge_details.surcharge_amount > max_surcharge_amount =>
+        {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "surcharge_details.surcharge_amount exceeds the maximum surcharge of {} permitted in {billing_country}",
+                    max_surcharge_amount.get_amount_as_i64()
+                ),
+            }
+            .into())
+        }
+        _ => Ok(()),
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn ensure_algorithm_cached(
     store: &dyn StorageInterface,
@@ -1,16 +1,35 @@
 pub mod access_token;
+pub mod card_hash;
+#[cfg(feature = "v1")]
+pub mod checkout_resume;
 pub mod conditional_configs;
 pub mod connector_integration_v2_impls;
 pub mod customers;
+pub mod cvv_recollection;
+pub mod duplicate_payments;
 pub mod flows;
 pub mod helpers;
+pub mod merchant_order_reference;
 pub mod operations;
+#[cfg(feature = "v1")]
+pub mod receipts;
 #[cfg(feature = "retry")]
 pub mod retry;
+pub mod retry_advice;
 pub mod routing;
+pub mod settlement;
+pub mod statement_descriptor;
+#[cfg(feature = "v1")]
+pub mod timeline;
+pub mod token_expiry;
 pub mod tokenization;
 pub mod transformers;
 pub mod types;
+pub mod velocity_limits;
+#[cfg(feature = "v1")]
+pub mod void_eligibility;
+#[cfg(feature = "v1")]
+pub mod webhook_simulation;
 #[cfg(feature = "olap")]
 use std::collections::HashMap;
 use std::{
@@ -560,6 +579,34 @@ where
                 }
             };
 
+            #[cfg(feature = "v1")]
+            if matches!(
+                payment_data.get_payment_attempt().status,
+                storage_enums::AttemptStatus::Failure
+                    | storage_enums::AttemptStatus::AuthorizationFailed
+            ) {
+                if is_operation_confirm(&operation) {
+                    super::customers::risk_profile::record_customer_risk_event_if_customer_present(
+                        state,
+                        merchant_account.get_id(),
+                        payment_data.get_payment_intent().customer_id.as_ref(),
+                        super::customers::risk_profile::CustomerRiskEvent::PaymentDeclined,
+                    )
+                    .await;
+                }
+
+                // The payment was recorded against the velocity limits before it was sent to the
+                // connector, by the confirm or the create operation
+                if is_operation_confirm(&operation) || is_operation_create(&operation) {
+                    velocity_limits::revert_failed_payment_velocity(
+                        state,
+                        merchant_account.get_id(),
+                        payment_data.get_payment_intent().get_id(),
+                    )
+                    .await;
+                }
+            }
+
             #[cfg(feature = "frm")]
             if let Some(fraud_info) = &mut frm_info {
                 #[cfg(feature = "v1")]
@@ -1647,6 +1694,14 @@ where
     // Validating the blocklist guard and generate the fingerprint
     blocklist_guard(state, merchant_account, key_store, operation, payment_data).await?;
 
+    // Retries of the payment are already counted against the velocity limits of the customer
+    if !is_retry_payment {
+        operation
+            .to_domain()?
+            .guard_payment_against_velocity_limits(state, merchant_account, payment_data)
+            .await?;
+    }
+
     let updated_customer = call_create_connector_customer_if_required(
         state,
         customer,
@@ -1845,6 +1900,19 @@ where
         Ok(router_data)
     }?;
 
+    #[cfg(feature = "v1")]
+    if let (Err(error_response), helpers::MerchantConnectorAccountType::DbVal(mca)) =
+        (&router_data.response, &merchant_connector_account)
+    {
+        // The payment is not failed further when the credentials could not be failed over
+        super::admin::fail_over_connector_credentials(state, key_store, mca, error_response)
+            .await
+            .inspect_err(|error| {
+                logger::error!(?error, "Failed to fail over the connector credentials")
+            })
+            .ok();
+    }
+
     let etime_connector = Instant::now();
     let duration_connector = etime_connector.saturating_duration_since(stime_connector);
     tracing::info!(duration = format!("Duration taken: {}", duration_connector.as_millis()));
@@ -2966,6 +3034,12 @@ where
     pub recurring_details: Option<RecurringDetails>,
     pub poll_config: Option<router_types::PollConfig>,
     pub tax_data: Option<TaxData>,
+    pub cvv_recollection_policy: Option<api_models::payments::CvvRecollectionPolicy>,
+    /// The number of times the last request to the connector was retried after a network error
+    pub connector_network_retry_count: Option<u8>,
+    /// The statement descriptor length policy of the merchant account, according to which the
+    /// statement descriptors were sent to the connector
+    pub statement_descriptor_length_policy: enums::StatementDescriptorLengthPolicy,
 }
 
 #[derive(Clone, serde::Serialize, Debug)]
@@ -3127,6 +3201,10 @@ pub fn is_operation_confirm<Op: Debug>(operation: &Op) -> bool {
     matches!(format!("{operation:?}").as_str(), "PaymentConfirm")
 }
 
+pub fn is_operation_create<Op: Debug>(operation: &Op) -> bool {
+    matches!(format!("{operation:?}").as_str(), "PaymentCreate")
+}
+
 pub fn is_operation_complete_authorize<Op: Debug>(operation: &Op) -> bool {
     matches!(format!("{operation:?}").as_str(), "CompleteAuthorize")
 }
@@ -3410,6 +3488,243 @@ pub async fn get_aggregates_for_payments(
     ))
 }
 
+/// Count the distinct cards used in the payments of the merchant created in the time range, by
+/// the card hashes stored on their attempts
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn get_distinct_card_count_for_payments(
+    state: SessionState,
+    merchant: domain::MerchantAccount,
+    time_range: common_utils::types::TimeRange,
+) -> RouterResponse<api::PaymentsDistinctCardCountResponse> {
+    let distinct_card_count = state
+        .store
+        .get_distinct_card_hash_count_by_merchant_id(
+            merchant.get_id(),
+            time_range,
+            merchant.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count the distinct card hashes of the payments")?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::PaymentsDistinctCardCountResponse {
+            distinct_card_count,
+        },
+    ))
+}
+
+/// Sum the amounts of the captured payments of the merchant which are expected to be settled on
+/// the date, by currency, for the cashflow forecasts of the merchant
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn get_settlement_amounts_for_payments(
+    state: SessionState,
+    merchant: domain::MerchantAccount,
+    req: api::PaymentsSettlementAmountsRequest,
+) -> RouterResponse<api::PaymentsSettlementAmountsResponse> {
+    let settlement_amounts = state
+        .store
+        .get_settlement_amounts_by_currency(
+            merchant.get_id(),
+            req.settlement_date,
+            merchant.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to sum the settlement amounts of the payments")?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::PaymentsSettlementAmountsResponse {
+            settlement_date: req.settlement_date,
+            settlement_amounts: settlement_amounts
+                .into_iter()
+                .map(|(currency, amount)| api::PaymentsSettlementAmount { currency, amount })
+                .collect(),
+        },
+    ))
+}
+
+/// The maximum number of payments listed for a chain of related payments
+#[cfg(all(feature = "olap", feature = "v1"))]
+const MAX_RELATED_PAYMENTS: i64 = 100;
+
+/// List the chain of related payments which the payment belongs to, such as the renewals of a
+/// subscription or the retries of a payment.
+///
+/// Every payment of a chain records the first payment of the chain as its root payment when it is
+/// created, so that the whole chain is found from the root payment of any of its payments.
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn list_related_payments(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    payment_id: id_type::PaymentId,
+) -> RouterResponse<api::RelatedPaymentsResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_account.get_id();
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            &payment_id,
+            merchant_id,
+            &key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    utils::validate_profile_id_from_auth_layer(profile_id.clone(), &payment_intent)?;
+
+    let root_payment_id = payment_intent
+        .root_payment_id
+        .clone()
+        .unwrap_or_else(|| payment_intent.get_id().to_owned());
+    let mut related_payment_intents = db
+        .find_payment_intents_by_root_payment_id_merchant_id(
+            key_manager_state,
+            &root_payment_id,
+            merchant_id,
+            &key_store,
+            storage_scheme,
+            MAX_RELATED_PAYMENTS,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the related payments")?;
+
+    // The payments of a chain are linked within a profile, the payments of other profiles are not
+    // listed for a profile
+    if let Some(profile_id) = profile_id.as_ref() {
+        related_payment_intents.retain(|related_payment_intent| {
+            related_payment_intent.profile_id.as_ref() == Some(profile_id)
+        });
+    }
+
+    related_payment_intents.sort_by_key(|related_payment_intent| related_payment_intent.created_at);
+
+    Ok(services::ApplicationResponse::Json(
+        api::RelatedPaymentsResponse {
+            payment_id,
+            related_payments: related_payment_intents
+                .into_iter()
+                .map(|related_payment_intent| api::RelatedPayment {
+                    payment_id: related_payment_intent.payment_id,
+                    parent_payment_id: related_payment_intent.parent_payment_id,
+                    status: related_payment_intent.status,
+                    amount: related_payment_intent.amount,
+                    currency: related_payment_intent.currency,
+                    created_at: related_payment_intent.created_at,
+                })
+                .collect(),
+        },
+    ))
+}
+
+/// Update the fulfillment status of the order of a payment, as reported by the merchant.
+///
+/// The fulfillment status is tracked separately from the status of the payment, and only moves
+/// forward from shipped to delivered.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn payments_fulfillment_update(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    req: api::PaymentsFulfillmentRequest,
+) -> RouterResponse<api::PaymentsFulfillmentResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            &req.payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    utils::validate_profile_id_from_auth_layer(profile_id, &payment_intent)?;
+
+    if !req
+        .fulfillment_status
+        .is_valid_transition_from(payment_intent.fulfillment_status)
+    {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "The fulfillment status of the payment cannot be changed from {} to {}",
+                payment_intent
+                    .fulfillment_status
+                    .map(|fulfillment_status| fulfillment_status.to_string())
+                    .unwrap_or_else(|| "unfulfilled".to_string()),
+                req.fulfillment_status
+            ),
+        }
+        .into());
+    }
+
+    let now = common_utils::date_time::now();
+    let fulfilled_at = req.fulfilled_at.unwrap_or(now);
+    common_utils::fp_utils::when(fulfilled_at > now, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "fulfilled_at cannot be in the future".to_string(),
+        })
+    })?;
+
+    let (shipped_at, delivered_at) = match req.fulfillment_status {
+        storage_enums::FulfillmentStatus::Shipped => (Some(fulfilled_at), None),
+        storage_enums::FulfillmentStatus::Delivered => {
+            common_utils::fp_utils::when(
+                payment_intent
+                    .shipped_at
+                    .is_some_and(|shipped_at| fulfilled_at < shipped_at),
+                || {
+                    Err(errors::ApiErrorResponse::InvalidRequestData {
+                        message:
+                            "fulfilled_at cannot be before the time at which the order was shipped"
+                                .to_string(),
+                    })
+                },
+            )?;
+            (None, Some(fulfilled_at))
+        }
+    };
+
+    let payment_intent_update = storage::PaymentIntentUpdate::FulfillmentUpdate {
+        fulfillment_status: req.fulfillment_status,
+        shipped_at,
+        delivered_at,
+        updated_by: storage_scheme.to_string(),
+    };
+    let updated_payment_intent = db
+        .update_payment_intent(
+            key_manager_state,
+            payment_intent,
+            payment_intent_update,
+            &key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+        .attach_printable("Failed to update the fulfillment status of the payment intent")?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::PaymentsFulfillmentResponse {
+            payment_id: updated_payment_intent.payment_id,
+            fulfillment_status: req.fulfillment_status,
+            shipped_at: updated_payment_intent.shipped_at,
+            delivered_at: updated_payment_intent.delivered_at,
+        },
+    ))
+}
+
 pub async fn add_process_sync_task(
     db: &dyn StorageInterface,
     payment_attempt: &storage::PaymentAttempt,
@@ -3555,6 +3870,19 @@ where
                 )
                 .await?
             }
+
+            api::ConnectorChoice::Override(connector_override) => {
+                connector_override_selection(
+                    state,
+                    business_profile,
+                    key_store,
+                    payment_data,
+                    connector_override,
+                    eligible_connectors,
+                    mandate_type,
+                )
+                .await?
+            }
         })
     } else if let api::ConnectorChoice::StraightThrough(algorithm) = connector_choice {
         update_straight_through_routing(payment_data, algorithm)
@@ -3562,6 +3890,11 @@ where
             .attach_printable("Failed to update straight through routing algorithm")?;
 
         None
+    } else if let api::ConnectorChoice::Override(_) = connector_choice {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`connector_override` can only be provided when the payment is confirmed"
+                .to_string(),
+        })?
     } else {
         None
     };
@@ -3641,6 +3974,102 @@ where
     Ok(decided_connector)
 }
 
+/// Use the connector forced in the request instead of routing the payment, after validating that
+/// the connector is configured for the profile of the payment and eligible for the payment
+#[cfg(feature = "v1")]
+pub async fn connector_override_selection<F, D>(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    key_store: &domain::MerchantKeyStore,
+    payment_data: &mut D,
+    connector_override: api::routing::RoutableConnectorChoice,
+    eligible_connectors: Option<Vec<enums::RoutableConnectors>>,
+    mandate_type: Option<api::MandateTransactionType>,
+) -> RouterResult<ConnectorCallType>
+where
+    F: Send + Clone,
+    D: OperationSessionGetters<F> + OperationSessionSetters<F> + Send + Sync + Clone,
+{
+    let connector_override = helpers::validate_connector_override(
+        state,
+        key_store,
+        business_profile,
+        connector_override,
+    )
+    .await?;
+
+    let transaction_data = core_routing::PaymentsDslInput::new(
+        payment_data.get_setup_mandate(),
+        payment_data.get_payment_attempt(),
+        payment_data.get_payment_intent(),
+        payment_data.get_payment_method_data(),
+        payment_data.get_address(),
+        payment_data.get_recurring_details(),
+        payment_data.get_currency(),
+    );
+    let eligible_connector_choices = routing::perform_eligibility_analysis(
+        state,
+        key_store,
+        vec![connector_override.clone()],
+        &TransactionData::Payment(transaction_data),
+        eligible_connectors.as_ref(),
+        business_profile.get_id(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("failed eligibility analysis of the connector override")?;
+
+    common_utils::fp_utils::when(eligible_connector_choices.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The connector `{}` is not eligible for this payment",
+                connector_override.connector
+            ),
+        })
+    })?;
+
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &connector_override.connector.to_string(),
+        api::GetToken::Connector,
+        connector_override.merchant_connector_id.clone(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Invalid connector name received in the connector override")?;
+
+    let mut routing_data = storage::RoutingData {
+        routed_through: None,
+        merchant_connector_id: None,
+        algorithm: None,
+        routing_info: storage::PaymentRoutingInfo {
+            algorithm: None,
+            pre_routing_results: None,
+        },
+    };
+
+    let decided_connector = decide_multiplex_connector_for_normal_or_recurring_payment(
+        state,
+        payment_data,
+        &mut routing_data,
+        vec![connector_data],
+        mandate_type,
+        business_profile.is_connector_agnostic_mit_enabled,
+        business_profile.is_network_tokenization_enabled,
+    )
+    .await?;
+
+    logger::info!(
+        connector = %connector_override.connector,
+        "Using the connector override instead of routing the payment"
+    );
+
+    payment_data.set_connector_in_payment_attempt(routing_data.routed_through);
+    payment_data.set_merchant_connector_id_in_attempt(routing_data.merchant_connector_id);
+    payment_data.set_connector_override_in_payment_attempt(Some(true));
+
+    Ok(decided_connector)
+}
+
 #[allow(clippy::too_many_arguments)]
 #[cfg(feature = "v2")]
 pub async fn decide_connector<F, D>(
@@ -3815,6 +4244,7 @@ where
         let (mut connectors, check_eligibility) = routing::perform_straight_through_routing(
             &routing_algorithm,
             payment_data.get_creds_identifier(),
+            Some(payment_data.get_payment_intent().get_id().get_string_repr()),
         )
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed execution of straight through routing")?;
@@ -3873,6 +4303,7 @@ where
         let (mut connectors, check_eligibility) = routing::perform_straight_through_routing(
             routing_algorithm,
             payment_data.get_creds_identifier(),
+            Some(payment_data.get_payment_intent().get_id().get_string_repr()),
         )
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed execution of straight through routing")?;
@@ -4568,7 +4999,7 @@ where
         algorithm_ref.algorithm_id
     };
 
-    let connectors = routing::perform_static_routing_v1(
+    let (connectors, is_routing_rule_matched) = routing::perform_static_routing_with_rule_match_v1(
         state,
         merchant_account.get_id(),
         routing_algorithm_id.as_ref(),
@@ -4578,11 +5009,45 @@ where
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)?;
 
+    // The default connector of the merchant applies when the connectors were not chosen by the
+    // routing configured by the merchant, either because no routing algorithm is active or because
+    // no rule of the active advanced routing algorithm matched. It is then kept ahead of the
+    // connectors ordered by least cost routing. The connectors chosen by the routing of the merchant
+    // are all ordered by least cost routing, the order of the routing breaking the ties.
+    let ranked_connectors = if is_routing_rule_matched {
+        Vec::new()
+    } else {
+        routing::get_default_connector_for_payment_method_type(
+            merchant_account,
+            transaction_data.payment_attempt.payment_method_type,
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .and_then(|default_connector| {
+            connectors
+                .iter()
+                .find(|choice| choice.connector == default_connector)
+                .cloned()
+        })
+        .into_iter()
+        .collect()
+    };
+
+    let connectors = if !is_routing_rule_matched {
+        routing::prioritize_default_connector_for_payment_method_type(
+            merchant_account,
+            transaction_data.payment_attempt.payment_method_type,
+            connectors,
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+    } else {
+        connectors
+    };
+
     let connectors = routing::perform_eligibility_analysis_with_fallback(
         &state.clone(),
         key_store,
         connectors,
-        &TransactionData::Payment(transaction_data),
+        &TransactionData::Payment(transaction_data.clone()),
         eligible_connectors,
         business_profile,
     )
@@ -4590,6 +5055,49 @@ where
     .change_context(errors::ApiErrorResponse::InternalServerError)
     .attach_printable("failed eligibility analysis and fallback")?;
 
+    let eligible_connectors_count = connectors.len();
+    let connectors = routing::filter_connectors_by_amount_limits(
+        transaction_data.payment_attempt.get_total_amount(),
+        transaction_data.currency,
+        connectors,
+    );
+    if connectors.is_empty() && eligible_connectors_count > 0 {
+        return Err(errors::ApiErrorResponse::NotSupported {
+            message: "The amount of the payment is not supported by any of the eligible connectors"
+                .to_string(),
+        }
+        .into());
+    }
+
+    let connectors = routing::filter_connectors_in_maintenance(state, key_store, connectors)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to filter the connectors in maintenance")?;
+
+    let connectors = routing::sort_connectors_by_cost(
+        merchant_account,
+        &transaction_data,
+        &ranked_connectors,
+        connectors,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("failed to order the connectors by their estimated cost")?;
+
+    let eligible_connectors_count = connectors.len();
+    let connectors = routing::filter_connectors_by_mandate_support(
+        &state.conf.connectors,
+        routing::is_new_mandate_setup(&transaction_data, payment_data.get_mandate_id()),
+        transaction_data.payment_attempt.payment_method_type,
+        transaction_data.payment_method_data,
+        connectors,
+    );
+    if connectors.is_empty() && eligible_connectors_count > 0 {
+        return Err(errors::ApiErrorResponse::NotSupported {
+            message: "Mandates are not supported by any of the eligible connectors".to_string(),
+        }
+        .into());
+    }
+
     let connector_data = connectors
         .into_iter()
         .map(|conn| {
@@ -5082,6 +5590,114 @@ pub async fn payments_manual_update(
     ))
 }
 
+/// Void a capture of a payment with multiple partial captures, which reverses the capture through a
+/// refund of its amount at the connector. The refund id is derived from the capture, so that a
+/// capture is reversed at most once.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn payments_void_capture(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payments_api::PaymentsCaptureVoidRequest,
+) -> RouterResponse<payments_api::CaptureResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            &req.payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            merchant_account.get_id(),
+            payment_intent.active_attempt.get_id().as_str(),
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let capture = db
+        .find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+            merchant_account.get_id(),
+            &payment_intent.payment_id,
+            &payment_attempt.attempt_id,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while fetching the captures of the payment")?
+        .into_iter()
+        .find(|capture| capture.capture_id == req.capture_id)
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::GenericNotFoundError {
+                message: format!("Capture with id {} does not exist", req.capture_id),
+            })
+        })?;
+
+    helpers::validate_capture_can_be_voided(
+        &capture,
+        payment_attempt.expected_settlement_date,
+        common_utils::date_time::now().date(),
+    )?;
+
+    let refund_request = api_models::refunds::RefundRequest {
+        payment_id: payment_intent.payment_id.clone(),
+        refund_id: Some(format!("{}_reversal", capture.capture_id)),
+        merchant_id: Some(merchant_account.get_id().to_owned()),
+        amount: Some(capture.amount),
+        reason: Some(format!("Reversal of the capture {}", capture.capture_id)),
+        refund_type: Some(api_models::refunds::RefundType::Instant),
+        ..Default::default()
+    };
+    let refund = match Box::pin(super::refunds::refund_create_core(
+        state.clone(),
+        merchant_account,
+        None,
+        key_store,
+        refund_request,
+    ))
+    .await?
+    {
+        services::ApplicationResponse::Json(refund) => refund,
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response while reversing the capture")?,
+    };
+    common_utils::fp_utils::when(
+        refund.status == api_models::refunds::RefundStatus::Failed,
+        || {
+            Err(report!(errors::ApiErrorResponse::RefundFailed {
+                data: None
+            }))
+            .attach_printable("The refund reversing the capture failed")
+        },
+    )?;
+
+    let updated_capture = state
+        .store
+        .update_capture_with_capture_id(
+            capture,
+            storage::CaptureUpdate::ReversalUpdate,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error while updating the reversed capture")?;
+
+    Ok(services::ApplicationResponse::Json(
+        payments_api::CaptureResponse::foreign_from(updated_capture),
+    ))
+}
+
 pub trait OperationSessionGetters<F> {
     fn get_payment_attempt(&self) -> &storage::PaymentAttempt;
     fn get_payment_intent(&self) -> &storage::PaymentIntent;
@@ -5095,6 +5711,8 @@ pub trait OperationSessionGetters<F> {
     fn get_ephemeral_key(&self) -> Option<ephemeral_key::EphemeralKey>;
     fn get_setup_mandate(&self) -> Option<&MandateData>;
     fn get_poll_config(&self) -> Option<router_types::PollConfig>;
+    fn get_connector_network_retry_count(&self) -> Option<u8>;
+    fn get_statement_descriptor_length_policy(&self) -> enums::StatementDescriptorLengthPolicy;
     fn get_authentication(&self) -> Option<&storage::Authentication>;
     fn get_frm_message(&self) -> Option<FraudCheck>;
     fn get_refunds(&self) -> Vec<storage::Refund>;
@@ -5156,6 +5774,7 @@ pub trait OperationSessionSetters<F> {
         straight_through_algorithm: serde_json::Value,
     );
     fn set_connector_in_payment_attempt(&mut self, connector: Option<String>);
+    fn set_connector_override_in_payment_attempt(&mut self, is_connector_override: Option<bool>);
 }
 
 impl<F: Clone> OperationSessionGetters<F> for PaymentData<F> {
@@ -5208,6 +5827,14 @@ impl<F: Clone> OperationSessionGetters<F> for PaymentData<F> {
         self.poll_config.clone()
     }
 
+    fn get_connector_network_retry_count(&self) -> Option<u8> {
+        self.connector_network_retry_count
+    }
+
+    fn get_statement_descriptor_length_policy(&self) -> enums::StatementDescriptorLengthPolicy {
+        self.statement_descriptor_length_policy
+    }
+
     fn get_authentication(&self) -> Option<&storage::Authentication> {
         self.authentication.as_ref()
     }
@@ -5388,6 +6015,10 @@ impl<F: Clone> OperationSessionSetters<F> for PaymentData<F> {
     fn set_connector_in_payment_attempt(&mut self, connector: Option<String>) {
         self.payment_attempt.connector = connector;
     }
+
+    fn set_connector_override_in_payment_attempt(&mut self, is_connector_override: Option<bool>) {
+        self.payment_attempt.is_connector_override = is_connector_override;
+    }
 }
 
 #[cfg(feature = "v2")]
@@ -5441,6 +6072,14 @@ impl<F: Clone> OperationSessionGetters<F> for PaymentIntentData<F> {
         todo!()
     }
 
+    fn get_connector_network_retry_count(&self) -> Option<u8> {
+        None
+    }
+
+    fn get_statement_descriptor_length_policy(&self) -> enums::StatementDescriptorLengthPolicy {
+        enums::StatementDescriptorLengthPolicy::default()
+    }
+
     fn get_authentication(&self) -> Option<&storage::Authentication> {
         todo!()
     }
@@ -5597,4 +6236,8 @@ impl<F: Clone> OperationSessionSetters<F> for PaymentIntentData<F> {
     fn set_connector_in_payment_attempt(&mut self, _connector: Option<String>) {
         todo!()
     }
+
+    fn set_connector_override_in_payment_attempt(&mut self, _is_connector_override: Option<bool>) {
+        todo!()
+    }
 }
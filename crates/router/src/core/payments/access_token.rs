@@ -230,6 +230,7 @@ pub async fn refresh_connector_auth(
                     status_code: 504,
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 };
 
                 Ok(Err(error_response))
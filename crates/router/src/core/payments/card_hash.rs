@@ -0,0 +1,71 @@
+//! Hashes of the card numbers used in payments, for analytics.
+//!
+//! The hash of a card is stored on the payment attempt, so that the payments made with the same
+//! card can be counted and grouped without access to the card number. The hash is a keyed HMAC of
+//! the card number, scoped to the merchant, with the salt from the `card_hash` settings, so it
+//! cannot be reversed or correlated across merchants. It is deliberately separate from the card
+//! fingerprint, which uses a secret of the merchant and is used for deduplication and blocklists.
+
+use cards::CardNumber;
+use common_utils::{
+    crypto::{HmacSha256, SignMessage},
+    id_type,
+};
+use error_stack::ResultExt;
+use masking::PeekInterface;
+
+use crate::{
+    core::errors::{self, RouterResult},
+    routes::SessionState,
+    types::{api, domain},
+};
+
+/// Generate the hash of the card number for the merchant
+pub fn generate_card_hash(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    card_number: &CardNumber,
+) -> RouterResult<String> {
+    let message = format!(
+        "{}:{}",
+        merchant_id.get_string_repr(),
+        card_number.get_card_no()
+    );
+    let card_hash = HmacSha256
+        .sign_message(
+            state.conf.card_hash.get_inner().salt.peek().as_bytes(),
+            message.as_bytes(),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate the card hash")?;
+
+    Ok(hex::encode(card_hash))
+}
+
+/// Generate the hash of the card, if the payment method data is a card
+pub fn get_card_hash_from_payment_method_data(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_method_data: Option<&domain::PaymentMethodData>,
+) -> RouterResult<Option<String>> {
+    match payment_method_data {
+        Some(domain::PaymentMethodData::Card(card)) => {
+            generate_card_hash(state, merchant_id, &card.card_number).map(Some)
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Generate the hash of the card, if the payment method data in the request is a card
+pub fn get_card_hash_from_request(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_method_data: Option<&api::PaymentMethodData>,
+) -> RouterResult<Option<String>> {
+    match payment_method_data {
+        Some(api::PaymentMethodData::Card(card)) => {
+            generate_card_hash(state, merchant_id, &card.card_number).map(Some)
+        }
+        _ => Ok(None),
+    }
+}
@@ -0,0 +1,332 @@
+//! Resumption of the payments which the customer left while the payments required their action.
+//!
+//! When a payment requires the action of the customer, such as a redirection to authenticate the
+//! payment, a resume token is returned along with the next action. The token is registered through
+//! a reverse lookup to the payment attempt which requires the action, so that a customer who
+//! abandoned the action and comes back continues the same payment instead of starting a new one.
+//! The token can be used until the client secret of the payment expires, along with the grace period
+//! of the customer who is in the middle of the action, and only while the attempt it was issued for is
+//! the active attempt of the payment.
+
+use api_models::payments::{PaymentsResponse, PaymentsResumeRequest};
+use common_utils::{fp_utils, id_type};
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    configs::settings::TokenExpiry,
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::{self, token_expiry, CallConnectorAction},
+    },
+    routes::{app::ReqState, SessionState},
+    services,
+    types::{api, domain, storage},
+};
+
+const RESUME_TOKEN_PREFIX: &str = "rsm";
+
+fn get_resume_token_lookup_id(resume_token: &str) -> String {
+    format!("payment_resume_{resume_token}")
+}
+
+/// The resume token of the payment attempt, if the payment requires the action of the customer.
+/// The token is derived from the client secret of the payment, so that only the holder of the
+/// client secret can know it.
+pub fn get_resume_token(
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) -> Option<Secret<String>> {
+    if payment_intent.status != storage::enums::IntentStatus::RequiresCustomerAction {
+        return None;
+    }
+
+    payment_intent
+        .client_secret
+        .as_deref()
+        .map(|client_secret| derive_resume_token(client_secret, &payment_attempt.attempt_id))
+}
+
+fn derive_resume_token(client_secret: &str, attempt_id: &str) -> Secret<String> {
+    let digest = ring::digest::digest(
+        &ring::digest::SHA256,
+        format!("{client_secret}_{attempt_id}").as_bytes(),
+    );
+    Secret::new(format!("{RESUME_TOKEN_PREFIX}_{}", hex::encode(digest)))
+}
+
+/// Check that the resume token has not expired, which it does along with the session of the
+/// payment, allowing the grace period of the customer who is in the middle of the action
+fn validate_resume_token_expiry(
+    config: &TokenExpiry,
+    session_expiry: PrimitiveDateTime,
+    status: storage::enums::IntentStatus,
+) -> RouterResult<()> {
+    let expiry_status = token_expiry::get_token_expiry_status(config, session_expiry, status);
+    fp_utils::when(!expiry_status.is_resolvable(), || {
+        Err(report!(errors::ApiErrorResponse::TokenExpired {
+            token: "resume token"
+        }))
+    })
+}
+
+/// Whether the resume token of the payment attempt has to be registered, which is the case once
+/// the payment comes to require the action of the customer
+fn is_resume_token_registration_required(
+    previous_status: storage::enums::IntentStatus,
+    status: storage::enums::IntentStatus,
+) -> bool {
+    previous_status != storage::enums::IntentStatus::RequiresCustomerAction
+        && status == storage::enums::IntentStatus::RequiresCustomerAction
+}
+
+/// Register the resume token of the payment attempt once the payment comes to require the action
+/// of the customer, before the token is first returned. The payment is updated with the response
+/// of the connector before the token is registered.
+#[instrument(skip_all)]
+pub(crate) async fn register_resume_token(
+    state: &SessionState,
+    previous_status: storage::enums::IntentStatus,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    storage_scheme: storage::enums::MerchantStorageScheme,
+) -> RouterResult<()> {
+    if !is_resume_token_registration_required(previous_status, payment_intent.status) {
+        return Ok(());
+    }
+    let Some(resume_token) = get_resume_token(payment_intent, payment_attempt) else {
+        return Ok(());
+    };
+
+    match state
+        .store
+        .insert_namespaced_reverse_lookup(
+            storage::ReverseLookupNew {
+                lookup_id: get_resume_token_lookup_id(resume_token.peek()),
+                pk_id: payment_attempt.payment_id.get_string_repr().to_owned(),
+                sk_id: payment_attempt.attempt_id.clone(),
+                source: "payment_attempt".to_string(),
+                updated_by: storage_scheme.to_string(),
+            },
+            &payment_attempt.merchant_id,
+            storage_scheme,
+        )
+        .await
+    {
+        Ok(_) => Ok(()),
+        // A concurrent update of the payment registered the token first
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert reverse lookup for the resume token"),
+    }
+}
+
+/// Resume the payment of the resume token, returning the payment in its current state along with
+/// the next action to continue it. The payment is synced with the connector once the token is
+/// found to be unexpired, so that a payment which completed while the customer was away is
+/// returned as completed.
+#[instrument(skip_all)]
+pub async fn resume_payment(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: PaymentsResumeRequest,
+) -> RouterResponse<PaymentsResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_account.get_id().to_owned();
+
+    let lookup_id = get_resume_token_lookup_id(req.resume_token.peek());
+    let lookup = db
+        .get_namespaced_lookup_by_lookup_id(
+            &lookup_id,
+            &merchant_id,
+            Some(&lookup_id),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_id = id_type::PaymentId::wrap(lookup.pk_id)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid payment id in the resume token lookup")?;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &payment_id,
+            &merchant_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    fp_utils::when(
+        payment_intent.active_attempt.get_id() != lookup.sk_id,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The payment was attempted again and can no longer be resumed with this resume token"
+                .to_string(),
+        }))
+        },
+    )?;
+
+    if payment_intent.status == storage::enums::IntentStatus::RequiresCustomerAction {
+        let session_expiry = payment_intent.session_expiry.unwrap_or(
+            payment_intent
+                .created_at
+                .saturating_add(time::Duration::seconds(consts::DEFAULT_SESSION_EXPIRY)),
+        );
+        validate_resume_token_expiry(
+            &state.conf.token_expiry,
+            session_expiry,
+            payment_intent.status,
+        )?;
+    }
+
+    let response = payments::payments_core::<
+        api::PSync,
+        PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::PSync>,
+    >(
+        state,
+        req_state,
+        merchant_account,
+        None,
+        key_store,
+        payments::PaymentStatus,
+        api::PaymentsRetrieveRequest {
+            resource_id: api::PaymentIdType::PaymentIntentId(payment_id),
+            merchant_id: Some(merchant_id),
+            force_sync: true,
+            ..Default::default()
+        },
+        services::AuthFlow::Client,
+        CallConnectorAction::Trigger,
+        None,
+        api::HeaderPayload::default(),
+    )
+    .await?;
+
+    let status = match &response {
+        services::ApplicationResponse::Json(payment)
+        | services::ApplicationResponse::JsonWithHeaders((payment, _)) => payment.status,
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response while syncing the payment to be resumed")?,
+    };
+
+    match status {
+        // The payment has to be confirmed again, which is not a resumption of the checkout
+        storage::enums::IntentStatus::RequiresPaymentMethod
+        | storage::enums::IntentStatus::RequiresConfirmation => {
+            return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!("The payment can no longer be resumed as its status is {status}"),
+            }));
+        }
+        // The payment still requires the action of the customer, or completed or proceeded while
+        // the customer was away, and is returned in its current state
+        storage::enums::IntentStatus::Succeeded
+        | storage::enums::IntentStatus::Failed
+        | storage::enums::IntentStatus::Cancelled
+        | storage::enums::IntentStatus::Processing
+        | storage::enums::IntentStatus::RequiresMerchantAction
+        | storage::enums::IntentStatus::RequiresCapture
+        | storage::enums::IntentStatus::PartiallyCaptured
+        | storage::enums::IntentStatus::PartiallyCapturedAndCapturable
+        | storage::enums::IntentStatus::RequiresCustomerAction => {}
+    }
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::date_time;
+
+    use super::*;
+
+    #[test]
+    fn test_resume_token_is_derived_per_attempt() {
+        let resume_token = derive_resume_token("pay_1_secret_1", "pay_1_1");
+
+        assert!(resume_token
+            .peek()
+            .starts_with(&format!("{RESUME_TOKEN_PREFIX}_")));
+        assert_eq!(
+            resume_token.peek(),
+            derive_resume_token("pay_1_secret_1", "pay_1_1").peek()
+        );
+        assert_ne!(
+            resume_token.peek(),
+            derive_resume_token("pay_1_secret_1", "pay_1_2").peek()
+        );
+        assert_ne!(
+            resume_token.peek(),
+            derive_resume_token("pay_1_secret_2", "pay_1_1").peek()
+        );
+    }
+
+    #[test]
+    fn test_resume_token_is_registered_once_customer_action_is_required() {
+        assert!(is_resume_token_registration_required(
+            storage::enums::IntentStatus::RequiresConfirmation,
+            storage::enums::IntentStatus::RequiresCustomerAction,
+        ));
+        assert!(is_resume_token_registration_required(
+            storage::enums::IntentStatus::Processing,
+            storage::enums::IntentStatus::RequiresCustomerAction,
+        ));
+        // The token was registered when the payment first came to require the action
+        assert!(!is_resume_token_registration_required(
+            storage::enums::IntentStatus::RequiresCustomerAction,
+            storage::enums::IntentStatus::RequiresCustomerAction,
+        ));
+        assert!(!is_resume_token_registration_required(
+            storage::enums::IntentStatus::RequiresCustomerAction,
+            storage::enums::IntentStatus::Succeeded,
+        ));
+        assert!(!is_resume_token_registration_required(
+            storage::enums::IntentStatus::RequiresConfirmation,
+            storage::enums::IntentStatus::Failed,
+        ));
+    }
+
+    #[test]
+    fn test_resume_token_expiry() {
+        let config = TokenExpiry {
+            max_session_expiry_in_secs: 3600,
+            grace_period_in_secs: 300,
+            receipt_token_expiry_in_secs: 3600,
+        };
+        let now = date_time::now();
+
+        assert!(validate_resume_token_expiry(
+            &config,
+            now.saturating_add(time::Duration::minutes(1)),
+            storage::enums::IntentStatus::RequiresCustomerAction,
+        )
+        .is_ok());
+        // The customer in the middle of the action may resume within the grace period
+        assert!(validate_resume_token_expiry(
+            &config,
+            now.saturating_sub(time::Duration::minutes(1)),
+            storage::enums::IntentStatus::RequiresCustomerAction,
+        )
+        .is_ok());
+        assert!(validate_resume_token_expiry(
+            &config,
+            now.saturating_sub(time::Duration::minutes(10)),
+            storage::enums::IntentStatus::RequiresCustomerAction,
+        )
+        .is_err());
+    }
+}
@@ -166,6 +166,16 @@ mod dummy_connector_default_impl {
     {
     }
 
+    impl<const T: u8>
+        services::ConnectorIntegrationV2<
+            api::UpdateConnectorCustomer,
+            types::PaymentFlowData,
+            types::ConnectorCustomerUpdateData,
+            types::PaymentsResponseData,
+        > for connector::DummyConnector<T>
+    {
+    }
+
     impl<const T: u8>
         services::ConnectorIntegrationV2<
             api::PreProcessing,
@@ -634,6 +644,13 @@ macro_rules! default_imp_for_new_connector_integration_payment {
                 types::ConnectorCustomerData,
                 types::PaymentsResponseData,
             > for $path::$connector{}
+            impl
+            services::ConnectorIntegrationV2<
+            api::UpdateConnectorCustomer,
+            types::PaymentFlowData,
+                types::ConnectorCustomerUpdateData,
+                types::PaymentsResponseData,
+            > for $path::$connector{}
             impl services::ConnectorIntegrationV2<
             api::PreProcessing,
             types::PaymentFlowData,
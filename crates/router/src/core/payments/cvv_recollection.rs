@@ -0,0 +1,326 @@
+//! Recollection of the CVV of stored cards.
+//!
+//! The CVV of a card is never stored, so a payment with a stored card is made without it by
+//! default. A merchant can require the CVV to be collected again from the customer for payments of
+//! high value, or for the first payment with the stored card in a session, either through a policy
+//! configured for the merchant or through the policy passed on the payment. A session starts
+//! whenever the CVV of the stored card is collected. The CVV is never required for merchant
+//! initiated payments, as the customer is not present to provide it.
+
+use std::collections::HashSet;
+
+use api_models::payments::CvvRecollectionPolicy;
+use common_utils::{id_type, types::MinorUnit};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, metrics::add_attributes, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        metrics as core_metrics,
+    },
+    routes::{metrics, SessionState},
+    services,
+};
+
+fn get_cvv_session_key(merchant_id: &id_type::MerchantId, payment_method_id: &str) -> String {
+    format!(
+        "cvv_session_{}_{payment_method_id}",
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Get the policy passed on the payment request. The policy is honored only when passed by the
+/// merchant, so that the client cannot relax the policy configured for the merchant.
+pub fn get_request_cvv_recollection_policy(
+    request_policy: Option<&CvvRecollectionPolicy>,
+    auth_flow: services::AuthFlow,
+) -> Option<CvvRecollectionPolicy> {
+    matches!(auth_flow, services::AuthFlow::Merchant)
+        .then(|| request_policy.cloned())
+        .flatten()
+}
+
+/// Get the policy applicable to the payment, which is the policy passed on the payment if any, or
+/// the policy configured for the merchant, stored as a config with the key
+/// `cvv_recollection_policy_{merchant_id}`
+#[instrument(skip_all)]
+pub async fn get_cvv_recollection_policy(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    request_policy: Option<&CvvRecollectionPolicy>,
+) -> RouterResult<CvvRecollectionPolicy> {
+    if let Some(request_policy) = request_policy {
+        return Ok(request_policy.clone());
+    }
+
+    let config = match state
+        .store
+        .find_config_by_key(&merchant_id.get_cvv_recollection_policy_key())
+        .await
+    {
+        Ok(config) => config,
+        Err(error) if error.current_context().is_db_not_found() => {
+            return Ok(CvvRecollectionPolicy::default())
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find the CVV recollection policy")
+        }
+    };
+
+    serde_json::from_str::<CvvRecollectionPolicy>(&config.config)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the CVV recollection policy")
+}
+
+/// Check whether the CVV is to be collected for a payment of the amount and currency without
+/// looking up the session of the stored card. Returns `None` when it depends on whether a session
+/// of the stored card is active.
+fn is_cvv_required_without_session(
+    policy: &CvvRecollectionPolicy,
+    amount: MinorUnit,
+    currency: Option<common_enums::Currency>,
+) -> Option<bool> {
+    if policy
+        .amount_thresholds
+        .as_ref()
+        .zip(currency)
+        .and_then(|(amount_thresholds, currency)| amount_thresholds.get(&currency))
+        .is_some_and(|amount_threshold| amount >= *amount_threshold)
+    {
+        return Some(true);
+    }
+
+    policy
+        .session_duration_in_seconds
+        .is_none()
+        .then_some(false)
+}
+
+/// Check whether the CVV of the stored card is to be collected for a payment of the amount and
+/// currency
+#[instrument(skip_all)]
+pub async fn is_cvv_recollection_required(
+    state: &SessionState,
+    policy: &CvvRecollectionPolicy,
+    merchant_id: &id_type::MerchantId,
+    payment_method_id: &str,
+    amount: MinorUnit,
+    currency: Option<common_enums::Currency>,
+) -> RouterResult<bool> {
+    if let Some(is_required) = is_cvv_required_without_session(policy, amount, currency) {
+        return Ok(is_required);
+    }
+
+    let is_session_active = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .exists::<()>(&get_cvv_session_key(merchant_id, payment_method_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to check whether the CVV session is active")?;
+
+    Ok(!is_session_active)
+}
+
+/// Get the stored cards whose CVV is to be collected for a payment of the amount and currency, out
+/// of the stored cards listed for the payment. The sessions of all the stored cards are looked up
+/// together, instead of a lookup for each stored card.
+#[instrument(skip_all)]
+pub async fn get_payment_methods_requiring_cvv_recollection(
+    state: &SessionState,
+    policy: &CvvRecollectionPolicy,
+    merchant_id: &id_type::MerchantId,
+    payment_method_ids: &[&str],
+    amount: MinorUnit,
+    currency: Option<common_enums::Currency>,
+) -> RouterResult<HashSet<String>> {
+    match is_cvv_required_without_session(policy, amount, currency) {
+        Some(true) => {
+            return Ok(payment_method_ids
+                .iter()
+                .map(|payment_method_id| (*payment_method_id).to_owned())
+                .collect())
+        }
+        Some(false) => return Ok(HashSet::new()),
+        None if payment_method_ids.is_empty() => return Ok(HashSet::new()),
+        None => {}
+    }
+
+    let session_keys = payment_method_ids
+        .iter()
+        .map(|payment_method_id| get_cvv_session_key(merchant_id, payment_method_id))
+        .collect::<Vec<_>>();
+    let active_sessions = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .exists_multiple_keys_pipelined(&session_keys)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to check whether the CVV sessions are active")?;
+
+    Ok(payment_method_ids
+        .iter()
+        .zip(active_sessions)
+        .filter(|(_, is_session_active)| !is_session_active)
+        .map(|(payment_method_id, _)| (*payment_method_id).to_owned())
+        .collect())
+}
+
+/// Reject the payment with the stored card if its CVV is required by the policy but was not
+/// provided, and start a session for the stored card when the CVV is provided
+#[instrument(skip_all)]
+pub async fn validate_cvv_recollection(
+    state: &SessionState,
+    policy: &CvvRecollectionPolicy,
+    merchant_id: &id_type::MerchantId,
+    payment_method_id: &str,
+    amount: MinorUnit,
+    currency: Option<common_enums::Currency>,
+    is_merchant_initiated: bool,
+    is_cvv_provided: bool,
+) -> RouterResult<()> {
+    if is_merchant_initiated {
+        return Ok(());
+    }
+
+    if is_cvv_provided {
+        if let Some(session_duration_in_seconds) = policy.session_duration_in_seconds {
+            state
+                .store
+                .get_redis_conn()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to get redis connection")?
+                .set_key_with_expiry(
+                    &get_cvv_session_key(merchant_id, payment_method_id),
+                    "true",
+                    session_duration_in_seconds.into(),
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to start the CVV session")?;
+        }
+        return Ok(());
+    }
+
+    if is_cvv_recollection_required(
+        state,
+        policy,
+        merchant_id,
+        payment_method_id,
+        amount,
+        currency,
+    )
+    .await?
+    {
+        core_metrics::CVV_RECOLLECTION_REQUIRED.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([("merchant_id", merchant_id.get_string_repr().to_owned())]),
+        );
+        return Err(report!(errors::ApiErrorResponse::CvvRecollectionRequired));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn get_policy(session_duration_in_seconds: Option<u32>) -> CvvRecollectionPolicy {
+        CvvRecollectionPolicy {
+            amount_thresholds: Some(HashMap::from([
+                (common_enums::Currency::USD, MinorUnit::new(1000)),
+                (common_enums::Currency::JPY, MinorUnit::new(150000)),
+            ])),
+            session_duration_in_seconds,
+        }
+    }
+
+    #[test]
+    fn test_cvv_is_required_above_the_amount_threshold_regardless_of_the_session() {
+        let policy = get_policy(Some(600));
+
+        assert_eq!(
+            is_cvv_required_without_session(
+                &policy,
+                MinorUnit::new(1000),
+                Some(common_enums::Currency::USD)
+            ),
+            Some(true)
+        );
+        assert_eq!(
+            is_cvv_required_without_session(
+                &policy,
+                MinorUnit::new(999),
+                Some(common_enums::Currency::USD)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_cvv_is_not_required_without_a_session_duration_below_the_amount_threshold() {
+        let policy = get_policy(None);
+
+        assert_eq!(
+            is_cvv_required_without_session(
+                &policy,
+                MinorUnit::new(999),
+                Some(common_enums::Currency::USD)
+            ),
+            Some(false)
+        );
+        assert_eq!(
+            is_cvv_required_without_session(
+                &CvvRecollectionPolicy::default(),
+                MinorUnit::new(999),
+                Some(common_enums::Currency::USD)
+            ),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn test_amount_threshold_applies_only_to_its_currency() {
+        let policy = get_policy(None);
+
+        assert_eq!(
+            is_cvv_required_without_session(
+                &policy,
+                MinorUnit::new(1000),
+                Some(common_enums::Currency::JPY)
+            ),
+            Some(false)
+        );
+        assert_eq!(
+            is_cvv_required_without_session(
+                &policy,
+                MinorUnit::new(150000),
+                Some(common_enums::Currency::JPY)
+            ),
+            Some(true)
+        );
+        assert_eq!(
+            is_cvv_required_without_session(
+                &policy,
+                MinorUnit::new(1000000),
+                Some(common_enums::Currency::EUR)
+            ),
+            Some(false)
+        );
+        assert_eq!(
+            is_cvv_required_without_session(&policy, MinorUnit::new(1000000), None),
+            Some(false)
+        );
+    }
+}
@@ -0,0 +1,365 @@
+//! Detection of accidental duplicate payments.
+//!
+//! A payment is registered against a dedup key, which is a hash of the amount, currency and
+//! payment method of the payment and its payer, for the window configured for the merchant. The
+//! payer is the customer of the payment, or for a guest checkout the card or the email of the
+//! payment. A payment created with the same dedup key within the window is a likely duplicate, such
+//! as a double submission of the checkout, and is either logged or rejected as configured. A payer
+//! can genuinely make the same purchase again, in which case the payment is created with
+//! `allow_duplicate_payment`.
+//!
+//! The payment is registered against its dedup key before it is created, in a single command with
+//! the check, so that one of the concurrent submissions of the same checkout is registered and the
+//! others are detected as its duplicates. The registration is removed if the payment cannot be
+//! created.
+
+use common_utils::{
+    crypto::{GenerateDigest, Sha256},
+    id_type, pii,
+    types::MinorUnit,
+};
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        metrics as core_metrics,
+    },
+    routes::{metrics, SessionState},
+    types::{domain, storage::enums},
+};
+
+/// How the likely duplicates of a payment are handled
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicatePaymentAction {
+    /// The duplicate payment is created, and a warning is logged
+    #[default]
+    Warn,
+    /// The duplicate payment is rejected
+    Block,
+}
+
+/// The duplicate payment detection configured for a merchant, stored as a config with the key
+/// `duplicate_payment_config_{merchant_id}`
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct DuplicatePaymentConfig {
+    /// The time in seconds after a payment within which a payment with the same dedup key is a
+    /// likely duplicate
+    pub window_in_seconds: i64,
+    #[serde(default)]
+    pub action: DuplicatePaymentAction,
+}
+
+/// The payer of a payment, whose payments with the same amount and payment method are likely
+/// duplicates
+pub enum PaymentDedupPayer<'a> {
+    Customer(&'a id_type::CustomerId),
+    /// The hash of the card of a payment without a customer
+    Card(&'a str),
+    /// The email of a payment without a customer, with a payment method other than a card
+    Email(&'a pii::Email),
+}
+
+impl<'a> PaymentDedupPayer<'a> {
+    /// Get the payer of the payment, which is not known for a payment without a customer, card or
+    /// email
+    pub fn new(
+        customer_id: Option<&'a id_type::CustomerId>,
+        card_hash: Option<&'a str>,
+        email: Option<&'a pii::Email>,
+    ) -> Option<Self> {
+        customer_id
+            .map(Self::Customer)
+            .or(card_hash.map(Self::Card))
+            .or(email.map(Self::Email))
+    }
+
+    fn to_dedup_string(&self) -> String {
+        match self {
+            Self::Customer(customer_id) => format!("customer_{}", customer_id.get_string_repr()),
+            Self::Card(card_hash) => format!("card_{card_hash}"),
+            Self::Email(email) => format!("email_{}", email.peek().to_lowercase()),
+        }
+    }
+}
+
+/// The fields of a payment which identify its likely duplicates
+pub struct PaymentDedupDetails<'a> {
+    pub payer: PaymentDedupPayer<'a>,
+    pub amount: MinorUnit,
+    pub currency: enums::Currency,
+    pub payment_method: Option<enums::PaymentMethod>,
+    pub payment_method_type: Option<enums::PaymentMethodType>,
+}
+
+/// The dedup key of a payment, along with the window within which it is checked
+pub struct PaymentDedupKey {
+    dedup_key: String,
+    window: time::Duration,
+}
+
+/// Generate the dedup key of the payment, which is a hash of the fields identifying its likely
+/// duplicates
+pub fn generate_dedup_key(details: &PaymentDedupDetails<'_>) -> RouterResult<String> {
+    let message = format!(
+        "{}:{}:{}:{}:{}",
+        details.payer.to_dedup_string(),
+        details.amount,
+        details.currency,
+        details
+            .payment_method
+            .map(|payment_method| payment_method.to_string())
+            .unwrap_or_default(),
+        details
+            .payment_method_type
+            .map(|payment_method_type| payment_method_type.to_string())
+            .unwrap_or_default(),
+    );
+
+    Sha256
+        .generate_digest(message.as_bytes())
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate the payment dedup key")
+}
+
+/// Get the duplicate payment detection configured for the merchant, if any
+#[instrument(skip_all)]
+pub async fn get_duplicate_payment_config(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<DuplicatePaymentConfig>> {
+    let config = match state
+        .store
+        .find_config_by_key(&merchant_id.get_duplicate_payment_config_key())
+        .await
+    {
+        Ok(config) => config,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find the duplicate payment config")
+        }
+    };
+
+    let duplicate_payment_config = serde_json::from_str::<DuplicatePaymentConfig>(&config.config)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the duplicate payment config")?;
+
+    if duplicate_payment_config.window_in_seconds <= 0 {
+        return Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("The window of the duplicate payment config must be positive");
+    }
+
+    Ok(Some(duplicate_payment_config))
+}
+
+/// Check whether the payment being created is a likely duplicate of a payment created recently by
+/// the payer, logging or rejecting it as configured for the merchant, and register the payment
+/// against its dedup key.
+///
+/// Returns the dedup key against which the payment is registered, if duplicate payment detection is
+/// configured for the merchant. The registration is to be removed with
+/// [`delete_payment_dedup_key`] if the payment cannot be created.
+#[instrument(skip_all)]
+pub async fn check_duplicate_payment(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    details: &PaymentDedupDetails<'_>,
+    payment_id: &id_type::PaymentId,
+    allow_duplicate_payment: bool,
+) -> RouterResult<Option<PaymentDedupKey>> {
+    let merchant_id = merchant_account.get_id();
+    let Some(duplicate_payment_config) = get_duplicate_payment_config(state, merchant_id).await?
+    else {
+        return Ok(None);
+    };
+
+    let dedup_key = PaymentDedupKey {
+        dedup_key: generate_dedup_key(details)?,
+        window: time::Duration::seconds(duplicate_payment_config.window_in_seconds),
+    };
+
+    if allow_duplicate_payment {
+        insert_payment_dedup_key(state, merchant_id, &dedup_key, payment_id).await?;
+        return Ok(Some(dedup_key));
+    }
+
+    let recent_payment_id = state
+        .store
+        .try_insert_payment_dedup_key(
+            merchant_id,
+            &dedup_key.dedup_key,
+            payment_id,
+            dedup_key.window,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the payment dedup key")?;
+
+    // A payment is not a duplicate of itself, such as when its creation is retried with the same
+    // payment id
+    let Some(recent_payment_id) =
+        recent_payment_id.filter(|recent_payment_id| recent_payment_id != payment_id)
+    else {
+        return Ok(Some(dedup_key));
+    };
+
+    core_metrics::DUPLICATE_PAYMENT_DETECTED.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([
+            ("merchant_id", merchant_id.get_string_repr().to_owned()),
+            (
+                "action",
+                format!("{:?}", duplicate_payment_config.action).to_lowercase(),
+            ),
+        ]),
+    );
+
+    match duplicate_payment_config.action {
+        DuplicatePaymentAction::Warn => {
+            logger::warn!(
+                recent_payment_id = recent_payment_id.get_string_repr(),
+                "The payment is a likely duplicate of a recent payment"
+            );
+            insert_payment_dedup_key(state, merchant_id, &dedup_key, payment_id).await?;
+            Ok(Some(dedup_key))
+        }
+        DuplicatePaymentAction::Block => Err(report!(
+            errors::ApiErrorResponse::DuplicatePaymentSuspected {
+                message: format!(
+                    "The payment is a likely duplicate of the payment {}, which was created recently with the same amount, currency, payment method and payer. Set `allow_duplicate_payment` to create the payment anyway",
+                    recent_payment_id.get_string_repr()
+                ),
+            }
+        )),
+    }
+}
+
+/// Register the payment against its dedup key, replacing the payment registered against it, so
+/// that the duplicates of the payment created within the window can be detected
+#[instrument(skip_all)]
+async fn insert_payment_dedup_key(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    dedup_key: &PaymentDedupKey,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<()> {
+    state
+        .store
+        .insert_payment_dedup_key(
+            merchant_id,
+            &dedup_key.dedup_key,
+            payment_id,
+            dedup_key.window,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the payment dedup key")
+}
+
+/// Remove the registration of a payment which could not be created against its dedup key, so that
+/// the payment created again is not detected as its duplicate. The registration expires along with
+/// its window if it cannot be removed.
+#[instrument(skip_all)]
+pub async fn delete_payment_dedup_key(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    dedup_key: &PaymentDedupKey,
+    payment_id: &id_type::PaymentId,
+) {
+    state
+        .store
+        .delete_payment_dedup_key(
+            merchant_id,
+            &dedup_key.dedup_key,
+            payment_id,
+            dedup_key.window,
+        )
+        .await
+        .map(|_| ())
+        .unwrap_or_else(|error| {
+            logger::error!(?error, "Failed to delete the payment dedup key");
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use super::*;
+
+    fn get_dedup_details(payer: PaymentDedupPayer<'_>) -> PaymentDedupDetails<'_> {
+        PaymentDedupDetails {
+            payer,
+            amount: MinorUnit::new(1000),
+            currency: enums::Currency::USD,
+            payment_method: Some(enums::PaymentMethod::Card),
+            payment_method_type: Some(enums::PaymentMethodType::Credit),
+        }
+    }
+
+    #[test]
+    fn test_dedup_key_is_the_same_for_the_same_payment() {
+        let customer_id = id_type::CustomerId::try_from(Cow::from("cus_dedup")).unwrap();
+        assert_eq!(
+            generate_dedup_key(&get_dedup_details(PaymentDedupPayer::Customer(
+                &customer_id
+            )))
+            .unwrap(),
+            generate_dedup_key(&get_dedup_details(PaymentDedupPayer::Customer(
+                &customer_id
+            )))
+            .unwrap()
+        );
+
+        let email = pii::Email::try_from("Guest@example.com".to_string()).unwrap();
+        let other_email = pii::Email::try_from("guest@example.com".to_string()).unwrap();
+        assert_eq!(
+            generate_dedup_key(&get_dedup_details(PaymentDedupPayer::Email(&email))).unwrap(),
+            generate_dedup_key(&get_dedup_details(PaymentDedupPayer::Email(&other_email))).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_dedup_key_differs_by_payer_and_amount() {
+        let customer_id = id_type::CustomerId::try_from(Cow::from("cus_dedup")).unwrap();
+        let customer_key = generate_dedup_key(&get_dedup_details(PaymentDedupPayer::Customer(
+            &customer_id,
+        )))
+        .unwrap();
+        let card_key =
+            generate_dedup_key(&get_dedup_details(PaymentDedupPayer::Card("cus_dedup"))).unwrap();
+        assert_ne!(customer_key, card_key);
+
+        let mut details = get_dedup_details(PaymentDedupPayer::Customer(&customer_id));
+        details.amount = MinorUnit::new(1001);
+        assert_ne!(customer_key, generate_dedup_key(&details).unwrap());
+    }
+
+    #[test]
+    fn test_payer_prefers_the_customer_over_the_card_and_email() {
+        let customer_id = id_type::CustomerId::try_from(Cow::from("cus_dedup")).unwrap();
+        let email = pii::Email::try_from("guest@example.com".to_string()).unwrap();
+
+        assert!(matches!(
+            PaymentDedupPayer::new(Some(&customer_id), Some("card_hash"), Some(&email)),
+            Some(PaymentDedupPayer::Customer(_))
+        ));
+        assert!(matches!(
+            PaymentDedupPayer::new(None, Some("card_hash"), Some(&email)),
+            Some(PaymentDedupPayer::Card("card_hash"))
+        ));
+        assert!(matches!(
+            PaymentDedupPayer::new(None, None, Some(&email)),
+            Some(PaymentDedupPayer::Email(_))
+        ));
+        assert!(PaymentDedupPayer::new(None, None, None).is_none());
+    }
+}
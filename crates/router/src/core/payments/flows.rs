@@ -336,6 +336,13 @@ macro_rules! default_imp_for_create_customer {
             types::ConnectorCustomerData,
             types::PaymentsResponseData,
         > for $path::$connector
+        {}
+            impl
+            services::ConnectorIntegration<
+            api::UpdateConnectorCustomer,
+            types::ConnectorCustomerUpdateData,
+            types::PaymentsResponseData,
+        > for $path::$connector
         {}
     )*
     };
@@ -352,6 +359,15 @@ impl<const T: u8>
     > for connector::DummyConnector<T>
 {
 }
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8>
+    services::ConnectorIntegration<
+        api::UpdateConnectorCustomer,
+        types::ConnectorCustomerUpdateData,
+        types::PaymentsResponseData,
+    > for connector::DummyConnector<T>
+{
+}
 
 default_imp_for_create_customer!(
     connector::Adyenplatform,
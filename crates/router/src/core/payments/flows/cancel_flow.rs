@@ -8,7 +8,10 @@ use crate::{
         payments::{self, access_token, helpers, transformers, PaymentData},
     },
     routes::{metrics, SessionState},
-    services,
+    services::{
+        self,
+        connector_read_cache::{self, ConnectorReadCacheKey, ConnectorReadFlow},
+    },
     types::{self, api, domain},
 };
 
@@ -91,6 +94,17 @@ impl Feature<api::Void, types::PaymentsCancelData>
         .await
         .to_payment_failed_response()?;
 
+        connector_read_cache::invalidate_connector_read_cache(
+            state,
+            &ConnectorReadCacheKey::new(
+                self.merchant_id.clone(),
+                self.connector.clone(),
+                ConnectorReadFlow::PaymentSync,
+                self.request.connector_transaction_id.clone(),
+            ),
+        )
+        .await;
+
         Ok(resp)
     }
 
@@ -7,7 +7,10 @@ use crate::{
         payments::{self, access_token, helpers, transformers, Feature, PaymentData},
     },
     routes::SessionState,
-    services,
+    services::{
+        self,
+        connector_read_cache::{self, ConnectorReadCacheKey, ConnectorReadFlow},
+    },
     types::{self, api, domain},
 };
 
@@ -85,6 +88,17 @@ impl Feature<api::Capture, types::PaymentsCaptureData>
         .await
         .to_payment_failed_response()?;
 
+        connector_read_cache::invalidate_connector_read_cache(
+            state,
+            &ConnectorReadCacheKey::new(
+                self.merchant_id.clone(),
+                self.connector.clone(),
+                ConnectorReadFlow::PaymentSync,
+                self.request.connector_transaction_id.clone(),
+            ),
+        )
+        .await;
+
         // Initiating Integrity check
         let integrity_result = helpers::check_integrity_based_on_flow(
             &new_router_data.request,
@@ -10,7 +10,14 @@ use crate::{
         payments::{self, access_token, helpers, transformers, PaymentData},
     },
     routes::SessionState,
-    services::{self, api::ConnectorValidation, logger},
+    services::{
+        self,
+        api::ConnectorValidation,
+        connector_read_cache::{
+            self, ConnectorReadCache, ConnectorReadCacheKey, ConnectorReadFlow,
+        },
+        logger,
+    },
     types::{self, api, domain},
 };
 #[async_trait]
@@ -108,15 +115,47 @@ impl Feature<api::PSync, types::PaymentsSyncData>
             (types::SyncRequestType::MultipleCaptureSync(_), Err(err)) => Err(err),
             _ => {
                 // for bulk sync of captures, above logic needs to be handled at connector end
-                let mut new_router_data = services::execute_connector_processing_step(
-                    state,
-                    connector_integration,
-                    &self,
-                    call_connector_action,
-                    connector_request,
-                )
-                .await
-                .to_payment_failed_response()?;
+                let read_cache_key = self
+                    .request
+                    .connector_transaction_id
+                    .get_connector_transaction_id()
+                    .ok()
+                    .map(|connector_transaction_id| {
+                        ConnectorReadCacheKey::new(
+                            self.merchant_id.clone(),
+                            self.connector.clone(),
+                            ConnectorReadFlow::PaymentSync,
+                            connector_transaction_id,
+                        )
+                    });
+                let read_cache = match (&call_connector_action, read_cache_key) {
+                    (payments::CallConnectorAction::Trigger, Some(key)) => {
+                        Some(ConnectorReadCache {
+                            key,
+                            is_cacheable: |router_data: &Self| {
+                                router_data.response.is_ok()
+                                    && router_data.status.is_terminal_status()
+                            },
+                        })
+                    }
+                    // The payment was updated through a webhook, so any cached read is stale
+                    (payments::CallConnectorAction::HandleResponse(_), Some(key)) => {
+                        connector_read_cache::invalidate_connector_read_cache(state, &key).await;
+                        None
+                    }
+                    _ => None,
+                };
+                let mut new_router_data =
+                    services::execute_connector_processing_step_with_read_cache(
+                        state,
+                        connector_integration,
+                        &self,
+                        call_connector_action,
+                        connector_request,
+                        read_cache,
+                    )
+                    .await
+                    .to_payment_failed_response()?;
 
                 // Initiating Integrity checks
                 let integrity_result = helpers::check_integrity_based_on_flow(
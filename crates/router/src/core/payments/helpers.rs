@@ -1,4 +1,4 @@
-use std::{borrow::Cow, str::FromStr};
+use std::{borrow::Cow, collections::HashSet, str::FromStr};
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 use api_models::customers::CustomerRequestWithEmail;
@@ -55,20 +55,25 @@ use super::{
 };
 #[cfg(feature = "v2")]
 use crate::core::admin as core_admin;
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+use crate::core::payment_methods::bank_account_tokenization;
 use crate::{
-    configs::settings::{ConnectorRequestReferenceIdConfig, TempLockerEnableConfig},
+    configs::settings::{ConnectorRequestReferenceIdConfig, TempLockerEnableConfig, TokenExpiry},
     connector,
     consts::{self, BASE64_ENGINE},
     core::{
         authentication,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
-        mandate::helpers::MandateGenericData,
+        mandate::{helpers::MandateGenericData, reserve_mandate_usage},
         payment_methods::{
             self,
             cards::{self},
             network_tokenization, vault,
         },
-        payments,
+        payments::{self, cvv_recollection, token_expiry},
         pm_auth::retrieve_payment_method_from_auth_service,
     },
     db::StorageInterface,
@@ -743,11 +748,15 @@ pub async fn get_token_for_recurring_mandate(
         };
         mandate.payment_method_id.clone()
     };
+    let request_amount: MinorUnit = req.amount.get_required_value("amount")?.into();
     verify_mandate_details(
-        req.amount.get_required_value("amount")?.into(),
-        req.currency.get_required_value("currency")?,
+        request_amount,
+        req.currency
+            .or(merchant_account.get_default_currency())
+            .get_required_value("currency")?,
         mandate.clone(),
     )?;
+    reserve_mandate_usage(state, &mandate, request_amount).await?;
 
     let payment_method = db
         .find_payment_method(
@@ -846,6 +855,24 @@ pub fn validate_merchant_id(
     })
 }
 
+/// Check whether the merchant account has completed onboarding and is allowed to accept payments
+pub fn validate_merchant_onboarding_status(
+    merchant_account: &domain::MerchantAccount,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let onboarding_status = merchant_account.onboarding_status;
+
+    utils::when(
+        onboarding_status != storage_enums::OnboardingStatus::Live,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Payments cannot be created for the merchant account until it is live, current onboarding status is `{onboarding_status}`"
+                )
+            }))
+        },
+    )
+}
+
 #[instrument(skip_all)]
 pub fn validate_request_amount_and_amount_to_capture(
     op_amount: Option<api::Amount>,
@@ -884,17 +911,55 @@ pub fn validate_request_amount_and_amount_to_capture(
     }
 }
 
+/// A capture is voided by reversing it through a refund of its amount at the connector, which can
+/// be done once the capture is charged and until it is settled by the connector. The outcome of a
+/// started or pending capture at the connector is not yet known, as the connector may have charged
+/// it even if no response was received, so the payment has to be synced first. A settled capture
+/// can be returned only through a refund of the payment.
+pub fn validate_capture_can_be_voided(
+    capture: &storage::Capture,
+    expected_settlement_date: Option<time::Date>,
+    today: time::Date,
+) -> RouterResult<()> {
+    match capture.status {
+        storage_enums::CaptureStatus::Charged => {
+            fp_utils::when(
+                expected_settlement_date.is_some_and(|settlement_date| settlement_date <= today),
+                || {
+                    Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                        message: "The capture cannot be voided as it is already settled, refund the payment instead".into(),
+                    }))
+                },
+            )
+        }
+        storage_enums::CaptureStatus::Started | storage_enums::CaptureStatus::Pending => {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The capture cannot be voided as its outcome at the connector is not yet known, sync the payment for the outcome of the capture".into(),
+            }))
+        }
+        storage_enums::CaptureStatus::Failed
+        | storage_enums::CaptureStatus::Voided
+        | storage_enums::CaptureStatus::Reversed => {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!("The capture cannot be voided as it is {}", capture.status),
+            }))
+        }
+    }
+}
+
 #[cfg(feature = "v1")]
 /// if capture method = automatic, amount_to_capture(if provided) must be equal to amount
 #[instrument(skip_all)]
 pub fn validate_amount_to_capture_and_capture_method(
     payment_attempt: Option<&PaymentAttempt>,
     request: &api_models::payments::PaymentsRequest,
+    default_capture_method: Option<api_enums::CaptureMethod>,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
     let capture_method = request
         .capture_method
         .or(payment_attempt
             .map(|payment_attempt| payment_attempt.capture_method.unwrap_or_default()))
+        .or(default_capture_method)
         .unwrap_or_default();
     if capture_method == api_enums::CaptureMethod::Automatic {
         let original_amount = request
@@ -1281,6 +1346,14 @@ pub fn verify_mandate_details(
             },
         ),
     }?;
+    if let Some(constraints) = mandate.constraints {
+        verify_mandate_constraints(
+            request_amount,
+            mandate.amount_captured,
+            mandate.last_charged_at,
+            constraints,
+        )?;
+    }
     utils::when(
         mandate
             .mandate_currency
@@ -1294,6 +1367,68 @@ pub fn verify_mandate_details(
     )
 }
 
+fn verify_mandate_constraints(
+    request_amount: MinorUnit,
+    amount_captured: Option<i64>,
+    last_charged_at: Option<time::PrimitiveDateTime>,
+    constraints: storage_enums::MandateConstraints,
+) -> RouterResult<()> {
+    utils::when(
+        constraints
+            .max_amount_per_transaction
+            .is_some_and(|max_amount| request_amount > max_amount),
+        || {
+            Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+                reason: "request amount is greater than the maximum amount per transaction allowed by the mandate".into()
+            }))
+        },
+    )?;
+    utils::when(
+        constraints.total_amount.is_some_and(|total_amount| {
+            amount_captured.unwrap_or(0) + request_amount.get_amount_as_i64()
+                > total_amount.get_amount_as_i64()
+        }),
+        || {
+            Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+                reason: "request amount exceeds the remaining amount allowed by the mandate".into()
+            }))
+        },
+    )?;
+    utils::when(
+        constraints
+            .frequency
+            .zip(last_charged_at)
+            .is_some_and(|(frequency, last_charged_at)| {
+                last_charged_at
+                    >= get_mandate_period_start(frequency, common_utils::date_time::now())
+            }),
+        || {
+            Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+                reason: "mandate has already been charged in the current period".into()
+            }))
+        },
+    )
+}
+
+/// The start of the calendar period of the frequency of a mandate which `now` falls in
+pub fn get_mandate_period_start(
+    frequency: storage_enums::MandateFrequency,
+    now: time::PrimitiveDateTime,
+) -> time::PrimitiveDateTime {
+    let date = now.date();
+    let period_start_date = match frequency {
+        storage_enums::MandateFrequency::Daily => date,
+        storage_enums::MandateFrequency::Weekly => date.saturating_sub(time::Duration::days(
+            i64::from(date.weekday().number_days_from_monday()),
+        )),
+        storage_enums::MandateFrequency::Monthly => date.replace_day(1).unwrap_or(date),
+        storage_enums::MandateFrequency::Yearly => {
+            time::Date::from_ordinal_date(date.year(), 1).unwrap_or(date)
+        }
+    };
+    period_start_date.midnight()
+}
+
 pub fn verify_mandate_details_for_recurring_payments(
     mandate_merchant_id: &id_type::MerchantId,
     merchant_id: &id_type::MerchantId,
@@ -1408,6 +1543,43 @@ pub fn validate_max_amount(
     }
 }
 
+/// Check whether the amount of the payment is within the transaction amount limits configured by
+/// the merchant for the currency and payment method type of the payment. The limits apply to the
+/// amount of the order, so the surcharge is not considered
+pub fn validate_transaction_amount_limits(
+    merchant_account: &domain::MerchantAccount,
+    amount: api_models::payments::Amount,
+    currency: Option<api_enums::Currency>,
+    payment_method_type: Option<api_enums::PaymentMethodType>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let Some(currency) = currency else {
+        return Ok(());
+    };
+    let amount = MinorUnit::from(amount);
+
+    match merchant_account.get_transaction_amount_limit(currency, payment_method_type) {
+        Some(limit) if !limit.is_within_limits(amount) => {
+            Err(report!(errors::ApiErrorResponse::AmountOutOfRange {
+                message: format!(
+                    "The amount {} is out of the range allowed by the merchant for {currency} payments, which is {} to {}",
+                    amount.get_amount_as_i64(),
+                    limit
+                        .min_amount
+                        .map_or("unbounded".to_string(), |min_amount| min_amount
+                            .get_amount_as_i64()
+                            .to_string()),
+                    limit
+                        .max_amount
+                        .map_or("unbounded".to_string(), |max_amount| max_amount
+                            .get_amount_as_i64()
+                            .to_string()),
+                ),
+            }))
+        }
+        _ => Ok(()),
+    }
+}
+
 /// Check whether the customer information that is sent in the root of payments request
 /// and in the customer object are same, if the values mismatch return an error
 pub fn validate_customer_information(
@@ -1476,6 +1648,108 @@ pub async fn get_connector_default(
     ))
 }
 
+pub async fn get_connector_override_or_default(
+    state: &SessionState,
+    request_connector: Option<serde_json::Value>,
+    connector_override: Option<api::routing::RoutableConnectorChoice>,
+) -> CustomResult<api::ConnectorChoice, errors::ApiErrorResponse> {
+    match connector_override {
+        Some(_) if request_connector.is_some() => {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Only one of `routing` and `connector_override` can be provided"
+                    .to_string(),
+            }))
+        }
+        Some(connector_override) => Ok(api::ConnectorChoice::Override(connector_override)),
+        None => get_connector_default(state, request_connector).await,
+    }
+}
+
+/// Validate that the connector forced in the request is configured and active for the profile of
+/// the payment, resolving the merchant connector account of the connector if it is not provided
+#[cfg(feature = "v1")]
+pub async fn validate_connector_override(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    connector_override: api::routing::RoutableConnectorChoice,
+) -> RouterResult<api::routing::RoutableConnectorChoice> {
+    let merchant_connector_accounts = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &state.into(),
+            &key_store.merchant_id,
+            false,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::InternalServerError)?;
+
+    select_connector_override_account(
+        merchant_connector_accounts,
+        business_profile.get_id(),
+        connector_override,
+    )
+}
+
+/// Select the only active payment processor account of the profile which matches the connector
+/// forced in the request
+#[cfg(feature = "v1")]
+fn select_connector_override_account(
+    merchant_connector_accounts: Vec<domain::MerchantConnectorAccount>,
+    profile_id: &id_type::ProfileId,
+    connector_override: api::routing::RoutableConnectorChoice,
+) -> RouterResult<api::routing::RoutableConnectorChoice> {
+    let connector_name = connector_override.connector.to_string();
+    let matching_accounts = filter_mca_based_on_profile_and_connector_type(
+        merchant_connector_accounts,
+        profile_id,
+        ConnectorType::PaymentProcessor,
+    )
+    .into_iter()
+    .filter(|mca| {
+        mca.connector_name == connector_name
+            && match &connector_override.merchant_connector_id {
+                Some(merchant_connector_id) => *merchant_connector_id == mca.get_id(),
+                None => true,
+            }
+    })
+    .collect::<Vec<_>>();
+
+    let merchant_connector_account = match matching_accounts.as_slice() {
+        [merchant_connector_account] => Ok(merchant_connector_account),
+        [] => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The connector `{connector_name}` is not configured for the profile of the payment"
+            ),
+        })),
+        _ => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The profile of the payment has multiple accounts of the connector `{connector_name}`, \
+                 the `merchant_connector_id` must be provided"
+            ),
+        })),
+    }?;
+
+    fp_utils::when(
+        merchant_connector_account.status != common_enums::ConnectorStatus::Active,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The account of the connector `{connector_name}` is not active, since its \
+                     configuration is incomplete"
+                ),
+            }))
+        },
+    )?;
+
+    Ok(api::routing::RoutableConnectorChoice {
+        choice_kind: api::routing::RoutableChoiceKind::FullStruct,
+        connector: connector_override.connector,
+        merchant_connector_id: Some(merchant_connector_account.get_id()),
+    })
+}
+
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
 #[instrument(skip_all)]
 #[allow(clippy::type_complexity)]
@@ -1619,6 +1893,7 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, D>(
                             connector_customer: None,
                             metadata: None,
                             address_id: None,
+                            tax_ids: None,
                         };
 
                         db.update_customer_by_customer_id_merchant_id(
@@ -1652,6 +1927,8 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, D>(
                         default_payment_method_id: None,
                         updated_by: None,
                         version: hyperswitch_domain_models::consts::API_VERSION,
+                        tax_ids: None,
+                        preferred_payment_method_id: None,
                     };
                     metrics::CUSTOMER_CREATED.add(&metrics::CONTEXT, 1, &[]);
                     db.insert_customer(new_customer, key_manager_state, key_store, storage_scheme)
@@ -1806,7 +2083,7 @@ pub async fn retrieve_card_with_permanent_token(
     _payment_method_id: &str,
     payment_intent: &PaymentIntent,
     card_token_data: Option<&domain::CardToken>,
-    _merchant_key_store: &domain::MerchantKeyStore,
+    merchant_key_store: &domain::MerchantKeyStore,
     _storage_scheme: enums::MerchantStorageScheme,
     mandate_id: Option<api_models::payments::MandateIds>,
     payment_method_info: Option<domain::PaymentMethod>,
@@ -1905,9 +2182,18 @@ pub async fn retrieve_card_with_permanent_token(
                                     token_data.card_exp_month = exp_month;
                                     token_data.card_exp_year = exp_year;
                                 }
+                                // A stale cryptogram is never sent, the payment fails instead
+                                let token_cryptogram =
+                                    network_tokenization::get_fresh_cryptogram_for_network_token(
+                                        state,
+                                        merchant_key_store,
+                                        &payment_intent.payment_id,
+                                        pm_data,
+                                    )
+                                    .await?;
                                 let network_token_data = domain::NetworkTokenData {
                                     token_number: token_data.card_number,
-                                    token_cryptogram: None,
+                                    token_cryptogram: Some(token_cryptogram),
                                     token_exp_month: token_data.card_exp_month,
                                     token_exp_year: token_data.card_exp_year,
                                     nick_name: token_data.nick_name.map(masking::Secret::new),
@@ -2060,7 +2346,8 @@ pub async fn retrieve_payment_method_from_db_with_token_data(
         storage::PaymentTokenData::Temporary(_)
         | storage::PaymentTokenData::TemporaryGeneric(_)
         | storage::PaymentTokenData::Permanent(_)
-        | storage::PaymentTokenData::AuthBankDebit(_) => Ok(None),
+        | storage::PaymentTokenData::AuthBankDebit(_)
+        | storage::PaymentTokenData::BankAccountToken(_) => Ok(None),
     }
 }
 
@@ -2069,6 +2356,14 @@ pub async fn retrieve_payment_token_data(
     token: String,
     payment_method: Option<storage_enums::PaymentMethod>,
 ) -> RouterResult<storage::PaymentTokenData> {
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    if let Some(token_data) = bank_account_tokenization::get_payment_token_data(&token) {
+        return Ok(token_data);
+    }
+
     let redis_conn = state
         .store
         .get_redis_conn()
@@ -2193,6 +2488,41 @@ pub async fn make_pm_data<'a, F: Clone, R, D>(
     // TODO: Handle case where payment method and token both are present in request properly.
     let (payment_method, pm_id) = match (&request, payment_data.token_data.as_ref()) {
         (_, Some(hyperswitch_token)) => {
+            if let storage::PaymentTokenData::PermanentCard(CardTokenData {
+                payment_method_id: Some(payment_method_id),
+                ..
+            })
+            | storage::PaymentTokenData::Permanent(CardTokenData {
+                payment_method_id: Some(payment_method_id),
+                ..
+            }) = hyperswitch_token
+            {
+                let merchant_id = &payment_data.payment_intent.merchant_id;
+                let cvv_recollection_policy = cvv_recollection::get_cvv_recollection_policy(
+                    state,
+                    merchant_id,
+                    payment_data.cvv_recollection_policy.as_ref(),
+                )
+                .await?;
+                let is_merchant_initiated = mandate_id.is_some()
+                    || payment_data.recurring_details.is_some()
+                    || payment_data.payment_intent.off_session == Some(true);
+
+                cvv_recollection::validate_cvv_recollection(
+                    state,
+                    &cvv_recollection_policy,
+                    merchant_id,
+                    payment_method_id,
+                    payment_data.payment_intent.amount,
+                    payment_data.payment_intent.currency,
+                    is_merchant_initiated,
+                    card_token_data
+                        .as_ref()
+                        .is_some_and(|token_data| token_data.card_cvc.is_some()),
+                )
+                .await?;
+            }
+
             let pm_data = Box::pin(payment_methods::retrieve_payment_method_with_token(
                 state,
                 merchant_key_store,
@@ -2411,6 +2741,91 @@ pub(crate) fn validate_amount_to_capture(
     )
 }
 
+/// Get the currency in which the payment is to be captured, which defaults to the currency of the
+/// payment. Capturing in a different currency is allowed only for the connectors which are
+/// configured to accept cross currency captures.
+pub(crate) fn validate_and_get_capture_currency(
+    connectors_with_cross_currency_capture: &HashSet<api_enums::Connector>,
+    connector: Option<&str>,
+    payment_currency: Option<storage_enums::Currency>,
+    capture_currency: Option<storage_enums::Currency>,
+) -> RouterResult<storage_enums::Currency> {
+    let payment_currency = payment_currency.get_required_value("currency")?;
+    let Some(capture_currency) =
+        capture_currency.filter(|capture_currency| *capture_currency != payment_currency)
+    else {
+        return Ok(payment_currency);
+    };
+
+    let connector = connector.get_required_value("connector")?;
+    let is_cross_currency_capture_supported = api_enums::Connector::from_str(connector)
+        .map(|connector_name| connectors_with_cross_currency_capture.contains(&connector_name))
+        .unwrap_or(false);
+
+    utils::when(!is_cross_currency_capture_supported, || {
+        Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: format!(
+                "Capturing a payment authorized in {payment_currency} in {capture_currency} is not supported by {connector}"
+            ),
+        }))
+    })?;
+
+    Ok(capture_currency)
+}
+
+/// Convert the amount to capture in a currency other than the currency of the payment into the
+/// currency of the payment, at the current exchange rates, so that it can be validated against the
+/// amount capturable. The connector validates the amount against the authorization at its own
+/// exchange rates.
+#[instrument(skip_all)]
+pub(crate) async fn get_amount_to_capture_in_payment_currency(
+    state: &SessionState,
+    amount_to_capture: MinorUnit,
+    capture_currency: storage_enums::Currency,
+    payment_currency: storage_enums::Currency,
+) -> RouterResult<MinorUnit> {
+    let forex_api = state.conf.forex_api.get_inner();
+    let rates = crate::utils::currency::get_forex_rates(
+        state,
+        forex_api.call_delay,
+        forex_api.local_fetch_retry_delay,
+        forex_api.local_fetch_retry_count,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the exchange rates for the cross currency capture")?;
+
+    let converted_amount = currency_conversion::conversion::convert(
+        &rates.data,
+        capture_currency,
+        payment_currency,
+        amount_to_capture.get_amount_as_i64(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to convert the amount to capture into the currency of the payment")?;
+
+    get_minor_unit_from_base_unit(converted_amount, payment_currency)
+}
+
+/// Get the amount in the minor unit of the currency from the amount in its base unit, rounding
+/// any fraction of the minor unit down
+fn get_minor_unit_from_base_unit(
+    amount: rust_decimal::Decimal,
+    currency: storage_enums::Currency,
+) -> RouterResult<MinorUnit> {
+    use rust_decimal::prelude::ToPrimitive;
+
+    let minor_units_per_base_unit = rust_decimal::Decimal::from(
+        10_i64.pow(currency.number_of_digits_after_decimal_point().into()),
+    );
+    (amount * minor_units_per_base_unit)
+        .floor()
+        .to_i64()
+        .map(MinorUnit::new)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("The converted amount to capture does not fit in the minor unit")
+}
+
 #[instrument(skip_all)]
 pub(crate) fn validate_payment_method_fields_present(
     req: &api::PaymentsRequest,
@@ -2472,6 +2887,24 @@ pub(crate) fn validate_payment_method_fields_present(
         },
     )?;
 
+    if let Some((req_payment_method, req_payment_experience)) =
+        req.payment_method.zip(req.payment_experience)
+    {
+        utils::when(
+            !validate_payment_experience_against_payment_method(
+                req_payment_method,
+                req_payment_experience,
+            ),
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message:
+                        "payment_experience doesn't correspond to the specified payment_method"
+                            .to_string(),
+                })
+            },
+        )?;
+    }
+
     let validate_payment_method_and_payment_method_data =
         |req_payment_method_data, req_payment_method: api_enums::PaymentMethod| {
             api_enums::PaymentMethod::foreign_try_from(req_payment_method_data).and_then(|payment_method|
@@ -2504,6 +2937,32 @@ pub(crate) fn validate_payment_method_fields_present(
     Ok(())
 }
 
+/// Validates that the client side experience requested for the payment can be provided for the
+/// payment method
+pub fn validate_payment_experience_against_payment_method(
+    payment_method: api_enums::PaymentMethod,
+    payment_experience: api_enums::PaymentExperience,
+) -> bool {
+    match payment_experience {
+        api_enums::PaymentExperience::DisplayVoucher => {
+            matches!(payment_method, api_enums::PaymentMethod::Voucher)
+        }
+        api_enums::PaymentExperience::DisplayQrCode => !matches!(
+            payment_method,
+            api_enums::PaymentMethod::Card
+                | api_enums::PaymentMethod::CardRedirect
+                | api_enums::PaymentMethod::BankDebit
+                | api_enums::PaymentMethod::GiftCard
+        ),
+        api_enums::PaymentExperience::RedirectToUrl
+        | api_enums::PaymentExperience::InvokeSdkClient
+        | api_enums::PaymentExperience::OneClick
+        | api_enums::PaymentExperience::LinkWallet
+        | api_enums::PaymentExperience::InvokePaymentApp
+        | api_enums::PaymentExperience::DisplayWaitScreen => true,
+    }
+}
+
 pub fn validate_payment_method_type_against_payment_method(
     payment_method: api_enums::PaymentMethod,
     payment_method_type: api_enums::PaymentMethodType,
@@ -2986,6 +3445,7 @@ pub fn generate_mandate(
                         new_mandate
                             .set_mandate_amount(Some(data.amount.get_amount_as_i64()))
                             .set_mandate_currency(Some(data.currency))
+                            .set_constraints(data.constraints.map(Into::into))
                             .set_mandate_type(storage_enums::MandateType::SingleUse)
                             .to_owned()
                     }
@@ -2996,7 +3456,8 @@ pub fn generate_mandate(
                                 .set_mandate_amount(Some(data.amount.get_amount_as_i64()))
                                 .set_mandate_currency(Some(data.currency))
                                 .set_start_date(data.start_date)
-                                .set_end_date(data.end_date),
+                                .set_end_date(data.end_date)
+                                .set_constraints(data.constraints.map(Into::into)),
                             // .set_metadata(data.metadata),
                             // we are storing PaymentMethodData in metadata of mandate
                             None => &mut new_mandate,
@@ -3012,25 +3473,30 @@ pub fn generate_mandate(
 }
 
 #[cfg(feature = "v1")]
-// A function to manually authenticate the client secret with intent fulfillment time
+// A function to manually authenticate the client secret with intent fulfillment time, allowing the
+// grace period for a customer in the middle of completing the payment
 pub fn authenticate_client_secret(
     request_client_secret: Option<&String>,
     payment_intent: &PaymentIntent,
+    token_expiry_config: &TokenExpiry,
 ) -> Result<(), errors::ApiErrorResponse> {
     match (request_client_secret, &payment_intent.client_secret) {
         (Some(req_cs), Some(pi_cs)) => {
             if req_cs != pi_cs {
                 Err(errors::ApiErrorResponse::ClientSecretInvalid)
             } else {
-                let current_timestamp = common_utils::date_time::now();
-
                 let session_expiry = payment_intent.session_expiry.unwrap_or(
                     payment_intent
                         .created_at
                         .saturating_add(time::Duration::seconds(consts::DEFAULT_SESSION_EXPIRY)),
                 );
+                let expiry_status = token_expiry::get_token_expiry_status(
+                    token_expiry_config,
+                    session_expiry,
+                    payment_intent.status,
+                );
 
-                fp_utils::when(current_timestamp > session_expiry, || {
+                fp_utils::when(!expiry_status.is_resolvable(), || {
                     Err(errors::ApiErrorResponse::ClientSecretExpired)
                 })
             }
@@ -3042,25 +3508,30 @@ pub fn authenticate_client_secret(
 }
 
 #[cfg(feature = "v2")]
-// A function to manually authenticate the client secret with intent fulfillment time
+// A function to manually authenticate the client secret with intent fulfillment time, allowing the
+// grace period for a customer in the middle of completing the payment
 pub fn authenticate_client_secret(
     request_client_secret: Option<&common_utils::types::ClientSecret>,
     payment_intent: &PaymentIntent,
+    token_expiry_config: &TokenExpiry,
 ) -> Result<(), errors::ApiErrorResponse> {
     match (request_client_secret, &payment_intent.client_secret) {
         (Some(req_cs), pi_cs) => {
             if req_cs != pi_cs {
                 Err(errors::ApiErrorResponse::ClientSecretInvalid)
             } else {
-                let current_timestamp = common_utils::date_time::now();
-
                 let session_expiry = payment_intent.session_expiry.unwrap_or(
                     payment_intent
                         .created_at
                         .saturating_add(time::Duration::seconds(consts::DEFAULT_SESSION_EXPIRY)),
                 );
+                let expiry_status = token_expiry::get_token_expiry_status(
+                    token_expiry_config,
+                    session_expiry,
+                    payment_intent.status,
+                );
 
-                fp_utils::when(current_timestamp > session_expiry, || {
+                fp_utils::when(!expiry_status.is_resolvable(), || {
                     Err(errors::ApiErrorResponse::ClientSecretExpired)
                 })
             }
@@ -3175,13 +3646,78 @@ pub async fn verify_payment_intent_time_and_client_secret(
                 .await
                 .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
 
-            authenticate_client_secret(Some(&cs), &payment_intent)?;
+            authenticate_client_secret(Some(&cs), &payment_intent, &state.conf.token_expiry)?;
             Ok(payment_intent)
         })
         .await
         .transpose()
 }
 
+#[cfg(feature = "v1")]
+/// Check whether the parent payment exists for the merchant in the profile of the payment, so that
+/// the payment can be linked to it. Returns the root payment of the chain of the parent payment,
+/// which the payment joins.
+pub async fn validate_parent_payment_id(
+    state: &SessionState,
+    payment_id: &id_type::PaymentId,
+    parent_payment_id: &id_type::PaymentId,
+    profile_id: &id_type::ProfileId,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<id_type::PaymentId> {
+    // The payment is created with its parent, so a new payment can only form a cycle with itself
+    fp_utils::when(parent_payment_id == payment_id, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "A payment cannot be its own parent_payment_id".to_string(),
+        }))
+    })?;
+
+    state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            parent_payment_id,
+            merchant_account.get_id(),
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "parent_payment_id {} does not exist",
+                parent_payment_id.get_string_repr()
+            ),
+        })
+        .and_then(|parent_payment_intent| {
+            get_root_payment_id_of_parent(parent_payment_intent, profile_id)
+        })
+}
+
+#[cfg(feature = "v1")]
+/// The root payment of the chain of the parent payment, which a payment in the profile joins when
+/// it is linked to the parent payment
+fn get_root_payment_id_of_parent(
+    parent_payment_intent: PaymentIntent,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<id_type::PaymentId> {
+    // The payments of a chain belong to a single profile
+    fp_utils::when(
+        parent_payment_intent.profile_id.as_ref() != Some(profile_id),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "parent_payment_id {} does not belong to the profile of the payment",
+                    parent_payment_intent.payment_id.get_string_repr()
+                ),
+            }))
+        },
+    )?;
+
+    Ok(parent_payment_intent
+        .root_payment_id
+        .unwrap_or(parent_payment_intent.payment_id))
+}
+
 #[cfg(feature = "v1")]
 /// Check whether the business details are configured in the merchant account
 pub fn validate_business_details(
@@ -3230,9 +3766,8 @@ mod tests {
 
     use super::*;
 
-    #[test]
-    fn test_authenticate_client_secret_session_not_expired() {
-        let payment_intent = PaymentIntent {
+    fn get_payment_intent(created_at: time::PrimitiveDateTime) -> PaymentIntent {
+        PaymentIntent {
             payment_id: id_type::PaymentId::try_from(Cow::Borrowed("23")).unwrap(),
             merchant_id: id_type::MerchantId::default(),
             status: storage_enums::IntentStatus::RequiresCapture,
@@ -3248,7 +3783,7 @@ mod tests {
             billing_address_id: None,
             statement_descriptor_name: None,
             statement_descriptor_suffix: None,
-            created_at: common_utils::date_time::now(),
+            created_at,
             modified_at: common_utils::date_time::now(),
             last_synced: None,
             setup_future_usage: None,
@@ -3277,8 +3812,7 @@ mod tests {
             incremental_authorization_allowed: None,
             authorization_count: None,
             session_expiry: Some(
-                common_utils::date_time::now()
-                    .saturating_add(time::Duration::seconds(consts::DEFAULT_SESSION_EXPIRY)),
+                created_at.saturating_add(time::Duration::seconds(consts::DEFAULT_SESSION_EXPIRY)),
             ),
             request_external_three_ds_authentication: None,
             charges: None,
@@ -3292,9 +3826,25 @@ mod tests {
             shipping_cost: None,
             tax_details: None,
             skip_external_tax_calculation: None,
-        };
+            amount_breakdown: None,
+            parent_payment_id: None,
+            root_payment_id: None,
+            fulfillment_status: None,
+            shipped_at: None,
+            delivered_at: None,
+        }
+    }
+
+    #[test]
+    fn test_authenticate_client_secret_session_not_expired() {
+        let payment_intent = get_payment_intent(common_utils::date_time::now());
         let req_cs = Some("1".to_string());
-        assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_ok());
+        assert!(authenticate_client_secret(
+            req_cs.as_ref(),
+            &payment_intent,
+            &TokenExpiry::default()
+        )
+        .is_ok());
         // Check if the result is an Ok variant
     }
 
@@ -3302,135 +3852,395 @@ mod tests {
     fn test_authenticate_client_secret_session_expired() {
         let created_at =
             common_utils::date_time::now().saturating_sub(time::Duration::seconds(20 * 60));
+        let payment_intent = get_payment_intent(created_at);
+        let req_cs = Some("1".to_string());
+        assert!(authenticate_client_secret(
+            req_cs.as_ref(),
+            &payment_intent,
+            &TokenExpiry::default()
+        )
+        .is_err())
+    }
+
+    #[test]
+    fn test_authenticate_client_secret_session_expired_in_grace_period() {
+        let created_at =
+            common_utils::date_time::now().saturating_sub(time::Duration::seconds(17 * 60));
         let payment_intent = PaymentIntent {
-            payment_id: id_type::PaymentId::try_from(Cow::Borrowed("23")).unwrap(),
-            merchant_id: id_type::MerchantId::default(),
-            status: storage_enums::IntentStatus::RequiresCapture,
-            amount: MinorUnit::new(200),
-            currency: None,
-            amount_captured: None,
-            customer_id: None,
-            description: None,
-            return_url: None,
-            metadata: None,
-            connector_id: None,
-            shipping_address_id: None,
-            billing_address_id: None,
-            statement_descriptor_name: None,
-            statement_descriptor_suffix: None,
-            created_at,
-            modified_at: common_utils::date_time::now(),
-            fingerprint_id: None,
-            last_synced: None,
-            setup_future_usage: None,
-            off_session: None,
-            client_secret: Some("1".to_string()),
-            active_attempt: hyperswitch_domain_models::RemoteStorageObject::ForeignID(
-                "nopes".to_string(),
-            ),
-            business_country: None,
-            business_label: None,
-            order_details: None,
-            allowed_payment_method_types: None,
-            connector_metadata: None,
-            feature_metadata: None,
-            attempt_count: 1,
-            payment_link_id: None,
-            profile_id: Some(common_utils::generate_profile_id_of_default_length()),
-            merchant_decision: None,
-            payment_confirm_source: None,
-            surcharge_applicable: None,
-            updated_by: storage_enums::MerchantStorageScheme::PostgresOnly.to_string(),
-            request_incremental_authorization: Some(
-                common_enums::RequestIncrementalAuthorization::default(),
-            ),
-            incremental_authorization_allowed: None,
-            authorization_count: None,
-            session_expiry: Some(
-                created_at.saturating_add(time::Duration::seconds(consts::DEFAULT_SESSION_EXPIRY)),
-            ),
-            request_external_three_ds_authentication: None,
-            charges: None,
-            frm_metadata: None,
-            customer_details: None,
-            billing_details: None,
-            merchant_order_reference_id: None,
-            shipping_details: None,
-            is_payment_processor_token_flow: None,
-            organization_id: id_type::OrganizationId::default(),
-            shipping_cost: None,
-            tax_details: None,
-            skip_external_tax_calculation: None,
+            status: storage_enums::IntentStatus::RequiresCustomerAction,
+            ..get_payment_intent(created_at)
         };
         let req_cs = Some("1".to_string());
-        assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent,).is_err())
+        assert!(authenticate_client_secret(
+            req_cs.as_ref(),
+            &payment_intent,
+            &TokenExpiry::default()
+        )
+        .is_ok());
     }
 
     #[test]
     fn test_authenticate_client_secret_expired() {
         let payment_intent = PaymentIntent {
-            payment_id: id_type::PaymentId::try_from(Cow::Borrowed("23")).unwrap(),
-            merchant_id: id_type::MerchantId::default(),
-            status: storage_enums::IntentStatus::RequiresCapture,
-            amount: MinorUnit::new(200),
-            currency: None,
-            amount_captured: None,
-            customer_id: None,
-            description: None,
-            return_url: None,
-            metadata: None,
-            connector_id: None,
-            shipping_address_id: None,
-            billing_address_id: None,
-            statement_descriptor_name: None,
-            statement_descriptor_suffix: None,
-            created_at: common_utils::date_time::now().saturating_sub(time::Duration::seconds(20)),
-            modified_at: common_utils::date_time::now(),
-            last_synced: None,
-            setup_future_usage: None,
-            off_session: None,
             client_secret: None,
-            fingerprint_id: None,
-            active_attempt: hyperswitch_domain_models::RemoteStorageObject::ForeignID(
-                "nopes".to_string(),
+            ..get_payment_intent(
+                common_utils::date_time::now().saturating_sub(time::Duration::seconds(20)),
+            )
+        };
+        let req_cs = Some("1".to_string());
+        assert!(authenticate_client_secret(
+            req_cs.as_ref(),
+            &payment_intent,
+            &TokenExpiry::default()
+        )
+        .is_err())
+    }
+
+    #[test]
+    fn test_payment_linked_to_a_payment_without_parent_joins_the_chain_of_the_parent() {
+        let parent_payment_intent = get_payment_intent(common_utils::date_time::now());
+        let profile_id = parent_payment_intent.profile_id.clone().unwrap();
+
+        let root_payment_id =
+            get_root_payment_id_of_parent(parent_payment_intent, &profile_id).unwrap();
+        assert_eq!(
+            root_payment_id,
+            id_type::PaymentId::try_from(Cow::Borrowed("23")).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_payment_linked_to_a_linked_payment_joins_the_chain_of_the_root_payment() {
+        let root_payment_id = id_type::PaymentId::try_from(Cow::Borrowed("1")).unwrap();
+        let parent_payment_intent = PaymentIntent {
+            parent_payment_id: Some(root_payment_id.clone()),
+            root_payment_id: Some(root_payment_id.clone()),
+            ..get_payment_intent(common_utils::date_time::now())
+        };
+        let profile_id = parent_payment_intent.profile_id.clone().unwrap();
+
+        assert_eq!(
+            get_root_payment_id_of_parent(parent_payment_intent, &profile_id).unwrap(),
+            root_payment_id
+        );
+    }
+
+    #[test]
+    fn test_payment_cannot_be_linked_to_a_payment_of_another_profile() {
+        let parent_payment_intent = get_payment_intent(common_utils::date_time::now());
+
+        assert!(get_root_payment_id_of_parent(
+            parent_payment_intent,
+            &common_utils::generate_profile_id_of_default_length()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_capture_currency_defaults_to_the_currency_of_the_payment() {
+        let connectors_with_cross_currency_capture =
+            HashSet::from([api_enums::Connector::Cybersource]);
+
+        let capture_currency = validate_and_get_capture_currency(
+            &connectors_with_cross_currency_capture,
+            Some("stripe"),
+            Some(storage_enums::Currency::USD),
+            None,
+        )
+        .unwrap();
+        assert_eq!(capture_currency, storage_enums::Currency::USD);
+
+        let capture_currency = validate_and_get_capture_currency(
+            &connectors_with_cross_currency_capture,
+            Some("stripe"),
+            Some(storage_enums::Currency::USD),
+            Some(storage_enums::Currency::USD),
+        )
+        .unwrap();
+        assert_eq!(capture_currency, storage_enums::Currency::USD);
+    }
+
+    #[test]
+    fn test_cross_currency_capture_is_allowed_only_for_the_configured_connectors() {
+        let connectors_with_cross_currency_capture =
+            HashSet::from([api_enums::Connector::Cybersource]);
+
+        let capture_currency = validate_and_get_capture_currency(
+            &connectors_with_cross_currency_capture,
+            Some("cybersource"),
+            Some(storage_enums::Currency::USD),
+            Some(storage_enums::Currency::EUR),
+        )
+        .unwrap();
+        assert_eq!(capture_currency, storage_enums::Currency::EUR);
+
+        assert!(validate_and_get_capture_currency(
+            &connectors_with_cross_currency_capture,
+            Some("stripe"),
+            Some(storage_enums::Currency::USD),
+            Some(storage_enums::Currency::EUR),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_converted_amount_is_rounded_down_to_the_minor_unit() {
+        assert_eq!(
+            get_minor_unit_from_base_unit(
+                rust_decimal::Decimal::new(123_456, 3),
+                storage_enums::Currency::USD
+            )
+            .unwrap(),
+            MinorUnit::new(12345)
+        );
+        assert_eq!(
+            get_minor_unit_from_base_unit(
+                rust_decimal::Decimal::new(1_005, 1),
+                storage_enums::Currency::JPY
+            )
+            .unwrap(),
+            MinorUnit::new(100)
+        );
+    }
+
+    #[test]
+    fn test_get_connector_account_environment() {
+        assert_eq!(
+            get_connector_account_environment(Some(true)),
+            Some(enums::ConnectorEnvironment::Sandbox)
+        );
+        assert_eq!(
+            get_connector_account_environment(Some(false)),
+            Some(enums::ConnectorEnvironment::Production)
+        );
+        assert_eq!(get_connector_account_environment(None), None);
+    }
+
+    #[test]
+    fn test_validate_connector_environment() {
+        assert!(
+            validate_connector_environment(enums::ConnectorEnvironment::Sandbox, Some(true))
+                .is_ok()
+        );
+        assert!(validate_connector_environment(
+            enums::ConnectorEnvironment::Production,
+            Some(false)
+        )
+        .is_ok());
+        assert!(
+            validate_connector_environment(enums::ConnectorEnvironment::Production, None).is_ok()
+        );
+        assert!(validate_connector_environment(
+            enums::ConnectorEnvironment::Production,
+            Some(true)
+        )
+        .is_err());
+        assert!(
+            validate_connector_environment(enums::ConnectorEnvironment::Sandbox, Some(false))
+                .is_err()
+        );
+    }
+
+    fn get_merchant_connector_account(
+        connector_name: &str,
+        merchant_connector_id: &str,
+        profile_id: &str,
+        status: common_enums::ConnectorStatus,
+    ) -> domain::MerchantConnectorAccount {
+        domain::MerchantConnectorAccount {
+            merchant_id: id_type::MerchantId::default(),
+            connector_name: connector_name.to_string(),
+            connector_account_details: Encryptable::new(
+                masking::Secret::new(serde_json::Value::default()),
+                masking::Secret::new(Vec::new()),
             ),
+            test_mode: None,
+            disabled: None,
+            merchant_connector_id: id_type::MerchantConnectorAccountId::wrap(
+                merchant_connector_id.to_string(),
+            )
+            .unwrap(),
+            payment_methods_enabled: None,
+            connector_type: ConnectorType::PaymentProcessor,
+            metadata: None,
+            frm_configs: None,
+            connector_label: None,
             business_country: None,
             business_label: None,
-            order_details: None,
-            allowed_payment_method_types: None,
-            connector_metadata: None,
-            feature_metadata: None,
-            attempt_count: 1,
-            payment_link_id: None,
-            profile_id: Some(common_utils::generate_profile_id_of_default_length()),
-            merchant_decision: None,
-            payment_confirm_source: None,
-            surcharge_applicable: None,
-            updated_by: storage_enums::MerchantStorageScheme::PostgresOnly.to_string(),
-            request_incremental_authorization: Some(
-                common_enums::RequestIncrementalAuthorization::default(),
+            business_sub_label: None,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+            connector_webhook_details: None,
+            profile_id: id_type::ProfileId::try_from(Cow::Owned(profile_id.to_string())).unwrap(),
+            applepay_verified_domains: None,
+            pm_auth_config: None,
+            status,
+            connector_wallets_details: None,
+            additional_merchant_data: None,
+            version: hyperswitch_domain_models::consts::API_VERSION,
+            request_timeout_secs: None,
+            webhook_identifier: None,
+            environment: None,
+            maintenance_windows: None,
+            pending_connector_account_details: None,
+            credential_rotation_status: None,
+        }
+    }
+
+    fn get_connector_override(
+        connector: api_enums::RoutableConnectors,
+        merchant_connector_id: Option<&str>,
+    ) -> api::routing::RoutableConnectorChoice {
+        api::routing::RoutableConnectorChoice {
+            choice_kind: api::routing::RoutableChoiceKind::FullStruct,
+            connector,
+            merchant_connector_id: merchant_connector_id.map(|merchant_connector_id| {
+                id_type::MerchantConnectorAccountId::wrap(merchant_connector_id.to_string())
+                    .unwrap()
+            }),
+        }
+    }
+
+    fn get_error_message(
+        result: RouterResult<api::routing::RoutableConnectorChoice>,
+    ) -> Option<String> {
+        match result.map_err(|error| error.current_context().clone()) {
+            Err(errors::ApiErrorResponse::InvalidRequestData { message }) => Some(message),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_connector_override_resolves_the_only_account_of_the_connector() {
+        let profile_id = id_type::ProfileId::try_from(Cow::Borrowed("pro_1")).unwrap();
+        let merchant_connector_accounts = vec![
+            get_merchant_connector_account(
+                "stripe",
+                "mca_stripe",
+                "pro_1",
+                common_enums::ConnectorStatus::Active,
             ),
-            incremental_authorization_allowed: None,
-            authorization_count: None,
-            session_expiry: Some(
-                common_utils::date_time::now()
-                    .saturating_add(time::Duration::seconds(consts::DEFAULT_SESSION_EXPIRY)),
+            get_merchant_connector_account(
+                "adyen",
+                "mca_adyen",
+                "pro_1",
+                common_enums::ConnectorStatus::Active,
             ),
-            request_external_three_ds_authentication: None,
-            charges: None,
-            frm_metadata: None,
-            customer_details: None,
-            billing_details: None,
-            merchant_order_reference_id: None,
-            shipping_details: None,
-            is_payment_processor_token_flow: None,
-            organization_id: id_type::OrganizationId::default(),
-            shipping_cost: None,
-            tax_details: None,
-            skip_external_tax_calculation: None,
-        };
-        let req_cs = Some("1".to_string());
-        assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_err())
+        ];
+
+        let connector = select_connector_override_account(
+            merchant_connector_accounts,
+            &profile_id,
+            get_connector_override(api_enums::RoutableConnectors::Stripe, None),
+        )
+        .unwrap();
+
+        assert_eq!(
+            connector,
+            get_connector_override(api_enums::RoutableConnectors::Stripe, Some("mca_stripe"))
+        );
+    }
+
+    #[test]
+    fn test_connector_override_of_another_profile_is_rejected() {
+        let profile_id = id_type::ProfileId::try_from(Cow::Borrowed("pro_1")).unwrap();
+        let merchant_connector_accounts = vec![get_merchant_connector_account(
+            "stripe",
+            "mca_stripe",
+            "pro_2",
+            common_enums::ConnectorStatus::Active,
+        )];
+
+        let result = select_connector_override_account(
+            merchant_connector_accounts,
+            &profile_id,
+            get_connector_override(api_enums::RoutableConnectors::Stripe, None),
+        );
+
+        assert_eq!(
+            get_error_message(result),
+            Some(
+                "The connector `stripe` is not configured for the profile of the payment"
+                    .to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_connector_override_with_multiple_accounts_requires_the_account() {
+        let profile_id = id_type::ProfileId::try_from(Cow::Borrowed("pro_1")).unwrap();
+        let merchant_connector_accounts = vec![
+            get_merchant_connector_account(
+                "stripe",
+                "mca_stripe_1",
+                "pro_1",
+                common_enums::ConnectorStatus::Active,
+            ),
+            get_merchant_connector_account(
+                "stripe",
+                "mca_stripe_2",
+                "pro_1",
+                common_enums::ConnectorStatus::Active,
+            ),
+        ];
+
+        let result = select_connector_override_account(
+            merchant_connector_accounts.clone(),
+            &profile_id,
+            get_connector_override(api_enums::RoutableConnectors::Stripe, None),
+        );
+        assert!(get_error_message(result).is_some_and(
+            |message| message.contains("the `merchant_connector_id` must be provided")
+        ));
+
+        let connector = select_connector_override_account(
+            merchant_connector_accounts,
+            &profile_id,
+            get_connector_override(api_enums::RoutableConnectors::Stripe, Some("mca_stripe_2")),
+        )
+        .unwrap();
+        assert_eq!(
+            connector,
+            get_connector_override(api_enums::RoutableConnectors::Stripe, Some("mca_stripe_2"))
+        );
+    }
+
+    #[test]
+    fn test_connector_override_of_an_account_of_another_connector_is_rejected() {
+        let profile_id = id_type::ProfileId::try_from(Cow::Borrowed("pro_1")).unwrap();
+        let merchant_connector_accounts = vec![get_merchant_connector_account(
+            "adyen",
+            "mca_adyen",
+            "pro_1",
+            common_enums::ConnectorStatus::Active,
+        )];
+
+        let result = select_connector_override_account(
+            merchant_connector_accounts,
+            &profile_id,
+            get_connector_override(api_enums::RoutableConnectors::Stripe, Some("mca_adyen")),
+        );
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_connector_override_of_an_inactive_account_is_rejected() {
+        let profile_id = id_type::ProfileId::try_from(Cow::Borrowed("pro_1")).unwrap();
+        let merchant_connector_accounts = vec![get_merchant_connector_account(
+            "stripe",
+            "mca_stripe",
+            "pro_1",
+            common_enums::ConnectorStatus::Inactive,
+        )];
+
+        let result = select_connector_override_account(
+            merchant_connector_accounts,
+            &profile_id,
+            get_connector_override(api_enums::RoutableConnectors::Stripe, None),
+        );
+
+        assert!(get_error_message(result).is_some_and(|message| message.contains("is not active")));
     }
 }
 
@@ -3476,6 +4286,39 @@ pub enum MerchantConnectorAccountType {
     CacheVal(api_models::admin::MerchantConnectorDetails),
 }
 
+/// The environment of a connector account according to its test mode, the connector account is
+/// in the sandbox of the connector when it is in test mode. The environment is not known when the
+/// test mode of the connector account is not set.
+pub fn get_connector_account_environment(
+    test_mode: Option<bool>,
+) -> Option<enums::ConnectorEnvironment> {
+    test_mode.map(|test_mode| {
+        if test_mode {
+            enums::ConnectorEnvironment::Sandbox
+        } else {
+            enums::ConnectorEnvironment::Production
+        }
+    })
+}
+
+/// Validate that the environment of the connector account agrees with its test mode, so that live
+/// transactions are not processed through a sandbox connector account and vice versa
+pub fn validate_connector_environment(
+    connector_environment: enums::ConnectorEnvironment,
+    test_mode: Option<bool>,
+) -> RouterResult<()> {
+    match get_connector_account_environment(test_mode) {
+        Some(test_mode_environment) if test_mode_environment != connector_environment => {
+            Err(report!(errors::ApiErrorResponse::EnvironmentMismatch {
+                message: format!(
+                    "A {connector_environment} connector account cannot be used in the {test_mode_environment} of the connector"
+                ),
+            }))
+        }
+        Some(_) | None => Ok(()),
+    }
+}
+
 impl MerchantConnectorAccountType {
     pub fn get_metadata(&self) -> Option<masking::Secret<serde_json::Value>> {
         match self {
@@ -3498,6 +4341,22 @@ impl MerchantConnectorAccountType {
         }
     }
 
+    pub fn get_environment(&self) -> Option<enums::ConnectorEnvironment> {
+        match self {
+            Self::DbVal(val) => val.environment,
+            Self::CacheVal(_) => None,
+        }
+    }
+
+    pub fn get_connector_request_timeout(&self) -> Option<u64> {
+        match self {
+            Self::DbVal(val) => val
+                .request_timeout_secs
+                .and_then(|timeout| u64::try_from(timeout).ok()),
+            Self::CacheVal(_) => None,
+        }
+    }
+
     pub fn is_disabled(&self) -> bool {
         match self {
             Self::DbVal(ref inner) => inner.disabled.unwrap_or(false),
@@ -3736,6 +4595,7 @@ pub fn router_data_type_conversion<F1, F2, Req1, Req2, Res1, Res2>(
         connector_api_version: router_data.connector_api_version,
         connector_http_status_code: router_data.connector_http_status_code,
         external_latency: router_data.external_latency,
+        connector_network_retry_count: router_data.connector_network_retry_count,
         apple_pay_flow: router_data.apple_pay_flow,
         frm_metadata: router_data.frm_metadata,
         refund_id: router_data.refund_id,
@@ -3743,6 +4603,7 @@ pub fn router_data_type_conversion<F1, F2, Req1, Req2, Res1, Res2>(
         connector_response: router_data.connector_response,
         integrity_check: Ok(()),
         connector_wallets_details: router_data.connector_wallets_details,
+        connector_request_timeout_secs: router_data.connector_request_timeout_secs,
         additional_merchant_data: router_data.additional_merchant_data,
         header_payload: router_data.header_payload,
     }
@@ -3774,6 +4635,7 @@ pub fn get_attempt_type(
                     | enums::AttemptStatus::AuthenticationPending
                     | enums::AttemptStatus::AuthenticationSuccessful
                     | enums::AttemptStatus::Authorized
+                    | enums::AttemptStatus::PartiallyAuthorized
                     | enums::AttemptStatus::Charged
                     | enums::AttemptStatus::Authorizing
                     | enums::AttemptStatus::CodInitiated
@@ -3960,6 +4822,15 @@ impl AttemptType {
             profile_id: old_payment_attempt.profile_id,
             shipping_cost: old_payment_attempt.shipping_cost,
             order_tax_amount: None,
+            fraud_score: None,
+            fraud_decision: None,
+            approved_amount: None,
+            expected_settlement_date: None,
+            card_hash: None,
+            retry_advice: None,
+            tags: old_payment_attempt.tags,
+            is_connector_override: None,
+            stepped_up_from_attempt_id: None,
         }
     }
 
@@ -4073,6 +4944,7 @@ pub fn is_manual_retry_allowed(
             | enums::AttemptStatus::AuthenticationPending
             | enums::AttemptStatus::AuthenticationSuccessful
             | enums::AttemptStatus::Authorized
+            | enums::AttemptStatus::PartiallyAuthorized
             | enums::AttemptStatus::Charged
             | enums::AttemptStatus::Authorizing
             | enums::AttemptStatus::CodInitiated
@@ -4121,6 +4993,9 @@ pub fn is_manual_retry_allowed(
 #[cfg(test)]
 mod test {
     #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
     #[test]
     fn test_client_secret_parse() {
         let client_secret1 = "pay_3TgelAms4RQec8xSStjF_secret_fc34taHLw1ekPgNh92qr";
@@ -4141,6 +5016,210 @@ mod test {
             super::get_payment_id_from_client_secret(client_secret3).unwrap()
         );
     }
+
+    #[test]
+    fn test_host_in_allowed_domain() {
+        assert!(super::is_host_in_allowed_domain(
+            "example.com",
+            "example.com"
+        ));
+        assert!(super::is_host_in_allowed_domain(
+            "Example.com",
+            "example.com"
+        ));
+        assert!(!super::is_host_in_allowed_domain(
+            "shop.example.com",
+            "example.com"
+        ));
+        assert!(super::is_host_in_allowed_domain(
+            "shop.example.com",
+            "*.example.com"
+        ));
+        assert!(super::is_host_in_allowed_domain(
+            "a.shop.example.com",
+            "*.example.com"
+        ));
+        assert!(!super::is_host_in_allowed_domain(
+            "example.com",
+            "*.example.com"
+        ));
+        assert!(!super::is_host_in_allowed_domain(
+            "badexample.com",
+            "*.example.com"
+        ));
+        assert!(!super::is_host_in_allowed_domain(
+            "example.com.evil.io",
+            "*.example.com"
+        ));
+    }
+
+    #[test]
+    fn test_validate_allowed_return_url_domains() {
+        let valid_domains = ["example.com".to_string(), "*.example.com".to_string()];
+        assert!(super::validate_allowed_return_url_domains(&valid_domains).is_ok());
+
+        for invalid_domain in [
+            "",
+            "*.",
+            "https://example.com",
+            "*.*.example.com",
+            "example.com/path",
+        ] {
+            assert!(
+                super::validate_allowed_return_url_domains(&[invalid_domain.to_string()]).is_err()
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_customer_pii_encryption_fields() {
+        let valid_fields = [
+            "metadata.national_id".to_string(),
+            "metadata.tax-id".to_string(),
+        ];
+        assert!(super::validate_customer_pii_encryption_fields(&valid_fields).is_ok());
+
+        for invalid_field in [
+            "",
+            "name",
+            "metadata.",
+            "metadata.address.line1",
+            "metadata.national id",
+            "metadata.$id",
+        ] {
+            assert!(
+                super::validate_customer_pii_encryption_fields(&[invalid_field.to_string()])
+                    .is_err()
+            );
+        }
+
+        let duplicate_fields = [
+            "metadata.national_id".to_string(),
+            "metadata.national_id".to_string(),
+        ];
+        assert!(super::validate_customer_pii_encryption_fields(&duplicate_fields).is_err());
+
+        let too_many_fields = (0..=crate::consts::MAX_CUSTOMER_PII_ENCRYPTION_FIELDS)
+            .map(|index| format!("metadata.field_{index}"))
+            .collect::<Vec<_>>();
+        assert!(super::validate_customer_pii_encryption_fields(&too_many_fields).is_err());
+    }
+
+    #[test]
+    fn test_validate_capture_can_be_voided() {
+        use crate::types::storage::{self, enums};
+
+        let capture = |status| storage::Capture {
+            capture_id: "capture_1".to_string(),
+            payment_id: common_utils::id_type::PaymentId::default(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            status,
+            amount: common_utils::types::MinorUnit::new(1000),
+            currency: Some(enums::Currency::USD),
+            connector: "stripe".to_string(),
+            error_message: None,
+            error_code: None,
+            error_reason: None,
+            tax_amount: None,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+            authorized_attempt_id: "attempt_1".to_string(),
+            connector_capture_id: None,
+            capture_sequence: 1,
+            connector_response_reference_id: None,
+        };
+
+        let today = time::macros::date!(2024 - 11 - 07);
+        let tomorrow = time::macros::date!(2024 - 11 - 08);
+
+        // A charged capture is reversed until it is settled
+        assert!(super::validate_capture_can_be_voided(
+            &capture(enums::CaptureStatus::Charged),
+            None,
+            today
+        )
+        .is_ok());
+        assert!(super::validate_capture_can_be_voided(
+            &capture(enums::CaptureStatus::Charged),
+            Some(tomorrow),
+            today
+        )
+        .is_ok());
+        assert!(super::validate_capture_can_be_voided(
+            &capture(enums::CaptureStatus::Charged),
+            Some(today),
+            today
+        )
+        .is_err());
+
+        // The outcome of a capture without a connector reference is not known either, as the
+        // response of the connector may have been lost
+        let sent_capture = storage::Capture {
+            connector_capture_id: Some("connector_capture_1".to_string()),
+            ..capture(enums::CaptureStatus::Started)
+        };
+        assert!(super::validate_capture_can_be_voided(&sent_capture, None, today).is_err());
+
+        for status in [
+            enums::CaptureStatus::Started,
+            enums::CaptureStatus::Pending,
+            enums::CaptureStatus::Failed,
+            enums::CaptureStatus::Voided,
+            enums::CaptureStatus::Reversed,
+        ] {
+            assert!(super::validate_capture_can_be_voided(&capture(status), None, today).is_err());
+        }
+    }
+
+    #[test]
+    fn test_get_mandate_period_start() {
+        // A Thursday
+        let now = time::macros::datetime!(2024-11-07 15:30:00);
+
+        assert_eq!(
+            get_mandate_period_start(storage_enums::MandateFrequency::Daily, now),
+            time::macros::datetime!(2024-11-07 00:00:00)
+        );
+        assert_eq!(
+            get_mandate_period_start(storage_enums::MandateFrequency::Weekly, now),
+            time::macros::datetime!(2024-11-04 00:00:00)
+        );
+        assert_eq!(
+            get_mandate_period_start(storage_enums::MandateFrequency::Monthly, now),
+            time::macros::datetime!(2024-11-01 00:00:00)
+        );
+        assert_eq!(
+            get_mandate_period_start(storage_enums::MandateFrequency::Yearly, now),
+            time::macros::datetime!(2024-01-01 00:00:00)
+        );
+    }
+
+    #[test]
+    fn test_verify_mandate_constraints_rejects_a_second_charge_in_the_period() {
+        let constraints = storage_enums::MandateConstraints {
+            max_amount_per_transaction: Some(MinorUnit::new(500)),
+            total_amount: Some(MinorUnit::new(1000)),
+            frequency: Some(storage_enums::MandateFrequency::Daily),
+        };
+        let now = common_utils::date_time::now();
+        let yesterday = now.saturating_sub(time::Duration::days(1));
+
+        assert!(verify_mandate_constraints(
+            MinorUnit::new(500),
+            Some(500),
+            Some(yesterday),
+            constraints
+        )
+        .is_ok());
+        assert!(
+            verify_mandate_constraints(MinorUnit::new(500), Some(500), Some(now), constraints)
+                .is_err()
+        );
+        assert!(verify_mandate_constraints(MinorUnit::new(600), None, None, constraints).is_err());
+        assert!(
+            verify_mandate_constraints(MinorUnit::new(500), Some(600), None, constraints).is_err()
+        );
+    }
 }
 
 #[instrument(skip_all)]
@@ -5240,6 +6319,135 @@ pub fn validate_intent_fulfillment_expiry(
     }
 }
 
+// This function validates the idempotency lock ttl set by the merchant in the request
+pub fn validate_idempotency_ttl(
+    idempotency_ttl_in_seconds: u32,
+) -> Result<(), errors::ApiErrorResponse> {
+    if !(consts::MIN_IDEMPOTENCY_TTL_IN_SECONDS..=consts::MAX_IDEMPOTENCY_TTL_IN_SECONDS)
+        .contains(&idempotency_ttl_in_seconds)
+    {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "idempotency_ttl_in_seconds should be between {} and {} seconds.",
+                consts::MIN_IDEMPOTENCY_TTL_IN_SECONDS,
+                consts::MAX_IDEMPOTENCY_TTL_IN_SECONDS
+            ),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+// This function validates that the fields designated for encryption by the merchant are distinct
+// keys of the customer metadata
+pub fn validate_customer_pii_encryption_fields(
+    customer_pii_encryption_fields: &[String],
+) -> Result<(), errors::ApiErrorResponse> {
+    if customer_pii_encryption_fields.len() > consts::MAX_CUSTOMER_PII_ENCRYPTION_FIELDS {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "customer_pii_encryption_fields must not contain more than {} fields",
+                consts::MAX_CUSTOMER_PII_ENCRYPTION_FIELDS
+            ),
+        });
+    }
+
+    if let Some(field) = customer_pii_encryption_fields.iter().find(|field| {
+        hyperswitch_domain_models::customer::get_encryptable_metadata_key(field).is_none()
+    }) {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "customer_pii_encryption_fields contains an invalid field `{field}`, fields must be of the form `{}<key>` where the key contains at most {} alphanumeric characters, `_` or `-`",
+                hyperswitch_domain_models::customer::ENCRYPTABLE_METADATA_FIELD_PREFIX,
+                hyperswitch_domain_models::customer::MAX_ENCRYPTABLE_METADATA_KEY_LENGTH
+            ),
+        });
+    }
+
+    let mut fields = HashSet::new();
+    match customer_pii_encryption_fields
+        .iter()
+        .find(|field| !fields.insert(field.as_str()))
+    {
+        Some(field) => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "customer_pii_encryption_fields contains the field `{field}` more than once"
+            ),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Validate the domains to which the customer may be redirected after a payment. A domain is a
+/// host name, optionally prefixed with `*.` to allow all of its subdomains.
+pub fn validate_allowed_return_url_domains(
+    allowed_return_url_domains: &[String],
+) -> Result<(), errors::ApiErrorResponse> {
+    match allowed_return_url_domains.iter().find(|allowed_domain| {
+        let domain = allowed_domain
+            .strip_prefix("*.")
+            .unwrap_or(allowed_domain.as_str());
+        domain.is_empty() || domain.contains('*') || url::Host::parse(domain).is_err()
+    }) {
+        Some(allowed_domain) => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "allowed_return_url_domains contains an invalid domain `{allowed_domain}`, domains must be host names such as `example.com` or `*.example.com`"
+            ),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Check whether the host is the allowed domain, or one of its subdomains if the allowed domain is
+/// prefixed with `*.`
+fn is_host_in_allowed_domain(host: &str, allowed_domain: &str) -> bool {
+    let host = host.to_ascii_lowercase();
+    let allowed_domain = allowed_domain.to_ascii_lowercase();
+
+    match allowed_domain.strip_prefix("*.") {
+        Some(parent_domain) => host
+            .strip_suffix(parent_domain)
+            .and_then(|subdomain| subdomain.strip_suffix('.'))
+            .is_some_and(|subdomain| !subdomain.is_empty()),
+        None => host == allowed_domain,
+    }
+}
+
+/// Validate the token of the wallet payment method data, so that a malformed wallet payload is
+/// rejected before the payment is routed instead of failing at the connector
+pub fn validate_wallet_data(request: &api::PaymentsRequest) -> RouterResult<()> {
+    request.validate_wallet_data().map_err(|error| {
+        let message = error.current_context().to_string();
+        error.change_context(errors::ApiErrorResponse::InvalidRequestData { message })
+    })
+}
+
+/// Validate that the customer may be redirected to the return url of the payment. Any return url
+/// is allowed when the merchant has not configured the allowed domains, in which case a warning is
+/// logged, so that merchants can be nudged to configure them.
+pub fn validate_return_url_domain(
+    return_url: &url::Url,
+    merchant_account: &domain::MerchantAccount,
+) -> RouterResult<()> {
+    let Some(allowed_return_url_domains) = merchant_account.get_allowed_return_url_domains() else {
+        logger::warn!(
+            merchant_id = merchant_account.get_id().get_string_repr(),
+            "The allowed return url domains are not configured for the merchant, the return_url is not validated"
+        );
+        return Ok(());
+    };
+
+    let is_allowed = return_url.host_str().is_some_and(|host| {
+        allowed_return_url_domains
+            .iter()
+            .any(|allowed_domain| is_host_in_allowed_domain(host, allowed_domain))
+    });
+
+    fp_utils::when(!is_allowed, || {
+        Err(report!(errors::ApiErrorResponse::InvalidReturnUrl))
+    })
+}
+
 pub fn add_connector_response_to_additional_payment_data(
     additional_payment_data: api_models::payments::AdditionalPaymentData,
     connector_response_payment_method_data: AdditionalPaymentMethodConnectorResponse,
@@ -5331,7 +6539,13 @@ pub async fn get_payment_method_details_from_payment_token(
     storage_scheme: enums::MerchantStorageScheme,
     business_profile: &domain::Profile,
 ) -> RouterResult<Option<(domain::PaymentMethodData, enums::PaymentMethod)>> {
-    let hyperswitch_token = if let Some(token) = payment_attempt.payment_token.clone() {
+    let hyperswitch_token = if let Some(token_data) = payment_attempt
+        .payment_token
+        .as_deref()
+        .and_then(bank_account_tokenization::get_payment_token_data)
+    {
+        Some(token_data)
+    } else if let Some(token) = payment_attempt.payment_token.clone() {
         let redis_conn = state
             .store
             .get_redis_conn()
@@ -5449,6 +6663,17 @@ pub async fn get_payment_method_details_from_payment_token(
             .await
         }
 
+        storage::PaymentTokenData::BankAccountToken(bank_account_token) => {
+            bank_account_tokenization::get_bank_debit_data_for_token(
+                state,
+                key_store,
+                &bank_account_token,
+                payment_intent.customer_id.as_ref(),
+            )
+            .await
+            .map(|bank_debit| Some((bank_debit, enums::PaymentMethod::BankDebit)))
+        }
+
         storage::PaymentTokenData::WalletToken(_) => Ok(None),
     }
 }
@@ -0,0 +1,418 @@
+//! Enforcement of one payment per merchant order reference.
+//!
+//! When requested, the `merchant_order_reference_id` of a payment is registered through a reverse
+//! lookup once the payment is created, so that a payment created again for the same order
+//! reference returns the existing payment instead of charging the customer twice. Concurrent
+//! submissions for the same order reference are serialized by a lock acquired through SETNX, which
+//! is held until the payment is created and its order reference is registered.
+
+use std::future::Future;
+
+use api_models::payments::{HeaderPayload, PaymentsRequest, PaymentsResponse};
+use common_utils::id_type;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments,
+    },
+    db::payment_processing_claim,
+    routes::{app::ReqState, SessionState},
+    services,
+    types::{api, domain, storage},
+};
+
+/// The lookup id of the merchant order reference in the namespace of the merchant
+fn get_merchant_order_reference_lookup_id(merchant_order_reference_id: &str) -> String {
+    format!("merchant_order_reference_{merchant_order_reference_id}")
+}
+
+/// The lookup id of the merchant order reference registered before the lookups were namespaced
+fn get_legacy_merchant_order_reference_lookup_id(
+    merchant_id: &id_type::MerchantId,
+    merchant_order_reference_id: &str,
+) -> String {
+    format!(
+        "merchant_order_reference_{}_{merchant_order_reference_id}",
+        merchant_id.get_string_repr()
+    )
+}
+
+fn get_merchant_order_reference_lock_key(
+    merchant_id: &id_type::MerchantId,
+    merchant_order_reference_id: &str,
+) -> String {
+    format!(
+        "merchant_order_reference_lock_{}_{merchant_order_reference_id}",
+        merchant_id.get_string_repr()
+    )
+}
+
+/// The outcome of claiming a merchant order reference for the payment being created
+enum MerchantOrderReferenceClaim {
+    /// No payment exists for the order reference, the payment can be created
+    Claimed,
+    /// A payment was already created for the order reference
+    ExistingPayment(id_type::PaymentId),
+}
+
+/// Find the payment which was created for the merchant order reference, if any
+#[instrument(skip_all)]
+async fn find_payment_for_merchant_order_reference(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    merchant_order_reference_id: &str,
+) -> RouterResult<Option<id_type::PaymentId>> {
+    let lookup = match state
+        .store
+        .get_namespaced_lookup_by_lookup_id(
+            &get_merchant_order_reference_lookup_id(merchant_order_reference_id),
+            merchant_account.get_id(),
+            Some(&get_legacy_merchant_order_reference_lookup_id(
+                merchant_account.get_id(),
+                merchant_order_reference_id,
+            )),
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(lookup) => lookup,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find the merchant order reference lookup")
+        }
+    };
+
+    id_type::PaymentId::wrap(lookup.pk_id)
+        .map(Some)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid payment id in the merchant order reference lookup")
+}
+
+/// Create the payment through `create_payment`, returning the existing payment instead if only one
+/// payment can be created for the merchant order reference of the request and it was already
+/// created.
+///
+/// The order reference is registered once the payment has been created, while the order reference
+/// is still claimed.
+#[cfg(feature = "v1")]
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub async fn payments_create_for_merchant_order_reference<F, Fut>(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    header_payload: HeaderPayload,
+    req: PaymentsRequest,
+    create_payment: F,
+) -> RouterResponse<PaymentsResponse>
+where
+    F: FnOnce(
+        SessionState,
+        ReqState,
+        domain::MerchantAccount,
+        Option<id_type::ProfileId>,
+        domain::MerchantKeyStore,
+        HeaderPayload,
+        PaymentsRequest,
+    ) -> Fut,
+    Fut: Future<Output = RouterResponse<PaymentsResponse>>,
+{
+    let Some(merchant_order_reference_id) = req
+        .get_unique_merchant_order_reference_id()
+        .map(ToOwned::to_owned)
+    else {
+        return create_payment(
+            state,
+            req_state,
+            merchant_account,
+            profile_id,
+            key_store,
+            header_payload,
+            req,
+        )
+        .await;
+    };
+
+    let payment_id = req
+        .payment_id
+        .as_ref()
+        .map(|payment_id| payment_id.get_payment_intent_id())
+        .transpose()
+        .change_context(errors::ApiErrorResponse::PaymentNotFound)?
+        .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "payment_id",
+        })?;
+
+    match claim_merchant_order_reference(
+        &state,
+        &merchant_account,
+        &merchant_order_reference_id,
+        &payment_id,
+    )
+    .await?
+    {
+        MerchantOrderReferenceClaim::Claimed => {
+            let merchant_id = merchant_account.get_id().clone();
+            let storage_scheme = merchant_account.storage_scheme;
+            let response = create_payment(
+                state.clone(),
+                req_state,
+                merchant_account,
+                profile_id,
+                key_store,
+                header_payload,
+                req,
+            )
+            .await;
+
+            // The payment which failed to be created is not registered, so that the order can be
+            // paid for by another payment
+            if response.is_ok() {
+                if let Err(error) = insert_merchant_order_reference_lookup(
+                    &state,
+                    &merchant_id,
+                    &merchant_order_reference_id,
+                    &payment_id,
+                    storage_scheme,
+                )
+                .await
+                {
+                    // The lock is left to expire instead of being released, so that the order
+                    // reference is not paid for again while it refers to the created payment
+                    logger::error!(
+                        ?error,
+                        "Failed to register the merchant order reference of the created payment"
+                    );
+                    return Err(error);
+                }
+            }
+
+            release_merchant_order_reference(
+                &state,
+                &merchant_id,
+                &merchant_order_reference_id,
+                &payment_id,
+            )
+            .await;
+            response
+        }
+        MerchantOrderReferenceClaim::ExistingPayment(existing_payment_id) => {
+            logger::info!(
+                ?existing_payment_id,
+                "Returning the existing payment for the merchant order reference"
+            );
+            payments::payments_core::<
+                api::PSync,
+                PaymentsResponse,
+                _,
+                _,
+                _,
+                payments::PaymentData<api::PSync>,
+            >(
+                state,
+                req_state,
+                merchant_account,
+                profile_id,
+                key_store,
+                payments::PaymentStatus,
+                api::PaymentsRetrieveRequest {
+                    resource_id: api::PaymentIdType::PaymentIntentId(existing_payment_id),
+                    ..Default::default()
+                },
+                services::AuthFlow::Merchant,
+                payments::CallConnectorAction::Avoid,
+                None,
+                header_payload,
+            )
+            .await
+        }
+    }
+}
+
+/// Claim the merchant order reference for the payment being created.
+///
+/// If a payment was already created for the order reference, it is returned. If another payment is
+/// being created for the order reference at the same time, the request is rejected.
+#[instrument(skip_all)]
+async fn claim_merchant_order_reference(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    merchant_order_reference_id: &str,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<MerchantOrderReferenceClaim> {
+    if let Some(existing_payment_id) = find_payment_for_merchant_order_reference(
+        state,
+        merchant_account,
+        merchant_order_reference_id,
+    )
+    .await?
+    {
+        return Ok(MerchantOrderReferenceClaim::ExistingPayment(
+            existing_payment_id,
+        ));
+    }
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let lock_key = get_merchant_order_reference_lock_key(
+        merchant_account.get_id(),
+        merchant_order_reference_id,
+    );
+
+    let lock_reply = redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &lock_key,
+            payment_id.get_string_repr(),
+            Some(i64::from(
+                state.conf.lock_settings.redis_lock_expiry_seconds,
+            )),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to acquire the merchant order reference lock")?;
+
+    match lock_reply {
+        redis_interface::SetnxReply::KeySet => Ok(MerchantOrderReferenceClaim::Claimed),
+        redis_interface::SetnxReply::KeyNotSet => {
+            // The payment holding the lock may have been created since the lookup was checked
+            if let Some(existing_payment_id) = find_payment_for_merchant_order_reference(
+                state,
+                merchant_account,
+                merchant_order_reference_id,
+            )
+            .await?
+            {
+                return Ok(MerchantOrderReferenceClaim::ExistingPayment(
+                    existing_payment_id,
+                ));
+            }
+
+            let in_progress_payment_id = redis_conn
+                .get_key::<String>(&lock_key)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to get the merchant order reference lock")?;
+
+            Err(errors::ApiErrorResponse::DuplicatePayment {
+                payment_id: id_type::PaymentId::wrap(in_progress_payment_id)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Invalid payment id in the merchant order reference lock")?,
+            })
+            .attach_printable("A payment is already being created for the merchant order reference")
+        }
+    }
+}
+
+/// Release the lock of the payment on the merchant order reference once the payment is created, or
+/// has failed to be created. The lock is released only if it is still held by the payment, since
+/// it may have expired and been acquired by another payment in the meantime.
+#[instrument(skip_all)]
+async fn release_merchant_order_reference(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    merchant_order_reference_id: &str,
+    payment_id: &id_type::PaymentId,
+) {
+    let lock_key = get_merchant_order_reference_lock_key(merchant_id, merchant_order_reference_id);
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to release the merchant order reference lock, it expires after the lock expiry"
+            );
+            return;
+        }
+    };
+
+    match payment_processing_claim::release_claim(
+        &redis_conn,
+        &lock_key,
+        payment_id.get_string_repr(),
+    )
+    .await
+    {
+        Ok(true) => {}
+        Ok(false) => logger::warn!(
+            "The merchant order reference lock expired before the payment released it"
+        ),
+        Err(error) => logger::error!(
+            ?error,
+            "Failed to release the merchant order reference lock, it expires after the lock expiry"
+        ),
+    }
+}
+
+/// Register the merchant order reference of the created payment, so that subsequent payments for
+/// the same order reference return the created payment
+#[instrument(skip_all)]
+async fn insert_merchant_order_reference_lookup(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    merchant_order_reference_id: &str,
+    payment_id: &id_type::PaymentId,
+    storage_scheme: storage::enums::MerchantStorageScheme,
+) -> RouterResult<()> {
+    state
+        .store
+        .insert_namespaced_reverse_lookup(
+            storage::ReverseLookupNew {
+                lookup_id: get_merchant_order_reference_lookup_id(merchant_order_reference_id),
+                pk_id: payment_id.get_string_repr().to_owned(),
+                sk_id: merchant_id.get_string_repr().to_owned(),
+                source: "payment_intent".to_string(),
+                updated_by: storage_scheme.to_string(),
+            },
+            merchant_id,
+            storage_scheme,
+        )
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
+            payment_id: payment_id.clone(),
+        })
+        .attach_printable("Failed to insert reverse lookup for the merchant order reference")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn merchant_id() -> id_type::MerchantId {
+        id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1")).unwrap()
+    }
+
+    #[test]
+    fn test_merchant_order_reference_lookup_id_is_namespaced_by_merchant() {
+        assert_eq!(
+            get_merchant_order_reference_lookup_id("order_1"),
+            "merchant_order_reference_order_1"
+        );
+        assert_eq!(
+            get_legacy_merchant_order_reference_lookup_id(&merchant_id(), "order_1"),
+            "merchant_order_reference_merchant_1_order_1"
+        );
+    }
+
+    #[test]
+    fn test_merchant_order_reference_lock_key_is_distinct_from_the_lookup_id() {
+        let lock_key = get_merchant_order_reference_lock_key(&merchant_id(), "order_1");
+        assert_eq!(lock_key, "merchant_order_reference_lock_merchant_1_order_1");
+        assert_ne!(
+            lock_key,
+            get_legacy_merchant_order_reference_lookup_id(&merchant_id(), "order_1")
+        );
+    }
+}
@@ -223,6 +223,17 @@ pub trait Domain<F: Clone, R, D>: Send + Sync {
         Ok(false)
     }
 
+    /// Record the payment against the velocity limits of the customer, rejecting the payment if
+    /// it exceeds any of the limits configured for the merchant
+    async fn guard_payment_against_velocity_limits<'a>(
+        &'a self,
+        _state: &SessionState,
+        _merchant_account: &domain::MerchantAccount,
+        _payment_data: &mut D,
+    ) -> CustomResult<(), errors::ApiErrorResponse> {
+        Ok(())
+    }
+
     async fn store_extended_card_info_temporarily<'a>(
         &'a self,
         _state: &SessionState,
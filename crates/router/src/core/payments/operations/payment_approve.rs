@@ -192,6 +192,10 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCaptureRequest>
             recurring_details: None,
             poll_config: None,
             tax_data: None,
+            cvv_recollection_policy: None,
+            connector_network_retry_count: None,
+            statement_descriptor_length_policy: merchant_account
+                .get_statement_descriptor_length_policy(),
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
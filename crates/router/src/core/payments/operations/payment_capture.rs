@@ -95,10 +95,42 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
 
         helpers::validate_status_with_capture_method(payment_intent.status, capture_method)?;
 
+        currency = helpers::validate_and_get_capture_currency(
+            &state
+                .conf
+                .cross_currency_capture
+                .connectors_with_cross_currency_capture,
+            payment_attempt.connector.as_deref(),
+            payment_attempt.currency,
+            request.currency,
+        )?;
+
+        // An amount in a different currency is compared with the amount capturable at the current
+        // exchange rates, the amount capturable in a different currency cannot be defaulted to
+        let amount_to_capture_in_payment_currency = match payment_attempt.currency {
+            Some(payment_currency) if payment_currency != currency => {
+                let amount_to_capture = request
+                    .amount_to_capture
+                    .get_required_value("amount_to_capture")
+                    .attach_printable(
+                        "amount_to_capture is required to capture in a different currency",
+                    )?;
+                Some(
+                    helpers::get_amount_to_capture_in_payment_currency(
+                        state,
+                        amount_to_capture,
+                        currency,
+                        payment_currency,
+                    )
+                    .await?,
+                )
+            }
+            _ => request.amount_to_capture,
+        };
+
         helpers::validate_amount_to_capture(
             payment_attempt.amount_capturable.get_amount_as_i64(),
-            request
-                .amount_to_capture
+            amount_to_capture_in_payment_currency
                 .map(|capture_amount| capture_amount.get_amount_as_i64()),
         )?;
 
@@ -109,11 +141,6 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
                 .amount_to_capture
                 .get_required_value("amount_to_capture")?;
 
-            helpers::validate_amount_to_capture(
-                payment_attempt.amount_capturable.get_amount_as_i64(),
-                Some(amount_to_capture.get_amount_as_i64()),
-            )?;
-
             let previous_captures = db
                 .find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
                     &payment_attempt.merchant_id,
@@ -126,8 +153,11 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
 
             let capture = db
                 .insert_capture(
-                    payment_attempt
-                        .make_new_capture(amount_to_capture, enums::CaptureStatus::Started)?,
+                    payment_attempt.make_new_capture(
+                        amount_to_capture,
+                        currency,
+                        enums::CaptureStatus::Started,
+                    )?,
                     storage_scheme,
                 )
                 .await
@@ -143,8 +173,6 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
             None
         };
 
-        currency = payment_attempt.currency.get_required_value("currency")?;
-
         amount = payment_attempt.get_total_amount().into();
 
         let shipping_address = helpers::get_address_by_id(
@@ -253,6 +281,10 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
             recurring_details: None,
             poll_config: None,
             tax_data: None,
+            cvv_recollection_policy: None,
+            connector_network_retry_count: None,
+            statement_descriptor_length_policy: merchant_account
+                .get_statement_descriptor_length_policy(),
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
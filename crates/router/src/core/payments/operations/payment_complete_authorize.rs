@@ -72,7 +72,11 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Co
             .setup_future_usage
             .or(payment_intent.setup_future_usage);
 
-        helpers::authenticate_client_secret(request.client_secret.as_ref(), &payment_intent)?;
+        helpers::authenticate_client_secret(
+            request.client_secret.as_ref(),
+            &payment_intent,
+            &state.conf.token_expiry,
+        )?;
 
         helpers::validate_payment_status_against_not_allowed_statuses(
             &payment_intent.status,
@@ -344,6 +348,10 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Co
             recurring_details,
             poll_config: None,
             tax_data: None,
+            cvv_recollection_policy: None,
+            connector_network_retry_count: None,
+            statement_descriptor_length_policy: merchant_account
+                .get_statement_descriptor_length_policy(),
         };
 
         let customer_details = Some(CustomerDetails {
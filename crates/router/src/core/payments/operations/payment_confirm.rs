@@ -33,8 +33,8 @@ use crate::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers as m_helpers,
         payments::{
-            self, helpers, operations, populate_surcharge_details, CustomerDetails, PaymentAddress,
-            PaymentData,
+            self, card_hash, cvv_recollection, helpers, operations, populate_surcharge_details,
+            velocity_limits, CustomerDetails, PaymentAddress, PaymentData,
         },
         utils as core_utils,
     },
@@ -137,7 +137,11 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             )?;
         }
 
-        helpers::authenticate_client_secret(request.client_secret.as_ref(), &payment_intent)?;
+        helpers::authenticate_client_secret(
+            request.client_secret.as_ref(),
+            &payment_intent,
+            &state.conf.token_expiry,
+        )?;
 
         let customer_details = helpers::get_customer_details_from_request(request);
 
@@ -581,6 +585,14 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                 .as_ref()
                 .and_then(|pm_info| pm_info.payment_method_type));
 
+        // The payment method type may only be known once the payment is confirmed
+        helpers::validate_transaction_amount_limits(
+            merchant_account,
+            payment_attempt.amount.into(),
+            Some(currency),
+            payment_attempt.payment_method_type,
+        )?;
+
         let token = token.or_else(|| payment_attempt.payment_token.clone());
 
         helpers::validate_pm_or_token_given(
@@ -734,6 +746,13 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             recurring_details,
             poll_config: None,
             tax_data: None,
+            cvv_recollection_policy: cvv_recollection::get_request_cvv_recollection_policy(
+                request.cvv_recollection_policy.as_ref(),
+                auth_flow,
+            ),
+            connector_network_retry_count: None,
+            statement_descriptor_length_policy: merchant_account
+                .get_statement_descriptor_length_policy(),
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -847,7 +866,12 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest, PaymentData<F>> for Paymen
     ) -> CustomResult<api::ConnectorChoice, errors::ApiErrorResponse> {
         // Use a new connector in the confirm call or use the same one which was passed when
         // creating the payment or if none is passed then use the routing algorithm
-        helpers::get_connector_default(state, request.routing.clone()).await
+        helpers::get_connector_override_or_default(
+            state,
+            request.routing.clone(),
+            request.connector_override.clone(),
+        )
+        .await
     }
 
     #[instrument(skip_all)]
@@ -966,6 +990,16 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest, PaymentData<F>> for Paymen
         .await
     }
 
+    #[instrument(skip_all)]
+    async fn guard_payment_against_velocity_limits<'a>(
+        &'a self,
+        state: &SessionState,
+        merchant_account: &domain::MerchantAccount,
+        payment_data: &mut PaymentData<F>,
+    ) -> CustomResult<(), errors::ApiErrorResponse> {
+        velocity_limits::guard_payment(state, merchant_account, payment_data).await
+    }
+
     #[instrument(skip_all)]
     async fn store_extended_card_info_temporarily<'a>(
         &'a self,
@@ -1268,6 +1302,12 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
         let m_error_code = error_code.clone();
         let m_error_message = error_message.clone();
         let m_fingerprint_id = payment_data.payment_attempt.fingerprint_id.clone();
+        let m_card_hash = card_hash::get_card_hash_from_payment_method_data(
+            state,
+            &payment_data.payment_attempt.merchant_id,
+            payment_data.payment_method_data.as_ref(),
+        )?
+        .or(payment_data.payment_attempt.card_hash.clone());
         let m_db = state.clone().store;
         let surcharge_amount = payment_data
             .surcharge_details
@@ -1353,6 +1393,8 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                         customer_acceptance: payment_data.payment_attempt.customer_acceptance,
                         shipping_cost,
                         order_tax_amount,
+                        card_hash: m_card_hash,
+                        is_connector_override: payment_data.payment_attempt.is_connector_override,
                     },
                     storage_scheme,
                 )
@@ -1515,6 +1557,12 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest, PaymentData<F>> f
             helpers::validate_max_amount(amount)?;
         }
 
+        if let Some(return_url) = &request.return_url {
+            helpers::validate_return_url_domain(return_url, merchant_account)?;
+        }
+
+        helpers::validate_wallet_data(request)?;
+
         let request_merchant_id = request.merchant_id.as_ref();
         helpers::validate_merchant_id(merchant_account.get_id(), request_merchant_id)
             .change_context(errors::ApiErrorResponse::InvalidDataFormat {
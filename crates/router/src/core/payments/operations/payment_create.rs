@@ -34,8 +34,11 @@ use crate::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers as m_helpers,
         payment_link,
-        payment_methods::cards::create_encrypted_data,
-        payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
+        payment_methods::{cards::create_encrypted_data, surcharge_decision_configs},
+        payments::{
+            self, card_hash, cvv_recollection, duplicate_payments, helpers, operations,
+            token_expiry, velocity_limits, CustomerDetails, PaymentAddress, PaymentData,
+        },
         utils as core_utils,
     },
     db::StorageInterface,
@@ -52,7 +55,7 @@ use crate::{
             self,
             enums::{self, IntentStatus},
         },
-        transformers::ForeignTryFrom,
+        transformers::{ForeignFrom, ForeignTryFrom},
     },
     utils::{self, OptionExt},
 };
@@ -75,7 +78,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         request: &api::PaymentsRequest,
         merchant_account: &domain::MerchantAccount,
         merchant_key_store: &domain::MerchantKeyStore,
-        _auth_flow: services::AuthFlow,
+        auth_flow: services::AuthFlow,
         header_payload: &api::HeaderPayload,
     ) -> RouterResult<operations::GetTrackerResponse<'a, F, api::PaymentsRequest, PaymentData<F>>>
     {
@@ -85,7 +88,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         let merchant_id = merchant_account.get_id();
         let storage_scheme = merchant_account.storage_scheme;
 
-        let money @ (amount, currency) = payments_create_request_validation(request)?;
+        let money @ (amount, currency) =
+            payments_create_request_validation(request, merchant_account.get_default_currency())?;
 
         let payment_id = payment_id
             .get_payment_intent_id()
@@ -120,6 +124,23 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             .get_required_value("profile_id")
             .attach_printable("Profile id is a mandatory parameter")?;
 
+        #[cfg(feature = "v1")]
+        let root_payment_id = request
+            .parent_payment_id
+            .as_ref()
+            .async_map(|parent_payment_id| {
+                helpers::validate_parent_payment_id(
+                    state,
+                    &payment_id,
+                    parent_payment_id,
+                    &profile_id,
+                    merchant_account,
+                    merchant_key_store,
+                )
+            })
+            .await
+            .transpose()?;
+
         // TODO: eliminate a redundant db call to fetch the business profile
         // Validate whether profile_id passed in request is valid and is linked to the merchant
         let business_profile = if let Some(business_profile) =
@@ -240,14 +261,13 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             payment_id.get_attempt_id(1)
         };
 
-        let session_expiry =
-            common_utils::date_time::now().saturating_add(time::Duration::seconds(
-                request.session_expiry.map(i64::from).unwrap_or(
-                    business_profile
-                        .session_expiry
-                        .unwrap_or(consts::DEFAULT_SESSION_EXPIRY),
-                ),
-            ));
+        let session_expiry = common_utils::date_time::now().saturating_add(
+            time::Duration::seconds(token_expiry::get_session_expiry_in_secs(
+                &state.conf.token_expiry,
+                request.session_expiry,
+                business_profile.session_expiry,
+            )?),
+        );
 
         let payment_link_data = match request.payment_link {
             Some(true) => {
@@ -275,6 +295,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                     payment_id.clone(),
                     db,
                     amount,
+                    currency,
                     request.description.clone(),
                     profile_id.clone(),
                     domain_name,
@@ -303,6 +324,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             attempt_id,
             profile_id.clone(),
             session_expiry,
+            root_payment_id,
         )
         .await?;
 
@@ -323,49 +345,102 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             merchant_key_store,
             profile_id,
             &customer_acceptance,
+            request
+                .capture_method
+                .or(merchant_account.get_default_capture_method()),
         )
         .await?;
 
-        let payment_intent = db
-            .insert_payment_intent(
-                key_manager_state,
-                payment_intent_new,
-                merchant_key_store,
-                storage_scheme,
-            )
-            .await
-            .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
-                payment_id: payment_id.clone(),
-            })?;
+        // The payment is registered against its dedup key before it is created, so that the
+        // concurrent submissions of the same payment are detected as its duplicates
+        let dedup_card_hash = card_hash::get_card_hash_from_request(
+            state,
+            merchant_id,
+            request
+                .payment_method_data
+                .as_ref()
+                .and_then(|payment_method_data| payment_method_data.payment_method_data.as_ref()),
+        )?;
+        let payment_dedup_key = match duplicate_payments::PaymentDedupPayer::new(
+            customer_details.customer_id.as_ref(),
+            dedup_card_hash.as_deref(),
+            customer_details.email.as_ref(),
+        ) {
+            Some(payer) => {
+                duplicate_payments::check_duplicate_payment(
+                    state,
+                    merchant_account,
+                    &duplicate_payments::PaymentDedupDetails {
+                        payer,
+                        amount: MinorUnit::from(amount),
+                        currency,
+                        payment_method,
+                        payment_method_type,
+                    },
+                    &payment_id,
+                    request.allow_duplicate_payment.unwrap_or(false),
+                )
+                .await?
+            }
+            None => None,
+        };
 
-        if let Some(order_details) = &request.order_details {
-            helpers::validate_order_details_amount(
-                order_details.to_owned(),
-                payment_intent.amount.get_amount_as_i64(),
-                false,
-            )?;
-        }
+        let created_payment = async {
+            let payment_intent = db
+                .insert_payment_intent(
+                    key_manager_state,
+                    payment_intent_new,
+                    merchant_key_store,
+                    storage_scheme,
+                )
+                .await
+                .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
+                    payment_id: payment_id.clone(),
+                })?;
+
+            if let Some(order_details) = &request.order_details {
+                helpers::validate_order_details_amount(
+                    order_details.to_owned(),
+                    payment_intent.amount.get_amount_as_i64(),
+                    false,
+                )?;
+            }
 
-        #[cfg(feature = "v1")]
-        let payment_attempt = db
-            .insert_payment_attempt(payment_attempt_new, storage_scheme)
-            .await
-            .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
-                payment_id: payment_id.clone(),
-            })?;
+            #[cfg(feature = "v1")]
+            let payment_attempt = db
+                .insert_payment_attempt(payment_attempt_new, storage_scheme)
+                .await
+                .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
+                    payment_id: payment_id.clone(),
+                })?;
 
-        #[cfg(feature = "v2")]
-        let payment_attempt = db
-            .insert_payment_attempt(
-                key_manager_state,
-                merchant_key_store,
-                payment_attempt_new,
-                storage_scheme,
+            #[cfg(feature = "v2")]
+            let payment_attempt = db
+                .insert_payment_attempt(
+                    key_manager_state,
+                    merchant_key_store,
+                    payment_attempt_new,
+                    storage_scheme,
+                )
+                .await
+                .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
+                    payment_id: payment_id.clone(),
+                })?;
+
+            RouterResult::Ok((payment_intent, payment_attempt))
+        }
+        .await;
+
+        if let (Err(_), Some(payment_dedup_key)) = (&created_payment, &payment_dedup_key) {
+            duplicate_payments::delete_payment_dedup_key(
+                state,
+                merchant_id,
+                payment_dedup_key,
+                &payment_id,
             )
-            .await
-            .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayment {
-                payment_id: payment_id.clone(),
-            })?;
+            .await;
+        }
+        let (payment_intent, payment_attempt) = created_payment?;
 
         let mandate_details_present = payment_attempt.mandate_details.is_some();
 
@@ -486,6 +561,16 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         let surcharge_details = request.surcharge_details.map(|request_surcharge_details| {
             payments::types::SurchargeDetails::from((&request_surcharge_details, &payment_attempt))
         });
+        surcharge_details
+            .as_ref()
+            .map(|surcharge_details| {
+                surcharge_decision_configs::validate_request_surcharge_limit(
+                    &state.conf.surcharge_limits,
+                    billing_address.as_ref().and_then(|address| address.country),
+                    surcharge_details,
+                )
+            })
+            .transpose()?;
 
         let payment_method_data_after_card_bin_call = request
             .payment_method_data
@@ -562,6 +647,13 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             recurring_details,
             poll_config: None,
             tax_data: None,
+            cvv_recollection_policy: cvv_recollection::get_request_cvv_recollection_policy(
+                request.cvv_recollection_policy.as_ref(),
+                auth_flow,
+            ),
+            connector_network_retry_count: None,
+            statement_descriptor_length_policy: merchant_account
+                .get_statement_descriptor_length_policy(),
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -749,7 +841,12 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest, PaymentData<F>> for Paymen
         _payment_intent: &storage::PaymentIntent,
         _merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<api::ConnectorChoice, errors::ApiErrorResponse> {
-        helpers::get_connector_default(state, request.routing.clone()).await
+        helpers::get_connector_override_or_default(
+            state,
+            request.routing.clone(),
+            request.connector_override.clone(),
+        )
+        .await
     }
 
     #[instrument(skip_all)]
@@ -762,6 +859,18 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest, PaymentData<F>> for Paymen
     ) -> CustomResult<bool, errors::ApiErrorResponse> {
         Ok(false)
     }
+
+    /// A payment created with `confirm` set is sent to the connector by the create operation, so
+    /// it is recorded against the velocity limits here
+    #[instrument(skip_all)]
+    async fn guard_payment_against_velocity_limits<'a>(
+        &'a self,
+        state: &SessionState,
+        merchant_account: &domain::MerchantAccount,
+        payment_data: &mut PaymentData<F>,
+    ) -> CustomResult<(), errors::ApiErrorResponse> {
+        velocity_limits::guard_payment(state, merchant_account, payment_data).await
+    }
 }
 
 #[async_trait]
@@ -806,6 +915,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
             .clone();
         let authorized_amount = payment_data.payment_attempt.amount;
         let merchant_connector_id = payment_data.payment_attempt.merchant_connector_id.clone();
+        let is_connector_override = payment_data.payment_attempt.is_connector_override;
 
         let surcharge_amount = payment_data
             .surcharge_details
@@ -832,6 +942,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                     tax_amount,
                     updated_by: storage_scheme.to_string(),
                     merchant_connector_id,
+                    is_connector_override,
                 },
                 storage_scheme,
             )
@@ -894,9 +1005,30 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest, PaymentData<F>> f
     ) -> RouterResult<(PaymentCreateOperation<'b, F>, operations::ValidateResult)> {
         helpers::validate_customer_information(request)?;
 
+        request.validate_tags().map_err(|error| {
+            let message = error.current_context().to_string();
+            error.change_context(errors::ApiErrorResponse::InvalidRequestData { message })
+        })?;
+
+        request.get_validated_amount_breakdown().map_err(|error| {
+            let message = error.current_context().to_string();
+            error.change_context(errors::ApiErrorResponse::InvalidRequestData { message })
+        })?;
+
         if let Some(amount) = request.amount {
             helpers::validate_max_amount(amount)?;
+            helpers::validate_transaction_amount_limits(
+                merchant_account,
+                amount,
+                request.currency.or(merchant_account.get_default_currency()),
+                request.payment_method_type,
+            )?;
         }
+
+        if let Some(return_url) = &request.return_url {
+            helpers::validate_return_url_domain(return_url, merchant_account)?;
+        }
+        helpers::validate_wallet_data(request)?;
         if let Some(session_expiry) = &request.session_expiry {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
@@ -914,6 +1046,7 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest, PaymentData<F>> f
         let request_merchant_id = request.merchant_id.as_ref();
         helpers::validate_merchant_id(merchant_account.get_id(), request_merchant_id)
             .change_context(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+        helpers::validate_merchant_onboarding_status(merchant_account)?;
 
         helpers::validate_request_amount_and_amount_to_capture(
             request.amount,
@@ -925,7 +1058,11 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest, PaymentData<F>> f
             expected_format: "amount_to_capture lesser than amount".to_string(),
         })?;
 
-        helpers::validate_amount_to_capture_and_capture_method(None, request)?;
+        helpers::validate_amount_to_capture_and_capture_method(
+            None,
+            request,
+            merchant_account.get_default_capture_method(),
+        )?;
         helpers::validate_card_data(
             request
                 .payment_method_data
@@ -1011,6 +1148,7 @@ impl PaymentCreate {
         _key_store: &domain::MerchantKeyStore,
         profile_id: common_utils::id_type::ProfileId,
         customer_acceptance: &Option<payments::CustomerAcceptance>,
+        capture_method: Option<enums::CaptureMethod>,
     ) -> RouterResult<(
         storage::PaymentAttemptNew,
         Option<api_models::payments::AdditionalPaymentData>,
@@ -1039,6 +1177,7 @@ impl PaymentCreate {
         _key_store: &domain::MerchantKeyStore,
         profile_id: common_utils::id_type::ProfileId,
         customer_acceptance: &Option<payments::CustomerAcceptance>,
+        capture_method: Option<enums::CaptureMethod>,
     ) -> RouterResult<(
         storage::PaymentAttemptNew,
         Option<api_models::payments::AdditionalPaymentData>,
@@ -1124,6 +1263,8 @@ impl PaymentCreate {
         let tax_amount = request
             .surcharge_details
             .and_then(|surcharge_details| surcharge_details.tax_amount);
+        let card_hash =
+            card_hash::get_card_hash_from_request(state, merchant_id, payment_method_data)?;
 
         if request.mandate_data.as_ref().map_or(false, |mandate_data| {
             mandate_data.update_mandate_id.is_some() && mandate_data.mandate_type.is_some()
@@ -1153,7 +1294,7 @@ impl PaymentCreate {
                 currency,
                 amount: MinorUnit::from(amount),
                 payment_method,
-                capture_method: request.capture_method,
+                capture_method,
                 capture_on: request.capture_on,
                 confirm: request.confirm.unwrap_or(false),
                 created_at,
@@ -1217,6 +1358,15 @@ impl PaymentCreate {
                 profile_id,
                 shipping_cost: request.shipping_cost,
                 order_tax_amount: None,
+                fraud_score: None,
+                fraud_decision: None,
+                approved_amount: None,
+                expected_settlement_date: None,
+                card_hash,
+                retry_advice: None,
+                tags: request.tags.clone(),
+                is_connector_override: None,
+                stepped_up_from_attempt_id: None,
             },
             additional_pm_data,
         ))
@@ -1237,6 +1387,7 @@ impl PaymentCreate {
         active_attempt_id: String,
         profile_id: common_utils::id_type::ProfileId,
         session_expiry: PrimitiveDateTime,
+        root_payment_id: Option<common_utils::id_type::PaymentId>,
     ) -> RouterResult<storage::PaymentIntent> {
         let created_at @ modified_at @ last_synced = common_utils::date_time::now();
 
@@ -1257,6 +1408,11 @@ impl PaymentCreate {
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to convert order details to value")?;
 
+        let amount_breakdown = request.get_validated_amount_breakdown().map_err(|error| {
+            let message = error.current_context().to_string();
+            error.change_context(errors::ApiErrorResponse::InvalidRequestData { message })
+        })?;
+
         let allowed_payment_method_types = request
             .get_allowed_payment_method_types_as_value()
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -1277,7 +1433,9 @@ impl PaymentCreate {
         let request_incremental_authorization =
             core_utils::get_request_incremental_authorization_value(
                 request.request_incremental_authorization,
-                request.capture_method,
+                request
+                    .capture_method
+                    .or(merchant_account.get_default_capture_method()),
             )?;
 
         let charges = request
@@ -1421,6 +1579,12 @@ impl PaymentCreate {
             shipping_cost: request.shipping_cost,
             tax_details: None,
             skip_external_tax_calculation,
+            amount_breakdown: amount_breakdown.map(ForeignFrom::foreign_from),
+            parent_payment_id: request.parent_payment_id.clone(),
+            root_payment_id,
+            fulfillment_status: None,
+            shipped_at: None,
+            delivered_at: None,
         })
     }
 
@@ -1450,11 +1614,17 @@ impl PaymentCreate {
     }
 }
 
+/// Get the amount and the currency of the payment, where the default currency of the merchant
+/// applies to a payment which does not specify its currency
 #[instrument(skip_all)]
 pub fn payments_create_request_validation(
     req: &api::PaymentsRequest,
+    default_currency: Option<enums::Currency>,
 ) -> RouterResult<(api::Amount, enums::Currency)> {
-    let currency = req.currency.get_required_value("currency")?;
+    let currency = req
+        .currency
+        .or(default_currency)
+        .get_required_value("currency")?;
     let amount = req.amount.get_required_value("amount")?;
     Ok((amount, currency))
 }
@@ -1467,6 +1637,7 @@ async fn create_payment_link(
     payment_id: common_utils::id_type::PaymentId,
     db: &dyn StorageInterface,
     amount: api::Amount,
+    currency: enums::Currency,
     description: Option<String>,
     profile_id: common_utils::id_type::ProfileId,
     domain_name: String,
@@ -1506,7 +1677,7 @@ async fn create_payment_link(
         merchant_id: merchant_id.clone(),
         link_to_pay: open_payment_link.clone(),
         amount: MinorUnit::from(amount),
-        currency: request.currency,
+        currency: Some(currency),
         created_at,
         last_modified_at,
         fulfilment_time: Some(session_expiry),
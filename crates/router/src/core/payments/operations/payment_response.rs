@@ -17,6 +17,8 @@ use storage_impl::DataModelExt;
 use tracing_futures::Instrument;
 
 use super::{Operation, OperationSessionSetters, PostUpdateTracker};
+#[cfg(feature = "v1")]
+use crate::core::payments::{checkout_resume, receipts, settlement};
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use crate::core::routing::helpers::push_metrics_for_success_based_routing;
 use crate::{
@@ -30,7 +32,7 @@ use crate::{
                 self as payments_helpers,
                 update_additional_payment_data_with_connector_response_pm_data,
             },
-            tokenization,
+            retry_advice, tokenization,
             types::MultipleCaptureData,
             PaymentData,
         },
@@ -178,6 +180,24 @@ impl<F: Send + Clone> PostUpdateTracker<F, PaymentData<F>, types::PaymentsAuthor
                 })
                 .ok();
             }
+
+            let is_merchant_initiated = payment_data.mandate_id.is_some()
+                || payment_data.recurring_details.is_some()
+                || payment_data.payment_intent.off_session == Some(true);
+            if should_update_preferred_payment_method(resp.status, is_merchant_initiated) {
+                #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+                payment_methods::cards::update_preferred_payment_method(
+                    payment_method_info,
+                    state,
+                    merchant_account.storage_scheme,
+                    key_store,
+                )
+                .await
+                .map_err(|e| {
+                    logger::error!("Failed to update the preferred payment method: {:?}", e);
+                })
+                .ok();
+            }
         };
 
         let save_payment_call_future = Box::pin(tokenization::save_payment_method(
@@ -1056,6 +1076,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     #[cfg(all(feature = "v1", feature = "dynamic_routing"))] business_profile: &domain::Profile,
 ) -> RouterResult<PaymentData<F>> {
     // Update additional payment data with the payment method response that we received from connector
+    payment_data.connector_network_retry_count = router_data.connector_network_retry_count;
 
     let additional_payment_method_data = match payment_data.payment_method_data.clone() {
         Some(payment_method_data) => match payment_method_data {
@@ -1201,6 +1222,10 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                             }
                         }
                     };
+                    let retry_advice = retry_advice::get_retry_advice_from_error_response(
+                        &payment_data.payment_attempt,
+                        &err,
+                    );
                     (
                         None,
                         Some(storage::PaymentAttemptUpdate::ErrorUpdate {
@@ -1219,6 +1244,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                             connector_transaction_id: err.connector_transaction_id,
                             payment_method_data: additional_payment_method_data,
                             authentication_type: auth_update,
+                            retry_advice,
                         }),
                     )
                 }
@@ -1256,6 +1282,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                             connector_transaction_id,
                             payment_method_data: None,
                             authentication_type: auth_update,
+                            retry_advice: None,
                         }),
                     )
                 }
@@ -1278,6 +1305,48 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                         },
                         _ => router_data.get_attempt_status_for_db_update(&payment_data),
                     };
+                    // The connector may approve less than the requested amount, in which case
+                    // only the approved amount can be captured or has to be reversed
+                    let approved_amount = router_data
+                        .connector_response
+                        .as_ref()
+                        .and_then(|connector_response| connector_response.approved_amount)
+                        .filter(|approved_amount| {
+                            *approved_amount < payment_data.payment_attempt.get_total_amount()
+                        });
+                    let capturable_approved_amount =
+                        approved_amount.or(payment_data.payment_attempt.approved_amount);
+                    let updated_attempt_status =
+                        match (updated_attempt_status, capturable_approved_amount) {
+                            (enums::AttemptStatus::Authorized, Some(_)) => {
+                                enums::AttemptStatus::PartiallyAuthorized
+                            }
+                            (updated_attempt_status, _) => updated_attempt_status,
+                        };
+                    let expected_settlement_date = match updated_attempt_status {
+                        enums::AttemptStatus::Charged
+                        | enums::AttemptStatus::PartialCharged
+                        | enums::AttemptStatus::PartialChargedAndChargeable => {
+                            let connector_settlement_date = router_data
+                                .connector_response
+                                .as_ref()
+                                .and_then(|connector_response| {
+                                    connector_response.expected_settlement_date
+                                });
+                            match payment_data.payment_attempt.expected_settlement_date {
+                                // The settlement date computed when the payment was captured is
+                                // kept when the payment is synced, unless reported by the connector
+                                Some(_) => connector_settlement_date,
+                                None => settlement::get_expected_settlement_date(
+                                    &state.conf.settlement_delay,
+                                    &router_data.connector,
+                                    connector_settlement_date,
+                                    common_utils::date_time::now(),
+                                ),
+                            }
+                        }
+                        _ => None,
+                    };
                     match payments_response {
                         types::PaymentsResponseData::PreProcessingResponse {
                             pre_processing_id,
@@ -1408,13 +1477,18 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                                         connector: None,
                                         connector_transaction_id: connector_transaction_id.clone(),
                                         authentication_type: auth_update,
-                                        amount_capturable: router_data
-                                            .request
-                                            .get_amount_capturable(
-                                                &payment_data,
-                                                updated_attempt_status,
-                                            )
-                                            .map(MinorUnit::new),
+                                        amount_capturable: match updated_attempt_status {
+                                            enums::AttemptStatus::PartiallyAuthorized => {
+                                                capturable_approved_amount
+                                            }
+                                            _ => router_data
+                                                .request
+                                                .get_amount_capturable(
+                                                    &payment_data,
+                                                    updated_attempt_status,
+                                                )
+                                                .map(MinorUnit::new),
+                                        },
                                         payment_method_id,
                                         mandate_id: payment_data.payment_attempt.mandate_id.clone(),
                                         connector_metadata,
@@ -1430,6 +1504,8 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                                         encoded_data,
                                         payment_method_data: additional_payment_method_data,
                                         charge_id,
+                                        approved_amount,
+                                        expected_settlement_date,
                                     }),
                                 ),
                             };
@@ -1705,6 +1781,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
         .in_current_span(),
     );
 
+    let previous_intent_status = payment_data.payment_intent.status;
     let (payment_intent, _, payment_attempt) = futures::try_join!(
         utils::flatten_join_error(payment_intent_fut),
         utils::flatten_join_error(mandate_update_fut),
@@ -1735,6 +1812,51 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
             );
         }
     }
+    receipts::register_receipt_token(
+        state,
+        previous_intent_status,
+        &payment_intent,
+        storage_scheme,
+    )
+    .await
+    .inspect_err(|error| logger::error!(?error, "Failed to register the receipt token"))
+    .ok();
+    // The resume token is returned along with the payment once it requires the action of the
+    // customer, and is registered before it is returned so that it resolves to the payment
+    checkout_resume::register_resume_token(
+        state,
+        previous_intent_status,
+        &payment_intent,
+        &payment_attempt,
+        storage_scheme,
+    )
+    .await
+    .inspect_err(|error| logger::error!(?error, "Failed to register the resume token"))
+    .ok();
+
+    // The usage of the mandate recorded when the payment was confirmed is released once the
+    // payment fails
+    if let Some(mandate_id) = payment_data
+        .mandate_id
+        .as_ref()
+        .and_then(|mandate_ids| mandate_ids.mandate_id.as_ref())
+        .filter(|_| {
+            previous_intent_status != enums::IntentStatus::Failed
+                && payment_intent.status == enums::IntentStatus::Failed
+        })
+    {
+        mandate::release_mandate_usage(
+            state,
+            &payment_intent.merchant_id,
+            mandate_id,
+            payment_intent.amount,
+            storage_scheme,
+        )
+        .await
+        .inspect_err(|error| logger::error!(?error, "Failed to release the mandate usage"))
+        .ok();
+    }
+
     payment_data.payment_intent = payment_intent;
     payment_data.payment_attempt = payment_attempt;
     router_data.payment_method_status.and_then(|status| {
@@ -1970,3 +2092,57 @@ fn get_total_amount_captured<F: Clone, T: types::Capturable>(
         }
     }
 }
+
+/// Whether the saved payment method used in the payment is to be recorded as the preferred payment
+/// method of the customer. Only the successful payments initiated by the customer record it, as the
+/// payment method of a merchant initiated payment is not chosen by the customer.
+fn should_update_preferred_payment_method(
+    status: enums::AttemptStatus,
+    is_merchant_initiated: bool,
+) -> bool {
+    !is_merchant_initiated
+        && matches!(
+            status,
+            enums::AttemptStatus::Charged
+                | enums::AttemptStatus::Authorized
+                | enums::AttemptStatus::PartialCharged
+                | enums::AttemptStatus::PartialChargedAndChargeable
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preferred_payment_method_is_updated_by_successful_customer_initiated_payments() {
+        assert!(should_update_preferred_payment_method(
+            enums::AttemptStatus::Charged,
+            false
+        ));
+        assert!(should_update_preferred_payment_method(
+            enums::AttemptStatus::Authorized,
+            false
+        ));
+        assert!(!should_update_preferred_payment_method(
+            enums::AttemptStatus::Failure,
+            false
+        ));
+        assert!(!should_update_preferred_payment_method(
+            enums::AttemptStatus::Pending,
+            false
+        ));
+    }
+
+    #[test]
+    fn test_preferred_payment_method_is_not_updated_by_merchant_initiated_payments() {
+        assert!(!should_update_preferred_payment_method(
+            enums::AttemptStatus::Charged,
+            true
+        ));
+        assert!(!should_update_preferred_payment_method(
+            enums::AttemptStatus::Authorized,
+            true
+        ));
+    }
+}
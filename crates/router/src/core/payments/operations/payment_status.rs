@@ -252,16 +252,37 @@ async fn get_tracker_for_sync<
 {
     let (payment_intent, mut payment_attempt, currency, amount);
 
-    (payment_intent, payment_attempt) = get_payment_intent_payment_attempt(
-        state,
-        payment_id,
-        merchant_account.get_id(),
-        key_store,
-        storage_scheme,
-    )
-    .await?;
+    (payment_intent, payment_attempt) = match (payment_id, request.connector.as_deref()) {
+        // The same transaction id may be used by different connectors, the payment is looked up
+        // among the payments made through the connector when the connector is known
+        (api::PaymentIdType::ConnectorTransactionId(connector_txn_id), Some(connector)) => {
+            find_payment_by_connector_transaction_id(
+                state,
+                merchant_account.get_id(),
+                key_store,
+                connector,
+                connector_txn_id,
+                storage_scheme,
+            )
+            .await?
+        }
+        _ => {
+            get_payment_intent_payment_attempt(
+                state,
+                payment_id,
+                merchant_account.get_id(),
+                key_store,
+                storage_scheme,
+            )
+            .await?
+        }
+    };
 
-    helpers::authenticate_client_secret(request.client_secret.as_ref(), &payment_intent)?;
+    helpers::authenticate_client_secret(
+        request.client_secret.as_ref(),
+        &payment_intent,
+        &state.conf.token_expiry,
+    )?;
 
     let payment_id = payment_attempt.payment_id.clone();
 
@@ -505,6 +526,10 @@ async fn get_tracker_for_sync<
         recurring_details: None,
         poll_config: None,
         tax_data: None,
+        cvv_recollection_policy: None,
+        connector_network_retry_count: None,
+        statement_descriptor_length_policy: merchant_account
+            .get_statement_descriptor_length_policy(),
     };
 
     let get_trackers_response = operations::GetTrackerResponse {
@@ -638,3 +663,38 @@ pub async fn get_payment_intent_payment_attempt(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
 }
+
+/// Find the payment of the merchant made through the connector, from the connector's transaction id
+pub async fn find_payment_by_connector_transaction_id(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    key_store: &domain::MerchantKeyStore,
+    connector: &str,
+    connector_txn_id: &str,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<(storage::PaymentIntent, storage::PaymentAttempt)> {
+    let db = &*state.store;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_merchant_id_connector_connector_txn_id(
+            merchant_id,
+            connector,
+            connector_txn_id,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            &payment_attempt.payment_id,
+            merchant_id,
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    Ok((payment_intent, payment_attempt))
+}
@@ -7,7 +7,7 @@ use async_trait::async_trait;
 use common_utils::{
     ext_traits::{AsyncExt, Encode, ValueExt},
     pii::Email,
-    types::keymanager::KeyManagerState,
+    types::{keymanager::KeyManagerState, MinorUnit},
 };
 use error_stack::{report, ResultExt};
 use hyperswitch_domain_models::payments::payment_intent::{
@@ -21,8 +21,11 @@ use crate::{
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers as m_helpers,
-        payment_methods::cards::create_encrypted_data,
-        payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
+        payment_methods::{cards::create_encrypted_data, surcharge_decision_configs},
+        payments::{
+            self, cvv_recollection, helpers, operations, CustomerDetails, PaymentAddress,
+            PaymentData,
+        },
         utils as core_utils,
     },
     routes::{app::ReqState, SessionState},
@@ -87,6 +90,19 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             )?;
         }
 
+        // The amount breakdown is reconciled against the amount when the payment is created
+        if payment_intent.amount_breakdown.is_some()
+            && request
+                .amount
+                .is_some_and(|amount| MinorUnit::from(amount) != payment_intent.amount)
+        {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "amount cannot be updated for a payment with an amount breakdown"
+                    .to_string(),
+            }
+            .into());
+        }
+
         payment_intent.setup_future_usage = request
             .setup_future_usage
             .or(payment_intent.setup_future_usage);
@@ -109,7 +125,11 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             "update",
         )?;
 
-        helpers::authenticate_client_secret(request.client_secret.as_ref(), &payment_intent)?;
+        helpers::authenticate_client_secret(
+            request.client_secret.as_ref(),
+            &payment_intent,
+            &state.conf.token_expiry,
+        )?;
 
         payment_intent.order_details = request
             .get_order_details_as_value()
@@ -160,7 +180,11 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             payment_intent.customer_id.as_ref(),
         )
         .await?;
-        helpers::validate_amount_to_capture_and_capture_method(Some(&payment_attempt), request)?;
+        helpers::validate_amount_to_capture_and_capture_method(
+            Some(&payment_attempt),
+            request,
+            merchant_account.get_default_capture_method(),
+        )?;
 
         helpers::validate_request_amount_and_amount_to_capture(
             request.amount,
@@ -188,6 +212,13 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             .amount
             .unwrap_or_else(|| payment_attempt.amount.into());
 
+        helpers::validate_transaction_amount_limits(
+            merchant_account,
+            amount,
+            Some(currency),
+            payment_attempt.payment_method_type,
+        )?;
+
         if request.confirm.unwrap_or(false) {
             helpers::validate_customer_id_mandatory_cases(
                 request.setup_future_usage.is_some(),
@@ -431,6 +462,16 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
         let surcharge_details = request.surcharge_details.map(|request_surcharge_details| {
             payments::types::SurchargeDetails::from((&request_surcharge_details, &payment_attempt))
         });
+        surcharge_details
+            .as_ref()
+            .map(|surcharge_details| {
+                surcharge_decision_configs::validate_request_surcharge_limit(
+                    &state.conf.surcharge_limits,
+                    billing_address.as_ref().and_then(|address| address.country),
+                    surcharge_details,
+                )
+            })
+            .transpose()?;
 
         let payment_data = PaymentData {
             flow: PhantomData,
@@ -479,6 +520,13 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             recurring_details,
             poll_config: None,
             tax_data: None,
+            cvv_recollection_policy: cvv_recollection::get_request_cvv_recollection_policy(
+                request.cvv_recollection_policy.as_ref(),
+                auth_flow,
+            ),
+            connector_network_retry_count: None,
+            statement_descriptor_length_policy: merchant_account
+                .get_statement_descriptor_length_policy(),
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -665,7 +713,12 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest, PaymentData<F>> for Paymen
         _payment_intent: &storage::PaymentIntent,
         _key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<api::ConnectorChoice, errors::ApiErrorResponse> {
-        helpers::get_connector_default(state, request.routing.clone()).await
+        helpers::get_connector_override_or_default(
+            state,
+            request.routing.clone(),
+            request.connector_override.clone(),
+        )
+        .await
     }
 
     #[instrument(skip_all)]
@@ -941,6 +994,11 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest, PaymentData<F>> f
         if let Some(amount) = request.amount {
             helpers::validate_max_amount(amount)?;
         }
+
+        if let Some(return_url) = &request.return_url {
+            helpers::validate_return_url_domain(return_url, merchant_account)?;
+        }
+        helpers::validate_wallet_data(request)?;
         if let Some(session_expiry) = &request.session_expiry {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
@@ -0,0 +1,371 @@
+//! Receipts of payments.
+//!
+//! A receipt is a read model assembled from the payment, its active attempt, the captures of the
+//! attempt and the refunds of the payment, with the payment method details masked as they are in
+//! the payment response. The merchant retrieves the receipt by the payment id, along with a receipt
+//! token which is registered through a reverse lookup to the payment once the payment succeeds, so
+//! that the receipt can be shared with the customer and retrieved with the publishable key until the
+//! receipt token expires.
+
+use api_models::payments::{
+    AdditionalPaymentData, PaymentMethodDataResponse, PaymentReceiptResponse, ReceiptCapture,
+    ReceiptMerchantDetails, ReceiptRefund,
+};
+use common_utils::{
+    crypto::Encryptable, date_time, ext_traits::ValueExt, fp_utils, id_type, types::MinorUnit,
+};
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use super::token_expiry;
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services,
+    types::{domain, storage, transformers::ForeignFrom},
+};
+
+const RECEIPT_TOKEN_PREFIX: &str = "rcpt";
+
+/// The number of bytes of the digest kept in the receipt token, so that the token is short enough
+/// to be shared in a link
+const RECEIPT_TOKEN_DIGEST_LENGTH: usize = 16;
+
+fn get_receipt_token_lookup_id(receipt_token: &str) -> String {
+    format!("payment_receipt_{receipt_token}")
+}
+
+/// Whether a receipt token is issued for the payment of the status, which it is once the amount of
+/// the payment is captured
+fn is_receipt_token_issued(status: storage::enums::IntentStatus) -> bool {
+    matches!(
+        status,
+        storage::enums::IntentStatus::Succeeded | storage::enums::IntentStatus::PartiallyCaptured
+    )
+}
+
+/// The receipt token of the payment, if one is issued for the payment. The token is derived from
+/// the client secret of the payment, so that only the merchant and the holder of the client secret
+/// can know it.
+fn get_receipt_token(payment_intent: &storage::PaymentIntent) -> Option<Secret<String>> {
+    if !is_receipt_token_issued(payment_intent.status) {
+        return None;
+    }
+
+    payment_intent
+        .client_secret
+        .as_deref()
+        .and_then(derive_receipt_token)
+}
+
+fn derive_receipt_token(client_secret: &str) -> Option<Secret<String>> {
+    let digest = ring::digest::digest(
+        &ring::digest::SHA256,
+        format!("{client_secret}_receipt").as_bytes(),
+    );
+    let shortened_digest = digest.as_ref().get(..RECEIPT_TOKEN_DIGEST_LENGTH)?;
+    Some(Secret::new(format!(
+        "{RECEIPT_TOKEN_PREFIX}_{}",
+        hex::encode(shortened_digest)
+    )))
+}
+
+/// Register the receipt token of the payment when the update of the payment from the previous
+/// status issues the token, so that the token is registered once rather than on each retrieval of
+/// the receipt
+#[instrument(skip_all)]
+pub(crate) async fn register_receipt_token(
+    state: &SessionState,
+    previous_status: storage::enums::IntentStatus,
+    payment_intent: &storage::PaymentIntent,
+    storage_scheme: storage::enums::MerchantStorageScheme,
+) -> RouterResult<()> {
+    if is_receipt_token_issued(previous_status) {
+        return Ok(());
+    }
+    let Some(receipt_token) = get_receipt_token(payment_intent) else {
+        return Ok(());
+    };
+
+    match state
+        .store
+        .insert_namespaced_reverse_lookup(
+            storage::ReverseLookupNew {
+                lookup_id: get_receipt_token_lookup_id(receipt_token.peek()),
+                pk_id: payment_intent.payment_id.get_string_repr().to_owned(),
+                sk_id: payment_intent.payment_id.get_string_repr().to_owned(),
+                source: "payment_intent".to_string(),
+                updated_by: storage_scheme.to_string(),
+            },
+            &payment_intent.merchant_id,
+            storage_scheme,
+        )
+        .await
+    {
+        Ok(_) => Ok(()),
+        // A concurrent update of the payment registered the token first
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert reverse lookup for the receipt token"),
+    }
+}
+
+/// Retrieve the receipt of the payment, along with the receipt token of the payment so that the
+/// receipt can be shared with the customer
+#[instrument(skip_all)]
+pub async fn retrieve_payment_receipt(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    payment_id: id_type::PaymentId,
+) -> RouterResponse<PaymentReceiptResponse> {
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payment_intent)?;
+
+    let receipt_token = get_receipt_token(&payment_intent);
+    let receipt =
+        construct_payment_receipt(&state, &merchant_account, payment_intent, receipt_token).await?;
+
+    Ok(services::ApplicationResponse::Json(receipt))
+}
+
+/// Retrieve the receipt of the payment of the receipt token
+#[instrument(skip_all)]
+pub async fn retrieve_payment_receipt_by_token(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    receipt_token: String,
+) -> RouterResponse<PaymentReceiptResponse> {
+    let lookup = state
+        .store
+        .get_namespaced_lookup_by_lookup_id(
+            &get_receipt_token_lookup_id(&receipt_token),
+            merchant_account.get_id(),
+            None,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_id = id_type::PaymentId::wrap(lookup.pk_id)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid payment id in the receipt token lookup")?;
+
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let receipt_token_expiry =
+        token_expiry::get_receipt_token_expiry(&state.conf.token_expiry, payment_intent.created_at);
+    fp_utils::when(date_time::now() > receipt_token_expiry, || {
+        Err(errors::ApiErrorResponse::TokenExpired {
+            token: "receipt token",
+        })
+    })?;
+
+    let receipt = construct_payment_receipt(
+        &state,
+        &merchant_account,
+        payment_intent,
+        Some(Secret::new(receipt_token)),
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(receipt))
+}
+
+/// Assemble the receipt of the payment from its active attempt, the captures of the attempt when
+/// the payment was captured in multiple parts, and the refunds of the payment
+async fn construct_payment_receipt(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payment_intent: storage::PaymentIntent,
+    receipt_token: Option<Secret<String>>,
+) -> RouterResult<PaymentReceiptResponse> {
+    let db = &*state.store;
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            &payment_intent.merchant_id,
+            &payment_intent.active_attempt.get_id(),
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let currency = payment_intent
+        .currency
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Missing currency in the payment of the receipt")?;
+
+    let captures = if payment_attempt.multiple_capture_count.is_some() {
+        let mut captures = db
+            .find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+                &payment_attempt.merchant_id,
+                &payment_attempt.payment_id,
+                &payment_attempt.attempt_id,
+                storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the captures of the payment")?;
+        captures.sort_by_key(|capture| capture.capture_sequence);
+        captures
+            .into_iter()
+            .map(|capture| ReceiptCapture {
+                capture_id: capture.capture_id,
+                status: capture.status,
+                amount: capture.amount,
+                created: capture.created_at,
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let mut refunds = db
+        .find_refund_by_payment_id_merchant_id(
+            &payment_intent.payment_id,
+            &payment_intent.merchant_id,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the refunds of the payment")?;
+    refunds.sort_by_key(|refund| refund.created_at);
+
+    let amount_refunded = refunds
+        .iter()
+        .filter(|refund| refund.refund_status == storage::enums::RefundStatus::Success)
+        .fold(MinorUnit::zero(), |amount_refunded, refund| {
+            amount_refunded + refund.refund_amount
+        });
+    let amount_captured = payment_intent.amount_captured.unwrap_or(MinorUnit::zero());
+
+    let payment_method_data = payment_attempt
+        .payment_method_data
+        .and_then(|data| match data {
+            serde_json::Value::Null => None,
+            _ => Some(data.parse_value::<AdditionalPaymentData>("AdditionalPaymentData")),
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(
+            "Failed to parse the AdditionalPaymentData from payment_attempt.payment_method_data",
+        )?
+        .map(PaymentMethodDataResponse::from);
+
+    Ok(PaymentReceiptResponse {
+        payment_id: payment_intent.payment_id,
+        receipt_token,
+        merchant: ReceiptMerchantDetails {
+            merchant_id: merchant_account.get_id().to_owned(),
+            merchant_name: merchant_account
+                .merchant_name
+                .clone()
+                .map(Encryptable::into_inner),
+        },
+        status: payment_intent.status,
+        currency,
+        amount: payment_intent.amount,
+        amount_breakdown: payment_intent
+            .amount_breakdown
+            .map(ForeignFrom::foreign_from),
+        amount_captured,
+        amount_refunded,
+        net_amount: amount_captured - amount_refunded,
+        payment_method: payment_attempt.payment_method,
+        payment_method_type: payment_attempt.payment_method_type,
+        payment_method_data,
+        description: payment_intent.description,
+        captures,
+        refunds: refunds
+            .into_iter()
+            .map(|refund| ReceiptRefund {
+                refund_id: refund.refund_id,
+                status: refund.refund_status.into(),
+                amount: refund.refund_amount,
+                reason: refund.refund_reason,
+                created: refund.created_at,
+            })
+            .collect(),
+        created: payment_intent.created_at,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_receipt_token_is_derived_from_client_secret() {
+        let receipt_token = derive_receipt_token("pay_1_secret_1").unwrap();
+
+        assert!(receipt_token
+            .peek()
+            .starts_with(&format!("{RECEIPT_TOKEN_PREFIX}_")));
+        assert_eq!(
+            receipt_token.peek().len(),
+            RECEIPT_TOKEN_PREFIX.len() + 1 + RECEIPT_TOKEN_DIGEST_LENGTH * 2
+        );
+        assert_eq!(
+            receipt_token.peek(),
+            derive_receipt_token("pay_1_secret_1").unwrap().peek()
+        );
+        assert_ne!(
+            receipt_token.peek(),
+            derive_receipt_token("pay_1_secret_2").unwrap().peek()
+        );
+    }
+
+    #[test]
+    fn test_receipt_token_is_issued_once_the_payment_is_captured() {
+        assert!(is_receipt_token_issued(
+            storage::enums::IntentStatus::Succeeded
+        ));
+        assert!(is_receipt_token_issued(
+            storage::enums::IntentStatus::PartiallyCaptured
+        ));
+        assert!(!is_receipt_token_issued(
+            storage::enums::IntentStatus::RequiresCapture
+        ));
+        assert!(!is_receipt_token_issued(
+            storage::enums::IntentStatus::PartiallyCapturedAndCapturable
+        ));
+        assert!(!is_receipt_token_issued(
+            storage::enums::IntentStatus::Processing
+        ));
+        assert!(!is_receipt_token_issued(
+            storage::enums::IntentStatus::Failed
+        ));
+    }
+}
@@ -9,12 +9,13 @@ use router_env::{
 };
 
 use crate::{
+    configs::settings,
     core::{
         errors::{self, RouterResult, StorageErrorExt},
         payments::{
             self,
             flows::{ConstructFlowSpecificData, Feature},
-            operations,
+            operations, retry_advice,
         },
     },
     db::StorageInterface,
@@ -24,7 +25,7 @@ use crate::{
         metrics,
     },
     services,
-    types::{self, api, domain, storage},
+    types::{self, api, domain, storage, transformers::ForeignTryFrom},
 };
 
 #[instrument(skip_all)]
@@ -34,7 +35,7 @@ pub async fn do_gsm_actions<F, ApiRequest, FData, D>(
     state: &app::SessionState,
     req_state: ReqState,
     payment_data: &mut D,
-    mut connectors: IntoIter<api::ConnectorData>,
+    connectors: IntoIter<api::ConnectorData>,
     original_connector_data: api::ConnectorData,
     mut router_data: types::RouterData<F, FData, types::PaymentsResponseData>,
     merchant_account: &domain::MerchantAccount,
@@ -59,6 +60,24 @@ where
     types::RouterData<F, FData, types::PaymentsResponseData>: Feature<F, FData>,
     dyn api::Connector: services::api::ConnectorIntegration<F, FData, types::PaymentsResponseData>,
 {
+    // A retry which the card network advised against would be declined regardless of the
+    // connector, so the advice takes precedence over the gateway status mapping
+    if is_retry_advised_against(payment_data.get_payment_attempt(), &router_data) {
+        logger::info!("card network advised against retrying the payment now");
+        return Ok(router_data);
+    }
+
+    let failover_chain = merchant_account.get_failover_chain(
+        payment_data.get_payment_attempt().payment_method_type,
+        payment_data.get_currency(),
+        payment_data
+            .get_address()
+            .get_payment_method_billing()
+            .and_then(|billing| billing.address.as_ref())
+            .and_then(|address| address.country),
+    );
+    let mut connectors = order_connectors_by_failover_chain(connectors, &failover_chain);
+
     let mut retries = None;
 
     metrics::AUTO_RETRY_ELIGIBLE_REQUEST_COUNT.add(&metrics::CONTEXT, 1, &[]);
@@ -66,10 +85,11 @@ where
     let mut initial_gsm = get_gsm(state, &router_data).await?;
 
     //Check if step-up to threeDS is possible and merchant has enabled
-    let step_up_possible = initial_gsm
-        .clone()
-        .map(|gsm| gsm.step_up_possible)
-        .unwrap_or(false);
+    // The gateway status mapping of the decline takes precedence over the configured decline codes
+    let step_up_possible = match initial_gsm.as_ref() {
+        Some(gsm) => gsm.step_up_possible,
+        None => is_authentication_required_decline(&state.conf.soft_decline_step_up, &router_data),
+    };
     let is_no_three_ds_payment = matches!(
         payment_data.get_payment_attempt().authentication_type,
         Some(storage_enums::AuthenticationType::NoThreeDs)
@@ -86,6 +106,9 @@ where
     };
 
     if should_step_up {
+        logger::info!("retrying the payment with 3DS since the connector requires authentication");
+        metrics::AUTO_RETRY_STEP_UP_COUNT.add(&metrics::CONTEXT, 1, &[]);
+
         router_data = do_retry(
             &state.clone(),
             req_state.clone(),
@@ -131,6 +154,11 @@ where
                         break;
                     }
 
+                    if is_retry_advised_against(payment_data.get_payment_attempt(), &router_data) {
+                        logger::info!("card network advised against retrying the payment now");
+                        break;
+                    }
+
                     let connector = super::get_connector_data(&mut connectors)?;
 
                     router_data = do_retry(
@@ -169,6 +197,65 @@ where
     Ok(router_data)
 }
 
+/// Order the connectors left to retry the payment with by the connector failover chain of the
+/// merchant. The connectors of the chain are retried first, in the order of the chain, followed by
+/// the other connectors in the order chosen by routing.
+fn order_connectors_by_failover_chain(
+    connectors: IntoIter<api::ConnectorData>,
+    failover_chain: &[common_enums::RoutableConnectors],
+) -> IntoIter<api::ConnectorData> {
+    let mut connectors = connectors.collect::<Vec<_>>();
+    // The sort is stable, so the connectors outside the chain keep the order chosen by routing
+    connectors.sort_by_key(|connector_data| {
+        common_enums::RoutableConnectors::foreign_try_from(connector_data.connector_name)
+            .ok()
+            .and_then(|connector| {
+                failover_chain
+                    .iter()
+                    .position(|chain_connector| *chain_connector == connector)
+            })
+            .unwrap_or(failover_chain.len())
+    });
+    connectors.into_iter()
+}
+
+/// Check whether the card network advised against retrying the payment now, along with the decline
+fn is_retry_advised_against<F, FData>(
+    payment_attempt: &storage::PaymentAttempt,
+    router_data: &types::RouterData<F, FData, types::PaymentsResponseData>,
+) -> bool {
+    router_data
+        .response
+        .as_ref()
+        .err()
+        .and_then(|error_response| {
+            retry_advice::get_retry_advice_from_error_response(payment_attempt, error_response)
+        })
+        .is_some_and(|retry_advice| !retry_advice.is_retryable_now())
+}
+
+/// Check whether the connector declined the payment since the customer must be authenticated, from
+/// the authentication required decline codes configured for the connector
+fn is_authentication_required_decline<F, FData>(
+    soft_decline_step_up: &settings::SoftDeclineStepUp,
+    router_data: &types::RouterData<F, FData, types::PaymentsResponseData>,
+) -> bool {
+    router_data
+        .response
+        .as_ref()
+        .err()
+        .is_some_and(|error_response| {
+            soft_decline_step_up
+                .connectors
+                .get(&router_data.connector)
+                .is_some_and(|step_up_decline_codes| {
+                    step_up_decline_codes
+                        .decline_codes
+                        .contains(&error_response.code)
+                })
+        })
+}
+
 #[instrument(skip_all)]
 pub async fn is_step_up_enabled_for_merchant_connector(
     state: &app::SessionState,
@@ -456,6 +543,8 @@ where
                 unified_message: None,
                 payment_method_data: additional_payment_method_data,
                 charge_id,
+                approved_amount: None,
+                expected_settlement_date: None,
             };
 
             #[cfg(feature = "v1")]
@@ -505,6 +594,10 @@ where
                 connector_transaction_id: error_response.connector_transaction_id.clone(),
                 payment_method_data: additional_payment_method_data,
                 authentication_type: auth_update,
+                retry_advice: retry_advice::get_retry_advice_from_error_response(
+                    payment_data.get_payment_attempt(),
+                    error_response,
+                ),
             };
 
             #[cfg(feature = "v1")]
@@ -647,6 +740,20 @@ pub fn make_new_payment_attempt(
         charge_id: Default::default(),
         customer_acceptance: Default::default(),
         order_tax_amount: Default::default(),
+        fraud_score: Default::default(),
+        fraud_decision: Default::default(),
+        approved_amount: Default::default(),
+        expected_settlement_date: Default::default(),
+        card_hash: old_payment_attempt.card_hash,
+        retry_advice: Default::default(),
+        tags: old_payment_attempt.tags,
+        // The step up is attempted with the same connector, so the override of the connector applies
+        is_connector_override: if is_step_up {
+            old_payment_attempt.is_connector_override
+        } else {
+            Default::default()
+        },
+        stepped_up_from_attempt_id: is_step_up.then_some(old_payment_attempt.attempt_id),
     }
 }
 
@@ -711,6 +818,7 @@ impl<F: Send + Clone + Sync, FData: Send + Sync>
                 | storage_enums::AttemptStatus::AuthenticationPending
                 | storage_enums::AttemptStatus::AuthenticationSuccessful
                 | storage_enums::AttemptStatus::Authorized
+                | storage_enums::AttemptStatus::PartiallyAuthorized
                 | storage_enums::AttemptStatus::Charged
                 | storage_enums::AttemptStatus::Authorizing
                 | storage_enums::AttemptStatus::CodInitiated
@@ -736,3 +844,145 @@ impl<F: Send + Clone + Sync, FData: Send + Sync>
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_connectors(names: &[&str]) -> IntoIter<api::ConnectorData> {
+        let connectors = crate::configs::settings::Connectors::default();
+        names
+            .iter()
+            .map(|name| {
+                api::ConnectorData::get_connector_by_name(
+                    &connectors,
+                    name,
+                    api::GetToken::Connector,
+                    None,
+                )
+                .unwrap()
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    fn get_names(connectors: IntoIter<api::ConnectorData>) -> Vec<String> {
+        connectors
+            .map(|connector_data| connector_data.connector_name.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_connectors_of_the_failover_chain_are_retried_first() {
+        let connectors = get_connectors(&["stripe", "adyen", "checkout", "cybersource"]);
+        let failover_chain = [
+            common_enums::RoutableConnectors::Cybersource,
+            common_enums::RoutableConnectors::Adyen,
+        ];
+
+        assert_eq!(
+            get_names(order_connectors_by_failover_chain(
+                connectors,
+                &failover_chain
+            )),
+            vec!["cybersource", "adyen", "stripe", "checkout"]
+        );
+    }
+
+    #[test]
+    fn test_connectors_keep_the_routing_order_without_a_failover_chain() {
+        let connectors = get_connectors(&["stripe", "adyen", "checkout"]);
+
+        assert_eq!(
+            get_names(order_connectors_by_failover_chain(connectors, &[])),
+            vec!["stripe", "adyen", "checkout"]
+        );
+    }
+
+    fn get_soft_decline_step_up() -> settings::SoftDeclineStepUp {
+        settings::SoftDeclineStepUp {
+            connectors: std::collections::HashMap::from([(
+                "stripe".to_string(),
+                settings::StepUpDeclineCodes {
+                    decline_codes: std::collections::HashSet::from([
+                        "authentication_required".to_string()
+                    ]),
+                },
+            )]),
+        }
+    }
+
+    fn get_router_data(
+        connector: &str,
+        response: Result<types::PaymentsResponseData, types::ErrorResponse>,
+    ) -> types::PaymentsCancelRouterData {
+        let mut router_data = services::conversion_impls::get_default_router_data(
+            "void",
+            types::PaymentsCancelData::default(),
+            response,
+        );
+        router_data.connector = connector.to_string();
+        router_data
+    }
+
+    fn get_error_response(code: &str) -> types::ErrorResponse {
+        types::ErrorResponse {
+            code: code.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_configured_decline_code_of_the_connector_requires_authentication() {
+        let router_data =
+            get_router_data("stripe", Err(get_error_response("authentication_required")));
+
+        assert!(is_authentication_required_decline(
+            &get_soft_decline_step_up(),
+            &router_data
+        ));
+    }
+
+    #[test]
+    fn test_decline_code_which_is_not_configured_does_not_require_authentication() {
+        let router_data = get_router_data("stripe", Err(get_error_response("card_declined")));
+
+        assert!(!is_authentication_required_decline(
+            &get_soft_decline_step_up(),
+            &router_data
+        ));
+    }
+
+    #[test]
+    fn test_decline_code_configured_for_another_connector_does_not_require_authentication() {
+        let router_data =
+            get_router_data("adyen", Err(get_error_response("authentication_required")));
+
+        assert!(!is_authentication_required_decline(
+            &get_soft_decline_step_up(),
+            &router_data
+        ));
+    }
+
+    #[test]
+    fn test_successful_response_does_not_require_authentication() {
+        let router_data = get_router_data(
+            "stripe",
+            Ok(
+                types::PaymentsResponseData::IncrementalAuthorizationResponse {
+                    status: common_enums::AuthorizationStatus::Success,
+                    connector_authorization_id: None,
+                    error_code: None,
+                    error_message: None,
+                },
+            ),
+        );
+
+        assert!(!is_authentication_required_decline(
+            &get_soft_decline_step_up(),
+            &router_data
+        ));
+    }
+}
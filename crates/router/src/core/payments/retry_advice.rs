@@ -0,0 +1,129 @@
+//! Advice of the card networks on retrying declined payments.
+//!
+//! Along with a decline, the card networks may advise whether the payment can be retried, and
+//! when. The connectors pass the advice code returned by the network through in the error
+//! response, and the code is parsed into a [`RetryAdvice`] according to the network which
+//! processed the card. The advice is stored on the payment attempt and exposed in the payment
+//! response, so that merchants can schedule their retries, and the automatic retries do not retry
+//! the payments which the network advised against retrying.
+
+use api_models::payments::AdditionalPaymentData;
+use common_utils::{ext_traits::ValueExt, types::RetryAdvice};
+
+use crate::types::{self, storage, storage::enums};
+
+const SECONDS_PER_HOUR: i64 = 60 * 60;
+const SECONDS_PER_DAY: i64 = 24 * SECONDS_PER_HOUR;
+
+fn retry_after(retry_after_seconds: i64) -> Option<RetryAdvice> {
+    Some(RetryAdvice::RetryAfter {
+        retry_after_seconds,
+    })
+}
+
+/// Parse the Merchant Advice Code returned by Mastercard
+fn get_mastercard_retry_advice(advice_code: &str) -> Option<RetryAdvice> {
+    match advice_code {
+        // Cannot approve at this time, try again later
+        "02" => retry_after(3 * SECONDS_PER_DAY),
+        "24" => retry_after(SECONDS_PER_HOUR),
+        "25" => retry_after(SECONDS_PER_DAY),
+        "26" => retry_after(2 * SECONDS_PER_DAY),
+        "27" => retry_after(4 * SECONDS_PER_DAY),
+        "28" => retry_after(6 * SECONDS_PER_DAY),
+        "29" => retry_after(8 * SECONDS_PER_DAY),
+        "30" => retry_after(10 * SECONDS_PER_DAY),
+        // New account information is available, do not try again, token requirements are not
+        // fulfilled, the payment was cancelled by the customer, or the card is a non-reloadable
+        // prepaid or single use virtual card
+        "01" | "03" | "04" | "21" | "40" | "41" => Some(RetryAdvice::NotRetryable),
+        _ => None,
+    }
+}
+
+/// Parse the advice code returned by the card network. Returns `None` if the network does not
+/// return advice codes, or if the advice code is not known. The response codes of the issuers, such
+/// as those returned for Visa cards, are not advice codes and are not parsed.
+pub fn get_retry_advice(
+    card_network: &enums::CardNetwork,
+    advice_code: &str,
+) -> Option<RetryAdvice> {
+    match card_network {
+        enums::CardNetwork::Mastercard => get_mastercard_retry_advice(advice_code),
+        _ => None,
+    }
+}
+
+fn get_card_network(payment_attempt: &storage::PaymentAttempt) -> Option<enums::CardNetwork> {
+    payment_attempt
+        .payment_method_data
+        .clone()?
+        .parse_value::<AdditionalPaymentData>("AdditionalPaymentData")
+        .ok()
+        .and_then(|additional_payment_data| match additional_payment_data {
+            AdditionalPaymentData::Card(card) => card.card_network,
+            _ => None,
+        })
+}
+
+/// Get the retry advice of the card network from the error response of the connector, for the
+/// card used in the payment attempt
+pub fn get_retry_advice_from_error_response(
+    payment_attempt: &storage::PaymentAttempt,
+    error_response: &types::ErrorResponse,
+) -> Option<RetryAdvice> {
+    let advice_code = error_response.network_advice_code.as_deref()?;
+    get_retry_advice(&get_card_network(payment_attempt)?, advice_code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mastercard_advice_codes() {
+        let network = enums::CardNetwork::Mastercard;
+
+        assert_eq!(
+            get_retry_advice(&network, "02"),
+            Some(RetryAdvice::RetryAfter {
+                retry_after_seconds: 72 * 60 * 60
+            })
+        );
+        assert_eq!(
+            get_retry_advice(&network, "24"),
+            Some(RetryAdvice::RetryAfter {
+                retry_after_seconds: 60 * 60
+            })
+        );
+        assert_eq!(
+            get_retry_advice(&network, "03"),
+            Some(RetryAdvice::NotRetryable)
+        );
+        assert_eq!(
+            get_retry_advice(&network, "21"),
+            Some(RetryAdvice::NotRetryable)
+        );
+        assert_eq!(get_retry_advice(&network, "99"), None);
+    }
+
+    #[test]
+    fn test_networks_without_advice_codes() {
+        assert_eq!(
+            get_retry_advice(&enums::CardNetwork::AmericanExpress, "03"),
+            None
+        );
+        // Response codes of the issuers, such as insufficient funds, are not advice codes
+        assert_eq!(get_retry_advice(&enums::CardNetwork::Visa, "51"), None);
+    }
+
+    #[test]
+    fn test_only_immediate_retries_are_retryable_now() {
+        assert!(RetryAdvice::Retryable.is_retryable_now());
+        assert!(!RetryAdvice::NotRetryable.is_retryable_now());
+        assert!(!RetryAdvice::RetryAfter {
+            retry_after_seconds: 60 * 60
+        }
+        .is_retryable_now());
+    }
+}
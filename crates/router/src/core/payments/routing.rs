@@ -31,7 +31,8 @@ use rand::{
     distributions::{self, Distribution},
     SeedableRng,
 };
-use rustc_hash::FxHashMap;
+use router_env::metrics::add_attributes;
+use rustc_hash::{FxHashMap, FxHashSet};
 use storage_impl::redis::cache::{CacheKey, CGRAPH_CACHE, ROUTING_CACHE};
 
 #[cfg(feature = "v2")]
@@ -40,10 +41,12 @@ use crate::core::admin;
 use crate::core::payouts;
 use crate::{
     core::{
-        errors, errors as oss_errors, payments as payments_oss,
+        errors, errors as oss_errors, metrics as core_metrics, payments as payments_oss,
         routing::{self},
     },
     logger,
+    routes::metrics,
+    services::ConnectorValidation,
     types::{
         api::{self, routing as routing_types},
         domain, storage as oss_storage,
@@ -286,6 +289,29 @@ pub async fn perform_static_routing_v1(
     business_profile: &domain::Profile,
     transaction_data: &routing::TransactionData<'_>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    perform_static_routing_with_rule_match_v1(
+        state,
+        merchant_id,
+        algorithm_id,
+        business_profile,
+        transaction_data,
+    )
+    .await
+    .map(|(connectors, _)| connectors)
+}
+
+/// Perform the static routing, and tell whether the connectors were chosen by the routing
+/// configured by the merchant. The connectors are not chosen by the merchant when no routing
+/// algorithm is active, in which case they are those of the fallback config, or when no rule of
+/// the active advanced routing algorithm matches, in which case they are the default selection of
+/// the algorithm.
+pub async fn perform_static_routing_with_rule_match_v1(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    algorithm_id: Option<&common_utils::id_type::RoutingId>,
+    business_profile: &domain::Profile,
+    transaction_data: &routing::TransactionData<'_>,
+) -> RoutingResult<(Vec<routing_types::RoutableConnectorChoice>, bool)> {
     let algorithm_id = if let Some(id) = algorithm_id {
         id
     } else {
@@ -302,7 +328,7 @@ pub async fn perform_static_routing_v1(
             .get_default_fallback_list_of_connector_under_profile()
             .change_context(errors::RoutingError::FallbackConfigFetchFailed)?;
 
-        return Ok(fallback_config);
+        return Ok((fallback_config, false));
     };
     let cached_algorithm = ensure_algorithm_cached_v1(
         state,
@@ -313,13 +339,28 @@ pub async fn perform_static_routing_v1(
     )
     .await?;
 
+    // Seed the volume splits with the identifier of the transaction, so that all the attempts of
+    // a transaction are split the same way
+    let rng_seed = match transaction_data {
+        routing::TransactionData::Payment(payment_data) => {
+            payment_data.payment_intent.get_id().get_string_repr()
+        }
+        #[cfg(feature = "payouts")]
+        routing::TransactionData::Payout(payout_data) => {
+            payout_data.payout_attempt.payout_id.as_str()
+        }
+    };
+
     Ok(match cached_algorithm.as_ref() {
-        CachedAlgorithm::Single(conn) => vec![(**conn).clone()],
+        CachedAlgorithm::Single(conn) => (vec![(**conn).clone()], true),
 
-        CachedAlgorithm::Priority(plist) => plist.clone(),
+        CachedAlgorithm::Priority(plist) => (plist.clone(), true),
 
-        CachedAlgorithm::VolumeSplit(splits) => perform_volume_split(splits.to_vec(), None)
-            .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
+        CachedAlgorithm::VolumeSplit(splits) => (
+            perform_volume_split(splits.to_vec(), Some(rng_seed))
+                .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
+            true,
+        ),
 
         CachedAlgorithm::Advanced(interpreter) => {
             let backend_input = match transaction_data {
@@ -330,11 +371,339 @@ pub async fn perform_static_routing_v1(
                 }
             };
 
-            execute_dsl_and_get_connector_v1(backend_input, interpreter)?
+            let (connectors, rule_name) = execute_dsl_and_get_connector_with_rule_name_v1(
+                backend_input,
+                interpreter,
+                Some(rng_seed),
+            )?;
+            (connectors, rule_name.is_some())
         }
     })
 }
 
+/// Get the default connector configured by the merchant for the payment method type of the payment
+#[cfg(feature = "v1")]
+pub fn get_default_connector_for_payment_method_type(
+    merchant_account: &domain::MerchantAccount,
+    payment_method_type: Option<api_enums::PaymentMethodType>,
+) -> RoutingResult<Option<api_enums::RoutableConnectors>> {
+    let Some(payment_method_type) = payment_method_type else {
+        return Ok(None);
+    };
+
+    Ok(merchant_account
+        .default_connector_by_method_type
+        .clone()
+        .map(|default_connector_by_method_type| {
+            default_connector_by_method_type
+                .parse_value::<HashMap<api_enums::PaymentMethodType, api_enums::RoutableConnectors>>(
+                    "DefaultConnectorByMethodType",
+                )
+        })
+        .transpose()
+        .change_context(errors::RoutingError::DefaultConnectorParsingError)?
+        .and_then(|default_connector_by_method_type| {
+            default_connector_by_method_type
+                .get(&payment_method_type)
+                .copied()
+        }))
+}
+
+/// Move the default connector configured by the merchant for the payment method type of the
+/// payment to the front of the connectors, when it is eligible for the payment
+#[cfg(feature = "v1")]
+pub fn prioritize_default_connector_for_payment_method_type(
+    merchant_account: &domain::MerchantAccount,
+    payment_method_type: Option<api_enums::PaymentMethodType>,
+    mut connectors: Vec<routing_types::RoutableConnectorChoice>,
+) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    let default_connector =
+        get_default_connector_for_payment_method_type(merchant_account, payment_method_type)?;
+
+    if let Some(position) = default_connector.and_then(|default_connector| {
+        connectors
+            .iter()
+            .position(|choice| choice.connector == default_connector)
+    }) {
+        let default_choice = connectors.remove(position);
+        connectors.insert(0, default_choice);
+    }
+
+    Ok(connectors)
+}
+
+/// Order the connectors by their estimated cost for the payment when least cost routing is enabled
+/// for the merchant, so that the payment is routed to the cheapest eligible connector among those
+/// the merchant has not ranked. The ranked connectors, which is the default connector when no
+/// routing algorithm is active, keep their order ahead of the others. The connectors chosen by an
+/// active routing algorithm are all ordered by their cost. The connectors without a cost applicable
+/// to the payment follow those with one, and the connectors which cost the same keep their order,
+/// which is the order of the routing algorithm, so that ties are broken deterministically.
+#[cfg(feature = "v1")]
+pub fn sort_connectors_by_cost(
+    merchant_account: &domain::MerchantAccount,
+    transaction_data: &routing::PaymentsDslInput<'_>,
+    ranked_connectors: &[routing_types::RoutableConnectorChoice],
+    connectors: Vec<routing_types::RoutableConnectorChoice>,
+) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    let Some(least_cost_routing) = merchant_account.get_least_cost_routing() else {
+        return Ok(connectors);
+    };
+
+    let amount = transaction_data.payment_intent.amount;
+    let country = transaction_data
+        .address
+        .get_payment_method_billing()
+        .and_then(|billing| billing.address.as_ref())
+        .and_then(|address| address.country);
+
+    let connectors_with_cost = connectors
+        .into_iter()
+        .map(|choice| {
+            least_cost_routing
+                .estimate_connector_cost(
+                    choice.connector,
+                    amount,
+                    transaction_data.currency,
+                    transaction_data.payment_attempt.payment_method,
+                    transaction_data.payment_attempt.payment_method_type,
+                    country,
+                )
+                .change_context(errors::RoutingError::ConnectorCostEstimationFailed)
+                .map(|cost| (choice, cost))
+        })
+        .collect::<RoutingResult<Vec<_>>>()?;
+
+    connectors_with_cost.iter().for_each(|(choice, cost)| {
+        logger::debug!(
+            connector = %choice.connector,
+            estimated_cost = ?cost,
+            "Estimated the cost of the connector for least cost routing"
+        );
+    });
+
+    Ok(order_connectors_by_cost(
+        ranked_connectors,
+        connectors_with_cost,
+    ))
+}
+
+/// Order the connectors by their rank, and the connectors which are not ranked by their cost after
+/// the ranked connectors. The sort is stable, which retains the order of the connectors which rank
+/// and cost the same.
+#[cfg(feature = "v1")]
+fn order_connectors_by_cost(
+    ranked_connectors: &[routing_types::RoutableConnectorChoice],
+    mut connectors_with_cost: Vec<(
+        routing_types::RoutableConnectorChoice,
+        Option<common_utils::types::MinorUnit>,
+    )>,
+) -> Vec<routing_types::RoutableConnectorChoice> {
+    connectors_with_cost.sort_by_key(|(choice, cost)| {
+        let rank = ranked_connectors
+            .iter()
+            .position(|ranked_choice| ranked_choice == choice);
+        (
+            rank.is_none(),
+            rank,
+            cost.is_none(),
+            cost.map(|cost| cost.get_amount_as_i64()),
+        )
+    });
+
+    connectors_with_cost
+        .into_iter()
+        .map(|(choice, _)| choice)
+        .collect()
+}
+
+/// Whether a new mandate is set up with the payment, in which case the payment is a customer
+/// initiated payment which is neither made with an existing mandate nor with the recurring details
+/// of an earlier payment
+pub fn is_new_mandate_setup(
+    transaction_data: &routing::PaymentsDslInput<'_>,
+    mandate_id: Option<&api_models::payments::MandateIds>,
+) -> bool {
+    let is_customer_initiated_mandate_payment = (transaction_data.setup_mandate.is_some()
+        || transaction_data
+            .payment_attempt
+            .customer_acceptance
+            .is_some())
+        && transaction_data.payment_intent.setup_future_usage
+            == Some(storage_enums::FutureUsage::OffSession);
+
+    is_customer_initiated_mandate_payment
+        && mandate_id.is_none()
+        && transaction_data.recurring_details.is_none()
+        && transaction_data.payment_intent.off_session != Some(true)
+}
+
+/// Remove the connectors which do not support mandates when a new mandate is to be set up with the
+/// payment. The support of the connector is checked against its integration for the payment method
+/// of the payment, and against the declared capabilities of the connector when the payment method
+/// data is not yet known.
+pub fn filter_connectors_by_mandate_support(
+    connectors_config: &crate::configs::settings::Connectors,
+    is_new_mandate_setup: bool,
+    payment_method_type: Option<storage_enums::PaymentMethodType>,
+    payment_method_data: Option<&domain::PaymentMethodData>,
+    connectors: Vec<routing_types::RoutableConnectorChoice>,
+) -> Vec<routing_types::RoutableConnectorChoice> {
+    if !is_new_mandate_setup {
+        return connectors;
+    }
+
+    connectors
+        .into_iter()
+        .filter(|choice| match payment_method_data {
+            Some(payment_method_data) => api::ConnectorData::get_connector_by_name(
+                connectors_config,
+                &choice.connector.to_string(),
+                api::GetToken::Connector,
+                choice.merchant_connector_id.clone(),
+            )
+            .inspect_err(|error| logger::error!(?error, "Failed to get routable connector"))
+            .is_ok_and(|connector_data| {
+                connector_data
+                    .connector
+                    .validate_mandate_payment(payment_method_type, payment_method_data.clone())
+                    .is_ok()
+            }),
+            None => api_enums::Connector::from_str(&choice.connector.to_string())
+                .map(|connector| connector.get_capabilities().mandates)
+                .inspect_err(|error| logger::error!(?error, "Failed to parse routable connector"))
+                .unwrap_or(false),
+        })
+        .collect()
+}
+
+/// Remove the connectors which do not accept a payment of the net amount in the currency, per the
+/// limits documented by the connectors, so that the payment is not routed to a connector which
+/// would decline it for its amount. The net amount is the amount charged to the customer, including
+/// the surcharge and the tax on it. The limits do not apply to zero amount payments, such as those
+/// which only set up a mandate or verify the payment method, which are made to all the connectors.
+/// The cost based and volume split routing only order the connectors retained by this.
+pub fn filter_connectors_by_amount_limits(
+    net_amount: common_utils::types::MinorUnit,
+    currency: api_enums::Currency,
+    connectors: Vec<routing_types::RoutableConnectorChoice>,
+) -> Vec<routing_types::RoutableConnectorChoice> {
+    if net_amount == common_utils::types::MinorUnit::zero() {
+        return connectors;
+    }
+
+    connectors
+        .into_iter()
+        .filter(|choice| {
+            let is_supported = api_enums::Connector::from_str(&choice.connector.to_string())
+                .map(|connector| {
+                    connector
+                        .get_capabilities()
+                        .is_amount_supported(net_amount, currency)
+                })
+                .inspect_err(|error| logger::error!(?error, "Failed to parse routable connector"))
+                .unwrap_or(true);
+            if !is_supported {
+                logger::info!(
+                    connector = %choice.connector,
+                    %currency,
+                    "Removing the connector which does not accept the amount of the payment"
+                );
+            }
+            is_supported
+        })
+        .collect()
+}
+
+/// Remove the connectors which are currently in one of their maintenance windows, so that the
+/// payment is routed to the alternate connectors. If all the connectors are in maintenance, the
+/// connectors are retained, since failing the payment would not be better than attempting it.
+///
+/// The connector accounts of the eligible connectors are read through the accounts cache, which is
+/// invalidated whenever a connector account is updated.
+#[cfg(feature = "v1")]
+pub async fn filter_connectors_in_maintenance(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    connectors: Vec<routing_types::RoutableConnectorChoice>,
+) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    let key_manager_state = &state.into();
+    let mut merchant_connector_ids_in_maintenance = FxHashSet::default();
+    for merchant_connector_id in connectors
+        .iter()
+        .filter_map(|choice| choice.merchant_connector_id.as_ref())
+    {
+        let merchant_connector_account = state
+            .store
+            .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+                key_manager_state,
+                &key_store.merchant_id,
+                merchant_connector_id,
+                key_store,
+            )
+            .await
+            .change_context(errors::RoutingError::MerchantConnectorAccountFetchFailed)?;
+        if merchant_connector_account.is_in_maintenance() {
+            merchant_connector_ids_in_maintenance.insert(merchant_connector_id.clone());
+        }
+    }
+
+    let (available_connectors, connectors_in_maintenance) =
+        partition_connectors_in_maintenance(connectors, &merchant_connector_ids_in_maintenance);
+
+    connectors_in_maintenance.iter().for_each(|choice| {
+        logger::info!(
+            connector = %choice.connector,
+            "Diverting traffic from the connector which is in maintenance"
+        );
+        core_metrics::ROUTING_CONNECTOR_IN_MAINTENANCE_DIVERTED.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([
+                (
+                    "merchant_id",
+                    key_store.merchant_id.get_string_repr().to_owned(),
+                ),
+                ("connector", choice.connector.to_string()),
+            ]),
+        );
+    });
+
+    Ok(available_connectors)
+}
+
+/// Split the connectors into those to route to and those diverted from for being in maintenance.
+/// No connector is diverted from if all the connectors are in maintenance.
+#[cfg(feature = "v1")]
+fn partition_connectors_in_maintenance(
+    connectors: Vec<routing_types::RoutableConnectorChoice>,
+    merchant_connector_ids_in_maintenance: &FxHashSet<
+        common_utils::id_type::MerchantConnectorAccountId,
+    >,
+) -> (
+    Vec<routing_types::RoutableConnectorChoice>,
+    Vec<routing_types::RoutableConnectorChoice>,
+) {
+    let (connectors_in_maintenance, available_connectors): (Vec<_>, Vec<_>) =
+        connectors.into_iter().partition(|choice| {
+            choice
+                .merchant_connector_id
+                .as_ref()
+                .is_some_and(|id| merchant_connector_ids_in_maintenance.contains(id))
+        });
+
+    if available_connectors.is_empty() {
+        if !connectors_in_maintenance.is_empty() {
+            logger::warn!(
+                "All the eligible connectors are in maintenance, routing to them regardless"
+            );
+        }
+        return (connectors_in_maintenance, Vec::new());
+    }
+
+    (available_connectors, connectors_in_maintenance)
+}
+
 async fn ensure_algorithm_cached_v1(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
@@ -381,6 +750,7 @@ async fn ensure_algorithm_cached_v1(
 pub fn perform_straight_through_routing(
     algorithm: &routing_types::StraightThroughAlgorithm,
     creds_identifier: Option<&str>,
+    rng_seed: Option<&str>,
 ) -> RoutingResult<(Vec<routing_types::RoutableConnectorChoice>, bool)> {
     Ok(match algorithm {
         routing_types::StraightThroughAlgorithm::Single(conn) => {
@@ -390,7 +760,7 @@ pub fn perform_straight_through_routing(
         routing_types::StraightThroughAlgorithm::Priority(conns) => (conns.clone(), true),
 
         routing_types::StraightThroughAlgorithm::VolumeSplit(splits) => (
-            perform_volume_split(splits.to_vec(), None)
+            perform_volume_split(splits.to_vec(), rng_seed)
                 .change_context(errors::RoutingError::ConnectorSelectionFailed)
                 .attach_printable(
                     "Volume Split connector selection error in straight through routing",
@@ -403,21 +773,38 @@ pub fn perform_straight_through_routing(
 fn execute_dsl_and_get_connector_v1(
     backend_input: dsl_inputs::BackendInput,
     interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
+    rng_seed: Option<&str>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
-    let routing_output: routing_types::RoutingAlgorithm = interpreter
+    execute_dsl_and_get_connector_with_rule_name_v1(backend_input, interpreter, rng_seed)
+        .map(|(connectors, _)| connectors)
+}
+
+/// Execute the advanced routing algorithm, along with the name of the rule which matched, which is
+/// not set when the default selection of the algorithm was chosen
+fn execute_dsl_and_get_connector_with_rule_name_v1(
+    backend_input: dsl_inputs::BackendInput,
+    interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
+    rng_seed: Option<&str>,
+) -> RoutingResult<(Vec<routing_types::RoutableConnectorChoice>, Option<String>)> {
+    let backend_output = interpreter
         .execute(backend_input)
-        .map(|out| out.connector_selection.foreign_into())
         .change_context(errors::RoutingError::DslExecutionError)?;
+    let routing_output: routing_types::RoutingAlgorithm =
+        backend_output.connector_selection.foreign_into();
 
-    Ok(match routing_output {
+    let connectors = match routing_output {
         routing_types::RoutingAlgorithm::Priority(plist) => plist,
 
-        routing_types::RoutingAlgorithm::VolumeSplit(splits) => perform_volume_split(splits, None)
-            .change_context(errors::RoutingError::DslFinalConnectorSelectionFailed)?,
+        routing_types::RoutingAlgorithm::VolumeSplit(splits) => {
+            perform_volume_split(splits, rng_seed)
+                .change_context(errors::RoutingError::DslFinalConnectorSelectionFailed)?
+        }
 
         _ => Err(errors::RoutingError::DslIncorrectSelectionAlgorithm)
             .attach_printable("Unsupported algorithm received as a result of static routing")?,
-    })
+    };
+
+    Ok((connectors, backend_output.rule_name))
 }
 
 pub async fn refresh_routing_cache_v1(
@@ -469,38 +856,57 @@ pub async fn refresh_routing_cache_v1(
     Ok(arc_cached_algorithm)
 }
 
+/// Order the connectors of a volume split by repeatedly drawing a connector in proportion to
+/// the splits of the connectors which are yet to be drawn. The connector drawn first receives the
+/// share of the traffic configured for it, and when a connector is later removed from the order,
+/// such as for being in maintenance, its share is redistributed to the connectors following it in
+/// proportion to their splits. The connectors with a zero split are placed last.
+///
+/// When a seed is provided, such as the identifier of the payment, the order is deterministic for
+/// the seed, so that the retries of a payment are routed consistently.
 pub fn perform_volume_split(
-    mut splits: Vec<routing_types::ConnectorVolumeSplit>,
+    splits: Vec<routing_types::ConnectorVolumeSplit>,
     rng_seed: Option<&str>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
-    let weights: Vec<u8> = splits.iter().map(|sp| sp.split).collect();
-    let weighted_index = distributions::WeightedIndex::new(weights)
-        .change_context(errors::RoutingError::VolumeSplitFailed)
-        .attach_printable("Error creating weighted distribution for volume split")?;
-
-    let idx = if let Some(seed) = rng_seed {
+    if let Some(seed) = rng_seed {
         let mut hasher = hash_map::DefaultHasher::new();
         seed.hash(&mut hasher);
         let hash = hasher.finish();
 
         let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(hash);
-        weighted_index.sample(&mut rng)
+        order_volume_split(splits, &mut rng)
     } else {
         let mut rng = rand::thread_rng();
-        weighted_index.sample(&mut rng)
-    };
+        order_volume_split(splits, &mut rng)
+    }
+}
 
-    splits
-        .get(idx)
-        .ok_or(errors::RoutingError::VolumeSplitFailed)
-        .attach_printable("Volume split index lookup failed")?;
+fn order_volume_split<R: rand::Rng>(
+    mut splits: Vec<routing_types::ConnectorVolumeSplit>,
+    rng: &mut R,
+) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    let mut ordered_connectors = Vec::with_capacity(splits.len());
+
+    while splits.iter().any(|split| split.split > 0) {
+        let weighted_index =
+            distributions::WeightedIndex::new(splits.iter().map(|split| u32::from(split.split)))
+                .change_context(errors::RoutingError::VolumeSplitFailed)
+                .attach_printable("Error creating weighted distribution for volume split")?;
+        let idx = weighted_index.sample(rng);
+
+        splits
+            .get(idx)
+            .ok_or(errors::RoutingError::VolumeSplitFailed)
+            .attach_printable("Volume split index lookup failed")?;
+
+        // Panic Safety: We have performed a `get(idx)` operation just above which will
+        // ensure that the index is always present, else throw an error.
+        ordered_connectors.push(splits.remove(idx).connector);
+    }
 
-    // Panic Safety: We have performed a `get(idx)` operation just above which will
-    // ensure that the index is always present, else throw an error.
-    let removed = splits.remove(idx);
-    splits.insert(0, removed);
+    ordered_connectors.extend(splits.into_iter().map(|split| split.connector));
 
-    Ok(splits.into_iter().map(|sp| sp.connector).collect())
+    Ok(ordered_connectors)
 }
 
 pub async fn get_merchant_cgraph<'a>(
@@ -1012,6 +1418,7 @@ async fn perform_session_routing_for_pm_type(
             CachedAlgorithm::Advanced(interpreter) => execute_dsl_and_get_connector_v1(
                 session_pm_input.backend_input.clone(),
                 interpreter,
+                Some(session_pm_input.attempt_id),
             )?,
         }
     } else {
@@ -1094,6 +1501,7 @@ async fn perform_session_routing_for_pm_type(
             CachedAlgorithm::Advanced(interpreter) => execute_dsl_and_get_connector_v1(
                 session_pm_input.backend_input.clone(),
                 interpreter,
+                Some(session_pm_input.attempt_id),
             )?,
         }
     } else {
@@ -1202,3 +1610,257 @@ pub fn make_dsl_input_for_surcharge(
     };
     Ok(backend_input)
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_connector_choice(
+        connector: api_enums::RoutableConnectors,
+        merchant_connector_id: &str,
+    ) -> routing_types::RoutableConnectorChoice {
+        routing_types::RoutableConnectorChoice {
+            choice_kind: routing_types::RoutableChoiceKind::FullStruct,
+            connector,
+            merchant_connector_id: Some(
+                common_utils::id_type::MerchantConnectorAccountId::wrap(
+                    merchant_connector_id.to_string(),
+                )
+                .unwrap(),
+            ),
+        }
+    }
+
+    #[test]
+    fn test_connectors_in_maintenance_are_diverted_from() {
+        let connectors = vec![
+            get_connector_choice(api_enums::RoutableConnectors::Stripe, "mca_stripe"),
+            get_connector_choice(api_enums::RoutableConnectors::Adyen, "mca_adyen"),
+        ];
+        let merchant_connector_ids_in_maintenance =
+            FxHashSet::from_iter([common_utils::id_type::MerchantConnectorAccountId::wrap(
+                "mca_stripe".to_string(),
+            )
+            .unwrap()]);
+
+        let (available_connectors, connectors_in_maintenance) =
+            partition_connectors_in_maintenance(connectors, &merchant_connector_ids_in_maintenance);
+
+        assert_eq!(available_connectors.len(), 1);
+        assert_eq!(
+            available_connectors[0].connector,
+            api_enums::RoutableConnectors::Adyen
+        );
+        assert_eq!(connectors_in_maintenance.len(), 1);
+    }
+
+    #[test]
+    fn test_connectors_are_retained_if_all_are_in_maintenance() {
+        let connectors = vec![get_connector_choice(
+            api_enums::RoutableConnectors::Stripe,
+            "mca_stripe",
+        )];
+        let merchant_connector_ids_in_maintenance =
+            FxHashSet::from_iter([common_utils::id_type::MerchantConnectorAccountId::wrap(
+                "mca_stripe".to_string(),
+            )
+            .unwrap()]);
+
+        let (available_connectors, connectors_in_maintenance) =
+            partition_connectors_in_maintenance(connectors, &merchant_connector_ids_in_maintenance);
+
+        assert_eq!(available_connectors.len(), 1);
+        assert!(connectors_in_maintenance.is_empty());
+    }
+
+    #[test]
+    fn test_connectors_are_filtered_by_the_net_amount() {
+        let connectors = vec![
+            get_connector_choice(api_enums::RoutableConnectors::Stripe, "mca_stripe"),
+            get_connector_choice(api_enums::RoutableConnectors::Razorpay, "mca_razorpay"),
+        ];
+
+        // Razorpay documents a minimum of 100 for INR
+        let filtered_connectors = filter_connectors_by_amount_limits(
+            common_utils::types::MinorUnit::new(60),
+            api_enums::Currency::INR,
+            connectors.clone(),
+        );
+        assert_eq!(filtered_connectors.len(), 1);
+        assert_eq!(
+            filtered_connectors[0].connector,
+            api_enums::RoutableConnectors::Stripe
+        );
+
+        let filtered_connectors = filter_connectors_by_amount_limits(
+            common_utils::types::MinorUnit::new(100),
+            api_enums::Currency::INR,
+            connectors,
+        );
+        assert_eq!(filtered_connectors.len(), 2);
+    }
+
+    #[test]
+    fn test_zero_amount_payments_are_not_filtered_by_amount_limits() {
+        let connectors = vec![
+            get_connector_choice(api_enums::RoutableConnectors::Stripe, "mca_stripe"),
+            get_connector_choice(api_enums::RoutableConnectors::Razorpay, "mca_razorpay"),
+        ];
+
+        let filtered_connectors = filter_connectors_by_amount_limits(
+            common_utils::types::MinorUnit::zero(),
+            api_enums::Currency::INR,
+            connectors,
+        );
+        assert_eq!(filtered_connectors.len(), 2);
+    }
+
+    #[test]
+    fn test_ranked_connectors_keep_their_order() {
+        let stripe = get_connector_choice(api_enums::RoutableConnectors::Stripe, "mca_stripe");
+        let adyen = get_connector_choice(api_enums::RoutableConnectors::Adyen, "mca_adyen");
+        let checkout =
+            get_connector_choice(api_enums::RoutableConnectors::Checkout, "mca_checkout");
+        let worldpay =
+            get_connector_choice(api_enums::RoutableConnectors::Worldpay, "mca_worldpay");
+        let cost = |amount| Some(common_utils::types::MinorUnit::new(amount));
+
+        let connectors = order_connectors_by_cost(
+            &[stripe.clone(), adyen.clone()],
+            vec![
+                (worldpay.clone(), None),
+                (checkout.clone(), cost(10)),
+                (stripe.clone(), cost(300)),
+                (adyen.clone(), cost(200)),
+            ],
+        );
+
+        // The cheaper connectors which are not ranked do not take precedence over the ranked ones
+        assert_eq!(connectors, vec![stripe, adyen, checkout, worldpay]);
+    }
+
+    #[test]
+    fn test_connectors_of_the_routing_algorithm_are_ordered_by_cost() {
+        let stripe = get_connector_choice(api_enums::RoutableConnectors::Stripe, "mca_stripe");
+        let adyen = get_connector_choice(api_enums::RoutableConnectors::Adyen, "mca_adyen");
+        let checkout =
+            get_connector_choice(api_enums::RoutableConnectors::Checkout, "mca_checkout");
+        let cost = |amount| Some(common_utils::types::MinorUnit::new(amount));
+
+        let connectors = order_connectors_by_cost(
+            &[],
+            vec![
+                (stripe.clone(), cost(300)),
+                (adyen.clone(), cost(200)),
+                (checkout.clone(), cost(300)),
+            ],
+        );
+
+        // The connectors which cost the same keep the order of the routing algorithm
+        assert_eq!(connectors, vec![adyen, stripe, checkout]);
+    }
+
+    fn get_volume_split(
+        connector: routing_types::RoutableConnectorChoice,
+        split: u8,
+    ) -> routing_types::ConnectorVolumeSplit {
+        routing_types::ConnectorVolumeSplit { connector, split }
+    }
+
+    #[test]
+    fn test_zero_split_connectors_are_ordered_last() {
+        let stripe = get_connector_choice(api_enums::RoutableConnectors::Stripe, "mca_stripe");
+        let adyen = get_connector_choice(api_enums::RoutableConnectors::Adyen, "mca_adyen");
+        let checkout =
+            get_connector_choice(api_enums::RoutableConnectors::Checkout, "mca_checkout");
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(42);
+
+        for _ in 0..100 {
+            let connectors = order_volume_split(
+                vec![
+                    get_volume_split(checkout.clone(), 0),
+                    get_volume_split(stripe.clone(), 50),
+                    get_volume_split(adyen.clone(), 50),
+                ],
+                &mut rng,
+            )
+            .unwrap();
+
+            assert_eq!(connectors.len(), 3);
+            assert!(connectors.contains(&stripe));
+            assert!(connectors.contains(&adyen));
+            assert_eq!(connectors[2], checkout);
+        }
+    }
+
+    #[test]
+    fn test_connector_with_the_whole_split_is_ordered_first() {
+        let stripe = get_connector_choice(api_enums::RoutableConnectors::Stripe, "mca_stripe");
+        let adyen = get_connector_choice(api_enums::RoutableConnectors::Adyen, "mca_adyen");
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(42);
+
+        for _ in 0..100 {
+            let connectors = order_volume_split(
+                vec![
+                    get_volume_split(adyen.clone(), 0),
+                    get_volume_split(stripe.clone(), 100),
+                ],
+                &mut rng,
+            )
+            .unwrap();
+
+            assert_eq!(connectors, vec![stripe.clone(), adyen.clone()]);
+        }
+    }
+
+    #[test]
+    fn test_connectors_are_ordered_first_in_proportion_to_their_splits() {
+        let stripe = get_connector_choice(api_enums::RoutableConnectors::Stripe, "mca_stripe");
+        let adyen = get_connector_choice(api_enums::RoutableConnectors::Adyen, "mca_adyen");
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(42);
+
+        let stripe_first_count = (0..10_000)
+            .filter(|_| {
+                let connectors = order_volume_split(
+                    vec![
+                        get_volume_split(stripe.clone(), 70),
+                        get_volume_split(adyen.clone(), 30),
+                    ],
+                    &mut rng,
+                )
+                .unwrap();
+                connectors[0] == stripe
+            })
+            .count();
+
+        assert!((6_700..=7_300).contains(&stripe_first_count));
+    }
+
+    #[test]
+    fn test_volume_split_is_deterministic_for_the_seed() {
+        let splits = vec![
+            get_volume_split(
+                get_connector_choice(api_enums::RoutableConnectors::Stripe, "mca_stripe"),
+                40,
+            ),
+            get_volume_split(
+                get_connector_choice(api_enums::RoutableConnectors::Adyen, "mca_adyen"),
+                35,
+            ),
+            get_volume_split(
+                get_connector_choice(api_enums::RoutableConnectors::Checkout, "mca_checkout"),
+                25,
+            ),
+        ];
+
+        let connectors = perform_volume_split(splits.clone(), Some("pay_seed")).unwrap();
+
+        for _ in 0..10 {
+            assert_eq!(
+                perform_volume_split(splits.clone(), Some("pay_seed")).unwrap(),
+                connectors
+            );
+        }
+    }
+}
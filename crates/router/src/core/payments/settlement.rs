@@ -0,0 +1,131 @@
+//! Expected settlement dates of the captured payments.
+//!
+//! The expected settlement date of a payment is the date reported by the connector, if the
+//! connector reports it. Otherwise, it is computed from the settlement delay configured for the
+//! connector, as the date which is that many working days after the capture of the payment. The
+//! days of the week and the holidays on which payments are not settled are configured in the
+//! settlement calendar.
+
+use time::{Date, PrimitiveDateTime};
+
+use crate::configs::settings::{SettlementCalendar, SettlementDelay};
+
+fn is_working_day(calendar: &SettlementCalendar, date: Date) -> bool {
+    !calendar.non_working_days.contains(&date.weekday()) && !calendar.holidays.contains(&date)
+}
+
+/// The date which is the number of working days after the date. With no working days, this is the
+/// date itself if it is a working day, and the next working day otherwise.
+fn add_working_days(calendar: &SettlementCalendar, date: Date, working_days: u16) -> Option<Date> {
+    let mut date = date;
+    let mut remaining_working_days = working_days;
+    while remaining_working_days > 0 || !is_working_day(calendar, date) {
+        date = date.next_day()?;
+        if is_working_day(calendar, date) {
+            remaining_working_days = remaining_working_days.saturating_sub(1);
+        }
+    }
+    Some(date)
+}
+
+/// The expected settlement date of the payment captured by the connector at the time. This is the
+/// settlement date reported by the connector, if any, and otherwise is computed from the
+/// settlement delay of the connector, if configured.
+pub fn get_expected_settlement_date(
+    settlement_delay: &SettlementDelay,
+    connector: &str,
+    connector_settlement_date: Option<Date>,
+    captured_at: PrimitiveDateTime,
+) -> Option<Date> {
+    connector_settlement_date.or_else(|| {
+        settlement_delay
+            .connectors
+            .get(connector)
+            .and_then(|delay_in_days| {
+                add_working_days(
+                    &settlement_delay.calendar,
+                    captured_at.date(),
+                    *delay_in_days,
+                )
+            })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use time::macros::{date, datetime};
+
+    use super::*;
+
+    fn get_settlement_delay() -> SettlementDelay {
+        SettlementDelay {
+            connectors: HashMap::from([("stripe".to_string(), 2), ("adyen".to_string(), 0)]),
+            calendar: SettlementCalendar {
+                non_working_days: HashSet::from([time::Weekday::Saturday, time::Weekday::Sunday]),
+                holidays: HashSet::from([date!(2024 - 12 - 25)]),
+            },
+        }
+    }
+
+    #[test]
+    fn test_settlement_date_skips_non_working_days() {
+        let settlement_delay = get_settlement_delay();
+
+        // Captured on a Friday, settled two working days later on the Tuesday
+        assert_eq!(
+            get_expected_settlement_date(
+                &settlement_delay,
+                "stripe",
+                None,
+                datetime!(2024-10-25 15:00)
+            ),
+            Some(date!(2024 - 10 - 29))
+        );
+        // Captured on a Monday, settled two working days later, skipping the holiday
+        assert_eq!(
+            get_expected_settlement_date(
+                &settlement_delay,
+                "stripe",
+                None,
+                datetime!(2024-12-23 15:00)
+            ),
+            Some(date!(2024 - 12 - 26))
+        );
+        // Captured on a Saturday without a delay, settled on the next working day
+        assert_eq!(
+            get_expected_settlement_date(
+                &settlement_delay,
+                "adyen",
+                None,
+                datetime!(2024-10-26 15:00)
+            ),
+            Some(date!(2024 - 10 - 28))
+        );
+    }
+
+    #[test]
+    fn test_settlement_date_reported_by_the_connector_is_preferred() {
+        let settlement_delay = get_settlement_delay();
+
+        assert_eq!(
+            get_expected_settlement_date(
+                &settlement_delay,
+                "stripe",
+                Some(date!(2024 - 11 - 01)),
+                datetime!(2024-10-25 15:00)
+            ),
+            Some(date!(2024 - 11 - 01))
+        );
+        assert_eq!(
+            get_expected_settlement_date(
+                &settlement_delay,
+                "checkout",
+                None,
+                datetime!(2024-10-25 15:00)
+            ),
+            None
+        );
+    }
+}
@@ -0,0 +1,279 @@
+//! Statement descriptors of payments, fitted to the maximum lengths supported by the connectors.
+//!
+//! The connectors accept statement descriptors only up to a maximum length, and either truncate or
+//! reject the longer descriptors. The descriptors of a payment are resolved against the maximum
+//! lengths of the connector before they are sent to it, according to the statement descriptor
+//! length policy of the merchant account, so that a descriptor is never altered without a warning
+//! in the payment response.
+
+use std::str::FromStr;
+
+use error_stack::report;
+
+use crate::{
+    core::errors::{self, RouterResult},
+    types::api::enums as api_enums,
+};
+
+/// The maximum lengths of the statement descriptors supported by a connector, `None` if the
+/// connector does not accept the descriptor
+#[derive(Clone, Copy, Debug)]
+pub struct StatementDescriptorMaxLengths {
+    pub statement_descriptor: Option<usize>,
+    pub statement_descriptor_suffix: Option<usize>,
+}
+
+/// The maximum lengths of the statement descriptors supported by the connectors. The descriptors
+/// sent to the connectors which are not listed are not altered.
+const STATEMENT_DESCRIPTOR_MAX_LENGTHS: &[(api_enums::Connector, StatementDescriptorMaxLengths)] =
+    &[
+        (
+            api_enums::Connector::Adyen,
+            StatementDescriptorMaxLengths {
+                statement_descriptor: Some(22),
+                statement_descriptor_suffix: None,
+            },
+        ),
+        (
+            api_enums::Connector::Stripe,
+            StatementDescriptorMaxLengths {
+                statement_descriptor: Some(22),
+                statement_descriptor_suffix: Some(22),
+            },
+        ),
+    ];
+
+/// Get the maximum lengths of the statement descriptors supported by the connector, if known
+pub fn get_statement_descriptor_max_lengths(
+    connector_name: &str,
+) -> Option<StatementDescriptorMaxLengths> {
+    let connector = api_enums::Connector::from_str(connector_name).ok()?;
+    STATEMENT_DESCRIPTOR_MAX_LENGTHS
+        .iter()
+        .find(|(listed_connector, _)| *listed_connector == connector)
+        .map(|(_, max_lengths)| *max_lengths)
+}
+
+/// The statement descriptors of a payment, resolved against the maximum lengths supported by the
+/// connector
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ResolvedStatementDescriptors {
+    pub statement_descriptor: Option<String>,
+    pub statement_descriptor_suffix: Option<String>,
+    /// The warnings about the descriptors which were truncated
+    pub warnings: Vec<String>,
+}
+
+fn resolve_statement_descriptor(
+    field_name: &str,
+    descriptor: Option<String>,
+    max_length: Option<usize>,
+    connector_name: &str,
+    policy: api_enums::StatementDescriptorLengthPolicy,
+    warnings: &mut Vec<String>,
+) -> RouterResult<Option<String>> {
+    let Some(max_length) = max_length else {
+        return Ok(descriptor);
+    };
+    let Some(descriptor) = descriptor else {
+        return Ok(None);
+    };
+
+    let length = descriptor.chars().count();
+    if length > max_length {
+        if policy == api_enums::StatementDescriptorLengthPolicy::Reject {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "{field_name} exceeds the maximum length of {max_length} characters supported by {connector_name}"
+                ),
+            }));
+        }
+
+        let truncated_descriptor = descriptor.chars().take(max_length).collect::<String>();
+        warnings.push(format!(
+            "{field_name} exceeds the maximum length of {max_length} characters supported by {connector_name}, and was truncated to `{truncated_descriptor}`"
+        ));
+        return Ok(Some(truncated_descriptor));
+    }
+
+    match policy {
+        api_enums::StatementDescriptorLengthPolicy::Pad => {
+            Ok(Some(format!("{descriptor:<max_length$}")))
+        }
+        api_enums::StatementDescriptorLengthPolicy::Truncate
+        | api_enums::StatementDescriptorLengthPolicy::Reject => Ok(Some(descriptor)),
+    }
+}
+
+/// Resolve the statement descriptors of the payment against the maximum lengths supported by the
+/// connector, according to the statement descriptor length policy of the merchant. The payment is
+/// rejected if a descriptor exceeds the maximum length and the policy is to reject such payments.
+pub fn resolve_statement_descriptors(
+    connector_name: &str,
+    policy: api_enums::StatementDescriptorLengthPolicy,
+    statement_descriptor: Option<String>,
+    statement_descriptor_suffix: Option<String>,
+) -> RouterResult<ResolvedStatementDescriptors> {
+    let Some(max_lengths) = get_statement_descriptor_max_lengths(connector_name) else {
+        return Ok(ResolvedStatementDescriptors {
+            statement_descriptor,
+            statement_descriptor_suffix,
+            warnings: Vec::new(),
+        });
+    };
+
+    let mut warnings = Vec::new();
+    let statement_descriptor = resolve_statement_descriptor(
+        "statement_descriptor_name",
+        statement_descriptor,
+        max_lengths.statement_descriptor,
+        connector_name,
+        policy,
+        &mut warnings,
+    )?;
+    let statement_descriptor_suffix = resolve_statement_descriptor(
+        "statement_descriptor_suffix",
+        statement_descriptor_suffix,
+        max_lengths.statement_descriptor_suffix,
+        connector_name,
+        policy,
+        &mut warnings,
+    )?;
+
+    Ok(ResolvedStatementDescriptors {
+        statement_descriptor,
+        statement_descriptor_suffix,
+        warnings,
+    })
+}
+
+/// Get the warnings about the statement descriptors of the payment which were truncated when they
+/// were sent to the connector, according to the statement descriptor length policy of the merchant.
+/// No descriptor is truncated if the policy is to reject the payments with longer descriptors.
+pub fn get_statement_descriptor_warnings(
+    connector_name: &str,
+    policy: api_enums::StatementDescriptorLengthPolicy,
+    statement_descriptor: Option<String>,
+    statement_descriptor_suffix: Option<String>,
+) -> Vec<String> {
+    resolve_statement_descriptors(
+        connector_name,
+        policy,
+        statement_descriptor,
+        statement_descriptor_suffix,
+    )
+    .map(|resolved_statement_descriptors| resolved_statement_descriptors.warnings)
+    .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_descriptors_within_the_limit_are_not_altered() {
+        let resolved = resolve_statement_descriptors(
+            "stripe",
+            api_enums::StatementDescriptorLengthPolicy::Truncate,
+            Some("HYPERSWITCH".to_string()),
+            Some("ORDER 1234".to_string()),
+        )
+        .unwrap();
+
+        assert_eq!(
+            resolved,
+            ResolvedStatementDescriptors {
+                statement_descriptor: Some("HYPERSWITCH".to_string()),
+                statement_descriptor_suffix: Some("ORDER 1234".to_string()),
+                warnings: Vec::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_long_descriptors_are_truncated_with_a_warning() {
+        let resolved = resolve_statement_descriptors(
+            "stripe",
+            api_enums::StatementDescriptorLengthPolicy::Truncate,
+            Some("HYPERSWITCH ONLINE STORE".to_string()),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            resolved.statement_descriptor,
+            Some("HYPERSWITCH ONLINE STO".to_string())
+        );
+        assert_eq!(resolved.warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_long_descriptors_are_rejected() {
+        assert!(resolve_statement_descriptors(
+            "adyen",
+            api_enums::StatementDescriptorLengthPolicy::Reject,
+            Some("HYPERSWITCH ONLINE STORE".to_string()),
+            None,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_warnings_follow_the_policy_of_the_merchant() {
+        let warnings = get_statement_descriptor_warnings(
+            "stripe",
+            api_enums::StatementDescriptorLengthPolicy::Pad,
+            Some("HYPERSWITCH ONLINE STORE".to_string()),
+            None,
+        );
+        assert_eq!(warnings.len(), 1);
+
+        // The payments with longer descriptors are rejected, so that no descriptor is truncated
+        let warnings = get_statement_descriptor_warnings(
+            "stripe",
+            api_enums::StatementDescriptorLengthPolicy::Reject,
+            Some("HYPERSWITCH ONLINE STORE".to_string()),
+            None,
+        );
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_short_descriptors_are_padded() {
+        let resolved = resolve_statement_descriptors(
+            "adyen",
+            api_enums::StatementDescriptorLengthPolicy::Pad,
+            Some("HYPERSWITCH".to_string()),
+            Some("ORDER 1234".to_string()),
+        )
+        .unwrap();
+
+        assert_eq!(
+            resolved.statement_descriptor,
+            Some("HYPERSWITCH           ".to_string())
+        );
+        // Adyen does not accept a descriptor suffix, so it is not altered
+        assert_eq!(
+            resolved.statement_descriptor_suffix,
+            Some("ORDER 1234".to_string())
+        );
+        assert!(resolved.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_descriptors_of_connectors_without_limits_are_not_altered() {
+        let resolved = resolve_statement_descriptors(
+            "checkout",
+            api_enums::StatementDescriptorLengthPolicy::Pad,
+            Some("HYPERSWITCH ONLINE STORE".to_string()),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            resolved.statement_descriptor,
+            Some("HYPERSWITCH ONLINE STORE".to_string())
+        );
+        assert!(resolved.warnings.is_empty());
+    }
+}
@@ -0,0 +1,501 @@
+//! Timeline of payments.
+//!
+//! The timeline is a read model assembled from the payment, every attempt of the payment, the
+//! captures of the attempts which were captured in multiple parts, the refunds and the disputes of
+//! the payment, the webhooks received from the connectors for the payment, its refunds and its
+//! disputes, and the webhooks sent to the merchant for them. The outcome of a record, such as an
+//! attempt being authorized or a refund succeeding, is placed at the time at which the record was
+//! last modified, since the records do not keep the history of their statuses.
+
+use api_models::payments::{
+    PaymentTimelineEvent, PaymentTimelineEventSource, PaymentTimelineEventType,
+    PaymentTimelineResponse,
+};
+use common_utils::{id_type, types::MinorUnit};
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+};
+
+/// The step of the lifecycle of the payment recorded by the event, by which the events which
+/// occurred at the same time are ordered
+fn get_lifecycle_step(event_type: PaymentTimelineEventType) -> u8 {
+    match event_type {
+        PaymentTimelineEventType::Created => 0,
+        PaymentTimelineEventType::AttemptCreated => 1,
+        // A webhook is received from the connector before the records which it updates
+        PaymentTimelineEventType::WebhookReceived => 2,
+        PaymentTimelineEventType::Authorized | PaymentTimelineEventType::Declined => 3,
+        PaymentTimelineEventType::Captured | PaymentTimelineEventType::Voided => 4,
+        PaymentTimelineEventType::RefundInitiated => 5,
+        PaymentTimelineEventType::Refunded | PaymentTimelineEventType::RefundFailed => 6,
+        PaymentTimelineEventType::Disputed => 7,
+        PaymentTimelineEventType::DisputeWon
+        | PaymentTimelineEventType::DisputeLost
+        | PaymentTimelineEventType::DisputeClosed => 8,
+        // A webhook is sent after the event which it notifies the merchant of
+        PaymentTimelineEventType::WebhookSent => 9,
+    }
+}
+
+/// Order the events chronologically. The events which occurred at the same time are ordered by the
+/// step of the lifecycle of the payment which they record, and then by the identifier of their
+/// records, so that the order is the same every time the timeline is retrieved.
+fn sort_timeline_events(events: &mut [PaymentTimelineEvent]) {
+    events.sort_by(|first, second| {
+        first
+            .timestamp
+            .cmp(&second.timestamp)
+            .then_with(|| {
+                get_lifecycle_step(first.event_type).cmp(&get_lifecycle_step(second.event_type))
+            })
+            .then_with(|| first.source_id.cmp(&second.source_id))
+    });
+}
+
+fn new_event(
+    event_type: PaymentTimelineEventType,
+    source: PaymentTimelineEventSource,
+    source_id: String,
+    timestamp: PrimitiveDateTime,
+) -> PaymentTimelineEvent {
+    PaymentTimelineEvent {
+        event_type,
+        source,
+        source_id,
+        timestamp,
+        connector: None,
+        amount: None,
+        error_code: None,
+        error_message: None,
+    }
+}
+
+/// The events of the attempt. The outcome of an attempt which was captured in multiple parts is
+/// recorded through its captures.
+fn get_attempt_events(payment_attempt: &storage::PaymentAttempt) -> Vec<PaymentTimelineEvent> {
+    let source = PaymentTimelineEventSource::PaymentAttempt;
+    let mut created = new_event(
+        PaymentTimelineEventType::AttemptCreated,
+        source,
+        payment_attempt.attempt_id.clone(),
+        payment_attempt.created_at,
+    );
+    created.connector = payment_attempt.connector.clone();
+    created.amount = Some(payment_attempt.get_total_amount());
+
+    let is_captured_in_parts = payment_attempt.multiple_capture_count.is_some();
+    let outcome = match payment_attempt.status {
+        enums::AttemptStatus::Authorized | enums::AttemptStatus::PartiallyAuthorized => Some((
+            PaymentTimelineEventType::Authorized,
+            payment_attempt.get_total_amount(),
+        )),
+        enums::AttemptStatus::Charged
+        | enums::AttemptStatus::PartialCharged
+        | enums::AttemptStatus::PartialChargedAndChargeable
+            if !is_captured_in_parts =>
+        {
+            Some((
+                PaymentTimelineEventType::Captured,
+                payment_attempt
+                    .amount_to_capture
+                    .unwrap_or(payment_attempt.get_total_amount()),
+            ))
+        }
+        enums::AttemptStatus::Failure
+        | enums::AttemptStatus::AuthorizationFailed
+        | enums::AttemptStatus::AuthenticationFailed
+        | enums::AttemptStatus::RouterDeclined
+        | enums::AttemptStatus::CaptureFailed => Some((
+            PaymentTimelineEventType::Declined,
+            payment_attempt.get_total_amount(),
+        )),
+        enums::AttemptStatus::Voided => Some((
+            PaymentTimelineEventType::Voided,
+            payment_attempt.get_total_amount(),
+        )),
+        _ => None,
+    };
+
+    let outcome = outcome.map(|(event_type, amount)| {
+        let mut event = new_event(
+            event_type,
+            source,
+            payment_attempt.attempt_id.clone(),
+            payment_attempt.modified_at,
+        );
+        event.connector = payment_attempt.connector.clone();
+        event.amount = Some(amount);
+        if event_type == PaymentTimelineEventType::Declined {
+            event.error_code = payment_attempt.error_code.clone();
+            event.error_message = payment_attempt.error_message.clone();
+        }
+        event
+    });
+
+    std::iter::once(created).chain(outcome).collect()
+}
+
+fn get_capture_event(capture: &storage::Capture) -> Option<PaymentTimelineEvent> {
+    let event_type = match capture.status {
+        // The reversal of the capture is in the timeline as the refund which reversed it
+        enums::CaptureStatus::Charged | enums::CaptureStatus::Reversed => {
+            PaymentTimelineEventType::Captured
+        }
+        enums::CaptureStatus::Failed => PaymentTimelineEventType::Declined,
+        enums::CaptureStatus::Voided => PaymentTimelineEventType::Voided,
+        enums::CaptureStatus::Started | enums::CaptureStatus::Pending => return None,
+    };
+
+    let mut event = new_event(
+        event_type,
+        PaymentTimelineEventSource::Capture,
+        capture.capture_id.clone(),
+        capture.modified_at,
+    );
+    event.connector = Some(capture.connector.clone());
+    event.amount = Some(capture.amount);
+    if event_type == PaymentTimelineEventType::Declined {
+        event.error_code = capture.error_code.clone();
+        event.error_message = capture.error_message.clone();
+    }
+    Some(event)
+}
+
+fn get_refund_events(refund: &storage::Refund) -> Vec<PaymentTimelineEvent> {
+    let source = PaymentTimelineEventSource::Refund;
+    let mut initiated = new_event(
+        PaymentTimelineEventType::RefundInitiated,
+        source,
+        refund.refund_id.clone(),
+        refund.created_at,
+    );
+    initiated.connector = Some(refund.connector.clone());
+    initiated.amount = Some(refund.refund_amount);
+
+    let outcome = match refund.refund_status {
+        enums::RefundStatus::Success => Some(PaymentTimelineEventType::Refunded),
+        enums::RefundStatus::Failure | enums::RefundStatus::TransactionFailure => {
+            Some(PaymentTimelineEventType::RefundFailed)
+        }
+        enums::RefundStatus::ManualReview | enums::RefundStatus::Pending => None,
+    }
+    .map(|event_type| {
+        let mut event = new_event(
+            event_type,
+            source,
+            refund.refund_id.clone(),
+            refund.modified_at,
+        );
+        event.connector = Some(refund.connector.clone());
+        event.amount = Some(refund.refund_amount);
+        if event_type == PaymentTimelineEventType::RefundFailed {
+            event.error_code = refund.refund_error_code.clone();
+            event.error_message = refund.refund_error_message.clone();
+        }
+        event
+    });
+
+    std::iter::once(initiated).chain(outcome).collect()
+}
+
+fn get_dispute_events(dispute: &storage::Dispute) -> Vec<PaymentTimelineEvent> {
+    let source = PaymentTimelineEventSource::Dispute;
+    let mut disputed = new_event(
+        PaymentTimelineEventType::Disputed,
+        source,
+        dispute.dispute_id.clone(),
+        dispute.created_at,
+    );
+    disputed.connector = Some(dispute.connector.clone());
+    disputed.amount = Some(MinorUnit::new(dispute.dispute_amount));
+
+    let outcome = match dispute.dispute_status {
+        enums::DisputeStatus::DisputeWon => Some(PaymentTimelineEventType::DisputeWon),
+        enums::DisputeStatus::DisputeLost | enums::DisputeStatus::DisputeAccepted => {
+            Some(PaymentTimelineEventType::DisputeLost)
+        }
+        enums::DisputeStatus::DisputeCancelled | enums::DisputeStatus::DisputeExpired => {
+            Some(PaymentTimelineEventType::DisputeClosed)
+        }
+        enums::DisputeStatus::DisputeOpened | enums::DisputeStatus::DisputeChallenged => None,
+    }
+    .map(|event_type| {
+        let mut event = new_event(
+            event_type,
+            source,
+            dispute.dispute_id.clone(),
+            dispute.modified_at,
+        );
+        event.connector = Some(dispute.connector.clone());
+        event
+    });
+
+    std::iter::once(disputed).chain(outcome).collect()
+}
+
+fn get_incoming_webhook_event(event: &storage::IncomingWebhookEvent) -> PaymentTimelineEvent {
+    let mut timeline_event = new_event(
+        PaymentTimelineEventType::WebhookReceived,
+        PaymentTimelineEventSource::IncomingWebhook,
+        event.event_id.clone(),
+        event.received_at,
+    );
+    timeline_event.connector = Some(event.connector.clone());
+    timeline_event
+}
+
+fn get_webhook_event(event: &domain::Event) -> PaymentTimelineEvent {
+    new_event(
+        PaymentTimelineEventType::WebhookSent,
+        PaymentTimelineEventSource::Webhook,
+        event.event_id.clone(),
+        event.created_at,
+    )
+}
+
+/// The webhooks sent to the merchant for the objects, which are the payment, its refunds and its
+/// disputes
+async fn get_webhook_events(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    primary_object_ids: impl Iterator<Item = &str>,
+) -> RouterResult<Vec<PaymentTimelineEvent>> {
+    let mut events = Vec::new();
+    for primary_object_id in primary_object_ids {
+        let webhooks = state
+            .store
+            .list_initial_events_by_merchant_id_primary_object_id(
+                &state.into(),
+                merchant_account.get_id(),
+                primary_object_id,
+                key_store,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the webhooks of the payment")?;
+        events.extend(webhooks.iter().map(get_webhook_event));
+    }
+
+    Ok(events)
+}
+
+/// Retrieve the timeline of the payment, which is every event of the payment across all its
+/// attempts in chronological order
+#[instrument(skip_all)]
+pub async fn get_payment_timeline(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    payment_id: id_type::PaymentId,
+) -> RouterResponse<PaymentTimelineResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_account.get_id();
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &payment_id,
+            merchant_id,
+            &key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payment_intent)?;
+
+    let mut events = vec![new_event(
+        PaymentTimelineEventType::Created,
+        PaymentTimelineEventSource::Payment,
+        payment_id.get_string_repr().to_owned(),
+        payment_intent.created_at,
+    )];
+
+    let payment_attempts = db
+        .find_attempts_by_merchant_id_payment_id(merchant_id, &payment_id, storage_scheme)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the attempts of the payment")?;
+
+    for payment_attempt in &payment_attempts {
+        events.extend(get_attempt_events(payment_attempt));
+
+        if payment_attempt.multiple_capture_count.is_some() {
+            let captures = db
+                .find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+                    merchant_id,
+                    &payment_id,
+                    &payment_attempt.attempt_id,
+                    storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the captures of the payment")?;
+            events.extend(captures.iter().filter_map(get_capture_event));
+        }
+    }
+
+    let refunds = db
+        .find_refund_by_payment_id_merchant_id(&payment_id, merchant_id, storage_scheme)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the refunds of the payment")?;
+    events.extend(refunds.iter().flat_map(get_refund_events));
+
+    let disputes = db
+        .find_disputes_by_merchant_id_payment_id(merchant_id, &payment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the disputes of the payment")?;
+    events.extend(disputes.iter().flat_map(get_dispute_events));
+
+    let incoming_webhooks = db
+        .list_incoming_webhook_events_by_merchant_id_payment_id(merchant_id, &payment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the webhooks received for the payment")?;
+    events.extend(incoming_webhooks.iter().map(get_incoming_webhook_event));
+
+    let primary_object_ids = std::iter::once(payment_id.get_string_repr())
+        .chain(refunds.iter().map(|refund| refund.refund_id.as_str()))
+        .chain(disputes.iter().map(|dispute| dispute.dispute_id.as_str()));
+    events.extend(
+        get_webhook_events(&state, &merchant_account, &key_store, primary_object_ids).await?,
+    );
+
+    sort_timeline_events(&mut events);
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentTimelineResponse { payment_id, events },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_events_at_the_same_time_are_ordered_by_lifecycle_step() {
+        let timestamp = common_utils::date_time::now();
+        let earlier = timestamp.saturating_sub(time::Duration::seconds(1));
+        let mut events = vec![
+            new_event(
+                PaymentTimelineEventType::WebhookSent,
+                PaymentTimelineEventSource::Webhook,
+                "evt_1".to_string(),
+                timestamp,
+            ),
+            new_event(
+                PaymentTimelineEventType::Captured,
+                PaymentTimelineEventSource::PaymentAttempt,
+                "pay_1_1".to_string(),
+                timestamp,
+            ),
+            new_event(
+                PaymentTimelineEventType::AttemptCreated,
+                PaymentTimelineEventSource::PaymentAttempt,
+                "pay_1_1".to_string(),
+                timestamp,
+            ),
+            new_event(
+                PaymentTimelineEventType::Created,
+                PaymentTimelineEventSource::Payment,
+                "pay_1".to_string(),
+                earlier,
+            ),
+        ];
+
+        sort_timeline_events(&mut events);
+
+        assert_eq!(
+            events
+                .iter()
+                .map(|event| event.event_type)
+                .collect::<Vec<_>>(),
+            vec![
+                PaymentTimelineEventType::Created,
+                PaymentTimelineEventType::AttemptCreated,
+                PaymentTimelineEventType::Captured,
+                PaymentTimelineEventType::WebhookSent,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_received_webhook_is_ordered_before_the_outcome_it_updates() {
+        let timestamp = common_utils::date_time::now();
+        let mut events = vec![
+            new_event(
+                PaymentTimelineEventType::WebhookSent,
+                PaymentTimelineEventSource::Webhook,
+                "evt_1".to_string(),
+                timestamp,
+            ),
+            new_event(
+                PaymentTimelineEventType::Authorized,
+                PaymentTimelineEventSource::PaymentAttempt,
+                "pay_1_1".to_string(),
+                timestamp,
+            ),
+            new_event(
+                PaymentTimelineEventType::WebhookReceived,
+                PaymentTimelineEventSource::IncomingWebhook,
+                "whin_1".to_string(),
+                timestamp,
+            ),
+        ];
+
+        sort_timeline_events(&mut events);
+
+        assert_eq!(
+            events
+                .iter()
+                .map(|event| event.event_type)
+                .collect::<Vec<_>>(),
+            vec![
+                PaymentTimelineEventType::WebhookReceived,
+                PaymentTimelineEventType::Authorized,
+                PaymentTimelineEventType::WebhookSent,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_events_of_the_same_step_are_ordered_by_source_id() {
+        let timestamp = common_utils::date_time::now();
+        let mut events = vec![
+            new_event(
+                PaymentTimelineEventType::RefundInitiated,
+                PaymentTimelineEventSource::Refund,
+                "ref_2".to_string(),
+                timestamp,
+            ),
+            new_event(
+                PaymentTimelineEventType::RefundInitiated,
+                PaymentTimelineEventSource::Refund,
+                "ref_1".to_string(),
+                timestamp,
+            ),
+        ];
+
+        sort_timeline_events(&mut events);
+
+        assert_eq!(events.first().unwrap().source_id, "ref_1");
+    }
+}
@@ -0,0 +1,99 @@
+//! Expiry of the short-lived tokens which resolve to a payment.
+//!
+//! The client secret and the payment link of a payment, and the resume token of a payment which
+//! requires the action of the customer, resolve until the session of the payment expires. The
+//! session expiry is requested for the payment, or defaults to the session expiry of the business
+//! profile, and is capped to `token_expiry.max_session_expiry_in_secs`. The receipt token of a
+//! payment resolves for `token_expiry.receipt_token_expiry_in_secs` after the payment was created.
+//!
+//! A customer who is completing the action required by the payment, such as the authentication of
+//! the payment, when its session expires may still complete the payment within the grace period
+//! of `token_expiry.grace_period_in_secs`, so that the payment is not left halfway through.
+
+use common_utils::date_time;
+use time::PrimitiveDateTime;
+
+use crate::{
+    configs::settings::TokenExpiry,
+    consts,
+    core::errors::{self, RouterResult},
+    types::storage::enums,
+};
+
+/// The status of a token against its expiry
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenExpiryStatus {
+    Active,
+    /// The token expired while the customer was completing the action required by the payment,
+    /// and still resolves for the customer to complete the payment
+    InGracePeriod,
+    Expired,
+}
+
+impl TokenExpiryStatus {
+    /// Whether the token still resolves to the payment
+    pub fn is_resolvable(self) -> bool {
+        matches!(self, Self::Active | Self::InGracePeriod)
+    }
+}
+
+/// Whether the customer was in the middle of completing the payment, having been asked to act on
+/// the payment
+fn is_customer_mid_flow(payment_status: enums::IntentStatus) -> bool {
+    payment_status == enums::IntentStatus::RequiresCustomerAction
+}
+
+/// The status against its expiry of a token of the payment, allowing the grace period for a
+/// customer in the middle of completing the payment
+pub fn get_token_expiry_status(
+    config: &TokenExpiry,
+    expires_at: PrimitiveDateTime,
+    payment_status: enums::IntentStatus,
+) -> TokenExpiryStatus {
+    let now = date_time::now();
+    let grace_period_ends_at = expires_at.saturating_add(time::Duration::seconds(i64::from(
+        config.grace_period_in_secs,
+    )));
+
+    if now <= expires_at {
+        TokenExpiryStatus::Active
+    } else if is_customer_mid_flow(payment_status) && now <= grace_period_ends_at {
+        TokenExpiryStatus::InGracePeriod
+    } else {
+        TokenExpiryStatus::Expired
+    }
+}
+
+/// The session expiry in seconds of a new payment, which is the expiry requested for the payment,
+/// or the default expiry of the business profile capped to the maximum session expiry
+pub fn get_session_expiry_in_secs(
+    config: &TokenExpiry,
+    requested_session_expiry: Option<u32>,
+    profile_session_expiry: Option<i64>,
+) -> RouterResult<i64> {
+    match requested_session_expiry {
+        Some(session_expiry) if session_expiry > config.max_session_expiry_in_secs => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "session_expiry should not be more than {} seconds",
+                    config.max_session_expiry_in_secs
+                ),
+            }
+            .into())
+        }
+        Some(session_expiry) => Ok(i64::from(session_expiry)),
+        None => Ok(profile_session_expiry
+            .unwrap_or(consts::DEFAULT_SESSION_EXPIRY)
+            .min(i64::from(config.max_session_expiry_in_secs))),
+    }
+}
+
+/// The time at which the receipt token of the payment expires
+pub fn get_receipt_token_expiry(
+    config: &TokenExpiry,
+    payment_created_at: PrimitiveDateTime,
+) -> PrimitiveDateTime {
+    payment_created_at.saturating_add(time::Duration::seconds(i64::from(
+        config.receipt_token_expiry_in_secs,
+    )))
+}
@@ -23,7 +23,7 @@ use crate::{
     connector::{Helcim, Nexinets},
     core::{
         errors::{self, RouterResponse, RouterResult},
-        payments::{self, helpers},
+        payments::{self, helpers, statement_descriptor},
         utils as core_utils,
     },
     headers::X_PAYMENT_CONFIRM_SOURCE,
@@ -60,6 +60,16 @@ where
         Err(errors::ApiErrorResponse::MerchantConnectorAccountDisabled)
     })?;
 
+    merchant_connector_account
+        .get_environment()
+        .map(|environment| {
+            helpers::validate_connector_environment(
+                environment,
+                merchant_connector_account.is_test_mode_on(),
+            )
+        })
+        .transpose()?;
+
     let test_mode = merchant_connector_account.is_test_mode_on();
 
     let auth_type: types::ConnectorAuthType = merchant_connector_account
@@ -74,6 +84,8 @@ where
         payment_data: payment_data.clone(),
         state,
         customer_data: customer,
+        statement_descriptor_length_policy: merchant_account
+            .get_statement_descriptor_length_policy(),
     };
 
     let router_data = types::RouterData {
@@ -99,6 +111,7 @@ where
             .unwrap_or_default(),
         connector_meta_data: None,
         connector_wallets_details: None,
+        connector_request_timeout_secs: None,
         request: T::try_from(additional_data)?,
         response: Err(hyperswitch_domain_models::router_data::ErrorResponse::default()),
         amount_captured: None,
@@ -125,6 +138,7 @@ where
         connector_api_version: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_network_retry_count: None,
         apple_pay_flow: None,
         frm_metadata: None,
         refund_id: None,
@@ -188,6 +202,16 @@ where
         Err(errors::ApiErrorResponse::MerchantConnectorAccountDisabled)
     })?;
 
+    merchant_connector_account
+        .get_environment()
+        .map(|environment| {
+            helpers::validate_connector_environment(
+                environment,
+                merchant_connector_account.is_test_mode_on(),
+            )
+        })
+        .transpose()?;
+
     let test_mode = merchant_connector_account.is_test_mode_on();
 
     let auth_type: types::ConnectorAuthType = merchant_connector_account
@@ -229,6 +253,8 @@ where
         payment_data: payment_data.clone(),
         state,
         customer_data: customer,
+        statement_descriptor_length_policy: merchant_account
+            .get_statement_descriptor_length_policy(),
     };
 
     let customer_id = customer.to_owned().map(|customer| customer.customer_id);
@@ -304,6 +330,7 @@ where
             .unwrap_or_default(),
         connector_meta_data: merchant_connector_account.get_metadata(),
         connector_wallets_details: merchant_connector_account.get_connector_wallets_details(),
+        connector_request_timeout_secs: merchant_connector_account.get_connector_request_timeout(),
         request: T::try_from(additional_data)?,
         response,
         amount_captured: payment_data
@@ -335,6 +362,7 @@ where
         connector_api_version,
         connector_http_status_code: None,
         external_latency: None,
+        connector_network_retry_count: None,
         apple_pay_flow,
         frm_metadata: None,
         refund_id: None,
@@ -1076,6 +1104,7 @@ where
                         start_date: i.start_date,
                         end_date: i.end_date,
                         metadata: i.metadata,
+                        constraints: i.constraints.map(Into::into),
                     }))
                 }
                 hyperswitch_domain_models::mandates::MandateDataType::SingleUse(i) => {
@@ -1085,6 +1114,7 @@ where
                         start_date: i.start_date,
                         end_date: i.end_date,
                         metadata: i.metadata,
+                        constraints: i.constraints.map(Into::into),
                     })
                 }
                 hyperswitch_domain_models::mandates::MandateDataType::MultiUse(None) => {
@@ -1108,6 +1138,15 @@ where
                             .or_else(|| tax.default.map(|a| a.order_tax_amount))
                     })
             });
+
+        // The experience is derived from the next action when it was not specified for the payment,
+        // so that the client knows what has to be rendered for the next action
+        let payment_experience = payment_attempt.payment_experience.or_else(|| {
+            next_action_response
+                .as_ref()
+                .and_then(|next_action| next_action.get_payment_experience())
+        });
+
         let connector_mandate_id = payment_data.get_mandate_id().and_then(|mandate| {
             mandate
                 .mandate_reference_id
@@ -1120,6 +1159,22 @@ where
                 })
         });
 
+        let statement_descriptor_warnings = payment_attempt
+            .connector
+            .as_deref()
+            .map(|connector_name| {
+                statement_descriptor::get_statement_descriptor_warnings(
+                    connector_name,
+                    payment_data.get_statement_descriptor_length_policy(),
+                    payment_intent.statement_descriptor_name.clone(),
+                    payment_intent.statement_descriptor_suffix.clone(),
+                )
+            })
+            .filter(|warnings| !warnings.is_empty());
+
+        let resume_token =
+            payments::checkout_resume::get_resume_token(&payment_intent, &payment_attempt);
+
         let payments_response = api::PaymentsResponse {
             payment_id: payment_intent.payment_id,
             merchant_id: payment_intent.merchant_id,
@@ -1144,7 +1199,7 @@ where
             setup_future_usage: payment_intent.setup_future_usage,
             off_session: payment_intent.off_session,
             capture_on: None,
-            capture_method: payment_attempt.capture_method,
+            capture_method: Some(payment_attempt.capture_method.unwrap_or_default()),
             payment_method: payment_attempt.payment_method,
             payment_method_data: payment_method_data_response,
             payment_token: payment_attempt.payment_token,
@@ -1164,7 +1219,9 @@ where
             authentication_type: payment_attempt.authentication_type,
             statement_descriptor_name: payment_intent.statement_descriptor_name,
             statement_descriptor_suffix: payment_intent.statement_descriptor_suffix,
+            statement_descriptor_warnings,
             next_action: next_action_response,
+            resume_token,
             cancellation_reason: payment_attempt.cancellation_reason,
             error_code: payment_attempt.error_code,
             error_message: payment_attempt
@@ -1172,7 +1229,7 @@ where
                 .or(payment_attempt.error_message),
             unified_code: payment_attempt.unified_code,
             unified_message: payment_attempt.unified_message,
-            payment_experience: payment_attempt.payment_experience,
+            payment_experience,
             payment_method_type: payment_attempt.payment_method_type,
             connector_label,
             business_country: payment_intent.business_country,
@@ -1190,6 +1247,21 @@ where
             ),
             connector_transaction_id: payment_attempt.connector_transaction_id,
             frm_message,
+            fraud_score: payment_attempt.fraud_score,
+            fraud_decision: payment_attempt.fraud_decision,
+            approved_amount: payment_attempt.approved_amount,
+            expected_settlement_date: payment_attempt.expected_settlement_date,
+            retry_advice: payment_attempt.retry_advice,
+            connector_network_retry_count: payment_data.get_connector_network_retry_count(),
+            stepped_up_from_attempt_id: payment_attempt.stepped_up_from_attempt_id,
+            tags: payment_attempt.tags,
+            amount_breakdown: payment_intent
+                .amount_breakdown
+                .map(ForeignFrom::foreign_from),
+            parent_payment_id: payment_intent.parent_payment_id,
+            fulfillment_status: payment_intent.fulfillment_status,
+            shipped_at: payment_intent.shipped_at,
+            delivered_at: payment_intent.delivered_at,
             metadata: payment_intent.metadata,
             connector_metadata: payment_intent.connector_metadata,
             feature_metadata: payment_intent.feature_metadata,
@@ -1441,7 +1513,9 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             return_url: None,
             statement_descriptor_name: None,
             statement_descriptor_suffix: None,
+            statement_descriptor_warnings: None,
             next_action: None,
+            resume_token: None,
             cancellation_reason: None,
             error_code: None,
             error_message: None,
@@ -1453,6 +1527,19 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             ephemeral_key: None,
             manual_retry_allowed: None,
             frm_message: None,
+            fraud_score: pa.fraud_score,
+            fraud_decision: pa.fraud_decision,
+            approved_amount: pa.approved_amount,
+            expected_settlement_date: pa.expected_settlement_date,
+            retry_advice: pa.retry_advice,
+            connector_network_retry_count: None,
+            stepped_up_from_attempt_id: pa.stepped_up_from_attempt_id,
+            tags: pa.tags,
+            amount_breakdown: pi.amount_breakdown.map(ForeignFrom::foreign_from),
+            parent_payment_id: pi.parent_payment_id,
+            fulfillment_status: pi.fulfillment_status,
+            shipped_at: pi.shipped_at,
+            delivered_at: pi.delivered_at,
             connector_metadata: None,
             feature_metadata: None,
             reference_id: None,
@@ -1604,6 +1691,7 @@ where
     payment_data: PaymentData<F>,
     state: &'a SessionState,
     customer_data: &'a Option<domain::Customer>,
+    statement_descriptor_length_policy: enums::StatementDescriptorLengthPolicy,
 }
 
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
@@ -1706,6 +1794,30 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
                     .map(|customer| customer.clone().into_inner())
             });
 
+        let customer_tax_ids = additional_data
+            .customer_data
+            .as_ref()
+            .map(domain::Customer::get_tax_ids)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse customer tax identifiers")?
+            .flatten();
+
+        // The tax identifier of the customer which applies to the payment is the one for the
+        // country of the billing address, or of the shipping address in its absence
+        let customer_tax_id = payment_data
+            .address
+            .get_payment_method_billing()
+            .and_then(|billing| billing.address.as_ref()?.country)
+            .or_else(|| {
+                payment_data
+                    .address
+                    .get_shipping()
+                    .and_then(|shipping| shipping.address.as_ref()?.country)
+            })
+            .zip(customer_tax_ids)
+            .and_then(|(country, tax_ids)| tax_ids.get_for_country(country).cloned());
+
         let customer_id = additional_data
             .customer_data
             .as_ref()
@@ -1726,6 +1838,19 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             .merchant_order_reference_id
             .clone();
 
+        let statement_descriptors = statement_descriptor::resolve_statement_descriptors(
+            connector_name,
+            additional_data.statement_descriptor_length_policy,
+            payment_data
+                .payment_intent
+                .statement_descriptor_name
+                .clone(),
+            payment_data
+                .payment_intent
+                .statement_descriptor_suffix
+                .clone(),
+        )?;
+
         Ok(Self {
             payment_method_data: (payment_method_data.get_required_value("payment_method_data")?),
             setup_future_usage: payment_data.payment_intent.setup_future_usage,
@@ -1733,8 +1858,8 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             off_session: payment_data.mandate_id.as_ref().map(|_| true),
             setup_mandate_details: payment_data.setup_mandate.clone(),
             confirm: payment_data.payment_attempt.confirm,
-            statement_descriptor_suffix: payment_data.payment_intent.statement_descriptor_suffix,
-            statement_descriptor: payment_data.payment_intent.statement_descriptor_name,
+            statement_descriptor_suffix: statement_descriptors.statement_descriptor_suffix,
+            statement_descriptor: statement_descriptors.statement_descriptor,
             capture_method: payment_data.payment_attempt.capture_method,
             amount: amount.get_amount_as_i64(),
             minor_amount: amount,
@@ -1770,6 +1895,8 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             customer_acceptance: payment_data.customer_acceptance,
             charges,
             merchant_order_reference_id,
+            amount_breakdown: payment_data.payment_intent.amount_breakdown,
+            customer_tax_id,
             integrity_object: None,
         })
     }
@@ -1933,9 +2060,11 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCaptureD
             api::GetToken::Connector,
             payment_data.payment_attempt.merchant_connector_id.clone(),
         )?;
+        // Only the approved amount can be captured when the payment was partially authorized
         let amount_to_capture = payment_data
             .payment_attempt
             .amount_to_capture
+            .or(payment_data.payment_attempt.approved_amount)
             .map_or(payment_data.amount.into(), |capture_amount| capture_amount);
         let browser_info: Option<types::BrowserInformation> = payment_data
             .payment_attempt
@@ -2005,7 +2134,11 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCancelDa
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "browser_info",
             })?;
-        let amount = MinorUnit::from(payment_data.amount);
+        // Reversing a partially authorized payment must void only the approved amount
+        let amount = payment_data
+            .payment_attempt
+            .approved_amount
+            .unwrap_or(MinorUnit::from(payment_data.amount));
         Ok(Self {
             amount: Some(amount.get_amount_as_i64()), // This should be removed once we start moving to connector module
             minor_amount: Some(amount),
@@ -2169,7 +2302,13 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::SetupMandateRequ
             payment_method_data: (payment_data
                 .payment_method_data
                 .get_required_value("payment_method_data")?),
-            statement_descriptor_suffix: payment_data.payment_intent.statement_descriptor_suffix,
+            statement_descriptor_suffix: statement_descriptor::resolve_statement_descriptors(
+                connector_name,
+                additional_data.statement_descriptor_length_policy,
+                None,
+                payment_data.payment_intent.statement_descriptor_suffix,
+            )?
+            .statement_descriptor_suffix,
             setup_future_usage: payment_data.payment_intent.setup_future_usage,
             off_session: payment_data.mandate_id.as_ref().map(|_| true),
             mandate_id: payment_data.mandate_id.clone(),
@@ -2285,7 +2424,13 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::CompleteAuthoriz
             off_session: payment_data.mandate_id.as_ref().map(|_| true),
             setup_mandate_details: payment_data.setup_mandate.clone(),
             confirm: payment_data.payment_attempt.confirm,
-            statement_descriptor_suffix: payment_data.payment_intent.statement_descriptor_suffix,
+            statement_descriptor_suffix: statement_descriptor::resolve_statement_descriptors(
+                connector_name,
+                additional_data.statement_descriptor_length_policy,
+                None,
+                payment_data.payment_intent.statement_descriptor_suffix,
+            )?
+            .statement_descriptor_suffix,
             capture_method: payment_data.payment_attempt.capture_method,
             amount: amount.get_amount_as_i64(), // need to change once we move to connector module
             minor_amount: amount,
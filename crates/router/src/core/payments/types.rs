@@ -89,9 +89,11 @@ impl MultipleCaptureData {
                 accumulator
                     + match capture.1.status {
                         storage_enums::CaptureStatus::Charged
+                        | storage_enums::CaptureStatus::Reversed
                         | storage_enums::CaptureStatus::Pending => capture.1.amount,
                         storage_enums::CaptureStatus::Started
-                        | storage_enums::CaptureStatus::Failed => common_types::MinorUnit::new(0),
+                        | storage_enums::CaptureStatus::Failed
+                        | storage_enums::CaptureStatus::Voided => common_types::MinorUnit::new(0),
                     }
             })
     }
@@ -101,10 +103,14 @@ impl MultipleCaptureData {
             .fold(common_types::MinorUnit::new(0), |accumulator, capture| {
                 accumulator
                     + match capture.1.status {
-                        storage_enums::CaptureStatus::Charged => capture.1.amount,
+                        // A reversed capture was charged, and its amount is returned through
+                        // the refund which reversed it
+                        storage_enums::CaptureStatus::Charged
+                        | storage_enums::CaptureStatus::Reversed => capture.1.amount,
                         storage_enums::CaptureStatus::Pending
                         | storage_enums::CaptureStatus::Started
-                        | storage_enums::CaptureStatus::Failed => common_types::MinorUnit::new(0),
+                        | storage_enums::CaptureStatus::Failed
+                        | storage_enums::CaptureStatus::Voided => common_types::MinorUnit::new(0),
                     }
             })
     }
@@ -119,6 +125,8 @@ impl MultipleCaptureData {
         hash_map.insert(storage_enums::CaptureStatus::Pending, 0);
         hash_map.insert(storage_enums::CaptureStatus::Started, 0);
         hash_map.insert(storage_enums::CaptureStatus::Failed, 0);
+        hash_map.insert(storage_enums::CaptureStatus::Voided, 0);
+        hash_map.insert(storage_enums::CaptureStatus::Reversed, 0);
         self.all_captures
             .iter()
             .fold(hash_map, |mut accumulator, capture| {
@@ -138,7 +146,13 @@ impl MultipleCaptureData {
             return storage_enums::AttemptStatus::Charged;
         }
         let status_count_map = self.get_status_count();
-        if status_count_map.get(&storage_enums::CaptureStatus::Charged) > Some(&0) {
+        let is_partially_charged = [
+            storage_enums::CaptureStatus::Charged,
+            storage_enums::CaptureStatus::Reversed,
+        ]
+        .iter()
+        .any(|status| status_count_map.get(status) > Some(&0));
+        if is_partially_charged {
             storage_enums::AttemptStatus::PartialChargedAndChargeable
         } else {
             storage_enums::AttemptStatus::CaptureInitiated
@@ -395,3 +409,75 @@ impl ForeignTryFrom<&storage::Authentication> for AuthenticationData {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn capture(capture_id: &str, status: storage_enums::CaptureStatus) -> storage::Capture {
+        storage::Capture {
+            capture_id: capture_id.to_string(),
+            payment_id: common_utils::id_type::PaymentId::default(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            status,
+            amount: common_types::MinorUnit::new(1000),
+            currency: Some(storage_enums::Currency::USD),
+            connector: "stripe".to_string(),
+            error_message: None,
+            error_code: None,
+            error_reason: None,
+            tax_amount: None,
+            created_at: common_utils::date_time::now(),
+            modified_at: common_utils::date_time::now(),
+            authorized_attempt_id: "attempt_1".to_string(),
+            connector_capture_id: None,
+            capture_sequence: 1,
+            connector_response_reference_id: None,
+        }
+    }
+
+    #[test]
+    fn test_reversed_capture_remains_charged() {
+        let mut multiple_capture_data = MultipleCaptureData::new_for_sync(
+            vec![
+                capture("capture_1", storage_enums::CaptureStatus::Charged),
+                capture("capture_2", storage_enums::CaptureStatus::Charged),
+            ],
+            None,
+        )
+        .unwrap();
+        multiple_capture_data
+            .update_capture(capture("capture_2", storage_enums::CaptureStatus::Reversed));
+
+        assert_eq!(
+            multiple_capture_data.get_total_charged_amount(),
+            common_types::MinorUnit::new(2000)
+        );
+        assert_eq!(
+            multiple_capture_data.get_total_blocked_amount(),
+            common_types::MinorUnit::new(2000)
+        );
+        assert_eq!(
+            multiple_capture_data.get_attempt_status(common_types::MinorUnit::new(3000)),
+            storage_enums::AttemptStatus::PartialChargedAndChargeable
+        );
+    }
+
+    #[test]
+    fn test_voided_capture_releases_its_amount() {
+        let multiple_capture_data = MultipleCaptureData::new_for_sync(
+            vec![
+                capture("capture_1", storage_enums::CaptureStatus::Charged),
+                capture("capture_2", storage_enums::CaptureStatus::Voided),
+            ],
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            multiple_capture_data.get_total_blocked_amount(),
+            common_types::MinorUnit::new(1000)
+        );
+    }
+}
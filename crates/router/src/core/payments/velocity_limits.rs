@@ -0,0 +1,546 @@
+//! Velocity limits on the payments made by a customer.
+//!
+//! The number of transactions made by a customer is counted per hour, and the amount paid by the
+//! customer is counted per day and per currency. The counters are stored in redis and are keyed by
+//! the window they count, so that they are shared by all the router instances and expire along with
+//! their window. A counter is incremented and compared with the limit atomically, and the increment
+//! is reverted when the payment is rejected, so that concurrent payments cannot exceed the limit
+//! together. The payments which fail at the connector are not counted either: the counters
+//! incremented for a payment are recorded along with the payment, and the increments are reverted
+//! from the recorded counters once the payment has failed, even if the window or the limits have
+//! changed since.
+
+use common_utils::{date_time, id_type, types::MinorUnit};
+use error_stack::{report, ResultExt};
+use redis_interface::{errors::RedisError, DelReply, RedisConnectionPool};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        customers::risk_profile,
+        errors::{self, RouterResponse, RouterResult},
+        payments::PaymentData,
+    },
+    routes::SessionState,
+    services,
+    types::{api::customers, domain, storage::enums},
+};
+
+const SECONDS_PER_HOUR: i64 = 60 * 60;
+const SECONDS_PER_DAY: i64 = 24 * SECONDS_PER_HOUR;
+
+/// Increment the counter, setting its expiry unless it already has one, and revert the increment
+/// if the counter exceeds the limit. Returns 1 if the increment was within the limit, 0 otherwise.
+const INCREMENT_COUNTER_SCRIPT: &str = r#"
+    local value = redis.call("INCRBY", KEYS[1], ARGV[1])
+    if redis.call("TTL", KEYS[1]) < 0 then
+        redis.call("EXPIRE", KEYS[1], ARGV[3])
+    end
+    if value > tonumber(ARGV[2]) then
+        redis.call("DECRBY", KEYS[1], ARGV[1])
+        return 0
+    end
+    return 1
+"#;
+
+/// Revert the increment of the counter, unless the counter has expired along with its window, so
+/// that a counter without an expiry is never created by the revert
+const REVERT_COUNTER_SCRIPT: &str = r#"
+    if redis.call("EXISTS", KEYS[1]) == 1 then
+        redis.call("DECRBY", KEYS[1], ARGV[1])
+        return 1
+    end
+    return 0
+"#;
+
+/// The velocity limits configured for a merchant, stored as a config with the key
+/// `velocity_limits_{merchant_id}`
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct VelocityLimits {
+    /// The maximum amount a customer can pay in a day, in each currency
+    pub max_amount_per_day: Option<MinorUnit>,
+    /// The maximum number of transactions a customer can make in an hour
+    pub max_transactions_per_hour: Option<i64>,
+    /// The maximum amount a customer of the high risk tier can pay in a day, in each currency,
+    /// which applies instead of `max_amount_per_day` to such customers
+    pub high_risk_max_amount_per_day: Option<MinorUnit>,
+    /// The maximum number of transactions a customer of the high risk tier can make in an hour,
+    /// which applies instead of `max_transactions_per_hour` to such customers
+    pub high_risk_max_transactions_per_hour: Option<i64>,
+}
+
+impl VelocityLimits {
+    fn has_high_risk_limits(&self) -> bool {
+        self.high_risk_max_amount_per_day.is_some()
+            || self.high_risk_max_transactions_per_hour.is_some()
+    }
+
+    /// The limits which apply to the customers of the high risk tier
+    fn for_high_risk_customer(self) -> Self {
+        Self {
+            max_amount_per_day: self
+                .high_risk_max_amount_per_day
+                .or(self.max_amount_per_day),
+            max_transactions_per_hour: self
+                .high_risk_max_transactions_per_hour
+                .or(self.max_transactions_per_hour),
+            ..self
+        }
+    }
+}
+
+/// Get the start of the window of the given length which contains the current time
+fn get_current_window_start(window_seconds: i64) -> i64 {
+    let now = date_time::now_unix_timestamp();
+    now - now.rem_euclid(window_seconds)
+}
+
+fn get_transaction_count_key(
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+) -> String {
+    format!(
+        "velocity_transaction_count_{}_{}_{}",
+        merchant_id.get_string_repr(),
+        customer_id.get_string_repr(),
+        get_current_window_start(SECONDS_PER_HOUR)
+    )
+}
+
+fn get_amount_key(
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+    currency: enums::Currency,
+) -> String {
+    format!(
+        "velocity_amount_{}_{}_{currency}_{}",
+        merchant_id.get_string_repr(),
+        customer_id.get_string_repr(),
+        get_current_window_start(SECONDS_PER_DAY)
+    )
+}
+
+fn get_velocity_record_key(
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> String {
+    format!(
+        "velocity_record_{}_{}",
+        merchant_id.get_string_repr(),
+        payment_id.get_string_repr()
+    )
+}
+
+/// A counter to be incremented for a payment, and the limit it must stay within
+#[derive(Clone, Debug, PartialEq)]
+struct VelocityCounter {
+    key: String,
+    increment: i64,
+    limit: i64,
+    window_seconds: i64,
+    exceeded_message: String,
+}
+
+/// An increment of a counter made for a payment, to be reverted if the payment fails
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+struct VelocityCounterIncrement {
+    key: String,
+    increment: i64,
+}
+
+/// The increments of the counters made for a payment, stored with the key
+/// `velocity_record_{merchant_id}_{payment_id}` for as long as the longest window
+#[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+struct VelocityRecord {
+    increments: Vec<VelocityCounterIncrement>,
+}
+
+/// The counters to be incremented for a payment of the customer under the velocity limits
+fn get_velocity_counters(
+    velocity_limits: &VelocityLimits,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+    amount: MinorUnit,
+    currency: enums::Currency,
+) -> Vec<VelocityCounter> {
+    let transaction_count_counter =
+        velocity_limits
+            .max_transactions_per_hour
+            .map(|max_transactions_per_hour| VelocityCounter {
+                key: get_transaction_count_key(merchant_id, customer_id),
+                increment: 1,
+                limit: max_transactions_per_hour,
+                window_seconds: SECONDS_PER_HOUR,
+                exceeded_message: format!(
+                    "The customer has exceeded the limit of {max_transactions_per_hour} transactions per hour"
+                ),
+            });
+    let amount_counter = velocity_limits
+        .max_amount_per_day
+        .map(|max_amount_per_day| VelocityCounter {
+            key: get_amount_key(merchant_id, customer_id, currency),
+            increment: amount.get_amount_as_i64(),
+            limit: max_amount_per_day.get_amount_as_i64(),
+            window_seconds: SECONDS_PER_DAY,
+            exceeded_message: format!(
+                "The customer has exceeded the limit of {max_amount_per_day} {currency} per day"
+            ),
+        });
+
+    transaction_count_counter
+        .into_iter()
+        .chain(amount_counter)
+        .collect()
+}
+
+/// Get the velocity limits configured for the merchant, if any
+#[instrument(skip_all)]
+pub async fn get_velocity_limits(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<VelocityLimits>> {
+    let config = match state
+        .store
+        .find_config_by_key(&merchant_id.get_velocity_limits_key())
+        .await
+    {
+        Ok(config) => config,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find the velocity limits config")
+        }
+    };
+
+    serde_json::from_str::<VelocityLimits>(&config.config)
+        .map(Some)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the velocity limits config")
+}
+
+/// Get the velocity limits configured for the merchant which apply to the customer, according to the
+/// risk tier of the customer. The limits of the customers of the other tiers apply if the risk
+/// profile cannot be fetched, so that the payment is not failed for it.
+async fn get_applicable_velocity_limits(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<Option<VelocityLimits>> {
+    let Some(velocity_limits) = get_velocity_limits(state, merchant_id).await? else {
+        return Ok(None);
+    };
+
+    if !velocity_limits.has_high_risk_limits() {
+        return Ok(Some(velocity_limits));
+    }
+
+    match risk_profile::get_customer_risk(state, merchant_id, customer_id).await {
+        Ok(customer_risk) if customer_risk.tier == enums::CustomerRiskTier::High => {
+            Ok(Some(velocity_limits.for_high_risk_customer()))
+        }
+        Ok(_) => Ok(Some(velocity_limits)),
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch the customer risk profile");
+            Ok(Some(velocity_limits))
+        }
+    }
+}
+
+/// Increment the counter, unless the increment would take the counter past its limit. Returns
+/// whether the increment was within the limit.
+async fn increment_counter_within_limit(
+    redis_conn: &RedisConnectionPool,
+    counter: &VelocityCounter,
+) -> RouterResult<bool> {
+    redis_conn
+        .evaluate_redis_script::<i64>(
+            INCREMENT_COUNTER_SCRIPT,
+            vec![counter.key.clone()],
+            vec![
+                counter.increment.to_string(),
+                counter.limit.to_string(),
+                counter.window_seconds.to_string(),
+            ],
+        )
+        .await
+        .map(|is_within_limit| is_within_limit == 1)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to increment the velocity counter")
+}
+
+async fn revert_counter_increment(
+    redis_conn: &RedisConnectionPool,
+    increment: &VelocityCounterIncrement,
+) {
+    let _ = redis_conn
+        .evaluate_redis_script::<i64>(
+            REVERT_COUNTER_SCRIPT,
+            vec![increment.key.clone()],
+            vec![increment.increment.to_string()],
+        )
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to revert the velocity counter"));
+}
+
+/// Record the payment of the customer against the velocity limits configured for the merchant,
+/// rejecting the payment if it would exceed any of the limits. The counters incremented for the
+/// payment are recorded, so that the increments can be reverted if the payment fails.
+///
+/// This is called before the payment is sent to the connector.
+#[instrument(skip_all)]
+pub async fn validate_and_record_velocity(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+    payment_id: &id_type::PaymentId,
+    amount: MinorUnit,
+    currency: enums::Currency,
+) -> RouterResult<()> {
+    let Some(velocity_limits) =
+        get_applicable_velocity_limits(state, merchant_id, customer_id).await?
+    else {
+        return Ok(());
+    };
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let mut record = VelocityRecord::default();
+    for counter in
+        get_velocity_counters(&velocity_limits, merchant_id, customer_id, amount, currency)
+    {
+        if !increment_counter_within_limit(&redis_conn, &counter).await? {
+            // The transaction is not made, so it must not be counted against the other limits
+            for increment in &record.increments {
+                revert_counter_increment(&redis_conn, increment).await;
+            }
+            return Err(report!(errors::ApiErrorResponse::VelocityLimitExceeded {
+                message: counter.exceeded_message,
+            }));
+        }
+        record.increments.push(VelocityCounterIncrement {
+            key: counter.key,
+            increment: counter.increment,
+        });
+    }
+
+    if !record.increments.is_empty() {
+        // A failure to record the increments only keeps a payment which fails counted
+        let _ = redis_conn
+            .serialize_and_set_key_with_expiry(
+                &get_velocity_record_key(merchant_id, payment_id),
+                &record,
+                SECONDS_PER_DAY,
+            )
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to record the velocity counters"));
+    }
+
+    Ok(())
+}
+
+/// Record the payment against the velocity limits of the customer of the payment, if any, before it
+/// is sent to the connector
+#[instrument(skip_all)]
+pub async fn guard_payment<F: Clone>(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<()> {
+    let Some(customer_id) = payment_data.payment_intent.customer_id.as_ref() else {
+        return Ok(());
+    };
+
+    validate_and_record_velocity(
+        state,
+        merchant_account.get_id(),
+        customer_id,
+        payment_data.payment_intent.get_id(),
+        payment_data.payment_attempt.get_total_amount(),
+        payment_data.currency,
+    )
+    .await
+}
+
+/// Revert the increments recorded for a payment which failed at the connector, so that the payment
+/// is not counted against the velocity limits of the customer. The increments are reverted at most
+/// once, by the call which removes the record of the payment. A failure to revert is logged, since
+/// the payment has already failed.
+#[instrument(skip_all)]
+pub async fn revert_failed_payment_velocity(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) {
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get redis connection to revert the counters"
+            );
+            return;
+        }
+    };
+
+    let record_key = get_velocity_record_key(merchant_id, payment_id);
+    let record = match redis_conn
+        .get_and_deserialize_key::<VelocityRecord>(&record_key, "VelocityRecord")
+        .await
+    {
+        Ok(record) => record,
+        // The payment was not counted against any of the limits
+        Err(error) if matches!(error.current_context(), RedisError::NotFound) => return,
+        Err(error) => {
+            logger::error!(?error, "Failed to get the velocity counters of the payment");
+            return;
+        }
+    };
+    match redis_conn.delete_key(&record_key).await {
+        Ok(DelReply::KeyDeleted) => {}
+        // The increments were reverted by a concurrent call
+        Ok(DelReply::KeyNotDeleted) => return,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to remove the velocity counters of the payment"
+            );
+            return;
+        }
+    }
+
+    for increment in &record.increments {
+        revert_counter_increment(&redis_conn, increment).await;
+    }
+}
+
+async fn get_counter_value(redis_conn: &RedisConnectionPool, key: &str) -> RouterResult<i64> {
+    redis_conn
+        .get_key::<Option<i64>>(key)
+        .await
+        .map(Option::unwrap_or_default)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get the velocity counter")
+}
+
+/// Get the current values of the velocity counters of the customer, for debugging the velocity
+/// limits
+#[instrument(skip_all)]
+pub async fn get_customer_velocity_counters(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: customers::CustomerVelocityCountersRequest,
+) -> RouterResponse<customers::CustomerVelocityCountersResponse> {
+    let merchant_id = merchant_account.get_id();
+    let velocity_limits = get_velocity_limits(&state, merchant_id)
+        .await?
+        .unwrap_or_default();
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let transaction_count = get_counter_value(
+        &redis_conn,
+        &get_transaction_count_key(merchant_id, &req.customer_id),
+    )
+    .await?;
+    let amount = get_counter_value(
+        &redis_conn,
+        &get_amount_key(merchant_id, &req.customer_id, req.currency),
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        customers::CustomerVelocityCountersResponse {
+            customer_id: req.customer_id,
+            currency: req.currency,
+            transaction_count,
+            max_transactions_per_hour: velocity_limits.max_transactions_per_hour,
+            amount: MinorUnit::new(amount),
+            max_amount_per_day: velocity_limits.max_amount_per_day,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_velocity_counters_follow_the_configured_limits() {
+        let merchant_id = id_type::MerchantId::default();
+        let customer_id = id_type::CustomerId::try_from(std::borrow::Cow::from("cus_1")).unwrap();
+        let velocity_limits = VelocityLimits {
+            max_amount_per_day: Some(MinorUnit::new(10000)),
+            max_transactions_per_hour: Some(5),
+            ..Default::default()
+        };
+
+        let counters = get_velocity_counters(
+            &velocity_limits,
+            &merchant_id,
+            &customer_id,
+            MinorUnit::new(2500),
+            enums::Currency::USD,
+        );
+        assert_eq!(
+            counters
+                .iter()
+                .map(|counter| (counter.increment, counter.limit, counter.window_seconds))
+                .collect::<Vec<_>>(),
+            vec![(1, 5, SECONDS_PER_HOUR), (2500, 10000, SECONDS_PER_DAY)]
+        );
+
+        let counters = get_velocity_counters(
+            &VelocityLimits::default(),
+            &merchant_id,
+            &customer_id,
+            MinorUnit::new(2500),
+            enums::Currency::USD,
+        );
+        assert!(counters.is_empty());
+    }
+
+    #[test]
+    fn test_high_risk_limits_fall_back_to_the_default_limits() {
+        let velocity_limits = VelocityLimits {
+            max_amount_per_day: Some(MinorUnit::new(10000)),
+            max_transactions_per_hour: Some(5),
+            high_risk_max_amount_per_day: None,
+            high_risk_max_transactions_per_hour: Some(1),
+        };
+        assert!(velocity_limits.has_high_risk_limits());
+
+        let high_risk_limits = velocity_limits.for_high_risk_customer();
+        assert_eq!(
+            high_risk_limits.max_amount_per_day,
+            Some(MinorUnit::new(10000))
+        );
+        assert_eq!(high_risk_limits.max_transactions_per_hour, Some(1));
+    }
+
+    #[test]
+    fn test_velocity_record_round_trips() {
+        let record = VelocityRecord {
+            increments: vec![VelocityCounterIncrement {
+                key: "velocity_amount_merchant_customer_USD_0".to_string(),
+                increment: 2500,
+            }],
+        };
+
+        let serialized = serde_json::to_string(&record).unwrap();
+        assert_eq!(
+            serde_json::from_str::<VelocityRecord>(&serialized).unwrap(),
+            record
+        );
+    }
+}
@@ -0,0 +1,226 @@
+//! Eligibility of payments to be voided.
+//!
+//! The eligibility follows the statuses from which a payment can be cancelled, along with the
+//! capabilities of the connector for the payments which hold an authorization at the connector, so
+//! that the action of voiding a payment can be disabled with the reason for which the void would
+//! fail. A payment which was partially captured can still be voided while a portion of its
+//! authorization has not been captured, in which case only the portion which has not been captured
+//! is released.
+
+use api_models::payments::{PaymentVoidEligibilityResponse, VoidIneligibilityReason};
+use common_utils::{id_type, types::MinorUnit};
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+};
+
+/// The reason for which a payment of the status cannot be voided, regardless of its connector
+fn get_status_ineligibility_reason(status: enums::IntentStatus) -> Option<VoidIneligibilityReason> {
+    match status {
+        enums::IntentStatus::Succeeded | enums::IntentStatus::PartiallyCaptured => {
+            Some(VoidIneligibilityReason::AlreadyCaptured)
+        }
+        enums::IntentStatus::Cancelled => Some(VoidIneligibilityReason::AlreadyVoided),
+        enums::IntentStatus::Failed => Some(VoidIneligibilityReason::PaymentFailed),
+        enums::IntentStatus::Processing | enums::IntentStatus::RequiresMerchantAction => {
+            Some(VoidIneligibilityReason::PaymentInProgress)
+        }
+        enums::IntentStatus::RequiresCustomerAction
+        | enums::IntentStatus::RequiresPaymentMethod
+        | enums::IntentStatus::RequiresConfirmation
+        | enums::IntentStatus::RequiresCapture
+        | enums::IntentStatus::PartiallyCapturedAndCapturable => None,
+    }
+}
+
+/// Whether the connector of the attempt supports voiding the authorization of the attempt. An
+/// attempt which has not reached the connector holds no authorization, and is cancelled without
+/// calling the connector.
+fn is_void_supported_by_connector(payment_attempt: &storage::PaymentAttempt) -> RouterResult<bool> {
+    if payment_attempt.connector_transaction_id.is_none() {
+        return Ok(true);
+    }
+
+    let Some(connector) = payment_attempt.connector.as_deref() else {
+        return Ok(true);
+    };
+
+    is_void_supported(connector)
+}
+
+fn is_void_supported(connector: &str) -> RouterResult<bool> {
+    let connector = connector
+        .parse::<api_models::enums::Connector>()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid connector populated in payment attempt")?;
+
+    Ok(connector.get_capabilities().void)
+}
+
+/// The reason for which the payment cannot be voided, if it cannot be voided
+pub fn get_void_ineligibility_reason(
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<Option<VoidIneligibilityReason>> {
+    if let Some(reason) =
+        get_capture_ineligibility_reason(payment_intent.status, payment_attempt.amount_capturable)
+    {
+        return Ok(Some(reason));
+    }
+
+    if !is_void_supported_by_connector(payment_attempt)? {
+        return Ok(Some(VoidIneligibilityReason::ConnectorNotSupported));
+    }
+
+    Ok(None)
+}
+
+/// The reason for which a payment of the status cannot be voided, given the amount of its
+/// authorization which has not been captured
+fn get_capture_ineligibility_reason(
+    status: enums::IntentStatus,
+    amount_capturable: MinorUnit,
+) -> Option<VoidIneligibilityReason> {
+    get_status_ineligibility_reason(status).or_else(|| {
+        // Every portion of the authorization of a partially captured payment has been captured or
+        // is held by a capture which is pending
+        (status == enums::IntentStatus::PartiallyCapturedAndCapturable
+            && amount_capturable <= MinorUnit::new(0))
+        .then_some(VoidIneligibilityReason::AlreadyCaptured)
+    })
+}
+
+/// Check whether the payment can be voided, without voiding it
+#[instrument(skip_all)]
+pub async fn check_void_eligibility(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    payment_id: id_type::PaymentId,
+) -> RouterResponse<PaymentVoidEligibilityResponse> {
+    let db = &*state.store;
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payment_intent)?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            merchant_account.get_id(),
+            payment_intent.active_attempt.get_id().as_str(),
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let reason = get_void_ineligibility_reason(&payment_intent, &payment_attempt)?;
+
+    // The amount capturable of the attempt is the portion of the authorization which has not been
+    // captured, which is all that is released by voiding a partially captured payment
+    let voidable_amount = match reason {
+        Some(_) => MinorUnit::new(0),
+        None => payment_attempt.amount_capturable,
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentVoidEligibilityResponse {
+            payment_id,
+            voidable: reason.is_none(),
+            reason,
+            voidable_amount,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_status_ineligibility_reason() {
+        assert_eq!(
+            get_status_ineligibility_reason(enums::IntentStatus::Succeeded),
+            Some(VoidIneligibilityReason::AlreadyCaptured)
+        );
+        assert_eq!(
+            get_status_ineligibility_reason(enums::IntentStatus::PartiallyCaptured),
+            Some(VoidIneligibilityReason::AlreadyCaptured)
+        );
+        assert_eq!(
+            get_status_ineligibility_reason(enums::IntentStatus::Cancelled),
+            Some(VoidIneligibilityReason::AlreadyVoided)
+        );
+        assert_eq!(
+            get_status_ineligibility_reason(enums::IntentStatus::Failed),
+            Some(VoidIneligibilityReason::PaymentFailed)
+        );
+        assert_eq!(
+            get_status_ineligibility_reason(enums::IntentStatus::Processing),
+            Some(VoidIneligibilityReason::PaymentInProgress)
+        );
+        assert_eq!(
+            get_status_ineligibility_reason(enums::IntentStatus::RequiresCapture),
+            None
+        );
+        assert_eq!(
+            get_status_ineligibility_reason(enums::IntentStatus::RequiresPaymentMethod),
+            None
+        );
+    }
+
+    #[test]
+    fn test_partially_captured_payment_is_voidable_while_amount_is_capturable() {
+        assert_eq!(
+            get_capture_ineligibility_reason(
+                enums::IntentStatus::PartiallyCapturedAndCapturable,
+                MinorUnit::new(500)
+            ),
+            None
+        );
+        assert_eq!(
+            get_capture_ineligibility_reason(
+                enums::IntentStatus::PartiallyCapturedAndCapturable,
+                MinorUnit::new(0)
+            ),
+            Some(VoidIneligibilityReason::AlreadyCaptured)
+        );
+        assert_eq!(
+            get_capture_ineligibility_reason(
+                enums::IntentStatus::RequiresCapture,
+                MinorUnit::new(0)
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_void_support_of_connector() {
+        assert!(is_void_supported("stripe").unwrap());
+        assert!(!is_void_supported("bitpay").unwrap());
+        assert!(is_void_supported("not_a_connector").is_err());
+    }
+}
@@ -0,0 +1,428 @@
+//! Simulation of the webhooks of test payments.
+//!
+//! A simulated webhook is generated from the current state of a test payment, and is signed and
+//! delivered to the webhook endpoint of the merchant exactly as a webhook of the payment is, so that
+//! the merchant can test the handling of the webhooks without moving the payment through the
+//! connector. The simulated webhook is stored as an event of its own, which is delivered once, and
+//! does not take part in the retries or in the ordering of the webhooks of the payment.
+
+use api_models::{
+    payments::{HeaderPayload, SimulateWebhookRequest, SimulateWebhookResponse},
+    webhook_events::OutgoingWebhookResponseContent,
+};
+use common_utils::{ext_traits::Encode, type_name, types::keymanager::Identifier};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::type_encryption::{crypto_operation, CryptoOperation};
+use masking::{PeekInterface, Secret};
+use router_env::{env, instrument, tracing};
+
+use super::{operations, CallConnectorAction, PaymentData};
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        webhooks,
+    },
+    routes::{app::ReqState, SessionState},
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+    utils::{OptionExt, StringExt},
+};
+
+/// Whether a webhook of the event type could have been sent for a payment of the status
+fn is_event_type_applicable(event_type: enums::EventType, status: enums::IntentStatus) -> bool {
+    match event_type {
+        enums::EventType::PaymentSucceeded => status == enums::IntentStatus::Succeeded,
+        enums::EventType::PaymentFailed => status == enums::IntentStatus::Failed,
+        enums::EventType::PaymentProcessing => status == enums::IntentStatus::Processing,
+        enums::EventType::PaymentCancelled => status == enums::IntentStatus::Cancelled,
+        enums::EventType::PaymentAuthorized => matches!(
+            status,
+            enums::IntentStatus::RequiresCapture
+                | enums::IntentStatus::PartiallyCapturedAndCapturable
+        ),
+        enums::EventType::PaymentCaptured => matches!(
+            status,
+            enums::IntentStatus::Succeeded
+                | enums::IntentStatus::PartiallyCaptured
+                | enums::IntentStatus::PartiallyCapturedAndCapturable
+        ),
+        enums::EventType::ActionRequired => matches!(
+            status,
+            enums::IntentStatus::RequiresCustomerAction
+                | enums::IntentStatus::RequiresMerchantAction
+        ),
+        enums::EventType::RefundSucceeded
+        | enums::EventType::RefundFailed
+        | enums::EventType::DisputeOpened
+        | enums::EventType::DisputeExpired
+        | enums::EventType::DisputeAccepted
+        | enums::EventType::DisputeCancelled
+        | enums::EventType::DisputeChallenged
+        | enums::EventType::DisputeWon
+        | enums::EventType::DisputeLost
+        | enums::EventType::MandateActive
+        | enums::EventType::MandateRevoked
+        | enums::EventType::PayoutSuccess
+        | enums::EventType::PayoutFailed
+        | enums::EventType::PayoutInitiated
+        | enums::EventType::PayoutProcessing
+        | enums::EventType::PayoutCancelled
+        | enums::EventType::PayoutExpired
+        | enums::EventType::PayoutReversed
+        | enums::EventType::PayoutReturned => false,
+    }
+}
+
+/// Ensure that the payment is a test payment. Every payment outside the production environment is
+/// a test payment, while in the production environment only the payments processed through a
+/// connector account in test mode are.
+async fn validate_test_payment(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payments_response: &api::PaymentsResponse,
+) -> RouterResult<()> {
+    if env::which() != env::Env::Production {
+        return Ok(());
+    }
+
+    let is_test_mode = match payments_response.merchant_connector_id.as_ref() {
+        Some(merchant_connector_id) => state
+            .store
+            .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+                &state.into(),
+                merchant_account.get_id(),
+                merchant_connector_id,
+                key_store,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+                id: merchant_connector_id.get_string_repr().to_owned(),
+            })?
+            .test_mode
+            .unwrap_or(false),
+        None => false,
+    };
+
+    if !is_test_mode {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Webhooks can only be simulated for test payments".to_string(),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Simulate a webhook of the event type for a test payment, delivering it to the webhook endpoint
+/// of the merchant and returning the outcome of the delivery
+#[instrument(skip_all)]
+pub async fn simulate_payment_webhook(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    req: SimulateWebhookRequest,
+) -> RouterResponse<SimulateWebhookResponse> {
+    let payments_response = match Box::pin(super::payments_core::<
+        api::PSync,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        PaymentData<api::PSync>,
+    >(
+        state.clone(),
+        req_state,
+        merchant_account.clone(),
+        profile_id,
+        key_store.clone(),
+        operations::PaymentStatus,
+        api::PaymentsRetrieveRequest {
+            resource_id: api::PaymentIdType::PaymentIntentId(req.payment_id.clone()),
+            merchant_id: Some(merchant_account.get_id().clone()),
+            force_sync: false,
+            connector: None,
+            param: None,
+            merchant_connector_details: None,
+            client_secret: None,
+            expand_attempts: None,
+            expand_captures: None,
+        },
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Avoid,
+        None,
+        HeaderPayload::default(),
+    ))
+    .await?
+    {
+        services::ApplicationResponse::JsonWithHeaders((payments_response, _))
+        | services::ApplicationResponse::Json(payments_response) => payments_response,
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Received non-json response from payments core")?,
+    };
+
+    validate_test_payment(&state, &merchant_account, &key_store, &payments_response).await?;
+
+    if !is_event_type_applicable(req.event_type, payments_response.status) {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "event_type `{}` is not applicable to a payment with status `{}`",
+                req.event_type, payments_response.status
+            ),
+        }
+        .into());
+    }
+
+    let profile_id = payments_response
+        .profile_id
+        .as_ref()
+        .get_required_value("profile_id")?;
+    let key_manager_state = &(&state).into();
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(key_manager_state, &key_store, profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let has_webhook_url = business_profile
+        .webhook_details
+        .as_ref()
+        .and_then(|webhook_details| webhook_details.webhook_url.as_ref())
+        .is_some_and(|webhook_url| !webhook_url.peek().is_empty());
+    if !state.conf.webhooks.outgoing_enabled || !has_webhook_url {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Outgoing webhooks are disabled, or the webhook URL is not configured in the \
+                      business profile"
+                .to_string(),
+        }
+        .into());
+    }
+
+    let event_id = webhooks::utils::generate_event_id();
+    let primary_object_created_at = payments_response.created;
+    let content = api::OutgoingWebhookContent::PaymentDetails(payments_response);
+    let outgoing_webhook = api::OutgoingWebhook {
+        merchant_id: merchant_account.get_id().clone(),
+        event_id: event_id.clone(),
+        event_type: req.event_type,
+        content: content.clone(),
+        timestamp: common_utils::date_time::now(),
+    };
+
+    // The simulated webhook is signed in the same way as the webhooks of the payments are
+    let request_content = webhooks::get_outgoing_webhook_request(
+        &merchant_account,
+        outgoing_webhook,
+        &business_profile,
+    )
+    .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+    .attach_printable("Failed to construct outgoing webhook request content")?;
+
+    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
+    let new_event = domain::Event {
+        event_id: event_id.clone(),
+        event_type: req.event_type,
+        event_class: enums::EventClass::Payments,
+        is_webhook_notified: false,
+        primary_object_id: req.payment_id.get_string_repr().to_owned(),
+        primary_object_type: enums::EventObjectType::PaymentDetails,
+        created_at: common_utils::date_time::now(),
+        merchant_id: Some(merchant_account.get_id().clone()),
+        business_profile_id: Some(business_profile.get_id().to_owned()),
+        primary_object_created_at,
+        // Every simulation is an event of its own, which is not deduplicated against the webhooks
+        // of the payment
+        idempotent_event_id: Some(event_id.clone()),
+        initial_attempt_id: Some(event_id.clone()),
+        request: Some(
+            crypto_operation(
+                key_manager_state,
+                type_name!(domain::Event),
+                CryptoOperation::Encrypt(
+                    request_content
+                        .encode_to_string_of_json()
+                        .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+                        .attach_printable("Failed to encode outgoing webhook request content")
+                        .map(Secret::new)?,
+                ),
+                Identifier::Merchant(key_store.merchant_id.clone()),
+                key_store.key.get_inner().peek(),
+            )
+            .await
+            .and_then(|val| val.try_into_operation())
+            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+            .attach_printable("Failed to encrypt outgoing webhook request content")?,
+        ),
+        response: None,
+        delivery_attempt: Some(delivery_attempt),
+        metadata: Some(storage::EventMetadata::foreign_from(&content)),
+        sequence_number: None,
+    };
+
+    let event = state
+        .store
+        .insert_event(key_manager_state, new_event, &key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert event")?;
+
+    let delivery_permit =
+        webhooks::delivery_scheduler::acquire_delivery_slot(&state, merchant_account.get_id())
+            .await;
+
+    Box::pin(webhooks::trigger_webhook_and_raise_event(
+        state.clone(),
+        business_profile,
+        &key_store,
+        event,
+        request_content,
+        delivery_attempt,
+        Some(content),
+        None,
+    ))
+    .await;
+    drop(delivery_permit);
+
+    let delivered_event = state
+        .store
+        .find_event_by_merchant_id_event_id(
+            key_manager_state,
+            merchant_account.get_id(),
+            &event_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
+
+    let delivery_status_code = delivered_event
+        .response
+        .as_ref()
+        .map(|response| {
+            response
+                .peek()
+                .parse_struct::<OutgoingWebhookResponseContent>("OutgoingWebhookResponseContent")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse webhook event response information")?
+        .and_then(|response| response.status_code);
+
+    Ok(services::ApplicationResponse::Json(
+        SimulateWebhookResponse {
+            payment_id: req.payment_id,
+            event_id,
+            event_type: req.event_type,
+            is_delivery_successful: delivered_event.is_webhook_notified,
+            delivery_status_code,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payment_event_types_are_applicable_to_their_statuses() {
+        assert!(is_event_type_applicable(
+            enums::EventType::PaymentSucceeded,
+            enums::IntentStatus::Succeeded
+        ));
+        assert!(is_event_type_applicable(
+            enums::EventType::PaymentFailed,
+            enums::IntentStatus::Failed
+        ));
+        assert!(is_event_type_applicable(
+            enums::EventType::PaymentProcessing,
+            enums::IntentStatus::Processing
+        ));
+        assert!(is_event_type_applicable(
+            enums::EventType::PaymentCancelled,
+            enums::IntentStatus::Cancelled
+        ));
+        assert!(is_event_type_applicable(
+            enums::EventType::ActionRequired,
+            enums::IntentStatus::RequiresCustomerAction
+        ));
+    }
+
+    #[test]
+    fn test_payment_event_types_are_not_applicable_to_other_statuses() {
+        assert!(!is_event_type_applicable(
+            enums::EventType::PaymentSucceeded,
+            enums::IntentStatus::Failed
+        ));
+        assert!(!is_event_type_applicable(
+            enums::EventType::PaymentFailed,
+            enums::IntentStatus::Succeeded
+        ));
+        assert!(!is_event_type_applicable(
+            enums::EventType::PaymentCancelled,
+            enums::IntentStatus::RequiresCapture
+        ));
+        assert!(!is_event_type_applicable(
+            enums::EventType::ActionRequired,
+            enums::IntentStatus::Processing
+        ));
+    }
+
+    #[test]
+    fn test_captured_and_authorized_event_types_of_partially_captured_payments() {
+        assert!(is_event_type_applicable(
+            enums::EventType::PaymentAuthorized,
+            enums::IntentStatus::RequiresCapture
+        ));
+        assert!(is_event_type_applicable(
+            enums::EventType::PaymentAuthorized,
+            enums::IntentStatus::PartiallyCapturedAndCapturable
+        ));
+        assert!(!is_event_type_applicable(
+            enums::EventType::PaymentAuthorized,
+            enums::IntentStatus::PartiallyCaptured
+        ));
+        assert!(is_event_type_applicable(
+            enums::EventType::PaymentCaptured,
+            enums::IntentStatus::Succeeded
+        ));
+        assert!(is_event_type_applicable(
+            enums::EventType::PaymentCaptured,
+            enums::IntentStatus::PartiallyCaptured
+        ));
+        assert!(is_event_type_applicable(
+            enums::EventType::PaymentCaptured,
+            enums::IntentStatus::PartiallyCapturedAndCapturable
+        ));
+        assert!(!is_event_type_applicable(
+            enums::EventType::PaymentCaptured,
+            enums::IntentStatus::RequiresCapture
+        ));
+    }
+
+    #[test]
+    fn test_event_types_of_other_resources_are_not_applicable_to_payments() {
+        assert!(!is_event_type_applicable(
+            enums::EventType::RefundSucceeded,
+            enums::IntentStatus::Succeeded
+        ));
+        assert!(!is_event_type_applicable(
+            enums::EventType::DisputeOpened,
+            enums::IntentStatus::Succeeded
+        ));
+        assert!(!is_event_type_applicable(
+            enums::EventType::MandateActive,
+            enums::IntentStatus::Succeeded
+        ));
+        assert!(!is_event_type_applicable(
+            enums::EventType::PayoutSuccess,
+            enums::IntentStatus::Succeeded
+        ));
+    }
+}
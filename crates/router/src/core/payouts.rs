@@ -2,6 +2,7 @@ pub mod access_token;
 pub mod helpers;
 #[cfg(feature = "payout_retry")]
 pub mod retry;
+pub mod returns;
 pub mod transformers;
 pub mod validator;
 use std::{collections::HashSet, vec::IntoIter};
@@ -107,6 +108,9 @@ pub async fn get_connector_choice(
                 .attach_printable("Invalid connector choice - SessionMultiple")?
         }
 
+        api::ConnectorChoice::Override(_) => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Invalid connector choice - Override")?,
+
         api::ConnectorChoice::StraightThrough(straight_through) => {
             let request_straight_through: api::routing::StraightThroughAlgorithm = straight_through
                 .clone()
@@ -387,6 +391,7 @@ pub async fn payouts_confirm_core(
         &[
             storage_enums::PayoutStatus::Cancelled,
             storage_enums::PayoutStatus::Success,
+            storage_enums::PayoutStatus::Returned,
             storage_enums::PayoutStatus::Failed,
             storage_enums::PayoutStatus::Pending,
             storage_enums::PayoutStatus::Ineligible,
@@ -353,6 +353,7 @@ impl GsmValidation for PayoutData {
             | common_enums::PayoutStatus::Pending
             | common_enums::PayoutStatus::Initiated
             | common_enums::PayoutStatus::Reversed
+            | common_enums::PayoutStatus::Returned
             | common_enums::PayoutStatus::Expired
             | common_enums::PayoutStatus::Ineligible
             | common_enums::PayoutStatus::RequiresCreation
@@ -0,0 +1,327 @@
+//! Returns of previously succeeded payouts.
+//!
+//! A payout which was accepted by the bank can still be returned days later, for instance when the
+//! beneficiary account turns out to be closed. The bank reports such returns either through
+//! webhooks or through return files, each return carrying the reference of the payout at the bank
+//! and the reason for which it was returned. The returned payouts are resolved through the
+//! reference of the payout at the connector, and transitioned from `success` to `returned`.
+//!
+//! Applying a return which was already applied leaves the payout unchanged, so return files can be
+//! submitted again safely. The bank reference is only unique for the payouts of a connector, hence
+//! the returns are resolved among the payouts of the connector which reported them.
+
+use api_models::payouts as payout_models;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult},
+    db::payment_processing_claim,
+    routes::SessionState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+};
+
+/// Apply the returns reported in a return file of the bank
+#[instrument(skip_all)]
+pub async fn payouts_returns_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payout_models::PayoutReturnsRequest,
+) -> RouterResponse<payout_models::PayoutReturnsResponse> {
+    let connector = req.connector.to_string();
+    let payout_returns = req
+        .returns
+        .into_iter()
+        .map(|entry| storage::PayoutReturn {
+            connector: connector.clone(),
+            bank_reference: entry.bank_reference,
+            return_reason_code: Some(entry.return_reason_code),
+            return_reason_message: entry.return_reason_message,
+        })
+        .collect();
+
+    let results = apply_payout_returns(&state, &merchant_account, &key_store, payout_returns)
+        .await?
+        .into_iter()
+        .map(get_payout_return_entry_response)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        payout_models::PayoutReturnsResponse { results },
+    ))
+}
+
+fn get_payout_return_entry_response(
+    return_result: storage::PayoutReturnResult,
+) -> payout_models::PayoutReturnEntryResponse {
+    let (result, payout_attempt, error_message) = match &return_result.outcome {
+        storage::PayoutReturnOutcome::Returned(returned_payout) => (
+            payout_models::PayoutReturnResult::Returned,
+            Some(&returned_payout.payout_attempt),
+            None,
+        ),
+        storage::PayoutReturnOutcome::AlreadyReturned(payout_attempt) => (
+            payout_models::PayoutReturnResult::AlreadyReturned,
+            Some(payout_attempt.as_ref()),
+            None,
+        ),
+        storage::PayoutReturnOutcome::NotReturnable(payout_attempt) => (
+            payout_models::PayoutReturnResult::NotReturnable,
+            Some(payout_attempt.as_ref()),
+            None,
+        ),
+        storage::PayoutReturnOutcome::NotFound => {
+            (payout_models::PayoutReturnResult::NotFound, None, None)
+        }
+        storage::PayoutReturnOutcome::Failed(error) => (
+            payout_models::PayoutReturnResult::Failed,
+            None,
+            Some(error.clone()),
+        ),
+    };
+
+    payout_models::PayoutReturnEntryResponse {
+        payout_id: payout_attempt.map(|attempt| attempt.payout_id.clone()),
+        status: payout_attempt.map(|attempt| attempt.status),
+        bank_reference: return_result.bank_reference,
+        result,
+        error_message,
+    }
+}
+
+/// Apply the returns to the payouts, and notify the merchant of each payout which was returned.
+///
+/// A failure to notify the merchant does not fail the application of the returns, as the payouts
+/// have been returned already.
+#[instrument(skip_all)]
+pub async fn apply_payout_returns(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payout_returns: Vec<storage::PayoutReturn>,
+) -> RouterResult<Vec<storage::PayoutReturnResult>> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let mut results = Vec::with_capacity(payout_returns.len());
+    for payout_return in payout_returns {
+        let return_result =
+            apply_payout_return(state, &redis_conn, merchant_account, payout_return).await?;
+
+        if let storage::PayoutReturnOutcome::Returned(returned_payout) = &return_result.outcome {
+            if let Err(error) =
+                trigger_payout_returned_webhook(state, merchant_account, key_store, returned_payout)
+                    .await
+            {
+                logger::error!(
+                    ?error,
+                    payout_id = %returned_payout.payouts.payout_id,
+                    "Failed to trigger the outgoing webhook for the returned payout"
+                );
+            }
+        }
+        results.push(return_result);
+    }
+
+    Ok(results)
+}
+
+fn get_payout_return_lock_key(
+    merchant_id: &common_utils::id_type::MerchantId,
+    connector: &str,
+    bank_reference: &str,
+) -> String {
+    format!(
+        "payout_return_lock_{}_{connector}_{bank_reference}",
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Apply the return of a payout while holding a lock on its bank reference.
+///
+/// The bank may deliver the same return concurrently, such as a webhook which is retried while
+/// the first delivery is being processed. Only the delivery holding the lock applies the return,
+/// the other deliveries fail so that they are retried, and find the payout returned already.
+async fn apply_payout_return(
+    state: &SessionState,
+    redis_conn: &redis_interface::RedisConnectionPool,
+    merchant_account: &domain::MerchantAccount,
+    payout_return: storage::PayoutReturn,
+) -> RouterResult<storage::PayoutReturnResult> {
+    let lock_key = get_payout_return_lock_key(
+        merchant_account.get_id(),
+        &payout_return.connector,
+        &payout_return.bank_reference,
+    );
+    let lock_id = uuid::Uuid::new_v4().to_string();
+
+    let lock_reply = redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &lock_key,
+            lock_id.as_str(),
+            Some(i64::from(
+                state.conf.lock_settings.redis_lock_expiry_seconds,
+            )),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to acquire the payout return lock")?;
+
+    if lock_reply != redis_interface::SetnxReply::KeySet {
+        return Ok(storage::PayoutReturnResult {
+            bank_reference: payout_return.bank_reference,
+            outcome: storage::PayoutReturnOutcome::Failed(
+                "The return of the payout is being applied".to_string(),
+            ),
+        });
+    }
+
+    let bank_reference = payout_return.bank_reference.clone();
+    let return_result = state
+        .store
+        .apply_payout_returns(
+            merchant_account.get_id(),
+            vec![payout_return],
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to apply the payout return")
+        .and_then(|mut results| {
+            results
+                .pop()
+                .ok_or(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to apply the payout return")
+        });
+
+    match payment_processing_claim::release_claim(redis_conn, &lock_key, &lock_id).await {
+        Ok(true) => {}
+        Ok(false) => logger::warn!(
+            %bank_reference,
+            "The payout return lock expired before the return was applied"
+        ),
+        Err(error) => logger::error!(
+            ?error,
+            %bank_reference,
+            "Failed to release the payout return lock, it expires after the lock expiry"
+        ),
+    }
+
+    return_result
+}
+
+async fn trigger_payout_returned_webhook(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    returned_payout: &storage::ReturnedPayout,
+) -> RouterResult<()> {
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(
+            &state.into(),
+            key_store,
+            &returned_payout.payouts.profile_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the business profile of the payout")?;
+
+    let action_req =
+        payout_models::PayoutRequest::PayoutActionRequest(payout_models::PayoutActionRequest {
+            payout_id: returned_payout.payouts.payout_id.clone(),
+        });
+    let payout_data = super::make_payout_data(
+        state,
+        merchant_account,
+        None,
+        key_store,
+        &action_req,
+        common_utils::consts::DEFAULT_LOCALE,
+    )
+    .await?;
+
+    let payout_create_response =
+        match super::response_handler(state, merchant_account, &payout_data).await? {
+            services::ApplicationResponse::Json(response) => response,
+            _ => Err(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the payout create response")?,
+        };
+
+    Box::pin(
+        crate::core::webhooks::create_event_and_trigger_outgoing_webhook(
+            state.clone(),
+            merchant_account.clone(),
+            business_profile,
+            key_store,
+            enums::EventType::PayoutReturned,
+            enums::EventClass::Payouts,
+            returned_payout.payout_attempt.payout_id.clone(),
+            enums::EventObjectType::PayoutDetails,
+            api::OutgoingWebhookContent::PayoutDetails(payout_create_response),
+            Some(returned_payout.payout_attempt.created_at),
+        ),
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_payout_return_lock_key_is_scoped_to_the_connector() {
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+
+        assert_ne!(
+            get_payout_return_lock_key(&merchant_id, "wise", "1727866418"),
+            get_payout_return_lock_key(&merchant_id, "adyenplatform", "1727866418")
+        );
+        assert_eq!(
+            get_payout_return_lock_key(&merchant_id, "wise", "1727866418"),
+            get_payout_return_lock_key(&merchant_id, "wise", "1727866418")
+        );
+    }
+
+    #[test]
+    fn test_payout_return_entry_response_of_a_payout_not_found() {
+        let response = get_payout_return_entry_response(storage::PayoutReturnResult {
+            bank_reference: "1727866418".to_string(),
+            outcome: storage::PayoutReturnOutcome::NotFound,
+        });
+
+        assert_eq!(response.bank_reference, "1727866418");
+        assert_eq!(response.result, payout_models::PayoutReturnResult::NotFound);
+        assert!(response.payout_id.is_none());
+        assert!(response.status.is_none());
+        assert!(response.error_message.is_none());
+    }
+
+    #[test]
+    fn test_payout_return_entry_response_of_a_return_being_applied() {
+        let response = get_payout_return_entry_response(storage::PayoutReturnResult {
+            bank_reference: "1727866418".to_string(),
+            outcome: storage::PayoutReturnOutcome::Failed(
+                "The return of the payout is being applied".to_string(),
+            ),
+        });
+
+        assert_eq!(response.result, payout_models::PayoutReturnResult::Failed);
+        assert_eq!(
+            response.error_message.as_deref(),
+            Some("The return of the payout is being applied")
+        );
+        assert!(response.payout_id.is_none());
+    }
+}
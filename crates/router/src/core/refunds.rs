@@ -7,7 +7,7 @@ use std::collections::HashMap;
 #[cfg(feature = "olap")]
 use api_models::admin::MerchantConnectorInfo;
 use common_utils::{
-    ext_traits::{AsyncExt, ValueExt},
+    ext_traits::{AsyncExt, StringExt, ValueExt},
     types::MinorUnit,
 };
 use diesel_models::process_tracker::business_status;
@@ -29,7 +29,12 @@ use crate::{
     },
     db, logger,
     routes::{metrics, SessionState},
-    services,
+    services::{
+        self,
+        connector_read_cache::{
+            self, ConnectorReadCache, ConnectorReadCacheKey, ConnectorReadFlow,
+        },
+    },
     types::{
         self,
         api::{self, refunds},
@@ -178,7 +183,7 @@ pub async fn trigger_refund_to_gateway(
         &routed_through,
         merchant_account,
         key_store,
-        (payment_attempt.amount, currency),
+        (payment_attempt.get_total_amount(), currency),
         payment_intent,
         payment_attempt,
         refund,
@@ -220,6 +225,30 @@ pub async fn trigger_refund_to_gateway(
             None,
         )
         .await;
+        // The refund modifies both the payment and the refund at the connector
+        let connector_refund_id = router_data_res
+            .as_ref()
+            .ok()
+            .and_then(|router_data| router_data.response.as_ref().ok())
+            .map(|response| response.connector_refund_id.clone())
+            .or_else(|| refund.connector_refund_id.clone());
+        let read_cache_keys = std::iter::once(ConnectorReadCacheKey::new(
+            router_data.merchant_id.clone(),
+            router_data.connector.clone(),
+            ConnectorReadFlow::PaymentSync,
+            router_data.request.connector_transaction_id.clone(),
+        ))
+        .chain(connector_refund_id.map(|connector_refund_id| {
+            ConnectorReadCacheKey::new(
+                router_data.merchant_id.clone(),
+                router_data.connector.clone(),
+                ConnectorReadFlow::RefundSync,
+                connector_refund_id,
+            )
+        }));
+        for read_cache_key in read_cache_keys {
+            connector_read_cache::invalidate_connector_read_cache(state, &read_cache_key).await;
+        }
         let option_refund_error_update =
             router_data_res
                 .as_ref()
@@ -521,7 +550,7 @@ pub async fn sync_refund_with_gateway(
         &connector_id,
         merchant_account,
         key_store,
-        (payment_attempt.amount, currency),
+        (payment_attempt.get_total_amount(), currency),
         payment_intent,
         payment_attempt,
         refund,
@@ -555,15 +584,43 @@ pub async fn sync_refund_with_gateway(
             types::RefundsData,
             types::RefundsResponseData,
         > = connector.connector.get_connector_integration();
-        let mut refund_sync_router_data = services::execute_connector_processing_step(
-            state,
-            connector_integration,
-            &router_data,
-            payments::CallConnectorAction::Trigger,
-            None,
-        )
-        .await
-        .to_refund_failed_response()?;
+        let read_cache =
+            router_data
+                .request
+                .connector_refund_id
+                .clone()
+                .map(|connector_refund_id| ConnectorReadCache {
+                    key: ConnectorReadCacheKey::new(
+                        router_data.merchant_id.clone(),
+                        router_data.connector.clone(),
+                        ConnectorReadFlow::RefundSync,
+                        connector_refund_id,
+                    ),
+                    is_cacheable: |refund_sync_router_data: &types::RefundSyncRouterData| {
+                        refund_sync_router_data
+                            .response
+                            .as_ref()
+                            .is_ok_and(|response| {
+                                matches!(
+                                    response.refund_status,
+                                    enums::RefundStatus::Success
+                                        | enums::RefundStatus::Failure
+                                        | enums::RefundStatus::TransactionFailure
+                                )
+                            })
+                    },
+                });
+        let mut refund_sync_router_data =
+            services::execute_connector_processing_step_with_read_cache(
+                state,
+                connector_integration,
+                &router_data,
+                payments::CallConnectorAction::Trigger,
+                None,
+                read_cache,
+            )
+            .await
+            .to_refund_failed_response()?;
 
         // Initiating connector integrity checks
         let integrity_result = check_refund_integrity(
@@ -683,6 +740,183 @@ pub async fn refund_update_core(
     Ok(services::ApplicationResponse::Json(response.foreign_into()))
 }
 
+// ********************************************** REFUND ELIGIBILITY **********************************************
+
+/// Get the captures of the payment attempt if it is captured in parts, which are the only attempts
+/// whose captures are recorded apart from the attempt
+async fn get_captures_of_multiple_capture_attempt(
+    db: &dyn db::StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<Vec<storage::Capture>> {
+    if payment_attempt.capture_method != Some(enums::CaptureMethod::ManualMultiple) {
+        return Ok(Vec::new());
+    }
+
+    db.find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+        &payment_attempt.merchant_id,
+        &payment_attempt.payment_id,
+        &payment_attempt.attempt_id,
+        storage_scheme,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to find the captures of the payment attempt")
+}
+
+/// Check whether a refund of the amount can be initiated against the payment, without initiating
+/// it. The refundable amount is the amount captured, which is less than the authorized amount in
+/// case of partial captures, less the amount of the refunds which have not failed.
+#[instrument(skip_all)]
+pub async fn check_refund_eligibility(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    req: refunds::RefundEligibilityRequest,
+) -> RouterResponse<refunds::RefundEligibilityResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_account.get_id();
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &req.payment_id,
+            merchant_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payment_intent)?;
+
+    if !matches!(
+        payment_intent.status,
+        enums::IntentStatus::Succeeded | enums::IntentStatus::PartiallyCaptured
+    ) {
+        return Ok(services::ApplicationResponse::Json(
+            refunds::RefundEligibilityResponse {
+                payment_id: req.payment_id,
+                eligible: false,
+                reason: Some(refunds::RefundIneligibilityReason::PaymentNotCaptured),
+                refundable_amount: MinorUnit::new(0),
+                refunded_amount: MinorUnit::new(0),
+            },
+        ));
+    }
+
+    let payment_attempt = db
+        .find_payment_attempt_last_successful_or_partially_captured_attempt_by_payment_id_merchant_id(
+            &req.payment_id,
+            merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::SuccessfulPaymentNotFound)?;
+
+    // The amount captured is tracked on the payment intent, which is the sum of the captures in
+    // case of partial captures
+    let amount_captured = match payment_intent.status {
+        enums::IntentStatus::PartiallyCaptured => payment_intent
+            .amount_captured
+            .get_required_value("amount_captured")?,
+        _ => payment_intent
+            .amount_captured
+            .unwrap_or(payment_attempt.get_total_amount()),
+    };
+
+    let connector_transaction_id = payment_attempt
+        .connector_transaction_id
+        .as_ref()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Missing connector_transaction_id in a successful payment attempt")?;
+
+    let all_refunds = db
+        .find_refund_by_merchant_id_connector_transaction_id(
+            merchant_id,
+            connector_transaction_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+
+    let refunded_amount = MinorUnit::new(validator::get_total_refunded_amount(&all_refunds));
+    let refundable_amount = amount_captured - refunded_amount;
+
+    let connector = payment_attempt
+        .connector
+        .as_ref()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("No connector populated in payment attempt")?
+        .parse::<api_models::enums::Connector>()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid connector populated in payment attempt")?;
+
+    let is_refund_supported_by_connector = connector.get_capabilities().refund
+        && match validator::validate_for_valid_refunds(&payment_attempt, connector) {
+            Ok(()) => true,
+            Err(error)
+                if matches!(
+                    error.current_context(),
+                    errors::ApiErrorResponse::RefundNotPossible { .. }
+                ) =>
+            {
+                false
+            }
+            Err(error) => return Err(error),
+        };
+
+    let amount = req.amount.unwrap_or(refundable_amount);
+
+    let reason = if !is_refund_supported_by_connector {
+        Some(refunds::RefundIneligibilityReason::ConnectorNotSupported)
+    } else if validator::validate_payment_order_age(
+        &payment_intent.created_at,
+        state.conf.refund.max_age,
+    )
+    .is_err()
+        || validator::validate_connector_refund_window(
+            &state.conf.refund,
+            &payment_intent,
+            &payment_attempt,
+            &get_captures_of_multiple_capture_attempt(
+                db,
+                &payment_attempt,
+                merchant_account.storage_scheme,
+            )
+            .await?,
+        )
+        .is_err()
+    {
+        Some(refunds::RefundIneligibilityReason::RefundWindowExpired)
+    } else if refundable_amount <= MinorUnit::new(0) {
+        Some(refunds::RefundIneligibilityReason::FullyRefunded)
+    } else if validator::validate_maximum_refund_against_payment_attempt(
+        &all_refunds,
+        state.conf.refund.max_attempts,
+    )
+    .is_err()
+    {
+        Some(refunds::RefundIneligibilityReason::MaximumRefundCountReached)
+    } else if amount <= MinorUnit::new(0) {
+        Some(refunds::RefundIneligibilityReason::InvalidAmount)
+    } else if amount > refundable_amount {
+        Some(refunds::RefundIneligibilityReason::AmountExceedsRefundableAmount)
+    } else {
+        None
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        refunds::RefundEligibilityResponse {
+            payment_id: req.payment_id,
+            eligible: reason.is_none(),
+            reason,
+            refundable_amount,
+            refunded_amount,
+        },
+    ))
+}
+
 // ********************************************** VALIDATIONS **********************************************
 
 #[instrument(skip_all)]
@@ -768,7 +1002,6 @@ pub async fn validate_and_create_refund(
 
     let currency = payment_attempt.currency.get_required_value("currency")?;
 
-    //[#249]: Add Connector Based Validation here.
     validator::validate_payment_order_age(&payment_intent.created_at, state.conf.refund.max_age)
         .change_context(errors::ApiErrorResponse::InvalidDataFormat {
             field_name: "created_at".to_string(),
@@ -778,9 +1011,21 @@ pub async fn validate_and_create_refund(
             ),
         })?;
 
+    validator::validate_connector_refund_window(
+        &state.conf.refund,
+        payment_intent,
+        payment_attempt,
+        &get_captures_of_multiple_capture_attempt(
+            db,
+            payment_attempt,
+            merchant_account.storage_scheme,
+        )
+        .await?,
+    )?;
+
     let total_amount_captured = payment_intent
         .amount_captured
-        .unwrap_or(payment_attempt.amount);
+        .unwrap_or(payment_attempt.get_total_amount());
 
     validator::validate_refund_amount(
         total_amount_captured.get_amount_as_i64(),
@@ -809,7 +1054,7 @@ pub async fn validate_and_create_refund(
         connector_transaction_id: connecter_transaction_id.to_string(),
         connector,
         refund_type: req.refund_type.unwrap_or_default().foreign_into(),
-        total_amount: payment_attempt.amount,
+        total_amount: payment_attempt.get_total_amount(),
         refund_amount,
         currency,
         created_at: common_utils::date_time::now(),
@@ -1141,6 +1386,7 @@ pub async fn get_aggregates_for_refunds(
 impl ForeignFrom<storage::Refund> for api::RefundResponse {
     fn foreign_from(refund: storage::Refund) -> Self {
         let refund = refund;
+        let awaiting_connector_confirmation = refund.is_awaiting_connector_confirmation();
 
         Self {
             payment_id: refund.payment_id,
@@ -1149,6 +1395,7 @@ impl ForeignFrom<storage::Refund> for api::RefundResponse {
             currency: refund.currency.to_string(),
             reason: refund.refund_reason,
             status: refund.refund_status.foreign_into(),
+            awaiting_connector_confirmation,
             profile_id: refund.profile_id,
             metadata: refund.metadata,
             error_message: refund.refund_error_message,
@@ -1318,13 +1565,21 @@ pub async fn sync_refund_with_gateway_workflow(
                 .await?
         }
         _ => {
-            _ = payment_sync::retry_sync_task(
+            let awaiting_connector_confirmation = response.is_awaiting_connector_confirmation();
+            let is_last_retry = retry_refund_sync_task(
                 &*state.store,
                 response.connector,
                 response.merchant_id,
                 refund_tracker.to_owned(),
             )
             .await?;
+
+            if is_last_retry && awaiting_connector_confirmation {
+                logger::warn!(
+                    refund_id = %response.refund_id,
+                    "Refund sync retries exhausted without a confirmation from the connector"
+                );
+            }
         }
     }
 
@@ -1581,24 +1836,53 @@ pub async fn add_refund_execute_task(
     Ok(response)
 }
 
+/// Get the time of the next sync of the refund, from the `pt_mapping_refund_sync_{connector}`
+/// config. The refunds of the connectors which do not send refund webhooks are only completed
+/// through these syncs, and may take longer than their payments, so the refund sync schedule can be
+/// configured separately. The mappings set in redis under the same key before the config was
+/// introduced are still read when the config is not set. Falls back to the payment sync schedule
+/// of the connector.
 pub async fn get_refund_sync_process_schedule_time(
     db: &dyn db::StorageInterface,
     connector: &str,
     merchant_id: &common_utils::id_type::MerchantId,
     retry_count: i32,
 ) -> Result<Option<time::PrimitiveDateTime>, errors::ProcessTrackerError> {
-    let redis_mapping: errors::CustomResult<process_data::ConnectorPTMapping, errors::RedisError> =
-        db::get_and_deserialize_key(
-            db,
-            &format!("pt_mapping_refund_sync_{connector}"),
-            "ConnectorPTMapping",
-        )
-        .await;
-
-    let mapping = match redis_mapping {
-        Ok(x) => x,
-        Err(err) => {
-            logger::error!("Error: while getting connector mapping: {err:?}");
+    let mapping_key = format!("pt_mapping_refund_sync_{connector}");
+    let mapping: process_data::ConnectorPTMapping = match db.find_config_by_key(&mapping_key).await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("ConnectorPTMapping")
+            .map_err(|error| {
+                logger::error!(?error, "Failed to parse the refund sync mapping");
+            })
+            .unwrap_or_default(),
+        Err(error) if error.current_context().is_db_not_found() => {
+            let redis_mapping: errors::CustomResult<
+                process_data::ConnectorPTMapping,
+                errors::RedisError,
+            > = db::get_and_deserialize_key(db, &mapping_key, "ConnectorPTMapping").await;
+
+            match redis_mapping {
+                Ok(mapping) => mapping,
+                Err(error) => {
+                    logger::debug!(
+                        ?error,
+                        "Refund sync mapping not found, falling back to the payment sync mapping"
+                    );
+                    return payment_sync::get_sync_process_schedule_time(
+                        db,
+                        connector,
+                        merchant_id,
+                        retry_count + 1,
+                    )
+                    .await;
+                }
+            }
+        }
+        Err(error) => {
+            logger::error!(?error, "Failed to find the refund sync mapping");
             process_data::ConnectorPTMapping::default()
         }
     };
@@ -1608,3 +1892,30 @@ pub async fn get_refund_sync_process_schedule_time(
 
     Ok(process_tracker_utils::get_time_from_delta(time_delta))
 }
+
+/// Schedule the refund sync task for retry, according to the refund sync schedule of the
+/// connector
+///
+/// Returns bool which indicates whether this was the last retry or not
+pub async fn retry_refund_sync_task(
+    db: &dyn db::StorageInterface,
+    connector: String,
+    merchant_id: common_utils::id_type::MerchantId,
+    pt: storage::ProcessTracker,
+) -> Result<bool, errors::ProcessTrackerError> {
+    let schedule_time =
+        get_refund_sync_process_schedule_time(db, &connector, &merchant_id, pt.retry_count).await?;
+
+    match schedule_time {
+        Some(s_time) => {
+            db.as_scheduler().retry_process(pt, s_time).await?;
+            Ok(false)
+        }
+        None => {
+            db.as_scheduler()
+                .finish_process_with_business_status(pt, business_status::RETRIES_EXCEEDED)
+                .await?;
+            Ok(true)
+        }
+    }
+}
@@ -1,8 +1,9 @@
-use error_stack::report;
+use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
 use time::PrimitiveDateTime;
 
 use crate::{
+    configs::settings,
     core::errors::{self, CustomResult, RouterResult},
     types::{
         self,
@@ -42,13 +43,10 @@ pub fn validate_success_transaction(
     Ok(())
 }
 
-#[instrument(skip_all)]
-pub fn validate_refund_amount(
-    amount_captured: i64,
-    all_refunds: &[storage::Refund],
-    refund_amount: i64,
-) -> CustomResult<(), RefundValidationError> {
-    let total_refunded_amount: i64 = all_refunds
+/// Get the total amount of the refunds which have not failed, since these have been refunded or
+/// are being refunded
+pub fn get_total_refunded_amount(all_refunds: &[storage::Refund]) -> i64 {
+    all_refunds
         .iter()
         .filter_map(|refund| {
             if refund.refund_status != enums::RefundStatus::Failure
@@ -59,7 +57,16 @@ pub fn validate_refund_amount(
                 None
             }
         })
-        .sum();
+        .sum()
+}
+
+#[instrument(skip_all)]
+pub fn validate_refund_amount(
+    amount_captured: i64,
+    all_refunds: &[storage::Refund],
+    refund_amount: i64,
+) -> CustomResult<(), RefundValidationError> {
+    let total_refunded_amount = get_total_refunded_amount(all_refunds);
 
     utils::when(
         refund_amount > (amount_captured - total_refunded_amount),
@@ -84,6 +91,119 @@ pub fn validate_payment_order_age(
     )
 }
 
+/// The time at which the first of the captures succeeded, if any succeeded
+fn get_first_capture_time(captures: &[storage::Capture]) -> Option<PrimitiveDateTime> {
+    captures
+        .iter()
+        .filter(|capture| capture.status == enums::CaptureStatus::Charged)
+        .map(|capture| capture.modified_at)
+        .min()
+}
+
+/// The time at which the payment attempt was captured. The attempts captured automatically are
+/// captured when they are authorized, and the scheduled captures at their scheduled time. The
+/// attempts captured in parts were captured when their first capture succeeded, which is when the
+/// capture was last updated. A single manual capture is not recorded apart from the attempt, which
+/// was last updated when its capture succeeded.
+fn get_capture_time(
+    payment_attempt: &storage::PaymentAttempt,
+    captures: &[storage::Capture],
+) -> PrimitiveDateTime {
+    match payment_attempt.capture_method {
+        Some(enums::CaptureMethod::ManualMultiple) => {
+            get_first_capture_time(captures).unwrap_or(payment_attempt.modified_at)
+        }
+        Some(enums::CaptureMethod::Scheduled) => payment_attempt
+            .capture_on
+            .unwrap_or(payment_attempt.modified_at),
+        Some(enums::CaptureMethod::Manual) => payment_attempt.modified_at,
+        Some(enums::CaptureMethod::Automatic) | None => payment_attempt.created_at,
+    }
+}
+
+/// The start of the refund window of the payment, which is the capture of the payment. The refund
+/// window of a partially captured payment starts when the capture is settled instead, once the
+/// settlement date of the capture is reached, since a settlement date in the future would
+/// lengthen the window beyond the one of the connector.
+pub fn get_refund_window_start(
+    intent_status: enums::IntentStatus,
+    captured_at: PrimitiveDateTime,
+    expected_settlement_date: Option<time::Date>,
+    current_time: PrimitiveDateTime,
+) -> PrimitiveDateTime {
+    match intent_status {
+        enums::IntentStatus::PartiallyCaptured
+        | enums::IntentStatus::PartiallyCapturedAndCapturable => expected_settlement_date
+            .map(|expected_settlement_date| expected_settlement_date.midnight())
+            .filter(|settled_at| *settled_at <= current_time)
+            .unwrap_or(captured_at),
+        _ => captured_at,
+    }
+}
+
+/// The number of days after the payment within which the connector accepts refunds, which is the
+/// one configured for the connector if any, and the refund window of the connector otherwise
+pub fn get_connector_refund_window_in_days(
+    refund_config: &settings::Refund,
+    connector: &str,
+) -> Option<i64> {
+    refund_config
+        .connector_max_age
+        .get(connector)
+        .copied()
+        .or_else(|| {
+            connector
+                .parse::<api_enums::Connector>()
+                .ok()
+                .and_then(|connector| connector.get_max_refund_window_in_days())
+        })
+}
+
+fn validate_refund_window(
+    refund_window_in_days: Option<i64>,
+    refund_window_start: PrimitiveDateTime,
+    current_time: PrimitiveDateTime,
+) -> RouterResult<()> {
+    match refund_window_in_days {
+        Some(refund_window_in_days)
+            if (current_time - refund_window_start).whole_days() > refund_window_in_days =>
+        {
+            Err(report!(errors::ApiErrorResponse::RefundWindowExpired {
+                refund_window_in_days,
+            }))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Reject the refunds of the payments whose refund window at the connector has expired, which the
+/// connector would decline. The captures of the attempt are only needed for the attempts captured
+/// in parts.
+#[instrument(skip_all)]
+pub fn validate_connector_refund_window(
+    refund_config: &settings::Refund,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    captures: &[storage::Capture],
+) -> RouterResult<()> {
+    let current_time = common_utils::date_time::now();
+    let refund_window_in_days = payment_attempt
+        .connector
+        .as_deref()
+        .and_then(|connector| get_connector_refund_window_in_days(refund_config, connector));
+
+    validate_refund_window(
+        refund_window_in_days,
+        get_refund_window_start(
+            payment_intent.status,
+            get_capture_time(payment_attempt, captures),
+            payment_attempt.expected_settlement_date,
+            current_time,
+        ),
+        current_time,
+    )
+}
+
 #[instrument(skip_all)]
 pub fn validate_maximum_refund_against_payment_attempt(
     all_refunds: &[storage::Refund],
@@ -170,3 +290,152 @@ pub fn validate_charge_refund(
         ),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::collections::HashMap;
+
+    use time::macros::{date, datetime};
+
+    use super::*;
+
+    #[test]
+    fn test_refund_window_starts_at_the_capture() {
+        let captured_at = datetime!(2024-06-01 10:00);
+        let current_time = datetime!(2024-10-01 10:00);
+
+        assert_eq!(
+            get_refund_window_start(
+                enums::IntentStatus::Succeeded,
+                captured_at,
+                Some(date!(2024 - 06 - 03)),
+                current_time
+            ),
+            captured_at
+        );
+        // A partial capture starts the window once it is settled
+        assert_eq!(
+            get_refund_window_start(
+                enums::IntentStatus::PartiallyCaptured,
+                captured_at,
+                Some(date!(2024 - 06 - 03)),
+                current_time
+            ),
+            datetime!(2024-06-03 00:00)
+        );
+        // A settlement date in the future does not lengthen the window
+        assert_eq!(
+            get_refund_window_start(
+                enums::IntentStatus::PartiallyCapturedAndCapturable,
+                captured_at,
+                Some(date!(2024 - 10 - 03)),
+                datetime!(2024-10-01 10:00)
+            ),
+            captured_at
+        );
+        assert_eq!(
+            get_refund_window_start(
+                enums::IntentStatus::PartiallyCaptured,
+                captured_at,
+                None,
+                current_time
+            ),
+            captured_at
+        );
+    }
+
+    fn get_capture(
+        capture_sequence: i16,
+        status: enums::CaptureStatus,
+        modified_at: PrimitiveDateTime,
+    ) -> storage::Capture {
+        storage::Capture {
+            capture_id: format!("capture_{capture_sequence}"),
+            payment_id: common_utils::id_type::PaymentId::default(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            status,
+            amount: common_utils::types::MinorUnit::new(100),
+            currency: Some(enums::Currency::USD),
+            connector: "stripe".to_string(),
+            error_message: None,
+            error_code: None,
+            error_reason: None,
+            tax_amount: None,
+            created_at: datetime!(2024-06-01 10:00),
+            modified_at,
+            authorized_attempt_id: "attempt_1".to_string(),
+            connector_capture_id: None,
+            capture_sequence,
+            connector_response_reference_id: None,
+        }
+    }
+
+    #[test]
+    fn test_capture_time_is_the_time_of_the_first_successful_capture() {
+        let captures = [
+            get_capture(1, enums::CaptureStatus::Failed, datetime!(2024-06-02 10:00)),
+            get_capture(
+                2,
+                enums::CaptureStatus::Charged,
+                datetime!(2024-06-05 10:00),
+            ),
+            get_capture(
+                3,
+                enums::CaptureStatus::Charged,
+                datetime!(2024-06-03 10:00),
+            ),
+        ];
+
+        assert_eq!(
+            get_first_capture_time(&captures),
+            Some(datetime!(2024-06-03 10:00))
+        );
+        assert_eq!(get_first_capture_time(&captures[..1]), None);
+    }
+
+    #[test]
+    fn test_connector_refund_window_prefers_the_configured_window() {
+        let refund_config = settings::Refund {
+            connector_max_age: HashMap::from([("authorizedotnet".to_string(), 90)]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            get_connector_refund_window_in_days(&refund_config, "authorizedotnet"),
+            Some(90)
+        );
+        assert_eq!(
+            get_connector_refund_window_in_days(&refund_config, "paypal"),
+            Some(180)
+        );
+        assert_eq!(
+            get_connector_refund_window_in_days(&refund_config, "stripe"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_refund_window_is_validated_against_the_window_of_the_connector() {
+        let refund_window_start = datetime!(2024-06-01 10:00);
+
+        assert!(validate_refund_window(
+            Some(120),
+            refund_window_start,
+            datetime!(2024-09-29 10:00)
+        )
+        .is_ok());
+        assert!(matches!(
+            validate_refund_window(Some(120), refund_window_start, datetime!(2024-10-01 10:00))
+                .unwrap_err()
+                .current_context(),
+            errors::ApiErrorResponse::RefundWindowExpired {
+                refund_window_in_days: 120
+            }
+        ));
+        // Without a window at the connector, the refunds are not rejected
+        assert!(
+            validate_refund_window(None, refund_window_start, datetime!(2030-01-01 10:00)).is_ok()
+        );
+    }
+}
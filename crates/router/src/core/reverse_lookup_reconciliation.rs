@@ -0,0 +1,112 @@
+//! The recurring reconciliation of the reverse lookups stored in redis with the ones stored in the
+//! database.
+//!
+//! Every run of the process tracker task reconciles a page of the reverse lookups found by scanning
+//! redis, and records the cursor of the next page in the task, so that the whole keyspace is
+//! reconciled over a number of runs without a run ever scanning all of it.
+
+use common_utils::{date_time, ext_traits::ValueExt};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    configs::settings::ReverseLookupReconciliation,
+    core::{
+        errors::{self, RouterResult},
+        utils,
+    },
+    db::StorageInterface,
+    routes::{metrics, SessionState},
+    types::storage::{
+        self,
+        reverse_lookup::{
+            ReverseLookupMismatchCategory, ReverseLookupReconciliationReport,
+            ReverseLookupReconciliationScope, ReverseLookupReconciliationTrackingData,
+            ReverseLookupSelection,
+        },
+    },
+};
+
+const REVERSE_LOOKUP_RECONCILIATION_TASK: &str = "REVERSE_LOOKUP_RECONCILIATION";
+const REVERSE_LOOKUP_RECONCILIATION_TAG: &str = "REVERSE_LOOKUP";
+const FIRST_SCAN_CURSOR: &str = "0";
+
+/// The time of the next run of the reconciliation
+pub fn get_next_schedule_time(config: &ReverseLookupReconciliation) -> time::PrimitiveDateTime {
+    date_time::now().saturating_add(time::Duration::seconds(i64::from(config.interval_in_secs)))
+}
+
+/// Schedule the recurring task which reconciles the reverse lookups. A single task is scheduled for
+/// the store, which reschedules itself after every run.
+#[instrument(skip_all)]
+pub async fn add_reconciliation_task(
+    db: &dyn StorageInterface,
+    config: &ReverseLookupReconciliation,
+) -> RouterResult<()> {
+    utils::add_recurring_process_tracker_task(
+        db,
+        storage::ProcessTrackerRunner::ReverseLookupReconciliationWorkflow,
+        REVERSE_LOOKUP_RECONCILIATION_TASK,
+        REVERSE_LOOKUP_RECONCILIATION_TAG,
+        ReverseLookupReconciliationTrackingData::default(),
+        get_next_schedule_time(config),
+        "ReverseLookupReconciliation",
+    )
+    .await
+}
+
+/// Reconcile the page of the reverse lookups following the cursor recorded in the tracking data of
+/// the task, returning the tracking data of the next run
+#[instrument(skip_all)]
+pub async fn reconcile_next_page(
+    state: &SessionState,
+    process: &storage::ProcessTracker,
+) -> RouterResult<ReverseLookupReconciliationTrackingData> {
+    let config = &state.conf.reverse_lookup_reconciliation;
+    let tracking_data: ReverseLookupReconciliationTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("ReverseLookupReconciliationTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let scope = ReverseLookupReconciliationScope {
+        lookups: ReverseLookupSelection::RedisScan {
+            cursor: tracking_data
+                .cursor
+                .unwrap_or_else(|| FIRST_SCAN_CURSOR.to_string()),
+            count: config.scan_count,
+        },
+        repair: config.repair,
+    };
+    let report = state
+        .store
+        .reconcile_reverse_lookups(scope)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to reconcile the reverse lookups")?;
+    log_reconciliation_report(&report);
+
+    Ok(ReverseLookupReconciliationTrackingData {
+        cursor: report.next_cursor,
+    })
+}
+
+fn log_reconciliation_report(report: &ReverseLookupReconciliationReport) {
+    let missing_in_database =
+        report.get_mismatch_count(ReverseLookupMismatchCategory::MissingInDatabase);
+    let value_mismatches = report.get_mismatch_count(ReverseLookupMismatchCategory::ValueMismatch);
+    metrics::REVERSE_LOOKUP_MISMATCHES.add(
+        &metrics::CONTEXT,
+        u64::try_from(report.mismatches.len()).unwrap_or(u64::MAX),
+        &[],
+    );
+    logger::info!(
+        checked = report.checked,
+        expired_in_redis = report.expired_in_redis,
+        pending_drain = report.pending_drain.len(),
+        missing_in_database,
+        value_mismatches,
+        repaired = report.get_repaired_count(),
+        "Reconciled a page of the reverse lookups"
+    );
+}
@@ -26,10 +26,11 @@ use crate::{
 };
 use crate::{
     core::{
+        audit_log,
         errors::{self, RouterResponse, StorageErrorExt},
         metrics, utils as core_utils,
     },
-    routes::SessionState,
+    routes::{app::ReqState, SessionState},
     services::api as service_api,
     types::{
         domain,
@@ -159,6 +160,7 @@ pub async fn create_routing_algorithm_under_profile(
     authentication_profile_id: Option<common_utils::id_type::ProfileId>,
     request: routing_types::RoutingConfigRequest,
     transaction_type: &enums::TransactionType,
+    req_state: ReqState,
 ) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
     metrics::ROUTING_CREATE_REQUEST_RECEIVED.add(&metrics::CONTEXT, 1, &[]);
     let db = &*state.store;
@@ -208,12 +210,13 @@ pub async fn create_routing_algorithm_under_profile(
         transaction_type,
     );
 
-    let record = state
-        .store
-        .as_ref()
-        .insert_routing_algorithm(algo.0)
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+    let record = helpers::insert_routing_algorithm(
+        state.store.as_ref(),
+        algo.0,
+        audit_log::get_audit_log_context(&state, &req_state),
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
     let new_record = record.foreign_into();
 
@@ -229,6 +232,7 @@ pub async fn create_routing_algorithm_under_profile(
     authentication_profile_id: Option<common_utils::id_type::ProfileId>,
     request: routing_types::RoutingConfigRequest,
     transaction_type: &enums::TransactionType,
+    req_state: ReqState,
 ) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
     metrics::ROUTING_CREATE_REQUEST_RECEIVED.add(&metrics::CONTEXT, 1, &[]);
     let db = state.store.as_ref();
@@ -300,10 +304,13 @@ pub async fn create_routing_algorithm_under_profile(
         modified_at: timestamp,
         algorithm_for: transaction_type.to_owned(),
     };
-    let record = db
-        .insert_routing_algorithm(algo)
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+    let record = helpers::insert_routing_algorithm(
+        db,
+        algo,
+        audit_log::get_audit_log_context(&state, &req_state),
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
     let new_record = record.foreign_into();
 
@@ -389,6 +396,7 @@ pub async fn link_routing_config(
     authentication_profile_id: Option<common_utils::id_type::ProfileId>,
     algorithm_id: common_utils::id_type::RoutingId,
     transaction_type: &enums::TransactionType,
+    req_state: ReqState,
 ) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
     metrics::ROUTING_LINK_CONFIG.add(&metrics::CONTEXT, 1, &[]);
     let db = state.store.as_ref();
@@ -453,6 +461,7 @@ pub async fn link_routing_config(
                 &key_store,
                 business_profile,
                 dynamic_routing_ref,
+                audit_log::get_audit_log_context(&state, &req_state),
             )
             .await?;
         }
@@ -496,6 +505,7 @@ pub async fn link_routing_config(
                 business_profile,
                 routing_ref,
                 transaction_type,
+                audit_log::get_audit_log_context(&state, &req_state),
             )
             .await?;
         }
@@ -584,6 +594,84 @@ pub async fn retrieve_routing_algorithm_from_algorithm_id(
     Ok(service_api::ApplicationResponse::Json(response))
 }
 
+/// Retrieve the share of the traffic which each connector of a volume split routing algorithm
+/// currently receives, considering the connectors which are disabled or in maintenance as
+/// unavailable
+#[cfg(feature = "v1")]
+pub async fn retrieve_routing_algorithm_effective_weights(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    algorithm_id: common_utils::id_type::RoutingId,
+) -> RouterResponse<routing_types::RoutingEffectiveWeightsResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(
+            &algorithm_id,
+            merchant_account.get_id(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        &key_store,
+        Some(&routing_algorithm.profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let routing_algorithm =
+        routing_types::MerchantRoutingAlgorithm::foreign_try_from(routing_algorithm)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("unable to parse routing algorithm")?;
+
+    let routing_types::RoutingAlgorithm::VolumeSplit(splits) = routing_algorithm.algorithm else {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Effective weights are only available for volume split routing algorithms"
+                .to_string(),
+        }
+        .into());
+    };
+
+    let available_merchant_connector_ids = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            key_manager_state,
+            merchant_account.get_id(),
+            false,
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the merchant connector accounts of the merchant")?
+        .into_iter()
+        .filter(|mca| !mca.is_in_maintenance())
+        .map(|mca| mca.get_id())
+        .collect::<FxHashSet<_>>();
+
+    let weights = routing_types::ConnectorVolumeSplit::get_effective_weights(&splits, |choice| {
+        choice
+            .merchant_connector_id
+            .as_ref()
+            .map_or(true, |id| available_merchant_connector_ids.contains(id))
+    });
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingEffectiveWeightsResponse {
+            algorithm_id,
+            weights,
+        },
+    ))
+}
+
 #[cfg(feature = "v2")]
 pub async fn unlink_routing_config_under_profile(
     state: SessionState,
@@ -646,6 +734,7 @@ pub async fn unlink_routing_config(
     request: routing_types::RoutingConfigRequest,
     authentication_profile_id: Option<common_utils::id_type::ProfileId>,
     transaction_type: &enums::TransactionType,
+    req_state: ReqState,
 ) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
     metrics::ROUTING_UNLINK_CONFIG.add(&metrics::CONTEXT, 1, &[]);
 
@@ -713,6 +802,7 @@ pub async fn unlink_routing_config(
                         business_profile,
                         routing_algorithm,
                         transaction_type,
+                        audit_log::get_audit_log_context(&state, &req_state),
                     )
                     .await?;
 
@@ -813,6 +903,7 @@ pub async fn update_default_routing_config(
     merchant_account: domain::MerchantAccount,
     updated_config: Vec<routing_types::RoutableConnectorChoice>,
     transaction_type: &enums::TransactionType,
+    req_state: ReqState,
 ) -> RouterResponse<Vec<routing_types::RoutableConnectorChoice>> {
     metrics::ROUTING_UPDATE_CONFIG.add(&metrics::CONTEXT, 1, &[]);
     let db = state.store.as_ref();
@@ -848,11 +939,13 @@ pub async fn update_default_routing_config(
         })
     })?;
 
-    helpers::update_merchant_default_config(
+    helpers::update_merchant_default_config_with_audit_log(
         db,
+        merchant_account.get_id(),
         merchant_account.get_id().get_string_repr(),
         updated_config.clone(),
         transaction_type,
+        audit_log::get_audit_log_context(&state, &req_state),
     )
     .await?;
 
@@ -1080,6 +1173,7 @@ pub async fn update_default_routing_config_for_profile(
     updated_config: Vec<routing_types::RoutableConnectorChoice>,
     profile_id: common_utils::id_type::ProfileId,
     transaction_type: &enums::TransactionType,
+    req_state: ReqState,
 ) -> RouterResponse<routing_types::ProfileDefaultRoutingConfig> {
     metrics::ROUTING_UPDATE_CONFIG_FOR_PROFILE.add(&metrics::CONTEXT, 1, &[]);
 
@@ -1140,11 +1234,13 @@ pub async fn update_default_routing_config_for_profile(
         })
     })?;
 
-    helpers::update_merchant_default_config(
+    helpers::update_merchant_default_config_with_audit_log(
         db,
+        merchant_account.get_id(),
         business_profile.get_id().get_string_repr(),
         updated_config.clone(),
         transaction_type,
+        audit_log::get_audit_log_context(&state, &req_state),
     )
     .await?;
 
@@ -1164,6 +1260,7 @@ pub async fn toggle_success_based_routing(
     key_store: domain::MerchantKeyStore,
     status: bool,
     profile_id: common_utils::id_type::ProfileId,
+    req_state: ReqState,
 ) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
     metrics::ROUTING_CREATE_REQUEST_RECEIVED.add(
         &metrics::CONTEXT,
@@ -1215,11 +1312,14 @@ pub async fn toggle_success_based_routing(
             algorithm_for: common_enums::TransactionType::Payment,
         };
 
-        let record = db
-            .insert_routing_algorithm(algo)
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Unable to insert record in routing algorithm table")?;
+        let record = helpers::insert_routing_algorithm(
+            db,
+            algo,
+            audit_log::get_audit_log_context(&state, &req_state),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to insert record in routing algorithm table")?;
 
         success_based_dynamic_routing_algo_ref.update_algorithm_id(algorithm_id);
         helpers::update_business_profile_active_dynamic_algorithm_ref(
@@ -1228,6 +1328,7 @@ pub async fn toggle_success_based_routing(
             &key_store,
             business_profile,
             success_based_dynamic_routing_algo_ref,
+            audit_log::get_audit_log_context(&state, &req_state),
         )
         .await?;
 
@@ -1288,6 +1389,7 @@ pub async fn toggle_success_based_routing(
                         &key_store,
                         business_profile,
                         dynamic_routing_algorithm,
+                        audit_log::get_audit_log_context(&state, &req_state),
                     )
                     .await?;
 
@@ -1317,6 +1419,7 @@ pub async fn success_based_routing_update_configs(
     request: routing_types::SuccessBasedRoutingConfig,
     algorithm_id: common_utils::id_type::RoutingId,
     profile_id: common_utils::id_type::ProfileId,
+    req_state: ReqState,
 ) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
     metrics::ROUTING_UPDATE_CONFIG_FOR_PROFILE.add(
         &metrics::CONTEXT,
@@ -1352,11 +1455,14 @@ pub async fn success_based_routing_update_configs(
         modified_at: timestamp,
         algorithm_for: dynamic_routing_algo_to_update.algorithm_for,
     };
-    let record = db
-        .insert_routing_algorithm(algo)
-        .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Unable to insert record in routing algorithm table")?;
+    let record = helpers::insert_routing_algorithm(
+        db,
+        algo,
+        audit_log::get_audit_log_context(&state, &req_state),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Unable to insert record in routing algorithm table")?;
 
     // redact cache for success based routing configs
     let cache_key = format!(
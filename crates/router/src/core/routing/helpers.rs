@@ -104,6 +104,47 @@ pub async fn update_merchant_default_config(
     Ok(())
 }
 
+/// Update the default config of the merchant or the profile, recording the change in the audit
+/// log if it is enabled
+pub async fn update_merchant_default_config_with_audit_log(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    // The id of the merchant or the profile whose default config is updated
+    config_owner_id: &str,
+    connectors: Vec<routing_types::RoutableConnectorChoice>,
+    transaction_type: &storage::enums::TransactionType,
+    audit_log: Option<storage::AuditLogContext>,
+) -> RouterResult<()> {
+    let Some(audit_log) = audit_log else {
+        return update_merchant_default_config(db, config_owner_id, connectors, transaction_type)
+            .await;
+    };
+
+    let key = get_default_config_key(config_owner_id, transaction_type);
+    let config_str = connectors
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize merchant default routing config during update")?;
+
+    let config_update = configs::ConfigUpdate::Update {
+        config: Some(config_str),
+    };
+
+    db.update_config_by_key_with_audit_log(
+        &key,
+        config_update,
+        merchant_id,
+        storage::enums::AuditLogResourceType::DefaultRoutingConfig,
+        config_owner_id,
+        audit_log,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Error updating the default routing config in DB")?;
+
+    Ok(())
+}
+
 pub async fn update_merchant_routing_dictionary(
     db: &dyn StorageInterface,
     merchant_id: &str,
@@ -162,6 +203,17 @@ pub async fn update_merchant_active_algorithm_ref(
         default_profile: None,
         payment_link_config: None,
         pm_collect_link_config: None,
+        idempotency_ttl_in_seconds: None,
+        customer_pii_encryption_fields: None,
+        statement_descriptor_length_policy: None,
+        allowed_return_url_domains: None,
+        connector_failover_order: None,
+        default_capture_method: None,
+        least_cost_routing: None,
+        transaction_amount_limits: None,
+        default_currency: None,
+        require_explicit_currency: None,
+        default_connector_by_method_type: None,
     };
 
     let db = &*state.store;
@@ -202,6 +254,7 @@ pub async fn update_profile_active_algorithm_ref(
     current_business_profile: domain::Profile,
     algorithm_id: routing_types::RoutingAlgorithmRef,
     transaction_type: &storage::enums::TransactionType,
+    audit_log: Option<storage::AuditLogContext>,
 ) -> RouterResult<()> {
     let ref_val = algorithm_id
         .encode_to_value()
@@ -232,11 +285,13 @@ pub async fn update_profile_active_algorithm_ref(
         payout_routing_algorithm,
     };
 
-    db.update_profile_by_profile_id(
+    update_profile_routing(
+        db,
         key_manager_state,
         merchant_key_store,
         current_business_profile,
         business_profile_update,
+        audit_log,
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -256,6 +311,7 @@ pub async fn update_business_profile_active_dynamic_algorithm_ref(
     merchant_key_store: &domain::MerchantKeyStore,
     current_business_profile: domain::Profile,
     dynamic_routing_algorithm: routing_types::DynamicRoutingAlgorithmRef,
+    audit_log: Option<storage::AuditLogContext>,
 ) -> RouterResult<()> {
     let ref_val = dynamic_routing_algorithm
         .encode_to_value()
@@ -264,11 +320,13 @@ pub async fn update_business_profile_active_dynamic_algorithm_ref(
     let business_profile_update = domain::ProfileUpdate::DynamicRoutingAlgorithmUpdate {
         dynamic_routing_algorithm: Some(ref_val),
     };
-    db.update_profile_by_profile_id(
+    update_profile_routing(
+        db,
         key_manager_state,
         merchant_key_store,
         current_business_profile,
         business_profile_update,
+        audit_log,
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -276,6 +334,55 @@ pub async fn update_business_profile_active_dynamic_algorithm_ref(
     Ok(())
 }
 
+/// Update the routing configuration of the profile, recording the change in the audit log if it
+/// is enabled
+#[cfg(feature = "v1")]
+async fn update_profile_routing(
+    db: &dyn StorageInterface,
+    key_manager_state: &KeyManagerState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    current_business_profile: domain::Profile,
+    business_profile_update: domain::ProfileUpdate,
+    audit_log: Option<storage::AuditLogContext>,
+) -> errors::CustomResult<domain::Profile, errors::StorageError> {
+    match audit_log {
+        Some(audit_log) => {
+            db.update_profile_routing_with_audit_log(
+                key_manager_state,
+                merchant_key_store,
+                current_business_profile.get_id(),
+                business_profile_update,
+                audit_log,
+            )
+            .await
+        }
+        None => {
+            db.update_profile_by_profile_id(
+                key_manager_state,
+                merchant_key_store,
+                current_business_profile,
+                business_profile_update,
+            )
+            .await
+        }
+    }
+}
+
+/// Insert the routing algorithm, recording its creation in the audit log if it is enabled
+pub async fn insert_routing_algorithm(
+    db: &dyn StorageInterface,
+    routing_algorithm: storage::RoutingAlgorithm,
+    audit_log: Option<storage::AuditLogContext>,
+) -> errors::CustomResult<storage::RoutingAlgorithm, errors::StorageError> {
+    match audit_log {
+        Some(audit_log) => {
+            db.insert_routing_algorithm_with_audit_log(routing_algorithm, audit_log)
+                .await
+        }
+        None => db.insert_routing_algorithm(routing_algorithm).await,
+    }
+}
+
 #[cfg(feature = "v2")]
 #[derive(Clone, Debug)]
 pub struct RoutingAlgorithmHelpers<'h> {
@@ -816,6 +923,7 @@ fn get_desired_payment_status_for_success_routing_metrics(
     match attempt_status {
         common_enums::AttemptStatus::Charged
         | common_enums::AttemptStatus::Authorized
+        | common_enums::AttemptStatus::PartiallyAuthorized
         | common_enums::AttemptStatus::PartialCharged
         | common_enums::AttemptStatus::PartialChargedAndChargeable => {
             common_enums::AttemptStatus::Charged
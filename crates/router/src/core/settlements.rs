@@ -0,0 +1,302 @@
+//! Reconciliation of the settlement files received from connectors against our records of the
+//! settled payments and refunds.
+
+use std::collections::HashSet;
+
+use api_models::settlements as settlements_api;
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, DataStorageError, RouterResponse, RouterResult},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, storage, storage::enums as storage_enums, transformers::ForeignFrom},
+};
+
+const UNMATCHED_LINE_ITEMS_LIST_MAX_LIMIT: i64 = 100;
+
+struct ReconciledTransaction {
+    payment_id: Option<common_utils::id_type::PaymentId>,
+    attempt_id: Option<String>,
+    refund_id: Option<String>,
+    reconciliation_status: storage_enums::SettlementReconciliationStatus,
+}
+
+impl ReconciledTransaction {
+    fn orphan() -> Self {
+        Self {
+            payment_id: None,
+            attempt_id: None,
+            refund_id: None,
+            reconciliation_status: storage_enums::SettlementReconciliationStatus::Orphan,
+        }
+    }
+}
+
+fn get_reconciliation_status(is_matched: bool) -> storage_enums::SettlementReconciliationStatus {
+    if is_matched {
+        storage_enums::SettlementReconciliationStatus::Matched
+    } else {
+        storage_enums::SettlementReconciliationStatus::Unmatched
+    }
+}
+
+async fn reconcile_line_item(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    connector_name: &str,
+    line_item: &settlements_api::SettlementLineItem,
+) -> RouterResult<ReconciledTransaction> {
+    let db = &*state.store;
+    match line_item.transaction_type {
+        storage_enums::SettlementTransactionType::Payment => {
+            let payment_attempt = db
+                .find_payment_attempt_by_merchant_id_connector_txn_id(
+                    merchant_account.get_id(),
+                    &line_item.connector_transaction_id,
+                    merchant_account.storage_scheme,
+                )
+                .await;
+            match payment_attempt {
+                Ok(payment_attempt) => {
+                    let is_matched = payment_attempt.connector.as_deref() == Some(connector_name)
+                        && payment_attempt.currency == Some(line_item.currency)
+                        && payment_attempt
+                            .amount_to_capture
+                            .unwrap_or(payment_attempt.net_amount)
+                            == line_item.amount;
+                    Ok(ReconciledTransaction {
+                        payment_id: Some(payment_attempt.payment_id),
+                        attempt_id: Some(payment_attempt.attempt_id),
+                        refund_id: None,
+                        reconciliation_status: get_reconciliation_status(is_matched),
+                    })
+                }
+                Err(error)
+                    if matches!(error.current_context(), DataStorageError::ValueNotFound(_)) =>
+                {
+                    Ok(ReconciledTransaction::orphan())
+                }
+                Err(error) => Err(error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to find the payment attempt of the line item")),
+            }
+        }
+        storage_enums::SettlementTransactionType::Refund => {
+            let refund = db
+                .find_refund_by_merchant_id_connector_refund_id_connector(
+                    merchant_account.get_id(),
+                    &line_item.connector_transaction_id,
+                    connector_name,
+                    merchant_account.storage_scheme,
+                )
+                .await;
+            match refund {
+                Ok(refund) => {
+                    let is_matched = refund.currency == line_item.currency
+                        && refund.refund_amount == line_item.amount;
+                    Ok(ReconciledTransaction {
+                        payment_id: Some(refund.payment_id),
+                        attempt_id: Some(refund.attempt_id),
+                        refund_id: Some(refund.refund_id),
+                        reconciliation_status: get_reconciliation_status(is_matched),
+                    })
+                }
+                Err(error) if error.current_context().is_db_not_found() => {
+                    Ok(ReconciledTransaction::orphan())
+                }
+                Err(error) => Err(error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to find the refund of the line item")),
+            }
+        }
+    }
+}
+
+/// Drop the line items which are repeated within the settlement file, keeping the last occurrence
+/// of each line item identifier. A line item can only be upserted once per statement.
+fn dedup_line_items(
+    line_items: Vec<settlements_api::SettlementLineItem>,
+) -> Vec<settlements_api::SettlementLineItem> {
+    let mut seen_line_item_ids = HashSet::with_capacity(line_items.len());
+    let mut deduplicated_line_items = line_items
+        .into_iter()
+        .rev()
+        .filter(|line_item| seen_line_item_ids.insert(line_item.line_item_id.clone()))
+        .collect::<Vec<_>>();
+    deduplicated_line_items.reverse();
+    deduplicated_line_items
+}
+
+/// Reconcile the line items of a settlement file against the payments and refunds processed
+/// through the connector, and store the outcome.
+///
+/// Line items are linked to our records through the connector's transaction identifier. Line items
+/// referencing a transaction we have no record of are stored as orphans for manual review instead
+/// of failing the ingestion of the file.
+#[instrument(skip_all)]
+pub async fn ingest_settlement_file(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: settlements_api::SettlementFileIngestRequest,
+) -> RouterResponse<settlements_api::SettlementFileIngestResponse> {
+    let connector_name = req.connector.to_string();
+    let line_items = dedup_line_items(req.line_items);
+
+    let mut reconciled_line_items = Vec::with_capacity(line_items.len());
+    for line_item in line_items {
+        let reconciled_transaction =
+            reconcile_line_item(&state, &merchant_account, &connector_name, &line_item).await?;
+        let now = common_utils::date_time::now();
+        reconciled_line_items.push(storage::SettlementReconciliationNew {
+            merchant_id: merchant_account.get_id().to_owned(),
+            connector_name: connector_name.clone(),
+            settlement_id: req.settlement_id.clone(),
+            line_item_id: line_item.line_item_id,
+            transaction_type: line_item.transaction_type,
+            connector_transaction_id: line_item.connector_transaction_id,
+            amount: line_item.amount,
+            currency: line_item.currency,
+            settled_at: line_item.settled_at,
+            payment_id: reconciled_transaction.payment_id,
+            attempt_id: reconciled_transaction.attempt_id,
+            refund_id: reconciled_transaction.refund_id,
+            reconciliation_status: reconciled_transaction.reconciliation_status,
+            created_at: now,
+            modified_at: now,
+        });
+    }
+
+    let line_items = state
+        .store
+        .ingest_settlement_line_items(reconciled_line_items)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store the settlement line items")?;
+
+    Ok(ApplicationResponse::Json(
+        settlements_api::SettlementFileIngestResponse {
+            line_items: line_items
+                .into_iter()
+                .map(settlements_api::SettlementLineItemResponse::foreign_from)
+                .collect(),
+        },
+    ))
+}
+
+/// List the settlement line items of the merchant which need manual review
+#[instrument(skip_all)]
+pub async fn list_unmatched_settlement_line_items(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: settlements_api::SettlementLineItemsUnmatchedListRequest,
+) -> RouterResponse<settlements_api::SettlementLineItemsUnmatchedListResponse> {
+    let limit = match req.limit {
+        Some(limit) if (1..=UNMATCHED_LINE_ITEMS_LIST_MAX_LIMIT).contains(&limit) => Ok(limit),
+        Some(_) => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`limit` must be a number between 1 and {UNMATCHED_LINE_ITEMS_LIST_MAX_LIMIT}"
+            ),
+        }),
+        None => Ok(UNMATCHED_LINE_ITEMS_LIST_MAX_LIMIT),
+    }?;
+    let offset = req.offset.unwrap_or_default().max(0);
+
+    let line_items = state
+        .store
+        .list_unmatched_settlement_line_items(merchant_account.get_id(), limit, offset)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the unmatched settlement line items")?;
+
+    Ok(ApplicationResponse::Json(
+        settlements_api::SettlementLineItemsUnmatchedListResponse {
+            line_items: line_items
+                .into_iter()
+                .map(settlements_api::SettlementLineItemResponse::foreign_from)
+                .collect(),
+        },
+    ))
+}
+
+impl ForeignFrom<storage::SettlementReconciliation>
+    for settlements_api::SettlementLineItemResponse
+{
+    fn foreign_from(line_item: storage::SettlementReconciliation) -> Self {
+        Self {
+            connector_name: line_item.connector_name,
+            settlement_id: line_item.settlement_id,
+            line_item_id: line_item.line_item_id,
+            transaction_type: line_item.transaction_type,
+            connector_transaction_id: line_item.connector_transaction_id,
+            amount: line_item.amount,
+            currency: line_item.currency,
+            settled_at: line_item.settled_at,
+            payment_id: line_item.payment_id,
+            attempt_id: line_item.attempt_id,
+            refund_id: line_item.refund_id,
+            reconciliation_status: line_item.reconciliation_status,
+            created_at: line_item.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common_utils::types::MinorUnit;
+
+    use super::*;
+
+    fn line_item(line_item_id: &str, amount: i64) -> settlements_api::SettlementLineItem {
+        settlements_api::SettlementLineItem {
+            line_item_id: line_item_id.to_string(),
+            transaction_type: storage_enums::SettlementTransactionType::Payment,
+            connector_transaction_id: format!("txn_{line_item_id}"),
+            amount: MinorUnit::new(amount),
+            currency: storage_enums::Currency::USD,
+            settled_at: None,
+        }
+    }
+
+    #[test]
+    fn test_dedup_line_items_keeps_last_occurrence() {
+        let line_items = dedup_line_items(vec![
+            line_item("li_1", 100),
+            line_item("li_2", 200),
+            line_item("li_1", 150),
+        ]);
+
+        let deduplicated = line_items
+            .iter()
+            .map(|line_item| (line_item.line_item_id.as_str(), line_item.amount))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            deduplicated,
+            vec![("li_2", MinorUnit::new(200)), ("li_1", MinorUnit::new(150))]
+        );
+    }
+
+    #[test]
+    fn test_dedup_line_items_without_repeats_is_unchanged() {
+        let line_items = dedup_line_items(vec![line_item("li_1", 100), line_item("li_2", 200)]);
+
+        let line_item_ids = line_items
+            .iter()
+            .map(|line_item| line_item.line_item_id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(line_item_ids, vec!["li_1", "li_2"]);
+    }
+
+    #[test]
+    fn test_get_reconciliation_status() {
+        assert_eq!(
+            get_reconciliation_status(true),
+            storage_enums::SettlementReconciliationStatus::Matched
+        );
+        assert_eq!(
+            get_reconciliation_status(false),
+            storage_enums::SettlementReconciliationStatus::Unmatched
+        );
+    }
+}
@@ -170,6 +170,7 @@ pub async fn construct_payout_router_data<'a, F>(
         auth_type: enums::AuthenticationType::default(),
         connector_meta_data: merchant_connector_account.get_metadata(),
         connector_wallets_details: merchant_connector_account.get_connector_wallets_details(),
+        connector_request_timeout_secs: merchant_connector_account.get_connector_request_timeout(),
         amount_captured: None,
         minor_amount_captured: None,
         payment_method_status: None,
@@ -209,6 +210,7 @@ pub async fn construct_payout_router_data<'a, F>(
         connector_api_version: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_network_retry_count: None,
         apple_pay_flow: None,
         frm_metadata: None,
         refund_id: None,
@@ -273,6 +275,16 @@ pub async fn construct_refund_router_data<'a, F>(
     )
     .await?;
 
+    merchant_connector_account
+        .get_environment()
+        .map(|environment| {
+            helpers::validate_connector_environment(
+                environment,
+                merchant_connector_account.is_test_mode_on(),
+            )
+        })
+        .transpose()?;
+
     let auth_type: types::ConnectorAuthType = merchant_connector_account
         .get_connector_account_details()
         .parse_value("ConnectorAuthType")
@@ -342,6 +354,7 @@ pub async fn construct_refund_router_data<'a, F>(
         auth_type: payment_attempt.authentication_type.unwrap_or_default(),
         connector_meta_data: merchant_connector_account.get_metadata(),
         connector_wallets_details: merchant_connector_account.get_connector_wallets_details(),
+        connector_request_timeout_secs: merchant_connector_account.get_connector_request_timeout(),
         amount_captured: payment_intent
             .amount_captured
             .map(|amt| amt.get_amount_as_i64()),
@@ -385,6 +398,7 @@ pub async fn construct_refund_router_data<'a, F>(
         connector_api_version,
         connector_http_status_code: None,
         external_latency: None,
+        connector_network_retry_count: None,
         apple_pay_flow: None,
         frm_metadata: None,
         refund_id: Some(refund.refund_id.clone()),
@@ -663,6 +677,7 @@ pub async fn construct_accept_dispute_router_data<'a>(
         auth_type: payment_attempt.authentication_type.unwrap_or_default(),
         connector_meta_data: merchant_connector_account.get_metadata(),
         connector_wallets_details: merchant_connector_account.get_connector_wallets_details(),
+        connector_request_timeout_secs: merchant_connector_account.get_connector_request_timeout(),
         amount_captured: payment_intent
             .amount_captured
             .map(|amt| amt.get_amount_as_i64()),
@@ -695,6 +710,7 @@ pub async fn construct_accept_dispute_router_data<'a>(
         connector_api_version: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_network_retry_count: None,
         apple_pay_flow: None,
         frm_metadata: None,
         dispute_id: Some(dispute.dispute_id.clone()),
@@ -761,6 +777,7 @@ pub async fn construct_submit_evidence_router_data<'a>(
         auth_type: payment_attempt.authentication_type.unwrap_or_default(),
         connector_meta_data: merchant_connector_account.get_metadata(),
         connector_wallets_details: merchant_connector_account.get_connector_wallets_details(),
+        connector_request_timeout_secs: merchant_connector_account.get_connector_request_timeout(),
         amount_captured: payment_intent
             .amount_captured
             .map(|amt| amt.get_amount_as_i64()),
@@ -790,6 +807,7 @@ pub async fn construct_submit_evidence_router_data<'a>(
         connector_api_version: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_network_retry_count: None,
         apple_pay_flow: None,
         frm_metadata: None,
         refund_id: None,
@@ -857,6 +875,7 @@ pub async fn construct_upload_file_router_data<'a>(
         auth_type: payment_attempt.authentication_type.unwrap_or_default(),
         connector_meta_data: merchant_connector_account.get_metadata(),
         connector_wallets_details: merchant_connector_account.get_connector_wallets_details(),
+        connector_request_timeout_secs: merchant_connector_account.get_connector_request_timeout(),
         amount_captured: payment_intent
             .amount_captured
             .map(|amt| amt.get_amount_as_i64()),
@@ -891,6 +910,7 @@ pub async fn construct_upload_file_router_data<'a>(
         connector_api_version: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_network_retry_count: None,
         apple_pay_flow: None,
         frm_metadata: None,
         refund_id: None,
@@ -981,6 +1001,7 @@ pub async fn construct_payments_dynamic_tax_calculation_router_data<'a, F: Clone
         auth_type: payment_attempt.authentication_type.unwrap_or_default(),
         connector_meta_data: None,
         connector_wallets_details: None,
+        connector_request_timeout_secs: None,
         amount_captured: None,
         access_token: None,
         session_token: None,
@@ -1010,6 +1031,7 @@ pub async fn construct_payments_dynamic_tax_calculation_router_data<'a, F: Clone
         test_mode,
         connector_http_status_code: None,
         external_latency: None,
+        connector_network_retry_count: None,
         apple_pay_flow: None,
         frm_metadata: None,
         refund_id: None,
@@ -1078,6 +1100,7 @@ pub async fn construct_defend_dispute_router_data<'a>(
         auth_type: payment_attempt.authentication_type.unwrap_or_default(),
         connector_meta_data: merchant_connector_account.get_metadata(),
         connector_wallets_details: merchant_connector_account.get_connector_wallets_details(),
+        connector_request_timeout_secs: merchant_connector_account.get_connector_request_timeout(),
         amount_captured: payment_intent
             .amount_captured
             .map(|amt| amt.get_amount_as_i64()),
@@ -1110,6 +1133,7 @@ pub async fn construct_defend_dispute_router_data<'a>(
         connector_api_version: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_network_retry_count: None,
         apple_pay_flow: None,
         frm_metadata: None,
         refund_id: None,
@@ -1174,6 +1198,7 @@ pub async fn construct_retrieve_file_router_data<'a>(
         auth_type: diesel_models::enums::AuthenticationType::default(),
         connector_meta_data: merchant_connector_account.get_metadata(),
         connector_wallets_details: merchant_connector_account.get_connector_wallets_details(),
+        connector_request_timeout_secs: merchant_connector_account.get_connector_request_timeout(),
         amount_captured: None,
         minor_amount_captured: None,
         payment_method_status: None,
@@ -1202,6 +1227,7 @@ pub async fn construct_retrieve_file_router_data<'a>(
         connector_api_version: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_network_retry_count: None,
         apple_pay_flow: None,
         frm_metadata: None,
         refund_id: None,
@@ -1645,3 +1671,64 @@ pub(crate) fn validate_profile_id_from_auth_layer<T: GetProfileId + std::fmt::De
         (None, None) | (None, Some(_)) => Ok(()),
     }
 }
+
+/// Schedule a recurring process tracker task, of which a single task is scheduled for the store and
+/// reschedules itself after every run. The task scheduled by an earlier start of the scheduler is
+/// left as it is, unless it was finished while it was disabled, in which case it is rescheduled.
+#[instrument(skip_all)]
+pub async fn add_recurring_process_tracker_task<T>(
+    db: &dyn StorageInterface,
+    runner: storage::ProcessTrackerRunner,
+    task: &'static str,
+    tag: &'static str,
+    tracking_data: T,
+    schedule_time: time::PrimitiveDateTime,
+    flow: &'static str,
+) -> RouterResult<()>
+where
+    T: serde::Serialize + std::fmt::Debug,
+{
+    let process_tracker_id = format!("{runner}_{task}");
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id.clone(),
+        task,
+        runner,
+        [tag],
+        tracking_data,
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable_lazy(|| format!("Failed to construct {task} process tracker task"))?;
+
+    match db.insert_process(process_tracker_entry).await {
+        Ok(_) => {
+            crate::routes::metrics::TASKS_ADDED_COUNT.add(
+                &crate::routes::metrics::CONTEXT,
+                1,
+                &router_env::metrics::add_attributes([("flow", flow)]),
+            );
+            Ok(())
+        }
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            let process = db
+                .find_process_by_id(&process_tracker_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| format!("Failed to fetch the {task} task"))?;
+
+            match process {
+                Some(process) if process.status == enums::ProcessTrackerStatus::Finish => db
+                    .reset_process(process, schedule_time)
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable_lazy(|| format!("Failed to reschedule the {task} task")),
+                Some(_) | None => Ok(()),
+            }
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!("Failed while inserting {task} task to process_tracker")
+            }),
+    }
+}
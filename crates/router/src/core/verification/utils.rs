@@ -81,6 +81,9 @@ pub async fn check_existence_and_add_domain_to_db(
         connector_label: None,
         status: None,
         connector_wallets_details: None,
+        request_timeout_secs: None,
+        environment: None,
+        maintenance_windows: None,
         additional_merchant_data: None,
     };
     #[cfg(feature = "v2")]
@@ -97,6 +100,9 @@ pub async fn check_existence_and_add_domain_to_db(
         connector_label: None,
         status: None,
         connector_wallets_details: None,
+        request_timeout_secs: None,
+        environment: None,
+        maintenance_windows: None,
         additional_merchant_data: None,
     };
     state
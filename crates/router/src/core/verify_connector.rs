@@ -1,15 +1,35 @@
-use api_models::{enums::Connector, verify_connector::VerifyConnectorRequest};
+use std::str::FromStr;
+
+use api_models::{
+    enums::Connector,
+    verify_connector::{
+        TokenizationTestRequest, TokenizationTestResponse, TokenizationTestStep,
+        TokenizationTestStepResult, TokenizationTestStepStatus, VerifyConnectorRequest,
+    },
+};
+use common_utils::{
+    ext_traits::ValueExt,
+    id_type::{self, GenerateId},
+};
 use error_stack::ResultExt;
+use masking::PeekInterface;
+use router_env::logger;
 
 use crate::{
     connector,
-    core::errors,
+    core::{
+        errors::{self, StorageErrorExt},
+        payment_methods::{network_tokenization, vault},
+        payments,
+    },
     services,
     types::{
+        self as router_types,
         api::{
             self,
             verify_connector::{self as types, VerifyConnector},
         },
+        domain,
         transformers::ForeignInto,
     },
     utils::verify_connector as utils,
@@ -65,3 +85,402 @@ pub async fn verify_connector_credentials(
         .into()),
     }
 }
+
+/// Tokenize a test card against the connector without charging it, running the steps of the
+/// tokenization one after the other: storing the card in the vault, provisioning the token at the
+/// connector and, if requested, provisioning the network token. The steps after the first failed
+/// step are skipped. The card stored in the vault and the network token are deleted once
+/// provisioned. The connectors provide no deletion of the tokens they provision, which are left to
+/// expire at the connector, so the test is only run with the connector accounts in test mode.
+pub async fn test_connector_tokenization(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: TokenizationTestRequest,
+) -> errors::RouterResponse<TokenizationTestResponse> {
+    let merchant_connector_account = get_tokenization_test_connector_account(
+        &state,
+        &merchant_account,
+        &key_store,
+        &req.merchant_connector_id,
+    )
+    .await?;
+
+    error_stack::ensure!(
+        get_connector_account_test_mode(&merchant_connector_account) == Some(true),
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: "The tokenization test can only be run with a connector account in test mode"
+                .to_string(),
+        }
+    );
+
+    let connector_name = Connector::from_str(&merchant_connector_account.connector_name)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Invalid connector name {} in the connector account",
+                merchant_connector_account.connector_name
+            )
+        })?;
+    let connector_auth: router_types::ConnectorAuthType = merchant_connector_account
+        .connector_account_details
+        .peek()
+        .clone()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the connector account details")?;
+
+    let boxed_connector = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &connector_name.to_string(),
+        api::GetToken::Connector,
+        None,
+    )
+    .change_context(errors::ApiErrorResponse::IncorrectConnectorNameGiven)?;
+
+    let card_details = match req.card {
+        Some(card) => {
+            error_stack::ensure!(
+                utils::is_test_card_number(&card.card_number),
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Only test card numbers can be used for the tokenization test"
+                        .to_string(),
+                }
+            );
+            utils::generate_card_from_details(
+                card.card_number.get_card_no(),
+                masking::ExposeInterface::expose(card.card_exp_year),
+                masking::ExposeInterface::expose(card.card_exp_month),
+                masking::ExposeInterface::expose(card.card_cvc),
+            )?
+        }
+        None => utils::get_tokenization_test_card_details(connector_name)?,
+    };
+
+    let connector_data = types::VerifyConnectorData {
+        connector: boxed_connector.connector,
+        connector_auth,
+        card_details,
+    };
+
+    let mut steps =
+        vec![test_vault_tokenization(&state, &key_store, &connector_data.card_details).await];
+
+    steps.push(if is_step_failed(&steps) {
+        skipped_step(TokenizationTestStep::ConnectorProvisioning)
+    } else {
+        test_connector_token_provisioning(&state, &connector_data).await
+    });
+
+    steps.push(if !req.network_tokenization || is_step_failed(&steps) {
+        skipped_step(TokenizationTestStep::NetworkToken)
+    } else {
+        test_network_token_provisioning(&state, &connector_data.card_details).await
+    });
+
+    let failed_step = get_failed_step(&steps);
+
+    Ok(services::ApplicationResponse::Json(
+        TokenizationTestResponse {
+            connector_name,
+            succeeded: failed_step.is_none(),
+            failed_step,
+            steps,
+        },
+    ))
+}
+
+#[cfg(feature = "v1")]
+async fn get_tokenization_test_connector_account(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> errors::RouterResult<domain::MerchantConnectorAccount> {
+    state
+        .store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &state.into(),
+            merchant_account.get_id(),
+            merchant_connector_id,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_string(),
+        })
+}
+
+#[cfg(feature = "v2")]
+async fn get_tokenization_test_connector_account(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> errors::RouterResult<domain::MerchantConnectorAccount> {
+    let merchant_connector_account = state
+        .store
+        .find_merchant_connector_account_by_id(&state.into(), merchant_connector_id, key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_string(),
+        })?;
+
+    error_stack::ensure!(
+        merchant_connector_account.merchant_id == *merchant_account.get_id(),
+        errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_string(),
+        }
+    );
+
+    Ok(merchant_connector_account)
+}
+
+#[cfg(feature = "v1")]
+fn get_connector_account_test_mode(
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+) -> Option<bool> {
+    merchant_connector_account.test_mode
+}
+
+#[cfg(feature = "v2")]
+fn get_connector_account_test_mode(
+    _merchant_connector_account: &domain::MerchantConnectorAccount,
+) -> Option<bool> {
+    None
+}
+
+fn get_failed_step(steps: &[TokenizationTestStepResult]) -> Option<TokenizationTestStep> {
+    steps
+        .iter()
+        .find(|step| step.status == TokenizationTestStepStatus::Failed)
+        .map(|step| step.step)
+}
+
+fn is_step_failed(steps: &[TokenizationTestStepResult]) -> bool {
+    get_failed_step(steps).is_some()
+}
+
+fn skipped_step(step: TokenizationTestStep) -> TokenizationTestStepResult {
+    TokenizationTestStepResult {
+        step,
+        status: TokenizationTestStepStatus::Skipped,
+        error_code: None,
+        error_message: None,
+        connector_http_status_code: None,
+    }
+}
+
+fn failed_step(step: TokenizationTestStep, error_message: String) -> TokenizationTestStepResult {
+    TokenizationTestStepResult {
+        step,
+        status: TokenizationTestStepStatus::Failed,
+        error_code: None,
+        error_message: Some(error_message),
+        connector_http_status_code: None,
+    }
+}
+
+async fn test_vault_tokenization(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    card_details: &domain::Card,
+) -> TokenizationTestStepResult {
+    match vault::Vault::store_payment_method_data_in_locker(
+        state,
+        None,
+        &domain::PaymentMethodData::Card(card_details.clone()),
+        None,
+        api::enums::PaymentMethod::Card,
+        key_store,
+    )
+    .await
+    {
+        Ok(lookup_key) => {
+            vault::Vault::delete_locker_payment_method_by_lookup_key(state, &Some(lookup_key))
+                .await;
+            TokenizationTestStepResult {
+                status: TokenizationTestStepStatus::Succeeded,
+                ..skipped_step(TokenizationTestStep::Vault)
+            }
+        }
+        Err(error) => {
+            logger::error!(?error, "Failed to store the test card in the vault");
+            failed_step(
+                TokenizationTestStep::Vault,
+                "Failed to store the card in the vault".to_string(),
+            )
+        }
+    }
+}
+
+async fn test_connector_token_provisioning(
+    state: &SessionState,
+    connector_data: &types::VerifyConnectorData,
+) -> TokenizationTestStepResult {
+    let connector_integration: services::BoxedPaymentConnectorIntegrationInterface<
+        api::PaymentMethodToken,
+        router_types::PaymentMethodTokenizationData,
+        router_types::PaymentsResponseData,
+    > = connector_data.connector.get_connector_integration();
+    let router_data = connector_data.get_tokenization_router_data();
+
+    let request = match connector_integration.build_request(&router_data, &state.conf.connectors) {
+        Ok(Some(request)) => request,
+        Ok(None) => {
+            return failed_step(
+                TokenizationTestStep::ConnectorProvisioning,
+                "The connector does not support the tokenization of cards".to_string(),
+            )
+        }
+        Err(error) => {
+            logger::error!(?error, "Failed to build the tokenization request");
+            return failed_step(
+                TokenizationTestStep::ConnectorProvisioning,
+                "The tokenization request cannot be built for the connector".to_string(),
+            );
+        }
+    };
+
+    match services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+        Some(request),
+    )
+    .await
+    {
+        Ok(router_data) => get_connector_provisioning_step_result(
+            router_data.response,
+            router_data.connector_http_status_code,
+        ),
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to provision the token of the test card at the connector"
+            );
+            failed_step(
+                TokenizationTestStep::ConnectorProvisioning,
+                error.current_context().to_string(),
+            )
+        }
+    }
+}
+
+fn get_connector_provisioning_step_result(
+    response: Result<router_types::PaymentsResponseData, router_types::ErrorResponse>,
+    connector_http_status_code: Option<u16>,
+) -> TokenizationTestStepResult {
+    match response {
+        Ok(_) => TokenizationTestStepResult {
+            status: TokenizationTestStepStatus::Succeeded,
+            connector_http_status_code,
+            ..skipped_step(TokenizationTestStep::ConnectorProvisioning)
+        },
+        Err(error_response) => TokenizationTestStepResult {
+            step: TokenizationTestStep::ConnectorProvisioning,
+            status: TokenizationTestStepStatus::Failed,
+            error_code: Some(error_response.code),
+            error_message: Some(error_response.reason.unwrap_or(error_response.message)),
+            connector_http_status_code: Some(error_response.status_code),
+        },
+    }
+}
+
+async fn test_network_token_provisioning(
+    state: &SessionState,
+    card_details: &domain::Card,
+) -> TokenizationTestStepResult {
+    let Some(tokenization_service) = &state.conf.network_tokenization_service else {
+        return failed_step(
+            TokenizationTestStep::NetworkToken,
+            "Network tokenization is not configured".to_string(),
+        );
+    };
+    let customer_id = id_type::CustomerId::generate();
+
+    match network_tokenization::make_card_network_tokenization_request(
+        state,
+        card_details,
+        &customer_id,
+    )
+    .await
+    {
+        Ok((_, network_token_requestor_reference_id)) => {
+            if let Some(network_token_requestor_reference_id) = network_token_requestor_reference_id
+            {
+                network_tokenization::delete_network_token_from_tokenization_service(
+                    state,
+                    network_token_requestor_reference_id,
+                    &customer_id,
+                    tokenization_service.get_inner(),
+                )
+                .await
+                .inspect_err(|error| {
+                    logger::error!(
+                        ?error,
+                        "Failed to delete the network token of the test card"
+                    )
+                })
+                .ok();
+            }
+            TokenizationTestStepResult {
+                status: TokenizationTestStepStatus::Succeeded,
+                ..skipped_step(TokenizationTestStep::NetworkToken)
+            }
+        }
+        Err(error) => failed_step(
+            TokenizationTestStep::NetworkToken,
+            error.current_context().to_string(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_failed_step_is_the_first_failed_step() {
+        let steps = vec![
+            TokenizationTestStepResult {
+                status: TokenizationTestStepStatus::Succeeded,
+                ..skipped_step(TokenizationTestStep::Vault)
+            },
+            failed_step(
+                TokenizationTestStep::ConnectorProvisioning,
+                "Failed".to_string(),
+            ),
+            skipped_step(TokenizationTestStep::NetworkToken),
+        ];
+        assert_eq!(
+            get_failed_step(&steps),
+            Some(TokenizationTestStep::ConnectorProvisioning)
+        );
+        assert_eq!(get_failed_step(&steps[..1]), None);
+        assert_eq!(get_failed_step(&steps[2..]), None);
+    }
+
+    #[test]
+    fn test_connector_error_is_reported_as_a_failed_step() {
+        let step = get_connector_provisioning_step_result(
+            Err(router_types::ErrorResponse {
+                code: "invalid_request_error".to_string(),
+                message: "Invalid API Key provided".to_string(),
+                reason: None,
+                status_code: 401,
+                ..Default::default()
+            }),
+            Some(401),
+        );
+        assert_eq!(step.step, TokenizationTestStep::ConnectorProvisioning);
+        assert_eq!(step.status, TokenizationTestStepStatus::Failed);
+        assert_eq!(step.error_code.as_deref(), Some("invalid_request_error"));
+        assert_eq!(
+            step.error_message.as_deref(),
+            Some("Invalid API Key provided")
+        );
+        assert_eq!(step.connector_http_status_code, Some(401));
+    }
+}
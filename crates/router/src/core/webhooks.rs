@@ -1,10 +1,16 @@
+pub mod delivery_scheduler;
 mod incoming;
+pub mod ordering;
 mod outgoing;
 pub mod types;
 pub mod utils;
 #[cfg(feature = "olap")]
 pub mod webhook_events;
+#[cfg(feature = "v1")]
+pub mod webhook_identifier;
 
+#[cfg(feature = "v1")]
+pub(crate) use self::incoming::incoming_webhooks_wrapper_by_identifier;
 pub(crate) use self::{
     incoming::incoming_webhooks_wrapper,
     outgoing::{
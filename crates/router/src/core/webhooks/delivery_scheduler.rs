@@ -0,0 +1,557 @@
+//! Scheduling of the delivery of outgoing webhooks, isolating the deliveries to each merchant.
+//!
+//! Each merchant has a limited number of delivery slots, so that a slow webhook endpoint only
+//! holds up the deliveries to its own merchant. A delivery waits in the queue of its merchant while
+//! all the slots of the merchant are in use, and is deferred to the outgoing webhook retry task
+//! when the queue is full. Once the configured number of consecutive deliveries to a merchant have
+//! failed, the deliveries to the merchant are deferred for a backoff which doubles with every
+//! further failure, until a delivery to the merchant succeeds again. Deliveries which have no retry
+//! task to be deferred to are never deferred, and only wait for a delivery slot.
+//!
+//! The state of the scheduler is kept in redis, so that the limits and the backoff of a merchant
+//! hold across all the instances of the application. The slots and the queue of a merchant are
+//! sorted sets of the deliveries holding a slot and waiting for one, each scored with the expiry of
+//! its lease, so that the deliveries of an instance which crashed do not hold the slots or the
+//! queue of the merchant forever. A delivery waiting in the queue renews its lease while it polls
+//! for a slot. Failures to reach redis are logged and the webhook is delivered without a slot, so
+//! that an outage of redis does not block the deliveries.
+
+use std::sync::Arc;
+
+use common_utils::{date_time, id_type};
+use error_stack::ResultExt;
+use redis_interface::RedisConnectionPool;
+use router_env::{instrument, metrics::add_attributes, tracing};
+
+use super::MERCHANT_ID;
+use crate::{
+    configs::settings::WebhookDeliverySchedulingSettings,
+    core::{
+        errors::{self, CustomResult},
+        metrics,
+    },
+    logger,
+    routes::SessionState,
+};
+
+/// Drop the slots and the places in the queue whose lease has expired, and take a slot for the
+/// delivery if the merchant has a free slot. Otherwise, the delivery takes or renews its place in
+/// the queue, unless the queue is full. A delivery which can be deferred is deferred while the
+/// deliveries to the merchant are backed off. Returns the decision, which is 1 to deliver, 2 to
+/// wait in the queue and 0 to defer, along with the end of the backoff of the merchant, if any.
+/// The time of redis is used for the leases, so that the leases do not depend on the clocks of
+/// the instances.
+const SCHEDULE_DELIVERY_SCRIPT: &str = r#"
+    local time = redis.call("TIME")
+    local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+    local can_defer = ARGV[1] == "1"
+    local lease_ttl = tonumber(ARGV[4])
+
+    local backed_off_until = tonumber(redis.call("HGET", KEYS[3], "backed_off_until")) or 0
+    if can_defer and backed_off_until * 1000 > now then
+        return {0, backed_off_until}
+    end
+
+    redis.call("ZREMRANGEBYSCORE", KEYS[1], "-inf", now)
+    redis.call("ZREMRANGEBYSCORE", KEYS[2], "-inf", now)
+    if redis.call("ZCARD", KEYS[1]) < tonumber(ARGV[2]) then
+        redis.call("ZADD", KEYS[1], now + lease_ttl, ARGV[5])
+        redis.call("PEXPIRE", KEYS[1], lease_ttl)
+        redis.call("ZREM", KEYS[2], ARGV[5])
+        return {1, 0}
+    end
+
+    local is_queued = redis.call("ZSCORE", KEYS[2], ARGV[5])
+    if can_defer and not is_queued and redis.call("ZCARD", KEYS[2]) >= tonumber(ARGV[3]) then
+        return {0, 0}
+    end
+    redis.call("ZADD", KEYS[2], now + lease_ttl, ARGV[5])
+    redis.call("PEXPIRE", KEYS[2], lease_ttl)
+    return {2, 0}
+"#;
+
+/// Release the slot or the place in the queue of the delivery, which is a no-op once its lease has
+/// expired and was dropped
+const RELEASE_LEASE_SCRIPT: &str = r#"
+    return redis.call("ZREM", KEYS[1], ARGV[1])
+"#;
+
+/// Count a failed delivery to the merchant, returning the number of consecutive failed deliveries
+const RECORD_FAILURE_SCRIPT: &str = r#"
+    local consecutive_failures = redis.call("HINCRBY", KEYS[1], "consecutive_failures", 1)
+    redis.call("EXPIRE", KEYS[1], ARGV[1])
+    return consecutive_failures
+"#;
+
+/// Count the slots and the places in the queue whose lease has not expired, along with the
+/// consecutive failed deliveries and the end of the backoff of the merchant
+const GET_STATUS_SCRIPT: &str = r#"
+    local time = redis.call("TIME")
+    local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+    return {
+        redis.call("ZCOUNT", KEYS[1], "(" .. now, "+inf"),
+        redis.call("ZCOUNT", KEYS[2], "(" .. now, "+inf"),
+        tonumber(redis.call("HGET", KEYS[3], "consecutive_failures")) or 0,
+        tonumber(redis.call("HGET", KEYS[3], "backed_off_until")) or 0,
+    }
+"#;
+
+/// Time for which a delivery holds its slot or its place in the queue, unless it is released or
+/// renewed. Deliveries are bounded by the timeout of the outgoing webhook request, well within it.
+const DELIVERY_LEASE_TTL_IN_MILLIS: u64 = 60_000;
+
+/// Time between the attempts of a delivery waiting in the queue to take a slot
+const DELIVERY_SLOT_POLL_INTERVAL_IN_MILLIS: u64 = 100;
+
+/// Minimum time in seconds after the last failed delivery to a merchant for which its consecutive
+/// failed deliveries are remembered
+const DELIVERY_FAILURES_TTL_IN_SECS: u64 = 24 * 60 * 60;
+
+/// A delivery slot of a merchant, which is released when the permit is dropped
+pub(crate) struct DeliveryPermit(Option<DeliveryLease>);
+
+impl std::fmt::Debug for DeliveryPermit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("DeliveryPermit")
+            .field(&self.0.as_ref().map(|lease| &lease.lease_id))
+            .finish()
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum ScheduledDelivery {
+    /// The webhook is to be delivered while holding the permit
+    Deliver(DeliveryPermit),
+    /// The delivery of the webhook is to be deferred to the retry task, until the given time
+    Defer { until: time::PrimitiveDateTime },
+}
+
+/// The state of the delivery of the webhooks of a merchant
+#[cfg(feature = "olap")]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct DeliveryQueueStatus {
+    pub in_flight_deliveries: u32,
+    pub queued_deliveries: u32,
+    pub consecutive_failures: u32,
+    pub backed_off_until: Option<time::PrimitiveDateTime>,
+}
+
+/// The keys of the state of the scheduler for a merchant, which share a hash tag so that they can
+/// be used in a single script
+#[derive(Debug, PartialEq, Eq)]
+struct DeliveryKeys {
+    slots: String,
+    queue: String,
+    failures: String,
+}
+
+impl DeliveryKeys {
+    fn new(merchant_id: &id_type::MerchantId) -> Self {
+        let merchant_id = merchant_id.get_string_repr();
+        Self {
+            slots: format!("webhook_delivery_{{{merchant_id}}}_slots"),
+            queue: format!("webhook_delivery_{{{merchant_id}}}_queue"),
+            failures: format!("webhook_delivery_{{{merchant_id}}}_failures"),
+        }
+    }
+
+    fn to_vec(&self) -> Vec<String> {
+        vec![
+            self.slots.clone(),
+            self.queue.clone(),
+            self.failures.clone(),
+        ]
+    }
+}
+
+/// The slot or the place in the queue held by a delivery, which is released in the background
+/// when dropped
+struct DeliveryLease {
+    redis_conn: Arc<RedisConnectionPool>,
+    key: String,
+    lease_id: String,
+}
+
+impl Drop for DeliveryLease {
+    fn drop(&mut self) {
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            logger::warn!("Leaving the webhook delivery lease to expire");
+            return;
+        };
+
+        let redis_conn = self.redis_conn.clone();
+        let key = std::mem::take(&mut self.key);
+        let lease_id = std::mem::take(&mut self.lease_id);
+        runtime.spawn(async move {
+            redis_conn
+                .evaluate_redis_script::<i64>(RELEASE_LEASE_SCRIPT, vec![key], vec![lease_id])
+                .await
+                .inspect_err(|error| {
+                    logger::error!(?error, "Failed to release webhook delivery lease")
+                })
+                .ok();
+        });
+    }
+}
+
+/// The outcome of an attempt to schedule a delivery
+#[derive(Debug, PartialEq, Eq)]
+enum ScheduleDecision {
+    Deliver,
+    Wait,
+    Defer { until: time::PrimitiveDateTime },
+}
+
+fn get_duration(seconds: u64) -> time::Duration {
+    time::Duration::seconds(i64::try_from(seconds).unwrap_or(i64::MAX))
+}
+
+fn from_unix_timestamp(timestamp: i64) -> Option<time::PrimitiveDateTime> {
+    time::OffsetDateTime::from_unix_timestamp(timestamp)
+        .ok()
+        .map(|date_time| time::PrimitiveDateTime::new(date_time.date(), date_time.time()))
+}
+
+/// Interpret the reply of the scheduling script
+fn get_schedule_decision(
+    config: &WebhookDeliverySchedulingSettings,
+    reply: &[i64],
+) -> Option<ScheduleDecision> {
+    match reply {
+        [1, _] => Some(ScheduleDecision::Deliver),
+        [2, _] => Some(ScheduleDecision::Wait),
+        // The queue of the merchant is full
+        [0, 0] => Some(ScheduleDecision::Defer {
+            until: date_time::now().saturating_add(get_duration(config.backoff_base)),
+        }),
+        [0, backed_off_until] => {
+            from_unix_timestamp(*backed_off_until).map(|until| ScheduleDecision::Defer { until })
+        }
+        _ => None,
+    }
+}
+
+/// Get the time for which the deliveries to a merchant are backed off after the number of
+/// consecutive failed deliveries, if the failure threshold is reached
+fn get_backoff_duration(
+    config: &WebhookDeliverySchedulingSettings,
+    consecutive_failures: u32,
+) -> Option<time::Duration> {
+    let exponent = consecutive_failures.checked_sub(config.failure_threshold)?;
+    let backoff = config
+        .backoff_base
+        .saturating_mul(2u64.saturating_pow(exponent))
+        .min(config.backoff_max);
+    Some(get_duration(backoff))
+}
+
+async fn try_schedule(
+    redis_conn: &RedisConnectionPool,
+    config: &WebhookDeliverySchedulingSettings,
+    keys: &DeliveryKeys,
+    lease_id: &str,
+    can_defer: bool,
+) -> CustomResult<ScheduleDecision, errors::StorageError> {
+    let reply = redis_conn
+        .evaluate_redis_script::<Vec<i64>>(
+            SCHEDULE_DELIVERY_SCRIPT,
+            keys.to_vec(),
+            vec![
+                u8::from(can_defer).to_string(),
+                config.max_concurrent_deliveries.to_string(),
+                config.max_queued_deliveries.to_string(),
+                DELIVERY_LEASE_TTL_IN_MILLIS.to_string(),
+                lease_id.to_owned(),
+            ],
+        )
+        .await
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("Failed to schedule the webhook delivery")?;
+
+    get_schedule_decision(config, &reply)
+        .ok_or(errors::StorageError::KVError)
+        .attach_printable_lazy(|| format!("Invalid webhook delivery schedule: {reply:?}"))
+}
+
+/// Schedule the delivery, waiting in the queue of the merchant until a slot is free. Failures to
+/// reach redis are logged and the webhook is delivered without a slot.
+async fn schedule(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    can_defer: bool,
+) -> ScheduledDelivery {
+    let config = &state.conf.webhooks.delivery_scheduling;
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get redis connection for webhook delivery scheduling"
+            );
+            return ScheduledDelivery::Deliver(DeliveryPermit(None));
+        }
+    };
+
+    let keys = DeliveryKeys::new(merchant_id);
+    let lease_id = uuid::Uuid::new_v4().to_string();
+    // The place in the queue is released when the delivery stops waiting, including when the
+    // delivery is cancelled while waiting. Taking a slot already gives up the place in the queue.
+    let mut queued_delivery = None;
+
+    loop {
+        match try_schedule(&redis_conn, config, &keys, &lease_id, can_defer).await {
+            Ok(ScheduleDecision::Deliver) => {
+                return ScheduledDelivery::Deliver(DeliveryPermit(Some(DeliveryLease {
+                    redis_conn,
+                    key: keys.slots,
+                    lease_id,
+                })));
+            }
+            Ok(ScheduleDecision::Wait) => {
+                queued_delivery.get_or_insert_with(|| DeliveryLease {
+                    redis_conn: redis_conn.clone(),
+                    key: keys.queue.clone(),
+                    lease_id: lease_id.clone(),
+                });
+            }
+            Ok(ScheduleDecision::Defer { until }) => return ScheduledDelivery::Defer { until },
+            Err(error) => {
+                logger::error!(?error, "Failed to schedule webhook delivery");
+                return ScheduledDelivery::Deliver(DeliveryPermit(None));
+            }
+        }
+
+        tokio::time::sleep(std::time::Duration::from_millis(
+            DELIVERY_SLOT_POLL_INTERVAL_IN_MILLIS,
+        ))
+        .await;
+    }
+}
+
+/// Schedule the delivery of a webhook which can be deferred to its retry task. The delivery is
+/// deferred while the deliveries to the merchant are backed off, or when the delivery queue of the
+/// merchant is full. Otherwise, the delivery waits for a delivery slot of the merchant.
+#[instrument(skip_all)]
+pub(crate) async fn schedule_delivery(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> ScheduledDelivery {
+    let scheduled_delivery = schedule(state, merchant_id, true).await;
+
+    if let ScheduledDelivery::Defer { until } = &scheduled_delivery {
+        logger::info!(
+            merchant_id = ?merchant_id,
+            deferred_until = ?until,
+            "Deferring webhook delivery to the retry task"
+        );
+        metrics::WEBHOOK_OUTGOING_DELIVERY_DEFERRED_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([(MERCHANT_ID, merchant_id.get_string_repr().to_owned())]),
+        );
+    }
+
+    scheduled_delivery
+}
+
+/// Wait for a delivery slot of the merchant, for the delivery of a webhook which cannot be
+/// deferred
+#[instrument(skip_all)]
+pub(crate) async fn acquire_delivery_slot(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> DeliveryPermit {
+    match schedule(state, merchant_id, false).await {
+        ScheduledDelivery::Deliver(permit) => permit,
+        ScheduledDelivery::Defer { .. } => DeliveryPermit(None),
+    }
+}
+
+/// Record whether a delivery to the merchant succeeded, backing off the deliveries to the merchant
+/// once the failure threshold is reached. Failures to record the outcome are logged.
+#[instrument(skip_all)]
+pub(crate) async fn record_delivery_outcome(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    is_successful: bool,
+) {
+    if let Err(error) = try_record_delivery_outcome(state, merchant_id, is_successful).await {
+        logger::error!(?error, "Failed to record webhook delivery outcome");
+    }
+}
+
+async fn try_record_delivery_outcome(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    is_successful: bool,
+) -> CustomResult<(), errors::StorageError> {
+    let config = &state.conf.webhooks.delivery_scheduling;
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("Failed to get redis connection")?;
+    let key = DeliveryKeys::new(merchant_id).failures;
+
+    if is_successful {
+        return redis_conn
+            .delete_key(&key)
+            .await
+            .map(|_| ())
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to reset the failed webhook deliveries");
+    }
+
+    let failures_ttl = config.backoff_max.max(DELIVERY_FAILURES_TTL_IN_SECS);
+    let consecutive_failures = redis_conn
+        .evaluate_redis_script::<i64>(
+            RECORD_FAILURE_SCRIPT,
+            vec![key.clone()],
+            vec![failures_ttl.to_string()],
+        )
+        .await
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("Failed to count the failed webhook delivery")?;
+    let consecutive_failures = u32::try_from(consecutive_failures).unwrap_or(u32::MAX);
+
+    if let Some(backoff) = get_backoff_duration(config, consecutive_failures) {
+        let backed_off_until = date_time::now().saturating_add(backoff);
+        logger::warn!(
+            merchant_id = ?merchant_id,
+            consecutive_failures,
+            ?backed_off_until,
+            "Backing off the webhook deliveries to the merchant after consecutive failures"
+        );
+        redis_conn
+            .set_hash_fields(
+                &key,
+                vec![(
+                    "backed_off_until".to_string(),
+                    backed_off_until.assume_utc().unix_timestamp().to_string(),
+                )],
+                Some(i64::try_from(failures_ttl).unwrap_or(i64::MAX)),
+            )
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to back off the webhook deliveries")?;
+    }
+
+    Ok(())
+}
+
+/// Get the state of the delivery of the webhooks of the merchant
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub(crate) async fn get_delivery_queue_status(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> CustomResult<DeliveryQueueStatus, errors::StorageError> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let reply = redis_conn
+        .evaluate_redis_script::<Vec<i64>>(
+            GET_STATUS_SCRIPT,
+            DeliveryKeys::new(merchant_id).to_vec(),
+            Vec::new(),
+        )
+        .await
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("Failed to get the webhook delivery queue status")?;
+
+    let [in_flight_deliveries, queued_deliveries, consecutive_failures, backed_off_until] =
+        reply.as_slice()
+    else {
+        return Err(errors::StorageError::KVError)
+            .attach_printable_lazy(|| format!("Invalid webhook delivery status: {reply:?}"));
+    };
+    let to_count = |count: &i64| u32::try_from(*count).unwrap_or(u32::MAX);
+
+    Ok(DeliveryQueueStatus {
+        in_flight_deliveries: to_count(in_flight_deliveries),
+        queued_deliveries: to_count(queued_deliveries),
+        consecutive_failures: to_count(consecutive_failures),
+        backed_off_until: from_unix_timestamp(*backed_off_until)
+            .filter(|backed_off_until| *backed_off_until > date_time::now()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_config() -> WebhookDeliverySchedulingSettings {
+        WebhookDeliverySchedulingSettings {
+            max_concurrent_deliveries: 1,
+            max_queued_deliveries: 1,
+            failure_threshold: 2,
+            backoff_base: 30,
+            backoff_max: 100,
+        }
+    }
+
+    fn get_merchant_id(merchant_id: &'static str) -> id_type::MerchantId {
+        id_type::MerchantId::try_from(std::borrow::Cow::from(merchant_id)).unwrap()
+    }
+
+    #[test]
+    fn test_backoff_doubles_from_the_failure_threshold_up_to_the_maximum() {
+        let config = get_config();
+
+        assert_eq!(get_backoff_duration(&config, 1), None);
+        assert_eq!(get_backoff_duration(&config, 2), Some(get_duration(30)));
+        assert_eq!(get_backoff_duration(&config, 3), Some(get_duration(60)));
+        assert_eq!(get_backoff_duration(&config, 4), Some(get_duration(100)));
+        assert_eq!(
+            get_backoff_duration(&config, u32::MAX),
+            Some(get_duration(100))
+        );
+    }
+
+    #[test]
+    fn test_keys_of_a_merchant_share_a_hash_tag() {
+        let keys = DeliveryKeys::new(&get_merchant_id("slow_merchant"));
+
+        assert_eq!(
+            keys.to_vec(),
+            vec![
+                "webhook_delivery_{slow_merchant}_slots".to_string(),
+                "webhook_delivery_{slow_merchant}_queue".to_string(),
+                "webhook_delivery_{slow_merchant}_failures".to_string(),
+            ]
+        );
+        assert_ne!(keys, DeliveryKeys::new(&get_merchant_id("other_merchant")));
+    }
+
+    #[test]
+    fn test_schedule_decision_is_read_from_the_script_reply() {
+        let config = get_config();
+        let backed_off_until = date_time::now().saturating_add(get_duration(60));
+        let backed_off_until_timestamp = backed_off_until.assume_utc().unix_timestamp();
+
+        assert_eq!(
+            get_schedule_decision(&config, &[1, 0]),
+            Some(ScheduleDecision::Deliver)
+        );
+        assert_eq!(
+            get_schedule_decision(&config, &[2, 0]),
+            Some(ScheduleDecision::Wait)
+        );
+        assert_eq!(
+            get_schedule_decision(&config, &[0, backed_off_until_timestamp]),
+            Some(ScheduleDecision::Defer {
+                until: from_unix_timestamp(backed_off_until_timestamp).unwrap()
+            })
+        );
+        // A full queue defers the delivery by the base backoff
+        assert!(matches!(
+            get_schedule_decision(&config, &[0, 0]),
+            Some(ScheduleDecision::Defer { until }) if until > date_time::now()
+        ));
+        assert_eq!(get_schedule_decision(&config, &[1]), None);
+    }
+}
@@ -1,4 +1,4 @@
-use std::{str::FromStr, time::Instant};
+use std::{str::FromStr, sync::Arc, time::Instant};
 
 use actix_web::FromRequest;
 #[cfg(feature = "payouts")]
@@ -7,9 +7,15 @@ use api_models::{
     payments::HeaderPayload,
     webhooks::{self, WebhookResponseTracker},
 };
-use common_utils::{errors::ReportSwitchExt, events::ApiEventsType};
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    errors::ReportSwitchExt,
+    events::ApiEventsType,
+    ext_traits::ValueExt,
+};
 use error_stack::{report, ResultExt};
 use hyperswitch_domain_models::{
+    router_data::ConnectorAuthType,
     router_request_types::VerifyWebhookSourceRequestData,
     router_response_types::{VerifyWebhookSourceResponseData, VerifyWebhookStatus},
 };
@@ -18,7 +24,10 @@ use masking::ExposeInterface;
 use router_env::{instrument, metrics::add_attributes, tracing, tracing_actix_web::RequestId};
 
 use super::{types, utils, MERCHANT_ID};
+#[cfg(feature = "v1")]
+use crate::core::customers::risk_profile::{self as customer_risk_profile, CustomerRiskEvent};
 use crate::{
+    configs::settings,
     consts,
     core::{
         api_locking,
@@ -116,6 +125,34 @@ pub async fn incoming_webhooks_wrapper<W: types::OutgoingWebhookType>(
     Ok(application_response)
 }
 
+/// Handle an incoming webhook sent to the webhook endpoint of a merchant connector account, which
+/// is identified by the webhook identifier of the merchant connector account
+#[cfg(feature = "v1")]
+pub async fn incoming_webhooks_wrapper_by_identifier<W: types::OutgoingWebhookType>(
+    flow: &impl router_env::types::FlowMetric,
+    state: SessionState,
+    req_state: ReqState,
+    req: &actix_web::HttpRequest,
+    webhook_identifier: &str,
+    body: actix_web::web::Bytes,
+) -> RouterResponse<serde_json::Value> {
+    let owner =
+        super::webhook_identifier::resolve_webhook_identifier(&state, webhook_identifier).await?;
+    let merchant_connector_id = owner.merchant_connector_account.get_id();
+
+    Box::pin(incoming_webhooks_wrapper::<W>(
+        flow,
+        state,
+        req_state,
+        req,
+        owner.merchant_account,
+        owner.key_store,
+        merchant_connector_id.get_string_repr(),
+        body,
+    ))
+    .await
+}
+
 #[instrument(skip_all)]
 async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
     state: SessionState,
@@ -131,6 +168,8 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
     serde_json::Value,
 )> {
     let key_manager_state = &(&state).into();
+    let received_at = common_utils::date_time::now();
+    let merchant_id = merchant_account.get_id().clone();
 
     metrics::WEBHOOK_INCOMING_COUNT.add(
         &metrics::CONTEXT,
@@ -240,6 +279,7 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
 
     let flow_type: api::WebhookFlow = event_type.into();
     let mut event_object: Box<dyn masking::ErasedMaskSerialize> = Box::new(serde_json::Value::Null);
+    let mut webhook_replay_claim = None;
     let webhook_effect = if process_webhook_further
         && !matches!(flow_type, api::WebhookFlow::ReturnResponse)
     {
@@ -331,14 +371,36 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
 
         logger::info!(source_verified=?source_verified);
 
+        // The signed timestamp can be trusted only if the webhook source is verified
+        if source_verified {
+            webhook_replay_claim = validate_webhook_replay_protection(
+                &state,
+                &merchant_account,
+                &connector,
+                &connector_name,
+                &request_details,
+            )
+            .await?;
+        }
+
         event_object = connector
             .get_webhook_resource_object(&request_details)
             .switch()
             .attach_printable("Could not find resource object in incoming webhook body")?;
 
+        let resource_object = enrich_webhook_resource_object(
+            &state,
+            &connector,
+            &connector_name,
+            &merchant_connector_account,
+            &request_details,
+            &event_object,
+        )
+        .await?;
+
         let webhook_details = api::IncomingWebhookDetails {
             object_reference_id: object_ref_id.clone(),
-            resource_object: serde_json::to_vec(&event_object)
+            resource_object: serde_json::to_vec(&resource_object)
                 .change_context(errors::ParsingError::EncodeError("byte-vec"))
                 .attach_printable("Unable to convert webhook payload to a value")
                 .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -462,6 +524,8 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
                 webhook_details,
                 event_type,
                 source_verified,
+                &connector,
+                &request_details,
             ))
             .await
             .attach_printable("Incoming webhook flow for payouts failed")?,
@@ -481,6 +545,18 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
         WebhookResponseTracker::NoEffect
     };
 
+    if let Some(payment_id) = webhook_effect.get_payment_id() {
+        record_incoming_webhook_event(
+            &state,
+            merchant_id,
+            payment_id,
+            &connector_name,
+            event_type,
+            received_at,
+        )
+        .await;
+    }
+
     let response = connector
         .get_webhook_api_response(&request_details)
         .switch()
@@ -490,9 +566,300 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
         .masked_serialize()
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Could not convert webhook effect to string")?;
+
+    if let Some(webhook_replay_claim) = webhook_replay_claim {
+        webhook_replay_claim.retain();
+    }
     Ok((response, webhook_effect, serialized_request))
 }
 
+/// Record the webhook received for the payment, its refund or its dispute, for the timeline of the
+/// payment. The webhook is recorded once it was processed, at the time at which it was received,
+/// and a failure to record it does not fail the webhook.
+async fn record_incoming_webhook_event(
+    state: &SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+    payment_id: common_utils::id_type::PaymentId,
+    connector_name: &str,
+    event_type: webhooks::IncomingWebhookEvent,
+    received_at: time::PrimitiveDateTime,
+) {
+    let event_type = serde_json::to_value(event_type)
+        .ok()
+        .and_then(|event_type| event_type.as_str().map(ToOwned::to_owned))
+        .unwrap_or_else(|| format!("{event_type:?}"));
+
+    let _ = state
+        .store
+        .insert_incoming_webhook_event(storage::IncomingWebhookEventNew {
+            event_id: generate_id(consts::ID_LENGTH, "whin"),
+            merchant_id,
+            payment_id,
+            connector: connector_name.to_owned(),
+            event_type,
+            received_at,
+        })
+        .await
+        .inspect_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to record the incoming webhook of the payment"
+            )
+        });
+}
+
+/// The claim of a webhook delivery against replays. The claim is released when the webhook is not
+/// processed, so that the connector can deliver the webhook again.
+struct WebhookReplayClaim {
+    redis_conn: Arc<redis_interface::RedisConnectionPool>,
+    key: Option<String>,
+}
+
+impl WebhookReplayClaim {
+    /// Keep the claim once the webhook is processed, so that replays of the webhook are rejected
+    /// until the claim expires
+    fn retain(mut self) {
+        self.key = None;
+    }
+}
+
+impl Drop for WebhookReplayClaim {
+    fn drop(&mut self) {
+        let Some(key) = self.key.take() else {
+            return;
+        };
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            logger::warn!("Leaving the replay claim of the webhook to expire");
+            return;
+        };
+
+        let redis_conn = self.redis_conn.clone();
+        runtime.spawn(async move {
+            redis_conn
+                .delete_key(&key)
+                .await
+                .inspect_err(|error| {
+                    logger::error!(?error, "Failed to release the replay claim of the webhook")
+                })
+                .ok();
+        });
+    }
+}
+
+fn get_webhook_replay_key(
+    merchant_id: &common_utils::id_type::MerchantId,
+    connector_name: &str,
+    signed_timestamp: i64,
+    body: &[u8],
+) -> errors::RouterResult<String> {
+    // A replayed webhook carries the same signed timestamp and body as the original delivery,
+    // while connector retries are signed afresh
+    let mut webhook_message = format!("{signed_timestamp}.").into_bytes();
+    webhook_message.extend_from_slice(body);
+    let webhook_digest = crypto::Sha256
+        .generate_digest(&webhook_message)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to compute the digest of incoming webhook")?;
+
+    Ok(format!(
+        "webhook_replay_{}_{connector_name}_{}",
+        merchant_id.get_string_repr(),
+        hex::encode(webhook_digest)
+    ))
+}
+
+/// The time in seconds for which a webhook has to be remembered. A webhook is accepted while its
+/// signed timestamp is within the tolerance of the current time, which is at most twice the
+/// tolerance after the webhook is first received.
+fn get_webhook_replay_key_ttl(timestamp_tolerance: &settings::WebhookTimestampTolerance) -> i64 {
+    let tolerance_in_seconds = timestamp_tolerance
+        .unit
+        .to_seconds(timestamp_tolerance.tolerance);
+    i64::try_from(tolerance_in_seconds.saturating_mul(2))
+        .unwrap_or(i64::MAX)
+        .max(1)
+}
+
+/// Reject webhooks whose signed timestamp is outside the tolerance window configured for the
+/// connector, and webhooks which were already received with the same signed timestamp.
+///
+/// Returns the claim of the webhook against replays, if the connector signs the timestamp of its
+/// webhooks.
+#[instrument(skip_all)]
+async fn validate_webhook_replay_protection(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    connector: &ConnectorEnum,
+    connector_name: &str,
+    request_details: &IncomingWebhookRequestDetails<'_>,
+) -> errors::RouterResult<Option<WebhookReplayClaim>> {
+    let Some(timestamp_tolerance) = state.conf.webhooks.timestamp_tolerance.get(connector_name)
+    else {
+        return Ok(None);
+    };
+
+    let Some(signed_timestamp) = connector
+        .get_webhook_signed_timestamp(request_details)
+        .switch()
+        .attach_printable("Could not find the signed timestamp in incoming webhook")?
+    else {
+        return Ok(None);
+    };
+
+    let current_timestamp = timestamp_tolerance.unit.current_timestamp();
+    if current_timestamp.abs_diff(signed_timestamp) > timestamp_tolerance.tolerance {
+        logger::error!(
+            signed_timestamp,
+            current_timestamp,
+            "Incoming webhook timestamp is outside the tolerance window"
+        );
+        return Err(report!(errors::ApiErrorResponse::WebhookTimestampExpired));
+    }
+
+    let key = get_webhook_replay_key(
+        merchant_account.get_id(),
+        connector_name,
+        signed_timestamp,
+        request_details.body,
+    )?;
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    match redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &key,
+            "",
+            Some(get_webhook_replay_key_ttl(timestamp_tolerance)),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to claim the incoming webhook against replays")?
+    {
+        redis_interface::SetnxReply::KeySet => Ok(Some(WebhookReplayClaim {
+            redis_conn,
+            key: Some(key),
+        })),
+        redis_interface::SetnxReply::KeyNotSet => {
+            Err(report!(errors::ApiErrorResponse::WebhookEventReplayed))
+        }
+    }
+}
+
+/// Enrich the resource of the webhook with the resource fetched from the connector, if the
+/// connector opted in to enrichment and the webhook is missing any of the required fields.
+///
+/// The resource is fetched at most once. If the fetched resource is missing the required fields as
+/// well, or cannot be fetched, the webhook is processed with the fields which are available.
+#[instrument(skip_all)]
+async fn enrich_webhook_resource_object(
+    state: &SessionState,
+    connector: &ConnectorEnum,
+    connector_name: &str,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    request_details: &IncomingWebhookRequestDetails<'_>,
+    event_object: &dyn masking::ErasedMaskSerialize,
+) -> errors::RouterResult<serde_json::Value> {
+    let mut resource_object = serde_json::to_value(event_object)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to convert webhook resource object to a value")?;
+
+    let Some(resource_enrichment) = state.conf.webhooks.resource_enrichment.get(connector_name)
+    else {
+        return Ok(resource_object);
+    };
+
+    let missing_fields =
+        utils::get_missing_resource_fields(&resource_object, &resource_enrichment.required_fields);
+    if missing_fields.is_empty() {
+        return Ok(resource_object);
+    }
+    logger::info!(
+        ?missing_fields,
+        "Incoming webhook is missing required fields, fetching the resource from the connector"
+    );
+
+    let auth_type: ConnectorAuthType =
+        payments::helpers::MerchantConnectorAccountType::DbVal(merchant_connector_account.clone())
+            .get_connector_account_details()
+            .parse_value("ConnectorAuthType")
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let request = match connector.build_webhook_resource_fetch_request(
+        request_details,
+        &auth_type,
+        &state.conf.connectors,
+    ) {
+        Ok(request) => request,
+        Err(error)
+            if matches!(
+                error.current_context(),
+                errors::ConnectorError::NotImplemented(_)
+            ) =>
+        {
+            logger::warn!(
+                ?error,
+                "Fetching the webhook resource is not implemented for the connector"
+            );
+            return Ok(resource_object);
+        }
+        Err(error) => {
+            return Err(error)
+                .switch()
+                .attach_printable("Failed to build the request to fetch the webhook resource")
+        }
+    };
+
+    let fetched_resource =
+        match services::call_connector_api(state, request, "fetch_webhook_resource").await {
+            Ok(Ok(response)) => serde_json::from_slice::<serde_json::Value>(&response.response)
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to parse the fetched webhook resource");
+                })
+                .ok(),
+            Ok(Err(response)) => {
+                logger::error!(
+                    status_code = response.status_code,
+                    "Failed to fetch the webhook resource from the connector"
+                );
+                None
+            }
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    "Failed to fetch the webhook resource from the connector"
+                );
+                None
+            }
+        };
+
+    if let Some(fetched_resource) = fetched_resource {
+        utils::merge_fetched_resource(&mut resource_object, fetched_resource);
+    }
+
+    let still_missing_fields =
+        utils::get_missing_resource_fields(&resource_object, &resource_enrichment.required_fields);
+    if !still_missing_fields.is_empty() {
+        logger::warn!(
+            ?still_missing_fields,
+            "Required fields are missing in the fetched webhook resource as well"
+        );
+    }
+
+    metrics::WEBHOOK_RESOURCE_ENRICHMENT_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([
+            ("connector", connector_name.to_owned()),
+            ("enriched", still_missing_fields.is_empty().to_string()),
+        ]),
+    );
+
+    Ok(resource_object)
+}
+
 #[instrument(skip_all)]
 async fn payments_incoming_webhook_flow(
     state: SessionState,
@@ -528,7 +895,11 @@ async fn payments_incoming_webhook_flow(
 
             lock_action
                 .clone()
-                .perform_locking_action(&state, merchant_account.get_id().to_owned())
+                .perform_locking_action(
+                    &state,
+                    merchant_account.get_id().to_owned(),
+                    merchant_account.idempotency_ttl_in_seconds,
+                )
                 .await?;
 
             let response = Box::pin(payments::payments_core::<
@@ -632,6 +1003,7 @@ async fn payments_incoming_webhook_flow(
 }
 
 #[cfg(feature = "payouts")]
+#[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
 async fn payouts_incoming_webhook_flow(
     state: SessionState,
@@ -641,6 +1013,8 @@ async fn payouts_incoming_webhook_flow(
     webhook_details: api::IncomingWebhookDetails,
     event_type: webhooks::IncomingWebhookEvent,
     source_verified: bool,
+    connector: &ConnectorEnum,
+    request_details: &IncomingWebhookRequestDetails<'_>,
 ) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
     metrics::INCOMING_PAYOUT_WEBHOOK_METRIC.add(&metrics::CONTEXT, 1, &[]);
     if source_verified {
@@ -671,6 +1045,65 @@ async fn payouts_incoming_webhook_flow(
                 .attach_printable("received a non-payout id when processing payout webhooks")?,
         };
 
+        // Returns reverse a succeeded payout, they are applied idempotently as the bank may report
+        // the same return more than once
+        if event_type == webhooks::IncomingWebhookEvent::PayoutReturned {
+            let bank_reference = payout_attempt
+                .connector_payout_id
+                .clone()
+                .ok_or(errors::ApiErrorResponse::WebhookResourceNotFound)
+                .attach_printable("Returned payout does not have a connector payout id")?;
+            let return_details = connector
+                .get_payout_return_details(request_details)
+                .switch()
+                .attach_printable("Failed to get the return details of the payout")?;
+
+            let return_result = payouts::returns::apply_payout_returns(
+                &state,
+                &merchant_account,
+                &key_store,
+                vec![storage::PayoutReturn {
+                    connector: connector.id().to_string(),
+                    bank_reference,
+                    return_reason_code: return_details.return_reason_code,
+                    return_reason_message: return_details.return_reason_message,
+                }],
+            )
+            .await?
+            .pop()
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to apply the payout return")?;
+
+            let payout_attempt = match return_result.outcome {
+                storage::PayoutReturnOutcome::Returned(returned_payout) => {
+                    returned_payout.payout_attempt
+                }
+                storage::PayoutReturnOutcome::AlreadyReturned(payout_attempt) => *payout_attempt,
+                storage::PayoutReturnOutcome::NotReturnable(payout_attempt) => {
+                    logger::warn!(
+                        payout_id = %payout_attempt.payout_id,
+                        status = ?payout_attempt.status,
+                        "Ignoring the return of a payout which did not succeed"
+                    );
+                    *payout_attempt
+                }
+                storage::PayoutReturnOutcome::NotFound => {
+                    Err(errors::ApiErrorResponse::WebhookResourceNotFound)
+                        .attach_printable("Failed to fetch the returned payout attempt")?
+                }
+                // The webhook is retried by the connector
+                storage::PayoutReturnOutcome::Failed(error) => {
+                    Err(errors::ApiErrorResponse::WebhookProcessingFailure)
+                        .attach_printable(format!("Failed to apply the payout return: {error}"))?
+                }
+            };
+
+            return Ok(WebhookResponseTracker::Payout {
+                payout_id: payout_attempt.payout_id,
+                status: payout_attempt.status,
+            });
+        }
+
         let payouts = db
             .find_payout_by_merchant_id_payout_id(
                 merchant_account.get_id(),
@@ -681,11 +1114,27 @@ async fn payouts_incoming_webhook_flow(
             .change_context(errors::ApiErrorResponse::WebhookResourceNotFound)
             .attach_printable("Failed to fetch the payout")?;
 
+        let status = common_enums::PayoutStatus::foreign_try_from(event_type)
+            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+            .attach_printable("failed payout status mapping from event type")?;
+
+        // A returned payout was reversed by the bank after it succeeded, the webhooks of the
+        // payout which are delivered late must not revert the return
+        if payout_attempt.status == common_enums::PayoutStatus::Returned {
+            logger::warn!(
+                payout_id = %payout_attempt.payout_id,
+                ?status,
+                "Ignoring the status update of a payout which was returned"
+            );
+            return Ok(WebhookResponseTracker::Payout {
+                payout_id: payout_attempt.payout_id,
+                status: payout_attempt.status,
+            });
+        }
+
         let payout_attempt_update = PayoutAttemptUpdate::StatusUpdate {
             connector_payout_id: payout_attempt.connector_payout_id.clone(),
-            status: common_enums::PayoutStatus::foreign_try_from(event_type)
-                .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-                .attach_printable("failed payout status mapping from event type")?,
+            status,
             error_message: None,
             error_code: None,
             is_eligible: payout_attempt.is_eligible,
@@ -983,6 +1432,60 @@ async fn get_or_update_dispute_object(
     }
 }
 
+/// Record the dispute in the risk profile of the customer of the disputed payment when the dispute
+/// is raised, and when the merchant loses the dispute
+#[cfg(feature = "v1")]
+async fn update_customer_risk_profile_for_dispute(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    dispute: &diesel_models::dispute::Dispute,
+    previous_dispute_status: Option<enums::DisputeStatus>,
+) {
+    let events: &[CustomerRiskEvent] = match (previous_dispute_status, dispute.dispute_status) {
+        // A dispute raised as lost is counted both as raised and as lost
+        (None, enums::DisputeStatus::DisputeLost) => &[
+            CustomerRiskEvent::DisputeOpened,
+            CustomerRiskEvent::DisputeLost,
+        ],
+        (None, _) => &[CustomerRiskEvent::DisputeOpened],
+        (Some(previous_dispute_status), enums::DisputeStatus::DisputeLost)
+            if previous_dispute_status != enums::DisputeStatus::DisputeLost =>
+        {
+            &[CustomerRiskEvent::DisputeLost]
+        }
+        (Some(_), _) => return,
+    };
+
+    let payment_intent = match state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            &dispute.payment_id,
+            merchant_account.get_id(),
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(payment_intent) => payment_intent,
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch the payment of the dispute");
+            return;
+        }
+    };
+
+    for event in events {
+        customer_risk_profile::record_customer_risk_event_if_customer_present(
+            state,
+            merchant_account.get_id(),
+            payment_intent.customer_id.as_ref(),
+            *event,
+        )
+        .await;
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
 async fn external_authentication_incoming_webhook_flow(
@@ -1055,6 +1558,14 @@ async fn external_authentication_incoming_webhook_flow(
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Error while updating authentication")?;
+        // The challenge can still be completed without a session, so a failure to store the
+        // result of the challenge does not fail the webhook
+        let _ = crate::core::authentication::three_ds_session::store_challenge_result(
+            &state,
+            &updated_authentication,
+        )
+        .await
+        .inspect_err(|error| logger::error!(?error, "Failed to update 3DS session"));
         // Check if it's a payment authentication flow, payment_id would be there only for payment authentication flows
         if let Some(payment_id) = updated_authentication.payment_id {
             let is_pull_mechanism_enabled = helper_utils::check_if_pull_mechanism_for_external_3ds_enabled_from_connector_metadata(merchant_connector_account.metadata.map(|metadata| metadata.expose()));
@@ -1386,6 +1897,10 @@ async fn disputes_incoming_webhook_flow(
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+        #[cfg(feature = "v1")]
+        let previous_dispute_status = option_dispute
+            .as_ref()
+            .map(|dispute| dispute.dispute_status);
         let dispute_object = get_or_update_dispute_object(
             state.clone(),
             option_dispute,
@@ -1398,6 +1913,15 @@ async fn disputes_incoming_webhook_flow(
             connector.id(),
         )
         .await?;
+        #[cfg(feature = "v1")]
+        update_customer_risk_profile_for_dispute(
+            &state,
+            &merchant_account,
+            &key_store,
+            &dispute_object,
+            previous_dispute_status,
+        )
+        .await;
         let disputes_response = Box::new(dispute_object.clone().foreign_into());
         let event_type: enums::EventType = dispute_object.dispute_status.foreign_into();
 
@@ -1715,3 +2239,50 @@ async fn fetch_optional_mca_and_connector(
         Ok((None, connector, connector_name))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_get_webhook_replay_key_ttl_covers_twice_the_tolerance() {
+        let tolerance = settings::WebhookTimestampTolerance {
+            tolerance: 300,
+            unit: settings::WebhookTimestampUnit::Seconds,
+        };
+        assert_eq!(get_webhook_replay_key_ttl(&tolerance), 600);
+
+        let tolerance = settings::WebhookTimestampTolerance {
+            tolerance: 1500,
+            unit: settings::WebhookTimestampUnit::Milliseconds,
+        };
+        assert_eq!(get_webhook_replay_key_ttl(&tolerance), 4);
+    }
+
+    #[test]
+    fn test_get_webhook_replay_key_ttl_is_never_zero() {
+        let tolerance = settings::WebhookTimestampTolerance {
+            tolerance: 0,
+            unit: settings::WebhookTimestampUnit::Seconds,
+        };
+        assert_eq!(get_webhook_replay_key_ttl(&tolerance), 1);
+    }
+
+    #[test]
+    fn test_get_webhook_replay_key_identifies_the_delivery() {
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+
+        let key = get_webhook_replay_key(&merchant_id, "stripe", 1700000000, b"{}").unwrap();
+        let replayed_key =
+            get_webhook_replay_key(&merchant_id, "stripe", 1700000000, b"{}").unwrap();
+        let retried_key =
+            get_webhook_replay_key(&merchant_id, "stripe", 1700000060, b"{}").unwrap();
+
+        assert!(key.starts_with("webhook_replay_merchant_1_stripe_"));
+        assert_eq!(key, replayed_key);
+        assert_ne!(key, retried_key);
+    }
+}
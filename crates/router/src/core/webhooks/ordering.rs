@@ -0,0 +1,171 @@
+//! In-order delivery of the outgoing webhooks of a resource.
+//!
+//! When the business profile enables ordered delivery, each event of a resource is assigned the
+//! next sequence number of the resource. An event is delivered only once the merchant has
+//! acknowledged the event preceding it, otherwise its delivery is deferred to the outgoing webhook
+//! retry task. An event whose predecessor is never acknowledged, for example because the delivery
+//! of the predecessor was abandoned, is delivered out of order once it has been held for the
+//! configured gap timeout.
+
+use router_env::{instrument, tracing};
+
+use crate::{logger, routes::SessionState, types::domain};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DeliveryOrder {
+    Deliver,
+    Hold,
+}
+
+/// Assign the next sequence number of the resource to the event, if the business profile enables
+/// ordered delivery.
+///
+/// Failures are logged and the event is then delivered without ordering guarantees.
+#[instrument(skip_all)]
+pub(crate) async fn assign_sequence_number(
+    state: &SessionState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    event: domain::Event,
+) -> domain::Event {
+    let is_ordered_delivery_enabled = business_profile
+        .webhook_details
+        .as_ref()
+        .is_some_and(|webhook_details| webhook_details.is_ordered_delivery_enabled());
+    if !is_ordered_delivery_enabled {
+        return event;
+    }
+
+    let sequence_number = match state
+        .store
+        .get_next_webhook_event_sequence_number(
+            &business_profile.merchant_id,
+            event.primary_object_type,
+            &event.primary_object_id,
+        )
+        .await
+    {
+        Ok(sequence_number) => sequence_number,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                event_id = %event.event_id,
+                "Failed to assign sequence number to event, delivering it without ordering"
+            );
+            return event;
+        }
+    };
+
+    state
+        .store
+        .update_event_by_merchant_id_event_id(
+            &state.into(),
+            &business_profile.merchant_id,
+            &event.event_id,
+            domain::EventUpdate::UpdateSequenceNumber { sequence_number },
+            merchant_key_store,
+        )
+        .await
+        .inspect_err(|error| {
+            logger::error!(
+                ?error,
+                event_id = %event.event_id,
+                "Failed to store sequence number of event, delivering it without ordering"
+            );
+        })
+        .unwrap_or(event)
+}
+
+/// Determine whether the event can be delivered, or has to be held until the event preceding it
+/// is acknowledged by the merchant. `held_since` is the time the event was first due for delivery.
+#[instrument(skip_all)]
+pub(crate) async fn get_delivery_order(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    event: &domain::Event,
+    held_since: time::PrimitiveDateTime,
+) -> DeliveryOrder {
+    let Some(sequence_number) = event.sequence_number else {
+        return DeliveryOrder::Deliver;
+    };
+
+    let webhook_event_sequence = match state
+        .store
+        .find_webhook_event_sequence_by_merchant_id_primary_object(
+            merchant_id,
+            event.primary_object_type,
+            &event.primary_object_id,
+        )
+        .await
+    {
+        Ok(Some(webhook_event_sequence)) => webhook_event_sequence,
+        Ok(None) => return DeliveryOrder::Deliver,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                event_id = %event.event_id,
+                "Failed to fetch webhook event sequence, delivering event without ordering"
+            );
+            return DeliveryOrder::Deliver;
+        }
+    };
+
+    if sequence_number <= webhook_event_sequence.last_acknowledged_sequence_number + 1 {
+        return DeliveryOrder::Deliver;
+    }
+
+    let gap_timeout = time::Duration::seconds(
+        i64::try_from(state.conf.webhooks.ordered_delivery.gap_timeout).unwrap_or(i64::MAX),
+    );
+    if common_utils::date_time::now() >= held_since.saturating_add(gap_timeout) {
+        logger::warn!(
+            event_id = %event.event_id,
+            sequence_number,
+            last_acknowledged_sequence_number =
+                webhook_event_sequence.last_acknowledged_sequence_number,
+            "Previous webhooks of the resource were not acknowledged within the gap timeout, \
+             delivering event out of order"
+        );
+        return DeliveryOrder::Deliver;
+    }
+
+    DeliveryOrder::Hold
+}
+
+/// Record that the merchant acknowledged the event, allowing the next event of the resource to be
+/// delivered
+#[instrument(skip_all)]
+pub(crate) async fn acknowledge_event(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    event: &domain::Event,
+) {
+    let Some(sequence_number) = event.sequence_number else {
+        return;
+    };
+
+    if let Err(error) = state
+        .store
+        .acknowledge_webhook_event_sequence_number(
+            merchant_id,
+            event.primary_object_type,
+            &event.primary_object_id,
+            sequence_number,
+        )
+        .await
+    {
+        logger::error!(
+            ?error,
+            event_id = %event.event_id,
+            "Failed to record acknowledgement of event sequence number"
+        );
+    }
+}
+
+/// Time after which a held event checks again whether it can be delivered
+pub(crate) fn get_hold_schedule_time(state: &SessionState) -> time::PrimitiveDateTime {
+    let poll_interval = time::Duration::seconds(
+        i64::try_from(state.conf.webhooks.ordered_delivery.poll_interval).unwrap_or(i64::MAX),
+    );
+    common_utils::date_time::now().saturating_add(poll_interval)
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, str::FromStr};
 
 use api_models::{
     webhook_events::{OutgoingWebhookRequestContent, OutgoingWebhookResponseContent},
@@ -8,7 +8,10 @@ use common_utils::{
     ext_traits::{Encode, StringExt},
     request::RequestContent,
     type_name,
-    types::keymanager::{Identifier, KeyManagerState},
+    types::{
+        keymanager::{Identifier, KeyManagerState},
+        MinorUnit,
+    },
 };
 use diesel_models::process_tracker::business_status;
 use error_stack::{report, ResultExt};
@@ -20,7 +23,7 @@ use router_env::{
     tracing::{self, Instrument},
 };
 
-use super::{types, utils, MERCHANT_ID};
+use super::{delivery_scheduler, ordering, types, utils, MERCHANT_ID};
 #[cfg(feature = "stripe")]
 use crate::compatibility::stripe::webhooks as stripe_webhooks;
 use crate::{
@@ -79,6 +82,16 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         return Ok(());
     }
 
+    if !is_outgoing_webhook_subscribed(&business_profile, event_type, &content) {
+        logger::debug!(
+            business_profile_id=?business_profile.get_id(),
+            %idempotent_event_id,
+            "Outgoing webhooks are disabled for the event type, or the amount of the event is below \
+             the minimum amount; skipping outgoing webhooks for event"
+        );
+        return Ok(());
+    }
+
     let event_id = utils::generate_event_id();
     let merchant_id = business_profile.merchant_id.clone();
     let now = common_utils::date_time::now();
@@ -133,6 +146,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         response: None,
         delivery_attempt: Some(delivery_attempt),
         metadata: Some(event_metadata),
+        sequence_number: None,
     };
 
     let event_insert_result = state
@@ -155,6 +169,10 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         }
     }?;
 
+    let event =
+        ordering::assign_sequence_number(&state, merchant_key_store, &business_profile, event)
+            .await;
+
     let process_tracker = add_outgoing_webhook_retry_task_to_process_tracker(
         &*state.store,
         &business_profile,
@@ -169,11 +187,36 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     })
     .ok();
 
+    // A held webhook is delivered by the retry task once the previous webhooks of the resource
+    // have been acknowledged. Without the retry task, the webhook is delivered right away.
+    if process_tracker.is_some()
+        && ordering::get_delivery_order(&state, &merchant_id, &event, event.created_at).await
+            == ordering::DeliveryOrder::Hold
+    {
+        logger::info!(
+            event_id = %event.event_id,
+            sequence_number = ?event.sequence_number,
+            "Holding webhook until the previous webhooks of the resource are acknowledged"
+        );
+        return Ok(());
+    }
+
     let cloned_key_store = merchant_key_store.clone();
     // Using a tokio spawn here and not arbiter because not all caller of this function
     // may have an actix arbiter
     tokio::spawn(
         async move {
+            // Without the retry task, the delivery cannot be deferred and only waits for a
+            // delivery slot of the merchant
+            let _delivery_permit = if process_tracker.is_some() {
+                match delivery_scheduler::schedule_delivery(&state, &merchant_id).await {
+                    delivery_scheduler::ScheduledDelivery::Deliver(permit) => permit,
+                    delivery_scheduler::ScheduledDelivery::Defer { .. } => return,
+                }
+            } else {
+                delivery_scheduler::acquire_delivery_slot(&state, &merchant_id).await
+            };
+
             Box::pin(trigger_webhook_and_raise_event(
                 state,
                 business_profile,
@@ -192,6 +235,48 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     Ok(())
 }
 
+/// Whether webhooks are posted for the event, based on the event types and the minimum amounts
+/// configured by the merchant. Events without an amount, such as those of mandates, are not
+/// filtered by their amount.
+fn is_outgoing_webhook_subscribed(
+    business_profile: &domain::Profile,
+    event_type: enums::EventType,
+    content: &api::OutgoingWebhookContent,
+) -> bool {
+    let Some(webhook_details) = business_profile.webhook_details.as_ref() else {
+        return true;
+    };
+
+    webhook_details.is_event_type_enabled(event_type)
+        && get_outgoing_webhook_content_amount(content).map_or(true, |(amount, currency)| {
+            webhook_details.meets_minimum_amount(amount, currency)
+        })
+}
+
+fn get_outgoing_webhook_content_amount(
+    content: &api::OutgoingWebhookContent,
+) -> Option<(MinorUnit, enums::Currency)> {
+    match content {
+        api::OutgoingWebhookContent::PaymentDetails(payment) => {
+            enums::Currency::from_str(&payment.currency)
+                .ok()
+                .map(|currency| (payment.amount, currency))
+        }
+        api::OutgoingWebhookContent::RefundDetails(refund) => {
+            enums::Currency::from_str(&refund.currency)
+                .ok()
+                .map(|currency| (refund.amount, currency))
+        }
+        #[cfg(feature = "payouts")]
+        api::OutgoingWebhookContent::PayoutDetails(payout) => {
+            Some((payout.amount, payout.currency))
+        }
+        // The amount of disputes is in the format of the connector
+        api::OutgoingWebhookContent::DisputeDetails(_)
+        | api::OutgoingWebhookContent::MandateDetails(_) => None,
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
 pub(crate) async fn trigger_webhook_and_raise_event(
@@ -270,11 +355,17 @@ async fn trigger_webhook_to_merchant(
 
     let event_id = event.event_id;
 
-    let headers = request_content
+    let mut headers: Vec<_> = request_content
         .headers
         .into_iter()
         .map(|(name, value)| (name, value.into_masked()))
         .collect();
+    if let Some(sequence_number) = event.sequence_number {
+        headers.push((
+            crate::headers::X_WEBHOOK_SEQUENCE_NUMBER.to_string(),
+            sequence_number.to_string().into(),
+        ));
+    }
     let request = services::RequestBuilder::new()
         .method(services::Method::Post)
         .url(&webhook_url)
@@ -290,6 +381,15 @@ async fn trigger_webhook_to_merchant(
         .send_request(&state, request, Some(OUTGOING_WEBHOOK_TIMEOUT_SECS), false)
         .await;
 
+    delivery_scheduler::record_delivery_outcome(
+        &state,
+        &business_profile.merchant_id,
+        response
+            .as_ref()
+            .is_ok_and(|response| response.status().is_success()),
+    )
+    .await;
+
     metrics::WEBHOOK_OUTGOING_COUNT.add(
         &metrics::CONTEXT,
         1,
@@ -826,7 +926,7 @@ async fn update_event_in_storage(
             .attach_printable("Failed to encrypt outgoing webhook response content")?,
         ),
     };
-    state
+    let updated_event = state
         .store
         .update_event_by_merchant_id_event_id(
             key_manager_state,
@@ -836,7 +936,13 @@ async fn update_event_in_storage(
             &merchant_key_store,
         )
         .await
-        .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)
+        .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)?;
+
+    if is_webhook_notified {
+        ordering::acknowledge_event(&state, merchant_id, &updated_event).await;
+    }
+
+    Ok(updated_event)
 }
 
 fn increment_webhook_outgoing_received_count(merchant_id: &common_utils::id_type::MerchantId) {
@@ -87,6 +87,7 @@ pub async fn construct_webhook_router_data<'a>(
         auth_type: diesel_models::enums::AuthenticationType::default(),
         connector_meta_data: None,
         connector_wallets_details: None,
+        connector_request_timeout_secs: None,
         amount_captured: None,
         minor_amount_captured: None,
         request: types::VerifyWebhookSourceRequestData {
@@ -114,6 +115,7 @@ pub async fn construct_webhook_router_data<'a>(
         connector_api_version: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_network_retry_count: None,
         apple_pay_flow: None,
         frm_metadata: None,
         refund_id: None,
@@ -149,3 +151,47 @@ pub(crate) fn get_idempotent_event_id(
 pub(crate) fn generate_event_id() -> String {
     common_utils::generate_time_ordered_id("evt")
 }
+
+const RESOURCE_FIELD_PATH_SEPARATOR: char = '.';
+
+/// Get the required fields which are missing or null in the resource of the webhook
+pub(crate) fn get_missing_resource_fields<'a>(
+    resource_object: &serde_json::Value,
+    required_fields: &'a [String],
+) -> Vec<&'a str> {
+    required_fields
+        .iter()
+        .map(String::as_str)
+        .filter(|field_path| {
+            field_path
+                .split(RESOURCE_FIELD_PATH_SEPARATOR)
+                .try_fold(resource_object, |value, key| value.get(key))
+                .map_or(true, serde_json::Value::is_null)
+        })
+        .collect()
+}
+
+/// Fill the fields which are missing or null in the resource of the webhook with the fields of the
+/// resource fetched from the connector. The fields sent in the webhook are retained, since the
+/// webhook describes the event which is being processed.
+pub(crate) fn merge_fetched_resource(
+    resource_object: &mut serde_json::Value,
+    fetched_resource: serde_json::Value,
+) {
+    match (resource_object, fetched_resource) {
+        (serde_json::Value::Object(fields), serde_json::Value::Object(fetched_fields)) => {
+            fetched_fields
+                .into_iter()
+                .for_each(|(key, fetched_field)| match fields.get_mut(&key) {
+                    Some(field) => merge_fetched_resource(field, fetched_field),
+                    None => {
+                        fields.insert(key, fetched_field);
+                    }
+                })
+        }
+        (resource_object, fetched_resource) if resource_object.is_null() => {
+            *resource_object = fetched_resource
+        }
+        _ => {}
+    }
+}
@@ -216,6 +216,7 @@ pub async fn retry_delivery_attempt(
         response: None,
         delivery_attempt: Some(delivery_attempt),
         metadata: event_to_retry.metadata,
+        sequence_number: event_to_retry.sequence_number,
     };
 
     let event = store
@@ -235,6 +236,9 @@ pub async fn retry_delivery_attempt(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to parse webhook event request information")?;
 
+    let delivery_permit =
+        super::delivery_scheduler::acquire_delivery_slot(&state, &key_store.merchant_id).await;
+
     Box::pin(super::outgoing::trigger_webhook_and_raise_event(
         state.clone(),
         business_profile,
@@ -246,6 +250,7 @@ pub async fn retry_delivery_attempt(
         None,
     ))
     .await;
+    drop(delivery_permit);
 
     let updated_event = store
         .find_event_by_merchant_id_event_id(
@@ -262,6 +267,37 @@ pub async fn retry_delivery_attempt(
     ))
 }
 
+#[instrument(skip(state))]
+pub async fn retrieve_delivery_queue(
+    state: SessionState,
+    merchant_id: common_utils::id_type::MerchantId,
+) -> RouterResponse<api::webhook_events::WebhookDeliveryQueueResponse> {
+    let store = state.store.as_ref();
+    store
+        .get_merchant_key_store_by_merchant_id(
+            &(&state).into(),
+            &merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let status = super::delivery_scheduler::get_delivery_queue_status(&state, &merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get the webhook delivery queue status")?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::WebhookDeliveryQueueResponse {
+            merchant_id,
+            in_flight_deliveries: status.in_flight_deliveries,
+            queued_deliveries: status.queued_deliveries,
+            consecutive_failures: status.consecutive_failures,
+            backed_off_until: status.backed_off_until,
+        },
+    ))
+}
+
 async fn get_account_and_key_store(
     state: SessionState,
     merchant_id: common_utils::id_type::MerchantId,
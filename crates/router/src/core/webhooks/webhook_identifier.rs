@@ -0,0 +1,176 @@
+//! Webhook endpoints of merchant connector accounts.
+//!
+//! Every merchant connector account is assigned an unguessable webhook identifier which is
+//! embedded in the path of its webhook endpoint. The identifier is resolved to the owning merchant
+//! connector account through a reverse lookup, so that incoming webhooks are attributed to the
+//! right merchant and their source is verified against the secret of the right connector account.
+//!
+//! When the identifier is rotated, the previous identifier continues to be accepted for a grace
+//! period so that webhooks already being delivered to the previous endpoint are not dropped.
+
+use common_utils::id_type;
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    types::{domain, storage},
+};
+
+const WEBHOOK_IDENTIFIER_PREFIX: &str = "whid";
+
+/// The merchant connector account to which a webhook identifier belongs
+pub struct WebhookIdentifierOwner {
+    pub merchant_account: domain::MerchantAccount,
+    pub key_store: domain::MerchantKeyStore,
+    pub merchant_connector_account: domain::MerchantConnectorAccount,
+}
+
+pub fn generate_webhook_identifier() -> String {
+    common_utils::generate_id(consts::WEBHOOK_IDENTIFIER_LENGTH, WEBHOOK_IDENTIFIER_PREFIX)
+}
+
+/// The lookup id of the webhook identifier. Unlike the other reverse lookups, the lookup is not in
+/// the namespace of the merchant, since it is what resolves a webhook to its merchant. The
+/// identifier is generated, so the lookup ids of different merchants cannot collide, and the
+/// lookups of a merchant can be deleted by the merchant id they point to.
+fn get_webhook_identifier_lookup_id(webhook_identifier: &str) -> String {
+    format!("mca_webhook_{webhook_identifier}")
+}
+
+fn get_previous_webhook_identifier_key(webhook_identifier: &str) -> String {
+    format!("mca_previous_webhook_{webhook_identifier}")
+}
+
+/// Register the webhook identifier so that it can be resolved to the merchant connector account
+#[instrument(skip_all)]
+pub async fn insert_webhook_identifier_lookup(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    webhook_identifier: &str,
+) -> RouterResult<()> {
+    let storage_scheme = storage::enums::MerchantStorageScheme::PostgresOnly;
+    state
+        .store
+        .insert_reverse_lookup(
+            storage::ReverseLookupNew {
+                lookup_id: get_webhook_identifier_lookup_id(webhook_identifier),
+                pk_id: merchant_id.get_string_repr().to_owned(),
+                sk_id: merchant_connector_id.get_string_repr().to_owned(),
+                source: "merchant_connector_account".to_string(),
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert reverse lookup for the webhook identifier")?;
+
+    Ok(())
+}
+
+/// Keep accepting webhooks sent to a rotated webhook identifier for the rotation grace period
+#[instrument(skip_all)]
+pub async fn retain_previous_webhook_identifier(
+    state: &SessionState,
+    webhook_identifier: &str,
+) -> RouterResult<()> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .set_key_with_expiry(
+            &get_previous_webhook_identifier_key(webhook_identifier),
+            "true",
+            consts::WEBHOOK_IDENTIFIER_ROTATION_GRACE_PERIOD,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retain the previous webhook identifier")
+}
+
+async fn is_previous_webhook_identifier_retained(
+    state: &SessionState,
+    webhook_identifier: &str,
+) -> RouterResult<bool> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .exists::<()>(&get_previous_webhook_identifier_key(webhook_identifier))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to check whether the previous webhook identifier is retained")
+}
+
+/// Resolve the webhook identifier to the merchant connector account which owns it.
+///
+/// Identifiers which are unknown, or which were rotated more than the grace period ago, are
+/// rejected.
+#[instrument(skip_all)]
+pub async fn resolve_webhook_identifier(
+    state: &SessionState,
+    webhook_identifier: &str,
+) -> RouterResult<WebhookIdentifierOwner> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+
+    let lookup = db
+        .get_lookup_by_lookup_id(
+            &get_webhook_identifier_lookup_id(webhook_identifier),
+            storage::enums::MerchantStorageScheme::PostgresOnly,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookAuthenticationFailed)?;
+
+    let merchant_id = id_type::MerchantId::wrap(lookup.pk_id)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid merchant id in the webhook identifier lookup")?;
+    let merchant_connector_id = id_type::MerchantConnectorAccountId::wrap(lookup.sk_id)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid merchant connector id in the webhook identifier lookup")?;
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookAuthenticationFailed)?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(key_manager_state, &merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookAuthenticationFailed)?;
+
+    let merchant_connector_account = db
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            key_manager_state,
+            &merchant_id,
+            &merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookAuthenticationFailed)?;
+
+    let is_current_identifier =
+        merchant_connector_account.webhook_identifier.as_deref() == Some(webhook_identifier);
+    if !is_current_identifier
+        && !is_previous_webhook_identifier_retained(state, webhook_identifier).await?
+    {
+        return Err(errors::ApiErrorResponse::WebhookAuthenticationFailed)
+            .attach_printable("Webhook identifier was rotated and is no longer accepted");
+    }
+
+    Ok(WebhookIdentifierOwner {
+        merchant_account,
+        key_store,
+        merchant_connector_account,
+    })
+}
@@ -1,7 +1,9 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_log;
 pub mod authentication;
 pub mod authorization;
+pub mod bank_account_token;
 pub mod blocklist;
 pub mod blocklist_fingerprint;
 pub mod blocklist_lookup;
@@ -9,8 +11,14 @@ pub mod business_profile;
 pub mod capture;
 pub mod cards_info;
 pub mod configs;
+pub mod connector_token_revocation;
+pub mod connector_webhook_offset;
+pub mod customer_communication;
+pub mod customer_connector_sync;
+pub mod customer_risk_profile;
 pub mod customers;
 pub mod dashboard_metadata;
+pub mod data_retention;
 pub mod dispute;
 pub mod ephemeral_key;
 pub mod events;
@@ -19,24 +27,33 @@ pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
 pub mod health_check;
+pub mod incoming_webhook_event;
 pub mod kafka_store;
+pub mod legal_hold;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
+pub mod merchant_onboarding_audit_log;
 pub mod organization;
+pub mod payment_dedup;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_processing_claim;
 pub mod refund;
+pub mod resource_sequence;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod settlement_reconciliation;
+pub mod three_ds_session;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_event_sequence;
 use common_utils::id_type;
 use diesel_models::{
     fraud_check::{FraudCheck, FraudCheckUpdate},
@@ -87,11 +104,13 @@ pub trait StorageInterface:
     + dyn_clone::DynClone
     + address::AddressInterface
     + api_keys::ApiKeyInterface
+    + audit_log::AuditLogInterface
     + blocklist_lookup::BlocklistLookupInterface
     + configs::ConfigInterface
     + capture::CaptureInterface
     + customers::CustomerInterface
     + dashboard_metadata::DashboardMetadataInterface
+    + data_retention::DataRetentionInterface
     + dispute::DisputeInterface
     + ephemeral_key::EphemeralKeyInterface
     + events::EventInterface
@@ -105,15 +124,21 @@ pub trait StorageInterface:
     + PaymentAttemptInterface
     + PaymentIntentInterface
     + payment_method::PaymentMethodInterface
+    + payment_processing_claim::PaymentProcessingClaimInterface
     + blocklist::BlocklistInterface
     + blocklist_fingerprint::BlocklistFingerprintInterface
     + scheduler::SchedulerInterface
     + PayoutAttemptInterface
     + PayoutsInterface
     + refund::RefundInterface
+    + resource_sequence::ResourceSequenceInterface
     + reverse_lookup::ReverseLookupInterface
     + cards_info::CardsInfoInterface
+    + customer_communication::CustomerCommunicationInterface
+    + customer_connector_sync::CustomerConnectorSyncInterface
+    + customer_risk_profile::CustomerRiskProfileInterface
     + merchant_key_store::MerchantKeyStoreInterface
+    + merchant_onboarding_audit_log::MerchantOnboardingAuditLogInterface
     + MasterKeyInterface
     + payment_link::PaymentLinkInterface
     + RedisConnInterface
@@ -121,7 +146,15 @@ pub trait StorageInterface:
     + business_profile::ProfileInterface
     + OrganizationInterface
     + routing_algorithm::RoutingAlgorithmInterface
+    + settlement_reconciliation::SettlementReconciliationInterface
+    + connector_token_revocation::ConnectorTokenRevocationInterface
+    + connector_webhook_offset::ConnectorWebhookOffsetInterface
+    + bank_account_token::BankAccountTokenInterface
+    + payment_dedup::PaymentDedupInterface
+    + three_ds_session::ThreeDsSessionInterface
     + gsm::GsmInterface
+    + incoming_webhook_event::IncomingWebhookEventInterface
+    + legal_hold::LegalHoldInterface
     + unified_translations::UnifiedTranslationsInterface
     + user_role::UserRoleInterface
     + authorization::AuthorizationInterface
@@ -131,6 +164,7 @@ pub trait StorageInterface:
     + user_authentication_method::UserAuthenticationMethodInterface
     + authentication::AuthenticationInterface
     + generic_link::GenericLinkInterface
+    + webhook_event_sequence::WebhookEventSequenceInterface
     + 'static
 {
     fn get_scheduler_db(&self) -> Box<dyn scheduler::SchedulerInterface>;
@@ -1,3 +1,4 @@
+use async_bb8_diesel::AsyncConnection;
 use error_stack::report;
 use router_env::{instrument, tracing};
 #[cfg(feature = "accounts_cache")]
@@ -30,6 +31,30 @@ pub trait ApiKeyInterface {
         key_id: &str,
     ) -> CustomResult<bool, errors::StorageError>;
 
+    /// Insert the API key and record its creation in the audit log, in the same transaction
+    async fn insert_api_key_with_audit_log(
+        &self,
+        api_key: storage::ApiKeyNew,
+        audit_log: storage::AuditLogContext,
+    ) -> CustomResult<storage::ApiKey, errors::StorageError>;
+
+    /// Update the API key and record the changed fields in the audit log, in the same transaction
+    async fn update_api_key_with_audit_log(
+        &self,
+        merchant_id: common_utils::id_type::MerchantId,
+        key_id: String,
+        api_key: storage::ApiKeyUpdate,
+        audit_log: storage::AuditLogContext,
+    ) -> CustomResult<storage::ApiKey, errors::StorageError>;
+
+    /// Revoke the API key and record the revocation in the audit log, in the same transaction
+    async fn revoke_api_key_with_audit_log(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        key_id: &str,
+        audit_log: storage::AuditLogContext,
+    ) -> CustomResult<bool, errors::StorageError>;
+
     async fn find_api_key_by_merchant_id_key_id_optional(
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -153,6 +178,154 @@ impl ApiKeyInterface for Store {
         }
     }
 
+    #[instrument(skip_all)]
+    async fn insert_api_key_with_audit_log(
+        &self,
+        api_key: storage::ApiKeyNew,
+        audit_log: storage::AuditLogContext,
+    ) -> CustomResult<storage::ApiKey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        let inserted_api_key = conn
+            .transaction_async(|conn| async move {
+                let inserted_api_key = api_key.insert(&conn).await?;
+
+                audit_log
+                    .record(
+                        &conn,
+                        &inserted_api_key.merchant_id,
+                        storage::enums::AuditLogResourceType::ApiKey,
+                        &inserted_api_key.key_id,
+                        storage::enums::AuditLogAction::Create,
+                        None,
+                        Some(&inserted_api_key),
+                    )
+                    .await?;
+
+                Ok::<_, errors::StorageError>(inserted_api_key)
+            })
+            .await?;
+
+        Ok(inserted_api_key)
+    }
+
+    #[instrument(skip_all)]
+    async fn update_api_key_with_audit_log(
+        &self,
+        merchant_id: common_utils::id_type::MerchantId,
+        key_id: String,
+        api_key: storage::ApiKeyUpdate,
+        audit_log: storage::AuditLogContext,
+    ) -> CustomResult<storage::ApiKey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        let updated_api_key = conn
+            .transaction_async(|conn| async move {
+                let current_api_key =
+                    storage::ApiKey::find_optional_by_merchant_id_key_id_for_update(
+                        &conn,
+                        &merchant_id,
+                        &key_id,
+                    )
+                    .await?
+                    .ok_or(errors::StorageError::ValueNotFound(format!(
+                        "ApiKey of {key_id} not found"
+                    )))?;
+
+                let updated_api_key = storage::ApiKey::update_by_merchant_id_key_id(
+                    &conn,
+                    merchant_id.clone(),
+                    key_id.clone(),
+                    api_key,
+                )
+                .await?;
+
+                audit_log
+                    .record(
+                        &conn,
+                        &merchant_id,
+                        storage::enums::AuditLogResourceType::ApiKey,
+                        &key_id,
+                        storage::enums::AuditLogAction::Update,
+                        Some(&current_api_key),
+                        Some(&updated_api_key),
+                    )
+                    .await?;
+
+                Ok::<_, errors::StorageError>(updated_api_key)
+            })
+            .await?;
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            // The key that's saved in cache is the HashedApiKey, which is not updated
+            cache::publish_into_redact_channel(
+                self,
+                [CacheKind::Accounts(
+                    updated_api_key.hashed_api_key.clone().into_inner().into(),
+                )],
+            )
+            .await?;
+        }
+
+        Ok(updated_api_key)
+    }
+
+    #[instrument(skip_all)]
+    async fn revoke_api_key_with_audit_log(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        key_id: &str,
+        audit_log: storage::AuditLogContext,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        let merchant_id = merchant_id.to_owned();
+        let key_id = key_id.to_owned();
+
+        let (revoked, _revoked_api_key) = conn
+            .transaction_async(|conn| async move {
+                let current_api_key =
+                    storage::ApiKey::find_optional_by_merchant_id_key_id_for_update(
+                        &conn,
+                        &merchant_id,
+                        &key_id,
+                    )
+                    .await?;
+
+                let revoked =
+                    storage::ApiKey::revoke_by_merchant_id_key_id(&conn, &merchant_id, &key_id)
+                        .await?;
+
+                if let Some(current_api_key) = &current_api_key {
+                    audit_log
+                        .record(
+                            &conn,
+                            &merchant_id,
+                            storage::enums::AuditLogResourceType::ApiKey,
+                            &key_id,
+                            storage::enums::AuditLogAction::Revoke,
+                            Some(current_api_key),
+                            None,
+                        )
+                        .await?;
+                }
+
+                Ok::<_, errors::StorageError>((revoked, current_api_key))
+            })
+            .await?;
+
+        #[cfg(feature = "accounts_cache")]
+        if let Some(revoked_api_key) = _revoked_api_key {
+            cache::publish_into_redact_channel(
+                self,
+                [CacheKind::Accounts(
+                    revoked_api_key.hashed_api_key.into_inner().into(),
+                )],
+            )
+            .await?;
+        }
+
+        Ok(revoked)
+    }
+
     #[instrument(skip_all)]
     async fn find_api_key_by_merchant_id_key_id_optional(
         &self,
@@ -299,6 +472,34 @@ impl ApiKeyInterface for MockDb {
         }
     }
 
+    // The audit log is not stored in the mock db, so the changes are made without being recorded
+    async fn insert_api_key_with_audit_log(
+        &self,
+        api_key: storage::ApiKeyNew,
+        _audit_log: storage::AuditLogContext,
+    ) -> CustomResult<storage::ApiKey, errors::StorageError> {
+        self.insert_api_key(api_key).await
+    }
+
+    async fn update_api_key_with_audit_log(
+        &self,
+        merchant_id: common_utils::id_type::MerchantId,
+        key_id: String,
+        api_key: storage::ApiKeyUpdate,
+        _audit_log: storage::AuditLogContext,
+    ) -> CustomResult<storage::ApiKey, errors::StorageError> {
+        self.update_api_key(merchant_id, key_id, api_key).await
+    }
+
+    async fn revoke_api_key_with_audit_log(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        key_id: &str,
+        _audit_log: storage::AuditLogContext,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.revoke_api_key(merchant_id, key_id).await
+    }
+
     async fn find_api_key_by_merchant_id_key_id_optional(
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
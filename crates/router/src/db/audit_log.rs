@@ -0,0 +1,126 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait AuditLogInterface {
+    #[allow(clippy::too_many_arguments)]
+    async fn find_audit_logs_by_resource(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        resource_type: enums::AuditLogResourceType,
+        resource_id: &str,
+        created_after: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::AuditLog>, errors::StorageError>;
+
+    /// Delete the entries of all the merchants which were created before the time
+    async fn delete_audit_logs_created_before(
+        &self,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AuditLogInterface for Store {
+    #[instrument(skip_all)]
+    async fn find_audit_logs_by_resource(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        resource_type: enums::AuditLogResourceType,
+        resource_id: &str,
+        created_after: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::AuditLog>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AuditLog::find_by_merchant_id_resource(
+            &conn,
+            merchant_id,
+            resource_type,
+            resource_id,
+            created_after,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_audit_logs_created_before(
+        &self,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::AuditLog::delete_created_before(&conn, created_before)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditLogInterface for MockDb {
+    async fn find_audit_logs_by_resource(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _resource_type: enums::AuditLogResourceType,
+        _resource_id: &str,
+        _created_after: time::PrimitiveDateTime,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::AuditLog>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_audit_logs_created_before(
+        &self,
+        _created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditLogInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn find_audit_logs_by_resource(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        resource_type: enums::AuditLogResourceType,
+        resource_id: &str,
+        created_after: time::PrimitiveDateTime,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::AuditLog>, errors::StorageError> {
+        self.diesel_store
+            .find_audit_logs_by_resource(
+                merchant_id,
+                resource_type,
+                resource_id,
+                created_after,
+                limit,
+                offset,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_audit_logs_created_before(
+        &self,
+        created_before: time::PrimitiveDateTime,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_audit_logs_created_before(created_before)
+            .await
+    }
+}
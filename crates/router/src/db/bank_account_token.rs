@@ -0,0 +1,310 @@
+use async_bb8_diesel::AsyncConnection;
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::{reverse_lookup::ReverseLookupInterface, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+/// The lookup id of the connector bank token in the namespace of the merchant
+fn get_connector_bank_token_lookup_id(
+    merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+    connector_bank_token: &str,
+) -> String {
+    format!(
+        "bank_account_token_{}_{connector_bank_token}",
+        merchant_connector_id.get_string_repr()
+    )
+}
+
+/// The lookup id of the connector bank token registered before the lookups were namespaced
+fn get_legacy_connector_bank_token_lookup_id(
+    merchant_id: &common_utils::id_type::MerchantId,
+    merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+    connector_bank_token: &str,
+) -> String {
+    format!(
+        "bank_account_token_{}_{}_{connector_bank_token}",
+        merchant_id.get_string_repr(),
+        merchant_connector_id.get_string_repr()
+    )
+}
+
+#[async_trait::async_trait]
+pub trait BankAccountTokenInterface {
+    /// Insert the tokenized bank account, and register its connector bank token, if any, so that
+    /// the connector bank token can be resolved to the tokenized bank account
+    async fn insert_bank_account_token(
+        &self,
+        bank_account_token: storage::BankAccountTokenNew,
+    ) -> CustomResult<storage::BankAccountToken, errors::StorageError>;
+
+    async fn find_bank_account_token_by_merchant_id_token_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        token_id: &str,
+    ) -> CustomResult<storage::BankAccountToken, errors::StorageError>;
+
+    /// Find the bank account of the customer which has the same fingerprint, if the bank account
+    /// was already tokenized
+    async fn find_bank_account_token_by_merchant_id_customer_id_fingerprint(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        fingerprint: &str,
+    ) -> CustomResult<storage::BankAccountToken, errors::StorageError>;
+
+    /// Resolve the token of a bank account at a connector to the tokenized bank account
+    async fn find_bank_account_token_by_connector_bank_token(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+        connector_bank_token: &str,
+    ) -> CustomResult<storage::BankAccountToken, errors::StorageError>;
+
+    async fn update_bank_account_token(
+        &self,
+        this: storage::BankAccountToken,
+        bank_account_token_update: storage::BankAccountTokenUpdate,
+    ) -> CustomResult<storage::BankAccountToken, errors::StorageError>;
+}
+
+/// Build the lookup of the connector bank token of the tokenized bank account, if any. The
+/// lookup is inserted in the same transaction as the bank account, so that a bank account is never
+/// stored without the lookup of its connector bank token.
+fn get_connector_bank_token_lookup(
+    bank_account_token: &storage::BankAccountToken,
+) -> Option<storage::ReverseLookupNew> {
+    let (Some(connector_bank_token), Some(merchant_connector_id)) = (
+        bank_account_token.connector_bank_token.as_deref(),
+        bank_account_token.merchant_connector_id.as_ref(),
+    ) else {
+        return None;
+    };
+
+    Some(storage::ReverseLookupNew {
+        lookup_id: storage::reverse_lookup::get_namespaced_lookup_id(
+            &bank_account_token.merchant_id,
+            &get_connector_bank_token_lookup_id(merchant_connector_id, connector_bank_token),
+        ),
+        pk_id: bank_account_token.token_id.clone(),
+        sk_id: bank_account_token.merchant_id.get_string_repr().to_owned(),
+        source: "bank_account_token".to_string(),
+        updated_by: enums::MerchantStorageScheme::PostgresOnly.to_string(),
+    })
+}
+
+#[async_trait::async_trait]
+impl BankAccountTokenInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_bank_account_token(
+        &self,
+        bank_account_token: storage::BankAccountTokenNew,
+    ) -> CustomResult<storage::BankAccountToken, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        conn.transaction_async(|conn| async move {
+            let bank_account_token = bank_account_token.insert(&conn).await?;
+            if let Some(lookup) = get_connector_bank_token_lookup(&bank_account_token) {
+                lookup.insert(&conn).await?;
+            }
+            Ok::<_, errors::StorageError>(bank_account_token)
+        })
+        .await
+        .map_err(|error| report!(error))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_bank_account_token_by_merchant_id_token_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        token_id: &str,
+    ) -> CustomResult<storage::BankAccountToken, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::BankAccountToken::find_by_merchant_id_token_id(&conn, merchant_id, token_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_bank_account_token_by_merchant_id_customer_id_fingerprint(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        fingerprint: &str,
+    ) -> CustomResult<storage::BankAccountToken, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::BankAccountToken::find_by_merchant_id_customer_id_fingerprint(
+            &conn,
+            merchant_id,
+            customer_id,
+            fingerprint,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_bank_account_token_by_connector_bank_token(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+        connector_bank_token: &str,
+    ) -> CustomResult<storage::BankAccountToken, errors::StorageError> {
+        let lookup = self
+            .get_namespaced_lookup_by_lookup_id(
+                &get_connector_bank_token_lookup_id(merchant_connector_id, connector_bank_token),
+                merchant_id,
+                Some(&get_legacy_connector_bank_token_lookup_id(
+                    merchant_id,
+                    merchant_connector_id,
+                    connector_bank_token,
+                )),
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await?;
+        self.find_bank_account_token_by_merchant_id_token_id(merchant_id, &lookup.pk_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_bank_account_token(
+        &self,
+        this: storage::BankAccountToken,
+        bank_account_token_update: storage::BankAccountTokenUpdate,
+    ) -> CustomResult<storage::BankAccountToken, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        let is_connector_bank_token_update = matches!(
+            bank_account_token_update,
+            storage::BankAccountTokenUpdate::ConnectorBankTokenUpdate { .. }
+        );
+        conn.transaction_async(|conn| async move {
+            let bank_account_token = this
+                .update_with_token_id(&conn, bank_account_token_update)
+                .await?;
+            if let Some(lookup) = get_connector_bank_token_lookup(&bank_account_token)
+                .filter(|_| is_connector_bank_token_update)
+            {
+                lookup.insert(&conn).await?;
+            }
+            Ok::<_, errors::StorageError>(bank_account_token)
+        })
+        .await
+        .map_err(|error| report!(error))
+    }
+}
+
+#[async_trait::async_trait]
+impl BankAccountTokenInterface for MockDb {
+    async fn insert_bank_account_token(
+        &self,
+        _bank_account_token: storage::BankAccountTokenNew,
+    ) -> CustomResult<storage::BankAccountToken, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_bank_account_token_by_merchant_id_token_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _token_id: &str,
+    ) -> CustomResult<storage::BankAccountToken, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_bank_account_token_by_merchant_id_customer_id_fingerprint(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _customer_id: &common_utils::id_type::CustomerId,
+        _fingerprint: &str,
+    ) -> CustomResult<storage::BankAccountToken, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_bank_account_token_by_connector_bank_token(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+        _connector_bank_token: &str,
+    ) -> CustomResult<storage::BankAccountToken, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_bank_account_token(
+        &self,
+        _this: storage::BankAccountToken,
+        _bank_account_token_update: storage::BankAccountTokenUpdate,
+    ) -> CustomResult<storage::BankAccountToken, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl BankAccountTokenInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_bank_account_token(
+        &self,
+        bank_account_token: storage::BankAccountTokenNew,
+    ) -> CustomResult<storage::BankAccountToken, errors::StorageError> {
+        self.diesel_store
+            .insert_bank_account_token(bank_account_token)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_bank_account_token_by_merchant_id_token_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        token_id: &str,
+    ) -> CustomResult<storage::BankAccountToken, errors::StorageError> {
+        self.diesel_store
+            .find_bank_account_token_by_merchant_id_token_id(merchant_id, token_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_bank_account_token_by_merchant_id_customer_id_fingerprint(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        fingerprint: &str,
+    ) -> CustomResult<storage::BankAccountToken, errors::StorageError> {
+        self.diesel_store
+            .find_bank_account_token_by_merchant_id_customer_id_fingerprint(
+                merchant_id,
+                customer_id,
+                fingerprint,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_bank_account_token_by_connector_bank_token(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+        connector_bank_token: &str,
+    ) -> CustomResult<storage::BankAccountToken, errors::StorageError> {
+        self.diesel_store
+            .find_bank_account_token_by_connector_bank_token(
+                merchant_id,
+                merchant_connector_id,
+                connector_bank_token,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_bank_account_token(
+        &self,
+        this: storage::BankAccountToken,
+        bank_account_token_update: storage::BankAccountTokenUpdate,
+    ) -> CustomResult<storage::BankAccountToken, errors::StorageError> {
+        self.diesel_store
+            .update_bank_account_token(this, bank_account_token_update)
+            .await
+    }
+}
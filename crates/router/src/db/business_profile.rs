@@ -1,3 +1,4 @@
+use async_bb8_diesel::AsyncConnection;
 use common_utils::{ext_traits::AsyncExt, types::keymanager::KeyManagerState};
 use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
@@ -59,6 +60,17 @@ where
         profile_update: domain::ProfileUpdate,
     ) -> CustomResult<domain::Profile, errors::StorageError>;
 
+    /// Update the routing configuration of the profile, and record the change in the audit log in
+    /// the same transaction
+    async fn update_profile_routing_with_audit_log(
+        &self,
+        key_manager_state: &KeyManagerState,
+        merchant_key_store: &domain::MerchantKeyStore,
+        profile_id: &common_utils::id_type::ProfileId,
+        profile_update: domain::ProfileUpdate,
+        audit_log: storage::AuditLogContext,
+    ) -> CustomResult<domain::Profile, errors::StorageError>;
+
     async fn delete_profile_by_profile_id_merchant_id(
         &self,
         profile_id: &common_utils::id_type::ProfileId,
@@ -184,6 +196,55 @@ impl ProfileInterface for Store {
             .change_context(errors::StorageError::DecryptionError)
     }
 
+    #[instrument(skip_all)]
+    async fn update_profile_routing_with_audit_log(
+        &self,
+        key_manager_state: &KeyManagerState,
+        merchant_key_store: &domain::MerchantKeyStore,
+        profile_id: &common_utils::id_type::ProfileId,
+        profile_update: domain::ProfileUpdate,
+        audit_log: storage::AuditLogContext,
+    ) -> CustomResult<domain::Profile, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        let profile_id = profile_id.to_owned();
+        let updated_profile = conn
+            .transaction_async(|conn| async move {
+                let current_profile =
+                    storage::Profile::find_by_profile_id_for_update(&conn, &profile_id).await?;
+                let current_routing_config = current_profile.get_routing_config();
+                let updated_profile = current_profile
+                    .update_by_profile_id(
+                        &conn,
+                        storage::ProfileUpdateInternal::from(profile_update),
+                    )
+                    .await?;
+
+                audit_log
+                    .record(
+                        &conn,
+                        &updated_profile.merchant_id,
+                        storage::enums::AuditLogResourceType::RoutingConfig,
+                        profile_id.get_string_repr(),
+                        storage::enums::AuditLogAction::Update,
+                        Some(&current_routing_config),
+                        Some(&updated_profile.get_routing_config()),
+                    )
+                    .await?;
+
+                Ok::<_, errors::StorageError>(updated_profile)
+            })
+            .await?;
+
+        updated_profile
+            .convert(
+                key_manager_state,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
     #[instrument(skip_all)]
     async fn delete_profile_by_profile_id_merchant_id(
         &self,
@@ -363,6 +424,18 @@ impl ProfileInterface for MockDb {
             )
     }
 
+    async fn update_profile_routing_with_audit_log(
+        &self,
+        _key_manager_state: &KeyManagerState,
+        _merchant_key_store: &domain::MerchantKeyStore,
+        _profile_id: &common_utils::id_type::ProfileId,
+        _profile_update: domain::ProfileUpdate,
+        _audit_log: storage::AuditLogContext,
+    ) -> CustomResult<domain::Profile, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn delete_profile_by_profile_id_merchant_id(
         &self,
         profile_id: &common_utils::id_type::ProfileId,
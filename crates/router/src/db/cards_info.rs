@@ -1,12 +1,16 @@
+use std::collections::{HashMap, HashSet};
+
+use async_bb8_diesel::AsyncConnection;
 use error_stack::report;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
+use storage_impl::redis::kv_store::RedisConnInterface;
 
 use crate::{
-    connection,
+    connection, consts,
     core::errors::{self, CustomResult},
     db::MockDb,
     services::Store,
-    types::storage::cards_info::CardInfo,
+    types::storage::cards_info::{CardInfo, CardInfoBulkUpsertSummary},
 };
 
 #[async_trait::async_trait]
@@ -15,6 +19,29 @@ pub trait CardsInfoInterface {
         &self,
         _card_iin: &str,
     ) -> CustomResult<Option<CardInfo>, errors::StorageError>;
+
+    /// Insert or update the provided card info records in batches, each batch being applied
+    /// within a transaction. When `prune_missing_records` is set, the records which are not
+    /// present in `records` are deleted once all the batches have been applied.
+    async fn bulk_upsert_card_info(
+        &self,
+        records: Vec<CardInfo>,
+        prune_missing_records: bool,
+    ) -> CustomResult<CardInfoBulkUpsertSummary, errors::StorageError>;
+}
+
+fn get_card_info_cache_key(card_iin: &str) -> String {
+    format!("{}{card_iin}", consts::CARD_INFO_CACHE_PREFIX)
+}
+
+/// Retain only the last record provided for each `card_iin`
+fn deduplicate_card_info_records(records: Vec<CardInfo>) -> Vec<CardInfo> {
+    records
+        .into_iter()
+        .map(|record| (record.card_iin.clone(), record))
+        .collect::<HashMap<_, _>>()
+        .into_values()
+        .collect()
 }
 
 #[async_trait::async_trait]
@@ -24,11 +51,122 @@ impl CardsInfoInterface for Store {
         &self,
         card_iin: &str,
     ) -> CustomResult<Option<CardInfo>, errors::StorageError> {
+        if let Ok(redis_conn) = self.get_redis_conn() {
+            match redis_conn
+                .get_and_deserialize_key::<CardInfo>(&get_card_info_cache_key(card_iin), "CardInfo")
+                .await
+            {
+                Ok(card_info) => return Ok(Some(card_info)),
+                Err(error) => logger::debug!(?error, "Card info not found in cache"),
+            }
+        }
+
         let conn = connection::pg_connection_read(self).await?;
         CardInfo::find_by_iin(&conn, card_iin)
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn bulk_upsert_card_info(
+        &self,
+        records: Vec<CardInfo>,
+        prune_missing_records: bool,
+    ) -> CustomResult<CardInfoBulkUpsertSummary, errors::StorageError> {
+        // Every imported record is marked as updated at the time of the import, which allows the
+        // records not present in the import to be identified for pruning
+        let import_time = common_utils::date_time::now();
+        let records = deduplicate_card_info_records(records)
+            .into_iter()
+            .map(|record| CardInfo {
+                last_updated: Some(import_time),
+                ..record
+            })
+            .collect::<Vec<_>>();
+
+        let conn = connection::pg_connection_write(self).await?;
+        let mut summary = CardInfoBulkUpsertSummary::default();
+
+        for batch in records.chunks(consts::CARD_INFO_BULK_UPSERT_BATCH_SIZE) {
+            let batch = batch.to_vec();
+            let batch_size = batch.len();
+            let card_iins = batch
+                .iter()
+                .map(|record| record.card_iin.clone())
+                .collect::<Vec<_>>();
+
+            let updated = conn
+                .transaction_async(|conn| async move {
+                    let existing_card_iins = CardInfo::find_existing_card_iins(&conn, card_iins)
+                        .await
+                        .map_err(|error| {
+                            logger::error!(?error, "Failed to find existing card info records");
+                            errors::StorageError::DatabaseConnectionError
+                        })?;
+
+                    CardInfo::bulk_upsert(&conn, batch).await.map_err(|error| {
+                        logger::error!(?error, "Failed to upsert card info records");
+                        errors::StorageError::DatabaseConnectionError
+                    })?;
+
+                    Ok::<_, errors::StorageError>(existing_card_iins.len())
+                })
+                .await?;
+
+            summary.updated += updated;
+            summary.inserted += batch_size.saturating_sub(updated);
+        }
+
+        let pruned_card_iins = if prune_missing_records {
+            CardInfo::delete_not_updated_since(&conn, import_time)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))?
+        } else {
+            Vec::new()
+        };
+        summary.pruned = pruned_card_iins.len();
+
+        // The database has already been updated at this point, failing to update the cache
+        // must not fail the import
+        match self.get_redis_conn() {
+            Ok(redis_conn) => {
+                for record in &records {
+                    redis_conn
+                        .serialize_and_set_key_with_expiry(
+                            &get_card_info_cache_key(&record.card_iin),
+                            record,
+                            consts::CARD_INFO_CACHE_TTL,
+                        )
+                        .await
+                        .map_err(|error| {
+                            logger::error!(?error, "Failed to update card info in cache")
+                        })
+                        .ok();
+                }
+
+                redis_conn
+                    .delete_multiple_keys(
+                        pruned_card_iins
+                            .iter()
+                            .map(|card_iin| get_card_info_cache_key(card_iin))
+                            .collect(),
+                    )
+                    .await
+                    .map_err(|error| {
+                        logger::error!(?error, "Failed to delete pruned card info from cache")
+                    })
+                    .ok();
+            }
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    "Failed to get redis connection to update card info cache"
+                )
+            }
+        }
+
+        Ok(summary)
+    }
 }
 
 #[async_trait::async_trait]
@@ -46,4 +184,52 @@ impl CardsInfoInterface for MockDb {
             .find(|ci| ci.card_iin == card_iin)
             .cloned())
     }
+
+    #[instrument(skip_all)]
+    async fn bulk_upsert_card_info(
+        &self,
+        records: Vec<CardInfo>,
+        prune_missing_records: bool,
+    ) -> CustomResult<CardInfoBulkUpsertSummary, errors::StorageError> {
+        let import_time = common_utils::date_time::now();
+        let records = deduplicate_card_info_records(records);
+        let imported_card_iins = records
+            .iter()
+            .map(|record| record.card_iin.clone())
+            .collect::<HashSet<_>>();
+
+        let mut cards_info = self.cards_info.lock().await;
+        let mut summary = CardInfoBulkUpsertSummary::default();
+
+        for record in records {
+            let record = CardInfo {
+                last_updated: Some(import_time),
+                ..record
+            };
+            match cards_info
+                .iter_mut()
+                .find(|card_info| card_info.card_iin == record.card_iin)
+            {
+                Some(card_info) => {
+                    *card_info = CardInfo {
+                        date_created: card_info.date_created,
+                        ..record
+                    };
+                    summary.updated += 1;
+                }
+                None => {
+                    cards_info.push(record);
+                    summary.inserted += 1;
+                }
+            }
+        }
+
+        if prune_missing_records {
+            let total_records = cards_info.len();
+            cards_info.retain(|card_info| imported_card_iins.contains(&card_info.card_iin));
+            summary.pruned = total_records - cards_info.len();
+        }
+
+        Ok(summary)
+    }
 }
@@ -1,3 +1,4 @@
+use async_bb8_diesel::AsyncConnection;
 use diesel_models::configs::ConfigUpdateInternal;
 use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
@@ -50,6 +51,19 @@ pub trait ConfigInterface {
         config_update: storage::ConfigUpdate,
     ) -> CustomResult<storage::Config, errors::StorageError>;
 
+    /// Update the config and record the change in the audit log as a change of the resource, in
+    /// the same transaction
+    #[allow(clippy::too_many_arguments)]
+    async fn update_config_by_key_with_audit_log(
+        &self,
+        key: &str,
+        config_update: storage::ConfigUpdate,
+        merchant_id: &common_utils::id_type::MerchantId,
+        resource_type: storage::enums::AuditLogResourceType,
+        resource_id: &str,
+        audit_log: storage::AuditLogContext,
+    ) -> CustomResult<storage::Config, errors::StorageError>;
+
     async fn delete_config_by_key(
         &self,
         key: &str,
@@ -93,6 +107,50 @@ impl ConfigInterface for Store {
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    #[instrument(skip_all)]
+    async fn update_config_by_key_with_audit_log(
+        &self,
+        key: &str,
+        config_update: storage::ConfigUpdate,
+        merchant_id: &common_utils::id_type::MerchantId,
+        resource_type: storage::enums::AuditLogResourceType,
+        resource_id: &str,
+        audit_log: storage::AuditLogContext,
+    ) -> CustomResult<storage::Config, errors::StorageError> {
+        let update_call = || async {
+            let conn = connection::pg_connection_write(self).await?;
+            let key = key.to_owned();
+            let merchant_id = merchant_id.to_owned();
+            let resource_id = resource_id.to_owned();
+
+            let updated_config = conn
+                .transaction_async(|conn| async move {
+                    let current_config = storage::Config::find_by_key(&conn, &key).await?;
+                    let updated_config =
+                        storage::Config::update_by_key(&conn, &key, config_update).await?;
+
+                    audit_log
+                        .record(
+                            &conn,
+                            &merchant_id,
+                            resource_type,
+                            &resource_id,
+                            storage::enums::AuditLogAction::Update,
+                            Some(&current_config),
+                            Some(&updated_config),
+                        )
+                        .await?;
+
+                    Ok::<_, errors::StorageError>(updated_config)
+                })
+                .await?;
+
+            Ok(updated_config)
+        };
+
+        cache::publish_and_redact(self, CacheKind::Config(key.into()), update_call).await
+    }
+
     //update in DB and remove in redis and cache
     #[instrument(skip_all)]
     async fn update_config_by_key(
@@ -216,6 +274,19 @@ impl ConfigInterface for MockDb {
         self.update_config_by_key(key, config_update).await
     }
 
+    // The audit log is not stored in the mock db, so the changes are made without being recorded
+    async fn update_config_by_key_with_audit_log(
+        &self,
+        key: &str,
+        config_update: storage::ConfigUpdate,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _resource_type: storage::enums::AuditLogResourceType,
+        _resource_id: &str,
+        _audit_log: storage::AuditLogContext,
+    ) -> CustomResult<storage::Config, errors::StorageError> {
+        self.update_config_by_key(key, config_update).await
+    }
+
     async fn update_config_by_key(
         &self,
         key: &str,
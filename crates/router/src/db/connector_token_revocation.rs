@@ -0,0 +1,197 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait ConnectorTokenRevocationInterface {
+    /// Record the outcome of revoking the tokens of a deleted payment method at the connectors,
+    /// replacing the outcome recorded for the same tokens by an earlier attempt
+    async fn upsert_connector_token_revocations(
+        &self,
+        revocations: Vec<storage::ConnectorTokenRevocationNew>,
+    ) -> CustomResult<Vec<storage::ConnectorTokenRevocation>, errors::StorageError>;
+
+    /// List the revocations recorded for the tokens of the payment method
+    async fn find_connector_token_revocations_by_payment_method_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_id: &str,
+    ) -> CustomResult<Vec<storage::ConnectorTokenRevocation>, errors::StorageError>;
+
+    /// List the oldest connector tokens which could not be revoked, for the cleanup job to retry
+    async fn find_connector_token_revocations_pending_cleanup(
+        &self,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::ConnectorTokenRevocation>, errors::StorageError>;
+
+    async fn update_connector_token_revocation(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_id: &str,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+        revocation_update: storage::ConnectorTokenRevocationUpdate,
+    ) -> CustomResult<storage::ConnectorTokenRevocation, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ConnectorTokenRevocationInterface for Store {
+    #[instrument(skip_all)]
+    async fn upsert_connector_token_revocations(
+        &self,
+        revocations: Vec<storage::ConnectorTokenRevocationNew>,
+    ) -> CustomResult<Vec<storage::ConnectorTokenRevocation>, errors::StorageError> {
+        if revocations.is_empty() {
+            return Ok(Vec::new());
+        }
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ConnectorTokenRevocationNew::bulk_upsert(&conn, revocations)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_connector_token_revocations_by_payment_method_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_id: &str,
+    ) -> CustomResult<Vec<storage::ConnectorTokenRevocation>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ConnectorTokenRevocation::find_by_merchant_id_payment_method_id(
+            &conn,
+            merchant_id,
+            payment_method_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_connector_token_revocations_pending_cleanup(
+        &self,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::ConnectorTokenRevocation>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ConnectorTokenRevocation::find_by_revocation_status(
+            &conn,
+            enums::ConnectorTokenRevocationStatus::PendingCleanup,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_connector_token_revocation(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_id: &str,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+        revocation_update: storage::ConnectorTokenRevocationUpdate,
+    ) -> CustomResult<storage::ConnectorTokenRevocation, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ConnectorTokenRevocation::update_by_merchant_id_payment_method_id_merchant_connector_id(
+            &conn,
+            merchant_id,
+            payment_method_id,
+            merchant_connector_id,
+            revocation_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectorTokenRevocationInterface for MockDb {
+    async fn upsert_connector_token_revocations(
+        &self,
+        _revocations: Vec<storage::ConnectorTokenRevocationNew>,
+    ) -> CustomResult<Vec<storage::ConnectorTokenRevocation>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_connector_token_revocations_by_payment_method_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_method_id: &str,
+    ) -> CustomResult<Vec<storage::ConnectorTokenRevocation>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_connector_token_revocations_pending_cleanup(
+        &self,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::ConnectorTokenRevocation>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_connector_token_revocation(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_method_id: &str,
+        _merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+        _revocation_update: storage::ConnectorTokenRevocationUpdate,
+    ) -> CustomResult<storage::ConnectorTokenRevocation, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectorTokenRevocationInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn upsert_connector_token_revocations(
+        &self,
+        revocations: Vec<storage::ConnectorTokenRevocationNew>,
+    ) -> CustomResult<Vec<storage::ConnectorTokenRevocation>, errors::StorageError> {
+        self.diesel_store
+            .upsert_connector_token_revocations(revocations)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_connector_token_revocations_by_payment_method_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_id: &str,
+    ) -> CustomResult<Vec<storage::ConnectorTokenRevocation>, errors::StorageError> {
+        self.diesel_store
+            .find_connector_token_revocations_by_payment_method_id(merchant_id, payment_method_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_connector_token_revocations_pending_cleanup(
+        &self,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::ConnectorTokenRevocation>, errors::StorageError> {
+        self.diesel_store
+            .find_connector_token_revocations_pending_cleanup(limit)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_connector_token_revocation(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_id: &str,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+        revocation_update: storage::ConnectorTokenRevocationUpdate,
+    ) -> CustomResult<storage::ConnectorTokenRevocation, errors::StorageError> {
+        self.diesel_store
+            .update_connector_token_revocation(
+                merchant_id,
+                payment_method_id,
+                merchant_connector_id,
+                revocation_update,
+            )
+            .await
+    }
+}
@@ -0,0 +1,617 @@
+//! Offsets of the webhooks of the connectors which deliver webhooks through a log or a queue which
+//! is polled, rather than by pushing them.
+//!
+//! A poller resumes reading the webhooks of a connector account from its offset after a restart.
+//! The pollers of all the instances claim the offset before reading from it, so that the webhooks
+//! are read by one poller at a time, and the offset is advanced only from the offset the webhooks
+//! were read from, so that a poller whose claim expired while reading does not move the offset of
+//! another poller. When the log of the connector is truncated or rotated, the offset is reset to a
+//! position in the new log.
+
+use common_utils::id_type;
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::{redis::kv_store::RedisConnInterface, MockDb};
+
+use super::{payment_processing_claim, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+fn get_connector_webhook_offset_claim_key(
+    merchant_id: &id_type::MerchantId,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> String {
+    format!(
+        "connector_webhook_offset_claim_{}_{}",
+        merchant_id.get_string_repr(),
+        merchant_connector_id.get_string_repr()
+    )
+}
+
+#[async_trait::async_trait]
+pub trait ConnectorWebhookOffsetInterface {
+    /// Get the offset of the connector account, creating it with no webhook read if it does not
+    /// exist
+    async fn get_or_create_connector_webhook_offset(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        connector_name: &str,
+    ) -> CustomResult<storage::ConnectorWebhookOffset, errors::StorageError>;
+
+    async fn find_connector_webhook_offset_by_merchant_id_merchant_connector_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    ) -> CustomResult<Option<storage::ConnectorWebhookOffset>, errors::StorageError>;
+
+    /// Advance the offset from the offset the webhooks were read from to the offset of the last
+    /// webhook read, in the same log. Returns the advanced offset, or `None` if the offset was
+    /// moved by another poller or reset since the webhooks were read, in which case the webhooks
+    /// must not be processed again.
+    async fn advance_connector_webhook_offset(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        log_id: Option<String>,
+        current_offset: Option<String>,
+        new_offset: String,
+    ) -> CustomResult<Option<storage::ConnectorWebhookOffset>, errors::StorageError>;
+
+    /// Reset the offset to the provided offset of the provided log, when the log of the connector
+    /// was truncated or rotated. An offset which is not set resumes reading from the start of the
+    /// log.
+    async fn reset_connector_webhook_offset(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        log_id: Option<String>,
+        read_offset: Option<String>,
+    ) -> CustomResult<storage::ConnectorWebhookOffset, errors::StorageError>;
+
+    /// Claim the offset of the connector account for the poller for the lease duration, in
+    /// seconds. Returns whether the claim succeeded, which is not the case when the offset is
+    /// already claimed by a poller, including the same poller.
+    async fn try_claim_connector_webhook_offset(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        worker_id: &str,
+        lease_duration: i64,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    /// Release the claim of the poller on the offset of the connector account before its lease
+    /// expires. Returns whether the claim was released.
+    async fn release_connector_webhook_offset_claim(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        worker_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ConnectorWebhookOffsetInterface for Store {
+    #[instrument(skip_all)]
+    async fn get_or_create_connector_webhook_offset(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        connector_name: &str,
+    ) -> CustomResult<storage::ConnectorWebhookOffset, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ConnectorWebhookOffsetNew::new(
+            merchant_id.to_owned(),
+            merchant_connector_id.to_owned(),
+            connector_name.to_owned(),
+        )
+        .insert_or_get(&conn)
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_connector_webhook_offset_by_merchant_id_merchant_connector_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    ) -> CustomResult<Option<storage::ConnectorWebhookOffset>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ConnectorWebhookOffset::find_optional_by_merchant_id_merchant_connector_id(
+            &conn,
+            merchant_id,
+            merchant_connector_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn advance_connector_webhook_offset(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        log_id: Option<String>,
+        current_offset: Option<String>,
+        new_offset: String,
+    ) -> CustomResult<Option<storage::ConnectorWebhookOffset>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ConnectorWebhookOffset::advance(
+            &conn,
+            merchant_id,
+            merchant_connector_id,
+            log_id,
+            current_offset,
+            new_offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn reset_connector_webhook_offset(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        log_id: Option<String>,
+        read_offset: Option<String>,
+    ) -> CustomResult<storage::ConnectorWebhookOffset, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ConnectorWebhookOffset::reset(
+            &conn,
+            merchant_id,
+            merchant_connector_id,
+            log_id,
+            read_offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn try_claim_connector_webhook_offset(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        worker_id: &str,
+        lease_duration: i64,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let redis_conn = self
+            .get_redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?;
+        payment_processing_claim::try_claim(
+            &redis_conn,
+            &get_connector_webhook_offset_claim_key(merchant_id, merchant_connector_id),
+            worker_id,
+            lease_duration,
+        )
+        .await
+    }
+
+    #[instrument(skip_all)]
+    async fn release_connector_webhook_offset_claim(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        worker_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let redis_conn = self
+            .get_redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?;
+        payment_processing_claim::release_claim(
+            &redis_conn,
+            &get_connector_webhook_offset_claim_key(merchant_id, merchant_connector_id),
+            worker_id,
+        )
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectorWebhookOffsetInterface for MockDb {
+    async fn get_or_create_connector_webhook_offset(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        connector_name: &str,
+    ) -> CustomResult<storage::ConnectorWebhookOffset, errors::StorageError> {
+        let mut offsets = self.connector_webhook_offsets.lock().await;
+        if let Some(offset) = offsets.iter().find(|offset| {
+            offset.merchant_id == *merchant_id
+                && offset.merchant_connector_id == *merchant_connector_id
+        }) {
+            return Ok(offset.clone());
+        }
+
+        let offset_new = storage::ConnectorWebhookOffsetNew::new(
+            merchant_id.to_owned(),
+            merchant_connector_id.to_owned(),
+            connector_name.to_owned(),
+        );
+        let offset = storage::ConnectorWebhookOffset {
+            merchant_id: offset_new.merchant_id,
+            merchant_connector_id: offset_new.merchant_connector_id,
+            connector_name: offset_new.connector_name,
+            read_offset: offset_new.read_offset,
+            log_id: offset_new.log_id,
+            reset_count: offset_new.reset_count,
+            last_reset_at: offset_new.last_reset_at,
+            created_at: offset_new.created_at,
+            modified_at: offset_new.modified_at,
+        };
+        offsets.push(offset.clone());
+        Ok(offset)
+    }
+
+    async fn find_connector_webhook_offset_by_merchant_id_merchant_connector_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    ) -> CustomResult<Option<storage::ConnectorWebhookOffset>, errors::StorageError> {
+        Ok(self
+            .connector_webhook_offsets
+            .lock()
+            .await
+            .iter()
+            .find(|offset| {
+                offset.merchant_id == *merchant_id
+                    && offset.merchant_connector_id == *merchant_connector_id
+            })
+            .cloned())
+    }
+
+    async fn advance_connector_webhook_offset(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        log_id: Option<String>,
+        current_offset: Option<String>,
+        new_offset: String,
+    ) -> CustomResult<Option<storage::ConnectorWebhookOffset>, errors::StorageError> {
+        Ok(self
+            .connector_webhook_offsets
+            .lock()
+            .await
+            .iter_mut()
+            .find(|offset| {
+                offset.merchant_id == *merchant_id
+                    && offset.merchant_connector_id == *merchant_connector_id
+                    && offset.log_id == log_id
+                    && offset.read_offset == current_offset
+            })
+            .map(|offset| {
+                offset.read_offset = Some(new_offset);
+                offset.modified_at = common_utils::date_time::now();
+                offset.clone()
+            }))
+    }
+
+    async fn reset_connector_webhook_offset(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        log_id: Option<String>,
+        read_offset: Option<String>,
+    ) -> CustomResult<storage::ConnectorWebhookOffset, errors::StorageError> {
+        self.connector_webhook_offsets
+            .lock()
+            .await
+            .iter_mut()
+            .find(|offset| {
+                offset.merchant_id == *merchant_id
+                    && offset.merchant_connector_id == *merchant_connector_id
+            })
+            .map(|offset| {
+                let now = common_utils::date_time::now();
+                offset.log_id = log_id;
+                offset.read_offset = read_offset;
+                offset.reset_count += 1;
+                offset.last_reset_at = Some(now);
+                offset.modified_at = now;
+                offset.clone()
+            })
+            .ok_or(
+                errors::StorageError::ValueNotFound(
+                    "Connector webhook offset not found".to_string(),
+                )
+                .into(),
+            )
+    }
+
+    async fn try_claim_connector_webhook_offset(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        worker_id: &str,
+        lease_duration: i64,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let redis_conn = self
+            .get_redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?;
+        payment_processing_claim::try_claim(
+            &redis_conn,
+            &get_connector_webhook_offset_claim_key(merchant_id, merchant_connector_id),
+            worker_id,
+            lease_duration,
+        )
+        .await
+    }
+
+    async fn release_connector_webhook_offset_claim(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        worker_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let redis_conn = self
+            .get_redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?;
+        payment_processing_claim::release_claim(
+            &redis_conn,
+            &get_connector_webhook_offset_claim_key(merchant_id, merchant_connector_id),
+            worker_id,
+        )
+        .await
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectorWebhookOffsetInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn get_or_create_connector_webhook_offset(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        connector_name: &str,
+    ) -> CustomResult<storage::ConnectorWebhookOffset, errors::StorageError> {
+        self.diesel_store
+            .get_or_create_connector_webhook_offset(
+                merchant_id,
+                merchant_connector_id,
+                connector_name,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_connector_webhook_offset_by_merchant_id_merchant_connector_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    ) -> CustomResult<Option<storage::ConnectorWebhookOffset>, errors::StorageError> {
+        self.diesel_store
+            .find_connector_webhook_offset_by_merchant_id_merchant_connector_id(
+                merchant_id,
+                merchant_connector_id,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn advance_connector_webhook_offset(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        log_id: Option<String>,
+        current_offset: Option<String>,
+        new_offset: String,
+    ) -> CustomResult<Option<storage::ConnectorWebhookOffset>, errors::StorageError> {
+        self.diesel_store
+            .advance_connector_webhook_offset(
+                merchant_id,
+                merchant_connector_id,
+                log_id,
+                current_offset,
+                new_offset,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn reset_connector_webhook_offset(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        log_id: Option<String>,
+        read_offset: Option<String>,
+    ) -> CustomResult<storage::ConnectorWebhookOffset, errors::StorageError> {
+        self.diesel_store
+            .reset_connector_webhook_offset(merchant_id, merchant_connector_id, log_id, read_offset)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn try_claim_connector_webhook_offset(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        worker_id: &str,
+        lease_duration: i64,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .try_claim_connector_webhook_offset(
+                merchant_id,
+                merchant_connector_id,
+                worker_id,
+                lease_duration,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn release_connector_webhook_offset_claim(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        worker_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .release_connector_webhook_offset_claim(merchant_id, merchant_connector_id, worker_id)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_ids() -> (id_type::MerchantId, id_type::MerchantConnectorAccountId) {
+        (
+            id_type::MerchantId::default(),
+            id_type::MerchantConnectorAccountId::wrap("mca_1".to_string()).unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_get_or_create_connector_webhook_offset_returns_the_existing_offset() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let (merchant_id, merchant_connector_id) = get_ids();
+
+        let created = mockdb
+            .get_or_create_connector_webhook_offset(&merchant_id, &merchant_connector_id, "stripe")
+            .await
+            .unwrap();
+        assert!(created.read_offset.is_none());
+
+        mockdb
+            .advance_connector_webhook_offset(
+                &merchant_id,
+                &merchant_connector_id,
+                None,
+                None,
+                "10".to_string(),
+            )
+            .await
+            .unwrap();
+
+        let existing = mockdb
+            .get_or_create_connector_webhook_offset(&merchant_id, &merchant_connector_id, "stripe")
+            .await
+            .unwrap();
+        assert_eq!(existing.read_offset.as_deref(), Some("10"));
+        assert_eq!(mockdb.connector_webhook_offsets.lock().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_advance_connector_webhook_offset_only_from_the_current_offset() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let (merchant_id, merchant_connector_id) = get_ids();
+        mockdb
+            .get_or_create_connector_webhook_offset(&merchant_id, &merchant_connector_id, "stripe")
+            .await
+            .unwrap();
+
+        let advanced = mockdb
+            .advance_connector_webhook_offset(
+                &merchant_id,
+                &merchant_connector_id,
+                None,
+                None,
+                "10".to_string(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            advanced.and_then(|offset| offset.read_offset).as_deref(),
+            Some("10")
+        );
+
+        // Another poller which read from the same offset must not move it again
+        let stale = mockdb
+            .advance_connector_webhook_offset(
+                &merchant_id,
+                &merchant_connector_id,
+                None,
+                None,
+                "12".to_string(),
+            )
+            .await
+            .unwrap();
+        assert!(stale.is_none());
+
+        let offset = mockdb
+            .find_connector_webhook_offset_by_merchant_id_merchant_connector_id(
+                &merchant_id,
+                &merchant_connector_id,
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(offset.read_offset.as_deref(), Some("10"));
+    }
+
+    #[tokio::test]
+    async fn test_reset_connector_webhook_offset_moves_the_offset_to_the_new_log() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let (merchant_id, merchant_connector_id) = get_ids();
+        mockdb
+            .get_or_create_connector_webhook_offset(&merchant_id, &merchant_connector_id, "stripe")
+            .await
+            .unwrap();
+        mockdb
+            .advance_connector_webhook_offset(
+                &merchant_id,
+                &merchant_connector_id,
+                None,
+                None,
+                "10".to_string(),
+            )
+            .await
+            .unwrap();
+
+        let reset = mockdb
+            .reset_connector_webhook_offset(
+                &merchant_id,
+                &merchant_connector_id,
+                Some("log_2".to_string()),
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(reset.log_id.as_deref(), Some("log_2"));
+        assert!(reset.read_offset.is_none());
+        assert_eq!(reset.reset_count, 1);
+        assert!(reset.last_reset_at.is_some());
+
+        // The offset of the previous log cannot be advanced after the reset
+        let stale = mockdb
+            .advance_connector_webhook_offset(
+                &merchant_id,
+                &merchant_connector_id,
+                None,
+                Some("10".to_string()),
+                "12".to_string(),
+            )
+            .await
+            .unwrap();
+        assert!(stale.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_reset_connector_webhook_offset_which_does_not_exist() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let (merchant_id, merchant_connector_id) = get_ids();
+
+        let result = mockdb
+            .reset_connector_webhook_offset(&merchant_id, &merchant_connector_id, None, None)
+            .await;
+        assert!(matches!(
+            result.unwrap_err().current_context(),
+            errors::StorageError::ValueNotFound(_)
+        ));
+    }
+}
@@ -0,0 +1,208 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+/// The entries of the customer communication log are only ever appended, as they are evidence for
+/// the disputes which must not be altered once written. The content of the entries is only
+/// redacted along with the customer.
+#[async_trait::async_trait]
+pub trait CustomerCommunicationInterface {
+    async fn insert_customer_communication(
+        &self,
+        customer_communication: storage::CustomerCommunicationNew,
+    ) -> CustomResult<storage::CustomerCommunication, errors::StorageError>;
+
+    async fn list_customer_communications_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::CustomerCommunication>, errors::StorageError>;
+
+    async fn list_customer_communications_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::CustomerCommunication>, errors::StorageError>;
+
+    /// Redact the content of the entries of the customer, returning the number of entries redacted
+    async fn redact_customer_communications_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        redaction: storage::CustomerCommunicationRedaction,
+    ) -> CustomResult<usize, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CustomerCommunicationInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_customer_communication(
+        &self,
+        customer_communication: storage::CustomerCommunicationNew,
+    ) -> CustomResult<storage::CustomerCommunication, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        customer_communication
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_customer_communications_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::CustomerCommunication>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::CustomerCommunication::find_by_merchant_id_customer_id(
+            &conn,
+            merchant_id,
+            customer_id,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_customer_communications_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::CustomerCommunication>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::CustomerCommunication::find_by_merchant_id_payment_id(
+            &conn,
+            merchant_id,
+            payment_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn redact_customer_communications_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        redaction: storage::CustomerCommunicationRedaction,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::CustomerCommunication::redact_by_merchant_id_customer_id(
+            &conn,
+            merchant_id,
+            customer_id,
+            redaction,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl CustomerCommunicationInterface for MockDb {
+    async fn insert_customer_communication(
+        &self,
+        _customer_communication: storage::CustomerCommunicationNew,
+    ) -> CustomResult<storage::CustomerCommunication, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_customer_communications_by_merchant_id_customer_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _customer_id: &common_utils::id_type::CustomerId,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::CustomerCommunication>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_customer_communications_by_merchant_id_payment_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::CustomerCommunication>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn redact_customer_communications_by_merchant_id_customer_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _customer_id: &common_utils::id_type::CustomerId,
+        _redaction: storage::CustomerCommunicationRedaction,
+    ) -> CustomResult<usize, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl CustomerCommunicationInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_customer_communication(
+        &self,
+        customer_communication: storage::CustomerCommunicationNew,
+    ) -> CustomResult<storage::CustomerCommunication, errors::StorageError> {
+        self.diesel_store
+            .insert_customer_communication(customer_communication)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_customer_communications_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::CustomerCommunication>, errors::StorageError> {
+        self.diesel_store
+            .list_customer_communications_by_merchant_id_customer_id(
+                merchant_id,
+                customer_id,
+                limit,
+                offset,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_customer_communications_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::CustomerCommunication>, errors::StorageError> {
+        self.diesel_store
+            .list_customer_communications_by_merchant_id_payment_id(merchant_id, payment_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn redact_customer_communications_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        redaction: storage::CustomerCommunicationRedaction,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .redact_customer_communications_by_merchant_id_customer_id(
+                merchant_id,
+                customer_id,
+                redaction,
+            )
+            .await
+    }
+}
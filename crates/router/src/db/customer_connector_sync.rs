@@ -0,0 +1,145 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait CustomerConnectorSyncInterface {
+    async fn upsert_customer_connector_sync(
+        &self,
+        customer_connector_sync: storage::CustomerConnectorSyncNew,
+    ) -> CustomResult<storage::CustomerConnectorSync, errors::StorageError>;
+
+    async fn find_customer_connector_syncs_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Vec<storage::CustomerConnectorSync>, errors::StorageError>;
+
+    /// List the customers of the merchant whose latest local change is yet to be propagated to a
+    /// connector, either because the sync is in progress or because it failed.
+    async fn list_customer_connector_syncs_needing_sync(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::CustomerConnectorSync>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CustomerConnectorSyncInterface for Store {
+    #[instrument(skip_all)]
+    async fn upsert_customer_connector_sync(
+        &self,
+        customer_connector_sync: storage::CustomerConnectorSyncNew,
+    ) -> CustomResult<storage::CustomerConnectorSync, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        customer_connector_sync
+            .upsert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_customer_connector_syncs_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Vec<storage::CustomerConnectorSync>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::CustomerConnectorSync::find_by_merchant_id_customer_id(
+            &conn,
+            merchant_id,
+            customer_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_customer_connector_syncs_needing_sync(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::CustomerConnectorSync>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::CustomerConnectorSync::find_by_merchant_id_sync_statuses(
+            &conn,
+            merchant_id,
+            vec![
+                enums::CustomerConnectorSyncStatus::Pending,
+                enums::CustomerConnectorSyncStatus::Failed,
+            ],
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl CustomerConnectorSyncInterface for MockDb {
+    async fn upsert_customer_connector_sync(
+        &self,
+        _customer_connector_sync: storage::CustomerConnectorSyncNew,
+    ) -> CustomResult<storage::CustomerConnectorSync, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_customer_connector_syncs_by_merchant_id_customer_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Vec<storage::CustomerConnectorSync>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_customer_connector_syncs_needing_sync(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::CustomerConnectorSync>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl CustomerConnectorSyncInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn upsert_customer_connector_sync(
+        &self,
+        customer_connector_sync: storage::CustomerConnectorSyncNew,
+    ) -> CustomResult<storage::CustomerConnectorSync, errors::StorageError> {
+        self.diesel_store
+            .upsert_customer_connector_sync(customer_connector_sync)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_customer_connector_syncs_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Vec<storage::CustomerConnectorSync>, errors::StorageError> {
+        self.diesel_store
+            .find_customer_connector_syncs_by_merchant_id_customer_id(merchant_id, customer_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_customer_connector_syncs_needing_sync(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::CustomerConnectorSync>, errors::StorageError> {
+        self.diesel_store
+            .list_customer_connector_syncs_needing_sync(merchant_id, limit)
+            .await
+    }
+}
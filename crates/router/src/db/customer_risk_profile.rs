@@ -0,0 +1,143 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait CustomerRiskProfileInterface {
+    async fn find_customer_risk_profile_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<storage::CustomerRiskProfile, errors::StorageError>;
+
+    /// Insert the risk profile of the customer, or add the counters to those of the existing risk
+    /// profile of the customer, atomically
+    async fn upsert_customer_risk_profile_adding_counters(
+        &self,
+        customer_risk_profile: storage::CustomerRiskProfileNew,
+    ) -> CustomResult<storage::CustomerRiskProfile, errors::StorageError>;
+
+    /// Update the risk score and the tier of the risk profile, unless its counters have changed
+    /// since it was read. Returns whether the risk profile was updated.
+    async fn update_customer_risk_profile_if_counters_unchanged(
+        &self,
+        customer_risk_profile: &storage::CustomerRiskProfile,
+        customer_risk_profile_update: storage::CustomerRiskProfileUpdateInternal,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CustomerRiskProfileInterface for Store {
+    #[instrument(skip_all)]
+    async fn find_customer_risk_profile_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<storage::CustomerRiskProfile, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::CustomerRiskProfile::find_by_merchant_id_customer_id(
+            &conn,
+            merchant_id,
+            customer_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn upsert_customer_risk_profile_adding_counters(
+        &self,
+        customer_risk_profile: storage::CustomerRiskProfileNew,
+    ) -> CustomResult<storage::CustomerRiskProfile, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        customer_risk_profile
+            .upsert_adding_counters(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_customer_risk_profile_if_counters_unchanged(
+        &self,
+        customer_risk_profile: &storage::CustomerRiskProfile,
+        customer_risk_profile_update: storage::CustomerRiskProfileUpdateInternal,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        customer_risk_profile
+            .update_if_counters_unchanged(&conn, customer_risk_profile_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl CustomerRiskProfileInterface for MockDb {
+    async fn find_customer_risk_profile_by_merchant_id_customer_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<storage::CustomerRiskProfile, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn upsert_customer_risk_profile_adding_counters(
+        &self,
+        _customer_risk_profile: storage::CustomerRiskProfileNew,
+    ) -> CustomResult<storage::CustomerRiskProfile, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_customer_risk_profile_if_counters_unchanged(
+        &self,
+        _customer_risk_profile: &storage::CustomerRiskProfile,
+        _customer_risk_profile_update: storage::CustomerRiskProfileUpdateInternal,
+    ) -> CustomResult<bool, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl CustomerRiskProfileInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn find_customer_risk_profile_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<storage::CustomerRiskProfile, errors::StorageError> {
+        self.diesel_store
+            .find_customer_risk_profile_by_merchant_id_customer_id(merchant_id, customer_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn upsert_customer_risk_profile_adding_counters(
+        &self,
+        customer_risk_profile: storage::CustomerRiskProfileNew,
+    ) -> CustomResult<storage::CustomerRiskProfile, errors::StorageError> {
+        self.diesel_store
+            .upsert_customer_risk_profile_adding_counters(customer_risk_profile)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_customer_risk_profile_if_counters_unchanged(
+        &self,
+        customer_risk_profile: &storage::CustomerRiskProfile,
+        customer_risk_profile_update: storage::CustomerRiskProfileUpdateInternal,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .update_customer_risk_profile_if_counters_unchanged(
+                customer_risk_profile,
+                customer_risk_profile_update,
+            )
+            .await
+    }
+}
@@ -1,8 +1,10 @@
 use common_utils::{ext_traits::AsyncExt, id_type, types::keymanager::KeyManagerState};
 use diesel_models::query::customers::CustomerListConstraints as DieselCustomerListConstraints;
+pub use diesel_models::query::customers::CustomerMetadataValueHash;
 use error_stack::ResultExt;
 use futures::future::try_join_all;
 use hyperswitch_domain_models::customer;
+use masking::PeekInterface;
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 use router_env::{instrument, tracing};
 
@@ -21,6 +23,7 @@ use crate::{
 pub struct CustomerListConstraints {
     pub limit: u16,
     pub offset: Option<u32>,
+    pub metadata_value_hash: Option<CustomerMetadataValueHash>,
 }
 
 impl From<CustomerListConstraints> for DieselCustomerListConstraints {
@@ -28,6 +31,7 @@ impl From<CustomerListConstraints> for DieselCustomerListConstraints {
         Self {
             limit: i64::from(value.limit),
             offset: value.offset.map(i64::from),
+            metadata_value_hash: value.metadata_value_hash,
         }
     }
 }
@@ -1420,6 +1424,27 @@ impl CustomerInterface for MockDb {
             customers
                 .iter()
                 .filter(|customer| customer.merchant_id == *merchant_id)
+                .filter(|customer| {
+                    constraints
+                        .metadata_value_hash
+                        .as_ref()
+                        .map_or(true, |metadata_value_hash| {
+                            customer
+                                .metadata
+                                .as_ref()
+                                .and_then(|metadata| {
+                                    metadata
+                                        .peek()
+                                        .get(&metadata_value_hash.metadata_key)?
+                                        .get("value_hash")?
+                                        .as_str()
+                                        .map(|value_hash| {
+                                            value_hash == metadata_value_hash.value_hash
+                                        })
+                                })
+                                .unwrap_or(false)
+                        })
+                })
                 .take(usize::from(constraints.limit))
                 .skip(usize::try_from(constraints.offset.unwrap_or(0)).unwrap_or(0))
                 .map(|customer| async {
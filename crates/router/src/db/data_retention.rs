@@ -0,0 +1,217 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+/// The enumeration and the purge of the records whose retention period has elapsed. The records
+/// under a legal hold, and the records which would break a record that must still be kept, such as
+/// a payment with an open dispute, are never enumerated.
+#[async_trait::async_trait]
+pub trait DataRetentionInterface {
+    /// The oldest records of the entity which are past the cutoff and can be purged
+    #[cfg(feature = "v1")]
+    async fn find_purgeable_records(
+        &self,
+        entity: enums::RetentionEntity,
+        retained_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PurgeableRecord>, errors::StorageError>;
+
+    /// Purge the records of the merchant, anonymizing the payments and the customers and deleting
+    /// the records of the other entities. Returns the number of the records purged.
+    #[cfg(feature = "v1")]
+    async fn purge_records(
+        &self,
+        entity: enums::RetentionEntity,
+        merchant_id: &common_utils::id_type::MerchantId,
+        record_ids: Vec<String>,
+    ) -> CustomResult<usize, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl DataRetentionInterface for Store {
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_purgeable_records(
+        &self,
+        entity: enums::RetentionEntity,
+        retained_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PurgeableRecord>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        let records = match entity {
+            enums::RetentionEntity::Payments => {
+                diesel_models::PaymentIntent::find_purgeable_created_before(
+                    &conn,
+                    retained_before,
+                    limit,
+                )
+                .await
+            }
+            enums::RetentionEntity::Refunds => {
+                storage::Refund::find_purgeable_modified_before(&conn, retained_before, limit).await
+            }
+            enums::RetentionEntity::Disputes => {
+                storage::Dispute::find_purgeable_modified_before(&conn, retained_before, limit)
+                    .await
+            }
+            enums::RetentionEntity::Customers => {
+                diesel_models::Customer::find_purgeable_modified_before(
+                    &conn,
+                    retained_before,
+                    limit,
+                )
+                .await
+            }
+            enums::RetentionEntity::Files => {
+                storage::FileMetadata::find_purgeable_created_before(&conn, retained_before, limit)
+                    .await
+            }
+        }
+        .map_err(|error| report!(errors::StorageError::from(error)))?;
+
+        Ok(records
+            .into_iter()
+            .map(|(merchant_id, record_id)| storage::PurgeableRecord {
+                merchant_id,
+                record_id,
+            })
+            .collect())
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn purge_records(
+        &self,
+        entity: enums::RetentionEntity,
+        merchant_id: &common_utils::id_type::MerchantId,
+        record_ids: Vec<String>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        // The addresses are anonymized before the payments and the customers, so that the records
+        // remain purgeable if the purge fails midway
+        match entity {
+            enums::RetentionEntity::Payments => {
+                storage::Address::anonymize_by_merchant_id_payment_ids(
+                    &conn,
+                    merchant_id,
+                    record_ids.clone(),
+                )
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))?;
+                storage::Address::anonymize_payment_method_billing_by_merchant_id_payment_ids(
+                    &conn,
+                    merchant_id,
+                    record_ids.clone(),
+                )
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))?;
+                diesel_models::PaymentAttempt::anonymize_by_merchant_id_payment_ids(
+                    &conn,
+                    merchant_id,
+                    record_ids.clone(),
+                )
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))?;
+                diesel_models::PaymentIntent::anonymize_by_merchant_id_payment_ids(
+                    &conn,
+                    merchant_id,
+                    record_ids,
+                )
+                .await
+            }
+            enums::RetentionEntity::Refunds => {
+                storage::Refund::delete_by_merchant_id_refund_ids(&conn, merchant_id, record_ids)
+                    .await
+                    .map(|refunds| refunds.len())
+            }
+            enums::RetentionEntity::Disputes => {
+                storage::Dispute::delete_by_merchant_id_dispute_ids(&conn, merchant_id, record_ids)
+                    .await
+                    .map(|disputes| disputes.len())
+            }
+            enums::RetentionEntity::Customers => {
+                storage::Address::anonymize_by_merchant_id_customer_ids(
+                    &conn,
+                    merchant_id,
+                    record_ids.clone(),
+                )
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))?;
+                diesel_models::Customer::anonymize_by_merchant_id_customer_ids(
+                    &conn,
+                    merchant_id,
+                    record_ids,
+                )
+                .await
+            }
+            enums::RetentionEntity::Files => storage::FileMetadata::delete_by_merchant_id_file_ids(
+                &conn,
+                merchant_id,
+                record_ids,
+            )
+            .await
+            .map(|files| files.len()),
+        }
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl DataRetentionInterface for MockDb {
+    #[cfg(feature = "v1")]
+    async fn find_purgeable_records(
+        &self,
+        _entity: enums::RetentionEntity,
+        _retained_before: time::PrimitiveDateTime,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::PurgeableRecord>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "v1")]
+    async fn purge_records(
+        &self,
+        _entity: enums::RetentionEntity,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _record_ids: Vec<String>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl DataRetentionInterface for KafkaStore {
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_purgeable_records(
+        &self,
+        entity: enums::RetentionEntity,
+        retained_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PurgeableRecord>, errors::StorageError> {
+        self.diesel_store
+            .find_purgeable_records(entity, retained_before, limit)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn purge_records(
+        &self,
+        entity: enums::RetentionEntity,
+        merchant_id: &common_utils::id_type::MerchantId,
+        record_ids: Vec<String>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .purge_records(entity, merchant_id, record_ids)
+            .await
+    }
+}
@@ -55,6 +55,15 @@ pub trait DisputeInterface {
         profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
         time_range: &common_utils::types::TimeRange,
     ) -> CustomResult<Vec<(common_enums::enums::DisputeStatus, i64)>, errors::StorageError>;
+
+    /// Get the count of the won, lost and pending disputes of the merchant created within the
+    /// time range, optionally grouped by the reason code or the connector of the disputes
+    async fn get_dispute_outcome_stats(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        time_range: &common_utils::types::TimeRange,
+        group_by: Option<disputes::DisputeOutcomeGroupBy>,
+    ) -> CustomResult<Vec<disputes::DisputeOutcomeStats>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -154,6 +163,22 @@ impl DisputeInterface for Store {
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn get_dispute_outcome_stats(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        time_range: &common_utils::types::TimeRange,
+        group_by: Option<disputes::DisputeOutcomeGroupBy>,
+    ) -> CustomResult<Vec<disputes::DisputeOutcomeStats>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Dispute::get_dispute_outcome_status_with_count(&conn, merchant_id, time_range)
+            .await
+            .map(|status_counts| {
+                disputes::DisputeOutcomeStats::from_status_counts(group_by, status_counts)
+            })
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -451,6 +476,40 @@ impl DisputeInterface for MockDb {
             .into_iter()
             .collect::<Vec<(common_enums::DisputeStatus, i64)>>())
     }
+
+    async fn get_dispute_outcome_stats(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        time_range: &common_utils::types::TimeRange,
+        group_by: Option<disputes::DisputeOutcomeGroupBy>,
+    ) -> CustomResult<Vec<disputes::DisputeOutcomeStats>, errors::StorageError> {
+        let locked_disputes = self.disputes.lock().await;
+
+        let status_counts = locked_disputes
+            .iter()
+            .filter(|d| {
+                d.merchant_id == *merchant_id
+                    && d.created_at >= time_range.start_time
+                    && time_range
+                        .end_time
+                        .as_ref()
+                        .map(|received_end_time| received_end_time >= &d.created_at)
+                        .unwrap_or(true)
+            })
+            .map(|d| {
+                (
+                    d.connector.clone(),
+                    d.connector_reason_code.clone(),
+                    d.dispute_status,
+                    1,
+                )
+            });
+
+        Ok(disputes::DisputeOutcomeStats::from_status_counts(
+            group_by,
+            status_counts,
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -463,7 +522,9 @@ mod tests {
             dispute::DisputeNew,
             enums::{DisputeStage, DisputeStatus},
         };
-        use hyperswitch_domain_models::disputes::DisputeListConstraints;
+        use hyperswitch_domain_models::disputes::{
+            DisputeListConstraints, DisputeOutcomeGroupBy, DisputeOutcomeStats,
+        };
         use masking::Secret;
         use redis_interface::RedisSettings;
         use serde_json::Value;
@@ -739,6 +800,92 @@ mod tests {
             assert_eq!(created_dispute, found_disputes.first().unwrap().clone());
         }
 
+        #[tokio::test]
+        async fn test_get_dispute_outcome_stats() {
+            let merchant_id =
+                common_utils::id_type::MerchantId::try_from(Cow::from("merchant_1")).unwrap();
+
+            let mockdb = MockDb::new(&RedisSettings::default())
+                .await
+                .expect("Failed to create Mock store");
+
+            for (index, (dispute_status, reason_code)) in [
+                (DisputeStatus::DisputeWon, "fraudulent"),
+                (DisputeStatus::DisputeWon, "fraudulent"),
+                (DisputeStatus::DisputeLost, "product_not_received"),
+                (DisputeStatus::DisputeChallenged, "fraudulent"),
+                (DisputeStatus::DisputeAccepted, "fraudulent"),
+            ]
+            .into_iter()
+            .enumerate()
+            {
+                mockdb
+                    .insert_dispute(DisputeNew {
+                        dispute_status,
+                        connector_reason_code: Some(reason_code.into()),
+                        ..create_dispute_new(DisputeNewIds {
+                            dispute_id: format!("dispute_{index}"),
+                            attempt_id: "attempt_1".into(),
+                            merchant_id: merchant_id.clone(),
+                            payment_id: common_utils::id_type::PaymentId::try_from(Cow::Owned(
+                                format!("payment_{index}"),
+                            ))
+                            .unwrap(),
+                            connector_dispute_id: format!("connector_dispute_{index}"),
+                        })
+                    })
+                    .await
+                    .unwrap();
+            }
+
+            let time_range = common_utils::types::TimeRange {
+                start_time: datetime!(2019-01-01 0:00),
+                end_time: None,
+            };
+
+            let outcome_stats = mockdb
+                .get_dispute_outcome_stats(&merchant_id, &time_range, None)
+                .await
+                .unwrap();
+
+            assert_eq!(
+                outcome_stats,
+                vec![DisputeOutcomeStats {
+                    group: None,
+                    won: 2,
+                    lost: 1,
+                    pending: 1,
+                }]
+            );
+
+            let outcome_stats = mockdb
+                .get_dispute_outcome_stats(
+                    &merchant_id,
+                    &time_range,
+                    Some(DisputeOutcomeGroupBy::ReasonCode),
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(
+                outcome_stats,
+                vec![
+                    DisputeOutcomeStats {
+                        group: Some("fraudulent".into()),
+                        won: 2,
+                        lost: 0,
+                        pending: 1,
+                    },
+                    DisputeOutcomeStats {
+                        group: Some("product_not_received".into()),
+                        won: 0,
+                        lost: 1,
+                        pending: 0,
+                    },
+                ]
+            );
+        }
+
         mod update_dispute {
             use std::borrow::Cow;
 
@@ -680,6 +680,9 @@ impl EventInterface for MockDb {
                 event_to_update.is_webhook_notified = is_webhook_notified;
                 event_to_update.response = response.map(Into::into);
             }
+            domain::EventUpdate::UpdateSequenceNumber { sequence_number } => {
+                event_to_update.sequence_number = Some(sequence_number);
+            }
         }
 
         event_to_update
@@ -799,6 +802,7 @@ mod tests {
                         )
                         .unwrap(),
                     }),
+                    sequence_number: None,
                 },
                 &merchant_key_store,
             )
@@ -1,4 +1,5 @@
 use error_stack::report;
+use masking::PeekInterface;
 use router_env::{instrument, tracing};
 
 use super::{MockDb, Store};
@@ -21,6 +22,14 @@ pub trait FileMetadataInterface {
         file_id: &str,
     ) -> CustomResult<storage::FileMetadata, errors::StorageError>;
 
+    /// List the metadata of the files attached as evidence to the dispute. Files which are
+    /// referenced by the dispute evidence but no longer exist are not returned.
+    async fn list_dispute_evidence_files(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        dispute_id: &str,
+    ) -> CustomResult<Vec<storage::FileMetadata>, errors::StorageError>;
+
     async fn delete_file_metadata_by_merchant_id_file_id(
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -59,6 +68,37 @@ impl FileMetadataInterface for Store {
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    #[instrument(skip_all)]
+    async fn list_dispute_evidence_files(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        dispute_id: &str,
+    ) -> CustomResult<Vec<storage::FileMetadata>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        let dispute =
+            storage::Dispute::find_by_merchant_id_dispute_id(&conn, merchant_id, dispute_id)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))?;
+        // The dispute evidence maps each evidence type to the identifier of the file attached for it
+        let file_ids = dispute
+            .evidence
+            .peek()
+            .as_object()
+            .map(|evidence| {
+                evidence
+                    .values()
+                    .filter_map(|file_id| file_id.as_str().map(ToOwned::to_owned))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        if file_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        storage::FileMetadata::find_by_merchant_id_file_ids(&conn, merchant_id, file_ids)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     #[instrument(skip_all)]
     async fn delete_file_metadata_by_merchant_id_file_id(
         &self,
@@ -103,6 +143,15 @@ impl FileMetadataInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn list_dispute_evidence_files(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _dispute_id: &str,
+    ) -> CustomResult<Vec<storage::FileMetadata>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn delete_file_metadata_by_merchant_id_file_id(
         &self,
         _merchant_id: &common_utils::id_type::MerchantId,
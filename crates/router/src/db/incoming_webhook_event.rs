@@ -0,0 +1,193 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait IncomingWebhookEventInterface {
+    async fn insert_incoming_webhook_event(
+        &self,
+        event: storage::IncomingWebhookEventNew,
+    ) -> CustomResult<storage::IncomingWebhookEvent, errors::StorageError>;
+
+    /// The webhooks received for the payment, its refunds and its disputes, in the order in which
+    /// they were received
+    async fn list_incoming_webhook_events_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::IncomingWebhookEvent>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl IncomingWebhookEventInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_incoming_webhook_event(
+        &self,
+        event: storage::IncomingWebhookEventNew,
+    ) -> CustomResult<storage::IncomingWebhookEvent, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        event
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_incoming_webhook_events_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::IncomingWebhookEvent>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::IncomingWebhookEvent::find_by_merchant_id_payment_id(
+            &conn,
+            merchant_id,
+            payment_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl IncomingWebhookEventInterface for MockDb {
+    async fn insert_incoming_webhook_event(
+        &self,
+        event: storage::IncomingWebhookEventNew,
+    ) -> CustomResult<storage::IncomingWebhookEvent, errors::StorageError> {
+        let mut events = self.incoming_webhook_events.lock().await;
+        if events
+            .iter()
+            .any(|existing_event| existing_event.event_id == event.event_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "incoming_webhook_event",
+                key: Some(event.event_id.clone()),
+            })?
+        }
+
+        let event = storage::IncomingWebhookEvent::from(event);
+        events.push(event.clone());
+        Ok(event)
+    }
+
+    async fn list_incoming_webhook_events_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::IncomingWebhookEvent>, errors::StorageError> {
+        let mut events: Vec<_> = self
+            .incoming_webhook_events
+            .lock()
+            .await
+            .iter()
+            .filter(|event| event.merchant_id == *merchant_id && event.payment_id == *payment_id)
+            .cloned()
+            .collect();
+        events.sort_by_key(|event| event.received_at);
+        Ok(events)
+    }
+}
+
+#[async_trait::async_trait]
+impl IncomingWebhookEventInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_incoming_webhook_event(
+        &self,
+        event: storage::IncomingWebhookEventNew,
+    ) -> CustomResult<storage::IncomingWebhookEvent, errors::StorageError> {
+        self.diesel_store.insert_incoming_webhook_event(event).await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_incoming_webhook_events_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::IncomingWebhookEvent>, errors::StorageError> {
+        self.diesel_store
+            .list_incoming_webhook_events_by_merchant_id_payment_id(merchant_id, payment_id)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_event(
+        event_id: &str,
+        payment_id: &common_utils::id_type::PaymentId,
+        received_at: time::PrimitiveDateTime,
+    ) -> storage::IncomingWebhookEventNew {
+        storage::IncomingWebhookEventNew {
+            event_id: event_id.to_string(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            payment_id: payment_id.to_owned(),
+            connector: "stripe".to_string(),
+            event_type: "payment_intent_success".to_string(),
+            received_at,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_incoming_webhook_events_are_listed_by_payment() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let payment_id =
+            common_utils::id_type::PaymentId::try_from(std::borrow::Cow::Borrowed("pay_1"))
+                .unwrap();
+        let other_payment_id =
+            common_utils::id_type::PaymentId::try_from(std::borrow::Cow::Borrowed("pay_2"))
+                .unwrap();
+        let now = common_utils::date_time::now();
+
+        mockdb
+            .insert_incoming_webhook_event(get_event("whin_2", &payment_id, now))
+            .await
+            .unwrap();
+        mockdb
+            .insert_incoming_webhook_event(get_event(
+                "whin_1",
+                &payment_id,
+                now.saturating_sub(time::Duration::seconds(1)),
+            ))
+            .await
+            .unwrap();
+        mockdb
+            .insert_incoming_webhook_event(get_event("whin_3", &other_payment_id, now))
+            .await
+            .unwrap();
+        assert!(mockdb
+            .insert_incoming_webhook_event(get_event("whin_1", &payment_id, now))
+            .await
+            .is_err());
+
+        let events = mockdb
+            .list_incoming_webhook_events_by_merchant_id_payment_id(
+                &common_utils::id_type::MerchantId::default(),
+                &payment_id,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            events
+                .iter()
+                .map(|event| event.event_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["whin_1", "whin_2"]
+        );
+    }
+}
@@ -242,6 +242,39 @@ impl ApiKeyInterface for KafkaStore {
         self.diesel_store.revoke_api_key(merchant_id, key_id).await
     }
 
+    async fn insert_api_key_with_audit_log(
+        &self,
+        api_key: storage::ApiKeyNew,
+        audit_log: storage::AuditLogContext,
+    ) -> CustomResult<storage::ApiKey, errors::StorageError> {
+        self.diesel_store
+            .insert_api_key_with_audit_log(api_key, audit_log)
+            .await
+    }
+
+    async fn update_api_key_with_audit_log(
+        &self,
+        merchant_id: id_type::MerchantId,
+        key_id: String,
+        api_key: storage::ApiKeyUpdate,
+        audit_log: storage::AuditLogContext,
+    ) -> CustomResult<storage::ApiKey, errors::StorageError> {
+        self.diesel_store
+            .update_api_key_with_audit_log(merchant_id, key_id, api_key, audit_log)
+            .await
+    }
+
+    async fn revoke_api_key_with_audit_log(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        key_id: &str,
+        audit_log: storage::AuditLogContext,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .revoke_api_key_with_audit_log(merchant_id, key_id, audit_log)
+            .await
+    }
+
     async fn find_api_key_by_merchant_id_key_id_optional(
         &self,
         merchant_id: &id_type::MerchantId,
@@ -281,6 +314,16 @@ impl CardsInfoInterface for KafkaStore {
     ) -> CustomResult<Option<storage::CardInfo>, errors::StorageError> {
         self.diesel_store.get_card_info(card_iin).await
     }
+
+    async fn bulk_upsert_card_info(
+        &self,
+        records: Vec<storage::CardInfo>,
+        prune_missing_records: bool,
+    ) -> CustomResult<storage::CardInfoBulkUpsertSummary, errors::StorageError> {
+        self.diesel_store
+            .bulk_upsert_card_info(records, prune_missing_records)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -326,6 +369,27 @@ impl ConfigInterface for KafkaStore {
             .await
     }
 
+    async fn update_config_by_key_with_audit_log(
+        &self,
+        key: &str,
+        config_update: storage::ConfigUpdate,
+        merchant_id: &id_type::MerchantId,
+        resource_type: enums::AuditLogResourceType,
+        resource_id: &str,
+        audit_log: storage::AuditLogContext,
+    ) -> CustomResult<storage::Config, errors::StorageError> {
+        self.diesel_store
+            .update_config_by_key_with_audit_log(
+                key,
+                config_update,
+                merchant_id,
+                resource_type,
+                resource_id,
+                audit_log,
+            )
+            .await
+    }
+
     async fn delete_config_by_key(
         &self,
         key: &str,
@@ -638,6 +702,17 @@ impl DisputeInterface for KafkaStore {
             .get_dispute_status_with_count(merchant_id, profile_id_list, time_range)
             .await
     }
+
+    async fn get_dispute_outcome_stats(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        time_range: &common_utils::types::TimeRange,
+        group_by: Option<disputes::DisputeOutcomeGroupBy>,
+    ) -> CustomResult<Vec<disputes::DisputeOutcomeStats>, errors::StorageError> {
+        self.diesel_store
+            .get_dispute_outcome_stats(merchant_id, time_range, group_by)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -913,6 +988,31 @@ impl MandateInterface for KafkaStore {
             .insert_mandate(mandate, storage_scheme)
             .await
     }
+
+    async fn reserve_mandate_usage(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        mandate_id: &str,
+        amount: i64,
+        total_amount: Option<i64>,
+        period_start: Option<time::PrimitiveDateTime>,
+    ) -> CustomResult<Option<storage::Mandate>, errors::StorageError> {
+        self.diesel_store
+            .reserve_mandate_usage(merchant_id, mandate_id, amount, total_amount, period_start)
+            .await
+    }
+
+    async fn release_mandate_usage(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        mandate_id: &str,
+        amount: i64,
+        charged_since: Option<time::PrimitiveDateTime>,
+    ) -> CustomResult<storage::Mandate, errors::StorageError> {
+        self.diesel_store
+            .release_mandate_usage(merchant_id, mandate_id, amount, charged_since)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -944,6 +1044,16 @@ impl PaymentLinkInterface for KafkaStore {
             .list_payment_link_by_merchant_id(merchant_id, payment_link_constraints)
             .await
     }
+
+    async fn delete_payment_links_expired_before(
+        &self,
+        expired_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentLink>, errors::StorageError> {
+        self.diesel_store
+            .delete_payment_links_expired_before(expired_before, limit)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -1003,6 +1113,46 @@ impl MerchantAccountInterface for KafkaStore {
             .await
     }
 
+    async fn update_specific_fields_in_merchant_with_audit_log(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        merchant_account: storage::MerchantAccountUpdate,
+        key_store: &domain::MerchantKeyStore,
+        audit_log: storage::AuditLogContext,
+    ) -> CustomResult<domain::MerchantAccount, errors::StorageError> {
+        self.diesel_store
+            .update_specific_fields_in_merchant_with_audit_log(
+                state,
+                merchant_id,
+                merchant_account,
+                key_store,
+                audit_log,
+            )
+            .await
+    }
+
+    async fn update_merchant_onboarding_status(
+        &self,
+        state: &KeyManagerState,
+        merchant_account: domain::MerchantAccount,
+        onboarding_status: enums::OnboardingStatus,
+        changed_by: String,
+        reason: Option<String>,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::MerchantAccount, errors::StorageError> {
+        self.diesel_store
+            .update_merchant_onboarding_status(
+                state,
+                merchant_account,
+                onboarding_status,
+                changed_by,
+                reason,
+                key_store,
+            )
+            .await
+    }
+
     async fn find_merchant_account_by_publishable_key(
         &self,
         state: &KeyManagerState,
@@ -1088,6 +1238,16 @@ impl FileMetadataInterface for KafkaStore {
             .await
     }
 
+    async fn list_dispute_evidence_files(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        dispute_id: &str,
+    ) -> CustomResult<Vec<storage::FileMetadata>, errors::StorageError> {
+        self.diesel_store
+            .list_dispute_evidence_files(merchant_id, dispute_id)
+            .await
+    }
+
     async fn delete_file_metadata_by_merchant_id_file_id(
         &self,
         merchant_id: &id_type::MerchantId,
@@ -1480,6 +1640,24 @@ impl PaymentAttemptInterface for KafkaStore {
             .await
     }
 
+    #[cfg(feature = "v1")]
+    async fn find_payment_attempt_by_merchant_id_connector_connector_txn_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        connector: &str,
+        connector_txn_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage::PaymentAttempt, errors::DataStorageError> {
+        self.diesel_store
+            .find_payment_attempt_by_merchant_id_connector_connector_txn_id(
+                merchant_id,
+                connector,
+                connector_txn_id,
+                storage_scheme,
+            )
+            .await
+    }
+
     #[cfg(feature = "v1")]
     async fn find_payment_attempt_by_payment_id_merchant_id_attempt_id(
         &self,
@@ -1619,6 +1797,59 @@ impl PaymentAttemptInterface for KafkaStore {
             .await
     }
 
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn list_payments_by_tag(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        tag_key: &str,
+        tag_value: &str,
+        filters: &hyperswitch_domain_models::payments::payment_attempt::PaymentAttemptTagFilters,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage::PaymentAttempt>, errors::DataStorageError> {
+        self.diesel_store
+            .list_payments_by_tag(merchant_id, tag_key, tag_value, filters, storage_scheme)
+            .await
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn list_payments_pending_review(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        filters: &hyperswitch_domain_models::payments::payment_attempt::PaymentAttemptReviewFilters,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage::PaymentAttempt>, errors::DataStorageError> {
+        self.diesel_store
+            .list_payments_pending_review(merchant_id, filters, storage_scheme)
+            .await
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn get_distinct_card_hash_count_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        time_range: common_utils::types::TimeRange,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<i64, errors::DataStorageError> {
+        self.diesel_store
+            .get_distinct_card_hash_count_by_merchant_id(merchant_id, time_range, storage_scheme)
+            .await
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn get_settlement_amounts_by_currency(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        settlement_date: time::Date,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<
+        Vec<(common_enums::Currency, common_utils::types::MinorUnit)>,
+        errors::DataStorageError,
+    > {
+        self.diesel_store
+            .get_settlement_amounts_by_currency(merchant_id, settlement_date, storage_scheme)
+            .await
+    }
+
     #[cfg(feature = "v1")]
     async fn find_attempts_by_merchant_id_payment_id(
         &self,
@@ -1773,6 +2004,66 @@ impl PaymentIntentInterface for KafkaStore {
             .await
     }
 
+    #[cfg(all(feature = "olap", feature = "v1"))]
+    async fn find_payments_by_metadata(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        key: &hyperswitch_domain_models::payments::payment_intent::PaymentMetadataKey,
+        value: &str,
+        pagination: hyperswitch_domain_models::payments::payment_intent::PaymentIntentPagination,
+        key_store: &domain::MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage::PaymentIntent>, errors::DataStorageError> {
+        self.diesel_store
+            .find_payments_by_metadata(
+                state,
+                merchant_id,
+                key,
+                value,
+                pagination,
+                key_store,
+                storage_scheme,
+            )
+            .await
+    }
+
+    #[cfg(all(feature = "olap", feature = "v1"))]
+    async fn find_payment_intents_by_root_payment_id_merchant_id(
+        &self,
+        state: &KeyManagerState,
+        root_payment_id: &id_type::PaymentId,
+        merchant_id: &id_type::MerchantId,
+        key_store: &domain::MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentIntent>, errors::DataStorageError> {
+        self.diesel_store
+            .find_payment_intents_by_root_payment_id_merchant_id(
+                state,
+                root_payment_id,
+                merchant_id,
+                key_store,
+                storage_scheme,
+                limit,
+            )
+            .await
+    }
+
+    #[cfg(all(feature = "olap", feature = "v1"))]
+    async fn get_merchant_volume_metrics(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        time_range: &common_utils::types::TimeRange,
+    ) -> error_stack::Result<
+        Vec<hyperswitch_domain_models::payments::payment_intent::MerchantVolumeMetric>,
+        errors::DataStorageError,
+    > {
+        self.diesel_store
+            .get_merchant_volume_metrics(merchant_id, time_range)
+            .await
+    }
+
     #[cfg(all(feature = "olap", feature = "v1"))]
     async fn get_filtered_payment_intents_attempt(
         &self,
@@ -1870,6 +2161,22 @@ impl PaymentMethodInterface for KafkaStore {
             .await
     }
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    async fn list_payment_methods_for_retokenization(
+        &self,
+        state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        criteria: &storage::PaymentMethodRetokenizationCriteria,
+        limit: i64,
+    ) -> CustomResult<Vec<domain::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .list_payment_methods_for_retokenization(state, key_store, criteria, limit)
+            .await
+    }
+
     #[cfg(all(feature = "v2", feature = "customer_v2"))]
     async fn find_payment_method_list_by_global_id(
         &self,
@@ -2118,6 +2425,42 @@ impl PayoutAttemptInterface for KafkaStore {
             .get_filters_for_payouts(payouts, merchant_id, storage_scheme)
             .await
     }
+
+    async fn apply_payout_returns(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payout_returns: Vec<storage::PayoutReturn>,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage::PayoutReturnResult>, errors::DataStorageError> {
+        let results = self
+            .diesel_store
+            .apply_payout_returns(merchant_id, payout_returns, storage_scheme)
+            .await?;
+
+        for result in &results {
+            if let storage::PayoutReturnOutcome::Returned(returned_payout) = &result.outcome {
+                if let Err(err) = self
+                    .kafka_producer
+                    .log_payout(
+                        &KafkaPayout::from_storage(
+                            &returned_payout.payouts,
+                            &returned_payout.payout_attempt,
+                        ),
+                        Some(KafkaPayout::from_storage(
+                            &returned_payout.previous_payouts,
+                            &returned_payout.previous_payout_attempt,
+                        )),
+                        self.tenant_id.clone(),
+                    )
+                    .await
+                {
+                    logger::error!(message="Failed to update analytics entry for returned Payouts {returned_payout:?}", error_message=?err);
+                };
+            }
+        }
+
+        Ok(results)
+    }
 }
 
 #[cfg(not(feature = "payouts"))]
@@ -2667,6 +3010,25 @@ impl ProfileInterface for KafkaStore {
             .await
     }
 
+    async fn update_profile_routing_with_audit_log(
+        &self,
+        key_manager_state: &KeyManagerState,
+        merchant_key_store: &domain::MerchantKeyStore,
+        profile_id: &id_type::ProfileId,
+        profile_update: domain::ProfileUpdate,
+        audit_log: storage::AuditLogContext,
+    ) -> CustomResult<domain::Profile, errors::StorageError> {
+        self.diesel_store
+            .update_profile_routing_with_audit_log(
+                key_manager_state,
+                merchant_key_store,
+                profile_id,
+                profile_update,
+                audit_log,
+            )
+            .await
+    }
+
     async fn delete_profile_by_profile_id_merchant_id(
         &self,
         profile_id: &id_type::ProfileId,
@@ -2727,6 +3089,53 @@ impl ReverseLookupInterface for KafkaStore {
             .get_lookup_by_lookup_id(id, storage_scheme)
             .await
     }
+
+    async fn delete_reverse_lookups_by_pk_id(
+        &self,
+        pk_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .delete_reverse_lookups_by_pk_id(pk_id, storage_scheme)
+            .await
+    }
+
+    async fn delete_reverse_lookups_by_namespace(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .delete_reverse_lookups_by_namespace(merchant_id, storage_scheme)
+            .await
+    }
+
+    async fn reconcile_reverse_lookups(
+        &self,
+        scope: storage::ReverseLookupReconciliationScope,
+    ) -> CustomResult<storage::ReverseLookupReconciliationReport, errors::StorageError> {
+        self.diesel_store.reconcile_reverse_lookups(scope).await
+    }
+
+    async fn find_orphaned_reverse_lookups(
+        &self,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> CustomResult<storage::OrphanedReverseLookups, errors::StorageError> {
+        self.diesel_store
+            .find_orphaned_reverse_lookups(starting_after, limit)
+            .await
+    }
+
+    async fn delete_orphaned_reverse_lookups(
+        &self,
+        lookup_ids: Vec<String>,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .delete_orphaned_reverse_lookups(lookup_ids, storage_scheme)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -2740,6 +3149,16 @@ impl RoutingAlgorithmInterface for KafkaStore {
             .await
     }
 
+    async fn insert_routing_algorithm_with_audit_log(
+        &self,
+        routing_algorithm: storage::RoutingAlgorithm,
+        audit_log: storage::AuditLogContext,
+    ) -> CustomResult<storage::RoutingAlgorithm, errors::StorageError> {
+        self.diesel_store
+            .insert_routing_algorithm_with_audit_log(routing_algorithm, audit_log)
+            .await
+    }
+
     async fn find_routing_algorithm_by_profile_id_algorithm_id(
         &self,
         profile_id: &id_type::ProfileId,
@@ -0,0 +1,146 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait LegalHoldInterface {
+    async fn insert_legal_hold(
+        &self,
+        legal_hold: storage::LegalHoldNew,
+    ) -> CustomResult<storage::LegalHold, errors::StorageError>;
+
+    async fn list_legal_holds_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::LegalHold>, errors::StorageError>;
+
+    async fn delete_legal_hold_by_merchant_id_entity_type_record_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        entity_type: enums::RetentionEntity,
+        record_id: &str,
+    ) -> CustomResult<storage::LegalHold, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl LegalHoldInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_legal_hold(
+        &self,
+        legal_hold: storage::LegalHoldNew,
+    ) -> CustomResult<storage::LegalHold, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        legal_hold
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_legal_holds_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::LegalHold>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::LegalHold::find_by_merchant_id(&conn, merchant_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_legal_hold_by_merchant_id_entity_type_record_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        entity_type: enums::RetentionEntity,
+        record_id: &str,
+    ) -> CustomResult<storage::LegalHold, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::LegalHold::delete_by_merchant_id_entity_type_record_id(
+            &conn,
+            merchant_id,
+            entity_type,
+            record_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl LegalHoldInterface for MockDb {
+    async fn insert_legal_hold(
+        &self,
+        _legal_hold: storage::LegalHoldNew,
+    ) -> CustomResult<storage::LegalHold, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_legal_holds_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::LegalHold>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_legal_hold_by_merchant_id_entity_type_record_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _entity_type: enums::RetentionEntity,
+        _record_id: &str,
+    ) -> CustomResult<storage::LegalHold, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl LegalHoldInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_legal_hold(
+        &self,
+        legal_hold: storage::LegalHoldNew,
+    ) -> CustomResult<storage::LegalHold, errors::StorageError> {
+        self.diesel_store.insert_legal_hold(legal_hold).await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_legal_holds_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::LegalHold>, errors::StorageError> {
+        self.diesel_store
+            .list_legal_holds_by_merchant_id(merchant_id, limit, offset)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_legal_hold_by_merchant_id_entity_type_record_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        entity_type: enums::RetentionEntity,
+        record_id: &str,
+    ) -> CustomResult<storage::LegalHold, errors::StorageError> {
+        self.diesel_store
+            .delete_legal_hold_by_merchant_id_entity_type_record_id(
+                merchant_id,
+                entity_type,
+                record_id,
+            )
+            .await
+    }
+}
@@ -55,6 +55,31 @@ pub trait MandateInterface {
         mandate: storage_types::MandateNew,
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage_types::Mandate, errors::StorageError>;
+
+    /// Record the amount of a payment against the usage of the mandate, only if the payment keeps
+    /// the mandate within its total amount and the mandate has not been charged since
+    /// `period_start`. Returns `None` if the payment would exceed the constraints of the mandate.
+    ///
+    /// The usage is always recorded in the database, as the check and the update have to be
+    /// atomic across the concurrent payments of the mandate.
+    async fn reserve_mandate_usage(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        mandate_id: &str,
+        amount: i64,
+        total_amount: Option<i64>,
+        period_start: Option<time::PrimitiveDateTime>,
+    ) -> CustomResult<Option<storage_types::Mandate>, errors::StorageError>;
+
+    /// Release the usage recorded for a payment which failed, clearing the charge of the mandate
+    /// recorded since `charged_since`, if provided
+    async fn release_mandate_usage(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        mandate_id: &str,
+        amount: i64,
+        charged_since: Option<time::PrimitiveDateTime>,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError>;
 }
 
 #[cfg(feature = "kv_store")]
@@ -155,14 +180,18 @@ mod storage {
             match storage_scheme {
                 MerchantStorageScheme::PostgresOnly => database_call().await,
                 MerchantStorageScheme::RedisKv => {
-                    let lookup_id = format!(
-                        "mid_{}_conn_mandate_{}",
-                        merchant_id.get_string_repr(),
-                        connector_mandate_id
-                    );
                     let lookup = fallback_reverse_lookup_not_found!(
-                        self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
-                            .await,
+                        self.get_namespaced_lookup_by_lookup_id(
+                            &format!("conn_mandate_{connector_mandate_id}"),
+                            merchant_id,
+                            Some(&format!(
+                                "mid_{}_conn_mandate_{}",
+                                merchant_id.get_string_repr(),
+                                connector_mandate_id
+                            )),
+                            storage_scheme,
+                        )
+                        .await,
                         database_call().await
                     );
 
@@ -254,16 +283,16 @@ mod storage {
                         let rev_lookup = diesel_models::ReverseLookupNew {
                             sk_id: field.clone(),
                             pk_id: key_str.clone(),
-                            lookup_id: format!(
-                                "mid_{}_conn_mandate_{}",
-                                merchant_id.get_string_repr(),
-                                val
-                            ),
+                            lookup_id: format!("conn_mandate_{val}"),
                             source: "mandate".to_string(),
                             updated_by: storage_scheme.to_string(),
                         };
-                        self.insert_reverse_lookup(rev_lookup, storage_scheme)
-                            .await?;
+                        self.insert_namespaced_reverse_lookup(
+                            rev_lookup,
+                            merchant_id,
+                            storage_scheme,
+                        )
+                        .await?;
                     }
 
                     let m_update = mandate_update.convert_to_mandate_update(storage_scheme);
@@ -351,11 +380,7 @@ mod storage {
                     };
 
                     if let Some(connector_val) = connector_mandate_id {
-                        let lookup_id = format!(
-                            "mid_{}_conn_mandate_{}",
-                            merchant_id.get_string_repr(),
-                            connector_val
-                        );
+                        let lookup_id = format!("conn_mandate_{connector_val}");
 
                         let reverse_lookup_entry = diesel_models::ReverseLookupNew {
                             sk_id: field.clone(),
@@ -365,8 +390,12 @@ mod storage {
                             updated_by: storage_scheme.to_string(),
                         };
 
-                        self.insert_reverse_lookup(reverse_lookup_entry, storage_scheme)
-                            .await?;
+                        self.insert_namespaced_reverse_lookup(
+                            reverse_lookup_entry,
+                            merchant_id,
+                            storage_scheme,
+                        )
+                        .await?;
                     }
 
                     match kv_wrapper::<diesel_models::Mandate, _, _>(
@@ -393,6 +422,48 @@ mod storage {
                 }
             }
         }
+
+        #[instrument(skip_all)]
+        async fn reserve_mandate_usage(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            mandate_id: &str,
+            amount: i64,
+            total_amount: Option<i64>,
+            period_start: Option<time::PrimitiveDateTime>,
+        ) -> CustomResult<Option<storage_types::Mandate>, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage_types::Mandate::reserve_usage_by_merchant_id_mandate_id(
+                &conn,
+                merchant_id,
+                mandate_id,
+                amount,
+                total_amount,
+                period_start,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn release_mandate_usage(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            mandate_id: &str,
+            amount: i64,
+            charged_since: Option<time::PrimitiveDateTime>,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage_types::Mandate::release_usage_by_merchant_id_mandate_id(
+                &conn,
+                merchant_id,
+                mandate_id,
+                amount,
+                charged_since,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
     }
 }
 
@@ -511,6 +582,48 @@ mod storage {
                 .await
                 .map_err(|error| report!(errors::StorageError::from(error)))
         }
+
+        #[instrument(skip_all)]
+        async fn reserve_mandate_usage(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            mandate_id: &str,
+            amount: i64,
+            total_amount: Option<i64>,
+            period_start: Option<time::PrimitiveDateTime>,
+        ) -> CustomResult<Option<storage_types::Mandate>, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage_types::Mandate::reserve_usage_by_merchant_id_mandate_id(
+                &conn,
+                merchant_id,
+                mandate_id,
+                amount,
+                total_amount,
+                period_start,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn release_mandate_usage(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            mandate_id: &str,
+            amount: i64,
+            charged_since: Option<time::PrimitiveDateTime>,
+        ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage_types::Mandate::release_usage_by_merchant_id_mandate_id(
+                &conn,
+                merchant_id,
+                mandate_id,
+                amount,
+                charged_since,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
     }
 }
 
@@ -686,8 +799,154 @@ impl MandateInterface for MockDb {
             connector_mandate_ids: mandate_new.connector_mandate_ids,
             merchant_connector_id: mandate_new.merchant_connector_id,
             updated_by: mandate_new.updated_by,
+            constraints: mandate_new.constraints,
+            last_charged_at: mandate_new.last_charged_at,
         };
         mandates.push(mandate.clone());
         Ok(mandate)
     }
+
+    async fn reserve_mandate_usage(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        mandate_id: &str,
+        amount: i64,
+        total_amount: Option<i64>,
+        period_start: Option<time::PrimitiveDateTime>,
+    ) -> CustomResult<Option<storage_types::Mandate>, errors::StorageError> {
+        let mut mandates = self.mandates.lock().await;
+        let mandate = mandates
+            .iter_mut()
+            .find(|mandate| mandate.merchant_id == *merchant_id && mandate.mandate_id == mandate_id)
+            .ok_or(errors::StorageError::ValueNotFound(
+                "mandate not found".to_string(),
+            ))?;
+        let amount_captured = mandate.amount_captured.unwrap_or(0) + amount;
+        let is_within_total_amount =
+            total_amount.map_or(true, |total_amount| amount_captured <= total_amount);
+        let is_outside_period = period_start
+            .zip(mandate.last_charged_at)
+            .map_or(true, |(period_start, last_charged_at)| {
+                last_charged_at < period_start
+            });
+        if !(is_within_total_amount && is_outside_period) {
+            return Ok(None);
+        }
+        mandate.amount_captured = Some(amount_captured);
+        mandate.last_charged_at = Some(common_utils::date_time::now());
+        Ok(Some(mandate.clone()))
+    }
+
+    async fn release_mandate_usage(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        mandate_id: &str,
+        amount: i64,
+        charged_since: Option<time::PrimitiveDateTime>,
+    ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
+        let mut mandates = self.mandates.lock().await;
+        let mandate = mandates
+            .iter_mut()
+            .find(|mandate| mandate.merchant_id == *merchant_id && mandate.mandate_id == mandate_id)
+            .ok_or(errors::StorageError::ValueNotFound(
+                "mandate not found".to_string(),
+            ))?;
+        mandate.amount_captured = Some((mandate.amount_captured.unwrap_or(0) - amount).max(0));
+        if charged_since
+            .zip(mandate.last_charged_at)
+            .is_some_and(|(charged_since, last_charged_at)| last_charged_at >= charged_since)
+        {
+            mandate.last_charged_at = None;
+        }
+        Ok(mandate.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+
+    async fn insert_mandate(mockdb: &MockDb) {
+        mockdb
+            .insert_mandate(
+                storage_types::MandateNew {
+                    mandate_id: "mandate_1".to_string(),
+                    mandate_type: storage_types::enums::MandateType::MultiUse,
+                    ..Default::default()
+                },
+                MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_mandate_usage_is_reserved_within_the_total_amount() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        insert_mandate(&mockdb).await;
+        let merchant_id = id_type::MerchantId::default();
+
+        let mandate = mockdb
+            .reserve_mandate_usage(&merchant_id, "mandate_1", 600, Some(1000), None)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(mandate.amount_captured, Some(600));
+
+        // The second payment would take the mandate past its total amount
+        assert!(mockdb
+            .reserve_mandate_usage(&merchant_id, "mandate_1", 600, Some(1000), None)
+            .await
+            .unwrap()
+            .is_none());
+
+        let mandate = mockdb
+            .release_mandate_usage(&merchant_id, "mandate_1", 600, None)
+            .await
+            .unwrap();
+        assert_eq!(mandate.amount_captured, Some(0));
+        assert!(mockdb
+            .reserve_mandate_usage(&merchant_id, "mandate_1", 600, Some(1000), None)
+            .await
+            .unwrap()
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn test_mandate_usage_is_reserved_once_per_period() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        insert_mandate(&mockdb).await;
+        let merchant_id = id_type::MerchantId::default();
+        let period_start =
+            common_utils::date_time::now().saturating_sub(time::Duration::minutes(1));
+
+        assert!(mockdb
+            .reserve_mandate_usage(&merchant_id, "mandate_1", 100, None, Some(period_start))
+            .await
+            .unwrap()
+            .is_some());
+        assert!(mockdb
+            .reserve_mandate_usage(&merchant_id, "mandate_1", 100, None, Some(period_start))
+            .await
+            .unwrap()
+            .is_none());
+
+        // The charge of a payment which failed does not count against the period
+        let mandate = mockdb
+            .release_mandate_usage(&merchant_id, "mandate_1", 100, Some(period_start))
+            .await
+            .unwrap();
+        assert_eq!(mandate.last_charged_at, None);
+        assert!(mockdb
+            .reserve_mandate_usage(&merchant_id, "mandate_1", 100, None, Some(period_start))
+            .await
+            .unwrap()
+            .is_some());
+    }
 }
@@ -1,6 +1,7 @@
 #[cfg(feature = "olap")]
 use std::collections::HashMap;
 
+use async_bb8_diesel::AsyncConnection;
 use common_utils::{ext_traits::AsyncExt, types::keymanager::KeyManagerState};
 use diesel_models::MerchantAccountUpdateInternal;
 use error_stack::{report, ResultExt};
@@ -64,6 +65,29 @@ where
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<domain::MerchantAccount, errors::StorageError>;
 
+    /// Update the merchant account and record the changed fields in the audit log, in the same
+    /// transaction
+    async fn update_specific_fields_in_merchant_with_audit_log(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_account: storage::MerchantAccountUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+        audit_log: storage::AuditLogContext,
+    ) -> CustomResult<domain::MerchantAccount, errors::StorageError>;
+
+    /// Move the merchant account to the provided onboarding status and record the transition in
+    /// the onboarding audit log
+    async fn update_merchant_onboarding_status(
+        &self,
+        state: &KeyManagerState,
+        merchant_account: domain::MerchantAccount,
+        onboarding_status: storage::enums::OnboardingStatus,
+        changed_by: String,
+        reason: Option<String>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::MerchantAccount, errors::StorageError>;
+
     async fn find_merchant_account_by_publishable_key(
         &self,
         state: &KeyManagerState,
@@ -224,6 +248,115 @@ impl MerchantAccountInterface for Store {
             .change_context(errors::StorageError::DecryptionError)
     }
 
+    #[instrument(skip_all)]
+    async fn update_specific_fields_in_merchant_with_audit_log(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_account: storage::MerchantAccountUpdate,
+        merchant_key_store: &domain::MerchantKeyStore,
+        audit_log: storage::AuditLogContext,
+    ) -> CustomResult<domain::MerchantAccount, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        let merchant_id = merchant_id.to_owned();
+
+        let updated_merchant_account = conn
+            .transaction_async(|conn| async move {
+                let current_merchant_account =
+                    storage::MerchantAccount::find_by_merchant_id_for_update(&conn, &merchant_id)
+                        .await?;
+
+                let updated_merchant_account =
+                    storage::MerchantAccount::update_with_specific_fields(
+                        &conn,
+                        &merchant_id,
+                        merchant_account.into(),
+                    )
+                    .await?;
+
+                audit_log
+                    .record(
+                        &conn,
+                        &merchant_id,
+                        storage::enums::AuditLogResourceType::MerchantAccount,
+                        merchant_id.get_string_repr(),
+                        storage::enums::AuditLogAction::Update,
+                        Some(&current_merchant_account),
+                        Some(&updated_merchant_account),
+                    )
+                    .await?;
+
+                Ok::<_, errors::StorageError>(updated_merchant_account)
+            })
+            .await?;
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            publish_and_redact_merchant_account_cache(self, &updated_merchant_account).await?;
+        }
+        updated_merchant_account
+            .convert(
+                state,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
+    #[instrument(skip_all)]
+    async fn update_merchant_onboarding_status(
+        &self,
+        state: &KeyManagerState,
+        merchant_account: domain::MerchantAccount,
+        onboarding_status: storage::enums::OnboardingStatus,
+        changed_by: String,
+        reason: Option<String>,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::MerchantAccount, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        let merchant_id = merchant_account.get_id().to_owned();
+        let previous_status = merchant_account.onboarding_status;
+
+        let updated_merchant_account = conn
+            .transaction_async(|conn| async move {
+                let updated_merchant_account = storage::MerchantAccount::update_onboarding_status(
+                    &conn,
+                    &merchant_id,
+                    previous_status,
+                    onboarding_status,
+                )
+                .await?;
+
+                storage::MerchantOnboardingAuditLogNew {
+                    merchant_id,
+                    previous_status,
+                    new_status: onboarding_status,
+                    changed_by,
+                    reason,
+                    created_at: common_utils::date_time::now(),
+                }
+                .insert(&conn)
+                .await?;
+
+                Ok::<_, errors::StorageError>(updated_merchant_account)
+            })
+            .await?;
+
+        #[cfg(feature = "accounts_cache")]
+        {
+            publish_and_redact_merchant_account_cache(self, &updated_merchant_account).await?;
+        }
+        updated_merchant_account
+            .convert(
+                state,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)
+    }
+
     #[instrument(skip_all)]
     async fn find_merchant_account_by_publishable_key(
         &self,
@@ -527,6 +660,29 @@ impl MerchantAccountInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn update_specific_fields_in_merchant_with_audit_log(
+        &self,
+        _state: &KeyManagerState,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _merchant_account: storage::MerchantAccountUpdate,
+        _merchant_key_store: &domain::MerchantKeyStore,
+        _audit_log: storage::AuditLogContext,
+    ) -> CustomResult<domain::MerchantAccount, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_merchant_onboarding_status(
+        &self,
+        _state: &KeyManagerState,
+        _merchant_account: domain::MerchantAccount,
+        _onboarding_status: storage::enums::OnboardingStatus,
+        _changed_by: String,
+        _reason: Option<String>,
+        _merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<domain::MerchantAccount, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn find_merchant_account_by_publishable_key(
         &self,
         _state: &KeyManagerState,
@@ -1192,6 +1192,14 @@ impl MerchantConnectorAccountInterface for MockDb {
             connector_wallets_details: t.connector_wallets_details.map(Encryption::from),
             additional_merchant_data: t.additional_merchant_data.map(|data| data.into()),
             version: t.version,
+            request_timeout_secs: t.request_timeout_secs,
+            webhook_identifier: t.webhook_identifier,
+            environment: t.environment,
+            maintenance_windows: t.maintenance_windows,
+            pending_connector_account_details: t
+                .pending_connector_account_details
+                .map(Encryption::from),
+            credential_rotation_status: t.credential_rotation_status,
         };
         accounts.push(account.clone());
         account
@@ -1233,6 +1241,14 @@ impl MerchantConnectorAccountInterface for MockDb {
             connector_wallets_details: t.connector_wallets_details.map(Encryption::from),
             additional_merchant_data: t.additional_merchant_data.map(|data| data.into()),
             version: t.version,
+            request_timeout_secs: t.request_timeout_secs,
+            webhook_identifier: t.webhook_identifier,
+            environment: t.environment,
+            maintenance_windows: t.maintenance_windows,
+            pending_connector_account_details: t
+                .pending_connector_account_details
+                .map(Encryption::from),
+            credential_rotation_status: t.credential_rotation_status,
         };
         accounts.push(account.clone());
         account
@@ -1607,6 +1623,12 @@ mod merchant_connector_account_cache_tests {
             ),
             additional_merchant_data: None,
             version: hyperswitch_domain_models::consts::API_VERSION,
+            request_timeout_secs: None,
+            webhook_identifier: None,
+            environment: None,
+            maintenance_windows: None,
+            pending_connector_account_details: None,
+            credential_rotation_status: None,
         };
 
         db.insert_merchant_connector_account(key_manager_state, mca.clone(), &merchant_key)
@@ -1781,6 +1803,12 @@ mod merchant_connector_account_cache_tests {
             ),
             additional_merchant_data: None,
             version: hyperswitch_domain_models::consts::API_VERSION,
+            request_timeout_secs: None,
+            webhook_identifier: None,
+            environment: None,
+            maintenance_windows: None,
+            pending_connector_account_details: None,
+            credential_rotation_status: None,
         };
 
         db.insert_merchant_connector_account(key_manager_state, mca.clone(), &merchant_key)
@@ -0,0 +1,56 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait MerchantOnboardingAuditLogInterface {
+    async fn find_merchant_onboarding_audit_logs_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::MerchantOnboardingAuditLog>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl MerchantOnboardingAuditLogInterface for Store {
+    #[instrument(skip_all)]
+    async fn find_merchant_onboarding_audit_logs_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::MerchantOnboardingAuditLog>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::MerchantOnboardingAuditLog::find_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl MerchantOnboardingAuditLogInterface for MockDb {
+    async fn find_merchant_onboarding_audit_logs_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::MerchantOnboardingAuditLog>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl MerchantOnboardingAuditLogInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn find_merchant_onboarding_audit_logs_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::MerchantOnboardingAuditLog>, errors::StorageError> {
+        self.diesel_store
+            .find_merchant_onboarding_audit_logs_by_merchant_id(merchant_id)
+            .await
+    }
+}
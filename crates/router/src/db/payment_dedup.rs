@@ -0,0 +1,412 @@
+use common_utils::id_type;
+use error_stack::ResultExt;
+use redis_interface::RedisConnectionPool;
+use router_env::{instrument, tracing};
+use storage_impl::{redis::kv_store::RedisConnInterface, MockDb};
+
+use super::{
+    payment_processing_claim::{release_claim, try_claim},
+    Store,
+};
+use crate::{
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+};
+
+/// The dedup lookups are scoped to the window they were registered with, so that a change in the
+/// window does not match the lookups registered with a different window
+fn get_payment_dedup_lookup_id(
+    merchant_id: &id_type::MerchantId,
+    dedup_key: &str,
+    window: time::Duration,
+) -> String {
+    format!(
+        "payment_dedup_{}_{}_{dedup_key}",
+        merchant_id.get_string_repr(),
+        window.whole_seconds()
+    )
+}
+
+#[async_trait::async_trait]
+pub trait PaymentDedupInterface {
+    /// Find the payment registered against the dedup key within the window, if any, without
+    /// registering a payment against it
+    async fn find_recent_payment_by_dedup_key(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        dedup_key: &str,
+        window: time::Duration,
+    ) -> CustomResult<Option<id_type::PaymentId>, errors::StorageError>;
+
+    /// Register the payment against its dedup key for the window, unless a payment is already
+    /// registered against it within the window. The dedup key is checked and registered in a
+    /// single command, so that one of the concurrent payments with the same dedup key is
+    /// registered. Returns the payment already registered against the dedup key, if any.
+    async fn try_insert_payment_dedup_key(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        dedup_key: &str,
+        payment_id: &id_type::PaymentId,
+        window: time::Duration,
+    ) -> CustomResult<Option<id_type::PaymentId>, errors::StorageError>;
+
+    /// Register the payment against its dedup key for the window, replacing the payment already
+    /// registered against it and restarting the window
+    async fn insert_payment_dedup_key(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        dedup_key: &str,
+        payment_id: &id_type::PaymentId,
+        window: time::Duration,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    /// Remove the registration of the payment against its dedup key, such as when the payment
+    /// could not be created. Returns whether the registration was removed, which is not the case
+    /// when another payment is registered against the dedup key.
+    async fn delete_payment_dedup_key(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        dedup_key: &str,
+        payment_id: &id_type::PaymentId,
+        window: time::Duration,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+async fn try_insert_dedup_key(
+    redis_conn: &RedisConnectionPool,
+    merchant_id: &id_type::MerchantId,
+    dedup_key: &str,
+    payment_id: &id_type::PaymentId,
+    window: time::Duration,
+) -> CustomResult<Option<id_type::PaymentId>, errors::StorageError> {
+    let lookup_id = get_payment_dedup_lookup_id(merchant_id, dedup_key, window);
+    if try_claim(
+        redis_conn,
+        &lookup_id,
+        payment_id.get_string_repr(),
+        window.whole_seconds(),
+    )
+    .await
+    .attach_printable("Failed to insert the payment dedup lookup")?
+    {
+        return Ok(None);
+    }
+
+    // The registered payment may have expired after the dedup key was checked, in which case no
+    // payment is registered against it any longer
+    find_dedup_key(redis_conn, merchant_id, dedup_key, window).await
+}
+
+async fn find_dedup_key(
+    redis_conn: &RedisConnectionPool,
+    merchant_id: &id_type::MerchantId,
+    dedup_key: &str,
+    window: time::Duration,
+) -> CustomResult<Option<id_type::PaymentId>, errors::StorageError> {
+    redis_conn
+        .get_key::<Option<String>>(&get_payment_dedup_lookup_id(merchant_id, dedup_key, window))
+        .await
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("Failed to find the payment dedup lookup")?
+        .map(id_type::PaymentId::wrap)
+        .transpose()
+        .change_context(errors::StorageError::DeserializationFailed)
+        .attach_printable("Invalid payment id in the payment dedup lookup")
+}
+
+async fn insert_dedup_key(
+    redis_conn: &RedisConnectionPool,
+    merchant_id: &id_type::MerchantId,
+    dedup_key: &str,
+    payment_id: &id_type::PaymentId,
+    window: time::Duration,
+) -> CustomResult<(), errors::StorageError> {
+    redis_conn
+        .set_key_with_expiry(
+            &get_payment_dedup_lookup_id(merchant_id, dedup_key, window),
+            payment_id.get_string_repr(),
+            window.whole_seconds(),
+        )
+        .await
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("Failed to insert the payment dedup lookup")
+}
+
+async fn delete_dedup_key(
+    redis_conn: &RedisConnectionPool,
+    merchant_id: &id_type::MerchantId,
+    dedup_key: &str,
+    payment_id: &id_type::PaymentId,
+    window: time::Duration,
+) -> CustomResult<bool, errors::StorageError> {
+    release_claim(
+        redis_conn,
+        &get_payment_dedup_lookup_id(merchant_id, dedup_key, window),
+        payment_id.get_string_repr(),
+    )
+    .await
+    .attach_printable("Failed to delete the payment dedup lookup")
+}
+
+/// The dedup lookups are stored only in redis, which expires them along with their window
+#[async_trait::async_trait]
+impl PaymentDedupInterface for Store {
+    #[instrument(skip_all)]
+    async fn find_recent_payment_by_dedup_key(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        dedup_key: &str,
+        window: time::Duration,
+    ) -> CustomResult<Option<id_type::PaymentId>, errors::StorageError> {
+        let redis_conn = self
+            .get_redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?;
+        find_dedup_key(&redis_conn, merchant_id, dedup_key, window).await
+    }
+
+    #[instrument(skip_all)]
+    async fn try_insert_payment_dedup_key(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        dedup_key: &str,
+        payment_id: &id_type::PaymentId,
+        window: time::Duration,
+    ) -> CustomResult<Option<id_type::PaymentId>, errors::StorageError> {
+        let redis_conn = self
+            .get_redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?;
+        try_insert_dedup_key(&redis_conn, merchant_id, dedup_key, payment_id, window).await
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_payment_dedup_key(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        dedup_key: &str,
+        payment_id: &id_type::PaymentId,
+        window: time::Duration,
+    ) -> CustomResult<(), errors::StorageError> {
+        let redis_conn = self
+            .get_redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?;
+        insert_dedup_key(&redis_conn, merchant_id, dedup_key, payment_id, window).await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_payment_dedup_key(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        dedup_key: &str,
+        payment_id: &id_type::PaymentId,
+        window: time::Duration,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let redis_conn = self
+            .get_redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?;
+        delete_dedup_key(&redis_conn, merchant_id, dedup_key, payment_id, window).await
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentDedupInterface for MockDb {
+    async fn find_recent_payment_by_dedup_key(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        dedup_key: &str,
+        window: time::Duration,
+    ) -> CustomResult<Option<id_type::PaymentId>, errors::StorageError> {
+        let redis_conn = self
+            .get_redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?;
+        find_dedup_key(&redis_conn, merchant_id, dedup_key, window).await
+    }
+
+    async fn try_insert_payment_dedup_key(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        dedup_key: &str,
+        payment_id: &id_type::PaymentId,
+        window: time::Duration,
+    ) -> CustomResult<Option<id_type::PaymentId>, errors::StorageError> {
+        let redis_conn = self
+            .get_redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?;
+        try_insert_dedup_key(&redis_conn, merchant_id, dedup_key, payment_id, window).await
+    }
+
+    async fn insert_payment_dedup_key(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        dedup_key: &str,
+        payment_id: &id_type::PaymentId,
+        window: time::Duration,
+    ) -> CustomResult<(), errors::StorageError> {
+        let redis_conn = self
+            .get_redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?;
+        insert_dedup_key(&redis_conn, merchant_id, dedup_key, payment_id, window).await
+    }
+
+    async fn delete_payment_dedup_key(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        dedup_key: &str,
+        payment_id: &id_type::PaymentId,
+        window: time::Duration,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let redis_conn = self
+            .get_redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?;
+        delete_dedup_key(&redis_conn, merchant_id, dedup_key, payment_id, window).await
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentDedupInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn find_recent_payment_by_dedup_key(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        dedup_key: &str,
+        window: time::Duration,
+    ) -> CustomResult<Option<id_type::PaymentId>, errors::StorageError> {
+        self.diesel_store
+            .find_recent_payment_by_dedup_key(merchant_id, dedup_key, window)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn try_insert_payment_dedup_key(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        dedup_key: &str,
+        payment_id: &id_type::PaymentId,
+        window: time::Duration,
+    ) -> CustomResult<Option<id_type::PaymentId>, errors::StorageError> {
+        self.diesel_store
+            .try_insert_payment_dedup_key(merchant_id, dedup_key, payment_id, window)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_payment_dedup_key(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        dedup_key: &str,
+        payment_id: &id_type::PaymentId,
+        window: time::Duration,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .insert_payment_dedup_key(merchant_id, dedup_key, payment_id, window)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_payment_dedup_key(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        dedup_key: &str,
+        payment_id: &id_type::PaymentId,
+        window: time::Duration,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_payment_dedup_key(merchant_id, dedup_key, payment_id, window)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use common_utils::id_type;
+
+    use super::PaymentDedupInterface;
+    use crate::db::MockDb;
+
+    fn generate_payment_id() -> id_type::PaymentId {
+        id_type::PaymentId::try_from(Cow::from(common_utils::generate_id_with_default_len("pay")))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_payment_dedup_key_is_registered_once_within_the_window() {
+        let mock_db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create mock DB");
+        let merchant_id = id_type::MerchantId::try_from(Cow::from("merchant_dedup")).unwrap();
+        let dedup_key = common_utils::generate_id_with_default_len("dedup");
+        let window = time::Duration::seconds(60);
+        let first_payment_id = generate_payment_id();
+        let second_payment_id = generate_payment_id();
+
+        assert_eq!(
+            mock_db
+                .find_recent_payment_by_dedup_key(&merchant_id, &dedup_key, window)
+                .await
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            mock_db
+                .try_insert_payment_dedup_key(&merchant_id, &dedup_key, &first_payment_id, window)
+                .await
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            mock_db
+                .find_recent_payment_by_dedup_key(&merchant_id, &dedup_key, window)
+                .await
+                .unwrap(),
+            Some(first_payment_id.clone())
+        );
+        // The lookups registered with a different window are not matched
+        assert_eq!(
+            mock_db
+                .find_recent_payment_by_dedup_key(
+                    &merchant_id,
+                    &dedup_key,
+                    time::Duration::seconds(120)
+                )
+                .await
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            mock_db
+                .try_insert_payment_dedup_key(&merchant_id, &dedup_key, &second_payment_id, window)
+                .await
+                .unwrap(),
+            Some(first_payment_id.clone())
+        );
+
+        // The registration of a payment is not removed for another payment
+        assert!(!mock_db
+            .delete_payment_dedup_key(&merchant_id, &dedup_key, &second_payment_id, window)
+            .await
+            .unwrap());
+
+        mock_db
+            .insert_payment_dedup_key(&merchant_id, &dedup_key, &second_payment_id, window)
+            .await
+            .unwrap();
+        assert!(!mock_db
+            .delete_payment_dedup_key(&merchant_id, &dedup_key, &first_payment_id, window)
+            .await
+            .unwrap());
+        assert!(mock_db
+            .delete_payment_dedup_key(&merchant_id, &dedup_key, &second_payment_id, window)
+            .await
+            .unwrap());
+
+        assert_eq!(
+            mock_db
+                .try_insert_payment_dedup_key(&merchant_id, &dedup_key, &first_payment_id, window)
+                .await
+                .unwrap(),
+            None
+        );
+    }
+}
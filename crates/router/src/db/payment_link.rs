@@ -26,6 +26,16 @@ pub trait PaymentLinkInterface {
         merchant_id: &common_utils::id_type::MerchantId,
         payment_link_constraints: api_models::payments::PaymentLinkListConstraints,
     ) -> CustomResult<Vec<storage::PaymentLink>, errors::StorageError>;
+
+    /// Delete at most `limit` of the payment links which expired before the time, oldest first, so
+    /// that the expired payment links can be purged in batches. The payment links which expired
+    /// within the grace period of the customers in the middle of completing the payments are to be
+    /// excluded by the time.
+    async fn delete_payment_links_expired_before(
+        &self,
+        expired_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentLink>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -64,24 +74,89 @@ impl PaymentLinkInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn delete_payment_links_expired_before(
+        &self,
+        expired_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentLink>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        let payment_link_ids =
+            storage::PaymentLink::find_expired_before(&conn, expired_before, limit)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))?
+                .into_iter()
+                .map(|payment_link| payment_link.payment_link_id)
+                .collect::<Vec<_>>();
+        if payment_link_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        storage::PaymentLink::delete_expired_before_by_payment_link_ids(
+            &conn,
+            payment_link_ids,
+            expired_before,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
 impl PaymentLinkInterface for MockDb {
     async fn insert_payment_link(
         &self,
-        _payment_link: storage::PaymentLinkNew,
+        payment_link: storage::PaymentLinkNew,
     ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
-        // TODO: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut payment_links = self.payment_link.lock().await;
+        if payment_links
+            .iter()
+            .any(|link| link.payment_link_id == payment_link.payment_link_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "payment_link_id",
+                key: Some(payment_link.payment_link_id.clone()),
+            })?
+        }
+
+        let now = common_utils::date_time::now();
+        let payment_link = storage::PaymentLink {
+            payment_link_id: payment_link.payment_link_id,
+            payment_id: payment_link.payment_id,
+            link_to_pay: payment_link.link_to_pay,
+            merchant_id: payment_link.merchant_id,
+            amount: payment_link.amount,
+            currency: payment_link.currency,
+            created_at: payment_link.created_at.unwrap_or(now),
+            last_modified_at: payment_link.last_modified_at.unwrap_or(now),
+            fulfilment_time: payment_link.fulfilment_time,
+            custom_merchant_name: payment_link.custom_merchant_name,
+            payment_link_config: payment_link.payment_link_config,
+            description: payment_link.description,
+            profile_id: payment_link.profile_id,
+            secure_link: payment_link.secure_link,
+        };
+        payment_links.push(payment_link.clone());
+        Ok(payment_link)
     }
 
     async fn find_payment_link_by_payment_link_id(
         &self,
-        _payment_link_id: &str,
+        payment_link_id: &str,
     ) -> CustomResult<storage::PaymentLink, errors::StorageError> {
-        // TODO: Implement function for `MockDb`x
-        Err(errors::StorageError::MockDbError)?
+        self.payment_link
+            .lock()
+            .await
+            .iter()
+            .find(|link| link.payment_link_id == payment_link_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No payment link found for payment_link_id = {payment_link_id}"
+                ))
+                .into(),
+            )
     }
 
     async fn list_payment_link_by_merchant_id(
@@ -92,4 +167,111 @@ impl PaymentLinkInterface for MockDb {
         // TODO: Implement function for `MockDb`x
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn delete_payment_links_expired_before(
+        &self,
+        expired_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentLink>, errors::StorageError> {
+        let mut payment_links = self.payment_link.lock().await;
+        let mut expired_links = payment_links
+            .iter()
+            .filter_map(|link| {
+                link.fulfilment_time
+                    .filter(|fulfilment_time| *fulfilment_time < expired_before)
+                    .map(|fulfilment_time| (fulfilment_time, link.payment_link_id.clone()))
+            })
+            .collect::<Vec<_>>();
+        expired_links.sort();
+        let expired_link_ids = expired_links
+            .into_iter()
+            .take(usize::try_from(limit).unwrap_or(0))
+            .map(|(_, payment_link_id)| payment_link_id)
+            .collect::<Vec<_>>();
+
+        let (deleted_links, retained_links) = payment_links
+            .drain(..)
+            .partition(|link| expired_link_ids.contains(&link.payment_link_id));
+        *payment_links = retained_links;
+        Ok(deleted_links)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_payment_link(
+        payment_link_id: &str,
+        fulfilment_time: time::PrimitiveDateTime,
+    ) -> storage::PaymentLinkNew {
+        storage::PaymentLinkNew {
+            payment_link_id: payment_link_id.to_string(),
+            payment_id: common_utils::id_type::PaymentId::try_from(std::borrow::Cow::Borrowed(
+                "pay_1",
+            ))
+            .unwrap(),
+            link_to_pay: format!("https://example.com/{payment_link_id}"),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            amount: common_utils::types::MinorUnit::new(100),
+            currency: None,
+            created_at: None,
+            last_modified_at: None,
+            fulfilment_time: Some(fulfilment_time),
+            custom_merchant_name: None,
+            payment_link_config: None,
+            description: None,
+            profile_id: None,
+            secure_link: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_expired_payment_links_are_deleted_in_batches_oldest_first() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let now = common_utils::date_time::now();
+
+        for (payment_link_id, minutes) in [("plink_2", -20), ("plink_1", -30), ("plink_3", 10)] {
+            mockdb
+                .insert_payment_link(get_payment_link(
+                    payment_link_id,
+                    now.saturating_add(time::Duration::minutes(minutes)),
+                ))
+                .await
+                .unwrap();
+        }
+
+        let deleted_links = mockdb
+            .delete_payment_links_expired_before(now, 1)
+            .await
+            .unwrap();
+        assert_eq!(
+            deleted_links
+                .iter()
+                .map(|link| link.payment_link_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["plink_1"]
+        );
+
+        let deleted_links = mockdb
+            .delete_payment_links_expired_before(now, 10)
+            .await
+            .unwrap();
+        assert_eq!(
+            deleted_links
+                .iter()
+                .map(|link| link.payment_link_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["plink_2"]
+        );
+
+        assert!(mockdb
+            .find_payment_link_by_payment_link_id("plink_3")
+            .await
+            .is_ok());
+    }
 }
@@ -60,6 +60,20 @@ pub trait PaymentMethodInterface {
         limit: Option<i64>,
     ) -> CustomResult<Vec<domain::PaymentMethod>, errors::StorageError>;
 
+    /// List the card payment methods of the merchant which match the criteria, so that they can be
+    /// re-tokenized with the network tokenization service
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    async fn list_payment_methods_for_retokenization(
+        &self,
+        state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        criteria: &storage_types::PaymentMethodRetokenizationCriteria,
+        limit: i64,
+    ) -> CustomResult<Vec<domain::PaymentMethod>, errors::StorageError>;
+
     // Need to fix this once we start moving to v2 for payment method
     #[cfg(all(feature = "v2", feature = "customer_v2"))]
     async fn find_payment_method_list_by_global_id(
@@ -199,8 +213,13 @@ mod storage {
                     MerchantStorageScheme::RedisKv => {
                         let lookup_id = format!("payment_method_{}", payment_method_id);
                         let lookup = fallback_reverse_lookup_not_found!(
-                            self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
-                                .await,
+                            self.get_namespaced_lookup_by_lookup_id(
+                                &lookup_id,
+                                &key_store.merchant_id,
+                                Some(&lookup_id),
+                                storage_scheme,
+                            )
+                            .await,
                             database_call().await
                         );
 
@@ -267,8 +286,13 @@ mod storage {
                         let lookup_id =
                             format!("payment_method_{}", payment_method_id.get_string_repr());
                         let lookup = fallback_reverse_lookup_not_found!(
-                            self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
-                                .await,
+                            self.get_namespaced_lookup_by_lookup_id(
+                                &lookup_id,
+                                &key_store.merchant_id,
+                                Some(&lookup_id),
+                                storage_scheme,
+                            )
+                            .await,
                             database_call().await
                         );
 
@@ -337,8 +361,13 @@ mod storage {
                     MerchantStorageScheme::RedisKv => {
                         let lookup_id = format!("payment_method_locker_{}", locker_id);
                         let lookup = fallback_reverse_lookup_not_found!(
-                            self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
-                                .await,
+                            self.get_namespaced_lookup_by_lookup_id(
+                                &lookup_id,
+                                &key_store.merchant_id,
+                                Some(&lookup_id),
+                                storage_scheme,
+                            )
+                            .await,
                             database_call().await
                         );
 
@@ -487,7 +516,11 @@ mod storage {
                     }
 
                     let results = reverse_lookups.into_iter().map(|v| {
-                        self.insert_reverse_lookup(reverse_lookup_entry(v), storage_scheme)
+                        self.insert_namespaced_reverse_lookup(
+                            reverse_lookup_entry(v),
+                            &merchant_id,
+                            storage_scheme,
+                        )
                     });
 
                     futures::future::try_join_all(results).await?;
@@ -708,6 +741,40 @@ mod storage {
             todo!()
         }
 
+        #[cfg(all(
+            any(feature = "v1", feature = "v2"),
+            not(feature = "payment_methods_v2")
+        ))]
+        #[instrument(skip_all)]
+        async fn list_payment_methods_for_retokenization(
+            &self,
+            state: &KeyManagerState,
+            key_store: &domain::MerchantKeyStore,
+            criteria: &storage_types::PaymentMethodRetokenizationCriteria,
+            limit: i64,
+        ) -> CustomResult<Vec<domain::PaymentMethod>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            let payment_methods =
+                storage_types::PaymentMethod::find_for_retokenization(&conn, criteria, limit)
+                    .await
+                    .map_err(|error| report!(errors::StorageError::from(error)))?;
+
+            let pm_futures = payment_methods
+                .into_iter()
+                .map(|pm| async {
+                    pm.convert(
+                        state,
+                        key_store.key.get_inner(),
+                        key_store.merchant_id.clone().into(),
+                    )
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)
+                })
+                .collect::<Vec<_>>();
+
+            futures::future::try_join_all(pm_futures).await
+        }
+
         #[instrument(skip_all)]
         async fn find_payment_method_by_customer_id_merchant_id_status(
             &self,
@@ -1125,6 +1192,40 @@ mod storage {
                 .map_err(|error| report!(errors::StorageError::from(error)))
         }
 
+        #[cfg(all(
+            any(feature = "v1", feature = "v2"),
+            not(feature = "payment_methods_v2")
+        ))]
+        #[instrument(skip_all)]
+        async fn list_payment_methods_for_retokenization(
+            &self,
+            state: &KeyManagerState,
+            key_store: &domain::MerchantKeyStore,
+            criteria: &storage_types::PaymentMethodRetokenizationCriteria,
+            limit: i64,
+        ) -> CustomResult<Vec<domain::PaymentMethod>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            let payment_methods =
+                storage_types::PaymentMethod::find_for_retokenization(&conn, criteria, limit)
+                    .await
+                    .map_err(|error| report!(errors::StorageError::from(error)))?;
+
+            let pm_futures = payment_methods
+                .into_iter()
+                .map(|pm| async {
+                    pm.convert(
+                        state,
+                        key_store.key.get_inner(),
+                        key_store.merchant_id.clone().into(),
+                    )
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)
+                })
+                .collect::<Vec<_>>();
+
+            futures::future::try_join_all(pm_futures).await
+        }
+
         #[instrument(skip_all)]
         async fn find_payment_method_by_customer_id_merchant_id_status(
             &self,
@@ -1424,6 +1525,59 @@ impl PaymentMethodInterface for MockDb {
         }
     }
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    async fn list_payment_methods_for_retokenization(
+        &self,
+        state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        criteria: &storage_types::PaymentMethodRetokenizationCriteria,
+        limit: i64,
+    ) -> CustomResult<Vec<domain::PaymentMethod>, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+        let mut payment_methods_found: Vec<storage_types::PaymentMethod> = payment_methods
+            .iter()
+            .filter(|pm| {
+                pm.merchant_id == criteria.merchant_id
+                    && pm.payment_method == Some(common_enums::PaymentMethod::Card)
+                    && pm.status == common_enums::PaymentMethodStatus::Active
+                    && (!criteria.retokenization_pending_only
+                        || pm.retokenization_pending == Some(true))
+                    && criteria
+                        .last_modified_before
+                        .map_or(true, |last_modified_before| {
+                            pm.last_modified < last_modified_before
+                        })
+                    && criteria
+                        .starting_after
+                        .as_ref()
+                        .map_or(true, |starting_after| {
+                            pm.payment_method_id > *starting_after
+                        })
+            })
+            .cloned()
+            .collect();
+        payment_methods_found.sort_by(|a, b| a.payment_method_id.cmp(&b.payment_method_id));
+        payment_methods_found.truncate(usize::try_from(limit).unwrap_or_default());
+
+        let pm_futures = payment_methods_found
+            .into_iter()
+            .map(|pm| async {
+                pm.convert(
+                    state,
+                    key_store.key.get_inner(),
+                    key_store.merchant_id.clone().into(),
+                )
+                .await
+                .change_context(errors::StorageError::DecryptionError)
+            })
+            .collect::<Vec<_>>();
+
+        futures::future::try_join_all(pm_futures).await
+    }
+
     // Need to fix this once we complete v2 payment method
     #[cfg(all(feature = "v2", feature = "customer_v2"))]
     async fn find_payment_method_list_by_global_id(
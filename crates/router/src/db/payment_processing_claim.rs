@@ -0,0 +1,288 @@
+//! Claims on payments for processing by a worker.
+//!
+//! A claim is a lease held in redis, so that a payment which is processed asynchronously, such as
+//! an async payment being polled, is processed by only one of the workers of all the instances at
+//! a time. The lease expires after its duration, so that the payment can be claimed again if the
+//! worker holding it fails without releasing it.
+
+use common_utils::id_type;
+use error_stack::ResultExt;
+use redis_interface::{RedisConnectionPool, SetnxReply};
+use router_env::{instrument, tracing};
+use storage_impl::{redis::kv_store::RedisConnInterface, MockDb};
+
+use super::Store;
+use crate::{
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+};
+
+fn get_payment_processing_claim_key(
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> String {
+    format!(
+        "payment_processing_claim_{}_{}",
+        merchant_id.get_string_repr(),
+        payment_id.get_string_repr()
+    )
+}
+
+#[async_trait::async_trait]
+pub trait PaymentProcessingClaimInterface {
+    /// Claim the payment for processing by the worker for the lease duration, in seconds. Returns
+    /// whether the claim succeeded, which is not the case when the payment is already claimed by
+    /// a worker, including the same worker.
+    async fn try_claim_payment_for_processing(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_id: &id_type::PaymentId,
+        worker_id: &str,
+        lease_duration: i64,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    /// Release the claim of the worker on the payment before its lease expires. Returns whether
+    /// the claim was released, which is not the case when the payment is not claimed by the
+    /// worker, for example once its lease has expired.
+    async fn release_payment_processing_claim(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_id: &id_type::PaymentId,
+        worker_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+/// Claim the key for the worker for the lease duration, in seconds, returning whether the claim
+/// succeeded
+pub(super) async fn try_claim(
+    redis_conn: &RedisConnectionPool,
+    claim_key: &str,
+    worker_id: &str,
+    lease_duration: i64,
+) -> CustomResult<bool, errors::StorageError> {
+    redis_conn
+        .set_key_if_not_exists_with_expiry(claim_key, worker_id, Some(lease_duration))
+        .await
+        .map(|reply| matches!(reply, SetnxReply::KeySet))
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("Failed to claim the key")
+}
+
+/// Release the claim of the worker on the key, returning whether the claim was released
+pub(crate) async fn release_claim(
+    redis_conn: &RedisConnectionPool,
+    claim_key: &str,
+    worker_id: &str,
+) -> CustomResult<bool, errors::StorageError> {
+    // The claim of another worker must not be released, the key may have been claimed by it
+    // after the lease of this worker expired. The claim is compared and deleted in a single
+    // script, so that the key cannot be claimed by another worker in between.
+    const RELEASE_CLAIM_SCRIPT: &str = r#"
+        if redis.call("GET", KEYS[1]) == ARGV[1] then
+            return redis.call("DEL", KEYS[1])
+        end
+        return 0
+    "#;
+
+    redis_conn
+        .evaluate_redis_script::<i64>(
+            RELEASE_CLAIM_SCRIPT,
+            vec![claim_key.to_owned()],
+            vec![worker_id.to_owned()],
+        )
+        .await
+        .map(|deleted_keys| deleted_keys > 0)
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("Failed to release the claim on the key")
+}
+
+async fn try_claim_payment(
+    redis_conn: &RedisConnectionPool,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+    worker_id: &str,
+    lease_duration: i64,
+) -> CustomResult<bool, errors::StorageError> {
+    try_claim(
+        redis_conn,
+        &get_payment_processing_claim_key(merchant_id, payment_id),
+        worker_id,
+        lease_duration,
+    )
+    .await
+    .attach_printable("Failed to claim the payment for processing")
+}
+
+async fn release_payment_claim(
+    redis_conn: &RedisConnectionPool,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+    worker_id: &str,
+) -> CustomResult<bool, errors::StorageError> {
+    release_claim(
+        redis_conn,
+        &get_payment_processing_claim_key(merchant_id, payment_id),
+        worker_id,
+    )
+    .await
+    .attach_printable("Failed to release the claim on the payment")
+}
+
+#[async_trait::async_trait]
+impl PaymentProcessingClaimInterface for Store {
+    #[instrument(skip_all)]
+    async fn try_claim_payment_for_processing(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_id: &id_type::PaymentId,
+        worker_id: &str,
+        lease_duration: i64,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let redis_conn = self
+            .get_redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?;
+        try_claim_payment(
+            &redis_conn,
+            merchant_id,
+            payment_id,
+            worker_id,
+            lease_duration,
+        )
+        .await
+    }
+
+    #[instrument(skip_all)]
+    async fn release_payment_processing_claim(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_id: &id_type::PaymentId,
+        worker_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let redis_conn = self
+            .get_redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?;
+        release_payment_claim(&redis_conn, merchant_id, payment_id, worker_id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentProcessingClaimInterface for MockDb {
+    async fn try_claim_payment_for_processing(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_id: &id_type::PaymentId,
+        worker_id: &str,
+        lease_duration: i64,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let redis_conn = self
+            .get_redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?;
+        try_claim_payment(
+            &redis_conn,
+            merchant_id,
+            payment_id,
+            worker_id,
+            lease_duration,
+        )
+        .await
+    }
+
+    async fn release_payment_processing_claim(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_id: &id_type::PaymentId,
+        worker_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let redis_conn = self
+            .get_redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?;
+        release_payment_claim(&redis_conn, merchant_id, payment_id, worker_id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentProcessingClaimInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn try_claim_payment_for_processing(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_id: &id_type::PaymentId,
+        worker_id: &str,
+        lease_duration: i64,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .try_claim_payment_for_processing(merchant_id, payment_id, worker_id, lease_duration)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn release_payment_processing_claim(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_id: &id_type::PaymentId,
+        worker_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .release_payment_processing_claim(merchant_id, payment_id, worker_id)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use common_utils::id_type;
+
+    use super::PaymentProcessingClaimInterface;
+    use crate::db::MockDb;
+
+    #[tokio::test]
+    async fn test_payment_is_claimed_by_one_worker_at_a_time() {
+        let mock_db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create mock DB");
+        let merchant_id = id_type::MerchantId::try_from(Cow::from("merchant_claim")).unwrap();
+        let payment_id = id_type::PaymentId::try_from(Cow::from(
+            common_utils::generate_id_with_default_len("pay"),
+        ))
+        .unwrap();
+
+        assert!(mock_db
+            .try_claim_payment_for_processing(&merchant_id, &payment_id, "worker_1", 60)
+            .await
+            .unwrap());
+        assert!(!mock_db
+            .try_claim_payment_for_processing(&merchant_id, &payment_id, "worker_2", 60)
+            .await
+            .unwrap());
+        assert!(!mock_db
+            .try_claim_payment_for_processing(&merchant_id, &payment_id, "worker_1", 60)
+            .await
+            .unwrap());
+
+        // The claim of a worker is not released by another worker
+        assert!(!mock_db
+            .release_payment_processing_claim(&merchant_id, &payment_id, "worker_2")
+            .await
+            .unwrap());
+        assert!(mock_db
+            .release_payment_processing_claim(&merchant_id, &payment_id, "worker_1")
+            .await
+            .unwrap());
+        assert!(!mock_db
+            .release_payment_processing_claim(&merchant_id, &payment_id, "worker_1")
+            .await
+            .unwrap());
+
+        assert!(mock_db
+            .try_claim_payment_for_processing(&merchant_id, &payment_id, "worker_2", 60)
+            .await
+            .unwrap());
+        assert!(mock_db
+            .release_payment_processing_claim(&merchant_id, &payment_id, "worker_2")
+            .await
+            .unwrap());
+    }
+}
@@ -344,13 +344,17 @@ mod storage {
             match storage_scheme {
                 enums::MerchantStorageScheme::PostgresOnly => database_call().await,
                 enums::MerchantStorageScheme::RedisKv => {
-                    let lookup_id = format!(
-                        "ref_inter_ref_{}_{internal_reference_id}",
-                        merchant_id.get_string_repr()
-                    );
                     let lookup = fallback_reverse_lookup_not_found!(
-                        self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
-                            .await,
+                        self.get_namespaced_lookup_by_lookup_id(
+                            &format!("ref_inter_ref_{internal_reference_id}"),
+                            merchant_id,
+                            Some(&format!(
+                                "ref_inter_ref_{}_{internal_reference_id}",
+                                merchant_id.get_string_repr()
+                            )),
+                            storage_scheme,
+                        )
+                        .await,
                         database_call().await
                     );
 
@@ -448,11 +452,7 @@ mod storage {
                     let mut reverse_lookups = vec![
                         storage_types::ReverseLookupNew {
                             sk_id: field.clone(),
-                            lookup_id: format!(
-                                "ref_ref_id_{}_{}",
-                                created_refund.merchant_id.get_string_repr(),
-                                created_refund.refund_id
-                            ),
+                            lookup_id: format!("ref_ref_id_{}", created_refund.refund_id),
                             pk_id: key_str.clone(),
                             source: "refund".to_string(),
                             updated_by: storage_scheme.to_string(),
@@ -461,8 +461,7 @@ mod storage {
                         storage_types::ReverseLookupNew {
                             sk_id: field.clone(),
                             lookup_id: format!(
-                                "ref_inter_ref_{}_{}",
-                                created_refund.merchant_id.get_string_repr(),
+                                "ref_inter_ref_{}",
                                 created_refund.internal_reference_id
                             ),
                             pk_id: key_str.clone(),
@@ -475,19 +474,21 @@ mod storage {
                         reverse_lookups.push(storage_types::ReverseLookupNew {
                             sk_id: field.clone(),
                             lookup_id: format!(
-                                "ref_connector_{}_{}_{}",
-                                created_refund.merchant_id.get_string_repr(),
-                                connector_refund_id,
-                                created_refund.connector
+                                "ref_connector_{}_{}",
+                                connector_refund_id, created_refund.connector
                             ),
                             pk_id: key_str.clone(),
                             source: "refund".to_string(),
                             updated_by: storage_scheme.to_string(),
                         })
                     };
-                    let rev_look = reverse_lookups
-                        .into_iter()
-                        .map(|rev| self.insert_reverse_lookup(rev, storage_scheme));
+                    let rev_look = reverse_lookups.into_iter().map(|rev| {
+                        self.insert_namespaced_reverse_lookup(
+                            rev,
+                            &created_refund.merchant_id,
+                            storage_scheme,
+                        )
+                    });
 
                     futures::future::try_join_all(rev_look).await?;
 
@@ -542,13 +543,17 @@ mod storage {
             match storage_scheme {
                 enums::MerchantStorageScheme::PostgresOnly => database_call().await,
                 enums::MerchantStorageScheme::RedisKv => {
-                    let lookup_id = format!(
-                        "pa_conn_trans_{}_{connector_transaction_id}",
-                        merchant_id.get_string_repr()
-                    );
                     let lookup = fallback_reverse_lookup_not_found!(
-                        self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
-                            .await,
+                        self.get_namespaced_lookup_by_lookup_id(
+                            &format!("pa_conn_trans_{connector_transaction_id}"),
+                            merchant_id,
+                            Some(&format!(
+                                "pa_conn_trans_{}_{connector_transaction_id}",
+                                merchant_id.get_string_repr()
+                            )),
+                            storage_scheme,
+                        )
+                        .await,
                         database_call().await
                     );
 
@@ -606,6 +611,31 @@ mod storage {
                     let key_str = key.to_string();
                     let updated_refund = refund.clone().apply_changeset(this.clone());
 
+                    // The connector refund id is usually known only once the refund is sent to
+                    // the connector, and is required to resolve the refund webhooks of the
+                    // connectors which process the refunds asynchronously
+                    if let Some(connector_refund_id) = updated_refund
+                        .connector_refund_id
+                        .as_ref()
+                        .filter(|&id| this.connector_refund_id.as_ref() != Some(id))
+                    {
+                        self.insert_namespaced_reverse_lookup(
+                            storage_types::ReverseLookupNew {
+                                sk_id: field.clone(),
+                                lookup_id: format!(
+                                    "ref_connector_{}_{}",
+                                    connector_refund_id, updated_refund.connector
+                                ),
+                                pk_id: key_str.clone(),
+                                source: "refund".to_string(),
+                                updated_by: storage_scheme.to_string(),
+                            },
+                            &updated_refund.merchant_id,
+                            storage_scheme,
+                        )
+                        .await?;
+                    }
+
                     let redis_value = updated_refund
                         .encode_to_string_of_json()
                         .change_context(errors::StorageError::SerializationFailed)?;
@@ -659,11 +689,17 @@ mod storage {
             match storage_scheme {
                 enums::MerchantStorageScheme::PostgresOnly => database_call().await,
                 enums::MerchantStorageScheme::RedisKv => {
-                    let lookup_id =
-                        format!("ref_ref_id_{}_{refund_id}", merchant_id.get_string_repr());
                     let lookup = fallback_reverse_lookup_not_found!(
-                        self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
-                            .await,
+                        self.get_namespaced_lookup_by_lookup_id(
+                            &format!("ref_ref_id_{refund_id}"),
+                            merchant_id,
+                            Some(&format!(
+                                "ref_ref_id_{}_{refund_id}",
+                                merchant_id.get_string_repr()
+                            )),
+                            storage_scheme,
+                        )
+                        .await,
                         database_call().await
                     );
 
@@ -715,13 +751,17 @@ mod storage {
             match storage_scheme {
                 enums::MerchantStorageScheme::PostgresOnly => database_call().await,
                 enums::MerchantStorageScheme::RedisKv => {
-                    let lookup_id = format!(
-                        "ref_connector_{}_{connector_refund_id}_{connector}",
-                        merchant_id.get_string_repr()
-                    );
                     let lookup = fallback_reverse_lookup_not_found!(
-                        self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
-                            .await,
+                        self.get_namespaced_lookup_by_lookup_id(
+                            &format!("ref_connector_{connector_refund_id}_{connector}"),
+                            merchant_id,
+                            Some(&format!(
+                                "ref_connector_{}_{connector_refund_id}_{connector}",
+                                merchant_id.get_string_repr()
+                            )),
+                            storage_scheme,
+                        )
+                        .await,
                         database_call().await
                     );
 
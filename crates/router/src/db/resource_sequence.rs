@@ -0,0 +1,418 @@
+//! Monotonic sequences of values per resource, such as the numbering of the events of a resource.
+//!
+//! The values of a sequence are drawn from a counter in redis with an atomic increment, so that the
+//! concurrent calls of all the instances never draw the same value. The counter also holds the
+//! last value reserved for it in postgres, where the values are reserved in blocks: a value within
+//! the reserved block is issued without reaching postgres, while the first value drawn past it
+//! reserves the next block. Postgres therefore always holds a value which is not lower than any
+//! value issued from the counter.
+//!
+//! A counter which is missing from redis, such as a counter which expired or was evicted, is
+//! seeded with the last value of the sequence in postgres before it is drawn from. A counter which
+//! is behind the sequence, such as a counter which missed the values issued while redis could not
+//! be reached, cannot reserve the next block, and is moved past the last value of the sequence
+//! instead. The values are issued by incrementing the last value of the sequence in postgres while
+//! redis cannot be reached.
+//!
+//! A sequence may skip values, but never issues the same value twice, and draws its values in
+//! increasing order. The first value of a new sequence is 1.
+
+use error_stack::{report, ResultExt};
+use redis_interface::RedisConnectionPool;
+use router_env::{instrument, logger, tracing};
+use storage_impl::{redis::kv_store::RedisConnInterface, MockDb};
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+/// The number of draws from the counter while the counter is missing or behind the sequence,
+/// after which the value is issued from postgres
+const MAX_COUNTER_DRAWS: u8 = 3;
+
+/// The number of values reserved in postgres at a time for the counter of a sequence
+const RESOURCE_SEQUENCE_RESERVATION_SIZE: i64 = 100;
+
+/// The time in seconds after the last draw at which the counter of a sequence expires. A counter
+/// which expired is seeded again from postgres on the next draw.
+const RESOURCE_SEQUENCE_COUNTER_TTL: i64 = 7 * 24 * 60 * 60;
+
+/// Draw the next value from the counter, returning the value and the last value reserved for the
+/// counter, or nothing if the counter does not exist. A missing counter is not created, as it
+/// would start behind the values issued before it. The expiry is refreshed with every draw, so
+/// that only the counters of the sequences which are no longer drawn from expire.
+const DRAW_COUNTER_SCRIPT: &str = r#"
+    if redis.call('EXISTS', KEYS[1]) == 0 then
+        return {}
+    end
+    local value = redis.call('HINCRBY', KEYS[1], 'value', 1)
+    local reserved = tonumber(redis.call('HGET', KEYS[1], 'reserved') or '0')
+    redis.call('EXPIRE', KEYS[1], ARGV[1])
+    return {value, reserved}
+"#;
+
+/// Raise the value of the counter and the last value reserved for it to at least the last value of
+/// the sequence, creating the counter if it does not exist
+const SEED_COUNTER_SCRIPT: &str = r#"
+    local value = tonumber(redis.call('HGET', KEYS[1], 'value') or ARGV[1])
+    local reserved = tonumber(redis.call('HGET', KEYS[1], 'reserved') or ARGV[1])
+    redis.call(
+        'HSET', KEYS[1],
+        'value', math.max(value, tonumber(ARGV[1])),
+        'reserved', math.max(reserved, tonumber(ARGV[1]))
+    )
+    redis.call('EXPIRE', KEYS[1], ARGV[2])
+    return 1
+"#;
+
+/// Record the block reserved by the value drawn from the counter, unless the counter was replaced
+/// since the value was drawn, in which case the counter is behind the value and was seeded without
+/// the reservation
+const RECORD_RESERVATION_SCRIPT: &str = r#"
+    local value = tonumber(redis.call('HGET', KEYS[1], 'value') or '0')
+    if value < tonumber(ARGV[1]) then
+        return 0
+    end
+    local reserved = tonumber(redis.call('HGET', KEYS[1], 'reserved') or '0')
+    redis.call('HSET', KEYS[1], 'reserved', math.max(reserved, tonumber(ARGV[2])))
+    return 1
+"#;
+
+fn get_resource_sequence_key(namespace: &str, resource_id: &str) -> String {
+    format!("resource_sequence_{namespace}_{resource_id}")
+}
+
+#[async_trait::async_trait]
+pub trait ResourceSequenceInterface {
+    /// Issue the next value of the sequence of the resource in the namespace, which is higher than
+    /// every value drawn from the sequence before it. The first value of a new sequence is 1.
+    async fn next_sequence(
+        &self,
+        namespace: &str,
+        resource_id: &str,
+    ) -> CustomResult<i64, errors::StorageError>;
+}
+
+/// A value drawn from the counter of a sequence
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CounterDraw {
+    value: i64,
+    /// The last value reserved in postgres for the counter
+    reserved: i64,
+}
+
+impl CounterDraw {
+    /// Whether the value is within the block reserved for the counter, and can be issued without
+    /// reserving the next block
+    fn is_reserved(&self) -> bool {
+        self.value <= self.reserved
+    }
+
+    /// The last value of the block reserved by the value
+    fn get_reservation_end(&self) -> i64 {
+        self.value
+            .saturating_add(RESOURCE_SEQUENCE_RESERVATION_SIZE - 1)
+    }
+}
+
+/// Draw the next value from the counter, which is `None` if the counter does not exist
+async fn draw_from_counter(
+    redis_conn: &RedisConnectionPool,
+    key: &str,
+) -> CustomResult<Option<CounterDraw>, errors::StorageError> {
+    let reply = redis_conn
+        .evaluate_redis_script::<Vec<i64>>(
+            DRAW_COUNTER_SCRIPT,
+            vec![key.to_owned()],
+            vec![RESOURCE_SEQUENCE_COUNTER_TTL.to_string()],
+        )
+        .await
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("Failed to draw from the resource sequence counter")?;
+
+    match reply.as_slice() {
+        [] => Ok(None),
+        [value, reserved] => Ok(Some(CounterDraw {
+            value: *value,
+            reserved: *reserved,
+        })),
+        _ => Err(report!(errors::StorageError::KVError))
+            .attach_printable_lazy(|| format!("Invalid resource sequence counter draw: {reply:?}")),
+    }
+}
+
+/// Raise the value of the counter and the last value reserved for it to at least the last value of
+/// the sequence
+async fn seed_counter(
+    redis_conn: &RedisConnectionPool,
+    key: &str,
+    last_value: i64,
+) -> CustomResult<(), errors::StorageError> {
+    redis_conn
+        .evaluate_redis_script::<i64>(
+            SEED_COUNTER_SCRIPT,
+            vec![key.to_owned()],
+            vec![
+                last_value.to_string(),
+                RESOURCE_SEQUENCE_COUNTER_TTL.to_string(),
+            ],
+        )
+        .await
+        .map(|_| ())
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("Failed to seed the resource sequence counter")
+}
+
+/// Record the last value reserved in postgres by the value drawn from the counter
+async fn record_reservation(
+    redis_conn: &RedisConnectionPool,
+    key: &str,
+    draw: CounterDraw,
+    reserved: i64,
+) -> CustomResult<(), errors::StorageError> {
+    redis_conn
+        .evaluate_redis_script::<i64>(
+            RECORD_RESERVATION_SCRIPT,
+            vec![key.to_owned()],
+            vec![draw.value.to_string(), reserved.to_string()],
+        )
+        .await
+        .map(|_| ())
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("Failed to record the resource sequence reservation")
+}
+
+/// The last value of the sequence in postgres, which is 0 for a new sequence. The value is read
+/// from the primary, as a counter seeded from a lagging replica would be behind the sequence.
+async fn find_last_value(
+    conn: &connection::PgPooledConn,
+    namespace: &str,
+    resource_id: &str,
+) -> CustomResult<i64, errors::StorageError> {
+    storage::ResourceSequence::find_optional_by_namespace_resource_id(conn, namespace, resource_id)
+        .await
+        .map(|sequence| sequence.map_or(0, |sequence| sequence.last_value))
+        .map_err(|error| report!(errors::StorageError::from(error)))
+}
+
+/// Issue the value drawn from the counter, reserving the next block of values first if the value
+/// is past the block reserved for the counter. A counter which cannot reserve the next block is
+/// behind the sequence, and is moved past the last value of the sequence, so that the next value
+/// drawn from it is higher.
+async fn try_issue_drawn_value(
+    store: &Store,
+    redis_conn: &RedisConnectionPool,
+    namespace: &str,
+    resource_id: &str,
+    draw: CounterDraw,
+) -> CustomResult<Option<i64>, errors::StorageError> {
+    if draw.is_reserved() {
+        return Ok(Some(draw.value));
+    }
+
+    let key = get_resource_sequence_key(namespace, resource_id);
+    let conn = connection::pg_connection_write(store).await?;
+    let reservation = storage::ResourceSequenceNew::new(
+        namespace.to_owned(),
+        resource_id.to_owned(),
+        draw.get_reservation_end(),
+    )
+    .reserve_from(&conn, draw.value)
+    .await
+    .map_err(|error| report!(errors::StorageError::from(error)))?;
+
+    if let Some(reservation) = reservation {
+        // The value is already reserved in postgres, failing to record the reservation in the
+        // counter only causes the next value to reserve a block again
+        record_reservation(redis_conn, &key, draw, reservation.last_value)
+            .await
+            .inspect_err(|error| {
+                logger::error!(?error, "Failed to record the resource sequence reservation")
+            })
+            .ok();
+        return Ok(Some(draw.value));
+    }
+
+    // The sequence may also have been raised past the value by a concurrent call which drew a
+    // later value of the same block, in which case the rest of the block is skipped
+    let last_value = find_last_value(&conn, namespace, resource_id).await?;
+    logger::info!(
+        namespace,
+        counter_value = draw.value,
+        last_value,
+        "Resource sequence counter is behind the sequence, moving the counter past it"
+    );
+    seed_counter(redis_conn, &key, last_value)
+        .await
+        .inspect_err(|error| logger::error!(?error, "Failed to move the resource sequence counter"))
+        .ok();
+
+    Ok(None)
+}
+
+#[async_trait::async_trait]
+impl ResourceSequenceInterface for Store {
+    #[instrument(skip_all)]
+    async fn next_sequence(
+        &self,
+        namespace: &str,
+        resource_id: &str,
+    ) -> CustomResult<i64, errors::StorageError> {
+        match self.get_redis_conn() {
+            Ok(redis_conn) => {
+                let key = get_resource_sequence_key(namespace, resource_id);
+                for _ in 0..MAX_COUNTER_DRAWS {
+                    let draw = match draw_from_counter(&redis_conn, &key).await {
+                        Ok(Some(draw)) => draw,
+                        Ok(None) => {
+                            let conn = connection::pg_connection_write(self).await?;
+                            let last_value = find_last_value(&conn, namespace, resource_id).await?;
+                            if let Err(error) = seed_counter(&redis_conn, &key, last_value).await {
+                                logger::warn!(
+                                    ?error,
+                                    "Failed to seed the resource sequence counter, issuing the \
+                                     value from postgres"
+                                );
+                                break;
+                            }
+                            continue;
+                        }
+                        Err(error) => {
+                            logger::warn!(
+                                ?error,
+                                "Failed to draw from the resource sequence counter, issuing the \
+                                 value from postgres"
+                            );
+                            break;
+                        }
+                    };
+
+                    if let Some(value) =
+                        try_issue_drawn_value(self, &redis_conn, namespace, resource_id, draw)
+                            .await?
+                    {
+                        return Ok(value);
+                    }
+                }
+            }
+            Err(error) => logger::warn!(
+                ?error,
+                "Failed to get redis connection, issuing the resource sequence value from postgres"
+            ),
+        }
+
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ResourceSequenceNew::new(namespace.to_owned(), resource_id.to_owned(), 1)
+            .increment(&conn)
+            .await
+            .map(|sequence| sequence.last_value)
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ResourceSequenceInterface for MockDb {
+    async fn next_sequence(
+        &self,
+        namespace: &str,
+        resource_id: &str,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let mut resource_sequences = self.resource_sequences.lock().await;
+        let now = common_utils::date_time::now();
+
+        match resource_sequences
+            .iter_mut()
+            .find(|sequence| sequence.namespace == namespace && sequence.resource_id == resource_id)
+        {
+            Some(sequence) => {
+                sequence.last_value = sequence.last_value.saturating_add(1);
+                sequence.modified_at = now;
+                Ok(sequence.last_value)
+            }
+            None => {
+                resource_sequences.push(storage::ResourceSequence {
+                    namespace: namespace.to_owned(),
+                    resource_id: resource_id.to_owned(),
+                    last_value: 1,
+                    created_at: now,
+                    modified_at: now,
+                });
+                Ok(1)
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ResourceSequenceInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn next_sequence(
+        &self,
+        namespace: &str,
+        resource_id: &str,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store
+            .next_sequence(namespace, resource_id)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_only_the_values_past_the_reserved_block_reserve_the_next_block() {
+        let draw = |value| CounterDraw {
+            value,
+            reserved: 100,
+        };
+
+        assert!(draw(1).is_reserved());
+        assert!(draw(100).is_reserved());
+        assert!(!draw(101).is_reserved());
+        assert_eq!(
+            draw(101).get_reservation_end(),
+            100 + RESOURCE_SEQUENCE_RESERVATION_SIZE
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sequence_of_new_namespace_starts_at_one() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+
+        assert_eq!(mockdb.next_sequence("events", "pay_1").await.unwrap(), 1);
+        assert_eq!(mockdb.next_sequence("events", "pay_1").await.unwrap(), 2);
+        assert_eq!(mockdb.next_sequence("refunds", "pay_1").await.unwrap(), 1);
+        assert_eq!(mockdb.next_sequence("events", "pay_2").await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_sequence_continues_past_its_last_value() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let now = common_utils::date_time::now();
+        mockdb
+            .resource_sequences
+            .lock()
+            .await
+            .push(storage::ResourceSequence {
+                namespace: "events".to_owned(),
+                resource_id: "pay_1".to_owned(),
+                last_value: 41,
+                created_at: now,
+                modified_at: now,
+            });
+
+        assert_eq!(mockdb.next_sequence("events", "pay_1").await.unwrap(), 42);
+    }
+}
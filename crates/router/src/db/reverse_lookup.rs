@@ -1,12 +1,36 @@
+use std::collections::HashSet;
+
+use common_utils::id_type;
+use error_stack::{report, ResultExt};
+use redis_interface::RedisConnectionPool;
+use storage_impl::redis::kv_store::PartitionKey;
+
 use super::{MockDb, Store};
+#[cfg(feature = "v1")]
+use crate::types::storage::reverse_lookup::{
+    get_candidate_attempt_id, get_payment_attempt_lookup_sk_id, PAYMENT_ATTEMPT_LOOKUP_SOURCE,
+};
 use crate::{
+    connection,
     errors::{self, CustomResult},
     types::storage::{
         enums,
-        reverse_lookup::{ReverseLookup, ReverseLookupNew},
+        reverse_lookup::{
+            get_candidate_refund_ids, get_namespaced_lookup_id, get_refund_lookup_sk_id,
+            get_reverse_lookup_namespace_prefix, OrphanedReverseLookups, ReverseLookup,
+            ReverseLookupNew, ReverseLookupReconciliationReport, ReverseLookupReconciliationScope,
+            ReverseLookupSelection, ORPHAN_CHECKED_LOOKUP_SOURCES, REFUND_LOOKUP_SOURCE,
+        },
     },
 };
 
+/// The maximum number of the reverse lookups checked by a single search for orphaned lookups
+const ORPHANED_LOOKUPS_SCAN_BUDGET: usize = 10_000;
+
+/// The number of the reverse lookups fetched from the database at a time while searching for
+/// orphaned lookups
+const ORPHANED_LOOKUPS_PAGE_SIZE: u16 = 1000;
+
 #[async_trait::async_trait]
 pub trait ReverseLookupInterface {
     async fn insert_reverse_lookup(
@@ -19,10 +43,296 @@ pub trait ReverseLookupInterface {
         _id: &str,
         _storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<ReverseLookup, errors::StorageError>;
+    /// Delete all the reverse lookups which point to the provided `pk_id`, returning the number
+    /// of lookups deleted. Deleting lookups which were already deleted is not an error, so this can
+    /// be safely retried after a partial failure.
+    async fn delete_reverse_lookups_by_pk_id(
+        &self,
+        _pk_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<usize, errors::StorageError>;
+    /// Delete all the reverse lookups in the namespace of the merchant, returning the number of
+    /// lookups deleted. The lookups of the merchant which were inserted before they were
+    /// namespaced are not deleted, they have to be deleted by their `pk_id`.
+    async fn delete_reverse_lookups_by_namespace(
+        &self,
+        _merchant_id: &id_type::MerchantId,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<usize, errors::StorageError>;
+    /// Insert the reverse lookup in the namespace of the merchant, so that its lookup id cannot
+    /// collide with the lookup ids of the other merchants. The lookup id does not have to carry
+    /// the merchant id, since the namespace does.
+    async fn insert_namespaced_reverse_lookup(
+        &self,
+        new: ReverseLookupNew,
+        merchant_id: &id_type::MerchantId,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<ReverseLookup, errors::StorageError> {
+        let new = ReverseLookupNew {
+            lookup_id: get_namespaced_lookup_id(merchant_id, &new.lookup_id),
+            ..new
+        };
+        self.insert_reverse_lookup(new, storage_scheme).await
+    }
+    /// Get the reverse lookup from the namespace of the merchant. A lookup which was inserted
+    /// before lookups were namespaced is found by its legacy lookup id, if any, so that the
+    /// existing lookups continue to be found while they are migrated.
+    async fn get_namespaced_lookup_by_lookup_id(
+        &self,
+        id: &str,
+        merchant_id: &id_type::MerchantId,
+        legacy_lookup_id: Option<&str>,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<ReverseLookup, errors::StorageError> {
+        let result = self
+            .get_lookup_by_lookup_id(&get_namespaced_lookup_id(merchant_id, id), storage_scheme)
+            .await;
+        match (result, legacy_lookup_id) {
+            (Err(error), Some(legacy_lookup_id)) if error.current_context().is_db_not_found() => {
+                self.get_lookup_by_lookup_id(legacy_lookup_id, storage_scheme)
+                    .await
+            }
+            (result, _) => result,
+        }
+    }
+    /// Compare the reverse lookups stored in redis with the ones stored in the database, reporting
+    /// the lookups which are missing in either of the stores or point to different values.
+    ///
+    /// The mismatches are repaired only when requested in the scope.
+    async fn reconcile_reverse_lookups(
+        &self,
+        _scope: ReverseLookupReconciliationScope,
+    ) -> CustomResult<ReverseLookupReconciliationReport, errors::StorageError>;
+    /// Find at most `limit` reverse lookups of payment attempts and refunds whose target no
+    /// longer exists, so that they can be reviewed before they are deleted. The lookups of the
+    /// other sources are not checked.
+    ///
+    /// The lookups are checked in the order of their lookup ids, starting after `starting_after`.
+    /// A single call checks a bounded number of lookups, so a page may contain fewer than `limit`
+    /// orphaned lookups even though more lookups are left to be checked, which is the case while
+    /// the returned `next_starting_after` is present.
+    ///
+    /// In KV, the target of a lookup exists if it is present in either redis or the database.
+    async fn find_orphaned_reverse_lookups(
+        &self,
+        _starting_after: Option<String>,
+        _limit: i64,
+    ) -> CustomResult<OrphanedReverseLookups, errors::StorageError>;
+    /// Delete the reverse lookups with the provided lookup ids, returning the number of lookups
+    /// deleted. The lookups are checked again, so that a lookup whose target exists by now is not
+    /// deleted.
+    async fn delete_orphaned_reverse_lookups(
+        &self,
+        _lookup_ids: Vec<String>,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<usize, errors::StorageError>;
+}
+
+/// Find the reverse lookups whose target is not present in the database. The targets are matched
+/// by rebuilding the `pk_id` and `sk_id` of the lookups pointing to them, so that a lookup is never
+/// reported because of an ambiguous parse of its ids.
+async fn find_lookups_without_database_target(
+    conn: &connection::PgPooledConn,
+    reverse_lookups: Vec<ReverseLookup>,
+) -> CustomResult<Vec<ReverseLookup>, errors::StorageError> {
+    let mut existing_targets = HashSet::new();
+
+    #[cfg(feature = "v1")]
+    {
+        let attempt_ids = reverse_lookups
+            .iter()
+            .filter(|reverse_lookup| reverse_lookup.source == PAYMENT_ATTEMPT_LOOKUP_SOURCE)
+            .filter_map(|reverse_lookup| get_candidate_attempt_id(&reverse_lookup.sk_id))
+            .map(ToOwned::to_owned)
+            .collect::<HashSet<_>>();
+        if !attempt_ids.is_empty() {
+            let payment_attempts = diesel_models::PaymentAttempt::find_by_attempt_ids(
+                conn,
+                attempt_ids.into_iter().collect(),
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))?;
+            existing_targets.extend(payment_attempts.into_iter().map(|payment_attempt| {
+                (
+                    PartitionKey::MerchantIdPaymentId {
+                        merchant_id: &payment_attempt.merchant_id,
+                        payment_id: &payment_attempt.payment_id,
+                    }
+                    .to_string(),
+                    get_payment_attempt_lookup_sk_id(&payment_attempt.attempt_id),
+                )
+            }));
+        }
+    }
+
+    let refund_ids = reverse_lookups
+        .iter()
+        .filter(|reverse_lookup| reverse_lookup.source == REFUND_LOOKUP_SOURCE)
+        .flat_map(|reverse_lookup| get_candidate_refund_ids(&reverse_lookup.sk_id))
+        .map(ToOwned::to_owned)
+        .collect::<HashSet<_>>();
+    if !refund_ids.is_empty() {
+        let refunds =
+            diesel_models::Refund::find_by_refund_ids(conn, refund_ids.into_iter().collect())
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))?;
+        existing_targets.extend(refunds.into_iter().map(|refund| {
+            (
+                PartitionKey::MerchantIdPaymentId {
+                    merchant_id: &refund.merchant_id,
+                    payment_id: &refund.payment_id,
+                }
+                .to_string(),
+                get_refund_lookup_sk_id(&refund.attempt_id, &refund.refund_id),
+            )
+        }));
+    }
+
+    Ok(filter_lookups_without_target(
+        reverse_lookups,
+        &existing_targets,
+    ))
+}
+
+/// Keep the reverse lookups of the checked sources whose target, identified by its `pk_id` and
+/// `sk_id`, is not among the existing targets
+fn filter_lookups_without_target(
+    reverse_lookups: Vec<ReverseLookup>,
+    existing_targets: &HashSet<(String, String)>,
+) -> Vec<ReverseLookup> {
+    reverse_lookups
+        .into_iter()
+        .filter(|reverse_lookup| {
+            ORPHAN_CHECKED_LOOKUP_SOURCES.contains(&reverse_lookup.source.as_str())
+                && !existing_targets
+                    .contains(&(reverse_lookup.pk_id.clone(), reverse_lookup.sk_id.clone()))
+        })
+        .collect()
+}
+
+/// Find the reverse lookups whose target is present in neither the database nor, when provided,
+/// redis
+async fn find_lookups_without_target(
+    conn: &connection::PgPooledConn,
+    redis_conn: Option<&RedisConnectionPool>,
+    reverse_lookups: Vec<ReverseLookup>,
+) -> CustomResult<Vec<ReverseLookup>, errors::StorageError> {
+    let reverse_lookups = find_lookups_without_database_target(conn, reverse_lookups).await?;
+    let Some(redis_conn) = redis_conn else {
+        return Ok(reverse_lookups);
+    };
+
+    let key_fields = reverse_lookups
+        .iter()
+        .map(|reverse_lookup| (reverse_lookup.pk_id.clone(), reverse_lookup.sk_id.clone()))
+        .collect::<Vec<_>>();
+    let targets_exist = redis_conn
+        .hash_fields_exist_pipelined(&key_fields)
+        .await
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("Failed to find the targets of the reverse lookups in redis")?;
+
+    Ok(reverse_lookups
+        .into_iter()
+        .zip(targets_exist)
+        .filter_map(|(reverse_lookup, target_exists)| (!target_exists).then_some(reverse_lookup))
+        .collect())
+}
+
+/// Page through the reverse lookups of the checked sources in the database in the order of their
+/// lookup ids, starting after `starting_after`, until `limit` orphaned lookups are found, the scan
+/// budget is spent or all the lookups are checked
+async fn find_orphaned_lookups(
+    store: &Store,
+    redis_conn: Option<&RedisConnectionPool>,
+    starting_after: Option<String>,
+    limit: i64,
+) -> CustomResult<OrphanedReverseLookups, errors::StorageError> {
+    let max_orphaned_lookups =
+        usize::try_from(limit).change_context(errors::StorageError::ValueNotFound(
+            "The limit of the orphaned reverse lookups must not be negative".to_string(),
+        ))?;
+    let conn = connection::pg_connection_read(store).await?;
+    let sources = ORPHAN_CHECKED_LOOKUP_SOURCES
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+
+    let mut orphaned_lookups = Vec::new();
+    let mut next_starting_after = starting_after;
+    let mut checked_lookups = 0;
+    while orphaned_lookups.len() < max_orphaned_lookups
+        && checked_lookups < ORPHANED_LOOKUPS_SCAN_BUDGET
+    {
+        let reverse_lookups = ReverseLookup::find_by_sources_after_lookup_id(
+            sources.clone(),
+            next_starting_after.as_deref(),
+            i64::from(ORPHANED_LOOKUPS_PAGE_SIZE),
+            &conn,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))?;
+        let is_last_page = reverse_lookups.len() < usize::from(ORPHANED_LOOKUPS_PAGE_SIZE);
+        checked_lookups += reverse_lookups.len();
+        next_starting_after = reverse_lookups
+            .last()
+            .map(|reverse_lookup| reverse_lookup.lookup_id.clone());
+
+        orphaned_lookups
+            .extend(find_lookups_without_target(&conn, redis_conn, reverse_lookups).await?);
+
+        if is_last_page {
+            next_starting_after = None;
+            break;
+        }
+    }
+
+    Ok(truncate_orphaned_lookups(
+        orphaned_lookups,
+        next_starting_after,
+        max_orphaned_lookups,
+    ))
+}
+
+/// Keep the first `max_orphaned_lookups` of the orphaned lookups. The lookups after the last one
+/// kept are checked again by the next page.
+fn truncate_orphaned_lookups(
+    mut orphaned_lookups: Vec<ReverseLookup>,
+    next_starting_after: Option<String>,
+    max_orphaned_lookups: usize,
+) -> OrphanedReverseLookups {
+    if orphaned_lookups.len() <= max_orphaned_lookups {
+        return OrphanedReverseLookups {
+            reverse_lookups: orphaned_lookups,
+            next_starting_after,
+        };
+    }
+
+    orphaned_lookups.truncate(max_orphaned_lookups);
+    OrphanedReverseLookups {
+        next_starting_after: orphaned_lookups
+            .last()
+            .map(|reverse_lookup| reverse_lookup.lookup_id.clone()),
+        reverse_lookups: orphaned_lookups,
+    }
+}
+
+/// Find the reverse lookups with the provided lookup ids which are still orphaned
+async fn find_orphaned_lookups_by_lookup_ids(
+    store: &Store,
+    redis_conn: Option<&RedisConnectionPool>,
+    lookup_ids: Vec<String>,
+) -> CustomResult<Vec<ReverseLookup>, errors::StorageError> {
+    let conn = connection::pg_connection_read(store).await?;
+    let reverse_lookups = ReverseLookup::find_by_lookup_ids(lookup_ids, &conn)
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))?;
+    find_lookups_without_target(&conn, redis_conn, reverse_lookups).await
 }
 
 #[cfg(not(feature = "kv_store"))]
 mod storage {
+    use common_utils::id_type;
     use error_stack::report;
     use router_env::{instrument, tracing};
 
@@ -32,7 +342,11 @@ mod storage {
         errors::{self, CustomResult},
         types::storage::{
             enums,
-            reverse_lookup::{ReverseLookup, ReverseLookupNew},
+            reverse_lookup::{
+                OrphanedReverseLookups, ReverseLookup, ReverseLookupNew,
+                ReverseLookupReconciliationReport, ReverseLookupReconciliationScope,
+                ReverseLookupSelection,
+            },
         },
     };
 
@@ -61,16 +375,125 @@ mod storage {
                 .await
                 .map_err(|error| report!(errors::StorageError::from(error)))
         }
+
+        #[instrument(skip_all)]
+        async fn delete_reverse_lookups_by_pk_id(
+            &self,
+            pk_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<usize, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            ReverseLookup::delete_by_pk_id(pk_id, &conn)
+                .await
+                .map(|deleted_lookups| deleted_lookups.len())
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn delete_reverse_lookups_by_namespace(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<usize, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            ReverseLookup::delete_by_namespace(merchant_id, &conn)
+                .await
+                .map(|deleted_lookups| deleted_lookups.len())
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        /// Without KV, reverse lookups are stored only in the database, so there is nothing to
+        /// reconcile. The report only records the lookups which were found.
+        #[instrument(skip_all)]
+        async fn reconcile_reverse_lookups(
+            &self,
+            scope: ReverseLookupReconciliationScope,
+        ) -> CustomResult<ReverseLookupReconciliationReport, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            let mut report = ReverseLookupReconciliationReport::default();
+            match scope.lookups {
+                ReverseLookupSelection::LookupIds(lookup_ids) => {
+                    for lookup_id in lookup_ids {
+                        report.checked += 1;
+                        match ReverseLookup::find_by_lookup_id(&lookup_id, &conn)
+                            .await
+                            .map_err(|error| report!(errors::StorageError::from(error)))
+                        {
+                            Ok(_) => {}
+                            Err(error) if error.current_context().is_db_not_found() => {
+                                report.not_found.push(lookup_id)
+                            }
+                            Err(error) => Err(error)?,
+                        }
+                    }
+                }
+                ReverseLookupSelection::PkId(pk_id) => {
+                    report.checked = ReverseLookup::find_by_pk_id(&pk_id, &conn)
+                        .await
+                        .map_err(|error| report!(errors::StorageError::from(error)))?
+                        .len();
+                }
+                ReverseLookupSelection::Source {
+                    source,
+                    limit,
+                    offset,
+                } => {
+                    report.checked =
+                        ReverseLookup::find_by_source(&source, Some(limit), Some(offset), &conn)
+                            .await
+                            .map_err(|error| report!(errors::StorageError::from(error)))?
+                            .len();
+                }
+                ReverseLookupSelection::RedisScan { .. } => {}
+            }
+            Ok(report)
+        }
+
+        #[instrument(skip_all)]
+        async fn find_orphaned_reverse_lookups(
+            &self,
+            starting_after: Option<String>,
+            limit: i64,
+        ) -> CustomResult<OrphanedReverseLookups, errors::StorageError> {
+            super::find_orphaned_lookups(self, None, starting_after, limit).await
+        }
+
+        #[instrument(skip_all)]
+        async fn delete_orphaned_reverse_lookups(
+            &self,
+            lookup_ids: Vec<String>,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<usize, errors::StorageError> {
+            let orphaned_lookup_ids =
+                super::find_orphaned_lookups_by_lookup_ids(self, None, lookup_ids)
+                    .await?
+                    .into_iter()
+                    .map(|reverse_lookup| reverse_lookup.lookup_id)
+                    .collect::<Vec<_>>();
+            if orphaned_lookup_ids.is_empty() {
+                return Ok(0);
+            }
+
+            let conn = connection::pg_connection_write(self).await?;
+            ReverseLookup::delete_by_lookup_ids(orphaned_lookup_ids, &conn)
+                .await
+                .map(|deleted_lookups| deleted_lookups.len())
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
     }
 }
 
 #[cfg(feature = "kv_store")]
 mod storage {
+    use std::collections::HashSet;
+
+    use common_utils::id_type;
     use error_stack::{report, ResultExt};
-    use redis_interface::SetnxReply;
-    use router_env::{instrument, tracing};
+    use redis_interface::{errors::RedisError, RedisConnectionPool, SetnxReply};
+    use router_env::{instrument, logger, tracing};
     use storage_impl::redis::kv_store::{
-        decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey,
+        decide_storage_scheme, kv_get_versioned, kv_wrapper, KvOperation, Op, PartitionKey,
+        RedisConnInterface,
     };
 
     use super::{ReverseLookupInterface, Store};
@@ -80,11 +503,223 @@ mod storage {
         errors::{self, CustomResult},
         types::storage::{
             enums, kv,
-            reverse_lookup::{ReverseLookup, ReverseLookupNew},
+            reverse_lookup::{
+                get_reverse_lookup_namespace_prefix, is_same_reverse_lookup,
+                OrphanedReverseLookups, ReverseLookup, ReverseLookupMismatch,
+                ReverseLookupMismatchCategory, ReverseLookupNew, ReverseLookupReconciliationReport,
+                ReverseLookupReconciliationScope, ReverseLookupSelection,
+            },
         },
         utils::db_utils,
     };
 
+    const REVERSE_LOOKUP_KEY_PREFIX: &str = "reverse_lookup_";
+
+    /// The number of keys requested from redis with each scan of the lookups of a namespace
+    const NAMESPACE_SCAN_PAGE_SIZE: u32 = 1000;
+
+    async fn find_reverse_lookup_in_redis(
+        redis_conn: &RedisConnectionPool,
+        key: &str,
+    ) -> CustomResult<Option<ReverseLookup>, errors::StorageError> {
+        match redis_conn
+            .get_and_deserialize_versioned_key::<ReverseLookup>(key, "ReverseLookup")
+            .await
+        {
+            Ok(reverse_lookup) => Ok(Some(reverse_lookup)),
+            Err(error) if matches!(error.current_context(), RedisError::NotFound) => Ok(None),
+            Err(error) => Err(error.change_context(errors::StorageError::KVError)),
+        }
+    }
+
+    async fn find_reverse_lookup_in_database(
+        store: &Store,
+        lookup_id: &str,
+    ) -> CustomResult<Option<ReverseLookup>, errors::StorageError> {
+        let conn = connection::pg_connection_read(store).await?;
+        match ReverseLookup::find_by_lookup_id(lookup_id, &conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        {
+            Ok(reverse_lookup) => Ok(Some(reverse_lookup)),
+            Err(error) if error.current_context().is_db_not_found() => Ok(None),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Find the lookup ids of the reverse lookups selected for reconciliation, along with the
+    /// cursor of the next page when the lookups were selected by scanning redis
+    async fn get_selected_lookup_ids(
+        store: &Store,
+        redis_conn: &RedisConnectionPool,
+        selection: ReverseLookupSelection,
+    ) -> CustomResult<(Vec<String>, Option<String>), errors::StorageError> {
+        let get_lookup_ids = |reverse_lookups: Vec<ReverseLookup>| {
+            (
+                reverse_lookups
+                    .into_iter()
+                    .map(|reverse_lookup| reverse_lookup.lookup_id)
+                    .collect(),
+                None,
+            )
+        };
+        match selection {
+            ReverseLookupSelection::LookupIds(lookup_ids) => Ok((lookup_ids, None)),
+            ReverseLookupSelection::PkId(pk_id) => {
+                let conn = connection::pg_connection_read(store).await?;
+                ReverseLookup::find_by_pk_id(&pk_id, &conn)
+                    .await
+                    .map(get_lookup_ids)
+                    .map_err(|error| report!(errors::StorageError::from(error)))
+            }
+            ReverseLookupSelection::Source {
+                source,
+                limit,
+                offset,
+            } => {
+                let conn = connection::pg_connection_read(store).await?;
+                ReverseLookup::find_by_source(&source, Some(limit), Some(offset), &conn)
+                    .await
+                    .map(get_lookup_ids)
+                    .map_err(|error| report!(errors::StorageError::from(error)))
+            }
+            ReverseLookupSelection::RedisScan { cursor, count } => {
+                // The scanned keys carry the key prefix of the tenant
+                let key_prefix = redis_conn.add_prefix(REVERSE_LOOKUP_KEY_PREFIX);
+                let (keys, next_cursor) = redis_conn
+                    .scan_page(&format!("{REVERSE_LOOKUP_KEY_PREFIX}*"), &cursor, count)
+                    .await
+                    .change_context(errors::StorageError::KVError)
+                    .attach_printable("Failed to scan the reverse lookups in redis")?;
+                Ok((
+                    keys.iter()
+                        .filter_map(|key| key.strip_prefix(&key_prefix))
+                        .map(ToOwned::to_owned)
+                        .collect(),
+                    (next_cursor != "0").then_some(next_cursor),
+                ))
+            }
+        }
+    }
+
+    /// Whether the drainer stream which the insertion of the lookup is appended to still has
+    /// entries waiting to be drained, in which case the lookup may not have been drained yet
+    async fn is_pending_drain(
+        store: &Store,
+        redis_conn: &RedisConnectionPool,
+        lookup_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let stream_name = store.get_drainer_stream_name_for_partition::<ReverseLookup>(
+            PartitionKey::CombinationKey {
+                combination: &format!("{REVERSE_LOOKUP_KEY_PREFIX}{lookup_id}"),
+            },
+        );
+        redis_conn
+            .stream_get_length(&stream_name)
+            .await
+            .map(|length| length > 0)
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to get the length of the drainer stream")
+    }
+
+    /// Repair the mismatch, treating the database as the source of truth. A lookup which is
+    /// missing in the database is inserted into the database, since its drainer entry was lost.
+    async fn repair_reverse_lookup(
+        store: &Store,
+        redis_conn: &RedisConnectionPool,
+        key: &str,
+        redis_lookup: ReverseLookup,
+        database_lookup: Option<ReverseLookup>,
+    ) -> CustomResult<(), errors::StorageError> {
+        match database_lookup {
+            None => {
+                let conn = connection::pg_connection_write(store).await?;
+                let new = ReverseLookupNew {
+                    lookup_id: redis_lookup.lookup_id,
+                    pk_id: redis_lookup.pk_id,
+                    sk_id: redis_lookup.sk_id,
+                    source: redis_lookup.source,
+                    updated_by: redis_lookup.updated_by,
+                };
+                match new
+                    .insert(&conn)
+                    .await
+                    .map_err(|error| report!(errors::StorageError::from(error)))
+                {
+                    Ok(_) => Ok(()),
+                    // The lookup was drained since it was checked
+                    Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+                    Err(error) => Err(error),
+                }
+            }
+            Some(database_lookup) => redis_conn
+                .serialize_and_replace_existing_key(key, &database_lookup)
+                .await
+                .change_context(errors::StorageError::KVError),
+        }
+    }
+
+    /// Find the lookup ids of the reverse lookups in the namespace of the merchant which are
+    /// present in redis, including the lookups which have not yet been drained to the database
+    async fn find_namespaced_lookup_ids_in_redis(
+        redis_conn: &RedisConnectionPool,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<HashSet<String>, errors::StorageError> {
+        // The scanned keys carry the key prefix of the tenant
+        let key_prefix = redis_conn.add_prefix(REVERSE_LOOKUP_KEY_PREFIX);
+        let pattern = format!(
+            "{REVERSE_LOOKUP_KEY_PREFIX}{}*",
+            get_reverse_lookup_namespace_prefix(merchant_id)
+        );
+        let mut lookup_ids = HashSet::new();
+        let mut cursor = "0".to_string();
+        loop {
+            let (keys, next_cursor) = redis_conn
+                .scan_page(&pattern, &cursor, NAMESPACE_SCAN_PAGE_SIZE)
+                .await
+                .change_context(errors::StorageError::KVError)
+                .attach_printable("Failed to scan the reverse lookups of the namespace in redis")?;
+            lookup_ids.extend(
+                keys.iter()
+                    .filter_map(|key| key.strip_prefix(&key_prefix))
+                    .map(ToOwned::to_owned),
+            );
+            if next_cursor == "0" {
+                return Ok(lookup_ids);
+            }
+            cursor = next_cursor;
+        }
+    }
+
+    async fn delete_reverse_lookups_from_redis(
+        store: &Store,
+        reverse_lookups: &[ReverseLookup],
+    ) -> CustomResult<(), errors::StorageError> {
+        let lookup_ids = reverse_lookups
+            .iter()
+            .map(|reverse_lookup| reverse_lookup.lookup_id.as_str())
+            .collect::<Vec<_>>();
+        delete_lookup_ids_from_redis(store, &lookup_ids).await
+    }
+
+    async fn delete_lookup_ids_from_redis(
+        store: &Store,
+        lookup_ids: &[&str],
+    ) -> CustomResult<(), errors::StorageError> {
+        let keys = lookup_ids
+            .iter()
+            .map(|lookup_id| format!("{REVERSE_LOOKUP_KEY_PREFIX}{lookup_id}"))
+            .collect::<Vec<_>>();
+
+        store
+            .get_redis_conn()
+            .map_err(Into::<errors::StorageError>::into)?
+            .delete_multiple_keys_pipelined(&keys)
+            .await
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to delete the reverse lookups from redis")
+    }
+
     #[async_trait::async_trait]
     impl ReverseLookupInterface for Store {
         #[instrument(skip_all)]
@@ -167,26 +802,307 @@ mod storage {
             match storage_scheme {
                 enums::MerchantStorageScheme::PostgresOnly => database_call().await,
                 enums::MerchantStorageScheme::RedisKv => {
-                    let redis_fut = async {
-                        kv_wrapper(
-                            self,
-                            KvOperation::<ReverseLookup>::Get,
-                            PartitionKey::CombinationKey {
-                                combination: &format!("reverse_lookup_{id}"),
-                            },
-                        )
-                        .await?
-                        .try_into_get()
+                    let key = format!("{REVERSE_LOOKUP_KEY_PREFIX}{id}");
+                    let redis_fut = kv_get_versioned::<ReverseLookup, _>(
+                        self,
+                        PartitionKey::CombinationKey { combination: &key },
+                    );
+                    let database_call_with_read_repair = || async {
+                        let reverse_lookup = database_call().await?;
+                        self.read_repair_reverse_lookup(&key, &reverse_lookup).await;
+                        Ok(reverse_lookup)
                     };
 
                     Box::pin(db_utils::try_redis_get_else_try_database_get(
                         redis_fut,
-                        database_call,
+                        database_call_with_read_repair,
                     ))
                     .await
                 }
             }
         }
+
+        /// In KV, the lookups to be deleted are found using the `pk_id` index in the database, so
+        /// lookups which have not yet been drained to the database are not deleted.
+        #[instrument(skip_all)]
+        async fn delete_reverse_lookups_by_pk_id(
+            &self,
+            pk_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<usize, errors::StorageError> {
+            let storage_scheme = Box::pin(decide_storage_scheme::<_, ReverseLookup>(
+                self,
+                storage_scheme,
+                Op::Delete,
+            ))
+            .await;
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    ReverseLookup::delete_by_pk_id(pk_id, &conn)
+                        .await
+                        .map(|deleted_lookups| deleted_lookups.len())
+                        .map_err(|error| report!(errors::StorageError::from(error)))
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let conn = connection::pg_connection_read(self).await?;
+                    let reverse_lookups = ReverseLookup::find_by_pk_id(pk_id, &conn)
+                        .await
+                        .map_err(|error| report!(errors::StorageError::from(error)))?;
+
+                    if reverse_lookups.is_empty() {
+                        return Ok(0);
+                    }
+
+                    delete_reverse_lookups_from_redis(self, &reverse_lookups).await?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Delete {
+                            deletable: kv::Deletable::ReverseLookUpsByPkId(
+                                kv::ReverseLookupDeleteMems {
+                                    pk_id: pk_id.to_owned(),
+                                },
+                            ),
+                        },
+                    };
+
+                    self.push_to_drainer_stream::<ReverseLookup>(
+                        redis_entry,
+                        PartitionKey::CombinationKey { combination: pk_id },
+                    )
+                    .await
+                    .change_context(errors::StorageError::KVError)
+                    .attach_printable("Failed to push the reverse lookup delete to drainer")?;
+
+                    Ok(reverse_lookups.len())
+                }
+            }
+        }
+
+        /// In KV, the lookups to be deleted are found by their namespace in both redis and the
+        /// database, so that the lookups which have not yet been drained to the database are
+        /// deleted as well.
+        #[instrument(skip_all)]
+        async fn delete_reverse_lookups_by_namespace(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<usize, errors::StorageError> {
+            let storage_scheme = Box::pin(decide_storage_scheme::<_, ReverseLookup>(
+                self,
+                storage_scheme,
+                Op::Delete,
+            ))
+            .await;
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    ReverseLookup::delete_by_namespace(merchant_id, &conn)
+                        .await
+                        .map(|deleted_lookups| deleted_lookups.len())
+                        .map_err(|error| report!(errors::StorageError::from(error)))
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let redis_conn = self
+                        .get_redis_conn()
+                        .map_err(Into::<errors::StorageError>::into)?;
+                    let mut lookup_ids =
+                        find_namespaced_lookup_ids_in_redis(&redis_conn, merchant_id).await?;
+
+                    let conn = connection::pg_connection_read(self).await?;
+                    lookup_ids.extend(
+                        ReverseLookup::find_by_namespace(merchant_id, &conn)
+                            .await
+                            .map_err(|error| report!(errors::StorageError::from(error)))?
+                            .into_iter()
+                            .map(|reverse_lookup| reverse_lookup.lookup_id),
+                    );
+
+                    if lookup_ids.is_empty() {
+                        return Ok(0);
+                    }
+
+                    let lookup_ids = lookup_ids.iter().map(String::as_str).collect::<Vec<_>>();
+                    delete_lookup_ids_from_redis(self, &lookup_ids).await?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Delete {
+                            deletable: kv::Deletable::ReverseLookUpsByNamespace(
+                                kv::ReverseLookupNamespaceDeleteMems {
+                                    merchant_id: merchant_id.to_owned(),
+                                },
+                            ),
+                        },
+                    };
+
+                    self.push_to_drainer_stream::<ReverseLookup>(
+                        redis_entry,
+                        PartitionKey::CombinationKey {
+                            combination: merchant_id.get_string_repr(),
+                        },
+                    )
+                    .await
+                    .change_context(errors::StorageError::KVError)
+                    .attach_printable("Failed to push the reverse lookup delete to drainer")?;
+
+                    Ok(lookup_ids.len())
+                }
+            }
+        }
+
+        #[instrument(skip_all)]
+        async fn reconcile_reverse_lookups(
+            &self,
+            scope: ReverseLookupReconciliationScope,
+        ) -> CustomResult<ReverseLookupReconciliationReport, errors::StorageError> {
+            let redis_conn = self
+                .get_redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?;
+            let (lookup_ids, next_cursor) =
+                get_selected_lookup_ids(self, &redis_conn, scope.lookups).await?;
+
+            let mut report = ReverseLookupReconciliationReport {
+                next_cursor,
+                ..Default::default()
+            };
+            for lookup_id in lookup_ids {
+                let key = format!("{REVERSE_LOOKUP_KEY_PREFIX}{lookup_id}");
+                let redis_lookup = find_reverse_lookup_in_redis(&redis_conn, &key).await?;
+                let database_lookup = find_reverse_lookup_in_database(self, &lookup_id).await?;
+                report.checked += 1;
+
+                let (redis_lookup, category) = match (redis_lookup, &database_lookup) {
+                    (None, None) => {
+                        report.not_found.push(lookup_id);
+                        continue;
+                    }
+                    (None, Some(_)) => {
+                        report.expired_in_redis += 1;
+                        continue;
+                    }
+                    (Some(redis_lookup), Some(database_lookup))
+                        if is_same_reverse_lookup(&redis_lookup, database_lookup) =>
+                    {
+                        continue;
+                    }
+                    (Some(redis_lookup), None) => {
+                        if is_pending_drain(self, &redis_conn, &lookup_id).await? {
+                            report.pending_drain.push(lookup_id);
+                            continue;
+                        }
+                        (
+                            redis_lookup,
+                            ReverseLookupMismatchCategory::MissingInDatabase,
+                        )
+                    }
+                    (Some(redis_lookup), Some(_)) => {
+                        (redis_lookup, ReverseLookupMismatchCategory::ValueMismatch)
+                    }
+                };
+                logger::warn!(%lookup_id, %category, "Reverse lookup mismatch found");
+
+                let repaired = scope.repair
+                    && repair_reverse_lookup(
+                        self,
+                        &redis_conn,
+                        &key,
+                        redis_lookup,
+                        database_lookup,
+                    )
+                    .await
+                    .map_err(|error| {
+                        logger::error!(?error, %lookup_id, "Failed to repair reverse lookup")
+                    })
+                    .is_ok();
+
+                report.mismatches.push(ReverseLookupMismatch {
+                    lookup_id,
+                    category,
+                    repaired,
+                });
+            }
+            Ok(report)
+        }
+
+        #[instrument(skip_all)]
+        async fn find_orphaned_reverse_lookups(
+            &self,
+            starting_after: Option<String>,
+            limit: i64,
+        ) -> CustomResult<OrphanedReverseLookups, errors::StorageError> {
+            let redis_conn = self
+                .get_redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?;
+            super::find_orphaned_lookups(self, Some(redis_conn.as_ref()), starting_after, limit)
+                .await
+        }
+
+        /// In KV, the orphaned lookups are deleted from redis, and their deletion from the
+        /// database is pushed to the drainer, after any pending insertion of the lookups.
+        #[instrument(skip_all)]
+        async fn delete_orphaned_reverse_lookups(
+            &self,
+            lookup_ids: Vec<String>,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<usize, errors::StorageError> {
+            let redis_conn = self
+                .get_redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?;
+            let orphaned_lookups = super::find_orphaned_lookups_by_lookup_ids(
+                self,
+                Some(redis_conn.as_ref()),
+                lookup_ids,
+            )
+            .await?;
+            if orphaned_lookups.is_empty() {
+                return Ok(0);
+            }
+            let orphaned_lookup_ids = orphaned_lookups
+                .iter()
+                .map(|reverse_lookup| reverse_lookup.lookup_id.clone())
+                .collect::<Vec<_>>();
+
+            let storage_scheme = Box::pin(decide_storage_scheme::<_, ReverseLookup>(
+                self,
+                storage_scheme,
+                Op::Delete,
+            ))
+            .await;
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    ReverseLookup::delete_by_lookup_ids(orphaned_lookup_ids, &conn)
+                        .await
+                        .map(|deleted_lookups| deleted_lookups.len())
+                        .map_err(|error| report!(errors::StorageError::from(error)))
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    delete_reverse_lookups_from_redis(self, &orphaned_lookups).await?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Delete {
+                            deletable: kv::Deletable::ReverseLookUpsByLookupIds(
+                                kv::ReverseLookupIdsDeleteMems {
+                                    lookup_ids: orphaned_lookup_ids,
+                                },
+                            ),
+                        },
+                    };
+
+                    self.push_to_drainer_stream::<ReverseLookup>(
+                        redis_entry,
+                        PartitionKey::CombinationKey {
+                            combination: "reverse_lookup_orphans",
+                        },
+                    )
+                    .await
+                    .change_context(errors::StorageError::KVError)
+                    .attach_printable("Failed to push the reverse lookup delete to drainer")?;
+
+                    Ok(orphaned_lookups.len())
+                }
+            }
+        }
     }
 }
 
@@ -224,4 +1140,264 @@ impl ReverseLookupInterface for MockDb {
             )
             .cloned()
     }
+
+    async fn delete_reverse_lookups_by_pk_id(
+        &self,
+        pk_id: &str,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let mut reverse_lookups = self.reverse_lookups.lock().await;
+        let initial_count = reverse_lookups.len();
+        reverse_lookups.retain(|reverse_lookup| reverse_lookup.pk_id != pk_id);
+        Ok(initial_count - reverse_lookups.len())
+    }
+
+    async fn delete_reverse_lookups_by_namespace(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let namespace_prefix = get_reverse_lookup_namespace_prefix(merchant_id);
+        let mut reverse_lookups = self.reverse_lookups.lock().await;
+        let initial_count = reverse_lookups.len();
+        reverse_lookups
+            .retain(|reverse_lookup| !reverse_lookup.lookup_id.starts_with(&namespace_prefix));
+        Ok(initial_count - reverse_lookups.len())
+    }
+
+    async fn reconcile_reverse_lookups(
+        &self,
+        _scope: ReverseLookupReconciliationScope,
+    ) -> CustomResult<ReverseLookupReconciliationReport, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_orphaned_reverse_lookups(
+        &self,
+        starting_after: Option<String>,
+        limit: i64,
+    ) -> CustomResult<OrphanedReverseLookups, errors::StorageError> {
+        let max_orphaned_lookups =
+            usize::try_from(limit).change_context(errors::StorageError::ValueNotFound(
+                "The limit of the orphaned reverse lookups must not be negative".to_string(),
+            ))?;
+        let existing_targets = find_mock_db_lookup_targets(self).await;
+
+        let mut reverse_lookups = self
+            .reverse_lookups
+            .lock()
+            .await
+            .iter()
+            .filter(|reverse_lookup| {
+                starting_after.as_ref().map_or(true, |starting_after| {
+                    reverse_lookup.lookup_id > *starting_after
+                })
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        reverse_lookups.sort_by(|a, b| a.lookup_id.cmp(&b.lookup_id));
+
+        // Every lookup is checked at once, so the next page only starts after a truncated page
+        Ok(truncate_orphaned_lookups(
+            filter_lookups_without_target(reverse_lookups, &existing_targets),
+            None,
+            max_orphaned_lookups,
+        ))
+    }
+
+    async fn delete_orphaned_reverse_lookups(
+        &self,
+        lookup_ids: Vec<String>,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let existing_targets = find_mock_db_lookup_targets(self).await;
+        let mut reverse_lookups = self.reverse_lookups.lock().await;
+        let orphaned_lookup_ids = filter_lookups_without_target(
+            reverse_lookups
+                .iter()
+                .filter(|reverse_lookup| lookup_ids.contains(&reverse_lookup.lookup_id))
+                .cloned()
+                .collect(),
+            &existing_targets,
+        )
+        .into_iter()
+        .map(|reverse_lookup| reverse_lookup.lookup_id)
+        .collect::<HashSet<_>>();
+
+        reverse_lookups
+            .retain(|reverse_lookup| !orphaned_lookup_ids.contains(&reverse_lookup.lookup_id));
+        Ok(orphaned_lookup_ids.len())
+    }
+}
+
+/// The `pk_id` and `sk_id` of the payment attempts and refunds in the mock db, as they are pointed
+/// to by their reverse lookups
+async fn find_mock_db_lookup_targets(mock_db: &MockDb) -> HashSet<(String, String)> {
+    let mut existing_targets = HashSet::new();
+
+    #[cfg(feature = "v1")]
+    existing_targets.extend(
+        mock_db
+            .payment_attempts
+            .lock()
+            .await
+            .iter()
+            .map(|payment_attempt| {
+                (
+                    PartitionKey::MerchantIdPaymentId {
+                        merchant_id: &payment_attempt.merchant_id,
+                        payment_id: &payment_attempt.payment_id,
+                    }
+                    .to_string(),
+                    get_payment_attempt_lookup_sk_id(&payment_attempt.attempt_id),
+                )
+            }),
+    );
+
+    existing_targets.extend(mock_db.refunds.lock().await.iter().map(|refund| {
+        (
+            PartitionKey::MerchantIdPaymentId {
+                merchant_id: &refund.merchant_id,
+                payment_id: &refund.payment_id,
+            }
+            .to_string(),
+            get_refund_lookup_sk_id(&refund.attempt_id, &refund.refund_id),
+        )
+    }));
+
+    existing_targets
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use storage_impl::redis::kv_store::RedisConnInterface;
+
+    use super::*;
+
+    fn reverse_lookup(lookup_id: &str, source: &str) -> ReverseLookup {
+        ReverseLookup {
+            lookup_id: lookup_id.to_string(),
+            sk_id: format!("pa_attempt_ref_{lookup_id}"),
+            pk_id: "mer_payment".to_string(),
+            source: source.to_string(),
+            updated_by: "postgres_only".to_string(),
+        }
+    }
+
+    async fn create_mock_db(reverse_lookups: Vec<ReverseLookup>) -> MockDb {
+        let mock_db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .unwrap();
+        mock_db.reverse_lookups.lock().await.extend(reverse_lookups);
+        mock_db
+    }
+
+    #[test]
+    fn test_truncate_orphaned_lookups_continues_after_the_last_lookup_kept() {
+        let orphaned_lookups = ["lookup_1", "lookup_2", "lookup_3"]
+            .into_iter()
+            .map(|lookup_id| reverse_lookup(lookup_id, REFUND_LOOKUP_SOURCE))
+            .collect();
+
+        let page = truncate_orphaned_lookups(orphaned_lookups, Some("lookup_9".to_string()), 2);
+
+        assert_eq!(page.reverse_lookups.len(), 2);
+        assert_eq!(page.next_starting_after.as_deref(), Some("lookup_2"));
+    }
+
+    #[test]
+    fn test_truncate_orphaned_lookups_keeps_the_cursor_of_a_full_page() {
+        let orphaned_lookups = vec![reverse_lookup("lookup_1", REFUND_LOOKUP_SOURCE)];
+
+        let page = truncate_orphaned_lookups(orphaned_lookups, Some("lookup_9".to_string()), 2);
+
+        assert_eq!(page.reverse_lookups.len(), 1);
+        assert_eq!(page.next_starting_after.as_deref(), Some("lookup_9"));
+    }
+
+    #[tokio::test]
+    async fn test_reverse_lookup_found_in_the_database_is_repopulated_in_redis() {
+        let lookup_id = common_utils::generate_id_with_default_len("lookup");
+        let key = format!("reverse_lookup_{lookup_id}");
+        let mock_db = create_mock_db(vec![reverse_lookup(&lookup_id, REFUND_LOOKUP_SOURCE)]).await;
+        let redis_conn = mock_db.get_redis_conn().unwrap();
+        let get_from_redis =
+            || redis_conn.get_and_deserialize_versioned_key::<ReverseLookup>(&key, "ReverseLookup");
+        assert!(get_from_redis().await.is_err());
+
+        let reverse_lookup = mock_db
+            .get_lookup_by_lookup_id(&lookup_id, enums::MerchantStorageScheme::RedisKv)
+            .await
+            .unwrap();
+        storage_impl::repopulate_reverse_lookup(&mock_db, &key, &reverse_lookup, 60).await;
+        assert_eq!(get_from_redis().await.unwrap(), reverse_lookup);
+
+        // A lookup written to redis in the meantime is not overwritten
+        let mut stale_reverse_lookup = reverse_lookup.clone();
+        stale_reverse_lookup.sk_id = "pa_attempt_ref_stale".to_string();
+        storage_impl::repopulate_reverse_lookup(&mock_db, &key, &stale_reverse_lookup, 60).await;
+        assert_eq!(get_from_redis().await.unwrap(), reverse_lookup);
+
+        redis_conn.delete_key(&key).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_mock_db_pages_through_orphaned_lookups() {
+        let mock_db = create_mock_db(vec![
+            reverse_lookup("lookup_3", REFUND_LOOKUP_SOURCE),
+            reverse_lookup("lookup_1", REFUND_LOOKUP_SOURCE),
+            reverse_lookup("lookup_2", "payment_intent"),
+            reverse_lookup("lookup_4", REFUND_LOOKUP_SOURCE),
+        ])
+        .await;
+
+        let first_page = mock_db
+            .find_orphaned_reverse_lookups(None, 2)
+            .await
+            .unwrap();
+        let first_lookup_ids = first_page
+            .reverse_lookups
+            .iter()
+            .map(|reverse_lookup| reverse_lookup.lookup_id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(first_lookup_ids, vec!["lookup_1", "lookup_3"]);
+        assert_eq!(first_page.next_starting_after.as_deref(), Some("lookup_3"));
+
+        let second_page = mock_db
+            .find_orphaned_reverse_lookups(first_page.next_starting_after, 2)
+            .await
+            .unwrap();
+        let second_lookup_ids = second_page
+            .reverse_lookups
+            .iter()
+            .map(|reverse_lookup| reverse_lookup.lookup_id.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(second_lookup_ids, vec!["lookup_4"]);
+        assert_eq!(second_page.next_starting_after, None);
+    }
+
+    #[tokio::test]
+    async fn test_mock_db_deletes_only_orphaned_lookups() {
+        let mock_db = create_mock_db(vec![
+            reverse_lookup("lookup_1", REFUND_LOOKUP_SOURCE),
+            reverse_lookup("lookup_2", "payment_intent"),
+        ])
+        .await;
+
+        let deleted_lookups = mock_db
+            .delete_orphaned_reverse_lookups(
+                vec!["lookup_1".to_string(), "lookup_2".to_string()],
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(deleted_lookups, 1);
+        let remaining_lookups = mock_db.reverse_lookups.lock().await;
+        assert_eq!(remaining_lookups.len(), 1);
+        assert!(remaining_lookups
+            .iter()
+            .all(|reverse_lookup| reverse_lookup.lookup_id == "lookup_2"));
+    }
 }
@@ -1,3 +1,4 @@
+use async_bb8_diesel::AsyncConnection;
 use diesel_models::routing_algorithm as routing_storage;
 use error_stack::report;
 use router_env::{instrument, tracing};
@@ -7,6 +8,7 @@ use crate::{
     connection,
     core::errors::{self, CustomResult},
     services::Store,
+    types::storage,
 };
 
 type StorageResult<T> = CustomResult<T, errors::StorageError>;
@@ -18,6 +20,14 @@ pub trait RoutingAlgorithmInterface {
         routing_algorithm: routing_storage::RoutingAlgorithm,
     ) -> StorageResult<routing_storage::RoutingAlgorithm>;
 
+    /// Insert the routing algorithm and record its creation in the audit log, in the same
+    /// transaction
+    async fn insert_routing_algorithm_with_audit_log(
+        &self,
+        routing_algorithm: routing_storage::RoutingAlgorithm,
+        audit_log: storage::AuditLogContext,
+    ) -> StorageResult<routing_storage::RoutingAlgorithm>;
+
     async fn find_routing_algorithm_by_profile_id_algorithm_id(
         &self,
         profile_id: &common_utils::id_type::ProfileId,
@@ -73,6 +83,36 @@ impl RoutingAlgorithmInterface for Store {
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    #[instrument(skip_all)]
+    async fn insert_routing_algorithm_with_audit_log(
+        &self,
+        routing_algorithm: routing_storage::RoutingAlgorithm,
+        audit_log: storage::AuditLogContext,
+    ) -> StorageResult<routing_storage::RoutingAlgorithm> {
+        let conn = connection::pg_connection_write(self).await?;
+        let inserted_routing_algorithm = conn
+            .transaction_async(|conn| async move {
+                let inserted_routing_algorithm = routing_algorithm.insert(&conn).await?;
+
+                audit_log
+                    .record(
+                        &conn,
+                        &inserted_routing_algorithm.merchant_id,
+                        storage::enums::AuditLogResourceType::RoutingAlgorithm,
+                        inserted_routing_algorithm.algorithm_id.get_string_repr(),
+                        storage::enums::AuditLogAction::Create,
+                        None,
+                        Some(&inserted_routing_algorithm),
+                    )
+                    .await?;
+
+                Ok::<_, errors::StorageError>(inserted_routing_algorithm)
+            })
+            .await?;
+
+        Ok(inserted_routing_algorithm)
+    }
+
     #[instrument(skip_all)]
     async fn find_routing_algorithm_by_profile_id_algorithm_id(
         &self,
@@ -183,6 +223,14 @@ impl RoutingAlgorithmInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn insert_routing_algorithm_with_audit_log(
+        &self,
+        _routing_algorithm: routing_storage::RoutingAlgorithm,
+        _audit_log: storage::AuditLogContext,
+    ) -> StorageResult<routing_storage::RoutingAlgorithm> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn find_routing_algorithm_by_profile_id_algorithm_id(
         &self,
         _profile_id: &common_utils::id_type::ProfileId,
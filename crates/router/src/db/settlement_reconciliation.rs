@@ -0,0 +1,125 @@
+use async_bb8_diesel::AsyncConnection;
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+/// The number of line items upserted per statement. Each line item binds 15 parameters, which
+/// keeps a statement well within the limit of 65535 bind parameters of Postgres.
+const LINE_ITEMS_UPSERT_BATCH_SIZE: usize = 1000;
+
+#[async_trait::async_trait]
+pub trait SettlementReconciliationInterface {
+    /// Store the reconciled line items of a settlement file received from a connector
+    async fn ingest_settlement_line_items(
+        &self,
+        line_items: Vec<storage::SettlementReconciliationNew>,
+    ) -> CustomResult<Vec<storage::SettlementReconciliation>, errors::StorageError>;
+
+    /// List the line items of the merchant which need manual review, either because they do not
+    /// match our record of the transaction or because we have no record of the transaction.
+    async fn list_unmatched_settlement_line_items(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::SettlementReconciliation>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl SettlementReconciliationInterface for Store {
+    #[instrument(skip_all)]
+    async fn ingest_settlement_line_items(
+        &self,
+        line_items: Vec<storage::SettlementReconciliationNew>,
+    ) -> CustomResult<Vec<storage::SettlementReconciliation>, errors::StorageError> {
+        if line_items.is_empty() {
+            return Ok(Vec::new());
+        }
+        let conn = connection::pg_connection_write(self).await?;
+        conn.transaction_async(|conn| async move {
+            let mut ingested_line_items = Vec::with_capacity(line_items.len());
+            for batch in line_items.chunks(LINE_ITEMS_UPSERT_BATCH_SIZE) {
+                ingested_line_items.extend(
+                    storage::SettlementReconciliation::bulk_upsert(&conn, batch.to_vec()).await?,
+                );
+            }
+            Ok::<_, errors::StorageError>(ingested_line_items)
+        })
+        .await
+        .map_err(|error| report!(error))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_unmatched_settlement_line_items(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::SettlementReconciliation>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SettlementReconciliation::find_by_merchant_id_reconciliation_statuses(
+            &conn,
+            merchant_id,
+            vec![
+                enums::SettlementReconciliationStatus::Unmatched,
+                enums::SettlementReconciliationStatus::Orphan,
+            ],
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl SettlementReconciliationInterface for MockDb {
+    async fn ingest_settlement_line_items(
+        &self,
+        _line_items: Vec<storage::SettlementReconciliationNew>,
+    ) -> CustomResult<Vec<storage::SettlementReconciliation>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_unmatched_settlement_line_items(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::SettlementReconciliation>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl SettlementReconciliationInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn ingest_settlement_line_items(
+        &self,
+        line_items: Vec<storage::SettlementReconciliationNew>,
+    ) -> CustomResult<Vec<storage::SettlementReconciliation>, errors::StorageError> {
+        self.diesel_store
+            .ingest_settlement_line_items(line_items)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_unmatched_settlement_line_items(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::SettlementReconciliation>, errors::StorageError> {
+        self.diesel_store
+            .list_unmatched_settlement_line_items(merchant_id, limit, offset)
+            .await
+    }
+}
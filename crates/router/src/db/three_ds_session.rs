@@ -0,0 +1,447 @@
+use async_bb8_diesel::AsyncConnection;
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::{reverse_lookup::ReverseLookupInterface, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+/// The lookup id of the DS transaction id in the namespace of the merchant
+fn get_ds_trans_id_lookup_id(ds_trans_id: &str) -> String {
+    format!("three_ds_session_{ds_trans_id}")
+}
+
+/// The lookup id of the DS transaction id registered before the lookups were namespaced
+fn get_legacy_ds_trans_id_lookup_id(
+    merchant_id: &common_utils::id_type::MerchantId,
+    ds_trans_id: &str,
+) -> String {
+    format!(
+        "three_ds_session_{}_{}",
+        merchant_id.get_string_repr(),
+        ds_trans_id
+    )
+}
+
+#[async_trait::async_trait]
+pub trait ThreeDsSessionInterface {
+    /// Insert the session along with the reverse lookup of the session by its DS transaction id
+    async fn insert_three_ds_session(
+        &self,
+        session: storage::ThreeDsSessionNew,
+    ) -> CustomResult<storage::ThreeDsSession, errors::StorageError>;
+
+    async fn find_three_ds_session_by_merchant_id_ds_trans_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        ds_trans_id: &str,
+    ) -> CustomResult<storage::ThreeDsSession, errors::StorageError>;
+
+    /// Update the session only if it is still in `current_status`
+    async fn update_three_ds_session_by_session_id_status(
+        &self,
+        session_id: &str,
+        current_status: enums::ThreeDsSessionStatus,
+        session_update: storage::ThreeDsSessionUpdate,
+    ) -> CustomResult<storage::ThreeDsSession, errors::StorageError>;
+
+    /// Delete the oldest sessions which expired before the cutoff, along with their reverse
+    /// lookups. Returns the number of the sessions deleted.
+    async fn delete_expired_three_ds_sessions(
+        &self,
+        expired_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<usize, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ThreeDsSessionInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_three_ds_session(
+        &self,
+        session: storage::ThreeDsSessionNew,
+    ) -> CustomResult<storage::ThreeDsSession, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        // The session is inserted along with its lookup, so that a session is never left without
+        // the lookup it is found by
+        conn.transaction_async(|conn| async move {
+            let session = session.insert(&conn).await?;
+            storage::ReverseLookupNew {
+                lookup_id: storage::reverse_lookup::get_namespaced_lookup_id(
+                    &session.merchant_id,
+                    &get_ds_trans_id_lookup_id(&session.ds_trans_id),
+                ),
+                pk_id: session.session_id.clone(),
+                sk_id: session.authentication_id.clone(),
+                source: "three_ds_session".to_string(),
+                updated_by: enums::MerchantStorageScheme::PostgresOnly.to_string(),
+            }
+            .insert(&conn)
+            .await?;
+            Ok::<_, errors::StorageError>(session)
+        })
+        .await
+        .map_err(|error| report!(error))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_three_ds_session_by_merchant_id_ds_trans_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        ds_trans_id: &str,
+    ) -> CustomResult<storage::ThreeDsSession, errors::StorageError> {
+        let lookup = self
+            .get_namespaced_lookup_by_lookup_id(
+                &get_ds_trans_id_lookup_id(ds_trans_id),
+                merchant_id,
+                Some(&get_legacy_ds_trans_id_lookup_id(merchant_id, ds_trans_id)),
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await?;
+
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ThreeDsSession::find_by_session_id(&conn, &lookup.pk_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_three_ds_session_by_session_id_status(
+        &self,
+        session_id: &str,
+        current_status: enums::ThreeDsSessionStatus,
+        session_update: storage::ThreeDsSessionUpdate,
+    ) -> CustomResult<storage::ThreeDsSession, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ThreeDsSession::update_by_session_id_status(
+            &conn,
+            session_id,
+            current_status,
+            session_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_expired_three_ds_sessions(
+        &self,
+        expired_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        let session_ids =
+            storage::ThreeDsSession::find_expired_before(&conn, expired_before, limit)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))?
+                .into_iter()
+                .map(|session| session.session_id)
+                .collect::<Vec<_>>();
+        if session_ids.is_empty() {
+            return Ok(0);
+        }
+
+        conn.transaction_async(|conn| async move {
+            storage::ReverseLookup::delete_by_pk_ids(session_ids.clone(), &conn).await?;
+            let sessions =
+                storage::ThreeDsSession::delete_by_session_ids(&conn, session_ids).await?;
+            Ok::<_, errors::StorageError>(sessions.len())
+        })
+        .await
+        .map_err(|error| report!(error))
+    }
+}
+
+#[async_trait::async_trait]
+impl ThreeDsSessionInterface for MockDb {
+    async fn insert_three_ds_session(
+        &self,
+        session: storage::ThreeDsSessionNew,
+    ) -> CustomResult<storage::ThreeDsSession, errors::StorageError> {
+        let mut sessions = self.three_ds_sessions.lock().await;
+        if sessions.iter().any(|session_inner| {
+            session_inner.session_id == session.session_id
+                || (session_inner.merchant_id == session.merchant_id
+                    && session_inner.ds_trans_id == session.ds_trans_id)
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "three_ds_session",
+                key: Some(session.session_id.clone()),
+            })?
+        }
+        let session = storage::ThreeDsSession {
+            session_id: session.session_id,
+            merchant_id: session.merchant_id,
+            authentication_id: session.authentication_id,
+            ds_trans_id: session.ds_trans_id,
+            status: session.status,
+            flow_state: session.flow_state,
+            expires_at: session.expires_at,
+            created_at: session.created_at,
+            modified_at: session.modified_at,
+        };
+        sessions.push(session.clone());
+        Ok(session)
+    }
+
+    async fn find_three_ds_session_by_merchant_id_ds_trans_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        ds_trans_id: &str,
+    ) -> CustomResult<storage::ThreeDsSession, errors::StorageError> {
+        self.three_ds_sessions
+            .lock()
+            .await
+            .iter()
+            .find(|session| {
+                session.merchant_id == *merchant_id && session.ds_trans_id == ds_trans_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No 3DS session found for ds_trans_id = {ds_trans_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn update_three_ds_session_by_session_id_status(
+        &self,
+        session_id: &str,
+        current_status: enums::ThreeDsSessionStatus,
+        session_update: storage::ThreeDsSessionUpdate,
+    ) -> CustomResult<storage::ThreeDsSession, errors::StorageError> {
+        let mut sessions = self.three_ds_sessions.lock().await;
+        let session = sessions
+            .iter_mut()
+            .find(|session| session.session_id == session_id && session.status == current_status)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No 3DS session found for session_id = {session_id} in status {current_status}"
+            )))?;
+        if let Some(status) = session_update.status {
+            session.status = status;
+        }
+        if let Some(flow_state) = session_update.flow_state {
+            session.flow_state = flow_state;
+        }
+        session.modified_at = session_update.modified_at;
+        Ok(session.clone())
+    }
+
+    async fn delete_expired_three_ds_sessions(
+        &self,
+        expired_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let mut sessions = self.three_ds_sessions.lock().await;
+        let mut expired_session_ids = sessions
+            .iter()
+            .filter(|session| session.expires_at < expired_before)
+            .map(|session| (session.expires_at, session.session_id.clone()))
+            .collect::<Vec<_>>();
+        expired_session_ids.sort();
+        expired_session_ids.truncate(usize::try_from(limit).unwrap_or(0));
+
+        sessions.retain(|session| {
+            !expired_session_ids
+                .iter()
+                .any(|(_, session_id)| *session_id == session.session_id)
+        });
+        Ok(expired_session_ids.len())
+    }
+}
+
+#[async_trait::async_trait]
+impl ThreeDsSessionInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_three_ds_session(
+        &self,
+        session: storage::ThreeDsSessionNew,
+    ) -> CustomResult<storage::ThreeDsSession, errors::StorageError> {
+        self.diesel_store.insert_three_ds_session(session).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_three_ds_session_by_merchant_id_ds_trans_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        ds_trans_id: &str,
+    ) -> CustomResult<storage::ThreeDsSession, errors::StorageError> {
+        self.diesel_store
+            .find_three_ds_session_by_merchant_id_ds_trans_id(merchant_id, ds_trans_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_three_ds_session_by_session_id_status(
+        &self,
+        session_id: &str,
+        current_status: enums::ThreeDsSessionStatus,
+        session_update: storage::ThreeDsSessionUpdate,
+    ) -> CustomResult<storage::ThreeDsSession, errors::StorageError> {
+        self.diesel_store
+            .update_three_ds_session_by_session_id_status(
+                session_id,
+                current_status,
+                session_update,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_expired_three_ds_sessions(
+        &self,
+        expired_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .delete_expired_three_ds_sessions(expired_before, limit)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use super::*;
+
+    fn get_session(
+        session_id: &str,
+        ds_trans_id: &str,
+        expires_at: time::PrimitiveDateTime,
+    ) -> storage::ThreeDsSessionNew {
+        let now = common_utils::date_time::now();
+        storage::ThreeDsSessionNew {
+            session_id: session_id.to_string(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            authentication_id: format!("authn_{session_id}"),
+            ds_trans_id: ds_trans_id.to_string(),
+            status: enums::ThreeDsSessionStatus::ChallengeRequired,
+            flow_state: storage::ThreeDsSessionFlowState::default(),
+            expires_at,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_session_is_found_by_its_ds_trans_id() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let expires_at = common_utils::date_time::now().saturating_add(time::Duration::minutes(10));
+        mockdb
+            .insert_three_ds_session(get_session("3ds_1", "ds_1", expires_at))
+            .await
+            .unwrap();
+
+        let session = mockdb
+            .find_three_ds_session_by_merchant_id_ds_trans_id(
+                &common_utils::id_type::MerchantId::default(),
+                "ds_1",
+            )
+            .await
+            .unwrap();
+        assert_eq!(session.session_id, "3ds_1");
+
+        let result = mockdb
+            .insert_three_ds_session(get_session("3ds_2", "ds_1", expires_at))
+            .await;
+        assert!(result.is_err_and(|error| matches!(
+            error.current_context(),
+            errors::StorageError::DuplicateValue { .. }
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_session_is_updated_only_in_the_expected_status() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let expires_at = common_utils::date_time::now().saturating_add(time::Duration::minutes(10));
+        mockdb
+            .insert_three_ds_session(get_session("3ds_1", "ds_1", expires_at))
+            .await
+            .unwrap();
+        let session_update = storage::ThreeDsSessionUpdate {
+            status: Some(enums::ThreeDsSessionStatus::Completed),
+            flow_state: None,
+            modified_at: common_utils::date_time::now(),
+        };
+
+        let session = mockdb
+            .update_three_ds_session_by_session_id_status(
+                "3ds_1",
+                enums::ThreeDsSessionStatus::ChallengeRequired,
+                session_update.clone(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(session.status, enums::ThreeDsSessionStatus::Completed);
+
+        // A second completion finds the session no longer awaiting the challenge
+        let result = mockdb
+            .update_three_ds_session_by_session_id_status(
+                "3ds_1",
+                enums::ThreeDsSessionStatus::ChallengeRequired,
+                session_update,
+            )
+            .await;
+        assert!(result.is_err_and(|error| error.current_context().is_db_not_found()));
+    }
+
+    #[tokio::test]
+    async fn test_expired_sessions_are_deleted_oldest_first() {
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+        let now = common_utils::date_time::now();
+        for (session_id, expires_at) in [
+            ("3ds_1", now.saturating_sub(time::Duration::minutes(5))),
+            ("3ds_2", now.saturating_sub(time::Duration::minutes(10))),
+            ("3ds_3", now.saturating_add(time::Duration::minutes(10))),
+        ] {
+            mockdb
+                .insert_three_ds_session(get_session(session_id, session_id, expires_at))
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(
+            mockdb
+                .delete_expired_three_ds_sessions(now, 1)
+                .await
+                .unwrap(),
+            1
+        );
+        let remaining_session_ids = mockdb
+            .three_ds_sessions
+            .lock()
+            .await
+            .iter()
+            .map(|session| session.session_id.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(remaining_session_ids, vec!["3ds_1", "3ds_3"]);
+
+        assert_eq!(
+            mockdb
+                .delete_expired_three_ds_sessions(now, 10)
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            mockdb
+                .delete_expired_three_ds_sessions(now, 10)
+                .await
+                .unwrap(),
+            0
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait WebhookEventSequenceInterface {
+    /// Assign the next sequence number to an event of the resource
+    async fn get_next_webhook_event_sequence_number(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        primary_object_type: enums::EventObjectType,
+        primary_object_id: &str,
+    ) -> CustomResult<i64, errors::StorageError>;
+
+    async fn find_webhook_event_sequence_by_merchant_id_primary_object(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        primary_object_type: enums::EventObjectType,
+        primary_object_id: &str,
+    ) -> CustomResult<Option<storage::WebhookEventSequence>, errors::StorageError>;
+
+    /// Record that the merchant acknowledged the event of the resource with the provided sequence
+    /// number
+    async fn acknowledge_webhook_event_sequence_number(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        primary_object_type: enums::EventObjectType,
+        primary_object_id: &str,
+        sequence_number: i64,
+    ) -> CustomResult<(), errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl WebhookEventSequenceInterface for Store {
+    #[instrument(skip_all)]
+    async fn get_next_webhook_event_sequence_number(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        primary_object_type: enums::EventObjectType,
+        primary_object_id: &str,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookEventSequenceNew::new(
+            merchant_id.to_owned(),
+            primary_object_type,
+            primary_object_id.to_owned(),
+        )
+        .increment_sequence_number(&conn)
+        .await
+        .map(|webhook_event_sequence| webhook_event_sequence.last_sequence_number)
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_webhook_event_sequence_by_merchant_id_primary_object(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        primary_object_type: enums::EventObjectType,
+        primary_object_id: &str,
+    ) -> CustomResult<Option<storage::WebhookEventSequence>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookEventSequence::find_optional_by_merchant_id_primary_object(
+            &conn,
+            merchant_id,
+            primary_object_type,
+            primary_object_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn acknowledge_webhook_event_sequence_number(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        primary_object_type: enums::EventObjectType,
+        primary_object_id: &str,
+        sequence_number: i64,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookEventSequence::acknowledge_sequence_number(
+            &conn,
+            merchant_id,
+            primary_object_type,
+            primary_object_id,
+            sequence_number,
+        )
+        .await
+        .map(|_| ())
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookEventSequenceInterface for MockDb {
+    async fn get_next_webhook_event_sequence_number(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _primary_object_type: enums::EventObjectType,
+        _primary_object_id: &str,
+    ) -> CustomResult<i64, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_webhook_event_sequence_by_merchant_id_primary_object(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _primary_object_type: enums::EventObjectType,
+        _primary_object_id: &str,
+    ) -> CustomResult<Option<storage::WebhookEventSequence>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn acknowledge_webhook_event_sequence_number(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _primary_object_type: enums::EventObjectType,
+        _primary_object_id: &str,
+        _sequence_number: i64,
+    ) -> CustomResult<(), errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookEventSequenceInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn get_next_webhook_event_sequence_number(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        primary_object_type: enums::EventObjectType,
+        primary_object_id: &str,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store
+            .get_next_webhook_event_sequence_number(
+                merchant_id,
+                primary_object_type,
+                primary_object_id,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_webhook_event_sequence_by_merchant_id_primary_object(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        primary_object_type: enums::EventObjectType,
+        primary_object_id: &str,
+    ) -> CustomResult<Option<storage::WebhookEventSequence>, errors::StorageError> {
+        self.diesel_store
+            .find_webhook_event_sequence_by_merchant_id_primary_object(
+                merchant_id,
+                primary_object_type,
+                primary_object_id,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn acknowledge_webhook_event_sequence_number(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        primary_object_type: enums::EventObjectType,
+        primary_object_id: &str,
+        sequence_number: i64,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .acknowledge_webhook_event_sequence_number(
+                merchant_id,
+                primary_object_type,
+                primary_object_id,
+                sequence_number,
+            )
+            .await
+    }
+}
@@ -73,6 +73,7 @@ pub mod headers {
     pub const X_ACCEPT_VERSION: &str = "X-Accept-Version";
     pub const X_DATE: &str = "X-Date";
     pub const X_WEBHOOK_SIGNATURE: &str = "X-Webhook-Signature-512";
+    pub const X_WEBHOOK_SEQUENCE_NUMBER: &str = "X-Webhook-Sequence-Number";
     pub const X_REQUEST_ID: &str = "X-Request-Id";
     pub const STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE: &str = "Stripe-Signature";
     pub const STRIPE_COMPATIBLE_CONNECT_ACCOUNT: &str = "Stripe-Account";
@@ -150,6 +151,7 @@ pub fn mk_app(
         server_app = server_app
             .service(routes::EphemeralKey::server(state.clone()))
             .service(routes::Webhooks::server(state.clone()))
+            .service(routes::ConnectorWebhooks::server(state.clone()))
             .service(routes::PaymentMethods::server(state.clone()))
             .service(routes::Poll::server(state.clone()))
     }
@@ -169,6 +171,7 @@ pub fn mk_app(
                 .service(routes::Files::server(state.clone()))
                 .service(routes::Disputes::server(state.clone()))
                 .service(routes::Blocklist::server(state.clone()))
+                .service(routes::Settlements::server(state.clone()))
                 .service(routes::Gsm::server(state.clone()))
                 .service(routes::ApplePayCertificatesMigration::server(state.clone()))
                 .service(routes::PaymentLink::server(state.clone()))
@@ -202,6 +205,8 @@ pub fn mk_app(
 
     server_app = server_app.service(routes::Cards::server(state.clone()));
     server_app = server_app.service(routes::Cache::server(state.clone()));
+    server_app = server_app.service(routes::ConnectorConcurrency::server(state.clone()));
+    server_app = server_app.service(routes::ConnectorLatency::server(state.clone()));
     server_app = server_app.service(routes::Health::server(state.clone()));
 
     server_app
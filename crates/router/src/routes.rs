@@ -8,6 +8,8 @@ pub mod blocklist;
 pub mod cache;
 pub mod cards_info;
 pub mod configs;
+pub mod connector_concurrency;
+pub mod connector_latency;
 #[cfg(feature = "olap")]
 pub mod connector_onboarding;
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -46,6 +48,8 @@ pub mod recon;
 pub mod refunds;
 #[cfg(feature = "olap")]
 pub mod routing;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod settlements;
 #[cfg(feature = "olap")]
 pub mod user;
 #[cfg(feature = "olap")]
@@ -65,11 +69,13 @@ pub use self::app::DummyConnector;
 pub use self::app::Forex;
 #[cfg(all(feature = "olap", feature = "recon", feature = "v1"))]
 pub use self::app::Recon;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub use self::app::Settlements;
 pub use self::app::{
-    ApiKeys, AppState, ApplePayCertificatesMigration, Cache, Cards, Configs, ConnectorOnboarding,
-    Customers, Disputes, EphemeralKey, Files, Gsm, Health, Mandates, MerchantAccount,
-    MerchantConnectorAccount, PaymentLink, PaymentMethods, Payments, Poll, Profile, ProfileNew,
-    Refunds, SessionState, User, Webhooks,
+    ApiKeys, AppState, ApplePayCertificatesMigration, Cache, Cards, Configs, ConnectorConcurrency,
+    ConnectorLatency, ConnectorOnboarding, ConnectorWebhooks, Customers, Disputes, EphemeralKey,
+    Files, Gsm, Health, Mandates, MerchantAccount, MerchantConnectorAccount, PaymentLink,
+    PaymentMethods, Payments, Poll, Profile, ProfileNew, Refunds, SessionState, User, Webhooks,
 };
 #[cfg(feature = "olap")]
 pub use self::app::{Blocklist, Organization, Routing, Verify, WebhookEvents};
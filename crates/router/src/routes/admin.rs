@@ -4,7 +4,7 @@ use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
 use crate::{
-    core::{admin::*, api_locking},
+    core::{admin::*, api_locking, audit_log},
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::admin,
 };
@@ -226,7 +226,9 @@ pub async fn update_merchant_account(
         state,
         &req,
         json_payload.into_inner(),
-        |state, _, req, _| merchant_account_update(state, &merchant_id, None, req),
+        |state, _, req, req_state| {
+            merchant_account_update(state, &merchant_id, None, req, req_state)
+        },
         auth::auth_type(
             &auth::AdminApiAuth,
             &auth::JWTAuthMerchantFromRoute {
@@ -748,6 +750,131 @@ pub async fn connector_delete(
     ))
     .await
 }
+
+/// Merchant Connector - Rotate Webhook Identifier
+///
+/// Rotate the identifier in the webhook endpoint of a Merchant Connector. Webhooks sent to the previous endpoint are accepted for a grace period of 24 hours
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorsWebhookIdentifierRotate))]
+pub async fn connector_webhook_identifier_rotate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::MerchantConnectorsWebhookIdentifierRotate;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+    let payload = web::Json(admin::MerchantConnectorId {
+        merchant_id: merchant_id.clone(),
+        merchant_connector_id,
+    })
+    .into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            rotate_connector_webhook_identifier(
+                state,
+                req.merchant_id,
+                auth.profile_id,
+                req.merchant_connector_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantConnectorAccountWrite,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Rotate the credentials of a Merchant Connector. The new credentials are kept pending until they are promoted
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorsCredentialsRotate))]
+pub async fn connector_credentials_rotate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+    json_payload: web::Json<admin::MerchantConnectorCredentialsRotateRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantConnectorsCredentialsRotate;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+    let mut payload = json_payload.into_inner();
+    payload.merchant_id = merchant_id.clone();
+    payload.merchant_connector_id = merchant_connector_id;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| rotate_connector_credentials(state, auth.profile_id, req),
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantConnectorAccountWrite,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Promote the pending credentials of a Merchant Connector, once verified with a test call to the connector
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorsCredentialsPromote))]
+pub async fn connector_credentials_promote(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+    json_payload: web::Json<admin::MerchantConnectorCredentialsPromoteRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantConnectorsCredentialsPromote;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+    let mut payload = json_payload.into_inner();
+    payload.merchant_id = merchant_id.clone();
+    payload.merchant_connector_id = merchant_connector_id;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| promote_connector_credentials(state, auth.profile_id, req),
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantConnectorAccountWrite,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Merchant Connector - Delete
 ///
 /// Delete or Detach a Merchant Connector from Merchant Account
@@ -837,6 +964,47 @@ pub async fn merchant_account_toggle_all_kv(
     .await
 }
 
+/// Merchant Account - Payment Methods Retokenize
+///
+/// Re-tokenize the stored cards of the Merchant Account with the network tokenization service,
+/// after the service was switched
+#[cfg(all(feature = "v1", not(feature = "payment_methods_v2")))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsRetokenize))]
+pub async fn merchant_account_payment_methods_retokenize(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<admin::PaymentMethodRetokenizationRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodsRetokenize;
+    let merchant_id = path.into_inner();
+    let mut payload = json_payload.into_inner();
+    payload.merchant_id = merchant_id.clone();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| {
+            crate::core::payment_methods::retokenization::retokenize_merchant_payment_methods(
+                state, req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantAccountWrite,
+                minimum_entity_level: EntityType::Merchant,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Merchant Account - KV Status
 ///
 /// Toggle KV mode for the Merchant Account
@@ -861,6 +1029,246 @@ pub async fn merchant_account_kv_status(
     .await
 }
 
+/// Merchant Account - Onboarding Status
+///
+/// Retrieve the onboarding status of the Merchant Account
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantOnboardingStatusRetrieve))]
+pub async fn merchant_account_onboarding_status(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::MerchantOnboardingStatusRetrieve;
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, req, _| retrieve_merchant_onboarding_status(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Merchant Account - Update Onboarding Status
+///
+/// Move the Merchant Account to the next onboarding status
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantOnboardingStatusUpdate))]
+pub async fn merchant_account_update_onboarding_status(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<admin::MerchantOnboardingStatusUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantOnboardingStatusUpdate;
+    let mut payload = json_payload.into_inner();
+    payload.merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| update_merchant_onboarding_status(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Audit Logs
+///
+/// Retrieve the audit trail of a resource of the Merchant Account
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::AuditLogRetrieve))]
+pub async fn merchant_account_audit_logs(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    query_params: web::Query<admin::AuditLogRetrieveRequest>,
+) -> HttpResponse {
+    let flow = Flow::AuditLogRetrieve;
+    let merchant_id = path.into_inner();
+    let mut payload = query_params.into_inner();
+    payload.merchant_id.clone_from(&merchant_id);
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| audit_log::retrieve_audit_logs(state, req),
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantAccountRead,
+                minimum_entity_level: EntityType::Merchant,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Merchant Account - Connector Failover Chain
+///
+/// Retrieve the connectors which would be tried for a payment, according to the connector failover
+/// order of the Merchant Account
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorFailoverChainRetrieve))]
+pub async fn merchant_account_connector_failover_chain(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    query_params: web::Query<admin::ConnectorFailoverChainRequest>,
+) -> HttpResponse {
+    let flow = Flow::ConnectorFailoverChainRetrieve;
+    let merchant_id = path.into_inner();
+    let mut payload = query_params.into_inner();
+    payload.merchant_id.clone_from(&merchant_id);
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| retrieve_connector_failover_chain(state, req),
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantAccountRead,
+                minimum_entity_level: EntityType::Merchant,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Merchant Account - Legal Hold Create
+///
+/// Place a legal hold on a record of the Merchant Account, which exempts the record from the purge
+/// of the records past their retention period
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::LegalHoldCreate))]
+pub async fn merchant_account_legal_hold_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<admin::LegalHoldCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::LegalHoldCreate;
+    let merchant_id = path.into_inner();
+    let mut payload = json_payload.into_inner();
+    payload.merchant_id.clone_from(&merchant_id);
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| crate::core::data_retention::create_legal_hold(state, req),
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantAccountWrite,
+                minimum_entity_level: EntityType::Merchant,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Legal Hold List
+///
+/// List the legal holds of the Merchant Account
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::LegalHoldList))]
+pub async fn merchant_account_legal_holds_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    query_params: web::Query<admin::LegalHoldListRequest>,
+) -> HttpResponse {
+    let flow = Flow::LegalHoldList;
+    let merchant_id = path.into_inner();
+    let mut payload = query_params.into_inner();
+    payload.merchant_id.clone_from(&merchant_id);
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| crate::core::data_retention::list_legal_holds(state, req),
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantAccountRead,
+                minimum_entity_level: EntityType::Merchant,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Merchant Account - Legal Hold Delete
+///
+/// Release a legal hold on a record of the Merchant Account
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::LegalHoldDelete))]
+pub async fn merchant_account_legal_hold_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_enums::RetentionEntity,
+        String,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::LegalHoldDelete;
+    let (merchant_id, entity_type, record_id) = path.into_inner();
+    let payload = admin::LegalHoldDeleteRequest {
+        merchant_id: merchant_id.clone(),
+        entity_type,
+        record_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| crate::core::data_retention::delete_legal_hold(state, req),
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantAccountWrite,
+                minimum_entity_level: EntityType::Merchant,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Merchant Account - KV Status
 ///
 /// Toggle KV mode for the Merchant Account
@@ -26,8 +26,8 @@ pub async fn api_key_create(
         state,
         &req,
         payload,
-        |state, auth_data, payload, _| async {
-            api_keys::create_api_key(state, payload, auth_data.key_store).await
+        |state, auth_data, payload, req_state| async {
+            api_keys::create_api_key(state, payload, auth_data.key_store, req_state).await
         },
         auth::auth_type(
             &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
@@ -58,8 +58,8 @@ pub async fn api_key_create(
         state,
         &req,
         payload,
-        |state, auth_data, payload, _| async {
-            api_keys::create_api_key(state, payload, auth_data.key_store).await
+        |state, auth_data, payload, req_state| async {
+            api_keys::create_api_key(state, payload, auth_data.key_store, req_state).await
         },
         auth::auth_type(
             &auth::AdminApiAuthWithMerchantIdFromHeader,
@@ -158,7 +158,7 @@ pub async fn api_key_update(
         state,
         &req,
         payload,
-        |state, _, payload, _| api_keys::update_api_key(state, payload),
+        |state, _, payload, req_state| api_keys::update_api_key(state, payload, req_state),
         auth::auth_type(
             &auth::AdminApiAuth,
             &auth::JWTAuthMerchantFromRoute {
@@ -190,9 +190,9 @@ pub async fn api_key_update(
         state,
         &req,
         payload,
-        |state, authentication_data, mut payload, _| {
+        |state, authentication_data, mut payload, req_state| {
             payload.merchant_id = authentication_data.merchant_account.get_id().to_owned();
-            api_keys::update_api_key(state, payload)
+            api_keys::update_api_key(state, payload, req_state)
         },
         auth::auth_type(
             &auth::AdminApiAuthWithMerchantIdFromHeader,
@@ -222,7 +222,9 @@ pub async fn api_key_revoke(
         state,
         &req,
         (&merchant_id, &key_id),
-        |state, _, (merchant_id, key_id), _| api_keys::revoke_api_key(state, merchant_id, key_id),
+        |state, _, (merchant_id, key_id), req_state| {
+            api_keys::revoke_api_key(state, merchant_id, key_id, req_state)
+        },
         auth::auth_type(
             &auth::AdminApiAuth,
             &auth::JWTAuthMerchantFromRoute {
@@ -252,7 +254,9 @@ pub async fn api_key_revoke(
         state,
         &req,
         (&merchant_id, &key_id),
-        |state, _, (merchant_id, key_id), _| api_keys::revoke_api_key(state, merchant_id, key_id),
+        |state, _, (merchant_id, key_id), req_state| {
+            api_keys::revoke_api_key(state, merchant_id, key_id, req_state)
+        },
         auth::auth_type(
             &auth::AdminApiAuth,
             &auth::JWTAuthMerchantFromRoute {
@@ -46,13 +46,15 @@ use super::pm_auth;
 use super::poll::retrieve_poll_status;
 #[cfg(feature = "olap")]
 use super::routing;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::settlements;
 #[cfg(feature = "olap")]
 use super::verification::{apple_pay_merchant_registration, retrieve_apple_pay_verified_domains};
 #[cfg(all(feature = "oltp", feature = "v1"))]
 use super::webhooks::*;
 use super::{
-    admin, api_keys, cache::*, connector_onboarding, disputes, files, gsm, health::*, profiles,
-    user, user_role,
+    admin, api_keys, cache::*, connector_concurrency, connector_latency, connector_onboarding,
+    disputes, files, gsm, health::*, profiles, user, user_role,
 };
 #[cfg(feature = "v1")]
 use super::{apple_pay_certificates_migration, blocklist, payment_link, webhook_events};
@@ -85,6 +87,8 @@ use crate::{
 #[derive(Clone)]
 pub struct ReqState {
     pub event_context: events::EventContext<crate::events::EventType, EventsHandler>,
+    /// The authentication with which the request was made, once it is authenticated
+    pub auth_type: Option<crate::services::authentication::AuthenticationType>,
 }
 
 #[derive(Clone)]
@@ -116,6 +120,7 @@ impl SessionState {
     pub fn get_req_state(&self) -> ReqState {
         ReqState {
             event_context: events::EventContext::new(self.event_handler.clone()),
+            auth_type: None,
         }
     }
 }
@@ -547,6 +552,18 @@ impl Payments {
                 .service(web::resource("/filter").route(web::post().to(get_filters_for_payments)))
                 .service(web::resource("/v2/filter").route(web::get().to(get_payment_filters)))
                 .service(web::resource("/aggregate").route(web::get().to(get_payments_aggregates)))
+                .service(
+                    web::resource("/distinct_cards")
+                        .route(web::get().to(get_payments_distinct_card_count)),
+                )
+                .service(
+                    web::resource("/settlements")
+                        .route(web::get().to(get_payments_settlement_amounts)),
+                )
+                .service(
+                    web::resource("/pending_review")
+                        .route(web::get().to(payments_list_pending_review)),
+                )
                 .service(
                     web::resource("/profile/aggregate")
                         .route(web::get().to(get_payments_aggregates_profile)),
@@ -559,6 +576,22 @@ impl Payments {
                     web::resource("/{payment_id}/manual-update")
                         .route(web::put().to(payments_manual_update)),
                 )
+                .service(
+                    web::resource("/{payment_id}/related")
+                        .route(web::get().to(payments_list_related)),
+                )
+        }
+        #[cfg(all(
+            feature = "oltp",
+            not(feature = "customer_v2"),
+            not(feature = "payment_methods_v2")
+        ))]
+        {
+            // Registered before the payment id routes, which would match the path otherwise
+            route = route.service(
+                web::resource("/payment_methods")
+                    .route(web::post().to(payments_list_payment_methods_for_session)),
+            );
         }
         #[cfg(feature = "oltp")]
         {
@@ -572,6 +605,11 @@ impl Payments {
                     web::resource("/sync")
                         .route(web::post().to(payments_retrieve_with_gateway_creds)),
                 )
+                .service(web::resource("/resume").route(web::post().to(payments_resume)))
+                .service(
+                    web::resource("/receipts/{receipt_token}")
+                        .route(web::get().to(payments_receipt_retrieve_by_token)),
+                )
                 .service(
                     web::resource("/{payment_id}")
                         .route(web::get().to(payments_retrieve))
@@ -580,12 +618,32 @@ impl Payments {
                 .service(
                     web::resource("/{payment_id}/confirm").route(web::post().to(payments_confirm)),
                 )
+                .service(
+                    web::resource("/{payment_id}/receipt")
+                        .route(web::get().to(payments_receipt_retrieve)),
+                )
+                .service(
+                    web::resource("/{payment_id}/simulate_webhook")
+                        .route(web::post().to(payments_simulate_webhook)),
+                )
                 .service(
                     web::resource("/{payment_id}/cancel").route(web::post().to(payments_cancel)),
                 )
+                .service(
+                    web::resource("/{payment_id}/void_eligibility")
+                        .route(web::get().to(payments_void_eligibility_check)),
+                )
+                .service(
+                    web::resource("/{payment_id}/timeline")
+                        .route(web::get().to(payments_timeline_retrieve)),
+                )
                 .service(
                     web::resource("/{payment_id}/capture").route(web::post().to(payments_capture)),
                 )
+                .service(
+                    web::resource("/{payment_id}/captures/{capture_id}/void")
+                        .route(web::post().to(payments_void_capture)),
+                )
                 .service(
                     web::resource("/{payment_id}/approve")
                         .route(web::post().to(payments_approve)),
@@ -594,6 +652,10 @@ impl Payments {
                     web::resource("/{payment_id}/reject")
                         .route(web::post().to(payments_reject)),
                 )
+                .service(
+                    web::resource("/{payment_id}/fulfillment")
+                        .route(web::post().to(payments_fulfillment_update)),
+                )
                 .service(
                     web::resource("/redirect/{payment_id}/{merchant_id}/{attempt_id}")
                         .route(web::get().to(payments_start)),
@@ -887,6 +949,10 @@ impl Routing {
                 web::resource("/{algorithm_id}")
                     .route(web::get().to(routing::routing_retrieve_config)),
             )
+            .service(
+                web::resource("/{algorithm_id}/effective_weights")
+                    .route(web::get().to(routing::routing_retrieve_effective_weights)),
+            )
             .service(
                 web::resource("/{algorithm_id}/activate").route(web::post().to(
                     |state, req, path| {
@@ -978,6 +1044,24 @@ impl Customers {
                 )
         }
 
+        #[cfg(all(feature = "oltp", feature = "v1"))]
+        {
+            route = route
+                .service(
+                    web::resource("/connector_sync/retry")
+                        .route(web::post().to(customers_connector_sync_retry)),
+                )
+                .service(
+                    web::resource("/{customer_id}/velocity_counters")
+                        .route(web::get().to(customers_velocity_counters_retrieve)),
+                )
+                .service(
+                    web::resource("/{customer_id}/communications")
+                        .route(web::post().to(customers_communications_create))
+                        .route(web::get().to(customers_communications_list)),
+                )
+        }
+
         route
     }
 }
@@ -1014,6 +1098,9 @@ impl Refunds {
             route = route
                 .service(web::resource("").route(web::post().to(refunds_create)))
                 .service(web::resource("/sync").route(web::post().to(refunds_retrieve_with_body)))
+                .service(
+                    web::resource("/eligibility").route(web::post().to(refunds_eligibility_check)),
+                )
                 .service(
                     web::resource("/{id}")
                         .route(web::get().to(refunds_retrieve))
@@ -1031,7 +1118,9 @@ pub struct Payouts;
 impl Payouts {
     pub fn server(state: AppState) -> Scope {
         let mut route = web::scope("/payouts").app_data(web::Data::new(state));
-        route = route.service(web::resource("/create").route(web::post().to(payouts_create)));
+        route = route
+            .service(web::resource("/create").route(web::post().to(payouts_create)))
+            .service(web::resource("/returns").route(web::post().to(payouts_returns)));
 
         #[cfg(feature = "olap")]
         {
@@ -1125,10 +1214,26 @@ impl PaymentMethods {
                 .service(
                     web::resource("/collect").route(web::post().to(initiate_pm_collect_link_flow)),
                 )
+                .service(
+                    web::resource("/recommendations")
+                        .route(web::post().to(recommend_payment_methods_api)),
+                )
                 .service(
                     web::resource("/collect/{merchant_id}/{collect_id}")
                         .route(web::get().to(render_pm_collect_link)),
                 )
+                .service(
+                    web::resource("/bank_accounts")
+                        .route(web::post().to(tokenize_bank_account_api)),
+                )
+                .service(
+                    web::resource("/bank_accounts/{token_id}")
+                        .route(web::get().to(retrieve_bank_account_token_api)),
+                )
+                .service(
+                    web::resource("/bank_accounts/{token_id}/verification")
+                        .route(web::post().to(update_bank_account_verification_api)),
+                )
                 .service(
                     web::resource("/{payment_method_id}")
                         .route(web::get().to(payment_method_retrieve_api))
@@ -1193,6 +1298,22 @@ impl Blocklist {
     }
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub struct Settlements;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl Settlements {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/settlements")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(settlements::ingest_settlement_file)))
+            .service(
+                web::resource("/unmatched")
+                    .route(web::get().to(settlements::list_unmatched_settlement_line_items)),
+            )
+    }
+}
+
 #[cfg(feature = "olap")]
 pub struct Organization;
 
@@ -1256,7 +1377,7 @@ impl MerchantAccount {
 #[cfg(all(feature = "olap", feature = "v1"))]
 impl MerchantAccount {
     pub fn server(state: AppState) -> Scope {
-        web::scope("/accounts")
+        let route = web::scope("/accounts")
             .app_data(web::Data::new(state))
             .service(web::resource("").route(web::post().to(admin::merchant_account_create)))
             .service(web::resource("/list").route(web::get().to(admin::merchant_account_list)))
@@ -1265,6 +1386,28 @@ impl MerchantAccount {
                     .route(web::post().to(admin::merchant_account_toggle_kv))
                     .route(web::get().to(admin::merchant_account_kv_status)),
             )
+            .service(
+                web::resource("/{id}/onboarding")
+                    .route(web::get().to(admin::merchant_account_onboarding_status))
+                    .route(web::post().to(admin::merchant_account_update_onboarding_status)),
+            )
+            .service(
+                web::resource("/{id}/audit_logs")
+                    .route(web::get().to(admin::merchant_account_audit_logs)),
+            )
+            .service(
+                web::resource("/{id}/failover_chain")
+                    .route(web::get().to(admin::merchant_account_connector_failover_chain)),
+            )
+            .service(
+                web::resource("/{id}/legal_holds")
+                    .route(web::post().to(admin::merchant_account_legal_hold_create))
+                    .route(web::get().to(admin::merchant_account_legal_holds_list)),
+            )
+            .service(
+                web::resource("/{id}/legal_holds/{entity_type}/{record_id}")
+                    .route(web::delete().to(admin::merchant_account_legal_hold_delete)),
+            )
             .service(
                 web::resource("/transfer")
                     .route(web::post().to(admin::merchant_account_transfer_keys)),
@@ -1277,7 +1420,15 @@ impl MerchantAccount {
                     .route(web::get().to(admin::retrieve_merchant_account))
                     .route(web::post().to(admin::update_merchant_account))
                     .route(web::delete().to(admin::delete_merchant_account)),
-            )
+            );
+
+        #[cfg(not(feature = "payment_methods_v2"))]
+        let route = route.service(
+            web::resource("/{id}/payment_methods/retokenize")
+                .route(web::post().to(admin::merchant_account_payment_methods_retokenize)),
+        );
+
+        route
     }
 }
 
@@ -1319,6 +1470,11 @@ impl MerchantConnectorAccount {
                     web::resource("/connectors/verify")
                         .route(web::post().to(super::verify_connector::payment_connector_verify)),
                 )
+                .service(
+                    web::resource("/connectors/verify/tokenization").route(
+                        web::post().to(super::verify_connector::payment_connector_tokenization_test),
+                    ),
+                )
                 .service(
                     web::resource("/{merchant_id}/connectors")
                         .route(web::post().to(connector_create))
@@ -1329,6 +1485,24 @@ impl MerchantConnectorAccount {
                         .route(web::get().to(connector_retrieve))
                         .route(web::post().to(connector_update))
                         .route(web::delete().to(connector_delete)),
+                )
+                .service(
+                    web::resource(
+                        "/{merchant_id}/connectors/{merchant_connector_id}/webhook_identifier/rotate",
+                    )
+                    .route(web::post().to(connector_webhook_identifier_rotate)),
+                )
+                .service(
+                    web::resource(
+                        "/{merchant_id}/connectors/{merchant_connector_id}/credentials/rotate",
+                    )
+                    .route(web::post().to(connector_credentials_rotate)),
+                )
+                .service(
+                    web::resource(
+                        "/{merchant_id}/connectors/{merchant_connector_id}/credentials/promote",
+                    )
+                    .route(web::post().to(connector_credentials_promote)),
                 );
         }
         #[cfg(feature = "oltp")]
@@ -1412,6 +1586,30 @@ impl Webhooks {
     }
 }
 
+pub struct ConnectorWebhooks;
+
+#[cfg(all(feature = "oltp", feature = "v1"))]
+impl ConnectorWebhooks {
+    pub fn server(config: AppState) -> Scope {
+        use api_models::webhooks as webhook_type;
+
+        web::scope("/connector_webhooks")
+            .app_data(web::Data::new(config))
+            .service(
+                web::resource("/{webhook_identifier}")
+                    .route(web::post().to(receive_incoming_webhook_by_identifier::<
+                        webhook_type::OutgoingWebhook,
+                    >))
+                    .route(web::get().to(receive_incoming_webhook_by_identifier::<
+                        webhook_type::OutgoingWebhook,
+                    >))
+                    .route(web::put().to(receive_incoming_webhook_by_identifier::<
+                        webhook_type::OutgoingWebhook,
+                    >)),
+            )
+    }
+}
+
 pub struct Configs;
 
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -1525,6 +1723,10 @@ impl Disputes {
                 web::resource("/evidence/{dispute_id}")
                     .route(web::get().to(disputes::retrieve_dispute_evidence)),
             )
+            .service(
+                web::resource("/evidence/{dispute_id}/download")
+                    .route(web::get().to(disputes::download_dispute_evidence)),
+            )
             .service(
                 web::resource("/{dispute_id}").route(web::get().to(disputes::retrieve_dispute)),
             )
@@ -1567,6 +1769,32 @@ impl Cache {
     }
 }
 
+pub struct ConnectorConcurrency;
+
+impl ConnectorConcurrency {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/connector_concurrency")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/{connector}")
+                    .route(web::get().to(connector_concurrency::retrieve_connector_concurrency)),
+            )
+    }
+}
+
+pub struct ConnectorLatency;
+
+impl ConnectorLatency {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/connector_latency")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/{connector}")
+                    .route(web::get().to(connector_latency::retrieve_connector_latency)),
+            )
+    }
+}
+
 pub struct PaymentLink;
 
 #[cfg(all(feature = "olap", feature = "v1"))]
@@ -2058,6 +2286,10 @@ impl WebhookEvents {
                 web::resource("")
                     .route(web::get().to(webhook_events::list_initial_webhook_delivery_attempts)),
             )
+            .service(
+                web::resource("delivery_queue")
+                    .route(web::get().to(webhook_events::retrieve_webhook_delivery_queue)),
+            )
             .service(
                 web::scope("/{event_id}")
                     .service(
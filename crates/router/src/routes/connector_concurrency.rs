@@ -0,0 +1,37 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::{connector_concurrency as connector_concurrency_api, enums};
+use router_env::{instrument, tracing, Flow};
+
+use super::AppState;
+use crate::{
+    core::{api_locking, connector_concurrency},
+    services::{api, authentication as auth},
+};
+
+/// Connector Concurrency - Retrieve
+///
+/// Retrieve the number of the requests in flight to a connector, against its concurrency limit
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorConcurrencyRetrieve))]
+pub async fn retrieve_connector_concurrency(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<enums::Connector>,
+) -> impl Responder {
+    let flow = Flow::ConnectorConcurrencyRetrieve;
+    let payload = connector_concurrency_api::ConnectorConcurrencyRequest {
+        connector: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| {
+            connector_concurrency::retrieve_connector_concurrency(state, payload)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,37 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::{connector_latency as connector_latency_api, enums};
+use router_env::{instrument, tracing, Flow};
+
+use super::AppState;
+use crate::{
+    core::{api_locking, connector_latency},
+    services::{api, authentication as auth},
+};
+
+/// Connector Latency - Retrieve
+///
+/// Retrieve the distribution of the latency of the calls made to a connector over a window
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorLatencyRetrieve))]
+pub async fn retrieve_connector_latency(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<enums::Connector>,
+    query: web::Query<connector_latency_api::ConnectorLatencyQuery>,
+) -> impl Responder {
+    let flow = Flow::ConnectorLatencyRetrieve;
+    let payload = connector_latency_api::ConnectorLatencyRequest {
+        connector: path.into_inner(),
+        window_in_seconds: query.into_inner().window_in_seconds,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| connector_latency::retrieve_connector_latency(state, payload),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -133,13 +133,7 @@ pub async fn customers_list(
         &req,
         payload,
         |state, auth, request, _| {
-            list_customers(
-                state,
-                auth.merchant_account.get_id().to_owned(),
-                None,
-                auth.key_store,
-                request,
-            )
+            list_customers(state, auth.merchant_account, None, auth.key_store, request)
         },
         auth::auth_type(
             &auth::HeaderAuth(auth::ApiKeyAuth),
@@ -329,3 +323,152 @@ pub async fn get_customer_mandates(
     ))
     .await
 }
+
+#[cfg(all(feature = "v1", not(feature = "customer_v2")))]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersVelocityCountersRetrieve))]
+pub async fn customers_velocity_counters_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::CustomerId>,
+    query: web::Query<customers::CustomerVelocityCountersQuery>,
+) -> HttpResponse {
+    let flow = Flow::CustomersVelocityCountersRetrieve;
+    let payload = customers::CustomerVelocityCountersRequest {
+        customer_id: path.into_inner(),
+        currency: query.into_inner().currency,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            crate::core::payments::velocity_limits::get_customer_velocity_counters(
+                state,
+                auth.merchant_account,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::CustomerRead,
+                minimum_entity_level: EntityType::Merchant,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", not(feature = "customer_v2")))]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersConnectorSyncRetry))]
+pub async fn customers_connector_sync_retry(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<customers::CustomerConnectorSyncRetryRequest>,
+) -> HttpResponse {
+    let flow = Flow::CustomersConnectorSyncRetry;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            connector_sync::retry_connector_syncs(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::CustomerWrite,
+                minimum_entity_level: EntityType::Merchant,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", not(feature = "customer_v2")))]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersCommunicationCreate))]
+pub async fn customers_communications_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::CustomerId>,
+    json_payload: web::Json<customers::CustomerCommunicationCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::CustomersCommunicationCreate;
+    let payload = customers::CustomerCommunicationCreate {
+        customer_id: path.into_inner(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            communication::create_customer_communication(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::CustomerWrite,
+                minimum_entity_level: EntityType::Merchant,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", not(feature = "customer_v2")))]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersCommunicationList))]
+pub async fn customers_communications_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::CustomerId>,
+    query: web::Query<customers::CustomerCommunicationListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::CustomersCommunicationList;
+    let payload = customers::CustomerCommunicationList {
+        customer_id: path.into_inner(),
+        constraints: query.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            communication::list_customer_communications(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::CustomerRead,
+                minimum_entity_level: EntityType::Merchant,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -443,6 +443,60 @@ pub async fn retrieve_dispute_evidence(
     .await
 }
 
+/// Disputes - Download Dispute Evidence
+///
+/// To download all the evidence files attached to a dispute as a zip archive
+#[utoipa::path(
+    get,
+    path = "/disputes/evidence/{dispute_id}/download",
+    params(
+        ("dispute_id" = String, Path, description = "The identifier for dispute")
+    ),
+    responses(
+        (status = 200, description = "The dispute evidence archive was created successfully", content_type = "application/zip"),
+        (status = 404, description = "Dispute does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Download Dispute Evidence",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DownloadDisputeEvidence))]
+pub async fn download_dispute_evidence(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::DownloadDisputeEvidence;
+    let dispute_id = dispute_types::DisputeId {
+        dispute_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        dispute_id,
+        |state, auth, req, _| {
+            disputes::download_dispute_evidence(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                auth.profile_id,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::DisputeRead,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Disputes - Delete Evidence attached to a Dispute
 ///
 /// To delete an evidence file attached to a dispute
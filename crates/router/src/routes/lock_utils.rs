@@ -21,6 +21,9 @@ pub enum ApiIdentifier {
     CardsInfo,
     Files,
     Cache,
+    ConnectorConcurrency,
+    ConnectorLatency,
+    Settlements,
     Profile,
     Verification,
     ApiKeys,
@@ -47,6 +50,14 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantsAccountUpdate
             | Flow::MerchantsAccountDelete
             | Flow::MerchantTransferKey
+            | Flow::MerchantOnboardingStatusRetrieve
+            | Flow::MerchantOnboardingStatusUpdate
+            | Flow::AuditLogRetrieve
+            | Flow::ConnectorFailoverChainRetrieve
+            | Flow::LegalHoldCreate
+            | Flow::LegalHoldList
+            | Flow::LegalHoldDelete
+            | Flow::PaymentMethodsRetokenize
             | Flow::MerchantAccountList => Self::MerchantAccount,
 
             Flow::OrganizationCreate | Flow::OrganizationRetrieve | Flow::OrganizationUpdate => {
@@ -57,6 +68,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RoutingLinkConfig
             | Flow::RoutingUnlinkConfig
             | Flow::RoutingRetrieveConfig
+            | Flow::RoutingRetrieveEffectiveWeights
             | Flow::RoutingRetrieveActiveConfig
             | Flow::RoutingRetrieveDefaultConfig
             | Flow::RoutingRetrieveDictionary
@@ -80,7 +92,10 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantConnectorsRetrieve
             | Flow::MerchantConnectorsUpdate
             | Flow::MerchantConnectorsDelete
-            | Flow::MerchantConnectorsList => Self::MerchantConnector,
+            | Flow::MerchantConnectorsList
+            | Flow::MerchantConnectorsWebhookIdentifierRotate
+            | Flow::MerchantConnectorsCredentialsRotate
+            | Flow::MerchantConnectorsCredentialsPromote => Self::MerchantConnector,
 
             Flow::ConfigKeyCreate
             | Flow::ConfigKeyFetch
@@ -93,7 +108,11 @@ impl From<Flow> for ApiIdentifier {
             | Flow::CustomersUpdate
             | Flow::CustomersDelete
             | Flow::CustomersGetMandates
-            | Flow::CustomersList => Self::Customers,
+            | Flow::CustomersList
+            | Flow::CustomersConnectorSyncRetry
+            | Flow::CustomersVelocityCountersRetrieve
+            | Flow::CustomersCommunicationCreate
+            | Flow::CustomersCommunicationList => Self::Customers,
 
             Flow::EphemeralKeyCreate | Flow::EphemeralKeyDelete => Self::Ephemeral,
 
@@ -103,6 +122,7 @@ impl From<Flow> for ApiIdentifier {
             Flow::PaymentMethodsCreate
             | Flow::PaymentMethodsMigrate
             | Flow::PaymentMethodsList
+            | Flow::PaymentMethodsRecommend
             | Flow::CustomerPaymentMethodsList
             | Flow::PaymentMethodsRetrieve
             | Flow::PaymentMethodsUpdate
@@ -111,7 +131,10 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ValidatePaymentMethod
             | Flow::ListCountriesCurrencies
             | Flow::DefaultPaymentMethodsSet
-            | Flow::PaymentMethodSave => Self::PaymentMethods,
+            | Flow::PaymentMethodSave
+            | Flow::BankAccountTokenize
+            | Flow::BankAccountTokenRetrieve
+            | Flow::BankAccountVerificationUpdate => Self::PaymentMethods,
 
             Flow::PmAuthLinkTokenCreate | Flow::PmAuthExchangeToken => Self::PaymentMethodAuth,
 
@@ -122,13 +145,24 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsConfirm
             | Flow::PaymentsCapture
             | Flow::PaymentsCancel
+            | Flow::PaymentsCaptureVoid
+            | Flow::PaymentsResume
+            | Flow::PaymentsReceiptRetrieve
+            | Flow::PaymentsVoidEligibilityCheck
+            | Flow::PaymentsTimelineRetrieve
+            | Flow::PaymentsSimulateWebhook
+            | Flow::PaymentMethodsListForSession
             | Flow::PaymentsApprove
             | Flow::PaymentsReject
             | Flow::PaymentsSessionToken
             | Flow::PaymentsStart
             | Flow::PaymentsList
+            | Flow::PaymentsPendingReviewList
             | Flow::PaymentsFilters
             | Flow::PaymentsAggregate
+            | Flow::PaymentsDistinctCardCount
+            | Flow::PaymentsSettlementAmounts
+            | Flow::PaymentsRelatedList
             | Flow::PaymentsRedirect
             | Flow::PaymentsIncrementalAuthorization
             | Flow::PaymentsExternalAuthentication
@@ -136,6 +170,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::GetExtendedCardInfo
             | Flow::PaymentsCompleteAuthorize
             | Flow::PaymentsManualUpdate
+            | Flow::PaymentsFulfillmentUpdate
             | Flow::SessionUpdateTaxCalculation => Self::Payments,
 
             Flow::PayoutsCreate
@@ -145,6 +180,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PayoutsFulfill
             | Flow::PayoutsList
             | Flow::PayoutsFilter
+            | Flow::PayoutsReturns
             | Flow::PayoutsAccounts
             | Flow::PayoutsConfirm
             | Flow::PayoutLinkInitiate => Self::Payouts,
@@ -156,13 +192,15 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RefundsList
             | Flow::RefundsFilters
             | Flow::RefundsAggregate
+            | Flow::RefundsEligibilityCheck
             | Flow::RefundsManualUpdate => Self::Refunds,
 
             Flow::FrmFulfillment
             | Flow::IncomingWebhookReceive
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
-            | Flow::WebhookEventDeliveryRetry => Self::Webhooks,
+            | Flow::WebhookEventDeliveryRetry
+            | Flow::WebhookEventDeliveryQueueRetrieve => Self::Webhooks,
 
             Flow::ApiKeyCreate
             | Flow::ApiKeyRetrieve
@@ -176,6 +214,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::DisputesEvidenceSubmit
             | Flow::AttachDisputeEvidence
             | Flow::RetrieveDisputeEvidence
+            | Flow::DownloadDisputeEvidence
             | Flow::DisputesAggregate
             | Flow::DeleteDisputeEvidence => Self::Disputes,
 
@@ -185,6 +224,14 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::CacheInvalidate => Self::Cache,
 
+            Flow::ConnectorConcurrencyRetrieve => Self::ConnectorConcurrency,
+
+            Flow::ConnectorLatencyRetrieve => Self::ConnectorLatency,
+
+            Flow::SettlementFileIngest | Flow::SettlementLineItemsUnmatchedList => {
+                Self::Settlements
+            }
+
             Flow::ProfileCreate
             | Flow::ProfileUpdate
             | Flow::ProfileRetrieve
@@ -217,6 +264,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::SetDashboardMetadata
             | Flow::GetMultipleDashboardMetadata
             | Flow::VerifyPaymentConnector
+            | Flow::TestConnectorTokenization
             | Flow::InternalUserSignup
             | Flow::SwitchOrg
             | Flow::SwitchMerchantV2
@@ -101,12 +101,17 @@ counter_metric!(APPLE_PAY_SIMPLIFIED_FLOW_FAILED_PAYMENT, GLOBAL_METER);
 
 // Metrics for Payment Auto Retries
 counter_metric!(AUTO_RETRY_CONNECTION_CLOSED, GLOBAL_METER);
+counter_metric!(CONNECTOR_NETWORK_RETRY_COUNT, GLOBAL_METER);
+counter_metric!(CONNECTOR_NETWORK_RETRY_EXHAUSTED_COUNT, GLOBAL_METER);
+counter_metric!(CONNECTOR_RATE_LIMITED_COUNT, GLOBAL_METER);
+counter_metric!(CONNECTOR_RATE_LIMIT_DEFERRAL_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_ELIGIBLE_REQUEST_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_GSM_MISS_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_GSM_FETCH_FAILURE_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_GSM_MATCH_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_EXHAUSTED_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_PAYMENT_COUNT, GLOBAL_METER);
+counter_metric!(AUTO_RETRY_STEP_UP_COUNT, GLOBAL_METER);
 
 // Metrics for Payout Auto Retries
 counter_metric!(AUTO_PAYOUT_RETRY_ELIGIBLE_REQUEST_COUNT, GLOBAL_METER);
@@ -120,6 +125,8 @@ counter_metric!(AUTO_RETRY_PAYOUT_COUNT, GLOBAL_METER);
 counter_metric!(TASKS_ADDED_COUNT, GLOBAL_METER); // Tasks added to process tracker
 counter_metric!(TASK_ADDITION_FAILURES_COUNT, GLOBAL_METER); // Failures in task addition to process tracker
 counter_metric!(TASKS_RESET_COUNT, GLOBAL_METER); // Tasks reset in process tracker for requeue flow
+counter_metric!(DATA_RETENTION_RECORDS_PURGED_COUNT, GLOBAL_METER); // Records purged past their retention period
+counter_metric!(REVERSE_LOOKUP_MISMATCHES, GLOBAL_METER); // Reverse lookup mismatches found by the reconciliation
 
 // Access token metrics
 //
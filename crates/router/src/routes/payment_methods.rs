@@ -411,6 +411,135 @@ pub async fn list_payment_method_api(
     .await
 }
 
+#[cfg(all(
+    any(feature = "v2", feature = "v1"),
+    not(feature = "payment_methods_v2"),
+    not(feature = "customer_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsRecommend))]
+pub async fn recommend_payment_methods_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payment_methods::PaymentMethodRecommendationRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodsRecommend;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            payment_methods_routes::recommendation::recommend_payment_methods(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                auth.profile_id,
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(
+    any(feature = "v2", feature = "v1"),
+    not(feature = "payment_methods_v2"),
+    not(feature = "customer_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::BankAccountTokenize))]
+pub async fn tokenize_bank_account_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payment_methods::BankAccountTokenizeRequest>,
+) -> HttpResponse {
+    let flow = Flow::BankAccountTokenize;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            payment_methods_routes::bank_account_tokenization::tokenize_bank_account(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(
+    any(feature = "v2", feature = "v1"),
+    not(feature = "payment_methods_v2"),
+    not(feature = "customer_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::BankAccountTokenRetrieve))]
+pub async fn retrieve_bank_account_token_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::BankAccountTokenRetrieve;
+    let payload = payment_methods::BankAccountTokenId {
+        token_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payment_methods_routes::bank_account_tokenization::retrieve_bank_account_token(
+                state,
+                auth.merchant_account,
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(
+    any(feature = "v2", feature = "v1"),
+    not(feature = "payment_methods_v2"),
+    not(feature = "customer_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::BankAccountVerificationUpdate))]
+pub async fn update_bank_account_verification_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<payment_methods::BankAccountVerificationRequest>,
+) -> HttpResponse {
+    let flow = Flow::BankAccountVerificationUpdate;
+    let mut payload = json_payload.into_inner();
+    payload.token_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payment_methods_routes::bank_account_tokenization::update_bank_account_verification(
+                state,
+                auth.merchant_account,
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(
     any(feature = "v2", feature = "v1"),
     not(feature = "payment_methods_v2"),
@@ -801,11 +930,13 @@ pub async fn payment_method_delete_api(
     state: web::Data<AppState>,
     req: HttpRequest,
     payment_method_id: web::Path<(String,)>,
+    query_payload: web::Query<payment_methods::PaymentMethodDeleteRequest>,
 ) -> HttpResponse {
     let flow = Flow::PaymentMethodsDelete;
     let pm = PaymentMethodId {
         payment_method_id: payment_method_id.into_inner().0,
     };
+    let revoke_connector_tokens = query_payload.into_inner().revoke_connector_tokens;
     let ephemeral_auth = match auth::is_ephemeral_auth(req.headers()) {
         Ok(auth) => auth,
         Err(err) => return api::log_and_return_error_response(err),
@@ -817,7 +948,15 @@ pub async fn payment_method_delete_api(
         &req,
         pm,
         |state, auth, req, _| {
-            cards::delete_payment_method(state, auth.merchant_account, req, auth.key_store)
+            cards::delete_payment_method(
+                state,
+                auth.merchant_account,
+                req,
+                auth.key_store,
+                payment_methods::PaymentMethodDeleteRequest {
+                    revoke_connector_tokens,
+                },
+            )
         },
         &*ephemeral_auth,
         api_locking::LockAction::NotApplicable,
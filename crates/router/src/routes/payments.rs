@@ -76,8 +76,7 @@ pub async fn payments_create(
         &req,
         payload,
         |state, auth, req, req_state| {
-            authorize_verify_select::<_>(
-                payments::PaymentCreate,
+            payments::merchant_order_reference::payments_create_for_merchant_order_reference(
                 state,
                 req_state,
                 auth.merchant_account,
@@ -85,7 +84,19 @@ pub async fn payments_create(
                 auth.key_store,
                 header_payload.clone(),
                 req,
-                api::AuthFlow::Merchant,
+                |state, req_state, merchant_account, profile_id, key_store, header_payload, req| {
+                    authorize_verify_select::<_>(
+                        payments::PaymentCreate,
+                        state,
+                        req_state,
+                        merchant_account,
+                        profile_id,
+                        key_store,
+                        header_payload,
+                        req,
+                        api::AuthFlow::Merchant,
+                    )
+                },
             )
         },
         match env::which() {
@@ -200,32 +211,59 @@ pub async fn payments_retrieve(
 
     let locking_action = payload.get_locking_input(flow.clone());
 
+    let fields = match json_payload
+        .fields
+        .as_deref()
+        .map(str::parse::<payment_types::PaymentsResponseFields>)
+        .transpose()
+    {
+        Ok(fields) => fields,
+        Err(error) => {
+            return api::log_and_return_error_response(report!(
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: error.to_string(),
+                }
+            ));
+        }
+    };
+
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
         payload,
         |state, auth, req, req_state| {
-            payments::payments_core::<
-                api_types::PSync,
-                payment_types::PaymentsResponse,
-                _,
-                _,
-                _,
-                payments::PaymentData<api_types::PSync>,
-            >(
-                state,
-                req_state,
-                auth.merchant_account,
-                auth.profile_id,
-                auth.key_store,
-                payments::PaymentStatus,
-                req,
-                auth_flow,
-                payments::CallConnectorAction::Trigger,
-                None,
-                header_payload.clone(),
-            )
+            let header_payload = header_payload.clone();
+            let fields = fields.clone();
+            async move {
+                payments::payments_core::<
+                    api_types::PSync,
+                    payment_types::PaymentsResponse,
+                    _,
+                    _,
+                    _,
+                    payments::PaymentData<api_types::PSync>,
+                >(
+                    state,
+                    req_state,
+                    auth.merchant_account,
+                    auth.profile_id,
+                    auth.key_store,
+                    payments::PaymentStatus,
+                    req,
+                    auth_flow,
+                    payments::CallConnectorAction::Trigger,
+                    None,
+                    header_payload,
+                )
+                .await
+                .map(|response| {
+                    response.map_json_body(|response| payment_types::SparsePaymentsResponse {
+                        response,
+                        fields,
+                    })
+                })
+            }
         },
         auth::auth_type(
             &*auth_type,
@@ -852,6 +890,290 @@ pub async fn payments_cancel(
     .await
 }
 
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCaptureVoid, payment_id))]
+pub async fn payments_void_capture(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<(common_utils::id_type::PaymentId, String)>,
+) -> impl Responder {
+    let flow = Flow::PaymentsCaptureVoid;
+    let (payment_id, capture_id) = path.into_inner();
+
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    let payload = payment_types::PaymentsCaptureVoidRequest {
+        payment_id,
+        capture_id,
+    };
+    let locking_action = payload.get_locking_input(flow.clone());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            payments::payments_void_capture(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        locking_action,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsResume))]
+pub async fn payments_resume(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsResumeRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentsResume;
+    let payload = json_payload.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, req_state| {
+            payments::checkout_resume::resume_payment(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::PublishableKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsReceiptRetrieve, payment_id))]
+pub async fn payments_receipt_retrieve(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsReceiptRetrieve;
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_id,
+        |state, auth: auth::AuthenticationData, payment_id, _| {
+            payments::receipts::retrieve_payment_receipt(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                payment_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::PaymentRead,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsVoidEligibilityCheck, payment_id))]
+pub async fn payments_void_eligibility_check(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsVoidEligibilityCheck;
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_id,
+        |state, auth: auth::AuthenticationData, payment_id, _| {
+            payments::void_eligibility::check_void_eligibility(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                payment_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::PaymentRead,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsTimelineRetrieve, payment_id))]
+pub async fn payments_timeline_retrieve(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsTimelineRetrieve;
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_id,
+        |state, auth: auth::AuthenticationData, payment_id, _| {
+            payments::timeline::get_payment_timeline(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                payment_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::PaymentRead,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsReceiptRetrieve))]
+pub async fn payments_receipt_retrieve_by_token(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentsReceiptRetrieve;
+    let receipt_token = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        receipt_token,
+        |state, auth, receipt_token, _| {
+            payments::receipts::retrieve_payment_receipt_by_token(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                receipt_token,
+            )
+        },
+        &auth::HeaderAuth(auth::PublishableKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(
+    feature = "oltp",
+    feature = "v1",
+    not(feature = "customer_v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsListForSession))]
+pub async fn payments_list_payment_methods_for_session(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<api_types::payment_methods::PaymentMethodsListForSessionRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentMethodsListForSession;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            crate::core::payment_methods::session_payment_methods::list_payment_methods_for_session(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                auth.profile_id,
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsSimulateWebhook, payment_id))]
+pub async fn payments_simulate_webhook(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::SimulateWebhookRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsSimulateWebhook;
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    payload.payment_id = payment_id;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            payments::webhook_simulation::simulate_payment_webhook(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::PaymentWrite,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::PaymentsList))]
 #[cfg(all(feature = "olap", feature = "v1"))]
 pub async fn payments_list(
@@ -1086,6 +1408,132 @@ pub async fn get_payments_aggregates(
     .await
 }
 
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsDistinctCardCount))]
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn get_payments_distinct_card_count(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    payload: web::Query<common_utils::types::TimeRange>,
+) -> impl Responder {
+    let flow = Flow::PaymentsDistinctCardCount;
+    let payload = payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payments::get_distinct_card_count_for_payments(state, auth.merchant_account, req)
+        },
+        &auth::JWTAuth {
+            permission: Permission::PaymentRead,
+            minimum_entity_level: EntityType::Merchant,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsSettlementAmounts))]
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn get_payments_settlement_amounts(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    payload: web::Query<payment_types::PaymentsSettlementAmountsRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentsSettlementAmounts;
+    let payload = payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payments::get_settlement_amounts_for_payments(state, auth.merchant_account, req)
+        },
+        &auth::JWTAuth {
+            permission: Permission::PaymentRead,
+            minimum_entity_level: EntityType::Merchant,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsPendingReviewList))]
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn payments_list_pending_review(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    payload: web::Query<payment_types::PaymentsPendingReviewListConstraints>,
+) -> impl Responder {
+    let flow = Flow::PaymentsPendingReviewList;
+    let payload = payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            crate::core::fraud_check::review::list_payments_pending_review(
+                state,
+                auth.merchant_account,
+                auth.profile_id.map(|profile_id| vec![profile_id]),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::PaymentRead,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsRelatedList, payment_id))]
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn payments_list_related(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsRelatedList;
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_id,
+        |state, auth: auth::AuthenticationData, payment_id, _| {
+            payments::list_related_payments(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                payment_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::PaymentRead,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(feature = "oltp", feature = "v1"))]
 #[instrument(skip_all, fields(flow = ?Flow::PaymentsApprove, payment_id))]
 pub async fn payments_approve(
@@ -1110,28 +1558,13 @@ pub async fn payments_approve(
         &http_req,
         payload.clone(),
         |state, auth, req, req_state| {
-            payments::payments_core::<
-                api_types::Capture,
-                payment_types::PaymentsResponse,
-                _,
-                _,
-                _,
-                payments::PaymentData<api_types::Capture>,
-            >(
+            crate::core::fraud_check::review::approve_reviewed_payment(
                 state,
                 req_state,
                 auth.merchant_account,
                 auth.profile_id,
                 auth.key_store,
-                payments::PaymentApprove,
-                payment_types::PaymentsCaptureRequest {
-                    payment_id: req.payment_id,
-                    ..Default::default()
-                },
-                api::AuthFlow::Merchant,
-                payments::CallConnectorAction::Trigger,
-                None,
-                HeaderPayload::default(),
+                req,
             )
         },
         match env::which() {
@@ -1174,29 +1607,13 @@ pub async fn payments_reject(
         &http_req,
         payload.clone(),
         |state, auth, req, req_state| {
-            payments::payments_core::<
-                api_types::Void,
-                payment_types::PaymentsResponse,
-                _,
-                _,
-                _,
-                payments::PaymentData<api_types::Void>,
-            >(
+            crate::core::fraud_check::review::reject_reviewed_payment(
                 state,
                 req_state,
                 auth.merchant_account,
                 auth.profile_id,
                 auth.key_store,
-                payments::PaymentReject,
-                payment_types::PaymentsCancelRequest {
-                    payment_id: req.payment_id,
-                    cancellation_reason: Some("Rejected by merchant".to_string()),
-                    ..Default::default()
-                },
-                api::AuthFlow::Merchant,
-                payments::CallConnectorAction::Trigger,
-                None,
-                HeaderPayload::default(),
+                req,
             )
         },
         match env::which() {
@@ -1464,6 +1881,50 @@ pub async fn payments_manual_update(
     .await
 }
 
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsFulfillmentUpdate, payment_id))]
+pub async fn payments_fulfillment_update(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsFulfillmentRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsFulfillmentUpdate;
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    payload.payment_id = payment_id;
+    let locking_action = payload.get_locking_input(flow.clone());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _req_state| {
+            payments::payments_fulfillment_update(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::PaymentWrite,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        locking_action,
+    ))
+    .await
+}
+
 #[cfg(feature = "v1")]
 /// Retrieve endpoint for merchant to fetch the encrypted customer payment method data
 #[instrument(skip_all, fields(flow = ?Flow::GetExtendedCardInfo, payment_id))]
@@ -1684,6 +2145,23 @@ impl GetLockingInput for payment_types::PaymentsCaptureRequest {
     }
 }
 
+#[cfg(feature = "v1")]
+impl GetLockingInput for payment_types::PaymentsCaptureVoidRequest {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: self.payment_id.get_string_repr().to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::from(flow),
+                override_lock_retries: None,
+            },
+        }
+    }
+}
+
 #[cfg(feature = "oltp")]
 struct FPaymentsApproveRequest<'a>(&'a payment_types::PaymentsApproveRequest);
 
@@ -1758,6 +2236,23 @@ impl GetLockingInput for payment_types::PaymentsExternalAuthenticationRequest {
     }
 }
 
+#[cfg(feature = "v1")]
+impl GetLockingInput for payment_types::PaymentsFulfillmentRequest {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: self.payment_id.get_string_repr().to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::from(flow),
+                override_lock_retries: None,
+            },
+        }
+    }
+}
+
 #[cfg(feature = "v1")]
 impl GetLockingInput for payment_types::PaymentsManualUpdateRequest {
     fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
@@ -207,6 +207,37 @@ pub async fn payouts_fulfill(
     .await
 }
 
+/// Payouts - Returns
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsReturns))]
+pub async fn payouts_returns(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutReturnsRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsReturns;
+    let payload = json_payload.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            returns::payouts_returns_core(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::PayoutWrite,
+                minimum_entity_level: EntityType::Merchant,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Payouts - List
 #[cfg(feature = "olap")]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsList))]
@@ -210,6 +210,44 @@ pub async fn refunds_update(
     ))
     .await
 }
+/// Refunds - Eligibility Check
+///
+/// To check whether a refund can be initiated against a payment, without initiating it
+#[instrument(skip_all, fields(flow = ?Flow::RefundsEligibilityCheck))]
+// #[post("/eligibility")]
+pub async fn refunds_eligibility_check(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<refunds::RefundEligibilityRequest>,
+) -> HttpResponse {
+    let flow = Flow::RefundsEligibilityCheck;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            check_refund_eligibility(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::RefundRead,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Refunds - List
 ///
 /// To list the refunds associated with a payment_id or with the merchant, if payment_id is not provided
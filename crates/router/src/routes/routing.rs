@@ -30,7 +30,7 @@ pub async fn routing_create_config(
         state,
         &req,
         json_payload.into_inner(),
-        |state, auth: auth::AuthenticationData, payload, _| {
+        |state, auth: auth::AuthenticationData, payload, req_state| {
             routing::create_routing_algorithm_under_profile(
                 state,
                 auth.merchant_account,
@@ -38,6 +38,7 @@ pub async fn routing_create_config(
                 auth.profile_id,
                 payload,
                 transaction_type,
+                req_state,
             )
         },
         #[cfg(not(feature = "release"))]
@@ -73,7 +74,7 @@ pub async fn routing_link_config(
         state,
         &req,
         path.into_inner(),
-        |state, auth: auth::AuthenticationData, algorithm, _| {
+        |state, auth: auth::AuthenticationData, algorithm, req_state| {
             routing::link_routing_config(
                 state,
                 auth.merchant_account,
@@ -81,6 +82,7 @@ pub async fn routing_link_config(
                 auth.profile_id,
                 algorithm,
                 transaction_type,
+                req_state,
             )
         },
         #[cfg(not(feature = "release"))]
@@ -195,6 +197,48 @@ pub async fn routing_retrieve_config(
     .await
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_retrieve_effective_weights(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::RoutingId>,
+) -> impl Responder {
+    let algorithm_id = path.into_inner();
+    let flow = Flow::RoutingRetrieveEffectiveWeights;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        algorithm_id,
+        |state, auth: auth::AuthenticationData, algorithm_id, _| {
+            routing::retrieve_routing_algorithm_effective_weights(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                auth.profile_id,
+                algorithm_id,
+            )
+        },
+        #[cfg(not(feature = "release"))]
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::RoutingRead,
+                minimum_entity_level: EntityType::Profile,
+            },
+            req.headers(),
+        ),
+        #[cfg(feature = "release")]
+        &auth::JWTAuth {
+            permission: Permission::RoutingRead,
+            minimum_entity_level: EntityType::Profile,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "olap")]
 #[instrument(skip_all)]
 pub async fn list_routing_configs(
@@ -338,7 +382,7 @@ pub async fn routing_unlink_config(
         state,
         &req,
         payload.into_inner(),
-        |state, auth: auth::AuthenticationData, payload_req, _| {
+        |state, auth: auth::AuthenticationData, payload_req, req_state| {
             routing::unlink_routing_config(
                 state,
                 auth.merchant_account,
@@ -346,6 +390,7 @@ pub async fn routing_unlink_config(
                 payload_req,
                 auth.profile_id,
                 transaction_type,
+                req_state,
             )
         },
         #[cfg(not(feature = "release"))]
@@ -425,12 +470,13 @@ pub async fn routing_update_default_config(
         state,
         &req,
         json_payload.into_inner(),
-        |state, auth: auth::AuthenticationData, updated_config, _| {
+        |state, auth: auth::AuthenticationData, updated_config, req_state| {
             routing::update_default_routing_config(
                 state,
                 auth.merchant_account,
                 updated_config,
                 transaction_type,
+                req_state,
             )
         },
         #[cfg(not(feature = "release"))]
@@ -948,7 +994,7 @@ pub async fn routing_update_default_config_for_profile(
         state,
         &req,
         routing_payload_wrapper.clone(),
-        |state, auth: auth::AuthenticationData, wrapper, _| {
+        |state, auth: auth::AuthenticationData, wrapper, req_state| {
             routing::update_default_routing_config_for_profile(
                 state,
                 auth.merchant_account,
@@ -956,6 +1002,7 @@ pub async fn routing_update_default_config_for_profile(
                 wrapper.updated_config,
                 wrapper.profile_id,
                 transaction_type,
+                req_state,
             )
         },
         #[cfg(not(feature = "release"))]
@@ -1000,13 +1047,14 @@ pub async fn toggle_success_based_routing(
         |state,
          auth: auth::AuthenticationData,
          wrapper: routing_types::ToggleSuccessBasedRoutingWrapper,
-         _| {
+         req_state| {
             routing::toggle_success_based_routing(
                 state,
                 auth.merchant_account,
                 auth.key_store,
                 wrapper.status,
                 wrapper.profile_id,
+                req_state,
             )
         },
         auth::auth_type(
@@ -1042,12 +1090,13 @@ pub async fn success_based_routing_update_configs(
         state,
         &req,
         routing_payload_wrapper,
-        |state, _, wrapper: routing_types::SuccessBasedRoutingPayloadWrapper, _| async {
+        |state, _, wrapper: routing_types::SuccessBasedRoutingPayloadWrapper, req_state| async {
             Box::pin(routing::success_based_routing_update_configs(
                 state,
                 wrapper.updated_config,
                 wrapper.algorithm_id,
                 wrapper.profile_id,
+                req_state,
             ))
             .await
         },
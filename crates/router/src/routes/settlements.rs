@@ -0,0 +1,74 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::settlements as settlements_api;
+use common_enums::EntityType;
+use router_env::{instrument, tracing, Flow};
+
+use super::AppState;
+use crate::{
+    core::{api_locking, settlements},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+/// Settlements - Ingest
+///
+/// Reconcile the line items of a settlement file received from a connector against the payments
+/// and refunds processed through the connector
+#[instrument(skip_all, fields(flow = ?Flow::SettlementFileIngest))]
+pub async fn ingest_settlement_file(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<settlements_api::SettlementFileIngestRequest>,
+) -> impl Responder {
+    let flow = Flow::SettlementFileIngest;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            settlements::ingest_settlement_file(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::PaymentWrite,
+                minimum_entity_level: EntityType::Merchant,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Settlements - List Unmatched
+///
+/// List the settlement line items which need manual review, either because they do not match our
+/// record of the transaction or because we have no record of the transaction
+#[instrument(skip_all, fields(flow = ?Flow::SettlementLineItemsUnmatchedList))]
+pub async fn list_unmatched_settlement_line_items(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<settlements_api::SettlementLineItemsUnmatchedListRequest>,
+) -> impl Responder {
+    let flow = Flow::SettlementLineItemsUnmatchedList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            settlements::list_unmatched_settlement_line_items(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::PaymentRead,
+                minimum_entity_level: EntityType::Merchant,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -1,5 +1,5 @@
 use actix_web::{web, HttpRequest, HttpResponse};
-use api_models::verify_connector::VerifyConnectorRequest;
+use api_models::verify_connector::{TokenizationTestRequest, VerifyConnectorRequest};
 use common_enums::EntityType;
 use router_env::{instrument, tracing, Flow};
 
@@ -32,3 +32,32 @@ pub async fn payment_connector_verify(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::TestConnectorTokenization))]
+pub async fn payment_connector_tokenization_test(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<TokenizationTestRequest>,
+) -> HttpResponse {
+    let flow = Flow::TestConnectorTokenization;
+    Box::pin(services::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            verify_connector::test_connector_tokenization(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::MerchantConnectorAccountWrite,
+            minimum_entity_level: EntityType::Merchant,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -8,7 +8,7 @@ use crate::{
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::webhook_events::{
         EventListConstraints, EventListRequestInternal, WebhookDeliveryAttemptListRequestInternal,
-        WebhookDeliveryRetryRequestInternal,
+        WebhookDeliveryQueueRequestInternal, WebhookDeliveryRetryRequestInternal,
     },
 };
 
@@ -133,3 +133,38 @@ pub async fn retry_webhook_delivery_attempt(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventDeliveryQueueRetrieve))]
+pub async fn retrieve_webhook_delivery_queue(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventDeliveryQueueRetrieve;
+    let merchant_id = path.into_inner();
+
+    let request_internal = WebhookDeliveryQueueRequestInternal {
+        merchant_id: merchant_id.clone(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal, _| {
+            webhook_events::retrieve_delivery_queue(state, request_internal.merchant_id)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::WebhookEventRead,
+                minimum_entity_level: EntityType::Merchant,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
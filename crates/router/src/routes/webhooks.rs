@@ -42,3 +42,35 @@ pub async fn receive_incoming_webhook<W: types::OutgoingWebhookType>(
     ))
     .await
 }
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::IncomingWebhookReceive))]
+pub async fn receive_incoming_webhook_by_identifier<W: types::OutgoingWebhookType>(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    body: web::Bytes,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::IncomingWebhookReceive;
+    let webhook_identifier = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow.clone(),
+        state,
+        &req,
+        (),
+        |state, _: (), _, req_state| {
+            webhooks::incoming_webhooks_wrapper_by_identifier::<W>(
+                &flow,
+                state.to_owned(),
+                req_state,
+                &req,
+                &webhook_identifier,
+                body.clone(),
+            )
+        },
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
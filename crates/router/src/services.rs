@@ -1,7 +1,13 @@
 pub mod api;
 pub mod authentication;
 pub mod authorization;
+pub mod connector_concurrency;
+pub mod connector_idempotency;
 pub mod connector_integration_interface;
+pub mod connector_latency;
+pub mod connector_log_redaction;
+pub mod connector_rate_limit;
+pub mod connector_read_cache;
 pub mod conversion_impls;
 #[cfg(feature = "email")]
 pub mod email;
@@ -88,6 +94,8 @@ pub async fn get_store(
         config.drainer.num_partitions,
         config.kv_config.ttl,
         config.kv_config.soft_kill,
+        config.kv_config.drainer_push_retry_count,
+        config.kv_config.reverse_lookup_read_repair,
     );
 
     Ok(store)
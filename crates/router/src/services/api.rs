@@ -53,7 +53,7 @@ use tera::{Context, Error as TeraError, Tera};
 
 use self::request::{HeaderExt, RequestBuilderExt};
 use super::{
-    authentication::AuthenticateAndFetch,
+    authentication::{AuthenticateAndFetch, GetLockExpiry},
     connector_integration_interface::BoxedConnectorIntegrationInterface,
 };
 use crate::{
@@ -74,7 +74,10 @@ use crate::{
         metrics, AppState, SessionState,
     },
     services::{
+        connector_concurrency, connector_idempotency,
         connector_integration_interface::RouterDataConversion,
+        connector_latency, connector_log_redaction, connector_rate_limit,
+        connector_read_cache::{self, ConnectorReadCache},
         generic_link_response::build_generic_link_html,
     },
     types::{self, api, ErrorResponse},
@@ -106,7 +109,6 @@ pub type BoxedFilesConnectorIntegrationInterface<T, Req, Resp> =
 /// Handle the flow by interacting with connector module
 /// `connector_request` is applicable only in case if the `CallConnectorAction` is `Trigger`
 /// In other cases, It will be created if required, even if it is not passed
-#[instrument(skip_all, fields(connector_name, payment_method))]
 pub async fn execute_connector_processing_step<
     'b,
     'a,
@@ -124,12 +126,52 @@ pub async fn execute_connector_processing_step<
 where
     T: Clone + Debug + 'static,
     // BoxedConnectorIntegration<T, Req, Resp>: 'b,
+{
+    execute_connector_processing_step_with_read_cache(
+        state,
+        connector_integration,
+        req,
+        call_connector_action,
+        connector_request,
+        None,
+    )
+    .await
+}
+
+/// Execute the connector call, serving the response from the connector read cache when one is
+/// provided and a cached response exists for the resource.
+#[instrument(skip_all, fields(connector_name, payment_method))]
+pub async fn execute_connector_processing_step_with_read_cache<
+    'b,
+    'a,
+    T,
+    ResourceCommonData: Clone + RouterDataConversion<T, Req, Resp> + 'static,
+    Req: Debug + Clone + 'static,
+    Resp: Debug + Clone + 'static,
+>(
+    state: &'b SessionState,
+    connector_integration: BoxedConnectorIntegrationInterface<T, ResourceCommonData, Req, Resp>,
+    req: &'b types::RouterData<T, Req, Resp>,
+    call_connector_action: payments::CallConnectorAction,
+    connector_request: Option<Request>,
+    read_cache: Option<ConnectorReadCache<T, Req, Resp>>,
+) -> CustomResult<types::RouterData<T, Req, Resp>, errors::ConnectorError>
+where
+    T: Clone + Debug + 'static,
 {
     // If needed add an error stack as follows
     // connector_integration.build_request(req).attach_printable("Failed to build request");
     tracing::Span::current().record("connector_name", &req.connector);
     tracing::Span::current().record("payment_method", req.payment_method.to_string());
-    logger::debug!(connector_request=?connector_request);
+    logger::debug!(
+        connector_request_url=?connector_request.as_ref().map(|request| &request.url),
+        connector_request_body=?connector_request.as_ref().map(|request| {
+            state
+                .conf
+                .connector_log_redaction
+                .get_redacted_request_body(request.body.as_ref())
+        })
+    );
     let mut router_data = req.clone();
     match call_connector_action {
         payments::CallConnectorAction::HandleResponse(res) => {
@@ -155,6 +197,7 @@ where
                     reason: None,
                     attempt_status: None,
                     connector_transaction_id: None,
+                    network_advice_code: None,
                 })
             } else {
                 None
@@ -170,15 +213,13 @@ where
                     ("connector", req.connector.to_string()),
                     (
                         "flow",
-                        std::any::type_name::<T>()
-                            .split("::")
-                            .last()
-                            .unwrap_or_default()
-                            .to_string(),
+                        connector_idempotency::get_flow_name::<T>().to_string(),
                     ),
                 ]),
             );
 
+            // A request built by the caller cannot be built again to retry a rate limited call
+            let is_request_rebuildable = connector_request.is_none();
             let connector_request = match connector_request {
                 Some(connector_request) => Some(connector_request),
                 None => connector_integration
@@ -196,29 +237,60 @@ where
                             )
                         }
                     })?,
-            };
+            }
+            .map(|request| connector_idempotency::add_idempotency_key_header(req, request));
 
             match connector_request {
                 Some(request) => {
-                    let masked_request_body = match &request.body {
-                        Some(request) => match request {
-                            RequestContent::Json(i)
-                            | RequestContent::FormUrlEncoded(i)
-                            | RequestContent::Xml(i) => i
-                                .masked_serialize()
-                                .unwrap_or(json!({ "error": "failed to mask serialize"})),
-                            RequestContent::FormData(_) => json!({"request_type": "FORM_DATA"}),
-                            RequestContent::RawBytes(_) => json!({"request_type": "RAW_BYTES"}),
-                        },
-                        None => serde_json::Value::Null,
-                    };
+                    let masked_request_body = state
+                        .conf
+                        .connector_log_redaction
+                        .get_redacted_request_body(request.body.as_ref());
                     let request_url = request.url.clone();
                     let request_method = request.method;
                     let current_time = Instant::now();
-                    let response =
-                        call_connector_api(state, request, "execute_connector_processing_step")
-                            .await;
-                    let external_latency = current_time.elapsed().as_millis();
+                    let cached_response = match &read_cache {
+                        Some(read_cache) => {
+                            connector_read_cache::get_cached_connector_response(
+                                state,
+                                &read_cache.key,
+                            )
+                            .await
+                        }
+                        None => None,
+                    };
+                    let is_cached_response = cached_response.is_some();
+                    let mut rate_limit_deferral =
+                        connector_rate_limit::RateLimitDeferral::default();
+                    let mut connector_network_retry_count = None;
+                    let response = match cached_response {
+                        Some(cached_response) => Ok(Ok(cached_response)),
+                        None => {
+                            call_connector_with_rate_limit(
+                                state,
+                                &connector_integration,
+                                req,
+                                request,
+                                is_request_rebuildable,
+                                &mut rate_limit_deferral,
+                                &mut connector_network_retry_count,
+                            )
+                            .await?
+                        }
+                    };
+                    // The time for which the call was deferred is not part of the latency of the
+                    // connector
+                    let external_latency = current_time
+                        .elapsed()
+                        .saturating_sub(rate_limit_deferral.deferred_for)
+                        .as_millis();
+                    if !is_cached_response {
+                        connector_latency::record_connector_latency(
+                            state,
+                            &req.connector,
+                            external_latency,
+                        );
+                    }
                     logger::info!(raw_connector_request=?masked_request_body);
                     let status_code = response
                         .as_ref()
@@ -241,12 +313,27 @@ where
                         req.dispute_id.clone(),
                         status_code,
                     );
+                    if let Some(network_retry_count) = connector_network_retry_count {
+                        connector_event.set_network_retry_count(network_retry_count);
+                    }
+                    if rate_limit_deferral.is_deferred() {
+                        connector_event.set_rate_limit_deferral(
+                            rate_limit_deferral.retry_count,
+                            rate_limit_deferral.deferred_for.as_millis(),
+                        );
+                    }
 
                     match response {
                         Ok(body) => {
                             let response = match body {
                                 Ok(body) => {
                                     let connector_http_status_code = Some(body.status_code);
+                                    let response_to_cache = read_cache
+                                        .as_ref()
+                                        .filter(|_| !is_cached_response)
+                                        .map(|read_cache| {
+                                            (read_cache, body.status_code, body.response.clone())
+                                        });
                                     let handle_response_result = connector_integration
                                         .handle_response(req, Some(&mut connector_event), body)
                                         .inspect_err(|error| {
@@ -265,9 +352,27 @@ where
                                         });
                                     match handle_response_result {
                                         Ok(mut data) => {
-                                            state.event_handler().log_event(&connector_event);
+                                            connector_log_redaction::log_connector_event(
+                                                state,
+                                                &mut connector_event,
+                                            );
+                                            if let Some((read_cache, status_code, body)) =
+                                                response_to_cache
+                                            {
+                                                if (read_cache.is_cacheable)(&data) {
+                                                    connector_read_cache::cache_connector_response(
+                                                        state,
+                                                        &read_cache.key,
+                                                        status_code,
+                                                        &body,
+                                                    )
+                                                    .await;
+                                                }
+                                            }
                                             data.connector_http_status_code =
                                                 connector_http_status_code;
+                                            data.connector_network_retry_count =
+                                                connector_network_retry_count;
                                             // Add up multiple external latencies in case of multiple external calls within the same request.
                                             data.external_latency = Some(
                                                 data.external_latency
@@ -281,13 +386,18 @@ where
                                             connector_event
                                                 .set_error(json!({"error": err.to_string()}));
 
-                                            state.event_handler().log_event(&connector_event);
+                                            connector_log_redaction::log_connector_event(
+                                                state,
+                                                &mut connector_event,
+                                            );
                                             Err(err)
                                         }
                                     }?
                                 }
                                 Err(body) => {
                                     router_data.connector_http_status_code = Some(body.status_code);
+                                    router_data.connector_network_retry_count =
+                                        connector_network_retry_count;
                                     router_data.external_latency = Some(
                                         router_data
                                             .external_latency
@@ -306,7 +416,10 @@ where
                                                     body,
                                                     Some(&mut connector_event),
                                                 )?;
-                                            state.event_handler().log_event(&connector_event);
+                                            connector_log_redaction::log_connector_event(
+                                                state,
+                                                &mut connector_event,
+                                            );
                                             error_res
                                         }
                                         _ => {
@@ -318,7 +431,10 @@ where
                                             if let Some(status) = error_res.attempt_status {
                                                 router_data.status = status;
                                             };
-                                            state.event_handler().log_event(&connector_event);
+                                            connector_log_redaction::log_connector_event(
+                                                state,
+                                                &mut connector_event,
+                                            );
                                             error_res
                                         }
                                     };
@@ -332,15 +448,26 @@ where
                         }
                         Err(error) => {
                             connector_event.set_error(json!({"error": error.to_string()}));
-                            state.event_handler().log_event(&connector_event);
+                            connector_log_redaction::log_connector_event(
+                                state,
+                                &mut connector_event,
+                            );
                             if error.current_context().is_upstream_timeout() {
+                                let timeout_error = error.change_context(
+                                    errors::ConnectorError::ConnectorTimeout {
+                                        connector: req.connector.clone(),
+                                        elapsed_time_ms: external_latency,
+                                    },
+                                );
+                                logger::error!(?timeout_error);
                                 let error_response = ErrorResponse {
                                     code: consts::REQUEST_TIMEOUT_ERROR_CODE.to_string(),
                                     message: consts::REQUEST_TIMEOUT_ERROR_MESSAGE.to_string(),
-                                    reason: Some(consts::REQUEST_TIMEOUT_ERROR_MESSAGE.to_string()),
+                                    reason: Some(timeout_error.current_context().to_string()),
                                     status_code: 504,
                                     attempt_status: None,
                                     connector_transaction_id: None,
+                                    network_advice_code: None,
                                 };
                                 router_data.response = Err(error_response);
                                 router_data.connector_http_status_code = Some(504);
@@ -364,18 +491,143 @@ where
     }
 }
 
+/// Call the connector, deferring the call while the connector is backed off after rate limiting the
+/// calls to it. A call which the connector rate limited is retried once the backoff ends, with the
+/// request built again from the router data, since the request is consumed by the call.
+///
+/// The number of network retries of the last call is recorded in `connector_network_retry_count`.
+#[allow(clippy::too_many_arguments)]
+async fn call_connector_with_rate_limit<T, ResourceCommonData, Req, Resp>(
+    state: &SessionState,
+    connector_integration: &BoxedConnectorIntegrationInterface<T, ResourceCommonData, Req, Resp>,
+    req: &types::RouterData<T, Req, Resp>,
+    request: Request,
+    is_request_rebuildable: bool,
+    rate_limit_deferral: &mut connector_rate_limit::RateLimitDeferral,
+    connector_network_retry_count: &mut Option<u8>,
+) -> CustomResult<
+    CustomResult<Result<types::Response, types::Response>, errors::ApiClientError>,
+    errors::ConnectorError,
+>
+where
+    T: Clone + Debug + 'static,
+    ResourceCommonData: Clone + RouterDataConversion<T, Req, Resp> + 'static,
+    Req: Debug + Clone + 'static,
+    Resp: Debug + Clone + 'static,
+{
+    let mut request = request;
+    let mut rate_limited_backoff = None;
+    loop {
+        connector_rate_limit::defer_while_backed_off(
+            state,
+            &req.connector,
+            rate_limit_deferral,
+            rate_limited_backoff,
+        )
+        .await?;
+
+        // The permit is held only while the call to the connector is in flight, and is released
+        // whatever the outcome of the call, including when the call is cancelled
+        let permit = connector_concurrency::acquire_permit(state, &req.connector).await?;
+        let (response, network_retry_count) = call_connector_api_with_network_retry_count(
+            state,
+            request,
+            "execute_connector_processing_step",
+            req.connector_request_timeout_secs,
+        )
+        .await;
+        *connector_network_retry_count = network_retry_count;
+        if let Some(permit) = permit {
+            permit.release().await;
+        }
+
+        let backoff = match &response {
+            Ok(Err(body)) if body.status_code == 429 => {
+                connector_rate_limit::record_rate_limited_response(state, &req.connector, body)
+                    .await
+            }
+            _ => None,
+        };
+        let Some(backoff) = backoff.filter(|backoff| {
+            is_request_rebuildable
+                && rate_limit_deferral.can_retry(&state.conf.connector_rate_limit, *backoff)
+        }) else {
+            return Ok(response);
+        };
+
+        // A connector does not process a call which it rate limited, so that the call is retried
+        // whether or not it is idempotent
+        let Some(next_request) = connector_integration
+            .build_request(req, &state.conf.connectors)?
+            .map(|request| connector_idempotency::add_idempotency_key_header(req, request))
+        else {
+            return Ok(response);
+        };
+        logger::info!(
+            connector = %req.connector,
+            ?backoff,
+            "Retrying the call rate limited by the connector once the backoff ends"
+        );
+        request = next_request;
+        rate_limited_backoff = Some(backoff);
+        rate_limit_deferral.retry_count += 1;
+    }
+}
+
+/// The number of times a connector request was retried after a network error, which
+/// [`send_request`] records in the extensions of the connector response. The count is kept out of
+/// the headers of the response, where the connector could set it.
+#[derive(Clone, Copy, Debug)]
+struct ConnectorNetworkRetryCount(u8);
+
+/// Get the number of times the connector request was retried after a network error
+fn get_connector_network_retry_count(response: &reqwest::Response) -> Option<u8> {
+    response
+        .extensions()
+        .get::<ConnectorNetworkRetryCount>()
+        .map(|ConnectorNetworkRetryCount(count)| *count)
+}
+
 #[instrument(skip_all)]
 pub async fn call_connector_api(
     state: &SessionState,
     request: Request,
     flow_name: &str,
 ) -> CustomResult<Result<types::Response, types::Response>, errors::ApiClientError> {
+    call_connector_api_with_timeout(state, request, flow_name, None).await
+}
+
+/// Call the connector API, overriding the default request timeout with `option_timeout_secs`
+#[instrument(skip_all)]
+pub async fn call_connector_api_with_timeout(
+    state: &SessionState,
+    request: Request,
+    flow_name: &str,
+    option_timeout_secs: Option<u64>,
+) -> CustomResult<Result<types::Response, types::Response>, errors::ApiClientError> {
+    call_connector_api_with_network_retry_count(state, request, flow_name, option_timeout_secs)
+        .await
+        .0
+}
+
+/// Call the connector API, returning the response along with the number of times the request was
+/// retried after a network error
+#[instrument(skip_all)]
+async fn call_connector_api_with_network_retry_count(
+    state: &SessionState,
+    request: Request,
+    flow_name: &str,
+    option_timeout_secs: Option<u64>,
+) -> (
+    CustomResult<Result<types::Response, types::Response>, errors::ApiClientError>,
+    Option<u8>,
+) {
     let current_time = Instant::now();
     let headers = request.headers.clone();
     let url = request.url.clone();
     let response = state
         .api_client
-        .send_request(state, request, None, true)
+        .send_request(state, request, option_timeout_secs, true)
         .await;
 
     match response.as_ref() {
@@ -397,7 +649,11 @@ pub async fn call_connector_api(
         }
     }
 
-    handle_response(response).await
+    let network_retry_count = response
+        .as_ref()
+        .ok()
+        .and_then(get_connector_network_retry_count);
+    (handle_response(response).await, network_retry_count)
 }
 
 #[instrument(skip_all)]
@@ -406,7 +662,15 @@ pub async fn send_request(
     request: Request,
     option_timeout_secs: Option<u64>,
 ) -> CustomResult<reqwest::Response, errors::ApiClientError> {
-    logger::info!(method=?request.method, headers=?request.headers, payload=?request.body, ?request);
+    logger::info!(
+        method=?request.method,
+        url=?request.url,
+        headers=?request.headers,
+        payload=?state
+            .conf
+            .connector_log_redaction
+            .get_redacted_request_body(request.body.as_ref())
+    );
 
     let url = reqwest::Url::parse(&request.url)
         .change_context(errors::ApiClientError::UrlEncodingFailed)?;
@@ -435,6 +699,14 @@ pub async fn send_request(
         request.certificate_key,
     )?;
 
+    let is_idempotent_request = request.headers.iter().any(|(key, _)| {
+        state
+            .conf
+            .connector_network_retry
+            .idempotency_key_headers
+            .iter()
+            .any(|header| header.eq_ignore_ascii_case(key))
+    });
     let headers = request.headers.construct_header_map()?;
     let metrics_tag = router_env::opentelemetry::KeyValue {
         key: consts::METRICS_HOST_TAG_NAME.into(),
@@ -497,86 +769,147 @@ pub async fn send_request(
     };
 
     // We cannot clone the request type, because it has Form trait which is not cloneable. So we are cloning the request builder here.
-    let cloned_send_request = request.try_clone().map(|cloned_request| async {
-        cloned_request
-            .send()
-            .await
-            .map_err(|error| match error {
-                error if error.is_timeout() => {
-                    metrics::REQUEST_BUILD_FAILURE.add(&metrics::CONTEXT, 1, &[]);
-                    errors::ApiClientError::RequestTimeoutReceived
-                }
-                error if is_connection_closed_before_message_could_complete(&error) => {
-                    metrics::REQUEST_BUILD_FAILURE.add(&metrics::CONTEXT, 1, &[]);
-                    errors::ApiClientError::ConnectionClosedIncompleteMessage
-                }
-                _ => errors::ApiClientError::RequestNotSent(error.to_string()),
-            })
-            .attach_printable("Unable to send request to connector")
-    });
-
-    let send_request = async {
-        request
-            .send()
-            .await
-            .map_err(|error| match error {
-                error if error.is_timeout() => {
-                    metrics::REQUEST_BUILD_FAILURE.add(&metrics::CONTEXT, 1, &[]);
-                    errors::ApiClientError::RequestTimeoutReceived
-                }
-                error if is_connection_closed_before_message_could_complete(&error) => {
-                    metrics::REQUEST_BUILD_FAILURE.add(&metrics::CONTEXT, 1, &[]);
-                    errors::ApiClientError::ConnectionClosedIncompleteMessage
-                }
-                _ => errors::ApiClientError::RequestNotSent(error.to_string()),
-            })
-            .attach_printable("Unable to send request to connector")
-    };
+    // The retries are sent with the same request builder, so that they carry the same idempotency key.
+    let mut retry_request = request.try_clone();
 
-    let response = common_utils::metrics::utils::record_operation_time(
-        send_request,
+    let mut response = common_utils::metrics::utils::record_operation_time(
+        send_request_builder(request),
         &metrics::EXTERNAL_REQUEST_TIME,
         &metrics::CONTEXT,
         &[metrics_tag.clone()],
     )
     .await;
-    // Retry once if the response is connection closed.
-    //
-    // This is just due to the racy nature of networking.
-    // hyper has a connection pool of idle connections, and it selected one to send your request.
-    // Most of the time, hyper will receive the server’s FIN and drop the dead connection from its pool.
-    // But occasionally, a connection will be selected from the pool
-    // and written to at the same time the server is deciding to close the connection.
-    // Since hyper already wrote some of the request,
-    // it can’t really retry it automatically on a new connection, since the server may have acted already
-    match response {
-        Ok(response) => Ok(response),
-        Err(error)
-            if error.current_context()
-                == &errors::ApiClientError::ConnectionClosedIncompleteMessage =>
-        {
+
+    let network_retry = &state.conf.connector_network_retry;
+    let mut network_retry_count = 0;
+    while let Err(error) = response.as_ref() {
+        let error = error.current_context().clone();
+        // Retry once if the response is connection closed.
+        //
+        // This is just due to the racy nature of networking.
+        // hyper has a connection pool of idle connections, and it selected one to send your request.
+        // Most of the time, hyper will receive the server’s FIN and drop the dead connection from its pool.
+        // But occasionally, a connection will be selected from the pool
+        // and written to at the same time the server is deciding to close the connection.
+        // Since hyper already wrote some of the request,
+        // it can’t really retry it automatically on a new connection, since the server may have acted already
+        let is_connection_closed_retry =
+            error.is_connection_closed_before_message_could_complete() && network_retry_count == 0;
+        // Any other network error is only retried if the request certainly did not reach the
+        // connector, or if the connector deduplicates the request by its idempotency key
+        let is_network_retry = network_retry_count < network_retry.max_retries
+            && (error.is_connection_failure()
+                || (error.is_network_error() && is_idempotent_request));
+        if !is_connection_closed_retry && !is_network_retry {
+            break;
+        }
+
+        let Some(request) = retry_request.take() else {
+            logger::info!(
+                "Retrying request due to a network error failed as request is not cloneable"
+            );
+            break;
+        };
+        retry_request = request.try_clone();
+
+        if is_connection_closed_retry {
             metrics::AUTO_RETRY_CONNECTION_CLOSED.add(&metrics::CONTEXT, 1, &[]);
-            match cloned_send_request {
-                Some(cloned_request) => {
-                    logger::info!(
-                        "Retrying request due to connection closed before message could complete"
-                    );
-                    common_utils::metrics::utils::record_operation_time(
-                        cloned_request,
-                        &metrics::EXTERNAL_REQUEST_TIME,
-                        &metrics::CONTEXT,
-                        &[metrics_tag],
-                    )
-                    .await
-                }
-                None => {
-                    logger::info!("Retrying request due to connection closed before message could complete failed as request is not cloneable");
-                    Err(error)
-                }
+            logger::info!(
+                "Retrying request due to connection closed before message could complete"
+            );
+        } else {
+            metrics::CONNECTOR_NETWORK_RETRY_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[metrics_tag.clone()],
+            );
+            let backoff = network_retry
+                .backoff_ms
+                .saturating_mul(2u64.saturating_pow(network_retry_count.into()));
+            logger::info!(
+                ?error,
+                backoff_ms = backoff,
+                "Retrying request due to a network error"
+            );
+            tokio::time::sleep(Duration::from_millis(backoff)).await;
+        }
+        network_retry_count += 1;
+
+        response = common_utils::metrics::utils::record_operation_time(
+            send_request_builder(request),
+            &metrics::EXTERNAL_REQUEST_TIME,
+            &metrics::CONTEXT,
+            &[metrics_tag.clone()],
+        )
+        .await;
+    }
+
+    match response {
+        Ok(mut response) => {
+            if network_retry_count > 0 {
+                response
+                    .extensions_mut()
+                    .insert(ConnectorNetworkRetryCount(network_retry_count));
+            }
+            Ok(response)
+        }
+        Err(error) => {
+            if network_retry_count > 0 {
+                metrics::CONNECTOR_NETWORK_RETRY_EXHAUSTED_COUNT.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &[metrics_tag],
+                );
+            }
+            Err(error.attach_printable(format!(
+                "Request failed after {network_retry_count} network retries"
+            )))
+        }
+    }
+}
+
+async fn send_request_builder(
+    request: reqwest::RequestBuilder,
+) -> CustomResult<reqwest::Response, errors::ApiClientError> {
+    request
+        .send()
+        .await
+        .map_err(|error| match error {
+            error if error.is_timeout() => {
+                metrics::REQUEST_BUILD_FAILURE.add(&metrics::CONTEXT, 1, &[]);
+                errors::ApiClientError::RequestTimeoutReceived
+            }
+            error if is_connection_closed_before_message_could_complete(&error) => {
+                metrics::REQUEST_BUILD_FAILURE.add(&metrics::CONTEXT, 1, &[]);
+                errors::ApiClientError::ConnectionClosedIncompleteMessage
+            }
+            // The DNS resolution, the TCP connection and the TLS handshake all take place before
+            // any part of the request is written
+            error if error.is_connect() => {
+                errors::ApiClientError::ConnectionFailed(error.to_string())
+            }
+            error if is_connection_reset(&error) => errors::ApiClientError::ConnectionReset,
+            _ => errors::ApiClientError::RequestNotSent(error.to_string()),
+        })
+        .attach_printable("Unable to send request to connector")
+}
+
+fn is_connection_reset(error: &reqwest::Error) -> bool {
+    let mut source = error.source();
+    while let Some(err) = source {
+        if let Some(io_err) = err.downcast_ref::<std::io::Error>() {
+            if matches!(
+                io_err.kind(),
+                std::io::ErrorKind::ConnectionReset
+                    | std::io::ErrorKind::ConnectionAborted
+                    | std::io::ErrorKind::BrokenPipe
+            ) {
+                return true;
             }
         }
-        err @ Err(_) => err,
+        source = err.source();
     }
+    false
 }
 
 fn is_connection_closed_before_message_could_complete(error: &reqwest::Error) -> bool {
@@ -699,6 +1032,7 @@ pub async fn server_wrap_util<'a, 'b, U, T, Q, F, Fut, E, OErr>(
     lock_action: api_locking::LockAction,
 ) -> CustomResult<ApplicationResponse<Q>, OErr>
 where
+    U: GetLockExpiry,
     F: Fn(SessionState, U, T, ReqState) -> Fut,
     'b: 'a,
     Fut: Future<Output = CustomResult<ApplicationResponse<Q>, E>>,
@@ -772,6 +1106,7 @@ where
         .switch()?;
 
     request_state.event_context.record_info(auth_type.clone());
+    request_state.auth_type = Some(auth_type.clone());
 
     let merchant_id = auth_type
         .get_merchant_id()
@@ -785,7 +1120,11 @@ where
     let output = {
         lock_action
             .clone()
-            .perform_locking_action(&session_state, merchant_id.to_owned())
+            .perform_locking_action(
+                &session_state,
+                merchant_id.to_owned(),
+                auth_out.get_lock_expiry_in_seconds(),
+            )
             .await
             .switch()?;
         let res = func(session_state.clone(), auth_out, payload, request_state)
@@ -882,6 +1221,7 @@ pub async fn server_wrap<'a, T, U, Q, F, Fut, E>(
     lock_action: api_locking::LockAction,
 ) -> HttpResponse
 where
+    U: GetLockExpiry,
     F: Fn(SessionState, U, T, ReqState) -> Fut,
     Fut: Future<Output = CustomResult<ApplicationResponse<Q>, E>>,
     Q: Serialize + Debug + ApiEventMetric + 'a,
@@ -1980,8 +2320,34 @@ pub fn get_payment_link_status(
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn test_mime_essence() {
         assert_eq!(mime::APPLICATION_JSON.essence_str(), "application/json");
     }
+
+    #[test]
+    fn test_connector_network_retry_count_is_read_from_response_extensions() {
+        let mut response = reqwest::Response::from(http::Response::new(String::new()));
+        assert_eq!(get_connector_network_retry_count(&response), None);
+
+        response
+            .extensions_mut()
+            .insert(ConnectorNetworkRetryCount(2));
+        assert_eq!(get_connector_network_retry_count(&response), Some(2));
+    }
+
+    #[allow(clippy::unwrap_used)]
+    #[test]
+    fn test_connector_network_retry_count_is_not_read_from_response_headers() {
+        let response = reqwest::Response::from(
+            http::Response::builder()
+                .header("x-hs-connector-network-retries", "3")
+                .body(String::new())
+                .unwrap(),
+        );
+
+        assert_eq!(get_connector_network_retry_count(&response), None);
+    }
 }
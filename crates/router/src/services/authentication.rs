@@ -160,6 +160,30 @@ impl AuthenticationType {
             | Self::NoAuth => None,
         }
     }
+
+    /// The user or the key which made the request, as recorded in the audit log
+    pub fn get_actor(&self) -> String {
+        match self {
+            Self::ApiKey { key_id, .. } => format!("api_key:{key_id}"),
+            Self::AdminApiKey | Self::AdminApiAuthWithMerchantId { .. } => {
+                "admin_api_key".to_string()
+            }
+            Self::OrganizationJwt { user_id, .. }
+            | Self::MerchantJwtWithProfileId { user_id, .. }
+            | Self::UserJwt { user_id }
+            | Self::SinglePurposeJwt { user_id, .. }
+            | Self::SinglePurposeOrLoginJwt { user_id, .. }
+            | Self::MerchantJwt {
+                user_id: Some(user_id),
+                ..
+            } => format!("user:{user_id}"),
+            Self::MerchantJwt { user_id: None, .. } => "user".to_string(),
+            Self::MerchantId { .. } => "merchant_id".to_string(),
+            Self::PublishableKey { .. } => "publishable_key".to_string(),
+            Self::WebhookAuth { .. } => "webhook".to_string(),
+            Self::NoAuth => "unauthenticated".to_string(),
+        }
+    }
 }
 
 #[cfg(feature = "olap")]
@@ -281,6 +305,47 @@ impl AuthInfo for AuthenticationDataWithMultipleProfiles {
     }
 }
 
+/// Provides the expiry of the API lock configured for the authenticated merchant, which overrides
+/// the expiry configured for the application
+pub trait GetLockExpiry {
+    fn get_lock_expiry_in_seconds(&self) -> Option<i64> {
+        None
+    }
+}
+
+impl GetLockExpiry for () {}
+
+impl<T> GetLockExpiry for Option<T> {}
+
+impl GetLockExpiry for AuthenticationData {
+    #[cfg(feature = "v1")]
+    fn get_lock_expiry_in_seconds(&self) -> Option<i64> {
+        self.merchant_account.idempotency_ttl_in_seconds
+    }
+}
+
+impl GetLockExpiry for AuthenticationDataWithMultipleProfiles {
+    #[cfg(feature = "v1")]
+    fn get_lock_expiry_in_seconds(&self) -> Option<i64> {
+        self.merchant_account.idempotency_ttl_in_seconds
+    }
+}
+
+impl GetLockExpiry for AuthenticationDataWithUser {}
+
+impl GetLockExpiry for AuthenticationDataWithUserId {
+    fn get_lock_expiry_in_seconds(&self) -> Option<i64> {
+        self.0.get_lock_expiry_in_seconds()
+    }
+}
+
+#[cfg(feature = "olap")]
+impl GetLockExpiry for UserFromSinglePurposeToken {}
+
+impl GetLockExpiry for UserFromToken {}
+
+impl GetLockExpiry for UserIdFromAuth {}
+
 #[async_trait]
 pub trait AuthenticateAndFetch<T, A>
 where
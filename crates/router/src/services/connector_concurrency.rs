@@ -0,0 +1,305 @@
+//! Limits on the number of the calls in flight to each connector at a time.
+//!
+//! Unlike a limit on the rate of the calls, the limit bounds how many calls to the connector are
+//! in flight at once, across all the instances of the application. The permits of a connector are
+//! the members of a sorted set in redis, each scored with the expiry of its lease. A permit is
+//! taken with a script which drops the permits whose lease has expired and adds the permit only if
+//! fewer permits than the limit are held, atomically, so that concurrent calls never exceed the
+//! limit. The permit of a call which is never released, such as when the instance making the call
+//! crashed, expires with its lease. A permit which is dropped without being released, such as when
+//! the call is cancelled, is released in the background. A call waits for a permit while all the
+//! permits of the connector are in use, and is shed once it has waited for too long.
+//!
+//! Failures to reach redis are logged and the call is made without a permit, so that an outage of
+//! redis does not block the calls to the connectors.
+
+use std::sync::Arc;
+
+use error_stack::{report, ResultExt};
+use redis_interface::RedisConnectionPool;
+use router_env::{instrument, tracing};
+
+use crate::{
+    configs::settings::ConnectorConcurrency,
+    consts,
+    core::errors::{self, CustomResult},
+    logger,
+    routes::SessionState,
+};
+
+/// Drop the permits whose lease has expired and take a permit, if fewer permits than the limit are
+/// held. The time of redis is used for the leases, so that the leases do not depend on the clocks
+/// of the instances. The set expires along with the last lease taken.
+const ACQUIRE_PERMIT_SCRIPT: &str = r#"
+    local time = redis.call("TIME")
+    local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+    redis.call("ZREMRANGEBYSCORE", KEYS[1], "-inf", now)
+    if redis.call("ZCARD", KEYS[1]) < tonumber(ARGV[1]) then
+        redis.call("ZADD", KEYS[1], now + tonumber(ARGV[2]), ARGV[3])
+        redis.call("PEXPIRE", KEYS[1], ARGV[2])
+        return 1
+    end
+    return 0
+"#;
+
+/// Release the permit of the lease, which is a no-op once the lease has expired and was dropped
+const RELEASE_PERMIT_SCRIPT: &str = r#"
+    return redis.call("ZREM", KEYS[1], ARGV[1])
+"#;
+
+/// Count the permits whose lease has not expired
+const COUNT_PERMITS_SCRIPT: &str = r#"
+    local time = redis.call("TIME")
+    local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+    return redis.call("ZCOUNT", KEYS[1], "(" .. now, "+inf")
+"#;
+
+/// A permit to make a call to a connector, which is to be released once the call completes. A
+/// permit which is dropped without being released is released in the background.
+pub struct ConnectorConcurrencyPermit {
+    redis_conn: Arc<RedisConnectionPool>,
+    key: String,
+    lease_id: Option<String>,
+}
+
+impl std::fmt::Debug for ConnectorConcurrencyPermit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConnectorConcurrencyPermit")
+            .field("key", &self.key)
+            .field("lease_id", &self.lease_id)
+            .finish()
+    }
+}
+
+fn get_redis_key(connector: &str) -> String {
+    format!("{}{connector}", consts::CONNECTOR_CONCURRENCY_PREFIX)
+}
+
+/// The limit on the calls in flight to the connector, if the calls to the connector are limited
+pub fn get_connector_concurrency_limit(
+    config: &ConnectorConcurrency,
+    connector: &str,
+) -> Option<u16> {
+    config
+        .enabled
+        .then(|| config.limits.get(connector).copied())
+        .flatten()
+}
+
+/// Acquire a permit to make a call to the connector, waiting for a permit while all the permits of
+/// the connector are in use. Calls to connectors without a limit, and calls made while redis cannot
+/// be reached, do not need a permit.
+#[instrument(skip(state))]
+pub async fn acquire_permit(
+    state: &SessionState,
+    connector: &str,
+) -> CustomResult<Option<ConnectorConcurrencyPermit>, errors::ConnectorError> {
+    let config = &state.conf.connector_concurrency;
+    let Some(limit) = get_connector_concurrency_limit(config, connector) else {
+        return Ok(None);
+    };
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get redis connection for connector concurrency"
+            );
+            return Ok(None);
+        }
+    };
+
+    let lease_id = uuid::Uuid::new_v4().to_string();
+    let max_wait = std::time::Duration::from_millis(config.max_wait_in_millis.into());
+    let retry_interval = std::time::Duration::from_millis(config.retry_interval_in_millis.into());
+    let wait_start = tokio::time::Instant::now();
+
+    let key = get_redis_key(connector);
+
+    loop {
+        match try_acquire_permit(&redis_conn, config, &key, limit, &lease_id).await {
+            Ok(true) => {
+                return Ok(Some(ConnectorConcurrencyPermit {
+                    redis_conn,
+                    key,
+                    lease_id: Some(lease_id),
+                }))
+            }
+            Ok(false) => {}
+            Err(error) => {
+                logger::error!(?error, "Failed to acquire connector concurrency permit");
+                return Ok(None);
+            }
+        }
+
+        if wait_start.elapsed() + retry_interval > max_wait {
+            logger::warn!(
+                connector,
+                limit,
+                "Shedding the connector call, all the permits of the connector are in use"
+            );
+            return Err(report!(errors::ConnectorError::ConcurrencyLimitReached {
+                connector: connector.to_owned(),
+                retry_after_in_secs: config.retry_after_in_secs,
+            }));
+        }
+        tokio::time::sleep(retry_interval).await;
+    }
+}
+
+/// Try to take a permit of the connector for the lease, returning whether a permit was free
+async fn try_acquire_permit(
+    redis_conn: &RedisConnectionPool,
+    config: &ConnectorConcurrency,
+    key: &str,
+    limit: u16,
+    lease_id: &str,
+) -> CustomResult<bool, errors::StorageError> {
+    let lease_ttl_in_millis = u64::from(config.lease_ttl_in_secs) * 1000;
+    redis_conn
+        .evaluate_redis_script::<i64>(
+            ACQUIRE_PERMIT_SCRIPT,
+            vec![key.to_owned()],
+            vec![
+                limit.to_string(),
+                lease_ttl_in_millis.to_string(),
+                lease_id.to_owned(),
+            ],
+        )
+        .await
+        .map(|acquired| acquired == 1)
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("Failed to acquire the connector concurrency permit")
+}
+
+async fn release_permit(
+    redis_conn: &RedisConnectionPool,
+    key: &str,
+    lease_id: &str,
+) -> CustomResult<(), errors::StorageError> {
+    redis_conn
+        .evaluate_redis_script::<i64>(
+            RELEASE_PERMIT_SCRIPT,
+            vec![key.to_owned()],
+            vec![lease_id.to_owned()],
+        )
+        .await
+        .map(|_| ())
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("Failed to release the connector concurrency permit")
+}
+
+impl ConnectorConcurrencyPermit {
+    /// Release the permit, unless its lease has expired. Failures to release the permit are
+    /// logged, and the permit expires with its lease.
+    #[instrument(skip_all)]
+    pub async fn release(mut self) {
+        if let Some(lease_id) = self.lease_id.take() {
+            release_permit(&self.redis_conn, &self.key, &lease_id)
+                .await
+                .inspect_err(|error| {
+                    logger::error!(?error, "Failed to release connector concurrency permit")
+                })
+                .ok();
+        }
+    }
+}
+
+impl Drop for ConnectorConcurrencyPermit {
+    fn drop(&mut self) {
+        let Some(lease_id) = self.lease_id.take() else {
+            return;
+        };
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            logger::warn!("Leaving the connector concurrency permit to expire with its lease");
+            return;
+        };
+
+        let redis_conn = self.redis_conn.clone();
+        let key = std::mem::take(&mut self.key);
+        runtime.spawn(async move {
+            release_permit(&redis_conn, &key, &lease_id)
+                .await
+                .inspect_err(|error| {
+                    logger::error!(
+                        ?error,
+                        "Failed to release dropped connector concurrency permit"
+                    )
+                })
+                .ok();
+        });
+    }
+}
+
+/// The number of the calls in flight to the connector, which are holding a permit of the connector
+#[instrument(skip(state))]
+pub async fn get_in_flight_count(
+    state: &SessionState,
+    connector: &str,
+) -> CustomResult<u16, errors::StorageError> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .evaluate_redis_script::<i64>(
+            COUNT_PERMITS_SCRIPT,
+            vec![get_redis_key(connector)],
+            Vec::new(),
+        )
+        .await
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("Failed to count the connector concurrency permits")
+        .and_then(|in_flight_count| {
+            u16::try_from(in_flight_count)
+                .change_context(errors::StorageError::KVError)
+                .attach_printable("Invalid count of the connector concurrency permits")
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn get_config(enabled: bool) -> ConnectorConcurrency {
+        ConnectorConcurrency {
+            enabled,
+            limits: HashMap::from([("stripe".to_string(), 10)]),
+            lease_ttl_in_secs: 60,
+            max_wait_in_millis: 1000,
+            retry_interval_in_millis: 50,
+            retry_after_in_secs: 5,
+        }
+    }
+
+    #[test]
+    fn test_connector_concurrency_limit() {
+        assert_eq!(
+            get_connector_concurrency_limit(&get_config(true), "stripe"),
+            Some(10)
+        );
+        // The calls to the connectors which are not listed are not limited
+        assert_eq!(
+            get_connector_concurrency_limit(&get_config(true), "adyen"),
+            None
+        );
+        assert_eq!(
+            get_connector_concurrency_limit(&get_config(false), "stripe"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_permits_of_a_connector_share_a_key() {
+        assert_eq!(
+            get_redis_key("stripe"),
+            format!("{}stripe", consts::CONNECTOR_CONCURRENCY_PREFIX)
+        );
+        assert_ne!(get_redis_key("stripe"), get_redis_key("adyen"));
+    }
+}
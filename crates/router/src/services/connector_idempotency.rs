@@ -0,0 +1,184 @@
+//! Propagation of idempotency keys to the connectors which support them.
+//!
+//! Idempotency keys are attached to the requests of the flows which move funds at the connector,
+//! which are the authorize, capture, refund and void flows. The idempotency key of such a request
+//! is derived from the operation the request is made for, which is the flow and the resource the
+//! flow is performed on: the refund for refund flows, the partial capture for partial captures,
+//! and the payment attempt otherwise, along with the body of the request. A request made again for
+//! the same operation, for example when the previous request timed out, carries the same key, so
+//! that the connector deduplicates it instead of processing the operation twice.
+
+use std::{any::Any, str::FromStr};
+
+use api_models::enums as api_enums;
+use common_utils::{id_type, request::Method};
+use masking::PeekInterface;
+
+use crate::{services::Request, types};
+
+/// The flows whose requests carry an idempotency key. Requests of other flows, such as the syncs
+/// which some connectors serve over POST, are left as they are.
+const IDEMPOTENT_FLOWS: [&str; 4] = ["Authorize", "Capture", "Execute", "Void"];
+
+/// The name of the flow, which is the name of the flow type
+pub fn get_flow_name<T>() -> &'static str {
+    std::any::type_name::<T>()
+        .split("::")
+        .last()
+        .unwrap_or_default()
+}
+
+fn get_resource_id<T, Req: 'static, Resp>(router_data: &types::RouterData<T, Req, Resp>) -> &str {
+    if let Some(refund_id) = &router_data.refund_id {
+        return refund_id;
+    }
+
+    (&router_data.request as &dyn Any)
+        .downcast_ref::<types::PaymentsCaptureData>()
+        .and_then(|capture_data| capture_data.multiple_capture_data.as_ref())
+        .map(|multiple_capture_data| multiple_capture_data.capture_reference.as_str())
+        .unwrap_or(&router_data.attempt_id)
+}
+
+fn is_idempotent_flow<T>() -> bool {
+    IDEMPOTENT_FLOWS.contains(&get_flow_name::<T>())
+}
+
+/// Derive the idempotency key of the operation, which is the hex encoded SHA-256 digest of the
+/// merchant, the connector, the flow, the resource the flow is performed on and the body of the
+/// request
+fn derive_idempotency_key(
+    merchant_id: &id_type::MerchantId,
+    connector: &str,
+    flow: &str,
+    resource_id: &str,
+    request_body: &str,
+) -> String {
+    let operation = format!(
+        "{}_{connector}_{flow}_{resource_id}_{request_body}",
+        merchant_id.get_string_repr()
+    );
+    hex::encode(ring::digest::digest(
+        &ring::digest::SHA256,
+        operation.as_bytes(),
+    ))
+}
+
+/// Attach the idempotency key of the operation to the request of a flow which moves funds, if the
+/// connector supports idempotency keys. Requests which already carry an idempotency key are left as
+/// they are.
+pub fn add_idempotency_key_header<T, Req: 'static, Resp>(
+    router_data: &types::RouterData<T, Req, Resp>,
+    mut request: Request,
+) -> Request {
+    if request.method == Method::Get || !is_idempotent_flow::<T>() {
+        return request;
+    }
+
+    let Some(header) = api_enums::Connector::from_str(&router_data.connector)
+        .ok()
+        .and_then(|connector| connector.get_idempotency_key_header())
+    else {
+        return request;
+    };
+
+    if request
+        .headers
+        .iter()
+        .any(|(name, _)| name.eq_ignore_ascii_case(header))
+    {
+        return request;
+    }
+
+    let request_body = request
+        .body
+        .as_ref()
+        .map(|body| body.get_inner_value())
+        .unwrap_or_default();
+    let idempotency_key = derive_idempotency_key(
+        &router_data.merchant_id,
+        &router_data.connector,
+        get_flow_name::<T>(),
+        get_resource_id(router_data),
+        request_body.peek(),
+    );
+    request.add_header(header, idempotency_key.into());
+    request
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_idempotency_key_is_stable_for_the_same_operation() {
+        let merchant_id = id_type::MerchantId::default();
+        let idempotency_key =
+            derive_idempotency_key(&merchant_id, "stripe", "Authorize", "pay_1_1", "{}");
+
+        assert_eq!(
+            idempotency_key,
+            derive_idempotency_key(&merchant_id, "stripe", "Authorize", "pay_1_1", "{}")
+        );
+        assert_eq!(idempotency_key.len(), 64);
+    }
+
+    #[test]
+    fn test_idempotency_key_differs_across_operations() {
+        let merchant_id = id_type::MerchantId::default();
+        let idempotency_key =
+            derive_idempotency_key(&merchant_id, "stripe", "Authorize", "pay_1_1", "{}");
+
+        assert_ne!(
+            idempotency_key,
+            derive_idempotency_key(&merchant_id, "stripe", "Capture", "pay_1_1", "{}")
+        );
+        assert_ne!(
+            idempotency_key,
+            derive_idempotency_key(&merchant_id, "stripe", "Authorize", "pay_1_2", "{}")
+        );
+        assert_ne!(
+            idempotency_key,
+            derive_idempotency_key(&merchant_id, "adyen", "Authorize", "pay_1_1", "{}")
+        );
+    }
+
+    #[test]
+    fn test_idempotency_key_differs_across_request_bodies() {
+        let merchant_id = id_type::MerchantId::default();
+
+        assert_ne!(
+            derive_idempotency_key(
+                &merchant_id,
+                "stripe",
+                "Authorize",
+                "pay_1_1",
+                r#"{"amount":1000}"#
+            ),
+            derive_idempotency_key(
+                &merchant_id,
+                "stripe",
+                "Authorize",
+                "pay_1_1",
+                r#"{"amount":2000}"#
+            )
+        );
+    }
+
+    #[test]
+    fn test_only_flows_which_move_funds_are_idempotent() {
+        assert!(is_idempotent_flow::<types::api::Authorize>());
+        assert!(is_idempotent_flow::<types::api::Capture>());
+        assert!(is_idempotent_flow::<types::api::Execute>());
+        assert!(is_idempotent_flow::<types::api::Void>());
+        assert!(!is_idempotent_flow::<types::api::PSync>());
+        assert!(!is_idempotent_flow::<types::api::RSync>());
+        assert!(!is_idempotent_flow::<types::api::CompleteAuthorize>());
+    }
+
+    #[test]
+    fn test_flow_name_is_the_name_of_the_flow_type() {
+        assert_eq!(get_flow_name::<types::api::Authorize>(), "Authorize");
+        assert_eq!(get_flow_name::<types::api::Execute>(), "Execute");
+    }
+}
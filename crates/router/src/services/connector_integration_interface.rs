@@ -1,7 +1,8 @@
 use common_utils::{crypto, errors::CustomResult, request::Request};
 use hyperswitch_domain_models::{router_data::RouterData, router_data_v2::RouterDataV2};
 use hyperswitch_interfaces::{
-    authentication::ExternalAuthenticationPayload, connector_integration_v2::ConnectorIntegrationV2,
+    authentication::ExternalAuthenticationPayload,
+    connector_integration_v2::ConnectorIntegrationV2, webhooks::PayoutReturnDetails,
 };
 
 use super::{BoxedConnectorIntegrationV2, ConnectorValidation};
@@ -212,6 +213,32 @@ impl api::IncomingWebhook for ConnectorEnum {
         }
     }
 
+    fn get_webhook_signed_timestamp(
+        &self,
+        request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<i64>, errors::ConnectorError> {
+        match self {
+            Self::Old(connector) => connector.get_webhook_signed_timestamp(request),
+            Self::New(connector) => connector.get_webhook_signed_timestamp(request),
+        }
+    }
+
+    fn build_webhook_resource_fetch_request(
+        &self,
+        request: &IncomingWebhookRequestDetails<'_>,
+        auth_type: &types::ConnectorAuthType,
+        connectors: &Connectors,
+    ) -> CustomResult<Request, errors::ConnectorError> {
+        match self {
+            Self::Old(connector) => {
+                connector.build_webhook_resource_fetch_request(request, auth_type, connectors)
+            }
+            Self::New(connector) => {
+                connector.build_webhook_resource_fetch_request(request, auth_type, connectors)
+            }
+        }
+    }
+
     async fn verify_webhook_source(
         &self,
         request: &IncomingWebhookRequestDetails<'_>,
@@ -297,6 +324,16 @@ impl api::IncomingWebhook for ConnectorEnum {
         }
     }
 
+    fn get_payout_return_details(
+        &self,
+        request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<PayoutReturnDetails, errors::ConnectorError> {
+        match self {
+            Self::Old(connector) => connector.get_payout_return_details(request),
+            Self::New(connector) => connector.get_payout_return_details(request),
+        }
+    }
+
     fn get_external_authentication_details(
         &self,
         request: &IncomingWebhookRequestDetails<'_>,
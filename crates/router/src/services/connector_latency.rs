@@ -0,0 +1,230 @@
+//! Tracking of the latency distribution of the calls made to each connector.
+//!
+//! The latency of a sampled fraction of the connector calls is recorded into a histogram with fixed
+//! buckets, which is kept in redis as a hash for every connector and bucket of time. The samples
+//! are recorded in the background, so that the tracking does not add latency to the connector
+//! call. The distribution over a window is computed by merging the histograms of the buckets of
+//! time within the window.
+
+use std::collections::HashMap;
+
+use common_utils::date_time;
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+use tracing_futures::Instrument;
+
+use crate::{
+    consts,
+    core::errors::{self, CustomResult},
+    logger,
+    routes::SessionState,
+};
+
+/// The upper bounds in milliseconds of the buckets of the latency histogram. Latencies above the
+/// last bound are counted in an overflow bucket, and are reported as the last bound.
+const LATENCY_BUCKET_UPPER_BOUNDS_MS: &[u64] = &[
+    5, 10, 25, 50, 75, 100, 150, 200, 300, 400, 500, 750, 1000, 1500, 2000, 3000, 5000, 7500,
+    10000, 15000, 20000, 30000, 60000,
+];
+
+/// The percentiles of the latency distribution of a connector, in milliseconds
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ConnectorLatencyDistribution {
+    pub sample_count: u64,
+    pub p50_ms: Option<u64>,
+    pub p95_ms: Option<u64>,
+    pub p99_ms: Option<u64>,
+}
+
+fn get_latency_bucket_index(latency_ms: u128) -> usize {
+    LATENCY_BUCKET_UPPER_BOUNDS_MS
+        .iter()
+        .position(|upper_bound| latency_ms <= u128::from(*upper_bound))
+        .unwrap_or(LATENCY_BUCKET_UPPER_BOUNDS_MS.len())
+}
+
+fn get_redis_key(connector: &str, bucket_start: i64) -> String {
+    format!(
+        "{}{connector}_{bucket_start}",
+        consts::CONNECTOR_LATENCY_PREFIX
+    )
+}
+
+/// Record the latency of a connector call, if the call is sampled.
+///
+/// The sample is written to redis in the background, and failures to write it are logged.
+pub fn record_connector_latency(state: &SessionState, connector: &str, latency_ms: u128) {
+    let config = &state.conf.connector_latency_tracking;
+    if !config.enabled || rand::random::<f64>() >= config.sampling_rate {
+        return;
+    }
+
+    let state = state.clone();
+    let connector = connector.to_owned();
+    tokio::spawn(
+        async move {
+            record_connector_latency_inner(&state, &connector, latency_ms)
+                .await
+                .inspect_err(|error| logger::error!(?error, "Failed to record connector latency"))
+                .ok();
+        }
+        .in_current_span(),
+    );
+}
+
+async fn record_connector_latency_inner(
+    state: &SessionState,
+    connector: &str,
+    latency_ms: u128,
+) -> CustomResult<(), errors::StorageError> {
+    let config = &state.conf.connector_latency_tracking;
+    let bucket_duration = i64::from(config.bucket_duration_in_secs);
+    let now = date_time::now_unix_timestamp();
+    let key = get_redis_key(connector, now - now.rem_euclid(bucket_duration));
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .increment_fields_in_hash(&key, &[(get_latency_bucket_index(latency_ms), 1)])
+        .await
+        .change_context(errors::StorageError::KVError)?;
+
+    redis_conn
+        .set_expiry(&key, config.retention_in_secs.into())
+        .await
+        .change_context(errors::StorageError::KVError)
+}
+
+/// Get the latency distribution of the calls made to the connector within the window, in seconds,
+/// ending now
+#[instrument(skip_all)]
+pub async fn get_connector_latency_distribution(
+    state: &SessionState,
+    connector: &str,
+    window_in_secs: u32,
+) -> CustomResult<ConnectorLatencyDistribution, errors::StorageError> {
+    let bucket_duration = i64::from(
+        state
+            .conf
+            .connector_latency_tracking
+            .bucket_duration_in_secs,
+    );
+    let now = date_time::now_unix_timestamp();
+    let window_start = now - i64::from(window_in_secs);
+    let first_bucket_start = window_start - window_start.rem_euclid(bucket_duration);
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let keys = std::iter::successors(Some(first_bucket_start), |bucket_start| {
+        Some(bucket_start + bucket_duration)
+    })
+    .take_while(|bucket_start| *bucket_start <= now)
+    .map(|bucket_start| get_redis_key(connector, bucket_start))
+    .collect::<Vec<_>>();
+
+    let bucket_histograms = redis_conn
+        .get_multiple_hash_fields_pipelined::<HashMap<String, u64>>(&keys)
+        .await
+        .change_context(errors::StorageError::KVError)?;
+
+    let mut histogram = vec![0; LATENCY_BUCKET_UPPER_BOUNDS_MS.len() + 1];
+    for (bucket_index, count) in bucket_histograms.into_iter().flatten() {
+        if let Some(total) = bucket_index
+            .parse::<usize>()
+            .ok()
+            .and_then(|bucket_index| histogram.get_mut(bucket_index))
+        {
+            *total += count;
+        }
+    }
+
+    Ok(get_distribution_from_histogram(&histogram))
+}
+
+fn get_distribution_from_histogram(histogram: &[u64]) -> ConnectorLatencyDistribution {
+    let sample_count = histogram.iter().sum::<u64>();
+
+    if sample_count == 0 {
+        return ConnectorLatencyDistribution::default();
+    }
+
+    let get_percentile = |percentile: u64| {
+        // The rank of the sample at the percentile, rounded up
+        let rank = (sample_count * percentile).div_ceil(100).max(1);
+        let mut cumulative_count = 0;
+        histogram
+            .iter()
+            .enumerate()
+            .find_map(|(bucket_index, count)| {
+                cumulative_count += count;
+                (cumulative_count >= rank).then(|| {
+                    LATENCY_BUCKET_UPPER_BOUNDS_MS
+                        .get(bucket_index)
+                        .or(LATENCY_BUCKET_UPPER_BOUNDS_MS.last())
+                        .copied()
+                        .unwrap_or_default()
+                })
+            })
+    };
+
+    ConnectorLatencyDistribution {
+        sample_count,
+        p50_ms: get_percentile(50),
+        p95_ms: get_percentile(95),
+        p99_ms: get_percentile(99),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::indexing_slicing)]
+    use super::*;
+
+    #[test]
+    fn test_latency_is_counted_in_the_smallest_bucket_containing_it() {
+        assert_eq!(get_latency_bucket_index(0), 0);
+        assert_eq!(get_latency_bucket_index(5), 0);
+        assert_eq!(get_latency_bucket_index(6), 1);
+        assert_eq!(
+            get_latency_bucket_index(60001),
+            LATENCY_BUCKET_UPPER_BOUNDS_MS.len()
+        );
+    }
+
+    #[test]
+    fn test_percentiles_are_the_upper_bounds_of_their_buckets() {
+        let mut histogram = vec![0; LATENCY_BUCKET_UPPER_BOUNDS_MS.len() + 1];
+        // 90 samples of at most 100ms, 8 of at most 500ms and 2 above the last bound
+        histogram[get_latency_bucket_index(100)] = 90;
+        histogram[get_latency_bucket_index(500)] = 8;
+        histogram[LATENCY_BUCKET_UPPER_BOUNDS_MS.len()] = 2;
+
+        assert_eq!(
+            get_distribution_from_histogram(&histogram),
+            ConnectorLatencyDistribution {
+                sample_count: 100,
+                p50_ms: Some(100),
+                p95_ms: Some(500),
+                p99_ms: Some(60000),
+            }
+        );
+    }
+
+    #[test]
+    fn test_empty_histogram_has_no_percentiles() {
+        let histogram = vec![0; LATENCY_BUCKET_UPPER_BOUNDS_MS.len() + 1];
+
+        assert_eq!(
+            get_distribution_from_histogram(&histogram),
+            ConnectorLatencyDistribution::default()
+        );
+    }
+}
@@ -0,0 +1,244 @@
+//! Redaction of the requests sent to and the responses received from connectors.
+//!
+//! The fields configured in the connector log redaction policy are replaced before a connector
+//! exchange is logged or stored, in addition to the masking of the fields which are marked
+//! as secrets.
+
+use common_utils::request::RequestContent;
+use serde_json::{json, Value};
+
+use crate::{
+    configs::settings::ConnectorLogRedaction,
+    events::connector_api_logs::ConnectorEvent,
+    routes::{app::SessionStateInfo, SessionState},
+};
+
+/// Fields known to carry sensitive payment method data or credentials, which the connector log
+/// redaction policy is expected to cover
+pub const KNOWN_SENSITIVE_FIELDS: &[&str] = &[
+    "card_number",
+    "cardNumber",
+    "number",
+    "card_cvc",
+    "cvc",
+    "cvv",
+    "cvv2",
+    "security_code",
+    "pan",
+    "account_number",
+    "routing_number",
+    "iban",
+    "api_key",
+    "password",
+];
+
+const FIELD_PATH_SEPARATOR: char = '.';
+const WILDCARD_KEY: &str = "*";
+
+impl ConnectorLogRedaction {
+    /// Replace the fields of the value which match any of the configured field paths
+    pub fn redact(&self, value: &mut Value) {
+        self.field_paths.iter().for_each(|field_path| {
+            let keys = field_path.split(FIELD_PATH_SEPARATOR).collect::<Vec<_>>();
+            match keys.as_slice() {
+                [key] => redact_key_at_any_depth(value, key, &self.replacement),
+                keys => redact_path(value, keys, &self.replacement),
+            }
+        })
+    }
+
+    /// Get the body of a request to a connector with the secrets masked and the configured fields
+    /// redacted, to be logged in place of the request
+    pub fn get_redacted_request_body(&self, request_body: Option<&RequestContent>) -> Value {
+        let mut masked_request_body = match request_body {
+            Some(
+                RequestContent::Json(body)
+                | RequestContent::FormUrlEncoded(body)
+                | RequestContent::Xml(body),
+            ) => body
+                .masked_serialize()
+                .unwrap_or(json!({ "error": "failed to mask serialize"})),
+            Some(RequestContent::FormData(_)) => json!({"request_type": "FORM_DATA"}),
+            Some(RequestContent::RawBytes(_)) => json!({"request_type": "RAW_BYTES"}),
+            None => Value::Null,
+        };
+        self.redact(&mut masked_request_body);
+        masked_request_body
+    }
+
+    /// Redact the request, the response and the error of the connector event
+    pub fn redact_connector_event(&self, connector_event: &mut ConnectorEvent) {
+        connector_event.redact(|value| self.redact(value))
+    }
+
+    /// Get the known sensitive fields which are not covered by any of the configured field paths
+    pub fn get_uncovered_sensitive_fields(&self) -> Vec<&'static str> {
+        KNOWN_SENSITIVE_FIELDS
+            .iter()
+            .copied()
+            .filter(|sensitive_field| {
+                !self.field_paths.iter().any(|field_path| {
+                    field_path
+                        .rsplit(FIELD_PATH_SEPARATOR)
+                        .next()
+                        .is_some_and(|key| key == *sensitive_field || key == WILDCARD_KEY)
+                })
+            })
+            .collect()
+    }
+}
+
+fn matches_key(pattern: &str, key: &str) -> bool {
+    pattern == WILDCARD_KEY || pattern == key
+}
+
+fn redact_key_at_any_depth(value: &mut Value, pattern: &str, replacement: &str) {
+    match value {
+        Value::Object(fields) => fields.iter_mut().for_each(|(key, field)| {
+            if matches_key(pattern, key) {
+                *field = Value::String(replacement.to_owned());
+            } else {
+                redact_key_at_any_depth(field, pattern, replacement);
+            }
+        }),
+        Value::Array(items) => items
+            .iter_mut()
+            .for_each(|item| redact_key_at_any_depth(item, pattern, replacement)),
+        _ => {}
+    }
+}
+
+fn redact_path(value: &mut Value, path: &[&str], replacement: &str) {
+    match (value, path) {
+        (Value::Array(items), path) => items
+            .iter_mut()
+            .for_each(|item| redact_path(item, path, replacement)),
+        (Value::Object(fields), [pattern, rest @ ..]) => fields
+            .iter_mut()
+            .filter(|(key, _)| matches_key(pattern, key))
+            .for_each(|(_, field)| {
+                if rest.is_empty() {
+                    *field = Value::String(replacement.to_owned());
+                } else {
+                    redact_path(field, rest, replacement);
+                }
+            }),
+        _ => {}
+    }
+}
+
+/// Redact the connector event according to the configured policy, and log it
+pub fn log_connector_event(state: &SessionState, connector_event: &mut ConnectorEvent) {
+    state
+        .conf
+        .connector_log_redaction
+        .redact_connector_event(connector_event);
+    state.event_handler().log_event(&*connector_event);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_card_data_is_redacted() {
+        let policy = ConnectorLogRedaction::default();
+        let mut request = json!({
+            "amount": 1000,
+            "source": {
+                "card_number": "4242424242424242",
+                "card_cvc": "123",
+                "expiry_month": "10",
+            },
+            "payment_methods": [{ "card": { "cvv": "123", "pan": "4242424242424242" } }],
+        });
+
+        policy.redact(&mut request);
+
+        assert_eq!(
+            request,
+            json!({
+                "amount": 1000,
+                "source": {
+                    "card_number": "Redacted",
+                    "card_cvc": "Redacted",
+                    "expiry_month": "10",
+                },
+                "payment_methods": [{ "card": { "cvv": "Redacted", "pan": "Redacted" } }],
+            })
+        );
+        assert!(!request.to_string().contains("4242424242424242"));
+    }
+
+    #[test]
+    fn test_nested_field_path_is_redacted() {
+        let policy = ConnectorLogRedaction {
+            field_paths: vec!["payment.*.number".to_string()],
+            replacement: "[redacted]".to_string(),
+        };
+        let mut request = json!({
+            "payment": { "card": { "number": "4242424242424242" } },
+            "reference": { "number": "order_1" },
+        });
+
+        policy.redact(&mut request);
+
+        assert_eq!(
+            request,
+            json!({
+                "payment": { "card": { "number": "[redacted]" } },
+                "reference": { "number": "order_1" },
+            })
+        );
+    }
+
+    #[test]
+    fn test_uncovered_sensitive_fields() {
+        let policy = ConnectorLogRedaction {
+            field_paths: vec!["card_number".to_string(), "source.card_cvc".to_string()],
+            replacement: "Redacted".to_string(),
+        };
+
+        let uncovered_fields = policy.get_uncovered_sensitive_fields();
+
+        assert!(!uncovered_fields.contains(&"card_number"));
+        assert!(!uncovered_fields.contains(&"card_cvc"));
+        assert!(uncovered_fields.contains(&"cvv"));
+    }
+
+    #[test]
+    fn test_card_number_field_variants_are_redacted() {
+        let policy = ConnectorLogRedaction::default();
+        let mut request = json!({
+            "paymentMethod": { "cardNumber": "4242424242424242" },
+            "card": { "number": "4242424242424242", "expiry_year": "2030" },
+        });
+
+        policy.redact(&mut request);
+
+        assert_eq!(
+            request,
+            json!({
+                "paymentMethod": { "cardNumber": "Redacted" },
+                "card": { "number": "Redacted", "expiry_year": "2030" },
+            })
+        );
+    }
+
+    #[test]
+    fn test_request_body_is_redacted() {
+        let policy = ConnectorLogRedaction::default();
+        let request_body = RequestContent::Json(Box::new(json!({
+            "amount": 1000,
+            "card": { "number": "4242424242424242" },
+        })));
+
+        let redacted_request_body = policy.get_redacted_request_body(Some(&request_body));
+
+        assert_eq!(
+            redacted_request_body,
+            json!({ "amount": 1000, "card": { "number": "Redacted" } })
+        );
+        assert_eq!(policy.get_redacted_request_body(None), Value::Null);
+    }
+}
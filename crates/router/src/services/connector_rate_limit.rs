@@ -0,0 +1,374 @@
+//! Honoring of the rate limits of the connectors.
+//!
+//! A connector which is rate limiting the calls to it responds with the status `429 Too Many
+//! Requests`, usually along with a `Retry-After` header carrying either the number of seconds
+//! after which the call can be retried or the HTTP date at which it can be retried. The connector
+//! is then backed off for that time across all the instances of the application, through a key in
+//! redis holding the time until which the connector is backed off, and the calls to the connector
+//! are deferred until the backoff ends. A call is deferred for at most `max_deferral_in_secs` in
+//! total, after which it is shed while the connector is backed off.
+//!
+//! Failures to reach redis are logged, in which case the backoff is only honored by the call which
+//! was rate limited.
+
+use std::time::Duration;
+
+use error_stack::{report, ResultExt};
+use router_env::{instrument, metrics::add_attributes, tracing};
+use time::OffsetDateTime;
+
+use crate::{
+    configs::settings::ConnectorRateLimit,
+    consts,
+    core::errors::{self, CustomResult},
+    logger,
+    routes::{metrics, SessionState},
+    types,
+};
+
+/// The deferrals of a call to a connector while the connector was backed off
+#[derive(Clone, Copy, Debug, Default)]
+pub struct RateLimitDeferral {
+    /// The number of times the call was retried after being rate limited by the connector
+    pub retry_count: u8,
+    /// The total time for which the call was deferred
+    pub deferred_for: Duration,
+}
+
+impl RateLimitDeferral {
+    /// Whether the call was deferred or retried at all
+    pub fn is_deferred(&self) -> bool {
+        self.retry_count > 0 || !self.deferred_for.is_zero()
+    }
+
+    /// Whether the call which was rate limited can be retried once the connector is no longer
+    /// backed off
+    pub fn can_retry(&self, config: &ConnectorRateLimit, backoff: Duration) -> bool {
+        self.retry_count < config.max_retries
+            && self.deferred_for.saturating_add(backoff) <= get_max_deferral(config)
+    }
+}
+
+fn get_redis_key(connector: &str) -> String {
+    format!("{}{connector}", consts::CONNECTOR_RATE_LIMIT_PREFIX)
+}
+
+fn get_max_deferral(config: &ConnectorRateLimit) -> Duration {
+    Duration::from_secs(config.max_deferral_in_secs.into())
+}
+
+fn get_unix_timestamp_millis(time: OffsetDateTime) -> i64 {
+    i64::try_from(time.unix_timestamp_nanos() / 1_000_000).unwrap_or(i64::MAX)
+}
+
+/// The number of whole seconds after which the backoff ends, rounded up
+fn get_retry_after_in_secs(backoff: Duration) -> u32 {
+    let seconds = backoff.as_secs() + u64::from(backoff.subsec_nanos() > 0);
+    u32::try_from(seconds).unwrap_or(u32::MAX)
+}
+
+/// Whether the `Retry-After` header of the responses of the connector is honored
+pub fn is_enabled_for_connector(config: &ConnectorRateLimit, connector: &str) -> bool {
+    config.connectors.contains(connector)
+}
+
+/// Parse the value of a `Retry-After` header, which is either a number of seconds or an HTTP date.
+/// A date which has already passed means that the call can be retried immediately.
+pub fn parse_retry_after(value: &str, now: OffsetDateTime) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let retry_at =
+        OffsetDateTime::parse(value, &time::format_description::well_known::Rfc2822).ok()?;
+    Some(Duration::try_from(retry_at - now).unwrap_or(Duration::ZERO))
+}
+
+/// The time for which the connector is backed off after the response which rate limited the call.
+/// The default backoff is used when the `Retry-After` header is missing or malformed, and the
+/// backoff is capped to `max_retry_after_in_secs`.
+pub fn get_backoff(
+    config: &ConnectorRateLimit,
+    response: &types::Response,
+    now: OffsetDateTime,
+) -> Duration {
+    let retry_after = response
+        .headers
+        .as_ref()
+        .and_then(|headers| headers.get(reqwest::header::RETRY_AFTER))
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| parse_retry_after(value, now));
+
+    retry_after
+        .unwrap_or_else(|| {
+            logger::warn!(
+                "Missing or malformed Retry-After header in the rate limited response, using the \
+                 default backoff"
+            );
+            Duration::from_secs(config.default_retry_after_in_secs.into())
+        })
+        .min(Duration::from_secs(config.max_retry_after_in_secs.into()))
+}
+
+/// Back off the connector until the time. The most recent backoff of the connector replaces any
+/// earlier backoff, since it reflects the latest rate limit of the connector.
+async fn set_backoff(
+    state: &SessionState,
+    connector: &str,
+    backoff: Duration,
+    backoff_until: OffsetDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .set_key_with_expiry(
+            &get_redis_key(connector),
+            get_unix_timestamp_millis(backoff_until),
+            get_retry_after_in_secs(backoff).into(),
+        )
+        .await
+        .change_context(errors::StorageError::KVError)
+}
+
+/// The time left until the backoff of the connector ends, if the connector is backed off
+async fn get_remaining_backoff(
+    state: &SessionState,
+    connector: &str,
+    now: OffsetDateTime,
+) -> CustomResult<Option<Duration>, errors::StorageError> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let backoff_until = redis_conn
+        .get_key::<Option<i64>>(&get_redis_key(connector))
+        .await
+        .change_context(errors::StorageError::KVError)?;
+
+    Ok(backoff_until
+        .and_then(|backoff_until| {
+            u64::try_from(backoff_until.saturating_sub(get_unix_timestamp_millis(now))).ok()
+        })
+        .filter(|remaining_millis| *remaining_millis > 0)
+        .map(Duration::from_millis))
+}
+
+/// Record that the connector rate limited the call, backing off the connector for the time in the
+/// `Retry-After` header of the response. Returns the time for which the connector is backed off,
+/// for the connectors of which the header is honored.
+#[instrument(skip(state, response))]
+pub async fn record_rate_limited_response(
+    state: &SessionState,
+    connector: &str,
+    response: &types::Response,
+) -> Option<Duration> {
+    let config = &state.conf.connector_rate_limit;
+    if !is_enabled_for_connector(config, connector) {
+        return None;
+    }
+
+    let now = OffsetDateTime::now_utc();
+    let backoff = get_backoff(config, response, now);
+    metrics::CONNECTOR_RATE_LIMITED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("connector", connector.to_owned())]),
+    );
+    logger::warn!(
+        connector,
+        ?backoff,
+        "Connector rate limited the call, backing off the connector"
+    );
+
+    // A connector which can be called again immediately is not backed off
+    if !backoff.is_zero() {
+        set_backoff(state, connector, backoff, now + backoff)
+            .await
+            .inspect_err(|error| logger::error!(?error, "Failed to back off the connector"))
+            .ok();
+    }
+
+    Some(backoff)
+}
+
+/// Defer the call to the connector while the connector is backed off, after which the call can be
+/// made. `rate_limited_backoff` is the backoff which followed the rate limited call which is being
+/// retried, which is honored even if the backoff of the connector could not be read from redis. A
+/// call which would be deferred for longer than `max_deferral_in_secs` in total is shed.
+#[instrument(skip(state, deferral))]
+pub async fn defer_while_backed_off(
+    state: &SessionState,
+    connector: &str,
+    deferral: &mut RateLimitDeferral,
+    rate_limited_backoff: Option<Duration>,
+) -> CustomResult<(), errors::ConnectorError> {
+    let config = &state.conf.connector_rate_limit;
+    if !is_enabled_for_connector(config, connector) {
+        return Ok(());
+    }
+
+    let remaining_backoff = get_remaining_backoff(state, connector, OffsetDateTime::now_utc())
+        .await
+        .inspect_err(|error| logger::error!(?error, "Failed to get the backoff of the connector"))
+        .ok()
+        .flatten();
+    let Some(backoff) = remaining_backoff
+        .max(rate_limited_backoff)
+        .filter(|backoff| !backoff.is_zero())
+    else {
+        return Ok(());
+    };
+
+    if deferral.deferred_for.saturating_add(backoff) > get_max_deferral(config) {
+        logger::warn!(
+            connector,
+            ?backoff,
+            "Shedding the connector call, the connector is backed off for longer than the call \
+             can be deferred"
+        );
+        return Err(report!(errors::ConnectorError::RateLimited {
+            connector: connector.to_owned(),
+            retry_after_in_secs: get_retry_after_in_secs(backoff),
+        }));
+    }
+
+    metrics::CONNECTOR_RATE_LIMIT_DEFERRAL_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("connector", connector.to_owned())]),
+    );
+    logger::info!(
+        connector,
+        ?backoff,
+        "Deferring the connector call while the connector is backed off"
+    );
+    tokio::time::sleep(backoff).await;
+    deferral.deferred_for = deferral.deferred_for.saturating_add(backoff);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::collections::HashSet;
+
+    use super::*;
+
+    fn get_config() -> ConnectorRateLimit {
+        ConnectorRateLimit {
+            connectors: HashSet::from(["stripe".to_string()]),
+            max_retries: 1,
+            max_deferral_in_secs: 5,
+            default_retry_after_in_secs: 1,
+            max_retry_after_in_secs: 60,
+        }
+    }
+
+    fn get_rate_limited_response(retry_after: Option<&str>) -> types::Response {
+        let mut headers = reqwest::header::HeaderMap::new();
+        if let Some(retry_after) = retry_after {
+            headers.insert(
+                reqwest::header::RETRY_AFTER,
+                reqwest::header::HeaderValue::from_str(retry_after).unwrap(),
+            );
+        }
+
+        types::Response {
+            headers: Some(headers),
+            response: bytes::Bytes::new(),
+            status_code: 429,
+        }
+    }
+
+    #[test]
+    fn test_retry_after_in_seconds_is_parsed() {
+        let now = OffsetDateTime::now_utc();
+
+        assert_eq!(
+            parse_retry_after("120", now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(parse_retry_after(" 0 ", now), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_retry_after_http_date_is_parsed() {
+        let now = OffsetDateTime::parse(
+            "Wed, 21 Oct 2015 07:27:30 GMT",
+            &time::format_description::well_known::Rfc2822,
+        )
+        .unwrap();
+
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT", now),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:27:00 GMT", now),
+            Some(Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn test_malformed_retry_after_is_not_parsed() {
+        let now = OffsetDateTime::now_utc();
+
+        assert_eq!(parse_retry_after("soon", now), None);
+        assert_eq!(parse_retry_after("-5", now), None);
+    }
+
+    #[test]
+    fn test_backoff_defaults_when_retry_after_is_missing_or_malformed() {
+        let config = get_config();
+        let now = OffsetDateTime::now_utc();
+
+        assert_eq!(
+            get_backoff(&config, &get_rate_limited_response(None), now),
+            Duration::from_secs(1)
+        );
+        assert_eq!(
+            get_backoff(&config, &get_rate_limited_response(Some("soon")), now),
+            Duration::from_secs(1)
+        );
+    }
+
+    #[test]
+    fn test_backoff_is_capped() {
+        let config = get_config();
+        let now = OffsetDateTime::now_utc();
+
+        assert_eq!(
+            get_backoff(&config, &get_rate_limited_response(Some("3600")), now),
+            Duration::from_secs(60)
+        );
+    }
+
+    #[test]
+    fn test_rate_limited_call_is_retried_within_the_deferral_limits() {
+        let config = get_config();
+        let deferral = RateLimitDeferral::default();
+
+        assert!(deferral.can_retry(&config, Duration::from_secs(2)));
+        assert!(!deferral.can_retry(&config, Duration::from_secs(10)));
+
+        let deferral = RateLimitDeferral {
+            retry_count: 1,
+            deferred_for: Duration::from_secs(2),
+        };
+        assert!(!deferral.can_retry(&config, Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn test_retry_after_in_secs_is_rounded_up() {
+        assert_eq!(get_retry_after_in_secs(Duration::from_millis(1500)), 2);
+        assert_eq!(get_retry_after_in_secs(Duration::from_secs(3)), 3);
+    }
+}
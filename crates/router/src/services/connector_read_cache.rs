@@ -0,0 +1,228 @@
+//! Short lived cache of connector responses for idempotent read operations.
+//!
+//! Responses are cached in redis keyed by the merchant, the connector, the read flow and the
+//! connector's identifier of the resource being read. The raw connector response is encrypted with the master key before
+//! it is written to redis, since connector responses may carry sensitive payment method data.
+//! Flows which modify the resource at the connector are expected to invalidate the cached response.
+
+use common_utils::{
+    crypto::{DecodeMessage, EncodeMessage, GcmAes256},
+    ext_traits::{ByteSliceExt, Encode},
+    id_type,
+};
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    consts,
+    core::errors::{self, CustomResult},
+    logger,
+    routes::SessionState,
+    types,
+};
+
+/// The read flow whose connector response is cached
+///
+/// Connectors may use the same identifier for a payment and its refunds, so each flow is cached
+/// under its own key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum ConnectorReadFlow {
+    PaymentSync,
+    RefundSync,
+}
+
+/// Identifies the connector resource whose read response is cached
+#[derive(Debug, Clone)]
+pub struct ConnectorReadCacheKey {
+    pub merchant_id: id_type::MerchantId,
+    pub connector: String,
+    pub flow: ConnectorReadFlow,
+    pub resource_id: String,
+}
+
+impl ConnectorReadCacheKey {
+    pub fn new(
+        merchant_id: id_type::MerchantId,
+        connector: impl Into<String>,
+        flow: ConnectorReadFlow,
+        resource_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            merchant_id,
+            connector: connector.into(),
+            flow,
+            resource_id: resource_id.into(),
+        }
+    }
+
+    fn get_redis_key(&self) -> String {
+        format!(
+            "{}{}_{}_{}_{}",
+            consts::CONNECTOR_READ_CACHE_PREFIX,
+            self.merchant_id.get_string_repr(),
+            self.connector,
+            self.flow,
+            self.resource_id
+        )
+    }
+}
+
+/// Enables the read cache for a connector call
+pub struct ConnectorReadCache<T, Req, Resp> {
+    pub key: ConnectorReadCacheKey,
+    /// Whether the router data obtained from the connector response may be served from the cache
+    /// on subsequent reads, for example only once the resource has reached a terminal state
+    pub is_cacheable: fn(&types::RouterData<T, Req, Resp>) -> bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedConnectorResponse {
+    status_code: u16,
+    body: Vec<u8>,
+}
+
+/// Fetch the cached connector response for the resource, if any.
+///
+/// Failures to read the cache are logged and treated as a cache miss.
+#[instrument(skip_all)]
+pub async fn get_cached_connector_response(
+    state: &SessionState,
+    key: &ConnectorReadCacheKey,
+) -> Option<types::Response> {
+    get_cached_connector_response_inner(state, key)
+        .await
+        .inspect_err(|error| logger::error!(?error, "Failed to read connector read cache"))
+        .ok()
+        .flatten()
+}
+
+async fn get_cached_connector_response_inner(
+    state: &SessionState,
+    key: &ConnectorReadCacheKey,
+) -> CustomResult<Option<types::Response>, errors::StorageError> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::StorageError::KVError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let Some(encrypted_response) = redis_conn
+        .get_key::<Option<bytes::Bytes>>(&key.get_redis_key())
+        .await
+        .change_context(errors::StorageError::KVError)?
+    else {
+        return Ok(None);
+    };
+
+    let decrypted_response = GcmAes256
+        .decode_message(
+            state.store.get_master_key(),
+            masking::Secret::new(encrypted_response.into()),
+        )
+        .change_context(errors::StorageError::DecryptionError)
+        .attach_printable("Failed to decrypt cached connector response")?;
+
+    let cached_response: CachedConnectorResponse = decrypted_response
+        .parse_struct("CachedConnectorResponse")
+        .change_context(errors::StorageError::DeserializationFailed)?;
+
+    Ok(Some(types::Response {
+        headers: None,
+        response: cached_response.body.into(),
+        status_code: cached_response.status_code,
+    }))
+}
+
+/// Cache the connector response for the resource with a short expiry.
+///
+/// Failures to write the cache are logged and do not fail the connector call.
+#[instrument(skip_all)]
+pub async fn cache_connector_response(
+    state: &SessionState,
+    key: &ConnectorReadCacheKey,
+    status_code: u16,
+    body: &[u8],
+) {
+    let cached_response = CachedConnectorResponse {
+        status_code,
+        body: body.to_vec(),
+    };
+    let result = async {
+        let serialized_response = cached_response
+            .encode_to_vec()
+            .change_context(errors::StorageError::SerializationFailed)?;
+
+        let encrypted_response = GcmAes256
+            .encode_message(state.store.get_master_key(), &serialized_response)
+            .change_context(errors::StorageError::EncryptionError)
+            .attach_printable("Failed to encrypt connector response")?;
+
+        state
+            .store
+            .get_redis_conn()
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to get redis connection")?
+            .set_key_with_expiry(
+                &key.get_redis_key(),
+                bytes::Bytes::from(encrypted_response),
+                consts::CONNECTOR_READ_CACHE_TTL,
+            )
+            .await
+            .change_context(errors::StorageError::KVError)
+    }
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to cache connector read response");
+    }
+}
+
+/// Remove the cached connector response for the resource, to be called whenever the resource is
+/// modified.
+#[instrument(skip_all)]
+pub async fn invalidate_connector_read_cache(state: &SessionState, key: &ConnectorReadCacheKey) {
+    let result = async {
+        state
+            .store
+            .get_redis_conn()
+            .change_context(errors::StorageError::KVError)
+            .attach_printable("Failed to get redis connection")?
+            .delete_key(&key.get_redis_key())
+            .await
+            .change_context(errors::StorageError::KVError)
+    }
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to invalidate connector read cache");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payment_and_refund_sync_of_same_resource_have_different_keys() {
+        let merchant_id = id_type::MerchantId::default();
+        let payment_sync_key = ConnectorReadCacheKey::new(
+            merchant_id.clone(),
+            "stripe",
+            ConnectorReadFlow::PaymentSync,
+            "txn_123",
+        );
+        let refund_sync_key = ConnectorReadCacheKey::new(
+            merchant_id,
+            "stripe",
+            ConnectorReadFlow::RefundSync,
+            "txn_123",
+        );
+
+        assert_ne!(
+            payment_sync_key.get_redis_key(),
+            refund_sync_key.get_redis_key()
+        );
+    }
+}
@@ -20,7 +20,7 @@ use crate::errors;
 fn get_irrelevant_id_string(id_name: &str, flow_name: &str) -> String {
     format!("irrelevant {id_name} in {flow_name} flow")
 }
-fn get_default_router_data<F, Req, Resp>(
+pub(crate) fn get_default_router_data<F, Req, Resp>(
     flow_name: &str,
     request: Req,
     response: Result<Resp, router_data::ErrorResponse>,
@@ -44,6 +44,7 @@ fn get_default_router_data<F, Req, Resp>(
         auth_type: common_enums::AuthenticationType::default(),
         connector_meta_data: None,
         connector_wallets_details: None,
+        connector_request_timeout_secs: None,
         amount_captured: None,
         access_token: None,
         session_token: None,
@@ -66,6 +67,7 @@ fn get_default_router_data<F, Req, Resp>(
         test_mode: None,
         connector_http_status_code: None,
         external_latency: None,
+        connector_network_retry_count: None,
         apple_pay_flow: None,
         frm_metadata: None,
         dispute_id: None,
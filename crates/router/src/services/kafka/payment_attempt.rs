@@ -57,6 +57,7 @@ pub struct KafkaPaymentAttempt<'a> {
     pub profile_id: &'a id_type::ProfileId,
     pub organization_id: &'a id_type::OrganizationId,
     pub card_network: Option<String>,
+    pub is_connector_override: Option<bool>,
 }
 
 #[cfg(feature = "v1")]
@@ -115,6 +116,7 @@ impl<'a> KafkaPaymentAttempt<'a> {
                 .and_then(|card| card.get("card_network"))
                 .and_then(|network| network.as_str())
                 .map(|network| network.to_string()),
+            is_connector_override: attempt.is_connector_override,
         }
     }
 }
@@ -33,7 +33,8 @@ use hyperswitch_domain_models::router_flow_types::{
     payments::{
         Approve, Authorize, AuthorizeSessionToken, Balance, CalculateTax, Capture,
         CompleteAuthorize, CreateConnectorCustomer, IncrementalAuthorization, InitPayment, PSync,
-        PostProcessing, PreProcessing, Reject, SdkSessionUpdate, Session, SetupMandate, Void,
+        PostProcessing, PreProcessing, Reject, SdkSessionUpdate, Session, SetupMandate,
+        UpdateConnectorCustomer, Void,
     },
     refunds::{Execute, RSync},
     webhooks::VerifyWebhookSource,
@@ -53,10 +54,10 @@ pub use hyperswitch_domain_models::{
     router_request_types::{
         AcceptDisputeRequestData, AccessTokenRequestData, AuthorizeSessionTokenData,
         BrowserInformation, ChargeRefunds, ChargeRefundsOptions, CompleteAuthorizeData,
-        CompleteAuthorizeRedirectResponse, ConnectorCustomerData, DefendDisputeRequestData,
-        DestinationChargeRefund, DirectChargeRefund, MandateRevokeRequestData,
-        MultipleCaptureRequestData, PaymentMethodTokenizationData, PaymentsApproveData,
-        PaymentsAuthorizeData, PaymentsCancelData, PaymentsCaptureData,
+        CompleteAuthorizeRedirectResponse, ConnectorCustomerData, ConnectorCustomerUpdateData,
+        DefendDisputeRequestData, DestinationChargeRefund, DirectChargeRefund,
+        MandateRevokeRequestData, MultipleCaptureRequestData, PaymentMethodTokenizationData,
+        PaymentsApproveData, PaymentsAuthorizeData, PaymentsCancelData, PaymentsCaptureData,
         PaymentsIncrementalAuthorizationData, PaymentsPostProcessingData,
         PaymentsPreProcessingData, PaymentsRejectData, PaymentsSessionData, PaymentsSyncData,
         PaymentsTaxCalculationData, RefundsData, ResponseId, RetrieveFileRequestData,
@@ -77,13 +78,13 @@ pub use hyperswitch_domain_models::{
     router_response_types::PayoutsResponseData,
 };
 pub use hyperswitch_interfaces::types::{
-    AcceptDisputeType, ConnectorCustomerType, DefendDisputeType, IncrementalAuthorizationType,
-    MandateRevokeType, PaymentsAuthorizeType, PaymentsBalanceType, PaymentsCaptureType,
-    PaymentsCompleteAuthorizeType, PaymentsInitType, PaymentsPostProcessingType,
-    PaymentsPreAuthorizeType, PaymentsPreProcessingType, PaymentsSessionType, PaymentsSyncType,
-    PaymentsVoidType, RefreshTokenType, RefundExecuteType, RefundSyncType, Response,
-    RetrieveFileType, SetupMandateType, SubmitEvidenceType, TokenizationType, UploadFileType,
-    VerifyWebhookSourceType,
+    AcceptDisputeType, ConnectorCustomerType, ConnectorCustomerUpdateType, DefendDisputeType,
+    IncrementalAuthorizationType, MandateRevokeType, PaymentsAuthorizeType, PaymentsBalanceType,
+    PaymentsCaptureType, PaymentsCompleteAuthorizeType, PaymentsInitType,
+    PaymentsPostProcessingType, PaymentsPreAuthorizeType, PaymentsPreProcessingType,
+    PaymentsSessionType, PaymentsSyncType, PaymentsVoidType, RefreshTokenType, RefundExecuteType,
+    RefundSyncType, Response, RetrieveFileType, SetupMandateType, SubmitEvidenceType,
+    TokenizationType, UploadFileType, VerifyWebhookSourceType,
 };
 #[cfg(feature = "payouts")]
 pub use hyperswitch_interfaces::types::{
@@ -148,6 +149,8 @@ pub type TokenizationRouterData = RouterData<
 >;
 pub type ConnectorCustomerRouterData =
     RouterData<CreateConnectorCustomer, ConnectorCustomerData, PaymentsResponseData>;
+pub type ConnectorCustomerUpdateRouterData =
+    RouterData<UpdateConnectorCustomer, ConnectorCustomerUpdateData, PaymentsResponseData>;
 
 pub type RefreshTokenRouterData = RouterData<AccessTokenAuth, AccessTokenRequestData, AccessToken>;
 
@@ -171,6 +174,12 @@ pub type TokenizationResponseRouterData<R> =
     ResponseRouterData<PaymentMethodToken, R, PaymentMethodTokenizationData, PaymentsResponseData>;
 pub type ConnectorCustomerResponseRouterData<R> =
     ResponseRouterData<CreateConnectorCustomer, R, ConnectorCustomerData, PaymentsResponseData>;
+pub type ConnectorCustomerUpdateResponseRouterData<R> = ResponseRouterData<
+    UpdateConnectorCustomer,
+    R,
+    ConnectorCustomerUpdateData,
+    PaymentsResponseData,
+>;
 
 pub type RefundsResponseRouterData<F, R> =
     ResponseRouterData<F, R, RefundsData, RefundsResponseData>;
@@ -848,6 +857,8 @@ impl ForeignFrom<&SetupMandateRouterData> for PaymentsAuthorizeData {
             customer_acceptance: data.request.customer_acceptance.clone(),
             charges: None, // TODO: allow charges on mandates?
             merchant_order_reference_id: None,
+            amount_breakdown: None,
+            customer_tax_id: None,
             integrity_object: None,
         }
     }
@@ -874,6 +885,7 @@ impl<F1, F2, T1, T2> ForeignFrom<(&RouterData<F1, T1, PaymentsResponseData>, T2)
             auth_type: data.auth_type,
             connector_meta_data: data.connector_meta_data.clone(),
             connector_wallets_details: data.connector_wallets_details.clone(),
+            connector_request_timeout_secs: data.connector_request_timeout_secs,
             amount_captured: data.amount_captured,
             minor_amount_captured: data.minor_amount_captured,
             access_token: data.access_token.clone(),
@@ -897,6 +909,7 @@ impl<F1, F2, T1, T2> ForeignFrom<(&RouterData<F1, T1, PaymentsResponseData>, T2)
             connector_api_version: data.connector_api_version.clone(),
             connector_http_status_code: data.connector_http_status_code,
             external_latency: data.external_latency,
+            connector_network_retry_count: data.connector_network_retry_count,
             apple_pay_flow: data.apple_pay_flow.clone(),
             frm_metadata: data.frm_metadata.clone(),
             dispute_id: data.dispute_id.clone(),
@@ -939,6 +952,7 @@ impl<F1, F2>
             auth_type: data.auth_type,
             connector_meta_data: data.connector_meta_data.clone(),
             connector_wallets_details: data.connector_wallets_details.clone(),
+            connector_request_timeout_secs: data.connector_request_timeout_secs,
             amount_captured: data.amount_captured,
             minor_amount_captured: data.minor_amount_captured,
             access_token: data.access_token.clone(),
@@ -961,6 +975,7 @@ impl<F1, F2>
             connector_api_version: None,
             connector_http_status_code: data.connector_http_status_code,
             external_latency: data.external_latency,
+            connector_network_retry_count: data.connector_network_retry_count,
             apple_pay_flow: None,
             frm_metadata: None,
             refund_id: None,
@@ -20,7 +20,6 @@ pub mod payouts;
 pub mod poll;
 pub mod refunds;
 pub mod routing;
-#[cfg(feature = "olap")]
 pub mod verify_connector;
 #[cfg(feature = "olap")]
 pub mod webhook_events;
@@ -269,6 +268,7 @@ pub enum ConnectorChoice {
     SessionMultiple(Vec<SessionConnectorData>),
     StraightThrough(serde_json::Value),
     Decide,
+    Override(RoutableConnectorChoice),
 }
 
 impl ConnectorData {
@@ -576,7 +576,16 @@ pub trait PayoutsV2 {}
 #[cfg(test)]
 mod test {
     #![allow(clippy::unwrap_used)]
+    use strum::IntoEnumIterator;
+
     use super::*;
+    use crate::{
+        services::{
+            self, connector_integration_interface::ConnectorIntegrationInterface,
+            conversion_impls::get_default_router_data, ConnectorValidation,
+        },
+        types::domain,
+    };
 
     #[test]
     fn test_convert_connector_parsing_success() {
@@ -613,6 +622,163 @@ mod test {
         let result = enums::Connector::from_str("Opennode");
         assert!(result.is_err());
     }
+
+    fn get_connectors_with_integration() -> Vec<(api_enums::Connector, ConnectorEnum)> {
+        api_enums::Connector::iter()
+            .filter_map(|connector_name| {
+                ConnectorData::convert_connector(
+                    &Connectors::default(),
+                    &connector_name.to_string(),
+                )
+                .ok()
+                .map(|connector| (connector_name, connector))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_declared_partial_capture_capability_matches_integration() {
+        for (connector_name, connector) in get_connectors_with_integration() {
+            let is_supported = connector
+                .validate_capture_method(
+                    Some(api_enums::CaptureMethod::ManualMultiple),
+                    Some(api_enums::PaymentMethodType::Credit),
+                )
+                .is_ok();
+            assert_eq!(
+                connector_name.get_capabilities().partial_capture,
+                is_supported,
+                "partial capture capability of {connector_name}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_declared_mandates_capability_matches_integration() {
+        let payment_methods_data = [
+            domain::PaymentMethodData::Card(domain::Card::default()),
+            domain::PaymentMethodData::BankDebit(domain::BankDebitData::SepaBankDebit {
+                iban: masking::Secret::new("DE89370400440532013000".to_string()),
+                bank_account_holder_name: None,
+            }),
+        ];
+        for (connector_name, connector) in get_connectors_with_integration() {
+            let is_supported = payment_methods_data.iter().any(|payment_method_data| {
+                connector
+                    .validate_mandate_payment(None, payment_method_data.clone())
+                    .is_ok()
+            });
+            assert_eq!(
+                connector_name.get_capabilities().mandates,
+                is_supported,
+                "mandates capability of {connector_name}"
+            );
+        }
+    }
+
+    /// Whether the connector builds a request for the flow. The default integration of a flow
+    /// builds no request, while the connectors which do not support an integrated flow reject it.
+    fn is_flow_implemented(
+        request: CustomResult<Option<services::Request>, errors::ConnectorError>,
+    ) -> bool {
+        match request {
+            Ok(request) => request.is_some(),
+            Err(error) => !matches!(
+                error.current_context(),
+                errors::ConnectorError::NotImplemented(_)
+                    | errors::ConnectorError::NotSupported { .. }
+                    | errors::ConnectorError::FlowNotSupported { .. }
+            ),
+        }
+    }
+
+    #[test]
+    fn test_declared_void_capability_matches_integration() {
+        let router_data: types::PaymentsCancelRouterData = get_default_router_data(
+            "void",
+            types::PaymentsCancelData::default(),
+            Err(types::ErrorResponse::default()),
+        );
+        for (connector_name, connector) in get_connectors_with_integration() {
+            let connector_integration: services::BoxedPaymentConnectorIntegrationInterface<
+                Void,
+                types::PaymentsCancelData,
+                types::PaymentsResponseData,
+            > = connector.get_connector_integration();
+            let is_supported = is_flow_implemented(
+                connector_integration.build_request(&router_data, &Connectors::default()),
+            );
+            assert_eq!(
+                connector_name.get_capabilities().void,
+                is_supported,
+                "void capability of {connector_name}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_declared_refund_capability_matches_integration() {
+        let router_data: types::RefundExecuteRouterData = get_default_router_data(
+            "refund",
+            types::RefundsData {
+                refund_id: "refund_id".to_string(),
+                connector_transaction_id: "connector_transaction_id".to_string(),
+                connector_refund_id: None,
+                currency: api_enums::Currency::USD,
+                payment_amount: 1000,
+                reason: None,
+                webhook_url: None,
+                refund_amount: 1000,
+                connector_metadata: None,
+                browser_info: None,
+                charges: None,
+                minor_payment_amount: common_utils::types::MinorUnit::new(1000),
+                minor_refund_amount: common_utils::types::MinorUnit::new(1000),
+                integrity_object: None,
+            },
+            Err(types::ErrorResponse::default()),
+        );
+        for (connector_name, connector) in get_connectors_with_integration() {
+            let connector_integration: services::BoxedRefundConnectorIntegrationInterface<
+                Execute,
+                types::RefundsData,
+                types::RefundsResponseData,
+            > = connector.get_connector_integration();
+            let is_supported = is_flow_implemented(
+                connector_integration.build_request(&router_data, &Connectors::default()),
+            );
+            assert_eq!(
+                connector_name.get_capabilities().refund,
+                is_supported,
+                "refund capability of {connector_name}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_declared_incremental_authorization_capability_matches_integration() {
+        let router_data: types::PaymentsIncrementalAuthorizationRouterData =
+            get_default_router_data(
+                "incremental authorization",
+                types::PaymentsIncrementalAuthorizationData::default(),
+                Err(types::ErrorResponse::default()),
+            );
+        for (connector_name, connector) in get_connectors_with_integration() {
+            let connector_integration: services::BoxedPaymentConnectorIntegrationInterface<
+                IncrementalAuthorization,
+                types::PaymentsIncrementalAuthorizationData,
+                types::PaymentsResponseData,
+            > = connector.get_connector_integration();
+            let is_supported = is_flow_implemented(
+                connector_integration.build_request(&router_data, &Connectors::default()),
+            );
+            assert_eq!(
+                connector_name.get_capabilities().incremental_authorization,
+                is_supported,
+                "incremental authorization capability of {connector_name}"
+            );
+        }
+    }
 }
 
 #[derive(Clone)]
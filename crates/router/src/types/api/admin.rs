@@ -2,14 +2,22 @@ use std::collections::HashMap;
 
 #[cfg(feature = "v2")]
 pub use api_models::admin;
+#[cfg(feature = "v1")]
+pub use api_models::admin::{
+    MerchantConnectorCredentialsPromoteRequest, MerchantConnectorCredentialsRotateRequest,
+};
 pub use api_models::{
     admin::{
+        AuditLogEntry, AuditLogFieldChange, AuditLogResponse, AuditLogRetrieveRequest,
+        LegalHoldCreateRequest, LegalHoldDeleteRequest, LegalHoldListRequest, LegalHoldResponse,
         MerchantAccountCreate, MerchantAccountDeleteResponse, MerchantAccountResponse,
         MerchantAccountUpdate, MerchantConnectorCreate, MerchantConnectorDeleteResponse,
         MerchantConnectorDetails, MerchantConnectorDetailsWrap, MerchantConnectorId,
-        MerchantConnectorResponse, MerchantDetails, MerchantId, PaymentMethodsEnabled,
-        ProfileCreate, ProfileResponse, ProfileUpdate, ToggleAllKVRequest, ToggleAllKVResponse,
-        ToggleKVRequest, ToggleKVResponse, WebhookDetails,
+        MerchantConnectorResponse, MerchantDetails, MerchantId, MerchantOnboardingStatusResponse,
+        MerchantOnboardingStatusUpdateRequest, PaymentMethodRetokenizationRequest,
+        PaymentMethodRetokenizationResponse, PaymentMethodsEnabled, ProfileCreate, ProfileResponse,
+        ProfileUpdate, ToggleAllKVRequest, ToggleAllKVResponse, ToggleKVRequest, ToggleKVResponse,
+        WebhookDetails,
     },
     organization::{
         OrganizationCreateRequest, OrganizationId, OrganizationResponse, OrganizationUpdateRequest,
@@ -44,6 +52,18 @@ impl ForeignFrom<diesel_models::organization::Organization> for OrganizationResp
     }
 }
 
+impl ForeignFrom<diesel_models::legal_hold::LegalHold> for LegalHoldResponse {
+    fn foreign_from(legal_hold: diesel_models::legal_hold::LegalHold) -> Self {
+        Self {
+            merchant_id: legal_hold.merchant_id,
+            entity_type: legal_hold.entity_type,
+            record_id: legal_hold.record_id,
+            reason: legal_hold.reason,
+            created_at: legal_hold.created_at,
+        }
+    }
+}
+
 #[cfg(feature = "v1")]
 impl ForeignTryFrom<domain::MerchantAccount> for MerchantAccountResponse {
     type Error = error_stack::Report<errors::ParsingError>;
@@ -58,6 +78,11 @@ impl ForeignTryFrom<domain::MerchantAccount> for MerchantAccountResponse {
             .map(|config| config.parse_value("pm_collect_link_config"))
             .transpose()?;
 
+        let default_connector_by_method_type = item
+            .default_connector_by_method_type
+            .map(|value| value.parse_value("default_connector_by_method_type"))
+            .transpose()?;
+
         Ok(Self {
             merchant_id,
             merchant_name: item.merchant_name,
@@ -82,6 +107,19 @@ impl ForeignTryFrom<domain::MerchantAccount> for MerchantAccountResponse {
             default_profile: item.default_profile,
             recon_status: item.recon_status,
             pm_collect_link_config,
+            idempotency_ttl_in_seconds: item.idempotency_ttl_in_seconds,
+            customer_pii_encryption_fields: item.customer_pii_encryption_fields,
+            statement_descriptor_length_policy: item.statement_descriptor_length_policy,
+            allowed_return_url_domains: item.allowed_return_url_domains,
+            default_connector_by_method_type,
+            connector_failover_order: item.connector_failover_order.map(ForeignInto::foreign_into),
+            default_capture_method: item.default_capture_method,
+            least_cost_routing: item.least_cost_routing.map(ForeignInto::foreign_into),
+            transaction_amount_limits: item
+                .transaction_amount_limits
+                .map(ForeignInto::foreign_into),
+            default_currency: item.default_currency,
+            require_explicit_currency: item.require_explicit_currency,
         })
     }
 }
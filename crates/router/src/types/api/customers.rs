@@ -1,9 +1,16 @@
 use api_models::customers;
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
 pub use api_models::customers::GlobalId;
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+pub use api_models::customers::{
+    CustomerCommunicationCreate, CustomerCommunicationCreateRequest, CustomerCommunicationList,
+    CustomerCommunicationResponse, CustomerConnectorSyncResponse, CustomerVelocityCountersRequest,
+    CustomerVelocityCountersResponse,
+};
 pub use api_models::customers::{
+    CustomerCommunicationListConstraints, CustomerConnectorSyncRetryRequest,
     CustomerDeleteResponse, CustomerId, CustomerListRequest, CustomerRequest,
-    CustomerUpdateRequest, UpdateCustomerId,
+    CustomerUpdateRequest, CustomerVelocityCountersQuery, UpdateCustomerId,
 };
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
 use hyperswitch_domain_models::customer;
@@ -11,6 +18,8 @@ use serde::Serialize;
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 use super::payments;
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+use crate::types::storage;
 use crate::{
     newtype,
     types::{domain, ForeignFrom},
@@ -30,6 +39,13 @@ impl common_utils::events::ApiEventMetric for CustomerResponse {
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 impl ForeignFrom<(domain::Customer, Option<payments::AddressDetails>)> for CustomerResponse {
     fn foreign_from((cust, address): (domain::Customer, Option<payments::AddressDetails>)) -> Self {
+        let tax_ids = cust
+            .get_tax_ids()
+            .inspect_err(|error| {
+                router_env::logger::error!(?error, "Failed to parse customer tax identifiers")
+            })
+            .ok()
+            .flatten();
         customers::CustomerResponse {
             customer_id: cust.customer_id,
             name: cust.name,
@@ -41,6 +57,8 @@ impl ForeignFrom<(domain::Customer, Option<payments::AddressDetails>)> for Custo
             metadata: cust.metadata,
             address,
             default_payment_method_id: cust.default_payment_method_id,
+            preferred_payment_method_id: cust.preferred_payment_method_id,
+            tax_ids,
         }
         .into()
     }
@@ -66,3 +84,17 @@ impl ForeignFrom<customer::Customer> for CustomerResponse {
         .into()
     }
 }
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+impl ForeignFrom<storage::CustomerConnectorSync> for CustomerConnectorSyncResponse {
+    fn foreign_from(sync_entry: storage::CustomerConnectorSync) -> Self {
+        Self {
+            customer_id: sync_entry.customer_id,
+            connector_label: sync_entry.connector_label,
+            connector_name: sync_entry.connector_name,
+            sync_status: sync_entry.sync_status,
+            error_message: sync_entry.error_message,
+            modified_at: sync_entry.modified_at,
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use api_models::mandates;
 pub use api_models::mandates::{MandateId, MandateResponse, MandateRevokedResponse};
-use common_utils::ext_traits::OptionExt;
+use common_utils::{ext_traits::OptionExt, types::MinorUnit};
 use error_stack::ResultExt;
 use serde::{Deserialize, Serialize};
 
@@ -14,6 +14,7 @@ use crate::{
     types::{
         api, domain,
         storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
     },
 };
 
@@ -93,6 +94,21 @@ impl MandateResponseExt for MandateResponse {
         let payment_method_type = payment_method
             .payment_method_type
             .map(|pmt| pmt.to_string());
+        let total_amount = mandate
+            .constraints
+            .and_then(|constraints| constraints.total_amount)
+            .map(|total_amount| total_amount.get_amount_as_i64())
+            .or(match mandate.mandate_type {
+                storage_enums::MandateType::MultiUse => mandate.mandate_amount,
+                storage_enums::MandateType::SingleUse => None,
+            });
+        let remaining_amount = total_amount.map(|total_amount| {
+            MinorUnit::new(
+                total_amount
+                    .saturating_sub(mandate.amount_captured.unwrap_or(0))
+                    .max(0),
+            )
+        });
         Ok(Self {
             mandate_id: mandate.mandate_id,
             customer_acceptance: Some(api::payments::CustomerAcceptance {
@@ -112,6 +128,10 @@ impl MandateResponseExt for MandateResponse {
             payment_method: pm.to_string(),
             payment_method_type,
             payment_method_id: mandate.payment_method_id,
+            constraints: mandate
+                .constraints
+                .map(mandates::MandateConstraints::foreign_from),
+            remaining_amount,
         })
     }
 }
@@ -7,19 +7,23 @@ pub use api_models::payments::{
     PaymentListResponseV2, PaymentMethodData, PaymentMethodDataRequest, PaymentMethodDataResponse,
     PaymentOp, PaymentRetrieveBody, PaymentRetrieveBodyWithCredentials, PaymentsAggregateResponse,
     PaymentsApproveRequest, PaymentsCancelRequest, PaymentsCaptureRequest,
-    PaymentsCompleteAuthorizeRequest, PaymentsDynamicTaxCalculationRequest,
-    PaymentsDynamicTaxCalculationResponse, PaymentsExternalAuthenticationRequest,
+    PaymentsCompleteAuthorizeRequest, PaymentsDistinctCardCountResponse,
+    PaymentsDynamicTaxCalculationRequest, PaymentsDynamicTaxCalculationResponse,
+    PaymentsExternalAuthenticationRequest, PaymentsFulfillmentRequest, PaymentsFulfillmentResponse,
     PaymentsIncrementalAuthorizationRequest, PaymentsManualUpdateRequest, PaymentsRedirectRequest,
     PaymentsRedirectionResponse, PaymentsRejectRequest, PaymentsRequest, PaymentsResponse,
-    PaymentsResponseForm, PaymentsRetrieveRequest, PaymentsSessionRequest, PaymentsSessionResponse,
-    PaymentsStartRequest, PgRedirectResponse, PhoneDetails, RedirectionResponse, SessionToken,
-    UrlDetails, VerifyRequest, VerifyResponse, WalletData,
+    PaymentsResponseFields, PaymentsResponseForm, PaymentsRetrieveRequest, PaymentsSessionRequest,
+    PaymentsSessionResponse, PaymentsSettlementAmount, PaymentsSettlementAmountsRequest,
+    PaymentsSettlementAmountsResponse, PaymentsStartRequest, PgRedirectResponse, PhoneDetails,
+    RedirectionResponse, RelatedPayment, RelatedPaymentsResponse, SessionToken,
+    SparsePaymentsResponse, UrlDetails, VerifyRequest, VerifyResponse, WalletData,
 };
 use error_stack::ResultExt;
 pub use hyperswitch_domain_models::router_flow_types::payments::{
     Approve, Authorize, AuthorizeSessionToken, Balance, CalculateTax, Capture, CompleteAuthorize,
     CreateConnectorCustomer, IncrementalAuthorization, InitPayment, PSync, PaymentMethodToken,
-    PostProcessing, PreProcessing, Reject, SdkSessionUpdate, Session, SetupMandate, Void,
+    PostProcessing, PreProcessing, Reject, SdkSessionUpdate, Session, SetupMandate,
+    UpdateConnectorCustomer, Void,
 };
 pub use hyperswitch_interfaces::api::payments::{
     ConnectorCustomer, MandateSetup, Payment, PaymentApprove, PaymentAuthorize,
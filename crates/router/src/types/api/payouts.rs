@@ -4,7 +4,9 @@ pub use api_models::payouts::{
     PayoutEnabledPaymentMethodsInfo, PayoutLinkResponse, PayoutListConstraints,
     PayoutListFilterConstraints, PayoutListFilters, PayoutListResponse, PayoutMethodData,
     PayoutMethodDataResponse, PayoutRequest, PayoutRetrieveBody, PayoutRetrieveRequest,
-    PixBankTransfer, RequiredFieldsOverrideRequest, SepaBankTransfer, Wallet as WalletPayout,
+    PayoutReturnEntry, PayoutReturnEntryResponse, PayoutReturnResult, PayoutReturnsRequest,
+    PayoutReturnsResponse, PixBankTransfer, RequiredFieldsOverrideRequest, SepaBankTransfer,
+    Wallet as WalletPayout,
 };
 pub use hyperswitch_domain_models::router_flow_types::payouts::{
     PoCancel, PoCreate, PoEligibility, PoFulfill, PoQuote, PoRecipient, PoRecipientAccount, PoSync,
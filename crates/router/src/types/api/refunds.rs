@@ -1,6 +1,6 @@
 pub use api_models::refunds::{
-    RefundRequest, RefundResponse, RefundStatus, RefundType, RefundUpdateRequest,
-    RefundsRetrieveRequest,
+    RefundEligibilityRequest, RefundEligibilityResponse, RefundIneligibilityReason, RefundRequest,
+    RefundResponse, RefundStatus, RefundType, RefundUpdateRequest, RefundsRetrieveRequest,
 };
 pub use hyperswitch_domain_models::router_flow_types::refunds::{Execute, RSync};
 pub use hyperswitch_interfaces::api::refunds::{Refund, RefundExecute, RefundSync};
@@ -56,10 +56,24 @@ impl VerifyConnectorData {
             customer_acceptance: None,
             charges: None,
             merchant_order_reference_id: None,
+            amount_breakdown: None,
+            customer_tax_id: None,
             integrity_object: None,
         }
     }
 
+    pub fn get_tokenization_router_data(&self) -> types::TokenizationRouterData {
+        self.get_router_data(
+            types::PaymentMethodTokenizationData {
+                payment_method_data: domain::PaymentMethodData::Card(self.card_details.clone()),
+                browser_info: None,
+                currency: storage_enums::Currency::USD,
+                amount: None,
+            },
+            None,
+        )
+    }
+
     fn get_router_data<F, R1, R2>(
         &self,
         request_data: R1,
@@ -91,6 +105,7 @@ impl VerifyConnectorData {
             connector_auth_type: self.connector_auth.clone(),
             connector_meta_data: None,
             connector_wallets_details: None,
+            connector_request_timeout_secs: None,
             payment_method_token: None,
             connector_api_version: None,
             recurring_mandate_payment_data: None,
@@ -107,6 +122,7 @@ impl VerifyConnectorData {
             payment_method_balance: None,
             connector_http_status_code: None,
             external_latency: None,
+            connector_network_retry_count: None,
             apple_pay_flow: None,
             frm_metadata: None,
             refund_id: None,
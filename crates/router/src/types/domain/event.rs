@@ -34,6 +34,7 @@ pub struct Event {
     pub response: OptionalEncryptableSecretString,
     pub delivery_attempt: Option<WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
+    pub sequence_number: Option<i64>,
 }
 
 #[derive(Debug)]
@@ -42,6 +43,9 @@ pub enum EventUpdate {
         is_webhook_notified: bool,
         response: OptionalEncryptableSecretString,
     },
+    UpdateSequenceNumber {
+        sequence_number: i64,
+    },
 }
 
 impl From<EventUpdate> for EventUpdateInternal {
@@ -53,6 +57,12 @@ impl From<EventUpdate> for EventUpdateInternal {
             } => Self {
                 is_webhook_notified: Some(is_webhook_notified),
                 response: response.map(Into::into),
+                sequence_number: None,
+            },
+            EventUpdate::UpdateSequenceNumber { sequence_number } => Self {
+                is_webhook_notified: None,
+                response: None,
+                sequence_number: Some(sequence_number),
             },
         }
     }
@@ -81,6 +91,7 @@ impl super::behaviour::Conversion for Event {
             response: self.response.map(Into::into),
             delivery_attempt: self.delivery_attempt,
             metadata: self.metadata,
+            sequence_number: self.sequence_number,
         })
     }
 
@@ -132,6 +143,7 @@ impl super::behaviour::Conversion for Event {
             response: encryptable_event.response,
             delivery_attempt: item.delivery_attempt,
             metadata: item.metadata,
+            sequence_number: item.sequence_number,
         })
     }
 
@@ -153,6 +165,7 @@ impl super::behaviour::Conversion for Event {
             response: self.response.map(Into::into),
             delivery_attempt: self.delivery_attempt,
             metadata: self.metadata,
+            sequence_number: self.sequence_number,
         })
     }
 }
@@ -438,6 +438,15 @@ impl NewUserMerchant {
             enable_payment_response_hash: None,
             redirect_to_merchant_with_http_post: None,
             pm_collect_link_config: None,
+            idempotency_ttl_in_seconds: None,
+            customer_pii_encryption_fields: None,
+            statement_descriptor_length_policy: None,
+            allowed_return_url_domains: None,
+            default_capture_method: None,
+            transaction_amount_limits: None,
+            default_currency: None,
+            require_explicit_currency: None,
+            connector_failover_order: None,
         })
     }
 
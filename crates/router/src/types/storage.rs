@@ -1,7 +1,9 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_log;
 pub mod authentication;
 pub mod authorization;
+pub mod bank_account_token;
 pub mod blocklist;
 pub mod blocklist_fingerprint;
 pub mod blocklist_lookup;
@@ -9,8 +11,14 @@ pub mod business_profile;
 pub mod capture;
 pub mod cards_info;
 pub mod configs;
+pub mod connector_token_revocation;
+pub mod connector_webhook_offset;
+pub mod customer_communication;
+pub mod customer_connector_sync;
+pub mod customer_risk_profile;
 pub mod customers;
 pub mod dashboard_metadata;
+pub mod data_retention;
 pub mod dispute;
 pub mod enums;
 pub mod ephemeral_key;
@@ -19,26 +27,33 @@ pub mod file;
 pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
+pub mod incoming_webhook_event;
 #[cfg(feature = "kv_store")]
 pub mod kv;
+pub mod legal_hold;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
+pub mod merchant_onboarding_audit_log;
 pub mod payment_attempt;
 pub mod payment_link;
 pub mod payment_method;
 pub mod payout_attempt;
 pub mod payouts;
 pub mod refund;
+pub mod resource_sequence;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod settlement_reconciliation;
+pub mod three_ds_session;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_role;
+pub mod webhook_event_sequence;
 
 use std::collections::HashMap;
 
@@ -53,20 +68,27 @@ pub use hyperswitch_domain_models::payments::{
 };
 #[cfg(feature = "payouts")]
 pub use hyperswitch_domain_models::payouts::{
-    payout_attempt::{PayoutAttempt, PayoutAttemptNew, PayoutAttemptUpdate},
+    payout_attempt::{
+        PayoutAttempt, PayoutAttemptNew, PayoutAttemptUpdate, PayoutReturn, PayoutReturnOutcome,
+        PayoutReturnResult, ReturnedPayout,
+    },
     payouts::{Payouts, PayoutsNew, PayoutsUpdate},
 };
 pub use scheduler::db::process_tracker;
 
 pub use self::{
-    address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
-    blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, capture::*, cards_info::*,
-    configs::*, customers::*, dashboard_metadata::*, dispute::*, ephemeral_key::*, events::*,
-    file::*, fraud_check::*, generic_link::*, gsm::*, locker_mock_up::*, mandate::*,
-    merchant_account::*, merchant_connector_account::*, merchant_key_store::*, payment_link::*,
-    payment_method::*, process_tracker::*, refund::*, reverse_lookup::*, role::*,
-    routing_algorithm::*, unified_translations::*, user::*, user_authentication_method::*,
-    user_role::*,
+    address::*, api_keys::*, audit_log::*, authentication::*, authorization::*,
+    bank_account_token::*, blocklist::*, blocklist_fingerprint::*, blocklist_lookup::*,
+    business_profile::*, capture::*, cards_info::*, configs::*, connector_token_revocation::*,
+    connector_webhook_offset::*, customer_communication::*, customer_connector_sync::*,
+    customer_risk_profile::*, customers::*, dashboard_metadata::*, data_retention::*, dispute::*,
+    ephemeral_key::*, events::*, file::*, fraud_check::*, generic_link::*, gsm::*, incoming_webhook_event::*, legal_hold::*,
+    locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
+    merchant_key_store::*, merchant_onboarding_audit_log::*, payment_link::*, payment_method::*,
+    process_tracker::*, refund::*, resource_sequence::*, reverse_lookup::*, role::*,
+    routing_algorithm::*, settlement_reconciliation::*, three_ds_session::*,
+    unified_translations::*, user::*, user_authentication_method::*, user_role::*,
+    webhook_event_sequence::*,
 };
 use crate::types::api::routing;
 
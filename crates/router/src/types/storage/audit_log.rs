@@ -0,0 +1,3 @@
+pub use diesel_models::audit_log::{
+    AuditLog, AuditLogContext, AuditLogFieldChange, AuditLogNew, REDACTED_VALUE,
+};
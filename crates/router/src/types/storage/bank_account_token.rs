@@ -0,0 +1,3 @@
+pub use diesel_models::bank_account_token::{
+    BankAccountToken, BankAccountTokenNew, BankAccountTokenUpdate,
+};
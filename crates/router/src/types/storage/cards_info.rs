@@ -1 +1,9 @@
 pub use diesel_models::cards_info::CardInfo;
+
+/// Counts of the card info records affected by a bulk upsert
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CardInfoBulkUpsertSummary {
+    pub inserted: usize,
+    pub updated: usize,
+    pub pruned: usize,
+}
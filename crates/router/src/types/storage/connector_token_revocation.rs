@@ -0,0 +1,3 @@
+pub use diesel_models::connector_token_revocation::{
+    ConnectorTokenRevocation, ConnectorTokenRevocationNew, ConnectorTokenRevocationUpdate,
+};
@@ -0,0 +1,3 @@
+pub use diesel_models::customer_communication::{
+    CustomerCommunication, CustomerCommunicationNew, CustomerCommunicationRedaction,
+};
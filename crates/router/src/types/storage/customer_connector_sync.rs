@@ -0,0 +1 @@
+pub use diesel_models::customer_connector_sync::{CustomerConnectorSync, CustomerConnectorSyncNew};
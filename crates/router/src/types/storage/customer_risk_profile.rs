@@ -0,0 +1,3 @@
+pub use diesel_models::customer_risk_profile::{
+    CustomerRiskProfile, CustomerRiskProfileNew, CustomerRiskProfileUpdateInternal,
+};
@@ -0,0 +1,8 @@
+/// A record whose retention period has elapsed, and which is neither under a legal hold nor
+/// referenced by a record which must still be kept
+#[derive(Clone, Debug)]
+pub struct PurgeableRecord {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    /// The identifier of the record within the entity, such as the payment id for payments
+    pub record_id: String,
+}
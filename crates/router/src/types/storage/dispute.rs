@@ -22,6 +22,15 @@ pub trait DisputeDbExt: Sized {
         profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
         time_range: &common_utils::types::TimeRange,
     ) -> CustomResult<Vec<(common_enums::enums::DisputeStatus, i64)>, errors::DatabaseError>;
+
+    async fn get_dispute_outcome_status_with_count(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        time_range: &common_utils::types::TimeRange,
+    ) -> CustomResult<
+        Vec<(String, Option<String>, common_enums::DisputeStatus, i64)>,
+        errors::DatabaseError,
+    >;
 }
 
 #[async_trait::async_trait]
@@ -146,4 +155,51 @@ impl DisputeDbExt for Dispute {
         .change_context(errors::DatabaseError::NotFound)
         .attach_printable_lazy(|| "Error filtering records by predicate")
     }
+
+    async fn get_dispute_outcome_status_with_count(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        time_range: &common_utils::types::TimeRange,
+    ) -> CustomResult<
+        Vec<(String, Option<String>, common_enums::DisputeStatus, i64)>,
+        errors::DatabaseError,
+    > {
+        let mut query = <Self as HasTable>::table()
+            .group_by((
+                dsl::connector,
+                dsl::connector_reason_code,
+                dsl::dispute_status,
+            ))
+            .select((
+                dsl::connector,
+                dsl::connector_reason_code,
+                dsl::dispute_status,
+                diesel::dsl::count_star(),
+            ))
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::dispute_status.eq_any([
+                common_enums::DisputeStatus::DisputeWon,
+                common_enums::DisputeStatus::DisputeLost,
+                common_enums::DisputeStatus::DisputeChallenged,
+            ]))
+            .filter(dsl::created_at.ge(time_range.start_time))
+            .into_boxed();
+
+        query = match time_range.end_time {
+            Some(ending_at) => query.filter(dsl::created_at.le(ending_at)),
+            None => query,
+        };
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg,_>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async::<(String, Option<String>, common_enums::DisputeStatus, i64)>(
+                conn,
+            ),
+            db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::NotFound)
+        .attach_printable_lazy(|| "Error filtering records by predicate")
+    }
 }
@@ -1,3 +1,11 @@
 pub use diesel_models::fraud_check::{
     FraudCheck, FraudCheckNew, FraudCheckUpdate, FraudCheckUpdateInternal,
 };
+
+/// The payment attempt to be resolved by the review timeout, if it is not reviewed before then
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct FrmReviewTimeoutTrackingData {
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub attempt_id: String,
+}
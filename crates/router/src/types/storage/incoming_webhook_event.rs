@@ -0,0 +1 @@
+pub use diesel_models::incoming_webhook_event::{IncomingWebhookEvent, IncomingWebhookEventNew};
@@ -0,0 +1 @@
+pub use diesel_models::legal_hold::{LegalHold, LegalHoldNew};
@@ -0,0 +1,3 @@
+pub use diesel_models::merchant_onboarding_audit_log::{
+    MerchantOnboardingAuditLog, MerchantOnboardingAuditLogNew,
+};
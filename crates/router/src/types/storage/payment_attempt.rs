@@ -12,6 +12,7 @@ pub trait PaymentAttemptExt {
     fn make_new_capture(
         &self,
         capture_amount: MinorUnit,
+        capture_currency: enums::Currency,
         capture_status: enums::CaptureStatus,
     ) -> RouterResult<CaptureNew>;
 
@@ -25,6 +26,7 @@ impl PaymentAttemptExt for PaymentAttempt {
     fn make_new_capture(
         &self,
         capture_amount: MinorUnit,
+        capture_currency: enums::Currency,
         capture_status: enums::CaptureStatus,
     ) -> RouterResult<CaptureNew> {
         todo!()
@@ -34,6 +36,7 @@ impl PaymentAttemptExt for PaymentAttempt {
     fn make_new_capture(
         &self,
         capture_amount: MinorUnit,
+        capture_currency: enums::Currency,
         capture_status: enums::CaptureStatus,
     ) -> RouterResult<CaptureNew> {
         let capture_sequence = self.multiple_capture_count.unwrap_or_default() + 1;
@@ -44,7 +47,7 @@ impl PaymentAttemptExt for PaymentAttempt {
             capture_id: self.get_next_capture_id(),
             status: capture_status,
             amount: capture_amount,
-            currency: self.currency,
+            currency: Some(capture_currency),
             connector: self
                 .connector
                 .clone()
@@ -220,6 +223,15 @@ mod tests {
             organization_id: Default::default(),
             shipping_cost: Default::default(),
             order_tax_amount: Default::default(),
+            fraud_score: Default::default(),
+            fraud_decision: Default::default(),
+            approved_amount: Default::default(),
+            expected_settlement_date: Default::default(),
+            card_hash: Default::default(),
+            retry_advice: Default::default(),
+            tags: Default::default(),
+            is_connector_override: Default::default(),
+            stepped_up_from_attempt_id: Default::default(),
         };
 
         let store = state
@@ -308,6 +320,15 @@ mod tests {
             organization_id: Default::default(),
             shipping_cost: Default::default(),
             order_tax_amount: Default::default(),
+            fraud_score: Default::default(),
+            fraud_decision: Default::default(),
+            approved_amount: Default::default(),
+            expected_settlement_date: Default::default(),
+            card_hash: Default::default(),
+            retry_advice: Default::default(),
+            tags: Default::default(),
+            is_connector_override: Default::default(),
+            stepped_up_from_attempt_id: Default::default(),
         };
         let store = state
             .stores
@@ -409,6 +430,15 @@ mod tests {
             organization_id: Default::default(),
             shipping_cost: Default::default(),
             order_tax_amount: Default::default(),
+            fraud_score: Default::default(),
+            fraud_decision: Default::default(),
+            approved_amount: Default::default(),
+            expected_settlement_date: Default::default(),
+            card_hash: Default::default(),
+            retry_advice: Default::default(),
+            tags: Default::default(),
+            is_connector_override: Default::default(),
+            stepped_up_from_attempt_id: Default::default(),
         };
         let store = state
             .stores
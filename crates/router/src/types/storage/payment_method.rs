@@ -5,6 +5,11 @@ use std::{
 
 use api_models::payment_methods;
 use diesel_models::enums;
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub use diesel_models::payment_method::PaymentMethodRetokenizationCriteria;
 pub use diesel_models::payment_method::{
     PaymentMethod, PaymentMethodNew, PaymentMethodUpdate, PaymentMethodUpdateInternal,
     TokenizeCoreWorkflow,
@@ -56,6 +61,11 @@ pub struct WalletTokenData {
     pub payment_method_id: String,
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BankAccountTokenReference {
+    pub token_id: String,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum PaymentTokenData {
@@ -67,6 +77,7 @@ pub enum PaymentTokenData {
     PermanentCard(CardTokenData),
     AuthBankDebit(payment_methods::BankAccountTokenData),
     WalletToken(WalletTokenData),
+    BankAccountToken(BankAccountTokenReference),
 }
 
 impl PaymentTokenData {
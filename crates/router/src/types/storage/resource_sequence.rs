@@ -0,0 +1 @@
+pub use diesel_models::resource_sequence::{ResourceSequence, ResourceSequenceNew};
@@ -1 +1,214 @@
-pub use diesel_models::reverse_lookup::{ReverseLookup, ReverseLookupNew};
+pub use diesel_models::reverse_lookup::{
+    get_namespaced_lookup_id, get_reverse_lookup_namespace_prefix, ReverseLookup, ReverseLookupNew,
+};
+
+/// The reverse lookups to be reconciled between redis and the database
+#[derive(Clone, Debug)]
+pub enum ReverseLookupSelection {
+    /// The reverse lookups with the provided lookup ids
+    LookupIds(Vec<String>),
+    /// The reverse lookups which point to the provided `pk_id`, as found in the database
+    PkId(String),
+    /// A page of the reverse lookups inserted by the provided source, as found in the database
+    Source {
+        source: String,
+        limit: i64,
+        offset: i64,
+    },
+    /// A page of the reverse lookups found by scanning redis, starting from the cursor returned
+    /// in the report of the previous page, or from `"0"` for the first page. `count` bounds the
+    /// number of the keys visited by the scan, not the number of the lookups found.
+    RedisScan { cursor: String, count: u32 },
+}
+
+#[derive(Clone, Debug)]
+pub struct ReverseLookupReconciliationScope {
+    pub lookups: ReverseLookupSelection,
+    /// Repair the mismatches found. The database is treated as the source of truth, except for the
+    /// lookups which were never drained to the database, and which are no longer waiting to be
+    /// drained. Repairing overwrites the values in redis, so it has to be explicitly requested.
+    pub repair: bool,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReverseLookupMismatchCategory {
+    /// The lookup is present in redis, but was never drained to the database while no entries are
+    /// waiting to be drained
+    MissingInDatabase,
+    /// The lookup is present in both the stores, but points to different values
+    ValueMismatch,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct ReverseLookupMismatch {
+    pub lookup_id: String,
+    pub category: ReverseLookupMismatchCategory,
+    /// Whether the mismatch was repaired, always `false` unless a repair was requested
+    pub repaired: bool,
+}
+
+#[derive(Clone, Debug, Default, serde::Serialize)]
+pub struct ReverseLookupReconciliationReport {
+    /// The number of reverse lookups which were checked
+    pub checked: usize,
+    /// The lookup ids which were found in neither of the stores
+    pub not_found: Vec<String>,
+    /// The number of the lookups which are present only in the database. Values written to redis
+    /// expire after the KV TTL, so these are not mismatches.
+    pub expired_in_redis: usize,
+    /// The lookup ids which are present only in redis, while the drainer stream they are appended
+    /// to still has entries waiting to be drained. These are checked again in a later run.
+    pub pending_drain: Vec<String>,
+    pub mismatches: Vec<ReverseLookupMismatch>,
+    /// The cursor of the next page of a redis scan, `None` once every key has been scanned or when
+    /// the lookups were not selected by scanning redis
+    pub next_cursor: Option<String>,
+}
+
+impl ReverseLookupReconciliationReport {
+    pub fn get_mismatch_count(&self, category: ReverseLookupMismatchCategory) -> usize {
+        self.mismatches
+            .iter()
+            .filter(|mismatch| mismatch.category == category)
+            .count()
+    }
+
+    pub fn get_repaired_count(&self) -> usize {
+        self.mismatches
+            .iter()
+            .filter(|mismatch| mismatch.repaired)
+            .count()
+    }
+}
+
+/// A page of the reverse lookups whose target no longer exists
+#[derive(Clone, Debug, Default)]
+pub struct OrphanedReverseLookups {
+    pub reverse_lookups: Vec<ReverseLookup>,
+    /// The lookup id after which the next page starts, `None` once every lookup has been checked
+    pub next_starting_after: Option<String>,
+}
+
+/// Whether the reverse lookups stored in redis and in the database point to the same values.
+///
+/// `updated_by` is not compared, since it records the storage scheme used for the insertion.
+pub fn is_same_reverse_lookup(
+    redis_lookup: &ReverseLookup,
+    database_lookup: &ReverseLookup,
+) -> bool {
+    redis_lookup.lookup_id == database_lookup.lookup_id
+        && redis_lookup.pk_id == database_lookup.pk_id
+        && redis_lookup.sk_id == database_lookup.sk_id
+        && redis_lookup.source == database_lookup.source
+}
+
+/// The source of the reverse lookups pointing to payment attempts
+pub const PAYMENT_ATTEMPT_LOOKUP_SOURCE: &str = "payment_attempt";
+
+/// The source of the reverse lookups pointing to refunds
+pub const REFUND_LOOKUP_SOURCE: &str = "refund";
+
+/// The sources of the reverse lookups which are checked for whether their target still exists
+#[cfg(feature = "v1")]
+pub const ORPHAN_CHECKED_LOOKUP_SOURCES: [&str; 2] =
+    [PAYMENT_ATTEMPT_LOOKUP_SOURCE, REFUND_LOOKUP_SOURCE];
+
+/// The sources of the reverse lookups which are checked for whether their target still exists.
+/// The payment attempts are not keyed by their `pk_id` in v2, so their lookups are not checked.
+#[cfg(feature = "v2")]
+pub const ORPHAN_CHECKED_LOOKUP_SOURCES: [&str; 1] = [REFUND_LOOKUP_SOURCE];
+
+/// The `sk_id` of the reverse lookups pointing to the payment attempt
+pub fn get_payment_attempt_lookup_sk_id(attempt_id: &str) -> String {
+    format!("pa_{attempt_id}")
+}
+
+/// The `sk_id` of the reverse lookups pointing to the refund
+pub fn get_refund_lookup_sk_id(attempt_id: &str, refund_id: &str) -> String {
+    format!("pa_{attempt_id}_ref_{refund_id}")
+}
+
+/// The attempt id of the payment attempt which the `sk_id` of a reverse lookup points to
+pub fn get_candidate_attempt_id(sk_id: &str) -> Option<&str> {
+    sk_id.strip_prefix("pa_")
+}
+
+/// The refund ids which the `sk_id` of a reverse lookup could point to. Since both the attempt id
+/// and the refund id may contain the separator, every suffix following a separator is a
+/// candidate, and the refund is matched by rebuilding its `sk_id`.
+pub fn get_candidate_refund_ids(sk_id: &str) -> Vec<&str> {
+    const SEPARATOR: &str = "_ref_";
+    sk_id
+        .match_indices(SEPARATOR)
+        .filter_map(|(index, _)| sk_id.get(index + SEPARATOR.len()..))
+        .filter(|refund_id| !refund_id.is_empty())
+        .collect()
+}
+
+/// The tracking data of the recurring reconciliation of the reverse lookups, which records the
+/// cursor of the page of the redis scan to be reconciled in the next run
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+pub struct ReverseLookupReconciliationTrackingData {
+    /// The cursor of the redis scan, `None` when the scan starts over from the first page
+    pub cursor: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_reverse_lookup(pk_id: &str, updated_by: &str) -> ReverseLookup {
+        ReverseLookup {
+            lookup_id: "lookup_id".to_string(),
+            sk_id: "pa_attempt_id".to_string(),
+            pk_id: pk_id.to_string(),
+            source: PAYMENT_ATTEMPT_LOOKUP_SOURCE.to_string(),
+            updated_by: updated_by.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_is_same_reverse_lookup_ignores_updated_by() {
+        let redis_lookup = get_reverse_lookup("pk_id", "redis_kv");
+
+        assert!(is_same_reverse_lookup(
+            &redis_lookup,
+            &get_reverse_lookup("pk_id", "postgres_only")
+        ));
+        assert!(!is_same_reverse_lookup(
+            &redis_lookup,
+            &get_reverse_lookup("other_pk_id", "redis_kv")
+        ));
+    }
+
+    #[test]
+    fn test_reconciliation_report_counts() {
+        let report = ReverseLookupReconciliationReport {
+            mismatches: vec![
+                ReverseLookupMismatch {
+                    lookup_id: "lookup_1".to_string(),
+                    category: ReverseLookupMismatchCategory::MissingInDatabase,
+                    repaired: true,
+                },
+                ReverseLookupMismatch {
+                    lookup_id: "lookup_2".to_string(),
+                    category: ReverseLookupMismatchCategory::ValueMismatch,
+                    repaired: false,
+                },
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            report.get_mismatch_count(ReverseLookupMismatchCategory::MissingInDatabase),
+            1
+        );
+        assert_eq!(
+            report.get_mismatch_count(ReverseLookupMismatchCategory::ValueMismatch),
+            1
+        );
+        assert_eq!(report.get_repaired_count(), 1);
+    }
+}
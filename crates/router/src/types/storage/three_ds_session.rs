@@ -0,0 +1,3 @@
+pub use diesel_models::three_ds_session::{
+    ThreeDsSession, ThreeDsSessionFlowState, ThreeDsSessionNew, ThreeDsSessionUpdate,
+};
@@ -143,7 +143,8 @@ impl ForeignFrom<storage_enums::AttemptStatus> for storage_enums::IntentStatus {
             storage_enums::AttemptStatus::ConfirmationAwaited => Self::RequiresConfirmation,
             storage_enums::AttemptStatus::PaymentMethodAwaited => Self::RequiresPaymentMethod,
 
-            storage_enums::AttemptStatus::Authorized => Self::RequiresCapture,
+            storage_enums::AttemptStatus::Authorized
+            | storage_enums::AttemptStatus::PartiallyAuthorized => Self::RequiresCapture,
             storage_enums::AttemptStatus::AuthenticationPending
             | storage_enums::AttemptStatus::DeviceDataCollectionPending => {
                 Self::RequiresCustomerAction
@@ -193,6 +194,7 @@ impl ForeignTryFrom<storage_enums::AttemptStatus> for storage_enums::CaptureStat
             | storage_enums::AttemptStatus::AuthenticationPending
             | storage_enums::AttemptStatus::AuthenticationSuccessful
             | storage_enums::AttemptStatus::Authorized
+            | storage_enums::AttemptStatus::PartiallyAuthorized
             | storage_enums::AttemptStatus::AuthorizationFailed
             | storage_enums::AttemptStatus::Authorizing
             | storage_enums::AttemptStatus::CodInitiated
@@ -368,6 +370,27 @@ impl ForeignFrom<storage_enums::MandateAmountData> for payments::MandateAmountDa
             start_date: from.start_date,
             end_date: from.end_date,
             metadata: from.metadata,
+            constraints: from.constraints.map(ForeignFrom::foreign_from),
+        }
+    }
+}
+
+impl ForeignFrom<storage_enums::MandateConstraints> for api_models::mandates::MandateConstraints {
+    fn foreign_from(from: storage_enums::MandateConstraints) -> Self {
+        Self {
+            max_amount_per_transaction: from.max_amount_per_transaction,
+            total_amount: from.total_amount,
+            frequency: from.frequency,
+        }
+    }
+}
+
+impl ForeignFrom<api_models::mandates::MandateConstraints> for storage_enums::MandateConstraints {
+    fn foreign_from(from: api_models::mandates::MandateConstraints) -> Self {
+        Self {
+            max_amount_per_transaction: from.max_amount_per_transaction,
+            total_amount: from.total_amount,
+            frequency: from.frequency,
         }
     }
 }
@@ -404,6 +427,7 @@ impl ForeignFrom<payments::MandateData> for hyperswitch_domain_models::mandates:
                             start_date: i.start_date,
                             end_date: i.end_date,
                             metadata: i.metadata,
+                            constraints: i.constraints.map(Into::into),
                         },
                     ))
                 }
@@ -415,6 +439,7 @@ impl ForeignFrom<payments::MandateData> for hyperswitch_domain_models::mandates:
                             start_date: i.start_date,
                             end_date: i.end_date,
                             metadata: i.metadata,
+                            constraints: i.constraints.map(Into::into),
                         },
                     )
                 }
@@ -435,6 +460,31 @@ impl ForeignFrom<payments::MandateAmountData> for storage_enums::MandateAmountDa
             start_date: from.start_date,
             end_date: from.end_date,
             metadata: from.metadata,
+            constraints: from.constraints.map(ForeignFrom::foreign_from),
+        }
+    }
+}
+
+impl ForeignFrom<payments::AmountBreakdown> for diesel_models::payment_intent::AmountBreakdown {
+    fn foreign_from(from: payments::AmountBreakdown) -> Self {
+        Self {
+            subtotal: from.subtotal,
+            tax_amount: from.tax_amount.unwrap_or_default(),
+            tax_rate: from.tax_rate,
+            shipping_amount: from.shipping_amount,
+            discount_amount: from.discount_amount,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::payment_intent::AmountBreakdown> for payments::AmountBreakdown {
+    fn foreign_from(from: diesel_models::payment_intent::AmountBreakdown) -> Self {
+        Self {
+            subtotal: from.subtotal,
+            tax_amount: Some(from.tax_amount),
+            tax_rate: from.tax_rate,
+            shipping_amount: from.shipping_amount,
+            discount_amount: from.discount_amount,
         }
     }
 }
@@ -624,6 +674,7 @@ impl ForeignFrom<storage_enums::PayoutStatus> for Option<storage_enums::EventTyp
             }
             storage_enums::PayoutStatus::Expired => Some(storage_enums::EventType::PayoutExpired),
             storage_enums::PayoutStatus::Reversed => Some(storage_enums::EventType::PayoutReversed),
+            storage_enums::PayoutStatus::Returned => Some(storage_enums::EventType::PayoutReturned),
             storage_enums::PayoutStatus::Ineligible
             | storage_enums::PayoutStatus::Pending
             | storage_enums::PayoutStatus::RequiresCreation
@@ -690,6 +741,7 @@ impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enum
             api_models::webhooks::IncomingWebhookEvent::PayoutCreated => Ok(Self::Initiated),
             api_models::webhooks::IncomingWebhookEvent::PayoutExpired => Ok(Self::Expired),
             api_models::webhooks::IncomingWebhookEvent::PayoutReversed => Ok(Self::Reversed),
+            api_models::webhooks::IncomingWebhookEvent::PayoutReturned => Ok(Self::Returned),
             _ => Err(errors::ValidationError::IncorrectValueProvided {
                 field_name: "incoming_webhook_event_type",
             }),
@@ -1227,6 +1279,12 @@ impl ForeignTryFrom<domain::MerchantConnectorAccount>
                         .change_context(errors::ApiErrorResponse::InternalServerError)
                 })
                 .transpose()?,
+            request_timeout_secs: item
+                .request_timeout_secs
+                .and_then(|timeout| u16::try_from(timeout).ok()),
+            environment: item.environment,
+            maintenance_windows: item.maintenance_windows.map(ForeignFrom::foreign_from),
+            credential_rotation_status: item.credential_rotation_status,
         };
         #[cfg(feature = "v1")]
         let response = Self {
@@ -1283,6 +1341,13 @@ impl ForeignTryFrom<domain::MerchantConnectorAccount>
                         .change_context(errors::ApiErrorResponse::InternalServerError)
                 })
                 .transpose()?,
+            request_timeout_secs: item
+                .request_timeout_secs
+                .and_then(|timeout| u16::try_from(timeout).ok()),
+            webhook_identifier: item.webhook_identifier,
+            environment: item.environment,
+            maintenance_windows: item.maintenance_windows.map(ForeignFrom::foreign_from),
+            credential_rotation_status: item.credential_rotation_status,
         };
         Ok(response)
     }
@@ -1809,6 +1874,196 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventRetrieveRespons
     }
 }
 
+impl ForeignFrom<Vec<api_models::admin::ConnectorMaintenanceWindow>>
+    for diesel_models::merchant_connector_account::ConnectorMaintenanceWindows
+{
+    fn foreign_from(item: Vec<api_models::admin::ConnectorMaintenanceWindow>) -> Self {
+        Self(
+            item.into_iter()
+                .map(|window| {
+                    diesel_models::merchant_connector_account::ConnectorMaintenanceWindow {
+                        start_time: window.start_time,
+                        end_time: window.end_time,
+                        recurrence: window.recurrence,
+                    }
+                })
+                .collect(),
+        )
+    }
+}
+
+impl ForeignFrom<diesel_models::merchant_connector_account::ConnectorMaintenanceWindows>
+    for Vec<api_models::admin::ConnectorMaintenanceWindow>
+{
+    fn foreign_from(
+        item: diesel_models::merchant_connector_account::ConnectorMaintenanceWindows,
+    ) -> Self {
+        item.0
+            .into_iter()
+            .map(|window| api_models::admin::ConnectorMaintenanceWindow {
+                start_time: window.start_time,
+                end_time: window.end_time,
+                recurrence: window.recurrence,
+            })
+            .collect()
+    }
+}
+
+impl ForeignFrom<Vec<api_models::admin::ConnectorFailoverRule>>
+    for diesel_models::merchant_account::ConnectorFailoverOrder
+{
+    fn foreign_from(item: Vec<api_models::admin::ConnectorFailoverRule>) -> Self {
+        Self(
+            item.into_iter()
+                .map(
+                    |rule| diesel_models::merchant_account::ConnectorFailoverRule {
+                        payment_method_type: rule.payment_method_type,
+                        currencies: rule.currencies,
+                        countries: rule.countries,
+                        connectors: rule.connectors,
+                    },
+                )
+                .collect(),
+        )
+    }
+}
+
+impl ForeignFrom<diesel_models::merchant_account::ConnectorFailoverOrder>
+    for Vec<api_models::admin::ConnectorFailoverRule>
+{
+    fn foreign_from(item: diesel_models::merchant_account::ConnectorFailoverOrder) -> Self {
+        item.0
+            .into_iter()
+            .map(|rule| api_models::admin::ConnectorFailoverRule {
+                payment_method_type: rule.payment_method_type,
+                currencies: rule.currencies,
+                countries: rule.countries,
+                connectors: rule.connectors,
+            })
+            .collect()
+    }
+}
+
+impl ForeignFrom<api_models::admin::ConnectorFee>
+    for diesel_models::merchant_account::ConnectorFee
+{
+    fn foreign_from(item: api_models::admin::ConnectorFee) -> Self {
+        Self {
+            fixed_fee: item.fixed_fee,
+            currency: item.currency,
+            percentage_fee: item.percentage_fee,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::merchant_account::ConnectorFee>
+    for api_models::admin::ConnectorFee
+{
+    fn foreign_from(item: diesel_models::merchant_account::ConnectorFee) -> Self {
+        Self {
+            fixed_fee: item.fixed_fee,
+            currency: item.currency,
+            percentage_fee: item.percentage_fee,
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::LeastCostRoutingConfig>
+    for diesel_models::merchant_account::LeastCostRouting
+{
+    fn foreign_from(item: api_models::admin::LeastCostRoutingConfig) -> Self {
+        Self {
+            enabled: item.enabled,
+            connector_costs: item
+                .connector_costs
+                .into_iter()
+                .map(
+                    |cost_model| diesel_models::merchant_account::ConnectorCostModel {
+                        connector: cost_model.connector,
+                        default_fee: ForeignFrom::foreign_from(cost_model.default_fee),
+                        fee_rules: cost_model
+                            .fee_rules
+                            .unwrap_or_default()
+                            .into_iter()
+                            .map(|rule| diesel_models::merchant_account::ConnectorFeeRule {
+                                payment_method: rule.payment_method,
+                                payment_method_type: rule.payment_method_type,
+                                countries: rule.countries,
+                                fee: ForeignFrom::foreign_from(rule.fee),
+                            })
+                            .collect(),
+                    },
+                )
+                .collect(),
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::merchant_account::LeastCostRouting>
+    for api_models::admin::LeastCostRoutingConfig
+{
+    fn foreign_from(item: diesel_models::merchant_account::LeastCostRouting) -> Self {
+        Self {
+            enabled: item.enabled,
+            connector_costs: item
+                .connector_costs
+                .into_iter()
+                .map(|cost_model| api_models::admin::ConnectorCostModel {
+                    connector: cost_model.connector,
+                    default_fee: ForeignFrom::foreign_from(cost_model.default_fee),
+                    fee_rules: Some(
+                        cost_model
+                            .fee_rules
+                            .into_iter()
+                            .map(|rule| api_models::admin::ConnectorFeeRule {
+                                payment_method: rule.payment_method,
+                                payment_method_type: rule.payment_method_type,
+                                countries: rule.countries,
+                                fee: ForeignFrom::foreign_from(rule.fee),
+                            })
+                            .collect(),
+                    ),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ForeignFrom<Vec<api_models::admin::TransactionAmountLimit>>
+    for diesel_models::merchant_account::TransactionAmountLimits
+{
+    fn foreign_from(item: Vec<api_models::admin::TransactionAmountLimit>) -> Self {
+        Self(
+            item.into_iter()
+                .map(
+                    |limit| diesel_models::merchant_account::TransactionAmountLimit {
+                        currency: limit.currency,
+                        payment_method_type: limit.payment_method_type,
+                        min_amount: limit.min_amount,
+                        max_amount: limit.max_amount,
+                    },
+                )
+                .collect(),
+        )
+    }
+}
+
+impl ForeignFrom<diesel_models::merchant_account::TransactionAmountLimits>
+    for Vec<api_models::admin::TransactionAmountLimit>
+{
+    fn foreign_from(item: diesel_models::merchant_account::TransactionAmountLimits) -> Self {
+        item.0
+            .into_iter()
+            .map(|limit| api_models::admin::TransactionAmountLimit {
+                currency: limit.currency,
+                payment_method_type: limit.payment_method_type,
+                min_amount: limit.min_amount,
+                max_amount: limit.max_amount,
+            })
+            .collect()
+    }
+}
+
 impl ForeignFrom<api_models::admin::AuthenticationConnectorDetails>
     for diesel_models::business_profile::AuthenticationConnectorDetails
 {
@@ -1843,6 +2098,9 @@ impl ForeignFrom<api_models::admin::WebhookDetails>
             payment_created_enabled: item.payment_created_enabled,
             payment_succeeded_enabled: item.payment_succeeded_enabled,
             payment_failed_enabled: item.payment_failed_enabled,
+            ordered_delivery_enabled: item.ordered_delivery_enabled,
+            minimum_amounts: item.minimum_amounts,
+            enabled_event_types: item.enabled_event_types,
         }
     }
 }
@@ -1859,6 +2117,9 @@ impl ForeignFrom<diesel_models::business_profile::WebhookDetails>
             payment_created_enabled: item.payment_created_enabled,
             payment_succeeded_enabled: item.payment_succeeded_enabled,
             payment_failed_enabled: item.payment_failed_enabled,
+            ordered_delivery_enabled: item.ordered_delivery_enabled,
+            minimum_amounts: item.minimum_amounts,
+            enabled_event_types: item.enabled_event_types,
         }
     }
 }
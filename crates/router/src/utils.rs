@@ -9,8 +9,8 @@ pub mod storage_partitioning;
 pub mod user;
 #[cfg(feature = "olap")]
 pub mod user_role;
-#[cfg(feature = "olap")]
 pub mod verify_connector;
+pub mod zip_archive;
 use std::fmt::Debug;
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -696,6 +696,7 @@ pub fn handle_json_response_deserialization_failure(
                 reason: Some(response_data),
                 attempt_status: None,
                 connector_transaction_id: None,
+                network_advice_code: None,
             })
         }
     }
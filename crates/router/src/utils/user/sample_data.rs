@@ -252,6 +252,12 @@ pub async fn generate_sample_data(
             shipping_cost: None,
             tax_details: None,
             skip_external_tax_calculation: None,
+            amount_breakdown: None,
+            parent_payment_id: None,
+            root_payment_id: None,
+            fulfillment_status: None,
+            shipped_at: None,
+            delivered_at: None,
         };
         let payment_attempt = PaymentAttemptBatchNew {
             attempt_id: attempt_id.clone(),
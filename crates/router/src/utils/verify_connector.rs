@@ -47,3 +47,37 @@ pub fn get_test_card_details(
         _ => Ok(None),
     }
 }
+
+/// The well known card numbers which the card networks and the connectors publish for testing,
+/// and which are never issued to cardholders
+const TEST_CARD_NUMBERS: [&str; 10] = [
+    "4242424242424242",
+    "4111111111111111",
+    "4000056655665556",
+    "5555555555554444",
+    "5105105105105100",
+    "2223003122003222",
+    "378282246310005",
+    "371449635398431",
+    "6011111111111117",
+    "3566002020360505",
+];
+
+pub fn is_test_card_number(card_number: &cards::CardNumber) -> bool {
+    TEST_CARD_NUMBERS.contains(&card_number.get_card_no().as_str())
+}
+
+/// The test card of the connector, or a generic test card for the connectors which have none
+pub fn get_tokenization_test_card_details(
+    connector_name: Connector,
+) -> errors::RouterResult<domain::Card> {
+    match get_test_card_details(connector_name)? {
+        Some(card) => Ok(card),
+        None => generate_card_from_details(
+            "4111111111111111".to_string(),
+            "2030".to_string(),
+            "03".to_string(),
+            "737".to_string(),
+        ),
+    }
+}
@@ -0,0 +1,339 @@
+//! Minimal writer for zip archives which stores the files without compression
+
+use common_utils::errors::CustomResult;
+use error_stack::{report, ResultExt};
+
+const LOCAL_FILE_HEADER_SIGNATURE: u32 = 0x04034b50;
+const CENTRAL_DIRECTORY_HEADER_SIGNATURE: u32 = 0x02014b50;
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x06054b50;
+const ZIP_VERSION: u16 = 20;
+/// Indicates that the file names are encoded as UTF-8
+const GENERAL_PURPOSE_FLAGS: u16 = 1 << 11;
+const COMPRESSION_METHOD_STORED: u16 = 0;
+/// MS-DOS representation of 1980-01-01 00:00:00, the earliest timestamp representable in the format
+const MODIFICATION_TIME: u16 = 0;
+const MODIFICATION_DATE: u16 = (1 << 5) | 1;
+
+/// Reversed polynomial of the CRC-32 checksum used by the zip format
+const CRC32_POLYNOMIAL: u32 = 0xedb88320;
+/// Lookup table of the CRC-32 checksum, processing one byte at a time
+const CRC32_TABLE: [u32; 256] = build_crc32_table();
+
+/// Largest number of entries an archive may contain without the zip64 extensions, `0xffff` being
+/// reserved to indicate that the count is stored in the zip64 end of central directory record
+const MAX_ENTRIES_COUNT: usize = 0xfffe;
+/// Largest size or offset an archive may contain without the zip64 extensions, `0xffffffff` being
+/// reserved to indicate that the value is stored in the zip64 extra field
+const MAX_SIZE: usize = 0xfffffffe;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ZipArchiveError {
+    #[error("The archive exceeds the limits supported by the zip format")]
+    SizeLimitExceeded,
+    #[error("The file name is not a valid name for a file of the archive")]
+    InvalidFileName,
+}
+
+struct CentralDirectoryEntry {
+    file_name: Vec<u8>,
+    crc32: u32,
+    size: u32,
+    offset: u32,
+}
+
+#[derive(Default)]
+pub struct ZipArchiveWriter {
+    buffer: Vec<u8>,
+    entries: Vec<CentralDirectoryEntry>,
+}
+
+#[allow(clippy::indexing_slicing)]
+const fn build_crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut index = 0;
+    while index < 256 {
+        #[allow(clippy::as_conversions)]
+        let mut crc = index as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ CRC32_POLYNOMIAL
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[index] = crc;
+        index += 1;
+    }
+    table
+}
+
+/// CRC-32 checksum of the data, as stored in the headers of the files of the archive
+// Indexing the table with a byte cannot go out of bounds
+#[allow(clippy::indexing_slicing)]
+fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(u32::MAX, |crc, byte| {
+        CRC32_TABLE[usize::from(crc.to_le_bytes()[0] ^ byte)] ^ (crc >> 8)
+    })
+}
+
+fn to_u16(value: usize) -> CustomResult<u16, ZipArchiveError> {
+    u16::try_from(value).change_context(ZipArchiveError::SizeLimitExceeded)
+}
+
+fn to_u32(value: usize) -> CustomResult<u32, ZipArchiveError> {
+    if value > MAX_SIZE {
+        return Err(report!(ZipArchiveError::SizeLimitExceeded));
+    }
+    u32::try_from(value).change_context(ZipArchiveError::SizeLimitExceeded)
+}
+
+/// Whether the name can be used as is for a file of the archive, without being extracted outside
+/// of the directory the archive is extracted to
+fn is_safe_file_name(file_name: &str) -> bool {
+    !file_name.is_empty()
+        && file_name != "."
+        && file_name != ".."
+        && !file_name.contains(['/', '\\', '\0'])
+}
+
+/// Name of the file without any of the directories in its path, if it is safe to be used as the
+/// name of a file of the archive
+pub fn sanitize_file_name(file_name: &str) -> Option<&str> {
+    file_name
+        .rsplit(['/', '\\'])
+        .next()
+        .filter(|file_name| is_safe_file_name(file_name))
+}
+
+impl ZipArchiveWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a file with the provided name and contents to the archive
+    ///
+    /// The name must not contain any path separators, so that the file is extracted in the
+    /// directory the archive is extracted to.
+    pub fn add_file(&mut self, file_name: &str, data: &[u8]) -> CustomResult<(), ZipArchiveError> {
+        if !is_safe_file_name(file_name) {
+            return Err(report!(ZipArchiveError::InvalidFileName));
+        }
+        if self.entries.len() >= MAX_ENTRIES_COUNT {
+            return Err(report!(ZipArchiveError::SizeLimitExceeded));
+        }
+        let file_name = file_name.as_bytes().to_vec();
+        let file_name_length = to_u16(file_name.len())?;
+        let size = to_u32(data.len())?;
+        let offset = to_u32(self.buffer.len())?;
+        // The local file header and the file must end within the limits as well, for the central
+        // directory to be addressable
+        to_u32(
+            self.buffer
+                .len()
+                .saturating_add(30)
+                .saturating_add(file_name.len())
+                .saturating_add(data.len()),
+        )?;
+        let crc32 = crc32(data);
+
+        self.write_u32(LOCAL_FILE_HEADER_SIGNATURE);
+        self.write_u16(ZIP_VERSION);
+        self.write_u16(GENERAL_PURPOSE_FLAGS);
+        self.write_u16(COMPRESSION_METHOD_STORED);
+        self.write_u16(MODIFICATION_TIME);
+        self.write_u16(MODIFICATION_DATE);
+        self.write_u32(crc32);
+        // Compressed and uncompressed sizes are the same as the file is stored as is
+        self.write_u32(size);
+        self.write_u32(size);
+        self.write_u16(file_name_length);
+        // Extra field length
+        self.write_u16(0);
+        self.buffer.extend_from_slice(&file_name);
+        self.buffer.extend_from_slice(data);
+
+        self.entries.push(CentralDirectoryEntry {
+            file_name,
+            crc32,
+            size,
+            offset,
+        });
+        Ok(())
+    }
+
+    /// Write the central directory and return the bytes of the archive
+    pub fn finish(mut self) -> CustomResult<Vec<u8>, ZipArchiveError> {
+        let central_directory_offset = to_u32(self.buffer.len())?;
+        let entries_count = to_u16(self.entries.len())?;
+        let entries = std::mem::take(&mut self.entries);
+
+        for entry in entries.iter() {
+            self.write_u32(CENTRAL_DIRECTORY_HEADER_SIGNATURE);
+            // Version made by and version needed to extract
+            self.write_u16(ZIP_VERSION);
+            self.write_u16(ZIP_VERSION);
+            self.write_u16(GENERAL_PURPOSE_FLAGS);
+            self.write_u16(COMPRESSION_METHOD_STORED);
+            self.write_u16(MODIFICATION_TIME);
+            self.write_u16(MODIFICATION_DATE);
+            self.write_u32(entry.crc32);
+            self.write_u32(entry.size);
+            self.write_u32(entry.size);
+            self.write_u16(to_u16(entry.file_name.len())?);
+            // Extra field length, file comment length, disk number start and internal attributes
+            self.write_u16(0);
+            self.write_u16(0);
+            self.write_u16(0);
+            self.write_u16(0);
+            // External attributes
+            self.write_u32(0);
+            self.write_u32(entry.offset);
+            self.buffer.extend_from_slice(&entry.file_name);
+        }
+
+        let central_directory_size = to_u32(self.buffer.len())?
+            .checked_sub(central_directory_offset)
+            .ok_or(report!(ZipArchiveError::SizeLimitExceeded))?;
+
+        self.write_u32(END_OF_CENTRAL_DIRECTORY_SIGNATURE);
+        // Number of this disk and the disk where the central directory starts
+        self.write_u16(0);
+        self.write_u16(0);
+        self.write_u16(entries_count);
+        self.write_u16(entries_count);
+        self.write_u32(central_directory_size);
+        self.write_u32(central_directory_offset);
+        // Comment length
+        self.write_u16(0);
+
+        Ok(self.buffer)
+    }
+
+    fn write_u16(&mut self, value: u16) {
+        self.buffer.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.buffer.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn read_u16(buffer: &[u8], offset: usize) -> u16 {
+        u16::from_le_bytes([buffer[offset], buffer[offset + 1]])
+    }
+
+    fn read_u32(buffer: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes([
+            buffer[offset],
+            buffer[offset + 1],
+            buffer[offset + 2],
+            buffer[offset + 3],
+        ])
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b""), 0);
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+    }
+
+    #[test]
+    fn test_sanitize_file_name() {
+        assert_eq!(sanitize_file_name("receipt.pdf"), Some("receipt.pdf"));
+        assert_eq!(sanitize_file_name("../../etc/passwd"), Some("passwd"));
+        assert_eq!(
+            sanitize_file_name("C:\\Users\\receipt.pdf"),
+            Some("receipt.pdf")
+        );
+        assert_eq!(sanitize_file_name("evidence/"), None);
+        assert_eq!(sanitize_file_name(".."), None);
+        assert_eq!(sanitize_file_name("receipt\0.pdf"), None);
+    }
+
+    #[test]
+    fn test_add_file_rejects_unsafe_names() {
+        let mut writer = ZipArchiveWriter::new();
+
+        assert!(writer.add_file("../receipt.pdf", b"receipt").is_err());
+        assert!(writer.add_file("evidence/receipt.pdf", b"receipt").is_err());
+        assert!(writer
+            .add_file("evidence\\receipt.pdf", b"receipt")
+            .is_err());
+        assert!(writer.add_file("receipt\0.pdf", b"receipt").is_err());
+        assert!(writer.add_file("..", b"receipt").is_err());
+    }
+
+    #[test]
+    fn test_entries_count_limit() {
+        let mut writer = ZipArchiveWriter::new();
+        for index in 0..MAX_ENTRIES_COUNT {
+            writer.add_file(&format!("{index}"), b"").unwrap();
+        }
+
+        assert!(writer.add_file("receipt.pdf", b"").is_err());
+        assert!(writer.finish().is_ok());
+    }
+
+    #[test]
+    fn test_empty_archive() {
+        let archive = ZipArchiveWriter::new().finish().unwrap();
+
+        assert_eq!(archive.len(), 22);
+        assert_eq!(read_u32(&archive, 0), END_OF_CENTRAL_DIRECTORY_SIGNATURE);
+        assert_eq!(read_u16(&archive, 10), 0);
+    }
+
+    #[test]
+    fn test_archive_with_files() {
+        let mut writer = ZipArchiveWriter::new();
+        writer.add_file("receipt.pdf", b"receipt").unwrap();
+        writer.add_file("manifest.json", b"{}").unwrap();
+        let archive = writer.finish().unwrap();
+
+        // Local file header of the first file, followed by its name and contents
+        assert_eq!(read_u32(&archive, 0), LOCAL_FILE_HEADER_SIGNATURE);
+        assert_eq!(read_u32(&archive, 14), crc32(b"receipt"));
+        assert_eq!(read_u32(&archive, 18), 7);
+        assert_eq!(read_u16(&archive, 26), 11);
+        assert_eq!(&archive[30..41], b"receipt.pdf");
+        assert_eq!(&archive[41..48], b"receipt");
+
+        // Local file header of the second file starts right after the first file
+        assert_eq!(read_u32(&archive, 48), LOCAL_FILE_HEADER_SIGNATURE);
+
+        let end_of_central_directory = archive.len() - 22;
+        assert_eq!(
+            read_u32(&archive, end_of_central_directory),
+            END_OF_CENTRAL_DIRECTORY_SIGNATURE
+        );
+        assert_eq!(read_u16(&archive, end_of_central_directory + 10), 2);
+        let central_directory_size =
+            usize::try_from(read_u32(&archive, end_of_central_directory + 12)).unwrap();
+        let central_directory_offset =
+            usize::try_from(read_u32(&archive, end_of_central_directory + 16)).unwrap();
+        assert_eq!(
+            central_directory_offset + central_directory_size,
+            end_of_central_directory
+        );
+
+        // The central directory entry of the second file points to its local file header
+        let second_entry = central_directory_offset + 46 + "receipt.pdf".len();
+        assert_eq!(
+            read_u32(&archive, second_entry),
+            CENTRAL_DIRECTORY_HEADER_SIGNATURE
+        );
+        assert_eq!(read_u32(&archive, second_entry + 42), 48);
+        assert_eq!(
+            &archive[second_entry + 46..second_entry + 46 + "manifest.json".len()],
+            b"manifest.json"
+        );
+    }
+}
@@ -2,6 +2,11 @@
 pub mod api_key_expiry;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+pub mod audit_log_purge;
+#[cfg(feature = "v1")]
+pub mod data_retention_purge;
+#[cfg(all(feature = "v1", feature = "frm"))]
+pub mod frm_review_timeout;
 #[cfg(feature = "v1")]
 pub mod outgoing_webhook_retry;
 #[cfg(feature = "v1")]
@@ -9,5 +14,6 @@ pub mod payment_method_status_update;
 pub mod payment_sync;
 #[cfg(feature = "v1")]
 pub mod refund_router;
+pub mod reverse_lookup_reconciliation;
 #[cfg(feature = "v1")]
 pub mod tokenized_data;
@@ -0,0 +1,55 @@
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{core::audit_log, errors, logger::error, routes::SessionState, types::storage};
+
+/// The recurring purge of the entries of the audit log past the retention period, which
+/// reschedules itself after every run, whether or not the run succeeded
+pub struct AuditLogPurgeWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for AuditLogPurgeWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+
+        if !state.conf.audit_log.enabled {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, "AUDIT_LOG_DISABLED")
+                .await
+                .map_err(Into::<errors::ProcessTrackerError>::into);
+        }
+
+        audit_log::purge_expired_audit_logs(state).await?;
+
+        db.as_scheduler()
+            .reset_process(
+                process,
+                audit_log::get_next_purge_schedule_time(&state.conf.audit_log),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        state
+            .store
+            .as_scheduler()
+            .reset_process(
+                process,
+                audit_log::get_next_purge_schedule_time(&state.conf.audit_log),
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use common_utils::date_time;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::{authentication::three_ds_session, data_retention, payment_link},
+    errors,
+    logger::{error, info},
+    routes::SessionState,
+    types::storage,
+};
+
+/// The recurring purge of the records past their retention period, which reschedules itself after
+/// every run, whether or not the run succeeded
+pub struct DataRetentionPurgeWorkflow;
+
+fn get_next_schedule_time(state: &SessionState) -> time::PrimitiveDateTime {
+    date_time::now().saturating_add(time::Duration::seconds(i64::from(
+        state.conf.data_retention.purge_interval_in_secs,
+    )))
+}
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for DataRetentionPurgeWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+
+        if !state.conf.data_retention.enabled {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, "DATA_RETENTION_DISABLED")
+                .await
+                .map_err(Into::<errors::ProcessTrackerError>::into);
+        }
+
+        let purged_records = data_retention::purge_expired_records(state).await?;
+        info!(?purged_records, "Completed the purge of the records");
+        let purged_sessions = three_ds_session::purge_expired_three_ds_sessions(state).await?;
+        info!(
+            purged_sessions,
+            "Completed the purge of the expired 3DS sessions"
+        );
+        let purged_payment_links = payment_link::purge_expired_payment_links(state).await?;
+        info!(
+            purged_payment_links,
+            "Completed the purge of the expired payment links"
+        );
+
+        db.as_scheduler()
+            .reset_process(process, get_next_schedule_time(state))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        state
+            .store
+            .as_scheduler()
+            .reset_process(process, get_next_schedule_time(state))
+            .await?;
+        Ok(())
+    }
+}
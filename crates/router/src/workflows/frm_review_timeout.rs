@@ -0,0 +1,87 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::fraud_check::review,
+    errors,
+    logger::error,
+    routes::SessionState,
+    types::storage::{self, FrmReviewTimeoutTrackingData},
+};
+
+pub struct FrmReviewTimeoutWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for FrmReviewTimeoutWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: FrmReviewTimeoutTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("FrmReviewTimeoutTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let payment_attempt = db
+            .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                &tracking_data.payment_id,
+                &tracking_data.merchant_id,
+                &tracking_data.attempt_id,
+                merchant_account.storage_scheme,
+            )
+            .await?;
+
+        // The payment was reviewed before the review timeout elapsed
+        if !review::is_review_pending(payment_attempt.fraud_decision) {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, "PROCESS_ALREADY_COMPLETED")
+                .await
+                .map_err(Into::<errors::ProcessTrackerError>::into);
+        }
+
+        review::resolve_unreviewed_payment(
+            state,
+            merchant_account,
+            key_store,
+            tracking_data.payment_id,
+        )
+        .await?;
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
@@ -99,6 +99,56 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             }
         };
 
+        // Hold the delivery until the previous webhooks of the resource are acknowledged, without
+        // counting the hold as a retry
+        if webhooks_core::ordering::get_delivery_order(
+            state,
+            &business_profile.merchant_id,
+            &initial_event,
+            initial_event.created_at,
+        )
+        .await
+            == webhooks_core::ordering::DeliveryOrder::Hold
+        {
+            let retry_count = process.retry_count;
+            db.as_scheduler()
+                .update_process(
+                    process,
+                    storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                        status: storage::enums::ProcessTrackerStatus::Pending,
+                        retry_count,
+                        schedule_time: webhooks_core::ordering::get_hold_schedule_time(state),
+                    },
+                )
+                .await?;
+            return Ok(());
+        }
+
+        // Defer the delivery while the deliveries to the merchant are backed off or its delivery
+        // queue is full, without counting the deferral as a retry
+        let _delivery_permit = match webhooks_core::delivery_scheduler::schedule_delivery(
+            state,
+            &business_profile.merchant_id,
+        )
+        .await
+        {
+            webhooks_core::delivery_scheduler::ScheduledDelivery::Deliver(permit) => permit,
+            webhooks_core::delivery_scheduler::ScheduledDelivery::Defer { until } => {
+                let retry_count = process.retry_count;
+                db.as_scheduler()
+                    .update_process(
+                        process,
+                        storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                            status: storage::enums::ProcessTrackerStatus::Pending,
+                            retry_count,
+                            schedule_time: until,
+                        },
+                    )
+                    .await?;
+                return Ok(());
+            }
+        };
+
         let now = common_utils::date_time::now();
         let new_event = domain::Event {
             event_id,
@@ -117,6 +167,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             response: None,
             delivery_attempt: Some(delivery_attempt),
             metadata: initial_event.metadata,
+            sequence_number: initial_event.sequence_number,
         };
 
         let event = db
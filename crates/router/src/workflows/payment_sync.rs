@@ -18,7 +18,7 @@ use crate::{
     routes::SessionState,
     services,
     types::{
-        api,
+        api::{self, PaymentIdTypeExt},
         storage::{self, enums},
     },
     utils,
@@ -48,170 +48,53 @@ impl ProcessTrackerWorkflow<SessionState> for PaymentsSyncWorkflow {
             .tracking_data
             .clone()
             .parse_value("PaymentsRetrieveRequest")?;
-        let key_manager_state = &state.into();
-        let key_store = db
-            .get_merchant_key_store_by_merchant_id(
-                key_manager_state,
-                tracking_data
-                    .merchant_id
-                    .as_ref()
-                    .get_required_value("merchant_id")?,
-                &db.get_master_key().to_vec().into(),
-            )
-            .await?;
+        let merchant_id = tracking_data
+            .merchant_id
+            .clone()
+            .get_required_value("merchant_id")?;
+        let payment_id = tracking_data.resource_id.get_payment_intent_id()?;
 
-        let merchant_account = db
-            .find_merchant_account_by_merchant_id(
-                key_manager_state,
-                tracking_data
-                    .merchant_id
-                    .as_ref()
-                    .get_required_value("merchant_id")?,
-                &key_store,
+        // The payment is synced by a single worker at a time across all the instances, the sync
+        // is tried again later while another worker holds the claim on the payment
+        let worker_id = common_utils::generate_id_with_default_len("psync_worker");
+        let is_claimed = db
+            .try_claim_payment_for_processing(
+                &merchant_id,
+                &payment_id,
+                &worker_id,
+                consts::PAYMENT_SYNC_CLAIM_LEASE_DURATION,
             )
             .await?;
-
-        // TODO: Add support for ReqState in PT flows
-        let (mut payment_data, _, customer, _, _) =
-            Box::pin(payment_flows::payments_operation_core::<
-                api::PSync,
-                _,
-                _,
-                _,
-                payment_flows::PaymentData<api::PSync>,
-            >(
-                state,
-                state.get_req_state(),
-                merchant_account.clone(),
-                None,
-                key_store.clone(),
-                operations::PaymentStatus,
-                tracking_data.clone(),
-                payment_flows::CallConnectorAction::Trigger,
-                services::AuthFlow::Client,
-                None,
-                api::HeaderPayload::default(),
-            ))
-            .await?;
-
-        let terminal_status = [
-            enums::AttemptStatus::RouterDeclined,
-            enums::AttemptStatus::Charged,
-            enums::AttemptStatus::AutoRefunded,
-            enums::AttemptStatus::Voided,
-            enums::AttemptStatus::VoidFailed,
-            enums::AttemptStatus::CaptureFailed,
-            enums::AttemptStatus::Failure,
-        ];
-        match &payment_data.payment_attempt.status {
-            status if terminal_status.contains(status) => {
-                state
-                    .store
-                    .as_scheduler()
-                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
-                    .await?
-            }
-            _ => {
-                let connector = payment_data
-                    .payment_attempt
-                    .connector
-                    .clone()
-                    .ok_or(sch_errors::ProcessTrackerError::MissingRequiredField)?;
-
-                let is_last_retry = retry_sync_task(
-                    db,
-                    connector,
-                    payment_data.payment_attempt.merchant_id.clone(),
+        if !is_claimed {
+            logger::info!(
+                payment_id = payment_id.get_string_repr(),
+                "Payment is being synced by another worker, rescheduling the sync"
+            );
+            let retry_count = process.retry_count;
+            let schedule_time = common_utils::date_time::now().saturating_add(
+                time::Duration::seconds(consts::PAYMENT_SYNC_CLAIM_LEASE_DURATION),
+            );
+            db.as_scheduler()
+                .update_process(
                     process,
+                    storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                        status: enums::ProcessTrackerStatus::Pending,
+                        retry_count,
+                        schedule_time,
+                    },
                 )
                 .await?;
+            return Ok(());
+        }
 
-                // If the payment status is still processing and there is no connector transaction_id
-                // then change the payment status to failed if all retries exceeded
-                if is_last_retry
-                    && payment_data.payment_attempt.status == enums::AttemptStatus::Pending
-                    && payment_data
-                        .payment_attempt
-                        .connector_transaction_id
-                        .as_ref()
-                        .is_none()
-                {
-                    let payment_intent_update = hyperswitch_domain_models::payments::payment_intent::PaymentIntentUpdate::PGStatusUpdate { status: api_models::enums::IntentStatus::Failed,updated_by: merchant_account.storage_scheme.to_string(), incremental_authorization_allowed: Some(false) };
-                    let payment_attempt_update =
-                        hyperswitch_domain_models::payments::payment_attempt::PaymentAttemptUpdate::ErrorUpdate {
-                            connector: None,
-                            status: api_models::enums::AttemptStatus::Failure,
-                            error_code: None,
-                            error_message: None,
-                            error_reason: Some(Some(
-                                consts::REQUEST_TIMEOUT_ERROR_MESSAGE_FROM_PSYNC.to_string(),
-                            )),
-                            amount_capturable: Some(common_utils::types::MinorUnit::new(0)),
-                            updated_by: merchant_account.storage_scheme.to_string(),
-                            unified_code: None,
-                            unified_message: None,
-                            connector_transaction_id: None,
-                            payment_method_data: None,
-                            authentication_type: None,
-                        };
-
-                    payment_data.payment_attempt = db
-                        .update_payment_attempt_with_attempt_id(
-                            payment_data.payment_attempt,
-                            payment_attempt_update,
-                            merchant_account.storage_scheme,
-                        )
-                        .await
-                        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
-
-                    payment_data.payment_intent = db
-                        .update_payment_intent(
-                            &state.into(),
-                            payment_data.payment_intent,
-                            payment_intent_update,
-                            &key_store,
-                            merchant_account.storage_scheme,
-                        )
-                        .await
-                        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
-
-                    let profile_id = payment_data
-                        .payment_intent
-                        .profile_id
-                        .as_ref()
-                        .get_required_value("profile_id")
-                        .change_context(errors::ApiErrorResponse::InternalServerError)
-                        .attach_printable("Could not find profile_id in payment intent")?;
+        let result = Box::pin(sync_payment(state, process, tracking_data)).await;
 
-                    let business_profile = db
-                        .find_business_profile_by_profile_id(
-                            key_manager_state,
-                            &key_store,
-                            profile_id,
-                        )
-                        .await
-                        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
-                            id: profile_id.get_string_repr().to_owned(),
-                        })?;
+        db.release_payment_processing_claim(&merchant_id, &payment_id, &worker_id)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to release the claim on the payment"))
+            .ok();
 
-                    // Trigger the outgoing webhook to notify the merchant about failed payment
-                    let operation = operations::PaymentStatus;
-                    Box::pin(utils::trigger_payments_webhook(
-                        merchant_account,
-                        business_profile,
-                        &key_store,
-                        payment_data,
-                        customer,
-                        state,
-                        operation,
-                    ))
-                    .await
-                    .map_err(|error| logger::warn!(payments_outgoing_webhook_error=?error))
-                    .ok();
-                }
-            }
-        };
-        Ok(())
+        result
     }
 
     async fn error_handler<'a>(
@@ -224,6 +107,177 @@ impl ProcessTrackerWorkflow<SessionState> for PaymentsSyncWorkflow {
     }
 }
 
+/// Sync the payment with the connector, and retry the sync later while the payment is not in a
+/// terminal status
+#[cfg(feature = "v1")]
+async fn sync_payment(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    tracking_data: api::PaymentsRetrieveRequest,
+) -> Result<(), sch_errors::ProcessTrackerError> {
+    let db: &dyn StorageInterface = &*state.store;
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            tracking_data
+                .merchant_id
+                .as_ref()
+                .get_required_value("merchant_id")?,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            tracking_data
+                .merchant_id
+                .as_ref()
+                .get_required_value("merchant_id")?,
+            &key_store,
+        )
+        .await?;
+
+    // TODO: Add support for ReqState in PT flows
+    let (mut payment_data, _, customer, _, _) = Box::pin(payment_flows::payments_operation_core::<
+        api::PSync,
+        _,
+        _,
+        _,
+        payment_flows::PaymentData<api::PSync>,
+    >(
+        state,
+        state.get_req_state(),
+        merchant_account.clone(),
+        None,
+        key_store.clone(),
+        operations::PaymentStatus,
+        tracking_data.clone(),
+        payment_flows::CallConnectorAction::Trigger,
+        services::AuthFlow::Client,
+        None,
+        api::HeaderPayload::default(),
+    ))
+    .await?;
+
+    let terminal_status = [
+        enums::AttemptStatus::RouterDeclined,
+        enums::AttemptStatus::Charged,
+        enums::AttemptStatus::AutoRefunded,
+        enums::AttemptStatus::Voided,
+        enums::AttemptStatus::VoidFailed,
+        enums::AttemptStatus::CaptureFailed,
+        enums::AttemptStatus::Failure,
+    ];
+    match &payment_data.payment_attempt.status {
+        status if terminal_status.contains(status) => {
+            state
+                .store
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?
+        }
+        _ => {
+            let connector = payment_data
+                .payment_attempt
+                .connector
+                .clone()
+                .ok_or(sch_errors::ProcessTrackerError::MissingRequiredField)?;
+
+            let is_last_retry = retry_sync_task(
+                db,
+                connector,
+                payment_data.payment_attempt.merchant_id.clone(),
+                process,
+            )
+            .await?;
+
+            // If the payment status is still processing and there is no connector transaction_id
+            // then change the payment status to failed if all retries exceeded
+            if is_last_retry
+                && payment_data.payment_attempt.status == enums::AttemptStatus::Pending
+                && payment_data
+                    .payment_attempt
+                    .connector_transaction_id
+                    .as_ref()
+                    .is_none()
+            {
+                let payment_intent_update = hyperswitch_domain_models::payments::payment_intent::PaymentIntentUpdate::PGStatusUpdate { status: api_models::enums::IntentStatus::Failed,updated_by: merchant_account.storage_scheme.to_string(), incremental_authorization_allowed: Some(false) };
+                let payment_attempt_update =
+                    hyperswitch_domain_models::payments::payment_attempt::PaymentAttemptUpdate::ErrorUpdate {
+                        connector: None,
+                        status: api_models::enums::AttemptStatus::Failure,
+                        error_code: None,
+                        error_message: None,
+                        error_reason: Some(Some(
+                            consts::REQUEST_TIMEOUT_ERROR_MESSAGE_FROM_PSYNC.to_string(),
+                        )),
+                        amount_capturable: Some(common_utils::types::MinorUnit::new(0)),
+                        updated_by: merchant_account.storage_scheme.to_string(),
+                        unified_code: None,
+                        unified_message: None,
+                        connector_transaction_id: None,
+                        payment_method_data: None,
+                        authentication_type: None,
+                        retry_advice: None,
+                    };
+
+                payment_data.payment_attempt = db
+                    .update_payment_attempt_with_attempt_id(
+                        payment_data.payment_attempt,
+                        payment_attempt_update,
+                        merchant_account.storage_scheme,
+                    )
+                    .await
+                    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+                payment_data.payment_intent = db
+                    .update_payment_intent(
+                        &state.into(),
+                        payment_data.payment_intent,
+                        payment_intent_update,
+                        &key_store,
+                        merchant_account.storage_scheme,
+                    )
+                    .await
+                    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+                let profile_id = payment_data
+                    .payment_intent
+                    .profile_id
+                    .as_ref()
+                    .get_required_value("profile_id")
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Could not find profile_id in payment intent")?;
+
+                let business_profile = db
+                    .find_business_profile_by_profile_id(key_manager_state, &key_store, profile_id)
+                    .await
+                    .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                        id: profile_id.get_string_repr().to_owned(),
+                    })?;
+
+                // Trigger the outgoing webhook to notify the merchant about failed payment
+                let operation = operations::PaymentStatus;
+                Box::pin(utils::trigger_payments_webhook(
+                    merchant_account,
+                    business_profile,
+                    &key_store,
+                    payment_data,
+                    customer,
+                    state,
+                    operation,
+                ))
+                .await
+                .map_err(|error| logger::warn!(payments_outgoing_webhook_error=?error))
+                .ok();
+            }
+        }
+    };
+    Ok(())
+}
+
 /// Get the next schedule time
 ///
 /// The schedule time can be configured in configs by this key `pt_mapping_trustpay`
@@ -0,0 +1,86 @@
+use common_utils::ext_traits::Encode;
+use error_stack::ResultExt;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    core::reverse_lookup_reconciliation,
+    errors,
+    logger::error,
+    routes::SessionState,
+    types::storage::{self, enums},
+};
+
+/// The recurring reconciliation of the reverse lookups, which reschedules itself after every run,
+/// whether or not the run succeeded
+pub struct ReverseLookupReconciliationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ReverseLookupReconciliationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let config = &state.conf.reverse_lookup_reconciliation;
+
+        if !config.enabled {
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    "REVERSE_LOOKUP_RECONCILIATION_DISABLED",
+                )
+                .await
+                .map_err(Into::<errors::ProcessTrackerError>::into);
+        }
+
+        let tracking_data =
+            reverse_lookup_reconciliation::reconcile_next_page(state, &process).await?;
+        let tracking_data = tracking_data
+            .encode_to_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "Failed to serialize the reverse lookup reconciliation tracking data",
+            )?;
+
+        db.as_scheduler()
+            .update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(0),
+                    schedule_time: Some(reverse_lookup_reconciliation::get_next_schedule_time(
+                        config,
+                    )),
+                    tracking_data: Some(tracking_data),
+                    business_status: None,
+                    status: Some(enums::ProcessTrackerStatus::New),
+                    updated_at: Some(common_utils::date_time::now()),
+                },
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(%process.id, "Failed while executing workflow");
+        state
+            .store
+            .as_scheduler()
+            .reset_process(
+                process,
+                reverse_lookup_reconciliation::get_next_schedule_time(
+                    &state.conf.reverse_lookup_reconciliation,
+                ),
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -101,6 +101,7 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
         ),
         connector_meta_data: None,
         connector_wallets_details: None,
+        connector_request_timeout_secs: None,
         amount_captured: None,
         minor_amount_captured: None,
         access_token: None,
@@ -122,6 +123,7 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
         connector_http_status_code: None,
         apple_pay_flow: None,
         external_latency: None,
+        connector_network_retry_count: None,
         frm_metadata: None,
         refund_id: None,
         dispute_id: None,
@@ -170,6 +172,7 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
         address: PaymentAddress::default(),
         connector_meta_data: None,
         connector_wallets_details: None,
+        connector_request_timeout_secs: None,
         amount_captured: None,
         minor_amount_captured: None,
         access_token: None,
@@ -191,6 +194,7 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
         connector_http_status_code: None,
         apple_pay_flow: None,
         external_latency: None,
+        connector_network_retry_count: None,
         frm_metadata: None,
         refund_id: None,
         dispute_id: None,
@@ -515,6 +515,7 @@ pub trait ConnectorActions: Connector {
                 .clone()
                 .and_then(|a| a.connector_meta_data.map(Secret::new)),
             connector_wallets_details: None,
+            connector_request_timeout_secs: None,
             amount_captured: None,
             minor_amount_captured: None,
             access_token: info.clone().and_then(|a| a.access_token),
@@ -539,6 +540,7 @@ pub trait ConnectorActions: Connector {
             connector_http_status_code: None,
             apple_pay_flow: None,
             external_latency: None,
+            connector_network_retry_count: None,
             frm_metadata: None,
             refund_id: None,
             dispute_id: None,
@@ -947,6 +949,8 @@ impl Default for PaymentAuthorizeType {
             charges: None,
             integrity_object: None,
             merchant_order_reference_id: None,
+            amount_breakdown: None,
+            customer_tax_id: None,
         };
         Self(data)
     }
@@ -1045,6 +1049,7 @@ impl Default for CustomerType {
             phone: None,
             name: None,
             preprocessing_id: None,
+            tax_id: None,
         };
         Self(data)
     }
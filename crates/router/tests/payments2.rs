@@ -164,6 +164,7 @@ async fn payments_create_core() {
         authentication_type: None,
         statement_descriptor_name: None,
         statement_descriptor_suffix: None,
+        statement_descriptor_warnings: None,
         next_action: None,
         cancellation_reason: None,
         error_code: None,
@@ -181,6 +182,14 @@ async fn payments_create_core() {
         manual_retry_allowed: None,
         connector_transaction_id: None,
         frm_message: None,
+        fraud_score: None,
+        fraud_decision: None,
+        approved_amount: None,
+        expected_settlement_date: None,
+        retry_advice: None,
+        connector_network_retry_count: None,
+        stepped_up_from_attempt_id: None,
+        tags: None,
         metadata: None,
         connector_metadata: None,
         feature_metadata: None,
@@ -431,6 +440,7 @@ async fn payments_create_core_adyen_no_redirect() {
             authentication_type: None,
             statement_descriptor_name: None,
             statement_descriptor_suffix: None,
+            statement_descriptor_warnings: None,
             next_action: None,
             cancellation_reason: None,
             error_code: None,
@@ -448,6 +458,14 @@ async fn payments_create_core_adyen_no_redirect() {
             manual_retry_allowed: None,
             connector_transaction_id: None,
             frm_message: None,
+            fraud_score: None,
+            fraud_decision: None,
+            approved_amount: None,
+            expected_settlement_date: None,
+            retry_advice: None,
+            connector_network_retry_count: None,
+            stepped_up_from_attempt_id: None,
+            tags: None,
             metadata: None,
             connector_metadata: None,
             feature_metadata: None,
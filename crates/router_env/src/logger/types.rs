@@ -87,8 +87,30 @@ pub enum Flow {
     MerchantConnectorsDelete,
     /// Merchant Connectors list flow.
     MerchantConnectorsList,
+    /// Merchant Connectors webhook identifier rotate flow.
+    MerchantConnectorsWebhookIdentifierRotate,
+    /// Merchant Connectors credentials rotate flow.
+    MerchantConnectorsCredentialsRotate,
+    /// Merchant Connectors credentials promote flow.
+    MerchantConnectorsCredentialsPromote,
     /// Merchant Transfer Keys
     MerchantTransferKey,
+    /// Merchant onboarding status retrieve flow.
+    MerchantOnboardingStatusRetrieve,
+    /// Merchant onboarding status update flow.
+    MerchantOnboardingStatusUpdate,
+    /// Audit log retrieve flow.
+    AuditLogRetrieve,
+    /// Connector failover chain retrieve flow.
+    ConnectorFailoverChainRetrieve,
+    /// Place a legal hold on a record
+    LegalHoldCreate,
+    /// List the legal holds of a merchant
+    LegalHoldList,
+    /// Release a legal hold on a record
+    LegalHoldDelete,
+    /// Re-tokenize the stored cards of a merchant with the network tokenization service
+    PaymentMethodsRetokenize,
     /// ConfigKey create flow.
     ConfigKeyCreate,
     /// ConfigKey fetch flow.
@@ -123,12 +145,28 @@ pub enum Flow {
     PaymentMethodsMigrate,
     /// Payment methods list flow.
     PaymentMethodsList,
+    /// Payment methods recommendation flow.
+    PaymentMethodsRecommend,
     /// Payment method save flow
     PaymentMethodSave,
+    /// Bank account tokenize flow.
+    BankAccountTokenize,
+    /// Bank account token retrieve flow.
+    BankAccountTokenRetrieve,
+    /// Bank account verification update flow.
+    BankAccountVerificationUpdate,
     /// Customer payment methods list flow.
     CustomerPaymentMethodsList,
     /// List Customers for a merchant
     CustomersList,
+    /// Retry the pending or failed syncs of customer details to connectors
+    CustomersConnectorSyncRetry,
+    /// Retrieve the velocity counters of a customer
+    CustomersVelocityCountersRetrieve,
+    /// Append an entry to the communication log of a customer
+    CustomersCommunicationCreate,
+    /// List the communication log of a customer
+    CustomersCommunicationList,
     /// Retrieve countries and currencies for connector and payment method
     ListCountriesCurrencies,
     /// Payment method create collect link flow.
@@ -155,6 +193,20 @@ pub enum Flow {
     PaymentsCapture,
     /// Payments cancel flow.
     PaymentsCancel,
+    /// Payments capture void flow.
+    PaymentsCaptureVoid,
+    /// Payments resume flow.
+    PaymentsResume,
+    /// Payments receipt retrieve flow.
+    PaymentsReceiptRetrieve,
+    /// Payments void eligibility check flow.
+    PaymentsVoidEligibilityCheck,
+    /// Payments timeline retrieve flow.
+    PaymentsTimelineRetrieve,
+    /// Payments simulate webhook flow.
+    PaymentsSimulateWebhook,
+    /// Payment methods list for a checkout session flow.
+    PaymentMethodsListForSession,
     /// Payments approve flow.
     PaymentsApprove,
     /// Payments reject flow.
@@ -165,10 +217,18 @@ pub enum Flow {
     PaymentsStart,
     /// Payments list flow.
     PaymentsList,
+    /// Payments pending review list flow.
+    PaymentsPendingReviewList,
     /// Payments filters flow
     PaymentsFilters,
     /// Payments aggregates flow
     PaymentsAggregate,
+    /// Payments distinct card count flow
+    PaymentsDistinctCardCount,
+    /// Payments settlement amounts flow
+    PaymentsSettlementAmounts,
+    /// Related payments list flow
+    PaymentsRelatedList,
     #[cfg(feature = "payouts")]
     /// Payouts create flow
     PayoutsCreate,
@@ -192,6 +252,9 @@ pub enum Flow {
     #[cfg(feature = "payouts")]
     /// Payouts filter flow.
     PayoutsFilter,
+    #[cfg(feature = "payouts")]
+    /// Payouts returns flow.
+    PayoutsReturns,
     /// Payouts accounts flow.
     PayoutsAccounts,
     /// Payout link initiate flow
@@ -214,6 +277,8 @@ pub enum Flow {
     RefundsFilters,
     /// Refunds aggregates flow
     RefundsAggregate,
+    /// Refunds eligibility check flow
+    RefundsEligibilityCheck,
     // Retrieve forex flow.
     RetrieveForexFlow,
     /// Toggles recon service for a merchant.
@@ -232,6 +297,8 @@ pub enum Flow {
     RoutingUnlinkConfig,
     /// Routing retrieve config
     RoutingRetrieveConfig,
+    /// Routing retrieve the effective weights of a volume split
+    RoutingRetrieveEffectiveWeights,
     /// Routing retrieve active config
     RoutingRetrieveActiveConfig,
     /// Routing retrieve default config
@@ -296,8 +363,18 @@ pub enum Flow {
     DisputesAggregate,
     /// Retrieve Dispute Evidence flow
     RetrieveDisputeEvidence,
+    /// Download Dispute Evidence flow
+    DownloadDisputeEvidence,
     /// Invalidate cache flow
     CacheInvalidate,
+    /// Connector concurrency retrieve flow
+    ConnectorConcurrencyRetrieve,
+    /// Connector latency retrieve flow
+    ConnectorLatencyRetrieve,
+    /// Settlement file ingest flow
+    SettlementFileIngest,
+    /// Unmatched settlement line items list flow
+    SettlementLineItemsUnmatchedList,
     /// Payment Link Retrieve flow
     PaymentLinkRetrieve,
     /// payment Link Initiate flow
@@ -360,6 +437,8 @@ pub enum Flow {
     GetMultipleDashboardMetadata,
     /// Payment Connector Verify
     VerifyPaymentConnector,
+    /// Tokenization test of a payment connector
+    TestConnectorTokenization,
     /// Internal user signup
     InternalUserSignup,
     /// Switch org
@@ -484,6 +563,8 @@ pub enum Flow {
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// Retrieve the state of the delivery of the webhooks of a merchant
+    WebhookEventDeliveryQueueRetrieve,
     /// Retrieve status of the Poll
     RetrievePollStatus,
     /// Toggles the extended card info feature in profile level
@@ -496,6 +577,8 @@ pub enum Flow {
     RefundsManualUpdate,
     /// Manually update the payment details like status, error code, error message etc.
     PaymentsManualUpdate,
+    /// Update the fulfillment status of the order of a payment
+    PaymentsFulfillmentUpdate,
     /// Dynamic Tax Calcultion
     SessionUpdateTaxCalculation,
 }
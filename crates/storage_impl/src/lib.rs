@@ -35,6 +35,13 @@ use router_env::logger;
 pub use crate::database::store::DatabaseStore;
 #[cfg(not(feature = "payouts"))]
 pub use crate::database::store::Store;
+pub use crate::lookup::repopulate_reverse_lookup;
+
+/// Number of times a failed push to the drainer stream is retried, when not configured
+pub const DEFAULT_DRAINER_PUSH_RETRY_COUNT: u8 = 3;
+
+/// Backoff before the first retry of a failed push to the drainer stream, doubled on every retry
+const DRAINER_PUSH_RETRY_BASE_BACKOFF_MS: u64 = 10;
 
 #[derive(Debug, Clone)]
 pub struct RouterStore<T: DatabaseStore> {
@@ -172,6 +179,8 @@ pub struct KVRouterStore<T: DatabaseStore> {
     ttl_for_kv: u32,
     pub request_id: Option<String>,
     soft_kill_mode: bool,
+    drainer_push_retry_count: u8,
+    reverse_lookup_read_repair: bool,
 }
 
 #[async_trait::async_trait]
@@ -180,13 +189,29 @@ where
     RouterStore<T>: DatabaseStore,
     T: DatabaseStore,
 {
-    type Config = (RouterStore<T>, String, u8, u32, Option<bool>);
+    type Config = (
+        RouterStore<T>,
+        String,
+        u8,
+        u32,
+        Option<bool>,
+        Option<u8>,
+        Option<bool>,
+    );
     async fn new(
         config: Self::Config,
         tenant_config: &dyn config::TenantConfig,
         _test_transaction: bool,
     ) -> StorageResult<Self> {
-        let (router_store, _, drainer_num_partitions, ttl_for_kv, soft_kill_mode) = config;
+        let (
+            router_store,
+            _,
+            drainer_num_partitions,
+            ttl_for_kv,
+            soft_kill_mode,
+            drainer_push_retry_count,
+            reverse_lookup_read_repair,
+        ) = config;
         let drainer_stream_name = format!("{}_{}", tenant_config.get_schema(), config.1);
         Ok(Self::from_store(
             router_store,
@@ -194,6 +219,8 @@ where
             drainer_num_partitions,
             ttl_for_kv,
             soft_kill_mode,
+            drainer_push_retry_count,
+            reverse_lookup_read_repair,
         ))
     }
     fn get_master_pool(&self) -> &PgPool {
@@ -217,6 +244,8 @@ impl<T: DatabaseStore> KVRouterStore<T> {
         drainer_num_partitions: u8,
         ttl_for_kv: u32,
         soft_kill: Option<bool>,
+        drainer_push_retry_count: Option<u8>,
+        reverse_lookup_read_repair: Option<bool>,
     ) -> Self {
         let request_id = store.request_id.clone();
 
@@ -227,6 +256,9 @@ impl<T: DatabaseStore> KVRouterStore<T> {
             ttl_for_kv,
             request_id,
             soft_kill_mode: soft_kill.unwrap_or(false),
+            drainer_push_retry_count: drainer_push_retry_count
+                .unwrap_or(DEFAULT_DRAINER_PUSH_RETRY_COUNT),
+            reverse_lookup_read_repair: reverse_lookup_read_repair.unwrap_or(false),
         }
     }
 
@@ -238,6 +270,19 @@ impl<T: DatabaseStore> KVRouterStore<T> {
         format!("{{{}}}_{}", shard_key, self.drainer_stream_name)
     }
 
+    /// The name of the drainer stream which the entries of the partition are appended to
+    pub fn get_drainer_stream_name_for_partition<R>(
+        &self,
+        partition_key: redis::kv_store::PartitionKey<'_>,
+    ) -> String
+    where
+        R: redis::kv_store::KvStorePartition,
+    {
+        self.get_drainer_stream_name(&R::shard_key(partition_key, self.drainer_num_partitions))
+    }
+
+    /// Append the entry to the drainer stream, retrying failed appends with an exponential backoff
+    /// for up to the configured number of retries.
     pub async fn push_to_drainer_stream<R>(
         &self,
         redis_entry: diesel_models::kv::TypedSql,
@@ -249,25 +294,48 @@ impl<T: DatabaseStore> KVRouterStore<T> {
         let global_id = format!("{}", partition_key);
         let request_id = self.request_id.clone().unwrap_or_default();
 
-        let shard_key = R::shard_key(partition_key, self.drainer_num_partitions);
-        let stream_name = self.get_drainer_stream_name(&shard_key);
-        self.router_store
-            .cache_store
-            .redis_conn
-            .stream_append_entry(
-                &stream_name,
-                &redis_interface::RedisEntryId::AutoGeneratedID,
-                redis_entry
-                    .to_field_value_pairs(request_id, global_id)
-                    .change_context(RedisError::JsonSerializationFailed)?,
-            )
-            .await
-            .map(|_| metrics::KV_PUSHED_TO_DRAINER.add(&metrics::CONTEXT, 1, &[]))
-            .inspect_err(|error| {
-                metrics::KV_FAILED_TO_PUSH_TO_DRAINER.add(&metrics::CONTEXT, 1, &[]);
-                logger::error!(?error, "Failed to add entry in drainer stream");
-            })
-            .change_context(RedisError::StreamAppendFailed)
+        let stream_name = self.get_drainer_stream_name_for_partition::<R>(partition_key);
+        let field_value_pairs = redis_entry
+            .to_field_value_pairs(request_id, global_id)
+            .change_context(RedisError::JsonSerializationFailed)?;
+
+        let mut retries_left = self.drainer_push_retry_count;
+        let mut backoff = std::time::Duration::from_millis(DRAINER_PUSH_RETRY_BASE_BACKOFF_MS);
+
+        loop {
+            match self
+                .router_store
+                .cache_store
+                .redis_conn
+                .stream_append_entry(
+                    &stream_name,
+                    &redis_interface::RedisEntryId::AutoGeneratedID,
+                    field_value_pairs.clone(),
+                )
+                .await
+            {
+                Ok(()) => {
+                    metrics::KV_PUSHED_TO_DRAINER.add(&metrics::CONTEXT, 1, &[]);
+                    break Ok(());
+                }
+                Err(error) if retries_left > 0 => {
+                    logger::warn!(
+                        ?error,
+                        retries_left,
+                        "Failed to add entry in drainer stream, retrying"
+                    );
+                    metrics::KV_PUSH_TO_DRAINER_RETRIED.add(&metrics::CONTEXT, 1, &[]);
+                    tokio::time::sleep(backoff).await;
+                    backoff *= 2;
+                    retries_left -= 1;
+                }
+                Err(error) => {
+                    metrics::KV_FAILED_TO_PUSH_TO_DRAINER.add(&metrics::CONTEXT, 1, &[]);
+                    logger::error!(?error, "Failed to add entry in drainer stream");
+                    break Err(error.change_context(RedisError::StreamAppendFailed));
+                }
+            }
+        }
     }
 }
 
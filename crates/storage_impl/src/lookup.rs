@@ -1,18 +1,26 @@
-use common_utils::errors::CustomResult;
+use common_utils::{errors::CustomResult, id_type};
 use diesel_models::{
-    enums as storage_enums, kv,
+    enums as storage_enums,
+    errors::DatabaseError,
+    kv,
     reverse_lookup::{
-        ReverseLookup as DieselReverseLookup, ReverseLookupNew as DieselReverseLookupNew,
+        get_namespaced_lookup_id, ReverseLookup as DieselReverseLookup,
+        ReverseLookupNew as DieselReverseLookupNew,
     },
 };
 use error_stack::ResultExt;
 use hyperswitch_domain_models::errors;
 use redis_interface::SetnxReply;
+use router_env::logger;
 
 use crate::{
     diesel_error_to_data_error,
     errors::RedisErrorExt,
-    redis::kv_store::{decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey},
+    metrics,
+    redis::kv_store::{
+        decide_storage_scheme, kv_get_versioned, kv_wrapper, KvOperation, Op, PartitionKey,
+        RedisConnInterface,
+    },
     utils::{self, try_redis_get_else_try_database_get},
     DatabaseStore, KVRouterStore, RouterStore,
 };
@@ -29,6 +37,52 @@ pub trait ReverseLookupInterface {
         _id: &str,
         storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> CustomResult<DieselReverseLookup, errors::StorageError>;
+    /// Insert the reverse lookup in the namespace of the merchant, so that it is deleted along
+    /// with the other lookups of the merchant
+    async fn insert_namespaced_reverse_lookup(
+        &self,
+        new: DieselReverseLookupNew,
+        merchant_id: &id_type::MerchantId,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<DieselReverseLookup, errors::StorageError> {
+        let new = DieselReverseLookupNew {
+            lookup_id: get_namespaced_lookup_id(merchant_id, &new.lookup_id),
+            ..new
+        };
+        self.insert_reverse_lookup(new, storage_scheme).await
+    }
+    /// Get the reverse lookup from the namespace of the merchant. A lookup which was inserted
+    /// before lookups were namespaced is found by its legacy lookup id, if any.
+    async fn get_namespaced_lookup_by_lookup_id(
+        &self,
+        id: &str,
+        merchant_id: &id_type::MerchantId,
+        legacy_lookup_id: Option<&str>,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<DieselReverseLookup, errors::StorageError> {
+        let result = self
+            .get_lookup_by_lookup_id(&get_namespaced_lookup_id(merchant_id, id), storage_scheme)
+            .await;
+        match (result, legacy_lookup_id) {
+            (Err(error), Some(legacy_lookup_id))
+                if is_lookup_not_found(error.current_context()) =>
+            {
+                self.get_lookup_by_lookup_id(legacy_lookup_id, storage_scheme)
+                    .await
+            }
+            (result, _) => result,
+        }
+    }
+}
+
+fn is_lookup_not_found(error: &errors::StorageError) -> bool {
+    match error {
+        errors::StorageError::ValueNotFound(_) => true,
+        errors::StorageError::DatabaseError(error) => {
+            matches!(error.current_context(), DatabaseError::NotFound)
+        }
+        _ => false,
+    }
 }
 
 #[async_trait::async_trait]
@@ -139,24 +193,70 @@ impl<T: DatabaseStore> ReverseLookupInterface for KVRouterStore<T> {
         match storage_scheme {
             storage_enums::MerchantStorageScheme::PostgresOnly => database_call().await,
             storage_enums::MerchantStorageScheme::RedisKv => {
-                let redis_fut = async {
-                    kv_wrapper(
-                        self,
-                        KvOperation::<DieselReverseLookup>::Get,
-                        PartitionKey::CombinationKey {
-                            combination: &format!("reverse_lookup_{id}"),
-                        },
-                    )
-                    .await?
-                    .try_into_get()
+                let key = format!("reverse_lookup_{id}");
+                let redis_fut = kv_get_versioned::<DieselReverseLookup, _>(
+                    self,
+                    PartitionKey::CombinationKey { combination: &key },
+                );
+                let database_call_with_read_repair = || async {
+                    let reverse_lookup = database_call().await?;
+                    self.read_repair_reverse_lookup(&key, &reverse_lookup).await;
+                    Ok(reverse_lookup)
                 };
 
                 Box::pin(try_redis_get_else_try_database_get(
                     redis_fut,
-                    database_call,
+                    database_call_with_read_repair,
                 ))
                 .await
             }
         }
     }
 }
+
+impl<T: DatabaseStore> KVRouterStore<T> {
+    /// Write a reverse lookup which was served from the database back to redis, when the read
+    /// repair of the reverse lookups is enabled
+    pub async fn read_repair_reverse_lookup(
+        &self,
+        key: &str,
+        reverse_lookup: &DieselReverseLookup,
+    ) {
+        if self.reverse_lookup_read_repair {
+            repopulate_reverse_lookup(self, key, reverse_lookup, self.ttl_for_kv).await;
+        }
+    }
+}
+
+/// Write a reverse lookup found in the database back to redis, so that subsequent lookups are
+/// served from redis.
+///
+/// The key is set only if it does not exist, to avoid overwriting a value written concurrently.
+/// The value is not pushed to the drainer since it is already present in the database.
+/// Failures are logged and do not fail the lookup.
+pub async fn repopulate_reverse_lookup<S: RedisConnInterface + ?Sized>(
+    store: &S,
+    key: &str,
+    reverse_lookup: &DieselReverseLookup,
+    ttl_in_secs: u32,
+) {
+    let result = async {
+        store
+            .get_redis_conn()?
+            .serialize_and_set_key_if_not_exist(key, reverse_lookup, Some(ttl_in_secs.into()))
+            .await
+    }
+    .await;
+
+    match result {
+        Ok(SetnxReply::KeySet) => {
+            metrics::KV_READ_REPAIR.add(&metrics::CONTEXT, 1, &[]);
+        }
+        Ok(SetnxReply::KeyNotSet) => {
+            logger::debug!(key, "Reverse lookup already repopulated in redis");
+        }
+        Err(error) => {
+            logger::error!(?error, key, "Failed to repopulate reverse lookup in redis");
+        }
+    }
+}
@@ -4,12 +4,15 @@ metrics_context!(CONTEXT);
 global_meter!(GLOBAL_METER, "ROUTER_API");
 
 counter_metric!(KV_MISS, GLOBAL_METER); // No. of KV misses
+counter_metric!(KV_READ_REPAIR, GLOBAL_METER); // No. of values written back to KV after a KV miss
 
 // Metrics for KV
 counter_metric!(KV_OPERATION_SUCCESSFUL, GLOBAL_METER);
 counter_metric!(KV_OPERATION_FAILED, GLOBAL_METER);
 counter_metric!(KV_PUSHED_TO_DRAINER, GLOBAL_METER);
 counter_metric!(KV_FAILED_TO_PUSH_TO_DRAINER, GLOBAL_METER);
+counter_metric!(KV_PUSH_TO_DRAINER_RETRIED, GLOBAL_METER);
+counter_metric!(KV_ROLLED_BACK_AFTER_DRAINER_PUSH_FAILURE, GLOBAL_METER);
 counter_metric!(KV_SOFT_KILL_ACTIVE_UPDATE, GLOBAL_METER);
 
 // Metrics for In-memory cache
@@ -60,6 +60,12 @@ pub struct MockDb {
     pub user_key_store: Arc<Mutex<Vec<store::user_key_store::UserKeyStore>>>,
     pub user_authentication_methods:
         Arc<Mutex<Vec<store::user_authentication_method::UserAuthenticationMethod>>>,
+    pub incoming_webhook_events:
+        Arc<Mutex<Vec<store::incoming_webhook_event::IncomingWebhookEvent>>>,
+    pub resource_sequences: Arc<Mutex<Vec<store::resource_sequence::ResourceSequence>>>,
+    pub three_ds_sessions: Arc<Mutex<Vec<store::three_ds_session::ThreeDsSession>>>,
+    pub connector_webhook_offsets:
+        Arc<Mutex<Vec<store::connector_webhook_offset::ConnectorWebhookOffset>>>,
 }
 
 impl MockDb {
@@ -105,6 +111,10 @@ impl MockDb {
             roles: Default::default(),
             user_key_store: Default::default(),
             user_authentication_methods: Default::default(),
+            incoming_webhook_events: Default::default(),
+            resource_sequences: Default::default(),
+            three_ds_sessions: Default::default(),
+            connector_webhook_offsets: Default::default(),
         })
     }
 }
@@ -58,6 +58,94 @@ impl PaymentAttemptInterface for MockDb {
         Err(StorageError::MockDbError)?
     }
 
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn list_payments_by_tag(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _tag_key: &str,
+        _tag_value: &str,
+        _filters: &hyperswitch_domain_models::payments::payment_attempt::PaymentAttemptTagFilters,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<PaymentAttempt>, StorageError> {
+        Err(StorageError::MockDbError)?
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn list_payments_pending_review(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _filters: &hyperswitch_domain_models::payments::payment_attempt::PaymentAttemptReviewFilters,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<PaymentAttempt>, StorageError> {
+        Err(StorageError::MockDbError)?
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn get_distinct_card_hash_count_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        time_range: common_utils::types::TimeRange,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<i64, StorageError> {
+        let payment_attempts = self.payment_attempts.lock().await;
+        let card_hashes = payment_attempts
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.merchant_id == *merchant_id
+                    && payment_attempt.created_at >= time_range.start_time
+                    && time_range
+                        .end_time
+                        .map_or(true, |end_time| payment_attempt.created_at <= end_time)
+            })
+            .filter_map(|payment_attempt| payment_attempt.card_hash.as_ref())
+            .collect::<std::collections::HashSet<_>>();
+
+        Ok(i64::try_from(card_hashes.len()).unwrap_or(i64::MAX))
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn get_settlement_amounts_by_currency(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        settlement_date: time::Date,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<(storage_enums::Currency, common_utils::types::MinorUnit)>, StorageError>
+    {
+        let payment_attempts = self.payment_attempts.lock().await;
+        let settlement_amounts = payment_attempts
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.merchant_id == *merchant_id
+                    && payment_attempt.expected_settlement_date == Some(settlement_date)
+                    && matches!(
+                        payment_attempt.status,
+                        storage_enums::AttemptStatus::Charged
+                            | storage_enums::AttemptStatus::PartialCharged
+                            | storage_enums::AttemptStatus::PartialChargedAndChargeable
+                    )
+            })
+            .filter_map(|payment_attempt| {
+                payment_attempt.currency.map(|currency| {
+                    (
+                        currency,
+                        payment_attempt
+                            .amount_to_capture
+                            .unwrap_or(payment_attempt.net_amount),
+                    )
+                })
+            })
+            .fold(
+                std::collections::HashMap::<_, common_utils::types::MinorUnit>::new(),
+                |mut settlement_amounts, (currency, amount)| {
+                    let settlement_amount = settlement_amounts.entry(currency).or_default();
+                    *settlement_amount = *settlement_amount + amount;
+                    settlement_amounts
+                },
+            );
+
+        Ok(settlement_amounts.into_iter().collect())
+    }
+
     #[cfg(feature = "v1")]
     async fn find_payment_attempt_by_attempt_id_merchant_id(
         &self,
@@ -103,6 +191,33 @@ impl PaymentAttemptInterface for MockDb {
         Err(StorageError::MockDbError)?
     }
 
+    #[cfg(feature = "v1")]
+    async fn find_payment_attempt_by_merchant_id_connector_connector_txn_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector: &str,
+        connector_txn_id: &str,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<PaymentAttempt, StorageError> {
+        let payment_attempts = self.payment_attempts.lock().await;
+
+        payment_attempts
+            .iter()
+            .find(|payment_attempt| {
+                payment_attempt.merchant_id == *merchant_id
+                    && payment_attempt.connector.as_deref() == Some(connector)
+                    && payment_attempt.connector_transaction_id.as_deref()
+                        == Some(connector_txn_id)
+            })
+            .cloned()
+            .ok_or(
+                StorageError::ValueNotFound(format!(
+                    "No payment attempt found for connector `{connector}` with connector transaction id `{connector_txn_id}`"
+                ))
+                .into(),
+            )
+    }
+
     #[cfg(feature = "v1")]
     async fn find_attempts_by_merchant_id_payment_id(
         &self,
@@ -187,6 +302,15 @@ impl PaymentAttemptInterface for MockDb {
             profile_id: payment_attempt.profile_id,
             shipping_cost: payment_attempt.shipping_cost,
             order_tax_amount: payment_attempt.order_tax_amount,
+            fraud_score: payment_attempt.fraud_score,
+            fraud_decision: payment_attempt.fraud_decision,
+            approved_amount: payment_attempt.approved_amount,
+            expected_settlement_date: payment_attempt.expected_settlement_date,
+            card_hash: payment_attempt.card_hash,
+            retry_advice: payment_attempt.retry_advice,
+            tags: payment_attempt.tags,
+            is_connector_override: payment_attempt.is_connector_override,
+            stepped_up_from_attempt_id: payment_attempt.stepped_up_from_attempt_id,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
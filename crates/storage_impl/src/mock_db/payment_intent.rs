@@ -28,6 +28,65 @@ impl PaymentIntentInterface for MockDb {
         Err(StorageError::MockDbError)?
     }
 
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn find_payment_intents_by_root_payment_id_merchant_id(
+        &self,
+        _state: &KeyManagerState,
+        root_payment_id: &common_utils::id_type::PaymentId,
+        merchant_id: &common_utils::id_type::MerchantId,
+        _key_store: &MerchantKeyStore,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+        limit: i64,
+    ) -> CustomResult<Vec<PaymentIntent>, StorageError> {
+        let payment_intents = self.payment_intents.lock().await;
+        let mut related_payment_intents = payment_intents
+            .iter()
+            .filter(|payment_intent| {
+                payment_intent.merchant_id == *merchant_id
+                    && (payment_intent.root_payment_id.as_ref() == Some(root_payment_id)
+                        || payment_intent.get_id() == root_payment_id)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        related_payment_intents.sort_by_key(|payment_intent| payment_intent.created_at);
+        related_payment_intents.truncate(usize::try_from(limit).unwrap_or(0));
+
+        Ok(related_payment_intents)
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn find_payments_by_metadata(
+        &self,
+        _state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        key: &hyperswitch_domain_models::payments::payment_intent::PaymentMetadataKey,
+        value: &str,
+        pagination: hyperswitch_domain_models::payments::payment_intent::PaymentIntentPagination,
+        _key_store: &MerchantKeyStore,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<PaymentIntent>, StorageError> {
+        let payment_intents = self.payment_intents.lock().await;
+        let mut matching_payment_intents = payment_intents
+            .iter()
+            .filter(|payment_intent| {
+                payment_intent.merchant_id == *merchant_id
+                    && payment_intent
+                        .metadata
+                        .as_ref()
+                        .is_some_and(|metadata| key.matches(metadata, value))
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        matching_payment_intents
+            .sort_by_key(|payment_intent| std::cmp::Reverse(payment_intent.created_at));
+
+        Ok(matching_payment_intents
+            .into_iter()
+            .skip(usize::try_from(pagination.offset).unwrap_or(usize::MAX))
+            .take(usize::try_from(pagination.limit).unwrap_or(usize::MAX))
+            .collect())
+    }
+
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn filter_payment_intents_by_time_range_constraints(
         &self,
@@ -52,6 +111,48 @@ impl PaymentIntentInterface for MockDb {
         Err(StorageError::MockDbError)?
     }
 
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn get_merchant_volume_metrics(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        time_range: &common_utils::types::TimeRange,
+    ) -> CustomResult<
+        Vec<hyperswitch_domain_models::payments::payment_intent::MerchantVolumeMetric>,
+        StorageError,
+    > {
+        let payment_intents = self.payment_intents.lock().await;
+        let mut metrics: Vec<
+            hyperswitch_domain_models::payments::payment_intent::MerchantVolumeMetric,
+        > = Vec::new();
+
+        for payment_intent in payment_intents.iter().filter(|payment_intent| {
+            payment_intent.merchant_id == *merchant_id
+                && payment_intent.created_at >= time_range.start_time
+                && time_range
+                    .end_time
+                    .map_or(true, |end_time| payment_intent.created_at <= end_time)
+        }) {
+            match metrics.iter_mut().find(|metric| {
+                metric.status == payment_intent.status && metric.currency == payment_intent.currency
+            }) {
+                Some(metric) => {
+                    metric.count += 1;
+                    metric.total_amount = metric.total_amount + payment_intent.amount;
+                }
+                None => metrics.push(
+                    hyperswitch_domain_models::payments::payment_intent::MerchantVolumeMetric {
+                        status: payment_intent.status,
+                        currency: payment_intent.currency,
+                        count: 1,
+                        total_amount: payment_intent.amount,
+                    },
+                ),
+            }
+        }
+
+        Ok(metrics)
+    }
+
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn get_filtered_active_attempt_ids_for_total_count(
         &self,
@@ -5,6 +5,7 @@ use hyperswitch_domain_models::{
     payouts::{
         payout_attempt::{
             PayoutAttempt, PayoutAttemptInterface, PayoutAttemptNew, PayoutAttemptUpdate,
+            PayoutReturn, PayoutReturnResult,
         },
         payouts::Payouts,
     },
@@ -66,4 +67,14 @@ impl PayoutAttemptInterface for MockDb {
     > {
         Err(StorageError::MockDbError)?
     }
+
+    async fn apply_payout_returns(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payout_returns: Vec<PayoutReturn>,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<PayoutReturnResult>, StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(StorageError::MockDbError)?
+    }
 }
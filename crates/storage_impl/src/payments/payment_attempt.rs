@@ -28,7 +28,10 @@ use hyperswitch_domain_models::{
 };
 #[cfg(feature = "olap")]
 use hyperswitch_domain_models::{
-    payments::payment_attempt::PaymentListFilters, payments::PaymentIntent,
+    payments::payment_attempt::{
+        PaymentAttemptReviewFilters, PaymentAttemptTagFilters, PaymentListFilters,
+    },
+    payments::PaymentIntent,
 };
 use redis_interface::HsetnxReply;
 use router_env::{instrument, tracing};
@@ -234,6 +237,30 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         .map(PaymentAttempt::from_storage_model)
     }
 
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_payment_attempt_by_merchant_id_connector_connector_txn_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector: &str,
+        connector_txn_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<PaymentAttempt, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPaymentAttempt::find_by_merchant_id_connector_connector_txn_id(
+            &conn,
+            merchant_id,
+            connector,
+            connector_txn_id,
+        )
+        .await
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+        .map(PaymentAttempt::from_storage_model)
+    }
+
     #[cfg(feature = "v1")]
     #[instrument(skip_all)]
     async fn find_payment_attempt_by_payment_id_merchant_id_attempt_id(
@@ -435,6 +462,138 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
             er.change_context(new_err)
         })
     }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    #[instrument(skip_all)]
+    async fn list_payments_by_tag(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        tag_key: &str,
+        tag_value: &str,
+        filters: &PaymentAttemptTagFilters,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+        let conn = self
+            .db_store
+            .get_replica_pool()
+            .get()
+            .await
+            .change_context(errors::StorageError::DatabaseConnectionError)?;
+
+        DieselPaymentAttempt::filter_by_merchant_id_tag(
+            &conn,
+            merchant_id,
+            tag_key,
+            tag_value,
+            filters.profile_id_list.clone(),
+            filters.status.clone(),
+            filters.time_range,
+            filters.limit,
+            filters.offset,
+        )
+        .await
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+        .map(|payment_attempts| {
+            payment_attempts
+                .into_iter()
+                .map(PaymentAttempt::from_storage_model)
+                .collect()
+        })
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    #[instrument(skip_all)]
+    async fn list_payments_pending_review(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        filters: &PaymentAttemptReviewFilters,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+        let conn = self
+            .db_store
+            .get_replica_pool()
+            .get()
+            .await
+            .change_context(errors::StorageError::DatabaseConnectionError)?;
+
+        DieselPaymentAttempt::filter_by_merchant_id_pending_review(
+            &conn,
+            merchant_id,
+            filters.profile_id_list.clone(),
+            filters.connector.clone(),
+            filters.min_fraud_score,
+            filters.created_before,
+            filters.limit,
+            filters.offset,
+        )
+        .await
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+        .map(|payment_attempts| {
+            payment_attempts
+                .into_iter()
+                .map(PaymentAttempt::from_storage_model)
+                .collect()
+        })
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    #[instrument(skip_all)]
+    async fn get_distinct_card_hash_count_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        time_range: common_utils::types::TimeRange,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = self
+            .db_store
+            .get_replica_pool()
+            .get()
+            .await
+            .change_context(errors::StorageError::DatabaseConnectionError)?;
+
+        DieselPaymentAttempt::get_distinct_card_hash_count(&conn, merchant_id, time_range)
+            .await
+            .map_err(|er| {
+                let new_err = diesel_error_to_data_error(er.current_context());
+                er.change_context(new_err)
+            })
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    #[instrument(skip_all)]
+    async fn get_settlement_amounts_by_currency(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        settlement_date: time::Date,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<
+        Vec<(common_enums::Currency, common_utils::types::MinorUnit)>,
+        errors::StorageError,
+    > {
+        let conn = self
+            .db_store
+            .get_replica_pool()
+            .get()
+            .await
+            .change_context(errors::StorageError::DatabaseConnectionError)?;
+
+        DieselPaymentAttempt::get_settlement_amounts_by_currency(
+            &conn,
+            merchant_id,
+            settlement_date,
+        )
+        .await
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+    }
 }
 
 #[async_trait::async_trait]
@@ -536,6 +695,15 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     profile_id: payment_attempt.profile_id.clone(),
                     shipping_cost: payment_attempt.shipping_cost,
                     order_tax_amount: payment_attempt.order_tax_amount,
+                    fraud_score: payment_attempt.fraud_score,
+                    fraud_decision: payment_attempt.fraud_decision,
+                    approved_amount: payment_attempt.approved_amount,
+                    expected_settlement_date: payment_attempt.expected_settlement_date,
+                    card_hash: payment_attempt.card_hash.clone(),
+                    retry_advice: payment_attempt.retry_advice,
+                    tags: payment_attempt.tags.clone(),
+                    is_connector_override: payment_attempt.is_connector_override,
+                    stepped_up_from_attempt_id: payment_attempt.stepped_up_from_attempt_id.clone(),
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -550,18 +718,18 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
 
                 //Reverse lookup for attempt_id
                 let reverse_lookup = ReverseLookupNew {
-                    lookup_id: format!(
-                        "pa_{}_{}",
-                        created_attempt.merchant_id.get_string_repr(),
-                        &created_attempt.attempt_id,
-                    ),
+                    lookup_id: format!("pa_{}", &created_attempt.attempt_id),
                     pk_id: key_str.clone(),
                     sk_id: field.clone(),
                     source: "payment_attempt".to_string(),
                     updated_by: storage_scheme.to_string(),
                 };
-                self.insert_reverse_lookup(reverse_lookup, storage_scheme)
-                    .await?;
+                self.insert_namespaced_reverse_lookup(
+                    reverse_lookup,
+                    &created_attempt.merchant_id,
+                    storage_scheme,
+                )
+                .await?;
 
                 match kv_wrapper::<PaymentAttempt, _, _>(
                     self,
@@ -672,6 +840,18 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                             storage_scheme,
                         )
                         .await?;
+                        if let Some(connector) = &updated_attempt.connector {
+                            add_connector_and_connector_txn_id_to_reverse_lookup(
+                                self,
+                                key_str.as_str(),
+                                &this.merchant_id,
+                                updated_attempt.attempt_id.as_str(),
+                                connector,
+                                connector_transaction_id.as_str(),
+                                storage_scheme,
+                            )
+                            .await?;
+                        }
                     }
                     (Some(old_connector_transaction_id), Some(connector_transaction_id)) => {
                         if old_connector_transaction_id.ne(connector_transaction_id) {
@@ -684,6 +864,18 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                                 storage_scheme,
                             )
                             .await?;
+                            if let Some(connector) = &updated_attempt.connector {
+                                add_connector_and_connector_txn_id_to_reverse_lookup(
+                                    self,
+                                    key_str.as_str(),
+                                    &this.merchant_id,
+                                    updated_attempt.attempt_id.as_str(),
+                                    connector,
+                                    connector_transaction_id.as_str(),
+                                    storage_scheme,
+                                )
+                                .await?;
+                            }
                         }
                     }
                     (_, _) => {}
@@ -782,13 +974,17 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
             }
             MerchantStorageScheme::RedisKv => {
                 // We assume that PaymentAttempt <=> PaymentIntent is a one-to-one relation for now
-                let lookup_id = format!(
-                    "pa_conn_trans_{}_{connector_transaction_id}",
-                    merchant_id.get_string_repr()
-                );
                 let lookup = fallback_reverse_lookup_not_found!(
-                    self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
-                        .await,
+                    self.get_namespaced_lookup_by_lookup_id(
+                        &format!("pa_conn_trans_{connector_transaction_id}"),
+                        merchant_id,
+                        Some(&format!(
+                            "pa_conn_trans_{}_{connector_transaction_id}",
+                            merchant_id.get_string_repr()
+                        )),
+                        storage_scheme,
+                    )
+                    .await,
                     self.router_store
                         .find_payment_attempt_by_connector_transaction_id_payment_id_merchant_id(
                             connector_transaction_id,
@@ -960,13 +1156,17 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     .await
             }
             MerchantStorageScheme::RedisKv => {
-                let lookup_id = format!(
-                    "pa_conn_trans_{}_{connector_txn_id}",
-                    merchant_id.get_string_repr()
-                );
                 let lookup = fallback_reverse_lookup_not_found!(
-                    self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
-                        .await,
+                    self.get_namespaced_lookup_by_lookup_id(
+                        &format!("pa_conn_trans_{connector_txn_id}"),
+                        merchant_id,
+                        Some(&format!(
+                            "pa_conn_trans_{}_{connector_txn_id}",
+                            merchant_id.get_string_repr()
+                        )),
+                        storage_scheme,
+                    )
+                    .await,
                     self.router_store
                         .find_payment_attempt_by_merchant_id_connector_txn_id(
                             merchant_id,
@@ -1004,6 +1204,83 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
         }
     }
 
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_payment_attempt_by_merchant_id_connector_connector_txn_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        connector: &str,
+        connector_txn_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<PaymentAttempt, errors::StorageError> {
+        let storage_scheme = Box::pin(decide_storage_scheme::<_, DieselPaymentAttempt>(
+            self,
+            storage_scheme,
+            Op::Find,
+        ))
+        .await;
+        match storage_scheme {
+            MerchantStorageScheme::PostgresOnly => {
+                self.router_store
+                    .find_payment_attempt_by_merchant_id_connector_connector_txn_id(
+                        merchant_id,
+                        connector,
+                        connector_txn_id,
+                        storage_scheme,
+                    )
+                    .await
+            }
+            MerchantStorageScheme::RedisKv => {
+                let lookup = fallback_reverse_lookup_not_found!(
+                    self.get_namespaced_lookup_by_lookup_id(
+                        &format!("pa_connector_txn_{connector}_{connector_txn_id}"),
+                        merchant_id,
+                        Some(&format!(
+                            "pa_connector_txn_{}_{connector}_{connector_txn_id}",
+                            merchant_id.get_string_repr()
+                        )),
+                        storage_scheme,
+                    )
+                    .await,
+                    self.router_store
+                        .find_payment_attempt_by_merchant_id_connector_connector_txn_id(
+                            merchant_id,
+                            connector,
+                            connector_txn_id,
+                            storage_scheme,
+                        )
+                        .await
+                );
+
+                let key = PartitionKey::CombinationKey {
+                    combination: &lookup.pk_id,
+                };
+                Box::pin(try_redis_get_else_try_database_get(
+                    async {
+                        kv_wrapper(
+                            self,
+                            KvOperation::<DieselPaymentAttempt>::HGet(&lookup.sk_id),
+                            key,
+                        )
+                        .await?
+                        .try_into_hget()
+                    },
+                    || async {
+                        self.router_store
+                            .find_payment_attempt_by_merchant_id_connector_connector_txn_id(
+                                merchant_id,
+                                connector,
+                                connector_txn_id,
+                                storage_scheme,
+                            )
+                            .await
+                    },
+                ))
+                .await
+            }
+        }
+    }
+
     #[cfg(feature = "v1")]
     #[instrument(skip_all)]
     async fn find_payment_attempt_by_payment_id_merchant_id_attempt_id(
@@ -1083,10 +1360,17 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     .await
             }
             MerchantStorageScheme::RedisKv => {
-                let lookup_id = format!("pa_{}_{attempt_id}", merchant_id.get_string_repr());
                 let lookup = fallback_reverse_lookup_not_found!(
-                    self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
-                        .await,
+                    self.get_namespaced_lookup_by_lookup_id(
+                        &format!("pa_{attempt_id}"),
+                        merchant_id,
+                        Some(&format!(
+                            "pa_{}_{attempt_id}",
+                            merchant_id.get_string_repr()
+                        )),
+                        storage_scheme,
+                    )
+                    .await,
                     self.router_store
                         .find_payment_attempt_by_attempt_id_merchant_id(
                             attempt_id,
@@ -1169,13 +1453,17 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     .await
             }
             MerchantStorageScheme::RedisKv => {
-                let lookup_id = format!(
-                    "pa_preprocessing_{}_{preprocessing_id}",
-                    merchant_id.get_string_repr()
-                );
                 let lookup = fallback_reverse_lookup_not_found!(
-                    self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
-                        .await,
+                    self.get_namespaced_lookup_by_lookup_id(
+                        &format!("pa_preprocessing_{preprocessing_id}"),
+                        merchant_id,
+                        Some(&format!(
+                            "pa_preprocessing_{}_{preprocessing_id}",
+                            merchant_id.get_string_repr()
+                        )),
+                        storage_scheme,
+                    )
+                    .await,
                     self.router_store
                         .find_payment_attempt_by_preprocessing_id_merchant_id(
                             preprocessing_id,
@@ -1304,6 +1592,63 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
             )
             .await
     }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    #[instrument(skip_all)]
+    async fn list_payments_by_tag(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        tag_key: &str,
+        tag_value: &str,
+        filters: &PaymentAttemptTagFilters,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+        self.router_store
+            .list_payments_by_tag(merchant_id, tag_key, tag_value, filters, storage_scheme)
+            .await
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    #[instrument(skip_all)]
+    async fn list_payments_pending_review(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        filters: &PaymentAttemptReviewFilters,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+        self.router_store
+            .list_payments_pending_review(merchant_id, filters, storage_scheme)
+            .await
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    #[instrument(skip_all)]
+    async fn get_distinct_card_hash_count_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        time_range: common_utils::types::TimeRange,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.router_store
+            .get_distinct_card_hash_count_by_merchant_id(merchant_id, time_range, storage_scheme)
+            .await
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    #[instrument(skip_all)]
+    async fn get_settlement_amounts_by_currency(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        settlement_date: time::Date,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<
+        Vec<(common_enums::Currency, common_utils::types::MinorUnit)>,
+        errors::StorageError,
+    > {
+        self.router_store
+            .get_settlement_amounts_by_currency(merchant_id, settlement_date, storage_scheme)
+            .await
+    }
 }
 
 impl DataModelExt for MandateAmountData {
@@ -1316,6 +1661,7 @@ impl DataModelExt for MandateAmountData {
             start_date: self.start_date,
             end_date: self.end_date,
             metadata: self.metadata,
+            constraints: self.constraints.map(Into::into),
         }
     }
 
@@ -1326,6 +1672,7 @@ impl DataModelExt for MandateAmountData {
             start_date: storage_model.start_date,
             end_date: storage_model.end_date,
             metadata: storage_model.metadata,
+            constraints: storage_model.constraints.map(Into::into),
         }
     }
 }
@@ -1446,6 +1793,15 @@ impl DataModelExt for PaymentAttempt {
             profile_id: self.profile_id,
             shipping_cost: self.shipping_cost,
             order_tax_amount: self.order_tax_amount,
+            fraud_score: self.fraud_score,
+            fraud_decision: self.fraud_decision,
+            approved_amount: self.approved_amount,
+            expected_settlement_date: self.expected_settlement_date,
+            card_hash: self.card_hash,
+            retry_advice: self.retry_advice,
+            tags: self.tags,
+            is_connector_override: self.is_connector_override,
+            stepped_up_from_attempt_id: self.stepped_up_from_attempt_id,
         }
     }
 
@@ -1517,6 +1873,15 @@ impl DataModelExt for PaymentAttempt {
             profile_id: storage_model.profile_id,
             shipping_cost: storage_model.shipping_cost,
             order_tax_amount: storage_model.order_tax_amount,
+            fraud_score: storage_model.fraud_score,
+            fraud_decision: storage_model.fraud_decision,
+            approved_amount: storage_model.approved_amount,
+            expected_settlement_date: storage_model.expected_settlement_date,
+            card_hash: storage_model.card_hash,
+            retry_advice: storage_model.retry_advice,
+            tags: storage_model.tags,
+            is_connector_override: storage_model.is_connector_override,
+            stepped_up_from_attempt_id: storage_model.stepped_up_from_attempt_id,
         }
     }
 }
@@ -1599,6 +1964,15 @@ impl DataModelExt for PaymentAttemptNew {
             profile_id: self.profile_id,
             shipping_cost: self.shipping_cost,
             order_tax_amount: self.order_tax_amount,
+            fraud_score: self.fraud_score,
+            fraud_decision: self.fraud_decision,
+            approved_amount: self.approved_amount,
+            expected_settlement_date: self.expected_settlement_date,
+            card_hash: self.card_hash,
+            retry_advice: self.retry_advice,
+            tags: self.tags,
+            is_connector_override: self.is_connector_override,
+            stepped_up_from_attempt_id: self.stepped_up_from_attempt_id,
         }
     }
 
@@ -1669,6 +2043,15 @@ impl DataModelExt for PaymentAttemptNew {
             profile_id: storage_model.profile_id,
             shipping_cost: storage_model.shipping_cost,
             order_tax_amount: storage_model.order_tax_amount,
+            fraud_score: storage_model.fraud_score,
+            fraud_decision: storage_model.fraud_decision,
+            approved_amount: storage_model.approved_amount,
+            expected_settlement_date: storage_model.expected_settlement_date,
+            card_hash: storage_model.card_hash,
+            retry_advice: storage_model.retry_advice,
+            tags: storage_model.tags,
+            is_connector_override: storage_model.is_connector_override,
+            stepped_up_from_attempt_id: storage_model.stepped_up_from_attempt_id,
         }
     }
 }
@@ -1725,6 +2108,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 surcharge_amount,
                 tax_amount,
                 merchant_connector_id,
+                is_connector_override,
             } => DieselPaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
                 connector,
@@ -1734,6 +2118,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 tax_amount,
                 updated_by,
                 merchant_connector_id,
+                is_connector_override,
             },
             Self::AuthenticationTypeUpdate {
                 authentication_type,
@@ -1793,6 +2178,8 @@ impl DataModelExt for PaymentAttemptUpdate {
                 customer_acceptance,
                 shipping_cost,
                 order_tax_amount,
+                card_hash,
+                is_connector_override,
             } => DieselPaymentAttemptUpdate::ConfirmUpdate {
                 amount,
                 currency,
@@ -1826,6 +2213,8 @@ impl DataModelExt for PaymentAttemptUpdate {
                 customer_acceptance,
                 shipping_cost,
                 order_tax_amount,
+                card_hash,
+                is_connector_override,
             },
             Self::VoidUpdate {
                 status,
@@ -1857,6 +2246,8 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_message,
                 payment_method_data,
                 charge_id,
+                approved_amount,
+                expected_settlement_date,
             } => DieselPaymentAttemptUpdate::ResponseUpdate {
                 status,
                 connector,
@@ -1878,6 +2269,8 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_message,
                 payment_method_data,
                 charge_id,
+                approved_amount,
+                expected_settlement_date,
             },
             Self::UnresolvedResponseUpdate {
                 status,
@@ -1916,6 +2309,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 connector_transaction_id,
                 payment_method_data,
                 authentication_type,
+                retry_advice,
             } => DieselPaymentAttemptUpdate::ErrorUpdate {
                 connector,
                 status,
@@ -1929,6 +2323,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 connector_transaction_id,
                 payment_method_data,
                 authentication_type,
+                retry_advice,
             },
             Self::CaptureUpdate {
                 multiple_capture_count,
@@ -2030,6 +2425,15 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_message,
                 connector_transaction_id,
             },
+            Self::FraudCheckUpdate {
+                fraud_score,
+                fraud_decision,
+                updated_by,
+            } => DieselPaymentAttemptUpdate::FraudCheckUpdate {
+                fraud_score,
+                fraud_decision,
+                updated_by,
+            },
         }
     }
 
@@ -2081,6 +2485,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 surcharge_amount,
                 tax_amount,
                 merchant_connector_id: connector_id,
+                is_connector_override,
             } => Self::UpdateTrackers {
                 payment_token,
                 connector,
@@ -2090,6 +2495,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 tax_amount,
                 updated_by,
                 merchant_connector_id: connector_id,
+                is_connector_override,
             },
             DieselPaymentAttemptUpdate::AuthenticationTypeUpdate {
                 authentication_type,
@@ -2131,6 +2537,8 @@ impl DataModelExt for PaymentAttemptUpdate {
                 customer_acceptance,
                 shipping_cost,
                 order_tax_amount,
+                card_hash,
+                is_connector_override,
             } => Self::ConfirmUpdate {
                 amount,
                 currency,
@@ -2164,6 +2572,8 @@ impl DataModelExt for PaymentAttemptUpdate {
                 customer_acceptance,
                 shipping_cost,
                 order_tax_amount,
+                card_hash,
+                is_connector_override,
             },
             DieselPaymentAttemptUpdate::VoidUpdate {
                 status,
@@ -2213,6 +2623,8 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_message,
                 payment_method_data,
                 charge_id,
+                approved_amount,
+                expected_settlement_date,
             } => Self::ResponseUpdate {
                 status,
                 connector,
@@ -2234,6 +2646,8 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_message,
                 payment_method_data,
                 charge_id,
+                approved_amount,
+                expected_settlement_date,
             },
             DieselPaymentAttemptUpdate::UnresolvedResponseUpdate {
                 status,
@@ -2272,6 +2686,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 connector_transaction_id,
                 payment_method_data,
                 authentication_type,
+                retry_advice,
             } => Self::ErrorUpdate {
                 connector,
                 status,
@@ -2285,6 +2700,7 @@ impl DataModelExt for PaymentAttemptUpdate {
                 connector_transaction_id,
                 payment_method_data,
                 authentication_type,
+                retry_advice,
             },
             DieselPaymentAttemptUpdate::CaptureUpdate {
                 amount_to_capture,
@@ -2386,6 +2802,15 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_message,
                 connector_transaction_id,
             },
+            DieselPaymentAttemptUpdate::FraudCheckUpdate {
+                fraud_score,
+                fraud_decision,
+                updated_by,
+            } => Self::FraudCheckUpdate {
+                fraud_score,
+                fraud_decision,
+                updated_by,
+            },
         }
     }
 }
@@ -2402,18 +2827,38 @@ async fn add_connector_txn_id_to_reverse_lookup<T: DatabaseStore>(
 ) -> CustomResult<ReverseLookup, errors::StorageError> {
     let field = format!("pa_{}", updated_attempt_attempt_id);
     let reverse_lookup_new = ReverseLookupNew {
-        lookup_id: format!(
-            "pa_conn_trans_{}_{}",
-            merchant_id.get_string_repr(),
-            connector_transaction_id
-        ),
+        lookup_id: format!("pa_conn_trans_{connector_transaction_id}"),
+        pk_id: key.to_owned(),
+        sk_id: field.clone(),
+        source: "payment_attempt".to_string(),
+        updated_by: storage_scheme.to_string(),
+    };
+    store
+        .insert_namespaced_reverse_lookup(reverse_lookup_new, merchant_id, storage_scheme)
+        .await
+}
+
+#[inline]
+#[instrument(skip_all)]
+async fn add_connector_and_connector_txn_id_to_reverse_lookup<T: DatabaseStore>(
+    store: &KVRouterStore<T>,
+    key: &str,
+    merchant_id: &common_utils::id_type::MerchantId,
+    updated_attempt_attempt_id: &str,
+    connector: &str,
+    connector_transaction_id: &str,
+    storage_scheme: MerchantStorageScheme,
+) -> CustomResult<ReverseLookup, errors::StorageError> {
+    let field = format!("pa_{}", updated_attempt_attempt_id);
+    let reverse_lookup_new = ReverseLookupNew {
+        lookup_id: format!("pa_connector_txn_{connector}_{connector_transaction_id}"),
         pk_id: key.to_owned(),
         sk_id: field.clone(),
         source: "payment_attempt".to_string(),
         updated_by: storage_scheme.to_string(),
     };
     store
-        .insert_reverse_lookup(reverse_lookup_new, storage_scheme)
+        .insert_namespaced_reverse_lookup(reverse_lookup_new, merchant_id, storage_scheme)
         .await
 }
 
@@ -2429,17 +2874,13 @@ async fn add_preprocessing_id_to_reverse_lookup<T: DatabaseStore>(
 ) -> CustomResult<ReverseLookup, errors::StorageError> {
     let field = format!("pa_{}", updated_attempt_attempt_id);
     let reverse_lookup_new = ReverseLookupNew {
-        lookup_id: format!(
-            "pa_preprocessing_{}_{}",
-            merchant_id.get_string_repr(),
-            preprocessing_id
-        ),
+        lookup_id: format!("pa_preprocessing_{preprocessing_id}"),
         pk_id: key.to_owned(),
         sk_id: field.clone(),
         source: "payment_attempt".to_string(),
         updated_by: storage_scheme.to_string(),
     };
     store
-        .insert_reverse_lookup(reverse_lookup_new, storage_scheme)
+        .insert_namespaced_reverse_lookup(reverse_lookup_new, merchant_id, storage_scheme)
         .await
 }
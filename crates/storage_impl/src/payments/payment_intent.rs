@@ -30,6 +30,10 @@ use diesel_models::{
     },
 };
 use error_stack::ResultExt;
+#[cfg(all(feature = "v1", feature = "olap"))]
+use hyperswitch_domain_models::payments::payment_intent::{
+    MerchantVolumeMetric, PaymentIntentPagination, PaymentMetadataKey,
+};
 #[cfg(feature = "olap")]
 use hyperswitch_domain_models::payments::{
     payment_attempt::PaymentAttempt, payment_intent::PaymentIntentFetchConstraints,
@@ -43,13 +47,17 @@ use hyperswitch_domain_models::{
         PaymentIntent,
     },
 };
+#[cfg(all(feature = "v1", feature = "olap"))]
+use redis_interface::errors::RedisError;
 use redis_interface::HsetnxReply;
-#[cfg(feature = "olap")]
+#[cfg(any(feature = "v1", feature = "olap"))]
 use router_env::logger;
 use router_env::{instrument, tracing};
 
 #[cfg(feature = "olap")]
 use crate::connection;
+#[cfg(feature = "v1")]
+use crate::redis::kv_store::RedisConnInterface;
 use crate::{
     diesel_error_to_data_error,
     errors::RedisErrorExt,
@@ -58,6 +66,57 @@ use crate::{
     DatabaseStore, KVRouterStore,
 };
 
+/// Time in seconds for which the merchant volume metrics are cached
+#[cfg(all(feature = "v1", feature = "olap"))]
+const MERCHANT_VOLUME_METRICS_CACHE_TTL: i64 = 60;
+
+/// The key of the list of the payments which were added in redis to the chain of related payments
+/// which starts at the root payment. The payments are listed only until they are drained to
+/// postgres, where the chain is found by the root payment of the payments.
+#[cfg(feature = "v1")]
+fn get_related_payments_key(
+    merchant_id: &common_utils::id_type::MerchantId,
+    root_payment_id: &common_utils::id_type::PaymentId,
+) -> String {
+    format!(
+        "related_payments_{}_{}",
+        merchant_id.get_string_repr(),
+        root_payment_id.get_string_repr()
+    )
+}
+
+#[cfg(feature = "v1")]
+impl<T: DatabaseStore> KVRouterStore<T> {
+    /// Add the payment to the list of the payments of the chain of related payments in redis, which
+    /// lasts as long as the payment is kept in redis. A payment which is not added to the list is
+    /// found in the chain once it is drained to postgres.
+    async fn add_to_related_payments(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        root_payment_id: &common_utils::id_type::PaymentId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) {
+        let key = get_related_payments_key(merchant_id, root_payment_id);
+        let result = async {
+            let redis_conn = self.get_redis_conn()?;
+            redis_conn
+                .append_elements_to_list(&key, payment_id.get_string_repr())
+                .await?;
+            redis_conn
+                .set_expiry(&key, i64::from(self.ttl_for_kv))
+                .await
+        }
+        .await;
+
+        if let Err(error) = result {
+            logger::error!(
+                ?error,
+                "Failed to add the payment to the related payments in redis"
+            );
+        }
+    }
+}
+
 #[async_trait::async_trait]
 impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
     #[cfg(feature = "v1")]
@@ -131,7 +190,17 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
                         key: Some(key_str),
                     }
                     .into()),
-                    Ok(HsetnxReply::KeySet) => Ok(payment_intent),
+                    Ok(HsetnxReply::KeySet) => {
+                        if let Some(root_payment_id) = payment_intent.root_payment_id.as_ref() {
+                            self.add_to_related_payments(
+                                &merchant_id,
+                                root_payment_id,
+                                &payment_id,
+                            )
+                            .await;
+                        }
+                        Ok(payment_intent)
+                    }
                     Err(error) => Err(error.change_context(StorageError::KVError)),
                 }
             }
@@ -392,6 +461,110 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
             .await
     }
 
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    #[instrument(skip_all)]
+    async fn find_payment_intents_by_root_payment_id_merchant_id(
+        &self,
+        state: &KeyManagerState,
+        root_payment_id: &common_utils::id_type::PaymentId,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_key_store: &MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
+        limit: i64,
+    ) -> error_stack::Result<Vec<PaymentIntent>, StorageError> {
+        use futures::future::try_join_all;
+
+        let payment_intents = self
+            .router_store
+            .find_payment_intents_by_root_payment_id_merchant_id(
+                state,
+                root_payment_id,
+                merchant_id,
+                merchant_key_store,
+                storage_scheme,
+                limit,
+            )
+            .await?;
+
+        let storage_scheme = Box::pin(decide_storage_scheme::<_, DieselPaymentIntent>(
+            self,
+            storage_scheme,
+            Op::Find,
+        ))
+        .await;
+        match storage_scheme {
+            MerchantStorageScheme::PostgresOnly => Ok(payment_intents),
+
+            // The payments which are not drained to postgres yet are found in the list of the
+            // payments added to the chain in redis, and every payment is read through redis so that
+            // its latest update is returned
+            MerchantStorageScheme::RedisKv => {
+                let key = get_related_payments_key(merchant_id, root_payment_id);
+                let redis_payment_ids = self
+                    .get_redis_conn()
+                    .map_err(|err| err.to_redis_failed_response(&key))?
+                    .get_list_elements(&key, 0, limit.saturating_sub(1))
+                    .await
+                    .map_err(|err| err.to_redis_failed_response(&key))?;
+
+                let mut payment_ids = vec![root_payment_id.to_owned()];
+                let chain_payment_ids = payment_intents
+                    .iter()
+                    .map(|payment_intent| payment_intent.get_id().to_owned())
+                    .chain(redis_payment_ids.into_iter().filter_map(|payment_id| {
+                        common_utils::id_type::PaymentId::try_from(std::borrow::Cow::Owned(
+                            payment_id,
+                        ))
+                        .ok()
+                    }));
+                for payment_id in chain_payment_ids {
+                    if !payment_ids.contains(&payment_id) {
+                        payment_ids.push(payment_id);
+                    }
+                }
+                payment_ids.truncate(usize::try_from(limit).unwrap_or(0));
+
+                let mut payment_intents = try_join_all(payment_ids.iter().map(|payment_id| {
+                    self.find_payment_intent_by_payment_id_merchant_id(
+                        state,
+                        payment_id,
+                        merchant_id,
+                        merchant_key_store,
+                        storage_scheme,
+                    )
+                }))
+                .await?;
+                payment_intents.sort_by_key(|payment_intent| payment_intent.created_at);
+
+                Ok(payment_intents)
+            }
+        }
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn find_payments_by_metadata(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        key: &PaymentMetadataKey,
+        value: &str,
+        pagination: PaymentIntentPagination,
+        merchant_key_store: &MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PaymentIntent>, StorageError> {
+        self.router_store
+            .find_payments_by_metadata(
+                state,
+                merchant_id,
+                key,
+                value,
+                pagination,
+                merchant_key_store,
+                storage_scheme,
+            )
+            .await
+    }
+
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn filter_payment_intents_by_time_range_constraints(
         &self,
@@ -424,6 +597,62 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
             .await
     }
 
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    #[instrument(skip_all)]
+    async fn get_merchant_volume_metrics(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        time_range: &common_utils::types::TimeRange,
+    ) -> error_stack::Result<Vec<MerchantVolumeMetric>, StorageError> {
+        let redis_conn = self
+            .get_redis_conn()
+            .map_err(|err| logger::error!(?err, "Failed to get redis connection"))
+            .ok();
+        let key = format!(
+            "merchant_volume_metrics_{}_{}_{}",
+            merchant_id.get_string_repr(),
+            time_range.start_time.assume_utc().unix_timestamp(),
+            time_range
+                .end_time
+                .map(|end_time| end_time.assume_utc().unix_timestamp().to_string())
+                .unwrap_or_default()
+        );
+
+        if let Some(redis_conn) = redis_conn.as_ref() {
+            match redis_conn
+                .get_and_deserialize_key::<Vec<MerchantVolumeMetric>>(&key, "MerchantVolumeMetric")
+                .await
+            {
+                Ok(metrics) => return Ok(metrics),
+                Err(err)
+                    if matches!(
+                        err.current_context(),
+                        RedisError::NotFound | RedisError::JsonDeserializationFailed
+                    ) => {}
+                Err(err) => logger::error!(?err, "Failed to read cached merchant volume metrics"),
+            }
+        }
+
+        let metrics = self
+            .router_store
+            .get_merchant_volume_metrics(merchant_id, time_range)
+            .await?;
+
+        if let Some(redis_conn) = redis_conn {
+            redis_conn
+                .serialize_and_set_key_with_expiry(
+                    &key,
+                    &metrics,
+                    MERCHANT_VOLUME_METRICS_CACHE_TTL,
+                )
+                .await
+                .map_err(|err| logger::error!(?err, "Failed to cache merchant volume metrics"))
+                .ok();
+        }
+
+        Ok(metrics)
+    }
+
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn get_filtered_payment_intents_attempt(
         &self,
@@ -712,6 +941,95 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         .await
     }
 
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    #[instrument(skip_all)]
+    async fn find_payment_intents_by_root_payment_id_merchant_id(
+        &self,
+        state: &KeyManagerState,
+        root_payment_id: &common_utils::id_type::PaymentId,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_key_store: &MerchantKeyStore,
+        _storage_scheme: MerchantStorageScheme,
+        limit: i64,
+    ) -> error_stack::Result<Vec<PaymentIntent>, StorageError> {
+        use futures::future::try_join_all;
+
+        let conn = pg_connection_read(self).await?;
+
+        let diesel_payment_intents = DieselPaymentIntent::find_by_root_payment_id_merchant_id(
+            &conn,
+            root_payment_id,
+            merchant_id,
+            limit,
+        )
+        .await
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })?;
+
+        try_join_all(
+            diesel_payment_intents
+                .into_iter()
+                .map(|diesel_payment_intent| {
+                    PaymentIntent::convert_back(
+                        state,
+                        diesel_payment_intent,
+                        merchant_key_store.key.get_inner(),
+                        merchant_key_store.merchant_id.clone().into(),
+                    )
+                }),
+        )
+        .await
+        .change_context(StorageError::DecryptionError)
+    }
+
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    #[instrument(skip_all)]
+    async fn find_payments_by_metadata(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        key: &PaymentMetadataKey,
+        value: &str,
+        pagination: PaymentIntentPagination,
+        merchant_key_store: &MerchantKeyStore,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PaymentIntent>, StorageError> {
+        use futures::future::try_join_all;
+
+        let conn = pg_connection_read(self).await?;
+
+        let diesel_payment_intents = DieselPaymentIntent::find_by_merchant_id_metadata_value(
+            &conn,
+            merchant_id,
+            key,
+            value,
+            pagination.limit.into(),
+            pagination.offset.into(),
+        )
+        .await
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })?;
+
+        try_join_all(
+            diesel_payment_intents
+                .into_iter()
+                .map(|diesel_payment_intent| {
+                    PaymentIntent::convert_back(
+                        state,
+                        diesel_payment_intent,
+                        merchant_key_store.key.get_inner(),
+                        merchant_key_store.merchant_id.clone().into(),
+                    )
+                }),
+        )
+        .await
+        .change_context(StorageError::DecryptionError)
+    }
+
     #[cfg(all(feature = "v1", feature = "olap"))]
     #[instrument(skip_all)]
     async fn filter_payment_intents_by_time_range_constraints(
@@ -778,6 +1096,68 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         })
     }
 
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    #[instrument(skip_all)]
+    async fn get_merchant_volume_metrics(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        time_range: &common_utils::types::TimeRange,
+    ) -> error_stack::Result<Vec<MerchantVolumeMetric>, StorageError> {
+        let conn = connection::pg_connection_read(self).await.switch()?;
+        let conn = async_bb8_diesel::Connection::as_async_conn(&conn);
+
+        // Amounts are summed per currency, summing them across currencies would be meaningless
+        let mut query = <DieselPaymentIntent as HasTable>::table()
+            .group_by((pi_dsl::status, pi_dsl::currency))
+            .select((
+                pi_dsl::status,
+                pi_dsl::currency,
+                diesel::dsl::count_star(),
+                diesel::dsl::sql::<diesel::sql_types::BigInt>("COALESCE(SUM(amount), 0)::BIGINT"),
+            ))
+            .filter(pi_dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(pi_dsl::created_at.ge(time_range.start_time))
+            .into_boxed();
+
+        query = match time_range.end_time {
+            Some(ending_at) => query.filter(pi_dsl::created_at.le(ending_at)),
+            None => query,
+        };
+
+        logger::debug!(filter = %diesel::debug_query::<diesel::pg::Pg,_>(&query).to_string());
+
+        db_metrics::track_database_call::<<DieselPaymentIntent as HasTable>::Table, _, _>(
+            query.get_results_async::<(
+                common_enums::IntentStatus,
+                Option<common_enums::Currency>,
+                i64,
+                i64,
+            )>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .map(|metrics| {
+            metrics
+                .into_iter()
+                .map(
+                    |(status, currency, count, total_amount)| MerchantVolumeMetric {
+                        status,
+                        currency,
+                        count,
+                        total_amount: common_utils::types::MinorUnit::new(total_amount),
+                    },
+                )
+                .collect()
+        })
+        .map_err(|er| {
+            StorageError::DatabaseError(
+                error_stack::report!(diesel_models::errors::DatabaseError::from(er))
+                    .attach_printable("Error fetching merchant volume metrics"),
+            )
+            .into()
+        })
+    }
+
     #[cfg(all(feature = "v1", feature = "olap"))]
     #[instrument(skip_all)]
     async fn get_filtered_payment_intents_attempt(
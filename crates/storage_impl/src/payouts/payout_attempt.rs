@@ -3,7 +3,7 @@ use std::str::FromStr;
 use api_models::enums::PayoutConnectors;
 use common_utils::{errors::CustomResult, ext_traits::Encode, fallback_reverse_lookup_not_found};
 use diesel_models::{
-    enums::MerchantStorageScheme,
+    enums::{MerchantStorageScheme, PayoutStatus},
     kv,
     payout_attempt::{
         PayoutAttempt as DieselPayoutAttempt, PayoutAttemptNew as DieselPayoutAttemptNew,
@@ -18,9 +18,10 @@ use hyperswitch_domain_models::{
     payouts::{
         payout_attempt::{
             PayoutAttempt, PayoutAttemptInterface, PayoutAttemptNew, PayoutAttemptUpdate,
-            PayoutListFilters,
+            PayoutListFilters, PayoutReturn, PayoutReturnOutcome, PayoutReturnResult,
+            ReturnedPayout,
         },
-        payouts::Payouts,
+        payouts::{Payouts, PayoutsInterface, PayoutsUpdate},
     },
 };
 use redis_interface::HsetnxReply;
@@ -102,18 +103,18 @@ impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
                 // Reverse lookup for payout_attempt_id
                 let field = format!("poa_{}", created_attempt.payout_attempt_id);
                 let reverse_lookup = ReverseLookupNew {
-                    lookup_id: format!(
-                        "poa_{}_{}",
-                        &created_attempt.merchant_id.get_string_repr(),
-                        &created_attempt.payout_attempt_id,
-                    ),
+                    lookup_id: format!("poa_{}", &created_attempt.payout_attempt_id),
                     pk_id: key_str.clone(),
                     sk_id: field.clone(),
                     source: "payout_attempt".to_string(),
                     updated_by: storage_scheme.to_string(),
                 };
-                self.insert_reverse_lookup(reverse_lookup, storage_scheme)
-                    .await?;
+                self.insert_namespaced_reverse_lookup(
+                    reverse_lookup,
+                    &created_attempt.merchant_id,
+                    storage_scheme,
+                )
+                .await?;
 
                 match kv_wrapper::<DieselPayoutAttempt, _, _>(
                     self,
@@ -266,11 +267,17 @@ impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
                     .await
             }
             MerchantStorageScheme::RedisKv => {
-                let lookup_id =
-                    format!("poa_{}_{payout_attempt_id}", merchant_id.get_string_repr());
                 let lookup = fallback_reverse_lookup_not_found!(
-                    self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
-                        .await,
+                    self.get_namespaced_lookup_by_lookup_id(
+                        &format!("poa_{payout_attempt_id}"),
+                        merchant_id,
+                        Some(&format!(
+                            "poa_{}_{payout_attempt_id}",
+                            merchant_id.get_string_repr()
+                        )),
+                        storage_scheme,
+                    )
+                    .await,
                     self.router_store
                         .find_payout_attempt_by_merchant_id_payout_attempt_id(
                             merchant_id,
@@ -325,13 +332,17 @@ impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
                     .await
             }
             MerchantStorageScheme::RedisKv => {
-                let lookup_id = format!(
-                    "po_conn_payout_{}_{connector_payout_id}",
-                    merchant_id.get_string_repr()
-                );
                 let lookup = fallback_reverse_lookup_not_found!(
-                    self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
-                        .await,
+                    self.get_namespaced_lookup_by_lookup_id(
+                        &format!("po_conn_payout_{connector_payout_id}"),
+                        merchant_id,
+                        Some(&format!(
+                            "po_conn_payout_{}_{connector_payout_id}",
+                            merchant_id.get_string_repr()
+                        )),
+                        storage_scheme,
+                    )
+                    .await,
                     self.router_store
                         .find_payout_attempt_by_merchant_id_connector_payout_id(
                             merchant_id,
@@ -379,6 +390,16 @@ impl<T: DatabaseStore> PayoutAttemptInterface for KVRouterStore<T> {
             .get_filters_for_payouts(payouts, merchant_id, storage_scheme)
             .await
     }
+
+    #[instrument(skip_all)]
+    async fn apply_payout_returns(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payout_returns: Vec<PayoutReturn>,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PayoutReturnResult>, errors::StorageError> {
+        Ok(apply_each_payout_return(self, merchant_id, payout_returns, storage_scheme).await)
+    }
 }
 
 #[async_trait::async_trait]
@@ -504,6 +525,16 @@ impl<T: DatabaseStore> PayoutAttemptInterface for crate::RouterStore<T> {
                 },
             )
     }
+
+    #[instrument(skip_all)]
+    async fn apply_payout_returns(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payout_returns: Vec<PayoutReturn>,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PayoutReturnResult>, errors::StorageError> {
+        Ok(apply_each_payout_return(self, merchant_id, payout_returns, storage_scheme).await)
+    }
 }
 
 impl DataModelExt for PayoutAttempt {
@@ -690,17 +721,142 @@ async fn add_connector_payout_id_to_reverse_lookup<T: DatabaseStore>(
 ) -> CustomResult<ReverseLookup, errors::StorageError> {
     let field = format!("poa_{}", updated_attempt_attempt_id);
     let reverse_lookup_new = ReverseLookupNew {
-        lookup_id: format!(
-            "po_conn_payout_{}_{}",
-            merchant_id.get_string_repr(),
-            connector_payout_id
-        ),
+        lookup_id: format!("po_conn_payout_{connector_payout_id}"),
         pk_id: key.to_owned(),
         sk_id: field.clone(),
         source: "payout_attempt".to_string(),
         updated_by: storage_scheme.to_string(),
     };
     store
-        .insert_reverse_lookup(reverse_lookup_new, storage_scheme)
+        .insert_namespaced_reverse_lookup(reverse_lookup_new, merchant_id, storage_scheme)
         .await
 }
+
+/// Apply each of the returns of payouts reported by the bank, a return which fails to be applied
+/// does not prevent the other returns from being applied.
+async fn apply_each_payout_return<S>(
+    store: &S,
+    merchant_id: &common_utils::id_type::MerchantId,
+    payout_returns: Vec<PayoutReturn>,
+    storage_scheme: MerchantStorageScheme,
+) -> Vec<PayoutReturnResult>
+where
+    S: PayoutAttemptInterface + PayoutsInterface + Sync,
+{
+    let mut results = Vec::with_capacity(payout_returns.len());
+    for payout_return in payout_returns {
+        let bank_reference = payout_return.bank_reference.clone();
+        let result = apply_payout_return(store, merchant_id, payout_return, storage_scheme)
+            .await
+            .unwrap_or_else(|error| {
+                logger::error!(?error, %bank_reference, "Failed to apply the payout return");
+                PayoutReturnResult {
+                    bank_reference,
+                    outcome: PayoutReturnOutcome::Failed(error.current_context().to_string()),
+                }
+            });
+        results.push(result);
+    }
+    results
+}
+
+/// Apply the return of a payout reported by the bank.
+///
+/// Only the succeeded payouts of the connector of the return can be returned, applying the return
+/// of a payout which was already returned has no effect. The payout is updated before the payout
+/// attempt, so that a return which was applied partially is completed when it is applied again.
+#[instrument(skip_all)]
+async fn apply_payout_return<S>(
+    store: &S,
+    merchant_id: &common_utils::id_type::MerchantId,
+    payout_return: PayoutReturn,
+    storage_scheme: MerchantStorageScheme,
+) -> CustomResult<PayoutReturnResult, errors::StorageError>
+where
+    S: PayoutAttemptInterface + PayoutsInterface + Sync,
+{
+    let payout_attempt = match store
+        .find_payout_attempt_by_merchant_id_connector_payout_id(
+            merchant_id,
+            &payout_return.bank_reference,
+            storage_scheme,
+        )
+        .await
+    {
+        // The bank reference of a payout of another connector may be the same
+        Ok(payout_attempt)
+            if payout_attempt.connector.as_deref() == Some(payout_return.connector.as_str()) =>
+        {
+            payout_attempt
+        }
+        Ok(_) => {
+            return Ok(PayoutReturnResult {
+                bank_reference: payout_return.bank_reference,
+                outcome: PayoutReturnOutcome::NotFound,
+            })
+        }
+        Err(error) => match error.current_context() {
+            errors::StorageError::ValueNotFound(_) => {
+                return Ok(PayoutReturnResult {
+                    bank_reference: payout_return.bank_reference,
+                    outcome: PayoutReturnOutcome::NotFound,
+                })
+            }
+            _ => return Err(error),
+        },
+    };
+
+    let outcome = match payout_attempt.status {
+        PayoutStatus::Success => {
+            let payouts = store
+                .find_payout_by_merchant_id_payout_id(
+                    merchant_id,
+                    &payout_attempt.payout_id,
+                    storage_scheme,
+                )
+                .await?;
+
+            let updated_payouts = store
+                .update_payout(
+                    &payouts,
+                    PayoutsUpdate::StatusUpdate {
+                        status: PayoutStatus::Returned,
+                    },
+                    &payout_attempt,
+                    storage_scheme,
+                )
+                .await?;
+
+            let updated_payout_attempt = store
+                .update_payout_attempt(
+                    &payout_attempt,
+                    PayoutAttemptUpdate::StatusUpdate {
+                        connector_payout_id: payout_attempt.connector_payout_id.clone(),
+                        status: PayoutStatus::Returned,
+                        error_message: payout_return.return_reason_message,
+                        error_code: payout_return.return_reason_code,
+                        is_eligible: payout_attempt.is_eligible,
+                        unified_code: None,
+                        unified_message: None,
+                    },
+                    &updated_payouts,
+                    storage_scheme,
+                )
+                .await?;
+
+            PayoutReturnOutcome::Returned(Box::new(ReturnedPayout {
+                payouts: updated_payouts,
+                payout_attempt: updated_payout_attempt,
+                previous_payouts: payouts,
+                previous_payout_attempt: payout_attempt,
+            }))
+        }
+        PayoutStatus::Returned => PayoutReturnOutcome::AlreadyReturned(Box::new(payout_attempt)),
+        _ => PayoutReturnOutcome::NotReturnable(Box::new(payout_attempt)),
+    };
+
+    Ok(PayoutReturnResult {
+        bank_reference: payout_return.bank_reference,
+        outcome,
+    })
+}
@@ -1,6 +1,6 @@
 use std::{fmt::Debug, sync::Arc};
 
-use common_utils::errors::CustomResult;
+use common_utils::{errors::CustomResult, versioned::VersionedValue};
 use diesel_models::enums::MerchantStorageScheme;
 use error_stack::report;
 use redis_interface::errors::RedisError;
@@ -222,9 +222,14 @@ where
                     .await?;
 
                 if matches!(result, redis_interface::HsetnxReply::KeySet) {
-                    store
-                        .push_to_drainer_stream::<S>(sql, partition_key)
-                        .await?;
+                    if let Err(error) = store.push_to_drainer_stream::<S>(sql, partition_key).await
+                    {
+                        // Without a drainer entry the field would never be persisted to the
+                        // database, remove it so that the operation fails without leaving orphaned
+                        // state. The other fields of the hash belong to other records.
+                        rollback_set_hash_field(&redis_conn, &key, field, &operation).await;
+                        return Err(error);
+                    }
                     Ok(KvResult::HSetNx(result))
                 } else {
                     Err(report!(RedisError::SetNxFailed))
@@ -241,9 +246,13 @@ where
                 value.check_for_constraints(&redis_conn).await?;
 
                 if matches!(result, redis_interface::SetnxReply::KeySet) {
-                    store
-                        .push_to_drainer_stream::<S>(sql, partition_key)
-                        .await?;
+                    if let Err(error) = store.push_to_drainer_stream::<S>(sql, partition_key).await
+                    {
+                        // Without a drainer entry the key would never be persisted to the database,
+                        // remove it so that the operation fails without leaving orphaned state
+                        rollback_set_key(&redis_conn, &key, &operation).await;
+                        return Err(error);
+                    }
                     Ok(KvResult::SetNx(result))
                 } else {
                     Err(report!(RedisError::SetNxFailed))
@@ -273,10 +282,79 @@ where
         })
 }
 
+async fn rollback_set_key(
+    redis_conn: &redis_interface::RedisConnectionPool,
+    key: &str,
+    operation: &str,
+) {
+    let keyvalue = router_env::opentelemetry::KeyValue::new("operation", operation.to_owned());
+    metrics::KV_ROLLED_BACK_AFTER_DRAINER_PUSH_FAILURE.add(&metrics::CONTEXT, 1, &[keyvalue]);
+
+    if let Err(error) = redis_conn.delete_key(key).await {
+        logger::error!(
+            ?error,
+            kv_operation = %operation,
+            "Failed to roll back key after drainer push failure"
+        );
+    }
+}
+
+async fn rollback_set_hash_field(
+    redis_conn: &redis_interface::RedisConnectionPool,
+    key: &str,
+    field: &str,
+    operation: &str,
+) {
+    let keyvalue = router_env::opentelemetry::KeyValue::new("operation", operation.to_owned());
+    metrics::KV_ROLLED_BACK_AFTER_DRAINER_PUSH_FAILURE.add(&metrics::CONTEXT, 1, &[keyvalue]);
+
+    if let Err(error) = redis_conn.delete_hash_field(key, field).await {
+        logger::error!(
+            ?error,
+            kv_operation = %operation,
+            "Failed to roll back hash field after drainer push failure"
+        );
+    }
+}
+
+/// Get a value whose redis representation is versioned, values written with an older version
+/// are upgraded to the current shape and migrated in redis on access.
+pub async fn kv_get_versioned<'a, T, D>(
+    store: &KVRouterStore<D>,
+    partition_key: PartitionKey<'a>,
+) -> CustomResult<T, RedisError>
+where
+    T: VersionedValue + serde::Serialize + Debug,
+    D: crate::database::store::DatabaseStore,
+{
+    let redis_conn = store.get_redis_conn()?;
+
+    let key = format!("{}", partition_key);
+    let type_name = std::any::type_name::<T>();
+    let operation = "GetVersioned";
+
+    redis_conn
+        .get_and_deserialize_versioned_key(&key, type_name)
+        .await
+        .inspect(|_| {
+            logger::debug!(kv_operation= %operation, status="success");
+            let keyvalue = router_env::opentelemetry::KeyValue::new("operation", operation);
+
+            metrics::KV_OPERATION_SUCCESSFUL.add(&metrics::CONTEXT, 1, &[keyvalue]);
+        })
+        .inspect_err(|err| {
+            logger::error!(kv_operation = %operation, status="error", error = ?err);
+            let keyvalue = router_env::opentelemetry::KeyValue::new("operation", operation);
+
+            metrics::KV_OPERATION_FAILED.add(&metrics::CONTEXT, 1, &[keyvalue]);
+        })
+}
+
 pub enum Op<'a> {
     Insert,
     Update(PartitionKey<'a>, &'a str, Option<&'a str>),
     Find,
+    Delete,
 }
 
 impl<'a> std::fmt::Display for Op<'a> {
@@ -284,6 +362,7 @@ impl<'a> std::fmt::Display for Op<'a> {
         match self {
             Op::Insert => f.write_str("insert"),
             Op::Find => f.write_str("find"),
+            Op::Delete => f.write_str("delete"),
             Op::Update(p_key, _, updated_by) => {
                 f.write_str(&format!("update_{} for updated_by_{:?}", p_key, updated_by))
             }
@@ -310,6 +389,9 @@ where
         let updated_scheme = match operation {
             Op::Insert => MerchantStorageScheme::PostgresOnly,
             Op::Find => MerchantStorageScheme::RedisKv,
+            // Entries could still be present in redis, which need to be removed along with the
+            // database records
+            Op::Delete => MerchantStorageScheme::RedisKv,
             Op::Update(_, _, Some("postgres_only")) => MerchantStorageScheme::PostgresOnly,
             Op::Update(partition_key, field, Some(_updated_by)) => {
                 match kv_wrapper::<D, _, _>(store, KvOperation::<D>::HGet(field), partition_key)
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS fraud_score INTEGER DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS fraud_decision VARCHAR(32) DEFAULT NULL;
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS merchant_onboarding_audit_log;
+
+DROP TRIGGER IF EXISTS merchant_account_onboarding_status_transition ON merchant_account;
+
+DROP FUNCTION IF EXISTS validate_merchant_onboarding_status_transition;
+
+ALTER TABLE merchant_account DROP COLUMN IF EXISTS onboarding_status;
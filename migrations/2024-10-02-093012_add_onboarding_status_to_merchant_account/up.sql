@@ -0,0 +1,34 @@
+-- Your SQL goes here
+-- Merchant accounts which existed before onboarding was tracked are considered to be live
+ALTER TABLE merchant_account
+ADD COLUMN IF NOT EXISTS onboarding_status VARCHAR(32) NOT NULL DEFAULT 'live'
+CHECK (onboarding_status IN ('kyc_pending', 'docs_submitted', 'approved', 'live'));
+
+CREATE OR REPLACE FUNCTION validate_merchant_onboarding_status_transition() RETURNS TRIGGER AS $$
+BEGIN
+    IF NEW.onboarding_status IS DISTINCT FROM OLD.onboarding_status AND NOT (
+        (OLD.onboarding_status = 'kyc_pending' AND NEW.onboarding_status = 'docs_submitted')
+        OR (OLD.onboarding_status = 'docs_submitted' AND NEW.onboarding_status IN ('kyc_pending', 'approved'))
+        OR (OLD.onboarding_status = 'approved' AND NEW.onboarding_status = 'live')
+    ) THEN
+        RAISE EXCEPTION 'Invalid merchant onboarding status transition from % to %', OLD.onboarding_status, NEW.onboarding_status;
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER merchant_account_onboarding_status_transition
+BEFORE UPDATE OF onboarding_status ON merchant_account
+FOR EACH ROW EXECUTE FUNCTION validate_merchant_onboarding_status_transition();
+
+CREATE TABLE IF NOT EXISTS merchant_onboarding_audit_log (
+    id SERIAL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    previous_status VARCHAR(32) NOT NULL,
+    new_status VARCHAR(32) NOT NULL,
+    changed_by VARCHAR(255) NOT NULL,
+    reason VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS merchant_onboarding_audit_log_merchant_id_index ON merchant_onboarding_audit_log (merchant_id);
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS reverse_lookup_pk_id_index ON reverse_lookup (pk_id);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS customer_connector_sync_merchant_id_sync_status_index;
+
+DROP TABLE IF EXISTS customer_connector_sync;
@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS customer_connector_sync (
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    connector_label VARCHAR(255) NOT NULL,
+    connector_name VARCHAR(64) NOT NULL,
+    sync_status VARCHAR(32) NOT NULL,
+    error_message VARCHAR(1024),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (merchant_id, customer_id, connector_label)
+);
+
+CREATE INDEX IF NOT EXISTS customer_connector_sync_merchant_id_sync_status_index ON customer_connector_sync (merchant_id, sync_status);
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE mandate
+ADD COLUMN IF NOT EXISTS constraints JSONB,
+ADD COLUMN IF NOT EXISTS last_charged_at TIMESTAMP;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS settlement_reconciliation_merchant_id_reconciliation_status_index;
+
+DROP TABLE IF EXISTS settlement_reconciliation;
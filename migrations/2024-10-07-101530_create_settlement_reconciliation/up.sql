@@ -0,0 +1,21 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS settlement_reconciliation (
+    merchant_id VARCHAR(64) NOT NULL,
+    connector_name VARCHAR(64) NOT NULL,
+    settlement_id VARCHAR(128) NOT NULL,
+    line_item_id VARCHAR(128) NOT NULL,
+    transaction_type VARCHAR(32) NOT NULL,
+    connector_transaction_id VARCHAR(128) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    settled_at TIMESTAMP,
+    payment_id VARCHAR(64),
+    attempt_id VARCHAR(64),
+    refund_id VARCHAR(64),
+    reconciliation_status VARCHAR(32) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (merchant_id, connector_name, settlement_id, line_item_id)
+);
+
+CREATE INDEX IF NOT EXISTS settlement_reconciliation_merchant_id_reconciliation_status_index ON settlement_reconciliation (merchant_id, reconciliation_status);
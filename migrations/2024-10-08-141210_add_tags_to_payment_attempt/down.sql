@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_attempt_tags_index;
+
+ALTER TABLE payment_attempt DROP COLUMN IF EXISTS tags;
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS tags JSONB DEFAULT NULL;
+
+CREATE INDEX IF NOT EXISTS payment_attempt_tags_index ON payment_attempt USING GIN (tags);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS connector_token_revocation_revocation_status_index;
+
+DROP TABLE IF EXISTS connector_token_revocation;
@@ -0,0 +1,16 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS connector_token_revocation (
+    merchant_id VARCHAR(64) NOT NULL,
+    payment_method_id VARCHAR(64) NOT NULL,
+    merchant_connector_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    connector_name VARCHAR(64) NOT NULL,
+    connector_token VARCHAR(255) NOT NULL,
+    revocation_status VARCHAR(32) NOT NULL,
+    error_message VARCHAR(1024),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (merchant_id, payment_method_id, merchant_connector_id)
+);
+
+CREATE INDEX IF NOT EXISTS connector_token_revocation_revocation_status_index ON connector_token_revocation (revocation_status, created_at);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS webhook_event_sequence;
+
+ALTER TABLE events DROP COLUMN IF EXISTS sequence_number;
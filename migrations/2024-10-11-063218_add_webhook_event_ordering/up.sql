@@ -0,0 +1,13 @@
+-- Your SQL goes here
+ALTER TABLE events ADD COLUMN IF NOT EXISTS sequence_number BIGINT;
+
+CREATE TABLE IF NOT EXISTS webhook_event_sequence (
+    merchant_id VARCHAR(64) NOT NULL,
+    primary_object_type "EventObjectType" NOT NULL,
+    primary_object_id VARCHAR(64) NOT NULL,
+    last_sequence_number BIGINT NOT NULL,
+    last_acknowledged_sequence_number BIGINT NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (merchant_id, primary_object_type, primary_object_id)
+);
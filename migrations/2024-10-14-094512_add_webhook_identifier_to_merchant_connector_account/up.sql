@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE merchant_connector_account ADD COLUMN IF NOT EXISTS webhook_identifier VARCHAR(64) DEFAULT NULL;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TYPE "PayoutStatus" ADD VALUE IF NOT EXISTS 'returned';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payout_returned';
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS bank_account_token_merchant_id_customer_id_fingerprint_index;
+
+DROP TABLE IF EXISTS bank_account_token;
@@ -0,0 +1,17 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS bank_account_token (
+    token_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    locker_id VARCHAR(64) NOT NULL,
+    fingerprint VARCHAR(128) NOT NULL,
+    masked_account_number VARCHAR(64) NOT NULL,
+    masked_routing_number VARCHAR(64) NOT NULL,
+    verification_status VARCHAR(32) NOT NULL,
+    connector_bank_token VARCHAR(255),
+    merchant_connector_id VARCHAR(64),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS bank_account_token_merchant_id_customer_id_fingerprint_index ON bank_account_token (merchant_id, customer_id, fingerprint);
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TYPE "AttemptStatus" ADD VALUE IF NOT EXISTS 'partially_authorized';
+
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS approved_amount BIGINT DEFAULT NULL;
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_intent_merchant_id_root_payment_id_index;
+
+ALTER TABLE payment_intent DROP COLUMN IF EXISTS root_payment_id;
+
+ALTER TABLE payment_intent DROP COLUMN IF EXISTS parent_payment_id;
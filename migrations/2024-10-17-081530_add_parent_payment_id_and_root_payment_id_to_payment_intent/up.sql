@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE payment_intent ADD COLUMN IF NOT EXISTS parent_payment_id VARCHAR(64);
+
+ALTER TABLE payment_intent ADD COLUMN IF NOT EXISTS root_payment_id VARCHAR(64);
+
+CREATE INDEX IF NOT EXISTS payment_intent_merchant_id_root_payment_id_index ON payment_intent (merchant_id, root_payment_id);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_attempt_merchant_id_created_at_card_hash_index;
+
+ALTER TABLE payment_attempt DROP COLUMN IF EXISTS card_hash;
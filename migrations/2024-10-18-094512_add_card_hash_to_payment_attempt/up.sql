@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS card_hash VARCHAR(128) DEFAULT NULL;
+
+CREATE INDEX IF NOT EXISTS payment_attempt_merchant_id_created_at_card_hash_index ON payment_attempt (merchant_id, created_at, card_hash);
@@ -0,0 +1,15 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS audit_log (
+    id SERIAL PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    actor VARCHAR(255) NOT NULL,
+    resource_type VARCHAR(32) NOT NULL,
+    resource_id VARCHAR(64) NOT NULL,
+    action VARCHAR(32) NOT NULL,
+    changes JSONB NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS audit_log_merchant_id_resource_index ON audit_log (merchant_id, resource_type, resource_id, created_at);
+
+CREATE INDEX IF NOT EXISTS audit_log_merchant_id_created_at_index ON audit_log (merchant_id, created_at);
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE customers ADD COLUMN IF NOT EXISTS tax_ids BYTEA;
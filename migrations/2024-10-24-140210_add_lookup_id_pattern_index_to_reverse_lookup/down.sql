@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX CONCURRENTLY IF EXISTS reverse_lookup_lookup_id_pattern_index;
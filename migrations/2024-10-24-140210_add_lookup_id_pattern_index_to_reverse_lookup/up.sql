@@ -0,0 +1,3 @@
+-- Your SQL goes here
+-- The index is built concurrently, so that the writes to the table are not blocked while it is built
+CREATE INDEX CONCURRENTLY IF NOT EXISTS reverse_lookup_lookup_id_pattern_index ON reverse_lookup (lookup_id text_pattern_ops);
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS expected_settlement_date DATE DEFAULT NULL;
+
+CREATE INDEX IF NOT EXISTS payment_attempt_merchant_id_expected_settlement_date_index ON payment_attempt (merchant_id, expected_settlement_date);
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE customers
+ADD COLUMN IF NOT EXISTS preferred_payment_method_id VARCHAR(64) DEFAULT NULL;
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP TRIGGER IF EXISTS customer_communication_immutable ON customer_communication;
+
+DROP FUNCTION IF EXISTS reject_customer_communication_update;
+
+DROP TABLE IF EXISTS customer_communication;
@@ -0,0 +1,38 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS customer_communication (
+    id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64),
+    channel VARCHAR(32) NOT NULL,
+    summary BYTEA NOT NULL,
+    file_id VARCHAR(64),
+    communicated_at TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS customer_communication_merchant_id_customer_id_index ON customer_communication (merchant_id, customer_id, communicated_at);
+
+CREATE INDEX IF NOT EXISTS customer_communication_merchant_id_payment_id_index ON customer_communication (merchant_id, payment_id);
+
+-- The entries are evidence for the disputes, and are never modified once written, except for the
+-- redaction of their content along with the customer
+CREATE OR REPLACE FUNCTION reject_customer_communication_update() RETURNS TRIGGER AS $$
+BEGIN
+    IF NEW.id IS DISTINCT FROM OLD.id
+        OR NEW.merchant_id IS DISTINCT FROM OLD.merchant_id
+        OR NEW.customer_id IS DISTINCT FROM OLD.customer_id
+        OR NEW.payment_id IS DISTINCT FROM OLD.payment_id
+        OR NEW.channel IS DISTINCT FROM OLD.channel
+        OR NEW.communicated_at IS DISTINCT FROM OLD.communicated_at
+        OR NEW.created_at IS DISTINCT FROM OLD.created_at
+    THEN
+        RAISE EXCEPTION 'Customer communication entries are immutable';
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER customer_communication_immutable
+BEFORE UPDATE ON customer_communication
+FOR EACH ROW EXECUTE FUNCTION reject_customer_communication_update();
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE merchant_connector_account DROP COLUMN IF EXISTS credential_rotation_status;
+ALTER TABLE merchant_connector_account DROP COLUMN IF EXISTS pending_connector_account_details;
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE merchant_connector_account ADD COLUMN IF NOT EXISTS pending_connector_account_details BYTEA DEFAULT NULL;
+ALTER TABLE merchant_connector_account ADD COLUMN IF NOT EXISTS credential_rotation_status VARCHAR(32) DEFAULT NULL;
@@ -0,0 +1,13 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS connector_webhook_offset (
+    merchant_id VARCHAR(64) NOT NULL,
+    merchant_connector_id VARCHAR(128) NOT NULL,
+    connector_name VARCHAR(64) NOT NULL,
+    read_offset VARCHAR(255),
+    log_id VARCHAR(255),
+    reset_count INTEGER NOT NULL DEFAULT 0,
+    last_reset_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (merchant_id, merchant_connector_id)
+);
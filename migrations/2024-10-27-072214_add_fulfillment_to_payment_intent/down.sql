@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_intent
+DROP COLUMN IF EXISTS fulfillment_status,
+DROP COLUMN IF EXISTS shipped_at,
+DROP COLUMN IF EXISTS delivered_at;
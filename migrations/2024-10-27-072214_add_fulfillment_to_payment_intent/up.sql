@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE payment_intent
+ADD COLUMN IF NOT EXISTS fulfillment_status VARCHAR(32),
+ADD COLUMN IF NOT EXISTS shipped_at TIMESTAMP,
+ADD COLUMN IF NOT EXISTS delivered_at TIMESTAMP;
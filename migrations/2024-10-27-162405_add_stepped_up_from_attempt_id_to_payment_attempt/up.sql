@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS stepped_up_from_attempt_id VARCHAR(64) DEFAULT NULL;
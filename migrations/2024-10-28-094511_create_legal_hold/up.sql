@@ -0,0 +1,9 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS legal_hold (
+    merchant_id VARCHAR(64) NOT NULL,
+    entity_type VARCHAR(32) NOT NULL,
+    record_id VARCHAR(64) NOT NULL,
+    reason VARCHAR(255),
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (merchant_id, entity_type, record_id)
+);
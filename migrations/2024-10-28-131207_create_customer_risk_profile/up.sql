@@ -0,0 +1,13 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS customer_risk_profile (
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    risk_score SMALLINT NOT NULL,
+    risk_tier VARCHAR(16) NOT NULL,
+    dispute_count INTEGER NOT NULL DEFAULT 0,
+    lost_dispute_count INTEGER NOT NULL DEFAULT 0,
+    decline_count INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (merchant_id, customer_id)
+);
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS payment_link_fulfilment_time_index ON payment_link (fulfilment_time);
@@ -0,0 +1,9 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS resource_sequence (
+    namespace VARCHAR(64) NOT NULL,
+    resource_id VARCHAR(255) NOT NULL,
+    last_value BIGINT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (namespace, resource_id)
+);
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE merchant_account DROP COLUMN IF EXISTS default_currency,
+DROP COLUMN IF EXISTS require_explicit_currency;
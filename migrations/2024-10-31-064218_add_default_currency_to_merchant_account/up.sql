@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE merchant_account
+ADD COLUMN IF NOT EXISTS default_currency "Currency",
+ADD COLUMN IF NOT EXISTS require_explicit_currency BOOLEAN;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS incoming_webhook_event_merchant_id_payment_id_index;
+
+DROP TABLE IF EXISTS incoming_webhook_event;
@@ -0,0 +1,11 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS incoming_webhook_event (
+    event_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(64) NOT NULL,
+    event_type VARCHAR(64) NOT NULL,
+    received_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS incoming_webhook_event_merchant_id_payment_id_index ON incoming_webhook_event (merchant_id, payment_id);
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TYPE "CaptureStatus" ADD VALUE IF NOT EXISTS 'reversed';